@@ -0,0 +1,301 @@
+//! End-to-end time of 100 bulk updates against a local mock Jira that
+//! answers every request after a fixed latency, at concurrency 1, 4 and 8.
+//!
+//! `BULK_BENCH_LATENCY_MS` sets the latency (default 20) and
+//! `BULK_BENCH_OUTPUT` the JSON file the results are written to for trend
+//! tracking (default `target/criterion/bulk_operations.json`). Run it with
+//! `cargo run --package xtask -- bench`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::types::jira::{
+    BulkOperationConfig, BulkOperationItem, BulkOperationResult, BulkOperationSummary,
+    BulkOperationType,
+};
+use serde_json::json;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+const OPERATIONS: usize = 100;
+const CONCURRENCY_LEVELS: [usize; 3] = [1, 4, 8];
+
+/// Counts allocations so the summary building can be measured
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// A mock Jira answering `GET issue/{key}` with the key and `PUT issue/{key}`
+/// with 204, each after `latency`. Returns its base URL.
+async fn start_mock_jira(latency: Duration) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                continue;
+            };
+            tokio::spawn(serve_connection(stream, latency));
+        }
+    });
+    format!("http://{address}")
+}
+
+/// Answer the requests of one kept-alive connection
+async fn serve_connection(stream: tokio::net::TcpStream, latency: Duration) {
+    let mut stream = BufReader::new(stream);
+    loop {
+        let mut request_line = String::new();
+        if stream.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+            return;
+        }
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            if stream.read_line(&mut header).await.unwrap_or(0) == 0 {
+                return;
+            }
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+        let mut body = vec![0; content_length];
+        if stream.read_exact(&mut body).await.is_err() {
+            return;
+        }
+
+        tokio::time::sleep(latency).await;
+
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default();
+        let path = parts.next().unwrap_or_default();
+        let key = path
+            .split('?')
+            .next()
+            .and_then(|path| path.rsplit('/').next())
+            .unwrap_or_default();
+        let response = match method {
+            "GET" => {
+                let body = json!({"key": key}).to_string();
+                format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                    body.len()
+                )
+            }
+            "PUT" => "HTTP/1.1 204 No Content\r\ncontent-length: 0\r\n\r\n".to_string(),
+            _ => "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n".to_string(),
+        };
+        if stream
+            .get_mut()
+            .write_all(response.as_bytes())
+            .await
+            .is_err()
+        {
+            return;
+        }
+    }
+}
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "bench@example.com".to_string(),
+        personal_access_token: "bench-token".to_string(),
+        strict_ssl: Some(false),
+        // The throttle would otherwise cap every level at 10 requests a second
+        core_requests_per_second: Some(10_000.0),
+        ..Default::default()
+    }
+}
+
+fn update_operations() -> Vec<BulkOperationItem> {
+    (1..=OPERATIONS)
+        .map(|i| BulkOperationItem {
+            issue_key: format!("BENCH-{i}"),
+            operation_type: BulkOperationType::Update,
+            data: json!({"fields": {"labels": ["benchmarked"]}}),
+        })
+        .collect()
+}
+
+/// Every operation in one batch, so only `max_concurrency` limits how many
+/// run at once
+fn bulk_config(concurrency: usize) -> BulkOperationConfig {
+    BulkOperationConfig {
+        batch_size: Some(OPERATIONS),
+        rate_limit_ms: None,
+        max_concurrency: Some(concurrency),
+        ..Default::default()
+    }
+}
+
+/// What must not depend on the concurrency: the keys in order, whether each
+/// succeeded, and the counts
+fn outcome(summary: &BulkOperationSummary) -> (Vec<(String, bool)>, [u32; 4]) {
+    (
+        summary
+            .results
+            .iter()
+            .map(|r| (r.issue_key.clone(), r.success))
+            .collect(),
+        [
+            summary.total_operations,
+            summary.successful_operations,
+            summary.failed_operations,
+            summary.skipped_operations,
+        ],
+    )
+}
+
+/// Allocations made building a summary from `results`
+fn summary_allocations(results: &[BulkOperationResult]) -> usize {
+    let results = results.to_vec();
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let mut summary = BulkOperationSummary::new();
+    for result in results {
+        summary.add_result(result);
+    }
+    let _ = summary.describe_errors();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    drop(summary);
+    allocations
+}
+
+fn benchmark_bulk_updates(c: &mut Criterion) {
+    let latency_ms: u64 = env_or("BULK_BENCH_LATENCY_MS", 20);
+    let output: String = env_or(
+        "BULK_BENCH_OUTPUT",
+        "target/criterion/bulk_operations.json".to_string(),
+    );
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let base_url = rt.block_on(start_mock_jira(Duration::from_millis(latency_ms)));
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+
+    // Concurrency must change the time taken, nothing else
+    let mut expected = None;
+    let mut allocations = Vec::new();
+    for concurrency in CONCURRENCY_LEVELS {
+        let summary = rt
+            .block_on(client.execute_bulk_operations(update_operations(), bulk_config(concurrency)))
+            .unwrap();
+        assert_eq!(
+            summary.successful_operations as usize,
+            OPERATIONS,
+            "{:?}",
+            summary.describe_errors()
+        );
+        let outcome = outcome(&summary);
+        match &expected {
+            None => expected = Some(outcome),
+            Some(expected) => assert_eq!(
+                expected, &outcome,
+                "results differ at concurrency {concurrency}"
+            ),
+        }
+        allocations.push(summary_allocations(&summary.results));
+    }
+
+    let timings: Arc<Mutex<Vec<Vec<Duration>>>> =
+        Arc::new(Mutex::new(vec![Vec::new(); CONCURRENCY_LEVELS.len()]));
+    let mut group = c.benchmark_group("bulk_operations");
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_secs(1));
+    group.measurement_time(Duration::from_secs(40));
+
+    for (level, concurrency) in CONCURRENCY_LEVELS.into_iter().enumerate() {
+        group.bench_with_input(
+            BenchmarkId::new("update_100", concurrency),
+            &concurrency,
+            |b, &concurrency| {
+                b.iter_custom(|iterations| {
+                    let mut total = Duration::ZERO;
+                    for _ in 0..iterations {
+                        let operations = update_operations();
+                        let start = Instant::now();
+                        let summary = rt
+                            .block_on(
+                                client
+                                    .execute_bulk_operations(operations, bulk_config(concurrency)),
+                            )
+                            .unwrap();
+                        let elapsed = start.elapsed();
+                        assert_eq!(summary.total_operations as usize, OPERATIONS);
+                        timings.lock().unwrap()[level].push(elapsed);
+                        total += elapsed;
+                    }
+                    total
+                });
+            },
+        );
+    }
+    group.finish();
+
+    let timings = timings.lock().unwrap();
+    let results: Vec<_> = CONCURRENCY_LEVELS
+        .iter()
+        .zip(timings.iter())
+        .zip(&allocations)
+        .map(|((concurrency, runs), allocations)| {
+            let mean_ms = runs.iter().map(Duration::as_secs_f64).sum::<f64>() * 1000.0
+                / runs.len().max(1) as f64;
+            json!({
+                "concurrency": concurrency,
+                "runs": runs.len(),
+                "mean_ms": mean_ms,
+                "min_ms": runs.iter().min().map(|d| d.as_secs_f64() * 1000.0),
+                "max_ms": runs.iter().max().map(|d| d.as_secs_f64() * 1000.0),
+                "summary_allocations": allocations,
+            })
+        })
+        .collect();
+    let report = json!({
+        "benchmark": "bulk_operations/update_100",
+        "operations": OPERATIONS,
+        "latency_ms": latency_ms,
+        "results": results,
+    });
+    if let Some(parent) = std::path::Path::new(&output).parent() {
+        std::fs::create_dir_all(parent).unwrap();
+    }
+    std::fs::write(&output, serde_json::to_string_pretty(&report).unwrap()).unwrap();
+    println!("Bulk operation results written to {output}");
+}
+
+criterion_group!(benches, benchmark_bulk_updates);
+criterion_main!(benches);
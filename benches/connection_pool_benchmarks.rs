@@ -0,0 +1,113 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::performance::get_global_metrics;
+use serde_json::json;
+use std::time::Duration;
+
+const SEQUENTIAL_REQUESTS: usize = 200;
+
+/// Requests sent through `JiraClient` to report its connection estimates;
+/// fewer, since the client spaces requests out
+const TRACKED_REQUESTS: usize = 20;
+
+fn create_test_config(base_url: &str, pool_max_idle_per_host: Option<usize>) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test_token".to_string(),
+        timeout_seconds: Some(30),
+        max_results: Some(100),
+        strict_ssl: Some(false),
+        default_project: Some("TEST".to_string()),
+        log_file: None,
+        pool_max_idle_per_host,
+        ..Default::default()
+    }
+}
+
+fn benchmark_sequential_requests(c: &mut Criterion) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut server = rt.block_on(mockito::Server::new_async());
+    let base_url = server.url();
+
+    let mock_response = json!({
+        "id": "10001",
+        "key": "TEST-1",
+        "self": format!("{base_url}/rest/api/2/issue/10001"),
+        "fields": {
+            "summary": "Test Issue"
+        }
+    });
+
+    let _mock = rt.block_on(
+        server
+            .mock("GET", "/rest/api/2/issue/TEST-1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_response.to_string())
+            .expect_at_least(0)
+            .create_async(),
+    );
+
+    let url = format!("{base_url}/rest/api/2/issue/TEST-1");
+    let mut group = c.benchmark_group("connection_pool");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(20));
+
+    // `None` keeps reqwest's default pool, `Some(0)` forces a new connection per request
+    for (label, pool_max_idle_per_host) in [("pooled", None), ("unpooled", Some(0))] {
+        let client = JiraClient::new(create_test_config(&base_url, pool_max_idle_per_host))
+            .expect("Failed to create test client");
+        // Go through the underlying HTTP client so the fixed request spacing of
+        // `JiraClient` does not drown out connection setup costs
+        let http_client = client.http_client();
+
+        group.bench_with_input(
+            BenchmarkId::new("sequential_requests", label),
+            &SEQUENTIAL_REQUESTS,
+            |b, &count| {
+                b.iter(|| {
+                    rt.block_on(async {
+                        for _ in 0..count {
+                            let response = http_client
+                                .get(&url)
+                                .header("Authorization", client.auth_header())
+                                .send()
+                                .await
+                                .unwrap();
+                            black_box(response.bytes().await.unwrap());
+                        }
+                    });
+                });
+            },
+        );
+    }
+
+    group.finish();
+
+    // The loops above bypass `JiraClient`, so its estimate of new and reused
+    // connections is reported separately
+    for (label, pool_max_idle_per_host) in [("pooled", None), ("unpooled", Some(0))] {
+        let client = JiraClient::new(create_test_config(&base_url, pool_max_idle_per_host))
+            .expect("Failed to create test client");
+        let metrics = get_global_metrics();
+        let before = metrics.get_stats();
+        rt.block_on(async {
+            for _ in 0..TRACKED_REQUESTS {
+                let issue: serde_json::Value = client.get("issue/TEST-1").await.unwrap();
+                black_box(issue);
+            }
+        });
+        let after = metrics.get_stats();
+        println!(
+            "connection_pool/{label}: {TRACKED_REQUESTS} requests through JiraClient, \
+             estimated {} new and {} reused connections",
+            after.new_connections - before.new_connections,
+            after.reused_connections - before.reused_connections,
+        );
+    }
+}
+
+criterion_group!(benches, benchmark_sequential_requests);
+criterion_main!(benches);
@@ -14,6 +14,7 @@ fn create_test_config() -> JiraConfig {
         strict_ssl: Some(true),
         default_project: Some("TEST".to_string()),
         log_file: Some("test.log".into()),
+        ..Default::default()
     }
 }
 
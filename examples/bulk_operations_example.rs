@@ -26,6 +26,7 @@ fn main() {
         continue_on_error: true,  // Continue processing even if some operations fail
         rate_limit_ms: Some(200), // Wait 200ms between operations
         max_retries: Some(3),     // Retry failed operations up to 3 times
+        ..Default::default()
     };
     println!("Custom config: {:?}", custom_config);
 
@@ -25,6 +25,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
 
     println!("🚀 Rust Jira MCP - Project Configuration and Metadata Example");
@@ -2,8 +2,13 @@
 """
 Anonymize Jira API fixtures for safe version control
 This script takes raw fixtures and creates anonymized versions suitable for testing
+
+With --gdpr, users are rewritten the way GDPR-strict Jira Cloud sites return
+them: identified by accountId only, without name, key or emailAddress, and with
+every other user hiding their displayName as users with a private profile do.
 """
 
+import argparse
 import json
 import os
 import re
@@ -11,7 +16,8 @@ from pathlib import Path
 from typing import Any, Dict, List
 
 class JiraFixtureAnonymizer:
-    def __init__(self):
+    def __init__(self, gdpr: bool = False):
+        self.gdpr = gdpr
         self.anonymized_data = {
             "users": {},
             "projects": {},
@@ -39,7 +45,9 @@ class JiraFixtureAnonymizer:
             anonymized["key"] = anonymized.get("name", "user1")
             
         if "displayName" in anonymized:
-            anonymized["displayName"] = f"Test User {len(self.anonymized_data['users'])}"
+            users = list(self.anonymized_data["users"])
+            number = users.index(user_data["name"]) + 1 if user_data.get("name") in users else len(users)
+            anonymized["displayName"] = f"Test User {number}"
             
         if "emailAddress" in anonymized:
             original_email = anonymized["emailAddress"]
@@ -51,7 +59,30 @@ class JiraFixtureAnonymizer:
         if "avatarUrls" in anonymized and isinstance(anonymized["avatarUrls"], dict):
             for size in anonymized["avatarUrls"]:
                 anonymized["avatarUrls"][size] = f"https://jira.example.com/secure/useravatar?ownerId=anon&avatarId=10000"
-                
+
+        if self.gdpr:
+            anonymized = self.gdpr_user(user_data, anonymized)
+
+        return anonymized
+
+    def gdpr_user(self, user_data: Dict[str, Any], anonymized: Dict[str, Any]) -> Dict[str, Any]:
+        """Reshape an anonymized user as a GDPR-strict Cloud site returns it"""
+        original = user_data.get("accountId") or user_data.get("key") or user_data.get("name")
+        account_ids = self.anonymized_data.setdefault("account_ids", {})
+        if original not in account_ids:
+            account_ids[original] = f"5b10ac8d82e05b22cc7d{len(account_ids) + 1:04x}"
+        account_id = account_ids[original]
+        number = list(account_ids).index(original) + 1
+
+        for field in ("name", "key", "emailAddress"):
+            anonymized.pop(field, None)
+        anonymized["accountId"] = account_id
+        anonymized["accountType"] = "atlassian"
+        if "self" in anonymized:
+            anonymized["self"] = f"https://jira.example.com/rest/api/2/user?accountId={account_id}"
+        # Users with a private profile hide their name as well
+        if number % 2 == 0:
+            anonymized.pop("displayName", None)
         return anonymized
     
     def anonymize_project(self, project_data: Dict[str, Any]) -> Dict[str, Any]:
@@ -156,7 +187,7 @@ class JiraFixtureAnonymizer:
         """Recursively anonymize all data"""
         if isinstance(data, dict):
             # Check if this looks like user data
-            if "name" in data and "displayName" in data:
+            if ("name" in data or "accountId" in data) and "displayName" in data:
                 data = self.anonymize_user(data)
             elif "key" in data and "name" in data and "projectTypeKey" in data:
                 data = self.anonymize_project(data)
@@ -213,10 +244,19 @@ class JiraFixtureAnonymizer:
 
 def main():
     """Main function"""
-    input_dir = Path("fixtures/raw")
-    output_dir = Path("fixtures/anonymized")
-    
-    anonymizer = JiraFixtureAnonymizer()
+    parser = argparse.ArgumentParser(description=__doc__.strip().splitlines()[0])
+    parser.add_argument("--input", type=Path, default=Path("fixtures/raw"))
+    parser.add_argument("--output", type=Path, default=Path("fixtures/anonymized"))
+    parser.add_argument(
+        "--gdpr",
+        action="store_true",
+        help="Shape users as GDPR-strict Cloud sites return them",
+    )
+    args = parser.parse_args()
+    input_dir = args.input
+    output_dir = args.output
+
+    anonymizer = JiraFixtureAnonymizer(gdpr=args.gdpr)
     anonymizer.process_directory(input_dir, output_dir)
     
     # Save anonymization mapping for reference
@@ -0,0 +1,274 @@
+//! Default settings of bulk operations, globally and per project.
+//!
+//! Configuration files may carry a `[bulk_defaults]` section, and a
+//! `[bulk_defaults.PROJKEY]` section for each project that needs different
+//! settings, e.g. a slower rate for a project whose automation runs on every
+//! update:
+//!
+//! ```toml
+//! [bulk_defaults]
+//! batch_size = 20
+//! rate_limit_ms = 100
+//!
+//! [bulk_defaults.LEGACY]
+//! batch_size = 5
+//! rate_limit_ms = 1000
+//! max_concurrency = 1
+//! ```
+//!
+//! A bulk tool takes each setting from the first place that has it: the
+//! `config` argument of the call, the section of the project most of its
+//! issues belong to, the global section, then the built-in default.
+
+use crate::error::{JiraError, Result};
+use crate::types::jira::BulkOperationConfig;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+
+/// Settings of a bulk operation, each optional so that the levels can be
+/// merged
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BulkSettings {
+    pub batch_size: Option<usize>,
+    pub rate_limit_ms: Option<u64>,
+    pub max_retries: Option<usize>,
+    pub max_concurrency: Option<usize>,
+    pub continue_on_error: Option<bool>,
+}
+
+/// The `[bulk_defaults]` section: global settings, and settings per project
+/// key from its subsections
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BulkDefaults {
+    #[serde(flatten)]
+    pub global: BulkSettings,
+    #[serde(flatten)]
+    pub projects: BTreeMap<String, BulkSettings>,
+}
+
+impl<'de> Deserialize<'de> for BulkDefaults {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Tables are projects, everything else a global setting
+        let table = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        let mut global = serde_json::Map::new();
+        let mut projects = BTreeMap::new();
+        for (key, value) in table {
+            if value.is_object() {
+                let settings = serde_json::from_value(value)
+                    .map_err(|e| D::Error::custom(format!("Invalid bulk_defaults.{key}: {e}")))?;
+                projects.insert(key.to_uppercase(), settings);
+            } else {
+                global.insert(key, value);
+            }
+        }
+        let global = serde_json::from_value(serde_json::Value::Object(global))
+            .map_err(|e| D::Error::custom(format!("Invalid bulk_defaults: {e}")))?;
+        Ok(Self { global, projects })
+    }
+}
+
+/// Where an effective bulk setting came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "level", content = "project")]
+pub enum BulkConfigSource {
+    /// The `config` argument of the tool call
+    Call,
+    /// The `[bulk_defaults.KEY]` section of this project
+    Project(String),
+    /// The `[bulk_defaults]` section
+    Global,
+    /// [`BulkOperationConfig::default`]
+    BuiltIn,
+}
+
+impl std::fmt::Display for BulkConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Call => write!(f, "call"),
+            Self::Project(key) => write!(f, "project {key}"),
+            Self::Global => write!(f, "bulk_defaults"),
+            Self::BuiltIn => write!(f, "built-in"),
+        }
+    }
+}
+
+/// The settings a bulk operation runs with and where each came from
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectiveBulkConfig {
+    #[serde(skip)]
+    pub config: BulkOperationConfig,
+    /// The project most of the operation's issues belong to
+    pub project: Option<String>,
+    /// Each setting with its value and source, in a fixed order
+    pub settings: Vec<(&'static str, serde_json::Value, BulkConfigSource)>,
+}
+
+impl EffectiveBulkConfig {
+    /// One line listing each setting with its source, e.g. `Bulk settings:
+    /// batch_size 5 (project LEGACY), rate_limit_ms 100 (built-in), …`
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let settings: Vec<String> = self
+            .settings
+            .iter()
+            .map(|(name, value, source)| format!("{name} {value} ({source})"))
+            .collect();
+        format!("Bulk settings: {}", settings.join(", "))
+    }
+
+    /// Whether any setting came from `[bulk_defaults]` rather than the call
+    /// or the built-in defaults
+    #[must_use]
+    pub fn from_bulk_defaults(&self) -> bool {
+        self.settings.iter().any(|(_, _, source)| {
+            matches!(
+                source,
+                BulkConfigSource::Project(_) | BulkConfigSource::Global
+            )
+        })
+    }
+}
+
+/// The project most of `issue_keys` belong to; the first one seen wins a
+/// tie. Keys without a project prefix are not counted.
+#[must_use]
+pub fn majority_project<S: AsRef<str>>(issue_keys: &[S]) -> Option<String> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for key in issue_keys {
+        let Some((project, number)) = key.as_ref().trim().rsplit_once('-') else {
+            continue;
+        };
+        if project.is_empty() || number.parse::<u64>().is_err() {
+            continue;
+        }
+        let project = project.to_uppercase();
+        match counts.iter_mut().find(|(known, _)| *known == project) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((project, 1)),
+        }
+    }
+    // `max_by_key` keeps the last of equal counts, so look from the back
+    counts
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(project, _)| project)
+}
+
+/// The settings a bulk operation on `issue_keys` runs with: those of the
+/// `call` argument, then of the majority project's section of `defaults`,
+/// then of its global section, then the built-in ones
+///
+/// # Errors
+///
+/// Returns a validation error if `call` is not an object of bulk settings.
+pub fn resolve_bulk_config<S: AsRef<str>>(
+    defaults: Option<&BulkDefaults>,
+    issue_keys: &[S],
+    call: Option<&serde_json::Value>,
+) -> Result<EffectiveBulkConfig> {
+    let call: BulkSettings = match call {
+        Some(value) if !value.is_null() => serde_json::from_value(value.clone()).map_err(|e| {
+            JiraError::validation_error("config", &format!("Invalid bulk settings: {e}"))
+        })?,
+        _ => BulkSettings::default(),
+    };
+    let project = majority_project(issue_keys);
+    let empty = BulkSettings::default();
+    let global = defaults.map_or(&empty, |defaults| &defaults.global);
+    let in_project = project
+        .as_ref()
+        .and_then(|key| defaults?.projects.get(key))
+        .unwrap_or(&empty);
+    let from_project = BulkConfigSource::Project(project.clone().unwrap_or_default());
+
+    let built_in = BulkOperationConfig::default();
+    let mut settings = Vec::new();
+    let batch_size = pick_setting(
+        &mut settings,
+        "batch_size",
+        [call.batch_size, in_project.batch_size, global.batch_size],
+        &from_project,
+        built_in.batch_size.unwrap_or(10),
+    );
+    let rate_limit_ms = pick_setting(
+        &mut settings,
+        "rate_limit_ms",
+        [
+            call.rate_limit_ms,
+            in_project.rate_limit_ms,
+            global.rate_limit_ms,
+        ],
+        &from_project,
+        built_in.rate_limit_ms.unwrap_or(100),
+    );
+    let max_retries = pick_setting(
+        &mut settings,
+        "max_retries",
+        [call.max_retries, in_project.max_retries, global.max_retries],
+        &from_project,
+        built_in.max_retries.unwrap_or(3),
+    );
+    let max_concurrency = pick_setting(
+        &mut settings,
+        "max_concurrency",
+        [
+            call.max_concurrency,
+            in_project.max_concurrency,
+            global.max_concurrency,
+        ],
+        &from_project,
+        built_in.max_concurrency.unwrap_or(1),
+    );
+    let continue_on_error = pick_setting(
+        &mut settings,
+        "continue_on_error",
+        [
+            call.continue_on_error,
+            in_project.continue_on_error,
+            global.continue_on_error,
+        ],
+        &from_project,
+        built_in.continue_on_error,
+    );
+
+    Ok(EffectiveBulkConfig {
+        config: BulkOperationConfig {
+            batch_size: Some(batch_size.max(1)),
+            continue_on_error,
+            rate_limit_ms: Some(rate_limit_ms),
+            max_retries: Some(max_retries),
+            max_concurrency: Some(max_concurrency.max(1)),
+            capture_previous_fields: false,
+        },
+        project,
+        settings,
+    })
+}
+
+/// The value of the first of the call, project and global `levels` that
+/// has one, else `built_in`, recorded with its source in `settings`
+fn pick_setting<T: Copy + Serialize>(
+    settings: &mut Vec<(&'static str, serde_json::Value, BulkConfigSource)>,
+    name: &'static str,
+    levels: [Option<T>; 3],
+    from_project: &BulkConfigSource,
+    built_in: T,
+) -> T {
+    let sources = [
+        BulkConfigSource::Call,
+        from_project.clone(),
+        BulkConfigSource::Global,
+    ];
+    let (value, source) = levels
+        .into_iter()
+        .zip(sources)
+        .find_map(|(value, source)| value.map(|value| (value, source)))
+        .unwrap_or((built_in, BulkConfigSource::BuiltIn));
+    settings.push((name, serde_json::json!(value), source));
+    value
+}
@@ -14,6 +14,176 @@ pub struct JiraConfig {
     pub timeout_seconds: Option<u64>,
     pub log_file: Option<PathBuf>,
     pub strict_ssl: Option<bool>,
+    /// Maximum number of idle connections kept per host (0 disables pooling)
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long an idle pooled connection is kept before being closed
+    pub pool_idle_timeout_seconds: Option<u64>,
+    /// TCP keep-alive interval for open connections
+    pub tcp_keepalive_seconds: Option<u64>,
+    /// Skip HTTP/1.1 negotiation and speak HTTP/2 directly
+    pub http2_prior_knowledge: Option<bool>,
+    /// Remaining rate-limit budget (percent) below which requests are slowed down
+    pub throttle_threshold_percent: Option<u32>,
+    /// Base rate (requests per second) of Jira REST API requests
+    pub core_requests_per_second: Option<f64>,
+    /// Base rate (requests per second) of Jira Software (`agile/1.0`) requests
+    pub agile_requests_per_second: Option<f64>,
+    /// Base rate (requests per second) of Zephyr requests
+    pub zephyr_requests_per_second: Option<f64>,
+    /// Base rate (requests per second) of attachment uploads and downloads
+    pub attachment_requests_per_second: Option<f64>,
+    /// Cache `search_jira_issues` results for repeated identical queries
+    pub search_cache_enabled: Option<bool>,
+    /// How long cached search results stay fresh
+    pub search_cache_ttl_seconds: Option<u64>,
+    /// Answer GET requests with their last response while Jira is in a
+    /// maintenance window
+    pub serve_stale_during_maintenance: Option<bool>,
+    /// Comma-separated endpoint categories (e.g. `project,field`) whose concurrent
+    /// identical GET requests share a single upstream call
+    pub coalesce_endpoints: Option<String>,
+    /// Longest text field (description, comment body, ...) included in tool
+    /// output before it is truncated; 0 disables truncation
+    pub max_field_output_chars: Option<usize>,
+    /// Largest message (bytes) written to the client; larger responses are
+    /// replaced by an explanation with the start of their text. 0 disables
+    /// the cap
+    pub max_response_bytes: Option<usize>,
+    /// Replace the display names and email addresses of users in tool output
+    /// with aliases, for sessions that are screenshotted or recorded
+    pub anonymize_output: Option<bool>,
+    /// Comma-separated directories tools may write files into; unset disables
+    /// writing files altogether
+    pub file_allowlist: Option<String>,
+    /// Custom field holding story points (e.g. `customfield_10016`); detected
+    /// from the field catalog when unset
+    pub story_points_field: Option<String>,
+    /// Largest attachment (bytes) `download_jira_attachment` fetches without
+    /// `force: true`
+    pub max_attachment_download_bytes: Option<u64>,
+    /// Directory `debug_capture: true` calls write their HTTP exchanges to;
+    /// defaults to `rust-jira-mcp-captures` in the system temp directory
+    pub debug_capture_dir: Option<PathBuf>,
+    /// Also retry POSTs that fail with a server error or timeout, at the risk
+    /// of applying them twice
+    pub retry_non_idempotent: Option<bool>,
+    /// Link type whose inward side marks an issue as blocked, checked by
+    /// `bulk_transition_issues` with `check_blockers: true`
+    pub blocker_link_type: Option<String>,
+    /// `User-Agent` of every request, `rust-jira-mcp/<version>` when unset
+    pub user_agent: Option<String>,
+    /// Most worklog entries `get_jira_issue_work_logs` reads for one issue
+    pub max_worklogs: Option<usize>,
+    /// Longest `get_project_metadata` waits for one of its sections; a
+    /// section taking longer is reported as timed out while the others are
+    /// returned
+    pub metadata_subcall_timeout_seconds: Option<u64>,
+    /// Check the connection and fill the metadata caches before serving
+    pub warmup: Option<bool>,
+    /// Keep the issues fetched most often warm by refreshing them in the
+    /// background; `false` stops prefetching altogether
+    pub prefetch_enabled: Option<bool>,
+    /// Fetches of an issue after which it is kept warm
+    pub prefetch_threshold: Option<u64>,
+    /// Time between two background fetches of a warm issue
+    pub prefetch_interval_seconds: Option<u64>,
+    /// Comma-separated fields (ids or names) `search_jira_issues` shows as
+    /// table columns when called without `fields`
+    pub search_default_fields: Option<String>,
+    /// Let comment and work log tools take `on_behalf_of`, which starts the
+    /// text with a line naming the user it was written for
+    pub annotate_on_behalf: Option<bool>,
+    /// Label added to every issue created or cloned, e.g. `created-by-mcp`
+    pub auto_label: Option<String>,
+    /// Line appended to every comment and work log comment written
+    pub comment_signature: Option<String>,
+    /// Link type from a test case to the requirement it tests, by name
+    /// (`Tests`) or the test's side of it (`tests`), read by
+    /// `zephyr_traceability_report`
+    pub test_link_type: Option<String>,
+    /// Language sent as `Accept-Language`, e.g. `en-US`, for error messages
+    /// Jira writes; the server's own locale when unset
+    pub locale: Option<String>,
+    /// Time zone dates are shown in: `local`, `UTC` or an offset such as
+    /// `+02:00`
+    pub display_timezone: Option<String>,
+    /// strftime format dates are shown in
+    pub display_date_format: Option<String>,
+    /// File finished bulk operations are appended to as JSON lines and read
+    /// back from at start-up; unset keeps the history in memory only
+    pub bulk_history_file: Option<PathBuf>,
+    /// How much tools say about each item: `minimal`, `normal` or
+    /// `detailed`; a call's `verbosity` argument takes precedence
+    pub output_verbosity: Option<String>,
+    /// How comments, descriptions and work log comments are written when a
+    /// call has no `input_format`: `wiki` (sent as written) or `markdown`
+    /// (converted to wiki markup, or to ADF for REST API v3)
+    pub default_input_format: Option<String>,
+    /// Hours in a working day, which is how long `1d` of logged work or an
+    /// estimate is; match the instance's time tracking settings
+    pub work_hours_per_day: Option<f64>,
+    /// Days in a working week, which is how long `1w` is
+    pub work_days_per_week: Option<f64>,
+    /// Root of the Jira site, e.g. `https://jira.example.com/jira`, which
+    /// Zephyr and other non-core APIs hang off; derived from `api_base_url`
+    /// when unset
+    pub site_base_url: Option<String>,
+    /// Largest `max_results` paginated tools accept; larger values are
+    /// rejected rather than silently capped
+    pub max_results_limit: Option<u32>,
+    /// Address to receive Jira webhooks on, e.g. `127.0.0.1:8787`; no
+    /// webhooks are received when unset
+    pub webhook_listen_addr: Option<String>,
+    /// Shared secret webhooks are signed with; required with
+    /// `webhook_listen_addr`
+    pub webhook_secret: Option<String>,
+    /// Webhook events kept for `get_recent_jira_events`; the oldest are
+    /// dropped beyond it
+    pub webhook_buffer_size: Option<usize>,
+    /// URL Jira reaches the webhook receiver at, e.g.
+    /// `https://hooks.example.com/webhooks/jira`; webhooks can only be
+    /// registered once it is set
+    pub webhook_public_url: Option<String>,
+    /// Register the Jira Service Management tools; off by default, as sites
+    /// without JSM answer their requests with 404
+    pub jsm_enabled: Option<bool>,
+    /// Register the Assets (Insight) tools and show the names of the objects
+    /// in Assets custom fields of issues; off by default
+    pub assets_enabled: Option<bool>,
+    /// Base URL of the Assets API; derived from the site when unset:
+    /// `{site}/rest/insight/1.0` on Data Center, the workspace's
+    /// `https://api.atlassian.com/jsm/assets` API on Cloud
+    pub assets_api_url: Option<String>,
+    /// Register the Zephyr tools: `true`, `false` or `auto` (default), which
+    /// asks ZAPI once at startup whether Zephyr is installed
+    pub zephyr_enabled: Option<String>,
+    /// Register the sprint and board tools of the Jira Software (agile) API;
+    /// on by default
+    pub agile_enabled: Option<bool>,
+    /// Where worklogs are read and written: `jira` (default) or `tempo`
+    pub worklog_provider: Option<String>,
+    /// Base URL of the Tempo REST API; `https://api.tempo.io/4` when unset
+    pub tempo_api_url: Option<String>,
+    /// Tempo API token; required with `worklog_provider = "tempo"`
+    pub tempo_api_token: Option<String>,
+    /// Settings of bulk operations from the `[bulk_defaults]` section of a
+    /// configuration file, globally and per project
+    pub bulk_defaults: Option<crate::config::bulk::BulkDefaults>,
+    /// Rules from the `[[policies]]` sections of a configuration file that
+    /// tool calls writing to Jira are checked against
+    pub policies: Option<Vec<crate::config::policies::WritePolicy>>,
+    /// Post-processing of tool output from the `[response_middleware]`
+    /// section of a configuration file
+    pub response_middleware: Option<crate::config::response::ResponseMiddlewareConfig>,
+}
+
+/// Whether `value` can be sent as `Accept-Language`: language tags with
+/// optional weights, in ASCII
+fn is_language_list(value: &str) -> bool {
+    !value.trim().is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_,;=.* ".contains(c))
 }
 
 impl Default for JiraConfig {
@@ -27,6 +197,69 @@ impl Default for JiraConfig {
             timeout_seconds: Some(30),
             log_file: None,
             strict_ssl: Some(true),
+            pool_max_idle_per_host: None,
+            pool_idle_timeout_seconds: Some(90),
+            tcp_keepalive_seconds: Some(60),
+            http2_prior_knowledge: Some(false),
+            throttle_threshold_percent: Some(20),
+            core_requests_per_second: Some(10.0),
+            agile_requests_per_second: Some(10.0),
+            zephyr_requests_per_second: Some(10.0),
+            attachment_requests_per_second: Some(10.0),
+            search_cache_enabled: Some(false),
+            serve_stale_during_maintenance: Some(false),
+            search_cache_ttl_seconds: Some(30),
+            coalesce_endpoints: None,
+            max_field_output_chars: Some(4000),
+            max_response_bytes: Some(
+                crate::mcp::transport::response_limit::DEFAULT_MAX_RESPONSE_BYTES,
+            ),
+            anonymize_output: Some(false),
+            file_allowlist: None,
+            story_points_field: None,
+            max_attachment_download_bytes: Some(10 * 1024 * 1024),
+            debug_capture_dir: None,
+            retry_non_idempotent: Some(false),
+            blocker_link_type: Some("Blocks".to_string()),
+            user_agent: Some(crate::jira::identity::default_user_agent()),
+            max_worklogs: Some(1000),
+            metadata_subcall_timeout_seconds: Some(15),
+            warmup: Some(false),
+            prefetch_enabled: Some(false),
+            prefetch_threshold: Some(crate::jira::prefetch::DEFAULT_PREFETCH_THRESHOLD),
+            prefetch_interval_seconds: Some(
+                crate::jira::prefetch::DEFAULT_PREFETCH_INTERVAL.as_secs(),
+            ),
+            search_default_fields: None,
+            annotate_on_behalf: Some(false),
+            auto_label: None,
+            comment_signature: None,
+            test_link_type: Some("Tests".to_string()),
+            locale: None,
+            display_timezone: Some("local".to_string()),
+            display_date_format: Some(crate::utils::datetime::DEFAULT_DATE_FORMAT.to_string()),
+            bulk_history_file: None,
+            output_verbosity: Some("normal".to_string()),
+            default_input_format: Some("wiki".to_string()),
+            work_hours_per_day: Some(crate::utils::duration::DEFAULT_WORK_HOURS_PER_DAY),
+            work_days_per_week: Some(crate::utils::duration::DEFAULT_WORK_DAYS_PER_WEEK),
+            site_base_url: None,
+            max_results_limit: Some(crate::mcp::tools::pagination::DEFAULT_MAX_RESULTS_LIMIT),
+            webhook_listen_addr: None,
+            webhook_secret: None,
+            webhook_buffer_size: Some(crate::jira::events::DEFAULT_EVENT_BUFFER_SIZE),
+            webhook_public_url: None,
+            jsm_enabled: Some(false),
+            assets_enabled: Some(false),
+            assets_api_url: None,
+            zephyr_enabled: Some("auto".to_string()),
+            agile_enabled: Some(true),
+            worklog_provider: Some("jira".to_string()),
+            tempo_api_url: None,
+            tempo_api_token: None,
+            bulk_defaults: None,
+            policies: None,
+            response_middleware: None,
         }
     }
 }
@@ -99,11 +332,234 @@ impl JiraConfig {
         }
     }
 
+    /// The configured `User-Agent`, or `rust-jira-mcp/<version>`
+    #[must_use]
+    pub fn user_agent(&self) -> String {
+        self.user_agent
+            .as_deref()
+            .map(str::trim)
+            .filter(|agent| !agent.is_empty())
+            .map_or_else(
+                crate::jira::identity::default_user_agent,
+                ToString::to_string,
+            )
+    }
+
     #[must_use]
     pub fn timeout_duration(&self) -> std::time::Duration {
         std::time::Duration::from_secs(self.timeout_seconds.unwrap_or(30))
     }
 
+    #[must_use]
+    pub fn pool_idle_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.pool_idle_timeout_seconds.unwrap_or(90))
+    }
+
+    #[must_use]
+    pub fn tcp_keepalive(&self) -> Option<std::time::Duration> {
+        match self.tcp_keepalive_seconds {
+            Some(0) => None,
+            Some(secs) => Some(std::time::Duration::from_secs(secs)),
+            None => Some(std::time::Duration::from_secs(60)),
+        }
+    }
+
+    #[must_use]
+    pub fn max_worklogs(&self) -> usize {
+        self.max_worklogs.unwrap_or(1000)
+    }
+
+    #[must_use]
+    pub fn metadata_subcall_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.metadata_subcall_timeout_seconds.unwrap_or(15))
+    }
+
+    #[must_use]
+    pub fn search_cache_ttl(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.search_cache_ttl_seconds.unwrap_or(30))
+    }
+
+    #[must_use]
+    pub fn prefetch_interval(&self) -> std::time::Duration {
+        self.prefetch_interval_seconds.map_or(
+            crate::jira::prefetch::DEFAULT_PREFETCH_INTERVAL,
+            std::time::Duration::from_secs,
+        )
+    }
+
+    /// Whether concurrent GET requests to `endpoint` should be coalesced.
+    ///
+    /// The category is the first path segment of the endpoint, so `project/TEST/statuses`
+    /// belongs to `project`.
+    #[must_use]
+    pub fn coalesces_endpoint(&self, endpoint: &str) -> bool {
+        let Some(categories) = &self.coalesce_endpoints else {
+            return false;
+        };
+        let category = endpoint.split(['/', '?']).next().unwrap_or_default();
+        categories
+            .split(',')
+            .map(str::trim)
+            .any(|c| c == "*" || (!c.is_empty() && c == category))
+    }
+
+    /// `Accept-Language` header for the configured `locale`; `None` when no
+    /// locale is set or it is not a list of language tags such as `en-US` or
+    /// `de-DE,de;q=0.9`
+    #[must_use]
+    pub fn accept_language(&self) -> Option<reqwest::header::HeaderValue> {
+        self.locale
+            .as_deref()
+            .filter(|locale| is_language_list(locale))
+            .and_then(|locale| reqwest::header::HeaderValue::from_str(locale).ok())
+    }
+
+    /// Directory debug captures are written to
+    #[must_use]
+    pub fn debug_capture_directory(&self) -> PathBuf {
+        self.debug_capture_dir
+            .clone()
+            .unwrap_or_else(|| std::env::temp_dir().join("rust-jira-mcp-captures"))
+    }
+
+    /// Directories tools are allowed to write files into
+    #[must_use]
+    pub fn allowed_directories(&self) -> Vec<PathBuf> {
+        self.file_allowlist
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    /// Fields `search_jira_issues` shows as columns when called without
+    /// `fields`; empty when unset
+    #[must_use]
+    pub fn default_search_fields(&self) -> Vec<String> {
+        self.search_default_fields
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Whether the configured base URL points at version 3 of the REST API,
+    /// which expects rich text as Atlassian Document Format.
+    #[must_use]
+    pub fn is_api_v3(&self) -> bool {
+        self.api_base_url
+            .trim_end_matches('/')
+            .ends_with("/rest/api/3")
+    }
+
+    /// Root of the Jira site without a trailing slash: `site_base_url` if
+    /// set, otherwise `api_base_url` up to its `/rest/api/N` suffix, keeping
+    /// any context path such as `/jira`.
+    #[must_use]
+    pub fn site_url(&self) -> String {
+        if let Some(site) = self
+            .site_base_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|site| !site.is_empty())
+        {
+            return site.trim_end_matches('/').to_string();
+        }
+        let base = self.api_base_url.trim().trim_end_matches('/');
+        match base.rfind("/rest/api/") {
+            Some(index)
+                if base[index + "/rest/api/".len()..]
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric()) =>
+            {
+                base[..index].to_string()
+            }
+            _ => base.to_string(),
+        }
+    }
+
+    /// Link to an issue in the Jira UI, e.g.
+    /// `https://example.atlassian.net/browse/PROJ-1`, or
+    /// `https://host/jira/browse/PROJ-1` on a context-path install.
+    #[must_use]
+    pub fn browse_url(&self, issue_key: &str) -> String {
+        format!("{}/browse/{issue_key}", self.site_url())
+    }
+
+    /// Whether the configured site is Jira Cloud rather than Server/Data Center.
+    #[must_use]
+    pub fn is_cloud(&self) -> bool {
+        url::Url::parse(&self.api_base_url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| host.ends_with(".atlassian.net")))
+            .unwrap_or(false)
+    }
+
+    /// The URL to register webhooks with: `webhook_public_url` with the
+    /// webhook secret as its `token` query parameter, since webhooks
+    /// registered through the REST API are not signed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `webhook_public_url` is unset or not an HTTP(S)
+    /// URL.
+    pub fn webhook_registration_url(&self) -> crate::error::Result<String> {
+        let public_url = self
+            .webhook_public_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .ok_or_else(|| {
+                crate::error::JiraError::config_error(
+                    "Set JIRA_WEBHOOK_PUBLIC_URL to the URL Jira reaches the webhook receiver at before registering webhooks",
+                )
+            })?;
+        let mut url = url::Url::parse(public_url)
+            .ok()
+            .filter(|url| matches!(url.scheme(), "http" | "https"))
+            .ok_or_else(|| {
+                crate::error::JiraError::config_error(&format!(
+                    "Invalid webhook public URL '{public_url}'"
+                ))
+            })?;
+        if let Some(secret) = self
+            .webhook_secret
+            .as_deref()
+            .filter(|secret| !secret.is_empty())
+        {
+            url.query_pairs_mut().append_pair("token", secret);
+        }
+        Ok(url.to_string())
+    }
+
+    /// Whether worklogs go through Tempo rather than Jira's worklog API
+    #[must_use]
+    pub fn uses_tempo(&self) -> bool {
+        self.worklog_provider
+            .as_deref()
+            .is_some_and(|provider| provider.trim().eq_ignore_ascii_case("tempo"))
+    }
+
+    /// Base URL of the Tempo REST API
+    #[must_use]
+    pub fn tempo_api_url(&self) -> &str {
+        self.tempo_api_url
+            .as_deref()
+            .unwrap_or("https://api.tempo.io/4/")
+    }
+
+    /// Whether idle connections are kept around for reuse.
+    #[must_use]
+    pub fn connection_pooling_enabled(&self) -> bool {
+        self.pool_max_idle_per_host != Some(0)
+    }
+
     /// Load configuration with secret management
     ///
     /// # Errors
@@ -114,17 +570,34 @@ impl JiraConfig {
     /// - Required environment variables are missing
     pub async fn load_with_secrets(secret_manager: &SecretManager) -> Result<Self> {
         let mut config = Self::load()?;
+        config.apply_secrets(secret_manager).await?;
+        Ok(config)
+    }
 
-        // Override sensitive fields with secrets if available
+    /// Override the sensitive fields with the secrets available in
+    /// `secret_manager`
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if secret resolution fails
+    pub async fn apply_secrets(&mut self, secret_manager: &SecretManager) -> Result<()> {
         if let Some(token) = secret_manager.get_secret("personal_access_token").await? {
-            config.personal_access_token = token;
+            self.personal_access_token = token;
         }
 
         if let Some(email) = secret_manager.get_secret("email").await? {
-            config.email = email;
+            self.email = email;
         }
 
-        Ok(config)
+        if let Some(secret) = secret_manager.get_secret("webhook_secret").await? {
+            self.webhook_secret = Some(secret);
+        }
+
+        if let Some(token) = secret_manager.get_secret("tempo_api_token").await? {
+            self.tempo_api_token = Some(token);
+        }
+
+        Ok(())
     }
 
     /// Validate the configuration
@@ -135,8 +608,14 @@ impl JiraConfig {
     /// - Email format is invalid
     /// - Personal access token is too short
     /// - API base URL format is invalid
+    /// - Locale cannot be sent as an `Accept-Language` header
+    /// - A webhook listen address is set without a webhook secret
     pub fn validate(&self) -> Result<()> {
         use crate::config::validation::ConfigValidator;
+        use crate::mcp::features::FeatureSetting;
+        use crate::mcp::tools::formatting::Verbosity;
+        use crate::mcp::transport::response_limit::MIN_MAX_RESPONSE_BYTES;
+        use crate::utils::markdown::InputFormat;
 
         let validator = ConfigValidator::new()
             .add_rule(
@@ -169,11 +648,241 @@ impl JiraConfig {
                             Err("Invalid URL format".to_string())
                         }
                     }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("locale".to_string())
+                    .custom_validator(|locale| {
+                        if is_language_list(locale) {
+                            Ok(())
+                        } else {
+                            Err(format!(
+                                "Invalid locale '{locale}'; use a language tag such as en-US"
+                            ))
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("output_verbosity".to_string())
+                    .custom_validator(|verbosity| {
+                        if Verbosity::parse(verbosity).is_some() {
+                            Ok(())
+                        } else {
+                            Err(format!(
+                                "Invalid output verbosity '{verbosity}'; use minimal, normal or detailed"
+                            ))
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("default_input_format".to_string())
+                    .custom_validator(|format| {
+                        if InputFormat::parse(format).is_some() {
+                            Ok(())
+                        } else {
+                            Err(format!(
+                                "Invalid default input format '{format}'; use wiki or markdown"
+                            ))
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("zephyr_enabled".to_string())
+                    .custom_validator(|setting| {
+                        if FeatureSetting::parse(setting).is_some() {
+                            Ok(())
+                        } else {
+                            Err(format!(
+                                "Invalid Zephyr setting '{setting}'; use true, false or auto"
+                            ))
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("site_base_url".to_string())
+                    .custom_validator(|url| {
+                        if url.starts_with("http://") || url.starts_with("https://") {
+                            Ok(())
+                        } else {
+                            Err("Invalid site URL format".to_string())
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("work_hours_per_day".to_string())
+                    .custom_validator(|hours| match hours.parse::<f64>() {
+                        Ok(hours) if hours > 0.0 && hours <= 24.0 => Ok(()),
+                        _ => Err(format!(
+                            "Invalid work hours per day '{hours}'; use a number above 0 and at most 24"
+                        )),
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("work_days_per_week".to_string())
+                    .custom_validator(|days| match days.parse::<f64>() {
+                        Ok(days) if days > 0.0 && days <= 7.0 => Ok(()),
+                        _ => Err(format!(
+                            "Invalid work days per week '{days}'; use a number above 0 and at most 7"
+                        )),
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("max_response_bytes".to_string())
+                    .custom_validator(|bytes| match bytes.parse::<usize>() {
+                        Ok(bytes) if bytes == 0 || bytes >= MIN_MAX_RESPONSE_BYTES => Ok(()),
+                        _ => Err(format!(
+                            "Invalid max response bytes '{bytes}'; use 0 to disable the cap or at least {MIN_MAX_RESPONSE_BYTES}"
+                        )),
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("webhook_public_url".to_string())
+                    .custom_validator(|url| {
+                        if url.starts_with("http://") || url.starts_with("https://") {
+                            Ok(())
+                        } else {
+                            Err("Invalid webhook public URL format".to_string())
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("worklog_provider".to_string())
+                    .custom_validator(|provider| {
+                        match provider.trim().to_lowercase().as_str() {
+                            "jira" | "tempo" => Ok(()),
+                            _ => Err(format!(
+                                "Invalid worklog provider '{provider}'; use jira or tempo"
+                            )),
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("tempo_api_url".to_string())
+                    .custom_validator(|url| {
+                        if url.starts_with("http://") || url.starts_with("https://") {
+                            Ok(())
+                        } else {
+                            Err("Invalid Tempo API URL format".to_string())
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("auto_label".to_string())
+                    .custom_validator(|label| {
+                        if label.is_empty() || label.len() > 255 {
+                            Err("The automatic label must be 1 to 255 characters".to_string())
+                        } else if label.chars().any(char::is_whitespace) {
+                            Err(format!(
+                                "Invalid automatic label '{label}'; Jira labels cannot contain spaces"
+                            ))
+                        } else {
+                            Ok(())
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("comment_signature".to_string())
+                    .custom_validator(|signature| {
+                        if signature.trim().is_empty() || signature.contains('\n') {
+                            Err("The comment signature must be a single line of text".to_string())
+                        } else if signature.chars().count() > 255 {
+                            Err("The comment signature must be at most 255 characters".to_string())
+                        } else {
+                            Ok(())
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("assets_api_url".to_string())
+                    .custom_validator(|url| {
+                        if url.starts_with("http://") || url.starts_with("https://") {
+                            Ok(())
+                        } else {
+                            Err("Invalid Assets API URL format".to_string())
+                        }
+                    }),
+            )
+            .add_rule(
+                crate::config::validation::ValidationRule::new("webhook_listen_addr".to_string())
+                    .custom_validator(|addr| match addr.rsplit_once(':') {
+                        Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => {
+                            Ok(())
+                        }
+                        _ => Err(format!(
+                            "Invalid webhook listen address '{addr}'; use host:port, e.g. 127.0.0.1:8787"
+                        )),
+                    }),
             );
 
         validator.validate("email", &self.email)?;
         validator.validate("personal_access_token", &self.personal_access_token)?;
         validator.validate("api_base_url", &self.api_base_url)?;
+        if let Some(locale) = &self.locale {
+            validator.validate("locale", locale)?;
+        }
+        if let Some(verbosity) = &self.output_verbosity {
+            validator.validate("output_verbosity", verbosity)?;
+        }
+        if let Some(format) = &self.default_input_format {
+            validator.validate("default_input_format", format)?;
+        }
+        if let Some(setting) = &self.zephyr_enabled {
+            validator.validate("zephyr_enabled", setting)?;
+        }
+        if let Some(site) = &self.site_base_url {
+            validator.validate("site_base_url", site)?;
+        }
+        if let Some(hours) = self.work_hours_per_day {
+            validator.validate("work_hours_per_day", &hours.to_string())?;
+        }
+        if let Some(days) = self.work_days_per_week {
+            validator.validate("work_days_per_week", &days.to_string())?;
+        }
+        if let Some(bytes) = self.max_response_bytes {
+            validator.validate("max_response_bytes", &bytes.to_string())?;
+        }
+        if let Some(url) = &self.webhook_public_url {
+            validator.validate("webhook_public_url", url)?;
+        }
+        if let Some(addr) = &self.webhook_listen_addr {
+            validator.validate("webhook_listen_addr", addr)?;
+            if self
+                .webhook_secret
+                .as_deref()
+                .is_none_or(|secret| secret.trim().is_empty())
+            {
+                anyhow::bail!("webhook_secret must be set when webhook_listen_addr is");
+            }
+        }
+        if let Some(provider) = &self.worklog_provider {
+            validator.validate("worklog_provider", provider)?;
+        }
+        if let Some(url) = &self.tempo_api_url {
+            validator.validate("tempo_api_url", url)?;
+        }
+        if let Some(url) = &self.assets_api_url {
+            validator.validate("assets_api_url", url)?;
+        }
+        if let Some(label) = &self.auto_label {
+            validator.validate("auto_label", label)?;
+        }
+        if let Some(signature) = &self.comment_signature {
+            validator.validate("comment_signature", signature)?;
+        }
+        if self.uses_tempo()
+            && self
+                .tempo_api_token
+                .as_deref()
+                .is_none_or(|token| token.trim().is_empty())
+        {
+            anyhow::bail!("tempo_api_token must be set when worklog_provider is tempo");
+        }
+        if let Some(policies) = &self.policies {
+            crate::config::policies::validate_policies(policies).map_err(anyhow::Error::msg)?;
+        }
+        if let Some(middleware) = &self.response_middleware {
+            crate::config::response::validate_response_middleware(middleware)
+                .map_err(anyhow::Error::msg)?;
+        }
 
         Ok(())
     }
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::RwLock;
+use tokio::sync::{watch, RwLock};
 
 use crate::config::jira::JiraConfig;
 use crate::config::validation::ConfigValidationError;
@@ -17,6 +17,8 @@ pub struct ConfigManager {
     config: Arc<RwLock<JiraConfig>>,
     config_paths: Vec<PathBuf>,
     watch_enabled: bool,
+    /// Publishes every loaded configuration to subscribers
+    updates: Arc<watch::Sender<JiraConfig>>,
 }
 
 /// Configuration sources in order of precedence (highest to lowest)
@@ -68,6 +70,7 @@ impl ConfigManager {
             config: Arc::new(RwLock::new(JiraConfig::default())),
             config_paths: Vec::new(),
             watch_enabled: false,
+            updates: Arc::new(watch::Sender::new(JiraConfig::default())),
         }
     }
 
@@ -82,10 +85,11 @@ impl ConfigManager {
     /// - Hot-reload setup fails
     pub async fn load_with_options(&mut self, options: ConfigOptions) -> Result<()> {
         let config = Self::load_config_from_sources(&options)?;
-        *self.config.write().await = config;
+        *self.config.write().await = config.clone();
+        self.updates.send_replace(config);
 
         if options.hot_reload {
-            self.enable_hot_reload(&options.watch_paths)?;
+            self.enable_hot_reload(&options)?;
         }
 
         Ok(())
@@ -96,13 +100,21 @@ impl ConfigManager {
         self.config.read().await.clone()
     }
 
+    /// Subscribe to configuration changes. The receiver sees the configuration
+    /// loaded last and is marked changed whenever hot-reloading loads another.
+    #[must_use]
+    pub fn subscribe(&self) -> watch::Receiver<JiraConfig> {
+        self.updates.subscribe()
+    }
+
     /// Enable hot-reloading for the specified paths
-    fn enable_hot_reload(&mut self, watch_paths: &[PathBuf]) -> Result<()> {
-        if watch_paths.is_empty() {
+    fn enable_hot_reload(&mut self, options: &ConfigOptions) -> Result<()> {
+        if options.watch_paths.is_empty() {
             return Ok(());
         }
 
         let config = self.config.clone();
+        let updates = self.updates.clone();
         let (tx, mut rx) = tokio::sync::mpsc::channel(100);
 
         let mut watcher = RecommendedWatcher::new(
@@ -116,7 +128,7 @@ impl ConfigManager {
             notify::Config::default(),
         )?;
 
-        for path in watch_paths {
+        for path in &options.watch_paths {
             if path.exists() {
                 watcher.watch(path, RecursiveMode::NonRecursive)?;
                 self.config_paths.push(path.clone());
@@ -126,16 +138,25 @@ impl ConfigManager {
         self.watch_enabled = true;
 
         // Spawn a task to handle file changes
+        let options = options.clone();
         tokio::spawn(async move {
+            // The watcher stops watching once dropped
+            let _watcher = watcher;
             while let Some(_event) = rx.recv().await {
                 // Debounce reloads to avoid rapid successive reloads
                 tokio::time::sleep(Duration::from_millis(500)).await;
+                while rx.try_recv().is_ok() {}
 
                 // Reload configuration
-                let options = ConfigOptions::default();
-                if let Ok(new_config) = Self::load_config_from_sources_static(&options) {
-                    *config.write().await = new_config;
-                    tracing::info!("Configuration reloaded due to file change");
+                match Self::load_config_from_sources_static(&options) {
+                    Ok(new_config) => {
+                        *config.write().await = new_config.clone();
+                        updates.send_replace(new_config);
+                        tracing::info!("Configuration reloaded due to file change");
+                    }
+                    Err(e) => {
+                        tracing::warn!("Keeping the current configuration: {e:#}");
+                    }
                 }
             }
         });
@@ -264,6 +285,39 @@ impl ConfigManager {
             }
         }
 
+        if let Some(idle_timeout) = config.pool_idle_timeout_seconds {
+            if idle_timeout > 3600 {
+                errors.push(ConfigValidationError::InvalidRange(
+                    "pool_idle_timeout_seconds".to_string(),
+                    idle_timeout.try_into().unwrap_or(i64::MAX),
+                    0,
+                    3600,
+                ));
+            }
+        }
+
+        if let Some(keepalive) = config.tcp_keepalive_seconds {
+            if keepalive > 3600 {
+                errors.push(ConfigValidationError::InvalidRange(
+                    "tcp_keepalive_seconds".to_string(),
+                    keepalive.try_into().unwrap_or(i64::MAX),
+                    0,
+                    3600,
+                ));
+            }
+        }
+
+        if let Some(threshold) = config.throttle_threshold_percent {
+            if threshold > 100 {
+                errors.push(ConfigValidationError::InvalidRange(
+                    "throttle_threshold_percent".to_string(),
+                    i64::from(threshold),
+                    0,
+                    100,
+                ));
+            }
+        }
+
         if !errors.is_empty() && fail_on_missing {
             return Err(ConfigValidationError::ValidationFailed(errors).into());
         }
@@ -330,3 +384,38 @@ impl Default for ConfigManager {
         Self::new()
     }
 }
+
+/// Settings whose values are never written to logs
+const SECRET_SETTINGS: [&str; 3] = ["personal_access_token", "webhook_secret", "tempo_api_token"];
+
+/// Describe the settings that differ between two configurations, one line per
+/// setting such as `api_base_url: "https://a" -> "https://b"`, in name order.
+/// Secrets are reported as changed without their values.
+#[must_use]
+pub fn config_changes(old: &JiraConfig, new: &JiraConfig) -> Vec<String> {
+    let (Ok(serde_json::Value::Object(old)), Ok(serde_json::Value::Object(new))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<&String> = old.keys().chain(new.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    let unset = serde_json::Value::Null;
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let before = old.get(name).unwrap_or(&unset);
+            let after = new.get(name).unwrap_or(&unset);
+            if before == after {
+                None
+            } else if SECRET_SETTINGS.contains(&name.as_str()) {
+                Some(format!("{name}: changed"))
+            } else {
+                Some(format!("{name}: {before} -> {after}"))
+            }
+        })
+        .collect()
+}
@@ -189,8 +189,11 @@
 //! }
 //! ```
 
+pub mod bulk;
 pub mod jira;
 pub mod manager;
+pub mod policies;
+pub mod response;
 pub mod secrets;
 pub mod validation;
 
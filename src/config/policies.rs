@@ -0,0 +1,328 @@
+//! Guardrails on writes: rules from the `[[policies]]` sections of a
+//! configuration file that every tool call which changes Jira is checked
+//! against before it runs.
+//!
+//! ```toml
+//! [[policies]]
+//! name = "no-priority-on-sec"
+//! effect = "deny"
+//! fields = ["priority"]
+//! issues = ["SEC-*"]
+//!
+//! [[policies]]
+//! name = "comments-reference-a-ticket"
+//! effect = "require"
+//! tools = ["add_jira_comment", "bulk_add_comments"]
+//! pattern = "[A-Z][A-Z0-9]+-[0-9]+"
+//! message = "Comments must mention the ticket they relate to"
+//! ```
+//!
+//! A rule matches a call when each of its `tools`, `fields`, `projects` and
+//! `issues` lists that is set has an entry the call matches; unset lists
+//! match every call. Rules are checked in the order they are written: the
+//! first `allow` or `deny` rule that matches decides, and each `require`
+//! rule matched before that must find its `pattern` in the call's text.
+
+use crate::error::{JiraError, Result};
+use crate::utils::paths::glob_matches;
+use crate::utils::pattern::TextPattern;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Arguments that name the issues a call writes to
+const ISSUE_KEY_ARGS: [&str; 7] = [
+    "issue_key",
+    "issue_id_or_key",
+    "issue_keys",
+    "inward_issue_key",
+    "outward_issue_key",
+    "original_issue_key",
+    "parent_key",
+];
+
+/// Text arguments `require` rules check when they name none
+const DEFAULT_TEXT_ARGS: [&str; 5] = ["comment", "comment_body", "body", "description", "text"];
+
+/// What a matching rule does with the call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PolicyEffect {
+    /// Let the call through without checking the rules after it
+    Allow,
+    /// Refuse the call
+    Deny,
+    /// Refuse the call unless its text matches the rule's `pattern`
+    Require,
+}
+
+/// A rule of the `[[policies]]` sections
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WritePolicy {
+    /// Named in the error of a call the rule refuses
+    pub name: String,
+    pub effect: PolicyEffect,
+    /// Tool names, `*` matching any characters; unset for every write tool
+    #[serde(default)]
+    pub tools: Vec<String>,
+    /// Field ids or names, of which the call must set one
+    #[serde(default)]
+    pub fields: Vec<String>,
+    /// Project keys, of which the call must write to one
+    #[serde(default)]
+    pub projects: Vec<String>,
+    /// Issue key patterns such as `SEC-*`, of which the call must write to a
+    /// matching issue
+    #[serde(default)]
+    pub issues: Vec<String>,
+    /// Regular expression the text of a `require` rule must match, in the
+    /// syntax of [`TextPattern`]
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// Arguments holding the text a `require` rule checks; unset for
+    /// comments, bodies, descriptions and text
+    #[serde(default)]
+    pub text_fields: Vec<String>,
+    /// Shown instead of the rule's description when it refuses a call
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl WritePolicy {
+    /// The rule in words, e.g. `deny writes setting priority on SEC-*`
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let mut text = match self.effect {
+            PolicyEffect::Allow => "allow".to_string(),
+            PolicyEffect::Deny => "deny".to_string(),
+            PolicyEffect::Require => format!(
+                "require {} to match /{}/",
+                self.text_args().join(", "),
+                self.pattern.as_deref().unwrap_or_default()
+            ),
+        };
+        if self.effect != PolicyEffect::Require {
+            let tools = if self.tools.is_empty() {
+                "writes".to_string()
+            } else {
+                self.tools.join(", ")
+            };
+            text.push_str(&format!(" {tools}"));
+        } else if !self.tools.is_empty() {
+            text.push_str(&format!(" in {}", self.tools.join(", ")));
+        }
+        if !self.fields.is_empty() {
+            text.push_str(&format!(" setting {}", self.fields.join(", ")));
+        }
+        if !self.projects.is_empty() {
+            text.push_str(&format!(" in project {}", self.projects.join(", ")));
+        }
+        if !self.issues.is_empty() {
+            text.push_str(&format!(" on {}", self.issues.join(", ")));
+        }
+        text
+    }
+
+    fn text_args(&self) -> Vec<&str> {
+        if self.text_fields.is_empty() {
+            DEFAULT_TEXT_ARGS.to_vec()
+        } else {
+            self.text_fields.iter().map(String::as_str).collect()
+        }
+    }
+
+    /// Whether the rule applies to `call`
+    fn matches(&self, call: &WriteCall) -> bool {
+        let tool_matches = self.tools.is_empty()
+            || self
+                .tools
+                .iter()
+                .any(|pattern| glob_matches(pattern, call.tool));
+        let field_matches = self.fields.is_empty()
+            || self.fields.iter().any(|field| {
+                call.fields
+                    .iter()
+                    .any(|set| set.eq_ignore_ascii_case(field))
+            });
+        let project_matches = self.projects.is_empty()
+            || self.projects.iter().any(|project| {
+                call.projects
+                    .iter()
+                    .any(|written| written.eq_ignore_ascii_case(project))
+            });
+        let issue_matches = self.issues.is_empty()
+            || self.issues.iter().any(|pattern| {
+                let pattern = pattern.to_uppercase();
+                call.issue_keys
+                    .iter()
+                    .any(|key| glob_matches(&pattern, &key.to_uppercase()))
+            });
+        tool_matches && field_matches && project_matches && issue_matches
+    }
+
+    fn violation(&self, reason: &str) -> JiraError {
+        let message = self
+            .message
+            .clone()
+            .unwrap_or_else(|| format!("{reason} ({})", self.describe()));
+        JiraError::policy_violation(&self.name, &message)
+    }
+}
+
+/// What a tool call writes, as far as its arguments tell
+#[derive(Debug, Default)]
+struct WriteCall<'a> {
+    tool: &'a str,
+    issue_keys: Vec<String>,
+    projects: Vec<String>,
+    fields: Vec<String>,
+}
+
+impl<'a> WriteCall<'a> {
+    fn from_args(tool: &'a str, args: &Value) -> Self {
+        let mut call = Self {
+            tool,
+            ..Self::default()
+        };
+        for name in ISSUE_KEY_ARGS {
+            match args.get(name) {
+                Some(Value::String(key)) => call.issue_keys.push(key.clone()),
+                Some(Value::Array(keys)) => call
+                    .issue_keys
+                    .extend(keys.iter().filter_map(Value::as_str).map(str::to_string)),
+                _ => {}
+            }
+        }
+        call.add_fields(args);
+        call.add_fields(args.get("update_data").unwrap_or(&Value::Null));
+        for operation in args
+            .get("operations")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            if let Some(key) = operation.get("issue_key").and_then(Value::as_str) {
+                call.issue_keys.push(key.to_string());
+            }
+            call.add_fields(operation.get("data").unwrap_or(&Value::Null));
+        }
+
+        let project = args.get("project_key").or_else(|| {
+            args.get("fields")
+                .and_then(|fields| fields.get("project"))
+                .and_then(|project| project.get("key"))
+        });
+        if let Some(project) = project.and_then(Value::as_str) {
+            call.projects.push(project.to_string());
+        }
+        for key in &call.issue_keys {
+            if let Some((project, _)) = key.rsplit_once('-') {
+                call.projects.push(project.to_string());
+            }
+        }
+        call
+    }
+
+    /// The fields `data` sets through `fields` and changes through `update`
+    fn add_fields(&mut self, data: &Value) {
+        for part in ["fields", "update"] {
+            if let Some(fields) = data.get(part).and_then(Value::as_object) {
+                self.fields.extend(fields.keys().cloned());
+            }
+        }
+    }
+}
+
+/// Check the write `tool` would make with `args` against `policies`
+///
+/// # Errors
+///
+/// Returns a policy violation naming the first rule that refuses the call,
+/// or a configuration error if a `require` rule's pattern is invalid
+pub fn check_write(policies: &[WritePolicy], tool: &str, args: &Value) -> Result<()> {
+    let call = WriteCall::from_args(tool, args);
+    for policy in policies.iter().filter(|policy| policy.matches(&call)) {
+        match policy.effect {
+            PolicyEffect::Allow => return Ok(()),
+            PolicyEffect::Deny => return Err(policy.violation(&format!("{tool} is not allowed"))),
+            PolicyEffect::Require => {
+                let pattern = compile(policy)?;
+                for name in policy.text_args() {
+                    let Some(text) = args.get(name).and_then(text_of) else {
+                        continue;
+                    };
+                    if !pattern.is_match(&text) {
+                        return Err(policy.violation(&format!("{name} does not match")));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The text of a string argument, or of the strings in an Atlassian
+/// Document Format argument
+fn text_of(value: &Value) -> Option<String> {
+    match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Object(_) => {
+            let mut text = String::new();
+            collect_text(value, &mut text);
+            Some(text)
+        }
+        _ => None,
+    }
+}
+
+fn collect_text(value: &Value, text: &mut String) {
+    match value {
+        Value::Object(node) => {
+            if let Some(Value::String(part)) = node.get("text") {
+                text.push_str(part);
+                text.push(' ');
+            }
+            if let Some(content) = node.get("content") {
+                collect_text(content, text);
+            }
+        }
+        Value::Array(nodes) => nodes.iter().for_each(|node| collect_text(node, text)),
+        _ => {}
+    }
+}
+
+fn compile(policy: &WritePolicy) -> Result<TextPattern> {
+    let pattern = policy.pattern.as_deref().unwrap_or_default();
+    TextPattern::new(pattern).map_err(|e| {
+        JiraError::config_error(&format!("Invalid pattern of policy '{}': {e}", policy.name))
+    })
+}
+
+/// Check that every rule has a name and that `require` rules, and only they,
+/// have a valid pattern
+///
+/// # Errors
+///
+/// Returns a description of the first invalid rule
+pub fn validate_policies(policies: &[WritePolicy]) -> std::result::Result<(), String> {
+    for (index, policy) in policies.iter().enumerate() {
+        if policy.name.trim().is_empty() {
+            return Err(format!("policies[{index}] needs a name"));
+        }
+        match (policy.effect, &policy.pattern) {
+            (PolicyEffect::Require, None) => {
+                return Err(format!("policy '{}' requires a pattern", policy.name));
+            }
+            (PolicyEffect::Require, Some(_)) => {
+                compile(policy).map_err(|e| e.to_string())?;
+            }
+            (_, Some(_)) => {
+                return Err(format!(
+                    "policy '{}' has a pattern, which only require rules use",
+                    policy.name
+                ));
+            }
+            (_, None) => {}
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,155 @@
+//! Post-processing of tool output: the `[response_middleware]` section of a
+//! configuration file selects built-in middleware that every tool result
+//! passes through before it is sent to the client.
+//!
+//! ```toml
+//! [response_middleware]
+//! order = ["redact", "links", "truncate", "footer"]
+//!
+//! [response_middleware.redact]
+//! patterns = ["\\d{3}-\\d{2}-\\d{4}"]
+//!
+//! [response_middleware.links]
+//! pattern = "https://jira\\.example\\.com/browse/"
+//! replacement = "https://go.example.com/jira/"
+//!
+//! [response_middleware.truncate]
+//! max_chars = 20000
+//!
+//! [response_middleware.footer]
+//! text = "Correlation id: {correlation_id}"
+//! ```
+//!
+//! Middleware run in the order of `order`; without it, every configured
+//! middleware runs in the order above. Patterns use the syntax of
+//! [`TextPattern`].
+
+use crate::utils::pattern::TextPattern;
+use serde::{Deserialize, Serialize};
+
+/// Names of the built-in middleware, in the order they run by default
+pub const BUILT_IN_MIDDLEWARE: [&str; 4] = ["redact", "links", "truncate", "footer"];
+
+/// The `[response_middleware]` section
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseMiddlewareConfig {
+    /// Names of the middleware to run, in order
+    #[serde(default)]
+    pub order: Option<Vec<String>>,
+    #[serde(default)]
+    pub redact: Option<RedactSettings>,
+    #[serde(default)]
+    pub links: Option<LinkRewriteSettings>,
+    #[serde(default)]
+    pub truncate: Option<TruncateSettings>,
+    #[serde(default)]
+    pub footer: Option<FooterSettings>,
+}
+
+/// `[response_middleware.redact]`: text hidden from every result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactSettings {
+    /// Patterns whose matches are replaced
+    pub patterns: Vec<String>,
+    /// Written instead of each match; `[REDACTED]` when unset
+    #[serde(default)]
+    pub replacement: Option<String>,
+}
+
+/// `[response_middleware.links]`: links rewritten, e.g. to an internal
+/// redirector
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkRewriteSettings {
+    /// Pattern matching the part of a link to replace
+    pub pattern: String,
+    /// Written instead of each match, `$0` standing for the matched text
+    pub replacement: String,
+}
+
+/// `[response_middleware.truncate]`: a cap on each content item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TruncateSettings {
+    pub max_chars: usize,
+}
+
+/// `[response_middleware.footer]`: a content item added to every result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FooterSettings {
+    /// The footer, in which `{correlation_id}` and `{tool}` are replaced by
+    /// the id of the call and the name of the tool
+    pub text: String,
+}
+
+impl ResponseMiddlewareConfig {
+    /// Names of the middleware to run, in order: `order` if set, otherwise
+    /// every configured middleware in the default order
+    #[must_use]
+    pub fn effective_order(&self) -> Vec<String> {
+        if let Some(order) = &self.order {
+            return order.clone();
+        }
+        BUILT_IN_MIDDLEWARE
+            .iter()
+            .filter(|name| self.is_configured(name))
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn is_configured(&self, name: &str) -> bool {
+        match name {
+            "redact" => self.redact.is_some(),
+            "links" => self.links.is_some(),
+            "truncate" => self.truncate.is_some(),
+            "footer" => self.footer.is_some(),
+            _ => false,
+        }
+    }
+}
+
+/// Check that `order` names only configured built-in middleware, once each,
+/// and that the patterns compile
+///
+/// # Errors
+///
+/// Returns a description of the first problem
+pub fn validate_response_middleware(
+    config: &ResponseMiddlewareConfig,
+) -> std::result::Result<(), String> {
+    let order = config.effective_order();
+    for (index, name) in order.iter().enumerate() {
+        if !BUILT_IN_MIDDLEWARE.contains(&name.as_str()) {
+            return Err(format!(
+                "response_middleware.order names unknown middleware '{name}'; use {}",
+                BUILT_IN_MIDDLEWARE.join(", ")
+            ));
+        }
+        if order[..index].contains(name) {
+            return Err(format!(
+                "response_middleware.order names '{name}' more than once"
+            ));
+        }
+        if !config.is_configured(name) {
+            return Err(format!(
+                "response_middleware.order names '{name}' without a [response_middleware.{name}] section"
+            ));
+        }
+    }
+
+    let compile = |section: &str, pattern: &str| {
+        TextPattern::new(pattern)
+            .map(|_| ())
+            .map_err(|e| format!("Invalid pattern in response_middleware.{section}: {e}"))
+    };
+    if let Some(redact) = &config.redact {
+        for pattern in &redact.patterns {
+            compile("redact", pattern)?;
+        }
+    }
+    if let Some(links) = &config.links {
+        compile("links", &links.pattern)?;
+    }
+    if config.truncate.is_some_and(|t| t.max_chars == 0) {
+        return Err("response_middleware.truncate.max_chars must be at least 1".to_string());
+    }
+    Ok(())
+}
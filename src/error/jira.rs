@@ -1,4 +1,5 @@
 use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use thiserror::Error;
 
@@ -24,6 +25,18 @@ pub enum JiraError {
     #[error("Authentication error: {message}")]
     AuthError { message: String },
 
+    /// Jira refused the request for lack of a permission (HTTP 403)
+    #[error("Permission denied: {message}")]
+    PermissionDenied { message: String },
+
+    /// The issue changed since the caller last read it
+    #[error("Conflict: {message}")]
+    Conflict { message: String },
+
+    /// A write refused by the `rule` of the configured write policies
+    #[error("Policy violation: {rule} - {message}")]
+    PolicyViolation { rule: String, message: String },
+
     /// Configuration-related errors
     #[error("Configuration error: {message}")]
     ConfigError { message: String },
@@ -57,7 +70,101 @@ pub enum JiraError {
     Unknown { message: String },
 }
 
+/// Broad category of a [`JiraError`], stable enough for automation to act on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JiraErrorKind {
+    PermissionDenied,
+    NotFound,
+    Validation,
+    Conflict,
+    Auth,
+    RateLimited,
+    Network,
+    InvalidResponse,
+    Other,
+}
+
+impl JiraErrorKind {
+    /// `count` errors of this kind in words, e.g. "3 permission errors"
+    #[must_use]
+    pub fn describe(self, count: usize) -> String {
+        let (one, many) = match self {
+            Self::PermissionDenied => ("permission error", "permission errors"),
+            Self::NotFound => ("not-found", "not-found"),
+            Self::Validation => ("validation error", "validation errors"),
+            Self::Conflict => ("conflict", "conflicts"),
+            Self::Auth => ("authentication error", "authentication errors"),
+            Self::RateLimited => ("rate-limited", "rate-limited"),
+            Self::Network => ("network error", "network errors"),
+            Self::InvalidResponse => ("invalid response", "invalid responses"),
+            Self::Other => ("other error", "other errors"),
+        };
+        format!("{count} {}", if count == 1 { one } else { many })
+    }
+
+    fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED => Self::Auth,
+            StatusCode::FORBIDDEN => Self::PermissionDenied,
+            StatusCode::NOT_FOUND => Self::NotFound,
+            StatusCode::CONFLICT => Self::Conflict,
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited,
+            StatusCode::BAD_REQUEST => Self::Validation,
+            _ => Self::Other,
+        }
+    }
+}
+
 impl JiraError {
+    /// Category of this error.
+    ///
+    /// Jira answers a missing issue with a 404 that is reported as an API
+    /// error, so API errors are classified by their field errors and message.
+    #[must_use]
+    pub fn kind(&self) -> JiraErrorKind {
+        match self {
+            Self::PermissionDenied { .. } | Self::PolicyViolation { .. } => {
+                JiraErrorKind::PermissionDenied
+            }
+            Self::AuthError { .. } => JiraErrorKind::Auth,
+            Self::ValidationError { .. } => JiraErrorKind::Validation,
+            Self::Conflict { .. } => JiraErrorKind::Conflict,
+            Self::HttpError { status, .. } => JiraErrorKind::from_status(*status),
+            Self::ApiError {
+                message,
+                error_codes,
+            } => {
+                let message = message.to_lowercase();
+                if message.contains("does not exist")
+                    || message.contains("not found")
+                    || message.contains("http 404")
+                {
+                    JiraErrorKind::NotFound
+                } else if message.contains("rate limit")
+                    || message.contains("too many requests")
+                    || message.contains("http 429")
+                {
+                    JiraErrorKind::RateLimited
+                } else if message.contains("http 401") {
+                    JiraErrorKind::Auth
+                } else if message.contains("http 403") {
+                    JiraErrorKind::PermissionDenied
+                } else if error_codes.as_ref().is_some_and(|codes| !codes.is_empty()) {
+                    JiraErrorKind::Validation
+                } else {
+                    JiraErrorKind::Other
+                }
+            }
+            Self::HttpClientError(e) if e.is_decode() => JiraErrorKind::InvalidResponse,
+            Self::HttpClientError(e) => e
+                .status()
+                .map_or(JiraErrorKind::Network, JiraErrorKind::from_status),
+            Self::SerializationError(_) => JiraErrorKind::InvalidResponse,
+            _ => JiraErrorKind::Other,
+        }
+    }
+
     /// Create a Jira API error from HTTP response
     #[must_use]
     pub fn from_jira_response(status: StatusCode, body: &Value) -> Self {
@@ -78,6 +185,10 @@ impl JiraError {
                 (format!("HTTP {status}"), vec![])
             };
 
+        if status == StatusCode::FORBIDDEN {
+            return Self::PermissionDenied { message };
+        }
+
         Self::ApiError {
             message,
             error_codes: Some(error_codes),
@@ -110,6 +221,31 @@ impl JiraError {
         }
     }
 
+    /// Create a permission denied error
+    #[must_use]
+    pub fn permission_denied(message: &str) -> Self {
+        Self::PermissionDenied {
+            message: message.to_string(),
+        }
+    }
+
+    /// Create a conflict error
+    #[must_use]
+    pub fn conflict(message: &str) -> Self {
+        Self::Conflict {
+            message: message.to_string(),
+        }
+    }
+
+    /// Create an error for a write refused by the write policy `rule`
+    #[must_use]
+    pub fn policy_violation(rule: &str, message: &str) -> Self {
+        Self::PolicyViolation {
+            rule: rule.to_string(),
+            message: message.to_string(),
+        }
+    }
+
     /// Create a configuration error
     #[must_use]
     pub fn config_error(message: &str) -> Self {
@@ -125,4 +261,51 @@ impl JiraError {
             message: message.to_string(),
         }
     }
+
+    /// Create a copy of this error to hand to another caller.
+    ///
+    /// Errors wrapping library types cannot be cloned and are carried over as
+    /// an unknown error with the same message.
+    #[must_use]
+    pub fn duplicate(&self) -> Self {
+        match self {
+            Self::HttpError { status, message } => Self::HttpError {
+                status: *status,
+                message: message.clone(),
+            },
+            Self::ApiError {
+                message,
+                error_codes,
+            } => Self::ApiError {
+                message: message.clone(),
+                error_codes: error_codes.clone(),
+            },
+            Self::ValidationError { field, message } => Self::ValidationError {
+                field: field.clone(),
+                message: message.clone(),
+            },
+            Self::AuthError { message } => Self::AuthError {
+                message: message.clone(),
+            },
+            Self::PermissionDenied { message } => Self::PermissionDenied {
+                message: message.clone(),
+            },
+            Self::Conflict { message } => Self::Conflict {
+                message: message.clone(),
+            },
+            Self::PolicyViolation { rule, message } => Self::PolicyViolation {
+                rule: rule.clone(),
+                message: message.clone(),
+            },
+            Self::ConfigError { message } => Self::ConfigError {
+                message: message.clone(),
+            },
+            Self::Unknown { message } => Self::Unknown {
+                message: message.clone(),
+            },
+            other => Self::Unknown {
+                message: other.to_string(),
+            },
+        }
+    }
 }
@@ -1,5 +1,5 @@
 pub mod jira;
 
-pub use jira::JiraError;
+pub use jira::{JiraError, JiraErrorKind};
 
 pub type Result<T> = std::result::Result<T, JiraError>;
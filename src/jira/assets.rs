@@ -0,0 +1,440 @@
+//! Assets (formerly Insight): objects of the CMDB schemas of Jira Service
+//! Management, and the names of the objects that Assets custom fields of
+//! issues refer to.
+//!
+//! Data Center serves them under `{site}/rest/insight/1.0` and searches with
+//! IQL by page number. Cloud serves them from the workspace's API at
+//! `https://api.atlassian.com/jsm/assets/workspace/{id}/v1`, where the search
+//! takes AQL in a POST and pages by `startAt`. Issues only hold object ids or
+//! keys in Assets fields, so their labels are looked up and cached.
+
+use crate::error::{JiraError, Result};
+use crate::jira::client::JiraClient;
+use crate::jira::urls::path_segment;
+use crate::performance::cache::{CacheStore, MokaCache};
+use crate::types::jira::JiraIssue;
+use reqwest::Method;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// Objects requested per page of a search
+const ASSET_PAGE_SIZE: usize = 50;
+
+/// Cloud Assets API, under which each workspace has its own
+const CLOUD_ASSETS_API_URL: &str = "https://api.atlassian.com/jsm/assets/workspace";
+
+/// How long an object label stays cached
+const ASSET_LABEL_TTL: Duration = Duration::from_secs(600);
+
+/// Looked-up objects as `label (key)`, by Assets API base URL and object id
+/// or key
+static ASSET_LABELS: LazyLock<MokaCache<String, String>> =
+    LazyLock::new(|| MokaCache::new(5000, ASSET_LABEL_TTL));
+
+/// Assets API base URLs of Cloud sites, by site URL
+static CLOUD_WORKSPACES: LazyLock<MokaCache<String, String>> =
+    LazyLock::new(|| MokaCache::new(100, Duration::from_secs(24 * 3600)));
+
+/// An attribute of an Assets object with the values it shows
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AssetAttribute {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+/// An Assets object
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AssetObject {
+    /// Numeric on Data Center, a string on Cloud; kept as text either way
+    pub id: String,
+    pub object_key: String,
+    pub label: String,
+    pub object_type: Option<String>,
+    pub attributes: Vec<AssetAttribute>,
+}
+
+impl AssetObject {
+    /// Read an object from a Data Center or Cloud response
+    #[must_use]
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let text = |field: &str| match value.get(field)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        };
+        let id = text("id")?;
+        let object_key = text("objectKey").unwrap_or_else(|| id.clone());
+        let attributes = value
+            .get("attributes")
+            .and_then(Value::as_array)
+            .map(|attributes| attributes.iter().filter_map(attribute).collect())
+            .unwrap_or_default();
+        Some(Self {
+            label: text("label")
+                .or_else(|| text("name"))
+                .unwrap_or_else(|| object_key.clone()),
+            object_type: value
+                .get("objectType")
+                .and_then(|t| t.get("name"))
+                .and_then(Value::as_str)
+                .map(ToString::to_string),
+            id,
+            object_key,
+            attributes,
+        })
+    }
+
+    /// The object as issues show it: `label (key)`
+    #[must_use]
+    pub fn display(&self) -> String {
+        format!("{} ({})", self.label, self.object_key)
+    }
+}
+
+/// An attribute with its displayed values; attributes without values are
+/// left out
+fn attribute(value: &Value) -> Option<AssetAttribute> {
+    let values: Vec<String> = value
+        .get("objectAttributeValues")?
+        .as_array()?
+        .iter()
+        .filter_map(|v| {
+            v.get("displayValue")
+                .or_else(|| v.get("value"))
+                .and_then(|v| match v {
+                    Value::String(s) => Some(s.clone()),
+                    Value::Null => None,
+                    other => Some(other.to_string()),
+                })
+        })
+        .collect();
+    if values.is_empty() {
+        return None;
+    }
+    let name = value
+        .get("objectTypeAttribute")
+        .and_then(|a| a.get("name"))
+        .and_then(Value::as_str)
+        .map(ToString::to_string)
+        .or_else(|| {
+            value.get("objectTypeAttributeId").map(|id| {
+                format!(
+                    "attribute {}",
+                    id.as_str().map_or(id.to_string(), str::to_string)
+                )
+            })
+        })?;
+    Some(AssetAttribute { name, values })
+}
+
+/// Objects found by an IQL or AQL search
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AssetSearch {
+    pub objects: Vec<AssetObject>,
+    /// Objects matching the query, when the API says
+    pub total: Option<u64>,
+    /// Whether matching objects were left out at the requested limit
+    pub truncated: bool,
+}
+
+/// One page of a search, as either API returns it
+struct AssetPage {
+    objects: Vec<AssetObject>,
+    total: Option<u64>,
+    is_last: bool,
+}
+
+/// Whether a field of the catalog holds Assets objects, by its schema type:
+/// `com.riadada.jira.plugins.insight:…` for Insight on Data Center,
+/// `com.atlassian.jira.plugins.cmdb:cmdb-object-cftype` on Cloud
+#[must_use]
+pub fn is_assets_field(field: &Value) -> bool {
+    field
+        .get("schema")
+        .and_then(|s| s.get("custom"))
+        .and_then(Value::as_str)
+        .map(str::to_ascii_lowercase)
+        .is_some_and(|custom| {
+            custom.contains("insight") || custom.contains("riadada") || custom.contains("cmdb")
+        })
+}
+
+/// A reference to an object in the value of an Assets field: either a label
+/// the value already carries, e.g. `MacBook Pro (HW-12)`, or an id or key to
+/// look up
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AssetRef {
+    Labelled(String),
+    Lookup(String),
+}
+
+/// The object references of an Assets field value
+fn asset_refs(value: &Value) -> Vec<AssetRef> {
+    match value {
+        Value::Array(items) => items.iter().flat_map(asset_refs).collect(),
+        Value::String(s) if s.ends_with(')') && s.contains(" (") => {
+            vec![AssetRef::Labelled(s.clone())]
+        }
+        Value::String(s) if !s.trim().is_empty() => vec![AssetRef::Lookup(s.trim().to_string())],
+        Value::Object(object) => {
+            let key = ["objectKey", "key", "objectId"].iter().find_map(|field| {
+                match object.get(*field)? {
+                    Value::String(s) => Some(s.clone()),
+                    Value::Number(n) => Some(n.to_string()),
+                    _ => None,
+                }
+            });
+            let label = ["label", "name"]
+                .iter()
+                .find_map(|field| object.get(*field).and_then(Value::as_str));
+            match (label, key) {
+                (Some(label), Some(key)) => vec![AssetRef::Labelled(format!("{label} ({key})"))],
+                (Some(label), None) => vec![AssetRef::Labelled(label.to_string())],
+                (None, Some(key)) => vec![AssetRef::Lookup(key)],
+                (None, None) => Vec::new(),
+            }
+        }
+        _ => Vec::new(),
+    }
+}
+
+impl JiraClient {
+    /// Whether Assets are on for this client
+    #[must_use]
+    pub fn assets_enabled(&self) -> bool {
+        self.config().assets_enabled.unwrap_or(false)
+    }
+
+    /// Whether the Assets API is the Cloud one, which pages by `startAt`
+    /// and searches with AQL in a POST
+    fn assets_on_cloud(&self) -> bool {
+        match self.config().assets_api_url.as_deref() {
+            Some(url) => url.contains("/jsm/assets/"),
+            None => self.config().is_cloud(),
+        }
+    }
+
+    /// Base URL of the Assets API: `assets_api_url`, the site's Insight API
+    /// on Data Center, or the API of the site's workspace on Cloud
+    async fn assets_api_url(&self) -> Result<String> {
+        if let Some(url) = self.config().assets_api_url.as_deref() {
+            return Ok(url.trim_end_matches('/').to_string());
+        }
+        let site = self.config().site_url();
+        if !self.config().is_cloud() {
+            return Ok(format!("{site}/rest/insight/1.0"));
+        }
+        if let Some(url) = CLOUD_WORKSPACES.get(&site).await {
+            return Ok(url);
+        }
+        let workspaces: Value = self
+            .service_desk_request(Method::GET, "assets/workspace", None::<&()>, true)
+            .await?;
+        let workspace = workspaces
+            .get("values")
+            .and_then(Value::as_array)
+            .and_then(|values| values.first())
+            .and_then(|w| w.get("workspaceId"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                JiraError::api_error("The site has no Assets workspace; is Assets enabled on it?")
+            })?;
+        let url = format!("{CLOUD_ASSETS_API_URL}/{workspace}/v1");
+        CLOUD_WORKSPACES.insert(site, url.clone()).await;
+        Ok(url)
+    }
+
+    /// Get the Assets object `object_key`, given as its key (e.g. `HW-12`)
+    /// or id
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object cannot be found or the request fails
+    pub async fn get_asset_object(&self, object_key: &str) -> Result<AssetObject> {
+        let base = self.assets_api_url().await?;
+        let value: Value = self
+            .assets_request(
+                Method::GET,
+                &base,
+                &format!("object/{}", path_segment(object_key)),
+                None::<&()>,
+                true,
+            )
+            .await?;
+        let object = AssetObject::from_json(&value)
+            .ok_or_else(|| JiraError::api_error("Invalid Assets object response format"))?;
+        ASSET_LABELS
+            .insert(format!("{base}|{object_key}"), object.display())
+            .await;
+        Ok(object)
+    }
+
+    /// Search Assets objects with an IQL (Data Center) or AQL (Cloud) query,
+    /// within the object schema `schema_id` if given, following pages until
+    /// `limit` objects are found
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query is invalid or a request fails
+    pub async fn search_asset_objects(
+        &self,
+        iql: &str,
+        schema_id: Option<&str>,
+        limit: usize,
+    ) -> Result<AssetSearch> {
+        let base = self.assets_api_url().await?;
+        let mut objects: Vec<AssetObject> = Vec::new();
+        let mut total = None;
+        let mut page_number = 1;
+        loop {
+            let page = if self.assets_on_cloud() {
+                self.aql_page(&base, iql, schema_id, objects.len()).await?
+            } else {
+                self.iql_page(&base, iql, schema_id, page_number).await?
+            };
+            total = page.total.or(total);
+            let found = page.objects.len();
+            objects.extend(page.objects);
+            if page.is_last || found == 0 || objects.len() >= limit {
+                break;
+            }
+            page_number += 1;
+        }
+
+        for object in &objects {
+            ASSET_LABELS
+                .insert(format!("{base}|{}", object.object_key), object.display())
+                .await;
+        }
+        let truncated = objects.len() > limit
+            || total.is_some_and(|total| total > limit as u64 && objects.len() >= limit);
+        objects.truncate(limit);
+        Ok(AssetSearch {
+            objects,
+            total,
+            truncated,
+        })
+    }
+
+    /// Page `page_number` (from 1) of a Data Center IQL search
+    async fn iql_page(
+        &self,
+        base: &str,
+        iql: &str,
+        schema_id: Option<&str>,
+        page_number: usize,
+    ) -> Result<AssetPage> {
+        let mut endpoint = format!(
+            "iql/objects?iql={}&page={page_number}&resultPerPage={ASSET_PAGE_SIZE}&includeAttributes=true",
+            urlencoding::encode(iql)
+        );
+        if let Some(schema_id) = schema_id {
+            endpoint.push_str(&format!(
+                "&objectSchemaId={}",
+                urlencoding::encode(schema_id)
+            ));
+        }
+        let page: Value = self
+            .assets_request(Method::GET, base, &endpoint, None::<&()>, true)
+            .await?;
+        let entries = page
+            .get("objectEntries")
+            .and_then(Value::as_array)
+            .ok_or_else(|| JiraError::api_error("Invalid IQL search response format"))?;
+        // `pageSize` is the number of pages, not their length
+        let pages = page.get("pageSize").and_then(Value::as_u64).unwrap_or(1);
+        Ok(AssetPage {
+            objects: entries.iter().filter_map(AssetObject::from_json).collect(),
+            total: page.get("totalFilterCount").and_then(Value::as_u64),
+            is_last: page_number as u64 >= pages,
+        })
+    }
+
+    /// The page from `start_at` of a Cloud AQL search
+    async fn aql_page(
+        &self,
+        base: &str,
+        iql: &str,
+        schema_id: Option<&str>,
+        start_at: usize,
+    ) -> Result<AssetPage> {
+        let query = match schema_id {
+            Some(schema_id) => format!("objectSchemaId = {schema_id} AND ({iql})"),
+            None => iql.to_string(),
+        };
+        let page: Value = self
+            .assets_request(
+                Method::POST,
+                base,
+                &format!(
+                    "object/aql?startAt={start_at}&maxResults={ASSET_PAGE_SIZE}&includeAttributes=true"
+                ),
+                Some(&json!({"qlQuery": query})),
+                true,
+            )
+            .await?;
+        let values = page
+            .get("values")
+            .and_then(Value::as_array)
+            .ok_or_else(|| JiraError::api_error("Invalid AQL search response format"))?;
+        Ok(AssetPage {
+            objects: values.iter().filter_map(AssetObject::from_json).collect(),
+            total: page.get("total").and_then(Value::as_u64),
+            is_last: page.get("isLast").and_then(Value::as_bool).unwrap_or(true),
+        })
+    }
+
+    /// The object `id_or_key` as `label (key)`, from the cache or the Assets
+    /// API; `None` if it cannot be looked up
+    pub async fn asset_label(&self, id_or_key: &str) -> Option<String> {
+        let base = self.assets_api_url().await.ok()?;
+        if let Some(label) = ASSET_LABELS.get(&format!("{base}|{id_or_key}")).await {
+            return Some(label);
+        }
+        self.get_asset_object(id_or_key)
+            .await
+            .ok()
+            .map(|object| object.display())
+    }
+
+    /// The Assets fields of `issue` that have a value, by field name, with
+    /// each object shown as `label (key)`; empty unless Assets are on
+    pub async fn issue_asset_fields(&self, issue: &JiraIssue) -> Vec<(String, Vec<String>)> {
+        if !self.assets_enabled() {
+            return Vec::new();
+        }
+        let has_custom_value = issue
+            .fields
+            .iter()
+            .any(|(id, value)| id.starts_with("customfield_") && !value.is_null());
+        if !has_custom_value {
+            return Vec::new();
+        }
+        let Ok(catalog) = self.field_catalog().await else {
+            return Vec::new();
+        };
+
+        let mut fields = Vec::new();
+        for field in catalog.iter().filter(|field| is_assets_field(field)) {
+            let Some(id) = field.get("id").and_then(Value::as_str) else {
+                continue;
+            };
+            let refs = issue.fields.get(id).map(asset_refs).unwrap_or_default();
+            if refs.is_empty() {
+                continue;
+            }
+            let mut objects = Vec::new();
+            for asset in refs {
+                objects.push(match asset {
+                    AssetRef::Labelled(label) => label,
+                    AssetRef::Lookup(key) => self.asset_label(&key).await.unwrap_or(key),
+                });
+            }
+            let name = field.get("name").and_then(Value::as_str).unwrap_or(id);
+            fields.push((name.to_string(), objects));
+        }
+        fields
+    }
+}
@@ -0,0 +1,59 @@
+//! Attribution of comments and work logs written on behalf of someone else.
+//!
+//! Jira records the account that makes a request as the author, so a service
+//! account writing for a user can only say so in the text. Every attributed
+//! body starts with the line built here; anything that needs to recognise
+//! such text (audit, duplicate detection) should go through
+//! [`parse_attribution`] and [`strip_attribution`] rather than matching the
+//! wording itself.
+
+/// Name of this server in attribution lines
+pub const ATTRIBUTION_SOURCE: &str = "rust-jira-mcp";
+
+const PREFIX: &str = "_on behalf of ";
+
+fn suffix() -> String {
+    format!(" via {ATTRIBUTION_SOURCE}_")
+}
+
+/// The attribution line for `display_name`
+#[must_use]
+pub fn attribution_line(display_name: &str) -> String {
+    format!("{PREFIX}{display_name}{}", suffix())
+}
+
+/// The display name `text` is attributed to, if it starts with an
+/// attribution line
+#[must_use]
+pub fn parse_attribution(text: &str) -> Option<&str> {
+    let first_line = text.lines().next()?.trim_end();
+    let suffix = suffix();
+    first_line
+        .strip_prefix(PREFIX)?
+        .strip_suffix(suffix.as_str())
+        .filter(|name| !name.is_empty())
+}
+
+/// `text` without its attribution line and the blank line after it
+#[must_use]
+pub fn strip_attribution(text: &str) -> &str {
+    if parse_attribution(text).is_none() {
+        return text;
+    }
+    text.split_once('\n')
+        .map_or("", |(_, rest)| rest.trim_start_matches(['\r', '\n']))
+}
+
+/// `text` attributed to `display_name`, replacing any attribution it already
+/// has so that editing an attributed body does not stack lines. An empty
+/// `text` becomes the attribution line alone.
+#[must_use]
+pub fn with_attribution(text: &str, display_name: &str) -> String {
+    let body = strip_attribution(text);
+    let line = attribution_line(display_name);
+    if body.is_empty() {
+        line
+    } else {
+        format!("{line}\n\n{body}")
+    }
+}
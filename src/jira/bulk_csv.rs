@@ -0,0 +1,381 @@
+//! Bulk operations read from a CSV file, one operation per row.
+//!
+//! A column mapping names where every used column goes. For operations on
+//! existing issues one column maps to `issue_key`, and the other targets are
+//! field ids for updates or keys of the operation data (`transition_id`,
+//! `comment`, `comment_body`, `account_id`) for the other operation types.
+//! For new issues every target is a field. Columns left out of the mapping
+//! are ignored. Rows keep their line number in the file so errors and results
+//! can point back at the spreadsheet.
+
+use crate::error::{JiraError, Result};
+use crate::types::jira::{BulkOperationItem, BulkOperationType, JiraCreateField, JiraCreateMeta};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// Mapping target of the column holding the issue key
+pub const ISSUE_KEY_TARGET: &str = "issue_key";
+
+/// One operation read from the file
+#[derive(Debug, Clone)]
+pub struct CsvOperation {
+    /// Line of the row in the file, counting the header as line 1
+    pub line: u64,
+    pub item: BulkOperationItem,
+}
+
+/// A row that could not be turned into an operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CsvRowError {
+    pub line: u64,
+    pub message: String,
+}
+
+impl std::fmt::Display for CsvRowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Data keys a row must fill for operations other than updates
+fn required_keys(operation_type: &BulkOperationType) -> &'static [&'static str] {
+    match operation_type {
+        BulkOperationType::Transition => &["transition_id"],
+        BulkOperationType::AddComment => &["comment_body"],
+        BulkOperationType::AddWatcher | BulkOperationType::RemoveWatcher => &["account_id"],
+        BulkOperationType::Update | BulkOperationType::Mixed => &[],
+    }
+}
+
+/// A cell as a JSON value: cells holding a JSON object or array (e.g.
+/// `{"name": "High"}`) are sent as that JSON, anything else as a string
+fn cell_value(cell: &str) -> Value {
+    let trimmed = cell.trim();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        if let Ok(value) = serde_json::from_str(trimmed) {
+            return value;
+        }
+    }
+    Value::String(cell.to_string())
+}
+
+/// A row of the file as the values of its mapped columns, keyed by target
+#[derive(Debug, Clone)]
+pub struct CsvRecord {
+    /// Line of the row in the file, counting the header as line 1
+    pub line: u64,
+    pub values: Map<String, Value>,
+}
+
+/// Read the rows of a CSV file as the values of the columns in
+/// `column_mapping`, which maps column names to targets.
+///
+/// A leading byte order mark is ignored and header names are trimmed. Empty
+/// cells leave their target out of the row, so a blank cell means "no value"
+/// rather than "clear the field".
+///
+/// # Errors
+///
+/// Returns a validation error if the header cannot be read or lacks a mapped
+/// column. Rows that cannot be parsed are returned alongside the others.
+pub fn read_mapped_rows(
+    content: &[u8],
+    column_mapping: &BTreeMap<String, String>,
+) -> Result<(Vec<CsvRecord>, Vec<CsvRowError>)> {
+    let content = content.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(content);
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(content);
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| JiraError::validation_error("file_path", &format!("Invalid CSV header: {e}")))?
+        .iter()
+        .map(|header| header.trim().to_string())
+        .collect();
+
+    // Column index and target of every mapped column
+    let mut columns = Vec::new();
+    for (header, target) in column_mapping {
+        let index = headers.iter().position(|h| h == header).ok_or_else(|| {
+            JiraError::validation_error(
+                "column_mapping",
+                &format!(
+                    "Column '{header}' is not in the CSV header ({})",
+                    headers.join(", ")
+                ),
+            )
+        })?;
+        columns.push((index, target.as_str()));
+    }
+
+    let mut records = Vec::new();
+    let mut errors = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                errors.push(CsvRowError {
+                    line: error.position().map_or(0, csv::Position::line),
+                    message: error.to_string(),
+                });
+                continue;
+            }
+        };
+        let mut values = Map::new();
+        for (index, target) in &columns {
+            let cell = record.get(*index).unwrap_or_default();
+            if !cell.trim().is_empty() {
+                values.insert((*target).to_string(), cell_value(cell));
+            }
+        }
+        records.push(CsvRecord {
+            line: record.position().map_or(0, csv::Position::line),
+            values,
+        });
+    }
+    Ok((records, errors))
+}
+
+/// Read the operations of a CSV file (see [`read_mapped_rows`]).
+///
+/// # Errors
+///
+/// Returns a validation error if the header is unusable: a mapped column is
+/// missing, no column maps to [`ISSUE_KEY_TARGET`], or the operation type
+/// cannot be read from CSV. Otherwise returns every row that failed to parse
+/// or lacks a required value, so they can all be fixed before anything runs.
+pub fn parse_bulk_csv(
+    content: &[u8],
+    operation_type: &BulkOperationType,
+    column_mapping: &BTreeMap<String, String>,
+) -> Result<std::result::Result<Vec<CsvOperation>, Vec<CsvRowError>>> {
+    if matches!(operation_type, BulkOperationType::Mixed) {
+        return Err(JiraError::validation_error(
+            "operation_type",
+            "mixed operations cannot be read from CSV; use update, transition, add_comment, \
+             add_watcher or remove_watcher",
+        ));
+    }
+    let (records, mut errors) = read_mapped_rows(content, column_mapping)?;
+    if !column_mapping
+        .values()
+        .any(|target| target == ISSUE_KEY_TARGET)
+    {
+        return Err(JiraError::validation_error(
+            "column_mapping",
+            &format!("No column is mapped to '{ISSUE_KEY_TARGET}'"),
+        ));
+    }
+
+    let mut operations = Vec::new();
+    for CsvRecord { line, mut values } in records {
+        let issue_key = values
+            .remove(ISSUE_KEY_TARGET)
+            .and_then(|key| key.as_str().map(|key| key.trim().to_string()))
+            .unwrap_or_default();
+        if issue_key.is_empty() {
+            errors.push(CsvRowError {
+                line,
+                message: "the issue key is empty".to_string(),
+            });
+            continue;
+        }
+
+        let missing: Vec<&str> = required_keys(operation_type)
+            .iter()
+            .copied()
+            .filter(|key| !values.contains_key(*key))
+            .collect();
+        if !missing.is_empty() {
+            errors.push(CsvRowError {
+                line,
+                message: format!("{issue_key} has no value for {}", missing.join(", ")),
+            });
+            continue;
+        }
+        let data = match operation_type {
+            BulkOperationType::Update if values.is_empty() => {
+                errors.push(CsvRowError {
+                    line,
+                    message: format!("{issue_key} has no field values to update"),
+                });
+                continue;
+            }
+            BulkOperationType::Update => serde_json::json!({ "fields": values }),
+            _ => Value::Object(values),
+        };
+
+        operations.push(CsvOperation {
+            line,
+            item: BulkOperationItem {
+                issue_key,
+                operation_type: operation_type.clone(),
+                data,
+            },
+        });
+    }
+
+    errors.sort_by_key(|error| error.line);
+    Ok(if errors.is_empty() {
+        Ok(operations)
+    } else {
+        Err(errors)
+    })
+}
+
+/// Problems with the fields of an update according to the issue's edit
+/// metadata (`GET issue/{key}/editmeta`): fields that cannot be edited, and
+/// values of option fields that are plain strings or not among the allowed
+/// values
+#[must_use]
+pub fn check_against_editmeta(fields: &Map<String, Value>, editmeta: &Value) -> Vec<String> {
+    const OPTION_KEYS: [&str; 4] = ["name", "value", "id", "key"];
+    let mut problems = Vec::new();
+    for (field_id, value) in fields {
+        let Some(meta) = editmeta.pointer(&format!("/fields/{field_id}")) else {
+            problems.push(format!("field '{field_id}' cannot be edited on this issue"));
+            continue;
+        };
+        let Some(allowed) = meta.get("allowedValues").and_then(Value::as_array) else {
+            continue;
+        };
+        if let Some(text) = value.as_str() {
+            problems.push(format!(
+                "field '{field_id}' takes one of its allowed values as an object, \
+                 e.g. {{\"name\": \"{text}\"}}"
+            ));
+            continue;
+        }
+        let Some((key, wanted)) = OPTION_KEYS
+            .iter()
+            .find_map(|key| Some((*key, value.get(key)?.as_str()?)))
+        else {
+            continue;
+        };
+        let known = allowed.iter().any(|option| {
+            option
+                .get(key)
+                .and_then(Value::as_str)
+                .is_some_and(|candidate| candidate.eq_ignore_ascii_case(wanted))
+        });
+        if !known {
+            problems.push(format!(
+                "'{wanted}' is not an allowed {key} of field '{field_id}'"
+            ));
+        }
+    }
+    problems
+}
+
+/// Fields of a new issue: `defaults` overlaid with a row's `values`, with a
+/// `project` or `issuetype` given as plain text turned into a reference by
+/// key or name
+#[must_use]
+pub fn create_fields(
+    defaults: &Map<String, Value>,
+    values: Map<String, Value>,
+) -> Map<String, Value> {
+    let mut fields = defaults.clone();
+    fields.extend(values);
+    for (field_id, reference) in [("project", "key"), ("issuetype", "name")] {
+        if let Some(Value::String(text)) = fields.get(field_id) {
+            let value = serde_json::json!({ reference: text.trim() });
+            fields.insert(field_id.to_string(), value);
+        }
+    }
+    fields
+}
+
+/// Turn plain text values of `fields` into what the create screen in `meta`
+/// expects: options of fields with allowed values are referenced by id,
+/// numbers are parsed, and array fields take comma-separated items. Returns
+/// the values that could not be converted.
+pub fn coerce_to_create_meta(
+    fields: &mut Map<String, Value>,
+    meta: &JiraCreateMeta,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    for (field_id, value) in fields.iter_mut() {
+        let Some(field) = meta.fields.iter().find(|f| &f.field_id == field_id) else {
+            continue;
+        };
+        let Value::String(text) = value else {
+            continue;
+        };
+        let schema_type = field
+            .schema
+            .as_ref()
+            .and_then(|s| s.get("type"))
+            .and_then(Value::as_str);
+        let converted = match schema_type {
+            Some("array") => text
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| coerce_text(item, field))
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map(Value::Array),
+            _ => coerce_text(text, field),
+        };
+        match converted {
+            Ok(converted) => *value = converted,
+            Err(problem) => problems.push(problem),
+        }
+    }
+    problems
+}
+
+/// One value of `field` given as text
+fn coerce_text(text: &str, field: &JiraCreateField) -> std::result::Result<Value, String> {
+    const OPTION_KEYS: [&str; 4] = ["name", "value", "id", "key"];
+    if let Some(allowed) = field.allowed_values.as_ref().filter(|a| !a.is_empty()) {
+        let label = |option: &Value| {
+            OPTION_KEYS
+                .iter()
+                .find_map(|key| option.get(key).and_then(Value::as_str))
+                .unwrap_or_default()
+                .to_string()
+        };
+        return allowed
+            .iter()
+            .find(|option| {
+                OPTION_KEYS.iter().any(|key| {
+                    option
+                        .get(key)
+                        .and_then(Value::as_str)
+                        .is_some_and(|candidate| candidate.eq_ignore_ascii_case(text.trim()))
+                })
+            })
+            .and_then(|option| option.get("id").cloned())
+            .map(|id| serde_json::json!({ "id": id }))
+            .ok_or_else(|| {
+                format!(
+                    "'{text}' is not an allowed value of {} ({}); allowed: {}",
+                    field.name,
+                    field.field_id,
+                    allowed.iter().map(label).collect::<Vec<_>>().join(", ")
+                )
+            });
+    }
+    let schema_type = field
+        .schema
+        .as_ref()
+        .and_then(|s| s.get("type"))
+        .and_then(Value::as_str);
+    if schema_type == Some("number") {
+        return text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| {
+                format!(
+                    "'{text}' is not a number for {} ({})",
+                    field.name, field.field_id
+                )
+            });
+    }
+    Ok(Value::String(text.to_string()))
+}
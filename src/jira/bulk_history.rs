@@ -0,0 +1,262 @@
+//! History of the bulk operations the server ran, read back with
+//! `get_bulk_operation_history` and `get_bulk_operation_detail`.
+//!
+//! Every finished bulk tool call is recorded with the issues it touched, its
+//! counts, its results and its arguments (credentials redacted) under an id
+//! the tool returns. The last [`MAX_BULK_HISTORY`] operations are kept for
+//! each Jira site. With `JIRA_BULK_HISTORY_FILE` set they are also appended to
+//! that file as JSON lines, and the first lookup reads the file back, so the
+//! history outlives the server.
+
+use crate::config::JiraConfig;
+use crate::logging::sanitize::sanitize_json;
+use crate::types::jira::{BulkOperationResult, BulkOperationSummary, BulkOperationType};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::{LazyLock, Mutex};
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+/// Bulk operations kept for each Jira site
+pub const MAX_BULK_HISTORY: usize = 50;
+
+/// Recorded bulk operations by API base URL, oldest first
+static HISTORIES: LazyLock<Mutex<HashMap<String, VecDeque<BulkHistoryEntry>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// One finished bulk operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkHistoryEntry {
+    pub id: String,
+    /// Tool that ran the operation, e.g. `bulk_update_issues`
+    pub tool: String,
+    pub operation_type: BulkOperationType,
+    /// Issues operated on, each once, in the order they were processed
+    pub issue_keys: Vec<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub total_operations: u32,
+    pub successful_operations: u32,
+    pub failed_operations: u32,
+    pub skipped_operations: u32,
+    pub duration_ms: u64,
+    /// Arguments of the tool call with credentials redacted
+    pub arguments: serde_json::Value,
+    pub results: Vec<BulkOperationResult>,
+}
+
+impl BulkHistoryEntry {
+    /// One line for people, e.g. `bulk-1a2b3c4d5e6f bulk_update_issues
+    /// (update) on 3 issues: 2 succeeded, 1 failed, 0 skipped`
+    #[must_use]
+    pub fn headline(&self) -> String {
+        format!(
+            "{} {} ({}) on {} issues: {} succeeded, {} failed, {} skipped",
+            self.id,
+            self.tool,
+            operation_type_name(&self.operation_type),
+            self.issue_keys.len(),
+            self.successful_operations,
+            self.failed_operations,
+            self.skipped_operations
+        )
+    }
+}
+
+/// Name of `operation_type` as tools take it, e.g. `add_comment`
+#[must_use]
+pub fn operation_type_name(operation_type: &BulkOperationType) -> String {
+    serde_json::to_value(operation_type)
+        .ok()
+        .and_then(|value| value.as_str().map(ToString::to_string))
+        .unwrap_or_default()
+}
+
+/// Which entries `get_bulk_operation_history` returns
+#[derive(Debug, Clone, Default)]
+pub struct BulkHistoryFilter {
+    /// Keep operations that finished at or after this instant
+    pub since: Option<DateTime<Utc>>,
+    /// Keep operations that finished before this instant
+    pub until: Option<DateTime<Utc>>,
+    pub operation_type: Option<BulkOperationType>,
+}
+
+impl BulkHistoryFilter {
+    /// Whether `entry` passes the filter
+    #[must_use]
+    pub fn matches(&self, entry: &BulkHistoryEntry) -> bool {
+        self.since.is_none_or(|since| entry.finished_at >= since)
+            && self.until.is_none_or(|until| entry.finished_at < until)
+            && self
+                .operation_type
+                .as_ref()
+                .is_none_or(|operation_type| *operation_type == entry.operation_type)
+    }
+}
+
+/// Whether `current`, as Jira returns a field, still holds the value
+/// `applied` set: equal values, objects holding every key of `applied` with a
+/// matching value (so `{"name": "1.0"}` matches the version Jira returns) and
+/// arrays whose items match in any order
+#[must_use]
+pub fn still_applied(applied: &serde_json::Value, current: &serde_json::Value) -> bool {
+    use serde_json::Value;
+    match (applied, current) {
+        (Value::Object(applied), Value::Object(current)) => applied.iter().all(|(key, value)| {
+            current
+                .get(key)
+                .is_some_and(|current| still_applied(value, current))
+        }),
+        (Value::Array(applied), Value::Array(current)) => {
+            applied.len() == current.len()
+                && applied
+                    .iter()
+                    .all(|value| current.iter().any(|item| still_applied(value, item)))
+        }
+        _ => applied == current,
+    }
+}
+
+/// Record the finished bulk operation `summary`, run by `tool` with
+/// `arguments`, and return its history entry. Failing to append it to the
+/// history file is logged as a warning.
+pub async fn record_bulk_operation(
+    config: &JiraConfig,
+    tool: &str,
+    operation_type: BulkOperationType,
+    arguments: &serde_json::Value,
+    summary: &BulkOperationSummary,
+) -> BulkHistoryEntry {
+    let finished_at = Utc::now();
+    let duration = Duration::milliseconds(i64::try_from(summary.duration_ms).unwrap_or(i64::MAX));
+    let mut issue_keys: Vec<String> = Vec::new();
+    for result in &summary.results {
+        if !issue_keys.contains(&result.issue_key) {
+            issue_keys.push(result.issue_key.clone());
+        }
+    }
+    let entry = BulkHistoryEntry {
+        id: format!("bulk-{}", &uuid::Uuid::new_v4().simple().to_string()[..12]),
+        tool: tool.to_string(),
+        operation_type,
+        issue_keys,
+        started_at: finished_at - duration,
+        finished_at,
+        total_operations: summary.total_operations,
+        successful_operations: summary.successful_operations,
+        failed_operations: summary.failed_operations,
+        skipped_operations: summary.skipped_operations,
+        duration_ms: summary.duration_ms,
+        arguments: sanitize_json(arguments),
+        results: summary.results.clone(),
+    };
+
+    with_history(config, |history| {
+        history.push_back(entry.clone());
+        while history.len() > MAX_BULK_HISTORY {
+            history.pop_front();
+        }
+    });
+    if let Some(path) = &config.bulk_history_file {
+        if let Err(e) = append_entry(path, &entry).await {
+            warn!(
+                "Failed to append bulk operation {} to {}: {}",
+                entry.id,
+                path.display(),
+                e
+            );
+        }
+    }
+    entry
+}
+
+/// Recorded bulk operations passing `filter`, newest first
+#[must_use]
+pub fn bulk_history(config: &JiraConfig, filter: &BulkHistoryFilter) -> Vec<BulkHistoryEntry> {
+    with_history(config, |history| {
+        history
+            .iter()
+            .rev()
+            .filter(|entry| filter.matches(entry))
+            .cloned()
+            .collect()
+    })
+}
+
+/// The recorded bulk operation `id`, if it is still kept
+#[must_use]
+pub fn bulk_operation(config: &JiraConfig, id: &str) -> Option<BulkHistoryEntry> {
+    with_history(config, |history| {
+        history.iter().find(|entry| entry.id == id).cloned()
+    })
+}
+
+/// Run `f` on the history of the site of `config`, read from the history file
+/// on first use
+fn with_history<T>(config: &JiraConfig, f: impl FnOnce(&mut VecDeque<BulkHistoryEntry>) -> T) -> T {
+    let mut histories = HISTORIES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let history = histories
+        .entry(config.api_base_url.clone())
+        .or_insert_with(|| {
+            config
+                .bulk_history_file
+                .as_deref()
+                .map(load_entries)
+                .unwrap_or_default()
+        });
+    f(history)
+}
+
+/// The last [`MAX_BULK_HISTORY`] entries of the history file at `path`;
+/// lines that cannot be read are skipped with a warning
+fn load_entries(path: &Path) -> VecDeque<BulkHistoryEntry> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return VecDeque::new(),
+        Err(e) => {
+            warn!(
+                "Failed to read the bulk history file {}: {}",
+                path.display(),
+                e
+            );
+            return VecDeque::new();
+        }
+    };
+    let mut entries = VecDeque::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(line) {
+            Ok(entry) => entries.push_back(entry),
+            Err(e) => warn!(
+                "Skipping line {} of the bulk history file {}: {}",
+                index + 1,
+                path.display(),
+                e
+            ),
+        }
+        if entries.len() > MAX_BULK_HISTORY {
+            entries.pop_front();
+        }
+    }
+    entries
+}
+
+/// Append `entry` to the history file at `path` as one JSON line
+async fn append_entry(path: &Path, entry: &BulkHistoryEntry) -> std::io::Result<()> {
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(line.as_bytes()).await?;
+    file.flush().await
+}
@@ -0,0 +1,123 @@
+//! Checking the operations of a mixed bulk request before any is executed.
+//!
+//! Every operation is checked and every problem reported at once, by its
+//! index in the array, so a caller can fix them all in one go instead of
+//! meeting them one run at a time. Beside the issue key and operation type
+//! this checks the data each type reads: `fields` for updates,
+//! `transition_id` for transitions, `comment_body` for comments and
+//! `account_id` for watcher changes.
+
+use crate::error::{JiraError, Result};
+use crate::types::jira::{BulkOperationItem, BulkOperationType};
+use serde_json::Value;
+
+/// Data keys a mixed operation is recognized by, in the order they are tried
+const MIXED_DATA_KEYS: [&str; 3] = ["fields", "transition_id", "comment_body"];
+
+/// Problems with the data of an operation of `operation_type`
+fn data_problems(operation_type: &BulkOperationType, data: Option<&Value>) -> Vec<String> {
+    let Some(data) = data.and_then(Value::as_object) else {
+        return vec!["data must be an object".to_string()];
+    };
+    let string = |key: &str| -> Option<String> {
+        match data.get(key) {
+            Some(Value::String(value)) if !value.trim().is_empty() => None,
+            Some(_) => Some(format!("data.{key} must be a non-empty string")),
+            None => Some(format!("data.{key} is required")),
+        }
+    };
+    let fields = || match data.get("fields") {
+        Some(Value::Object(fields)) if !fields.is_empty() => None,
+        Some(_) => Some("data.fields must be a non-empty object".to_string()),
+        None => Some("data.fields is required".to_string()),
+    };
+    let comment_body = || match data.get("comment_body") {
+        // A string, or an ADF document
+        Some(Value::String(body)) if !body.trim().is_empty() => None,
+        Some(Value::Object(_)) => None,
+        Some(_) => Some("data.comment_body must be a non-empty string or an ADF document".into()),
+        None => Some("data.comment_body is required".to_string()),
+    };
+
+    let problem = match operation_type {
+        BulkOperationType::Update => fields(),
+        BulkOperationType::Transition => string("transition_id"),
+        BulkOperationType::AddComment => comment_body(),
+        BulkOperationType::AddWatcher | BulkOperationType::RemoveWatcher => string("account_id"),
+        BulkOperationType::Mixed => {
+            match MIXED_DATA_KEYS.iter().find(|key| data.contains_key(**key)) {
+                Some(&"fields") => fields(),
+                Some(&"transition_id") => string("transition_id"),
+                Some(_) => comment_body(),
+                None => Some(format!(
+                    "data needs one of {} to tell what a mixed operation does",
+                    MIXED_DATA_KEYS.join(", ")
+                )),
+            }
+        }
+    };
+    problem.into_iter().collect()
+}
+
+/// Problems with the operation at one index of the array
+fn operation_problems(operation: &Value) -> Vec<String> {
+    let Some(object) = operation.as_object() else {
+        return vec!["operation must be an object".to_string()];
+    };
+
+    let mut problems = Vec::new();
+    match object.get("issue_key") {
+        Some(Value::String(key)) if !key.trim().is_empty() => {}
+        Some(_) => problems.push("issue_key must be a non-empty string".to_string()),
+        None => problems.push("issue_key is required".to_string()),
+    }
+    match object.get("operation_type") {
+        None => problems.push("operation_type is required".to_string()),
+        Some(value) => match serde_json::from_value::<BulkOperationType>(value.clone()) {
+            Ok(operation_type) => {
+                problems.extend(data_problems(&operation_type, object.get("data")));
+            }
+            Err(_) => problems.push(format!(
+                "operation_type {value} is not one of update, transition, add_comment, \
+                 add_watcher, remove_watcher or mixed"
+            )),
+        },
+    }
+    problems
+}
+
+/// Read the `operations` of a mixed bulk request, checking all of them first.
+///
+/// # Errors
+///
+/// Returns one validation error listing every problem found, as
+/// `operation <index>: <problem>` lines, if any operation is malformed.
+pub fn validate_bulk_operations(operations: &[Value]) -> Result<Vec<BulkOperationItem>> {
+    let problems: Vec<String> = operations
+        .iter()
+        .enumerate()
+        .flat_map(|(index, operation)| {
+            operation_problems(operation)
+                .into_iter()
+                .map(move |problem| format!("operation {index}: {problem}"))
+        })
+        .collect();
+    if !problems.is_empty() {
+        return Err(JiraError::validation_error(
+            "operations",
+            &format!(
+                "{} problems found, nothing was executed:\n{}",
+                problems.len(),
+                problems.join("\n")
+            ),
+        ));
+    }
+
+    operations
+        .iter()
+        .map(|operation| {
+            serde_json::from_value(operation.clone())
+                .map_err(|e| JiraError::validation_error("operations", &e.to_string()))
+        })
+        .collect()
+}
@@ -0,0 +1,715 @@
+//! Portable bundles of single issues, for moving a few issues from one Jira
+//! instance to another.
+//!
+//! An export writes [`BUNDLE_FILE_NAME`] into a directory, with the issue's
+//! attachments next to it under [`BUNDLE_ATTACHMENT_DIR`]. The bundle holds
+//! the fields as Jira returned them, the comments and worklogs with their
+//! authors and times, the attachments' file names and checksums, and the links
+//! by the key of the other issue, since ids mean nothing on another instance.
+//!
+//! An import creates the issue from the fields a [`JiraFieldMapping`] selects,
+//! as `clone_jira_issue` does, replays comments and worklogs under a line
+//! naming their original author, uploads the attachments again and, when
+//! asked, links the new issue to the same keys. Each item is reported on its
+//! own rather than stopping at the first failure.
+//!
+//! `schema_version` goes up whenever a change would make an older server
+//! misread a bundle; bundles of a newer version are refused.
+
+use crate::error::{JiraError, Result};
+use crate::jira::attribution::ATTRIBUTION_SOURCE;
+use crate::jira::client::JiraClient;
+use crate::types::jira::{
+    JiraAttachment, JiraFieldMapping, JiraWorkLogCreateRequest, JiraWorkLogEstimateAdjustment,
+};
+use crate::utils::paths::{sanitize_file_name, unique_file_name};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::path::Path;
+use tracing::{info, warn};
+
+/// Version of the bundle layout this server writes and the newest it reads
+pub const BUNDLE_SCHEMA_VERSION: u32 = 1;
+
+/// Name of the bundle file inside a bundle directory
+pub const BUNDLE_FILE_NAME: &str = "bundle.json";
+
+/// Directory, relative to the bundle file, holding the attachments
+pub const BUNDLE_ATTACHMENT_DIR: &str = "attachments";
+
+/// A portable copy of one issue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueBundle {
+    pub schema_version: u32,
+    /// Server that wrote the bundle
+    pub exported_by: String,
+    pub exported_at: String,
+    pub source: BundleSource,
+    /// Fields of the issue as Jira returned them
+    pub fields: Map<String, Value>,
+    #[serde(default)]
+    pub comments: Vec<BundleComment>,
+    #[serde(default)]
+    pub worklogs: Vec<BundleWorklog>,
+    #[serde(default)]
+    pub attachments: Vec<BundleAttachment>,
+    #[serde(default)]
+    pub links: Vec<BundleLink>,
+}
+
+/// Where an exported issue came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleSource {
+    pub site_url: String,
+    pub issue_key: String,
+    pub issue_id: String,
+}
+
+/// A comment of an exported issue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleComment {
+    pub id: String,
+    /// Display name of the author
+    pub author: Option<String>,
+    pub created: Option<String>,
+    /// Wiki markup, or an ADF document from REST API v3
+    pub body: Value,
+}
+
+/// A worklog of an exported issue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleWorklog {
+    pub id: String,
+    /// Display name of the author
+    pub author: Option<String>,
+    pub started: Option<String>,
+    /// As Jira formats it, e.g. `1h 30m`
+    pub time_spent: String,
+    pub comment: Option<Value>,
+}
+
+/// An attachment of an exported issue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleAttachment {
+    pub id: String,
+    pub filename: String,
+    pub mime_type: Option<String>,
+    pub size: u64,
+    /// Path of the downloaded file relative to the bundle file; `None` when
+    /// the download failed
+    pub file: Option<String>,
+    /// SHA-256 of the downloaded file as lowercase hex
+    pub sha256: Option<String>,
+    /// Why the download failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Which end of a link the exported issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkDirection {
+    /// The exported issue is the outward end: it `blocks` the other issue
+    Outward,
+    /// The exported issue is the inward end: it `is blocked by` the other
+    /// issue
+    Inward,
+}
+
+/// A link of an exported issue, by the key of the issue at the other end
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleLink {
+    /// Name of the link type, e.g. `Blocks`
+    pub link_type: String,
+    pub direction: LinkDirection,
+    /// The link as read from the exported issue, e.g. `blocks`
+    pub description: String,
+    pub issue_key: String,
+}
+
+impl BundleLink {
+    /// Read a link from an entry of the `issuelinks` field
+    #[must_use]
+    pub fn from_issue_link(link: &Value) -> Option<Self> {
+        let link_type = &link["type"];
+        let (direction, other, description) = match (
+            link.pointer("/outwardIssue/key").and_then(Value::as_str),
+            link.pointer("/inwardIssue/key").and_then(Value::as_str),
+        ) {
+            (Some(key), _) => (LinkDirection::Outward, key, &link_type["outward"]),
+            (None, Some(key)) => (LinkDirection::Inward, key, &link_type["inward"]),
+            (None, None) => return None,
+        };
+        Some(Self {
+            link_type: link_type.get("name")?.as_str()?.to_string(),
+            direction,
+            description: description.as_str().unwrap_or_default().to_string(),
+            issue_key: other.to_string(),
+        })
+    }
+}
+
+impl IssueBundle {
+    /// Read a bundle from the text of a bundle file
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the text is not a bundle or was written
+    /// with a newer schema version than this server reads.
+    pub fn parse(text: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(text)
+            .map_err(|e| JiraError::validation_error("bundle", &format!("Not JSON: {e}")))?;
+        let version = value
+            .get("schema_version")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| {
+                JiraError::validation_error("bundle", "Not an issue bundle: no schema_version")
+            })?;
+        if version > u64::from(BUNDLE_SCHEMA_VERSION) {
+            return Err(JiraError::validation_error(
+                "bundle",
+                &format!(
+                    "The bundle has schema version {version}, newer than version \
+                     {BUNDLE_SCHEMA_VERSION} this server reads; import it with a newer {ATTRIBUTION_SOURCE}"
+                ),
+            ));
+        }
+        serde_json::from_value(value).map_err(|e| {
+            JiraError::validation_error("bundle", &format!("Invalid issue bundle: {e}"))
+        })
+    }
+
+    /// Read the bundle file of the bundle directory `dir`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a bundle this
+    /// server reads.
+    pub async fn read(dir: &Path) -> Result<Self> {
+        let text = tokio::fs::read_to_string(dir.join(BUNDLE_FILE_NAME)).await?;
+        Self::parse(&text)
+    }
+
+    /// Key of the project the issue was in
+    #[must_use]
+    pub fn project_key(&self) -> Option<&str> {
+        self.fields
+            .get("project")
+            .and_then(|project| project.get("key"))
+            .and_then(Value::as_str)
+    }
+
+    /// Name of the issue type the issue had
+    #[must_use]
+    pub fn issue_type(&self) -> Option<&str> {
+        self.fields
+            .get("issuetype")
+            .and_then(|issue_type| issue_type.get("name"))
+            .and_then(Value::as_str)
+    }
+}
+
+/// The line that starts a replayed comment or worklog comment, naming who
+/// wrote it, when, and on which issue
+#[must_use]
+pub fn replay_line(author: Option<&str>, created: Option<&str>, source_key: &str) -> String {
+    let author = author.unwrap_or("an unknown user");
+    match created {
+        Some(created) => format!("_Originally by {author} on {source_key}, {created}_"),
+        None => format!("_Originally by {author} on {source_key}_"),
+    }
+}
+
+/// `body`, wiki markup or an ADF document, starting with `line`
+#[must_use]
+pub fn with_replay_line(body: &Value, line: &str) -> Value {
+    match body {
+        Value::String(text) if text.trim().is_empty() => json!(line),
+        Value::String(text) => json!(format!("{line}\n\n{text}")),
+        Value::Object(_) => {
+            let mut document = body.clone();
+            let paragraph = json!({
+                "type": "paragraph",
+                "content": [{ "type": "text", "text": line }]
+            });
+            match document.get_mut("content").and_then(Value::as_array_mut) {
+                Some(blocks) => blocks.insert(0, paragraph),
+                None => document["content"] = json!([paragraph]),
+            }
+            document
+        }
+        _ => json!(line),
+    }
+}
+
+/// How to create the issue of a bundle on this instance
+#[derive(Debug, Clone, Default)]
+pub struct BundleImportOptions {
+    /// Project to create the issue in; the bundle's project by default
+    pub project_key: Option<String>,
+    /// Issue type to create; the bundle's issue type by default
+    pub issue_type: Option<String>,
+    /// Fields to copy from the bundle
+    pub field_mapping: JiraFieldMapping,
+    /// Link the new issue to the issues the exported one was linked to, by
+    /// the same keys
+    pub link_issues: bool,
+}
+
+/// The kind of a bundle item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleItemKind {
+    Comment,
+    Worklog,
+    Attachment,
+    Link,
+}
+
+impl BundleItemKind {
+    /// The kind in lower case, as in JSON
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Comment => "comment",
+            Self::Worklog => "worklog",
+            Self::Attachment => "attachment",
+            Self::Link => "link",
+        }
+    }
+}
+
+/// What happened to a bundle item on import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleItemOutcome {
+    Imported,
+    Skipped,
+    Failed,
+}
+
+/// One item in the report of an import
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BundleItemResult {
+    pub kind: BundleItemKind,
+    /// The item in the bundle: its id, file name or linked key
+    pub source: String,
+    pub outcome: BundleItemOutcome,
+    /// Id of what the import created
+    pub target_id: Option<String>,
+    /// Why the item was skipped or failed
+    pub message: Option<String>,
+}
+
+impl BundleItemResult {
+    fn imported(kind: BundleItemKind, source: &str, target_id: String) -> Self {
+        Self {
+            kind,
+            source: source.to_string(),
+            outcome: BundleItemOutcome::Imported,
+            target_id: Some(target_id),
+            message: None,
+        }
+    }
+
+    fn not_imported(
+        kind: BundleItemKind,
+        source: &str,
+        outcome: BundleItemOutcome,
+        message: String,
+    ) -> Self {
+        Self {
+            kind,
+            source: source.to_string(),
+            outcome,
+            target_id: None,
+            message: Some(message),
+        }
+    }
+
+    fn from_result(kind: BundleItemKind, source: &str, result: Result<String>) -> Self {
+        match result {
+            Ok(target_id) => Self::imported(kind, source, target_id),
+            Err(error) => {
+                Self::not_imported(kind, source, BundleItemOutcome::Failed, error.to_string())
+            }
+        }
+    }
+}
+
+/// The outcome of importing a bundle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BundleImportReport {
+    pub source_key: String,
+    pub issue_key: String,
+    pub items: Vec<BundleItemResult>,
+}
+
+impl BundleImportReport {
+    /// Number of items of `kind` with `outcome`
+    #[must_use]
+    pub fn count(&self, kind: BundleItemKind, outcome: BundleItemOutcome) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.kind == kind && item.outcome == outcome)
+            .count()
+    }
+}
+
+/// Display name of the user object `user`
+fn display_name(user: &Value) -> Option<String> {
+    user.get("displayName")
+        .and_then(Value::as_str)
+        .map(ToString::to_string)
+}
+
+/// The string `key` of `value`
+fn text_of(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(ToString::to_string)
+}
+
+impl JiraClient {
+    /// Export `issue_key` as a bundle into the existing directory `dir`,
+    /// downloading its attachments of at most `download_limit` bytes. An
+    /// attachment that cannot be downloaded is recorded with its error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` already holds a bundle, the issue, its
+    /// comments or worklogs cannot be read, or the bundle cannot be written.
+    pub async fn export_issue_bundle(
+        &self,
+        issue_key: &str,
+        dir: &Path,
+        download_limit: Option<u64>,
+    ) -> Result<IssueBundle> {
+        let bundle_path = dir.join(BUNDLE_FILE_NAME);
+        if tokio::fs::try_exists(&bundle_path).await? {
+            return Err(JiraError::validation_error(
+                "target_dir",
+                &format!("{} already exists", bundle_path.display()),
+            ));
+        }
+
+        info!("Exporting issue {} to {}", issue_key, dir.display());
+        let issue = self.get_issue(issue_key).await?;
+        let comments = self
+            .get_all_comments(issue_key)
+            .await?
+            .iter()
+            .map(|comment| BundleComment {
+                id: text_of(comment, "id").unwrap_or_default(),
+                author: display_name(&comment["author"]),
+                created: text_of(comment, "created"),
+                body: comment.get("body").cloned().unwrap_or(Value::Null),
+            })
+            .collect();
+        let (worklogs, _) = self.get_all_work_logs(issue_key, None, usize::MAX).await?;
+        let worklogs = worklogs
+            .iter()
+            .map(|worklog| BundleWorklog {
+                id: text_of(worklog, "id").unwrap_or_default(),
+                author: display_name(&worklog["author"]),
+                started: text_of(worklog, "started"),
+                time_spent: text_of(worklog, "timeSpent").unwrap_or_default(),
+                comment: worklog.get("comment").filter(|c| !c.is_null()).cloned(),
+            })
+            .collect();
+        let links = issue
+            .fields
+            .get("issuelinks")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(BundleLink::from_issue_link)
+            .collect();
+        let attachments = self
+            .export_attachments(&issue.fields, dir, download_limit)
+            .await?;
+
+        let bundle = IssueBundle {
+            schema_version: BUNDLE_SCHEMA_VERSION,
+            exported_by: ATTRIBUTION_SOURCE.to_string(),
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            source: BundleSource {
+                site_url: self.config().site_url(),
+                issue_key: issue.key.clone(),
+                issue_id: issue.id.clone(),
+            },
+            fields: issue.fields.into_iter().collect(),
+            comments,
+            worklogs,
+            attachments,
+            links,
+        };
+        tokio::fs::write(&bundle_path, serde_json::to_vec_pretty(&bundle)?).await?;
+        Ok(bundle)
+    }
+
+    /// Download the attachments listed in `fields` into the attachment
+    /// directory of the bundle directory `dir`
+    async fn export_attachments(
+        &self,
+        fields: &std::collections::HashMap<String, Value>,
+        dir: &Path,
+        download_limit: Option<u64>,
+    ) -> Result<Vec<BundleAttachment>> {
+        let listed = fields
+            .get("attachment")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        if listed.is_empty() {
+            return Ok(Vec::new());
+        }
+        let attachment_dir = dir.join(BUNDLE_ATTACHMENT_DIR);
+        tokio::fs::create_dir_all(&attachment_dir).await?;
+
+        let mut taken = HashSet::new();
+        let mut attachments = Vec::new();
+        for value in listed {
+            let attachment: JiraAttachment = serde_json::from_value(value)?;
+            let size = u64::try_from(attachment.size).unwrap_or(0);
+            let mut entry = BundleAttachment {
+                id: attachment.id.clone(),
+                filename: attachment.filename.clone(),
+                mime_type: Some(attachment.mime_type.clone()).filter(|m| !m.is_empty()),
+                size,
+                file: None,
+                sha256: None,
+                error: None,
+            };
+            if let Some(limit) = download_limit.filter(|limit| size > *limit) {
+                entry.error = Some(format!(
+                    "{size} bytes is more than the download limit of {limit} bytes"
+                ));
+                attachments.push(entry);
+                continue;
+            }
+            let name = unique_file_name(
+                &attachment_dir,
+                &sanitize_file_name(&attachment.filename),
+                &mut taken,
+            );
+            match self
+                .download_attachment_to_file(
+                    &attachment,
+                    &attachment_dir.join(&name),
+                    download_limit,
+                )
+                .await
+            {
+                Ok((_, sha256)) => {
+                    entry.file = Some(format!("{BUNDLE_ATTACHMENT_DIR}/{name}"));
+                    entry.sha256 = Some(sha256);
+                }
+                Err(error) => {
+                    warn!("Could not export attachment {}: {}", attachment.id, error);
+                    entry.error = Some(error.to_string());
+                }
+            }
+            attachments.push(entry);
+        }
+        Ok(attachments)
+    }
+
+    /// Create the issue of `bundle`, read from the bundle directory `dir`, on
+    /// this instance and replay its comments, worklogs, attachments and, if
+    /// `options` ask for it, links
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue cannot be created; failures of the other
+    /// items are reported in the result instead.
+    pub async fn import_issue_bundle(
+        &self,
+        bundle: &IssueBundle,
+        dir: &Path,
+        options: &BundleImportOptions,
+    ) -> Result<BundleImportReport> {
+        let project_key = options
+            .project_key
+            .as_deref()
+            .or_else(|| bundle.project_key())
+            .ok_or_else(|| {
+                JiraError::validation_error("project_key", "The bundle names no project")
+            })?;
+        let issue_type = options
+            .issue_type
+            .as_deref()
+            .or_else(|| bundle.issue_type())
+            .ok_or_else(|| {
+                JiraError::validation_error("issue_type", "The bundle names no issue type")
+            })?;
+
+        let mut issue_data = json!({
+            "fields": {
+                "project": { "key": project_key },
+                "issuetype": { "name": issue_type },
+                "summary": bundle.fields.get("summary").cloned().unwrap_or_default()
+            }
+        });
+        let original_fields = bundle
+            .fields
+            .iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(id, value)| (id.clone(), value.clone()))
+            .collect();
+        Self::apply_field_mapping(&original_fields, &mut issue_data, &options.field_mapping);
+
+        let source_key = bundle.source.issue_key.as_str();
+        info!(
+            "Importing issue bundle of {} into {}",
+            source_key, project_key
+        );
+        let issue_key = self.create_issue(&issue_data).await?.key;
+
+        let mut items = Vec::new();
+        for comment in &bundle.comments {
+            let line = replay_line(
+                comment.author.as_deref(),
+                comment.created.as_deref(),
+                source_key,
+            );
+            let result = self
+                .add_internal_comment(&issue_key, with_replay_line(&comment.body, &line), None)
+                .await
+                .map(|created| created.id);
+            items.push(BundleItemResult::from_result(
+                BundleItemKind::Comment,
+                &comment.id,
+                result,
+            ));
+        }
+        for worklog in &bundle.worklogs {
+            let line = replay_line(
+                worklog.author.as_deref(),
+                worklog.started.as_deref(),
+                source_key,
+            );
+            let comment = worklog.comment.as_ref().unwrap_or(&Value::Null);
+            let request = JiraWorkLogCreateRequest {
+                comment: Some(with_replay_line(comment, &line)),
+                time_spent: worklog.time_spent.clone(),
+                started: worklog.started.clone(),
+                visibility: None,
+            };
+            // Replayed time must not eat into the remaining estimate set by
+            // the copied fields
+            let result = self
+                .add_work_log(&issue_key, &request, &JiraWorkLogEstimateAdjustment::Leave)
+                .await
+                .map(|created| created.id);
+            items.push(BundleItemResult::from_result(
+                BundleItemKind::Worklog,
+                &worklog.id,
+                result,
+            ));
+        }
+        for attachment in &bundle.attachments {
+            items.push(self.import_attachment(&issue_key, attachment, dir).await);
+        }
+        for link in &bundle.links {
+            items.push(
+                self.import_link(&issue_key, link, options.link_issues)
+                    .await,
+            );
+        }
+
+        Ok(BundleImportReport {
+            source_key: source_key.to_string(),
+            issue_key,
+            items,
+        })
+    }
+
+    /// Upload the exported file of `attachment` to `issue_key`, after checking
+    /// it is the file that was exported
+    async fn import_attachment(
+        &self,
+        issue_key: &str,
+        attachment: &BundleAttachment,
+        dir: &Path,
+    ) -> BundleItemResult {
+        let Some(file) = &attachment.file else {
+            return BundleItemResult::not_imported(
+                BundleItemKind::Attachment,
+                &attachment.filename,
+                BundleItemOutcome::Skipped,
+                format!(
+                    "Not in the bundle: {}",
+                    attachment
+                        .error
+                        .as_deref()
+                        .unwrap_or("the export did not download it")
+                ),
+            );
+        };
+        let result = async {
+            let content = tokio::fs::read(dir.join(file)).await?;
+            let sha256 = format!("{:x}", Sha256::digest(&content));
+            if attachment
+                .sha256
+                .as_ref()
+                .is_some_and(|expected| *expected != sha256)
+            {
+                return Err(JiraError::validation_error(
+                    "bundle_dir",
+                    &format!("{file} changed since the export: its SHA-256 does not match"),
+                ));
+            }
+            self.check_attachment_size(&attachment.filename, content.len() as u64)
+                .await?;
+            let uploaded = self
+                .upload_attachment(
+                    issue_key,
+                    &attachment.filename,
+                    &content,
+                    attachment.mime_type.as_deref(),
+                )
+                .await?;
+            uploaded
+                .into_iter()
+                .next()
+                .map(|created| created.id)
+                .ok_or_else(|| JiraError::api_error("Jira returned no attachment"))
+        }
+        .await;
+        BundleItemResult::from_result(BundleItemKind::Attachment, &attachment.filename, result)
+    }
+
+    /// Link `issue_key` to the issue at the other end of `link`, or skip it
+    /// unless `create` is set
+    async fn import_link(
+        &self,
+        issue_key: &str,
+        link: &BundleLink,
+        create: bool,
+    ) -> BundleItemResult {
+        if !create {
+            return BundleItemResult::not_imported(
+                BundleItemKind::Link,
+                &link.issue_key,
+                BundleItemOutcome::Skipped,
+                format!(
+                    "{} {}; pass link_issues: true to link the new issue to {} on this instance",
+                    link.description, link.issue_key, link.issue_key
+                ),
+            );
+        }
+        let (inward, outward) = match link.direction {
+            LinkDirection::Outward => (issue_key, link.issue_key.as_str()),
+            LinkDirection::Inward => (link.issue_key.as_str(), issue_key),
+        };
+        let result = self
+            .link_issues(inward, outward, &link.link_type, None)
+            .await
+            .map(|()| format!("{} {}", link.description, link.issue_key));
+        BundleItemResult::from_result(BundleItemKind::Link, &link.issue_key, result)
+    }
+}
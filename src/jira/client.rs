@@ -1,97 +1,365 @@
 use crate::config::JiraConfig;
 use crate::error::{JiraError, Result};
+use crate::jira::attribution;
+use crate::jira::coalesce::get_global_in_flight_requests;
+use crate::jira::conflicts::{self, ExpectedUpdated};
+use crate::jira::hierarchy::IssueTypeHierarchy;
+use crate::jira::identity;
+use crate::jira::jql::{self, Jql};
+use crate::jira::link_graph::{self, IssueLinkRef, LinkGraph, LinkGraphOptions};
+use crate::jira::maintenance::{self, MaintenanceWindow};
+use crate::jira::prefetch;
+use crate::jira::telemetry::RequestTracker;
+use crate::jira::throttle::{
+    current_priority, in_background, shared_schedulers, AdaptiveThrottle, RateLimitHeaders,
+    RequestClass, RequestSchedulers,
+};
+use crate::jira::urls::{self, path_segment};
+use crate::jira::webhooks::{JiraWebhook, DC_WEBHOOK_NAME};
+use crate::logging::capture::{self, CapturedRequest};
+use crate::logging::sanitize;
+use crate::performance::{get_global_cache_manager, profiler, CacheKeyGenerator};
 use crate::types::jira::{
     BulkOperationConfig, BulkOperationItem, BulkOperationResult, BulkOperationSummary,
-    BulkOperationType, JiraAttachment, JiraComment, JiraComponent, JiraComponentCreateRequest,
-    JiraComponentUpdateRequest, JiraIssue, JiraIssueCloneRequest, JiraIssueCloneResponse,
-    JiraIssueLink, JiraIssueLinkCreateRequest, JiraLabel, JiraLabelCreateRequest,
-    JiraLabelUpdateRequest, JiraLinkType, JiraSearchResult, JiraSprint, JiraSprintAddIssuesRequest,
-    JiraSprintAddIssuesResponse, JiraSprintCreateRequest, JiraSprintCreateResponse,
-    JiraSprintIssuesResponse, JiraSprintUpdateRequest, JiraTransition, JiraWatchersResponse,
-    JiraWorkLog, JiraWorkLogCreateRequest, JiraWorkLogUpdateRequest, ZephyrTestCase,
-    ZephyrTestCaseCreateRequest, ZephyrTestCaseSearchResult, ZephyrTestCycle, ZephyrTestExecution,
+    BulkOperationType, IssueActionFailure, IssueActionReport, IssueActionStep, IssueActions,
+    JiraAttachment, JiraAttachmentMeta, JiraComment, JiraCommentVisibility, JiraComponent,
+    JiraComponentCreateRequest, JiraComponentUpdateRequest, JiraCreateMeta, JiraIssue,
+    JiraIssueCloneRequest, JiraIssueCloneResponse, JiraIssueLink, JiraIssueLinkCreateRequest,
+    JiraIssueTypeStatuses, JiraLabel, JiraLabelCreateRequest, JiraLabelUpdateRequest, JiraLinkType,
+    JiraProject, JiraProjectCategory, JiraSearchResult, JiraSecurityLevel, JiraSprint,
+    JiraSprintAddIssuesRequest, JiraSprintAddIssuesResponse, JiraSprintCreateRequest,
+    JiraSprintCreateResponse, JiraSprintIssuesResponse, JiraSprintUpdateRequest, JiraTransition,
+    JiraWatchersResponse, JiraWorkLog, JiraWorkLogCreateRequest, JiraWorkLogEstimateAdjustment,
+    JiraWorkLogUpdateRequest, JiraWorkflowScheme, ProjectFilter, TransitionOptions,
+    ZephyrAddTestsToCycleRequest, ZephyrAttachment, ZephyrFolder, ZephyrFolderCreateRequest,
+    ZephyrStepFailure, ZephyrTestCase, ZephyrTestCaseCreateRequest, ZephyrTestCaseCreation,
+    ZephyrTestCaseSearchResult, ZephyrTestCycle, ZephyrTestExecution,
     ZephyrTestExecutionCreateRequest, ZephyrTestPlan, ZephyrTestStep, ZephyrTestStepCreateRequest,
     ZephyrTestStepUpdateRequest,
 };
+use crate::utils::anonymize;
+use crate::utils::mentions::{self, MentionCandidate, MentionMatch, MentionResolution};
 use reqwest::{Client, Method, RequestBuilder};
 use serde::{de::DeserializeOwned, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::sync::{Arc, LazyLock};
 use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
 use tracing::{debug, error, info, warn};
 use url::Url;
 
+/// How often a failed request is retried
+const MAX_REQUEST_RETRIES: u32 = 3;
+
+/// Issues fetched per search while walking a link graph
+const LINK_GRAPH_SEARCH_BATCH: usize = 50;
+
+/// Comments requested per page by `visit_comment_pages`
+const COMMENT_PAGE_SIZE: usize = 100;
+
+/// Changelog histories requested per page by `visit_changelog_pages` on Cloud
+const CHANGELOG_PAGE_SIZE: usize = 100;
+
+/// Worklogs requested per page by `get_all_work_logs`
+const WORKLOG_PAGE_SIZE: usize = 100;
+
+/// Data Center's webhook admin API, under the site root
+const DC_WEBHOOKS_PATH: &str = "rest/webhooks/1.0/webhook";
+
+/// Jira Service Management's REST API, under the site root
+const SERVICE_DESK_API_PATH: &str = "rest/servicedeskapi";
+
 /// Jira HTTP Client with comprehensive API support
 pub struct JiraClient {
     client: Client,
     config: JiraConfig,
-    rate_limiter: RateLimiter,
+    schedulers: Arc<RequestSchedulers>,
+    connection_tracker: Arc<ConnectionTracker>,
 }
 
-/// Simple rate limiter to respect Jira API limits
-struct RateLimiter {
-    last_request: std::sync::Mutex<std::time::Instant>,
-    min_interval: Duration,
+/// Estimates whether requests use a new or a pooled connection.
+///
+/// `reqwest` does not expose pool internals, so this mirrors the pool's
+/// behaviour: a request whose response was read to the end leaves one idle
+/// connection behind (up to `pool_max_idle_per_host`), which the next request
+/// picks up unless it has outlived the idle timeout. The counts it feeds into
+/// the metrics are estimates, not read from the pool: Jira closing idle
+/// connections early, for one, goes unnoticed.
+struct ConnectionTracker {
+    idle_since: std::sync::Mutex<Vec<Instant>>,
+    max_idle: Option<usize>,
+    idle_timeout: Duration,
 }
 
-impl RateLimiter {
-    fn new(min_interval: Duration) -> Self {
+impl ConnectionTracker {
+    fn new(config: &JiraConfig) -> Self {
         Self {
-            last_request: std::sync::Mutex::new(
-                std::time::Instant::now()
-                    .checked_sub(min_interval)
-                    .unwrap_or_else(std::time::Instant::now),
-            ),
-            min_interval,
+            idle_since: std::sync::Mutex::new(Vec::new()),
+            max_idle: config.pool_max_idle_per_host,
+            idle_timeout: config.pool_idle_timeout(),
         }
     }
 
-    async fn wait_if_needed(&self) {
-        let now = std::time::Instant::now();
-        let elapsed = {
-            let last_request = self.last_request.lock().unwrap();
-            now.duration_since(*last_request)
-        };
+    /// Take a connection for a new request, reusing an idle one when available.
+    fn acquire(&self) -> ConnectionLease<'_> {
+        let mut idle = self
+            .idle_since
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let idle_timeout = self.idle_timeout;
+        idle.retain(|since| since.elapsed() < idle_timeout);
+        let reused = idle.pop().is_some();
+        debug!(
+            "Using {} connection (estimated)",
+            if reused { "pooled" } else { "new" }
+        );
+        crate::performance::get_global_metrics().record_connection(reused);
+        ConnectionLease {
+            tracker: self,
+            response_read: false,
+        }
+    }
+}
+
+/// A connection taken for one request. Dropping it gives the connection up;
+/// [`ConnectionLease::release`] once the response has been read returns it
+/// to the idle ones, as the pool does. Every way out of a request drops the
+/// lease, so errors cannot leave a connection counted as taken.
+struct ConnectionLease<'a> {
+    tracker: &'a ConnectionTracker,
+    response_read: bool,
+}
 
-        if elapsed < self.min_interval {
-            let sleep_duration = self.min_interval - elapsed;
-            debug!("Rate limiting: sleeping for {:?}", sleep_duration);
-            tokio::time::sleep(sleep_duration).await;
+impl ConnectionLease<'_> {
+    /// Return the connection to the pool; its response has been read
+    fn release(mut self) {
+        self.response_read = true;
+    }
+}
+
+impl Drop for ConnectionLease<'_> {
+    fn drop(&mut self) {
+        if !self.response_read {
+            return;
+        }
+        let mut idle = self
+            .tracker
+            .idle_since
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if self.tracker.max_idle.is_none_or(|max| idle.len() < max) {
+            idle.push(Instant::now());
         }
+    }
+}
 
-        {
-            let mut last_request = self.last_request.lock().unwrap();
-            *last_request = std::time::Instant::now();
+/// An HTTP client and the tracker of its connection pool
+type SharedHttpClient = (Client, Arc<ConnectionTracker>);
+
+/// HTTP clients by connection settings, see [`shared_http_client`]
+static SHARED_HTTP_CLIENTS: LazyLock<std::sync::Mutex<HashMap<String, SharedHttpClient>>> =
+    LazyLock::new(|| std::sync::Mutex::new(HashMap::new()));
+
+/// Get the HTTP client for the connection settings of `config`, building it
+/// the first time. `reqwest` clients are handles to one connection pool, so
+/// every `JiraClient` with these settings uses the same connections, and the
+/// same tracker of them.
+fn shared_http_client(config: &JiraConfig) -> Result<SharedHttpClient> {
+    let key = format!(
+        "{}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}",
+        config.api_base_url,
+        config.timeout_duration(),
+        config.strict_ssl,
+        config.pool_max_idle_per_host,
+        config.pool_idle_timeout(),
+        config.tcp_keepalive(),
+        config.http2_prior_knowledge,
+        config.locale,
+    );
+    let mut clients = SHARED_HTTP_CLIENTS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(shared) = clients.get(&key) {
+        return Ok(shared.clone());
+    }
+
+    let mut builder = Client::builder()
+        .timeout(config.timeout_duration())
+        .danger_accept_invalid_certs(!config.strict_ssl.unwrap_or(true))
+        .pool_idle_timeout(config.pool_idle_timeout())
+        .tcp_keepalive(config.tcp_keepalive());
+
+    if let Some(max_idle) = config.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max_idle);
+    }
+    if config.http2_prior_knowledge.unwrap_or(false) {
+        builder = builder.http2_prior_knowledge();
+    }
+    // `JiraConfig::validate` reports a bad locale; here it only costs the
+    // header
+    if let Some(value) = config.accept_language() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::ACCEPT_LANGUAGE, value);
+        builder = builder.default_headers(headers);
+    } else if let Some(locale) = config.locale.as_deref().filter(|l| !l.is_empty()) {
+        warn!("Invalid locale '{}', not sending Accept-Language", locale);
+    }
+
+    let client = builder.build().map_err(JiraError::HttpClientError)?;
+    let shared = (client, Arc::new(ConnectionTracker::new(config)));
+    clients.insert(key, shared.clone());
+    Ok(shared)
+}
+
+/// Sections of `get_project_metadata`, in the order they are returned
+pub const PROJECT_METADATA_SECTIONS: [&str; 6] = [
+    "configuration",
+    "issue_types",
+    "components",
+    "priorities",
+    "statuses",
+    "custom_fields",
+];
+
+/// Every page of a search, each issue once
+#[derive(Debug, Clone, Default)]
+pub struct FetchedIssues {
+    /// The issues in the order they were first returned
+    pub issues: Vec<serde_json::Value>,
+    /// Whether `ORDER BY key ASC` was added to a query without an order
+    pub order_added: bool,
+    /// Issues returned again on a later page, which were dropped
+    pub duplicates: usize,
+    /// `total` of the first page
+    pub first_total: usize,
+    /// `total` of the last page; it differs from the first when issues
+    /// started or stopped matching while the pages were read
+    pub last_total: usize,
+}
+
+impl FetchedIssues {
+    /// Whether the number of matching issues changed during pagination
+    #[must_use]
+    pub fn total_changed(&self) -> bool {
+        self.first_total != self.last_total
+    }
+
+    /// Lines on how the pages were read, for tool output; empty when there
+    /// is nothing to say
+    #[must_use]
+    pub fn note(&self) -> String {
+        let mut note = String::new();
+        if self.order_added {
+            note.push_str("Sorted by key (ORDER BY key ASC added) so pages could not shift\n");
+        }
+        if self.duplicates > 0 {
+            note.push_str(&format!(
+                "{} issues returned on more than one page were kept once\n",
+                self.duplicates
+            ));
         }
+        if self.total_changed() {
+            note.push_str(&format!(
+                "The query matched {} issues at the first page and {} at the last; \
+                 issues changed while they were read, so some may be missing\n",
+                self.first_total, self.last_total
+            ));
+        }
+        note
     }
 }
 
+/// Read one section of project metadata as JSON, or nothing when it was not
+/// asked for. A section still unread after `timeout` is abandoned with an
+/// error; how long it took is recorded in the metrics either way.
+async fn metadata_section<T: Serialize>(
+    section: Option<(&str, Duration)>,
+    read: impl std::future::Future<Output = Result<T>>,
+) -> Option<Result<serde_json::Value>> {
+    let (name, timeout) = section?;
+    let started = Instant::now();
+    let result = tokio::time::timeout(timeout, read).await;
+    crate::performance::get_global_metrics().record_metadata_section(
+        name,
+        started.elapsed(),
+        result.is_err(),
+    );
+    Some(match result {
+        Ok(read) => read.and_then(|value| Ok(serde_json::to_value(value)?)),
+        Err(_) => Err(JiraError::api_error(&format!(
+            "timed out after {timeout:?} (JIRA_METADATA_SUBCALL_TIMEOUT_SECONDS)"
+        ))),
+    })
+}
+
 impl JiraClient {
     /// Create a new Jira client with the given configuration.
     ///
+    /// Clients with the same connection settings share one HTTP client and
+    /// with it the pool of open connections, so a connection one tool opened
+    /// (or warm-up did) is reused by the next.
+    ///
     /// # Errors
     ///
     /// Returns an error if the HTTP client cannot be created.
     pub fn new(config: JiraConfig) -> Result<Self> {
-        let client = Client::builder()
-            .timeout(config.timeout_duration())
-            .danger_accept_invalid_certs(!config.strict_ssl.unwrap_or(true))
-            .build()
-            .map_err(JiraError::HttpClientError)?;
-
-        let rate_limiter = RateLimiter::new(Duration::from_millis(100)); // 10 requests per second max
+        let (client, connection_tracker) = shared_http_client(&config)?;
+        let schedulers = shared_schedulers(&config);
 
         Ok(Self {
             client,
             config,
-            rate_limiter,
+            schedulers,
+            connection_tracker,
         })
     }
 
+    /// Wait for a slot of `class`, queued by the priority of the current task.
+    async fn wait_for_slot(&self, class: RequestClass) {
+        self.schedulers.get(class).acquire(current_priority()).await;
+    }
+
+    /// Feed rate-limit headers from a response into the throttle of `class`.
+    fn observe_rate_limits(&self, class: RequestClass, response: &reqwest::Response) {
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            crate::performance::get_global_metrics().record_rate_limited();
+        }
+        let mut headers = RateLimitHeaders::from_headers(response.headers());
+        // A maintenance window is waited out by the maintenance state instead
+        if maintenance::is_long_retry_after(response.status(), response.headers()) {
+            headers.retry_after = None;
+        }
+        self.schedulers.get(class).throttle().observe(&headers);
+    }
+
+    /// Get the throttle of core Jira requests shared with other clients.
+    #[must_use]
+    pub fn throttle(&self) -> &AdaptiveThrottle {
+        self.schedulers.get(RequestClass::Core).throttle()
+    }
+
+    /// Get the configuration this client was created with.
+    #[must_use]
+    pub fn config(&self) -> &JiraConfig {
+        &self.config
+    }
+
     /// Get the API base URL from the configuration.
     #[must_use]
     pub fn api_base_url(&self) -> &str {
         &self.config.api_base_url
     }
 
+    /// Link to an issue in the Jira UI; see [`JiraConfig::browse_url`].
+    #[must_use]
+    pub fn browse_url(&self, issue_key: &str) -> String {
+        self.config.browse_url(issue_key)
+    }
+
     /// Get the authentication header from the configuration.
     #[must_use]
     pub fn auth_header(&self) -> String {
@@ -113,10 +381,27 @@ impl JiraClient {
     where
         T: DeserializeOwned,
     {
-        self.request(Method::GET, endpoint, None::<&()>).await
+        if self.config.coalesces_endpoint(endpoint) {
+            let url = self.build_url(endpoint)?;
+            // Keep credentials out of the key while still separating users
+            let mut hasher = DefaultHasher::new();
+            self.auth_header().hash(&mut hasher);
+            let key = format!("GET {url} {:x}", hasher.finish());
+            let value = get_global_in_flight_requests()
+                .run(key, || {
+                    self.request::<serde_json::Value, ()>(Method::GET, endpoint, None, true)
+                })
+                .await?;
+            return serde_json::from_value(value).map_err(JiraError::SerializationError);
+        }
+
+        self.request(Method::GET, endpoint, None::<&()>, true).await
     }
 
-    /// Make a POST request to the Jira API
+    /// Make a POST request to the Jira API.
+    ///
+    /// A POST that fails with a server error or timeout may still have been
+    /// applied, so it is only retried when `retry_non_idempotent` is set.
     ///
     /// # Errors
     ///
@@ -126,7 +411,21 @@ impl JiraClient {
         T: DeserializeOwned,
         U: Serialize + ?Sized,
     {
-        self.request(Method::POST, endpoint, Some(body)).await
+        self.request(Method::POST, endpoint, Some(body), false)
+            .await
+    }
+
+    /// Make a POST request that is safe to repeat, so it is retried like a GET
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn post_idempotent<T, U>(&self, endpoint: &str, body: &U) -> Result<T>
+    where
+        T: DeserializeOwned,
+        U: Serialize + ?Sized,
+    {
+        self.request(Method::POST, endpoint, Some(body), true).await
     }
 
     /// Make a PUT request to the Jira API
@@ -139,7 +438,7 @@ impl JiraClient {
         T: DeserializeOwned,
         U: Serialize + ?Sized,
     {
-        self.request(Method::PUT, endpoint, Some(body)).await
+        self.request(Method::PUT, endpoint, Some(body), true).await
     }
 
     /// Make a DELETE request to the Jira API
@@ -151,56 +450,246 @@ impl JiraClient {
     where
         T: DeserializeOwned,
     {
-        self.request(Method::DELETE, endpoint, None::<&()>).await
+        self.request(Method::DELETE, endpoint, None::<&()>, true)
+            .await
     }
 
     /// Make a generic HTTP request with retry logic
     ///
+    /// Requests that are not `idempotent` are not retried after a server error
+    /// or timeout (see [`Self::may_retry`]); the status of such a failure is
+    /// kept in an [`JiraError::HttpError`].
+    ///
     /// # Errors
     ///
     /// Returns an error if the request fails after all retries or the response cannot be parsed.
-    async fn request<T, U>(&self, method: Method, endpoint: &str, body: Option<&U>) -> Result<T>
+    async fn request<T, U>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<&U>,
+        idempotent: bool,
+    ) -> Result<T>
     where
         T: DeserializeOwned,
         U: Serialize + ?Sized,
     {
         let url = self.build_url(endpoint)?;
+        self.request_url(method, url, endpoint, body, idempotent)
+            .await
+    }
+
+    /// Make a request to `path` under the site root rather than the REST API
+    /// base URL, for APIs such as `rest/webhooks/1.0` that live next to it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails after all retries or the response cannot be parsed.
+    async fn site_request<T, U>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&U>,
+        idempotent: bool,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        U: Serialize + ?Sized,
+    {
+        let url = urls::join(&self.config.site_url(), path, "site base URL")?;
+        self.request_url(method, url, path, body, idempotent).await
+    }
+
+    /// Make a request to the Jira Service Management API (`rest/servicedeskapi`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails after all retries or the response cannot be parsed.
+    pub(crate) async fn service_desk_request<T, U>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<&U>,
+        idempotent: bool,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        U: Serialize + ?Sized,
+    {
+        let path = format!(
+            "{SERVICE_DESK_API_PATH}/{}",
+            endpoint.trim_start_matches('/')
+        );
+        self.site_request(method, &path, body, idempotent).await
+    }
+
+    /// Make a request to the Tempo REST API at `tempo_api_url`, authenticated
+    /// with the Tempo token rather than the Jira credentials
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails after all retries or the response cannot be parsed.
+    pub(crate) async fn tempo_request<T, U>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<&U>,
+        idempotent: bool,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        U: Serialize + ?Sized,
+    {
+        let url = urls::join(self.config.tempo_api_url(), endpoint, "Tempo API URL")?;
+        self.request_url(method, url, endpoint, body, idempotent)
+            .await
+    }
+
+    /// Make a request to the Assets (Insight) API under `base`, the Cloud
+    /// Assets API or `{site}/rest/insight/1.0`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails after all retries or the response cannot be parsed.
+    pub(crate) async fn assets_request<T, U>(
+        &self,
+        method: Method,
+        base: &str,
+        endpoint: &str,
+        body: Option<&U>,
+        idempotent: bool,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        U: Serialize + ?Sized,
+    {
+        let url = urls::join(base, endpoint, "Assets API URL")?;
+        self.request_url(method, url, endpoint, body, idempotent)
+            .await
+    }
+
+    /// Send a request to `url`, which `endpoint` names for rate limiting,
+    /// profiling and cache invalidation, with the retries of [`Self::request`]
+    async fn request_url<T, U>(
+        &self,
+        method: Method,
+        url: Url,
+        endpoint: &str,
+        body: Option<&U>,
+        idempotent: bool,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        U: Serialize + ?Sized,
+    {
+        let _segment = profiler::http_segment(&method, endpoint);
+        let class = RequestClass::for_endpoint(endpoint);
         let mut retry_count = 0;
-        let max_retries = 3;
+        let max_retries = MAX_REQUEST_RETRIES;
+        let mut tracker = RequestTracker::start();
+        // Tempo has maintenance windows of its own
+        let on_site = url.as_str().starts_with(self.config.site_url().as_str());
+        if let Some(window) = on_site
+            .then(|| maintenance::blocking(&self.config))
+            .flatten()
+        {
+            return self
+                .during_maintenance(&method, &url, &window, &mut tracker)
+                .await;
+        }
 
         loop {
+            tracker.attempt();
             // Apply rate limiting
-            self.rate_limiter.wait_if_needed().await;
+            self.wait_for_slot(class).await;
 
-            let request_builder = self.build_request(method.clone(), &url, body)?;
+            let request = self
+                .build_request(method.clone(), &url, body)?
+                .build()
+                .map_err(JiraError::HttpClientError)?;
 
-            info!("Making {} request to {}", method, url);
+            info!(
+                "Making {} request to {}",
+                method,
+                sanitize::sanitize_url(url.as_str())
+            );
 
             // Log detailed request information for debugging
-            let auth_header = self.auth_header();
-            debug!("Request URL: {}", url);
-            debug!("Authorization header: {}", auth_header);
-            debug!("Content-Type: application/json");
-            debug!("Accept: application/json");
+            debug!("Request URL: {}", sanitize::sanitize_url(url.as_str()));
+            for (key, value) in request.headers() {
+                debug!(
+                    "Request header {}: {}",
+                    key,
+                    sanitize::sanitize_header(key.as_str(), value.to_str().unwrap_or("<invalid>"))
+                );
+            }
+            let captured = capture::is_active().then(|| CapturedRequest::from_request(&request));
+
+            let lease = self.connection_tracker.acquire();
 
-            match request_builder.send().await {
+            match self.client.execute(request).await {
                 Ok(response) => {
                     let status = response.status();
+                    let headers = response.headers().clone();
+                    self.observe_rate_limits(class, &response);
                     debug!("Response status: {}", status);
 
                     // Log response headers for debugging redirects
                     debug!("Response headers:");
                     for (key, value) in response.headers() {
-                        debug!("  {}: {}", key, value.to_str().unwrap_or("<invalid>"));
+                        debug!(
+                            "  {}: {}",
+                            key,
+                            sanitize::sanitize_header(
+                                key.as_str(),
+                                value.to_str().unwrap_or("<invalid>")
+                            )
+                        );
                     }
 
                     if status.is_success() {
+                        tracker.succeeded();
+                        if on_site {
+                            maintenance::clear(&self.config);
+                        }
                         let response_text =
                             response.text().await.map_err(JiraError::HttpClientError)?;
-
-                        debug!("Response body: {}", response_text);
-
-                        return serde_json::from_str(&response_text).map_err(|e| {
+                        lease.release();
+                        if let Some(captured) = captured {
+                            capture::record(
+                                captured.with_response(status.as_u16(), &response_text),
+                            );
+                        }
+
+                        debug!("Response body: {}", sanitize::sanitize_text(&response_text));
+
+                        if method != Method::GET {
+                            Self::invalidate_cached_searches(endpoint);
+                            prefetch::invalidate_endpoint(&self.config, endpoint);
+                        }
+
+                        // Endpoints such as transitions answer 204 with no body
+                        let body = if response_text.trim().is_empty() {
+                            "null"
+                        } else {
+                            response_text.as_str()
+                        };
+
+                        let keeps_response = method == Method::GET && self.serves_stale();
+                        if keeps_response || self.anonymizes() {
+                            let value: serde_json::Value = serde_json::from_str(body)?;
+                            if keeps_response {
+                                maintenance::keep_response(url.as_str(), &value).await;
+                            }
+                            if self.anonymizes() {
+                                anonymize::remember_users(&value);
+                            }
+                            return serde_json::from_value(value).map_err(|e| {
+                                error!("Failed to parse JSON response: {}", e);
+                                JiraError::SerializationError(e)
+                            });
+                        }
+                        return serde_json::from_str(body).map_err(|e| {
                             error!("Failed to parse JSON response: {}", e);
                             JiraError::SerializationError(e)
                         });
@@ -208,14 +697,33 @@ impl JiraClient {
 
                     let error_text = response.text().await.map_err(JiraError::HttpClientError)?;
 
-                    error!("HTTP error {}: {}", status, error_text);
+                    lease.release();
+                    if let Some(captured) = captured {
+                        capture::record(captured.with_response(status.as_u16(), &error_text));
+                    }
+
+                    // Retries would only add to the load of an upgrade
+                    if on_site && maintenance::is_maintenance(status, &headers, &error_text) {
+                        tracker.failed(Some(status));
+                        let window = maintenance::enter(&self.config, &headers);
+                        return Err(window.error());
+                    }
+
+                    error!(
+                        "HTTP error {}: {}",
+                        status,
+                        sanitize::sanitize_text(&error_text)
+                    );
 
                     // Log additional details for redirects (like Okta SSO)
                     if status.is_redirection() {
                         error!("REDIRECT DETECTED - This might be Okta SSO redirect");
                         error!(
                             "Response body (first 500 chars): {}",
-                            error_text.chars().take(500).collect::<String>()
+                            sanitize::sanitize_text(&error_text)
+                                .chars()
+                                .take(500)
+                                .collect::<String>()
                         );
                     }
 
@@ -224,18 +732,25 @@ impl JiraClient {
                         .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new()));
 
                     let jira_error = JiraError::from_jira_response(status, &error_json);
+                    tracker.failed(Some(status));
 
                     // Retry on certain status codes
                     if retry_count < max_retries && Self::should_retry(status) {
+                        if !self.may_retry(idempotent, Some(status)) {
+                            warn!(
+                                "Not retrying {} {}: it may already have been applied",
+                                method,
+                                sanitize::sanitize_url(url.as_str())
+                            );
+                            return Err(Self::unretried_error(status, jira_error));
+                        }
                         retry_count += 1;
-                        // retry_count is always positive (starts at 0, only incremented)
-                        let delay = Duration::from_millis(
-                            1000 * u64::try_from(retry_count.max(0)).unwrap_or(0),
-                        );
+                        let delay = Duration::from_millis(1000 * u64::from(retry_count));
                         warn!(
                             "Retrying request in {:?} (attempt {}/{})",
                             delay, retry_count, max_retries
                         );
+                        tracker.backoff(delay);
                         tokio::time::sleep(delay).await;
                         continue;
                     }
@@ -244,17 +759,22 @@ impl JiraClient {
                 }
                 Err(e) => {
                     error!("Request failed: {}", e);
+                    tracker.failed(None);
+                    if let Some(captured) = captured {
+                        capture::record(captured.with_error(&e.to_string()));
+                    }
 
-                    if retry_count < max_retries && e.is_timeout() {
+                    if retry_count < max_retries
+                        && e.is_timeout()
+                        && self.may_retry(idempotent, None)
+                    {
                         retry_count += 1;
-                        // retry_count is always positive (starts at 0, only incremented)
-                        let delay = Duration::from_millis(
-                            1000 * u64::try_from(retry_count.max(0)).unwrap_or(0),
-                        );
+                        let delay = Duration::from_millis(1000 * u64::from(retry_count));
                         warn!(
                             "Retrying request after timeout in {:?} (attempt {}/{})",
                             delay, retry_count, max_retries
                         );
+                        tracker.backoff(delay);
                         tokio::time::sleep(delay).await;
                         continue;
                     }
@@ -265,21 +785,65 @@ impl JiraClient {
         }
     }
 
+    /// Whether GET responses are kept for, and served during, maintenance
+    fn serves_stale(&self) -> bool {
+        self.config.serve_stale_during_maintenance.unwrap_or(false)
+    }
+
+    /// Whether users read from Jira get aliases for `anonymize_output`
+    fn anonymizes(&self) -> bool {
+        self.config.anonymize_output.unwrap_or(false)
+    }
+
+    /// Answer a request not sent while Jira is in `window`: with the last
+    /// response of a GET when stale serving is on, with the window's error
+    /// otherwise
+    async fn during_maintenance<T>(
+        &self,
+        method: &Method,
+        url: &Url,
+        window: &MaintenanceWindow,
+        tracker: &mut RequestTracker,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        if *method == Method::GET && self.serves_stale() {
+            if let Some(body) = maintenance::stale_response(url.as_str()).await {
+                info!(
+                    "Serving {} from cache: {}",
+                    sanitize::sanitize_url(url.as_str()),
+                    window.describe()
+                );
+                tracker.served_stale(window.describe());
+                return serde_json::from_value(body).map_err(JiraError::SerializationError);
+            }
+        }
+        debug!(
+            "Not sending {} {}: {}",
+            method,
+            sanitize::sanitize_url(url.as_str()),
+            window.describe()
+        );
+        Err(window.error())
+    }
+
     /// Build a complete URL from the endpoint
     fn build_url(&self, endpoint: &str) -> Result<Url> {
-        // Ensure the base URL ends with a slash for proper joining
-        let base_url_str = if self.config.api_base_url.ends_with('/') {
-            self.config.api_base_url.clone()
-        } else {
-            format!("{}/", self.config.api_base_url)
-        };
-
-        let base_url = Url::parse(&base_url_str)
-            .map_err(|e| JiraError::config_error(&format!("Invalid API base URL: {e}")))?;
+        urls::join(&self.config.api_base_url, endpoint, "API base URL")
+    }
 
-        base_url
-            .join(endpoint)
-            .map_err(|e| JiraError::config_error(&format!("Invalid endpoint URL: {e}")))
+    /// The `Authorization` header for `url`: the Tempo token for the Tempo
+    /// API, the Jira credentials for everything else
+    fn authorization(&self, url: &Url) -> String {
+        let is_tempo = || {
+            urls::base_url(self.config.tempo_api_url(), "Tempo API URL")
+                .is_ok_and(|base| url.as_str().starts_with(base.as_str()))
+        };
+        match self.config.tempo_api_token.as_deref() {
+            Some(token) if is_tempo() => format!("Bearer {token}"),
+            _ => self.auth_header(),
+        }
     }
 
     /// Build a request with proper headers and authentication
@@ -292,23 +856,63 @@ impl JiraClient {
     where
         U: Serialize + ?Sized,
     {
-        let mut request = self
+        let request = self
             .client
             .request(method, url.as_str())
-            .header("Authorization", self.auth_header())
+            .header("Authorization", self.authorization(url))
             .header("Content-Type", "application/json")
-            .header("Accept", "application/json")
-            .header("User-Agent", "rust-jira-mcp/0.4.1");
+            .header("Accept", "application/json");
+        let mut request = identity::identify(request, &self.config);
+        // Parts of the service desk API are experimental and refuse requests
+        // that do not opt in; the header is ignored by the stable parts
+        if url.path().contains(&format!("/{SERVICE_DESK_API_PATH}/")) {
+            request = request.header("X-ExperimentalApi", "opt-in");
+        }
 
         if let Some(body) = body {
             let json_body = serde_json::to_string(body).map_err(JiraError::SerializationError)?;
-            debug!("Request body: {}", json_body);
+            debug!("Request body: {}", sanitize::sanitize_text(&json_body));
             request = request.body(json_body);
         }
 
         Ok(request)
     }
 
+    /// Whether a request that failed with `status`, or timed out when `None`,
+    /// may be sent again.
+    ///
+    /// Jira applied nothing when it answered 429, but after a server error or
+    /// timeout the request may have gone through with only the response lost,
+    /// so those are only retried when repeating the request is harmless.
+    fn may_retry(&self, idempotent: bool, status: Option<reqwest::StatusCode>) -> bool {
+        idempotent
+            || status == Some(reqwest::StatusCode::TOO_MANY_REQUESTS)
+            || self.config.retry_non_idempotent.unwrap_or(false)
+    }
+
+    /// Error for a request not retried after `status`, keeping the status so
+    /// callers can tell the request may have been applied
+    fn unretried_error(status: reqwest::StatusCode, error: JiraError) -> JiraError {
+        let message = match error {
+            JiraError::ApiError { message, .. } => message,
+            other => other.to_string(),
+        };
+        JiraError::HttpError { status, message }
+    }
+
+    /// Whether a POST that failed with `error` may nevertheless have been
+    /// applied by Jira
+    #[must_use]
+    pub fn may_have_been_applied(error: &JiraError) -> bool {
+        match error {
+            JiraError::HttpError { status, .. } => {
+                Self::should_retry(*status) && *status != reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            JiraError::HttpClientError(e) => e.is_timeout(),
+            _ => false,
+        }
+    }
+
     /// Determine if a request should be retried based on the HTTP status code
     #[must_use]
     pub fn should_retry(status: reqwest::StatusCode) -> bool {
@@ -330,7 +934,42 @@ impl JiraClient {
     ///
     /// Returns an error if the issue cannot be found or the request fails.
     pub async fn get_issue(&self, issue_key: &str) -> Result<JiraIssue> {
-        let endpoint = format!("issue/{issue_key}");
+        let endpoint = format!("issue/{issue_key}", issue_key = path_segment(issue_key));
+        if !self.config.prefetch_enabled.unwrap_or(false) {
+            return self.get(&endpoint).await;
+        }
+
+        // Issues fetched often are kept warm by the prefetcher
+        let metrics = crate::performance::get_global_metrics();
+        let prefetcher = prefetch::shared_prefetcher(&self.config);
+        let issue_key = issue_key.to_uppercase();
+        let now = Instant::now();
+        let accesses = metrics.record_issue_access(&issue_key);
+        if prefetcher.record_access(&issue_key, accesses, now) {
+            if let Some(cached) = prefetcher.cached(&issue_key, now) {
+                metrics.record_cache_hit();
+                return Ok(serde_json::from_value(cached)?);
+            }
+            metrics.record_cache_miss();
+        }
+        // A change sent while this fetch is under way makes its response stale
+        let generation = prefetcher.generation();
+        let issue: serde_json::Value = self.get(&endpoint).await?;
+        prefetcher.store(&issue_key, &issue, generation, now);
+        Ok(serde_json::from_value(issue)?)
+    }
+
+    /// Get a Jira issue with only the given fields populated
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_issue_fields(&self, issue_key: &str, fields: &[String]) -> Result<JiraIssue> {
+        let endpoint = format!(
+            "issue/{issue_key}?fields={}",
+            urlencoding::encode(&fields.join(",")),
+            issue_key = path_segment(issue_key)
+        );
         self.get(&endpoint).await
     }
 
@@ -344,6 +983,19 @@ impl JiraClient {
         jql: &str,
         start_at: Option<i32>,
         max_results: Option<i32>,
+    ) -> Result<JiraSearchResult> {
+        self.search_issue_page(jql, start_at, max_results, &[])
+            .await
+    }
+
+    /// Search for Jira issues using JQL, returning only `fields` unless it is
+    /// empty
+    async fn search_issue_page(
+        &self,
+        jql: &str,
+        start_at: Option<i32>,
+        max_results: Option<i32>,
+        fields: &[String],
     ) -> Result<JiraSearchResult> {
         let mut params = vec![("jql".to_string(), jql.to_string())];
 
@@ -357,6 +1009,10 @@ impl JiraClient {
             params.push(("maxResults".to_string(), default_max.to_string()));
         }
 
+        if !fields.is_empty() {
+            params.push(("fields".to_string(), fields.join(",")));
+        }
+
         let query_string = params
             .iter()
             .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
@@ -367,41 +1023,683 @@ impl JiraClient {
         self.get(&endpoint).await
     }
 
-    /// Create a new Jira issue
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the issue creation fails or the response cannot be parsed.
-    pub async fn create_issue(&self, issue_data: &serde_json::Value) -> Result<JiraIssue> {
-        self.post("issue", issue_data).await
-    }
-
-    /// Update a Jira issue
+    /// Search for Jira issues, returning only the given fields
     ///
     /// # Errors
     ///
-    /// Returns an error if the issue update fails or the response cannot be parsed.
-    pub async fn update_issue(
+    /// Returns an error if the search fails or the response cannot be parsed.
+    pub async fn search_issues_with_fields(
         &self,
-        issue_key: &str,
-        update_data: &serde_json::Value,
-    ) -> Result<()> {
-        let endpoint = format!("issue/{issue_key}");
-        let _: serde_json::Value = self.put(&endpoint, update_data).await?;
-        Ok(())
+        jql: &str,
+        max_results: i32,
+        fields: &[String],
+    ) -> Result<JiraSearchResult> {
+        let endpoint = format!(
+            "search?jql={}&maxResults={max_results}&fields={}",
+            urlencoding::encode(jql),
+            urlencoding::encode(&fields.join(","))
+        );
+        self.get(&endpoint).await
     }
 
-    /// Add a comment to a Jira issue
+    /// Count the issues matching `jql` without fetching any of them.
+    ///
+    /// Jira Cloud answers from `search/approximate-count`, which may lag
+    /// recent changes by a few seconds; elsewhere, or if that endpoint is
+    /// unavailable, a search with `maxResults=0` returns just the total.
     ///
     /// # Errors
     ///
-    /// Returns an error if the comment creation fails or the response cannot be parsed.
-    pub async fn add_comment(&self, issue_key: &str, comment_body: &str) -> Result<JiraComment> {
-        let endpoint = format!("issue/{issue_key}/comment");
-        let comment_data = serde_json::json!({
-            "body": comment_body
+    /// Returns an error if the search fails or the response has no total.
+    pub async fn count_issues(&self, jql: &str) -> Result<u64> {
+        if self.config.is_cloud() {
+            match self
+                .post::<serde_json::Value, _>(
+                    "search/approximate-count",
+                    &serde_json::json!({ "jql": jql }),
+                )
+                .await
+            {
+                Ok(response) => {
+                    if let Some(count) = response.get("count").and_then(serde_json::Value::as_u64) {
+                        return Ok(count);
+                    }
+                }
+                // The search reports errors in the query itself just as well
+                Err(e) => debug!("Approximate count unavailable, searching instead: {e}"),
+            }
+        }
+        let endpoint = format!(
+            "search?jql={}&maxResults=0&fields=id",
+            urlencoding::encode(jql)
+        );
+        let response: serde_json::Value = self.get(&endpoint).await?;
+        response
+            .get("total")
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| JiraError::api_error("Invalid search response format - missing total"))
+    }
+
+    /// Search for issues with their changelogs, fetching every page.
+    ///
+    /// Stops before fetching any further pages if the query matches more than
+    /// `max_issues` issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the query matches more than `max_issues`
+    /// issues, or an error if a request fails or the response cannot be parsed.
+    pub async fn search_issues_with_changelog(
+        &self,
+        jql: &str,
+        fields: &[String],
+        max_issues: usize,
+    ) -> Result<FetchedIssues> {
+        self.search_all_issue_pages(jql, fields, max_issues, Some("changelog"))
+            .await
+    }
+
+    /// Search for issues with only `fields` populated, fetching every page.
+    ///
+    /// Stops before fetching any further pages if the query matches more than
+    /// `max_issues` issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the query matches more than `max_issues`
+    /// issues, or an error if a request fails or the response cannot be parsed.
+    pub async fn search_issues_bounded(
+        &self,
+        jql: &str,
+        fields: &[String],
+        max_issues: usize,
+    ) -> Result<FetchedIssues> {
+        self.search_all_issue_pages(jql, fields, max_issues, None)
+            .await
+    }
+
+    /// Every page of a search as JSON issues, expanding `expand` if given.
+    ///
+    /// Without an `ORDER BY` Jira may move issues between pages as they are
+    /// read, so the query is sorted by key then. Issues seen on an earlier
+    /// page are dropped, by id.
+    async fn search_all_issue_pages(
+        &self,
+        jql: &str,
+        fields: &[String],
+        max_issues: usize,
+        expand: Option<&str>,
+    ) -> Result<FetchedIssues> {
+        let page_size = self.config.max_results.unwrap_or(50).max(1);
+        let expand = expand
+            .map(|expand| format!("&expand={expand}"))
+            .unwrap_or_default();
+        let ordered = jql::with_key_order(jql);
+        let mut fetched = FetchedIssues {
+            order_added: ordered.is_some(),
+            ..FetchedIssues::default()
+        };
+        let jql = ordered.as_deref().unwrap_or(jql);
+        let mut seen = std::collections::HashSet::new();
+        let mut start_at = 0;
+
+        loop {
+            let endpoint = format!(
+                "search?jql={}&startAt={start_at}&maxResults={page_size}&fields={}{expand}",
+                urlencoding::encode(jql),
+                urlencoding::encode(&fields.join(","))
+            );
+            let response: serde_json::Value = self.get(&endpoint).await?;
+            let total = response
+                .get("total")
+                .and_then(serde_json::Value::as_u64)
+                .and_then(|total| usize::try_from(total).ok())
+                .unwrap_or(0);
+            if total > max_issues {
+                return Err(JiraError::validation_error(
+                    "jql",
+                    &format!(
+                        "The query matches {total} issues, more than the limit of {max_issues}. \
+                         Narrow the JQL, e.g. by project, sprint or date"
+                    ),
+                ));
+            }
+
+            if start_at == 0 {
+                fetched.first_total = total;
+            }
+            fetched.last_total = total;
+
+            let page = response
+                .get("issues")
+                .and_then(|v| v.as_array())
+                .ok_or_else(|| JiraError::api_error("Invalid search response format"))?;
+            for issue in page {
+                let id = issue
+                    .get("id")
+                    .or_else(|| issue.get("key"))
+                    .and_then(serde_json::Value::as_str)
+                    .map(ToString::to_string);
+                if id.is_some_and(|id| !seen.insert(id)) {
+                    fetched.duplicates += 1;
+                } else {
+                    fetched.issues.push(issue.clone());
+                }
+            }
+            start_at += page.len();
+            if page.is_empty() || start_at >= total {
+                break;
+            }
+        }
+
+        Ok(fetched)
+    }
+
+    /// Search for Jira issues, serving repeated identical queries from the search cache.
+    ///
+    /// Results are only cached when `search_cache_enabled` is set. The returned flag
+    /// is `true` when the result came from the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the search fails or the response cannot be parsed.
+    pub async fn search_issues_cached(
+        &self,
+        jql: &str,
+        start_at: Option<i32>,
+        max_results: Option<i32>,
+        fields: &[String],
+        bypass_cache: bool,
+    ) -> Result<(JiraSearchResult, bool)> {
+        if !self.config.search_cache_enabled.unwrap_or(false) {
+            let result = self
+                .search_issue_page(jql, start_at, max_results, fields)
+                .await?;
+            return Ok((result, false));
+        }
+
+        let cache = &get_global_cache_manager().search_results;
+        let metrics = crate::performance::get_global_metrics();
+        let key = CacheKeyGenerator::search_results(
+            &self.config.api_base_url,
+            jql,
+            start_at,
+            max_results,
+            fields,
+        );
+
+        if !bypass_cache {
+            if let Some(cached) = cache.get(&key, self.config.search_cache_ttl()) {
+                if let Ok(result) = serde_json::from_value(cached) {
+                    metrics.record_cache_hit();
+                    return Ok((result, true));
+                }
+            }
+        }
+        metrics.record_cache_miss();
+
+        let result = self
+            .search_issue_page(jql, start_at, max_results, fields)
+            .await?;
+        let issue_keys = result
+            .issues
+            .iter()
+            .flat_map(|issue| [issue.key.clone(), issue.id.clone()])
+            .collect();
+        cache.insert(
+            key,
+            serde_json::to_value(&result)?,
+            issue_keys,
+            self.config.search_cache_ttl(),
+        );
+
+        Ok((result, false))
+    }
+
+    /// Drop cached searches containing the issue a mutating request was sent to.
+    fn invalidate_cached_searches(endpoint: &str) {
+        if let Some(issue_key) = endpoint
+            .strip_prefix("issue/")
+            .and_then(|rest| rest.split(['/', '?']).next())
+            .filter(|key| !key.is_empty())
+        {
+            get_global_cache_manager()
+                .search_results
+                .invalidate_issue(issue_key);
+        }
+    }
+
+    /// Create a new Jira issue, with the `auto_label` label if one is set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue creation fails or the response cannot be parsed.
+    pub async fn create_issue(&self, issue_data: &serde_json::Value) -> Result<JiraIssue> {
+        self.post("issue", &self.labelled(issue_data)).await
+    }
+
+    /// Update a Jira issue with `update_data`: values to set under `fields`,
+    /// verb operations under `update`, or both
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue update fails or the response cannot be parsed.
+    pub async fn update_issue(
+        &self,
+        issue_key: &str,
+        update_data: &serde_json::Value,
+    ) -> Result<()> {
+        let endpoint = format!("issue/{issue_key}", issue_key = path_segment(issue_key));
+        let _: serde_json::Value = self.put(&endpoint, update_data).await?;
+        Ok(())
+    }
+
+    /// Fail with a conflict error if the issue has been updated since the
+    /// `expected_updated` timestamp the caller last saw.
+    ///
+    /// The error lists the field changes made in between. The check is not
+    /// atomic with a following update, so it narrows the window for lost
+    /// updates rather than closing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `expected_updated` is not a timestamp, a
+    /// conflict error if the issue changed, or an error if it cannot be read.
+    pub async fn ensure_unchanged_since(
+        &self,
+        issue_key: &str,
+        expected_updated: &str,
+    ) -> Result<()> {
+        let expected = ExpectedUpdated::parse(expected_updated)?;
+        let endpoint = format!(
+            "issue/{issue_key}?fields=updated&expand=changelog",
+            issue_key = path_segment(issue_key)
+        );
+        let issue: serde_json::Value = self.get(&endpoint).await?;
+        let actual = issue
+            .pointer("/fields/updated")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                JiraError::api_error(&format!("Issue {issue_key} has no 'updated' field"))
+            })?;
+        let unchanged = crate::utils::datetime::parse_timestamp(actual)
+            .is_some_and(|time| expected.matches(time.with_timezone(&chrono::Utc)));
+        if unchanged {
+            return Ok(());
+        }
+
+        let changes = conflicts::changes_since(&issue, &expected);
+        Err(JiraError::conflict(&conflicts::describe_conflict(
+            issue_key,
+            expected_updated,
+            actual,
+            &changes,
+        )))
+    }
+
+    /// Apply an update payload, first checking the issue is unchanged when
+    /// the payload carries an `expected_updated` timestamp, which is not sent
+    ///
+    /// # Errors
+    ///
+    /// Returns a conflict error if the issue changed, or an error if the
+    /// check or the update fails.
+    pub async fn update_issue_if_unchanged(
+        &self,
+        issue_key: &str,
+        update_data: &serde_json::Value,
+    ) -> Result<()> {
+        let Some(expected) = update_data.get(conflicts::EXPECTED_UPDATED_KEY) else {
+            return self.update_issue(issue_key, update_data).await;
+        };
+        let expected = expected.as_str().ok_or_else(|| {
+            JiraError::validation_error(
+                conflicts::EXPECTED_UPDATED_KEY,
+                "must be a timestamp string",
+            )
+        })?;
+        self.ensure_unchanged_since(issue_key, expected).await?;
+
+        let mut update_data = update_data.clone();
+        if let Some(data) = update_data.as_object_mut() {
+            data.remove(conflicts::EXPECTED_UPDATED_KEY);
+        }
+        self.update_issue(issue_key, &update_data).await
+    }
+
+    /// Assign an issue, or unassign it when `assignee` is `None`.
+    ///
+    /// `assignee` is an account id on Cloud and a username on Server/Data Center.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn assign_issue(&self, issue_key: &str, assignee: Option<&str>) -> Result<()> {
+        let endpoint = format!(
+            "issue/{issue_key}/assignee",
+            issue_key = path_segment(issue_key)
+        );
+        let field = if self.config.is_cloud() {
+            "accountId"
+        } else {
+            "name"
+        };
+        let body = serde_json::json!({ field: assignee });
+        let _: serde_json::Value = self.put(&endpoint, &body).await?;
+        Ok(())
+    }
+
+    /// Add and remove labels of an issue in one update, leaving its other
+    /// labels alone
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    pub async fn update_issue_labels(
+        &self,
+        issue_key: &str,
+        add: &[String],
+        remove: &[String],
+    ) -> Result<()> {
+        self.update_issue_items(issue_key, "labels", add, remove)
+            .await
+    }
+
+    /// Add a comment to a Jira issue
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the comment creation fails or the response cannot be parsed.
+    pub async fn add_comment(&self, issue_key: &str, comment_body: &str) -> Result<JiraComment> {
+        self.add_comment_with_visibility(issue_key, comment_body, None)
+            .await
+    }
+
+    /// Add a comment to a Jira issue, optionally restricted to a role or group
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the visibility is invalid, the request fails or the
+    /// response cannot be parsed.
+    pub async fn add_comment_with_visibility(
+        &self,
+        issue_key: &str,
+        comment_body: impl Into<serde_json::Value> + Send,
+        visibility: Option<&JiraCommentVisibility>,
+    ) -> Result<JiraComment> {
+        let comment_data = Self::comment_payload(comment_body, visibility)?;
+        self.post_comment(issue_key, &comment_data).await
+    }
+
+    /// Post the comment `comment_data` (see [`Self::comment_payload`]) to
+    /// `issue_key`, signed with `comment_signature` if one is set, retrying
+    /// only when the failed attempt did not go through
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub(crate) async fn post_comment(
+        &self,
+        issue_key: &str,
+        comment_data: &serde_json::Value,
+    ) -> Result<JiraComment> {
+        let endpoint = format!(
+            "issue/{issue_key}/comment",
+            issue_key = path_segment(issue_key)
+        );
+        let mut comment_data = comment_data.clone();
+        comment_data["body"] = self.signed(&comment_data["body"]);
+        let comment_data = &comment_data;
+        if self.config.retry_non_idempotent.unwrap_or(false) {
+            return self.post(&endpoint, comment_data).await;
+        }
+
+        // Jira has no idempotency keys, so before posting a comment again
+        // check whether the failed attempt went through after all
+        let since = chrono::Utc::now() - chrono::Duration::minutes(1);
+        let mut retry_count = 0;
+        loop {
+            match self.post(&endpoint, comment_data).await {
+                Err(e) if retry_count < MAX_REQUEST_RETRIES && Self::may_have_been_applied(&e) => {
+                    retry_count += 1;
+                    tokio::time::sleep(Duration::from_millis(1000 * u64::from(retry_count))).await;
+                    if let Some(comment) = self
+                        .find_recent_comment(issue_key, &comment_data["body"], since)
+                        .await?
+                    {
+                        warn!(
+                            "Comment on {} was created despite the error, not posting it again",
+                            issue_key
+                        );
+                        return Ok(comment);
+                    }
+                    warn!(
+                        "Retrying comment on {} (attempt {}/{})",
+                        issue_key, retry_count, MAX_REQUEST_RETRIES
+                    );
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Find a comment on `issue_key` with `body` that the current user created
+    /// after `since`
+    async fn find_recent_comment(
+        &self,
+        issue_key: &str,
+        body: &serde_json::Value,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<JiraComment>> {
+        let me = self.current_user_watcher_id().await?;
+        let comments = self.get_recent_comments(issue_key, 20).await?;
+        for comment in comments {
+            let author = &comment["author"];
+            let by_me = ["accountId", "name"]
+                .iter()
+                .any(|id| author.get(*id).and_then(|v| v.as_str()) == Some(me.as_str()));
+            let recent = comment
+                .get("created")
+                .and_then(|v| v.as_str())
+                .and_then(crate::utils::datetime::parse_timestamp)
+                .is_some_and(|created| created >= since);
+            if by_me && recent && comment.get("body") == Some(body) {
+                return serde_json::from_value(comment)
+                    .map(Some)
+                    .map_err(JiraError::SerializationError);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Get a single comment of an issue
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the comment cannot be found or the request fails.
+    pub async fn get_comment(
+        &self,
+        issue_key: &str,
+        comment_id: &str,
+    ) -> Result<serde_json::Value> {
+        let endpoint = format!(
+            "issue/{}/comment/{}",
+            path_segment(issue_key),
+            path_segment(comment_id)
+        );
+        self.get(&endpoint).await
+    }
+
+    /// Update the body and visibility of an existing comment, keeping it
+    /// signed with `comment_signature` if one is set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the visibility is invalid, the request fails or the
+    /// response cannot be parsed.
+    pub async fn update_comment(
+        &self,
+        issue_key: &str,
+        comment_id: &str,
+        comment_body: &str,
+        visibility: Option<&JiraCommentVisibility>,
+    ) -> Result<JiraComment> {
+        let endpoint = format!(
+            "issue/{issue_key}/comment/{comment_id}",
+            issue_key = path_segment(issue_key)
+        );
+        let mut comment_data = Self::comment_payload(comment_body, visibility)?;
+        comment_data["body"] = self.signed(&comment_data["body"]);
+        self.put(&endpoint, &comment_data).await
+    }
+
+    /// Search for users matching `query` by name, username or email.
+    ///
+    /// Results are cached, since the same people tend to be looked up repeatedly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn search_users(&self, query: &str) -> Result<Vec<serde_json::Value>> {
+        // Cloud takes `query`; Server/Data Center still uses `username`
+        let param = if self.config.is_cloud() {
+            "query"
+        } else {
+            "username"
+        };
+        let endpoint = format!(
+            "user/search?{param}={}&maxResults=20",
+            urlencoding::encode(query)
+        );
+
+        let cache = &get_global_cache_manager().parsed_objects;
+        let metrics = crate::performance::get_global_metrics();
+        let key = CacheKeyGenerator::parsed_object(
+            "user_search",
+            &format!("{}:{endpoint}", self.config.api_base_url),
+        );
+
+        if let Some(cached) = cache.get(&key).await {
+            if let Ok(users) = serde_json::from_value(cached) {
+                metrics.record_cache_hit();
+                return Ok(users);
+            }
+        }
+        metrics.record_cache_miss();
+
+        let users: Vec<serde_json::Value> = self.get(&endpoint).await?;
+        cache
+            .insert(key, serde_json::Value::Array(users.clone()))
+            .await;
+        Ok(users)
+    }
+
+    /// Replace `@name` mentions in `text` with Jira mention syntax.
+    ///
+    /// Each distinct name is looked up once through the user search API. The
+    /// body is wiki markup for API v2 and an ADF document for v3; names that
+    /// match no user, or several, are left as written and reported back.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a user search request fails.
+    pub async fn resolve_mentions(&self, text: &str) -> Result<MentionResolution> {
+        let mut matches = HashMap::new();
+        for token in mentions::find_mentions(text) {
+            if matches.contains_key(&token.query) {
+                continue;
+            }
+            let candidates: Vec<MentionCandidate> = self
+                .search_users(&token.query)
+                .await?
+                .iter()
+                .filter_map(MentionCandidate::from_user_json)
+                .collect();
+            let found = mentions::match_candidate(&token.query, &candidates);
+            matches.insert(token.query, found);
+        }
+
+        Ok(MentionResolution::new(
+            text,
+            &matches,
+            self.config.is_api_v3(),
+        ))
+    }
+
+    /// Find the one user `query` (display name, email, username or account
+    /// id) refers to, through the user search API
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming `field` if no user or several users
+    /// match, or an error if the user search fails.
+    pub async fn resolve_user(&self, field: &str, query: &str) -> Result<MentionCandidate> {
+        let candidates: Vec<MentionCandidate> = self
+            .search_users(query)
+            .await?
+            .iter()
+            .filter_map(MentionCandidate::from_user_json)
+            .collect();
+        match mentions::match_candidate(query, &candidates) {
+            MentionMatch::Resolved(user) => Ok(user),
+            MentionMatch::NotFound => Err(JiraError::validation_error(
+                field,
+                &format!("No user matches '{query}'"),
+            )),
+            MentionMatch::Ambiguous(names) => Err(JiraError::validation_error(
+                field,
+                &format!(
+                    "'{query}' matches several users: {}; use an email or account id",
+                    names.join(", ")
+                ),
+            )),
+        }
+    }
+
+    /// Attribute `text` to the user `on_behalf_of` refers to, for comments and
+    /// work logs written by a service account for someone else.
+    ///
+    /// Returns the text starting with the attribution line, and the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `annotate_on_behalf` is not enabled or
+    /// the user cannot be resolved, so nothing is written unattributed.
+    pub async fn attribute_on_behalf(
+        &self,
+        text: &str,
+        on_behalf_of: &str,
+    ) -> Result<(String, MentionCandidate)> {
+        if !self.config.annotate_on_behalf.unwrap_or(false) {
+            return Err(JiraError::validation_error(
+                "on_behalf_of",
+                "Writing on behalf of another user is disabled; set JIRA_ANNOTATE_ON_BEHALF=true to allow it",
+            ));
+        }
+        let user = self.resolve_user("on_behalf_of", on_behalf_of).await?;
+        Ok((
+            attribution::with_attribution(text, &user.display_name),
+            user,
+        ))
+    }
+
+    /// Build the request body for adding or updating a comment
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the visibility type is not supported.
+    pub fn comment_payload(
+        comment_body: impl Into<serde_json::Value>,
+        visibility: Option<&JiraCommentVisibility>,
+    ) -> Result<serde_json::Value> {
+        let mut comment_data = serde_json::json!({
+            "body": comment_body.into()
         });
-        self.post(&endpoint, &comment_data).await
+        if let Some(visibility) = visibility {
+            visibility.validate()?;
+            comment_data["visibility"] = serde_json::to_value(visibility)?;
+        }
+        Ok(comment_data)
     }
 
     /// Get comments for a Jira issue
@@ -410,7 +1708,10 @@ impl JiraClient {
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn get_comments(&self, issue_key: &str) -> Result<Vec<JiraComment>> {
-        let endpoint = format!("issue/{issue_key}/comment");
+        let endpoint = format!(
+            "issue/{issue_key}/comment",
+            issue_key = path_segment(issue_key)
+        );
         let response: serde_json::Value = self.get(&endpoint).await?;
 
         // Extract comments from the response
@@ -425,73 +1726,596 @@ impl JiraClient {
                 serde_json::from_value(comment.clone()).map_err(JiraError::SerializationError)?;
             result.push(comment);
         }
-
-        Ok(result)
+
+        Ok(result)
+    }
+
+    /// Every comment of an issue as returned by Jira, oldest first, fetched
+    /// a page at a time
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or the response cannot be parsed.
+    pub async fn get_all_comments(&self, issue_key: &str) -> Result<Vec<serde_json::Value>> {
+        let mut comments = Vec::new();
+        self.visit_comment_pages(issue_key, |page| comments.extend(page.iter().cloned()))
+            .await?;
+        Ok(comments)
+    }
+
+    /// Every comment of an issue with its entity properties, which hold the
+    /// public/internal flag of service desk comments on Data Center
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or the response cannot be parsed.
+    pub async fn get_all_comments_with_properties(
+        &self,
+        issue_key: &str,
+    ) -> Result<Vec<serde_json::Value>> {
+        let mut comments = Vec::new();
+        self.comment_pages(issue_key, Some("properties"), |page| {
+            comments.extend(page.iter().cloned());
+        })
+        .await?;
+        Ok(comments)
+    }
+
+    /// Fetch the comments of an issue a page at a time, oldest first, handing
+    /// each page to `visit` so callers can keep only what they need.
+    /// Returns the number of comments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or the response cannot be parsed.
+    pub async fn visit_comment_pages<F>(&self, issue_key: &str, visit: F) -> Result<usize>
+    where
+        F: FnMut(&[serde_json::Value]) + Send,
+    {
+        self.comment_pages(issue_key, None, visit).await
+    }
+
+    /// [`Self::visit_comment_pages`] with the comments expanded by `expand`
+    async fn comment_pages<F>(
+        &self,
+        issue_key: &str,
+        expand: Option<&str>,
+        mut visit: F,
+    ) -> Result<usize>
+    where
+        F: FnMut(&[serde_json::Value]) + Send,
+    {
+        let expand = expand
+            .map(|expand| format!("&expand={expand}"))
+            .unwrap_or_default();
+        let mut seen = 0;
+        loop {
+            let endpoint = format!(
+                "issue/{issue_key}/comment?startAt={seen}&maxResults={COMMENT_PAGE_SIZE}&orderBy=created{expand}", issue_key = path_segment(issue_key));
+            let response: serde_json::Value = self.get(&endpoint).await?;
+            let page = response
+                .get("comments")
+                .and_then(|c| c.as_array())
+                .ok_or_else(|| JiraError::api_error("Invalid comments response format"))?;
+            visit(page);
+            seen += page.len();
+
+            let total = response
+                .get("total")
+                .and_then(serde_json::Value::as_u64)
+                .and_then(|total| usize::try_from(total).ok())
+                .unwrap_or(seen);
+            if page.is_empty() || seen >= total {
+                return Ok(seen);
+            }
+        }
+    }
+
+    /// Fetch the changelog of an issue a page at a time, handing each page
+    /// of histories to `visit`. Returns the number of histories.
+    ///
+    /// Cloud pages through `issue/{key}/changelog`; Server/Data Center has no
+    /// such endpoint and returns the whole changelog with `expand=changelog`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or the response cannot be parsed.
+    pub async fn visit_changelog_pages<F>(&self, issue_key: &str, mut visit: F) -> Result<usize>
+    where
+        F: FnMut(&[serde_json::Value]) + Send,
+    {
+        if !self.config.is_cloud() {
+            let endpoint = format!(
+                "issue/{issue_key}?fields=created&expand=changelog",
+                issue_key = path_segment(issue_key)
+            );
+            let issue: serde_json::Value = self.get(&endpoint).await?;
+            let histories = issue
+                .pointer("/changelog/histories")
+                .and_then(|h| h.as_array())
+                .ok_or_else(|| JiraError::api_error("Invalid changelog response format"))?;
+            visit(histories);
+            return Ok(histories.len());
+        }
+
+        let mut seen = 0;
+        loop {
+            let endpoint = format!(
+                "issue/{issue_key}/changelog?startAt={seen}&maxResults={CHANGELOG_PAGE_SIZE}",
+                issue_key = path_segment(issue_key)
+            );
+            let response: serde_json::Value = self.get(&endpoint).await?;
+            let page = response
+                .get("values")
+                .and_then(|v| v.as_array())
+                .ok_or_else(|| JiraError::api_error("Invalid changelog response format"))?;
+            visit(page);
+            seen += page.len();
+
+            let is_last = response
+                .get("isLast")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true);
+            if page.is_empty() || is_last {
+                return Ok(seen);
+            }
+        }
+    }
+
+    /// Get the most recent comments of an issue as returned by Jira, newest
+    /// first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_recent_comments(
+        &self,
+        issue_key: &str,
+        max_results: usize,
+    ) -> Result<Vec<serde_json::Value>> {
+        let endpoint = format!(
+            "issue/{issue_key}/comment?maxResults={max_results}&orderBy=-created",
+            issue_key = path_segment(issue_key)
+        );
+        let response: serde_json::Value = self.get(&endpoint).await?;
+
+        response
+            .get("comments")
+            .and_then(|c| c.as_array())
+            .cloned()
+            .ok_or_else(|| JiraError::api_error("Invalid comments response format"))
+    }
+
+    /// Get the fields that can be edited on an issue, with their allowed
+    /// values
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the issue doesn't exist
+    pub async fn get_edit_metadata(&self, issue_key: &str) -> Result<serde_json::Value> {
+        self.get(&format!(
+            "issue/{issue_key}/editmeta",
+            issue_key = path_segment(issue_key)
+        ))
+        .await
+    }
+
+    /// Get available transitions for a Jira issue
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_transitions(&self, issue_key: &str) -> Result<Vec<JiraTransition>> {
+        let endpoint = format!(
+            "issue/{issue_key}/transitions",
+            issue_key = path_segment(issue_key)
+        );
+        let response: serde_json::Value = self.get(&endpoint).await?;
+
+        // Extract transitions from the response
+        let transitions = response
+            .get("transitions")
+            .and_then(|t| t.as_array())
+            .ok_or_else(|| JiraError::api_error("Invalid transitions response format"))?;
+
+        let mut result = Vec::new();
+        for transition in transitions {
+            let transition: JiraTransition = serde_json::from_value(transition.clone())
+                .map_err(JiraError::SerializationError)?;
+            result.push(transition);
+        }
+
+        Ok(result)
+    }
+
+    /// Transition a Jira issue to a new status
+    ///
+    /// Any `fields` and `resolution` in `options` are sent in the transition
+    /// payload, which is needed for transitions whose screen requires them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transition fails or the response cannot be parsed.
+    pub async fn transition_issue(
+        &self,
+        issue_key: &str,
+        transition_id: &str,
+        options: &TransitionOptions,
+    ) -> Result<()> {
+        let endpoint = format!(
+            "issue/{issue_key}/transitions",
+            issue_key = path_segment(issue_key)
+        );
+        let mut transition_data = Self::transition_payload(transition_id, options);
+        if let Some(body) = transition_data.pointer_mut("/update/comment/0/add/body") {
+            *body = self.signed(body);
+        }
+
+        let _: serde_json::Value = self.post(&endpoint, &transition_data).await?;
+        Ok(())
+    }
+
+    /// Build the request body for a transition
+    #[must_use]
+    pub fn transition_payload(
+        transition_id: &str,
+        options: &TransitionOptions,
+    ) -> serde_json::Value {
+        let mut transition_data = serde_json::json!({
+            "transition": {
+                "id": transition_id
+            }
+        });
+
+        if let Some(fields) = &options.fields {
+            transition_data["fields"] = serde_json::Value::Object(fields.clone());
+        }
+
+        if let Some(resolution) = &options.resolution {
+            transition_data["fields"]["resolution"] = match resolution {
+                serde_json::Value::String(name) => serde_json::json!({ "name": name }),
+                other => other.clone(),
+            };
+        }
+
+        if let Some(comment_text) = &options.comment {
+            transition_data["update"] = serde_json::json!({
+                "comment": [{ "add": { "body": comment_text } }]
+            });
+        }
+
+        transition_data
+    }
+
+    /// Find the transition matching `name`, ignoring case.
+    ///
+    /// Transition names are matched first; if none match, the name of the
+    /// target status is tried so that e.g. "In Progress" finds "Start Progress".
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error listing the valid transition names if no
+    /// transition matches or the name matches more than one transition.
+    pub fn match_transition<'a>(
+        transitions: &'a [JiraTransition],
+        name: &str,
+    ) -> Result<&'a JiraTransition> {
+        let wanted = name.trim();
+        let mut matches: Vec<&JiraTransition> = transitions
+            .iter()
+            .filter(|t| t.name.eq_ignore_ascii_case(wanted))
+            .collect();
+        if matches.is_empty() {
+            matches = transitions
+                .iter()
+                .filter(|t| t.to.name.eq_ignore_ascii_case(wanted))
+                .collect();
+        }
+
+        let valid_names = || {
+            transitions
+                .iter()
+                .map(|t| format!("'{}'", t.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        match matches.as_slice() {
+            [transition] => Ok(transition),
+            [] => Err(JiraError::validation_error(
+                "transition_name",
+                &format!(
+                    "No transition named '{wanted}' is available. Valid transitions: {}",
+                    valid_names()
+                ),
+            )),
+            ambiguous => Err(JiraError::validation_error(
+                "transition_name",
+                &format!(
+                    "Transition name '{wanted}' is ambiguous, it matches {}. Valid transitions: {}",
+                    ambiguous
+                        .iter()
+                        .map(|t| format!("'{}' (id {})", t.name, t.id))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    valid_names()
+                ),
+            )),
+        }
+    }
+
+    /// Resolve a transition name to the transition available on an issue
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transitions cannot be fetched or the name does
+    /// not identify exactly one of them.
+    pub async fn resolve_transition(
+        &self,
+        issue_key: &str,
+        transition_name: &str,
+    ) -> Result<JiraTransition> {
+        let transitions = self.get_transitions(issue_key).await?;
+        Self::match_transition(&transitions, transition_name).cloned()
+    }
+
+    /// Resolve a transition name for many issues at once.
+    ///
+    /// Issues sharing a project, issue type and status have the same
+    /// transitions available, so transitions are fetched once per group
+    /// rather than once per issue. Returns the transition id for each issue key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issues or their transitions cannot be fetched,
+    /// or if the name does not identify exactly one transition for any group.
+    pub async fn resolve_transition_ids(
+        &self,
+        issue_keys: &[String],
+        transition_name: &str,
+    ) -> Result<HashMap<String, String>> {
+        let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+        for chunk in issue_keys.chunks(100) {
+            let jql = Jql::new().key_in(chunk).build();
+            let fields = ["project", "issuetype", "status"].map(ToString::to_string);
+            let max_results = i32::try_from(chunk.len()).unwrap_or(100);
+            let (result, _) = self
+                .search_issues_cached(&jql, Some(0), Some(max_results), &fields, true)
+                .await?;
+
+            for issue in result.issues {
+                let field_id = |name: &str| {
+                    issue
+                        .fields
+                        .get(name)
+                        .and_then(|f| f.get("id").or_else(|| f.get("key")))
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .to_string()
+                };
+                let workflow_key = format!(
+                    "{}/{}/{}",
+                    field_id("project"),
+                    field_id("issuetype"),
+                    field_id("status")
+                );
+                groups.entry(workflow_key).or_default().push(issue.key);
+            }
+        }
+
+        // Keys the search did not return are resolved individually
+        let found: std::collections::HashSet<&String> = groups.values().flatten().collect();
+        let missing: Vec<String> = issue_keys
+            .iter()
+            .filter(|key| !found.contains(key))
+            .cloned()
+            .collect();
+        for key in missing {
+            groups.insert(format!("issue/{key}"), vec![key]);
+        }
+
+        let mut resolved = HashMap::new();
+        for keys in groups.into_values() {
+            let transition = self.resolve_transition(&keys[0], transition_name).await?;
+            debug!(
+                "Resolved transition '{}' to id {} for {} issues",
+                transition_name,
+                transition.id,
+                keys.len()
+            );
+            for key in keys {
+                resolved.insert(key, transition.id.clone());
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Apply `actions` to an issue one step at a time, in the order of
+    /// [`IssueActions::steps`], stopping at the first step that fails.
+    ///
+    /// Jira cannot apply the steps atomically, so the report says which steps
+    /// were applied and which were left undone rather than returning an error.
+    pub async fn apply_issue_actions(
+        &self,
+        issue_key: &str,
+        actions: &IssueActions,
+    ) -> IssueActionReport {
+        let mut report = IssueActionReport {
+            issue_key: issue_key.to_string(),
+            completed: Vec::new(),
+            failed: None,
+            not_attempted: Vec::new(),
+        };
+        for step in actions.steps() {
+            if report.failed.is_some() {
+                report.not_attempted.push(step);
+                continue;
+            }
+            match self.apply_issue_action(issue_key, actions, step).await {
+                Ok(()) => report.completed.push(step),
+                Err(e) => {
+                    warn!("Step {step} of the actions on {issue_key} failed: {e}");
+                    report.failed = Some(IssueActionFailure {
+                        step,
+                        error_message: e.to_string(),
+                        error_kind: e.kind(),
+                    });
+                }
+            }
+        }
+        report
+    }
+
+    /// Apply a single step of `actions`
+    async fn apply_issue_action(
+        &self,
+        issue_key: &str,
+        actions: &IssueActions,
+        step: IssueActionStep,
+    ) -> Result<()> {
+        match step {
+            IssueActionStep::Assign => {
+                let assignee = actions
+                    .assign
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|a| !a.is_empty());
+                self.assign_issue(issue_key, assignee).await
+            }
+            IssueActionStep::Labels => {
+                self.update_issue_labels(issue_key, &actions.add_labels, &actions.remove_labels)
+                    .await
+            }
+            IssueActionStep::Comment => {
+                let comment = actions.comment.as_deref().unwrap_or_default();
+                self.add_internal_comment(issue_key, comment, None)
+                    .await
+                    .map(|_| ())
+            }
+            IssueActionStep::Transition => {
+                let transition = actions.transition.as_deref().unwrap_or_default().trim();
+                let transition_id =
+                    if !transition.is_empty() && transition.chars().all(|c| c.is_ascii_digit()) {
+                        transition.to_string()
+                    } else {
+                        self.resolve_transition(issue_key, transition).await?.id
+                    };
+                self.transition_issue(issue_key, &transition_id, &TransitionOptions::default())
+                    .await
+            }
+        }
     }
 
-    /// Get available transitions for a Jira issue
+    // Project Configuration and Metadata methods
+
+    /// List the projects matching `filter`.
+    ///
+    /// Cloud pages through `project/search`, which only returns the requested
+    /// statuses. Server/Data Center returns every project, archived ones
+    /// included, from one request; it has no deleted projects.
     ///
     /// # Errors
     ///
-    /// Returns an error if the request fails or the response cannot be parsed.
-    pub async fn get_transitions(&self, issue_key: &str) -> Result<Vec<JiraTransition>> {
-        let endpoint = format!("issue/{issue_key}/transitions");
-        let response: serde_json::Value = self.get(&endpoint).await?;
-
-        // Extract transitions from the response
-        let transitions = response
-            .get("transitions")
-            .and_then(|t| t.as_array())
-            .ok_or_else(|| JiraError::api_error("Invalid transitions response format"))?;
-
-        let mut result = Vec::new();
-        for transition in transitions {
-            let transition: JiraTransition = serde_json::from_value(transition.clone())
-                .map_err(JiraError::SerializationError)?;
-            result.push(transition);
-        }
+    /// Returns an error if a request fails or the response cannot be parsed.
+    pub async fn list_projects(&self, filter: &ProjectFilter) -> Result<Vec<JiraProject>> {
+        let projects: Vec<JiraProject> = if self.config.is_cloud() {
+            let statuses: String = filter
+                .statuses
+                .iter()
+                .map(|status| format!("&status={}", status.as_str()))
+                .collect();
+            let mut projects = Vec::new();
+            loop {
+                let endpoint = format!(
+                    "project/search?startAt={}&maxResults=50{statuses}",
+                    projects.len()
+                );
+                let page: serde_json::Value = self.get(&endpoint).await?;
+                let values: Vec<JiraProject> =
+                    serde_json::from_value(page.get("values").cloned().unwrap_or_default())?;
+                let is_last = page
+                    .get("isLast")
+                    .and_then(serde_json::Value::as_bool)
+                    .unwrap_or(true);
+                let empty = values.is_empty();
+                projects.extend(values);
+                if is_last || empty {
+                    break projects;
+                }
+            }
+        } else {
+            self.get("project?includeArchived=true").await?
+        };
 
-        Ok(result)
+        Ok(projects
+            .into_iter()
+            .filter(|project| filter.matches(project))
+            .collect())
     }
 
-    /// Transition a Jira issue to a new status
+    /// Get every project category, kept in the metadata cache
     ///
     /// # Errors
     ///
-    /// Returns an error if the transition fails or the response cannot be parsed.
-    pub async fn transition_issue(
-        &self,
-        issue_key: &str,
-        transition_id: &str,
-        comment: Option<&str>,
-    ) -> Result<()> {
-        let endpoint = format!("issue/{issue_key}/transitions");
-
-        let mut transition_data = serde_json::json!({
-            "transition": {
-                "id": transition_id
-            }
-        });
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_project_categories(&self) -> Result<Vec<JiraProjectCategory>> {
+        self.get_cached_metadata("projectCategory").await
+    }
 
-        if let Some(comment_text) = comment {
-            transition_data["update"]["comment"][0]["add"]["body"] =
-                serde_json::Value::String(comment_text.to_string());
+    /// Whether the site is Jira Cloud: by its host or, for Cloud sites behind
+    /// another name, by the `deploymentType` of `serverInfo`, which is kept
+    /// in the metadata cache. A site whose server info cannot be read is taken
+    /// for Server/Data Center.
+    pub async fn is_cloud_deployment(&self) -> bool {
+        if self.config.is_cloud() {
+            return true;
         }
-
-        let _: serde_json::Value = self.post(&endpoint, &transition_data).await?;
-        Ok(())
+        self.get_cached_metadata::<serde_json::Value>("serverInfo")
+            .await
+            .ok()
+            .and_then(|info| {
+                info.get("deploymentType")
+                    .and_then(|t| t.as_str())
+                    .map(|t| t.eq_ignore_ascii_case("cloud"))
+            })
+            .unwrap_or(false)
     }
 
-    // Project Configuration and Metadata methods
-
-    /// Get project configuration details
+    /// Get project configuration details.
+    ///
+    /// Server/Data Center answers `project/{key}/configuration`. Cloud has no
+    /// such endpoint, so there the view is put together from the project
+    /// details, its workflow scheme (left `null` without permission to read
+    /// it) and the statuses of each issue type.
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn get_project_configuration(&self, project_key: &str) -> Result<serde_json::Value> {
-        let endpoint = format!("project/{project_key}/configuration");
-        self.get(&endpoint).await
+        if !self.is_cloud_deployment().await {
+            let endpoint = format!("project/{project_key}/configuration");
+            return self.get(&endpoint).await;
+        }
+
+        let endpoint =
+            format!("project/{project_key}?expand=description,lead,issueTypes,url,projectKeys");
+        let (project, workflow_scheme, statuses) = tokio::join!(
+            self.get::<serde_json::Value>(&endpoint),
+            self.get_workflow_scheme(project_key),
+            self.get_project_statuses(project_key)
+        );
+
+        let mut configuration = project?;
+        configuration["workflowScheme"] = match workflow_scheme {
+            Ok(scheme) => serde_json::to_value(scheme)?,
+            Err(e) => {
+                debug!("Workflow scheme of {} not available: {}", project_key, e);
+                serde_json::Value::Null
+            }
+        };
+        configuration["issueTypeStatuses"] = serde_json::to_value(statuses?)?;
+        Ok(configuration)
     }
 
     /// Get issue types for a project
@@ -524,6 +2348,277 @@ impl JiraClient {
         Ok(result)
     }
 
+    /// The type of a project (`software`, `service_desk`, `business`), read
+    /// from the project in the metadata cache
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project cannot be read.
+    pub async fn get_project_type(&self, project_key: &str) -> Result<Option<String>> {
+        let project: serde_json::Value = self
+            .get_cached_metadata(&format!("project/{project_key}"))
+            .await?;
+        Ok(project
+            .get("projectTypeKey")
+            .and_then(|t| t.as_str())
+            .map(ToString::to_string))
+    }
+
+    /// Get the issue type hierarchy of a project, kept in the metadata cache.
+    ///
+    /// The project's issue types carry their hierarchy levels on Cloud; on
+    /// Data Center the levels are inferred (see [`IssueTypeHierarchy`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the project has no issue types.
+    pub async fn get_issue_type_hierarchy(&self, project_key: &str) -> Result<IssueTypeHierarchy> {
+        let project: serde_json::Value = self
+            .get_cached_metadata(&format!("project/{project_key}"))
+            .await?;
+        IssueTypeHierarchy::from_project(project_key, &project)
+    }
+
+    /// Get the statuses available to each issue type in a project.
+    ///
+    /// Results are kept in the metadata cache, as workflows rarely change.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_project_statuses(
+        &self,
+        project_key: &str,
+    ) -> Result<Vec<JiraIssueTypeStatuses>> {
+        let endpoint = format!("project/{project_key}/statuses");
+        self.get_cached_metadata(&endpoint).await
+    }
+
+    /// Get the workflow scheme used by a project.
+    ///
+    /// Reading workflow schemes requires administer permissions on most instances,
+    /// so callers should treat failures as "not available" rather than fatal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project or scheme cannot be read, or if no scheme
+    /// is associated with the project.
+    pub async fn get_workflow_scheme(&self, project_key: &str) -> Result<JiraWorkflowScheme> {
+        let project: serde_json::Value = self
+            .get_cached_metadata(&format!("project/{project_key}"))
+            .await?;
+        let project_id = project
+            .get("id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Invalid project response format - missing id"))?;
+
+        let response: serde_json::Value = self
+            .get_cached_metadata(&format!("workflowscheme/project?projectId={project_id}"))
+            .await?;
+
+        let scheme = response
+            .get("values")
+            .and_then(|v| v.as_array())
+            .and_then(|values| values.first())
+            .and_then(|v| v.get("workflowScheme"))
+            .ok_or_else(|| {
+                JiraError::api_error(&format!(
+                    "No workflow scheme associated with project {project_key}"
+                ))
+            })?;
+
+        serde_json::from_value(scheme.clone()).map_err(JiraError::SerializationError)
+    }
+
+    /// Fill the metadata cache entry of `endpoint` (e.g. `status`, `field`)
+    /// ahead of the lookups that read it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn prefetch_metadata(&self, endpoint: &str) -> Result<()> {
+        self.get_cached_metadata::<serde_json::Value>(endpoint)
+            .await
+            .map(|_| ())
+    }
+
+    /// Whether the metadata cache holds an entry for `endpoint`
+    pub async fn is_metadata_cached(&self, endpoint: &str) -> bool {
+        get_global_cache_manager()
+            .config_cache
+            .get(&self.metadata_cache_key(endpoint))
+            .await
+            .is_some()
+    }
+
+    /// GET a metadata endpoint through the global metadata cache
+    async fn get_cached_metadata<T>(&self, endpoint: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let cache = &get_global_cache_manager().config_cache;
+        let metrics = crate::performance::get_global_metrics();
+        let key = self.metadata_cache_key(endpoint);
+
+        if let Some(cached) = cache.get(&key).await {
+            if let Ok(value) = serde_json::from_value(cached) {
+                metrics.record_cache_hit();
+                return Ok(value);
+            }
+        }
+        metrics.record_cache_miss();
+
+        let value: serde_json::Value = self.get(endpoint).await?;
+        cache.insert(key, value.clone()).await;
+        serde_json::from_value(value).map_err(JiraError::SerializationError)
+    }
+
+    /// GET a metadata endpoint through the metadata cache, dropping the cached
+    /// copy first when `refresh` is set
+    async fn get_refreshed_metadata<T>(&self, endpoint: &str, refresh: bool) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        if refresh {
+            get_global_cache_manager()
+                .config_cache
+                .remove(&self.metadata_cache_key(endpoint))
+                .await;
+        }
+        self.get_cached_metadata(endpoint).await
+    }
+
+    fn metadata_cache_key(&self, endpoint: &str) -> String {
+        CacheKeyGenerator::config(&format!("{}:{endpoint}", self.config.api_base_url))
+    }
+
+    /// Get the fields on the create screen of an issue type in a project.
+    ///
+    /// `issue_type` is an issue type id or name. Create-meta is kept in the
+    /// metadata cache; `refresh` fetches it again, e.g. after a screen change.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error listing the creatable issue types if
+    /// `issue_type` is not one of them, or an error if a request fails or a
+    /// response cannot be parsed.
+    pub async fn get_create_meta(
+        &self,
+        project_key: &str,
+        issue_type: &str,
+        refresh: bool,
+    ) -> Result<JiraCreateMeta> {
+        // Data Center pages under "values", Cloud under "issueTypes" / "fields"
+        let list = |response: &serde_json::Value, cloud_key: &str| -> Vec<serde_json::Value> {
+            response
+                .get("values")
+                .or_else(|| response.get(cloud_key))
+                .and_then(|v| v.as_array())
+                .cloned()
+                .unwrap_or_default()
+        };
+
+        let response: serde_json::Value = self
+            .get_refreshed_metadata(
+                &format!("issue/createmeta/{project_key}/issuetypes?maxResults=200"),
+                refresh,
+            )
+            .await?;
+        let issue_types = list(&response, "issueTypes");
+
+        let text = |t: &serde_json::Value, key: &str| {
+            t.get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let wanted = issue_type.trim();
+        let matched = issue_types
+            .iter()
+            .find(|t| text(t, "id") == wanted)
+            .or_else(|| {
+                issue_types
+                    .iter()
+                    .find(|t| text(t, "name").eq_ignore_ascii_case(wanted))
+            })
+            .ok_or_else(|| {
+                let available: Vec<String> =
+                    issue_types.iter().map(|t| text(t, "name")).collect();
+                JiraError::validation_error(
+                    "issue_type",
+                    &format!(
+                        "Issue type '{wanted}' cannot be created in project {project_key}. Creatable issue types: {}",
+                        available.join(", ")
+                    ),
+                )
+            })?;
+        let issue_type_id = text(matched, "id");
+
+        let response: serde_json::Value = self
+            .get_refreshed_metadata(
+                &format!(
+                    "issue/createmeta/{project_key}/issuetypes/{issue_type_id}?maxResults=500"
+                ),
+                refresh,
+            )
+            .await?;
+        let fields = serde_json::from_value(serde_json::Value::Array(list(&response, "fields")))
+            .map_err(JiraError::SerializationError)?;
+
+        Ok(JiraCreateMeta {
+            project_key: project_key.to_string(),
+            issue_type_name: text(matched, "name"),
+            issue_type_id,
+            fields,
+        })
+    }
+
+    /// Explain why `fields` may not be accepted by the create screen in `meta`:
+    /// fields that are not on the screen, and required fields without a value
+    /// or default.
+    #[must_use]
+    pub fn create_screen_problems(
+        meta: &JiraCreateMeta,
+        fields: &serde_json::Map<String, serde_json::Value>,
+    ) -> Vec<String> {
+        let screen = format!(
+            "the create screen of {} in {}",
+            meta.issue_type_name, meta.project_key
+        );
+        let mut problems = Vec::new();
+
+        let off_screen: Vec<&str> = fields
+            .keys()
+            .filter(|id| !matches!(id.as_str(), "project" | "issuetype"))
+            .filter(|id| !meta.fields.iter().any(|f| &f.field_id == *id))
+            .map(String::as_str)
+            .collect();
+        if !off_screen.is_empty() {
+            problems.push(format!("Not on {screen}: {}", off_screen.join(", ")));
+        }
+
+        let missing: Vec<String> = meta
+            .fields
+            .iter()
+            .filter(|f| f.required && !f.has_default_value)
+            .filter(|f| !matches!(f.field_id.as_str(), "project" | "issuetype"))
+            .filter(|f| {
+                fields
+                    .get(&f.field_id)
+                    .is_none_or(serde_json::Value::is_null)
+            })
+            .map(|f| format!("{} ({})", f.name, f.field_id))
+            .collect();
+        if !missing.is_empty() {
+            problems.push(format!(
+                "Required on {screen} but not set: {}",
+                missing.join(", ")
+            ));
+        }
+
+        problems
+    }
+
     /// Get issue type metadata by ID
     ///
     /// # Errors
@@ -550,6 +2645,97 @@ impl JiraClient {
         self.get(&endpoint).await
     }
 
+    /// Get the issue security levels of a project.
+    ///
+    /// Reads the project's issue security scheme, which needs the Administer
+    /// Projects permission, and falls back to the levels the caller may set on
+    /// the project's issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns a permission denied error with guidance if neither endpoint is
+    /// readable, or an error if the request fails or the response cannot be
+    /// parsed.
+    pub async fn get_issue_security_levels(
+        &self,
+        project_key: &str,
+    ) -> Result<Vec<JiraSecurityLevel>> {
+        let scheme: serde_json::Value = match self
+            .get(&format!("project/{project_key}/issuesecuritylevelscheme"))
+            .await
+        {
+            Ok(scheme) => scheme,
+            Err(JiraError::PermissionDenied { .. }) => self
+                .get(&format!("project/{project_key}/securitylevel"))
+                .await
+                .map_err(|e| match e {
+                    JiraError::PermissionDenied { message } => {
+                        JiraError::permission_denied(&format!(
+                            "Cannot read the issue security levels of project {project_key}: {message}. \
+                             Listing the scheme needs the Administer Projects permission, and listing \
+                             the levels you can set needs the Set Issue Security permission"
+                        ))
+                    }
+                    other => other,
+                })?,
+            Err(e) => return Err(e),
+        };
+
+        let levels = scheme
+            .get("levels")
+            .cloned()
+            .unwrap_or_else(|| serde_json::Value::Array(Vec::new()));
+        serde_json::from_value(levels).map_err(JiraError::SerializationError)
+    }
+
+    /// Find the level in `levels` whose id or name (ignoring case) is `level`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error listing the available levels if none matches.
+    pub fn match_security_level<'a>(
+        levels: &'a [JiraSecurityLevel],
+        level: &str,
+        project_key: &str,
+    ) -> Result<&'a JiraSecurityLevel> {
+        let wanted = level.trim();
+        levels
+            .iter()
+            .find(|l| l.id == wanted)
+            .or_else(|| levels.iter().find(|l| l.name.eq_ignore_ascii_case(wanted)))
+            .ok_or_else(|| {
+                let available: Vec<String> = levels
+                    .iter()
+                    .map(|l| format!("'{}' ({})", l.name, l.id))
+                    .collect();
+                JiraError::validation_error(
+                    "security",
+                    &if available.is_empty() {
+                        format!("Project {project_key} has no issue security levels")
+                    } else {
+                        format!(
+                            "Security level '{wanted}' does not exist in project {project_key}. Available levels: {}",
+                            available.join(", ")
+                        )
+                    },
+                )
+            })
+    }
+
+    /// Look up a security level of a project by id or name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the levels cannot be read or none matches `level`.
+    pub async fn resolve_security_level(
+        &self,
+        project_key: &str,
+        level: &str,
+    ) -> Result<JiraSecurityLevel> {
+        let levels = self.get_issue_security_levels(project_key).await?;
+        Self::match_security_level(&levels, level, project_key).cloned()
+    }
+
     /// Get all priorities
     ///
     /// # Errors
@@ -568,6 +2754,19 @@ impl JiraClient {
         self.get("status").await
     }
 
+    /// Status id to category lookup, built from the cached status catalog
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_status_categories(&self) -> Result<crate::jira::timeline::StatusCategories> {
+        let statuses: Vec<crate::types::jira::JiraStatus> =
+            self.get_cached_metadata("status").await?;
+        Ok(crate::jira::timeline::StatusCategories::from_statuses(
+            &statuses,
+        ))
+    }
+
     /// Get custom fields
     ///
     /// # Errors
@@ -584,6 +2783,94 @@ impl JiraClient {
         Ok(fields.clone())
     }
 
+    /// Find the field definition for `field`, given as a field id or display name.
+    ///
+    /// The field catalog is cached like other metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the catalog cannot be fetched or `field` does not
+    /// identify exactly one field.
+    pub async fn resolve_field(&self, field: &str) -> Result<serde_json::Value> {
+        let catalog: Vec<serde_json::Value> = self.get_cached_metadata("field").await?;
+        Self::match_field(&catalog, field).cloned()
+    }
+
+    /// The field catalog, cached like other metadata
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn field_catalog(&self) -> Result<Vec<serde_json::Value>> {
+        self.get_cached_metadata("field").await
+    }
+
+    /// Find the field in `catalog` whose id or display name is `field`.
+    ///
+    /// Ids are matched exactly (ignoring case) before names are considered, so
+    /// `customfield_10010` always wins over a field that happens to be named that.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if no field matches, suggesting similarly
+    /// named fields, or if the name is shared by several fields.
+    pub fn match_field<'a>(
+        catalog: &'a [serde_json::Value],
+        field: &str,
+    ) -> Result<&'a serde_json::Value> {
+        let wanted = field.trim();
+        let text = |f: &'a serde_json::Value, key: &str| {
+            f.get(key).and_then(|v| v.as_str()).unwrap_or_default()
+        };
+
+        if let Some(by_id) = catalog
+            .iter()
+            .find(|f| text(f, "id").eq_ignore_ascii_case(wanted))
+        {
+            return Ok(by_id);
+        }
+
+        let by_name: Vec<&serde_json::Value> = catalog
+            .iter()
+            .filter(|f| text(f, "name").eq_ignore_ascii_case(wanted))
+            .collect();
+
+        match by_name.as_slice() {
+            [found] => Ok(found),
+            [] => {
+                let lowered = wanted.to_lowercase();
+                let similar: Vec<String> = catalog
+                    .iter()
+                    .filter(|f| {
+                        !lowered.is_empty() && text(f, "name").to_lowercase().contains(&lowered)
+                    })
+                    .take(5)
+                    .map(|f| format!("'{}' ({})", text(f, "name"), text(f, "id")))
+                    .collect();
+                let hint = if similar.is_empty() {
+                    String::new()
+                } else {
+                    format!(". Similar fields: {}", similar.join(", "))
+                };
+                Err(JiraError::validation_error(
+                    "field",
+                    &format!("Field '{wanted}' does not exist{hint}"),
+                ))
+            }
+            ambiguous => Err(JiraError::validation_error(
+                "field",
+                &format!(
+                    "Field name '{wanted}' is ambiguous, use one of the field ids: {}",
+                    ambiguous
+                        .iter()
+                        .map(|f| text(f, "id"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )),
+        }
+    }
+
     // Issue Linking Operations
     /// Get all available link types
     ///
@@ -600,7 +2887,10 @@ impl JiraClient {
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn get_issue_links(&self, issue_key: &str) -> Result<Vec<JiraIssueLink>> {
-        let endpoint = format!("issue/{issue_key}/remotelink");
+        let endpoint = format!(
+            "issue/{issue_key}/remotelink",
+            issue_key = path_segment(issue_key)
+        );
         let response: serde_json::Value = self.get(&endpoint).await?;
 
         let links = response
@@ -613,8 +2903,102 @@ impl JiraClient {
                 serde_json::from_value(link.clone()).map_err(JiraError::SerializationError)?;
             result.push(issue_link);
         }
-
-        Ok(result)
+
+        Ok(result)
+    }
+
+    /// Walk the links of `issue_key` breadth first, one search per level,
+    /// following only the links `options` selects.
+    ///
+    /// Each issue is expanded once, so cycles end the walk, and no more than
+    /// [`MAX_LINK_GRAPH_NODES`](crate::jira::link_graph::MAX_LINK_GRAPH_NODES)
+    /// issues are collected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a search fails or the response cannot be parsed.
+    pub async fn get_issue_link_graph(
+        &self,
+        issue_key: &str,
+        options: &LinkGraphOptions,
+    ) -> Result<LinkGraph> {
+        let mut graph = LinkGraph::new(issue_key, options.max_depth);
+        let mut frontier = vec![issue_key.to_string()];
+
+        for depth in 0..options.max_depth {
+            let mut next = Vec::new();
+            for keys in frontier.chunks(LINK_GRAPH_SEARCH_BATCH) {
+                let issues = self.get_issues_with_links(keys).await?;
+
+                // Expand in frontier order so the graph does not depend on
+                // the order Jira returns the issues in
+                for key in keys {
+                    let Some(issue) = issues
+                        .iter()
+                        .find(|issue| issue.get("key").and_then(|v| v.as_str()) == Some(key))
+                    else {
+                        continue;
+                    };
+                    graph.describe(issue);
+                    for link in IssueLinkRef::from_issue(issue)
+                        .iter()
+                        .filter(|link| link.matches(options))
+                    {
+                        next.extend(graph.add_link(key, depth, link));
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        Ok(graph)
+    }
+
+    /// Summary, status and links of the issues `keys`, as JSON, in one search
+    async fn get_issues_with_links(&self, keys: &[String]) -> Result<Vec<serde_json::Value>> {
+        let fields = ["summary", "status", "issuelinks"].map(ToString::to_string);
+        let jql = Jql::new().key_in(keys).build();
+        let max_results = i32::try_from(keys.len()).unwrap_or(i32::MAX);
+        let issues = self
+            .search_issues_with_fields(&jql, max_results, &fields)
+            .await?
+            .issues
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(issues)
+    }
+
+    /// Issues among `issue_keys` that are blocked by issues not done yet,
+    /// mapped to the blocking keys. A blocker is the inward side of a
+    /// `link_type` link, by default the configured `blocker_link_type`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issues cannot be searched.
+    pub async fn find_unresolved_blockers(
+        &self,
+        issue_keys: &[String],
+        link_type: Option<&str>,
+    ) -> Result<HashMap<String, Vec<String>>> {
+        let link_type = link_type
+            .or(self.config.blocker_link_type.as_deref())
+            .unwrap_or("Blocks");
+        let mut blocked = HashMap::new();
+        for keys in issue_keys.chunks(LINK_GRAPH_SEARCH_BATCH) {
+            for issue in self.get_issues_with_links(keys).await? {
+                let blockers = link_graph::unresolved_blockers(&issue, link_type);
+                if let Some(key) = issue.get("key").and_then(|v| v.as_str()) {
+                    if !blockers.is_empty() {
+                        blocked.insert(key.to_string(), blockers);
+                    }
+                }
+            }
+        }
+        Ok(blocked)
     }
 
     /// Create a link between two issues
@@ -696,6 +3080,55 @@ impl JiraClient {
         Ok(result)
     }
 
+    /// Whether attachments are enabled and the largest file Jira accepts,
+    /// read once and cached
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_attachment_meta(&self) -> Result<JiraAttachmentMeta> {
+        self.get_cached_metadata("attachment/meta").await
+    }
+
+    /// Largest file Jira accepts as an attachment, or `None` when the
+    /// instance does not say; the upload then reports any limit itself
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if attachments are disabled.
+    pub async fn attachment_upload_limit(&self) -> Result<Option<u64>> {
+        match self.get_attachment_meta().await {
+            Ok(meta) if !meta.enabled => Err(JiraError::validation_error(
+                "issue_key",
+                "Attachments are disabled on this Jira instance",
+            )),
+            Ok(meta) => Ok(meta.upload_limit),
+            Err(e) => {
+                debug!("Could not read the attachment size limit: {}", e);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Check before uploading that a file of `size` bytes is within the
+    /// attachment size limit of the instance
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the file is too large or attachments
+    /// are disabled.
+    pub async fn check_attachment_size(&self, filename: &str, size: u64) -> Result<()> {
+        match self.attachment_upload_limit().await? {
+            Some(limit) if size > limit => Err(JiraError::validation_error(
+                "content",
+                &format!(
+                    "{filename} is {size} bytes, more than the attachment size limit of this Jira instance ({limit} bytes)"
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
     /// Upload an attachment to a Jira issue
     ///
     /// # Errors
@@ -708,18 +3141,6 @@ impl JiraClient {
         content: &[u8],
         mime_type: Option<&str>,
     ) -> Result<Vec<JiraAttachment>> {
-        let endpoint = format!("issue/{issue_key}/attachments");
-        let url = self.build_url(&endpoint)?;
-
-        // Apply rate limiting
-        self.rate_limiter.wait_if_needed().await;
-
-        let mut request = self
-            .client
-            .request(Method::POST, url.as_str())
-            .header("Authorization", self.auth_header())
-            .header("X-Atlassian-Token", "no-check"); // Required for file uploads
-
         // Create multipart form data
         let mut form = reqwest::multipart::Form::new();
         form = form.part(
@@ -731,16 +3152,75 @@ impl JiraClient {
             form = form.part("mimeType", reqwest::multipart::Part::text(mime.to_string()));
         }
 
-        request = request.multipart(form);
-
         info!("Uploading attachment to issue: {}", issue_key);
+        self.post_attachments(issue_key, form).await
+    }
+
+    /// Upload several files to a Jira issue in one request, as `(filename,
+    /// content)` pairs. Jira returns the created attachments in the order the
+    /// files were sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upload fails or the response cannot be parsed.
+    pub async fn upload_attachments(
+        &self,
+        issue_key: &str,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> Result<Vec<JiraAttachment>> {
+        info!(
+            "Uploading {} attachments to issue: {}",
+            files.len(),
+            issue_key
+        );
+        let form = files.into_iter().fold(
+            reqwest::multipart::Form::new(),
+            |form, (filename, content)| {
+                form.part(
+                    "file",
+                    reqwest::multipart::Part::bytes(content).file_name(filename),
+                )
+            },
+        );
+        self.post_attachments(issue_key, form).await
+    }
+
+    /// POST a multipart form of files to an issue's attachments
+    async fn post_attachments(
+        &self,
+        issue_key: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<Vec<JiraAttachment>> {
+        let endpoint = format!(
+            "issue/{issue_key}/attachments",
+            issue_key = path_segment(issue_key)
+        );
+        let _segment = profiler::http_segment(&Method::POST, &endpoint);
+        let url = self.build_url(&endpoint)?;
+
+        // Apply rate limiting
+        self.wait_for_slot(RequestClass::Attachments).await;
+
+        let request = self
+            .client
+            .request(Method::POST, url.as_str())
+            .header("Authorization", self.auth_header())
+            .header("X-Atlassian-Token", "no-check") // Required for file uploads
+            .multipart(form);
+        let request = identity::identify(request, &self.config);
+
+        let lease = self.connection_tracker.acquire();
 
         let response = request.send().await.map_err(JiraError::HttpClientError)?;
         let status = response.status();
+        self.observe_rate_limits(RequestClass::Attachments, &response);
 
         if status.is_success() {
             let response_text = response.text().await.map_err(JiraError::HttpClientError)?;
+            lease.release();
             debug!("Attachment upload response: {}", response_text);
+            Self::invalidate_cached_searches(&endpoint);
+            prefetch::invalidate_endpoint(&self.config, &endpoint);
 
             let attachments: Vec<JiraAttachment> =
                 serde_json::from_str(&response_text).map_err(JiraError::SerializationError)?;
@@ -748,6 +3228,7 @@ impl JiraClient {
             Ok(attachments)
         } else {
             let error_text = response.text().await.map_err(JiraError::HttpClientError)?;
+            lease.release();
             error!("Attachment upload failed {}: {}", status, error_text);
 
             let error_json: serde_json::Value = serde_json::from_str(&error_text)
@@ -768,33 +3249,156 @@ impl JiraClient {
         Ok(())
     }
 
+    /// Get the metadata of an attachment (filename, size, type, author, ...)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_attachment_metadata(&self, attachment_id: &str) -> Result<JiraAttachment> {
+        let endpoint = format!("attachment/{attachment_id}");
+        self.get(&endpoint).await
+    }
+
+    /// Get the thumbnail image of an attachment, or `None` if Jira has no
+    /// thumbnail for it (only images get one)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata or the thumbnail cannot be fetched.
+    pub async fn get_attachment_thumbnail(&self, attachment_id: &str) -> Result<Option<Vec<u8>>> {
+        let attachment = self.get_attachment_metadata(attachment_id).await?;
+        match attachment.thumbnail {
+            Some(thumbnail_url) => Ok(Some(self.fetch_bytes(&thumbnail_url).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Download the content of an attachment from its content URL, falling
+    /// back to the attachment endpoint when the metadata has none
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the attachment download fails.
+    pub async fn download_attachment_content(
+        &self,
+        attachment: &JiraAttachment,
+    ) -> Result<Vec<u8>> {
+        match &attachment.content {
+            Some(content_url) => self.fetch_bytes(content_url).await,
+            None => self.download_attachment(&attachment.id).await,
+        }
+    }
+
     /// Download an attachment
     ///
     /// # Errors
     ///
     /// Returns an error if the attachment download fails.
     pub async fn download_attachment(&self, attachment_id: &str) -> Result<Vec<u8>> {
-        let endpoint = format!("attachment/{attachment_id}");
-        let url = self.build_url(&endpoint)?;
+        info!("Downloading attachment: {}", attachment_id);
+        self.fetch_bytes(&format!("attachment/{attachment_id}"))
+            .await
+    }
+
+    /// Stream the content of an attachment into a new file at `path`,
+    /// stopping once more than `limit` bytes have arrived. Returns the number
+    /// of bytes written and their SHA-256 as lowercase hex. The file must not
+    /// exist yet; a partly written file is removed on failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the download fails, exceeds `limit` or the file
+    /// cannot be written.
+    pub async fn download_attachment_to_file(
+        &self,
+        attachment: &JiraAttachment,
+        path: &Path,
+        limit: Option<u64>,
+    ) -> Result<(u64, String)> {
+        let endpoint = attachment
+            .content
+            .clone()
+            .unwrap_or_else(|| format!("attachment/{}", attachment.id));
+        let (mut response, lease) = self.get_raw(&endpoint).await?;
+        let file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .await;
+        let written = match file {
+            Ok(mut file) => {
+                let written = Self::stream_to_file(&mut response, &mut file, limit).await;
+                if written.is_err() {
+                    let _ = tokio::fs::remove_file(path).await;
+                }
+                written
+            }
+            Err(error) => Err(error.into()),
+        };
+        if written.is_ok() {
+            lease.release();
+        }
+        written
+    }
+
+    async fn stream_to_file(
+        response: &mut reqwest::Response,
+        file: &mut tokio::fs::File,
+        limit: Option<u64>,
+    ) -> Result<(u64, String)> {
+        let mut hasher = Sha256::new();
+        let mut written = 0u64;
+        while let Some(chunk) = response.chunk().await.map_err(JiraError::HttpClientError)? {
+            written += chunk.len() as u64;
+            if let Some(limit) = limit.filter(|limit| written > *limit) {
+                return Err(JiraError::validation_error(
+                    "size",
+                    &format!("Download exceeded the limit of {limit} bytes"),
+                ));
+            }
+            hasher.update(&chunk);
+            file.write_all(&chunk).await?;
+        }
+        file.flush().await?;
+        Ok((written, format!("{:x}", hasher.finalize())))
+    }
+
+    /// GET raw bytes from an endpoint or an absolute URL on the Jira host
+    async fn fetch_bytes(&self, endpoint: &str) -> Result<Vec<u8>> {
+        let (response, lease) = self.get_raw(endpoint).await?;
+        let bytes = response.bytes().await.map_err(JiraError::HttpClientError)?;
+        lease.release();
+        Ok(bytes.to_vec())
+    }
+
+    /// Start a GET of raw content from an endpoint or an absolute URL on the
+    /// Jira host. On success the caller reads the body and then releases the
+    /// connection lease returned with it.
+    async fn get_raw(&self, endpoint: &str) -> Result<(reqwest::Response, ConnectionLease<'_>)> {
+        let _segment = profiler::http_segment(&Method::GET, endpoint);
+        let url = self.build_url(endpoint)?;
+        let class = RequestClass::for_endpoint(endpoint);
 
         // Apply rate limiting
-        self.rate_limiter.wait_if_needed().await;
+        self.wait_for_slot(class).await;
 
         let request = self
             .client
             .request(Method::GET, url.as_str())
             .header("Authorization", self.auth_header());
+        let request = identity::identify(request, &self.config);
 
-        info!("Downloading attachment: {}", attachment_id);
+        let lease = self.connection_tracker.acquire();
 
         let response = request.send().await.map_err(JiraError::HttpClientError)?;
         let status = response.status();
+        self.observe_rate_limits(class, &response);
 
         if status.is_success() {
-            let bytes = response.bytes().await.map_err(JiraError::HttpClientError)?;
-            Ok(bytes.to_vec())
+            Ok((response, lease))
         } else {
-            let error_text = response.text().await.map_err(JiraError::HttpClientError)?;
+            let error_text = response.text().await.unwrap_or_default();
+            lease.release();
             error!("Attachment download failed {}: {}", status, error_text);
 
             let error_json: serde_json::Value = serde_json::from_str(&error_text)
@@ -812,7 +3416,10 @@ impl JiraClient {
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn get_issue_work_logs(&self, issue_key: &str) -> Result<Vec<JiraWorkLog>> {
-        let endpoint = format!("issue/{issue_key}/worklog");
+        let endpoint = format!(
+            "issue/{issue_key}/worklog",
+            issue_key = path_segment(issue_key)
+        );
         let response: serde_json::Value = self.get(&endpoint).await?;
 
         let work_logs = response
@@ -830,7 +3437,50 @@ impl JiraClient {
         Ok(result)
     }
 
-    /// Add a work log entry to an issue
+    /// Worklogs of an issue as returned by Jira, oldest first, fetched a
+    /// page at a time until `limit` have been read, together with the total
+    /// Jira reports. With `started_after` only worklogs started since then are
+    /// asked for (`startedAfter`); versions of Jira that ignore the parameter
+    /// return them all.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or the response cannot be parsed.
+    pub async fn get_all_work_logs(
+        &self,
+        issue_key: &str,
+        started_after: Option<chrono::DateTime<chrono::Utc>>,
+        limit: usize,
+    ) -> Result<(Vec<serde_json::Value>, usize)> {
+        let started_after = started_after
+            .map(|after| format!("&startedAfter={}", after.timestamp_millis()))
+            .unwrap_or_default();
+        let mut work_logs = Vec::new();
+        loop {
+            let endpoint = format!(
+                "issue/{issue_key}/worklog?startAt={}&maxResults={WORKLOG_PAGE_SIZE}{started_after}",
+                work_logs.len(), issue_key = path_segment(issue_key));
+            let response: serde_json::Value = self.get(&endpoint).await?;
+            let page = response
+                .get("worklogs")
+                .and_then(|w| w.as_array())
+                .ok_or_else(|| JiraError::api_error("Invalid work logs response format"))?;
+            work_logs.extend(page.iter().cloned());
+
+            let total = response
+                .get("total")
+                .and_then(serde_json::Value::as_u64)
+                .and_then(|total| usize::try_from(total).ok())
+                .unwrap_or(work_logs.len());
+            if page.is_empty() || work_logs.len() >= total.min(limit) {
+                work_logs.truncate(limit);
+                return Ok((work_logs, total));
+            }
+        }
+    }
+
+    /// Add a work log entry to an issue, its comment signed with
+    /// `comment_signature` if one is set
     ///
     /// # Errors
     ///
@@ -839,12 +3489,22 @@ impl JiraClient {
         &self,
         issue_key: &str,
         work_log: &JiraWorkLogCreateRequest,
+        estimate: &JiraWorkLogEstimateAdjustment,
     ) -> Result<JiraWorkLog> {
-        let endpoint = format!("issue/{issue_key}/worklog");
-        self.post(&endpoint, work_log).await
+        let endpoint = format!(
+            "issue/{issue_key}/worklog{}",
+            estimate.query(),
+            issue_key = path_segment(issue_key)
+        );
+        let work_log = JiraWorkLogCreateRequest {
+            comment: self.signed_work_log_comment(work_log.comment.as_ref()),
+            ..work_log.clone()
+        };
+        self.post(&endpoint, &work_log).await
     }
 
-    /// Update an existing work log entry
+    /// Update an existing work log entry; a new comment is signed with
+    /// `comment_signature` if one is set
     ///
     /// # Errors
     ///
@@ -854,9 +3514,21 @@ impl JiraClient {
         issue_key: &str,
         work_log_id: &str,
         work_log: &JiraWorkLogUpdateRequest,
+        estimate: &JiraWorkLogEstimateAdjustment,
     ) -> Result<JiraWorkLog> {
-        let endpoint = format!("issue/{issue_key}/worklog/{work_log_id}");
-        self.put(&endpoint, work_log).await
+        let endpoint = format!(
+            "issue/{issue_key}/worklog/{work_log_id}{}",
+            estimate.query(),
+            issue_key = path_segment(issue_key)
+        );
+        let work_log = JiraWorkLogUpdateRequest {
+            comment: work_log
+                .comment
+                .as_ref()
+                .map(|comment| self.signed(comment)),
+            ..work_log.clone()
+        };
+        self.put(&endpoint, &work_log).await
     }
 
     /// Delete a work log entry
@@ -865,7 +3537,10 @@ impl JiraClient {
     ///
     /// Returns an error if the work log deletion fails.
     pub async fn delete_work_log(&self, issue_key: &str, work_log_id: &str) -> Result<()> {
-        let endpoint = format!("issue/{issue_key}/worklog/{work_log_id}");
+        let endpoint = format!(
+            "issue/{issue_key}/worklog/{work_log_id}",
+            issue_key = path_segment(issue_key)
+        );
         let _: serde_json::Value = self.delete(&endpoint).await?;
         Ok(())
     }
@@ -875,30 +3550,98 @@ impl JiraClient {
     /// # Errors
     ///
     /// Returns an error if the work log cannot be found or the request fails.
-    /// Get project metadata including all configuration details
+    pub async fn get_work_log(
+        &self,
+        issue_key: &str,
+        work_log_id: &str,
+    ) -> Result<serde_json::Value> {
+        let endpoint = format!(
+            "issue/{}/worklog/{}",
+            path_segment(issue_key),
+            path_segment(work_log_id)
+        );
+        self.get(&endpoint).await
+    }
+
+    /// Get project metadata including all configuration details.
+    ///
+    /// Reads the `sections` asked for, every one of
+    /// [`PROJECT_METADATA_SECTIONS`] when empty, concurrently. A section that
+    /// fails, or takes longer than the configured subcall timeout, is left
+    /// out and its error is kept under `errors`, keyed by the section name,
+    /// so the sections that could be read are still returned.
     ///
     /// # Errors
     ///
-    /// Returns an error if any of the metadata requests fail.
-    pub async fn get_project_metadata(&self, project_key: &str) -> Result<serde_json::Value> {
-        let (configuration, issue_types, components, priorities, statuses, custom_fields) = tokio::try_join!(
-            self.get_project_configuration(project_key),
-            self.get_project_issue_types(project_key),
-            self.get_project_components(project_key),
-            self.get_priorities(),
-            self.get_statuses(),
-            self.get_custom_fields()
-        )?;
+    /// Returns an error if a section is unknown or if every section fails.
+    pub async fn get_project_metadata(
+        &self,
+        project_key: &str,
+        sections: &[String],
+    ) -> Result<serde_json::Value> {
+        if let Some(unknown) = sections
+            .iter()
+            .find(|section| !PROJECT_METADATA_SECTIONS.contains(&section.as_str()))
+        {
+            return Err(JiraError::validation_error(
+                "include",
+                &format!(
+                    "Unknown section '{unknown}'; expected one of {}",
+                    PROJECT_METADATA_SECTIONS.join(", ")
+                ),
+            ));
+        }
+        let wanted = |name: &str| sections.is_empty() || sections.iter().any(|s| s == name);
+        let timeout = self.config.metadata_subcall_timeout();
+        let section = |name: &'static str| wanted(name).then_some((name, timeout));
+
+        let (configuration, issue_types, components, priorities, statuses, custom_fields) = tokio::join!(
+            metadata_section(
+                section("configuration"),
+                self.get_project_configuration(project_key)
+            ),
+            metadata_section(
+                section("issue_types"),
+                self.get_project_issue_types(project_key)
+            ),
+            metadata_section(
+                section("components"),
+                self.get_project_components(project_key)
+            ),
+            metadata_section(section("priorities"), self.get_priorities()),
+            metadata_section(section("statuses"), self.get_statuses()),
+            metadata_section(section("custom_fields"), self.get_custom_fields())
+        );
+
+        let mut metadata = serde_json::json!({ "project_key": project_key });
+        let mut errors = serde_json::Map::new();
+        let mut first_error = None;
+        let results = [
+            configuration,
+            issue_types,
+            components,
+            priorities,
+            statuses,
+            custom_fields,
+        ];
+        for (name, result) in PROJECT_METADATA_SECTIONS.into_iter().zip(results) {
+            match result {
+                None => {}
+                Some(Ok(value)) => metadata[name] = value,
+                Some(Err(e)) => {
+                    errors.insert(name.to_string(), serde_json::Value::String(e.to_string()));
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        if metadata.as_object().is_some_and(|object| object.len() == 1) {
+            if let Some(e) = first_error {
+                return Err(e);
+            }
+        }
+        metadata["errors"] = serde_json::Value::Object(errors);
 
-        Ok(serde_json::json!({
-            "project_key": project_key,
-            "configuration": configuration,
-            "issue_types": issue_types,
-            "components": components,
-            "priorities": priorities,
-            "statuses": statuses,
-            "custom_fields": custom_fields
-        }))
+        Ok(metadata)
     }
 
     // Bulk Operations
@@ -929,21 +3672,37 @@ impl JiraClient {
         let batch_size = config.batch_size.unwrap_or(10);
         let mut processed = 0;
 
-        for chunk in operations.chunks(batch_size) {
-            let batch_results = self.process_batch(chunk, &config).await;
+        // Bulk requests wait behind interactive calls made meanwhile
+        in_background(async {
+            for chunk in operations.chunks(batch_size) {
+                let batch_results = self.process_batch(chunk, &config).await;
+                let failed = batch_results.iter().any(|r| !r.success);
 
-            for result in batch_results {
-                summary.add_result(result);
-            }
+                for result in batch_results {
+                    summary.add_result(result);
+                }
+
+                processed += chunk.len();
+                info!("Processed {}/{} operations", processed, operations.len());
 
-            processed += chunk.len();
-            info!("Processed {}/{} operations", processed, operations.len());
+                if failed && !config.continue_on_error {
+                    warn!(
+                        "Stopping after a failure; {} operations not run",
+                        operations.len() - processed
+                    );
+                    for operation in &operations[processed..] {
+                        summary.add_result(BulkOperationResult::not_run(operation));
+                    }
+                    break;
+                }
 
-            // Apply rate limiting between batches
-            if let Some(rate_limit_ms) = config.rate_limit_ms {
-                tokio::time::sleep(Duration::from_millis(rate_limit_ms)).await;
+                // Apply rate limiting between batches
+                if let Some(rate_limit_ms) = config.rate_limit_ms {
+                    tokio::time::sleep(Duration::from_millis(rate_limit_ms)).await;
+                }
             }
-        }
+        })
+        .await;
 
         summary.duration_ms = u64::try_from(start_time.elapsed().as_millis()).unwrap_or(u64::MAX);
 
@@ -957,28 +3716,55 @@ impl JiraClient {
         Ok(summary)
     }
 
-    /// Process a batch of operations
+    /// Process a batch of operations, up to `max_concurrency` at a time,
+    /// returning their results in order. Without `continue_on_error` the
+    /// operations after a failed group are not run.
     async fn process_batch(
         &self,
         operations: &[BulkOperationItem],
         config: &BulkOperationConfig,
     ) -> Vec<BulkOperationResult> {
-        let mut results = Vec::new();
-
-        for operation in operations {
-            let result = self.execute_single_operation(operation, config).await;
-            results.push(result);
+        let concurrency = config.max_concurrency.unwrap_or(1).max(1);
+        let mut results = Vec::with_capacity(operations.len());
 
-            // Apply rate limiting between individual operations
-            if let Some(rate_limit_ms) = config.rate_limit_ms {
-                tokio::time::sleep(Duration::from_millis(rate_limit_ms)).await;
+        for group in operations.chunks(concurrency) {
+            let runs: Vec<_> = group
+                .iter()
+                .map(|operation| self.execute_rate_limited(operation, config))
+                .collect();
+            results.extend(futures::future::join_all(runs).await);
+
+            if !config.continue_on_error && results.iter().any(|r| !r.success) {
+                results.extend(
+                    operations[results.len()..]
+                        .iter()
+                        .map(BulkOperationResult::not_run),
+                );
+                break;
             }
         }
 
         results
     }
 
-    /// Execute a single operation within a bulk operation
+    /// Execute `operation`, then wait `rate_limit_ms` before the slot is
+    /// given to the next
+    async fn execute_rate_limited(
+        &self,
+        operation: &BulkOperationItem,
+        config: &BulkOperationConfig,
+    ) -> BulkOperationResult {
+        let result = self.execute_single_operation(operation, config).await;
+
+        // Apply rate limiting between individual operations
+        if let Some(rate_limit_ms) = config.rate_limit_ms {
+            tokio::time::sleep(Duration::from_millis(rate_limit_ms)).await;
+        }
+        result
+    }
+
+    /// Execute a single operation within a bulk operation, on the key the
+    /// issue has now if it was moved
     async fn execute_single_operation(
         &self,
         operation: &BulkOperationItem,
@@ -987,6 +3773,44 @@ impl JiraClient {
         let mut retry_count = 0;
         let max_retries = config.max_retries.unwrap_or(3);
 
+        let resolved = self.resolve_issue_key(&operation.issue_key).await;
+        let moved_from = resolved.was_moved().then(|| resolved.requested.clone());
+        let moved_operation;
+        let operation = if moved_from.is_some() {
+            moved_operation = BulkOperationItem {
+                issue_key: resolved.key.clone(),
+                ..operation.clone()
+            };
+            &moved_operation
+        } else {
+            operation
+        };
+
+        // Without the values from before, the update could not be undone
+        let previous_fields = if config.capture_previous_fields
+            && matches!(operation.operation_type, BulkOperationType::Update)
+        {
+            match self.read_updated_fields(operation).await {
+                Ok(fields) => Some(fields),
+                Err(e) => {
+                    return BulkOperationResult {
+                        issue_key: operation.issue_key.clone(),
+                        success: false,
+                        error_message: Some(format!(
+                            "Not updated; the current values could not be read: {e}"
+                        )),
+                        operation_type: operation.operation_type.clone(),
+                        error_kind: Some(e.kind()),
+                        skipped: false,
+                        previous_fields: None,
+                        moved_from: moved_from.clone(),
+                    };
+                }
+            }
+        } else {
+            None
+        };
+
         loop {
             match self.perform_operation(operation).await {
                 Ok(()) => {
@@ -995,14 +3819,17 @@ impl JiraClient {
                         success: true,
                         error_message: None,
                         operation_type: operation.operation_type.clone(),
+                        error_kind: None,
+                        skipped: false,
+                        previous_fields,
+                        moved_from: moved_from.clone(),
                     };
                 }
                 Err(e) => {
-                    if retry_count < max_retries && Self::should_retry_operation(self, &e) {
+                    if retry_count < max_retries && self.should_retry_operation(operation, &e) {
                         retry_count += 1;
-                        let delay = Duration::from_millis(
-                            1000 * u64::try_from(retry_count.max(0)).unwrap_or(0),
-                        );
+                        let delay =
+                            Duration::from_millis(1000 * u64::try_from(retry_count).unwrap_or(0));
                         warn!(
                             "Retrying operation for {} in {:?} (attempt {}/{})",
                             operation.issue_key, delay, retry_count, max_retries
@@ -1016,18 +3843,56 @@ impl JiraClient {
                         success: false,
                         error_message: Some(e.to_string()),
                         operation_type: operation.operation_type.clone(),
+                        error_kind: Some(e.kind()),
+                        skipped: false,
+                        previous_fields: None,
+                        moved_from: moved_from.clone(),
                     };
                 }
             }
         }
     }
 
+    /// Current values of the fields the update `operation` sets or changes
+    /// through verb operations, read with one request; a field the issue
+    /// doesn't have is `null`
+    async fn read_updated_fields(
+        &self,
+        operation: &BulkOperationItem,
+    ) -> Result<serde_json::Map<String, serde_json::Value>> {
+        let mut names: Vec<String> = Vec::new();
+        for part in ["fields", "update"] {
+            if let Some(fields) = operation.data.get(part).and_then(|f| f.as_object()) {
+                for name in fields.keys() {
+                    if !names.contains(name) {
+                        names.push(name.clone());
+                    }
+                }
+            }
+        }
+        if names.is_empty() {
+            return Ok(serde_json::Map::new());
+        }
+        let issue = self.get_issue_fields(&operation.issue_key, &names).await?;
+        Ok(names
+            .into_iter()
+            .map(|name| {
+                let value = issue
+                    .fields
+                    .get(&name)
+                    .cloned()
+                    .unwrap_or(serde_json::Value::Null);
+                (name, value)
+            })
+            .collect())
+    }
+
     /// Perform the actual operation based on the operation type
     async fn perform_operation(&self, operation: &BulkOperationItem) -> Result<()> {
         match operation.operation_type {
             BulkOperationType::Update => {
-                let fields = &operation.data;
-                self.update_issue(&operation.issue_key, fields).await
+                self.update_issue_if_unchanged(&operation.issue_key, &operation.data)
+                    .await
             }
             BulkOperationType::Transition => {
                 let transition_id = operation
@@ -1037,26 +3902,41 @@ impl JiraClient {
                     .ok_or_else(|| {
                         JiraError::api_error("Missing transition_id in operation data")
                     })?;
-                let comment = operation.data.get("comment").and_then(|v| v.as_str());
-                self.transition_issue(&operation.issue_key, transition_id, comment)
+                let options = TransitionOptions {
+                    comment: operation
+                        .data
+                        .get("comment")
+                        .and_then(|v| v.as_str())
+                        .map(ToString::to_string),
+                    ..Default::default()
+                };
+                self.transition_issue(&operation.issue_key, transition_id, &options)
                     .await
             }
             BulkOperationType::AddComment => {
                 let comment_body = operation
                     .data
                     .get("comment_body")
-                    .and_then(|v| v.as_str())
+                    // A string, or an ADF document when mentions were resolved for v3
+                    .filter(|v| v.is_string() || v.is_object())
+                    .cloned()
                     .ok_or_else(|| {
                         JiraError::api_error("Missing comment_body in operation data")
                     })?;
-                self.add_comment(&operation.issue_key, comment_body).await?;
+                let visibility: Option<JiraCommentVisibility> = operation
+                    .data
+                    .get("visibility")
+                    .map(|v| serde_json::from_value(v.clone()))
+                    .transpose()?;
+                self.add_internal_comment(&operation.issue_key, comment_body, visibility.as_ref())
+                    .await?;
                 Ok(())
             }
             BulkOperationType::Mixed => {
                 // For mixed operations, we need to determine the operation type from the data
                 if operation.data.get("fields").is_some() {
                     // This is an update operation
-                    self.update_issue(&operation.issue_key, &operation.data)
+                    self.update_issue_if_unchanged(&operation.issue_key, &operation.data)
                         .await
                 } else if operation.data.get("transition_id").is_some() {
                     // This is a transition operation
@@ -1067,19 +3947,37 @@ impl JiraClient {
                         .ok_or_else(|| {
                             JiraError::api_error("Missing transition_id in operation data")
                         })?;
-                    let comment = operation.data.get("comment").and_then(|v| v.as_str());
-                    self.transition_issue(&operation.issue_key, transition_id, comment)
+                    let options = TransitionOptions {
+                        comment: operation
+                            .data
+                            .get("comment")
+                            .and_then(|v| v.as_str())
+                            .map(ToString::to_string),
+                        ..Default::default()
+                    };
+                    self.transition_issue(&operation.issue_key, transition_id, &options)
                         .await
                 } else if operation.data.get("comment_body").is_some() {
                     // This is a comment operation
                     let comment_body = operation
                         .data
                         .get("comment_body")
-                        .and_then(|v| v.as_str())
+                        .filter(|v| v.is_string() || v.is_object())
+                        .cloned()
                         .ok_or_else(|| {
                             JiraError::api_error("Missing comment_body in operation data")
                         })?;
-                    self.add_comment(&operation.issue_key, comment_body).await?;
+                    let visibility: Option<JiraCommentVisibility> = operation
+                        .data
+                        .get("visibility")
+                        .map(|v| serde_json::from_value(v.clone()))
+                        .transpose()?;
+                    self.add_internal_comment(
+                        &operation.issue_key,
+                        comment_body,
+                        visibility.as_ref(),
+                    )
+                    .await?;
                     Ok(())
                 } else {
                     Err(JiraError::api_error(
@@ -1087,13 +3985,36 @@ impl JiraClient {
                     ))
                 }
             }
+            BulkOperationType::AddWatcher | BulkOperationType::RemoveWatcher => {
+                let account_id = operation
+                    .data
+                    .get("account_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| JiraError::api_error("Missing account_id in operation data"))?;
+                if matches!(operation.operation_type, BulkOperationType::AddWatcher) {
+                    self.add_issue_watcher(&operation.issue_key, account_id)
+                        .await
+                } else {
+                    self.remove_issue_watcher(&operation.issue_key, account_id)
+                        .await
+                }
+            }
         }
     }
 
-    /// Determine if an operation should be retried based on the error
-    fn should_retry_operation(_self: &Self, error: &JiraError) -> bool {
+    /// Determine if an operation should be retried based on the error.
+    ///
+    /// Operations that create something (comments, transitions) are not
+    /// repeated after a timeout, which they may have survived.
+    fn should_retry_operation(&self, operation: &BulkOperationItem, error: &JiraError) -> bool {
+        let idempotent = matches!(
+            operation.operation_type,
+            BulkOperationType::Update
+                | BulkOperationType::AddWatcher
+                | BulkOperationType::RemoveWatcher
+        );
         match error {
-            JiraError::HttpClientError(e) => e.is_timeout(),
+            JiraError::HttpClientError(e) => e.is_timeout() && self.may_retry(idempotent, None),
             JiraError::ApiError { message, .. } => {
                 message.contains("timeout")
                     || message.contains("rate limit")
@@ -1160,27 +4081,107 @@ impl JiraClient {
         self.execute_bulk_operations(operations, config).await
     }
 
+    /// Bulk transition multiple issues using a transition name.
+    ///
+    /// The name is resolved to a transition id once per distinct workflow
+    /// before any issue is transitioned, so an unknown or ambiguous name fails
+    /// the whole operation up front.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name cannot be resolved or the bulk transition
+    /// cannot be processed.
+    pub async fn bulk_transition_issues_by_name(
+        &self,
+        issue_keys: Vec<String>,
+        transition_name: &str,
+        comment: Option<String>,
+        config: Option<BulkOperationConfig>,
+    ) -> Result<BulkOperationSummary> {
+        let config = config.unwrap_or_default();
+        let transition_ids = self
+            .resolve_transition_ids(&issue_keys, transition_name)
+            .await?;
+
+        let operations = issue_keys
+            .into_iter()
+            .map(|issue_key| {
+                let mut operation_data = serde_json::json!({
+                    "transition_id": transition_ids.get(&issue_key).cloned().unwrap_or_default()
+                });
+                if let Some(comment_text) = &comment {
+                    operation_data["comment"] = serde_json::Value::String(comment_text.clone());
+                }
+                BulkOperationItem {
+                    issue_key,
+                    operation_type: BulkOperationType::Transition,
+                    data: operation_data,
+                }
+            })
+            .collect();
+
+        self.execute_bulk_operations(operations, config).await
+    }
+
     /// Bulk add comments to multiple issues
     ///
     /// # Errors
     ///
-    /// Returns an error if the bulk comment operation fails or cannot be processed.
-    pub async fn bulk_add_comments(
+    /// Returns an error if the bulk comment operation fails or cannot be processed.
+    pub async fn bulk_add_comments(
+        &self,
+        issue_keys: Vec<String>,
+        comment_body: impl Into<serde_json::Value>,
+        visibility: Option<JiraCommentVisibility>,
+        config: Option<BulkOperationConfig>,
+    ) -> Result<BulkOperationSummary> {
+        let config = config.unwrap_or_default();
+        let mut operation_data = serde_json::json!({
+            "comment_body": comment_body.into()
+        });
+
+        if let Some(visibility) = visibility {
+            visibility.validate()?;
+            operation_data["visibility"] = serde_json::to_value(visibility)?;
+        }
+
+        let operations = issue_keys
+            .into_iter()
+            .map(|issue_key| BulkOperationItem {
+                issue_key,
+                operation_type: BulkOperationType::AddComment,
+                data: operation_data.clone(),
+            })
+            .collect();
+
+        self.execute_bulk_operations(operations, config).await
+    }
+
+    /// Add or remove a watcher on multiple issues
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bulk watcher operation fails or cannot be processed.
+    pub async fn bulk_update_watchers(
         &self,
         issue_keys: Vec<String>,
-        comment_body: String,
+        account_id: &str,
+        watch: bool,
         config: Option<BulkOperationConfig>,
     ) -> Result<BulkOperationSummary> {
         let config = config.unwrap_or_default();
-        let operation_data = serde_json::json!({
-            "comment_body": comment_body
-        });
+        let operation_type = if watch {
+            BulkOperationType::AddWatcher
+        } else {
+            BulkOperationType::RemoveWatcher
+        };
+        let operation_data = serde_json::json!({ "account_id": account_id });
 
         let operations = issue_keys
             .into_iter()
             .map(|issue_key| BulkOperationItem {
                 issue_key,
-                operation_type: BulkOperationType::AddComment,
+                operation_type: operation_type.clone(),
                 data: operation_data.clone(),
             })
             .collect();
@@ -1190,13 +4191,99 @@ impl JiraClient {
 
     // Issue Watcher Operations
 
+    /// Get the version, build and time of the Jira server
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_server_info(&self) -> Result<serde_json::Value> {
+        self.get("serverInfo").await
+    }
+
+    /// Get the user the client is authenticated as.
+    ///
+    /// The answer only changes with the credentials, so it is cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_current_user(&self) -> Result<serde_json::Value> {
+        let cache = &get_global_cache_manager().parsed_objects;
+        let metrics = crate::performance::get_global_metrics();
+        let key = CacheKeyGenerator::parsed_object(
+            "myself",
+            &format!("{}:{}", self.config.api_base_url, self.config.email),
+        );
+
+        if let Some(cached) = cache.get(&key).await {
+            metrics.record_cache_hit();
+            return Ok(cached);
+        }
+        metrics.record_cache_miss();
+
+        let user: serde_json::Value = self.get("myself").await?;
+        cache.insert(key, user.clone()).await;
+        Ok(user)
+    }
+
+    /// Id of the current user for watcher requests: the account id on Cloud,
+    /// the username on Server/Data Center
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the current user cannot be read or has neither.
+    pub async fn current_user_watcher_id(&self) -> Result<String> {
+        let user = self.get_current_user().await?;
+        user.get("accountId")
+            .or_else(|| user.get("name"))
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string)
+            .ok_or_else(|| {
+                JiraError::api_error("Current user has neither an account id nor a username")
+            })
+    }
+
     /// Get watchers for a specific issue
     ///
     /// # Errors
     ///
     /// Returns an error if the request fails or the response cannot be parsed.
     pub async fn get_issue_watchers(&self, issue_key: &str) -> Result<JiraWatchersResponse> {
-        let endpoint = format!("issue/{issue_key}/watchers");
+        self.get_issue_watchers_page(issue_key, None, None).await
+    }
+
+    /// Get a page of an issue's watchers, for servers that page the list
+    /// with `startAt` and `maxResults`. Servers that do not are sent the
+    /// parameters too and answer with the whole list.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed.
+    pub async fn get_issue_watchers_page(
+        &self,
+        issue_key: &str,
+        start_at: Option<u32>,
+        max_results: Option<u32>,
+    ) -> Result<JiraWatchersResponse> {
+        let mut params = Vec::new();
+        if let Some(start) = start_at {
+            params.push(format!("startAt={start}"));
+        }
+        if let Some(max) = max_results {
+            params.push(format!("maxResults={max}"));
+        }
+        let endpoint = if params.is_empty() {
+            format!(
+                "issue/{issue_key}/watchers",
+                issue_key = path_segment(issue_key)
+            )
+        } else {
+            format!(
+                "issue/{issue_key}/watchers?{}",
+                params.join("&"),
+                issue_key = path_segment(issue_key)
+            )
+        };
         self.get(&endpoint).await
     }
 
@@ -1206,11 +4293,15 @@ impl JiraClient {
     ///
     /// Returns an error if the watcher addition fails.
     pub async fn add_issue_watcher(&self, issue_key: &str, account_id: &str) -> Result<()> {
-        let endpoint = format!("issue/{issue_key}/watchers");
+        let endpoint = format!(
+            "issue/{issue_key}/watchers",
+            issue_key = path_segment(issue_key)
+        );
         let watcher_request = serde_json::json!({
             "accountId": account_id
         });
-        let _: serde_json::Value = self.post(&endpoint, &watcher_request).await?;
+        // Adding a watcher twice is harmless
+        let _: serde_json::Value = self.post_idempotent(&endpoint, &watcher_request).await?;
         Ok(())
     }
 
@@ -1220,7 +4311,10 @@ impl JiraClient {
     ///
     /// Returns an error if the watcher removal fails.
     pub async fn remove_issue_watcher(&self, issue_key: &str, account_id: &str) -> Result<()> {
-        let endpoint = format!("issue/{issue_key}/watchers?accountId={account_id}");
+        let endpoint = format!(
+            "issue/{issue_key}/watchers?accountId={account_id}",
+            issue_key = path_segment(issue_key)
+        );
         let _: serde_json::Value = self.delete(&endpoint).await?;
         Ok(())
     }
@@ -1269,7 +4363,7 @@ impl JiraClient {
         label_name: &str,
         label: &JiraLabelUpdateRequest,
     ) -> Result<JiraLabel> {
-        let endpoint = format!("label/{label_name}");
+        let endpoint = format!("label/{label_name}", label_name = path_segment(label_name));
         self.put(&endpoint, label).await
     }
 
@@ -1279,7 +4373,7 @@ impl JiraClient {
     ///
     /// Returns an error if the label deletion fails.
     pub async fn delete_label(&self, label_name: &str) -> Result<()> {
-        let endpoint = format!("label/{label_name}");
+        let endpoint = format!("label/{label_name}", label_name = path_segment(label_name));
         let _: serde_json::Value = self.delete(&endpoint).await?;
         Ok(())
     }
@@ -1359,7 +4453,7 @@ impl JiraClient {
 
         // Apply field mapping
         if let Some(field_mapping) = &clone_request.field_mapping {
-            Self::apply_field_mapping(&original_issue, &mut new_issue_data, field_mapping);
+            Self::apply_field_mapping(&original_issue.fields, &mut new_issue_data, field_mapping);
         }
 
         // Create the new issue
@@ -1379,13 +4473,10 @@ impl JiraClient {
         })
     }
 
-    /// Apply field mapping to clone issue data
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if field mapping fails.
-    fn apply_field_mapping(
-        original_issue: &crate::types::jira::JiraIssue,
+    /// Copy the fields of an original issue that `field_mapping` selects into
+    /// the create request `new_issue_data`, for clones and bundle imports
+    pub(crate) fn apply_field_mapping(
+        original_fields: &HashMap<String, serde_json::Value>,
         new_issue_data: &mut serde_json::Value,
         field_mapping: &crate::types::jira::JiraFieldMapping,
     ) {
@@ -1393,9 +4484,6 @@ impl JiraClient {
 
         debug!("Applying field mapping: {:?}", field_mapping);
 
-        // Get the fields from the original issue
-        let original_fields = &original_issue.fields;
-
         // Determine which fields to copy
         let fields_to_copy = if field_mapping.copy_fields.is_empty() {
             // If no specific fields are specified, use default behavior
@@ -1515,8 +4603,8 @@ impl JiraClient {
     /// Get the Zephyr API base URL
     #[must_use]
     pub fn zephyr_api_base_url(&self) -> String {
-        // Zephyr typically uses the same base URL as Jira but with /rest/zapi/latest/ prefix
-        format!("{}/rest/zapi/latest", self.config.api_base_url)
+        // Zephyr hangs off the site root, next to rather than under /rest/api/N
+        format!("{}/rest/zapi/latest", self.config.site_url())
     }
 
     /// Make a GET request to the Zephyr API
@@ -1528,7 +4616,7 @@ impl JiraClient {
     where
         T: DeserializeOwned,
     {
-        self.zephyr_request(Method::GET, endpoint, None::<&()>)
+        self.zephyr_request(Method::GET, endpoint, None::<&()>, true)
             .await
     }
 
@@ -1542,7 +4630,7 @@ impl JiraClient {
         T: DeserializeOwned,
         U: Serialize + ?Sized,
     {
-        self.zephyr_request(Method::POST, endpoint, Some(body))
+        self.zephyr_request(Method::POST, endpoint, Some(body), false)
             .await
     }
 
@@ -1556,7 +4644,8 @@ impl JiraClient {
         T: DeserializeOwned,
         U: Serialize + ?Sized,
     {
-        self.zephyr_request(Method::PUT, endpoint, Some(body)).await
+        self.zephyr_request(Method::PUT, endpoint, Some(body), true)
+            .await
     }
 
     /// Make a DELETE request to the Zephyr API
@@ -1568,7 +4657,7 @@ impl JiraClient {
     where
         T: DeserializeOwned,
     {
-        self.zephyr_request(Method::DELETE, endpoint, None::<&()>)
+        self.zephyr_request(Method::DELETE, endpoint, None::<&()>, true)
             .await
     }
 
@@ -1582,33 +4671,58 @@ impl JiraClient {
         method: Method,
         endpoint: &str,
         body: Option<&U>,
+        idempotent: bool,
     ) -> Result<T>
     where
         T: DeserializeOwned,
         U: Serialize + ?Sized,
     {
+        let _segment = profiler::http_segment(&method, endpoint);
         let url = self.build_zephyr_url(endpoint)?;
         let mut retry_count = 0;
-        let max_retries = 3;
+        let max_retries = MAX_REQUEST_RETRIES;
+        let mut tracker = RequestTracker::start();
 
         loop {
+            tracker.attempt();
             // Apply rate limiting
-            self.rate_limiter.wait_if_needed().await;
+            self.wait_for_slot(RequestClass::Zephyr).await;
+
+            let request = self
+                .build_zephyr_request(method.clone(), &url, body)?
+                .build()
+                .map_err(JiraError::HttpClientError)?;
 
-            let request_builder = self.build_zephyr_request(method.clone(), &url, body)?;
+            info!(
+                "Making Zephyr {} request to {}",
+                method,
+                sanitize::sanitize_url(url.as_str())
+            );
+            let captured = capture::is_active().then(|| CapturedRequest::from_request(&request));
 
-            info!("Making Zephyr {} request to {}", method, url);
+            let lease = self.connection_tracker.acquire();
 
-            match request_builder.send().await {
+            match self.client.execute(request).await {
                 Ok(response) => {
                     let status = response.status();
+                    self.observe_rate_limits(RequestClass::Zephyr, &response);
                     debug!("Zephyr response status: {}", status);
 
                     if status.is_success() {
+                        tracker.succeeded();
                         let response_text =
                             response.text().await.map_err(JiraError::HttpClientError)?;
-
-                        debug!("Zephyr response body: {}", response_text);
+                        lease.release();
+                        if let Some(captured) = captured {
+                            capture::record(
+                                captured.with_response(status.as_u16(), &response_text),
+                            );
+                        }
+
+                        debug!(
+                            "Zephyr response body: {}",
+                            sanitize::sanitize_text(&response_text)
+                        );
 
                         return serde_json::from_str(&response_text).map_err(|e| {
                             error!("Failed to parse Zephyr JSON response: {}", e);
@@ -1618,24 +4732,41 @@ impl JiraClient {
 
                     let error_text = response.text().await.map_err(JiraError::HttpClientError)?;
 
-                    error!("Zephyr HTTP error {}: {}", status, error_text);
+                    lease.release();
+                    if let Some(captured) = captured {
+                        capture::record(captured.with_response(status.as_u16(), &error_text));
+                    }
+
+                    error!(
+                        "Zephyr HTTP error {}: {}",
+                        status,
+                        sanitize::sanitize_text(&error_text)
+                    );
 
                     // Parse Zephyr error response
                     let error_json: serde_json::Value = serde_json::from_str(&error_text)
                         .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new()));
 
                     let jira_error = JiraError::from_jira_response(status, &error_json);
+                    tracker.failed(Some(status));
 
                     // Retry on certain status codes
                     if retry_count < max_retries && Self::should_retry(status) {
+                        if !self.may_retry(idempotent, Some(status)) {
+                            warn!(
+                                "Not retrying Zephyr {} {}: it may already have been applied",
+                                method,
+                                sanitize::sanitize_url(url.as_str())
+                            );
+                            return Err(Self::unretried_error(status, jira_error));
+                        }
                         retry_count += 1;
-                        let delay = Duration::from_millis(
-                            1000 * u64::try_from(retry_count.max(0)).unwrap_or(0),
-                        );
+                        let delay = Duration::from_millis(1000 * u64::from(retry_count));
                         warn!(
                             "Retrying Zephyr request in {:?} (attempt {}/{})",
                             delay, retry_count, max_retries
                         );
+                        tracker.backoff(delay);
                         tokio::time::sleep(delay).await;
                         continue;
                     }
@@ -1644,16 +4775,22 @@ impl JiraClient {
                 }
                 Err(e) => {
                     error!("Zephyr request failed: {}", e);
+                    tracker.failed(None);
+                    if let Some(captured) = captured {
+                        capture::record(captured.with_error(&e.to_string()));
+                    }
 
-                    if retry_count < max_retries && e.is_timeout() {
+                    if retry_count < max_retries
+                        && e.is_timeout()
+                        && self.may_retry(idempotent, None)
+                    {
                         retry_count += 1;
-                        let delay = Duration::from_millis(
-                            1000 * u64::try_from(retry_count.max(0)).unwrap_or(0),
-                        );
+                        let delay = Duration::from_millis(1000 * u64::from(retry_count));
                         warn!(
                             "Retrying Zephyr request after timeout in {:?} (attempt {}/{})",
                             delay, retry_count, max_retries
                         );
+                        tracker.backoff(delay);
                         tokio::time::sleep(delay).await;
                         continue;
                     }
@@ -1666,21 +4803,7 @@ impl JiraClient {
 
     /// Build a complete URL from the Zephyr endpoint
     fn build_zephyr_url(&self, endpoint: &str) -> Result<Url> {
-        let zephyr_base_url = self.zephyr_api_base_url();
-
-        // Ensure the base URL ends with a slash for proper joining
-        let base_url_str = if zephyr_base_url.ends_with('/') {
-            zephyr_base_url
-        } else {
-            format!("{}/", zephyr_base_url)
-        };
-
-        let base_url = Url::parse(&base_url_str)
-            .map_err(|e| JiraError::config_error(&format!("Invalid Zephyr API base URL: {e}")))?;
-
-        base_url
-            .join(endpoint)
-            .map_err(|e| JiraError::config_error(&format!("Invalid Zephyr endpoint URL: {e}")))
+        urls::join(&self.zephyr_api_base_url(), endpoint, "Zephyr API base URL")
     }
 
     /// Build a request with proper headers and authentication for Zephyr
@@ -1693,17 +4816,20 @@ impl JiraClient {
     where
         U: Serialize + ?Sized,
     {
-        let mut request = self
+        let request = self
             .client
             .request(method, url.as_str())
             .header("Authorization", self.auth_header())
             .header("Content-Type", "application/json")
-            .header("Accept", "application/json")
-            .header("User-Agent", "rust-jira-mcp/0.4.1");
+            .header("Accept", "application/json");
+        let mut request = identity::identify(request, &self.config);
 
         if let Some(body) = body {
             let json_body = serde_json::to_string(body).map_err(JiraError::SerializationError)?;
-            debug!("Zephyr request body: {}", json_body);
+            debug!(
+                "Zephyr request body: {}",
+                sanitize::sanitize_text(&json_body)
+            );
             request = request.body(json_body);
         }
 
@@ -1826,6 +4952,65 @@ impl JiraClient {
         self.zephyr_post("testcase", test_case).await
     }
 
+    /// Create a test case, then its inline `steps` one at a time in order.
+    ///
+    /// Creating the steps stops at the first one that fails; the result then
+    /// names it and holds the steps created before it, so the rest can be
+    /// added from there.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the test case itself cannot be created
+    pub async fn create_zephyr_test_case_with_steps(
+        &self,
+        test_case: &ZephyrTestCaseCreateRequest,
+    ) -> Result<ZephyrTestCaseCreation> {
+        let created = self.create_zephyr_test_case(test_case).await?;
+        let mut creation = ZephyrTestCaseCreation {
+            test_case: created,
+            steps: Vec::new(),
+            failed_step: None,
+        };
+        let steps = test_case.steps.as_deref().unwrap_or_default();
+        let test_case_id = creation
+            .test_case
+            .id
+            .clone()
+            .or_else(|| creation.test_case.key.clone());
+
+        for (index, step) in steps.iter().enumerate() {
+            let order = i32::try_from(index + 1).unwrap_or(i32::MAX);
+            let outcome = match &test_case_id {
+                Some(test_case_id) => {
+                    self.create_zephyr_test_step(&ZephyrTestStepCreateRequest {
+                        step: step.step.clone(),
+                        data: step.data.clone(),
+                        result: step.result.clone(),
+                        order,
+                        test_case_id: test_case_id.clone(),
+                    })
+                    .await
+                }
+                None => Err(JiraError::api_error(
+                    "The created test case has no id to add steps to",
+                )),
+            };
+            match outcome {
+                Ok(created_step) => creation.steps.push(created_step),
+                Err(e) => {
+                    creation.failed_step = Some(ZephyrStepFailure {
+                        order,
+                        step: step.step.clone(),
+                        error: e.to_string(),
+                        not_tried: steps.len() - index - 1,
+                    });
+                    break;
+                }
+            }
+        }
+        Ok(creation)
+    }
+
     /// Update an existing test case
     ///
     /// # Errors
@@ -1877,6 +5062,126 @@ impl JiraClient {
         self.zephyr_post("execution", execution).await
     }
 
+    /// Build the multipart request that attaches a file to a Zephyr test
+    /// execution. ZAPI wants `X-Atlassian-Token: nocheck` rather than the
+    /// `no-check` of Jira's attachment endpoint, takes the MIME type on the
+    /// file part itself, and must not be sent the JSON content type the
+    /// other Zephyr requests use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL or the MIME type is invalid.
+    pub fn zephyr_execution_attachment_request(
+        &self,
+        execution_id: &str,
+        filename: &str,
+        content: &[u8],
+        mime_type: Option<&str>,
+    ) -> Result<reqwest::Request> {
+        let mut url = self.build_zephyr_url("attachment")?;
+        url.query_pairs_mut()
+            .append_pair("entityId", execution_id)
+            .append_pair("entityType", "execution");
+
+        let mut part =
+            reqwest::multipart::Part::bytes(content.to_vec()).file_name(filename.to_string());
+        if let Some(mime) = mime_type {
+            part = part.mime_str(mime).map_err(|_| {
+                JiraError::validation_error("mime_type", &format!("Invalid MIME type '{mime}'"))
+            })?;
+        }
+        let form = reqwest::multipart::Form::new().part("file", part);
+
+        let request = self
+            .client
+            .request(Method::POST, url.as_str())
+            .header("Authorization", self.auth_header())
+            .header("Accept", "application/json")
+            .header("X-Atlassian-Token", "nocheck")
+            .multipart(form);
+        identity::identify(request, &self.config)
+            .build()
+            .map_err(JiraError::HttpClientError)
+    }
+
+    /// Attach a file to a Zephyr test execution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be built or the upload fails.
+    pub async fn add_zephyr_execution_attachment(
+        &self,
+        execution_id: &str,
+        filename: &str,
+        content: &[u8],
+        mime_type: Option<&str>,
+    ) -> Result<()> {
+        let _segment = profiler::http_segment(&Method::POST, "attachment");
+        let request =
+            self.zephyr_execution_attachment_request(execution_id, filename, content, mime_type)?;
+
+        // Apply rate limiting
+        self.wait_for_slot(RequestClass::Zephyr).await;
+
+        info!(
+            "Uploading attachment {} to Zephyr execution: {}",
+            filename, execution_id
+        );
+        let lease = self.connection_tracker.acquire();
+
+        let response = self
+            .client
+            .execute(request)
+            .await
+            .map_err(JiraError::HttpClientError)?;
+        let status = response.status();
+        self.observe_rate_limits(RequestClass::Zephyr, &response);
+        let response_text = response.text().await.map_err(JiraError::HttpClientError)?;
+        lease.release();
+
+        if status.is_success() {
+            debug!("Zephyr attachment upload response: {}", response_text);
+            Ok(())
+        } else {
+            error!(
+                "Zephyr attachment upload failed {}: {}",
+                status,
+                sanitize::sanitize_text(&response_text)
+            );
+            let error_json: serde_json::Value = serde_json::from_str(&response_text)
+                .unwrap_or_else(|_| serde_json::Value::Object(serde_json::Map::new()));
+            Err(JiraError::from_jira_response(status, &error_json))
+        }
+    }
+
+    /// Get the files attached to a Zephyr test execution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed
+    pub async fn get_zephyr_execution_attachments(
+        &self,
+        execution_id: &str,
+    ) -> Result<Vec<ZephyrAttachment>> {
+        let endpoint = format!(
+            "attachment/attachmentsByEntity?entityId={}&entityType=execution",
+            urlencoding::encode(execution_id)
+        );
+        let response: serde_json::Value = self.zephyr_get(&endpoint).await?;
+
+        let attachments = response
+            .get("data")
+            .and_then(|d| d.as_array())
+            .ok_or_else(|| JiraError::api_error("Invalid execution attachments response format"))?;
+
+        attachments
+            .iter()
+            .map(|attachment| {
+                serde_json::from_value(attachment.clone()).map_err(JiraError::SerializationError)
+            })
+            .collect()
+    }
+
     /// Update an existing test execution
     ///
     /// # Errors
@@ -1913,6 +5218,102 @@ impl JiraClient {
         Ok(result)
     }
 
+    /// Get the folders of a test cycle. Cycles belong to a version; ZAPI
+    /// uses version `-1` for unscheduled ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed
+    pub async fn get_cycle_folders(
+        &self,
+        cycle_id: &str,
+        project_id: &str,
+        version_id: &str,
+    ) -> Result<Vec<ZephyrFolder>> {
+        let endpoint = format!(
+            "cycle/{}/folders?projectId={}&versionId={}",
+            urlencoding::encode(cycle_id),
+            urlencoding::encode(project_id),
+            urlencoding::encode(version_id)
+        );
+        self.zephyr_get(&endpoint).await
+    }
+
+    /// Create a folder in a test cycle
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the folder creation fails or the response holds no folder id
+    pub async fn create_folder(&self, folder: &ZephyrFolderCreateRequest) -> Result<ZephyrFolder> {
+        let response: serde_json::Value = self.zephyr_post("folder/create", folder).await?;
+        let id = response
+            .get("id")
+            .or_else(|| response.get("folderId"))
+            .and_then(|id| id.as_i64().or_else(|| id.as_str()?.parse().ok()))
+            .ok_or_else(|| JiraError::api_error("Invalid folder creation response format"))?;
+
+        Ok(ZephyrFolder {
+            id,
+            name: folder.name.clone(),
+            description: folder.description.clone(),
+            cycle_id: folder.cycle_id.parse().ok(),
+            total_executions: None,
+            total_executed: None,
+        })
+    }
+
+    /// Add test issues to a cycle, or to one of its folders when the request
+    /// names one. ZAPI adds them in the background and answers with the job.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed
+    pub async fn add_tests_to_cycle(
+        &self,
+        request: &ZephyrAddTestsToCycleRequest,
+    ) -> Result<serde_json::Value> {
+        let mut body = serde_json::to_value(request).map_err(JiraError::SerializationError)?;
+        // Method 1 adds the issues listed, rather than those of a filter or cycle
+        body["method"] = serde_json::Value::String("1".to_string());
+        self.zephyr_post("execution/addTestsToCycle/", &body).await
+    }
+
+    /// Get the executions of a test cycle, only those of one folder when
+    /// `folder_id` is given
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed
+    pub async fn get_cycle_executions(
+        &self,
+        cycle_id: &str,
+        project_id: &str,
+        version_id: &str,
+        folder_id: Option<i64>,
+    ) -> Result<Vec<ZephyrTestExecution>> {
+        let mut endpoint = format!(
+            "execution?cycleId={}&projectId={}&versionId={}",
+            urlencoding::encode(cycle_id),
+            urlencoding::encode(project_id),
+            urlencoding::encode(version_id)
+        );
+        if let Some(folder_id) = folder_id {
+            endpoint.push_str(&format!("&folderId={folder_id}"));
+        }
+        let response: serde_json::Value = self.zephyr_get(&endpoint).await?;
+
+        let executions = response
+            .get("executions")
+            .and_then(|e| e.as_array())
+            .ok_or_else(|| JiraError::api_error("Invalid test executions response format"))?;
+        executions
+            .iter()
+            .map(|execution| {
+                serde_json::from_value(execution.clone()).map_err(JiraError::SerializationError)
+            })
+            .collect()
+    }
+
     /// Create a new test cycle
     ///
     /// # Errors
@@ -1958,6 +5359,16 @@ impl JiraClient {
     // Sprint Operations (Jira Agile API)
     // ============================================================================
 
+    /// Get a board by ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the board cannot be found or the request fails
+    pub async fn get_board(&self, board_id: i64) -> Result<serde_json::Value> {
+        let endpoint = format!("agile/1.0/board/{board_id}");
+        self.get(&endpoint).await
+    }
+
     /// Get a sprint by ID
     ///
     /// # Errors
@@ -2008,7 +5419,8 @@ impl JiraClient {
         let request = JiraSprintAddIssuesRequest {
             issues: issues.to_vec(),
         };
-        self.post(&endpoint, &request).await
+        // Moving an issue into the sprint it is already in changes nothing
+        self.post_idempotent(&endpoint, &request).await
     }
 
     /// Get issues in a sprint
@@ -2109,4 +5521,245 @@ impl JiraClient {
 
         Ok(result)
     }
+
+    /// Get the active sprint of a board
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the board's sprints cannot be fetched, or if the board
+    /// has no active sprint or several of them (parallel sprints).
+    pub async fn get_active_sprint(&self, board_id: i32) -> Result<JiraSprint> {
+        let mut active: Vec<JiraSprint> = self
+            .get_board_sprints(board_id)
+            .await?
+            .into_iter()
+            .filter(|sprint| sprint.state == crate::types::jira::JiraSprintState::Active)
+            .collect();
+
+        match active.len() {
+            1 => Ok(active.remove(0)),
+            0 => Err(JiraError::validation_error(
+                "sprint_id",
+                &format!("Board {board_id} has no active sprint"),
+            )),
+            _ => Err(JiraError::validation_error(
+                "sprint_id",
+                &format!(
+                    "Board {board_id} has several active sprints, pass one of their ids: {}",
+                    active
+                        .iter()
+                        .map(|sprint| format!("{} ({})", sprint.id, sprint.name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )),
+        }
+    }
+
+    /// Get every issue in a sprint, with `fields` and its changelog
+    ///
+    /// Pages through the Agile API issue list until all issues are collected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails or the response cannot be parsed
+    pub async fn get_sprint_issues_with_changelog(
+        &self,
+        sprint_id: i32,
+        fields: &[String],
+    ) -> Result<Vec<serde_json::Value>> {
+        let page_size = self.config.max_results.unwrap_or(50).max(1);
+        let mut issues = Vec::new();
+
+        loop {
+            let endpoint = format!(
+                "agile/1.0/sprint/{sprint_id}/issue?startAt={}&maxResults={page_size}&fields={}&expand=changelog",
+                issues.len(),
+                urlencoding::encode(&fields.join(","))
+            );
+            let response: serde_json::Value = self.get(&endpoint).await?;
+            let page = response
+                .get("issues")
+                .and_then(|v| v.as_array())
+                .ok_or_else(|| JiraError::api_error("Invalid sprint issues response format"))?;
+            let total = response
+                .get("total")
+                .and_then(serde_json::Value::as_u64)
+                .unwrap_or(0);
+
+            issues.extend(page.iter().cloned());
+            if page.is_empty() || issues.len() as u64 >= total {
+                break;
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// The story points field: the configured one, otherwise the first field in
+    /// the catalog with a well-known story points name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field catalog cannot be fetched
+    pub async fn story_points_field(&self) -> Result<Option<String>> {
+        if let Some(field) = &self.config.story_points_field {
+            return Ok(Some(field.clone()));
+        }
+        let catalog: Vec<serde_json::Value> = self.get_cached_metadata("field").await?;
+        Ok(crate::jira::sprint_report::detect_story_points_field(
+            &catalog,
+        ))
+    }
+
+    // ============================================================================
+    // Webhook Operations (Cloud dynamic webhooks, Data Center admin API)
+    // ============================================================================
+
+    /// List the webhooks registered with Jira: on Cloud the dynamic
+    /// webhooks of this app, on Data Center all of the site's webhooks
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed
+    pub async fn list_webhooks(&self) -> Result<Vec<JiraWebhook>> {
+        if !self.config.is_cloud() {
+            let values: Vec<serde_json::Value> = self
+                .site_request(Method::GET, DC_WEBHOOKS_PATH, None::<&()>, true)
+                .await?;
+            return Ok(values.iter().filter_map(JiraWebhook::from_dc).collect());
+        }
+
+        let mut webhooks = Vec::new();
+        loop {
+            let page: serde_json::Value = self
+                .get(&format!(
+                    "webhook?startAt={}&maxResults=100",
+                    webhooks.len()
+                ))
+                .await?;
+            let values = page
+                .get("values")
+                .and_then(serde_json::Value::as_array)
+                .ok_or_else(|| JiraError::api_error("Invalid webhooks response format"))?;
+            webhooks.extend(values.iter().filter_map(JiraWebhook::from_cloud));
+            let is_last = page
+                .get("isLast")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true);
+            if is_last || values.is_empty() {
+                return Ok(webhooks);
+            }
+        }
+    }
+
+    /// Register a webhook posting `events` for the issues matching
+    /// `jql_filter` to `url`, returning its id. Cloud requires the filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if no events are given or Cloud gets no
+    /// filter, and an API error if Jira refuses the registration
+    pub async fn register_webhook(
+        &self,
+        url: &str,
+        events: &[String],
+        jql_filter: Option<&str>,
+    ) -> Result<i64> {
+        if events.is_empty() {
+            return Err(JiraError::validation_error(
+                "events",
+                "Give at least one event to register",
+            ));
+        }
+
+        if !self.config.is_cloud() {
+            let body = serde_json::json!({
+                "name": DC_WEBHOOK_NAME,
+                "url": url,
+                "events": events,
+                "filters": {"issue-related-events-section": jql_filter.unwrap_or_default()},
+                "excludeBody": false
+            });
+            let created: serde_json::Value = self
+                .site_request(Method::POST, DC_WEBHOOKS_PATH, Some(&body), false)
+                .await?;
+            return JiraWebhook::from_dc(&created)
+                .map(|webhook| webhook.id)
+                .ok_or_else(|| JiraError::api_error("Jira did not return the new webhook"));
+        }
+
+        let jql_filter = jql_filter
+            .filter(|jql| !jql.trim().is_empty())
+            .ok_or_else(|| {
+                JiraError::validation_error(
+                    "jql_filter",
+                    "Jira Cloud webhooks need a JQL filter, e.g. project = PROJ",
+                )
+            })?;
+        let body = serde_json::json!({
+            "url": url,
+            "webhooks": [{"events": events, "jqlFilter": jql_filter}]
+        });
+        let response: serde_json::Value = self.post("webhook", &body).await?;
+        let result = response
+            .pointer("/webhookRegistrationResult/0")
+            .ok_or_else(|| JiraError::api_error("Invalid webhook registration response"))?;
+        if let Some(id) = result
+            .get("createdWebhookId")
+            .and_then(serde_json::Value::as_i64)
+        {
+            return Ok(id);
+        }
+        let errors: Vec<&str> = result
+            .get("errors")
+            .and_then(serde_json::Value::as_array)
+            .map(|errors| errors.iter().filter_map(|e| e.as_str()).collect())
+            .unwrap_or_default();
+        Err(JiraError::api_error(&format!(
+            "Jira refused the webhook: {}",
+            errors.join("; ")
+        )))
+    }
+
+    /// Delete the webhook `id`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the webhook cannot be deleted
+    pub async fn delete_webhook(&self, id: i64) -> Result<()> {
+        if !self.config.is_cloud() {
+            let _: serde_json::Value = self
+                .site_request(
+                    Method::DELETE,
+                    &format!("{DC_WEBHOOKS_PATH}/{id}"),
+                    None::<&()>,
+                    true,
+                )
+                .await?;
+            return Ok(());
+        }
+        let body = serde_json::json!({"webhookIds": [id]});
+        let _: serde_json::Value = self
+            .request(Method::DELETE, "webhook", Some(&body), true)
+            .await?;
+        Ok(())
+    }
+
+    /// Extend the Cloud webhooks `ids` by another 30 days, returning their
+    /// new expiration date
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the webhooks cannot be refreshed
+    pub async fn refresh_webhooks(
+        &self,
+        ids: &[i64],
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        let body = serde_json::json!({"webhookIds": ids});
+        let response: serde_json::Value = self.put("webhook/refresh", &body).await?;
+        Ok(response
+            .get("expirationDate")
+            .and_then(crate::jira::webhooks::parse_expiration))
+    }
 }
@@ -0,0 +1,135 @@
+use crate::error::{JiraError, Result};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, LazyLock, Mutex};
+use tokio::sync::broadcast;
+use tracing::debug;
+
+type SharedResult = std::result::Result<serde_json::Value, Arc<JiraError>>;
+
+/// Deduplicates identical requests that are in flight at the same time.
+///
+/// The first caller for a key performs the request; callers arriving while it
+/// is running wait for and share its result instead of hitting Jira again.
+/// Only use this for reads, where sharing a response has no side effects.
+pub struct InFlightRequests {
+    requests: Mutex<HashMap<String, broadcast::Sender<SharedResult>>>,
+}
+
+/// Removes the in-flight entry if the leading request is dropped before finishing,
+/// so waiting callers fall back to issuing their own request.
+struct LeaderGuard<'a> {
+    requests: &'a InFlightRequests,
+    key: Option<String>,
+}
+
+impl LeaderGuard<'_> {
+    fn finish(mut self) -> Option<broadcast::Sender<SharedResult>> {
+        self.key
+            .take()
+            .and_then(|key| self.requests.requests.lock().unwrap().remove(&key))
+    }
+}
+
+impl Drop for LeaderGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.requests.requests.lock().unwrap().remove(&key);
+        }
+    }
+}
+
+impl InFlightRequests {
+    /// Create an empty in-flight request registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            requests: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Run `request` unless an identical one is already in flight, in which case
+    /// wait for that request and return a copy of its result.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by whichever caller performed the request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub async fn run<F, Fut>(&self, key: String, request: F) -> Result<serde_json::Value>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<serde_json::Value>>,
+    {
+        let waiting = {
+            let mut requests = self.requests.lock().unwrap();
+            if let Some(sender) = requests.get(&key) {
+                Some(sender.subscribe())
+            } else {
+                let (sender, _) = broadcast::channel(1);
+                requests.insert(key.clone(), sender);
+                None
+            }
+        };
+
+        if let Some(mut receiver) = waiting {
+            if let Ok(shared) = receiver.recv().await {
+                debug!("Served request from an identical in-flight request");
+                crate::performance::get_global_metrics().record_coalesced_request();
+                return shared.map_err(|e| e.duplicate());
+            }
+            // The leading request was cancelled; perform our own
+            return request().await;
+        }
+
+        let guard = LeaderGuard {
+            requests: self,
+            key: Some(key),
+        };
+        let result = request().await;
+
+        if let Some(sender) = guard.finish() {
+            if sender.receiver_count() > 0 {
+                let shared = match &result {
+                    Ok(value) => Ok(value.clone()),
+                    Err(e) => Err(Arc::new(e.duplicate())),
+                };
+                let _ = sender.send(shared);
+            }
+        }
+
+        result
+    }
+
+    /// Number of distinct requests currently in flight
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    /// Whether no requests are currently in flight
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for InFlightRequests {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static GLOBAL_IN_FLIGHT: LazyLock<InFlightRequests> = LazyLock::new(InFlightRequests::new);
+
+/// Get the in-flight request registry shared by all clients
+#[must_use]
+pub fn get_global_in_flight_requests() -> &'static InFlightRequests {
+    &GLOBAL_IN_FLIGHT
+}
@@ -0,0 +1,189 @@
+//! Optimistic concurrency for issue updates.
+//!
+//! A caller passes the `updated` timestamp of the issue it last read, and the
+//! update is refused when the issue has been updated since. The refusal lists
+//! the field changes made in between, taken from the issue's changelog, so the
+//! caller can reconcile them before trying again.
+
+use crate::error::{JiraError, Result};
+use crate::utils::datetime::parse_timestamp;
+use chrono::{DateTime, Timelike, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Key of an update payload holding the `updated` timestamp the caller last saw
+pub const EXPECTED_UPDATED_KEY: &str = "expected_updated";
+
+/// The `updated` timestamp a caller last saw.
+///
+/// Jira writes `2024-03-01T09:00:00.000+0000`, while callers may pass the
+/// same instant as RFC 3339 in another offset, with more digits, or without
+/// the milliseconds. Times are compared to the millisecond, or to the second
+/// when the caller gave no fractional seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpectedUpdated {
+    at: DateTime<Utc>,
+    whole_seconds: bool,
+}
+
+impl ExpectedUpdated {
+    /// Read the timestamp passed as `expected_updated`
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `value` is not an ISO timestamp.
+    pub fn parse(value: &str) -> Result<Self> {
+        let at = parse_timestamp(value.trim())
+            .ok_or_else(|| {
+                JiraError::validation_error(
+                    EXPECTED_UPDATED_KEY,
+                    &format!(
+                        "'{value}' is not an ISO timestamp such as the issue's 'updated' field"
+                    ),
+                )
+            })?
+            .with_timezone(&Utc);
+        let mut expected = Self {
+            at,
+            whole_seconds: !value.contains('.'),
+        };
+        expected.at = expected.truncate(at);
+        Ok(expected)
+    }
+
+    /// `time` cut to the precision the timestamps are compared at
+    fn truncate(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let nanos = if self.whole_seconds {
+            0
+        } else {
+            time.nanosecond() / 1_000_000 * 1_000_000
+        };
+        time.with_nanosecond(nanos).unwrap_or(time)
+    }
+
+    /// Whether `time` is the instant the caller saw
+    #[must_use]
+    pub fn matches(&self, time: DateTime<Utc>) -> bool {
+        self.truncate(time) == self.at
+    }
+
+    /// Whether `time` is after the instant the caller saw
+    #[must_use]
+    pub fn is_before(&self, time: DateTime<Utc>) -> bool {
+        self.truncate(time) > self.at
+    }
+}
+
+/// Changes to one field made after the caller's read
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    /// Value before the first change
+    pub from: Option<String>,
+    /// Value after the last change
+    pub to: Option<String>,
+    /// Display names of the people who changed it, in order
+    pub authors: Vec<String>,
+    /// Time of the last change
+    pub changed_at: String,
+}
+
+/// Field changes in an issue's changelog (`expand=changelog`) made after
+/// `expected`, one per field in the order the fields were first changed
+#[must_use]
+pub fn changes_since(issue: &Value, expected: &ExpectedUpdated) -> Vec<FieldChange> {
+    let mut histories: Vec<(DateTime<Utc>, &Value)> = issue
+        .pointer("/changelog/histories")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|history| {
+            let created = history.get("created").and_then(Value::as_str)?;
+            Some((parse_timestamp(created)?.with_timezone(&Utc), history))
+        })
+        .filter(|(created, _)| expected.is_before(*created))
+        .collect();
+    histories.sort_by_key(|(created, _)| *created);
+
+    let text = |item: &Value, key: &str| {
+        item.get(format!("{key}String"))
+            .or_else(|| item.get(key))
+            .and_then(Value::as_str)
+            .map(ToString::to_string)
+    };
+    let mut changes: Vec<FieldChange> = Vec::new();
+    for (_, history) in histories {
+        let author = history
+            .pointer("/author/displayName")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown")
+            .to_string();
+        let changed_at = history
+            .get("created")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let items = history.get("items").and_then(Value::as_array);
+        for item in items.into_iter().flatten() {
+            let Some(field) = item.get("field").and_then(Value::as_str) else {
+                continue;
+            };
+            if let Some(change) = changes.iter_mut().find(|c| c.field == field) {
+                change.to = text(item, "to");
+                if !change.authors.contains(&author) {
+                    change.authors.push(author.clone());
+                }
+                change.changed_at.clone_from(&changed_at);
+            } else {
+                changes.push(FieldChange {
+                    field: field.to_string(),
+                    from: text(item, "from"),
+                    to: text(item, "to"),
+                    authors: vec![author.clone()],
+                    changed_at: changed_at.clone(),
+                });
+            }
+        }
+    }
+    changes
+}
+
+/// Message of the conflict error for an issue updated at `actual` rather
+/// than at the `expected` time the caller saw
+#[must_use]
+pub fn describe_conflict(
+    issue_key: &str,
+    expected: &str,
+    actual: &str,
+    changes: &[FieldChange],
+) -> String {
+    let mut message = format!(
+        "{issue_key} was last updated at {actual}, not {expected} as expected; \
+         nothing was changed. Read the issue again and reapply the update"
+    );
+    if changes.is_empty() {
+        message.push_str(
+            "\nNo field changes are in the changelog; comments, work logs and \
+             attachments also change 'updated'",
+        );
+        return message;
+    }
+    message.push_str("\nChanges since then:");
+    for change in changes {
+        let value = |value: &Option<String>| match value {
+            Some(value) if !value.is_empty() => format!("'{value}'"),
+            _ => "empty".to_string(),
+        };
+        let _ = write!(
+            message,
+            "\n• {}: {} → {} by {} (last at {})",
+            change.field,
+            value(&change.from),
+            value(&change.to),
+            change.authors.join(", "),
+            change.changed_at
+        );
+    }
+    message
+}
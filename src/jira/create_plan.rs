@@ -0,0 +1,250 @@
+//! Planning an issue creation: which fields of an issue type's create screen
+//! the fields gathered so far satisfy, and which required ones are still
+//! missing, so that a client can ask for them and plan again.
+
+use crate::jira::bulk_csv::coerce_to_create_meta;
+use crate::types::jira::{JiraCreateField, JiraCreateMeta};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Allowed values listed per missing field before the list is cut
+pub const ALLOWED_VALUES_SAMPLE: usize = 10;
+
+/// Type of a field from its create-meta schema, e.g. `option` or
+/// `array of string`
+#[must_use]
+pub fn schema_type(schema: Option<&Value>) -> String {
+    let field_type = schema
+        .and_then(|s| s.get("type"))
+        .and_then(|t| t.as_str())
+        .unwrap_or("unknown");
+    match schema.and_then(|s| s.get("items")).and_then(|i| i.as_str()) {
+        Some(items) if field_type == "array" => format!("array of {items}"),
+        _ => field_type.to_string(),
+    }
+}
+
+/// Display form of an allowed or default value
+#[must_use]
+pub fn value_label(value: &Value) -> String {
+    ["name", "value", "key", "id"]
+        .iter()
+        .find_map(|key| value.get(key).and_then(|v| v.as_str()))
+        .map_or_else(
+            || match value {
+                Value::String(s) => s.clone(),
+                Value::Array(values) => values
+                    .iter()
+                    .map(value_label)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                other => other.to_string(),
+            },
+            ToString::to_string,
+        )
+}
+
+/// A required field the plan still needs a value for
+#[derive(Debug, Clone, Serialize)]
+pub struct MissingField {
+    pub field_id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: String,
+    /// The first [`ALLOWED_VALUES_SAMPLE`] allowed values, if the field has
+    /// a list of them
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_values: Vec<String>,
+    /// How many allowed values there are in all
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_values_total: Option<usize>,
+}
+
+impl MissingField {
+    fn from_meta(field: &JiraCreateField) -> Self {
+        let allowed = field.allowed_values.as_deref().unwrap_or_default();
+        Self {
+            field_id: field.field_id.clone(),
+            name: field.name.clone(),
+            field_type: schema_type(field.schema.as_ref()),
+            allowed_values: allowed
+                .iter()
+                .take(ALLOWED_VALUES_SAMPLE)
+                .map(value_label)
+                .collect(),
+            allowed_values_total: (!allowed.is_empty()).then_some(allowed.len()),
+        }
+    }
+}
+
+/// A field of the create screen the plan has a value for
+#[derive(Debug, Clone, Serialize)]
+pub struct SatisfiedField {
+    pub field_id: String,
+    pub name: String,
+    pub required: bool,
+    /// The value as it will be sent; absent for a screen default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    /// Whether Jira fills the field with its default
+    pub from_default: bool,
+}
+
+/// Where the creation of an issue stands
+#[derive(Debug, Clone, Serialize)]
+pub struct CreationPlan {
+    pub project_key: String,
+    pub issue_type_id: String,
+    pub issue_type_name: String,
+    /// Whether the issue can be created with `fields`
+    pub complete: bool,
+    pub missing: Vec<MissingField>,
+    pub satisfied: Vec<SatisfiedField>,
+    /// Values that cannot be used: fields not on the screen and values the
+    /// screen does not allow
+    pub problems: Vec<String>,
+    /// The fields to create the issue with, by field id and converted to
+    /// what the screen expects
+    pub fields: Map<String, Value>,
+}
+
+impl CreationPlan {
+    /// Plan creating an issue on the create screen in `meta` with `given`:
+    /// fields named by id or screen name, with options, numbers and lists
+    /// given as text converted the way CSV values are
+    #[must_use]
+    pub fn new(meta: &JiraCreateMeta, given: &Map<String, Value>) -> Self {
+        let mut fields = Map::new();
+        let mut problems = Vec::new();
+        for (key, value) in given {
+            if matches!(key.as_str(), "project" | "issuetype") {
+                continue;
+            }
+            let field = meta.fields.iter().find(|f| &f.field_id == key).or_else(|| {
+                meta.fields
+                    .iter()
+                    .find(|f| f.name.eq_ignore_ascii_case(key))
+            });
+            match field {
+                Some(field) => {
+                    fields.insert(field.field_id.clone(), value.clone());
+                }
+                None => problems.push(format!(
+                    "{key} is not on the create screen of {} in {}",
+                    meta.issue_type_name, meta.project_key
+                )),
+            }
+        }
+        problems.extend(coerce_to_create_meta(&mut fields, meta));
+
+        let mut missing = Vec::new();
+        let mut satisfied = Vec::new();
+        for field in &meta.fields {
+            if matches!(field.field_id.as_str(), "project" | "issuetype") {
+                continue;
+            }
+            match fields.get(&field.field_id).filter(|value| !is_blank(value)) {
+                Some(value) => satisfied.push(SatisfiedField {
+                    field_id: field.field_id.clone(),
+                    name: field.name.clone(),
+                    required: field.required,
+                    value: Some(value.clone()),
+                    from_default: false,
+                }),
+                None if field.required && field.has_default_value => {
+                    satisfied.push(SatisfiedField {
+                        field_id: field.field_id.clone(),
+                        name: field.name.clone(),
+                        required: true,
+                        value: None,
+                        from_default: true,
+                    });
+                }
+                None if field.required => missing.push(MissingField::from_meta(field)),
+                None => {}
+            }
+        }
+
+        fields.insert(
+            "project".to_string(),
+            serde_json::json!({"key": meta.project_key}),
+        );
+        fields.insert(
+            "issuetype".to_string(),
+            serde_json::json!({"id": meta.issue_type_id}),
+        );
+        Self {
+            project_key: meta.project_key.clone(),
+            issue_type_id: meta.issue_type_id.clone(),
+            issue_type_name: meta.issue_type_name.clone(),
+            complete: missing.is_empty() && problems.is_empty(),
+            missing,
+            satisfied,
+            problems,
+            fields,
+        }
+    }
+
+    /// What is set, what is missing and what to do next
+    #[must_use]
+    pub fn render(&self) -> String {
+        let required_set = self.satisfied.iter().filter(|f| f.required).count();
+        let mut text = format!(
+            "Creating a {} in {}: {} of {} required fields set",
+            self.issue_type_name,
+            self.project_key,
+            required_set,
+            required_set + self.missing.len()
+        );
+        let defaults: Vec<&str> = self
+            .satisfied
+            .iter()
+            .filter(|f| f.from_default)
+            .map(|f| f.name.as_str())
+            .collect();
+        if !defaults.is_empty() {
+            text.push_str(&format!(" (by default: {})", defaults.join(", ")));
+        }
+
+        if !self.missing.is_empty() {
+            text.push_str("\n\nStill required:");
+            for field in &self.missing {
+                text.push_str(&format!(
+                    "\n• {} ({}) - {}",
+                    field.name, field.field_id, field.field_type
+                ));
+                if let Some(total) = field.allowed_values_total {
+                    text.push_str(&format!("; allowed: {}", field.allowed_values.join(", ")));
+                    if total > field.allowed_values.len() {
+                        text.push_str(&format!(
+                            ", … and {} more",
+                            total - field.allowed_values.len()
+                        ));
+                    }
+                }
+            }
+        }
+        if !self.problems.is_empty() {
+            text.push_str("\n\nProblems:");
+            for problem in &self.problems {
+                text.push_str(&format!("\n• {problem}"));
+            }
+        }
+
+        text.push_str(if self.complete {
+            "\n\nAll required fields are set; call again with create_when_complete to create the issue"
+        } else {
+            "\n\nCall plan_issue_creation again with the fields gathered so far and the missing ones added"
+        });
+        text
+    }
+}
+
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
@@ -0,0 +1,409 @@
+//! Diagnosis of a configuration, for `validate_configuration` and the
+//! `--check-config` flag: where the settings come from, whether the base URL
+//! has the shape the client expects, and whether Jira can be reached, accepts
+//! the credentials and lets them browse the default project. Each check
+//! reports pass, warn or fail, with a hint on what to change.
+
+use crate::config::manager::ConfigSource;
+use crate::config::JiraConfig;
+use crate::error::{JiraErrorKind, Result};
+use crate::jira::client::JiraClient;
+use crate::jira::urls::path_segment;
+use crate::types::jira::JiraUser;
+use serde::Serialize;
+use serde_json::Value;
+
+/// REST API versions the client works with
+const API_VERSIONS: [&str; 2] = ["2", "3"];
+
+/// Outcome of one check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl std::fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Pass => "PASS",
+            Self::Warn => "WARN",
+            Self::Fail => "FAIL",
+        };
+        f.write_str(label)
+    }
+}
+
+/// One check of the configuration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// What to change, for checks that did not pass
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+impl ConfigCheck {
+    #[must_use]
+    pub fn pass(name: &str, detail: String) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Pass,
+            detail,
+            hint: None,
+        }
+    }
+
+    #[must_use]
+    pub fn warn(name: &str, detail: String, hint: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Warn,
+            detail,
+            hint: Some(hint.to_string()),
+        }
+    }
+
+    #[must_use]
+    pub fn fail(name: &str, detail: String, hint: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Fail,
+            detail,
+            hint: Some(hint.to_string()),
+        }
+    }
+}
+
+/// The checks of one diagnosis, in the order they ran
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConfigReport {
+    pub checks: Vec<ConfigCheck>,
+}
+
+impl ConfigReport {
+    /// Number of checks with `status`
+    #[must_use]
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.status == status)
+            .count()
+    }
+
+    /// Whether any check failed
+    #[must_use]
+    pub fn has_failures(&self) -> bool {
+        self.count(CheckStatus::Fail) > 0
+    }
+
+    /// A summary line, then one line per check with its hint below
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut text = format!(
+            "Configuration check: {} passed, {} warnings, {} failed\n",
+            self.count(CheckStatus::Pass),
+            self.count(CheckStatus::Warn),
+            self.count(CheckStatus::Fail)
+        );
+        for check in &self.checks {
+            text.push_str(&format!(
+                "[{}] {}: {}\n",
+                check.status, check.name, check.detail
+            ));
+            if let Some(hint) = &check.hint {
+                text.push_str(&format!("       → {hint}\n"));
+            }
+        }
+        text
+    }
+}
+
+/// The sources the settings were read from, highest precedence first
+#[must_use]
+pub fn check_sources(sources: &[ConfigSource]) -> ConfigCheck {
+    let names: Vec<String> = sources
+        .iter()
+        .map(|source| match source {
+            ConfigSource::Environment => "environment variables".to_string(),
+            ConfigSource::DotEnv => ".env file".to_string(),
+            ConfigSource::Toml(path) => format!("TOML file {}", path.display()),
+            ConfigSource::Yaml(path) => format!("YAML file {}", path.display()),
+            ConfigSource::Json(path) => format!("JSON file {}", path.display()),
+            ConfigSource::Default => "defaults".to_string(),
+        })
+        .collect();
+    ConfigCheck::pass("sources", names.join(", "))
+}
+
+/// Whether the settings pass the same validation as at startup
+#[must_use]
+pub fn check_settings(config: &JiraConfig) -> ConfigCheck {
+    match config.validate() {
+        Ok(()) => ConfigCheck::pass("settings", "all settings are valid".to_string()),
+        Err(e) => ConfigCheck::fail(
+            "settings",
+            e.to_string(),
+            "Fix the setting named in the error; CONFIGURATION.md lists each one with its \
+             environment variable",
+        ),
+    }
+}
+
+/// Whether `api_base_url` is an http(s) URL ending in `/rest/api/2` or
+/// `/rest/api/3`, the root every endpoint is joined onto
+#[must_use]
+pub fn check_base_url(config: &JiraConfig) -> ConfigCheck {
+    const NAME: &str = "base URL";
+    let base = config.api_base_url.trim().trim_end_matches('/');
+    let example = "Set JIRA_API_BASE_URL to the REST API root of the site, e.g. \
+                   https://your-site.atlassian.net/rest/api/2";
+    let url = match url::Url::parse(base) {
+        Ok(url) if ["http", "https"].contains(&url.scheme()) => url,
+        _ => {
+            return ConfigCheck::fail(
+                NAME,
+                format!("'{base}' is not an absolute http(s) URL"),
+                example,
+            )
+        }
+    };
+
+    let Some(index) = base.rfind("/rest/api/") else {
+        return ConfigCheck::fail(
+            NAME,
+            format!("'{base}' does not end in /rest/api/2 or /rest/api/3"),
+            &format!("Use {base}/rest/api/2, keeping any context path such as /jira"),
+        );
+    };
+    let version = &base[index + "/rest/api/".len()..];
+    if !API_VERSIONS.contains(&version) {
+        let site = &base[..index];
+        return ConfigCheck::fail(
+            NAME,
+            format!("'{base}' names REST API version '{version}'"),
+            &format!("Use {site}/rest/api/2, or {site}/rest/api/3 on Jira Cloud"),
+        );
+    }
+
+    let host = url.host_str().unwrap_or_default();
+    if url.scheme() == "http" {
+        return ConfigCheck::warn(
+            NAME,
+            format!("REST API v{version} at {host} over plain http"),
+            "The credentials are sent unencrypted; use https unless the site is local",
+        );
+    }
+    ConfigCheck::pass(NAME, format!("REST API v{version} at {host}"))
+}
+
+/// Whether the site answers at all: DNS resolution, the TCP connection and
+/// the TLS handshake. Any HTTP status passes.
+pub async fn check_reachability(client: &JiraClient) -> ConfigCheck {
+    const NAME: &str = "reachability";
+    let site = client.config().site_url();
+    match client.http_client().get(&site).send().await {
+        Ok(response) => ConfigCheck::pass(
+            NAME,
+            format!("{site} answered with HTTP {}", response.status().as_u16()),
+        ),
+        Err(e) => {
+            // The cause of a failed connection is only in its description
+            let cause = format!("{e:?}").to_lowercase();
+            let hint = if e.is_timeout() {
+                "Check the host and port, any proxy or VPN the site needs, or raise \
+                 JIRA_TIMEOUT_SECONDS"
+            } else if cause.contains("certificate") || cause.contains("tls") {
+                "The TLS handshake failed; install the site's CA certificate, or set \
+                 JIRA_STRICT_SSL=false for a self-signed test instance"
+            } else if cause.contains("dns") || cause.contains("resolve") {
+                "The host name could not be resolved; check it for typos and that a VPN \
+                 the site needs is connected"
+            } else {
+                "Check the host and port of JIRA_API_BASE_URL and that nothing blocks the \
+                 connection"
+            };
+            ConfigCheck::fail(NAME, format!("{site} could not be reached: {e}"), hint)
+        }
+    }
+}
+
+/// Whether Jira accepts the credentials, naming the scheme they are sent with
+pub async fn check_authentication(client: &JiraClient) -> ConfigCheck {
+    const NAME: &str = "authentication";
+    let config = client.config();
+    let bearer = config.auth_header().starts_with("Bearer ");
+    let scheme = if bearer { "Bearer token" } else { "Basic" };
+    match client.get_current_user().await {
+        Ok(user) => {
+            let name = JiraUser::label_of(&user);
+            ConfigCheck::pass(NAME, format!("authenticated as {name} ({scheme} auth)"))
+        }
+        Err(e) => {
+            let hint = match e.kind() {
+                JiraErrorKind::Auth if bearer && config.is_cloud() => {
+                    "Jira Cloud does not take personal access tokens as Bearer tokens; use \
+                     an API token with the account's JIRA_EMAIL"
+                }
+                JiraErrorKind::Auth if bearer => {
+                    "The token is sent as a Bearer personal access token; check that \
+                     JIRA_PERSONAL_ACCESS_TOKEN is a current token of this instance"
+                }
+                JiraErrorKind::Auth => {
+                    "The token is sent with JIRA_EMAIL as Basic credentials; check that both \
+                     belong to the same account and the token has not expired"
+                }
+                JiraErrorKind::PermissionDenied => {
+                    "Jira may ask for a CAPTCHA after failed logins; log in once in a \
+                     browser, then check the token"
+                }
+                _ => "Check that JIRA_API_BASE_URL points at this instance's REST API",
+            };
+            ConfigCheck::fail(NAME, format!("{scheme} auth was refused: {e}"), hint)
+        }
+    }
+}
+
+/// Whether the configured REST API version answers, with the Jira version
+/// behind it
+pub async fn check_api_version(client: &JiraClient) -> ConfigCheck {
+    const NAME: &str = "API version";
+    let config = client.config();
+    match client.get_server_info().await {
+        Ok(info) => {
+            let text = |key: &str| {
+                info.get(key)
+                    .and_then(Value::as_str)
+                    .unwrap_or("unknown")
+                    .to_string()
+            };
+            let api = if config.is_api_v3() { "v3" } else { "v2" };
+            ConfigCheck::pass(
+                NAME,
+                format!(
+                    "Jira {} ({}) answers REST API {api}",
+                    text("version"),
+                    text("deploymentType")
+                ),
+            )
+        }
+        Err(e) if e.kind() == JiraErrorKind::NotFound => ConfigCheck::fail(
+            NAME,
+            format!("no REST API at {}", config.api_base_url),
+            "Check the /rest/api/N suffix and any context path such as /jira; Server and \
+             Data Center only have /rest/api/2",
+        ),
+        Err(e) => ConfigCheck::fail(
+            NAME,
+            format!("the server info could not be read: {e}"),
+            "Check JIRA_API_BASE_URL and the authentication check above",
+        ),
+    }
+}
+
+/// Whether Zephyr answers, for the Zephyr tools
+pub async fn check_zephyr(client: &JiraClient) -> ConfigCheck {
+    const NAME: &str = "Zephyr";
+    match client.zephyr_get::<Value>("moduleInfo").await {
+        Ok(_) => ConfigCheck::pass(
+            NAME,
+            format!("Zephyr answers at {}", client.zephyr_api_base_url()),
+        ),
+        Err(e) => ConfigCheck::warn(
+            NAME,
+            format!("Zephyr did not answer: {e}"),
+            "The Zephyr tools will fail; ignore this if Zephyr Squad is not installed",
+        ),
+    }
+}
+
+/// Whether the credentials may browse `project`
+pub async fn check_project_permission(client: &JiraClient, project: &str) -> ConfigCheck {
+    const NAME: &str = "permissions";
+    match client
+        .has_project_permission(project, "BROWSE_PROJECTS")
+        .await
+    {
+        Ok(true) => ConfigCheck::pass(NAME, format!("you can browse project {project}")),
+        Ok(false) => ConfigCheck::fail(
+            NAME,
+            format!("you cannot browse project {project}"),
+            "Ask a Jira administrator for Browse Projects on it, or change \
+             JIRA_DEFAULT_PROJECT",
+        ),
+        Err(e) => ConfigCheck::warn(
+            NAME,
+            format!("the permissions on project {project} could not be read: {e}"),
+            "Check that JIRA_DEFAULT_PROJECT is the key of an existing project",
+        ),
+    }
+}
+
+/// Run every check against `config`, read from `sources`. Checks that need
+/// the network are skipped when the base URL is unusable, and those that
+/// need a response when the site cannot be reached.
+pub async fn run_config_checks(config: &JiraConfig, sources: &[ConfigSource]) -> ConfigReport {
+    let mut report = ConfigReport::default();
+    report.checks.push(check_sources(sources));
+    report.checks.push(check_settings(config));
+    report.checks.push(check_base_url(config));
+    let usable = url::Url::parse(config.api_base_url.trim())
+        .is_ok_and(|url| ["http", "https"].contains(&url.scheme()));
+    if !usable {
+        return report;
+    }
+
+    let client = match JiraClient::new(config.clone()) {
+        Ok(client) => client,
+        Err(e) => {
+            report.checks.push(ConfigCheck::fail(
+                "client",
+                format!("the HTTP client could not be created: {e}"),
+                "Check the connection settings such as JIRA_TIMEOUT_SECONDS",
+            ));
+            return report;
+        }
+    };
+    let reachability = check_reachability(&client).await;
+    let reachable = reachability.status != CheckStatus::Fail;
+    report.checks.push(reachability);
+    if !reachable {
+        return report;
+    }
+
+    report.checks.push(check_authentication(&client).await);
+    report.checks.push(check_api_version(&client).await);
+    report.checks.push(check_zephyr(&client).await);
+    if let Some(project) = config.default_project.as_deref() {
+        report
+            .checks
+            .push(check_project_permission(&client, project).await);
+    }
+    report
+}
+
+impl JiraClient {
+    /// Whether the credentials hold `permission`, e.g. `BROWSE_PROJECTS`, on
+    /// `project`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the project doesn't exist.
+    pub async fn has_project_permission(&self, project: &str, permission: &str) -> Result<bool> {
+        let response: Value = self
+            .get(&format!(
+                "mypermissions?projectKey={}&permissions={}",
+                path_segment(project),
+                path_segment(permission)
+            ))
+            .await?;
+        Ok(response
+            .pointer(&format!("/permissions/{permission}/havePermission"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false))
+    }
+}
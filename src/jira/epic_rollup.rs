@@ -0,0 +1,319 @@
+//! Epic roll-ups: the state of an epic's children in one view, from status
+//! categories and story points to blockers, recent activity and who holds
+//! the work.
+//!
+//! Children are read from a single search for the issues whose parent is the
+//! epic, either through the `parent` field or, on Data Center, the Epic Link
+//! field (see [`crate::jira::hierarchy::ParentMechanism`]). Everything here
+//! works on the JSON of that search, so the roll-up takes no further calls.
+
+use crate::jira::jql::{jql_escape_string, jql_field, Jql, SortOrder};
+use crate::jira::link_graph::unresolved_blockers;
+use crate::jira::sprint_report::fmt_points;
+use crate::utils::datetime::{parse_timestamp, DateFormatter};
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Fields requested for each child, besides the story points field
+pub const EPIC_CHILD_FIELDS: [&str; 6] = [
+    "summary",
+    "status",
+    "assignee",
+    "issuetype",
+    "updated",
+    "issuelinks",
+];
+
+/// Status category keys in workflow order, with their display names
+pub const STATUS_CATEGORY_NAMES: [(&str, &str); 3] = [
+    ("new", "To Do"),
+    ("indeterminate", "In Progress"),
+    ("done", "Done"),
+];
+
+/// JQL for the children of `epic_key`: issues whose `parent` is the epic
+/// or, given the id of the instance's Epic Link field, whose Epic Link is
+#[must_use]
+pub fn epic_children_jql(epic_key: &str, epic_link_field: Option<&str>) -> String {
+    let key = jql_escape_string(epic_key);
+    let clause = match epic_link_field.and_then(|id| id.strip_prefix("customfield_")) {
+        Some(number) => format!(
+            "(parent = {key} OR {} = {key})",
+            jql_field(&format!("cf[{number}]"))
+        ),
+        None => format!("parent = {key}"),
+    };
+    Jql::new()
+        .raw(clause)
+        .order_by("key", SortOrder::Asc)
+        .build()
+}
+
+/// One child issue of the epic
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EpicChild {
+    pub key: String,
+    pub summary: String,
+    pub issue_type: Option<String>,
+    pub status: Option<String>,
+    /// Key of the status category: `new`, `indeterminate` or `done`
+    pub status_category: Option<String>,
+    pub assignee: Option<String>,
+    /// Story points, `None` when the issue is not estimated
+    pub points: Option<f64>,
+    pub updated: Option<DateTime<FixedOffset>>,
+    /// Keys of the issues blocking this one that are not done yet
+    pub blocked_by: Vec<String>,
+}
+
+impl EpicChild {
+    /// Read a child from a search result, with its blockers taken from
+    /// `link_type` links
+    #[must_use]
+    pub fn from_json(issue: &Value, points_field: Option<&str>, link_type: &str) -> Self {
+        let fields = issue.get("fields").unwrap_or(&Value::Null);
+        let text = |pointer: &str| {
+            fields
+                .pointer(pointer)
+                .and_then(Value::as_str)
+                .map(ToString::to_string)
+        };
+        Self {
+            key: issue
+                .get("key")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            summary: text("/summary").unwrap_or_default(),
+            issue_type: text("/issuetype/name"),
+            status: text("/status/name"),
+            status_category: text("/status/statusCategory/key"),
+            assignee: text("/assignee/displayName"),
+            points: points_field
+                .and_then(|field| fields.get(field))
+                .and_then(Value::as_f64),
+            updated: text("/updated").as_deref().and_then(parse_timestamp),
+            blocked_by: unresolved_blockers(issue, link_type),
+        }
+    }
+
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.status_category.as_deref() == Some("done")
+    }
+}
+
+/// Children of one assignee
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AssigneeShare {
+    /// Display name, `None` for unassigned children
+    pub assignee: Option<String>,
+    pub issues: usize,
+    /// Children not done yet
+    pub open: usize,
+}
+
+/// Aggregated view of an epic's children
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EpicRollup {
+    pub total: usize,
+    /// Children per status category key; children without a category are
+    /// counted under `unknown`
+    pub status_categories: BTreeMap<String, usize>,
+    pub points_done: f64,
+    pub points_remaining: f64,
+    /// Children without story points, which count as zero above
+    pub unestimated: usize,
+    /// Children blocked by issues that are not done yet
+    pub blocked: Vec<String>,
+    /// The child updated last, with the time of that update
+    pub last_activity: Option<(String, DateTime<FixedOffset>)>,
+    /// Children per assignee, most first, unassigned last among equals
+    pub assignees: Vec<AssigneeShare>,
+}
+
+impl EpicRollup {
+    #[must_use]
+    pub fn from_children(children: &[EpicChild]) -> Self {
+        let mut status_categories = BTreeMap::new();
+        let mut assignees: BTreeMap<(bool, String), AssigneeShare> = BTreeMap::new();
+        let (mut points_done, mut points_remaining, mut unestimated) = (0.0, 0.0, 0);
+
+        for child in children {
+            *status_categories
+                .entry(
+                    child
+                        .status_category
+                        .clone()
+                        .unwrap_or_else(|| "unknown".to_string()),
+                )
+                .or_insert(0) += 1;
+
+            match child.points {
+                Some(points) if child.is_done() => points_done += points,
+                Some(points) => points_remaining += points,
+                None => unestimated += 1,
+            }
+
+            let share = assignees
+                .entry((
+                    child.assignee.is_none(),
+                    child.assignee.clone().unwrap_or_default(),
+                ))
+                .or_insert_with(|| AssigneeShare {
+                    assignee: child.assignee.clone(),
+                    issues: 0,
+                    open: 0,
+                });
+            share.issues += 1;
+            share.open += usize::from(!child.is_done());
+        }
+
+        let mut assignees: Vec<AssigneeShare> = assignees.into_values().collect();
+        // Stable, so equal counts keep the name order with unassigned last
+        assignees.sort_by_key(|share| std::cmp::Reverse(share.issues));
+
+        Self {
+            total: children.len(),
+            status_categories,
+            points_done,
+            points_remaining,
+            unestimated,
+            blocked: children
+                .iter()
+                .filter(|child| !child.is_done() && !child.blocked_by.is_empty())
+                .map(|child| child.key.clone())
+                .collect(),
+            last_activity: children
+                .iter()
+                .filter_map(|child| child.updated.map(|at| (child.key.clone(), at)))
+                .max_by_key(|(_, at)| *at),
+            assignees,
+        }
+    }
+
+    /// Share of the estimated points that are done, as a percentage
+    #[must_use]
+    pub fn points_done_percent(&self) -> Option<f64> {
+        let total = self.points_done + self.points_remaining;
+        (total > 0.0).then(|| self.points_done / total * 100.0)
+    }
+
+    /// Children in the status category `key`
+    #[must_use]
+    pub fn in_category(&self, key: &str) -> usize {
+        self.status_categories.get(key).copied().unwrap_or(0)
+    }
+}
+
+/// Render the roll-up of `epic_key` as compact text, listing each child when
+/// `list_children` is set. Points are shown only when a story points field
+/// was found.
+#[must_use]
+pub fn render_rollup(
+    epic_key: &str,
+    epic_summary: &str,
+    children: &[EpicChild],
+    rollup: &EpicRollup,
+    with_points: bool,
+    list_children: bool,
+    dates: &DateFormatter,
+) -> String {
+    let mut out = format!("Epic {epic_key}: {epic_summary}\n");
+    if rollup.total == 0 {
+        out.push_str("No child issues");
+        return out;
+    }
+
+    let mut categories: Vec<String> = STATUS_CATEGORY_NAMES
+        .iter()
+        .map(|(key, name)| format!("{} {name}", rollup.in_category(key)))
+        .collect();
+    let unknown = rollup.total
+        - STATUS_CATEGORY_NAMES
+            .iter()
+            .map(|(key, _)| rollup.in_category(key))
+            .sum::<usize>();
+    if unknown > 0 {
+        categories.push(format!("{unknown} without a status category"));
+    }
+    let _ = writeln!(out, "{} children: {}", rollup.total, categories.join(", "));
+
+    if with_points {
+        let _ = write!(
+            out,
+            "Points: {} done, {} remaining",
+            fmt_points(rollup.points_done),
+            fmt_points(rollup.points_remaining)
+        );
+        if let Some(percent) = rollup.points_done_percent() {
+            let _ = write!(out, " ({percent:.0}% done)");
+        }
+        if rollup.unestimated > 0 {
+            let _ = write!(out, "; {} unestimated", rollup.unestimated);
+        }
+        out.push('\n');
+    } else {
+        out.push_str("Points: no story points field found\n");
+    }
+
+    if rollup.blocked.is_empty() {
+        out.push_str("Blocked: none\n");
+    } else {
+        let blocked: Vec<String> = children
+            .iter()
+            .filter(|child| rollup.blocked.contains(&child.key))
+            .map(|child| format!("{} (by {})", child.key, child.blocked_by.join(", ")))
+            .collect();
+        let _ = writeln!(out, "Blocked: {}", blocked.join("; "));
+    }
+
+    if let Some((key, at)) = &rollup.last_activity {
+        let _ = writeln!(out, "Last activity: {} on {key}", dates.format(at));
+    }
+
+    let assignees: Vec<String> = rollup
+        .assignees
+        .iter()
+        .map(|share| {
+            format!(
+                "{} {} ({} open)",
+                share.assignee.as_deref().unwrap_or("Unassigned"),
+                share.issues,
+                share.open
+            )
+        })
+        .collect();
+    let _ = write!(out, "Assignees: {}", assignees.join(", "));
+
+    if list_children {
+        out.push_str("\n\nChildren:");
+        for child in children {
+            let _ = write!(
+                out,
+                "\n• {} [{}] {}",
+                child.key,
+                child.status.as_deref().unwrap_or("?"),
+                child.summary
+            );
+            if with_points {
+                match child.points {
+                    Some(points) => {
+                        let _ = write!(out, " ({} pts)", fmt_points(points));
+                    }
+                    None => out.push_str(" (unestimated)"),
+                }
+            }
+            if let Some(assignee) = &child.assignee {
+                let _ = write!(out, " — {assignee}");
+            }
+            if !child.blocked_by.is_empty() && !child.is_done() {
+                let _ = write!(out, " — blocked by {}", child.blocked_by.join(", "));
+            }
+        }
+    }
+    out
+}
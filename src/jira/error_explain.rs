@@ -0,0 +1,906 @@
+//! Explanations of Jira errors for people: what an error means, why it
+//! usually happens and what to change, for an error a tool returned or a raw
+//! Jira error body with its status code.
+//!
+//! Explanations come from [`RULES`], checked in order against every message
+//! and field error of the error: a rule applies when one of its patterns,
+//! all of whose fragments are in the lower-cased message, matches. The
+//! patterns include the wording Jira uses in other languages, since with
+//! `JIRA_LOCALE` set, or a non-English profile, Jira answers in that
+//! language. A message no rule knows is explained by the rule for its status
+//! code from [`STATUS_RULES`]. To recognise a new error, add a rule (and its
+//! localized variants) before any broader rule it overlaps with.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Where the name a rule's `{subject}` placeholder refers to comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subject {
+    /// The rule has no placeholder
+    None,
+    /// The id of the field the error is for, or the first quoted name in
+    /// the message
+    Field,
+    /// The first name in quotes in the message
+    Quoted,
+    /// The first text in parentheses, e.g. the transition of a workflow
+    /// error
+    Parenthesized,
+}
+
+/// How to explain one kind of Jira error
+#[derive(Debug)]
+pub struct ErrorRule {
+    /// Stable name of the rule, reported with the explanation
+    pub id: &'static str,
+    /// Status codes the rule is for; any status when empty. Errors without a
+    /// known status match every rule.
+    pub statuses: &'static [u16],
+    /// Lower-case fragments; the rule matches a message containing every
+    /// fragment of one of the patterns
+    pub patterns: &'static [&'static [&'static str]],
+    /// Jira permission the error is about
+    pub permission: Option<&'static str>,
+    pub subject: Subject,
+    /// What `{subject}` stands for, e.g. "the field"; the placeholder is
+    /// replaced with it and the name found, or with it alone
+    pub noun: &'static str,
+    pub explanation: &'static str,
+    pub causes: &'static [&'static str],
+    /// What to change; `{subject}` is replaced as for [`Self::noun`]
+    pub remediation: &'static str,
+}
+
+impl ErrorRule {
+    /// Whether the rule explains `message`, returned with `status`
+    #[must_use]
+    pub fn matches(&self, status: Option<u16>, message: &str) -> bool {
+        if let Some(status) = status {
+            if !self.statuses.is_empty() && !self.statuses.contains(&status) {
+                return false;
+            }
+        }
+        let message = message.to_lowercase();
+        self.patterns
+            .iter()
+            .any(|fragments| fragments.iter().all(|fragment| message.contains(fragment)))
+    }
+}
+
+/// Rules for the messages Jira returns, most specific first
+pub static RULES: &[ErrorRule] = &[
+    ErrorRule {
+        id: "write_policy",
+        statuses: &[],
+        patterns: &[&["policy violation"]],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "This server refused the write before it reached Jira: a write policy of its configuration forbids it.",
+        causes: &[
+            "The tool, project or field is covered by a [[policies]] rule",
+            "The call is outside the hours or limits a policy allows",
+        ],
+        remediation: "Run list_write_policies to see the rule named in the error; change the call to satisfy it or ask whoever runs the server to change the policy.",
+    },
+    ErrorRule {
+        id: "xsrf_check_failed",
+        statuses: &[403],
+        patterns: &[&["xsrf check failed"]],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira rejected the request as a possible cross-site request forgery.",
+        causes: &[
+            "A proxy in front of Jira strips or changes the X-Atlassian-Token header",
+            "The request was sent with a browser's User-Agent",
+        ],
+        remediation: "Check that proxies pass X-Atlassian-Token through and that JIRA_USER_AGENT does not imitate a browser.",
+    },
+    ErrorRule {
+        id: "captcha_required",
+        statuses: &[401, 403],
+        patterns: &[&["captcha"]],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira wants a CAPTCHA solved after too many failed logins and refuses API logins until then.",
+        causes: &["Earlier requests used a wrong password or token"],
+        remediation: "Log in to Jira once in a browser and solve the CAPTCHA, then check that JIRA_PERSONAL_ACCESS_TOKEN is current.",
+    },
+    ErrorRule {
+        id: "not_authenticated",
+        statuses: &[401, 403],
+        patterns: &[
+            &["client must be authenticated"],
+            &["not authenticated"],
+            &["nicht authentifiziert"],
+            &["non authentifié"],
+            &["pas authentifié"],
+            &["no autenticado"],
+        ],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira did not accept the credentials of the request.",
+        causes: &[
+            "JIRA_PERSONAL_ACCESS_TOKEN expired or was revoked",
+            "JIRA_EMAIL does not belong to the token (Cloud)",
+            "JIRA_API_BASE_URL points at another site than the token is for",
+        ],
+        remediation: "Run test_jira_auth, then validate_configuration; create a new token if the current one is rejected.",
+    },
+    ErrorRule {
+        id: "rate_limited",
+        statuses: &[],
+        patterns: &[
+            &["rate limit"],
+            &["too many requests"],
+            &["zu viele anfragen"],
+            &["trop de requêtes"],
+            &["demasiadas solicitudes"],
+        ],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira is limiting how many requests this account may send.",
+        causes: &[
+            "Bulk tools or many parallel calls used up the account's budget",
+            "Other integrations share the same account",
+        ],
+        remediation: "Wait for the time in Retry-After, then retry with fewer parallel calls; lower the JIRA_*_REQUESTS_PER_SECOND settings for a lasting fix.",
+    },
+    ErrorRule {
+        id: "jql_invalid",
+        statuses: &[400],
+        patterns: &[
+            &["jql"],
+            &["does not exist for the field"],
+            &["existiert nicht für das feld"],
+            &["n'existe pas pour le champ"],
+            &["no existe para el campo"],
+        ],
+        permission: None,
+        subject: Subject::Quoted,
+        noun: "the value",
+        explanation: "The JQL query could not be run: it has a syntax error or names a value Jira does not know.",
+        causes: &[
+            "The query names {subject}, which is misspelt or not visible to this account",
+            "A field or function name is wrong, or a value with spaces is not quoted",
+        ],
+        remediation: "Check {subject} in the query; quote values containing spaces and compare field names with get_custom_fields.",
+    },
+    ErrorRule {
+        id: "transition_not_available",
+        statuses: &[400, 409],
+        patterns: &[
+            &["workflow operation", "not valid"],
+            &["transition id", "not valid"],
+            &["workflow-vorgang", "nicht gültig"],
+            &["opération de workflow", "pas valide"],
+            &["operación de flujo de trabajo", "no es válida"],
+        ],
+        permission: Some("Transition Issues"),
+        subject: Subject::Parenthesized,
+        noun: "the transition",
+        explanation: "Jira cannot make {subject} from the issue's current status.",
+        causes: &[
+            "Someone moved the issue since its transitions were read",
+            "A workflow condition hides the transition from this account, e.g. only the assignee or a group may use it",
+            "The transition needs sub-tasks or linked issues in a certain state",
+        ],
+        remediation: "Run get_jira_transitions on the issue to see the transitions available now. If {subject} is missing there, its workflow condition is not met for this account; get_project_workflow shows the workflow.",
+    },
+    ErrorRule {
+        id: "field_not_on_screen",
+        statuses: &[400],
+        patterns: &[
+            &["not on the appropriate screen"],
+            &["nicht auf dem entsprechenden bildschirm"],
+            &["pas sur l'écran approprié"],
+            &["no está en la pantalla apropiada"],
+        ],
+        permission: None,
+        subject: Subject::Field,
+        noun: "the field",
+        explanation: "Jira cannot set {subject} because it is not on the create or edit screen of this project and issue type, or does not exist.",
+        causes: &[
+            "The field exists but was not added to the screen",
+            "The field id belongs to another Jira instance or was deleted",
+            "The field context does not cover this project or issue type",
+        ],
+        remediation: "Leave {subject} out, or ask a Jira admin to add it to the screen of the issue type; list_creatable_fields shows the fields the create screen has.",
+    },
+    ErrorRule {
+        id: "create_permission",
+        statuses: &[],
+        patterns: &[
+            &["permission to create issues"],
+            &["berechtigung", "vorgänge", "zu erstellen"],
+            &["permission de créer des tickets"],
+            &["permiso para crear incidencias"],
+        ],
+        permission: Some("Create Issues"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "This account may not create issues in the project.",
+        causes: &["The permission scheme of the project does not grant Create Issues to the account, its groups or roles"],
+        remediation: "Ask a project admin to grant the Create Issues permission, or create the issue in another project.",
+    },
+    ErrorRule {
+        id: "comment_permission",
+        statuses: &[],
+        patterns: &[
+            &["permission to comment"],
+            &["berechtigung", "kommentieren"],
+            &["permission de commenter"],
+            &["permiso para comentar"],
+        ],
+        permission: Some("Add Comments"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "This account may not comment on the issue.",
+        causes: &[
+            "The permission scheme does not grant Add Comments",
+            "The issue's status forbids comments (jira.permission.comment property)",
+        ],
+        remediation: "Ask a project admin to grant the Add Comments permission, or check whether the issue's workflow status blocks comments.",
+    },
+    ErrorRule {
+        id: "edit_permission",
+        statuses: &[],
+        patterns: &[
+            &["permission to edit"],
+            &["berechtigung", "bearbeiten"],
+            &["permission de modifier"],
+            &["permiso para editar"],
+        ],
+        permission: Some("Edit Issues"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "This account may not edit the issue.",
+        causes: &[
+            "The permission scheme does not grant Edit Issues",
+            "The issue is in a status whose workflow properties make it read-only, e.g. Closed",
+        ],
+        remediation: "Ask a project admin to grant the Edit Issues permission, or reopen the issue first if its status is read-only.",
+    },
+    ErrorRule {
+        id: "assignee_not_assignable",
+        statuses: &[],
+        patterns: &[
+            &["cannot be assigned issues"],
+            &["keine vorgänge zugewiesen werden"],
+            &["ne peut pas être assigné"],
+            &["no se le pueden asignar incidencias"],
+        ],
+        permission: Some("Assignable User"),
+        subject: Subject::Quoted,
+        noun: "the user",
+        explanation: "Issues in this project cannot be assigned to {subject}.",
+        causes: &[
+            "The user lacks the Assignable User permission in the project",
+            "The user is inactive or the name is another user's",
+        ],
+        remediation: "Pick an assignee that has the Assignable User permission, or ask a project admin to grant it to {subject}.",
+    },
+    ErrorRule {
+        id: "worklog_permission",
+        statuses: &[],
+        patterns: &[
+            &["permission to associate a worklog"],
+            &["permission to log work"],
+            &["berechtigung", "arbeit zu protokollieren"],
+            &["permission de consigner"],
+            &["permiso para registrar trabajo"],
+        ],
+        permission: Some("Work On Issues"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "This account may not log work on the issue.",
+        causes: &[
+            "The permission scheme does not grant Work On Issues",
+            "Time tracking is disabled on the instance",
+        ],
+        remediation: "Ask a project admin to grant the Work On Issues permission and check that time tracking is on.",
+    },
+    ErrorRule {
+        id: "attachment_permission",
+        statuses: &[],
+        patterns: &[
+            &["permission to create attachments"],
+            &["berechtigung", "anhänge"],
+            &["permission de créer des pièces jointes"],
+            &["permiso para crear archivos adjuntos"],
+        ],
+        permission: Some("Create Attachments"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "This account may not attach files to the issue.",
+        causes: &[
+            "The permission scheme does not grant Create Attachments",
+            "Attachments are disabled on the instance",
+        ],
+        remediation: "Ask a project admin to grant the Create Attachments permission; get_jira_server_info shows whether attachments are enabled.",
+    },
+    ErrorRule {
+        id: "link_permission",
+        statuses: &[],
+        patterns: &[
+            &["permission to link issues"],
+            &["berechtigung", "verknüpfen"],
+            &["permission de lier"],
+            &["permiso para vincular"],
+        ],
+        permission: Some("Link Issues"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "This account may not link these issues.",
+        causes: &[
+            "The permission scheme of one of the projects does not grant Link Issues",
+            "Issue linking is disabled on the instance",
+        ],
+        remediation: "Ask a project admin to grant the Link Issues permission in both projects.",
+    },
+    ErrorRule {
+        id: "issue_not_found",
+        statuses: &[400, 404],
+        patterns: &[
+            &["issue does not exist"],
+            &["vorgang existiert nicht"],
+            &["le ticket n'existe pas"],
+            &["la incidencia no existe"],
+        ],
+        permission: Some("Browse Projects"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira found no issue with that key that this account may see. Jira answers the same whether the issue is missing or hidden.",
+        causes: &[
+            "The key is misspelt or the issue was deleted",
+            "The issue was moved to another project and has a new key",
+            "The account lacks Browse Projects in the project, or an issue security level hides the issue",
+        ],
+        remediation: "Check the key with search_jira_issues; if it exists for other users, ask a project admin to grant Browse Projects or the issue's security level to this account.",
+    },
+    ErrorRule {
+        id: "user_not_found",
+        statuses: &[],
+        patterns: &[
+            &["user", "does not exist"],
+            &["benutzer", "existiert nicht"],
+            &["l'utilisateur", "n'existe pas"],
+            &["usuario", "no existe"],
+        ],
+        permission: Some("Browse users and groups"),
+        subject: Subject::Quoted,
+        noun: "the user",
+        explanation: "Jira does not know {subject}, or this account may not look users up.",
+        causes: &[
+            "On Cloud users are given by account id, not by name or email",
+            "The user was deactivated or renamed",
+        ],
+        remediation: "Give users by account id on Cloud and by user name on Data Center; mentions like @name are resolved for you in comments.",
+    },
+    ErrorRule {
+        id: "project_invalid",
+        statuses: &[],
+        patterns: &[
+            &["valid project is required"],
+            &["no project could be found"],
+            &["gültiges projekt"],
+            &["projet valide"],
+            &["proyecto válido"],
+        ],
+        permission: Some("Browse Projects"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "The project of the request does not exist or this account cannot see it.",
+        causes: &[
+            "The project key is misspelt or the project was archived",
+            "The account lacks Browse Projects in the project",
+        ],
+        remediation: "Check the key with list_jira_projects, which lists the projects this account sees.",
+    },
+    ErrorRule {
+        id: "issue_type_invalid",
+        statuses: &[],
+        patterns: &[
+            &["valid issue type"],
+            &["specify an issue type"],
+            &["gültigen vorgangstyp"],
+            &["type de ticket valide"],
+            &["tipo de incidencia válido"],
+        ],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "The issue type is not one the project uses.",
+        causes: &[
+            "The issue type scheme of the project does not include it",
+            "Sub-task types need a parent, and standard types must not have one",
+        ],
+        remediation: "Run get_project_issue_types for the project and use one of the names it lists.",
+    },
+    ErrorRule {
+        id: "security_level_invalid",
+        statuses: &[],
+        patterns: &[
+            &["security level"],
+            &["sicherheitsstufe"],
+            &["niveau de sécurité"],
+            &["nivel de seguridad"],
+        ],
+        permission: Some("Set Issue Security"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "The security level cannot be set on this issue.",
+        causes: &[
+            "The level is not in the project's issue security scheme",
+            "The account lacks Set Issue Security or is not in the level itself",
+        ],
+        remediation: "Run get_issue_security_levels for the project and pick a level listed there.",
+    },
+    ErrorRule {
+        id: "value_too_long",
+        statuses: &[400],
+        patterns: &[
+            &["less than 255 characters"],
+            &["weniger als 255 zeichen"],
+            &["moins de 255 caractères"],
+            &["menos de 255 caracteres"],
+        ],
+        permission: None,
+        subject: Subject::Field,
+        noun: "the field",
+        explanation: "The value of {subject} is longer than Jira allows.",
+        causes: &["Summaries and single-line text fields hold at most 255 characters"],
+        remediation: "Shorten {subject} to under 255 characters and move the rest into the description.",
+    },
+    ErrorRule {
+        id: "invalid_value",
+        statuses: &[400],
+        patterns: &[
+            &["specify a valid"],
+            &["is not a valid"],
+            &["valid option"],
+            &["einen gültigen wert"],
+            &["une valeur valide"],
+            &["un valor válido"],
+        ],
+        permission: None,
+        subject: Subject::Field,
+        noun: "the field",
+        explanation: "Jira does not accept the value given for {subject}.",
+        causes: &[
+            "Select lists, priorities and versions only take values that exist, by id or exact name",
+            "The value exists in another project or field context",
+        ],
+        remediation: "Run get_issue_type_metadata for the project and issue type to see the allowed values of {subject}.",
+    },
+    ErrorRule {
+        id: "field_required",
+        statuses: &[400],
+        patterns: &[
+            &["is required"],
+            &["you must specify"],
+            &["ist erforderlich"],
+            &["sie müssen", "angeben"],
+            &["est obligatoire"],
+            &["vous devez indiquer"],
+            &["es obligatorio"],
+            &["debe especificar"],
+        ],
+        permission: None,
+        subject: Subject::Field,
+        noun: "the field",
+        explanation: "Jira needs a value for {subject} on this screen or transition.",
+        causes: &[
+            "The field configuration of the project makes it required",
+            "A workflow validator of the transition requires it",
+        ],
+        remediation: "Set {subject} in the call; list_creatable_fields marks the required fields of the create screen, and transition_jira_issue takes fields for a transition screen.",
+    },
+];
+
+/// Rules for errors no message rule explains, by status code
+pub static STATUS_RULES: &[ErrorRule] = &[
+    ErrorRule {
+        id: "bad_request",
+        statuses: &[400],
+        patterns: &[],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira could not process the request as sent.",
+        causes: &[
+            "A field value has the wrong shape, e.g. a name where an object is expected",
+            "A workflow validator rejected the change",
+        ],
+        remediation: "Compare the request with get_issue_type_metadata, or repeat the call with debug_capture: true to see exactly what was sent.",
+    },
+    ErrorRule {
+        id: "unauthorized",
+        statuses: &[401],
+        patterns: &[],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira did not accept the credentials of the request.",
+        causes: &["The token expired, was revoked or belongs to another site"],
+        remediation: "Run test_jira_auth and validate_configuration.",
+    },
+    ErrorRule {
+        id: "forbidden",
+        statuses: &[403],
+        patterns: &[],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "The account is known, but lacks a permission this request needs.",
+        causes: &["The permission scheme of the project does not grant the permission to the account"],
+        remediation: "Ask a project admin which permission the action needs and to grant it; the Jira message above often names it.",
+    },
+    ErrorRule {
+        id: "not_found",
+        statuses: &[404],
+        patterns: &[],
+        permission: Some("Browse Projects"),
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira found nothing at that address that this account may see.",
+        causes: &[
+            "A key or id is wrong, or the item was deleted",
+            "The API is not on this instance, e.g. Jira Software or an add-on is missing",
+        ],
+        remediation: "Check the key or id; for whole tool groups failing, see the JIRA_*_ENABLED settings.",
+    },
+    ErrorRule {
+        id: "conflict",
+        statuses: &[409],
+        patterns: &[],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "The issue changed since it was read, so the change was not applied.",
+        causes: &["Someone else edited or transitioned the issue in the meantime"],
+        remediation: "Read the issue again and repeat the change against its current state.",
+    },
+    ErrorRule {
+        id: "payload_too_large",
+        statuses: &[413],
+        patterns: &[],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "The request was larger than Jira or a proxy accepts.",
+        causes: &["An attachment exceeds the attachment size limit"],
+        remediation: "get_jira_server_info shows the attachment size limit; split or compress the file.",
+    },
+    ErrorRule {
+        id: "too_many_requests",
+        statuses: &[429],
+        patterns: &[],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira is limiting how many requests this account may send.",
+        causes: &["Too many calls in a short time"],
+        remediation: "Wait and retry with fewer parallel calls.",
+    },
+    ErrorRule {
+        id: "server_error",
+        statuses: &[500, 502, 503, 504],
+        patterns: &[],
+        permission: None,
+        subject: Subject::None,
+        noun: "",
+        explanation: "Jira or a proxy in front of it failed to handle the request.",
+        causes: &[
+            "Jira is restarting, overloaded or in maintenance",
+            "An add-on failed while handling the request",
+        ],
+        remediation: "Retry later; if it keeps failing for one request, repeat it with debug_capture: true and give the capture to a Jira admin.",
+    },
+];
+
+/// Explanation of a message that neither rule table knows
+static UNRECOGNIZED: ErrorRule = ErrorRule {
+    id: "unrecognized",
+    statuses: &[],
+    patterns: &[],
+    permission: None,
+    subject: Subject::None,
+    noun: "",
+    explanation: "This error is not one of the known Jira errors.",
+    causes: &[],
+    remediation: "Read Jira's message as it is; repeating the call with debug_capture: true shows the full request and response.",
+};
+
+/// A Jira error reduced to what rules look at
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct JiraErrorReport {
+    pub status: Option<u16>,
+    pub messages: Vec<String>,
+    /// Field errors by field id
+    pub field_errors: BTreeMap<String, String>,
+}
+
+impl JiraErrorReport {
+    /// Read a raw Jira error body, JSON or text, returned with `status`
+    #[must_use]
+    pub fn from_body(status: Option<u16>, body: &Value) -> Self {
+        let mut report = Self {
+            status,
+            ..Self::default()
+        };
+        match body {
+            Value::String(text) => match serde_json::from_str::<Value>(text) {
+                Ok(json) if json.is_object() => return Self::from_body(status, &json),
+                // HTML error pages say nothing the status does not
+                _ if text.trim_start().starts_with('<') => {}
+                _ if !text.trim().is_empty() => report.messages.push(text.trim().to_string()),
+                _ => {}
+            },
+            Value::Object(_) => report.read_error_object(body),
+            _ => {}
+        }
+        report
+    }
+
+    /// Read the error of a tool call: a JSON-RPC error (with the server's
+    /// message in `data.details`), a bulk result with `error_message` and
+    /// `error_kind`, a Jira error body, or the error text alone
+    #[must_use]
+    pub fn from_tool_error(error: &Value) -> Self {
+        match error {
+            Value::String(text) => Self::from_details(text),
+            Value::Object(object) => {
+                if let Some(inner) = object.get("error").filter(|e| e.is_object()) {
+                    return Self::from_tool_error(inner);
+                }
+                let details = error
+                    .pointer("/data/details")
+                    .or_else(|| object.get("details"))
+                    .or_else(|| object.get("error_message"))
+                    .and_then(Value::as_str);
+                if let Some(details) = details {
+                    let mut report = Self::from_details(details);
+                    if report.status.is_none() {
+                        report.status = object
+                            .get("error_kind")
+                            .and_then(Value::as_str)
+                            .and_then(status_of_kind);
+                    }
+                    return report;
+                }
+                Self::from_body(None, error)
+            }
+            _ => Self::default(),
+        }
+    }
+
+    /// Read the text a tool error was rendered as, e.g. `HTTP error: 404 Not
+    /// Found - ...` or `Jira API error: summary: "..."`. Notes after the
+    /// first line, such as retry counts, are left out.
+    #[must_use]
+    pub fn from_details(details: &str) -> Self {
+        let line = details.lines().next().unwrap_or_default().trim();
+        let mut report = Self::default();
+        let rest = if let Some(rest) = line.strip_prefix("HTTP error: ") {
+            let (code, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+            report.status = code.parse().ok();
+            rest.split_once(" - ").map_or("", |(_, message)| message)
+        } else if let Some(rest) = line.strip_prefix("Permission denied: ") {
+            report.status = Some(403);
+            rest
+        } else if let Some(rest) = line.strip_prefix("Authentication error: ") {
+            report.status = Some(401);
+            rest
+        } else if let Some(rest) = line.strip_prefix("Conflict: ") {
+            report.status = Some(409);
+            rest
+        } else if let Some(rest) = line.strip_prefix("Jira API error: ") {
+            if let Some(code) = rest
+                .strip_prefix("HTTP ")
+                .and_then(|status| status.get(..3))
+                .and_then(|code| code.parse().ok())
+            {
+                report.status = Some(code);
+                return report;
+            }
+            rest
+        } else {
+            line
+        };
+        match parse_field_errors(rest) {
+            Some(field_errors) => report.field_errors = field_errors,
+            None if !rest.is_empty() => report.messages.push(rest.to_string()),
+            None => {}
+        }
+        report
+    }
+
+    /// Collect the messages of a Jira error object: `errorMessages`,
+    /// `errors` by field, and the `message` of gateway and service desk
+    /// errors
+    fn read_error_object(&mut self, body: &Value) {
+        if self.status.is_none() {
+            self.status = body
+                .get("status")
+                .or_else(|| body.get("status-code"))
+                .and_then(Value::as_u64)
+                .and_then(|status| u16::try_from(status).ok());
+        }
+        for message in body
+            .get("errorMessages")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            self.messages.push(message.to_string());
+        }
+        for (field, message) in body
+            .get("errors")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+        {
+            let message = message
+                .as_str()
+                .map_or_else(|| message.to_string(), ToString::to_string);
+            self.field_errors.insert(field.clone(), message);
+        }
+        for key in ["message", "errorMessage"] {
+            if let Some(message) = body.get(key).and_then(Value::as_str) {
+                self.messages.push(message.to_string());
+            }
+        }
+    }
+}
+
+/// The status code an `error_kind` of a bulk result stands for
+fn status_of_kind(kind: &str) -> Option<u16> {
+    match kind {
+        "permission_denied" => Some(403),
+        "not_found" => Some(404),
+        "validation" => Some(400),
+        "conflict" => Some(409),
+        "auth" => Some(401),
+        "rate_limited" => Some(429),
+        _ => None,
+    }
+}
+
+/// Field errors as [`crate::error::JiraError::from_jira_response`] writes
+/// them, `field: "message", field: "message"`, or `None` for other text
+fn parse_field_errors(text: &str) -> Option<BTreeMap<String, String>> {
+    let mut field_errors = BTreeMap::new();
+    let mut rest = text.trim();
+    while !rest.is_empty() {
+        let (field, value) = rest.split_once(": ")?;
+        if field.is_empty() || field.contains(char::is_whitespace) || !value.starts_with('"') {
+            return None;
+        }
+        let mut stream = serde_json::Deserializer::from_str(value).into_iter::<String>();
+        let message = stream.next()?.ok()?;
+        let consumed = stream.byte_offset();
+        field_errors.insert(field.to_string(), message);
+        rest = value[consumed..].trim_start();
+        if !rest.is_empty() {
+            rest = rest.strip_prefix(',')?.trim_start();
+        }
+    }
+    (!field_errors.is_empty()).then_some(field_errors)
+}
+
+/// The first text between `open` and `close` in `message`
+fn enclosed(message: &str, open: char, close: char) -> Option<String> {
+    let start = message.find(open)? + open.len_utf8();
+    let length = message[start..].find(close)?;
+    Some(message[start..start + length].to_string()).filter(|text| !text.is_empty())
+}
+
+/// The first name in single or double quotes in `message`
+fn quoted(message: &str) -> Option<String> {
+    enclosed(message, '\'', '\'').or_else(|| enclosed(message, '"', '"'))
+}
+
+/// One message of an error with its explanation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    /// Id of the rule that explained the message
+    pub rule: &'static str,
+    /// Field the message is for
+    pub field: Option<String>,
+    /// Jira's message; `None` when only the status was known
+    pub message: Option<String>,
+    /// Jira permission the error is about
+    pub permission: Option<&'static str>,
+    pub explanation: String,
+    pub likely_causes: Vec<String>,
+    pub remediation: String,
+}
+
+impl Finding {
+    /// Explain `message` (for `field`, if it is a field error) with `rule`
+    #[must_use]
+    pub fn new(rule: &ErrorRule, field: Option<&str>, message: Option<&str>) -> Self {
+        let message_text = message.unwrap_or_default();
+        let subject = match rule.subject {
+            Subject::None => None,
+            Subject::Field => field
+                .map(ToString::to_string)
+                .or_else(|| quoted(message_text)),
+            Subject::Quoted => quoted(message_text),
+            Subject::Parenthesized => enclosed(message_text, '(', ')'),
+        };
+        let subject = match subject {
+            Some(name) => format!("{} '{name}'", rule.noun),
+            None => rule.noun.to_string(),
+        };
+        let fill = |text: &str| text.replace("{subject}", &subject);
+        Self {
+            rule: rule.id,
+            field: field.map(ToString::to_string),
+            message: message.map(ToString::to_string),
+            permission: rule.permission,
+            explanation: fill(rule.explanation),
+            likely_causes: rule.causes.iter().map(|cause| fill(cause)).collect(),
+            remediation: fill(rule.remediation),
+        }
+    }
+}
+
+/// The explanation of a whole error
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorExplanation {
+    pub status: Option<u16>,
+    pub findings: Vec<Finding>,
+}
+
+/// The first rule of [`RULES`] that explains `message`
+#[must_use]
+pub fn match_rule(status: Option<u16>, message: &str) -> Option<&'static ErrorRule> {
+    RULES.iter().find(|rule| rule.matches(status, message))
+}
+
+/// The rule of [`STATUS_RULES`] for `status`
+#[must_use]
+pub fn status_rule(status: u16) -> Option<&'static ErrorRule> {
+    STATUS_RULES
+        .iter()
+        .find(|rule| rule.statuses.contains(&status))
+}
+
+/// Explain every message and field error of `report`. Messages no rule
+/// knows are explained by their status code.
+#[must_use]
+pub fn explain(report: &JiraErrorReport) -> ErrorExplanation {
+    let fallback = report.status.and_then(status_rule).unwrap_or(&UNRECOGNIZED);
+    let explain_one = |field: Option<&str>, message: &str| {
+        let rule = match_rule(report.status, message).unwrap_or(fallback);
+        Finding::new(rule, field, Some(message))
+    };
+
+    let mut findings: Vec<Finding> = report
+        .field_errors
+        .iter()
+        .map(|(field, message)| explain_one(Some(field), message))
+        .chain(
+            report
+                .messages
+                .iter()
+                .map(|message| explain_one(None, message)),
+        )
+        .collect();
+    if findings.is_empty() {
+        findings.push(Finding::new(fallback, None, None));
+    }
+    ErrorExplanation {
+        status: report.status,
+        findings,
+    }
+}
@@ -0,0 +1,438 @@
+//! Jira webhook events, normalized and kept in a bounded buffer.
+//!
+//! The webhook receiver (see [`crate::mcp::transport::webhook`]) turns each
+//! accepted payload into a compact [`JiraEvent`] and pushes it to the
+//! process-wide buffer returned by [`jira_events`]. Once the buffer holds
+//! `webhook_buffer_size` events the oldest is dropped for each new one.
+//! `get_recent_jira_events` reads the buffer back, and the MCP server sends
+//! each new event to clients that subscribed to them.
+//!
+//! Cloud and Data Center send the same `webhookEvent` names for issue
+//! events but differ for comments: Cloud sends `comment_created` and the
+//! like, Data Center a `jira:issue_updated` whose `issue_event_type_name` is
+//! `issue_commented`. Both come out as the same [`JiraEventType`].
+
+use crate::error::{JiraError, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::sync::{LazyLock, Mutex};
+
+/// Events kept when `webhook_buffer_size` is not configured
+pub const DEFAULT_EVENT_BUFFER_SIZE: usize = 200;
+
+/// Characters of a comment body kept in an event
+const MAX_COMMENT_CHARS: usize = 280;
+
+/// Events received by the webhook receiver of this process
+static EVENTS: LazyLock<EventBuffer> =
+    LazyLock::new(|| EventBuffer::new(DEFAULT_EVENT_BUFFER_SIZE));
+
+/// The buffer the webhook receiver pushes to
+#[must_use]
+pub fn jira_events() -> &'static EventBuffer {
+    &EVENTS
+}
+
+/// What happened, as tools and filters name it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JiraEventType {
+    IssueCreated,
+    IssueUpdated,
+    IssueDeleted,
+    CommentCreated,
+    CommentUpdated,
+    CommentDeleted,
+    /// Any other webhook event, e.g. a worklog or sprint event
+    Other,
+}
+
+impl JiraEventType {
+    /// Every type, in the order tools list them
+    pub const ALL: [Self; 7] = [
+        Self::IssueCreated,
+        Self::IssueUpdated,
+        Self::IssueDeleted,
+        Self::CommentCreated,
+        Self::CommentUpdated,
+        Self::CommentDeleted,
+        Self::Other,
+    ];
+
+    /// The name tools take, e.g. `issue_created`
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::IssueCreated => "issue_created",
+            Self::IssueUpdated => "issue_updated",
+            Self::IssueDeleted => "issue_deleted",
+            Self::CommentCreated => "comment_created",
+            Self::CommentUpdated => "comment_updated",
+            Self::CommentDeleted => "comment_deleted",
+            Self::Other => "other",
+        }
+    }
+
+    /// The type named `name`, as returned by [`Self::name`]
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// The type of a payload from its `webhookEvent` and, for Data Center
+    /// comments, its `issue_event_type_name`
+    fn classify(webhook_event: &str, issue_event_type: Option<&str>) -> Self {
+        match (webhook_event, issue_event_type) {
+            ("jira:issue_created", _) => Self::IssueCreated,
+            ("jira:issue_deleted", _) => Self::IssueDeleted,
+            ("jira:issue_updated", Some("issue_commented")) | ("comment_created", _) => {
+                Self::CommentCreated
+            }
+            ("jira:issue_updated", Some("issue_comment_edited")) | ("comment_updated", _) => {
+                Self::CommentUpdated
+            }
+            ("jira:issue_updated", Some("issue_comment_deleted")) | ("comment_deleted", _) => {
+                Self::CommentDeleted
+            }
+            ("jira:issue_updated", _) => Self::IssueUpdated,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// One field changed by an `issue_updated` event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JiraEventChange {
+    pub field: String,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+/// A webhook payload reduced to what a client needs to react to it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JiraEvent {
+    /// Position in the buffer, counting from 1; 0 until the event is pushed
+    pub sequence: u64,
+    pub event_type: JiraEventType,
+    /// `webhookEvent` as Jira sent it, e.g. `jira:issue_updated`
+    pub webhook_event: String,
+    pub issue_key: Option<String>,
+    pub issue_id: Option<String>,
+    pub project_key: Option<String>,
+    pub summary: Option<String>,
+    /// Display name of whoever caused the event
+    pub actor: Option<String>,
+    /// When Jira raised the event, or when it arrived if the payload has no
+    /// timestamp
+    pub timestamp: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<JiraEventChange>,
+    /// Start of the comment body for comment events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl JiraEvent {
+    /// Normalize a Cloud or Data Center webhook payload
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the payload is not a JSON object with a
+    /// `webhookEvent`.
+    pub fn from_webhook(payload: &Value) -> Result<Self> {
+        let webhook_event = payload
+            .get("webhookEvent")
+            .and_then(Value::as_str)
+            .ok_or_else(|| {
+                JiraError::validation_error(
+                    "webhookEvent",
+                    "Not a Jira webhook payload: webhookEvent is missing",
+                )
+            })?;
+        let event_type = JiraEventType::classify(
+            webhook_event,
+            payload.get("issue_event_type_name").and_then(Value::as_str),
+        );
+
+        let issue = payload.get("issue");
+        let fields = issue.and_then(|issue| issue.get("fields"));
+        let issue_key = text(issue, "key");
+        let project_key = fields
+            .and_then(|fields| fields.get("project"))
+            .and_then(|project| text(Some(project), "key"))
+            .or_else(|| {
+                issue_key
+                    .as_deref()
+                    .and_then(|key| key.rsplit_once('-'))
+                    .map(|(project, _)| project.to_string())
+            });
+
+        let comment = payload.get("comment");
+        let is_comment_event = matches!(
+            event_type,
+            JiraEventType::CommentCreated
+                | JiraEventType::CommentUpdated
+                | JiraEventType::CommentDeleted
+        );
+        // Comment events name the commenter as the comment author rather
+        // than as the user of the event, which Cloud leaves out
+        let actor = if is_comment_event {
+            comment
+                .and_then(|comment| comment.get("updateAuthor").or(comment.get("author")))
+                .and_then(user_name)
+                .or_else(|| payload.get("user").and_then(user_name))
+        } else {
+            payload.get("user").and_then(user_name)
+        };
+
+        let changes = payload
+            .pointer("/changelog/items")
+            .and_then(Value::as_array)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| {
+                        Some(JiraEventChange {
+                            field: text(Some(item), "field")?,
+                            from: text(Some(item), "fromString"),
+                            to: text(Some(item), "toString"),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let timestamp = payload
+            .get("timestamp")
+            .and_then(Value::as_i64)
+            .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+            .unwrap_or_else(Utc::now);
+
+        Ok(Self {
+            sequence: 0,
+            event_type,
+            webhook_event: webhook_event.to_string(),
+            issue_key,
+            issue_id: text(issue, "id"),
+            project_key,
+            summary: fields.and_then(|fields| text(Some(fields), "summary")),
+            actor,
+            timestamp,
+            changes,
+            comment: comment
+                .and_then(|comment| comment.get("body"))
+                .and_then(Value::as_str)
+                .map(|body| shorten(body.trim(), MAX_COMMENT_CHARS)),
+        })
+    }
+
+    /// One line for people, e.g. `#12 issue_updated PROJ-1 "Login fails"
+    /// by Alice Smith: status To Do → Done`
+    #[must_use]
+    pub fn headline(&self) -> String {
+        let mut line = format!("#{} {}", self.sequence, self.event_type.name());
+        if self.event_type == JiraEventType::Other {
+            line.push_str(&format!(" ({})", self.webhook_event));
+        }
+        if let Some(key) = &self.issue_key {
+            line.push_str(&format!(" {key}"));
+        }
+        if let Some(summary) = &self.summary {
+            line.push_str(&format!(" \"{summary}\""));
+        }
+        if let Some(actor) = &self.actor {
+            line.push_str(&format!(" by {actor}"));
+        }
+        if !self.changes.is_empty() {
+            let changes: Vec<String> = self
+                .changes
+                .iter()
+                .map(|change| {
+                    format!(
+                        "{} {} → {}",
+                        change.field,
+                        change.from.as_deref().unwrap_or("(none)"),
+                        change.to.as_deref().unwrap_or("(none)")
+                    )
+                })
+                .collect();
+            line.push_str(&format!(": {}", changes.join("; ")));
+        } else if let Some(comment) = &self.comment {
+            line.push_str(&format!(": {comment}"));
+        }
+        line
+    }
+}
+
+/// The non-empty string `field` of `value`, or its number as a string
+fn text(value: Option<&Value>, field: &str) -> Option<String> {
+    match value?.get(field)? {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Display name of a Cloud (`accountId`) or Data Center (`name`) user
+fn user_name(user: &Value) -> Option<String> {
+    ["displayName", "name", "accountId"]
+        .into_iter()
+        .find_map(|field| text(Some(user), field))
+}
+
+fn shorten(body: &str, max_chars: usize) -> String {
+    if body.chars().count() <= max_chars {
+        body.to_string()
+    } else {
+        let kept: String = body.chars().take(max_chars).collect();
+        format!("{}…", kept.trim_end())
+    }
+}
+
+/// Which events [`EventBuffer::recent`] returns. Empty lists match
+/// everything.
+#[derive(Debug, Clone, Default)]
+pub struct JiraEventFilter {
+    /// Keep events of these projects, compared case-insensitively
+    pub project_keys: Vec<String>,
+    pub event_types: Vec<JiraEventType>,
+    pub issue_key: Option<String>,
+    /// Keep events pushed after this sequence number
+    pub after_sequence: Option<u64>,
+}
+
+impl JiraEventFilter {
+    #[must_use]
+    pub fn matches(&self, event: &JiraEvent) -> bool {
+        (self.project_keys.is_empty()
+            || event.project_key.as_deref().is_some_and(|project| {
+                self.project_keys
+                    .iter()
+                    .any(|wanted| wanted.eq_ignore_ascii_case(project))
+            }))
+            && (self.event_types.is_empty() || self.event_types.contains(&event.event_type))
+            && self.issue_key.as_deref().is_none_or(|wanted| {
+                event
+                    .issue_key
+                    .as_deref()
+                    .is_some_and(|key| key.eq_ignore_ascii_case(wanted))
+            })
+            && self
+                .after_sequence
+                .is_none_or(|after| event.sequence > after)
+    }
+}
+
+struct BufferState {
+    events: VecDeque<JiraEvent>,
+    capacity: usize,
+    last_sequence: u64,
+    evicted: u64,
+}
+
+/// The last `capacity` events, oldest first, numbered in arrival order
+pub struct EventBuffer {
+    state: Mutex<BufferState>,
+}
+
+impl EventBuffer {
+    /// A buffer keeping at most `capacity` events (at least 1)
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            state: Mutex::new(BufferState {
+                events: VecDeque::new(),
+                capacity: capacity.max(1),
+                last_sequence: 0,
+                evicted: 0,
+            }),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, BufferState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Keep at most `capacity` events (at least 1) from now on, dropping the
+    /// oldest ones over it
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut state = self.state();
+        state.capacity = capacity.max(1);
+        while state.events.len() > state.capacity {
+            state.events.pop_front();
+            state.evicted += 1;
+        }
+    }
+
+    /// Number `event` and keep it, dropping the oldest event if the buffer is
+    /// full. Returns the sequence number given.
+    pub fn push(&self, mut event: JiraEvent) -> u64 {
+        let mut state = self.state();
+        state.last_sequence += 1;
+        event.sequence = state.last_sequence;
+        if state.events.len() >= state.capacity {
+            state.events.pop_front();
+            state.evicted += 1;
+        }
+        state.events.push_back(event);
+        state.last_sequence
+    }
+
+    /// Up to `limit` events matching `filter`, newest first
+    #[must_use]
+    pub fn recent(&self, filter: &JiraEventFilter, limit: usize) -> Vec<JiraEvent> {
+        self.state()
+            .events
+            .iter()
+            .rev()
+            .filter(|event| filter.matches(event))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Events pushed after `sequence` that are still kept, oldest first
+    #[must_use]
+    pub fn since(&self, sequence: u64) -> Vec<JiraEvent> {
+        let state = self.state();
+        let skip = state
+            .events
+            .iter()
+            .take_while(|event| event.sequence <= sequence)
+            .count();
+        state.events.iter().skip(skip).cloned().collect()
+    }
+
+    /// Sequence number of the newest event, 0 before the first
+    #[must_use]
+    pub fn last_sequence(&self) -> u64 {
+        self.state().last_sequence
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.state().events.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.state().capacity
+    }
+
+    /// Events dropped to make room for newer ones
+    #[must_use]
+    pub fn evicted(&self) -> u64 {
+        self.state().evicted
+    }
+}
@@ -0,0 +1,204 @@
+//! The changes made to one field of an issue, read from its changelog, and
+//! how long the field held each of its values.
+//!
+//! Changelog items name the field they changed by `fieldId` on Cloud and
+//! recent Server versions, and only by its display name (`field`) on older
+//! ones. Items are matched by id when they carry one, so a field renamed since
+//! is still followed, and by name otherwise.
+
+use crate::utils::datetime::parse_timestamp;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Schema types of fields that hold one value at a time, for which the time
+/// spent in each value is worth reporting
+const STATUS_LIKE_TYPES: [&str; 7] = [
+    "status",
+    "priority",
+    "resolution",
+    "user",
+    "option",
+    "issuetype",
+    "securitylevel",
+];
+
+/// Identifies the changelog items of one field
+#[derive(Debug, Clone)]
+pub struct FieldMatcher {
+    id: String,
+    name: String,
+}
+
+impl FieldMatcher {
+    #[must_use]
+    pub fn new(id: &str, name: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    /// Matcher for a field definition from the field catalog
+    #[must_use]
+    pub fn from_definition(definition: &Value) -> Self {
+        let text = |key: &str| definition.get(key).and_then(Value::as_str).unwrap_or("");
+        Self::new(text("id"), text("name"))
+    }
+
+    /// Whether the changelog `item` changed this field: by `fieldId` when the
+    /// item has one, by its `field` name otherwise. Older Server versions
+    /// name system fields by id there, e.g. `duedate`.
+    #[must_use]
+    pub fn matches(&self, item: &Value) -> bool {
+        if let Some(id) = item.get("fieldId").and_then(Value::as_str) {
+            return id.eq_ignore_ascii_case(&self.id);
+        }
+        item.get("field")
+            .and_then(Value::as_str)
+            .is_some_and(|field| {
+                field.eq_ignore_ascii_case(&self.name) || field.eq_ignore_ascii_case(&self.id)
+            })
+    }
+}
+
+/// Whether the field with this catalog definition holds one status-like
+/// value at a time, such as a status, priority or assignee
+#[must_use]
+pub fn is_status_like(definition: &Value) -> bool {
+    definition
+        .pointer("/schema/type")
+        .and_then(Value::as_str)
+        .is_some_and(|kind| STATUS_LIKE_TYPES.contains(&kind))
+}
+
+/// One change to a field
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub at: DateTime<Utc>,
+    pub author: Option<String>,
+    /// Raw previous value, e.g. a status id
+    pub from: Option<String>,
+    /// Previous value as displayed
+    pub from_string: Option<String>,
+    /// Raw new value
+    pub to: Option<String>,
+    /// New value as displayed
+    pub to_string: Option<String>,
+}
+
+impl FieldChange {
+    /// Previous value as displayed, falling back to the raw value
+    #[must_use]
+    pub fn from_display(&self) -> Option<&str> {
+        self.from_string.as_deref().or(self.from.as_deref())
+    }
+
+    /// New value as displayed, falling back to the raw value
+    #[must_use]
+    pub fn to_display(&self) -> Option<&str> {
+        self.to_string.as_deref().or(self.to.as_deref())
+    }
+}
+
+/// The changes `field` went through in `histories`, oldest first. Histories
+/// whose time cannot be read are skipped.
+#[must_use]
+pub fn field_changes<'a, I>(histories: I, field: &FieldMatcher) -> Vec<FieldChange>
+where
+    I: IntoIterator<Item = &'a Value>,
+{
+    let text = |value: &Value, key: &str| {
+        value
+            .get(key)
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+            .map(ToString::to_string)
+    };
+
+    let mut changes = Vec::new();
+    for history in histories {
+        let Some(at) = history
+            .get("created")
+            .and_then(Value::as_str)
+            .and_then(parse_timestamp)
+        else {
+            continue;
+        };
+        let author = history
+            .pointer("/author/displayName")
+            .and_then(Value::as_str)
+            .map(ToString::to_string);
+        for item in history
+            .get("items")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter(|item| field.matches(item))
+        {
+            changes.push(FieldChange {
+                at: at.with_timezone(&Utc),
+                author: author.clone(),
+                from: text(item, "from"),
+                from_string: text(item, "fromString"),
+                to: text(item, "to"),
+                to_string: text(item, "toString"),
+            });
+        }
+    }
+    // Stable, so changes made in one history keep their order
+    changes.sort_by_key(|change| change.at);
+    changes
+}
+
+/// Time a field held one of its values
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValueTime {
+    /// The value as displayed; `None` while the field was empty
+    pub value: Option<String>,
+    pub seconds: u64,
+    /// How often the field was set to this value, counting the value it was
+    /// created with
+    pub times_set: usize,
+}
+
+/// How long the field held each of its values from `created` until `now`,
+/// in the order the values first appeared.
+///
+/// The value at creation is the previous value of the first change, or
+/// `current` if the field never changed.
+#[must_use]
+pub fn time_in_values(
+    created: DateTime<Utc>,
+    current: Option<&str>,
+    changes: &[FieldChange],
+    now: DateTime<Utc>,
+) -> Vec<ValueTime> {
+    let initial = changes.first().map_or(current, FieldChange::from_display);
+
+    let mut spans: Vec<(Option<&str>, DateTime<Utc>)> = vec![(initial, created)];
+    spans.extend(
+        changes
+            .iter()
+            .map(|change| (change.to_display(), change.at.max(created))),
+    );
+
+    let mut totals: Vec<ValueTime> = Vec::new();
+    for (index, (value, start)) in spans.iter().enumerate() {
+        let end = spans.get(index + 1).map_or(now, |(_, next)| *next);
+        let seconds = u64::try_from((end - *start).num_seconds()).unwrap_or(0);
+        let value = value.map(ToString::to_string);
+        match totals.iter_mut().find(|total| total.value == value) {
+            Some(total) => {
+                total.seconds += seconds;
+                total.times_set += 1;
+            }
+            None => totals.push(ValueTime {
+                value,
+                seconds,
+                times_set: 1,
+            }),
+        }
+    }
+    totals
+}
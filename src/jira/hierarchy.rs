@@ -0,0 +1,278 @@
+//! Issue type hierarchy: which issue types may be the parent of which.
+//!
+//! Cloud reports a `hierarchyLevel` for each issue type: 1 for epics, 0 for
+//! standard types, -1 for subtasks, and higher levels added in Advanced
+//! Roadmaps. Data Center reports none, so levels are inferred there: subtask
+//! types are -1, a type named "Epic" is 1 and every other type is 0. A parent
+//! must be exactly one level above its child.
+//!
+//! Issues are attached to their parent through the `parent` field, except
+//! that Data Center attaches standard issues to epics through the Epic Link
+//! custom field.
+
+use crate::error::{JiraError, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Name of the epic issue type on Data Center, where levels are inferred
+const EPIC_TYPE_NAME: &str = "Epic";
+
+/// Name of the custom field Data Center links issues to their epic with
+pub const EPIC_LINK_FIELD_NAME: &str = "Epic Link";
+
+/// An issue type and its level in the hierarchy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueTypeLevel {
+    pub id: String,
+    pub name: String,
+    pub level: i64,
+    pub subtask: bool,
+}
+
+impl IssueTypeLevel {
+    /// Read an issue type object, as found in a project's `issueTypes` or an
+    /// issue's `issuetype` field. Returns the type and whether its level was
+    /// inferred rather than reported.
+    #[must_use]
+    pub fn from_json(issue_type: &Value) -> (Self, bool) {
+        let text = |key: &str| {
+            issue_type
+                .get(key)
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string()
+        };
+        let name = text("name");
+        let subtask = issue_type
+            .get("subtask")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let reported = issue_type.get("hierarchyLevel").and_then(Value::as_i64);
+        let level = reported.unwrap_or(if subtask {
+            -1
+        } else if name.eq_ignore_ascii_case(EPIC_TYPE_NAME) {
+            1
+        } else {
+            0
+        });
+        (
+            Self {
+                id: text("id"),
+                name,
+                level,
+                subtask,
+            },
+            reported.is_none(),
+        )
+    }
+}
+
+/// The issue types of a project by hierarchy level
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueTypeHierarchy {
+    pub project_key: String,
+    pub issue_types: Vec<IssueTypeLevel>,
+    /// Whether the levels were inferred because Jira reported none
+    pub inferred: bool,
+    /// Whether the project is team-managed (Cloud `next-gen` style), where
+    /// parents must be in the same project
+    pub team_managed: bool,
+}
+
+impl IssueTypeHierarchy {
+    /// Build the hierarchy from a project (`GET project/{key}`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project has no `issueTypes`.
+    pub fn from_project(project_key: &str, project: &Value) -> Result<Self> {
+        let issue_types = project
+            .get("issueTypes")
+            .and_then(Value::as_array)
+            .ok_or_else(|| {
+                JiraError::api_error("Invalid project response format - missing issueTypes")
+            })?;
+        let mut inferred = false;
+        let issue_types = issue_types
+            .iter()
+            .map(|issue_type| {
+                let (issue_type, guessed) = IssueTypeLevel::from_json(issue_type);
+                inferred |= guessed;
+                issue_type
+            })
+            .collect();
+        let team_managed = project.get("style").and_then(Value::as_str) == Some("next-gen");
+        Ok(Self {
+            project_key: project_key.to_string(),
+            issue_types,
+            inferred,
+            team_managed,
+        })
+    }
+
+    /// Find an issue type by id or name, ignoring case
+    #[must_use]
+    pub fn find(&self, id_or_name: &str) -> Option<&IssueTypeLevel> {
+        let wanted = id_or_name.trim();
+        self.issue_types
+            .iter()
+            .find(|t| t.id == wanted)
+            .or_else(|| {
+                self.issue_types
+                    .iter()
+                    .find(|t| t.name.eq_ignore_ascii_case(wanted))
+            })
+    }
+
+    /// Issue types grouped by level, highest level first
+    #[must_use]
+    pub fn levels(&self) -> Vec<(i64, Vec<&IssueTypeLevel>)> {
+        let mut levels: Vec<i64> = self.issue_types.iter().map(|t| t.level).collect();
+        levels.sort_unstable_by(|a, b| b.cmp(a));
+        levels.dedup();
+        levels
+            .into_iter()
+            .map(|level| {
+                let types = self
+                    .issue_types
+                    .iter()
+                    .filter(|t| t.level == level)
+                    .collect();
+                (level, types)
+            })
+            .collect()
+    }
+
+    /// Check that an issue of type `child` (id or name) may have a parent of
+    /// type `parent`
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `child` is not an issue type of the
+    /// project, or if `parent` is not one level above it.
+    pub fn check_parent(&self, child: &str, parent: &IssueTypeLevel) -> Result<()> {
+        let child = self.find(child).ok_or_else(|| {
+            JiraError::validation_error(
+                "issuetype",
+                &format!(
+                    "Issue type '{child}' is not one of the issue types of {}",
+                    self.project_key
+                ),
+            )
+        })?;
+        if parent.level == child.level + 1 {
+            return Ok(());
+        }
+
+        let parents: Vec<&str> = self
+            .issue_types
+            .iter()
+            .filter(|t| t.level == child.level + 1)
+            .map(|t| t.name.as_str())
+            .collect();
+        let hint = if parents.is_empty() {
+            format!("{} issues have no parent", child.name)
+        } else {
+            format!("{} issues go under {}", child.name, parents.join(" or "))
+        };
+        Err(JiraError::validation_error(
+            "parent",
+            &format!(
+                "{} cannot be a child of {} on this instance; {hint}",
+                child.name, parent.name
+            ),
+        ))
+    }
+}
+
+/// How an issue is attached to its parent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "mechanism", rename_all = "snake_case")]
+pub enum ParentMechanism {
+    /// The `parent` field, on Cloud and for subtasks
+    ParentField,
+    /// The Epic Link custom field of Data Center
+    EpicLink { field_id: String },
+}
+
+impl ParentMechanism {
+    /// The mechanism attaching an issue of type `child` to a parent of type
+    /// `parent` in `hierarchy`, given the id of the instance's Epic Link
+    /// field if it has one
+    #[must_use]
+    pub fn choose(
+        hierarchy: &IssueTypeHierarchy,
+        child: &IssueTypeLevel,
+        parent: &IssueTypeLevel,
+        epic_link_field: Option<&str>,
+    ) -> Self {
+        match epic_link_field {
+            // Levels are only inferred on Data Center
+            Some(field_id) if hierarchy.inferred && !child.subtask && parent.level == 1 => {
+                Self::EpicLink {
+                    field_id: field_id.to_string(),
+                }
+            }
+            _ => Self::ParentField,
+        }
+    }
+
+    /// The field the mechanism sets
+    #[must_use]
+    pub fn field_id(&self) -> &str {
+        match self {
+            Self::ParentField => "parent",
+            Self::EpicLink { field_id } => field_id,
+        }
+    }
+}
+
+impl std::fmt::Display for ParentMechanism {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ParentField => f.write_str("parent field"),
+            Self::EpicLink { field_id } => write!(f, "{EPIC_LINK_FIELD_NAME} field {field_id}"),
+        }
+    }
+}
+
+/// The parent of an issue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueParent {
+    pub key: String,
+    /// Only known through the `parent` field
+    pub summary: Option<String>,
+    #[serde(flatten)]
+    pub mechanism: ParentMechanism,
+}
+
+impl IssueParent {
+    /// The parent set in an issue's `fields`, through the `parent` field or
+    /// the Epic Link field `epic_link_field`
+    #[must_use]
+    pub fn from_fields(
+        fields: &HashMap<String, Value>,
+        epic_link_field: Option<&str>,
+    ) -> Option<Self> {
+        if let Some(parent) = fields.get("parent").filter(|p| !p.is_null()) {
+            return Some(Self {
+                key: parent.get("key").and_then(Value::as_str)?.to_string(),
+                summary: parent
+                    .pointer("/fields/summary")
+                    .and_then(Value::as_str)
+                    .map(ToString::to_string),
+                mechanism: ParentMechanism::ParentField,
+            });
+        }
+        let field_id = epic_link_field?;
+        let key = fields.get(field_id).and_then(Value::as_str)?;
+        Some(Self {
+            key: key.to_string(),
+            summary: None,
+            mechanism: ParentMechanism::EpicLink {
+                field_id: field_id.to_string(),
+            },
+        })
+    }
+}
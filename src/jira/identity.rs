@@ -0,0 +1,82 @@
+//! How requests identify this tool, so Jira admins can pick its traffic out
+//! of access logs.
+//!
+//! Every request carries the configured `User-Agent` plus `X-Client-Name` and
+//! `X-Client-Version`. Once an MCP client has initialized the server, its
+//! name and version are appended to the `User-Agent`.
+
+use crate::config::JiraConfig;
+use crate::types::mcp::ClientInfo;
+use reqwest::RequestBuilder;
+use std::sync::{LazyLock, RwLock};
+
+/// Name sent in `X-Client-Name`
+pub const CLIENT_NAME: &str = env!("CARGO_PKG_NAME");
+
+/// Version sent in `X-Client-Version`
+pub const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// `User-Agent` sent when none is configured
+#[must_use]
+pub fn default_user_agent() -> String {
+    format!("{CLIENT_NAME}/{CLIENT_VERSION}")
+}
+
+static MCP_CLIENT: LazyLock<RwLock<Option<ClientInfo>>> = LazyLock::new(|| RwLock::new(None));
+
+/// Remember the MCP client that initialized the server, for the `User-Agent`
+/// of every later request.
+///
+/// # Panics
+///
+/// Panics if the lock is poisoned.
+pub fn set_mcp_client(client_info: &ClientInfo) {
+    *MCP_CLIENT.write().unwrap() = Some(client_info.clone());
+}
+
+/// The MCP client that initialized the server, if any
+///
+/// # Panics
+///
+/// Panics if the lock is poisoned.
+#[must_use]
+pub fn mcp_client() -> Option<ClientInfo> {
+    MCP_CLIENT.read().unwrap().clone()
+}
+
+/// The `User-Agent` to send: the configured one, followed by the MCP client
+/// as `(name/version)` when known. Spaces in the client's name and version
+/// become dashes and characters a header cannot carry are dropped.
+#[must_use]
+pub fn user_agent(config: &JiraConfig) -> String {
+    let base = config.user_agent();
+    let Some(client) = mcp_client() else {
+        return base;
+    };
+    let clean = |text: &str| -> String {
+        text.split_whitespace()
+            .collect::<Vec<_>>()
+            .join("-")
+            .chars()
+            .filter(|c| c.is_ascii_graphic() && !matches!(c, '(' | ')'))
+            .collect()
+    };
+    let name = clean(&client.name);
+    if name.is_empty() {
+        return base;
+    }
+    let version = clean(&client.version);
+    if version.is_empty() {
+        format!("{base} ({name})")
+    } else {
+        format!("{base} ({name}/{version})")
+    }
+}
+
+/// Add the identification headers to `request`
+pub fn identify(request: RequestBuilder, config: &JiraConfig) -> RequestBuilder {
+    request
+        .header("User-Agent", user_agent(config))
+        .header("X-Client-Name", CLIENT_NAME)
+        .header("X-Client-Version", CLIENT_VERSION)
+}
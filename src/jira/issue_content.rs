@@ -0,0 +1,142 @@
+//! Searching the text of one issue: its description, comments and changelog.
+//!
+//! Comments and changelog histories are matched a page at a time as they are
+//! fetched, so only the hits are kept however long the history of the issue
+//! is. A text is a hit only if it contains the whole query, ignoring case.
+
+use crate::mcp::tools::formatting::rich_text;
+use crate::utils::datetime::parse_timestamp;
+use crate::utils::snippets::{find_matches, find_snippets, SNIPPET_CONTEXT_CHARS};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Excerpts kept from one description, comment or changelog value
+pub const MAX_SNIPPETS_PER_TEXT: usize = 3;
+
+/// Where in the issue a hit was found
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HitLocation {
+    Description,
+    Comment { id: String },
+    Changelog { id: String, field: String },
+}
+
+impl std::fmt::Display for HitLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Description => f.write_str("description"),
+            Self::Comment { id } => write!(f, "comment #{id}"),
+            Self::Changelog { id, field } => write!(f, "changelog #{id} ({field})"),
+        }
+    }
+}
+
+/// One match of the query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContentHit {
+    /// When the text was written, as Jira reports it
+    pub timestamp: String,
+    pub author: String,
+    pub location: HitLocation,
+    /// Excerpt with the match highlighted
+    pub snippet: String,
+}
+
+fn text<'a>(value: &'a Value, pointer: &str) -> &'a str {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+fn text_hits(
+    content: Option<String>,
+    query: &str,
+    location: &HitLocation,
+    author: &str,
+    timestamp: &str,
+) -> Vec<ContentHit> {
+    let content = content.unwrap_or_default();
+    if find_matches(&content, query).is_empty() {
+        return Vec::new();
+    }
+    find_snippets(
+        &content,
+        query,
+        SNIPPET_CONTEXT_CHARS,
+        MAX_SNIPPETS_PER_TEXT,
+    )
+    .into_iter()
+    .map(|snippet| ContentHit {
+        timestamp: timestamp.to_string(),
+        author: author.to_string(),
+        location: location.clone(),
+        snippet: snippet.excerpt,
+    })
+    .collect()
+}
+
+/// Hits in the description of an issue with the `description`, `created`
+/// and `reporter` fields. The description is dated to the creation of the
+/// issue; later edits show up as changelog hits.
+#[must_use]
+pub fn description_hits(fields: &HashMap<String, Value>, query: &str) -> Vec<ContentHit> {
+    let field = |name: &str| fields.get(name).unwrap_or(&Value::Null);
+    text_hits(
+        rich_text(field("description")),
+        query,
+        &HitLocation::Description,
+        text(field("reporter"), "/displayName"),
+        field("created").as_str().unwrap_or_default(),
+    )
+}
+
+/// Hits in a comment as returned by Jira
+#[must_use]
+pub fn comment_hits(comment: &Value, query: &str) -> Vec<ContentHit> {
+    text_hits(
+        comment.get("body").and_then(rich_text),
+        query,
+        &HitLocation::Comment {
+            id: text(comment, "/id").to_string(),
+        },
+        text(comment, "/author/displayName"),
+        text(comment, "/created"),
+    )
+}
+
+/// Hits in the values set by a changelog history
+#[must_use]
+pub fn changelog_hits(history: &Value, query: &str) -> Vec<ContentHit> {
+    let items = history.get("items").and_then(Value::as_array);
+    items
+        .into_iter()
+        .flatten()
+        .flat_map(|item| {
+            let value = item
+                .get("toString")
+                .or_else(|| item.get("to"))
+                .and_then(Value::as_str)
+                .map(ToString::to_string);
+            text_hits(
+                value,
+                query,
+                &HitLocation::Changelog {
+                    id: text(history, "/id").to_string(),
+                    field: text(item, "/field").to_string(),
+                },
+                text(history, "/author/displayName"),
+                text(history, "/created"),
+            )
+        })
+        .collect()
+}
+
+/// Order hits oldest first; hits whose time cannot be read go last
+pub fn sort_hits(hits: &mut [ContentHit]) {
+    hits.sort_by_cached_key(|hit| {
+        parse_timestamp(&hit.timestamp).map_or(i64::MAX, |time| time.timestamp_millis())
+    });
+}
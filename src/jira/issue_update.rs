@@ -0,0 +1,157 @@
+//! The `update` part of an issue edit: per field, a list of verb operations
+//! (`add`, `remove`, `set`, `edit`) that Jira applies to the value the issue
+//! has when the request arrives. Adding one label or removing one fix version
+//! this way needs no read of the current list, so concurrent edits of the
+//! same array don't overwrite each other.
+
+use crate::error::{JiraError, Result};
+use crate::jira::client::JiraClient;
+use serde_json::{json, Map, Value};
+
+/// The verbs Jira accepts in an `update` operation
+pub const UPDATE_VERBS: [&str; 4] = ["add", "remove", "set", "edit"];
+
+/// Fields whose items are referenced by name, such as components and versions;
+/// the items of other array fields, such as labels, are plain strings
+const NAMED_ITEM_FIELDS: [&str; 3] = ["components", "fixVersions", "versions"];
+
+/// Check that `update` is an object mapping field ids to lists of operations,
+/// each an object with exactly one known verb, and return it
+///
+/// # Errors
+///
+/// Returns a validation error naming the first field whose operations are
+/// malformed
+pub fn parse_update(update: &Value) -> Result<Map<String, Value>> {
+    let fields = update.as_object().ok_or_else(|| {
+        JiraError::validation_error(
+            "update",
+            "must map field ids to lists of operations, e.g. {\"labels\": [{\"add\": \"x\"}]}",
+        )
+    })?;
+    for (field_id, operations) in fields {
+        let operations = operations.as_array().ok_or_else(|| {
+            JiraError::validation_error(
+                "update",
+                &format!("the operations of field '{field_id}' must be a list"),
+            )
+        })?;
+        for operation in operations {
+            let verb = operation
+                .as_object()
+                .filter(|operation| operation.len() == 1)
+                .and_then(|operation| operation.keys().next())
+                .ok_or_else(|| {
+                    JiraError::validation_error(
+                        "update",
+                        &format!(
+                            "each operation of field '{field_id}' must be an object with \
+                             one verb: {}",
+                            UPDATE_VERBS.join(", ")
+                        ),
+                    )
+                })?;
+            if !UPDATE_VERBS.contains(&verb.as_str()) {
+                return Err(JiraError::validation_error(
+                    "update",
+                    &format!(
+                        "unknown verb '{verb}' for field '{field_id}'; use {}",
+                        UPDATE_VERBS.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(fields.clone())
+}
+
+/// Operations applying `verb` to each of `items` of the array field
+/// `field_id`: labels as plain strings, components and versions by name
+#[must_use]
+pub fn item_operations(field_id: &str, verb: &str, items: &[String]) -> Vec<Value> {
+    items
+        .iter()
+        .map(|item| {
+            if NAMED_ITEM_FIELDS.contains(&field_id) {
+                json!({ verb: { "name": item } })
+            } else {
+                json!({ verb: item })
+            }
+        })
+        .collect()
+}
+
+/// Problems with the operations of `update` according to the issue's edit
+/// metadata: fields that cannot be edited, and verbs a field doesn't support
+#[must_use]
+pub fn check_update_against_editmeta(update: &Map<String, Value>, editmeta: &Value) -> Vec<String> {
+    let mut problems = Vec::new();
+    for (field_id, operations) in update {
+        let Some(meta) = editmeta.pointer(&format!("/fields/{field_id}")) else {
+            problems.push(format!("field '{field_id}' cannot be edited on this issue"));
+            continue;
+        };
+        let Some(supported) = meta.get("operations").and_then(Value::as_array) else {
+            continue;
+        };
+        let supported: Vec<&str> = supported.iter().filter_map(Value::as_str).collect();
+        let verbs = operations
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_object)
+            .flat_map(Map::keys);
+        for verb in verbs {
+            if !supported.contains(&verb.as_str()) {
+                problems.push(format!(
+                    "field '{field_id}' does not support '{verb}'; it supports {}",
+                    if supported.is_empty() {
+                        "no operations".to_string()
+                    } else {
+                        supported.join(", ")
+                    }
+                ));
+            }
+        }
+    }
+    problems
+}
+
+impl JiraClient {
+    /// Add and remove items of the array field `field_id` of an issue in one
+    /// update, leaving its other items alone
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    pub async fn update_issue_items(
+        &self,
+        issue_key: &str,
+        field_id: &str,
+        add: &[String],
+        remove: &[String],
+    ) -> Result<()> {
+        let mut operations = item_operations(field_id, "add", add);
+        operations.extend(item_operations(field_id, "remove", remove));
+        self.update_issue(issue_key, &json!({ "update": { field_id: operations } }))
+            .await
+    }
+
+    /// Problems with the `fields` and `update` of an edit of `issue_key`
+    /// according to the issue's edit metadata
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the edit metadata cannot be read.
+    pub async fn check_issue_edit(
+        &self,
+        issue_key: &str,
+        fields: &Map<String, Value>,
+        update: &Map<String, Value>,
+    ) -> Result<Vec<String>> {
+        let editmeta = self.get_edit_metadata(issue_key).await?;
+        let mut problems = crate::jira::bulk_csv::check_against_editmeta(fields, &editmeta);
+        problems.extend(check_update_against_editmeta(update, &editmeta));
+        Ok(problems)
+    }
+}
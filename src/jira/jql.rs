@@ -0,0 +1,250 @@
+//! Building JQL from untrusted values.
+//!
+//! Values are always emitted as quoted JQL string literals, so quotes,
+//! backslashes or reserved words in user input can't end the literal early and
+//! inject clauses of their own. Field names are quoted only when they are not
+//! plain identifiers (e.g. `"Story Points"`).
+
+use std::fmt;
+
+/// Words JQL reserves; used unquoted as a field name they break the query
+pub const RESERVED_WORDS: &[&str] = &[
+    "and", "or", "not", "empty", "null", "order", "by", "asc", "desc", "in", "is", "was",
+    "changed", "from", "to", "on", "during", "before", "after",
+];
+
+/// Quote `value` as a JQL string literal, escaping backslashes, quotes and
+/// control characters
+#[must_use]
+pub fn jql_escape_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Read back a literal produced by [`jql_escape_string`].
+///
+/// Returns `None` if `literal` is not a single, complete quoted string.
+#[must_use]
+pub fn jql_unescape_string(literal: &str) -> Option<String> {
+    let inner = literal.strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => value.push(match chars.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                escaped @ ('"' | '\\' | '\'') => escaped,
+                _ => return None,
+            }),
+            // An unescaped quote would have ended the literal
+            '"' => return None,
+            _ => value.push(c),
+        }
+    }
+    Some(value)
+}
+
+/// A field name as it can appear in JQL: plain identifiers (`status`,
+/// `customfield_10016`, `cf[10016]`) as they are, anything else quoted
+#[must_use]
+pub fn jql_field(name: &str) -> String {
+    let plain = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '[' | ']'))
+        && !RESERVED_WORDS.contains(&name.to_ascii_lowercase().as_str());
+    if plain {
+        name.to_string()
+    } else {
+        jql_escape_string(name)
+    }
+}
+
+/// Whether `jql` sorts its results, i.e. has an `ORDER BY` outside its
+/// string literals
+#[must_use]
+pub fn has_order_by(jql: &str) -> bool {
+    let mut unquoted = String::with_capacity(jql.len());
+    let mut quote = None;
+    let mut chars = jql.chars();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(_), '\\') => {
+                chars.next();
+            }
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                unquoted.push(' ');
+            }
+            (None, c) => unquoted.push(c.to_ascii_lowercase()),
+        }
+    }
+    let words: Vec<&str> = unquoted
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|word| !word.is_empty())
+        .collect();
+    words.windows(2).any(|pair| pair == ["order", "by"])
+}
+
+/// `jql` sorted by issue key when it has no order of its own, so pages of
+/// its results can't shift while they are read; `None` when it is sorted
+/// already
+#[must_use]
+pub fn with_key_order(jql: &str) -> Option<String> {
+    if has_order_by(jql) {
+        return None;
+    }
+    let clauses = jql.trim();
+    Some(if clauses.is_empty() {
+        "ORDER BY key ASC".to_string()
+    } else {
+        format!("{clauses} ORDER BY key ASC")
+    })
+}
+
+/// Direction of an `ORDER BY` field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// A JQL query assembled from clauses joined with `AND`, e.g.
+/// `Jql::new().project("PROJ").text_contains(query).order_by("updated", SortOrder::Desc)`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Jql {
+    clauses: Vec<String>,
+    order: Vec<String>,
+}
+
+impl Jql {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `project = value`
+    #[must_use]
+    pub fn project(self, key: &str) -> Self {
+        self.field_eq("project", key)
+    }
+
+    /// `status in (values)`; an empty list adds no clause
+    #[must_use]
+    pub fn status_in<S: AsRef<str>>(self, statuses: &[S]) -> Self {
+        self.field_in("status", statuses)
+    }
+
+    /// `key in (values)`; an empty list adds no clause
+    #[must_use]
+    pub fn key_in<S: AsRef<str>>(self, keys: &[S]) -> Self {
+        self.field_in("key", keys)
+    }
+
+    /// `text ~ value`, Jira's full-text search over summary, description and
+    /// comments
+    #[must_use]
+    pub fn text_contains(self, query: &str) -> Self {
+        self.compare("text", "~", query)
+    }
+
+    /// `field = value`
+    #[must_use]
+    pub fn field_eq(self, field: &str, value: &str) -> Self {
+        self.compare(field, "=", value)
+    }
+
+    /// `field >= value`, e.g. a date
+    #[must_use]
+    pub fn field_gte(self, field: &str, value: &str) -> Self {
+        self.compare(field, ">=", value)
+    }
+
+    /// `field < value`, e.g. a date
+    #[must_use]
+    pub fn field_lt(self, field: &str, value: &str) -> Self {
+        self.compare(field, "<", value)
+    }
+
+    /// `field in (values)`; an empty list adds no clause
+    #[must_use]
+    pub fn field_in<S: AsRef<str>>(mut self, field: &str, values: &[S]) -> Self {
+        if !values.is_empty() {
+            let values: Vec<String> = values
+                .iter()
+                .map(|v| jql_escape_string(v.as_ref()))
+                .collect();
+            self.clauses
+                .push(format!("{} in ({})", jql_field(field), values.join(", ")));
+        }
+        self
+    }
+
+    /// Add a clause verbatim. Only for fixed clauses written in code (e.g.
+    /// `resolution is not EMPTY`), never for user input.
+    #[must_use]
+    pub fn raw(mut self, clause: impl Into<String>) -> Self {
+        self.clauses.push(clause.into());
+        self
+    }
+
+    /// Append an `ORDER BY` field
+    #[must_use]
+    pub fn order_by(mut self, field: &str, order: SortOrder) -> Self {
+        let direction = match order {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        };
+        self.order.push(format!("{} {direction}", jql_field(field)));
+        self
+    }
+
+    fn compare(mut self, field: &str, operator: &str, value: &str) -> Self {
+        self.clauses.push(format!(
+            "{} {operator} {}",
+            jql_field(field),
+            jql_escape_string(value)
+        ));
+        self
+    }
+
+    /// The clauses added so far, without `ORDER BY`
+    #[must_use]
+    pub fn clauses(&self) -> &[String] {
+        &self.clauses
+    }
+
+    #[must_use]
+    pub fn build(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Jql {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.clauses.join(" AND "))?;
+        if !self.order.is_empty() {
+            if !self.clauses.is_empty() {
+                f.write_str(" ")?;
+            }
+            write!(f, "ORDER BY {}", self.order.join(", "))?;
+        }
+        Ok(())
+    }
+}
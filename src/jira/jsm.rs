@@ -0,0 +1,446 @@
+//! Jira Service Management: service desks, request types, customer requests,
+//! their SLAs and customer-facing comments.
+//!
+//! These live in the service desk API under `{site}/rest/servicedeskapi`
+//! rather than the core REST API, and some of its endpoints are experimental;
+//! [`JiraClient`] sends the opt-in header those need with every request to it.
+
+use crate::error::{JiraError, Result};
+use crate::jira::client::JiraClient;
+use crate::jira::urls::path_segment;
+use crate::types::jira::{
+    JiraComment, JiraCommentVisibility, JsmComment, JsmCustomerRequest, JsmDuration,
+    JsmRequestType, JsmServiceDesk, JsmSla, JsmSlaCycle,
+};
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use tracing::warn;
+
+/// Items requested per page of a service desk API list
+const JSM_PAGE_SIZE: usize = 50;
+
+/// Comment property that marks service desk comments as internal on Data
+/// Center, and on Cloud when set through the core REST API
+pub const PUBLIC_COMMENT_PROPERTY: &str = "sd.public.comment";
+
+/// Project type of Jira Service Management projects
+const SERVICE_DESK_PROJECT_TYPE: &str = "service_desk";
+
+/// Whether the raw comment `comment` of a service desk issue is shared with
+/// the customer: Cloud's `jsdPublic`, else the `sd.public.comment` property,
+/// which JSM only sets on internal comments
+#[must_use]
+pub fn comment_is_public(comment: &serde_json::Value) -> bool {
+    if let Some(public) = comment
+        .get("jsdPublic")
+        .and_then(serde_json::Value::as_bool)
+    {
+        return public;
+    }
+    let internal = comment
+        .get("properties")
+        .and_then(serde_json::Value::as_array)
+        .and_then(|properties| {
+            properties.iter().find(|property| {
+                property.get("key").and_then(serde_json::Value::as_str)
+                    == Some(PUBLIC_COMMENT_PROPERTY)
+            })
+        })
+        .and_then(|property| property.get("value"))
+        .and_then(|value| value.get("internal"))
+        .and_then(|internal| match internal {
+            serde_json::Value::Bool(internal) => Some(*internal),
+            // Older versions store it as a string
+            serde_json::Value::String(internal) => Some(internal == "true"),
+            _ => None,
+        })
+        .unwrap_or(false);
+    !internal
+}
+
+impl JiraClient {
+    /// Every page of the service desk API list at `endpoint`
+    async fn service_desk_pages<T: DeserializeOwned>(&self, endpoint: &str) -> Result<Vec<T>> {
+        let separator = if endpoint.contains('?') { '&' } else { '?' };
+        let mut items = Vec::new();
+        loop {
+            let page: serde_json::Value = self
+                .service_desk_request(
+                    Method::GET,
+                    &format!(
+                        "{endpoint}{separator}start={}&limit={JSM_PAGE_SIZE}",
+                        items.len()
+                    ),
+                    None::<&()>,
+                    true,
+                )
+                .await?;
+            let values = page
+                .get("values")
+                .and_then(serde_json::Value::as_array)
+                .ok_or_else(|| JiraError::api_error("Invalid service desk response format"))?;
+            for value in values {
+                items.push(
+                    serde_json::from_value(value.clone()).map_err(JiraError::SerializationError)?,
+                );
+            }
+            let is_last = page
+                .get("isLastPage")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true);
+            if is_last || values.is_empty() {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// List the service desks the user can see
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response cannot be parsed
+    pub async fn get_service_desks(&self) -> Result<Vec<JsmServiceDesk>> {
+        self.service_desk_pages("servicedesk").await
+    }
+
+    /// List the request types customers can raise on `service_desk_id`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the service desk cannot be found or the request fails
+    pub async fn get_request_types(&self, service_desk_id: &str) -> Result<Vec<JsmRequestType>> {
+        self.service_desk_pages(&format!(
+            "servicedesk/{}/requesttype",
+            path_segment(service_desk_id)
+        ))
+        .await
+    }
+
+    /// Raise a customer request of `request_type` on `service_desk`, with
+    /// `fields` as its request field values (e.g. `summary`, `description`).
+    /// Request types have no labels field, so the `auto_label` label is added
+    /// to the new issue afterwards; failing to add it is only logged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `fields` is not an object or Jira refuses the
+    /// request
+    pub async fn create_customer_request(
+        &self,
+        service_desk: &str,
+        request_type: &str,
+        fields: &serde_json::Value,
+    ) -> Result<JsmCustomerRequest> {
+        if !fields.is_object() {
+            return Err(JiraError::validation_error(
+                "fields",
+                "fields must be an object of request field values, e.g. {\"summary\": \"...\"}",
+            ));
+        }
+        let body = serde_json::json!({
+            "serviceDeskId": service_desk,
+            "requestTypeId": request_type,
+            "requestFieldValues": fields
+        });
+        let request: JsmCustomerRequest = self
+            .service_desk_request(Method::POST, "request", Some(&body), false)
+            .await?;
+        if let Some(label) = self.auto_label() {
+            let labels = serde_json::json!({"update": {"labels": [{"add": label}]}});
+            if let Err(e) = self.update_issue(&request.issue_key, &labels).await {
+                warn!(
+                    "Could not label {} with {}: {}",
+                    request.issue_key, label, e
+                );
+            }
+        }
+        Ok(request)
+    }
+
+    /// The SLAs of the customer request `issue_key`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be found or the request fails
+    pub async fn get_request_sla(&self, issue_key: &str) -> Result<Vec<JsmSla>> {
+        self.service_desk_pages(&format!("request/{}/sla", path_segment(issue_key)))
+            .await
+    }
+
+    /// Whether `issue_key` is in a Jira Service Management project, by the
+    /// type of its project in the metadata cache
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `issue_key` names no project (e.g. an issue id)
+    /// or the project cannot be read, so callers choose what an unknown
+    /// project type means rather than assuming it is no service desk
+    pub async fn is_service_desk_issue(&self, issue_key: &str) -> Result<bool> {
+        let Some((project_key, _)) = issue_key.rsplit_once('-') else {
+            return Err(JiraError::validation_error(
+                "issue_key",
+                &format!("'{issue_key}' is not an issue key, so its project is unknown"),
+            ));
+        };
+        let project_type = self.get_project_type(project_key).await?;
+        Ok(project_type.as_deref() == Some(SERVICE_DESK_PROJECT_TYPE))
+    }
+
+    /// Comment on `issue_key` without sharing the comment with customers:
+    /// internal on service desk issues, and also when the project type
+    /// cannot be read, since other projects ignore the `sd.public.comment`
+    /// property. Every comment written for other tools than
+    /// `add_jira_comment` goes through here.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the visibility is invalid or Jira refuses the
+    /// comment
+    pub async fn add_internal_comment(
+        &self,
+        issue_key: &str,
+        body: impl Into<serde_json::Value> + Send,
+        visibility: Option<&JiraCommentVisibility>,
+    ) -> Result<JiraComment> {
+        match self.is_service_desk_issue(issue_key).await {
+            Ok(false) => {
+                self.add_comment_with_visibility(issue_key, body, visibility)
+                    .await
+            }
+            Ok(true) => {
+                self.add_comment_with_public_flag(issue_key, body.into(), visibility, false)
+                    .await
+            }
+            Err(e) => {
+                warn!(
+                    "Could not read the project type of {}, marking the comment internal: {}",
+                    issue_key, e
+                );
+                self.add_comment_with_public_flag(issue_key, body.into(), visibility, false)
+                    .await
+            }
+        }
+    }
+
+    /// Comment on the service desk issue `issue_key`, shared with the
+    /// customer if `public`, otherwise internal.
+    ///
+    /// Plain comments go through the service desk API like
+    /// [`Self::add_customer_comment`]; rich text (ADF) bodies and comments
+    /// restricted to a role or group, which that API cannot take, go through
+    /// the core comment API with the `sd.public.comment` property.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error for a public comment restricted to a role
+    /// or group, or an error if Jira refuses the comment
+    pub async fn add_service_desk_comment(
+        &self,
+        issue_key: &str,
+        body: serde_json::Value,
+        visibility: Option<&JiraCommentVisibility>,
+        public: bool,
+    ) -> Result<JiraComment> {
+        if public && visibility.is_some() {
+            return Err(JiraError::validation_error(
+                "public",
+                "a comment restricted to a role or group cannot be shared with the customer",
+            ));
+        }
+        if let (Some(text), None) = (body.as_str(), visibility) {
+            let comment = self.add_customer_comment(issue_key, text, public).await?;
+            return Ok(JiraComment {
+                id: comment.id,
+                body: comment.body,
+                author: match comment.author {
+                    Some(author) => author,
+                    None => serde_json::from_value(serde_json::json!({}))
+                        .map_err(JiraError::SerializationError)?,
+                },
+                created: comment
+                    .created
+                    .and_then(|created| created.iso8601)
+                    .unwrap_or_default(),
+                updated: None,
+                visibility: None,
+            });
+        }
+
+        self.add_comment_with_public_flag(issue_key, body, visibility, public)
+            .await
+    }
+
+    /// Comment on `issue_key` through the core comment API with the
+    /// `sd.public.comment` property set, internal unless `public`.
+    ///
+    /// For issues whose project type is unknown: JSM keeps the comment
+    /// internal, and other projects ignore the property.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if Jira refuses the comment
+    pub async fn add_comment_with_public_flag(
+        &self,
+        issue_key: &str,
+        body: serde_json::Value,
+        visibility: Option<&JiraCommentVisibility>,
+        public: bool,
+    ) -> Result<JiraComment> {
+        let mut comment_data = Self::comment_payload(body, visibility)?;
+        comment_data["properties"] = serde_json::json!([{
+            "key": PUBLIC_COMMENT_PROPERTY,
+            "value": {"internal": !public}
+        }]);
+        self.post_comment(issue_key, &comment_data).await
+    }
+
+    /// Comment on the customer request `issue_key`, visible to the customer
+    /// if `public`, otherwise to agents only
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request cannot be found or the comment is
+    /// refused
+    pub async fn add_customer_comment(
+        &self,
+        issue_key: &str,
+        body: &str,
+        public: bool,
+    ) -> Result<JsmComment> {
+        let body = self.signed(&serde_json::json!(body));
+        let comment = serde_json::json!({"body": body, "public": public});
+        self.service_desk_request(
+            Method::POST,
+            &format!("request/{}/comment", path_segment(issue_key)),
+            Some(&comment),
+            false,
+        )
+        .await
+    }
+}
+
+/// `duration` as Jira words it, or in hours and minutes
+fn friendly(duration: &JsmDuration) -> String {
+    duration
+        .friendly
+        .clone()
+        .filter(|text| !text.is_empty())
+        .unwrap_or_else(|| {
+            let minutes = duration.millis.abs() / 60_000;
+            let text = match (minutes / 60, minutes % 60) {
+                (0, minutes) => format!("{minutes}m"),
+                (hours, 0) => format!("{hours}h"),
+                (hours, minutes) => format!("{hours}h {minutes}m"),
+            };
+            if duration.millis < 0 {
+                format!("-{text}")
+            } else {
+                text
+            }
+        })
+}
+
+fn goal(cycle: &JsmSlaCycle) -> String {
+    cycle
+        .goal_duration
+        .as_ref()
+        .map(|goal| format!(" (goal {})", friendly(goal)))
+        .unwrap_or_default()
+}
+
+/// Milliseconds left in an ongoing cycle at `now`: until its breach time
+/// while it runs, since Jira's remaining time is as of the response, and
+/// Jira's remaining time while it is paused
+fn remaining_millis(cycle: &JsmSlaCycle, now: DateTime<Utc>) -> Option<i64> {
+    let breach = cycle
+        .breach_time
+        .as_ref()
+        .and_then(|breach| breach.epoch_millis)
+        .filter(|_| cycle.paused != Some(true));
+    match breach {
+        Some(breach) => Some(breach - now.timestamp_millis()),
+        None => cycle
+            .remaining_time
+            .as_ref()
+            .map(|remaining| remaining.millis),
+    }
+}
+
+/// The state of an ongoing cycle at `now`
+fn ongoing_state(cycle: &JsmSlaCycle, now: DateTime<Utc>) -> String {
+    let mut state = match remaining_millis(cycle, now) {
+        Some(millis) if millis < 0 => format!(
+            "BREACHED {} ago",
+            friendly(&JsmDuration {
+                millis: -millis,
+                friendly: None
+            })
+        ),
+        _ if cycle.breached => "BREACHED".to_string(),
+        Some(millis) => format!(
+            "{} remaining",
+            friendly(&JsmDuration {
+                millis,
+                friendly: None
+            })
+        ),
+        None => "running".to_string(),
+    };
+    if cycle.paused == Some(true) {
+        state.push_str(", paused");
+    }
+    if cycle.within_calendar_hours == Some(false) {
+        state.push_str(", outside calendar hours");
+    }
+    state
+}
+
+/// One line per SLA: ongoing cycles with the time remaining at `now` (or
+/// how long ago they breached), otherwise the outcome of the last completed
+/// cycle
+#[must_use]
+pub fn render_slas(slas: &[JsmSla], now: DateTime<Utc>) -> String {
+    if slas.is_empty() {
+        return "No SLAs apply to this request".to_string();
+    }
+    slas.iter()
+        .map(|sla| {
+            let status = if let Some(cycle) = &sla.ongoing_cycle {
+                let breach = cycle
+                    .breach_time
+                    .as_ref()
+                    .and_then(|breach| breach.friendly.as_deref().or(breach.iso8601.as_deref()))
+                    .map(|at| format!(", due {at}"))
+                    .unwrap_or_default();
+                format!(
+                    "ongoing, {}{}{breach}",
+                    ongoing_state(cycle, now),
+                    goal(cycle)
+                )
+            } else if let Some(cycle) = sla.completed_cycles.last() {
+                let took = cycle
+                    .elapsed_time
+                    .as_ref()
+                    .map(|elapsed| format!(" in {}", friendly(elapsed)))
+                    .unwrap_or_default();
+                format!(
+                    "completed, {}{took}{}",
+                    if cycle.breached { "breached" } else { "met" },
+                    goal(cycle)
+                )
+            } else {
+                "not started".to_string()
+            };
+            let earlier = sla.completed_cycles.len()
+                - usize::from(sla.ongoing_cycle.is_none() && !sla.completed_cycles.is_empty());
+            let earlier = if earlier > 0 {
+                format!(" [{earlier} earlier cycles]")
+            } else {
+                String::new()
+            };
+            format!("• {}: {status}{earlier}", sla.name)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
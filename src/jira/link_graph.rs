@@ -0,0 +1,327 @@
+//! Issues reachable from one issue through its links, for questions such as
+//! "everything blocking PROJ-123, transitively".
+//!
+//! The client walks the links breadth first, one level per search; this module
+//! holds the graph it builds and reads the `issuelinks` field. Edges point the
+//! way Jira names them: a `Blocks` edge goes from the blocking issue to the
+//! blocked one, whichever side it was found from.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// Depth searched when the caller does not give one
+pub const DEFAULT_LINK_GRAPH_DEPTH: usize = 3;
+
+/// Deepest traversal allowed; larger depths are capped to this
+pub const MAX_LINK_GRAPH_DEPTH: usize = 5;
+
+/// Issues visited before the traversal stops expanding
+pub const MAX_LINK_GRAPH_NODES: usize = 200;
+
+/// Which links of an issue to follow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkDirection {
+    /// Links where the other issue acts on this one (e.g. "is blocked by")
+    Inward,
+    /// Links where this issue acts on the other one (e.g. "blocks")
+    Outward,
+    Both,
+}
+
+impl LinkDirection {
+    /// Parse `inward`, `outward` or `both`, ignoring case
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "inward" => Some(Self::Inward),
+            "outward" => Some(Self::Outward),
+            "both" => Some(Self::Both),
+            _ => None,
+        }
+    }
+
+    fn follows(self, inward: bool) -> bool {
+        match self {
+            Self::Inward => inward,
+            Self::Outward => !inward,
+            Self::Both => true,
+        }
+    }
+}
+
+/// What to traverse
+#[derive(Debug, Clone)]
+pub struct LinkGraphOptions {
+    /// Link types to follow, by name (`Blocks`) or either description
+    /// (`is blocked by`), ignoring case; empty follows every type
+    pub link_types: Vec<String>,
+    pub direction: LinkDirection,
+    /// Levels of links to follow from the root; the root itself is only
+    /// described when this is at least 1
+    pub max_depth: usize,
+}
+
+impl Default for LinkGraphOptions {
+    fn default() -> Self {
+        Self {
+            link_types: Vec::new(),
+            direction: LinkDirection::Both,
+            max_depth: DEFAULT_LINK_GRAPH_DEPTH,
+        }
+    }
+}
+
+/// One link of an issue as read from its `issuelinks` field
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueLinkRef {
+    pub id: String,
+    pub type_name: String,
+    /// How the linked issue relates to this one, e.g. "is blocked by"
+    pub relation: String,
+    /// Whether the linked issue is on the inward side
+    pub inward: bool,
+    pub other_key: String,
+    pub other_summary: Option<String>,
+    pub other_status: Option<String>,
+    /// Status category key of the linked issue: `new`, `indeterminate` or
+    /// `done`
+    pub other_status_category: Option<String>,
+}
+
+impl IssueLinkRef {
+    /// Links of an issue returned with the `issuelinks` field
+    #[must_use]
+    pub fn from_issue(issue: &Value) -> Vec<Self> {
+        issue
+            .pointer("/fields/issuelinks")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Self::from_json)
+            .collect()
+    }
+
+    fn from_json(link: &Value) -> Option<Self> {
+        let (other, inward) = match (link.get("inwardIssue"), link.get("outwardIssue")) {
+            (Some(other), _) => (other, true),
+            (None, Some(other)) => (other, false),
+            (None, None) => return None,
+        };
+        let link_type = link.get("type");
+        let text = |value: Option<&Value>| value.and_then(Value::as_str).map(ToString::to_string);
+        let type_name = text(link_type.and_then(|t| t.get("name"))).unwrap_or_default();
+        let relation =
+            text(link_type.and_then(|t| t.get(if inward { "inward" } else { "outward" })))
+                .unwrap_or_else(|| type_name.clone());
+
+        Some(Self {
+            id: text(link.get("id")).unwrap_or_default(),
+            type_name,
+            relation,
+            inward,
+            other_key: text(other.get("key"))?,
+            other_summary: text(other.pointer("/fields/summary")),
+            other_status: text(other.pointer("/fields/status/name")),
+            other_status_category: text(other.pointer("/fields/status/statusCategory/key")),
+        })
+    }
+
+    /// Whether `options` asks for this link to be followed
+    #[must_use]
+    pub fn matches(&self, options: &LinkGraphOptions) -> bool {
+        options.direction.follows(self.inward)
+            && (options.link_types.is_empty()
+                || options.link_types.iter().any(|wanted| {
+                    wanted.eq_ignore_ascii_case(&self.type_name)
+                        || wanted.eq_ignore_ascii_case(&self.relation)
+                }))
+    }
+
+    /// Whether this link blocks the issue it was read from: the linked issue
+    /// is on the inward side of a `link_type` link (matched like
+    /// [`LinkGraphOptions::link_types`]) and its status is not in the done
+    /// category
+    #[must_use]
+    pub fn is_unresolved_blocker(&self, link_type: &str) -> bool {
+        self.inward
+            && (link_type.eq_ignore_ascii_case(&self.type_name)
+                || link_type.eq_ignore_ascii_case(&self.relation))
+            && self.other_status_category.as_deref() != Some("done")
+    }
+}
+
+/// Keys of the issues blocking `issue` that are not done yet, read from its
+/// `issuelinks` field
+#[must_use]
+pub fn unresolved_blockers(issue: &Value, link_type: &str) -> Vec<String> {
+    let mut keys: Vec<String> = IssueLinkRef::from_issue(issue)
+        .into_iter()
+        .filter(|link| link.is_unresolved_blocker(link_type))
+        .map(|link| link.other_key)
+        .collect();
+    keys.dedup();
+    keys
+}
+
+/// An issue in the graph
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LinkNode {
+    pub key: String,
+    pub summary: Option<String>,
+    pub status: Option<String>,
+    /// Links between the root and this issue
+    pub depth: usize,
+    /// Issue this one was first reached from; `None` for the root
+    pub parent: Option<String>,
+    /// How this issue relates to its parent, e.g. "is blocked by"
+    pub relation: Option<String>,
+}
+
+/// A link between two issues in the graph
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LinkEdge {
+    pub id: String,
+    pub link_type: String,
+    /// Issue on the outward side, e.g. the blocking issue of a `Blocks` link
+    pub from: String,
+    /// Issue on the inward side, e.g. the blocked issue
+    pub to: String,
+}
+
+/// Issues reachable from `root` and the links between them
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LinkGraph {
+    pub root: String,
+    pub max_depth: usize,
+    /// Issues in the order they were reached
+    pub nodes: Vec<LinkNode>,
+    pub edges: Vec<LinkEdge>,
+    /// Set when [`MAX_LINK_GRAPH_NODES`] stopped the traversal early
+    pub truncated: bool,
+    #[serde(skip)]
+    edge_ids: HashSet<String>,
+}
+
+impl LinkGraph {
+    #[must_use]
+    pub fn new(root: &str, max_depth: usize) -> Self {
+        Self {
+            root: root.to_string(),
+            max_depth,
+            nodes: vec![LinkNode {
+                key: root.to_string(),
+                summary: None,
+                status: None,
+                depth: 0,
+                parent: None,
+                relation: None,
+            }],
+            edges: Vec::new(),
+            truncated: false,
+            edge_ids: HashSet::new(),
+        }
+    }
+
+    /// Whether `key` has already been reached
+    #[must_use]
+    pub fn contains(&self, key: &str) -> bool {
+        self.nodes.iter().any(|node| node.key == key)
+    }
+
+    /// Fill in the summary and status of an issue fetched while traversing
+    pub fn describe(&mut self, issue: &Value) {
+        let Some(key) = issue.get("key").and_then(Value::as_str) else {
+            return;
+        };
+        if let Some(node) = self.nodes.iter_mut().find(|node| node.key == key) {
+            let text = |pointer| {
+                issue
+                    .pointer(pointer)
+                    .and_then(Value::as_str)
+                    .map(ToString::to_string)
+            };
+            node.summary = text("/fields/summary").or(node.summary.take());
+            node.status = text("/fields/status/name").or(node.status.take());
+        }
+    }
+
+    /// Record `link` of the issue `from_key`, which sits at `depth`.
+    ///
+    /// Returns the linked issue's key when it was reached for the first time
+    /// and should be expanded next; issues seen before (cycles) and issues
+    /// beyond [`MAX_LINK_GRAPH_NODES`] are not.
+    pub fn add_link(
+        &mut self,
+        from_key: &str,
+        depth: usize,
+        link: &IssueLinkRef,
+    ) -> Option<String> {
+        if link.id.is_empty() || self.edge_ids.insert(link.id.clone()) {
+            let (from, to) = if link.inward {
+                (link.other_key.clone(), from_key.to_string())
+            } else {
+                (from_key.to_string(), link.other_key.clone())
+            };
+            self.edges.push(LinkEdge {
+                id: link.id.clone(),
+                link_type: link.type_name.clone(),
+                from,
+                to,
+            });
+        }
+
+        if self.contains(&link.other_key) {
+            return None;
+        }
+        if self.nodes.len() >= MAX_LINK_GRAPH_NODES {
+            self.truncated = true;
+            return None;
+        }
+        self.nodes.push(LinkNode {
+            key: link.other_key.clone(),
+            summary: link.other_summary.clone(),
+            status: link.other_status.clone(),
+            depth: depth + 1,
+            parent: Some(from_key.to_string()),
+            relation: Some(link.relation.clone()),
+        });
+        Some(link.other_key.clone())
+    }
+
+    /// The graph as an indented tree, each issue under the one it was first
+    /// reached from
+    #[must_use]
+    pub fn tree(&self) -> String {
+        let mut children: HashMap<&str, Vec<&LinkNode>> = HashMap::new();
+        for node in &self.nodes {
+            if let Some(parent) = &node.parent {
+                children.entry(parent.as_str()).or_default().push(node);
+            }
+        }
+
+        let mut out = String::new();
+        let mut stack: Vec<&LinkNode> = self.nodes.iter().take(1).collect();
+        while let Some(node) = stack.pop() {
+            let indent = "  ".repeat(node.depth);
+            let relation = node
+                .relation
+                .as_ref()
+                .map(|relation| format!("{relation} "))
+                .unwrap_or_default();
+            let line = format!(
+                "{indent}{relation}{} [{}] {}",
+                node.key,
+                node.status.as_deref().unwrap_or("?"),
+                node.summary.as_deref().unwrap_or("")
+            );
+            let _ = writeln!(out, "{}", line.trim_end());
+            if let Some(nodes) = children.get(node.key.as_str()) {
+                stack.extend(nodes.iter().rev());
+            }
+        }
+        out
+    }
+}
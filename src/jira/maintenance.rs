@@ -0,0 +1,202 @@
+//! Jira maintenance and read-only windows.
+//!
+//! During a Data Center upgrade Jira answers 503 with a maintenance page, often
+//! with a `Retry-After` far in the future. Retrying only adds load, so a
+//! response with that signature puts the site in maintenance: requests then
+//! fail at once with "Jira is in maintenance until ~T" instead of being sent,
+//! except for one probe per [`MAINTENANCE_PROBE_INTERVAL`] (or once the
+//! advertised end has passed). The first successful response ends the window.
+//!
+//! With `serve_stale_during_maintenance`, successful GET responses are kept
+//! for [`STALE_RESPONSE_TTL`] and answer GET requests during a window.
+
+use crate::config::JiraConfig;
+use crate::error::JiraError;
+use crate::performance::cache::{CacheStore, MokaCache};
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// A `Retry-After` of at least this long on a 503 means maintenance rather
+/// than a passing overload
+pub const MAINTENANCE_RETRY_AFTER: Duration = Duration::from_secs(300);
+
+/// How often a request is let through to see whether a window without an
+/// advertised end is over
+pub const MAINTENANCE_PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long GET responses are kept for serving during maintenance
+pub const STALE_RESPONSE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Phrases of the maintenance and read-only pages of Jira and its proxies,
+/// matched without regard to case
+const MAINTENANCE_MARKERS: [&str; 5] = [
+    "maintenance",
+    "read-only mode",
+    "read only mode",
+    "upgrade in progress",
+    "jira is being upgraded",
+];
+
+/// Open maintenance windows by site URL
+static WINDOWS: LazyLock<RwLock<HashMap<String, MaintenanceWindow>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Last successful GET responses by URL
+static STALE_RESPONSES: LazyLock<MokaCache<String, serde_json::Value>> =
+    LazyLock::new(|| MokaCache::new(2000, STALE_RESPONSE_TTL));
+
+/// A maintenance window a site is in
+#[derive(Debug, Clone, Serialize)]
+pub struct MaintenanceWindow {
+    pub since: DateTime<Utc>,
+    /// End advertised with `Retry-After`, if any
+    pub until: Option<DateTime<Utc>>,
+    /// When the next request is let through to Jira
+    #[serde(skip)]
+    next_probe: DateTime<Utc>,
+}
+
+impl MaintenanceWindow {
+    /// e.g. "Jira is in maintenance until ~2026-10-17 14:30 UTC"
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self.until {
+            Some(until) => format!(
+                "Jira is in maintenance until ~{}",
+                until.format("%Y-%m-%d %H:%M UTC")
+            ),
+            None => format!(
+                "Jira is in maintenance since {} (no end time advertised)",
+                self.since.format("%Y-%m-%d %H:%M UTC")
+            ),
+        }
+    }
+
+    /// The error requests fail with during the window
+    #[must_use]
+    pub fn error(&self) -> JiraError {
+        JiraError::HttpError {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            message: self.describe(),
+        }
+    }
+}
+
+/// `Retry-After` of `headers`, as seconds or as an HTTP date
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get("retry-after")?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = DateTime::parse_from_rfc2822(value).ok()?;
+    (at.with_timezone(&Utc) - Utc::now()).to_std().ok()
+}
+
+/// Whether a response with `status` and `headers` asks to wait so long that
+/// it can only be maintenance, which the throttle should not pause for
+#[must_use]
+pub fn is_long_retry_after(status: StatusCode, headers: &HeaderMap) -> bool {
+    status == StatusCode::SERVICE_UNAVAILABLE
+        && retry_after(headers).is_some_and(|wait| wait >= MAINTENANCE_RETRY_AFTER)
+}
+
+/// Whether a failed response is a maintenance page: a 503 with a long
+/// `Retry-After` or a body naming maintenance or read-only mode
+#[must_use]
+pub fn is_maintenance(status: StatusCode, headers: &HeaderMap, body: &str) -> bool {
+    if status != StatusCode::SERVICE_UNAVAILABLE {
+        return false;
+    }
+    let body = body.to_lowercase();
+    is_long_retry_after(status, headers)
+        || MAINTENANCE_MARKERS
+            .iter()
+            .any(|marker| body.contains(marker))
+}
+
+/// Put the site of `config` in maintenance after a response with `headers`,
+/// returning the window
+pub fn enter(config: &JiraConfig, headers: &HeaderMap) -> MaintenanceWindow {
+    let now = Utc::now();
+    let until = retry_after(headers)
+        .and_then(|wait| chrono::Duration::from_std(wait).ok())
+        .map(|wait| now + wait);
+    let probe_interval = chrono::Duration::from_std(MAINTENANCE_PROBE_INTERVAL).unwrap_or_default();
+    let mut windows = WINDOWS
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let site = config.site_url();
+    let window = MaintenanceWindow {
+        since: windows.get(&site).map_or(now, |window| window.since),
+        until,
+        next_probe: until.unwrap_or(now + probe_interval),
+    };
+    if !windows.contains_key(&site) {
+        warn!("{} ({})", window.describe(), site);
+    }
+    windows.insert(site, window.clone());
+    window
+}
+
+/// End the maintenance window of the site of `config`, if it is in one
+pub fn clear(config: &JiraConfig) {
+    if WINDOWS.read().is_ok_and(|windows| windows.is_empty()) {
+        return;
+    }
+    let site = config.site_url();
+    let ended = WINDOWS
+        .write()
+        .ok()
+        .and_then(|mut windows| windows.remove(&site));
+    if let Some(window) = ended {
+        info!(
+            "Jira maintenance of {} since {} is over",
+            site,
+            window.since.format("%Y-%m-%d %H:%M UTC")
+        );
+    }
+}
+
+/// The maintenance window the site of `config` is in, if any
+#[must_use]
+pub fn current(config: &JiraConfig) -> Option<MaintenanceWindow> {
+    WINDOWS
+        .read()
+        .ok()
+        .and_then(|windows| windows.get(&config.site_url()).cloned())
+}
+
+/// The window a request to the site of `config` should not be sent during,
+/// or `None` when it may be sent. Once a probe is due one request is let
+/// through, and the next probe is scheduled.
+#[must_use]
+pub fn blocking(config: &JiraConfig) -> Option<MaintenanceWindow> {
+    if WINDOWS.read().is_ok_and(|windows| windows.is_empty()) {
+        return None;
+    }
+    let mut windows = WINDOWS.write().ok()?;
+    let window = windows.get_mut(&config.site_url())?;
+    let now = Utc::now();
+    if now >= window.next_probe {
+        window.next_probe =
+            now + chrono::Duration::from_std(MAINTENANCE_PROBE_INTERVAL).unwrap_or_default();
+        return None;
+    }
+    Some(window.clone())
+}
+
+/// Keep the successful GET response `body` of `url` for maintenance windows
+pub async fn keep_response(url: &str, body: &serde_json::Value) {
+    STALE_RESPONSES.insert(url.to_string(), body.clone()).await;
+}
+
+/// The last kept response of `url`, if any
+pub async fn stale_response(url: &str) -> Option<serde_json::Value> {
+    STALE_RESPONSES.get(&url.to_string()).await
+}
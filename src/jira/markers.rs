@@ -0,0 +1,169 @@
+//! Markers that make what this server writes identifiable in Jira itself.
+//!
+//! With `auto_label` set, every issue created or cloned gets that label; with
+//! `comment_signature` set, every comment and work log comment ends with that
+//! line. Both are applied by the client's write paths, so tools cannot skip
+//! them, and applying a marker twice leaves a single copy.
+
+use crate::jira::client::JiraClient;
+use serde_json::{json, Value};
+
+/// `issue_data` (a create request) with `label` added to its labels. Labels
+/// set with verbs under `update` get an `add`; otherwise the label joins
+/// `fields.labels`.
+#[must_use]
+pub fn with_label(issue_data: &Value, label: &str) -> Value {
+    let mut issue_data = issue_data.clone();
+    if let Some(operations) = issue_data
+        .pointer_mut("/update/labels")
+        .and_then(Value::as_array_mut)
+    {
+        let added = operations
+            .iter()
+            .any(|op| op.get("add").and_then(Value::as_str) == Some(label));
+        if !added {
+            operations.push(json!({ "add": label }));
+        }
+        return issue_data;
+    }
+
+    if !issue_data.get("fields").is_some_and(Value::is_object) {
+        issue_data["fields"] = json!({});
+    }
+    let fields = &mut issue_data["fields"];
+    match fields.get_mut("labels").and_then(Value::as_array_mut) {
+        Some(labels) => {
+            if !labels.iter().any(|l| l.as_str() == Some(label)) {
+                labels.push(json!(label));
+            }
+        }
+        None => fields["labels"] = json!([label]),
+    }
+    issue_data
+}
+
+/// Whether `body`, wiki markup or an ADF document, already ends with
+/// `signature`
+#[must_use]
+pub fn has_signature(body: &Value, signature: &str) -> bool {
+    match body {
+        Value::String(text) => text.trim_end().ends_with(signature),
+        Value::Object(_) => body
+            .get("content")
+            .and_then(Value::as_array)
+            .and_then(|blocks| blocks.last())
+            .map(adf_text)
+            .is_some_and(|text| text.trim_end().ends_with(signature)),
+        _ => false,
+    }
+}
+
+/// `body` with `signature` as its last line: after a blank line in wiki
+/// markup, as a paragraph of its own in an ADF document. An empty body
+/// becomes the signature alone.
+#[must_use]
+pub fn with_signature(body: &Value, signature: &str) -> Value {
+    if has_signature(body, signature) {
+        return body.clone();
+    }
+    match body {
+        Value::String(text) if text.trim().is_empty() => json!(signature),
+        Value::String(text) => json!(format!("{}\n\n{signature}", text.trim_end())),
+        Value::Object(_) => {
+            let mut document = body.clone();
+            let paragraph = json!({
+                "type": "paragraph",
+                "content": [{ "type": "text", "text": signature }]
+            });
+            match document.get_mut("content").and_then(Value::as_array_mut) {
+                Some(blocks) => blocks.push(paragraph),
+                None => document["content"] = json!([paragraph]),
+            }
+            document
+        }
+        _ => body.clone(),
+    }
+}
+
+/// The text of an ADF node and its children
+fn adf_text(node: &Value) -> String {
+    let mut text = node
+        .get("text")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string();
+    for child in node
+        .get("content")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        text.push_str(&adf_text(child));
+    }
+    text
+}
+
+impl JiraClient {
+    /// The label `auto_label` adds to created issues, if set
+    #[must_use]
+    pub fn auto_label(&self) -> Option<&str> {
+        self.config()
+            .auto_label
+            .as_deref()
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+    }
+
+    /// The line `comment_signature` ends comments with, if set
+    #[must_use]
+    pub fn comment_signature(&self) -> Option<&str> {
+        self.config()
+            .comment_signature
+            .as_deref()
+            .map(str::trim)
+            .filter(|signature| !signature.is_empty())
+    }
+
+    /// `issue_data` with the automatic label, if one is set
+    #[must_use]
+    pub fn labelled(&self, issue_data: &Value) -> Value {
+        match self.auto_label() {
+            Some(label) => with_label(issue_data, label),
+            None => issue_data.clone(),
+        }
+    }
+
+    /// `body` ending with the comment signature, if one is set
+    #[must_use]
+    pub fn signed(&self, body: &Value) -> Value {
+        match self.comment_signature() {
+            Some(signature) => with_signature(body, signature),
+            None => body.clone(),
+        }
+    }
+
+    /// The comment of a new work log, signed; with a signature set, a work
+    /// log without a comment gets the signature as its comment
+    #[must_use]
+    pub fn signed_work_log_comment(&self, comment: Option<&Value>) -> Option<Value> {
+        match (comment, self.comment_signature()) {
+            (Some(comment), _) => Some(self.signed(comment)),
+            (None, Some(signature)) => Some(json!(signature)),
+            (None, None) => None,
+        }
+    }
+
+    /// Line for tool output naming the label added to created issues
+    #[must_use]
+    pub fn label_note(&self) -> Option<String> {
+        self.auto_label()
+            .map(|label| format!("Label added: {label}"))
+    }
+
+    /// Line for tool output naming the signature added to comments
+    #[must_use]
+    pub fn signature_note(&self) -> Option<String> {
+        self.comment_signature()
+            .map(|signature| format!("Signature added: {signature}"))
+    }
+}
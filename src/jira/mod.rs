@@ -1,2 +1,40 @@
+pub mod assets;
+pub mod attribution;
+pub mod bulk_csv;
+pub mod bulk_history;
+pub mod bulk_validation;
+pub mod bundle;
 pub mod client;
+pub mod coalesce;
+pub mod conflicts;
+pub mod create_plan;
+pub mod doctor;
+pub mod epic_rollup;
+pub mod error_explain;
+pub mod events;
+pub mod field_history;
+pub mod hierarchy;
+pub mod identity;
+pub mod issue_content;
+pub mod issue_update;
+pub mod jql;
+pub mod jsm;
+pub mod link_graph;
+pub mod maintenance;
+pub mod markers;
+pub mod moved;
 pub mod operations;
+pub mod parent;
+pub mod prefetch;
+pub mod project_stats;
+pub mod sprint_report;
+pub mod telemetry;
+pub mod throttle;
+pub mod timeline;
+pub mod traceability;
+pub mod update_preview;
+pub mod urls;
+pub mod warmup;
+pub mod webhooks;
+pub mod worklogs;
+pub mod zephyr_report;
@@ -0,0 +1,72 @@
+//! Issues moved to another project keep answering to their old key, but Jira
+//! returns them under their new one. Resolving a key first lets output and
+//! endpoints use the key the issue has now.
+
+use crate::jira::client::JiraClient;
+use crate::jira::urls::path_segment;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::{debug, info};
+
+/// An issue key as requested, and the key Jira knows the issue by now
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResolvedIssueKey {
+    pub requested: String,
+    pub key: String,
+}
+
+impl ResolvedIssueKey {
+    /// `key` as returned for a request for `requested`
+    #[must_use]
+    pub fn new(requested: &str, key: &str) -> Self {
+        Self {
+            requested: requested.to_string(),
+            key: key.to_string(),
+        }
+    }
+
+    /// Whether the issue was moved since it had the requested key; keys
+    /// differing only in case are the same issue
+    #[must_use]
+    pub fn was_moved(&self) -> bool {
+        !self.requested.eq_ignore_ascii_case(&self.key)
+    }
+
+    /// Line stating that the issue was moved, empty otherwise
+    #[must_use]
+    pub fn note(&self) -> String {
+        if self.was_moved() {
+            format!("Issue {} was moved to {}\n", self.requested, self.key)
+        } else {
+            String::new()
+        }
+    }
+}
+
+impl JiraClient {
+    /// The key `issue_key` has now, following a move to another project
+    ///
+    /// If the issue cannot be read, `issue_key` is returned unchanged so that
+    /// the request it was resolved for reports the error.
+    pub async fn resolve_issue_key(&self, issue_key: &str) -> ResolvedIssueKey {
+        // No field is needed, only the key the issue is returned under
+        let endpoint = format!("issue/{}?fields=key", path_segment(issue_key));
+        match self.get::<Value>(&endpoint).await {
+            Ok(issue) => {
+                let key = issue
+                    .get("key")
+                    .and_then(Value::as_str)
+                    .unwrap_or(issue_key);
+                let resolved = ResolvedIssueKey::new(issue_key, key);
+                if resolved.was_moved() {
+                    info!("Issue {} was moved to {}", issue_key, key);
+                }
+                resolved
+            }
+            Err(e) => {
+                debug!("Could not resolve issue key {}: {}", issue_key, e);
+                ResolvedIssueKey::new(issue_key, issue_key)
+            }
+        }
+    }
+}
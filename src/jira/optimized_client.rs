@@ -101,11 +101,19 @@ impl OptimizedJiraClient {
         Ok(())
     }
 
-    /// Add a comment with optimized serialization
+    /// Add a comment with optimized serialization.
+    ///
+    /// This client does not read project types, so every comment carries the
+    /// internal `sd.public.comment` property: on Jira Service Management
+    /// projects it stays hidden from customers, and other projects ignore it.
     pub async fn add_comment(&self, issue_key: &str, comment_body: &str) -> Result<JiraComment> {
         let endpoint = format!("issue/{}/comment", issue_key);
         let comment_data = json!({
-            "body": comment_body
+            "body": comment_body,
+            "properties": [{
+                "key": crate::jira::jsm::PUBLIC_COMMENT_PROPERTY,
+                "value": {"internal": true}
+            }]
         });
         self.base_client.post_optimized(&endpoint, &comment_data).await
     }
@@ -258,6 +266,10 @@ impl OptimizedJiraClient {
                         success: false,
                         error_message: Some(format!("Task failed: {}", e)),
                         operation_type: crate::types::jira::BulkOperationType::Update,
+                        error_kind: Some(crate::error::JiraErrorKind::Other),
+                        skipped: false,
+                        previous_fields: None,
+                        moved_from: None,
                     });
                 }
             }
@@ -283,6 +295,10 @@ impl OptimizedJiraClient {
                         success: true,
                         error_message: None,
                         operation_type: operation.operation_type.clone(),
+                        error_kind: None,
+                        skipped: false,
+                        previous_fields: None,
+                        moved_from: None,
                     };
                 }
                 Err(e) => {
@@ -304,6 +320,10 @@ impl OptimizedJiraClient {
                         success: false,
                         error_message: Some(e.to_string()),
                         operation_type: operation.operation_type.clone(),
+                        error_kind: Some(e.kind()),
+                        skipped: false,
+                        previous_fields: None,
+                        moved_from: None,
                     };
                 }
             }
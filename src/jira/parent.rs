@@ -0,0 +1,163 @@
+//! Reading and changing the parent of an issue, through whichever mechanism
+//! the instance uses for it (see [`ParentMechanism`]).
+
+use crate::error::{JiraError, Result};
+use crate::jira::client::JiraClient;
+use crate::jira::hierarchy::{IssueParent, IssueTypeLevel, ParentMechanism, EPIC_LINK_FIELD_NAME};
+use crate::types::jira::JiraIssue;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// A change of an issue's parent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ParentChange {
+    pub issue_key: String,
+    /// The new parent, or `None` when the issue was detached
+    pub parent_key: Option<String>,
+    pub previous_parent_key: Option<String>,
+    /// The mechanism the change went through; `None` if there was nothing to
+    /// change
+    pub mechanism: Option<ParentMechanism>,
+}
+
+/// Key of the project in an issue's `project` field
+fn project_key(issue: &JiraIssue) -> Result<String> {
+    issue
+        .fields
+        .get("project")
+        .and_then(|p| p.get("key"))
+        .and_then(Value::as_str)
+        .map(ToString::to_string)
+        .ok_or_else(|| {
+            JiraError::api_error(&format!(
+                "Could not determine the project of issue {}",
+                issue.key
+            ))
+        })
+}
+
+fn issue_type(issue: &JiraIssue) -> IssueTypeLevel {
+    IssueTypeLevel::from_json(issue.fields.get("issuetype").unwrap_or(&Value::Null)).0
+}
+
+impl JiraClient {
+    /// Id of the instance's Epic Link custom field, if the field catalog has
+    /// one
+    pub async fn epic_link_field(&self) -> Option<String> {
+        let field = self.resolve_field(EPIC_LINK_FIELD_NAME).await.ok()?;
+        field
+            .get("id")
+            .and_then(Value::as_str)
+            .filter(|id| id.starts_with("customfield_"))
+            .map(ToString::to_string)
+    }
+
+    /// The parent of `issue`, through the `parent` field or, when that is
+    /// empty, the Epic Link field
+    pub async fn get_issue_parent(&self, issue: &JiraIssue) -> Option<IssueParent> {
+        if let Some(parent) = IssueParent::from_fields(&issue.fields, None) {
+            return Some(parent);
+        }
+        // Epic Links are keys in a custom field; without any there is no
+        // need to look the field up
+        let has_text_custom_field = issue
+            .fields
+            .iter()
+            .any(|(id, value)| id.starts_with("customfield_") && value.is_string());
+        if !has_text_custom_field {
+            return None;
+        }
+        let epic_link_field = self.epic_link_field().await?;
+        IssueParent::from_fields(&issue.fields, Some(&epic_link_field))
+    }
+
+    /// Attach `issue_key` to `parent_key`, or detach it from its parent when
+    /// `parent_key` is `None`
+    ///
+    /// The parent must be one level above the issue in the hierarchy of the
+    /// issue's project, and in the same project when the issue is a subtask
+    /// or the project is team-managed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the parent is not allowed or a subtask
+    /// would be detached, or an error if a request fails.
+    pub async fn set_issue_parent(
+        &self,
+        issue_key: &str,
+        parent_key: Option<&str>,
+    ) -> Result<ParentChange> {
+        let epic_link_field = self.epic_link_field().await;
+        let mut fields: Vec<String> = ["issuetype", "project", "parent"]
+            .map(ToString::to_string)
+            .to_vec();
+        fields.extend(epic_link_field.clone());
+        let issue = self.get_issue_fields(issue_key, &fields).await?;
+        let previous = IssueParent::from_fields(&issue.fields, epic_link_field.as_deref());
+        let child_type = issue_type(&issue);
+        let mut change = ParentChange {
+            issue_key: issue_key.to_string(),
+            parent_key: parent_key.map(ToString::to_string),
+            previous_parent_key: previous.as_ref().map(|p| p.key.clone()),
+            mechanism: None,
+        };
+
+        let Some(parent_key) = parent_key else {
+            let Some(previous) = previous else {
+                return Ok(change);
+            };
+            if child_type.subtask {
+                return Err(JiraError::validation_error(
+                    "parent_key",
+                    &format!("{issue_key} is a subtask and cannot be detached from its parent"),
+                ));
+            }
+            let update = json!({ "fields": { previous.mechanism.field_id(): null } });
+            self.update_issue(issue_key, &update).await?;
+            change.mechanism = Some(previous.mechanism);
+            return Ok(change);
+        };
+
+        let project = project_key(&issue)?;
+        let hierarchy = self.get_issue_type_hierarchy(&project).await?;
+        let parent = self
+            .get_issue_fields(
+                parent_key,
+                &["issuetype".to_string(), "project".to_string()],
+            )
+            .await?;
+        let parent_type = issue_type(&parent);
+        hierarchy.check_parent(&child_type.id, &parent_type)?;
+        let parent_project = project_key(&parent)?;
+        if (child_type.subtask || hierarchy.team_managed)
+            && !parent_project.eq_ignore_ascii_case(&project)
+        {
+            let reason = if child_type.subtask {
+                "subtasks"
+            } else {
+                "issues of team-managed projects"
+            };
+            return Err(JiraError::validation_error(
+                "parent_key",
+                &format!(
+                    "{parent_key} is in project {parent_project}, but the parent of {reason} must be in the same project ({project})"
+                ),
+            ));
+        }
+
+        let mechanism = ParentMechanism::choose(
+            &hierarchy,
+            &child_type,
+            &parent_type,
+            epic_link_field.as_deref(),
+        );
+        let value = match mechanism {
+            ParentMechanism::ParentField => json!({"key": parent_key}),
+            ParentMechanism::EpicLink { .. } => json!(parent_key),
+        };
+        let update = json!({ "fields": { mechanism.field_id(): value } });
+        self.update_issue(issue_key, &update).await?;
+        change.mechanism = Some(mechanism);
+        Ok(change)
+    }
+}
@@ -0,0 +1,456 @@
+//! Optional background prefetch of the issues fetched most often
+//! (`JIRA_PREFETCH_ENABLED=true`).
+//!
+//! Sessions tend to read the same few issues over and over. The metrics
+//! count how often each issue is fetched with `get_issue`; once an issue has
+//! been fetched more than `prefetch_threshold` times it is kept warm: its
+//! last response is served from memory, and a background task fetches it
+//! again every `prefetch_interval_seconds`, queued behind every interactive
+//! request. Any change this server sends to an issue drops its copy, and a
+//! change whose endpoint names no issue (links, sprints, Tempo work logs)
+//! drops them all, so the next read goes to Jira.
+//!
+//! The schedule is worked out from the `now` each method is given, so it can
+//! be checked without waiting.
+
+use crate::config::JiraConfig;
+use crate::error::Result;
+use crate::jira::client::JiraClient;
+use crate::jira::throttle::in_background;
+use crate::jira::urls::path_segment;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// Fetches of an issue after which it is kept warm, unless configured
+pub const DEFAULT_PREFETCH_THRESHOLD: u64 = 5;
+
+/// Time between two background fetches of a warm issue, unless configured
+pub const DEFAULT_PREFETCH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Most issues kept warm at once; issues getting hot after that are not
+pub const MAX_WARM_ISSUES: usize = 50;
+
+/// Shortest wait of the refresh task between two rounds
+const MIN_REFRESH_WAIT: Duration = Duration::from_secs(1);
+
+/// An issue kept warm
+struct WarmIssue {
+    /// Fetches counted when it was last accessed
+    accesses: u64,
+    /// Last response; `None` until fetched, and after a change
+    issue: Option<Value>,
+    /// Id of the issue, so changes sent by id drop the copy of the key
+    id: Option<String>,
+    fetched_at: Option<Instant>,
+    next_refresh: Instant,
+    refreshes: u64,
+    last_error: Option<String>,
+    /// [`PrefetchState::generation`] when the issue was last invalidated, or
+    /// became warm; a fetch started before that is not stored
+    invalidated_at: u64,
+}
+
+/// Point-in-time view of a warm issue, as reported by `get_server_metrics`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WarmIssueSnapshot {
+    pub issue_key: String,
+    pub accesses: u64,
+    /// Whether a copy is held, i.e. the next read is served from memory
+    pub cached: bool,
+    /// Age of the copy
+    pub age_seconds: Option<u64>,
+    pub next_refresh_in_seconds: u64,
+    /// Background fetches so far
+    pub refreshes: u64,
+    /// Why the last background fetch failed
+    pub last_error: Option<String>,
+}
+
+/// Point-in-time view of the prefetcher, as reported by `get_server_metrics`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PrefetchSnapshot {
+    pub enabled: bool,
+    pub threshold: u64,
+    pub interval_seconds: u64,
+    /// Reads served from a warm copy
+    pub served_from_cache: u64,
+    /// Background fetches that succeeded and failed
+    pub refreshes: u64,
+    pub failed_refreshes: u64,
+    /// Copies dropped because this server changed the issue
+    pub invalidations: u64,
+    pub warm_issues: Vec<WarmIssueSnapshot>,
+}
+
+#[derive(Default)]
+struct PrefetchState {
+    warm: BTreeMap<String, WarmIssue>,
+    served_from_cache: u64,
+    refreshes: u64,
+    failed_refreshes: u64,
+    invalidations: u64,
+    /// Counts invalidations, so a fetch that started before one can tell
+    generation: u64,
+}
+
+/// Which issues are warm, their copies and when each is due to be fetched
+/// again, for one Jira instance
+pub struct Prefetcher {
+    threshold: u64,
+    interval: Duration,
+    state: Mutex<PrefetchState>,
+}
+
+impl Prefetcher {
+    /// Create a prefetcher keeping issues fetched more than `threshold`
+    /// times warm, refreshing them every `interval`
+    #[must_use]
+    pub fn new(threshold: u64, interval: Duration) -> Self {
+        Self {
+            threshold,
+            interval: interval.max(MIN_REFRESH_WAIT),
+            state: Mutex::new(PrefetchState::default()),
+        }
+    }
+
+    /// Create a prefetcher with the threshold and interval of `config`
+    #[must_use]
+    pub fn from_config(config: &JiraConfig) -> Self {
+        Self::new(
+            config
+                .prefetch_threshold
+                .unwrap_or(DEFAULT_PREFETCH_THRESHOLD),
+            config.prefetch_interval(),
+        )
+    }
+
+    /// Time between two background fetches of a warm issue
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Note that `issue_key` has been fetched `accesses` times, and start
+    /// keeping it warm once that is above the threshold. Returns whether the
+    /// issue is warm.
+    pub fn record_access(&self, issue_key: &str, accesses: u64, now: Instant) -> bool {
+        let mut state = self.lock();
+        if let Some(warm) = state.warm.get_mut(issue_key) {
+            warm.accesses = accesses;
+            return true;
+        }
+        if accesses <= self.threshold || state.warm.len() >= MAX_WARM_ISSUES {
+            return false;
+        }
+        info!(
+            "Keeping {} warm after {} fetches, refreshing every {:?}",
+            issue_key, accesses, self.interval
+        );
+        let invalidated_at = state.generation;
+        state.warm.insert(
+            issue_key.to_string(),
+            WarmIssue {
+                accesses,
+                issue: None,
+                id: None,
+                fetched_at: None,
+                next_refresh: now + self.interval,
+                refreshes: 0,
+                last_error: None,
+                invalidated_at,
+            },
+        );
+        true
+    }
+
+    /// The copy of `issue_key`, if it is warm and the copy is no older than
+    /// two intervals, so one late or failed refresh does not drop it
+    #[must_use]
+    pub fn cached(&self, issue_key: &str, now: Instant) -> Option<Value> {
+        let mut state = self.lock();
+        let warm = state.warm.get(issue_key)?;
+        let fetched_at = warm.fetched_at?;
+        if now.saturating_duration_since(fetched_at) > self.interval * 2 {
+            return None;
+        }
+        let issue = warm.issue.clone()?;
+        state.served_from_cache += 1;
+        Some(issue)
+    }
+
+    /// The invalidation generation to hand to [`Self::store`] with the
+    /// response of a fetch starting now
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.lock().generation
+    }
+
+    /// Keep `issue` as the copy of `issue_key` fetched at `now`, if the issue
+    /// is warm, and schedule its next refresh an interval later.
+    ///
+    /// `generation` is [`Self::generation`] from before the fetch started;
+    /// if the issue has been invalidated since, the response may predate the
+    /// change and is not kept. Returns whether it was.
+    pub fn store(&self, issue_key: &str, issue: &Value, generation: u64, now: Instant) -> bool {
+        let mut state = self.lock();
+        let Some(warm) = state.warm.get_mut(issue_key) else {
+            return false;
+        };
+        if warm.invalidated_at > generation {
+            debug!(
+                "Not keeping the copy of {} fetched before a change to it",
+                issue_key
+            );
+            return false;
+        }
+        warm.id = issue.get("id").and_then(Value::as_str).map(String::from);
+        warm.issue = Some(issue.clone());
+        warm.fetched_at = Some(now);
+        warm.next_refresh = now + self.interval;
+        warm.last_error = None;
+        true
+    }
+
+    /// Warm issues whose refresh is due at `now`, longest overdue first
+    #[must_use]
+    pub fn due(&self, now: Instant) -> Vec<String> {
+        let state = self.lock();
+        let mut due: Vec<(&String, Instant)> = state
+            .warm
+            .iter()
+            .filter(|(_, warm)| warm.next_refresh <= now)
+            .map(|(key, warm)| (key, warm.next_refresh))
+            .collect();
+        due.sort_by_key(|(_, next_refresh)| *next_refresh);
+        due.into_iter().map(|(key, _)| key.clone()).collect()
+    }
+
+    /// How long after `now` the next refresh is due; `None` without warm
+    /// issues
+    #[must_use]
+    pub fn next_wait(&self, now: Instant) -> Option<Duration> {
+        self.lock()
+            .warm
+            .values()
+            .map(|warm| warm.next_refresh.saturating_duration_since(now))
+            .min()
+    }
+
+    /// Record that refreshing `issue_key` at `now` failed; it is tried again
+    /// an interval later
+    pub fn refresh_failed(&self, issue_key: &str, error: String, now: Instant) {
+        let mut state = self.lock();
+        state.failed_refreshes += 1;
+        if let Some(warm) = state.warm.get_mut(issue_key) {
+            warm.last_error = Some(error);
+            warm.next_refresh = now + self.interval;
+        }
+    }
+
+    /// Record a successful background fetch of `issue_key` at `now`, started
+    /// at `generation` as for [`Self::store`]. A response overtaken by a
+    /// change is dropped, and the issue stays due. Returns whether it was
+    /// kept.
+    pub fn refreshed(&self, issue_key: &str, issue: &Value, generation: u64, now: Instant) -> bool {
+        if !self.store(issue_key, issue, generation, now) {
+            return false;
+        }
+        let mut state = self.lock();
+        state.refreshes += 1;
+        if let Some(warm) = state.warm.get_mut(issue_key) {
+            warm.refreshes += 1;
+        }
+        true
+    }
+
+    /// Drop the copy of the issue with key or id `issue`. Returns whether
+    /// there was one.
+    pub fn invalidate(&self, issue: &str) -> bool {
+        let mut state = self.lock();
+        state.generation += 1;
+        let generation = state.generation;
+        let mut dropped = 0;
+        for (key, warm) in &mut state.warm {
+            let matches = key.eq_ignore_ascii_case(issue) || warm.id.as_deref() == Some(issue);
+            if !matches {
+                continue;
+            }
+            // Also for issues without a copy, whose first fetch may be
+            // under way
+            warm.invalidated_at = generation;
+            if warm.issue.take().is_some() {
+                debug!("Dropped the warm copy of {} after a change", key);
+                warm.fetched_at = None;
+                dropped += 1;
+            }
+        }
+        state.invalidations += dropped;
+        dropped > 0
+    }
+
+    /// Drop every copy, for a change that may touch any issue. Returns how
+    /// many there were.
+    pub fn invalidate_all(&self) -> usize {
+        let mut state = self.lock();
+        state.generation += 1;
+        let generation = state.generation;
+        let mut dropped = 0;
+        for warm in state.warm.values_mut() {
+            warm.invalidated_at = generation;
+            if warm.issue.take().is_some() {
+                warm.fetched_at = None;
+                dropped += 1;
+            }
+        }
+        state.invalidations += dropped as u64;
+        dropped
+    }
+
+    /// Snapshot the prefetcher at `now` for reporting
+    #[must_use]
+    pub fn snapshot(&self, enabled: bool, now: Instant) -> PrefetchSnapshot {
+        let state = self.lock();
+        PrefetchSnapshot {
+            enabled,
+            threshold: self.threshold,
+            interval_seconds: self.interval.as_secs(),
+            served_from_cache: state.served_from_cache,
+            refreshes: state.refreshes,
+            failed_refreshes: state.failed_refreshes,
+            invalidations: state.invalidations,
+            warm_issues: state
+                .warm
+                .iter()
+                .map(|(key, warm)| WarmIssueSnapshot {
+                    issue_key: key.clone(),
+                    accesses: warm.accesses,
+                    cached: warm.issue.is_some(),
+                    age_seconds: warm
+                        .fetched_at
+                        .map(|fetched_at| now.saturating_duration_since(fetched_at).as_secs()),
+                    next_refresh_in_seconds: warm
+                        .next_refresh
+                        .saturating_duration_since(now)
+                        .as_secs(),
+                    refreshes: warm.refreshes,
+                    last_error: warm.last_error.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, PrefetchState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+static SHARED_PREFETCHERS: LazyLock<Mutex<HashMap<String, Arc<Prefetcher>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Get the prefetcher shared by every client talking to the configured Jira
+/// instance
+#[must_use]
+pub fn shared_prefetcher(config: &JiraConfig) -> Arc<Prefetcher> {
+    SHARED_PREFETCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .entry(config.api_base_url.clone())
+        .or_insert_with(|| Arc::new(Prefetcher::from_config(config)))
+        .clone()
+}
+
+/// Status of the prefetcher of the configured Jira instance for
+/// `get_server_metrics`
+#[must_use]
+pub fn status(config: &JiraConfig) -> PrefetchSnapshot {
+    let enabled = config.prefetch_enabled.unwrap_or(false);
+    let existing = SHARED_PREFETCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get(&config.api_base_url)
+        .cloned();
+    existing
+        .unwrap_or_else(|| Arc::new(Prefetcher::from_config(config)))
+        .snapshot(enabled, Instant::now())
+}
+
+/// Drop the warm copies a change sent to `endpoint` may have made stale:
+/// the copy of the issue it names, or every copy if it names none
+pub fn invalidate_endpoint(config: &JiraConfig, endpoint: &str) {
+    if !config.prefetch_enabled.unwrap_or(false) {
+        return;
+    }
+    let prefetcher = shared_prefetcher(config);
+    match endpoint
+        .strip_prefix("issue/")
+        .and_then(|rest| rest.split(['/', '?']).next())
+        .filter(|key| !key.is_empty() && *key != "bulk")
+    {
+        Some(issue) => {
+            prefetcher.invalidate(issue);
+        }
+        None => {
+            prefetcher.invalidate_all();
+        }
+    }
+}
+
+impl JiraClient {
+    /// Fetch the issues of `prefetcher` that are due at `now` again, one at a
+    /// time behind any interactive request. Returns how many copies were kept.
+    pub async fn refresh_warm_issues(&self, prefetcher: &Prefetcher, now: Instant) -> usize {
+        let mut refreshed = 0;
+        for issue_key in prefetcher.due(now) {
+            let endpoint = format!("issue/{}", path_segment(&issue_key));
+            let generation = prefetcher.generation();
+            let result: Result<Value> = in_background(self.get(&endpoint)).await;
+            match result {
+                Ok(issue) => {
+                    if prefetcher.refreshed(&issue_key, &issue, generation, now) {
+                        refreshed += 1;
+                    }
+                }
+                Err(e) => {
+                    warn!("Cannot refresh warm issue {}: {}", issue_key, e);
+                    prefetcher.refresh_failed(&issue_key, e.to_string(), now);
+                }
+            }
+        }
+        refreshed
+    }
+}
+
+/// Refresh the warm issues of the configured Jira instance in the
+/// background, if `prefetch_enabled` is set
+#[must_use]
+pub fn spawn_prefetcher(config: &JiraConfig) -> Option<tokio::task::JoinHandle<()>> {
+    if !config.prefetch_enabled.unwrap_or(false) {
+        return None;
+    }
+    let client = match JiraClient::new(config.clone()) {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Cannot prefetch issues: {}", e);
+            return None;
+        }
+    };
+    let prefetcher = shared_prefetcher(config);
+    Some(tokio::spawn(async move {
+        loop {
+            client
+                .refresh_warm_issues(&prefetcher, Instant::now())
+                .await;
+            let wait = prefetcher
+                .next_wait(Instant::now())
+                .unwrap_or_else(|| prefetcher.interval())
+                .max(MIN_REFRESH_WAIT);
+            tokio::time::sleep(wait).await;
+        }
+    }))
+}
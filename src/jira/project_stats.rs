@@ -0,0 +1,238 @@
+//! Project issue statistics built from JQL counts.
+//!
+//! Counts come from searches with `maxResults=0`, which return only the
+//! `total`, so no issue bodies are transferred. Only the time-to-resolution
+//! and assignee figures need issue data, and those come from a single search
+//! bounded by [`RESOLUTION_SAMPLE_SIZE`].
+
+use crate::jira::jql::Jql;
+use crate::types::jira::{JiraIssue, JiraUser};
+use crate::utils::datetime::parse_timestamp;
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Weeks in the created/resolved series when no window is given
+pub const DEFAULT_WEEKS: usize = 8;
+/// Most weeks in the created/resolved series; longer windows show the last ones
+pub const MAX_WEEKS: usize = 12;
+/// Resolved issues fetched for time-to-resolution and assignee figures
+pub const RESOLUTION_SAMPLE_SIZE: i32 = 200;
+/// Assignees listed in the report
+pub const TOP_ASSIGNEES: usize = 5;
+
+/// Status categories as named in JQL
+pub const STATUS_CATEGORIES: [&str; 3] = ["To Do", "In Progress", "Done"];
+
+/// Optional date window, inclusive on both ends
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatsWindow {
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+}
+
+impl StatsWindow {
+    /// JQL clauses restricting `field` (e.g. `created`) to the window
+    #[must_use]
+    pub fn clauses(&self, field: &str) -> Vec<String> {
+        let mut jql = Jql::new();
+        if let Some(since) = self.since {
+            jql = jql.field_gte(field, &since.to_string());
+        }
+        if let Some(until) = self.until {
+            jql = jql.field_lt(field, &(until + Duration::days(1)).to_string());
+        }
+        jql.clauses().to_vec()
+    }
+
+    /// Mondays starting the weeks of the created/resolved series.
+    ///
+    /// Covers the window, or the last [`DEFAULT_WEEKS`] weeks up to `today` if
+    /// it has no start, keeping at most the last [`MAX_WEEKS`].
+    #[must_use]
+    pub fn weeks(&self, today: NaiveDate) -> Vec<NaiveDate> {
+        let monday = |date: NaiveDate| {
+            date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+        };
+        let last = monday(self.until.unwrap_or(today));
+        let first = self
+            .since
+            .map_or_else(|| last - Duration::weeks(DEFAULT_WEEKS as i64 - 1), monday);
+
+        let weeks: Vec<NaiveDate> = first
+            .iter_weeks()
+            .take_while(|week| *week <= last)
+            .collect();
+        weeks[weeks.len().saturating_sub(MAX_WEEKS)..].to_vec()
+    }
+
+    fn describe(&self) -> String {
+        match (self.since, self.until) {
+            (None, None) => "all time".to_string(),
+            (Some(since), None) => format!("created since {since}"),
+            (None, Some(until)) => format!("created until {until}"),
+            (Some(since), Some(until)) => format!("created {since} to {until}"),
+        }
+    }
+}
+
+/// JQL for the issues of `project` matching all `clauses`
+#[must_use]
+pub fn project_jql(project: &str, clauses: &[String]) -> String {
+    clauses
+        .iter()
+        .fold(Jql::new().project(project), |jql, clause| {
+            jql.raw(clause.clone())
+        })
+        .build()
+}
+
+/// A named count, e.g. issues of one type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CountEntry {
+    pub name: String,
+    pub count: u64,
+}
+
+/// Issues created and resolved in the week starting `week_start`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WeekCounts {
+    pub week_start: NaiveDate,
+    pub created: u64,
+    pub resolved: u64,
+}
+
+/// Median hours from creation to resolution of the resolved issues in
+/// `issues`, or `None` if none of them are resolved
+#[must_use]
+pub fn median_resolution_hours(issues: &[JiraIssue]) -> Option<f64> {
+    let timestamp = |issue: &JiraIssue, field: &str| {
+        issue
+            .fields
+            .get(field)
+            .and_then(|v| v.as_str())
+            .and_then(parse_timestamp)
+    };
+    let mut hours: Vec<f64> = issues
+        .iter()
+        .filter_map(|issue| {
+            let created = timestamp(issue, "created")?;
+            let resolved = timestamp(issue, "resolutiondate")?;
+            #[allow(clippy::cast_precision_loss)]
+            Some((resolved - created).num_minutes() as f64 / 60.0)
+        })
+        .collect();
+    if hours.is_empty() {
+        return None;
+    }
+
+    hours.sort_by(f64::total_cmp);
+    let middle = hours.len() / 2;
+    Some(if hours.len().is_multiple_of(2) {
+        (hours[middle - 1] + hours[middle]) / 2.0
+    } else {
+        hours[middle]
+    })
+}
+
+/// Assignees with the most issues in `issues`, most first
+#[must_use]
+pub fn top_assignees(issues: &[JiraIssue], limit: usize) -> Vec<CountEntry> {
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    for issue in issues {
+        let name = issue
+            .fields
+            .get("assignee")
+            .filter(|a| !a.is_null())
+            .map_or_else(|| "Unassigned".to_string(), JiraUser::label_of);
+        *counts.entry(name).or_default() += 1;
+    }
+
+    let mut entries: Vec<CountEntry> = counts
+        .into_iter()
+        .map(|(name, count)| CountEntry { name, count })
+        .collect();
+    entries.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    entries.truncate(limit);
+    entries
+}
+
+/// Statistics for one project
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectStats {
+    pub project: String,
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+    pub total: u64,
+    pub by_status_category: Vec<CountEntry>,
+    pub by_issue_type: Vec<CountEntry>,
+    pub by_priority: Vec<CountEntry>,
+    pub weekly: Vec<WeekCounts>,
+    pub median_resolution_hours: Option<f64>,
+    /// Resolved issues the median and top assignees are based on
+    pub resolution_sample: usize,
+    /// Assignees with the most resolved issues in the sample
+    pub top_assignees: Vec<CountEntry>,
+    /// Requests made to Jira to compute the statistics
+    pub upstream_requests: usize,
+}
+
+impl ProjectStats {
+    /// Render the statistics as plain text
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let window = StatsWindow {
+            since: self.since,
+            until: self.until,
+        };
+        let mut out = format!(
+            "Project statistics for {} ({})\nTotal issues: {}\n",
+            self.project,
+            window.describe(),
+            self.total
+        );
+
+        for (title, entries) in [
+            ("By status category", &self.by_status_category),
+            ("By issue type", &self.by_issue_type),
+            ("By priority", &self.by_priority),
+        ] {
+            let _ = writeln!(out, "\n{title}:");
+            for entry in entries.iter().filter(|e| e.count > 0) {
+                let _ = writeln!(out, "  {}: {}", entry.name, entry.count);
+            }
+        }
+
+        out.push_str("\nCreated vs resolved per week:\n");
+        for week in &self.weekly {
+            let _ = writeln!(
+                out,
+                "  Week of {}: created {}, resolved {}",
+                week.week_start, week.created, week.resolved
+            );
+        }
+
+        match self.median_resolution_hours {
+            Some(hours) => {
+                let _ = writeln!(
+                    out,
+                    "\nMedian time to resolution: {:.1} days (over the {} most recently resolved issues)",
+                    hours / 24.0,
+                    self.resolution_sample
+                );
+            }
+            None => out.push_str("\nMedian time to resolution: no resolved issues\n"),
+        }
+
+        if !self.top_assignees.is_empty() {
+            out.push_str("Top assignees by resolved issues:\n");
+            for entry in &self.top_assignees {
+                let _ = writeln!(out, "  {}: {}", entry.name, entry.count);
+            }
+        }
+
+        let _ = write!(out, "\nUpstream requests: {}", self.upstream_requests);
+        out
+    }
+}
@@ -0,0 +1,359 @@
+//! Sprint reports: what a sprint committed to, what it finished and what was
+//! added after it started.
+//!
+//! Issues are classified from the Agile API sprint issue list (with
+//! `expand=changelog`) so the numbers can be computed without further calls.
+//! Issues removed from the sprint before it ended no longer appear in that
+//! list and are therefore not counted.
+
+use crate::types::jira::JiraSprint;
+use crate::utils::datetime::{parse_timestamp, DateFormatter};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Field names used for story points, in order of preference
+pub const STORY_POINTS_FIELD_NAMES: [&str; 2] = ["Story Points", "Story point estimate"];
+
+/// Fields requested for each sprint issue, besides the story points field
+pub const SPRINT_REPORT_FIELDS: [&str; 6] = [
+    "summary",
+    "status",
+    "assignee",
+    "created",
+    "resolutiondate",
+    "issuetype",
+];
+
+/// Find the story points custom field in the field catalog
+#[must_use]
+pub fn detect_story_points_field(catalog: &[Value]) -> Option<String> {
+    STORY_POINTS_FIELD_NAMES.iter().find_map(|wanted| {
+        catalog
+            .iter()
+            .find(|field| {
+                field
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .is_some_and(|name| name.eq_ignore_ascii_case(wanted))
+            })
+            .and_then(|field| field.get("id").and_then(Value::as_str))
+            .map(String::from)
+    })
+}
+
+/// One issue of the sprint, classified for the report
+#[derive(Debug, Clone, PartialEq)]
+pub struct SprintIssue {
+    pub key: String,
+    pub summary: String,
+    pub assignee: Option<String>,
+    pub points: Option<f64>,
+    /// Done by the time the sprint was completed (or now, for open sprints)
+    pub completed: bool,
+    /// Added to the sprint after it started
+    pub added_mid_sprint: bool,
+}
+
+impl SprintIssue {
+    /// Classify an issue from the sprint issue list
+    #[must_use]
+    pub fn from_json(issue: &Value, sprint: &JiraSprint, points_field: Option<&str>) -> Self {
+        let fields = issue.get("fields").unwrap_or(&Value::Null);
+        let text = |pointer: &str| fields.pointer(pointer).and_then(Value::as_str);
+
+        let done = text("/status/statusCategory/key") == Some("done");
+        let resolved_in_time = match (
+            sprint.complete_date.as_deref().and_then(parse_timestamp),
+            text("/resolutiondate").and_then(parse_timestamp),
+        ) {
+            (Some(completed_at), Some(resolved_at)) => resolved_at <= completed_at,
+            _ => true,
+        };
+
+        Self {
+            key: issue
+                .get("key")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            summary: text("/summary").unwrap_or_default().to_string(),
+            assignee: text("/assignee/displayName").map(String::from),
+            points: points_field
+                .and_then(|field| fields.get(field))
+                .and_then(Value::as_f64),
+            completed: done && resolved_in_time,
+            added_mid_sprint: Self::added_after_start(issue, sprint),
+        }
+    }
+
+    /// Whether the issue joined the sprint after it started, judged by the last
+    /// changelog entry adding it to the sprint, or by its creation date if it
+    /// was created straight into the sprint
+    fn added_after_start(issue: &Value, sprint: &JiraSprint) -> bool {
+        let Some(started) = sprint
+            .activated_date
+            .as_deref()
+            .or(sprint.start_date.as_deref())
+            .and_then(parse_timestamp)
+        else {
+            return false;
+        };
+
+        let sprint_id = sprint.id.to_string();
+        let contains_sprint = |ids: Option<&str>| {
+            ids.unwrap_or_default()
+                .split(',')
+                .any(|id| id.trim() == sprint_id)
+        };
+
+        let histories = issue
+            .pointer("/changelog/histories")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let last_added = histories
+            .iter()
+            .filter(|history| {
+                history
+                    .get("items")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .any(|item| {
+                        item.get("field").and_then(Value::as_str) == Some("Sprint")
+                            && contains_sprint(item.get("to").and_then(Value::as_str))
+                            && !contains_sprint(item.get("from").and_then(Value::as_str))
+                    })
+            })
+            .filter_map(|history| history.get("created").and_then(Value::as_str))
+            .filter_map(parse_timestamp)
+            .max();
+
+        let joined = last_added.or_else(|| {
+            issue
+                .pointer("/fields/created")
+                .and_then(Value::as_str)
+                .and_then(parse_timestamp)
+        });
+        joined.is_some_and(|joined| joined > started)
+    }
+}
+
+/// Per-assignee totals
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssigneeTotals {
+    pub assignee: String,
+    pub issues: usize,
+    pub completed: usize,
+    pub points: f64,
+    pub completed_points: f64,
+}
+
+/// Aggregated view of one sprint
+#[derive(Debug, Clone)]
+pub struct SprintReport {
+    pub sprint: JiraSprint,
+    pub issues: Vec<SprintIssue>,
+    /// Story points field used, if any was configured or detected
+    pub points_field: Option<String>,
+}
+
+impl SprintReport {
+    #[must_use]
+    pub fn new(sprint: JiraSprint, issues: Vec<SprintIssue>, points_field: Option<String>) -> Self {
+        Self {
+            sprint,
+            issues,
+            points_field,
+        }
+    }
+
+    /// Issues that were in the sprint when it started
+    pub fn committed(&self) -> impl Iterator<Item = &SprintIssue> {
+        self.issues.iter().filter(|i| !i.added_mid_sprint)
+    }
+
+    /// Issues added after the sprint started
+    pub fn added(&self) -> impl Iterator<Item = &SprintIssue> {
+        self.issues.iter().filter(|i| i.added_mid_sprint)
+    }
+
+    pub fn completed(&self) -> impl Iterator<Item = &SprintIssue> {
+        self.issues.iter().filter(|i| i.completed)
+    }
+
+    /// Issues not done by the end of the sprint, which carry over
+    pub fn not_completed(&self) -> impl Iterator<Item = &SprintIssue> {
+        self.issues.iter().filter(|i| !i.completed)
+    }
+
+    /// Story points of `issues`; unestimated issues count as zero
+    pub fn points<'a>(issues: impl Iterator<Item = &'a SprintIssue>) -> f64 {
+        issues.filter_map(|i| i.points).sum()
+    }
+
+    /// Completed story points
+    #[must_use]
+    pub fn velocity(&self) -> f64 {
+        Self::points(self.completed())
+    }
+
+    /// Share of committed points that were completed, as a percentage
+    #[must_use]
+    pub fn commitment_completion_percent(&self) -> Option<f64> {
+        let committed = Self::points(self.committed());
+        (committed > 0.0)
+            .then(|| Self::points(self.committed().filter(|i| i.completed)) / committed * 100.0)
+    }
+
+    /// Totals per assignee, sorted by name with unassigned issues last
+    #[must_use]
+    pub fn by_assignee(&self) -> Vec<AssigneeTotals> {
+        let mut totals: BTreeMap<(bool, String), AssigneeTotals> = BTreeMap::new();
+        for issue in &self.issues {
+            let name = issue.assignee.clone();
+            let entry = totals
+                .entry((name.is_none(), name.clone().unwrap_or_default()))
+                .or_insert_with(|| AssigneeTotals {
+                    assignee: name.unwrap_or_else(|| "Unassigned".to_string()),
+                    issues: 0,
+                    completed: 0,
+                    points: 0.0,
+                    completed_points: 0.0,
+                });
+            let points = issue.points.unwrap_or_default();
+            entry.issues += 1;
+            entry.points += points;
+            if issue.completed {
+                entry.completed += 1;
+                entry.completed_points += points;
+            }
+        }
+        totals.into_values().collect()
+    }
+
+    /// Render the report as Markdown, with the sprint dates written by `dates`
+    #[must_use]
+    pub fn to_markdown(&self, dates: &DateFormatter) -> String {
+        let with_points = self.points_field.is_some();
+        let mut out = String::new();
+
+        let _ = writeln!(out, "# Sprint report: {}\n", self.sprint.name);
+        let _ = writeln!(
+            out,
+            "State: {:?} | {} → {}",
+            self.sprint.state,
+            self.sprint
+                .start_date
+                .as_deref()
+                .map_or_else(|| "not started".to_string(), |d| dates.format_timestamp(d)),
+            self.sprint
+                .complete_date
+                .as_deref()
+                .or(self.sprint.end_date.as_deref())
+                .map_or_else(|| "no end date".to_string(), |d| dates.format_timestamp(d)),
+        );
+        if let Some(goal) = self.sprint.goal.as_deref().filter(|g| !g.is_empty()) {
+            let _ = writeln!(out, "Goal: {goal}");
+        }
+
+        out.push_str(if with_points {
+            "\n| | Issues | Points |\n|---|---|---|\n"
+        } else {
+            "\n| | Issues |\n|---|---|\n"
+        });
+        let rows: [(&str, Vec<&SprintIssue>); 4] = [
+            ("Committed", self.committed().collect()),
+            ("Added mid-sprint", self.added().collect()),
+            ("Completed", self.completed().collect()),
+            ("Not completed", self.not_completed().collect()),
+        ];
+        for (label, issues) in &rows {
+            if with_points {
+                let points = Self::points(issues.iter().copied());
+                let _ = writeln!(
+                    out,
+                    "| {label} | {} | {} |",
+                    issues.len(),
+                    fmt_points(points)
+                );
+            } else {
+                let _ = writeln!(out, "| {label} | {} |", issues.len());
+            }
+        }
+
+        if with_points {
+            let _ = write!(
+                out,
+                "\nVelocity: {} points completed",
+                fmt_points(self.velocity())
+            );
+            if let Some(percent) = self.commitment_completion_percent() {
+                let _ = write!(out, " ({percent:.0}% of committed points)");
+            }
+            out.push('\n');
+        } else {
+            out.push_str("\nNo story points field found; counting issues only.\n");
+        }
+
+        out.push_str("\n## Per assignee\n\n");
+        if with_points {
+            out.push_str("| Assignee | Issues | Completed | Points | Completed points |\n|---|---|---|---|---|\n");
+        } else {
+            out.push_str("| Assignee | Issues | Completed |\n|---|---|---|\n");
+        }
+        for totals in self.by_assignee() {
+            let _ = write!(
+                out,
+                "| {} | {} | {} |",
+                totals.assignee, totals.issues, totals.completed
+            );
+            if with_points {
+                let _ = write!(
+                    out,
+                    " {} | {} |",
+                    fmt_points(totals.points),
+                    fmt_points(totals.completed_points)
+                );
+            }
+            out.push('\n');
+        }
+
+        for (title, issues) in [
+            ("Completed", self.completed().collect::<Vec<_>>()),
+            (
+                "Not completed (carried over)",
+                self.not_completed().collect(),
+            ),
+            ("Added mid-sprint", self.added().collect()),
+        ] {
+            let _ = writeln!(out, "\n## {title} ({})\n", issues.len());
+            if issues.is_empty() {
+                out.push_str("None\n");
+            }
+            for issue in issues {
+                let _ = write!(out, "- {} {}", issue.key, issue.summary);
+                if let Some(points) = issue.points.filter(|_| with_points) {
+                    let _ = write!(out, " ({} pts)", fmt_points(points));
+                }
+                if let Some(assignee) = &issue.assignee {
+                    let _ = write!(out, " — {assignee}");
+                }
+                out.push('\n');
+            }
+        }
+
+        out
+    }
+}
+
+/// Points without a trailing `.0` for whole numbers
+#[must_use]
+pub fn fmt_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{points:.0}")
+    } else {
+        format!("{points:.1}")
+    }
+}
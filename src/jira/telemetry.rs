@@ -0,0 +1,187 @@
+//! Retry telemetry of the HTTP requests behind a tool call.
+//!
+//! [`track`] runs a future with telemetry switched on for the current task;
+//! `JiraClient` opens a [`RequestTracker`] for every request it sends, which
+//! adds the request's attempts and backoff to the call's [`CallTelemetry`]
+//! when dropped. Requests sent from other tasks (e.g. `tokio::spawn`) are not
+//! counted.
+
+use reqwest::StatusCode;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+tokio::task_local! {
+    static CURRENT: Arc<Mutex<CallTelemetry>>;
+}
+
+/// Attempts made for one request and the time spent waiting between them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestTelemetry {
+    pub attempts: u32,
+    pub total_backoff_ms: u64,
+    /// Status of the last failed attempt; `None` when it got no response or
+    /// no attempt failed
+    pub last_status: Option<u16>,
+}
+
+impl RequestTelemetry {
+    fn retried(&self) -> bool {
+        self.attempts > 1
+    }
+}
+
+/// Telemetry of a request as it is sent, recorded for the current tool call
+/// when dropped
+#[derive(Debug, Default)]
+pub struct RequestTracker {
+    telemetry: RequestTelemetry,
+    succeeded: bool,
+    /// Why the response came from the cache of a maintenance window
+    served_stale: Option<String>,
+}
+
+impl RequestTracker {
+    #[must_use]
+    pub fn start() -> Self {
+        Self::default()
+    }
+
+    /// Count an attempt about to be sent
+    pub fn attempt(&mut self) {
+        self.telemetry.attempts += 1;
+    }
+
+    /// Note a failed attempt: its status, or `None` when it got no response
+    pub fn failed(&mut self, status: Option<StatusCode>) {
+        self.telemetry.last_status = status.map(|status| status.as_u16());
+    }
+
+    /// Note the wait before the next attempt
+    pub fn backoff(&mut self, delay: Duration) {
+        self.telemetry.total_backoff_ms += u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+    }
+
+    /// Note that an attempt got a successful response
+    pub fn succeeded(&mut self) {
+        self.succeeded = true;
+    }
+
+    /// Note that the response was served from the cache during `maintenance`,
+    /// as described by [`MaintenanceWindow::describe`](crate::jira::maintenance::MaintenanceWindow::describe)
+    pub fn served_stale(&mut self, maintenance: String) {
+        self.succeeded = true;
+        self.served_stale = Some(maintenance);
+    }
+
+    #[must_use]
+    pub fn telemetry(&self) -> RequestTelemetry {
+        self.telemetry
+    }
+}
+
+impl Drop for RequestTracker {
+    fn drop(&mut self) {
+        let _ = CURRENT.try_with(|call| {
+            if let Ok(mut call) = call.lock() {
+                call.record(self.telemetry, self.succeeded);
+                if let Some(maintenance) = self.served_stale.take() {
+                    call.served_stale += 1;
+                    call.maintenance = Some(maintenance);
+                }
+            }
+        });
+    }
+}
+
+/// Requests made for one tool call, with those that needed retries
+#[derive(Debug, Clone, Default)]
+pub struct CallTelemetry {
+    pub requests: u32,
+    /// Requests that succeeded after more than one attempt
+    pub recovered: Vec<RequestTelemetry>,
+    /// Requests that failed after more than one attempt
+    pub gave_up: Vec<RequestTelemetry>,
+    /// Requests answered from the cache during a maintenance window
+    pub served_stale: u32,
+    /// The maintenance window of the last of them
+    pub maintenance: Option<String>,
+}
+
+impl CallTelemetry {
+    fn record(&mut self, request: RequestTelemetry, succeeded: bool) {
+        self.requests += 1;
+        if !request.retried() {
+            return;
+        }
+        if succeeded {
+            self.recovered.push(request);
+        } else {
+            self.gave_up.push(request);
+        }
+    }
+
+    /// One line for the requests that succeeded after retries, one for those
+    /// that gave up and one for those served from the cache, or `None` when
+    /// nothing was retried or served from the cache
+    #[must_use]
+    pub fn note(&self) -> Option<String> {
+        let stale = self.maintenance.as_ref().map(|maintenance| {
+            format!(
+                "{maintenance}; {} served from the last copy read before it",
+                plural(self.served_stale, "response was", "responses were")
+            )
+        });
+        let lines: Vec<String> = [
+            summarize(&self.recovered, "succeeded after"),
+            summarize(&self.gave_up, "gave up after"),
+            stale,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+}
+
+/// e.g. "Request succeeded after 3 attempts, 4.2s in backoff (last failure:
+/// HTTP 502)"
+fn summarize(requests: &[RequestTelemetry], outcome: &str) -> Option<String> {
+    let last = requests.last()?;
+    let attempts: u32 = requests.iter().map(|request| request.attempts).sum();
+    let backoff_ms: u64 = requests
+        .iter()
+        .map(|request| request.total_backoff_ms)
+        .sum();
+    #[allow(clippy::cast_precision_loss)]
+    let backoff = backoff_ms as f64 / 1000.0;
+    let failure = last.last_status.map_or_else(
+        || "no response".to_string(),
+        |status| format!("HTTP {status}"),
+    );
+    let subject = if requests.len() == 1 {
+        "Request".to_string()
+    } else {
+        format!("{} requests", requests.len())
+    };
+    Some(format!(
+        "{subject} {outcome} {attempts} attempts, {backoff:.1}s in backoff (last failure: {failure})"
+    ))
+}
+
+fn plural(count: u32, one: &str, many: &str) -> String {
+    if count == 1 {
+        format!("1 {one}")
+    } else {
+        format!("{count} {many}")
+    }
+}
+
+/// Run `future` with retry telemetry switched on for the current task,
+/// returning its output and the telemetry of the requests it made
+pub async fn track<F: Future>(future: F) -> (F::Output, CallTelemetry) {
+    let call = Arc::new(Mutex::new(CallTelemetry::default()));
+    let output = CURRENT.scope(call.clone(), future).await;
+    let telemetry = call.lock().map(|call| call.clone()).unwrap_or_default();
+    (output, telemetry)
+}
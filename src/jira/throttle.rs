@@ -0,0 +1,598 @@
+use crate::config::JiraConfig;
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+use tracing::{debug, info, warn};
+
+/// Spacing between requests when Jira reports plenty of budget (10 requests per second)
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Base rate of each request class unless configured otherwise
+pub const DEFAULT_REQUESTS_PER_SECOND: f64 = 10.0;
+
+/// Slowest spacing the throttle will back off to while budget is scarce
+pub const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Remaining budget (as a percentage of the limit) below which requests are slowed down
+pub const DEFAULT_THRESHOLD_PERCENT: u32 = 20;
+
+/// Coarse state of the adaptive throttle, logged whenever it changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThrottleState {
+    /// Requests are sent at the base rate
+    Normal,
+    /// Remaining budget is low, or recovering from a low budget
+    Throttled,
+    /// Jira asked us to back off with `Retry-After`
+    BackingOff,
+}
+
+/// Rate-limit information parsed from a Jira response
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitHeaders {
+    pub limit: Option<u64>,
+    pub remaining: Option<u64>,
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitHeaders {
+    /// Parse `X-RateLimit-Limit`, `X-RateLimit-Remaining` and `Retry-After` headers.
+    ///
+    /// Missing or malformed headers are left as `None`.
+    #[must_use]
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let parse = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+
+        Self {
+            limit: parse("x-ratelimit-limit"),
+            remaining: parse("x-ratelimit-remaining"),
+            retry_after: parse("retry-after").map(Duration::from_secs),
+        }
+    }
+}
+
+/// Point-in-time view of a throttle, as reported by `get_server_metrics`
+#[derive(Debug, Clone, Serialize)]
+pub struct ThrottleSnapshot {
+    pub state: ThrottleState,
+    pub interval_ms: u64,
+    pub effective_rate_per_second: f64,
+    pub remaining: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+struct ThrottleInner {
+    interval: Duration,
+    state: ThrottleState,
+    next_slot: Option<Instant>,
+    paused_until: Option<Instant>,
+    remaining: Option<u64>,
+    limit: Option<u64>,
+}
+
+/// Request spacing that adapts to the rate-limit budget Jira reports.
+///
+/// Each response feeds [`AdaptiveThrottle::observe`]. While the remaining budget
+/// is above the threshold, requests are spaced by the minimum interval. Below it
+/// the interval grows linearly towards the maximum as the budget runs out, and a
+/// `Retry-After` pauses all requests until it has elapsed. Once the budget
+/// recovers the interval is halved on each response until it is back at the
+/// minimum.
+pub struct AdaptiveThrottle {
+    inner: Mutex<ThrottleInner>,
+    min_interval: Duration,
+    max_interval: Duration,
+    threshold: f64,
+}
+
+impl AdaptiveThrottle {
+    /// Create a throttle spacing requests between `min_interval` and `max_interval`.
+    #[must_use]
+    pub fn new(min_interval: Duration, max_interval: Duration, threshold_percent: u32) -> Self {
+        Self {
+            inner: Mutex::new(ThrottleInner {
+                interval: min_interval,
+                state: ThrottleState::Normal,
+                next_slot: None,
+                paused_until: None,
+                remaining: None,
+                limit: None,
+            }),
+            min_interval,
+            max_interval: max_interval.max(min_interval),
+            threshold: f64::from(threshold_percent.min(100)) / 100.0,
+        }
+    }
+
+    /// Create a throttle using the threshold from the configuration.
+    #[must_use]
+    pub fn from_config(config: &JiraConfig) -> Self {
+        Self::new(
+            DEFAULT_MIN_INTERVAL,
+            DEFAULT_MAX_INTERVAL,
+            config
+                .throttle_threshold_percent
+                .unwrap_or(DEFAULT_THRESHOLD_PERCENT),
+        )
+    }
+
+    /// Wait until the next request slot is available and reserve it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub async fn wait_if_needed(&self) {
+        let wait = {
+            let mut inner = self.inner.lock().unwrap();
+            let now = Instant::now();
+
+            let mut slot = inner.next_slot.map_or(now, |slot| slot.max(now));
+            if let Some(paused_until) = inner.paused_until {
+                slot = slot.max(paused_until);
+            }
+
+            inner.next_slot = Some(slot + inner.interval);
+            slot - now
+        };
+
+        if !wait.is_zero() {
+            debug!("Rate limiting: sleeping for {:?}", wait);
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Feed rate-limit headers from a response into the controller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    pub fn observe(&self, headers: &RateLimitHeaders) {
+        let mut inner = self.inner.lock().unwrap();
+        let previous = inner.state;
+
+        if headers.limit.is_some() {
+            inner.limit = headers.limit;
+        }
+        if headers.remaining.is_some() {
+            inner.remaining = headers.remaining;
+        }
+
+        if let Some(retry_after) = headers.retry_after {
+            inner.interval = self.max_interval;
+            inner.paused_until = Some(Instant::now() + retry_after);
+            inner.state = ThrottleState::BackingOff;
+        } else if let Some(ratio) = Self::budget_ratio(headers) {
+            if ratio < self.threshold {
+                let pressure = 1.0 - ratio / self.threshold;
+                inner.interval = self.min_interval
+                    + (self.max_interval - self.min_interval).mul_f64(pressure.clamp(0.0, 1.0));
+                inner.state = ThrottleState::Throttled;
+            } else {
+                self.recover(&mut inner);
+            }
+        } else {
+            self.recover(&mut inner);
+        }
+
+        if inner.state != previous {
+            let message = format!(
+                "Throttle state changed: {:?} -> {:?} (interval {:?}, remaining {:?}/{:?})",
+                previous, inner.state, inner.interval, inner.remaining, inner.limit
+            );
+            if inner.state == ThrottleState::Normal {
+                info!("{}", message);
+            } else {
+                warn!("{}", message);
+            }
+        }
+    }
+
+    /// Current spacing between requests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn current_interval(&self) -> Duration {
+        self.inner.lock().unwrap().interval
+    }
+
+    /// Current state of the controller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn state(&self) -> ThrottleState {
+        self.inner.lock().unwrap().state
+    }
+
+    /// Requests per second allowed by the current interval.
+    #[must_use]
+    pub fn effective_rate(&self) -> f64 {
+        Self::rate_for(self.current_interval())
+    }
+
+    /// Snapshot the throttle for reporting.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal mutex is poisoned.
+    #[must_use]
+    pub fn snapshot(&self) -> ThrottleSnapshot {
+        let inner = self.inner.lock().unwrap();
+        ThrottleSnapshot {
+            state: inner.state,
+            interval_ms: u64::try_from(inner.interval.as_millis()).unwrap_or(u64::MAX),
+            effective_rate_per_second: Self::rate_for(inner.interval),
+            remaining: inner.remaining,
+            limit: inner.limit,
+        }
+    }
+
+    fn rate_for(interval: Duration) -> f64 {
+        1.0 / interval.as_secs_f64().max(f64::EPSILON)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn budget_ratio(headers: &RateLimitHeaders) -> Option<f64> {
+        match (headers.remaining, headers.limit) {
+            (Some(remaining), Some(limit)) if limit > 0 => {
+                Some((remaining as f64 / limit as f64).min(1.0))
+            }
+            _ => None,
+        }
+    }
+
+    fn recover(&self, inner: &mut ThrottleInner) {
+        if inner
+            .paused_until
+            .is_some_and(|paused_until| paused_until > Instant::now())
+        {
+            return;
+        }
+        inner.paused_until = None;
+        inner.interval = (inner.interval / 2).max(self.min_interval);
+        inner.state = if inner.interval == self.min_interval {
+            ThrottleState::Normal
+        } else {
+            ThrottleState::Throttled
+        };
+    }
+}
+
+/// Family of endpoints a request belongs to. Each class has its own rate and
+/// queue, so a long Zephyr sync does not hold up issue lookups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestClass {
+    /// The Jira REST API
+    Core,
+    /// The Jira Software (`agile/1.0`) API
+    Agile,
+    /// The Zephyr (ZAPI) API
+    Zephyr,
+    /// Attachment uploads and downloads
+    Attachments,
+}
+
+impl RequestClass {
+    /// Every class, in the order metrics report them
+    pub const ALL: [Self; 4] = [Self::Core, Self::Agile, Self::Zephyr, Self::Attachments];
+
+    /// Class of a Jira endpoint (relative or absolute). Zephyr requests are
+    /// built by their own helpers, which pass [`RequestClass::Zephyr`].
+    #[must_use]
+    pub fn for_endpoint(endpoint: &str) -> Self {
+        let path = endpoint.split('?').next().unwrap_or(endpoint);
+        if path.starts_with("agile/") || path.contains("/agile/") {
+            Self::Agile
+        } else if path.contains("attachment") {
+            Self::Attachments
+        } else {
+            Self::Core
+        }
+    }
+
+    /// Configured rate of this class in requests per second
+    fn configured_rate(self, config: &JiraConfig) -> Option<f64> {
+        match self {
+            Self::Core => config.core_requests_per_second,
+            Self::Agile => config.agile_requests_per_second,
+            Self::Zephyr => config.zephyr_requests_per_second,
+            Self::Attachments => config.attachment_requests_per_second,
+        }
+    }
+}
+
+/// Which tier of a class's queue a request waits in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestPriority {
+    /// Single calls someone is waiting on; served before any background request
+    Interactive,
+    /// Bulk operations and other long runs
+    Background,
+}
+
+tokio::task_local! {
+    static PRIORITY: RequestPriority;
+}
+
+/// Run `future` with its requests queued behind interactive ones.
+pub async fn in_background<F: Future>(future: F) -> F::Output {
+    PRIORITY.scope(RequestPriority::Background, future).await
+}
+
+/// Priority of requests made by the current task: background inside
+/// [`in_background`], interactive otherwise.
+#[must_use]
+pub fn current_priority() -> RequestPriority {
+    PRIORITY
+        .try_with(|priority| *priority)
+        .unwrap_or(RequestPriority::Interactive)
+}
+
+/// Point-in-time view of a request class, as reported by `get_server_metrics`
+#[derive(Debug, Clone, Serialize)]
+pub struct SchedulerSnapshot {
+    pub class: RequestClass,
+    /// Configured base rate
+    pub requests_per_second: f64,
+    /// Interactive requests waiting for a slot
+    pub queued_interactive: usize,
+    /// Background requests waiting for a slot
+    pub queued_background: usize,
+    /// Requests that got a slot so far
+    pub requests: u64,
+    pub average_wait_ms: f64,
+    pub max_wait_ms: f64,
+    pub throttle: ThrottleSnapshot,
+}
+
+#[derive(Default)]
+struct SchedulerQueue {
+    interactive: VecDeque<u64>,
+    background: VecDeque<u64>,
+    next_ticket: u64,
+    requests: u64,
+    total_wait: Duration,
+    max_wait: Duration,
+}
+
+impl SchedulerQueue {
+    /// The request allowed to reserve the next slot
+    fn head(&self) -> Option<u64> {
+        self.interactive
+            .front()
+            .or_else(|| self.background.front())
+            .copied()
+    }
+}
+
+/// Rate limiter of one request class with a two-tier queue.
+///
+/// Requests line up in an interactive and a background queue. Only the
+/// request at the head, the first interactive one or else the first
+/// background one, reserves a slot from the class's [`AdaptiveThrottle`], so
+/// an interactive request arriving behind a stream of background ones gets
+/// the next free slot.
+pub struct RequestScheduler {
+    class: RequestClass,
+    requests_per_second: f64,
+    throttle: Arc<AdaptiveThrottle>,
+    queue: Mutex<SchedulerQueue>,
+    turn: Notify,
+}
+
+/// Place of a request in a scheduler queue, given up when dropped so a
+/// cancelled request does not hold up the ones behind it
+struct QueueTicket<'a> {
+    scheduler: &'a RequestScheduler,
+    id: u64,
+}
+
+impl Drop for QueueTicket<'_> {
+    fn drop(&mut self) {
+        let mut queue = self.scheduler.lock_queue();
+        queue.interactive.retain(|id| *id != self.id);
+        queue.background.retain(|id| *id != self.id);
+        drop(queue);
+        self.scheduler.turn.notify_waiters();
+    }
+}
+
+impl RequestScheduler {
+    /// Create a scheduler for `class` sending `requests_per_second` at most.
+    /// Rates that are not positive fall back to [`DEFAULT_REQUESTS_PER_SECOND`].
+    #[must_use]
+    pub fn new(class: RequestClass, requests_per_second: f64, threshold_percent: u32) -> Self {
+        let requests_per_second = if requests_per_second.is_finite() && requests_per_second > 0.0 {
+            requests_per_second
+        } else {
+            DEFAULT_REQUESTS_PER_SECOND
+        };
+        Self {
+            class,
+            requests_per_second,
+            throttle: Arc::new(AdaptiveThrottle::new(
+                Duration::from_secs_f64(1.0 / requests_per_second),
+                DEFAULT_MAX_INTERVAL,
+                threshold_percent,
+            )),
+            queue: Mutex::new(SchedulerQueue::default()),
+            turn: Notify::new(),
+        }
+    }
+
+    /// Class this scheduler paces
+    #[must_use]
+    pub fn class(&self) -> RequestClass {
+        self.class
+    }
+
+    /// Throttle pacing this class, fed with the responses of its requests
+    #[must_use]
+    pub fn throttle(&self) -> &Arc<AdaptiveThrottle> {
+        &self.throttle
+    }
+
+    /// Wait in the `priority` queue until this request may be sent.
+    pub async fn acquire(&self, priority: RequestPriority) {
+        let start = Instant::now();
+        let ticket = {
+            let mut queue = self.lock_queue();
+            let id = queue.next_ticket;
+            queue.next_ticket += 1;
+            match priority {
+                RequestPriority::Interactive => queue.interactive.push_back(id),
+                RequestPriority::Background => queue.background.push_back(id),
+            }
+            QueueTicket {
+                scheduler: self,
+                id,
+            }
+        };
+
+        loop {
+            // Register before checking so a turn handed over in between is not missed
+            let turn = self.turn.notified();
+            tokio::pin!(turn);
+            turn.as_mut().enable();
+            if self.lock_queue().head() == Some(ticket.id) {
+                break;
+            }
+            turn.await;
+        }
+
+        self.throttle.wait_if_needed().await;
+        drop(ticket);
+
+        let wait = start.elapsed();
+        let mut queue = self.lock_queue();
+        queue.requests += 1;
+        queue.total_wait += wait;
+        queue.max_wait = queue.max_wait.max(wait);
+    }
+
+    /// Snapshot the scheduler for reporting.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn snapshot(&self) -> SchedulerSnapshot {
+        let queue = self.lock_queue();
+        let average_wait_ms = if queue.requests == 0 {
+            0.0
+        } else {
+            queue.total_wait.as_secs_f64() * 1000.0 / queue.requests as f64
+        };
+        SchedulerSnapshot {
+            class: self.class,
+            requests_per_second: self.requests_per_second,
+            queued_interactive: queue.interactive.len(),
+            queued_background: queue.background.len(),
+            requests: queue.requests,
+            average_wait_ms,
+            max_wait_ms: queue.max_wait.as_secs_f64() * 1000.0,
+            throttle: self.throttle.snapshot(),
+        }
+    }
+
+    fn lock_queue(&self) -> std::sync::MutexGuard<'_, SchedulerQueue> {
+        self.queue
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// The schedulers of every request class for one Jira instance
+pub struct RequestSchedulers {
+    schedulers: [RequestScheduler; 4],
+}
+
+impl RequestSchedulers {
+    /// Create schedulers with the rates and threshold from the configuration.
+    #[must_use]
+    pub fn from_config(config: &JiraConfig) -> Self {
+        let threshold = config
+            .throttle_threshold_percent
+            .unwrap_or(DEFAULT_THRESHOLD_PERCENT);
+        Self {
+            schedulers: RequestClass::ALL.map(|class| {
+                RequestScheduler::new(
+                    class,
+                    class
+                        .configured_rate(config)
+                        .unwrap_or(DEFAULT_REQUESTS_PER_SECOND),
+                    threshold,
+                )
+            }),
+        }
+    }
+
+    /// Scheduler of `class`
+    #[must_use]
+    pub fn get(&self, class: RequestClass) -> &RequestScheduler {
+        &self.schedulers[class as usize]
+    }
+
+    /// Snapshot every class for reporting.
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<SchedulerSnapshot> {
+        self.schedulers
+            .iter()
+            .map(RequestScheduler::snapshot)
+            .collect()
+    }
+}
+
+static SHARED_SCHEDULERS: LazyLock<Mutex<HashMap<String, Arc<RequestSchedulers>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Get the request schedulers shared by every client talking to the
+/// configured Jira instance.
+///
+/// Jira budgets are per user and instance, so all tools must draw from the same
+/// schedulers rather than each pacing itself independently.
+#[must_use]
+pub fn shared_schedulers(config: &JiraConfig) -> Arc<RequestSchedulers> {
+    SHARED_SCHEDULERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .entry(config.api_base_url.clone())
+        .or_insert_with(|| Arc::new(RequestSchedulers::from_config(config)))
+        .clone()
+}
+
+/// Replace the request schedulers of the configured Jira instance with ones
+/// built from the rate limits of `config`, for a configuration reloaded while
+/// running. Clients created before keep pacing with the old schedulers until
+/// they are dropped.
+pub fn replace_shared_schedulers(config: &JiraConfig) {
+    SHARED_SCHEDULERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(
+            config.api_base_url.clone(),
+            Arc::new(RequestSchedulers::from_config(config)),
+        );
+}
+
+/// Get the throttle of the core request class shared by every client talking
+/// to the configured Jira instance.
+#[must_use]
+pub fn shared_throttle(config: &JiraConfig) -> Arc<AdaptiveThrottle> {
+    shared_schedulers(config)
+        .get(RequestClass::Core)
+        .throttle()
+        .clone()
+}
@@ -0,0 +1,254 @@
+//! Daily status-category counts for a set of issues, reconstructed from their
+//! changelogs for burndown-style charts.
+//!
+//! Statuses are bucketed by category (To Do / In Progress / Done) using their
+//! ids, so a status renamed part-way through the range is still counted in the
+//! right bucket. Each day is sampled at its end (UTC); issues created after
+//! that point are not counted for the day. Status changes are read from the
+//! changelog like those of any other field, see [`crate::jira::field_history`].
+
+use crate::jira::field_history::{field_changes, FieldMatcher};
+use crate::types::jira::JiraStatus;
+use crate::utils::datetime::parse_timestamp;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Status category buckets used by the timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusCategory {
+    ToDo,
+    InProgress,
+    Done,
+}
+
+impl StatusCategory {
+    /// Map a Jira status category key (`new`, `indeterminate`, `done`)
+    #[must_use]
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "new" | "undefined" => Some(Self::ToDo),
+            "indeterminate" => Some(Self::InProgress),
+            "done" => Some(Self::Done),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ToDo => "To Do",
+            Self::InProgress => "In Progress",
+            Self::Done => "Done",
+        }
+    }
+}
+
+/// Lookup from status id (and, as a fallback, name) to category
+#[derive(Debug, Clone, Default)]
+pub struct StatusCategories {
+    by_id: HashMap<String, StatusCategory>,
+    by_name: HashMap<String, StatusCategory>,
+}
+
+impl StatusCategories {
+    /// Build the lookup from the status catalog (`GET /status`)
+    #[must_use]
+    pub fn from_statuses(statuses: &[JiraStatus]) -> Self {
+        let mut lookup = Self::default();
+        for status in statuses {
+            if let Some(category) = StatusCategory::from_key(&status.status_category.key) {
+                lookup.by_id.insert(status.id.clone(), category);
+                lookup.by_name.insert(status.name.to_lowercase(), category);
+            }
+        }
+        lookup
+    }
+
+    /// Category of a status, by id first since names can change
+    #[must_use]
+    pub fn category(&self, id: Option<&str>, name: Option<&str>) -> Option<StatusCategory> {
+        id.and_then(|id| self.by_id.get(id))
+            .or_else(|| name.and_then(|name| self.by_name.get(&name.to_lowercase())))
+            .copied()
+    }
+}
+
+/// Status category history of one issue
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueHistory {
+    pub key: String,
+    pub created: DateTime<Utc>,
+    /// Category the issue was created in
+    pub initial: StatusCategory,
+    /// Category changes in chronological order
+    pub transitions: Vec<(DateTime<Utc>, StatusCategory)>,
+}
+
+impl IssueHistory {
+    /// Read an issue returned by a search with `expand=changelog`.
+    ///
+    /// Returns `None` if the issue has no parseable creation date or its status
+    /// categories cannot be determined.
+    #[must_use]
+    pub fn from_json(issue: &Value, categories: &StatusCategories) -> Option<Self> {
+        let fields = issue.get("fields")?;
+        let created = fields
+            .get("created")
+            .and_then(Value::as_str)
+            .and_then(parse_timestamp)?
+            .with_timezone(&Utc);
+        let current = fields
+            .pointer("/status/statusCategory/key")
+            .and_then(Value::as_str)
+            .and_then(StatusCategory::from_key)
+            .or_else(|| {
+                categories.category(
+                    fields.pointer("/status/id").and_then(Value::as_str),
+                    fields.pointer("/status/name").and_then(Value::as_str),
+                )
+            })?;
+
+        let histories = issue
+            .pointer("/changelog/histories")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten();
+        let changes: Vec<(
+            DateTime<Utc>,
+            Option<StatusCategory>,
+            Option<StatusCategory>,
+        )> = field_changes(histories, &FieldMatcher::new("status", "status"))
+            .into_iter()
+            .map(|change| {
+                (
+                    change.at,
+                    categories.category(change.from.as_deref(), change.from_string.as_deref()),
+                    categories.category(change.to.as_deref(), change.to_string.as_deref()),
+                )
+            })
+            .collect();
+
+        let initial = changes
+            .first()
+            .and_then(|(_, from, _)| *from)
+            .unwrap_or(current);
+        let transitions = changes
+            .into_iter()
+            .filter_map(|(at, _, to)| to.map(|to| (at, to)))
+            .collect();
+
+        Some(Self {
+            key: issue
+                .get("key")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            created,
+            initial,
+            transitions,
+        })
+    }
+
+    /// Category at instant `at`, or `None` if the issue didn't exist yet
+    #[must_use]
+    pub fn category_at(&self, at: DateTime<Utc>) -> Option<StatusCategory> {
+        if at < self.created {
+            return None;
+        }
+        Some(
+            self.transitions
+                .iter()
+                .take_while(|(changed, _)| *changed <= at)
+                .last()
+                .map_or(self.initial, |(_, category)| *category),
+        )
+    }
+}
+
+/// Issue counts per status category at the end of one day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct TimelineDay {
+    pub date: NaiveDate,
+    pub to_do: usize,
+    pub in_progress: usize,
+    pub done: usize,
+}
+
+impl TimelineDay {
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.to_do + self.in_progress + self.done
+    }
+}
+
+/// Count issues per category at the end of each day from `start` to `end`
+/// (inclusive)
+#[must_use]
+pub fn build_timeline(
+    issues: &[IssueHistory],
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Vec<TimelineDay> {
+    start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .map(|date| {
+            let end_of_day = date
+                .and_hms_milli_opt(23, 59, 59, 999)
+                .expect("valid time")
+                .and_utc();
+            let mut day = TimelineDay {
+                date,
+                to_do: 0,
+                in_progress: 0,
+                done: 0,
+            };
+            for issue in issues {
+                match issue.category_at(end_of_day) {
+                    Some(StatusCategory::ToDo) => day.to_do += 1,
+                    Some(StatusCategory::InProgress) => day.in_progress += 1,
+                    Some(StatusCategory::Done) => day.done += 1,
+                    None => {}
+                }
+            }
+            day
+        })
+        .collect()
+}
+
+/// Render the timeline as a Markdown table
+#[must_use]
+pub fn timeline_table(days: &[TimelineDay]) -> String {
+    let mut out =
+        String::from("| Date | To Do | In Progress | Done | Total |\n|---|---|---|---|---|\n");
+    for day in days {
+        let _ = writeln!(
+            out,
+            "| {} | {} | {} | {} | {} |",
+            day.date,
+            day.to_do,
+            day.in_progress,
+            day.done,
+            day.total()
+        );
+    }
+    out
+}
+
+/// The timeline as plottable series: one array of dates and one array of
+/// counts per category
+#[must_use]
+pub fn timeline_series(days: &[TimelineDay]) -> Value {
+    let series = |pick: fn(&TimelineDay) -> usize| days.iter().map(pick).collect::<Vec<_>>();
+    serde_json::json!({
+        "dates": days.iter().map(|d| d.date.to_string()).collect::<Vec<_>>(),
+        "series": {
+            StatusCategory::ToDo.label(): series(|d| d.to_do),
+            StatusCategory::InProgress.label(): series(|d| d.in_progress),
+            StatusCategory::Done.label(): series(|d| d.done),
+        }
+    })
+}
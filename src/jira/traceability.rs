@@ -0,0 +1,139 @@
+//! Coverage of requirement issues by test case issues, for questions such as
+//! "which requirements does DNA-T123 cover, and which have no tests?".
+//!
+//! The client fetches the test cases with their `issuelinks` and the
+//! requirements matched by a JQL; this module joins the two. A test covers a
+//! requirement when it is linked to it with the configured link type, read
+//! from the test's side: either the relation itself (`tests`) or the type
+//! name (`Tests`) on an outward link. Links to issues outside the requirement
+//! query are ignored.
+
+use crate::jira::link_graph::IssueLinkRef;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Requirements or test cases read for one report; queries matching more
+/// are rejected rather than reported on in part
+pub const TRACEABILITY_ISSUE_LIMIT: usize = 1000;
+
+/// A requirement and the tests covering it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RequirementCoverage {
+    pub key: String,
+    pub summary: String,
+    /// Keys of the covering tests, sorted
+    pub tests: Vec<String>,
+}
+
+/// A test case and the requirements it covers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TestCoverage {
+    pub key: String,
+    pub summary: String,
+    /// Keys of the covered requirements, sorted
+    pub requirements: Vec<String>,
+}
+
+/// Requirements joined with the tests that cover them
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TraceabilityMatrix {
+    /// Every requirement, in the order the query returned them
+    pub requirements: Vec<RequirementCoverage>,
+    /// Every test case, in the order the query returned them
+    pub tests: Vec<TestCoverage>,
+    /// Keys of the requirements no test covers
+    pub uncovered: Vec<String>,
+}
+
+impl TraceabilityMatrix {
+    /// Share of requirements covered by at least one test, in percent; 100
+    /// when there are no requirements
+    #[must_use]
+    pub fn coverage_percent(&self) -> f64 {
+        if self.requirements.is_empty() {
+            return 100.0;
+        }
+        let covered = self.requirements.len() - self.uncovered.len();
+        #[allow(clippy::cast_precision_loss)]
+        let percent = covered as f64 * 100.0 / self.requirements.len() as f64;
+        percent
+    }
+}
+
+fn key_and_summary(issue: &Value) -> Option<(String, String)> {
+    let key = issue.get("key").and_then(Value::as_str)?;
+    let summary = issue
+        .pointer("/fields/summary")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    Some((key.to_string(), summary.to_string()))
+}
+
+/// Whether `link`, read from a test case, says the test covers the linked
+/// issue with `link_type`
+#[must_use]
+pub fn is_coverage_link(link: &IssueLinkRef, link_type: &str) -> bool {
+    link.relation.eq_ignore_ascii_case(link_type)
+        || (!link.inward && link.type_name.eq_ignore_ascii_case(link_type))
+}
+
+/// Join the `requirements` with the `tests` covering them. Both are issues
+/// as JSON; the tests need their `issuelinks` field.
+#[must_use]
+pub fn build_matrix(
+    requirements: &[Value],
+    tests: &[Value],
+    link_type: &str,
+) -> TraceabilityMatrix {
+    let requirements: Vec<(String, String)> =
+        requirements.iter().filter_map(key_and_summary).collect();
+    let mut covering: BTreeMap<&str, BTreeSet<String>> = requirements
+        .iter()
+        .map(|(key, _)| (key.as_str(), BTreeSet::new()))
+        .collect();
+
+    let mut test_coverage = Vec::new();
+    for test in tests {
+        let Some((key, summary)) = key_and_summary(test) else {
+            continue;
+        };
+        let covered: BTreeSet<String> = IssueLinkRef::from_issue(test)
+            .into_iter()
+            .filter(|link| is_coverage_link(link, link_type))
+            .filter_map(|link| {
+                let tests = covering.get_mut(link.other_key.as_str())?;
+                tests.insert(key.clone());
+                Some(link.other_key)
+            })
+            .collect();
+        test_coverage.push(TestCoverage {
+            key,
+            summary,
+            requirements: covered.into_iter().collect(),
+        });
+    }
+
+    let requirements: Vec<RequirementCoverage> = requirements
+        .iter()
+        .map(|(key, summary)| RequirementCoverage {
+            key: key.clone(),
+            summary: summary.clone(),
+            tests: covering
+                .get(key.as_str())
+                .map(|tests| tests.iter().cloned().collect())
+                .unwrap_or_default(),
+        })
+        .collect();
+    let uncovered = requirements
+        .iter()
+        .filter(|requirement| requirement.tests.is_empty())
+        .map(|requirement| requirement.key.clone())
+        .collect();
+
+    TraceabilityMatrix {
+        requirements,
+        tests: test_coverage,
+        uncovered,
+    }
+}
@@ -0,0 +1,266 @@
+//! Previews of issue updates: the current and proposed value of each field an
+//! update sets, read from the issue without changing it.
+//!
+//! Values are shown the way tables show them elsewhere, with users, options
+//! and statuses by name and rich text flattened. Rich text too long for a
+//! table cell is shown in full below the table.
+
+use crate::error::Result;
+use crate::jira::client::JiraClient;
+use crate::jira::conflicts::ExpectedUpdated;
+use crate::mcp::tools::formatting::{cell_text, render_table, rich_text, TABLE_COLUMN_MAX_CHARS};
+use crate::utils::datetime::parse_timestamp;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// How the proposed value of a field differs from the current one
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FieldDiff {
+    Unchanged,
+    /// The field is empty now
+    Set,
+    /// The proposed value is empty
+    Cleared,
+    Changed,
+    /// A list field, by the items added and removed
+    Items {
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+}
+
+impl std::fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unchanged => f.write_str("unchanged"),
+            Self::Set => f.write_str("set"),
+            Self::Cleared => f.write_str("cleared"),
+            Self::Changed => f.write_str("changed"),
+            Self::Items { added, removed } => {
+                let changes: Vec<String> = added
+                    .iter()
+                    .map(|item| format!("+{item}"))
+                    .chain(removed.iter().map(|item| format!("-{item}")))
+                    .collect();
+                if changes.is_empty() {
+                    f.write_str("unchanged")
+                } else {
+                    f.write_str(&changes.join(" "))
+                }
+            }
+        }
+    }
+}
+
+/// One field of a preview
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldPreview {
+    pub id: String,
+    /// Name from the field catalog, or the id if the catalog lacks it
+    pub name: String,
+    pub current: Value,
+    pub proposed: Value,
+    pub diff: FieldDiff,
+}
+
+/// What an update would change on an issue
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UpdatePreview {
+    pub issue_key: String,
+    /// The issue's `updated` timestamp, to pass back as `expected_updated`
+    pub updated: Option<String>,
+    /// Whether the issue changed since the `expected_updated` the preview was
+    /// asked with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changed_since_expected: Option<bool>,
+    pub fields: Vec<FieldPreview>,
+}
+
+/// Whether `value` holds nothing
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => cell_text(value).is_empty(),
+    }
+}
+
+/// How `proposed` differs from `current`
+#[must_use]
+pub fn diff_values(current: &Value, proposed: &Value) -> FieldDiff {
+    if let (Value::Array(_) | Value::Null, Value::Array(proposed)) = (current, proposed) {
+        let current: Vec<String> = current
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(cell_text)
+            .collect();
+        let proposed: Vec<String> = proposed.iter().map(cell_text).collect();
+        return FieldDiff::Items {
+            added: proposed
+                .iter()
+                .filter(|item| !current.contains(item))
+                .cloned()
+                .collect(),
+            removed: current
+                .iter()
+                .filter(|item| !proposed.contains(item))
+                .cloned()
+                .collect(),
+        };
+    }
+    match (is_empty(current), is_empty(proposed)) {
+        (true, true) => FieldDiff::Unchanged,
+        (true, false) => FieldDiff::Set,
+        (false, true) => FieldDiff::Cleared,
+        (false, false)
+            if refers_to(current, proposed) || cell_text(current) == cell_text(proposed) =>
+        {
+            FieldDiff::Unchanged
+        }
+        (false, false) => FieldDiff::Changed,
+    }
+}
+
+/// Whether `proposed` names the `current` object by the keys it gives, as
+/// updates name users by `name` or options by `id`
+fn refers_to(current: &Value, proposed: &Value) -> bool {
+    if rich_text(proposed).is_some() {
+        return false;
+    }
+    match (current, proposed) {
+        (Value::Object(current), Value::Object(proposed)) => proposed
+            .iter()
+            .all(|(key, value)| current.get(key) == Some(value)),
+        _ => false,
+    }
+}
+
+/// Whether `value` is rich text too long or too many lines for a table cell
+fn is_long_text(value: &Value) -> bool {
+    rich_text(value)
+        .is_some_and(|text| text.contains('\n') || text.chars().count() > TABLE_COLUMN_MAX_CHARS)
+}
+
+impl UpdatePreview {
+    /// A table of the fields with their current and proposed values, then
+    /// the long rich text values in full and the timestamp to pass back
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut text = format!(
+            "Preview of the update to {}; nothing was changed:\n",
+            self.issue_key
+        );
+        let headers = ["Field", "Current", "Proposed", "Change"].map(ToString::to_string);
+        let rows: Vec<Vec<String>> = self
+            .fields
+            .iter()
+            .map(|field| {
+                let cell = |value: &Value| {
+                    if is_empty(value) {
+                        "(empty)".to_string()
+                    } else {
+                        cell_text(value)
+                    }
+                };
+                vec![
+                    field.name.clone(),
+                    cell(&field.current),
+                    cell(&field.proposed),
+                    field.diff.to_string(),
+                ]
+            })
+            .collect();
+        text.push_str(&render_table(&headers, &rows, TABLE_COLUMN_MAX_CHARS));
+
+        for field in &self.fields {
+            if field.diff == FieldDiff::Unchanged
+                || !(is_long_text(&field.current) || is_long_text(&field.proposed))
+            {
+                continue;
+            }
+            let full = |value: &Value| rich_text(value).unwrap_or_default();
+            text.push_str(&format!(
+                "\n\n{} (current):\n{}\n\n{} (proposed):\n{}",
+                field.name,
+                full(&field.current),
+                field.name,
+                full(&field.proposed)
+            ));
+        }
+
+        match (&self.updated, self.changed_since_expected) {
+            (Some(updated), Some(true)) => text.push_str(&format!(
+                "\n\nThe issue was updated since expected_updated, at {updated}; review the current values and pass expected_updated \"{updated}\" to apply the update"
+            )),
+            (Some(updated), _) => text.push_str(&format!(
+                "\n\nPass expected_updated \"{updated}\" to apply the update only if the issue is still as shown"
+            )),
+            (None, _) => {}
+        }
+        text
+    }
+}
+
+impl JiraClient {
+    /// Preview setting `fields` on `issue_key`: reads only those fields and
+    /// the `updated` timestamp, and compares it to `expected_updated` if
+    /// given
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue cannot be read or `expected_updated` is
+    /// not a timestamp
+    pub async fn preview_issue_update(
+        &self,
+        issue_key: &str,
+        fields: &Map<String, Value>,
+        expected_updated: Option<&str>,
+    ) -> Result<UpdatePreview> {
+        let expected = expected_updated.map(ExpectedUpdated::parse).transpose()?;
+        let mut wanted: Vec<String> = fields.keys().cloned().collect();
+        wanted.push("updated".to_string());
+        let issue = self.get_issue_fields(issue_key, &wanted).await?;
+
+        let mut previews = Vec::new();
+        for (id, proposed) in fields {
+            // The catalog only names fields; an id it lacks is shown as is
+            let name = match self.resolve_field(id).await {
+                Ok(field) => field
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or(id)
+                    .to_string(),
+                Err(_) => id.clone(),
+            };
+            let current = issue.fields.get(id).cloned().unwrap_or(Value::Null);
+            previews.push(FieldPreview {
+                id: id.clone(),
+                name,
+                diff: diff_values(&current, proposed),
+                current,
+                proposed: proposed.clone(),
+            });
+        }
+
+        let updated = issue
+            .fields
+            .get("updated")
+            .and_then(Value::as_str)
+            .map(ToString::to_string);
+        let changed_since_expected = expected.map(|expected| {
+            !updated
+                .as_deref()
+                .and_then(parse_timestamp)
+                .is_some_and(|time| expected.matches(time.with_timezone(&chrono::Utc)))
+        });
+        Ok(UpdatePreview {
+            issue_key: issue_key.to_string(),
+            updated,
+            changed_since_expected,
+            fields: previews,
+        })
+    }
+}
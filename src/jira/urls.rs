@@ -0,0 +1,48 @@
+//! Turning endpoints into request URLs.
+//!
+//! `Url::join` resolves relative to the last path segment of the base, so a
+//! base without a trailing slash loses the `2` of `/rest/api/2`, and an
+//! endpoint with a leading slash loses the whole base path. Endpoints are
+//! therefore joined onto a base that always ends in a slash, with leading
+//! slashes stripped. Values put into a path, such as issue keys and label
+//! names, go through [`path_segment`] so spaces, `/`, `?`, `#` and `%` stay
+//! part of that one segment.
+
+use crate::error::{JiraError, Result};
+use std::borrow::Cow;
+use url::Url;
+
+/// Parse `base` as a URL ending in a slash, ready to have endpoints joined
+/// onto it; `what` names it in the error
+///
+/// # Errors
+///
+/// Returns a configuration error if `base` is not an absolute URL.
+pub fn base_url(base: &str, what: &str) -> Result<Url> {
+    let base = base.trim();
+    let with_slash = if base.ends_with('/') {
+        Cow::Borrowed(base)
+    } else {
+        Cow::Owned(format!("{base}/"))
+    };
+    Url::parse(&with_slash).map_err(|e| JiraError::config_error(&format!("Invalid {what}: {e}")))
+}
+
+/// The URL of `endpoint` under `base`, e.g. `issue/KEY-1?fields=summary`
+/// under `https://jira.example.com/rest/api/2`
+///
+/// # Errors
+///
+/// Returns a configuration error if `base` is not an absolute URL or the
+/// endpoint cannot be joined onto it.
+pub fn join(base: &str, endpoint: &str, what: &str) -> Result<Url> {
+    base_url(base, what)?
+        .join(endpoint.trim_start_matches('/'))
+        .map_err(|e| JiraError::config_error(&format!("Invalid endpoint URL: {e}")))
+}
+
+/// `value` percent-encoded to be used as a single path segment
+#[must_use]
+pub fn path_segment(value: &str) -> Cow<'_, str> {
+    urlencoding::encode(value)
+}
@@ -0,0 +1,156 @@
+//! Optional warm-up before the server starts serving (`JIRA_WARMUP=true`).
+//!
+//! The first tool call otherwise pays for reaching Jira and for fetching the
+//! metadata most tools look things up in. Warm-up asks for `serverInfo` to
+//! open a connection in the HTTP client the tools share and check the
+//! credentials, then fills the metadata cache with the priorities, statuses
+//! and field catalog and caches the current user. Each step has a short
+//! timeout, and a failed step is a warning rather than a reason not to start.
+//! The outcome is kept for `test_jira_auth` and `get_server_metrics`.
+
+use crate::config::JiraConfig;
+use crate::jira::client::JiraClient;
+use crate::jira::throttle::in_background;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{LazyLock, RwLock};
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+/// Longest a single warm-up step may take
+pub const WARMUP_STEP_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Last warm-up report by API base URL
+static LAST_WARMUPS: LazyLock<RwLock<HashMap<String, WarmupReport>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Outcome of one warm-up step
+#[derive(Debug, Clone, Serialize)]
+pub struct WarmupStep {
+    pub name: String,
+    pub duration_ms: u64,
+    /// Why the step failed; `None` when it succeeded
+    pub error: Option<String>,
+}
+
+/// Outcome of a warm-up
+#[derive(Debug, Clone, Serialize)]
+pub struct WarmupReport {
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub steps: Vec<WarmupStep>,
+}
+
+impl WarmupReport {
+    /// Names of the steps that succeeded
+    #[must_use]
+    pub fn primed(&self) -> Vec<&str> {
+        self.steps
+            .iter()
+            .filter(|step| step.error.is_none())
+            .map(|step| step.name.as_str())
+            .collect()
+    }
+
+    /// One line for people, e.g. `Start-up warm-up finished in 120 ms;
+    /// primed: server info, fields; failed: statuses (timed out after 5s)`
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let mut text = format!(
+            "Start-up warm-up finished in {} ms; primed: {}",
+            self.duration_ms,
+            match self.primed() {
+                primed if primed.is_empty() => "nothing".to_string(),
+                primed => primed.join(", "),
+            }
+        );
+        let failed: Vec<String> = self
+            .steps
+            .iter()
+            .filter_map(|step| {
+                let error = step.error.as_ref()?;
+                Some(format!("{} ({error})", step.name))
+            })
+            .collect();
+        if !failed.is_empty() {
+            text.push_str(&format!("; failed: {}", failed.join(", ")));
+        }
+        text
+    }
+}
+
+/// Run `future` as the warm-up step `name`, within [`WARMUP_STEP_TIMEOUT`]
+async fn step<T>(name: &str, future: impl Future<Output = crate::error::Result<T>>) -> WarmupStep {
+    let start = Instant::now();
+    let error = match tokio::time::timeout(WARMUP_STEP_TIMEOUT, future).await {
+        Ok(Ok(_)) => None,
+        Ok(Err(e)) => Some(e.to_string()),
+        Err(_) => Some(format!("timed out after {WARMUP_STEP_TIMEOUT:?}")),
+    };
+    if let Some(error) = &error {
+        warn!("Warm-up step '{}' failed: {}", name, error);
+    }
+    WarmupStep {
+        name: name.to_string(),
+        duration_ms: u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX),
+        error,
+    }
+}
+
+/// Warm up for `config` and keep the report for [`last_warmup`]. Never fails;
+/// problems are logged and recorded in the report.
+pub async fn warm_up(config: &JiraConfig) -> WarmupReport {
+    let started_at = Utc::now();
+    let start = Instant::now();
+    info!("Warming up against {}", config.api_base_url);
+
+    let steps = match JiraClient::new(config.clone()) {
+        // Tool calls arriving meanwhile go first
+        Ok(client) => {
+            in_background(async {
+                let mut steps = vec![step("server info", client.get_server_info()).await];
+                let (priorities, statuses, fields, user) = tokio::join!(
+                    step("priorities", client.prefetch_metadata("priority")),
+                    step("statuses", client.prefetch_metadata("status")),
+                    step("fields", client.prefetch_metadata("field")),
+                    step("current user", client.get_current_user()),
+                );
+                steps.extend([priorities, statuses, fields, user]);
+                steps
+            })
+            .await
+        }
+        Err(e) => vec![WarmupStep {
+            name: "client".to_string(),
+            duration_ms: 0,
+            error: Some(e.to_string()),
+        }],
+    };
+
+    let report = WarmupReport {
+        started_at,
+        duration_ms: u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX),
+        steps,
+    };
+    info!(
+        "Warm-up finished in {} ms; primed: {}",
+        report.duration_ms,
+        report.primed().join(", ")
+    );
+    if let Ok(mut last) = LAST_WARMUPS.write() {
+        last.insert(config.api_base_url.clone(), report.clone());
+    }
+    report
+}
+
+/// Report of the last warm-up against the Jira instance of `config`, or
+/// `None` if there was none
+#[must_use]
+pub fn last_warmup(config: &JiraConfig) -> Option<WarmupReport> {
+    LAST_WARMUPS
+        .read()
+        .ok()
+        .and_then(|last| last.get(&config.api_base_url).cloned())
+}
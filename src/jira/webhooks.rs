@@ -0,0 +1,231 @@
+//! Webhooks registered with Jira through its REST API, and keeping them
+//! alive.
+//!
+//! Cloud registers dynamic webhooks under `/rest/api/N/webhook`; they expire
+//! [`CLOUD_WEBHOOK_LIFETIME_DAYS`] days after they were registered or last
+//! refreshed. [`spawn_refresher`] extends them in the background before they
+//! do. Data Center registers webhooks with the `/rest/webhooks/1.0` admin API
+//! instead, and those never expire.
+
+use crate::config::JiraConfig;
+use crate::jira::client::JiraClient;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{info, warn};
+
+/// Days a Cloud dynamic webhook lives after it was registered or refreshed
+pub const CLOUD_WEBHOOK_LIFETIME_DAYS: i64 = 30;
+
+/// Webhooks expiring within this many days are refreshed
+pub const REFRESH_MARGIN_DAYS: i64 = 7;
+
+/// Shortest and longest wait between two refresh checks, in minutes
+const MIN_CHECK_INTERVAL_MINUTES: i64 = 60;
+const MAX_CHECK_INTERVAL_MINUTES: i64 = 24 * 60;
+
+/// Events registered when none are given
+pub const DEFAULT_WEBHOOK_EVENTS: [&str; 3] = [
+    "jira:issue_created",
+    "jira:issue_updated",
+    "comment_created",
+];
+
+/// Name Data Center webhooks are registered under
+pub const DC_WEBHOOK_NAME: &str = "rust-jira-mcp";
+
+/// A webhook registered with Jira, from either API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JiraWebhook {
+    pub id: i64,
+    /// Data Center only; Cloud sends all of an app's webhooks to one URL
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub events: Vec<String>,
+    pub jql_filter: Option<String>,
+    /// Cloud only
+    pub expiration_date: Option<DateTime<Utc>>,
+    /// Data Center only
+    pub enabled: Option<bool>,
+}
+
+impl JiraWebhook {
+    /// A webhook from the `values` of Cloud's `GET webhook`
+    #[must_use]
+    pub fn from_cloud(value: &Value) -> Option<Self> {
+        Some(Self {
+            id: value.get("id")?.as_i64()?,
+            name: None,
+            url: value.get("url").and_then(Value::as_str).map(String::from),
+            events: strings(value.get("events")),
+            jql_filter: value
+                .get("jqlFilter")
+                .and_then(Value::as_str)
+                .map(String::from),
+            expiration_date: value.get("expirationDate").and_then(parse_expiration),
+            enabled: None,
+        })
+    }
+
+    /// A webhook from Data Center's `GET rest/webhooks/1.0/webhook`, whose
+    /// id is the last segment of its `self` link
+    #[must_use]
+    pub fn from_dc(value: &Value) -> Option<Self> {
+        let id = value
+            .get("self")
+            .and_then(Value::as_str)
+            .and_then(|link| link.trim_end_matches('/').rsplit('/').next())
+            .and_then(|id| id.parse().ok())
+            .or_else(|| value.get("id").and_then(Value::as_i64))?;
+        Some(Self {
+            id,
+            name: value.get("name").and_then(Value::as_str).map(String::from),
+            url: value.get("url").and_then(Value::as_str).map(String::from),
+            events: strings(value.get("events")),
+            jql_filter: value
+                .pointer("/filters/issue-related-events-section")
+                .and_then(Value::as_str)
+                .filter(|jql| !jql.is_empty())
+                .map(String::from),
+            expiration_date: None,
+            enabled: value.get("enabled").and_then(Value::as_bool),
+        })
+    }
+
+    /// One line for people, e.g. `10000: jira:issue_created,
+    /// jira:issue_updated for project = PROJ, expires 2025-11-16 12:00 UTC`
+    #[must_use]
+    pub fn headline(&self) -> String {
+        let mut line = format!("{}", self.id);
+        if let Some(name) = &self.name {
+            line.push_str(&format!(" ({name})"));
+        }
+        line.push_str(&format!(": {}", self.events.join(", ")));
+        if let Some(jql) = &self.jql_filter {
+            line.push_str(&format!(" for {jql}"));
+        }
+        if let Some(url) = &self.url {
+            line.push_str(&format!(
+                " to {}",
+                crate::logging::sanitize::sanitize_url(url)
+            ));
+        }
+        if let Some(expiration) = self.expiration_date {
+            line.push_str(&format!(
+                ", expires {}",
+                expiration.format("%Y-%m-%d %H:%M UTC")
+            ));
+        }
+        if self.enabled == Some(false) {
+            line.push_str(", disabled");
+        }
+        line
+    }
+}
+
+fn strings(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_array)
+        .map(|values| {
+            values
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// An `expirationDate`, which Cloud sends as epoch milliseconds or as a
+/// timestamp such as `2025-11-16T12:00:00.000+0000`
+#[must_use]
+pub fn parse_expiration(value: &Value) -> Option<DateTime<Utc>> {
+    match value {
+        Value::Number(millis) => Utc.timestamp_millis_opt(millis.as_i64()?).single(),
+        Value::String(text) => DateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f%z")
+            .or_else(|_| DateTime::parse_from_rfc3339(text))
+            .ok()
+            .map(|date| date.with_timezone(&Utc)),
+        _ => None,
+    }
+}
+
+/// What the refresher does next
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshPlan {
+    /// Webhooks to refresh now
+    pub due: Vec<i64>,
+    /// When to look again
+    pub next_check: DateTime<Utc>,
+}
+
+/// Which of `webhooks` to refresh at `now`: those expiring within
+/// [`REFRESH_MARGIN_DAYS`], including ones already past their date. The
+/// next check is when the first of the others becomes due, but no sooner
+/// than an hour and no later than a day from now. Webhooks without an
+/// expiration date are left alone.
+#[must_use]
+pub fn plan_refresh(webhooks: &[JiraWebhook], now: DateTime<Utc>) -> RefreshPlan {
+    let margin = Duration::days(REFRESH_MARGIN_DAYS);
+    let mut due = Vec::new();
+    let mut next_due: Option<DateTime<Utc>> = None;
+    for webhook in webhooks {
+        let Some(expiration) = webhook.expiration_date else {
+            continue;
+        };
+        let due_at = expiration - margin;
+        if due_at <= now {
+            due.push(webhook.id);
+        } else {
+            next_due = Some(next_due.map_or(due_at, |next| next.min(due_at)));
+        }
+    }
+    let earliest = now + Duration::minutes(MIN_CHECK_INTERVAL_MINUTES);
+    let latest = now + Duration::minutes(MAX_CHECK_INTERVAL_MINUTES);
+    RefreshPlan {
+        due,
+        next_check: next_due.unwrap_or(latest).clamp(earliest, latest),
+    }
+}
+
+/// Keep the Cloud webhooks of this app registered by refreshing them before
+/// they expire, if `webhook_public_url` is set. Does nothing on Data Center.
+#[must_use]
+pub fn spawn_refresher(config: &JiraConfig) -> Option<tokio::task::JoinHandle<()>> {
+    if !config.is_cloud() || config.webhook_public_url.is_none() {
+        return None;
+    }
+    let client = match JiraClient::new(config.clone()) {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Cannot refresh webhooks: {}", e);
+            return None;
+        }
+    };
+    Some(tokio::spawn(async move {
+        loop {
+            let now = Utc::now();
+            let next_check = match client.list_webhooks().await {
+                Ok(webhooks) => {
+                    let plan = plan_refresh(&webhooks, now);
+                    if !plan.due.is_empty() {
+                        match client.refresh_webhooks(&plan.due).await {
+                            Ok(expiration) => {
+                                info!("Refreshed webhooks {:?} until {:?}", plan.due, expiration)
+                            }
+                            Err(e) => warn!("Cannot refresh webhooks {:?}: {}", plan.due, e),
+                        }
+                    }
+                    plan.next_check
+                }
+                Err(e) => {
+                    warn!("Cannot list webhooks to refresh: {}", e);
+                    now + Duration::minutes(MIN_CHECK_INTERVAL_MINUTES)
+                }
+            };
+            let wait = (next_check - Utc::now())
+                .to_std()
+                .unwrap_or(std::time::Duration::ZERO);
+            tokio::time::sleep(wait).await;
+        }
+    }))
+}
@@ -0,0 +1,616 @@
+//! Where worklogs are read and written.
+//!
+//! Teams tracking time with Tempo do not see their worklogs through Jira's
+//! worklog API, so the worklog tools go through a [`WorklogProvider`]:
+//! [`JiraWorklogProvider`] for Jira's own API, or [`TempoWorklogProvider`]
+//! for the Tempo REST API when `worklog_provider` is `tempo`. Both hand back
+//! worklogs in Jira's shape, with authors as Jira users, so the tools read
+//! the same either way.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+use crate::jira::client::JiraClient;
+use crate::jira::urls::path_segment;
+use crate::mcp::tools::formatting::rich_text;
+use crate::types::jira::{
+    JiraUser, JiraWorkLog, JiraWorkLogCreateRequest, JiraWorkLogEstimateAdjustment,
+    JiraWorkLogUpdateRequest,
+};
+use crate::utils::datetime::parse_timestamp;
+use crate::utils::duration::{WorkCalendar, WorkDuration};
+use crate::utils::time::format_time_spent;
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Worklogs requested per page of the Tempo API
+const TEMPO_PAGE_SIZE: usize = 1000;
+
+/// Reads and writes the worklogs of issues
+#[async_trait::async_trait]
+pub trait WorklogProvider: Send + Sync {
+    /// Worklogs of `issue_key` in Jira's shape, oldest first, up to `limit`
+    /// of them, with the total there are. With `started_after` only worklogs
+    /// started since then need be returned.
+    async fn get_work_logs(
+        &self,
+        issue_key: &str,
+        started_after: Option<DateTime<Utc>>,
+        limit: usize,
+    ) -> Result<(Vec<Value>, usize)>;
+
+    /// Log work on `issue_key`, adjusting its remaining estimate as `estimate` says
+    async fn add_work_log(
+        &self,
+        issue_key: &str,
+        work_log: &JiraWorkLogCreateRequest,
+        estimate: &JiraWorkLogEstimateAdjustment,
+    ) -> Result<JiraWorkLog>;
+
+    /// Change the worklog `work_log_id` of `issue_key`
+    async fn update_work_log(
+        &self,
+        issue_key: &str,
+        work_log_id: &str,
+        work_log: &JiraWorkLogUpdateRequest,
+        estimate: &JiraWorkLogEstimateAdjustment,
+    ) -> Result<JiraWorkLog>;
+
+    /// Delete the worklog `work_log_id` of `issue_key`
+    async fn delete_work_log(&self, issue_key: &str, work_log_id: &str) -> Result<()>;
+
+    /// Time logged on `issue_keys` by author and by issue, counting worklogs
+    /// started from `started_after` up to `started_before`, at most `limit`
+    /// per issue
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the worklogs of an issue cannot be read.
+    async fn report(
+        &self,
+        issue_keys: &[String],
+        started_after: Option<DateTime<Utc>>,
+        started_before: Option<DateTime<Utc>>,
+        limit: usize,
+    ) -> Result<WorklogReport> {
+        let mut report = WorklogReport::default();
+        for issue_key in issue_keys {
+            let (work_logs, _) = self.get_work_logs(issue_key, started_after, limit).await?;
+            for work_log in &work_logs {
+                let started = work_log
+                    .get("started")
+                    .and_then(Value::as_str)
+                    .and_then(parse_timestamp);
+                let in_range = started.is_some_and(|started| {
+                    started_after.is_none_or(|after| started >= after)
+                        && started_before.is_none_or(|before| started < before)
+                });
+                if in_range {
+                    report.add(issue_key, work_log);
+                }
+            }
+        }
+        report.sort();
+        Ok(report)
+    }
+}
+
+/// Time logged, in seconds, in total, by author and by issue, the largest
+/// first
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WorklogReport {
+    pub total_seconds: u64,
+    pub worklogs: usize,
+    /// Author display names (account id when Jira gave none)
+    pub by_author: Vec<(String, u64)>,
+    pub by_issue: Vec<(String, u64)>,
+}
+
+impl WorklogReport {
+    fn add(&mut self, issue_key: &str, work_log: &Value) {
+        let seconds = work_log
+            .get("timeSpentSeconds")
+            .and_then(Value::as_u64)
+            .unwrap_or(0);
+        let author = work_log
+            .pointer("/author/displayName")
+            .or_else(|| work_log.pointer("/author/accountId"))
+            .and_then(Value::as_str)
+            .unwrap_or("Unknown")
+            .to_string();
+        self.total_seconds += seconds;
+        self.worklogs += 1;
+        Self::add_to(&mut self.by_author, author, seconds);
+        Self::add_to(&mut self.by_issue, issue_key.to_string(), seconds);
+    }
+
+    fn add_to(totals: &mut Vec<(String, u64)>, name: String, seconds: u64) {
+        match totals.iter_mut().find(|(known, _)| *known == name) {
+            Some((_, total)) => *total += seconds,
+            None => totals.push((name, seconds)),
+        }
+    }
+
+    fn sort(&mut self) {
+        for totals in [&mut self.by_author, &mut self.by_issue] {
+            totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        }
+    }
+}
+
+/// The provider `config` selects with `worklog_provider`
+///
+/// # Errors
+///
+/// Returns an error if the Jira client cannot be created or Tempo is
+/// selected without an API token.
+pub fn worklog_provider(config: &JiraConfig) -> Result<Box<dyn WorklogProvider>> {
+    let client = JiraClient::new(config.clone())?;
+    if config.uses_tempo() {
+        Ok(Box::new(TempoWorklogProvider::new(client)?))
+    } else {
+        Ok(Box::new(JiraWorklogProvider { client }))
+    }
+}
+
+/// Worklogs through Jira's own worklog API
+pub struct JiraWorklogProvider {
+    client: JiraClient,
+}
+
+impl JiraWorklogProvider {
+    #[must_use]
+    pub fn new(client: JiraClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait::async_trait]
+impl WorklogProvider for JiraWorklogProvider {
+    async fn get_work_logs(
+        &self,
+        issue_key: &str,
+        started_after: Option<DateTime<Utc>>,
+        limit: usize,
+    ) -> Result<(Vec<Value>, usize)> {
+        self.client
+            .get_all_work_logs(issue_key, started_after, limit)
+            .await
+    }
+
+    async fn add_work_log(
+        &self,
+        issue_key: &str,
+        work_log: &JiraWorkLogCreateRequest,
+        estimate: &JiraWorkLogEstimateAdjustment,
+    ) -> Result<JiraWorkLog> {
+        self.client
+            .add_work_log(issue_key, work_log, estimate)
+            .await
+    }
+
+    async fn update_work_log(
+        &self,
+        issue_key: &str,
+        work_log_id: &str,
+        work_log: &JiraWorkLogUpdateRequest,
+        estimate: &JiraWorkLogEstimateAdjustment,
+    ) -> Result<JiraWorkLog> {
+        self.client
+            .update_work_log(issue_key, work_log_id, work_log, estimate)
+            .await
+    }
+
+    async fn delete_work_log(&self, issue_key: &str, work_log_id: &str) -> Result<()> {
+        self.client.delete_work_log(issue_key, work_log_id).await
+    }
+}
+
+/// Worklogs through the Tempo REST API.
+///
+/// Tempo addresses issues by id and authors by Jira account id (Cloud) or
+/// user key (`worker`, Data Center); issue keys are looked up in Jira and
+/// authors resolved to Jira users, so worklogs come back as Jira would give
+/// them. Tempo's worklog id stands in for Jira's.
+pub struct TempoWorklogProvider {
+    client: JiraClient,
+    calendar: WorkCalendar,
+    /// Jira users by Tempo author id, looked up once each
+    users: Mutex<HashMap<String, Value>>,
+}
+
+impl TempoWorklogProvider {
+    /// # Errors
+    ///
+    /// Returns a configuration error if `tempo_api_token` is not set.
+    pub fn new(client: JiraClient) -> Result<Self> {
+        if client
+            .config()
+            .tempo_api_token
+            .as_deref()
+            .is_none_or(|token| token.trim().is_empty())
+        {
+            return Err(JiraError::config_error(
+                "Set JIRA_TEMPO_API_TOKEN to read and write worklogs through Tempo",
+            ));
+        }
+        Ok(Self {
+            calendar: WorkCalendar::from_config(client.config()),
+            client,
+            users: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// The id and remaining estimate in seconds of `issue_key`
+    async fn issue(&self, issue_key: &str) -> Result<(String, Option<u64>)> {
+        let issue: Value = self
+            .client
+            .get(&format!(
+                "issue/{}?fields=timetracking",
+                path_segment(issue_key)
+            ))
+            .await?;
+        let id = issue
+            .get("id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| JiraError::api_error(&format!("Issue {issue_key} has no id")))?;
+        let remaining = issue
+            .pointer("/fields/timetracking/remainingEstimateSeconds")
+            .and_then(Value::as_u64);
+        Ok((id.to_string(), remaining))
+    }
+
+    /// The Jira user behind a Tempo worklog's author: `author.accountId` on
+    /// Cloud, the user key in `worker` on Data Center. Authors Jira cannot
+    /// find are shown by their id.
+    async fn jira_author(&self, tempo_work_log: &Value) -> Value {
+        let (param, id) = match (
+            tempo_work_log
+                .pointer("/author/accountId")
+                .and_then(Value::as_str),
+            tempo_work_log.get("worker").and_then(Value::as_str),
+        ) {
+            (Some(account_id), _) => ("accountId", account_id),
+            (None, Some(key)) => ("key", key),
+            (None, None) => return json!({"displayName": "Unknown"}),
+        };
+        if let Some(user) = self
+            .users
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(id)
+        {
+            return user.clone();
+        }
+        let user = match self
+            .client
+            .get::<Value>(&format!("user?{param}={}", path_segment(id)))
+            .await
+        {
+            Ok(user) => user,
+            Err(e) => {
+                tracing::warn!("Cannot find the Jira user of Tempo author {}: {}", id, e);
+                json!({ param: id, "displayName": id })
+            }
+        };
+        self.users
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(id.to_string(), user.clone());
+        user
+    }
+
+    /// A Tempo worklog in the shape of Jira's
+    async fn to_jira(&self, tempo_work_log: &Value) -> Value {
+        let text = |field: &str| tempo_work_log.get(field).and_then(Value::as_str);
+        let seconds = tempo_work_log
+            .get("timeSpentSeconds")
+            .and_then(Value::as_u64)
+            .unwrap_or(0);
+        // Cloud gives the start in UTC and as the worker's local date and
+        // time; Data Center as `started`, e.g. `2025-10-17 08:00:00.000`
+        let started = text("startDateTimeUtc")
+            .map(ToString::to_string)
+            .or_else(|| {
+                let date = text("startDate")?;
+                Some(format!(
+                    "{date}T{}",
+                    text("startTime").unwrap_or("00:00:00")
+                ))
+            })
+            .or_else(|| text("started").map(|started| started.replacen(' ', "T", 1)))
+            .and_then(|started| parse_timestamp(&started))
+            .map(|started| {
+                started
+                    .with_timezone(&Utc)
+                    .format("%Y-%m-%dT%H:%M:%S%.3f+0000")
+                    .to_string()
+            });
+        let id = tempo_work_log
+            .get("tempoWorklogId")
+            .or_else(|| tempo_work_log.get("id"))
+            .map(|id| match id {
+                Value::String(id) => id.clone(),
+                other => other.to_string(),
+            });
+        json!({
+            "id": id,
+            "issueId": tempo_work_log.pointer("/issue/id").map(|id| match id {
+                Value::String(id) => id.clone(),
+                other => other.to_string(),
+            }),
+            "author": self.jira_author(tempo_work_log).await,
+            "comment": text("description").or_else(|| text("comment")),
+            "started": started,
+            "timeSpent": format_time_spent(seconds),
+            "timeSpentSeconds": seconds,
+            "created": text("createdAt").or_else(|| text("dateCreated")),
+            "updated": text("updatedAt").or_else(|| text("dateUpdated")),
+        })
+    }
+
+    /// A Tempo worklog as the [`JiraWorkLog`] the write tools report
+    async fn to_work_log(&self, tempo_work_log: &Value) -> Result<JiraWorkLog> {
+        let work_log = self.to_jira(tempo_work_log).await;
+        let text = |pointer: &str| {
+            work_log
+                .pointer(pointer)
+                .and_then(Value::as_str)
+                .map(ToString::to_string)
+        };
+        let author = &work_log["author"];
+        Ok(JiraWorkLog {
+            id: text("/id").ok_or_else(|| JiraError::api_error("Tempo worklog has no id"))?,
+            comment: text("/comment"),
+            time_spent: text("/timeSpent").unwrap_or_default(),
+            time_spent_seconds: work_log["timeSpentSeconds"]
+                .as_u64()
+                .and_then(|seconds| i32::try_from(seconds).ok())
+                .unwrap_or(0),
+            author: JiraUser {
+                self_url: text("/author/self").unwrap_or_default(),
+                name: text("/author/name").unwrap_or_default(),
+                key: text("/author/key").unwrap_or_default(),
+                account_id: text("/author/accountId"),
+                email_address: text("/author/emailAddress"),
+                avatar_urls: None,
+                display_name: text("/author/displayName"),
+                active: author
+                    .get("active")
+                    .and_then(Value::as_bool)
+                    .unwrap_or(true),
+                time_zone: text("/author/timeZone"),
+            },
+            created: text("/created").unwrap_or_default(),
+            updated: text("/updated"),
+        })
+    }
+
+    /// Seconds of the Jira duration `value`, e.g. `1h 30m`
+    fn seconds(&self, field: &str, value: &str) -> Result<u64> {
+        Ok(WorkDuration::parse(field, value, &self.calendar)?.seconds())
+    }
+
+    /// The remaining estimate to send for `estimate`, or `None` to let Tempo
+    /// reduce it by the time spent
+    fn remaining_estimate(
+        &self,
+        estimate: &JiraWorkLogEstimateAdjustment,
+        remaining: Option<u64>,
+    ) -> Result<Option<u64>> {
+        Ok(match estimate {
+            JiraWorkLogEstimateAdjustment::Auto => None,
+            JiraWorkLogEstimateAdjustment::Leave => remaining,
+            JiraWorkLogEstimateAdjustment::New(estimate) => {
+                Some(self.seconds("new_estimate", estimate)?)
+            }
+            JiraWorkLogEstimateAdjustment::Manual(reduce_by) => Some(
+                remaining
+                    .unwrap_or(0)
+                    .saturating_sub(self.seconds("reduce_by", reduce_by)?),
+            ),
+        })
+    }
+
+    /// The body of a Tempo worklog write; `started` keeps the local date and
+    /// time it was given in, as Tempo's `startDate` and `startTime` do
+    fn tempo_body(
+        issue_id: &str,
+        author_account_id: &str,
+        seconds: u64,
+        started: DateTime<chrono::FixedOffset>,
+        description: Option<&str>,
+        remaining_estimate: Option<u64>,
+    ) -> Value {
+        let mut body = json!({
+            "issueId": issue_id.parse::<u64>().map_or_else(|_| json!(issue_id), |id| json!(id)),
+            "authorAccountId": author_account_id,
+            "timeSpentSeconds": seconds,
+            "startDate": started.format("%Y-%m-%d").to_string(),
+            "startTime": started.format("%H:%M:%S").to_string(),
+        });
+        if let Some(description) = description {
+            body["description"] = json!(description);
+        }
+        if let Some(remaining) = remaining_estimate {
+            body["remainingEstimateSeconds"] = json!(remaining);
+        }
+        body
+    }
+
+    /// When a worklog starts: `started` if given, now otherwise
+    fn started(started: Option<&str>) -> Result<DateTime<chrono::FixedOffset>> {
+        match started {
+            Some(started) => parse_timestamp(started).ok_or_else(|| {
+                JiraError::validation_error(
+                    "started",
+                    &format!("Invalid start time '{started}'; use an ISO 8601 timestamp"),
+                )
+            }),
+            None => Ok(Utc::now().fixed_offset()),
+        }
+    }
+
+    /// Account id of the user the token belongs to, whom new worklogs are
+    /// logged for
+    async fn current_account_id(&self) -> Result<String> {
+        self.client
+            .get_current_user()
+            .await?
+            .get("accountId")
+            .and_then(Value::as_str)
+            .map(ToString::to_string)
+            .ok_or_else(|| JiraError::api_error("Current user has no account id for Tempo"))
+    }
+}
+
+#[async_trait::async_trait]
+impl WorklogProvider for TempoWorklogProvider {
+    async fn get_work_logs(
+        &self,
+        issue_key: &str,
+        started_after: Option<DateTime<Utc>>,
+        limit: usize,
+    ) -> Result<(Vec<Value>, usize)> {
+        let (issue_id, _) = self.issue(issue_key).await?;
+        let from = started_after
+            .map(|after| format!("&from={}", after.format("%Y-%m-%d")))
+            .unwrap_or_default();
+        let mut work_logs = Vec::new();
+        let mut total = 0;
+        loop {
+            let page: Value = self
+                .client
+                .tempo_request(
+                    Method::GET,
+                    &format!(
+                        "worklogs/issue/{}?offset={total}&limit={TEMPO_PAGE_SIZE}{from}",
+                        path_segment(&issue_id)
+                    ),
+                    None::<&()>,
+                    true,
+                )
+                .await?;
+            // Tempo on Data Center answers with a plain array and no pages
+            let results = page
+                .get("results")
+                .unwrap_or(&page)
+                .as_array()
+                .ok_or_else(|| JiraError::api_error("Invalid Tempo worklogs response format"))?;
+            total += results.len();
+            for result in results {
+                if work_logs.len() < limit {
+                    work_logs.push(self.to_jira(result).await);
+                }
+            }
+            let has_next = page
+                .pointer("/metadata/next")
+                .is_some_and(|next| !next.is_null());
+            if results.is_empty() || !has_next {
+                return Ok((work_logs, total));
+            }
+        }
+    }
+
+    async fn add_work_log(
+        &self,
+        issue_key: &str,
+        work_log: &JiraWorkLogCreateRequest,
+        estimate: &JiraWorkLogEstimateAdjustment,
+    ) -> Result<JiraWorkLog> {
+        let seconds = self.seconds("time_spent", &work_log.time_spent)?;
+        let started = Self::started(work_log.started.as_deref())?;
+        let (issue_id, remaining) = self.issue(issue_key).await?;
+        // Tempo descriptions are plain text
+        let description = self
+            .client
+            .signed_work_log_comment(work_log.comment.as_ref())
+            .as_ref()
+            .and_then(rich_text);
+        let body = Self::tempo_body(
+            &issue_id,
+            &self.current_account_id().await?,
+            seconds,
+            started,
+            description.as_deref(),
+            self.remaining_estimate(estimate, remaining)?,
+        );
+        let created: Value = self
+            .client
+            .tempo_request(Method::POST, "worklogs", Some(&body), false)
+            .await?;
+        self.to_work_log(&created).await
+    }
+
+    async fn update_work_log(
+        &self,
+        issue_key: &str,
+        work_log_id: &str,
+        work_log: &JiraWorkLogUpdateRequest,
+        estimate: &JiraWorkLogEstimateAdjustment,
+    ) -> Result<JiraWorkLog> {
+        let endpoint = format!("worklogs/{}", path_segment(work_log_id));
+        // Tempo replaces the whole worklog, so unchanged values are sent as they are
+        let current: Value = self
+            .client
+            .tempo_request(Method::GET, &endpoint, None::<&()>, true)
+            .await?;
+        let current_jira = self.to_jira(&current).await;
+        let seconds = match &work_log.time_spent {
+            Some(time_spent) => self.seconds("time_spent", time_spent)?,
+            None => current_jira["timeSpentSeconds"].as_u64().unwrap_or(0),
+        };
+        let started = Self::started(
+            work_log
+                .started
+                .as_deref()
+                .or_else(|| current_jira["started"].as_str()),
+        )?;
+        let author = current
+            .pointer("/author/accountId")
+            .and_then(Value::as_str)
+            .map(ToString::to_string);
+        let author = match author {
+            Some(author) => author,
+            None => self.current_account_id().await?,
+        };
+        let (issue_id, remaining) = self.issue(issue_key).await?;
+        let description = work_log
+            .comment
+            .as_ref()
+            .map(|comment| self.client.signed(comment))
+            .as_ref()
+            .and_then(rich_text);
+        let body = Self::tempo_body(
+            &issue_id,
+            &author,
+            seconds,
+            started,
+            description
+                .as_deref()
+                .or_else(|| current_jira["comment"].as_str()),
+            self.remaining_estimate(estimate, remaining)?,
+        );
+        let updated: Value = self
+            .client
+            .tempo_request(Method::PUT, &endpoint, Some(&body), true)
+            .await?;
+        self.to_work_log(&updated).await
+    }
+
+    async fn delete_work_log(&self, _issue_key: &str, work_log_id: &str) -> Result<()> {
+        let _: Value = self
+            .client
+            .tempo_request(
+                Method::DELETE,
+                &format!("worklogs/{}", path_segment(work_log_id)),
+                None::<&()>,
+                true,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+//! Pass rates of the executions in a Zephyr test cycle, overall and per
+//! folder of the cycle.
+//!
+//! ZAPI reports an execution's status as a code (`1` pass, `2` fail, `3` work
+//! in progress, `4` blocked, `-1` unexecuted) while other responses and users
+//! name it, so both are read. Pass rates count executed tests only; a cycle
+//! nobody has run yet has no pass rate rather than a rate of zero.
+
+use crate::types::jira::{ZephyrFolder, ZephyrTestExecution};
+use serde::Serialize;
+
+/// What an execution's status says about the test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionOutcome {
+    Passed,
+    Failed,
+    Blocked,
+    InProgress,
+    Unexecuted,
+    /// A custom status, counted as executed but not passed
+    Other,
+}
+
+impl ExecutionOutcome {
+    /// Read a ZAPI status code or name
+    #[must_use]
+    pub fn from_status(status: &str) -> Self {
+        match status
+            .trim()
+            .to_ascii_uppercase()
+            .replace(' ', "_")
+            .as_str()
+        {
+            "1" | "PASS" | "PASSED" => Self::Passed,
+            "2" | "FAIL" | "FAILED" => Self::Failed,
+            "4" | "BLOCKED" => Self::Blocked,
+            "3" | "WIP" | "IN_PROGRESS" => Self::InProgress,
+            "-1" | "" | "UNEXECUTED" | "NOT_EXECUTED" => Self::Unexecuted,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Executions counted by outcome
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PassRate {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub blocked: usize,
+    pub in_progress: usize,
+    pub unexecuted: usize,
+    pub other: usize,
+}
+
+impl PassRate {
+    fn add(&mut self, outcome: ExecutionOutcome) {
+        self.total += 1;
+        *match outcome {
+            ExecutionOutcome::Passed => &mut self.passed,
+            ExecutionOutcome::Failed => &mut self.failed,
+            ExecutionOutcome::Blocked => &mut self.blocked,
+            ExecutionOutcome::InProgress => &mut self.in_progress,
+            ExecutionOutcome::Unexecuted => &mut self.unexecuted,
+            ExecutionOutcome::Other => &mut self.other,
+        } += 1;
+    }
+
+    /// Executions that were run, whatever their outcome
+    #[must_use]
+    pub fn executed(&self) -> usize {
+        self.total - self.unexecuted
+    }
+
+    /// Share of the executed tests that passed, in percent; `None` when
+    /// nothing was executed
+    #[must_use]
+    pub fn pass_percent(&self) -> Option<f64> {
+        let executed = self.executed();
+        #[allow(clippy::cast_precision_loss)]
+        (executed > 0).then(|| self.passed as f64 * 100.0 / executed as f64)
+    }
+
+    /// One line such as `3/4 passed (75.0%), 1 failed, 2 unexecuted`
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let mut parts = vec![match self.pass_percent() {
+            Some(percent) => format!("{}/{} passed ({percent:.1}%)", self.passed, self.executed()),
+            None => "nothing executed".to_string(),
+        }];
+        for (count, label) in [
+            (self.failed, "failed"),
+            (self.blocked, "blocked"),
+            (self.in_progress, "in progress"),
+            (self.other, "other"),
+            (self.unexecuted, "unexecuted"),
+        ] {
+            if count > 0 {
+                parts.push(format!("{count} {label}"));
+            }
+        }
+        parts.join(", ")
+    }
+}
+
+/// Pass rate of one folder, or of the executions outside any folder
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FolderPassRate {
+    /// `None` for the executions at the top level of the cycle
+    pub folder_id: Option<i64>,
+    pub name: String,
+    pub rate: PassRate,
+}
+
+/// Pass rates of a cycle
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CycleReport {
+    pub overall: PassRate,
+    /// The cycle's folders in the order listed, including empty ones, then
+    /// executions of unlisted folders and finally those outside any folder
+    pub folders: Vec<FolderPassRate>,
+}
+
+impl CycleReport {
+    /// Count the `executions` of a cycle, broken down by `folders`
+    #[must_use]
+    pub fn from_executions(executions: &[ZephyrTestExecution], folders: &[ZephyrFolder]) -> Self {
+        let mut overall = PassRate::default();
+        let mut rates: Vec<FolderPassRate> = folders
+            .iter()
+            .map(|folder| FolderPassRate {
+                folder_id: Some(folder.id),
+                name: folder.name.clone(),
+                rate: PassRate::default(),
+            })
+            .collect();
+        let mut root = PassRate::default();
+
+        for execution in executions {
+            let outcome = ExecutionOutcome::from_status(&execution.status);
+            overall.add(outcome);
+            let Some(folder_id) = execution.folder_id else {
+                root.add(outcome);
+                continue;
+            };
+            let index = rates
+                .iter()
+                .position(|rate| rate.folder_id == Some(folder_id))
+                .unwrap_or_else(|| {
+                    rates.push(FolderPassRate {
+                        folder_id: Some(folder_id),
+                        name: format!("Folder {folder_id}"),
+                        rate: PassRate::default(),
+                    });
+                    rates.len() - 1
+                });
+            rates[index].rate.add(outcome);
+        }
+
+        if root.total > 0 {
+            rates.push(FolderPassRate {
+                folder_id: None,
+                name: "(no folder)".to_string(),
+                rate: root,
+            });
+        }
+        Self {
+            overall,
+            folders: rates,
+        }
+    }
+}
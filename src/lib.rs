@@ -45,7 +45,7 @@
 //!     let client = JiraClient::new(config)?;
 //!     
 //!     // Test authentication by getting user info
-//!     let user_info = client.get("myself").await?;
+//!     let user_info: serde_json::Value = client.get("myself").await?;
 //!     println!("Authentication successful: {:?}", user_info);
 //!     
 //!     // Search for issues
@@ -171,8 +171,8 @@ pub mod test_utils;
 
 pub use crate::config::jira::JiraConfig;
 pub use crate::types::mcp::{
-    CallToolParams, CallToolResult, ClientInfo, InitializeParams, InitializeResult, JsonRpcError,
-    JsonRpcRequest, JsonRpcResponse, ListToolsParams, ListToolsResult, MCPContent, MCPTool,
-    MCPToolCall, MCPToolResult, ServerCapabilities, ServerInfo, ToolsCapability,
+    CallToolParams, CallToolResult, ClientInfo, ClientSession, InitializeParams, InitializeResult,
+    JsonRpcError, JsonRpcRequest, JsonRpcResponse, ListToolsParams, ListToolsResult, MCPContent,
+    MCPTool, MCPToolCall, MCPToolResult, ServerCapabilities, ServerInfo, ToolsCapability,
 };
 pub use error::{JiraError, Result};
@@ -0,0 +1,162 @@
+//! Per-call recording of the HTTP exchanges behind a tool invocation.
+//!
+//! [`capture`] runs a future with recording switched on for the current task;
+//! `JiraClient` then [`record`]s every request it sends. Requests sent from
+//! other tasks (e.g. `tokio::spawn`) are not recorded. All details pass
+//! through the [`sanitize`](crate::logging::sanitize) rules used for logging.
+
+use crate::logging::sanitize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Characters of a response body kept in a capture
+pub const CAPTURED_BODY_LIMIT: usize = 4000;
+
+tokio::task_local! {
+    static EXCHANGES: Arc<Mutex<Vec<CapturedExchange>>>;
+}
+
+/// Outbound request as captured, with credentials redacted
+#[derive(Debug, Clone, Serialize)]
+pub struct CapturedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+}
+
+/// Response to a captured request, with its body redacted and truncated
+#[derive(Debug, Clone, Serialize)]
+pub struct CapturedResponse {
+    pub status: u16,
+    pub body: String,
+    pub truncated: bool,
+}
+
+/// One request and its response, or the error that prevented one
+#[derive(Debug, Clone, Serialize)]
+pub struct CapturedExchange {
+    pub request: CapturedRequest,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<CapturedResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CapturedRequest {
+    /// Capture `request` as it is about to be sent
+    #[must_use]
+    pub fn from_request(request: &reqwest::Request) -> Self {
+        let headers = request
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    sanitize::sanitize_header(name.as_str(), value.to_str().unwrap_or("<invalid>")),
+                )
+            })
+            .collect();
+        let body = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .map(|bytes| {
+                let text = String::from_utf8_lossy(bytes);
+                serde_json::from_str(&text).map_or_else(
+                    |_| serde_json::Value::String(sanitize::sanitize_text(&text)),
+                    |json| sanitize::sanitize_json(&json),
+                )
+            });
+
+        Self {
+            method: request.method().to_string(),
+            url: sanitize::sanitize_url(request.url().as_str()),
+            headers,
+            body,
+        }
+    }
+
+    /// Exchange for this request answered with `status` and `body`
+    #[must_use]
+    pub fn with_response(self, status: u16, body: &str) -> CapturedExchange {
+        let body = sanitize::sanitize_text(body);
+        let truncated = body.chars().count() > CAPTURED_BODY_LIMIT;
+        CapturedExchange {
+            request: self,
+            response: Some(CapturedResponse {
+                status,
+                body: body.chars().take(CAPTURED_BODY_LIMIT).collect(),
+                truncated,
+            }),
+            error: None,
+        }
+    }
+
+    /// Exchange for this request failing before a response arrived
+    #[must_use]
+    pub fn with_error(self, error: &str) -> CapturedExchange {
+        CapturedExchange {
+            request: self,
+            response: None,
+            error: Some(sanitize::sanitize_text(error)),
+        }
+    }
+}
+
+/// Whether the current task is recording exchanges
+#[must_use]
+pub fn is_active() -> bool {
+    EXCHANGES.try_with(|_| ()).is_ok()
+}
+
+/// Record `exchange` if the current task is recording; a no-op otherwise
+pub fn record(exchange: CapturedExchange) {
+    let _ = EXCHANGES.try_with(|exchanges| {
+        if let Ok(mut exchanges) = exchanges.lock() {
+            exchanges.push(exchange);
+        }
+    });
+}
+
+/// Run `future`, returning its output along with the exchanges it recorded
+pub async fn capture<F>(future: F) -> (F::Output, Vec<CapturedExchange>)
+where
+    F: Future,
+{
+    let exchanges = Arc::new(Mutex::new(Vec::new()));
+    let output = EXCHANGES.scope(exchanges.clone(), future).await;
+    let recorded = exchanges
+        .lock()
+        .map(|mut exchanges| std::mem::take(&mut *exchanges))
+        .unwrap_or_default();
+    (output, recorded)
+}
+
+/// Exchanges as pretty-printed JSON
+#[must_use]
+pub fn to_json(exchanges: &[CapturedExchange]) -> String {
+    serde_json::to_string_pretty(exchanges).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Write `exchanges` of a `tool` call to a new timestamped file in `dir`
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created or the file written.
+pub async fn write_capture(
+    dir: &Path,
+    tool: &str,
+    exchanges: &[CapturedExchange],
+) -> std::io::Result<PathBuf> {
+    tokio::fs::create_dir_all(dir).await?;
+    let path = dir.join(format!(
+        "{}-{tool}.json",
+        chrono::Utc::now().format("%Y%m%dT%H%M%S%.3fZ")
+    ));
+    tokio::fs::write(&path, to_json(exchanges)).await?;
+    Ok(path)
+}
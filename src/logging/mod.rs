@@ -1,5 +1,7 @@
+pub mod capture;
 pub mod config;
 pub mod metrics;
+pub mod sanitize;
 pub mod tracing_setup;
 
 pub use config::LoggingConfig;
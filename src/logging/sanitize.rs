@@ -0,0 +1,152 @@
+//! Redaction of credentials from logged and captured HTTP traffic.
+//!
+//! Everything that writes request or response details somewhere a person may
+//! read them goes through these functions, so logs and debug captures hide
+//! the same things.
+
+use serde_json::Value;
+
+/// Replacement for redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Headers whose values are always redacted (compared ignoring case)
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+    "x-auth-token",
+];
+
+/// JSON keys and query parameters containing any of these (ignoring case) are
+/// redacted
+const SENSITIVE_KEY_PARTS: &[&str] = &[
+    "password",
+    "passwd",
+    "secret",
+    "token",
+    "apikey",
+    "api_key",
+    "authorization",
+    "credential",
+];
+
+/// Authentication schemes whose credentials are redacted from free text
+const AUTH_SCHEMES: &[&str] = &["Basic", "Bearer"];
+
+/// Shortest word after `Basic`/`Bearer` treated as a credential, so prose such
+/// as "Basic setup" is left alone
+const MIN_CREDENTIAL_LEN: usize = 16;
+
+/// Whether the value of header `name` must not be shown
+#[must_use]
+pub fn is_sensitive_header(name: &str) -> bool {
+    SENSITIVE_HEADERS
+        .iter()
+        .any(|header| header.eq_ignore_ascii_case(name))
+}
+
+/// Whether the value under JSON key or query parameter `key` must not be shown
+#[must_use]
+pub fn is_sensitive_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    SENSITIVE_KEY_PARTS.iter().any(|part| key.contains(part))
+}
+
+/// Header value safe to show
+#[must_use]
+pub fn sanitize_header(name: &str, value: &str) -> String {
+    if is_sensitive_header(name) {
+        REDACTED.to_string()
+    } else {
+        sanitize_text(value)
+    }
+}
+
+/// Copy of `value` with the values of sensitive keys redacted, at any depth
+#[must_use]
+pub fn sanitize_json(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = if is_sensitive_key(key) {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        sanitize_json(value)
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.iter().map(sanitize_json).collect()),
+        Value::String(text) => Value::String(redact_auth_schemes(text)),
+        other => other.clone(),
+    }
+}
+
+/// URL with any password and sensitive query parameters redacted
+#[must_use]
+pub fn sanitize_url(url: &str) -> String {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return redact_auth_schemes(url);
+    };
+    if parsed.password().is_some() {
+        let _ = parsed.set_password(Some(REDACTED));
+    }
+    if parsed.query().is_some() {
+        let pairs: Vec<(String, String)> = parsed
+            .query_pairs()
+            .map(|(key, value)| {
+                let value = if is_sensitive_key(&key) {
+                    REDACTED.to_string()
+                } else {
+                    value.into_owned()
+                };
+                (key.into_owned(), value)
+            })
+            .collect();
+        parsed.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    parsed.to_string()
+}
+
+/// Text safe to show: JSON has its sensitive keys redacted, anything else has
+/// `Basic`/`Bearer` credentials redacted
+#[must_use]
+pub fn sanitize_text(text: &str) -> String {
+    match serde_json::from_str::<Value>(text) {
+        Ok(value @ (Value::Object(_) | Value::Array(_))) => sanitize_json(&value).to_string(),
+        _ => redact_auth_schemes(text),
+    }
+}
+
+/// Whether `word` could be an encoded credential rather than prose
+fn looks_like_credential(word: &str) -> bool {
+    word.len() >= MIN_CREDENTIAL_LEN
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+/=._-:".contains(c))
+}
+
+/// Replace the credential following each `Basic` or `Bearer` word
+fn redact_auth_schemes(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut redact_next = false;
+    for (i, word) in text.split(' ').enumerate() {
+        if i > 0 {
+            result.push(' ');
+        }
+        if redact_next && looks_like_credential(word) {
+            result.push_str(REDACTED);
+            redact_next = false;
+            continue;
+        }
+        result.push_str(word);
+        redact_next = AUTH_SCHEMES
+            .iter()
+            .any(|scheme| scheme.eq_ignore_ascii_case(word));
+    }
+    result
+}
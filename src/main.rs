@@ -1,19 +1,13 @@
 use anyhow::Result;
 use std::collections::HashMap;
-use tracing::info;
+use tracing::{info, warn};
 
-mod config;
-mod error;
-mod jira;
-mod logging;
-mod mcp;
 mod test_usage;
-mod types;
-mod utils;
 
-use crate::config::{jira::JiraConfig, ConfigManager, ConfigOptions, SecretManager};
-use crate::logging::{Logger, LoggingConfig, MetricsCollector};
-use crate::mcp::server::MCPServer;
+use rust_jira_mcp::config::{jira::JiraConfig, ConfigManager, ConfigOptions, SecretManager};
+use rust_jira_mcp::logging::{Logger, LoggingConfig, MetricsCollector};
+use rust_jira_mcp::mcp::features::FeatureGates;
+use rust_jira_mcp::mcp::server::MCPServer;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -28,7 +22,7 @@ async fn main() -> Result<()> {
     };
 
     // Set up logging
-    crate::logging::setup_logging(&logging_config);
+    rust_jira_mcp::logging::setup_logging(&logging_config);
 
     // Initialize metrics collector
     let metrics_collector = MetricsCollector::new();
@@ -41,6 +35,11 @@ async fn main() -> Result<()> {
     // Test that our code compiles and uses all functions
     crate::test_usage::test_usage();
 
+    // Diagnose the configuration and exit instead of serving
+    let check_config = std::env::args().skip(1).any(|arg| arg == "--check-config");
+    // Write the tool schemas to a file and exit instead of serving
+    let dump_tools = dump_tools_path(std::env::args().skip(1))?;
+
     // Initialize configuration manager with hot-reloading
     let mut config_manager = ConfigManager::new();
     let config_options = ConfigOptions {
@@ -50,7 +49,9 @@ async fn main() -> Result<()> {
             std::path::PathBuf::from("config/local.toml"),
         ],
         strict_validation: true,
-        fail_on_missing: true,
+        // Missing settings are reported by the check instead, and listing
+        // the tools needs no credentials
+        fail_on_missing: !check_config && dump_tools.is_none(),
     };
 
     // Load configuration with options
@@ -75,11 +76,31 @@ async fn main() -> Result<()> {
     // Load configuration with secrets
     let config_with_secrets = JiraConfig::load_with_secrets(&secret_manager).await?;
 
+    if check_config {
+        let sources = config_manager.get_config_sources();
+        let report =
+            rust_jira_mcp::jira::doctor::run_config_checks(&config_with_secrets, &sources).await;
+        println!("{}", report.render());
+        std::process::exit(i32::from(report.has_failures()));
+    }
+
+    if let Some(path) = dump_tools {
+        let features = FeatureGates::detect(&config_with_secrets).await;
+        let count =
+            MCPServer::with_feature_gates(config_with_secrets, features).dump_tools(&path)?;
+        println!("Wrote {count} tool schemas to {}", path.display());
+        return Ok(());
+    }
+
     // Validate configuration
     config_with_secrets.validate()?;
 
     // Health checks removed for now
 
+    if config_with_secrets.warmup.unwrap_or(false) {
+        rust_jira_mcp::jira::warmup::warm_up(&config_with_secrets).await;
+    }
+
     info!(
         "Configuration loaded successfully: API URL = {}, Email = {}",
         config_with_secrets.api_base_url, config_with_secrets.email
@@ -113,8 +134,36 @@ async fn main() -> Result<()> {
         &startup_metadata,
     );
 
-    // Create and run MCP server with the configuration that includes secrets
-    let mut server = MCPServer::new(config_with_secrets);
+    // Receive Jira webhooks alongside stdio when configured
+    rust_jira_mcp::mcp::transport::webhook::start(&config_with_secrets).await?;
+    // Keep Cloud webhooks registered through register_jira_webhook alive
+    let _webhook_refresher = rust_jira_mcp::jira::webhooks::spawn_refresher(&config_with_secrets);
+    // Keep the issues fetched most often warm when prefetching is enabled
+    let _prefetcher = rust_jira_mcp::jira::prefetch::spawn_prefetcher(&config_with_secrets);
+
+    // Create and run MCP server with the configuration that includes secrets,
+    // leaving out the tool groups the site has no API for
+    let features = FeatureGates::detect(&config_with_secrets).await;
+    let mut server = MCPServer::with_feature_gates(config_with_secrets.clone(), features);
+
+    // Rebuild the server's Jira clients when hot-reloading picks up changes
+    if config_manager.is_hot_reload_enabled() {
+        let mut reloads = config_manager.subscribe();
+        reloads.mark_unchanged();
+        let (updates, config_updates) = tokio::sync::watch::channel(config_with_secrets);
+        tokio::spawn(async move {
+            while reloads.changed().await.is_ok() {
+                let mut config = reloads.borrow_and_update().clone();
+                match config.apply_secrets(&secret_manager).await {
+                    Ok(()) => {
+                        updates.send_replace(config);
+                    }
+                    Err(e) => warn!("Ignoring reloaded configuration: {}", e),
+                }
+            }
+        });
+        server.watch_config(config_updates);
+    }
 
     info!("MCP Server initialized, starting stdio transport");
 
@@ -141,3 +190,19 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// The path given with `--dump-tools <path>` or `--dump-tools=<path>`
+fn dump_tools_path(mut args: impl Iterator<Item = String>) -> Result<Option<std::path::PathBuf>> {
+    while let Some(arg) = args.next() {
+        if let Some(path) = arg.strip_prefix("--dump-tools=") {
+            return Ok(Some(path.into()));
+        }
+        if arg == "--dump-tools" {
+            return args
+                .next()
+                .map(|path| Some(path.into()))
+                .ok_or_else(|| anyhow::anyhow!("--dump-tools needs the path to write to"));
+        }
+    }
+    Ok(None)
+}
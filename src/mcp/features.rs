@@ -0,0 +1,153 @@
+//! Tool groups a server registers, by the add-ons and APIs the Jira site has.
+//!
+//! Sites without Zephyr, Jira Software, Jira Service Management or Assets
+//! answer the requests of those tools with 404, so their tools are only registered, and
+//! listed by `tools/list`, when the group is on. Zephyr defaults to `auto`:
+//! [`FeatureGates::detect`] asks ZAPI once at startup whether it is there.
+
+use crate::config::JiraConfig;
+use crate::jira::client::JiraClient;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Longest the Zephyr probe may take before Zephyr is taken as missing
+pub const ZEPHYR_PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// ZAPI endpoint the probe reads; every ZAPI install answers it
+const ZEPHYR_PROBE_ENDPOINT: &str = "moduleInfo";
+
+/// How a tool group is switched on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureSetting {
+    On,
+    Off,
+    /// On when the site turns out to have the feature
+    Auto,
+}
+
+impl FeatureSetting {
+    /// Parse `true`, `false` or `auto`, ignoring case
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "true" | "on" => Some(Self::On),
+            "false" | "off" => Some(Self::Off),
+            "auto" => Some(Self::Auto),
+            _ => None,
+        }
+    }
+
+    /// The Zephyr setting of `config`; `auto` when unset or invalid
+    fn zephyr(config: &JiraConfig) -> Self {
+        config
+            .zephyr_enabled
+            .as_deref()
+            .and_then(Self::parse)
+            .unwrap_or(Self::Auto)
+    }
+}
+
+/// Tool groups to register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureGates {
+    pub zephyr: bool,
+    pub agile: bool,
+    pub jsm: bool,
+    pub assets: bool,
+    /// What the Zephyr probe found; `None` when ZAPI was not asked
+    pub zephyr_available: Option<bool>,
+}
+
+impl FeatureGates {
+    /// The groups `config` switches on, with Zephyr on under `auto` unless
+    /// `zephyr_available` says ZAPI is missing
+    #[must_use]
+    pub fn resolve(config: &JiraConfig, zephyr_available: Option<bool>) -> Self {
+        Self {
+            zephyr: match FeatureSetting::zephyr(config) {
+                FeatureSetting::On => true,
+                FeatureSetting::Off => false,
+                FeatureSetting::Auto => zephyr_available.unwrap_or(true),
+            },
+            agile: config.agile_enabled.unwrap_or(true),
+            jsm: config.jsm_enabled.unwrap_or(false),
+            assets: config.assets_enabled.unwrap_or(false),
+            zephyr_available,
+        }
+    }
+
+    /// The groups `config` switches on without asking the site
+    #[must_use]
+    pub fn from_config(config: &JiraConfig) -> Self {
+        Self::resolve(config, None)
+    }
+
+    /// The groups a reloaded `config` switches on, keeping what the probe
+    /// found at startup
+    #[must_use]
+    pub fn reconfigure(&self, config: &JiraConfig) -> Self {
+        Self::resolve(config, self.zephyr_available)
+    }
+
+    /// The groups `config` switches on, probing ZAPI when Zephyr is `auto`,
+    /// and log the decision
+    pub async fn detect(config: &JiraConfig) -> Self {
+        let zephyr_available = match FeatureSetting::zephyr(config) {
+            FeatureSetting::Auto => Some(probe_zephyr(config).await),
+            FeatureSetting::On | FeatureSetting::Off => None,
+        };
+        let gates = Self::resolve(config, zephyr_available);
+        info!(
+            "Tool groups: zephyr {}, agile {}, jsm {}, assets {}",
+            on_off(gates.zephyr),
+            on_off(gates.agile),
+            on_off(gates.jsm),
+            on_off(gates.assets)
+        );
+        gates
+    }
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled {
+        "on"
+    } else {
+        "off"
+    }
+}
+
+/// Whether ZAPI answers on the site of `config` within
+/// [`ZEPHYR_PROBE_TIMEOUT`]
+async fn probe_zephyr(config: &JiraConfig) -> bool {
+    let client = match JiraClient::new(config.clone()) {
+        Ok(client) => client,
+        Err(e) => {
+            warn!(
+                "Zephyr tools off: cannot create a client to probe ZAPI: {}",
+                e
+            );
+            return false;
+        }
+    };
+    let probe = client.zephyr_get::<serde_json::Value>(ZEPHYR_PROBE_ENDPOINT);
+    match tokio::time::timeout(ZEPHYR_PROBE_TIMEOUT, probe).await {
+        Ok(Ok(_)) => {
+            info!(
+                "Zephyr tools on: ZAPI answered at {}",
+                client.zephyr_api_base_url()
+            );
+            true
+        }
+        Ok(Err(e)) => {
+            info!("Zephyr tools off: ZAPI is not available ({})", e);
+            false
+        }
+        Err(_) => {
+            warn!(
+                "Zephyr tools off: ZAPI did not answer within {:?}",
+                ZEPHYR_PROBE_TIMEOUT
+            );
+            false
+        }
+    }
+}
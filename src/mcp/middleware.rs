@@ -0,0 +1,298 @@
+//! Middleware applied to every tool result before it is sent to the client,
+//! for output changes that belong to no tool in particular: a footer with the
+//! correlation id, links rewritten to a redirector, a cap on the output,
+//! redaction and, with `anonymize_output`, aliases for the people in it.
+//!
+//! The built-in middleware are configured by the `[response_middleware]`
+//! section, see [`crate::config::response`]. Programs embedding the server
+//! add their own with [`crate::mcp::server::MCPServer::add_response_middleware`]:
+//!
+//! ```
+//! use rust_jira_mcp::mcp::middleware::{ResponseMiddleware, ToolContext};
+//! use rust_jira_mcp::types::mcp::MCPToolResult;
+//!
+//! /// Plain ASCII output for a client that cannot show emoji
+//! struct StripEmoji;
+//!
+//! impl ResponseMiddleware for StripEmoji {
+//!     fn name(&self) -> &str {
+//!         "strip_emoji"
+//!     }
+//!
+//!     fn process(&self, result: &mut MCPToolResult, context: &ToolContext) {
+//!         if context.client_name.as_deref() == Some("legacy-terminal") {
+//!             for item in &mut result.content {
+//!                 item.text.retain(|c| c.is_ascii());
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::config::response::{
+    FooterSettings, LinkRewriteSettings, RedactSettings, ResponseMiddlewareConfig, TruncateSettings,
+};
+use crate::error::{JiraError, Result};
+use crate::logging::sanitize::REDACTED;
+use crate::mcp::tools::formatting::truncate_chars;
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use crate::utils::anonymize;
+use crate::utils::pattern::TextPattern;
+use std::sync::Arc;
+
+/// What middleware know about the call whose result they process
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolContext {
+    pub tool_name: String,
+    /// Id of this call, written to the server's log when middleware run
+    pub correlation_id: String,
+    /// Name the MCP client gave in `initialize`
+    pub client_name: Option<String>,
+}
+
+/// A step of the [`ResponsePipeline`]
+pub trait ResponseMiddleware: Send + Sync {
+    /// Name of the middleware, for logs
+    fn name(&self) -> &str;
+
+    /// Change `result` of the call described by `context` in place
+    fn process(&self, result: &mut MCPToolResult, context: &ToolContext);
+}
+
+/// Middleware run one after another over every tool result
+#[derive(Clone, Default)]
+pub struct ResponsePipeline {
+    middleware: Vec<Arc<dyn ResponseMiddleware>>,
+}
+
+impl std::fmt::Debug for ResponsePipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl ResponsePipeline {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build the built-in middleware of the `[response_middleware]` section in
+    /// their configured order
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the section is invalid
+    pub fn from_config(config: &ResponseMiddlewareConfig) -> Result<Self> {
+        crate::config::response::validate_response_middleware(config)
+            .map_err(|e| JiraError::config_error(&e))?;
+
+        let mut pipeline = Self::new();
+        for name in config.effective_order() {
+            let middleware = match name.as_str() {
+                "redact" => config
+                    .redact
+                    .as_ref()
+                    .map(RedactMiddleware::new)
+                    .transpose()?
+                    .map(shared),
+                "links" => config
+                    .links
+                    .as_ref()
+                    .map(LinkRewriteMiddleware::new)
+                    .transpose()?
+                    .map(shared),
+                "truncate" => config.truncate.map(TruncateMiddleware::new).map(shared),
+                "footer" => config
+                    .footer
+                    .as_ref()
+                    .map(FooterMiddleware::new)
+                    .map(shared),
+                _ => None,
+            };
+            pipeline.middleware.extend(middleware);
+        }
+        Ok(pipeline)
+    }
+
+    /// Add `middleware` to run after those already in the pipeline
+    pub fn push(&mut self, middleware: Arc<dyn ResponseMiddleware>) {
+        self.middleware.push(middleware);
+    }
+
+    /// Names of the middleware, in the order they run
+    #[must_use]
+    pub fn names(&self) -> Vec<&str> {
+        self.middleware.iter().map(|m| m.name()).collect()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.middleware.is_empty()
+    }
+
+    /// Run every middleware over `result`
+    pub fn process(&self, result: &mut MCPToolResult, context: &ToolContext) {
+        for middleware in &self.middleware {
+            middleware.process(result, context);
+        }
+    }
+}
+
+fn shared<M: ResponseMiddleware + 'static>(middleware: M) -> Arc<dyn ResponseMiddleware> {
+    Arc::new(middleware)
+}
+
+fn compile(section: &str, pattern: &str) -> Result<TextPattern> {
+    TextPattern::new(pattern).map_err(|e| {
+        JiraError::config_error(&format!(
+            "Invalid pattern in response_middleware.{section}: {e}"
+        ))
+    })
+}
+
+/// Replaces the matches of patterns in every content item
+#[derive(Debug, Clone)]
+pub struct RedactMiddleware {
+    patterns: Vec<TextPattern>,
+    replacement: String,
+}
+
+impl RedactMiddleware {
+    /// # Errors
+    ///
+    /// Returns a configuration error if a pattern is invalid
+    pub fn new(settings: &RedactSettings) -> Result<Self> {
+        Ok(Self {
+            patterns: settings
+                .patterns
+                .iter()
+                .map(|pattern| compile("redact", pattern))
+                .collect::<Result<_>>()?,
+            replacement: settings
+                .replacement
+                .clone()
+                .unwrap_or_else(|| REDACTED.to_string()),
+        })
+    }
+}
+
+impl ResponseMiddleware for RedactMiddleware {
+    fn name(&self) -> &str {
+        "redact"
+    }
+
+    fn process(&self, result: &mut MCPToolResult, _context: &ToolContext) {
+        for item in &mut result.content {
+            for pattern in &self.patterns {
+                item.text = pattern.replace_all(&item.text, &self.replacement);
+            }
+        }
+    }
+}
+
+/// Masks email addresses and replaces the names of users with aliases, see
+/// [`crate::utils::anonymize`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnonymizeMiddleware;
+
+impl ResponseMiddleware for AnonymizeMiddleware {
+    fn name(&self) -> &str {
+        "anonymize"
+    }
+
+    fn process(&self, result: &mut MCPToolResult, _context: &ToolContext) {
+        for item in &mut result.content {
+            item.text = anonymize::anonymize_text(&item.text);
+        }
+    }
+}
+
+/// Rewrites links matching a pattern
+#[derive(Debug, Clone)]
+pub struct LinkRewriteMiddleware {
+    pattern: TextPattern,
+    replacement: String,
+}
+
+impl LinkRewriteMiddleware {
+    /// # Errors
+    ///
+    /// Returns a configuration error if the pattern is invalid
+    pub fn new(settings: &LinkRewriteSettings) -> Result<Self> {
+        Ok(Self {
+            pattern: compile("links", &settings.pattern)?,
+            replacement: settings.replacement.clone(),
+        })
+    }
+}
+
+impl ResponseMiddleware for LinkRewriteMiddleware {
+    fn name(&self) -> &str {
+        "links"
+    }
+
+    fn process(&self, result: &mut MCPToolResult, _context: &ToolContext) {
+        for item in &mut result.content {
+            item.text = self.pattern.replace_all(&item.text, &self.replacement);
+        }
+    }
+}
+
+/// Cuts each content item down to a number of characters
+#[derive(Debug, Clone, Copy)]
+pub struct TruncateMiddleware {
+    max_chars: usize,
+}
+
+impl TruncateMiddleware {
+    #[must_use]
+    pub fn new(settings: TruncateSettings) -> Self {
+        Self {
+            max_chars: settings.max_chars,
+        }
+    }
+}
+
+impl ResponseMiddleware for TruncateMiddleware {
+    fn name(&self) -> &str {
+        "truncate"
+    }
+
+    fn process(&self, result: &mut MCPToolResult, _context: &ToolContext) {
+        for item in &mut result.content {
+            if let Some((kept, omitted)) = truncate_chars(&item.text, self.max_chars) {
+                item.text = format!("{kept}\n[… truncated: {omitted} more characters]");
+            }
+        }
+    }
+}
+
+/// Adds a footer as the last content item
+#[derive(Debug, Clone)]
+pub struct FooterMiddleware {
+    text: String,
+}
+
+impl FooterMiddleware {
+    #[must_use]
+    pub fn new(settings: &FooterSettings) -> Self {
+        Self {
+            text: settings.text.clone(),
+        }
+    }
+}
+
+impl ResponseMiddleware for FooterMiddleware {
+    fn name(&self) -> &str {
+        "footer"
+    }
+
+    fn process(&self, result: &mut MCPToolResult, context: &ToolContext) {
+        let footer = self
+            .text
+            .replace("{correlation_id}", &context.correlation_id)
+            .replace("{tool}", &context.tool_name);
+        result.content.push(MCPContent::text(footer));
+    }
+}
@@ -1,3 +1,6 @@
+pub mod features;
+pub mod middleware;
 pub mod server;
 pub mod tools;
+pub mod transport;
 pub mod zephyr_tools;
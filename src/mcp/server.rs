@@ -1,18 +1,34 @@
+use crate::config::manager::config_changes;
+use crate::config::policies::check_write;
 use crate::config::JiraConfig;
 use crate::error::Result;
+use crate::jira::events::jira_events;
+use crate::jira::telemetry;
+use crate::jira::warmup::last_warmup;
+use crate::logging::capture;
+use crate::mcp::features::FeatureGates;
+use crate::mcp::middleware::{
+    AnonymizeMiddleware, ResponseMiddleware, ResponsePipeline, ToolContext,
+};
+use crate::mcp::tools::context::read_context;
 use crate::mcp::tools::{
     AddCommentTool,
+    AddCustomerCommentTool,
     AddIssueWatcherTool,
     AddIssuesToSprintTool,
     AddWorkLogTool,
     BulkAddCommentsTool,
+    BulkCreateIssuesFromCsvTool,
+    BulkOperationsFromCsvTool,
     BulkTransitionIssuesTool,
     BulkUpdateIssuesTool,
     // Issue Cloning Tools
     CloneIssueTool,
     CloseSprintTool,
+    CountIssuesTool,
     // Issue Component Tools
     CreateComponentTool,
+    CreateCustomerRequestTool,
     CreateIssueLinkTool,
     CreateIssueTool,
     CreateLabelTool,
@@ -21,65 +37,179 @@ use crate::mcp::tools::{
     DeleteComponentTool,
     DeleteIssueLinkTool,
     DeleteLabelTool,
+    DeleteWebhookTool,
     DeleteWorkLogTool,
+    DownloadAllIssueAttachmentsTool,
     DownloadAttachmentTool,
+    ExplainJiraErrorTool,
+    ExportIssueBundleTool,
+    GetAssetObjectTool,
+    GetAttachmentInfoTool,
     GetBoardSprintsTool,
+    GetBulkOperationDetailTool,
+    GetBulkOperationHistoryTool,
     GetCommentsTool,
     GetCustomFieldsTool,
+    GetFieldHistoryTool,
     // File Attachment Tools
     GetIssueAttachmentsTool,
+    GetIssueFieldTool,
+    GetIssueLinkGraphTool,
     GetIssueLinksTool,
+    GetIssueSecurityLevelsTool,
     GetIssueTool,
+    GetIssueTypeHierarchyTool,
     GetIssueTypeMetadataTool,
     GetIssueTypesTool,
     // Issue Watcher Tools
     GetIssueWatchersTool,
     // Work Log Tools
     GetIssueWorkLogsTool,
+    GetJiraContextTool,
     // Issue Label Tools
     GetLabelsTool,
     // Issue Linking Tools
     GetLinkTypesTool,
+    GetPerformanceProfileTool,
     GetPrioritiesAndStatusesTool,
+    GetProjectCategoriesTool,
     GetProjectComponentsTool,
     GetProjectConfigTool,
     GetProjectMetadataTool,
+    GetProjectStatsTool,
+    GetProjectWorkflowTool,
+    GetRecentJiraEventsTool,
+    GetRequestSlaTool,
+    GetRequestTypesTool,
+    GetServerInfoTool,
+    // Server Metrics Tools
+    GetServerMetricsTool,
+    GetServiceDesksTool,
     GetSprintIssuesTool,
     GetSprintTool,
     GetTransitionsTool,
+    ImportIssueBundleTool,
+    IssueActionTool,
     LinkIssuesTool,
+    ListCreatableFieldsTool,
+    ListProjectsTool,
+    ListWebhooksTool,
+    ListWritePoliciesTool,
     MixedBulkOperationsTool,
+    PlanIssueCreationTool,
+    PreviewIssueUpdateTool,
+    RegisterWebhookTool,
     RemoveIssueWatcherTool,
+    SearchAssetObjectsTool,
+    SearchIssueContentTool,
     SearchIssuesTool,
+    SearchTextTool,
+    SetIssueParentTool,
+    SetJiraContextTool,
+    SharedSessionContext,
+    SprintReportTool,
     StartSprintTool,
+    StatusTimelineTool,
+    SummarizeEpicTool,
     TestAuthTool,
     TransitionIssueTool,
+    UndoBulkOperationTool,
+    UnwatchIssueTool,
+    UpdateCommentTool,
     UpdateComponentTool,
     UpdateIssueTool,
     UpdateLabelTool,
     UpdateWorkLogTool,
     UploadAttachmentTool,
+    UploadDirectoryAttachmentsTool,
+    ValidateConfigurationTool,
+    WatchIssueTool,
 };
+use crate::mcp::transport::response_limit;
+use crate::mcp::transport::stdio::MessageStream;
 use crate::mcp::zephyr_tools::{
-    CreateZephyrTestCaseTool, CreateZephyrTestExecutionTool, CreateZephyrTestStepTool,
-    DeleteZephyrTestStepTool, GetZephyrTestCasesTool, GetZephyrTestCyclesTool,
+    CreateZephyrFolderTool, CreateZephyrTestCaseTool, CreateZephyrTestExecutionTool,
+    CreateZephyrTestStepTool, DeleteZephyrTestStepTool, GetZephyrExecutionAttachmentsTool,
+    GetZephyrFoldersTool, GetZephyrTestCasesTool, GetZephyrTestCyclesTool,
     GetZephyrTestExecutionsTool, GetZephyrTestPlansTool, GetZephyrTestStepsTool,
-    UpdateZephyrTestStepTool,
+    UpdateZephyrTestStepTool, UploadZephyrExecutionAttachmentTool, ZephyrCycleReportTool,
+    ZephyrTraceabilityReportTool,
 };
+use crate::performance::{get_global_metrics, profiler};
 use crate::types::mcp::{
-    CallToolParams, CallToolResult, InitializeParams, InitializeResult, JsonRpcError,
-    JsonRpcRequest, JsonRpcResponse, ListToolsParams, ListToolsResult, MCPTool, MCPToolCall,
-    MCPToolResult, ServerCapabilities, ServerInfo, ToolsCapability,
+    CallToolParams, CallToolResult, ClientSession, InitializeParams, InitializeResult,
+    JsonRpcError, JsonRpcRequest, JsonRpcResponse, ListToolsParams, ListToolsResult, MCPContent,
+    MCPTool, MCPToolCall, MCPToolResult, ServerCapabilities, ServerInfo, ToolAnnotations,
+    ToolCategory, ToolMeta, ToolsCapability,
 };
+use crate::utils::anonymize;
+use futures::stream::{self, StreamExt};
 use serde_json::json;
 use std::collections::HashMap;
-use tokio::io::{self, AsyncBufReadExt, AsyncWriteExt, BufReader};
+use std::sync::{Arc, LazyLock};
+use tokio::io::{self, AsyncRead, AsyncWrite};
+use tokio::sync::watch;
 use tracing::{error, info, warn};
 
+/// Appended to the description of every tool that reports a bulk summary
+const BULK_SUMMARY_SCHEMA: &str = "The result is a text report followed by a second content item holding the summary as JSON: {\"total_operations\": int, \"successful_operations\": int, \"failed_operations\": int, \"skipped_operations\": int, \"duration_ms\": int, \"history_id\": string, \"settings\": {\"project\": string|null, \"settings\": [[name, value, {\"level\": \"call\"|\"project\"|\"global\"|\"built_in\", \"project\"?: string}]]}, \"results\": [{\"issue_key\": string, \"success\": bool, \"error_message\": string|null, \"operation_type\": \"update\"|\"transition\"|\"add_comment\"|\"mixed\"|\"add_watcher\"|\"remove_watcher\", \"error_kind\": null|\"permission_denied\"|\"not_found\"|\"validation\"|\"auth\"|\"rate_limited\"|\"network\"|\"invalid_response\"|\"other\", \"skipped\": bool}]}. Skipped operations were not attempted and count as neither successful nor failed; their error_message says why. history_id looks the operation up again with get_bulk_operation_detail.";
+
+/// Tools whose output follows the `verbosity` argument
+pub const VERBOSITY_TOOLS: [&str; 10] = [
+    "search_jira_issues",
+    "get_jira_issue",
+    "get_jira_comments",
+    "get_jira_transitions",
+    "get_jira_issue_attachments",
+    "bulk_update_issues",
+    "bulk_transition_issues",
+    "bulk_add_comments",
+    "mixed_bulk_operations",
+    "undo_bulk_operation",
+];
+
+/// MCP protocol versions the server speaks, newest first. 2025-03-26 needs
+/// JSON-RPC batches, which [`MCPServer::handle_batch`] answers.
+pub const SUPPORTED_PROTOCOL_VERSIONS: [&str; 2] = ["2025-03-26", "2024-11-05"];
+
+/// Tool calls of one batch that run at the same time
+pub const BATCH_CONCURRENCY: usize = 4;
+
+/// JSON-RPC error code for requests that need an initialized session
+const SERVER_NOT_INITIALIZED: i32 = -32002;
+
+/// Input schema of every tool by name, for filling arguments from the
+/// session context
+static TOOL_SCHEMAS: LazyLock<HashMap<String, serde_json::Value>> = LazyLock::new(|| {
+    MCPServer::list_tools()
+        .into_iter()
+        .map(|tool| (tool.name, tool.input_schema))
+        .collect()
+});
+
 pub struct MCPServer {
     config: JiraConfig,
     tools: HashMap<String, Box<dyn MCPToolHandler + Send + Sync>>,
+    /// Set by the `initialize` request
+    session: Option<ClientSession>,
+    /// Set once the client confirms with `notifications/initialized`
     initialized: bool,
+    /// Notifications waiting to be sent to the client
+    notifications: Vec<serde_json::Value>,
+    /// Sequence number of the last webhook event sent to the client
+    last_event_sequence: u64,
+    /// Reloaded configurations, set by [`MCPServer::watch_config`]
+    config_updates: Option<watch::Receiver<JiraConfig>>,
+    /// Middleware every tool result passes through
+    pipeline: ResponsePipeline,
+    /// Middleware added with [`MCPServer::add_response_middleware`], run
+    /// after the configured ones
+    custom_middleware: Vec<Arc<dyn ResponseMiddleware>>,
+    /// Project, board, sprint and issue set with `set_jira_context`, kept
+    /// across configuration reloads
+    session_context: SharedSessionContext,
+    /// Tool groups registered
+    features: FeatureGates,
 }
 
 #[async_trait::async_trait]
@@ -88,29 +218,150 @@ pub trait MCPToolHandler {
 }
 
 impl MCPServer {
-    /// Create a new MCP server with the given configuration.
+    /// Create a new MCP server with the given configuration, with the tool
+    /// groups it switches on and Zephyr on unless set off.
     #[must_use]
     pub fn new(config: JiraConfig) -> Self {
-        let mut tools: HashMap<String, Box<dyn MCPToolHandler + Send + Sync>> = HashMap::new();
-
-        Self::register_basic_tools(&mut tools, &config);
-        Self::register_project_tools(&mut tools, &config);
-        Self::register_bulk_tools(&mut tools, &config);
-        Self::register_linking_tools(&mut tools, &config);
-        Self::register_attachment_tools(&mut tools, &config);
-        Self::register_worklog_tools(&mut tools, &config);
-        Self::register_watcher_tools(&mut tools, &config);
-        Self::register_label_tools(&mut tools, &config);
-        Self::register_component_tools(&mut tools, &config);
-        Self::register_cloning_tools(&mut tools, &config);
-        Self::register_sprint_tools(&mut tools, &config);
-        Self::register_zephyr_tools(&mut tools, &config);
+        let features = FeatureGates::from_config(&config);
+        Self::with_feature_gates(config, features)
+    }
 
+    /// Create a new MCP server registering the tool groups of `features`,
+    /// e.g. as found by [`FeatureGates::detect`].
+    #[must_use]
+    pub fn with_feature_gates(config: JiraConfig, features: FeatureGates) -> Self {
+        let session_context = SharedSessionContext::default();
         Self {
+            tools: Self::build_tools(&config, &session_context, features),
+            pipeline: Self::build_pipeline(&config, &[]),
             config,
-            tools,
+            session: None,
             initialized: false,
+            notifications: Vec::new(),
+            last_event_sequence: jira_events().last_sequence(),
+            config_updates: None,
+            custom_middleware: Vec::new(),
+            session_context,
+            features,
+        }
+    }
+
+    /// The configured response middleware followed by `custom` ones. An
+    /// invalid `[response_middleware]` section is logged and skipped.
+    fn build_pipeline(
+        config: &JiraConfig,
+        custom: &[Arc<dyn ResponseMiddleware>],
+    ) -> ResponsePipeline {
+        let mut pipeline = config
+            .response_middleware
+            .as_ref()
+            .map_or_else(
+                || Ok(ResponsePipeline::new()),
+                ResponsePipeline::from_config,
+            )
+            .unwrap_or_else(|e| {
+                warn!("Response middleware disabled: {}", e);
+                ResponsePipeline::new()
+            });
+        for middleware in custom {
+            pipeline.push(middleware.clone());
+        }
+        // Last, so that nothing added to the output escapes it
+        if config.anonymize_output.unwrap_or(false) {
+            pipeline.push(Arc::new(AnonymizeMiddleware));
+        }
+        pipeline
+    }
+
+    /// Run `middleware` over every tool result, after the middleware of the
+    /// `[response_middleware]` section and those added before
+    pub fn add_response_middleware(&mut self, middleware: Arc<dyn ResponseMiddleware>) {
+        self.custom_middleware.push(middleware);
+        self.pipeline = Self::build_pipeline(&self.config, &self.custom_middleware);
+    }
+
+    /// Create the tool handlers and their Jira clients for `config`, for the
+    /// tool groups `features` switches on
+    fn build_tools(
+        config: &JiraConfig,
+        session_context: &SharedSessionContext,
+        features: FeatureGates,
+    ) -> HashMap<String, Box<dyn MCPToolHandler + Send + Sync>> {
+        let mut tools: HashMap<String, Box<dyn MCPToolHandler + Send + Sync>> = HashMap::new();
+
+        Self::register_basic_tools(&mut tools, config);
+        Self::register_context_tools(&mut tools, config, session_context);
+        Self::register_project_tools(&mut tools, config);
+        Self::register_bulk_tools(&mut tools, config);
+        Self::register_linking_tools(&mut tools, config);
+        Self::register_attachment_tools(&mut tools, config);
+        Self::register_worklog_tools(&mut tools, config);
+        Self::register_watcher_tools(&mut tools, config);
+        Self::register_label_tools(&mut tools, config);
+        Self::register_component_tools(&mut tools, config);
+        Self::register_cloning_tools(&mut tools, config);
+        if features.agile {
+            Self::register_sprint_tools(&mut tools, config);
+        }
+        Self::register_report_tools(&mut tools, config);
+        if features.zephyr {
+            Self::register_zephyr_tools(&mut tools, config);
+        }
+        Self::register_metrics_tools(&mut tools, config);
+        Self::register_event_tools(&mut tools, config);
+        if features.jsm {
+            Self::register_jsm_tools(&mut tools, config);
+        }
+        if features.assets {
+            Self::register_assets_tools(&mut tools, config);
+        }
+
+        tools
+    }
+
+    /// Follow configuration reloads published on `updates`.
+    ///
+    /// Before handling each message the server checks for a new configuration
+    /// and, when it changed, rebuilds its tools and their Jira clients from it.
+    /// Tool calls already running finish with the clients they started with.
+    pub fn watch_config(&mut self, updates: watch::Receiver<JiraConfig>) {
+        self.config_updates = Some(updates);
+    }
+
+    /// Switch to the configuration last published to
+    /// [`MCPServer::watch_config`], if it is new and differs from the current
+    fn apply_config_update(&mut self) {
+        let Some(updates) = self.config_updates.as_mut() else {
+            return;
+        };
+        if !updates.has_changed().unwrap_or(false) {
+            return;
+        }
+        let config = updates.borrow_and_update().clone();
+
+        let changes = config_changes(&self.config, &config);
+        if changes.is_empty() {
+            return;
+        }
+        info!(
+            "Configuration changed, rebuilding Jira clients: {}",
+            changes.join("; ")
+        );
+
+        let rate_limits_changed = config.throttle_threshold_percent
+            != self.config.throttle_threshold_percent
+            || config.core_requests_per_second != self.config.core_requests_per_second
+            || config.agile_requests_per_second != self.config.agile_requests_per_second
+            || config.zephyr_requests_per_second != self.config.zephyr_requests_per_second
+            || config.attachment_requests_per_second != self.config.attachment_requests_per_second;
+        if rate_limits_changed {
+            crate::jira::throttle::replace_shared_schedulers(&config);
         }
+
+        self.features = self.features.reconfigure(&config);
+        self.tools = Self::build_tools(&config, &self.session_context, self.features);
+        self.pipeline = Self::build_pipeline(&config, &self.custom_middleware);
+        self.config = config;
     }
 
     /// Register basic Jira tools
@@ -122,10 +373,30 @@ impl MCPServer {
             "test_jira_auth".to_string(),
             Box::new(TestAuthTool::new(config.clone())),
         );
+        tools.insert(
+            "validate_configuration".to_string(),
+            Box::new(ValidateConfigurationTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_jira_server_info".to_string(),
+            Box::new(GetServerInfoTool::new(config.clone())),
+        );
         tools.insert(
             "search_jira_issues".to_string(),
             Box::new(SearchIssuesTool::new(config.clone())),
         );
+        tools.insert(
+            "count_jira_issues".to_string(),
+            Box::new(CountIssuesTool::new(config.clone())),
+        );
+        tools.insert(
+            "search_jira_text".to_string(),
+            Box::new(SearchTextTool::new(config.clone())),
+        );
+        tools.insert(
+            "search_issue_content".to_string(),
+            Box::new(SearchIssueContentTool::new(config.clone())),
+        );
         tools.insert(
             "create_jira_issue".to_string(),
             Box::new(CreateIssueTool::new(config.clone())),
@@ -134,10 +405,26 @@ impl MCPServer {
             "update_jira_issue".to_string(),
             Box::new(UpdateIssueTool::new(config.clone())),
         );
+        tools.insert(
+            "preview_issue_update".to_string(),
+            Box::new(PreviewIssueUpdateTool::new(config.clone())),
+        );
+        tools.insert(
+            "set_jira_issue_parent".to_string(),
+            Box::new(SetIssueParentTool::new(config.clone())),
+        );
+        tools.insert(
+            "jira_issue_action".to_string(),
+            Box::new(IssueActionTool::new(config.clone())),
+        );
         tools.insert(
             "get_jira_issue".to_string(),
             Box::new(GetIssueTool::new(config.clone())),
         );
+        tools.insert(
+            "get_jira_issue_field".to_string(),
+            Box::new(GetIssueFieldTool::new(config.clone())),
+        );
         tools.insert(
             "get_jira_comments".to_string(),
             Box::new(GetCommentsTool::new(config.clone())),
@@ -146,6 +433,10 @@ impl MCPServer {
             "add_jira_comment".to_string(),
             Box::new(AddCommentTool::new(config.clone())),
         );
+        tools.insert(
+            "update_jira_comment".to_string(),
+            Box::new(UpdateCommentTool::new(config.clone())),
+        );
         tools.insert(
             "get_jira_transitions".to_string(),
             Box::new(GetTransitionsTool::new(config.clone())),
@@ -161,6 +452,14 @@ impl MCPServer {
         tools: &mut HashMap<String, Box<dyn MCPToolHandler + Send + Sync>>,
         config: &JiraConfig,
     ) {
+        tools.insert(
+            "list_jira_projects".to_string(),
+            Box::new(ListProjectsTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_jira_project_categories".to_string(),
+            Box::new(GetProjectCategoriesTool::new(config.clone())),
+        );
         tools.insert(
             "get_project_config".to_string(),
             Box::new(GetProjectConfigTool::new(config.clone())),
@@ -169,6 +468,10 @@ impl MCPServer {
             "get_project_issue_types".to_string(),
             Box::new(GetIssueTypesTool::new(config.clone())),
         );
+        tools.insert(
+            "get_issue_type_hierarchy".to_string(),
+            Box::new(GetIssueTypeHierarchyTool::new(config.clone())),
+        );
         tools.insert(
             "get_issue_type_metadata".to_string(),
             Box::new(GetIssueTypeMetadataTool::new(config.clone())),
@@ -189,6 +492,22 @@ impl MCPServer {
             "get_project_metadata".to_string(),
             Box::new(GetProjectMetadataTool::new(config.clone())),
         );
+        tools.insert(
+            "get_project_workflow".to_string(),
+            Box::new(GetProjectWorkflowTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_issue_security_levels".to_string(),
+            Box::new(GetIssueSecurityLevelsTool::new(config.clone())),
+        );
+        tools.insert(
+            "list_creatable_fields".to_string(),
+            Box::new(ListCreatableFieldsTool::new(config.clone())),
+        );
+        tools.insert(
+            "plan_issue_creation".to_string(),
+            Box::new(PlanIssueCreationTool::new(config.clone())),
+        );
     }
 
     /// Register bulk operation tools
@@ -212,6 +531,26 @@ impl MCPServer {
             "mixed_bulk_operations".to_string(),
             Box::new(MixedBulkOperationsTool::new(config.clone())),
         );
+        tools.insert(
+            "bulk_operations_from_csv".to_string(),
+            Box::new(BulkOperationsFromCsvTool::new(config.clone())),
+        );
+        tools.insert(
+            "bulk_create_issues_from_csv".to_string(),
+            Box::new(BulkCreateIssuesFromCsvTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_bulk_operation_history".to_string(),
+            Box::new(GetBulkOperationHistoryTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_bulk_operation_detail".to_string(),
+            Box::new(GetBulkOperationDetailTool::new(config.clone())),
+        );
+        tools.insert(
+            "undo_bulk_operation".to_string(),
+            Box::new(UndoBulkOperationTool::new(config.clone())),
+        );
     }
 
     /// Register issue linking tools
@@ -227,6 +566,10 @@ impl MCPServer {
             "get_jira_issue_links".to_string(),
             Box::new(GetIssueLinksTool::new(config.clone())),
         );
+        tools.insert(
+            "get_issue_link_graph".to_string(),
+            Box::new(GetIssueLinkGraphTool::new(config.clone())),
+        );
         tools.insert(
             "create_jira_issue_link".to_string(),
             Box::new(CreateIssueLinkTool::new(config.clone())),
@@ -262,6 +605,18 @@ impl MCPServer {
             "download_jira_attachment".to_string(),
             Box::new(DownloadAttachmentTool::new(config.clone())),
         );
+        tools.insert(
+            "get_jira_attachment_info".to_string(),
+            Box::new(GetAttachmentInfoTool::new(config.clone())),
+        );
+        tools.insert(
+            "download_all_issue_attachments".to_string(),
+            Box::new(DownloadAllIssueAttachmentsTool::new(config.clone())),
+        );
+        tools.insert(
+            "upload_directory_attachments".to_string(),
+            Box::new(UploadDirectoryAttachmentsTool::new(config.clone())),
+        );
     }
 
     /// Register work log tools
@@ -304,6 +659,14 @@ impl MCPServer {
             "remove_jira_issue_watcher".to_string(),
             Box::new(RemoveIssueWatcherTool::new(config.clone())),
         );
+        tools.insert(
+            "watch_jira_issue".to_string(),
+            Box::new(WatchIssueTool::new(config.clone())),
+        );
+        tools.insert(
+            "unwatch_jira_issue".to_string(),
+            Box::new(UnwatchIssueTool::new(config.clone())),
+        );
     }
 
     /// Register issue label tools
@@ -357,6 +720,37 @@ impl MCPServer {
             "clone_jira_issue".to_string(),
             Box::new(CloneIssueTool::new(config.clone())),
         );
+        tools.insert(
+            "export_issue_bundle".to_string(),
+            Box::new(ExportIssueBundleTool::new(config.clone())),
+        );
+        tools.insert(
+            "import_issue_bundle".to_string(),
+            Box::new(ImportIssueBundleTool::new(config.clone())),
+        );
+    }
+
+    /// Register reporting tools
+    fn register_report_tools(
+        tools: &mut HashMap<String, Box<dyn MCPToolHandler + Send + Sync>>,
+        config: &JiraConfig,
+    ) {
+        tools.insert(
+            "status_timeline".to_string(),
+            Box::new(StatusTimelineTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_field_history".to_string(),
+            Box::new(GetFieldHistoryTool::new(config.clone())),
+        );
+        tools.insert(
+            "summarize_epic".to_string(),
+            Box::new(SummarizeEpicTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_project_stats".to_string(),
+            Box::new(GetProjectStatsTool::new(config.clone())),
+        );
     }
 
     /// Register sprint management tools
@@ -392,6 +786,30 @@ impl MCPServer {
             "get_board_sprints".to_string(),
             Box::new(GetBoardSprintsTool::new(config.clone())),
         );
+        tools.insert(
+            "sprint_report".to_string(),
+            Box::new(SprintReportTool::new(config.clone())),
+        );
+    }
+
+    /// Register the session context tools, which share `session_context`
+    /// with the server
+    fn register_context_tools(
+        tools: &mut HashMap<String, Box<dyn MCPToolHandler + Send + Sync>>,
+        config: &JiraConfig,
+        session_context: &SharedSessionContext,
+    ) {
+        tools.insert(
+            "set_jira_context".to_string(),
+            Box::new(SetJiraContextTool::new(
+                config.clone(),
+                session_context.clone(),
+            )),
+        );
+        tools.insert(
+            "get_jira_context".to_string(),
+            Box::new(GetJiraContextTool::new(session_context.clone())),
+        );
     }
 
     /// Register Zephyr test management tools
@@ -431,14 +849,126 @@ impl MCPServer {
             "create_zephyr_test_execution".to_string(),
             Box::new(CreateZephyrTestExecutionTool::new(config.clone())),
         );
+        tools.insert(
+            "upload_zephyr_execution_attachment".to_string(),
+            Box::new(UploadZephyrExecutionAttachmentTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_zephyr_execution_attachments".to_string(),
+            Box::new(GetZephyrExecutionAttachmentsTool::new(config.clone())),
+        );
         tools.insert(
             "get_zephyr_test_cycles".to_string(),
             Box::new(GetZephyrTestCyclesTool::new(config.clone())),
         );
+        tools.insert(
+            "get_zephyr_folders".to_string(),
+            Box::new(GetZephyrFoldersTool::new(config.clone())),
+        );
+        tools.insert(
+            "create_zephyr_folder".to_string(),
+            Box::new(CreateZephyrFolderTool::new(config.clone())),
+        );
+        tools.insert(
+            "zephyr_cycle_report".to_string(),
+            Box::new(ZephyrCycleReportTool::new(config.clone())),
+        );
         tools.insert(
             "get_zephyr_test_plans".to_string(),
             Box::new(GetZephyrTestPlansTool::new(config.clone())),
         );
+        tools.insert(
+            "zephyr_traceability_report".to_string(),
+            Box::new(ZephyrTraceabilityReportTool::new(config.clone())),
+        );
+    }
+
+    /// Register server metrics tools
+    fn register_metrics_tools(
+        tools: &mut HashMap<String, Box<dyn MCPToolHandler + Send + Sync>>,
+        config: &JiraConfig,
+    ) {
+        tools.insert(
+            "get_server_metrics".to_string(),
+            Box::new(GetServerMetricsTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_performance_profile".to_string(),
+            Box::new(GetPerformanceProfileTool::new(config.clone())),
+        );
+        tools.insert(
+            "list_write_policies".to_string(),
+            Box::new(ListWritePoliciesTool::new(config.clone())),
+        );
+        tools.insert(
+            "explain_jira_error".to_string(),
+            Box::new(ExplainJiraErrorTool::new(config.clone())),
+        );
+    }
+
+    /// Register webhook event tools
+    fn register_event_tools(
+        tools: &mut HashMap<String, Box<dyn MCPToolHandler + Send + Sync>>,
+        config: &JiraConfig,
+    ) {
+        tools.insert(
+            "get_recent_jira_events".to_string(),
+            Box::new(GetRecentJiraEventsTool::new(config.clone())),
+        );
+        tools.insert(
+            "list_jira_webhooks".to_string(),
+            Box::new(ListWebhooksTool::new(config.clone())),
+        );
+        tools.insert(
+            "register_jira_webhook".to_string(),
+            Box::new(RegisterWebhookTool::new(config.clone())),
+        );
+        tools.insert(
+            "delete_jira_webhook".to_string(),
+            Box::new(DeleteWebhookTool::new(config.clone())),
+        );
+    }
+
+    /// Register Jira Service Management tools
+    fn register_jsm_tools(
+        tools: &mut HashMap<String, Box<dyn MCPToolHandler + Send + Sync>>,
+        config: &JiraConfig,
+    ) {
+        tools.insert(
+            "get_jsm_service_desks".to_string(),
+            Box::new(GetServiceDesksTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_jsm_request_types".to_string(),
+            Box::new(GetRequestTypesTool::new(config.clone())),
+        );
+        tools.insert(
+            "create_jsm_customer_request".to_string(),
+            Box::new(CreateCustomerRequestTool::new(config.clone())),
+        );
+        tools.insert(
+            "get_jsm_request_sla".to_string(),
+            Box::new(GetRequestSlaTool::new(config.clone())),
+        );
+        tools.insert(
+            "add_jsm_customer_comment".to_string(),
+            Box::new(AddCustomerCommentTool::new(config.clone())),
+        );
+    }
+
+    /// Register Assets (Insight) tools
+    fn register_assets_tools(
+        tools: &mut HashMap<String, Box<dyn MCPToolHandler + Send + Sync>>,
+        config: &JiraConfig,
+    ) {
+        tools.insert(
+            "get_jira_asset".to_string(),
+            Box::new(GetAssetObjectTool::new(config.clone())),
+        );
+        tools.insert(
+            "search_jira_assets".to_string(),
+            Box::new(SearchAssetObjectsTool::new(config.clone())),
+        );
     }
 
     /// Run the MCP server with stdio transport.
@@ -450,64 +980,64 @@ impl MCPServer {
         info!("Starting MCP server with stdio transport");
         info!("Configuration: API URL = {}", self.config.api_base_url);
 
-        let stdin = io::stdin();
-        let stdout = io::stdout();
-        let mut reader = BufReader::new(stdin);
-        let mut writer = stdout;
+        self.serve(io::stdin(), io::stdout()).await
+    }
 
-        let mut line = String::new();
+    /// Serve JSON-RPC messages from `reader` until it ends, writing the
+    /// responses to `writer`. Messages are newline-delimited or framed with a
+    /// `Content-Length` header, whichever the first message uses (see
+    /// [`MessageStream`]). A message holding an array is a batch and is
+    /// answered with an array.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading or writing fails or a message is not
+    /// framed properly.
+    pub async fn serve<R, W>(&mut self, reader: R, writer: W) -> Result<()>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut stream = MessageStream::new(reader, writer);
 
         info!("MCP server is running and ready to accept requests");
 
         loop {
-            line.clear();
-            match reader.read_line(&mut line).await {
-                Ok(0) => {
+            let message = match stream.read_message().await {
+                Ok(Some(message)) => message,
+                Ok(None) => {
                     info!("EOF received, shutting down");
                     break;
                 }
-                Ok(_) => {
-                    let trimmed = line.trim();
-                    if trimmed.is_empty() {
-                        continue;
-                    }
-
-                    match self.handle_request(trimmed).await {
-                        Ok(Some(response)) => {
-                            let response_json = serde_json::to_string(&response)?;
-                            writer.write_all(response_json.as_bytes()).await?;
-                            writer.write_all(b"\n").await?;
-                            writer.flush().await?;
-                        }
-                        Ok(None) => {
-                            // Notification, no response needed
-                        }
-                        Err(e) => {
-                            error!("Error handling request: {}", e);
-                            // Send error response
-                            let error_response = JsonRpcResponse {
-                                jsonrpc: "2.0".to_string(),
-                                id: None,
-                                result: None,
-                                error: Some(JsonRpcError {
-                                    code: -32603,
-                                    message: "Internal error".to_string(),
-                                    data: Some(json!({ "details": e.to_string() })),
-                                }),
-                            };
-                            let error_json = serde_json::to_string(&error_response)?;
-                            writer.write_all(error_json.as_bytes()).await?;
-                            writer.write_all(b"\n").await?;
-                            writer.flush().await?;
-                        }
-                    }
-                }
                 Err(e) => {
                     error!("Error reading from stdin: {}", e);
                     return Err(crate::error::JiraError::unknown_error(&format!(
                         "IO error: {e}"
                     )));
                 }
+            };
+
+            let reply = if message.trim_start().starts_with('[') {
+                self.handle_batch(&message).await
+            } else {
+                match self.handle_request(&message).await {
+                    Ok(response) => response.map(serde_json::to_value).transpose()?,
+                    Err(e) => {
+                        error!("Error handling request: {}", e);
+                        Some(serde_json::to_value(Self::internal_error(None, &e))?)
+                    }
+                }
+            };
+
+            // Notifications get no reply
+            if let Some(reply) = reply {
+                stream.write_message(&self.encode_reply(&reply)?).await?;
+            }
+            self.queue_jira_events();
+            for notification in self.take_notifications() {
+                stream
+                    .write_message(&serde_json::to_string(&notification)?)
+                    .await?;
             }
         }
 
@@ -515,61 +1045,355 @@ impl MCPServer {
         Ok(())
     }
 
-    /// Handle a JSON-RPC request
-    async fn handle_request(&mut self, request_str: &str) -> Result<Option<JsonRpcResponse>> {
-        let request: JsonRpcRequest = serde_json::from_str(request_str)?;
+    /// `reply` serialized for writing, replaced if it exceeds
+    /// `max_response_bytes`, with its size recorded in the metrics
+    fn encode_reply(&self, reply: &serde_json::Value) -> Result<String> {
+        let encoded = response_limit::encode_reply(reply, self.config.max_response_bytes)?;
+        get_global_metrics().record_response_size(encoded.original_bytes, encoded.replaced);
+        if encoded.replaced {
+            warn!(
+                "Replaced a response of {} bytes, over the {} bytes max_response_bytes allows",
+                encoded.original_bytes,
+                self.config.max_response_bytes.unwrap_or_default()
+            );
+        }
+        Ok(encoded.text)
+    }
 
-        let response = match request.method.as_str() {
-            "initialize" => Self::handle_initialize(request)?,
-            "tools/list" => Self::handle_list_tools(request)?,
-            "tools/call" => self.handle_call_tool(request).await?,
-            "notifications/initialized" => {
-                // Handle initialization notification
-                self.initialized = true;
-                info!("MCP client initialized successfully");
-                return Ok(None);
-            }
-            _ => {
-                warn!("Unknown method: {}", request.method);
-                return Ok(Some(JsonRpcResponse {
-                    jsonrpc: "2.0".to_string(),
-                    id: request.id,
-                    result: None,
-                    error: Some(JsonRpcError {
-                        code: -32601,
-                        message: "Method not found".to_string(),
-                        data: None,
-                    }),
-                }));
+    /// Handle a JSON-RPC batch: the responses to its requests in order, or
+    /// `None` if it only held notifications.
+    ///
+    /// Entries run in order, as each may depend on the session state an
+    /// earlier one set up (e.g. `initialize`), except that consecutive tool
+    /// calls run concurrently, up to [`BATCH_CONCURRENCY`] at a time.
+    /// Entries that are not requests are answered with an "Invalid Request"
+    /// error each; a batch that is not valid JSON or is empty with a single
+    /// error. Notifications are never answered.
+    pub async fn handle_batch(&mut self, batch: &str) -> Option<serde_json::Value> {
+        self.apply_config_update();
+        let entries = match serde_json::from_str::<Vec<serde_json::Value>>(batch) {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!("Unparseable batch: {}", e);
+                return Some(Self::error_value(None, -32700, "Parse error"));
             }
         };
+        if entries.is_empty() {
+            return Some(Self::error_value(None, -32600, "Invalid Request"));
+        }
 
-        Ok(Some(response))
+        let is_tool_call = |entry: &std::result::Result<JsonRpcRequest, _>| matches!(entry, Ok(request) if request.method == "tools/call");
+        let mut pending = entries
+            .into_iter()
+            .map(|entry| {
+                serde_json::from_value::<JsonRpcRequest>(entry.clone())
+                    .map_err(|_| entry.get("id").cloned())
+            })
+            .peekable();
+
+        let mut responses = Vec::new();
+        while let Some(entry) = pending.next() {
+            match entry {
+                Err(id) => responses.push(Self::error_value(id, -32600, "Invalid Request")),
+                Ok(request) if request.method == "tools/call" => {
+                    let mut calls = vec![request];
+                    while let Some(Ok(call)) = pending.next_if(is_tool_call) {
+                        calls.push(call);
+                    }
+                    let server = &*self;
+                    let outcomes: Vec<_> = stream::iter(calls)
+                        .map(|call| async move {
+                            let id = call.id.clone();
+                            (id, server.call_tool_request(call).await.map(Some))
+                        })
+                        .buffered(BATCH_CONCURRENCY)
+                        .collect()
+                        .await;
+                    responses.extend(
+                        outcomes
+                            .into_iter()
+                            .filter_map(|(id, outcome)| Self::batch_response(id, outcome)),
+                    );
+                }
+                Ok(request) => {
+                    let id = request.id.clone();
+                    let outcome = self.dispatch(request).await;
+                    responses.extend(Self::batch_response(id, outcome));
+                }
+            }
+        }
+
+        (!responses.is_empty()).then_some(serde_json::Value::Array(responses))
     }
 
-    /// Handle initialize request
-    fn handle_initialize(request: JsonRpcRequest) -> Result<JsonRpcResponse> {
-        let _params: InitializeParams = if let Some(params) = request.params {
-            serde_json::from_value(params)?
-        } else {
-            return Ok(JsonRpcResponse {
-                jsonrpc: "2.0".to_string(),
-                id: request.id,
-                result: None,
-                error: Some(JsonRpcError {
-                    code: -32602,
-                    message: "Invalid params".to_string(),
-                    data: None,
-                }),
-            });
-        };
+    /// The response to a batch entry with `id` as JSON, `None` for
+    /// notifications
+    fn batch_response(
+        id: Option<serde_json::Value>,
+        outcome: Result<Option<JsonRpcResponse>>,
+    ) -> Option<serde_json::Value> {
+        match Self::unless_notification(id.is_none(), outcome) {
+            Ok(response) => response.map(|response| {
+                serde_json::to_value(response).unwrap_or_else(|e| {
+                    Self::error_value(id, -32603, &format!("Internal error: {e}"))
+                })
+            }),
+            Err(e) => {
+                error!("Error handling batch entry: {}", e);
+                Some(
+                    serde_json::to_value(Self::internal_error(id, &e))
+                        .unwrap_or(serde_json::Value::Null),
+                )
+            }
+        }
+    }
 
-        let result = InitializeResult {
-            protocol_version: "2024-11-05".to_string(),
-            capabilities: ServerCapabilities {
-                tools: Some(ToolsCapability {
-                    list_changed: Some(false),
+    /// `outcome`, or nothing if the request was a notification: those are
+    /// never answered, not even with an error
+    fn unless_notification(
+        is_notification: bool,
+        outcome: Result<Option<JsonRpcResponse>>,
+    ) -> Result<Option<JsonRpcResponse>> {
+        if !is_notification {
+            return outcome;
+        }
+        if let Err(e) = outcome {
+            warn!("Error handling notification: {}", e);
+        }
+        Ok(None)
+    }
+
+    /// Response to a request that failed unexpectedly
+    fn internal_error(
+        id: Option<serde_json::Value>,
+        error: &crate::error::JiraError,
+    ) -> JsonRpcResponse {
+        JsonRpcResponse {
+            jsonrpc: "2.0".to_string(),
+            id,
+            result: None,
+            error: Some(JsonRpcError {
+                code: -32603,
+                message: "Internal error".to_string(),
+                data: Some(json!({ "details": error.to_string() })),
+            }),
+        }
+    }
+
+    /// A JSON-RPC error response as JSON, with a null id when the request's
+    /// id is unknown
+    fn error_value(id: Option<serde_json::Value>, code: i32, message: &str) -> serde_json::Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": {"code": code, "message": message}
+        })
+    }
+
+    /// What was agreed with the client during initialization, once it has
+    /// sent `initialize`
+    #[must_use]
+    pub fn client_session(&self) -> Option<&ClientSession> {
+        self.session.as_ref()
+    }
+
+    /// Whether the client declared `capability`. Optional notifications are
+    /// only sent to clients that declared the capability they belong to.
+    #[must_use]
+    pub fn client_supports(&self, capability: &str) -> bool {
+        self.session
+            .as_ref()
+            .is_some_and(|session| session.supports(capability))
+    }
+
+    /// Queue the notification `method` for the client, if it declared
+    /// `capability`; other clients never see it
+    fn notify(&mut self, capability: &str, method: &str, params: serde_json::Value) {
+        if self.client_supports(capability) {
+            self.notifications.push(json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": params
+            }));
+        }
+    }
+
+    /// Queue a `notifications/jira/event` for each webhook event received
+    /// since the last call, if the client declared `experimental.jiraEvents`.
+    ///
+    /// Stdin is not read while waiting for events, so events go out after
+    /// the next reply rather than the moment they arrive.
+    pub fn queue_jira_events(&mut self) {
+        let events = jira_events().since(self.last_event_sequence);
+        let Some(last) = events.last() else {
+            return;
+        };
+        self.last_event_sequence = last.sequence;
+        for event in events {
+            let params = serde_json::to_value(&event).unwrap_or_default();
+            self.notify(
+                "experimental.jiraEvents",
+                "notifications/jira/event",
+                params,
+            );
+        }
+    }
+
+    /// The notifications queued for the client since the last call, in order
+    pub fn take_notifications(&mut self) -> Vec<serde_json::Value> {
+        std::mem::take(&mut self.notifications)
+    }
+
+    /// The protocol version to answer a client requesting `requested` with:
+    /// that version if the server speaks it, otherwise the newest one it does
+    #[must_use]
+    pub fn negotiate_protocol_version(requested: &str) -> &'static str {
+        SUPPORTED_PROTOCOL_VERSIONS
+            .iter()
+            .find(|version| **version == requested)
+            .unwrap_or(&SUPPORTED_PROTOCOL_VERSIONS[0])
+    }
+
+    /// Handle a JSON-RPC request, returning the response to send back, or
+    /// `None` for notifications
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request is not valid JSON-RPC or its response
+    /// cannot be built.
+    pub async fn handle_request(&mut self, request_str: &str) -> Result<Option<JsonRpcResponse>> {
+        self.apply_config_update();
+        let request: JsonRpcRequest = serde_json::from_str(request_str)?;
+        let is_notification = request.id.is_none();
+        let outcome = self.dispatch(request).await;
+        Self::unless_notification(is_notification, outcome)
+    }
+
+    /// Run `request`, returning its response
+    async fn dispatch(&mut self, request: JsonRpcRequest) -> Result<Option<JsonRpcResponse>> {
+        let response = match request.method.as_str() {
+            "initialize" => self.handle_initialize(request)?,
+            "tools/list" => self.handle_list_tools(request)?,
+            "tools/call" => self.call_tool_request(request).await?,
+            "notifications/initialized" => {
+                // Handle initialization notification
+                if self.session.is_none() {
+                    warn!("notifications/initialized received before initialize");
+                    return Ok(None);
+                }
+                self.initialized = true;
+                info!("MCP client initialized successfully");
+                // Clients that take log messages hear about a failed warm-up
+                if let Some(warmup) = last_warmup(&self.config) {
+                    if warmup.steps.iter().any(|step| step.error.is_some()) {
+                        self.notify(
+                            "logging",
+                            "notifications/message",
+                            json!({"level": "warning", "logger": "warmup", "data": warmup.summary()}),
+                        );
+                    }
+                }
+                return Ok(None);
+            }
+            _ => {
+                warn!("Unknown method: {}", request.method);
+                JsonRpcResponse {
+                    jsonrpc: "2.0".to_string(),
+                    id: request.id,
+                    result: None,
+                    error: Some(JsonRpcError {
+                        code: -32601,
+                        message: "Method not found".to_string(),
+                        data: None,
+                    }),
+                }
+            }
+        };
+
+        Ok(Some(response))
+    }
+
+    /// Handle a `tools/call` request. Tools only run once the client has
+    /// finished initializing.
+    async fn call_tool_request(&self, request: JsonRpcRequest) -> Result<JsonRpcResponse> {
+        if !self.initialized {
+            return Ok(JsonRpcResponse {
+                jsonrpc: "2.0".to_string(),
+                id: request.id,
+                result: None,
+                error: Some(JsonRpcError {
+                    code: SERVER_NOT_INITIALIZED,
+                    message: "Server not initialized".to_string(),
+                    data: Some(json!({
+                        "details": "Send initialize and notifications/initialized before calling tools"
+                    })),
+                }),
+            });
+        }
+        self.handle_call_tool(request).await
+    }
+
+    /// Handle initialize request. A session is initialized once; a second
+    /// `initialize` is rejected and leaves the session as it was.
+    fn handle_initialize(&mut self, request: JsonRpcRequest) -> Result<JsonRpcResponse> {
+        if self.session.is_some() {
+            warn!("initialize received for an already initialized session");
+            return Ok(JsonRpcResponse {
+                jsonrpc: "2.0".to_string(),
+                id: request.id,
+                result: None,
+                error: Some(JsonRpcError {
+                    code: -32600,
+                    message: "Invalid Request".to_string(),
+                    data: Some(json!({"details": "The session is already initialized"})),
+                }),
+            });
+        }
+        let params: InitializeParams = if let Some(params) = request.params {
+            serde_json::from_value(params)?
+        } else {
+            return Ok(JsonRpcResponse {
+                jsonrpc: "2.0".to_string(),
+                id: request.id,
+                result: None,
+                error: Some(JsonRpcError {
+                    code: -32602,
+                    message: "Invalid params".to_string(),
+                    data: None,
+                }),
+            });
+        };
+
+        // Requests name the MCP client in their User-Agent from now on
+        crate::jira::identity::set_mcp_client(&params.client_info);
+        info!(
+            "MCP client: {} {}",
+            params.client_info.name, params.client_info.version
+        );
+
+        let protocol_version = Self::negotiate_protocol_version(&params.protocol_version);
+        if protocol_version != params.protocol_version {
+            info!(
+                "MCP client requested protocol version {}, answering with {}",
+                params.protocol_version, protocol_version
+            );
+        }
+        self.session = Some(ClientSession {
+            protocol_version: protocol_version.to_string(),
+            capabilities: params.capabilities,
+            client_info: params.client_info,
+        });
+
+        let result = InitializeResult {
+            protocol_version: protocol_version.to_string(),
+            capabilities: ServerCapabilities {
+                tools: Some(ToolsCapability {
+                    list_changed: Some(false),
                 }),
+                logging: self.client_supports("logging").then(|| json!({})),
+                experimental: (self.config.webhook_listen_addr.is_some()
+                    && self.client_supports("experimental.jiraEvents"))
+                .then(|| json!({"jiraEvents": {}})),
             },
             server_info: ServerInfo {
                 name: "rust-jira-mcp".to_string(),
@@ -585,8 +1409,46 @@ impl MCPServer {
         })
     }
 
+    /// The tools this server offers, as `tools/list` returns them: tools
+    /// behind a config flag only when it is on, and without the arguments the
+    /// session context fills among the required ones
+    #[must_use]
+    pub fn available_tools(&self) -> Vec<MCPTool> {
+        let context = read_context(&self.session_context);
+        Self::list_tools()
+            .into_iter()
+            .filter(|tool| self.tools.contains_key(&tool.name))
+            .map(|mut tool| {
+                if !context.is_empty() && !tool.name.ends_with("_jira_context") {
+                    context.relax_required(&mut tool.input_schema);
+                }
+                tool
+            })
+            .collect()
+    }
+
+    /// Write [`Self::available_tools`] to `path` as pretty JSON in the shape
+    /// of a `tools/list` result, for reviewing what a configuration lets the
+    /// assistant do. Returns the number of tools written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn dump_tools(&self, path: &std::path::Path) -> Result<usize> {
+        let result = ListToolsResult {
+            tools: self.available_tools(),
+        };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut json = serde_json::to_string_pretty(&result)?;
+        json.push('\n');
+        std::fs::write(path, json)?;
+        Ok(result.tools.len())
+    }
+
     /// Handle list tools request
-    fn handle_list_tools(request: JsonRpcRequest) -> Result<JsonRpcResponse> {
+    fn handle_list_tools(&self, request: JsonRpcRequest) -> Result<JsonRpcResponse> {
         let _params: ListToolsParams = if let Some(params) = request.params {
             serde_json::from_value(params)?
         } else {
@@ -602,8 +1464,9 @@ impl MCPServer {
             });
         };
 
-        let tools = Self::list_tools();
-        let result = ListToolsResult { tools };
+        let result = ListToolsResult {
+            tools: self.available_tools(),
+        };
 
         Ok(JsonRpcResponse {
             jsonrpc: "2.0".to_string(),
@@ -615,6 +1478,18 @@ impl MCPServer {
 
     /// Handle call tool request
     async fn handle_call_tool(&self, request: JsonRpcRequest) -> Result<JsonRpcResponse> {
+        let tool = request
+            .params
+            .as_ref()
+            .and_then(|params| params.get("name"))
+            .and_then(|name| name.as_str())
+            .unwrap_or("unknown")
+            .to_string();
+        profiler::profile_call(&tool, self.profiled_call_tool(request)).await
+    }
+
+    /// Body of [`Self::handle_call_tool`], run under the profiler
+    async fn profiled_call_tool(&self, request: JsonRpcRequest) -> Result<JsonRpcResponse> {
         let params: CallToolParams = if let Some(params) = request.params {
             serde_json::from_value(params)?
         } else {
@@ -630,27 +1505,59 @@ impl MCPServer {
             });
         };
 
+        let context = ToolContext {
+            tool_name: params.name.clone(),
+            correlation_id: uuid::Uuid::new_v4().to_string(),
+            client_name: self
+                .session
+                .as_ref()
+                .map(|session| session.client_info.name.clone()),
+        };
         let tool_call = MCPToolCall {
             name: params.name,
             arguments: params.arguments.unwrap_or(json!({})),
         };
+        profiler::checkpoint("deserialize arguments");
 
-        match self.call_tool(tool_call).await {
-            Ok(tool_result) => {
+        let (outcome, telemetry) = telemetry::track(self.call_tool(tool_call)).await;
+        profiler::checkpoint("format response");
+        let retries = telemetry.note();
+        match outcome {
+            Ok(mut tool_result) => {
+                if let Some(retries) = &retries {
+                    append_note(&mut tool_result, retries);
+                }
+                if !self.pipeline.is_empty() {
+                    info!(
+                        "Tool call {} has correlation id {}",
+                        context.tool_name, context.correlation_id
+                    );
+                    self.pipeline.process(&mut tool_result, &context);
+                    profiler::checkpoint("response middleware");
+                }
                 let result = CallToolResult {
                     content: tool_result.content,
                     is_error: tool_result.is_error.unwrap_or(false),
                 };
+                let result = serde_json::to_value(result)?;
+                profiler::checkpoint("serialize result");
 
                 Ok(JsonRpcResponse {
                     jsonrpc: "2.0".to_string(),
                     id: request.id,
-                    result: Some(serde_json::to_value(result)?),
+                    result: Some(result),
                     error: None,
                 })
             }
             Err(e) => {
                 error!("Tool execution error: {}", e);
+                let mut details = match retries {
+                    Some(retries) => format!("{e}\n{retries}"),
+                    None => e.to_string(),
+                };
+                if self.config.anonymize_output.unwrap_or(false) {
+                    details = anonymize::anonymize_text(&details);
+                }
                 Ok(JsonRpcResponse {
                     jsonrpc: "2.0".to_string(),
                     id: request.id,
@@ -658,7 +1565,7 @@ impl MCPServer {
                     error: Some(JsonRpcError {
                         code: -32603,
                         message: "Tool execution failed".to_string(),
-                        data: Some(json!({ "details": e.to_string() })),
+                        data: Some(json!({ "details": details })),
                     }),
                 })
             }
@@ -671,15 +1578,62 @@ impl MCPServer {
         tools.extend(Self::get_auth_and_search_tools());
         tools.extend(Self::get_issue_crud_tools());
         tools.extend(Self::get_comment_and_transition_tools());
+        tools.extend(Self::get_context_tool_definitions());
         tools
     }
 
+    /// Get session context tool definitions
+    fn get_context_tool_definitions() -> Vec<MCPTool> {
+        vec![
+            MCPTool {
+                name: "set_jira_context".to_string(),
+                description: "Set the project, board, sprint and issue of this session; later tool calls that leave out a project, board, sprint or issue argument use them and say so. Each entry is checked against Jira before anything changes; an explicit argument always wins, and the session project wins over JIRA_DEFAULT_PROJECT".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "project": {
+                            "type": ["string", "null"],
+                            "description": "Project key; null or \"\" clears it"
+                        },
+                        "board": {
+                            "type": ["integer", "null"],
+                            "description": "Agile board id; null clears it"
+                        },
+                        "sprint": {
+                            "type": ["integer", "null"],
+                            "description": "Sprint id; null clears it"
+                        },
+                        "issue": {
+                            "type": ["string", "null"],
+                            "description": "Issue key; null or \"\" clears it"
+                        },
+                        "clear": {
+                            "type": "boolean",
+                            "description": "Clear every entry before setting the given ones",
+                            "default": false
+                        }
+                    }
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_jira_context".to_string(),
+                description: "Show the project, board, sprint and issue set with set_jira_context".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {}
+                }),
+                ..Default::default()
+            },
+        ]
+    }
+
     /// Get authentication and search tools
     fn get_auth_and_search_tools() -> Vec<MCPTool> {
         vec![
             MCPTool {
                 name: "test_jira_auth".to_string(),
-                description: "Test authentication with Jira API".to_string(),
+                description: "Test authentication with Jira API; when the server warmed up at start (JIRA_WARMUP), a second content item reports what was primed and what failed".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
@@ -690,6 +1644,25 @@ impl MCPServer {
                     },
                     "required": ["random_string"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "validate_configuration".to_string(),
+                description: "Diagnose the server's configuration: the sources it was read from, the settings, the shape of the base URL, whether the site can be reached (DNS, TLS), whether the credentials are accepted and with which auth scheme, whether the REST API version answers, whether Zephyr answers, and whether the credentials can browse the default project. Each check is reported as PASS, WARN or FAIL with a hint on what to change; the second content item is the report as JSON ({\"checks\": [{\"name\", \"status\": \"pass\"|\"warn\"|\"fail\", \"detail\", \"hint\"}]}). Run the binary with --check-config for the same report before connecting a client".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {}
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_jira_server_info".to_string(),
+                description: "Get the version, build, deployment type and time of the Jira instance, and whether attachments are enabled with the largest file it accepts. The result is a text summary followed by the server info as JSON, with an `attachments` object ({\"enabled\": bool, \"uploadLimit\": int|null}) when the limit could be read".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {}
+                }),
+                ..Default::default()
             },
             MCPTool {
                 name: "search_jira_issues".to_string(),
@@ -703,28 +1676,102 @@ impl MCPServer {
                         },
                         "start_at": {
                             "type": "integer",
-                            "description": "The index of the first item to return (also accepts startAt)"
+                            "description": "The index of the first item to return (also accepts startAt)",
+                            "minimum": 0
                         },
                         "startAt": {
                             "type": "integer",
-                            "description": "The index of the first item to return (alternative to start_at)"
+                            "description": "The index of the first item to return (alternative to start_at)",
+                            "minimum": 0
                         },
                         "max_results": {
                             "type": "integer",
-                            "description": "The maximum number of items to return (also accepts maxResults)"
+                            "description": "The maximum number of items to return, up to JIRA_MAX_RESULTS_LIMIT (default 1000); 0 returns only the total (also accepts maxResults)",
+                            "minimum": 0
                         },
                         "maxResults": {
                             "type": "integer",
-                            "description": "The maximum number of items to return (alternative to max_results)"
+                            "description": "The maximum number of items to return, up to JIRA_MAX_RESULTS_LIMIT (default 1000); 0 returns only the total (alternative to max_results)",
+                            "minimum": 0
                         },
                         "fields": {
                             "type": "array",
                             "items": {"type": "string"},
-                            "description": "List of fields to return for each issue"
+                            "description": "List of fields to return for each issue. When omitted and JIRA_SEARCH_DEFAULT_FIELDS is set, those fields are shown as a table after the key and summary"
+                        },
+                        "bypass_cache": {
+                            "type": "boolean",
+                            "description": "Skip the search result cache and query Jira directly (default: false)"
+                        },
+                        "format": {
+                            "type": "string",
+                            "enum": ["text", "json"],
+                            "description": "'text' (default) for a readable list or table, 'json' for the issues with their full field values"
+                        }
+                    },
+                    "required": ["jql"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "count_jira_issues".to_string(),
+                description: "Count the issues matching JQL without fetching any of them, e.g. to answer 'how many open bugs?'. Takes one query or a list of up to 50, counted concurrently; a query that fails is reported next to the others' counts. Counts on Jira Cloud may lag changes made in the last few seconds".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "jql": {
+                            "oneOf": [
+                                {"type": "string"},
+                                {"type": "array", "items": {"type": "string"}, "minItems": 1, "maxItems": 50}
+                            ],
+                            "description": "The JQL query, or a list of queries to count separately"
                         }
                     },
                     "required": ["jql"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "search_jira_text".to_string(),
+                description: "Find issues that mention a string in their summary, description or comments (JQL `text ~`), showing the field each match is in and an excerpt of about 120 characters either side with the match highlighted. Scans at most 50 issues and their 50 most recent comments".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "query": {
+                            "type": "string",
+                            "description": "The text to look for"
+                        },
+                        "project_key": {
+                            "type": "string",
+                            "description": "Optional project to limit the search to"
+                        },
+                        "max_issues": {
+                            "type": "integer",
+                            "description": "Maximum number of issues to scan (default: 10, max: 50)"
+                        }
+                    },
+                    "required": ["query"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "search_issue_content".to_string(),
+                description: "Find every mention of a string in one issue: its description, all of its comments and the values set in its changelog. Matches ignore case; each hit shows when and by whom it was written, where it is (description, comment #id or changelog entry) and an excerpt with the match highlighted, oldest first (at most 100 listed)".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The issue key (e.g., PROJ-123)"
+                        },
+                        "query": {
+                            "type": "string",
+                            "description": "The text to look for"
+                        }
+                    },
+                    "required": ["issue_key", "query"]
+                }),
+                ..Default::default()
             },
         ]
     }
@@ -738,20 +1785,52 @@ impl MCPServer {
                 input_schema: json!({
                     "type": "object",
                     "properties": {
+                        "input_format": {
+                            "type": "string",
+                            "enum": ["wiki", "markdown"],
+                            "description": "How the description is written: Jira wiki markup, sent as written, or Markdown, converted to wiki markup (to ADF on REST API v3). Defaults to JIRA_DEFAULT_INPUT_FORMAT, usually wiki"
+                        },
                         "fields": {
                             "type": "object",
                             "description": "The issue field values"
+                        },
+                        "project_key": {
+                            "type": "string",
+                            "description": "Project to create the issue in when fields.project is absent (defaults to JIRA_DEFAULT_PROJECT)"
+                        },
+                        "parent_key": {
+                            "type": "string",
+                            "description": "Key of the parent issue, e.g. the epic of a story or the issue of a sub-task. The parent's issue type is checked against the issue type hierarchy (see get_issue_type_hierarchy) before creating"
+                        },
+                        "security": {
+                            "type": "string",
+                            "description": "Issue security level id or name; checked against the levels of the project"
+                        },
+                        "return_issue": {
+                            "type": "boolean",
+                            "description": "Read the issue back and include its details and JSON in the result (default false)"
+                        },
+                        "return_fields": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Fields to read back with return_issue (default all)"
                         }
                     },
                     "required": ["fields"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "update_jira_issue".to_string(),
-                description: "Update an existing Jira issue".to_string(),
+                description: "Update an existing Jira issue. Values under fields replace the field's value; operations under update change it as Jira applies them, so adding one label or removing one fix version leaves the others alone even if the issue changed since it was read".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
+                        "input_format": {
+                            "type": "string",
+                            "enum": ["wiki", "markdown"],
+                            "description": "How the description is written: Jira wiki markup, sent as written, or Markdown, converted to wiki markup (to ADF on REST API v3). Defaults to JIRA_DEFAULT_INPUT_FORMAT, usually wiki"
+                        },
                         "issue_id_or_key": {
                             "type": "string",
                             "description": "The ID or key of the issue to update"
@@ -759,62 +1838,276 @@ impl MCPServer {
                         "fields": {
                             "type": "object",
                             "description": "The fields to update"
+                        },
+                        "update": {
+                            "type": "object",
+                            "description": "Operations per field id, each an object with one verb: add, remove, set or edit. E.g. {\"labels\": [{\"add\": \"urgent\"}, {\"remove\": \"triage\"}], \"fixVersions\": [{\"add\": {\"name\": \"2.1\"}}], \"components\": [{\"remove\": {\"name\": \"UI\"}}]}",
+                            "additionalProperties": {
+                                "type": "array",
+                                "items": {"type": "object"}
+                            }
+                        },
+                        "validate": {
+                            "type": "boolean",
+                            "description": "Check the fields and update operations against the issue's edit metadata first: fields that cannot be edited, values not allowed and verbs a field doesn't support are all reported and nothing is changed (default false)"
+                        },
+                        "security": {
+                            "type": "string",
+                            "description": "Issue security level id or name; checked against the levels of the issue's project"
+                        },
+                        "expected_updated": {
+                            "type": "string",
+                            "description": "The issue's 'updated' timestamp when you last read it. If the issue has been updated since, nothing is changed and the error lists the field changes made in between"
+                        },
+                        "preview": {
+                            "type": "boolean",
+                            "description": "Show each field's current and proposed value, and the 'updated' timestamp to pass back as expected_updated, without changing the issue (default false)"
+                        },
+                        "return_issue": {
+                            "type": "boolean",
+                            "description": "Read the issue back and include its details and JSON in the result (default false)"
+                        },
+                        "return_fields": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Fields to read back with return_issue (default all)"
                         }
                     },
-                    "required": ["issue_id_or_key", "fields"]
+                    "required": ["issue_id_or_key"]
                 }),
+                ..Default::default()
             },
             MCPTool {
-                name: "get_jira_issue".to_string(),
-                description: "Get details of a specific Jira issue".to_string(),
+                name: "preview_issue_update".to_string(),
+                description: "Show what update_jira_issue would change: a table of each field's current and proposed value, with rich text in full and the 'updated' timestamp to pass back as expected_updated. Reads only the fields being changed and changes nothing".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
-                        "issue_key": {
+                        "issue_id_or_key": {
                             "type": "string",
-                            "description": "The key of the issue to retrieve"
+                            "description": "The ID or key of the issue"
+                        },
+                        "fields": {
+                            "type": "object",
+                            "description": "The fields the update would set"
+                        },
+                        "expected_updated": {
+                            "type": "string",
+                            "description": "The issue's 'updated' timestamp when you last read it; the preview says whether the issue has changed since"
                         }
                     },
-                    "required": ["issue_key"]
+                    "required": ["issue_id_or_key", "fields"]
                 }),
+                ..Default::default()
             },
-        ]
-    }
-
-    /// Get comment and transition tools
-    fn get_comment_and_transition_tools() -> Vec<MCPTool> {
-        vec![
             MCPTool {
-                name: "get_jira_comments".to_string(),
-                description: "Get all comments for a Jira issue".to_string(),
+                name: "set_jira_issue_parent".to_string(),
+                description: "Move an issue under a different parent, e.g. a story to another epic, or detach it with a null parent_key. Uses the parent field, or the Epic Link field where Data Center links issues to epics. The parent must be one level up in the issue type hierarchy, and in the same project for subtasks and team-managed projects".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
                         "issue_key": {
                             "type": "string",
-                            "description": "The key of the issue to get comments for"
+                            "description": "The key of the issue to move"
+                        },
+                        "parent_key": {
+                            "type": ["string", "null"],
+                            "description": "The key of the new parent, or null to detach the issue from its parent"
                         }
                     },
-                    "required": ["issue_key"]
+                    "required": ["issue_key", "parent_key"]
                 }),
+                ..Default::default()
             },
             MCPTool {
-                name: "add_jira_comment".to_string(),
-                description: "Add a comment to a Jira issue".to_string(),
+                name: "jira_issue_action".to_string(),
+                description: "Assign, relabel, comment on and transition an issue in one call. Steps run in the order assign, labels, comment, transition and stop at the first failure; the result lists the steps done, the one that failed and those not attempted".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
                         "issue_key": {
                             "type": "string",
-                            "description": "The key of the issue to add a comment to"
+                            "description": "The key of the issue"
+                        },
+                        "assign": {
+                            "type": "string",
+                            "description": "Account id (Cloud) or username (Server/Data Center) to assign the issue to; an empty string unassigns it"
+                        },
+                        "add_labels": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Labels to add"
+                        },
+                        "remove_labels": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Labels to remove"
+                        },
+                        "comment": {
+                            "type": "string",
+                            "description": "Comment to add"
+                        },
+                        "transition": {
+                            "type": "string",
+                            "description": "Transition id, or the name of a transition or its target status (e.g. 'In Progress')"
+                        }
+                    },
+                    "required": ["issue_key"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_jira_issue".to_string(),
+                description: "Get details of a specific Jira issue".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue to retrieve"
+                        }
+                    },
+                    "required": ["issue_key"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_jira_issue_field".to_string(),
+                description: "Get the complete value of one field of a Jira issue, e.g. a description that was truncated in other output".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue"
+                        },
+                        "field": {
+                            "type": "string",
+                            "description": "Field id (e.g. 'description', 'customfield_10010') or display name, or 'comment:<id>' / 'worklog:<id>' for a single comment or work log"
+                        },
+                        "output_path": {
+                            "type": "string",
+                            "description": "Absolute path to write the value to instead of returning it; must be inside a directory listed in JIRA_FILE_ALLOWLIST"
+                        }
+                    },
+                    "required": ["issue_key", "field"]
+                }),
+                ..Default::default()
+            },
+        ]
+    }
+
+    /// Get comment and transition tools
+    fn get_comment_and_transition_tools() -> Vec<MCPTool> {
+        vec![
+            MCPTool {
+                name: "get_jira_comments".to_string(),
+                description: "Get the comments of a Jira issue, oldest first. Each comment shows its id, the author's name and account id, and its creation time with how long ago that was. Filter with since and author, e.g. comments by alice since Monday. On Jira Service Management issues each comment is marked [public] (shared with the customer) or [internal]".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue to get comments for"
+                        },
+                        "since": {
+                            "type": "string",
+                            "description": "Only comments created at or after this date (YYYY-MM-DD, UTC) or ISO timestamp"
+                        },
+                        "author": {
+                            "type": "string",
+                            "description": "Only comments by this author, matched case-insensitively against account id, username, email address and display name"
+                        },
+                        "format": {
+                            "type": "string",
+                            "enum": ["text", "json"],
+                            "description": "'json' returns the raw Jira comment objects as a JSON array after the header (default: text)"
+                        }
+                    },
+                    "required": ["issue_key"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "add_jira_comment".to_string(),
+                description: "Add a comment to a Jira issue".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "input_format": {
+                            "type": "string",
+                            "enum": ["wiki", "markdown"],
+                            "description": "How the comment is written: Jira wiki markup, sent as written, or Markdown, converted to wiki markup (to ADF on REST API v3). Defaults to JIRA_DEFAULT_INPUT_FORMAT, usually wiki"
+                        },
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue to add a comment to"
                         },
                         "comment_body": {
                             "type": "string",
                             "description": "The comment text to add"
+                        },
+                        "resolve_mentions": {
+                            "type": "boolean",
+                            "description": "Turn @name, @email or @\"Display Name\" into real Jira mentions (default: false). Names that match no user or several users are left as text and reported"
+                        },
+                        "on_behalf_of": {
+                            "type": "string",
+                            "description": "Display name, email or username of the user this is written for. The text then starts with '_on behalf of <name> via rust-jira-mcp_'; fails without writing if the user cannot be resolved. Needs JIRA_ANNOTATE_ON_BEHALF=true"
+                        },
+                        "public": {
+                            "type": "boolean",
+                            "description": "On Jira Service Management issues, share the comment with the customer, who is notified (default: false, internal to agents). Ignored on other issues"
+                        },
+                        "visibility": {
+                            "type": "object",
+                            "description": "Optional restriction so only a role or group can see the comment",
+                            "properties": {
+                                "type": {"type": "string", "enum": ["role", "group"], "description": "Whether value names a project role or a group"},
+                                "value": {"type": "string", "description": "Name of the role or group"}
+                            },
+                            "required": ["type", "value"]
                         }
                     },
                     "required": ["issue_key", "comment_body"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "update_jira_comment".to_string(),
+                description: "Update the text or visibility of an existing comment".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue the comment belongs to"
+                        },
+                        "comment_id": {
+                            "type": "string",
+                            "description": "The ID of the comment to update"
+                        },
+                        "comment": {
+                            "type": "string",
+                            "description": "The new comment text"
+                        },
+                        "on_behalf_of": {
+                            "type": "string",
+                            "description": "Display name, email or username of the user this is written for. The text then starts with '_on behalf of <name> via rust-jira-mcp_'; fails without writing if the user cannot be resolved. Needs JIRA_ANNOTATE_ON_BEHALF=true"
+                        },
+                        "visibility": {
+                            "type": "object",
+                            "description": "Optional restriction so only a role or group can see the comment",
+                            "properties": {
+                                "type": {"type": "string", "enum": ["role", "group"], "description": "Whether value names a project role or a group"},
+                                "value": {"type": "string", "description": "Name of the role or group"}
+                            },
+                            "required": ["type", "value"]
+                        }
+                    },
+                    "required": ["issue_key", "comment_id", "comment"]
+                }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_jira_transitions".to_string(),
@@ -829,6 +2122,7 @@ impl MCPServer {
                     },
                     "required": ["issue_key"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "transition_jira_issue".to_string(),
@@ -844,13 +2138,25 @@ impl MCPServer {
                             "type": "string",
                             "description": "The ID of the transition to apply"
                         },
+                        "transition_name": {
+                            "type": "string",
+                            "description": "Name of the transition (or target status) to apply, matched case-insensitively. Used when transition_id is not given"
+                        },
                         "comment": {
                             "type": "string",
                             "description": "Optional comment to add during transition"
+                        },
+                        "fields": {
+                            "type": "object",
+                            "description": "Optional field values required by the transition screen"
+                        },
+                        "resolution": {
+                            "description": "Optional resolution to set, as a name (e.g. \"Fixed\") or a resolution object such as {\"id\": \"1\"}"
                         }
                     },
-                    "required": ["issue_key", "transition_id"]
+                    "required": ["issue_key"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -858,19 +2164,51 @@ impl MCPServer {
     /// Get project tool definitions
     fn get_project_tool_definitions() -> Vec<MCPTool> {
         vec![
+            MCPTool {
+                name: "list_jira_projects".to_string(),
+                description: "List projects with their category and status. Only live projects are listed unless status says otherwise".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "status": {
+                            "type": "string",
+                            "enum": ["live", "archived", "deleted", "all"],
+                            "description": "Project status to list (default live); deleted projects exist only on Jira Cloud"
+                        },
+                        "category": {
+                            "type": "string",
+                            "description": "Project category id or name (see get_jira_project_categories)"
+                        },
+                        "key_prefix": {
+                            "type": "string",
+                            "description": "Only list projects whose key starts with this, ignoring case"
+                        }
+                    }
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_jira_project_categories".to_string(),
+                description: "List the project categories".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {}
+                }),
+                ..Default::default()
+            },
             MCPTool {
                 name: "get_project_config".to_string(),
-                description: "Get project configuration details".to_string(),
+                description: "Get project configuration details. On Jira Cloud, which has no configuration endpoint, this combines the project details, its workflow scheme (when permitted) and the statuses of each issue type".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
                         "project_key": {
                             "type": "string",
-                            "description": "The key of the project to get configuration for"
+                            "description": "The key of the project to get configuration for (defaults to JIRA_DEFAULT_PROJECT)"
                         }
-                    },
-                    "required": ["project_key"]
+                    }
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_project_issue_types".to_string(),
@@ -880,11 +2218,25 @@ impl MCPServer {
                     "properties": {
                         "project_key": {
                             "type": "string",
-                            "description": "The key of the project to get issue types for"
+                            "description": "The key of the project to get issue types for (defaults to JIRA_DEFAULT_PROJECT)"
                         }
-                    },
-                    "required": ["project_key"]
+                    }
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_issue_type_hierarchy".to_string(),
+                description: "Get the issue type hierarchy of a project (e.g. Epic > Story > Sub-task), showing which issue types can be the parent of which".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "project_key": {
+                            "type": "string",
+                            "description": "The key of the project (defaults to JIRA_DEFAULT_PROJECT)"
+                        }
+                    }
+                }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_issue_type_metadata".to_string(),
@@ -899,6 +2251,7 @@ impl MCPServer {
                     },
                     "required": ["issue_type_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_project_components".to_string(),
@@ -908,11 +2261,11 @@ impl MCPServer {
                     "properties": {
                         "project_key": {
                             "type": "string",
-                            "description": "The key of the project to get components for"
+                            "description": "The key of the project to get components for (defaults to JIRA_DEFAULT_PROJECT)"
                         }
-                    },
-                    "required": ["project_key"]
+                    }
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_priorities_and_statuses".to_string(),
@@ -921,6 +2274,7 @@ impl MCPServer {
                     "type": "object",
                     "properties": {}
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_custom_fields".to_string(),
@@ -929,20 +2283,122 @@ impl MCPServer {
                     "type": "object",
                     "properties": {}
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_project_metadata".to_string(),
-                description: "Get comprehensive project metadata including configuration, issue types, components, priorities, statuses, and custom fields".to_string(),
+                description: "Get comprehensive project metadata including configuration, issue types, components, priorities, statuses, and custom fields. Sections that cannot be read (e.g. configuration without admin permission) are left out, with the reason under `errors`".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "project_key": {
+                            "type": "string",
+                            "description": "The key of the project to get comprehensive metadata for (defaults to JIRA_DEFAULT_PROJECT)"
+                        },
+                        "include": {
+                            "type": "array",
+                            "items": {
+                                "type": "string",
+                                "enum": ["configuration", "issue_types", "components", "priorities", "statuses", "custom_fields"]
+                            },
+                            "description": "Sections to read (default: all). Leave out configuration to skip its slow, admin-only request"
+                        }
+                    }
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_project_workflow".to_string(),
+                description: "Get the workflow for each issue type in a project: available statuses with their categories, the workflow scheme when permitted, and the transitions available from an example issue".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "project_key": {
+                            "type": "string",
+                            "description": "The key of the project to inspect (defaults to JIRA_DEFAULT_PROJECT)"
+                        },
+                        "issue_type": {
+                            "type": "string",
+                            "description": "Optional issue type name to limit the output to (e.g., 'Bug')"
+                        },
+                        "example_issue_key": {
+                            "type": "string",
+                            "description": "Optional issue key whose concrete transitions should be listed"
+                        }
+                    }
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_issue_security_levels".to_string(),
+                description: "Get the issue security levels of a project, for setting `security` when creating or updating issues".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
                         "project_key": {
                             "type": "string",
-                            "description": "The key of the project to get comprehensive metadata for"
+                            "description": "The key of the project (defaults to JIRA_DEFAULT_PROJECT)"
+                        }
+                    }
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "list_creatable_fields".to_string(),
+                description: "List the fields on the create screen of an issue type in a project, with whether each is required, its type, allowed values and default. Unlike get_custom_fields this only shows fields that can be set when creating".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "project_key": {
+                            "type": "string",
+                            "description": "The key of the project (defaults to JIRA_DEFAULT_PROJECT)"
+                        },
+                        "issue_type": {
+                            "type": "string",
+                            "description": "Issue type id or name (e.g., 'Bug')"
+                        },
+                        "refresh": {
+                            "type": "boolean",
+                            "description": "Fetch the create screen again instead of using the cached copy",
+                            "default": false
                         }
                     },
-                    "required": ["project_key"]
+                    "required": ["issue_type"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "plan_issue_creation".to_string(),
+                description: "Work out what a new issue still needs before it can be created. Given the fields gathered so far, returns the create screen's required fields that are missing (id, name, type and a sample of allowed values) and those already set, so you can ask the user for the rest and call again. Text values of options, numbers and lists are converted as create_jira_issue needs them; with create_when_complete the issue is created once nothing is missing".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "project_key": {
+                            "type": "string",
+                            "description": "The key of the project (defaults to JIRA_DEFAULT_PROJECT)"
+                        },
+                        "issue_type": {
+                            "type": "string",
+                            "description": "Issue type id or name (e.g., 'Bug')"
+                        },
+                        "fields": {
+                            "type": "object",
+                            "description": "The fields gathered so far, by field id or name (e.g., {\"summary\": \"Login fails\", \"Priority\": \"High\"})"
+                        },
+                        "create_when_complete": {
+                            "type": "boolean",
+                            "description": "Create the issue in this call if no required field is missing (default false)",
+                            "default": false
+                        },
+                        "refresh": {
+                            "type": "boolean",
+                            "description": "Fetch the create screen again instead of using the cached copy",
+                            "default": false
+                        }
+                    },
+                    "required": ["issue_type"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -952,6 +2408,7 @@ impl MCPServer {
         let mut tools = Vec::new();
         tools.extend(Self::get_simple_bulk_tools());
         tools.extend(Self::get_mixed_bulk_tools());
+        tools.extend(Self::get_bulk_history_tools());
         tools
     }
 
@@ -960,140 +2417,356 @@ impl MCPServer {
         vec![
             MCPTool {
                 name: "bulk_update_issues".to_string(),
-                description: "Bulk update multiple Jira issues with the same fields".to_string(),
+                description: format!("Bulk update multiple Jira issues with the same fields. The values the fields had are read first, one request per issue, so undo_bulk_operation can put them back; an issue they cannot be read from is not updated. {BULK_SUMMARY_SCHEMA}"),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
                         "issue_keys": {
                             "type": "array",
                             "items": {"type": "string"},
-                            "description": "Array of issue keys to update"
+                            "description": "Array of issue keys to update; give either this or jql"
                         },
-                        "fields": {
+                        "jql": {
+                            "type": "string",
+                            "description": "Select the issues matching this JQL instead of listing issue_keys; every page of the search is read, sorted by key unless the JQL has an ORDER BY. Requires max_issues"
+                        },
+                        "max_issues": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "description": "Required with jql: the most issues it may select. Nothing is changed when it matches more"
+                        },
+                        "dry_run": {
+                            "type": "boolean",
+                            "description": "Only list the selected issues, without changing them (default: false)"
+                        },
+                        "update_data": {
                             "type": "object",
-                            "description": "The fields to update on all issues"
+                            "description": "The update of every issue: values to set under fields and operations per field under update, as for update_jira_issue, e.g. {\"fields\": {\"priority\": {\"name\": \"High\"}}, \"update\": {\"components\": [{\"add\": {\"name\": \"API\"}}]}}"
+                        },
+                        "add_labels": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Labels to add to every issue, leaving their other labels alone"
+                        },
+                        "remove_labels": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Labels to remove from every issue"
+                        },
+                        "add_fix_versions": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Names of fix versions to add to every issue, leaving their other fix versions alone"
+                        },
+                        "remove_fix_versions": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Names of fix versions to remove from every issue"
+                        },
+                        "return_issues": {
+                            "type": "string",
+                            "enum": ["none", "failed", "all"],
+                            "description": "Read back none (default), the failed or all issues and include their details and JSON in the result, at most 20"
+                        },
+                        "return_fields": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Fields to read back with return_issues (default all)"
                         },
                         "config": {
                             "type": "object",
-                            "description": "Optional configuration for batch processing",
+                            "description": "Optional configuration for batch processing; unset settings come from [bulk_defaults] of the project most issues belong to, then the global [bulk_defaults]",
                             "properties": {
                                 "batch_size": {"type": "integer", "description": "Number of issues to process per batch"},
                                 "continue_on_error": {"type": "boolean", "description": "Whether to continue processing if individual operations fail"},
                                 "rate_limit_ms": {"type": "integer", "description": "Delay between operations in milliseconds"},
-                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"}
+                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"},
+                                "max_concurrency": {"type": "integer", "description": "Operations of a batch run at the same time"}
                             }
                         }
-                    },
-                    "required": ["issue_keys", "fields"]
+                    }
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "bulk_transition_issues".to_string(),
-                description: "Bulk transition multiple Jira issues to the same status".to_string(),
+                description: format!("Bulk transition multiple Jira issues to the same status. {BULK_SUMMARY_SCHEMA}"),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
                         "issue_keys": {
                             "type": "array",
                             "items": {"type": "string"},
-                            "description": "Array of issue keys to transition"
+                            "description": "Array of issue keys to transition; give either this or jql"
+                        },
+                        "jql": {
+                            "type": "string",
+                            "description": "Select the issues matching this JQL instead of listing issue_keys; every page of the search is read, sorted by key unless the JQL has an ORDER BY. Requires max_issues"
+                        },
+                        "max_issues": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "description": "Required with jql: the most issues it may select. Nothing is changed when it matches more"
+                        },
+                        "dry_run": {
+                            "type": "boolean",
+                            "description": "Only list the selected issues, without changing them (default: false)"
                         },
                         "transition_id": {
                             "type": "string",
                             "description": "The ID of the transition to apply to all issues"
                         },
+                        "transition_name": {
+                            "type": "string",
+                            "description": "Name of the transition (or target status) to apply, matched case-insensitively. Used when transition_id is not given"
+                        },
                         "comment": {
                             "type": "string",
                             "description": "Optional comment to add during transition"
                         },
+                        "check_blockers": {
+                            "type": "boolean",
+                            "description": "Skip issues that are blocked by issues not yet done, listing the blocking keys in the summary (default: false)"
+                        },
+                        "blocker_link_type": {
+                            "type": "string",
+                            "description": "Link type, or its inward description, that marks an issue as blocked (default: the configured blocker link type, usually 'Blocks')"
+                        },
+                        "config": {
+                            "type": "object",
+                            "description": "Optional configuration for batch processing; unset settings come from [bulk_defaults] of the project most issues belong to, then the global [bulk_defaults]",
+                            "properties": {
+                                "batch_size": {"type": "integer", "description": "Number of issues to process per batch"},
+                                "continue_on_error": {"type": "boolean", "description": "Whether to continue processing if individual operations fail"},
+                                "rate_limit_ms": {"type": "integer", "description": "Delay between operations in milliseconds"},
+                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"},
+                                "max_concurrency": {"type": "integer", "description": "Operations of a batch run at the same time"}
+                            }
+                        }
+                    }
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "bulk_add_comments".to_string(),
+                description: format!("Bulk add the same comment to multiple Jira issues. {BULK_SUMMARY_SCHEMA}"),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_keys": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Array of issue keys to add comments to; give either this or jql"
+                        },
+                        "jql": {
+                            "type": "string",
+                            "description": "Select the issues matching this JQL instead of listing issue_keys; every page of the search is read, sorted by key unless the JQL has an ORDER BY. Requires max_issues"
+                        },
+                        "max_issues": {
+                            "type": "integer",
+                            "minimum": 1,
+                            "description": "Required with jql: the most issues it may select. Nothing is changed when it matches more"
+                        },
+                        "dry_run": {
+                            "type": "boolean",
+                            "description": "Only list the selected issues, without changing them (default: false)"
+                        },
+                        "comment_body": {
+                            "type": "string",
+                            "description": "The comment text to add to all issues"
+                        },
+                        "resolve_mentions": {
+                            "type": "boolean",
+                            "description": "Turn @name, @email or @\"Display Name\" into real Jira mentions (default: false). Names that match no user or several users are left as text and reported"
+                        },
+                        "visibility": {
+                            "type": "object",
+                            "description": "Optional restriction so only a role or group can see the comment",
+                            "properties": {
+                                "type": {"type": "string", "enum": ["role", "group"], "description": "Whether value names a project role or a group"},
+                                "value": {"type": "string", "description": "Name of the role or group"}
+                            },
+                            "required": ["type", "value"]
+                        },
+                        "config": {
+                            "type": "object",
+                            "description": "Optional configuration for batch processing; unset settings come from [bulk_defaults] of the project most issues belong to, then the global [bulk_defaults]",
+                            "properties": {
+                                "batch_size": {"type": "integer", "description": "Number of issues to process per batch"},
+                                "continue_on_error": {"type": "boolean", "description": "Whether to continue processing if individual operations fail"},
+                                "rate_limit_ms": {"type": "integer", "description": "Delay between operations in milliseconds"},
+                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"},
+                                "max_concurrency": {"type": "integer", "description": "Operations of a batch run at the same time"}
+                            }
+                        }
+                    },
+                    "required": ["comment_body"]
+                }),
+                ..Default::default()
+            },
+        ]
+    }
+
+    /// Get mixed bulk operation tools
+    fn get_mixed_bulk_tools() -> Vec<MCPTool> {
+        vec![
+            MCPTool {
+                name: "mixed_bulk_operations".to_string(),
+                description: format!("Execute mixed bulk operations on multiple Jira issues (update, transition, add comments, or mixed operations). {BULK_SUMMARY_SCHEMA}"),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "operations": {
+                            "type": "array",
+                            "description": "Array of operation objects. All are checked before any runs; if any is malformed nothing is executed and every problem is reported by index",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "issue_key": {"type": "string", "description": "The issue key for this operation"},
+                                    "operation_type": {
+                                        "type": "string",
+                                        "enum": ["update", "transition", "add_comment", "mixed"],
+                                        "description": "The type of operation to perform"
+                                    },
+                                    "data": {
+                                        "type": "object",
+                                        "description": "The data for the operation (fields for update, transition_id for transition, comment_body for add_comment). An update may add expected_updated, the issue's 'updated' timestamp when last read, to fail with a conflict if the issue changed since"
+                                    }
+                                },
+                                "required": ["issue_key", "operation_type", "data"]
+                            }
+                        },
+                        "config": {
+                            "type": "object",
+                            "description": "Optional configuration for batch processing; unset settings come from [bulk_defaults] of the project most issues belong to, then the global [bulk_defaults]",
+                            "properties": {
+                                "batch_size": {"type": "integer", "description": "Number of issues to process per batch"},
+                                "continue_on_error": {"type": "boolean", "description": "Whether to continue processing if individual operations fail"},
+                                "rate_limit_ms": {"type": "integer", "description": "Delay between operations in milliseconds"},
+                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"},
+                                "max_concurrency": {"type": "integer", "description": "Operations of a batch run at the same time"}
+                            }
+                        }
+                    },
+                    "required": ["operations"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "bulk_operations_from_csv".to_string(),
+                description: format!("Run bulk operations read from a CSV file, one operation per row. The file must be inside a directory listed in JIRA_FILE_ALLOWLIST. Rows that cannot be read are reported with their line numbers and nothing is executed. A cell holding a JSON object or array (e.g. {{\"name\": \"High\"}}) is sent as that JSON, any other cell as a string; empty cells are left out. Failures are reported with the CSV line of their row. {BULK_SUMMARY_SCHEMA} Each result also carries the \"line\" of its row."),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "file_path": {"type": "string", "description": "Absolute path of the CSV file; its first line is the header"},
+                        "operation_type": {
+                            "type": "string",
+                            "enum": ["update", "transition", "add_comment", "add_watcher", "remove_watcher"],
+                            "description": "The operation every row performs"
+                        },
+                        "column_mapping": {
+                            "type": "object",
+                            "additionalProperties": {"type": "string"},
+                            "description": "Maps CSV column names to where their values go. One column must map to \"issue_key\". For update, the other targets are field ids (e.g. {\"Key\": \"issue_key\", \"Priority\": \"priority\"}); for the other operations they are transition_id and comment, comment_body, or account_id. Unmapped columns are ignored"
+                        },
+                        "validate": {"type": "boolean", "description": "For updates, check the field values of the first rows against each issue's edit metadata before executing anything (default: false)"},
+                        "validate_rows": {"type": "integer", "description": "Number of rows to validate (default: 5)"},
                         "config": {
                             "type": "object",
-                            "description": "Optional configuration for batch processing",
+                            "description": "Optional configuration for batch processing; unset settings come from [bulk_defaults] of the project most issues belong to, then the global [bulk_defaults]",
                             "properties": {
                                 "batch_size": {"type": "integer", "description": "Number of issues to process per batch"},
                                 "continue_on_error": {"type": "boolean", "description": "Whether to continue processing if individual operations fail"},
                                 "rate_limit_ms": {"type": "integer", "description": "Delay between operations in milliseconds"},
-                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"}
+                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"},
+                                "max_concurrency": {"type": "integer", "description": "Operations of a batch run at the same time"}
                             }
                         }
                     },
-                    "required": ["issue_keys", "transition_id"]
+                    "required": ["file_path", "operation_type", "column_mapping"]
                 }),
+                ..Default::default()
             },
             MCPTool {
-                name: "bulk_add_comments".to_string(),
-                description: "Bulk add the same comment to multiple Jira issues".to_string(),
+                name: "bulk_create_issues_from_csv".to_string(),
+                description: "Create one Jira issue per row of a CSV file. The file must be inside a directory listed in JIRA_FILE_ALLOWLIST. Every row is checked against the create screen of its project and issue type first; if any row fails, all problems are reported with their CSV line numbers and nothing is created. Option fields take the option's name or id, number fields a number, and array fields (labels, components) comma-separated items; a cell holding a JSON object or array is sent as that JSON. Empty cells are left out. The result is a text report listing the created keys in row order, followed by a second content item holding JSON: {\"created\": int, \"issues\": [{\"line\": int, \"issue_key\": string|null, \"error\": string|null}]}. With dry_run, the JSON is instead the fields of the first 5 rows: [{\"line\": int, \"fields\": object}]".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
-                        "issue_keys": {
-                            "type": "array",
-                            "items": {"type": "string"},
-                            "description": "Array of issue keys to add comments to"
-                        },
-                        "comment_body": {
-                            "type": "string",
-                            "description": "The comment text to add to all issues"
-                        },
-                        "config": {
+                        "file_path": {"type": "string", "description": "Absolute path of the CSV file; its first line is the header"},
+                        "column_mapping": {
                             "type": "object",
-                            "description": "Optional configuration for batch processing",
-                            "properties": {
-                                "batch_size": {"type": "integer", "description": "Number of issues to process per batch"},
-                                "continue_on_error": {"type": "boolean", "description": "Whether to continue processing if individual operations fail"},
-                                "rate_limit_ms": {"type": "integer", "description": "Delay between operations in milliseconds"},
-                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"}
-                            }
-                        }
+                            "additionalProperties": {"type": "string"},
+                            "description": "Maps CSV column names to field ids or display names (e.g. {\"Title\": \"summary\", \"Story Points\": \"Story Points\"}). Map columns to project (key) and issuetype (name) unless project_key and issue_type cover every row. Unmapped columns are ignored"
+                        },
+                        "project_key": {"type": "string", "description": "Project of rows without a project column value (defaults to JIRA_DEFAULT_PROJECT)"},
+                        "issue_type": {"type": "string", "description": "Issue type name or id of rows without an issue type column value"},
+                        "defaults": {"type": "object", "description": "Field values (by field id or name) every row starts from; row values take precedence"},
+                        "dry_run": {"type": "boolean", "description": "Validate the rows and show the fields of the first 5 without creating anything (default: false)"}
                     },
-                    "required": ["issue_keys", "comment_body"]
+                    "required": ["file_path", "column_mapping"]
                 }),
+                ..Default::default()
             },
         ]
     }
 
-    /// Get mixed bulk operation tools
-    fn get_mixed_bulk_tools() -> Vec<MCPTool> {
+    /// Get bulk operation history tools
+    fn get_bulk_history_tools() -> Vec<MCPTool> {
         vec![
             MCPTool {
-                name: "mixed_bulk_operations".to_string(),
-                description: "Execute mixed bulk operations on multiple Jira issues (update, transition, add comments, or mixed operations)".to_string(),
+                name: "get_bulk_operation_history".to_string(),
+                description: "The bulk operations run by bulk_update_issues, bulk_transition_issues, bulk_add_comments, mixed_bulk_operations and bulk_operations_from_csv, newest first: id, tool, operation type, issue keys, start and finish times, counts and the arguments with credentials redacted. The last 50 are kept, across restarts when JIRA_BULK_HISTORY_FILE is set. Returns text and JSON".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
-                        "operations": {
-                            "type": "array",
-                            "description": "Array of operation objects",
-                            "items": {
-                                "type": "object",
-                                "properties": {
-                                    "issue_key": {"type": "string", "description": "The issue key for this operation"},
-                                    "operation_type": {
-                                        "type": "string",
-                                        "enum": ["update", "transition", "add_comment", "mixed"],
-                                        "description": "The type of operation to perform"
-                                    },
-                                    "data": {
-                                        "type": "object",
-                                        "description": "The data for the operation (fields for update, transition_id for transition, comment_body for add_comment)"
-                                    }
-                                },
-                                "required": ["issue_key", "operation_type", "data"]
-                            }
+                        "since": {"type": "string", "description": "Only operations that finished on or after this date (YYYY-MM-DD) or ISO timestamp"},
+                        "until": {"type": "string", "description": "Only operations that finished before this date (YYYY-MM-DD) or ISO timestamp"},
+                        "operation_type": {
+                            "type": "string",
+                            "enum": ["update", "transition", "add_comment", "mixed", "add_watcher", "remove_watcher"],
+                            "description": "Only operations of this type"
                         },
+                        "limit": {"type": "integer", "description": "Most operations to return (default all kept)"}
+                    }
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_bulk_operation_detail".to_string(),
+                description: "One bulk operation from the history by the history_id its bulk tool returned, with the result of every issue. Returns text and JSON".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "string", "description": "The history_id of the operation, e.g. bulk-1a2b3c4d5e6f"}
+                    },
+                    "required": ["id"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "undo_bulk_operation".to_string(),
+                description: format!("Put back the values a bulk_update_issues operation replaced, on the issues it updated, as a bulk operation of its own. Each issue is first read again; an issue whose fields no longer hold the values the operation set is skipped unless force is true. {BULK_SUMMARY_SCHEMA}"),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "string", "description": "The history_id bulk_update_issues returned"},
+                        "force": {"type": "boolean", "description": "Also put back fields changed again since the operation (default: false)"},
                         "config": {
                             "type": "object",
-                            "description": "Optional configuration for batch processing",
+                            "description": "Optional configuration for batch processing; unset settings come from [bulk_defaults] of the project most issues belong to, then the global [bulk_defaults]",
                             "properties": {
                                 "batch_size": {"type": "integer", "description": "Number of issues to process per batch"},
                                 "continue_on_error": {"type": "boolean", "description": "Whether to continue processing if individual operations fail"},
                                 "rate_limit_ms": {"type": "integer", "description": "Delay between operations in milliseconds"},
-                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"}
+                                "max_retries": {"type": "integer", "description": "Maximum number of retries for failed operations"},
+                                "max_concurrency": {"type": "integer", "description": "Operations of a batch run at the same time"}
                             }
                         }
                     },
-                    "required": ["operations"]
+                    "required": ["id"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -1123,6 +2796,7 @@ impl MCPServer {
                     },
                     "required": ["sprint_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "create_sprint".to_string(),
@@ -1153,6 +2827,7 @@ impl MCPServer {
                     },
                     "required": ["name", "rapid_view_id"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -1180,6 +2855,7 @@ impl MCPServer {
                     },
                     "required": ["sprint_id", "issues"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "start_sprint".to_string(),
@@ -1194,6 +2870,7 @@ impl MCPServer {
                     },
                     "required": ["sprint_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "close_sprint".to_string(),
@@ -1208,6 +2885,7 @@ impl MCPServer {
                     },
                     "required": ["sprint_id"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -1236,6 +2914,7 @@ impl MCPServer {
                     },
                     "required": ["sprint_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_board_sprints".to_string(),
@@ -1250,6 +2929,30 @@ impl MCPServer {
                     },
                     "required": ["rapid_view_id"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "sprint_report".to_string(),
+                description: "Markdown sprint report: committed vs completed issues and story points, carried-over and mid-sprint additions, and totals per assignee".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "board_id": {
+                            "type": "integer",
+                            "description": "The ID of the board (rapid view)"
+                        },
+                        "sprint_id": {
+                            "oneOf": [{"type": "integer"}, {"type": "string", "enum": ["active"]}],
+                            "description": "The ID of the sprint, or \"active\" for the board's active sprint"
+                        },
+                        "story_points_field": {
+                            "type": "string",
+                            "description": "Custom field id holding story points (optional, detected from the field catalog by default)"
+                        }
+                    },
+                    "required": ["board_id", "sprint_id"]
+                }),
+                ..Default::default()
             },
         ]
     }
@@ -1280,6 +2983,7 @@ impl MCPServer {
                     },
                     "required": ["test_case_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "create_zephyr_test_step".to_string(),
@@ -1310,6 +3014,7 @@ impl MCPServer {
                     },
                     "required": ["test_case_id", "step", "order"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "update_zephyr_test_step".to_string(),
@@ -1344,6 +3049,7 @@ impl MCPServer {
                     },
                     "required": ["test_case_id", "step_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "delete_zephyr_test_step".to_string(),
@@ -1362,6 +3068,7 @@ impl MCPServer {
                     },
                     "required": ["test_case_id", "step_id"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -1377,31 +3084,35 @@ impl MCPServer {
                     "properties": {
                         "project_key": {
                             "type": "string",
-                            "description": "The key of the project to search test cases in"
+                            "description": "The key of the project to search test cases in (defaults to JIRA_DEFAULT_PROJECT)"
                         },
                         "start_at": {
                             "type": "integer",
-                            "description": "The index of the first item to return (also accepts startAt)"
+                            "description": "The index of the first item to return (also accepts startAt)",
+                            "minimum": 0
                         },
                         "startAt": {
                             "type": "integer",
-                            "description": "The index of the first item to return (alternative to start_at)"
+                            "description": "The index of the first item to return (alternative to start_at)",
+                            "minimum": 0
                         },
                         "max_results": {
                             "type": "integer",
-                            "description": "The maximum number of items to return (also accepts maxResults)"
+                            "description": "The maximum number of items to return, up to JIRA_MAX_RESULTS_LIMIT (default 1000); 0 returns only the total (also accepts maxResults)",
+                            "minimum": 0
                         },
                         "maxResults": {
                             "type": "integer",
-                            "description": "The maximum number of items to return (alternative to max_results)"
+                            "description": "The maximum number of items to return, up to JIRA_MAX_RESULTS_LIMIT (default 1000); 0 returns only the total (alternative to max_results)",
+                            "minimum": 0
                         }
-                    },
-                    "required": ["project_key"]
+                    }
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "create_zephyr_test_case".to_string(),
-                description: "Create a new Zephyr test case".to_string(),
+                description: "Create a new Zephyr test case, optionally with its steps; returns the case key and the ids of the steps created".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
@@ -1428,10 +3139,24 @@ impl MCPServer {
                         "description": {
                             "type": "string",
                             "description": "Optional description for the test case"
+                        },
+                        "steps": {
+                            "type": "array",
+                            "description": "Optional steps, created one at a time in this order after the test case. If one fails, the steps created before it are reported and the rest are not tried",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "step": {"type": "string", "description": "What to do"},
+                                    "data": {"type": "string", "description": "Test data for the step"},
+                                    "result": {"type": "string", "description": "Expected result"}
+                                },
+                                "required": ["step"]
+                            }
                         }
                     },
                     "required": ["name", "project_key", "issue_type"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -1448,10 +3173,15 @@ impl MCPServer {
                         "test_case_id": {
                             "type": "string",
                             "description": "The ID of the test case to get executions for"
+                        },
+                        "folder_id": {
+                            "type": "integer",
+                            "description": "Optional cycle folder ID; only executions in that folder are listed"
                         }
                     },
                     "required": ["test_case_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "create_zephyr_test_execution".to_string(),
@@ -1479,6 +3209,10 @@ impl MCPServer {
                             "type": "string",
                             "description": "Optional version ID"
                         },
+                        "folder_id": {
+                            "type": "integer",
+                            "description": "Optional folder of the cycle to create the execution in; needs cycle_id"
+                        },
                         "assignee": {
                             "type": "string",
                             "description": "Optional assignee for the execution"
@@ -1490,6 +3224,55 @@ impl MCPServer {
                     },
                     "required": ["test_case_id", "project_id", "status"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "upload_zephyr_execution_attachment".to_string(),
+                description:
+                    "Attach a file, such as a screenshot or log, to a Zephyr test execution"
+                        .to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "execution_id": {
+                            "type": "string",
+                            "description": "The ID of the test execution to attach the file to"
+                        },
+                        "file_path": {
+                            "type": "string",
+                            "description": "Path of a file inside the allowed directories to upload; use instead of content"
+                        },
+                        "filename": {
+                            "type": "string",
+                            "description": "The name of the file; required with content, defaults to the file's name with file_path"
+                        },
+                        "content": {
+                            "type": "string",
+                            "description": "The file content as base64 encoded string"
+                        },
+                        "mime_type": {
+                            "type": "string",
+                            "description": "Optional MIME type of the file"
+                        }
+                    },
+                    "required": ["execution_id"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_zephyr_execution_attachments".to_string(),
+                description: "Get the files attached to a Zephyr test execution".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "execution_id": {
+                            "type": "string",
+                            "description": "The ID of the test execution to get attachments for"
+                        }
+                    },
+                    "required": ["execution_id"]
+                }),
+                ..Default::default()
             },
         ]
     }
@@ -1510,6 +3293,88 @@ impl MCPServer {
                     },
                     "required": ["project_key"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_zephyr_folders".to_string(),
+                description: "Get the folders of a Zephyr test cycle".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "cycle_id": {
+                            "type": "string",
+                            "description": "The ID of the test cycle"
+                        },
+                        "project_id": {
+                            "type": "string",
+                            "description": "The ID of the project"
+                        },
+                        "version_id": {
+                            "type": "string",
+                            "description": "The ID of the cycle's version; defaults to -1, unscheduled"
+                        }
+                    },
+                    "required": ["cycle_id", "project_id"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "create_zephyr_folder".to_string(),
+                description: "Create a folder in a Zephyr test cycle".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "name": {
+                            "type": "string",
+                            "description": "The name of the folder"
+                        },
+                        "cycle_id": {
+                            "type": "string",
+                            "description": "The ID of the test cycle"
+                        },
+                        "project_id": {
+                            "type": "string",
+                            "description": "The ID of the project"
+                        },
+                        "version_id": {
+                            "type": "string",
+                            "description": "The ID of the cycle's version; defaults to -1, unscheduled"
+                        },
+                        "description": {
+                            "type": "string",
+                            "description": "Optional description for the folder"
+                        }
+                    },
+                    "required": ["name", "cycle_id", "project_id"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "zephyr_cycle_report".to_string(),
+                description: "Pass rate of the executions in a Zephyr test cycle, broken down by folder, or of one folder".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "cycle_id": {
+                            "type": "string",
+                            "description": "The ID of the test cycle"
+                        },
+                        "project_id": {
+                            "type": "string",
+                            "description": "The ID of the project"
+                        },
+                        "version_id": {
+                            "type": "string",
+                            "description": "The ID of the cycle's version; defaults to -1, unscheduled"
+                        },
+                        "folder_id": {
+                            "type": "integer",
+                            "description": "Optional folder to report on instead of the whole cycle"
+                        }
+                    },
+                    "required": ["cycle_id", "project_id"]
+                }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_zephyr_test_plans".to_string(),
@@ -1524,6 +3389,34 @@ impl MCPServer {
                     },
                     "required": ["project_key"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "zephyr_traceability_report".to_string(),
+                description: "Coverage matrix of a project's requirements by its test cases: the tests covering each requirement, the requirements each test covers, and the requirements no test covers".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "project_key": {
+                            "type": "string",
+                            "description": "The key of the project; defaults to the configured default project"
+                        },
+                        "requirements_jql": {
+                            "type": "string",
+                            "description": "JQL matching the requirement issues, e.g. 'project = DNA AND issuetype = Story'"
+                        },
+                        "tests_jql": {
+                            "type": "string",
+                            "description": "JQL matching the test case issues (default: issues of type Test in the project)"
+                        },
+                        "link_type": {
+                            "type": "string",
+                            "description": "Link type from a test case to the requirement it covers, by name or the test's side of it (default: JIRA_TEST_LINK_TYPE, 'Tests')"
+                        }
+                    },
+                    "required": ["requirements_jql"]
+                }),
+                ..Default::default()
             },
         ]
     }
@@ -1538,6 +3431,7 @@ impl MCPServer {
                     "type": "object",
                     "properties": {}
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "get_jira_issue_links".to_string(),
@@ -1552,6 +3446,36 @@ impl MCPServer {
                     },
                     "required": ["issue_key"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_issue_link_graph".to_string(),
+                description: "Follow the links of a Jira issue transitively, e.g. everything blocking it. Returns an indented tree with each issue's status and summary, followed by the graph as JSON: {\"root\", \"max_depth\", \"nodes\": [{\"key\", \"summary\", \"status\", \"depth\", \"parent\", \"relation\"}], \"edges\": [{\"id\", \"link_type\", \"from\", \"to\"}], \"truncated\"}. Edges point from the outward side (e.g. the blocking issue) to the inward side".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue to start from"
+                        },
+                        "link_types": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Only follow these link types, by name (e.g. \"Blocks\") or description (e.g. \"is blocked by\"). Default: all types"
+                        },
+                        "direction": {
+                            "type": "string",
+                            "enum": ["inward", "outward", "both"],
+                            "description": "Follow inward links (e.g. \"is blocked by\"), outward links (e.g. \"blocks\") or both (default: both)"
+                        },
+                        "max_depth": {
+                            "type": "integer",
+                            "description": "Levels of links to follow (default: 3, at most 5)"
+                        }
+                    },
+                    "required": ["issue_key"]
+                }),
+                ..Default::default()
             },
             MCPTool {
                 name: "create_jira_issue_link".to_string(),
@@ -1578,6 +3502,7 @@ impl MCPServer {
                     },
                     "required": ["link_type", "inward_issue", "outward_issue"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "delete_jira_issue_link".to_string(),
@@ -1592,6 +3517,7 @@ impl MCPServer {
                     },
                     "required": ["link_id"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -1605,63 +3531,157 @@ impl MCPServer {
                 input_schema: json!({
                     "type": "object",
                     "properties": {
-                        "issue_key": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue to get attachments for"
+                        }
+                    },
+                    "required": ["issue_key"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "upload_jira_attachment".to_string(),
+                description: "Upload a file attachment to a Jira issue. A file over the attachment size limit of the instance is rejected before it is sent".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue to attach the file to"
+                        },
+                        "file_path": {
+                            "type": "string",
+                            "description": "Path of a file inside the allowed directories to upload; use instead of content"
+                        },
+                        "filename": {
+                            "type": "string",
+                            "description": "The name of the file; required with content, defaults to the file's name with file_path"
+                        },
+                        "content": {
+                            "type": "string",
+                            "description": "The file content as base64 encoded string"
+                        },
+                        "mime_type": {
+                            "type": "string",
+                            "description": "Optional MIME type of the file"
+                        }
+                    },
+                    "required": ["issue_key"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "delete_jira_attachment".to_string(),
+                description: "Delete an attachment from a Jira issue".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "attachment_id": {
+                            "type": "string",
+                            "description": "The ID of the attachment to delete"
+                        }
+                    },
+                    "required": ["attachment_id"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "download_jira_attachment".to_string(),
+                description: "Download an attachment from a Jira issue. Attachments larger than the configured limit (10 MiB by default) are refused unless force is true".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "attachment_id": {
                             "type": "string",
-                            "description": "The key of the issue to get attachments for"
+                            "description": "The ID of the attachment to download"
+                        },
+                        "force": {
+                            "type": "boolean",
+                            "description": "Download even if the attachment is larger than the configured limit (default: false)"
                         }
                     },
-                    "required": ["issue_key"]
+                    "required": ["attachment_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
-                name: "upload_jira_attachment".to_string(),
-                description: "Upload a file attachment to a Jira issue".to_string(),
+                name: "get_jira_attachment_info".to_string(),
+                description: "Get an attachment's filename, size, MIME type, author, creation date and whether it has a thumbnail, without downloading it".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
-                        "issue_key": {
-                            "type": "string",
-                            "description": "The key of the issue to attach the file to"
-                        },
-                        "filename": {
+                        "attachment_id": {
                             "type": "string",
-                            "description": "The name of the file"
+                            "description": "The ID of the attachment"
                         },
-                        "content": {
-                            "type": "string",
-                            "description": "The file content as base64 encoded string"
+                        "include_thumbnail": {
+                            "type": "boolean",
+                            "description": "Return the thumbnail as base64 when it exists and is at most 64 KiB (default: false)"
                         }
                     },
-                    "required": ["issue_key", "filename", "content"]
+                    "required": ["attachment_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
-                name: "delete_jira_attachment".to_string(),
-                description: "Delete an attachment from a Jira issue".to_string(),
+                name: "download_all_issue_attachments".to_string(),
+                description: "Download every attachment of a Jira issue into a directory allowed by JIRA_FILE_ALLOWLIST, three at a time. Files are named after the attachments, with path separators removed and a numeric suffix (report-1.pdf) on collisions. Attachments that fail or exceed the configured size limit are reported without stopping the rest. The result is a text report followed by the manifest as JSON: [{\"attachment_id\": string, \"filename\": string, \"local_path\": string|null, \"size\": int|null, \"sha256\": string|null, \"error\": string|null}]".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
-                        "attachment_id": {
+                        "issue_key": {
                             "type": "string",
-                            "description": "The ID of the attachment to delete"
+                            "description": "The key of the issue whose attachments to download"
+                        },
+                        "target_dir": {
+                            "type": "string",
+                            "description": "Absolute path of an existing directory inside JIRA_FILE_ALLOWLIST to save the attachments to"
+                        },
+                        "force": {
+                            "type": "boolean",
+                            "description": "Download attachments larger than the configured limit too (default: false)"
                         }
                     },
-                    "required": ["attachment_id"]
+                    "required": ["issue_key", "target_dir"]
                 }),
+                ..Default::default()
             },
             MCPTool {
-                name: "download_jira_attachment".to_string(),
-                description: "Download an attachment from a Jira issue".to_string(),
+                name: "upload_directory_attachments".to_string(),
+                description: "Upload the files of a directory allowed by JIRA_FILE_ALLOWLIST as attachments of a Jira issue, several per request. Files over max_file_size or the instance's attachment size limit, matching an exclusion (.DS_Store, Thumbs.db, desktop.ini, ._* and any given in exclude) and symlinks leading outside the allowed directories are skipped with a notice instead of failing the call. The result is a text report followed by JSON: {\"uploaded\": int, \"total_bytes\": int, \"files\": [{\"path\": string, \"size\": int|null, \"outcome\": \"uploaded\"|\"skipped\"|\"failed\", \"attachment_id\": string|null, \"message\": string|null}]}".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
-                        "attachment_id": {
+                        "issue_key": {
                             "type": "string",
-                            "description": "The ID of the attachment to download"
+                            "description": "The key of the issue to attach the files to"
+                        },
+                        "directory": {
+                            "type": "string",
+                            "description": "Absolute path of a directory inside JIRA_FILE_ALLOWLIST"
+                        },
+                        "pattern": {
+                            "type": "string",
+                            "description": "Glob the file names must match, with * and ? wildcards (e.g. '*.log'); all files when omitted"
+                        },
+                        "recursive": {
+                            "type": "boolean",
+                            "description": "Include files in subdirectories (default: false)"
+                        },
+                        "max_file_size": {
+                            "type": "integer",
+                            "description": "Largest file to upload in bytes; larger files are skipped (default: 10485760)"
+                        },
+                        "exclude": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Further file name globs to skip"
                         }
                     },
-                    "required": ["attachment_id"]
+                    "required": ["issue_key", "directory"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -1671,17 +3691,30 @@ impl MCPServer {
         vec![
             MCPTool {
                 name: "get_jira_issue_work_logs".to_string(),
-                description: "Get all work log entries for a specific Jira issue".to_string(),
+                description: "Get the work log entries of a Jira issue, every page of them up to JIRA_MAX_WORKLOGS, with the total time logged by the entries returned".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
                         "issue_key": {
                             "type": "string",
                             "description": "The key of the issue to get work logs for"
+                        },
+                        "started_after": {
+                            "type": "string",
+                            "description": "Only entries started at or after this YYYY-MM-DD date (UTC) or ISO timestamp"
+                        },
+                        "started_before": {
+                            "type": "string",
+                            "description": "Only entries started before this YYYY-MM-DD date (UTC) or ISO timestamp"
+                        },
+                        "author": {
+                            "type": "string",
+                            "description": "Only entries by this account id, username, email address or display name"
                         }
                     },
                     "required": ["issue_key"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "add_jira_work_log".to_string(),
@@ -1689,18 +3722,40 @@ impl MCPServer {
                 input_schema: json!({
                     "type": "object",
                     "properties": {
+                        "input_format": {
+                            "type": "string",
+                            "enum": ["wiki", "markdown"],
+                            "description": "How the comment is written: Jira wiki markup, sent as written, or Markdown, converted to wiki markup (to ADF on REST API v3). Defaults to JIRA_DEFAULT_INPUT_FORMAT, usually wiki"
+                        },
                         "issue_key": {
                             "type": "string",
                             "description": "The key of the issue to add work log to"
                         },
                         "time_spent": {
                             "type": "string",
-                            "description": "The time spent (e.g., '1h 30m', '2d', '3w')"
+                            "description": "The time spent, e.g. '1h 30m', '90m', '1.5h', '2d 4h' or '1:30'; sent as Jira's 'Nw Nd Nh Nm' using JIRA_WORK_HOURS_PER_DAY and JIRA_WORK_DAYS_PER_WEEK"
+                        },
+                        "adjust_estimate": {
+                            "type": "string",
+                            "enum": ["auto", "leave", "new", "manual"],
+                            "description": "What happens to the remaining estimate: reduce it by the time spent (auto, default), leave it, set it to new_estimate (new) or reduce it by reduce_by (manual)"
+                        },
+                        "new_estimate": {
+                            "type": "string",
+                            "description": "Remaining estimate to set, e.g. '2d'; implies adjust_estimate 'new'"
+                        },
+                        "reduce_by": {
+                            "type": "string",
+                            "description": "Duration to reduce the remaining estimate by, e.g. '30m'; implies adjust_estimate 'manual'"
                         },
                         "comment": {
                             "type": "string",
                             "description": "Optional comment for the work log"
                         },
+                        "on_behalf_of": {
+                            "type": "string",
+                            "description": "Display name, email or username of the user this is written for. The text then starts with '_on behalf of <name> via rust-jira-mcp_'; fails without writing if the user cannot be resolved. Needs JIRA_ANNOTATE_ON_BEHALF=true"
+                        },
                         "started": {
                             "type": "string",
                             "description": "Optional start time in ISO 8601 format"
@@ -1708,6 +3763,7 @@ impl MCPServer {
                     },
                     "required": ["issue_key", "time_spent"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "update_jira_work_log".to_string(),
@@ -1715,6 +3771,11 @@ impl MCPServer {
                 input_schema: json!({
                     "type": "object",
                     "properties": {
+                        "input_format": {
+                            "type": "string",
+                            "enum": ["wiki", "markdown"],
+                            "description": "How the comment is written: Jira wiki markup, sent as written, or Markdown, converted to wiki markup (to ADF on REST API v3). Defaults to JIRA_DEFAULT_INPUT_FORMAT, usually wiki"
+                        },
                         "issue_key": {
                             "type": "string",
                             "description": "The key of the issue containing the work log"
@@ -1725,12 +3786,25 @@ impl MCPServer {
                         },
                         "time_spent": {
                             "type": "string",
-                            "description": "The updated time spent"
+                            "description": "The updated time spent, e.g. '1h 30m', '90m', '1.5h' or '2d 4h'; sent as Jira's 'Nw Nd Nh Nm'"
+                        },
+                        "adjust_estimate": {
+                            "type": "string",
+                            "enum": ["auto", "leave", "new"],
+                            "description": "What happens to the remaining estimate: adjust it by the change in time spent (auto, default), leave it, or set it to new_estimate (new)"
+                        },
+                        "new_estimate": {
+                            "type": "string",
+                            "description": "Remaining estimate to set, e.g. '2d'; implies adjust_estimate 'new'"
                         },
                         "comment": {
                             "type": "string",
                             "description": "The updated comment"
                         },
+                        "on_behalf_of": {
+                            "type": "string",
+                            "description": "Display name, email or username of the user this is written for. The text then starts with '_on behalf of <name> via rust-jira-mcp_'; fails without writing if the user cannot be resolved. Needs JIRA_ANNOTATE_ON_BEHALF=true"
+                        },
                         "started": {
                             "type": "string",
                             "description": "The updated start time"
@@ -1738,6 +3812,7 @@ impl MCPServer {
                     },
                     "required": ["issue_key", "work_log_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "delete_jira_work_log".to_string(),
@@ -1756,6 +3831,7 @@ impl MCPServer {
                     },
                     "required": ["issue_key", "work_log_id"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -1765,17 +3841,28 @@ impl MCPServer {
         vec![
             MCPTool {
                 name: "get_jira_issue_watchers".to_string(),
-                description: "Get all watchers for a specific Jira issue".to_string(),
+                description: "Get the watchers of a Jira issue. Without permission to view voters and watchers only their count is shown".to_string(),
                 input_schema: json!({
                     "type": "object",
                     "properties": {
                         "issue_key": {
                             "type": "string",
                             "description": "The key of the issue to get watchers for"
+                        },
+                        "start_at": {
+                            "type": "integer",
+                            "description": "Index of the first watcher to show (default: 0)",
+                            "minimum": 0
+                        },
+                        "max_results": {
+                            "type": "integer",
+                            "description": "Most watchers to show (default: all); 0 shows only the count",
+                            "minimum": 0
                         }
                     },
                     "required": ["issue_key"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "add_jira_issue_watcher".to_string(),
@@ -1794,6 +3881,7 @@ impl MCPServer {
                     },
                     "required": ["issue_key", "account_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "remove_jira_issue_watcher".to_string(),
@@ -1812,6 +3900,61 @@ impl MCPServer {
                     },
                     "required": ["issue_key", "account_id"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "watch_jira_issue".to_string(),
+                description: format!("Watch Jira issues as the current user, without needing your account ID, and report the resulting watcher counts. {BULK_SUMMARY_SCHEMA}. The JSON is only included when issue_keys is given"),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue to watch"
+                        },
+                        "issue_keys": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Keys of several issues to watch, instead of issue_key"
+                        },
+                        "account_id": {
+                            "type": "string",
+                            "description": "Account ID (username on Server/Data Center) to act for instead of the current user, when permitted"
+                        },
+                        "config": {
+                            "type": "object",
+                            "description": "Bulk operation configuration used with issue_keys"
+                        }
+                    }
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "unwatch_jira_issue".to_string(),
+                description: format!("Stop watching Jira issues as the current user, without needing your account ID, and report the resulting watcher counts. {BULK_SUMMARY_SCHEMA}. The JSON is only included when issue_keys is given"),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {
+                            "type": "string",
+                            "description": "The key of the issue to stop watching"
+                        },
+                        "issue_keys": {
+                            "type": "array",
+                            "items": {"type": "string"},
+                            "description": "Keys of several issues to stop watching, instead of issue_key"
+                        },
+                        "account_id": {
+                            "type": "string",
+                            "description": "Account ID (username on Server/Data Center) to act for instead of the current user, when permitted"
+                        },
+                        "config": {
+                            "type": "object",
+                            "description": "Bulk operation configuration used with issue_keys"
+                        }
+                    }
+                }),
+                ..Default::default()
             },
         ]
     }
@@ -1826,6 +3969,7 @@ impl MCPServer {
                     "type": "object",
                     "properties": {}
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "create_jira_label".to_string(),
@@ -1840,6 +3984,7 @@ impl MCPServer {
                     },
                     "required": ["name"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "update_jira_label".to_string(),
@@ -1858,6 +4003,7 @@ impl MCPServer {
                     },
                     "required": ["old_name", "new_name"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "delete_jira_label".to_string(),
@@ -1872,6 +4018,7 @@ impl MCPServer {
                     },
                     "required": ["name"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -1891,7 +4038,7 @@ impl MCPServer {
                         },
                         "project": {
                             "type": "string",
-                            "description": "The key of the project to create the component in"
+                            "description": "The key of the project to create the component in (defaults to JIRA_DEFAULT_PROJECT)"
                         },
                         "description": {
                             "type": "string",
@@ -1906,8 +4053,9 @@ impl MCPServer {
                             "description": "Optional lead account ID for the component"
                         }
                     },
-                    "required": ["name", "project"]
+                    "required": ["name"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "update_jira_component".to_string(),
@@ -1938,6 +4086,7 @@ impl MCPServer {
                     },
                     "required": ["component_id"]
                 }),
+                ..Default::default()
             },
             MCPTool {
                 name: "delete_jira_component".to_string(),
@@ -1952,6 +4101,7 @@ impl MCPServer {
                     },
                     "required": ["component_id"]
                 }),
+                ..Default::default()
             },
         ]
     }
@@ -2029,25 +4179,477 @@ impl MCPServer {
                     },
                     "required": ["original_issue_key", "project_key", "issue_type", "summary"]
                 }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "export_issue_bundle".to_string(),
+                description: "Export an issue as a portable bundle for moving it to another Jira instance: writes bundle.json (fields, comments, worklogs, links by key) into target_dir and downloads the attachments into target_dir/attachments. Returns text and the bundle as JSON".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {"type": "string", "description": "Issue to export, e.g. PROJ-123"},
+                        "target_dir": {"type": "string", "description": "Existing directory inside JIRA_FILE_ALLOWLIST that holds no bundle yet"},
+                        "force": {"type": "boolean", "description": "Download attachments larger than JIRA_MAX_ATTACHMENT_DOWNLOAD_BYTES too (default: false)"}
+                    },
+                    "required": ["issue_key", "target_dir"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "import_issue_bundle".to_string(),
+                description: "Create an issue from a bundle written by export_issue_bundle: copies the fields field_mapping selects, replays comments and worklogs under a line naming their original author, re-uploads attachments and optionally links the issue to the same keys. Reports the outcome of every item; returns text and JSON".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "bundle_dir": {"type": "string", "description": "Directory inside JIRA_FILE_ALLOWLIST holding bundle.json"},
+                        "project_key": {"type": "string", "description": "Project to create the issue in (default: the exported issue's project)"},
+                        "issue_type": {"type": "string", "description": "Issue type to create (default: the exported issue's type)"},
+                        "link_issues": {"type": "boolean", "description": "Link the new issue to the issues the exported one was linked to, by the same keys (default: false)"},
+                        "field_mapping": {
+                            "type": "object",
+                            "description": "Fields to copy, as for clone_jira_issue (default: summary, description, priority, labels, components, fixVersions, environment, duedate)",
+                            "properties": {
+                                "copy_fields": {"type": "array", "items": {"type": "string"}, "description": "Field IDs to copy; empty copies every field except system fields"},
+                                "exclude_fields": {"type": "array", "items": {"type": "string"}, "description": "Field IDs not to copy"},
+                                "custom_field_mapping": {"type": "object", "description": "Map exported field IDs to field IDs of this instance", "additionalProperties": {"type": "string"}}
+                            }
+                        }
+                    },
+                    "required": ["bundle_dir"]
+                }),
+                ..Default::default()
+            },
+        ]
+    }
+
+    /// Get reporting tool definitions
+    fn get_report_tool_definitions() -> Vec<MCPTool> {
+        vec![
+        MCPTool {
+            name: "status_timeline".to_string(),
+            description: "Daily counts of issues per status category (To Do / In Progress / Done) for a JQL query over a date range, reconstructed from changelogs; returns a table and JSON series for plotting".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "jql": {
+                        "type": "string",
+                        "description": "JQL query selecting the issues"
+                    },
+                    "start_date": {
+                        "type": "string",
+                        "description": "First day of the range (YYYY-MM-DD)"
+                    },
+                    "end_date": {
+                        "type": "string",
+                        "description": "Last day of the range (YYYY-MM-DD, defaults to today)"
+                    },
+                    "max_issues": {
+                        "type": "integer",
+                        "description": "Refuse queries matching more issues than this (default 500, at most 2000)"
+                    }
+                },
+                "required": ["jql", "start_date"]
+            }),
+            ..Default::default()
+        },
+        MCPTool {
+            name: "get_field_history".to_string(),
+            description: "Every change made to one field of an issue, oldest first, with author, old value, new value and time; for status-like fields (status, priority, assignee, select lists) also the total time spent in each value".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "issue_key": {
+                        "type": "string",
+                        "description": "The issue key (e.g., 'PROJ-123')"
+                    },
+                    "field": {
+                        "type": "string",
+                        "description": "Field id or name (e.g., 'duedate', 'Due date', 'customfield_10010')"
+                    }
+                },
+                "required": ["issue_key", "field"]
+            }),
+            ..Default::default()
+        },
+        MCPTool {
+            name: "summarize_epic".to_string(),
+            description: "Roll-up of an epic's children, found through the parent field or the Epic Link field: counts per status category, story points done and remaining (unestimated children counted separately), children blocked by open issues, the latest activity and the assignee distribution; optionally one line per child. Reads the children with one bounded search".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "epic_key": {
+                        "type": "string",
+                        "description": "The epic's issue key (e.g., 'PROJ-100')"
+                    },
+                    "list_children": {
+                        "type": "boolean",
+                        "description": "Also list each child with its status, points, assignee and blockers",
+                        "default": false
+                    },
+                    "story_points_field": {
+                        "type": "string",
+                        "description": "Custom field id holding story points (optional, detected from the field catalog by default)"
+                    },
+                    "blocker_link_type": {
+                        "type": "string",
+                        "description": "Link type, or its inward description, that marks a child as blocked (default: the configured blocker link type, usually 'Blocks')"
+                    },
+                    "max_children": {
+                        "type": "integer",
+                        "description": "Fail instead of reading more children than this (default 500, at most 2000)"
+                    }
+                },
+                "required": ["epic_key"]
+            }),
+            ..Default::default()
+        },
+        MCPTool {
+            name: "get_project_stats".to_string(),
+            description: "Project issue statistics: totals by status category, issue type and priority, created vs resolved per week, median time to resolution and top assignees, as text and JSON. Uses count-only searches (one per category, type, priority and week) plus one fetch of at most 200 resolved issues".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "project_key": {
+                        "type": "string",
+                        "description": "The project key (defaults to JIRA_DEFAULT_PROJECT)"
+                    },
+                    "since": {
+                        "type": "string",
+                        "description": "Only count issues created (or resolved) on or after this date (YYYY-MM-DD, optional)"
+                    },
+                    "until": {
+                        "type": "string",
+                        "description": "Only count issues created (or resolved) on or before this date (YYYY-MM-DD, optional)"
+                    }
+                }
+            }),
+            ..Default::default()
+        }]
+    }
+
+    /// Get server metrics tool definitions
+    fn get_metrics_tool_definitions() -> Vec<MCPTool> {
+        vec![MCPTool {
+            name: "get_server_metrics".to_string(),
+            description: "Get server performance metrics, estimated connection reuse, the current Jira request throttle, the queue depth and wait time of each request class (core, agile, zephyr, attachments), the outcome of the start-up warm-up (null unless JIRA_WARMUP is set) and the issues the prefetcher keeps warm (JIRA_PREFETCH_ENABLED)".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {}
+            }),
+            ..Default::default()
+        },
+        MCPTool {
+            name: "get_performance_profile".to_string(),
+            description: "The slowest of the last 100 tool calls, each broken down into segments in the order they ran: deserialize arguments, processing between HTTP requests, one segment per request labelled with its method and endpoint, format response and serialize result. Returns text and JSON".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "limit": {
+                        "type": "integer",
+                        "description": "How many of the slowest calls to report (default 10)"
+                    },
+                    "reset": {
+                        "type": "boolean",
+                        "description": "Clear the kept profiles after reporting them (default false)"
+                    }
+                }
+            }),
+            ..Default::default()
+        },
+        MCPTool {
+            name: "list_write_policies".to_string(),
+            description: "List the write policies from the [[policies]] configuration that every tool call changing Jira is checked against, in the order they are checked, to see why a write was refused with a policy violation. Returns text and JSON".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {}
+            }),
+            ..Default::default()
+        },
+        MCPTool {
+            name: "explain_jira_error".to_string(),
+            description: "Explain a Jira error in plain words: what it means, its likely causes and what to change, such as the permission that is missing, the field that is not on the screen or the transition whose workflow condition is not met. Understands English, German, French and Spanish Jira messages. Give the error a tool call returned, or a raw Jira error body with its status code. Returns text and JSON".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "error": {
+                        "description": "The error of a failed tool call: its JSON-RPC error object, its details text, or a bulk result with error_message and error_kind"
+                    },
+                    "status": {
+                        "type": "integer",
+                        "description": "HTTP status code Jira returned"
+                    },
+                    "body": {
+                        "description": "Raw Jira error body, as JSON or text; used when error is not given"
+                    }
+                }
+            }),
+            ..Default::default()
+        }]
+    }
+
+    /// Get Jira Service Management tool definitions
+    fn get_assets_tool_definitions() -> Vec<MCPTool> {
+        vec![
+            MCPTool {
+                name: "get_jira_asset".to_string(),
+                description: "An Assets (Insight) object by key or id: its label, object type and attribute values. Only available with JIRA_ASSETS_ENABLED. Returns text and JSON".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "object_key": {"type": "string", "description": "Object key, e.g. HW-12, or object id"}
+                    },
+                    "required": ["object_key"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "search_jira_assets".to_string(),
+                description: "Search Assets (Insight) objects with IQL on Data Center or AQL on Cloud, e.g. objectType = Laptop AND Owner = jdoe, following result pages up to max_results. Returns text and JSON".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "iql": {"type": "string", "description": "IQL (Data Center) or AQL (Cloud) query"},
+                        "schema_id": {"type": "string", "description": "Object schema to search in (default: all schemas)"},
+                        "max_results": {"type": "integer", "description": "Most objects to return (default: 50)", "minimum": 0}
+                    },
+                    "required": ["iql"]
+                }),
+                ..Default::default()
+            },
+        ]
+    }
+
+    fn get_jsm_tool_definitions() -> Vec<MCPTool> {
+        vec![
+            MCPTool {
+                name: "get_jsm_service_desks".to_string(),
+                description: "The Jira Service Management service desks the user can see: id, project name and key. Only available with JIRA_JSM_ENABLED. Returns text and JSON".to_string(),
+                input_schema: json!({"type": "object", "properties": {}}),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_jsm_request_types".to_string(),
+                description: "The request types customers can raise on a service desk, with their ids for create_jsm_customer_request. Returns text and JSON".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "service_desk_id": {"type": "string", "description": "Id from get_jsm_service_desks"}
+                    },
+                    "required": ["service_desk_id"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "create_jsm_customer_request".to_string(),
+                description: "Raise a customer request on a service desk, as the customer portal would, and return its issue key".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "service_desk_id": {"type": "string", "description": "Id from get_jsm_service_desks"},
+                        "request_type_id": {"type": "string", "description": "Id from get_jsm_request_types"},
+                        "fields": {"type": "object", "description": "Request field values by field id, e.g. {\"summary\": \"VPN is down\", \"description\": \"Since 9am\"}"}
+                    },
+                    "required": ["service_desk_id", "request_type_id", "fields"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "get_jsm_request_sla".to_string(),
+                description: "The SLAs of a customer request: ongoing cycles with the time remaining or how long ago they breached, and the outcome of completed ones. Returns text and JSON".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {"type": "string", "description": "Key of the request, e.g. HELP-12"}
+                    },
+                    "required": ["issue_key"]
+                }),
+                ..Default::default()
+            },
+            MCPTool {
+                name: "add_jsm_customer_comment".to_string(),
+                description: "Comment on a customer request, either internally for agents or publicly, which shows it to the customer and notifies them".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "issue_key": {"type": "string", "description": "Key of the request, e.g. HELP-12"},
+                        "body": {"type": "string", "description": "Comment text"},
+                        "public": {"type": "boolean", "description": "Show the comment to the customer (default: false, internal)"}
+                    },
+                    "required": ["issue_key", "body"]
+                }),
+                ..Default::default()
             },
         ]
     }
 
+    /// Get webhook event tool definitions
+    fn get_event_tool_definitions() -> Vec<MCPTool> {
+        vec![MCPTool {
+            name: "get_recent_jira_events".to_string(),
+            description: "Issue and comment events Jira sent to the webhook receiver (JIRA_WEBHOOK_LISTEN_ADDR), newest first: sequence number, event type, issue key, project, summary, who caused it, when, changed fields and the start of the comment. Only the last JIRA_WEBHOOK_BUFFER_SIZE events are kept. Clients that declare the experimental capability jiraEvents also get each event as a notifications/jira/event notification after their next reply. Returns text and JSON".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "project_key": {
+                        "oneOf": [
+                            {"type": "string"},
+                            {"type": "array", "items": {"type": "string"}}
+                        ],
+                        "description": "Only events of this project or these projects"
+                    },
+                    "event_type": {
+                        "oneOf": [
+                            {"type": "string", "enum": ["issue_created", "issue_updated", "issue_deleted", "comment_created", "comment_updated", "comment_deleted", "other"]},
+                            {"type": "array", "items": {"type": "string", "enum": ["issue_created", "issue_updated", "issue_deleted", "comment_created", "comment_updated", "comment_deleted", "other"]}}
+                        ],
+                        "description": "Only events of this type or these types"
+                    },
+                    "issue_key": {"type": "string", "description": "Only events of this issue"},
+                    "after_sequence": {"type": "integer", "description": "Only events after this sequence number, to poll for new ones"},
+                    "limit": {"type": "integer", "description": "Most events to return (default 20)"}
+                }
+            }),
+            ..Default::default()
+        },
+        MCPTool {
+            name: "list_jira_webhooks".to_string(),
+            description: "The webhooks registered with Jira: on Cloud the dynamic webhooks registered by this server, with their expiration dates; on Data Center all webhooks of the site (needs administrator rights). Returns text and JSON".to_string(),
+            input_schema: json!({"type": "object", "properties": {}}),
+            ..Default::default()
+        },
+        MCPTool {
+            name: "register_jira_webhook".to_string(),
+            description: "Register a webhook that posts to this server's webhook receiver at JIRA_WEBHOOK_PUBLIC_URL, which must be set, so the events show up in get_recent_jira_events. Cloud webhooks expire after 30 days and are refreshed by the server while it runs; Data Center webhooks need administrator rights".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "events": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Jira webhook events, e.g. jira:issue_created, jira:issue_updated, jira:issue_deleted, comment_created, comment_updated (default: jira:issue_created, jira:issue_updated, comment_created)"
+                    },
+                    "jql_filter": {"type": "string", "description": "Only issues matching this JQL, e.g. project = PROJ; required on Cloud"}
+                }
+            }),
+            ..Default::default()
+        },
+        MCPTool {
+            name: "delete_jira_webhook".to_string(),
+            description: "Delete a webhook by the id list_jira_webhooks shows".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "webhook_id": {"type": "integer", "description": "Id of the webhook"}
+                },
+                "required": ["webhook_id"]
+            }),
+            ..Default::default()
+        }]
+    }
+
+    /// Annotations of a tool from its name: reads (`get_`, `list_`,
+    /// `search_`, reports) are read-only, and writes that delete, remove or
+    /// overwrite data are destructive while purely additive ones are not
+    #[must_use]
+    pub fn tool_annotations(name: &str) -> ToolAnnotations {
+        const READ_ONLY_PREFIXES: [&str; 3] = ["get_", "list_", "search_"];
+        const READ_ONLY_TOOLS: [&str; 10] = [
+            "explain_jira_error",
+            "preview_issue_update",
+            "set_jira_context",
+            "sprint_report",
+            "status_timeline",
+            "summarize_epic",
+            "test_jira_auth",
+            "validate_configuration",
+            "zephyr_cycle_report",
+            "zephyr_traceability_report",
+        ];
+        const DESTRUCTIVE_PREFIXES: [&str; 4] = ["delete_", "remove_", "update_", "bulk_update_"];
+        const DESTRUCTIVE_TOOLS: [&str; 5] = [
+            "bulk_operations_from_csv",
+            "mixed_bulk_operations",
+            "jira_issue_action",
+            "close_sprint",
+            "undo_bulk_operation",
+        ];
+
+        let read_only = READ_ONLY_PREFIXES.iter().any(|p| name.starts_with(p))
+            || READ_ONLY_TOOLS.contains(&name);
+        let destructive = DESTRUCTIVE_PREFIXES.iter().any(|p| name.starts_with(p))
+            || DESTRUCTIVE_TOOLS.contains(&name);
+        ToolAnnotations {
+            read_only_hint: Some(read_only),
+            destructive_hint: (!read_only).then_some(destructive),
+        }
+    }
+
     #[must_use]
     pub fn list_tools() -> Vec<MCPTool> {
+        let groups = [
+            (ToolCategory::Basic, Self::get_basic_tool_definitions()),
+            (ToolCategory::Project, Self::get_project_tool_definitions()),
+            (ToolCategory::Bulk, Self::get_bulk_tool_definitions()),
+            (ToolCategory::Sprints, Self::get_sprint_tool_definitions()),
+            (ToolCategory::Reports, Self::get_report_tool_definitions()),
+            (ToolCategory::Zephyr, Self::get_zephyr_tool_definitions()),
+            (ToolCategory::Linking, Self::get_linking_tool_definitions()),
+            (
+                ToolCategory::Attachments,
+                Self::get_attachment_tool_definitions(),
+            ),
+            (ToolCategory::Worklogs, Self::get_worklog_tool_definitions()),
+            (ToolCategory::Watchers, Self::get_watcher_tool_definitions()),
+            (ToolCategory::Labels, Self::get_label_tool_definitions()),
+            (
+                ToolCategory::Components,
+                Self::get_component_tool_definitions(),
+            ),
+            (ToolCategory::Cloning, Self::get_cloning_tool_definitions()),
+            (ToolCategory::Metrics, Self::get_metrics_tool_definitions()),
+            (ToolCategory::Events, Self::get_event_tool_definitions()),
+            (
+                ToolCategory::ServiceManagement,
+                Self::get_jsm_tool_definitions(),
+            ),
+            (ToolCategory::Assets, Self::get_assets_tool_definitions()),
+        ];
         let mut tools = Vec::new();
-        tools.extend(Self::get_basic_tool_definitions());
-        tools.extend(Self::get_project_tool_definitions());
-        tools.extend(Self::get_bulk_tool_definitions());
-        tools.extend(Self::get_sprint_tool_definitions());
-        tools.extend(Self::get_zephyr_tool_definitions());
-        tools.extend(Self::get_linking_tool_definitions());
-        tools.extend(Self::get_attachment_tool_definitions());
-        tools.extend(Self::get_worklog_tool_definitions());
-        tools.extend(Self::get_watcher_tool_definitions());
-        tools.extend(Self::get_label_tool_definitions());
-        tools.extend(Self::get_component_tool_definitions());
-        tools.extend(Self::get_cloning_tool_definitions());
+        for (category, definitions) in groups {
+            tools.extend(definitions.into_iter().map(|tool| MCPTool {
+                annotations: Some(Self::tool_annotations(&tool.name)),
+                meta: Some(ToolMeta { category }),
+                ..tool
+            }));
+        }
+
+        // Handled by call_tool for every tool
+        for tool in &mut tools {
+            if let Some(properties) = tool
+                .input_schema
+                .get_mut("properties")
+                .and_then(|p| p.as_object_mut())
+            {
+                properties.insert(
+                    "debug_capture".to_string(),
+                    json!({
+                        "type": "boolean",
+                        "description": "Record the HTTP requests and responses of this call (credentials redacted), append them to the output and write them to the debug capture directory",
+                        "default": false
+                    }),
+                );
+                if VERBOSITY_TOOLS.contains(&tool.name.as_str()) {
+                    properties.insert(
+                        "verbosity".to_string(),
+                        json!({
+                            "type": "string",
+                            "enum": ["minimal", "normal", "detailed"],
+                            "description": "How much to show: minimal gives one 'KEY — summary' line per item without descriptions, detailed adds the raw JSON (default: JIRA_OUTPUT_VERBOSITY, normal)"
+                        }),
+                    );
+                }
+            }
+        }
         tools
     }
 
@@ -2057,13 +4659,89 @@ impl MCPServer {
     ///
     /// Returns an error if the tool is not found or if the tool execution fails.
     pub async fn call_tool(&self, tool_call: MCPToolCall) -> Result<MCPToolResult> {
-        if let Some(handler) = self.tools.get(&tool_call.name) {
-            handler.handle(tool_call.arguments).await
-        } else {
-            Err(crate::error::JiraError::unknown_error(&format!(
+        let Some(handler) = self.tools.get(&tool_call.name) else {
+            return Err(crate::error::JiraError::unknown_error(&format!(
                 "Unknown tool: {}",
                 tool_call.name
-            )))
+            )));
+        };
+
+        // Arguments left out fall back to the session context
+        let mut arguments = tool_call.arguments;
+        let context = read_context(&self.session_context);
+        let notes = match TOOL_SCHEMAS.get(&tool_call.name) {
+            Some(schema) if !context.is_empty() && !tool_call.name.ends_with("_jira_context") => {
+                context.fill_arguments(&mut arguments, schema)
+            }
+            _ => Vec::new(),
+        };
+
+        // Writes are checked against the write policies before they run
+        if Self::tool_annotations(&tool_call.name).read_only_hint != Some(true) {
+            if let Some(policies) = &self.config.policies {
+                check_write(policies, &tool_call.name, &arguments)?;
+            }
+        }
+
+        let debug_capture = arguments
+            .as_object_mut()
+            .and_then(|args| args.remove("debug_capture"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if !debug_capture {
+            return handler
+                .handle(arguments)
+                .await
+                .map(|result| with_notes(result, &notes));
         }
+
+        let (result, exchanges) = capture::capture(handler.handle(arguments)).await;
+        // A failed call is still returned as a result, since its capture is
+        // what the caller asked for
+        let mut result = result.unwrap_or_else(|e| MCPToolResult {
+            content: vec![MCPContent::text(e.to_string())],
+            is_error: Some(true),
+        });
+
+        let location = match capture::write_capture(
+            &self.config.debug_capture_directory(),
+            &tool_call.name,
+            &exchanges,
+        )
+        .await
+        {
+            Ok(path) => format!("written to {}", path.display()),
+            Err(e) => {
+                warn!("Failed to write debug capture: {}", e);
+                format!("could not be written to a file: {e}")
+            }
+        };
+        result.content.push(MCPContent::text(format!(
+            "Debug capture of {} HTTP exchanges ({location}):\n```json\n{}\n```",
+            exchanges.len(),
+            capture::to_json(&exchanges)
+        )));
+        Ok(with_notes(result, &notes))
+    }
+}
+
+/// Add `note` as a last line of the first content item of `result`
+fn append_note(result: &mut MCPToolResult, note: &str) {
+    match result.content.first_mut() {
+        Some(first) => first.text = format!("{}\n{note}", first.text),
+        None => result.content.push(MCPContent::text(note.to_string())),
+    }
+}
+
+/// `result` with `notes` as lines before its first content item
+fn with_notes(mut result: MCPToolResult, notes: &[String]) -> MCPToolResult {
+    if notes.is_empty() {
+        return result;
+    }
+    let notes = notes.join("\n");
+    match result.content.first_mut() {
+        Some(first) => first.text = format!("{notes}\n{}", first.text),
+        None => result.content.push(MCPContent::text(notes)),
     }
+    result
 }
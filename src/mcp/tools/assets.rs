@@ -0,0 +1,126 @@
+use crate::config::JiraConfig;
+use crate::error::Result;
+use crate::jira::assets::AssetObject;
+use crate::jira::client::JiraClient;
+use crate::mcp::tools::pagination::PageArgs;
+use crate::mcp::tools::required_str;
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use tracing::info;
+
+/// Objects `search_jira_assets` returns when the call has no `max_results`
+const DEFAULT_ASSET_RESULTS: usize = 50;
+
+/// One line per object: `KEY — label (type)`
+fn object_line(object: &AssetObject) -> String {
+    match &object.object_type {
+        Some(object_type) => format!("• {} — {} ({object_type})", object.object_key, object.label),
+        None => format!("• {} — {}", object.object_key, object.label),
+    }
+}
+
+/// Get an Assets (Insight) object by key or id
+pub struct GetAssetObjectTool {
+    client: JiraClient,
+}
+
+impl GetAssetObjectTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetAssetObjectTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let object_key = required_str(&args, "object_key")?;
+
+        info!("Getting Assets object {}", object_key);
+
+        let object = self.client.get_asset_object(object_key).await?;
+        let mut text = format!("Assets object {}: {}", object.object_key, object.label);
+        if let Some(object_type) = &object.object_type {
+            text.push_str(&format!("\nType: {object_type}"));
+        }
+        text.push_str(&format!("\nId: {}", object.id));
+        if !object.attributes.is_empty() {
+            text.push_str("\nAttributes:");
+            for attribute in &object.attributes {
+                text.push_str(&format!(
+                    "\n• {}: {}",
+                    attribute.name,
+                    attribute.values.join(", ")
+                ));
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&object)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Search Assets (Insight) objects with IQL or AQL
+pub struct SearchAssetObjectsTool {
+    client: JiraClient,
+}
+
+impl SearchAssetObjectsTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for SearchAssetObjectsTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let iql = required_str(&args, "iql")?;
+        let schema_id = args.get("schema_id").and_then(|v| match v {
+            serde_json::Value::String(s) => Some(s.clone()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        });
+        let limit = PageArgs::from_args(&args, self.client.config())?
+            .max_results
+            .map_or(DEFAULT_ASSET_RESULTS, |max| max as usize);
+
+        info!("Searching Assets objects: {}", iql);
+
+        let search = self
+            .client
+            .search_asset_objects(iql, schema_id.as_deref(), limit)
+            .await?;
+        let mut text = match search.total {
+            Some(total) => format!(
+                "Found {total} Assets objects for {iql}, showing {}:",
+                search.objects.len()
+            ),
+            None => format!("Found {} Assets objects for {iql}:", search.objects.len()),
+        };
+        for object in &search.objects {
+            text.push_str(&format!("\n{}", object_line(object)));
+        }
+        if search.truncated {
+            text.push_str(&format!(
+                "\nMore objects match; raise max_results (now {limit}) or narrow the query"
+            ));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&search)],
+            is_error: Some(false),
+        })
+    }
+}
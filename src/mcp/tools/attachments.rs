@@ -1,10 +1,81 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
+use crate::mcp::tools::formatting::ResponseFormatter;
+use crate::types::jira::JiraAttachment;
 use crate::types::mcp::{MCPContent, MCPToolResult};
+use crate::utils::datetime::DateFormatter;
+use crate::utils::paths::{
+    glob_matches, list_files, resolve_allowed_dir, resolve_allowed_file, sanitize_file_name,
+    unique_file_name,
+};
 use base64::Engine;
+use futures::StreamExt;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use tracing::info;
 
+/// Download limit used when `max_attachment_download_bytes` is not configured
+pub const DEFAULT_MAX_ATTACHMENT_DOWNLOAD_BYTES: u64 = 10 * 1024 * 1024;
+/// Attachments `download_all_issue_attachments` downloads at the same time
+pub const ATTACHMENT_DOWNLOAD_CONCURRENCY: usize = 3;
+/// Largest file `upload_directory_attachments` uploads when the call does
+/// not give `max_file_size`
+pub const DEFAULT_MAX_UPLOAD_FILE_BYTES: u64 = 10 * 1024 * 1024;
+/// Files `upload_directory_attachments` sends per upload request
+pub const UPLOAD_BATCH_FILES: usize = 10;
+/// Files `upload_directory_attachments` never uploads, as globs
+pub const UPLOAD_EXCLUDED_FILES: &[&str] = &[".DS_Store", "Thumbs.db", "desktop.ini", "._*"];
+/// Largest thumbnail `get_jira_attachment_info` returns inline as base64
+pub const THUMBNAIL_INLINE_LIMIT_BYTES: usize = 64 * 1024;
+
+/// The name and bytes of the file an upload tool was given: either
+/// `file_path`, a file inside the allowed directories, or base64 `content`
+/// with a `filename`. A `filename` given with `file_path` renames the upload.
+///
+/// # Errors
+///
+/// Returns an error if neither form is complete, the path is not allowed or
+/// cannot be read, or the content is not valid base64.
+pub(crate) async fn read_upload_file(
+    client: &JiraClient,
+    args: &serde_json::Value,
+) -> Result<(String, Vec<u8>)> {
+    let filename = args.get("filename").and_then(|v| v.as_str());
+
+    if let Some(file_path) = args.get("file_path").and_then(|v| v.as_str()) {
+        let allowed_dirs = client.config().allowed_directories();
+        let path = resolve_allowed_file("file_path", file_path, &allowed_dirs)?;
+        let content = tokio::fs::read(&path).await?;
+        let filename = filename.map_or_else(
+            || {
+                path.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default()
+            },
+            ToString::to_string,
+        );
+        return Ok((filename, content));
+    }
+
+    let filename = filename.ok_or_else(|| {
+        crate::error::JiraError::api_error("Missing required parameter: filename")
+    })?;
+
+    let content_base64 = args
+        .get("content")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| crate::error::JiraError::api_error("Missing required parameter: content"))?;
+
+    // Decode base64 content
+    let content = base64::engine::general_purpose::STANDARD
+        .decode(content_base64)
+        .map_err(|_| crate::error::JiraError::api_error("Invalid base64 content"))?;
+
+    Ok((filename.to_string(), content))
+}
+
 /// Get attachments for a Jira issue
 pub struct GetIssueAttachmentsTool {
     client: JiraClient,
@@ -32,6 +103,8 @@ impl crate::mcp::server::MCPToolHandler for GetIssueAttachmentsTool {
                 crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
 
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
+
         info!("Getting attachments for issue: {}", issue_key);
 
         let attachments = self.client.get_issue_attachments(issue_key).await?;
@@ -42,13 +115,20 @@ impl crate::mcp::server::MCPToolHandler for GetIssueAttachmentsTool {
             issue_key
         ))];
 
-        for attachment in attachments {
+        for attachment in &attachments {
+            if output.is_minimal() {
+                content[0]
+                    .text
+                    .push_str(&output.item_line(&attachment.id, &attachment.filename));
+                continue;
+            }
             let attachment_text = format!(
                 "• {} ({}) - {} bytes\n",
                 attachment.filename, &attachment.mime_type, attachment.size
             );
             content.push(MCPContent::text(attachment_text));
         }
+        output.push_raw(&mut content, &attachments);
 
         Ok(MCPToolResult {
             content,
@@ -84,32 +164,19 @@ impl crate::mcp::server::MCPToolHandler for UploadAttachmentTool {
                 crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
 
-        let filename = args
-            .get("filename")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: filename")
-            })?;
-
-        let content_base64 = args
-            .get("content")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: content")
-            })?;
+        let (filename, content) = read_upload_file(&self.client, &args).await?;
+        // Fail before sending a file Jira would only reject at the end
+        self.client
+            .check_attachment_size(&filename, u64::try_from(content.len()).unwrap_or(u64::MAX))
+            .await?;
 
         info!("Uploading attachment {} to issue: {}", filename, issue_key);
 
-        // Decode base64 content
-        let content = base64::engine::general_purpose::STANDARD
-            .decode(content_base64)
-            .map_err(|_| crate::error::JiraError::api_error("Invalid base64 content"))?;
-
         let mime_type = args.get("mime_type").and_then(|v| v.as_str());
 
         let uploaded_attachments = self
             .client
-            .upload_attachment(issue_key, filename, &content, mime_type)
+            .upload_attachment(issue_key, &filename, &content, mime_type)
             .await?;
 
         let response_text = format!(
@@ -191,10 +258,32 @@ impl crate::mcp::server::MCPToolHandler for DownloadAttachmentTool {
             .ok_or_else(|| {
                 crate::error::JiraError::api_error("Missing required parameter: attachment_id")
             })?;
+        let force = args
+            .get("force")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
 
         info!("Downloading attachment: {}", attachment_id);
 
-        let content = self.client.download_attachment(attachment_id).await?;
+        let attachment = self.client.get_attachment_metadata(attachment_id).await?;
+        let limit = self
+            .client
+            .config()
+            .max_attachment_download_bytes
+            .unwrap_or(DEFAULT_MAX_ATTACHMENT_DOWNLOAD_BYTES);
+        let size = u64::try_from(attachment.size).unwrap_or(0);
+        if size > limit && !force {
+            return Err(crate::error::JiraError::validation_error(
+                "attachment_id",
+                &format!(
+                    "Attachment {} ({}) is {size} bytes, more than the download limit of {limit} bytes \
+                     (JIRA_MAX_ATTACHMENT_DOWNLOAD_BYTES). Pass force: true to download it anyway",
+                    attachment.id, attachment.filename
+                ),
+            ));
+        }
+
+        let content = self.client.download_attachment_content(&attachment).await?;
 
         // Encode content as base64 for transmission
         let content_base64 = base64::engine::general_purpose::STANDARD.encode(&content);
@@ -212,3 +301,465 @@ impl crate::mcp::server::MCPToolHandler for DownloadAttachmentTool {
         })
     }
 }
+
+/// Attachment metadata, to inspect an attachment before downloading it
+pub struct GetAttachmentInfoTool {
+    client: JiraClient,
+}
+
+impl GetAttachmentInfoTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetAttachmentInfoTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let attachment_id = args
+            .get("attachment_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: attachment_id")
+            })?;
+        let include_thumbnail = args
+            .get("include_thumbnail")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        info!("Getting attachment metadata: {}", attachment_id);
+
+        let attachment = self.client.get_attachment_metadata(attachment_id).await?;
+
+        let mut response_text = format!(
+            "Attachment {}: {}\nSize: {} bytes\nMIME type: {}\nAuthor: {}\nCreated: {}\nThumbnail: {}\n",
+            attachment.id,
+            attachment.filename,
+            attachment.size,
+            attachment.mime_type,
+            attachment.author.label(),
+            DateFormatter::from_config(self.client.config()).format_timestamp(&attachment.created),
+            if attachment.thumbnail.is_some() {
+                "available"
+            } else {
+                "none"
+            }
+        );
+
+        if include_thumbnail && attachment.thumbnail.is_some() {
+            match self.client.get_attachment_thumbnail(attachment_id).await? {
+                Some(thumbnail) if thumbnail.len() <= THUMBNAIL_INLINE_LIMIT_BYTES => {
+                    response_text.push_str(&format!(
+                        "Thumbnail base64 ({} bytes): {}\n",
+                        thumbnail.len(),
+                        base64::engine::general_purpose::STANDARD.encode(&thumbnail)
+                    ));
+                }
+                Some(thumbnail) => {
+                    response_text.push_str(&format!(
+                        "Thumbnail not included: {} bytes is more than the inline limit of {THUMBNAIL_INLINE_LIMIT_BYTES}\n",
+                        thumbnail.len()
+                    ));
+                }
+                None => {}
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// One attachment in the manifest returned by `download_all_issue_attachments`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AttachmentManifestEntry {
+    pub attachment_id: String,
+    /// Name of the attachment in Jira
+    pub filename: String,
+    /// Where the attachment was saved; `None` when it was not
+    pub local_path: Option<String>,
+    pub size: Option<u64>,
+    /// SHA-256 of the saved content as lowercase hex
+    pub sha256: Option<String>,
+    /// Why the attachment was not saved
+    pub error: Option<String>,
+}
+
+/// Download every attachment of an issue into a directory
+pub struct DownloadAllIssueAttachmentsTool {
+    client: JiraClient,
+}
+
+impl DownloadAllIssueAttachmentsTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+
+    /// Save one attachment as `dir/file_name`, recording the outcome instead
+    /// of failing so the other downloads carry on
+    async fn download(
+        &self,
+        attachment: JiraAttachment,
+        path: PathBuf,
+        limit: Option<u64>,
+    ) -> AttachmentManifestEntry {
+        let mut entry = AttachmentManifestEntry {
+            attachment_id: attachment.id.clone(),
+            filename: attachment.filename.clone(),
+            local_path: None,
+            size: None,
+            sha256: None,
+            error: None,
+        };
+        let size = u64::try_from(attachment.size).unwrap_or(0);
+        if let Some(limit) = limit.filter(|limit| size > *limit) {
+            entry.error = Some(format!(
+                "{size} bytes is more than the download limit of {limit} bytes \
+                 (JIRA_MAX_ATTACHMENT_DOWNLOAD_BYTES). Pass force: true to download it anyway"
+            ));
+            return entry;
+        }
+
+        match self
+            .client
+            .download_attachment_to_file(&attachment, &path, limit)
+            .await
+        {
+            Ok((size, sha256)) => {
+                entry.local_path = Some(path.display().to_string());
+                entry.size = Some(size);
+                entry.sha256 = Some(sha256);
+            }
+            Err(error) => entry.error = Some(error.to_string()),
+        }
+        entry
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for DownloadAllIssueAttachmentsTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = args
+            .get("issue_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: issue_key")
+            })?;
+        let target_dir = args
+            .get("target_dir")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: target_dir")
+            })?;
+        let force = args
+            .get("force")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        // Check the destination before fetching anything
+        let dir = resolve_allowed_dir(
+            "target_dir",
+            target_dir,
+            &self.client.config().allowed_directories(),
+        )?;
+        let limit = (!force).then(|| {
+            self.client
+                .config()
+                .max_attachment_download_bytes
+                .unwrap_or(DEFAULT_MAX_ATTACHMENT_DOWNLOAD_BYTES)
+        });
+
+        info!(
+            "Downloading all attachments of issue {} to {}",
+            issue_key,
+            dir.display()
+        );
+
+        let attachments = self.client.get_issue_attachments(issue_key).await?;
+
+        // Names are chosen up front so they don't depend on which download
+        // finishes first
+        let mut taken = HashSet::new();
+        let downloads: Vec<(JiraAttachment, PathBuf)> = attachments
+            .into_iter()
+            .map(|attachment| {
+                let name =
+                    unique_file_name(&dir, &sanitize_file_name(&attachment.filename), &mut taken);
+                (attachment, dir.join(name))
+            })
+            .collect();
+        let manifest: Vec<AttachmentManifestEntry> = futures::stream::iter(downloads)
+            .map(|(attachment, path)| self.download(attachment, path, limit))
+            .buffered(ATTACHMENT_DOWNLOAD_CONCURRENCY)
+            .collect()
+            .await;
+
+        let saved = manifest
+            .iter()
+            .filter(|entry| entry.error.is_none())
+            .count();
+        let mut response_text = format!(
+            "Downloaded {saved} of {} attachments of {issue_key} to {}",
+            manifest.len(),
+            dir.display()
+        );
+        for entry in &manifest {
+            match (&entry.local_path, &entry.error) {
+                (Some(path), None) => response_text.push_str(&format!(
+                    "\n• {} ({} bytes, sha256 {}) -> {path}",
+                    entry.filename,
+                    entry.size.unwrap_or(0),
+                    entry.sha256.as_deref().unwrap_or("")
+                )),
+                (_, error) => response_text.push_str(&format!(
+                    "\n• {} failed: {}",
+                    entry.filename,
+                    error.as_deref().unwrap_or("unknown error")
+                )),
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text), MCPContent::json(&manifest)],
+            is_error: Some(saved == 0 && !manifest.is_empty()),
+        })
+    }
+}
+
+/// What happened to a file given to `upload_directory_attachments`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UploadOutcome {
+    Uploaded,
+    Skipped,
+    Failed,
+}
+
+/// One file in the report of `upload_directory_attachments`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UploadManifestEntry {
+    /// Path relative to the uploaded directory
+    pub path: String,
+    pub size: Option<u64>,
+    pub outcome: UploadOutcome,
+    /// Id of the attachment created for the file
+    pub attachment_id: Option<String>,
+    /// Why the file was skipped or failed
+    pub message: Option<String>,
+}
+
+/// Upload the files of a directory as attachments of an issue
+pub struct UploadDirectoryAttachmentsTool {
+    client: JiraClient,
+}
+
+impl UploadDirectoryAttachmentsTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+
+    /// Upload one batch of `(entry index, path)` files, recording the
+    /// outcome of each in `manifest`
+    async fn upload_batch(
+        &self,
+        issue_key: &str,
+        batch: &[(usize, PathBuf)],
+        manifest: &mut [UploadManifestEntry],
+    ) {
+        let mut files = Vec::new();
+        let mut sent = Vec::new();
+        for (index, path) in batch {
+            match tokio::fs::read(path).await {
+                Ok(content) => {
+                    let filename = path.file_name().unwrap_or_default().to_string_lossy();
+                    files.push((filename.into_owned(), content));
+                    sent.push(*index);
+                }
+                Err(error) => {
+                    manifest[*index].outcome = UploadOutcome::Failed;
+                    manifest[*index].message = Some(format!("Could not read the file: {error}"));
+                }
+            }
+        }
+        if files.is_empty() {
+            return;
+        }
+
+        match self.client.upload_attachments(issue_key, files).await {
+            Ok(attachments) => {
+                for (position, index) in sent.into_iter().enumerate() {
+                    manifest[index].outcome = UploadOutcome::Uploaded;
+                    manifest[index].attachment_id = attachments
+                        .get(position)
+                        .map(|attachment| attachment.id.clone());
+                }
+            }
+            Err(error) => {
+                for index in sent {
+                    manifest[index].outcome = UploadOutcome::Failed;
+                    manifest[index].message = Some(error.to_string());
+                }
+            }
+        }
+    }
+}
+
+/// `path` relative to `dir`, for reports
+fn relative_display(dir: &Path, path: &Path) -> String {
+    path.strip_prefix(dir).unwrap_or(path).display().to_string()
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for UploadDirectoryAttachmentsTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = args
+            .get("issue_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: issue_key")
+            })?;
+        let directory = args
+            .get("directory")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: directory")
+            })?;
+        let pattern = args.get("pattern").and_then(|v| v.as_str());
+        let recursive = args
+            .get("recursive")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let max_file_size = args
+            .get("max_file_size")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(DEFAULT_MAX_UPLOAD_FILE_BYTES);
+        let exclude: Vec<&str> = args
+            .get("exclude")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_str())
+            .chain(UPLOAD_EXCLUDED_FILES.iter().copied())
+            .collect();
+
+        let allowed_dirs = self.client.config().allowed_directories();
+        let dir = resolve_allowed_dir("directory", directory, &allowed_dirs)?;
+        let listing = list_files(&dir, pattern, recursive, &allowed_dirs)?;
+        let upload_limit = self.client.attachment_upload_limit().await?;
+
+        info!(
+            "Uploading {} files from {} to issue {}",
+            listing.files.len(),
+            dir.display(),
+            issue_key
+        );
+
+        let mut manifest = Vec::new();
+        let mut pending = Vec::new();
+        for path in listing.files {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            let size = tokio::fs::metadata(&path).await.ok().map(|meta| meta.len());
+            let mut entry = UploadManifestEntry {
+                path: relative_display(&dir, &path),
+                size,
+                outcome: UploadOutcome::Skipped,
+                attachment_id: None,
+                message: None,
+            };
+            if let Some(excluded) = exclude.iter().find(|pattern| glob_matches(pattern, &name)) {
+                entry.message = Some(format!("excluded by '{excluded}'"));
+            } else if let Some(size) = size.filter(|size| *size > max_file_size) {
+                entry.message = Some(format!(
+                    "{size} bytes is more than max_file_size ({max_file_size} bytes)"
+                ));
+            } else if let Some((size, limit)) =
+                size.zip(upload_limit).filter(|(size, limit)| size > limit)
+            {
+                entry.message = Some(format!(
+                    "{size} bytes is more than the attachment size limit of this Jira instance ({limit} bytes)"
+                ));
+            } else {
+                pending.push((manifest.len(), path.clone()));
+            }
+            manifest.push(entry);
+        }
+        for (path, reason) in listing.skipped {
+            manifest.push(UploadManifestEntry {
+                path: relative_display(&dir, &path),
+                size: None,
+                outcome: UploadOutcome::Skipped,
+                attachment_id: None,
+                message: Some(reason),
+            });
+        }
+
+        for batch in pending.chunks(UPLOAD_BATCH_FILES) {
+            self.upload_batch(issue_key, batch, &mut manifest).await;
+        }
+
+        let uploaded: Vec<&UploadManifestEntry> = manifest
+            .iter()
+            .filter(|entry| entry.outcome == UploadOutcome::Uploaded)
+            .collect();
+        let total_bytes: u64 = uploaded.iter().filter_map(|entry| entry.size).sum();
+        let failed = manifest
+            .iter()
+            .any(|entry| entry.outcome == UploadOutcome::Failed);
+
+        let mut response_text = format!(
+            "Uploaded {} of {} files ({total_bytes} bytes) from {} to {issue_key}",
+            uploaded.len(),
+            manifest.len(),
+            dir.display()
+        );
+        for entry in &manifest {
+            let detail = match entry.outcome {
+                UploadOutcome::Uploaded => format!(
+                    "uploaded ({} bytes, attachment {})",
+                    entry.size.unwrap_or(0),
+                    entry.attachment_id.as_deref().unwrap_or("?")
+                ),
+                UploadOutcome::Skipped => {
+                    format!("skipped ({})", entry.message.as_deref().unwrap_or(""))
+                }
+                UploadOutcome::Failed => format!(
+                    "failed ({})",
+                    entry.message.as_deref().unwrap_or("unknown error")
+                ),
+            };
+            response_text.push_str(&format!("\n• {}: {detail}", entry.path));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![
+                MCPContent::text(response_text),
+                MCPContent::json(&serde_json::json!({
+                    "uploaded": uploaded.len(),
+                    "total_bytes": total_bytes,
+                    "files": manifest,
+                })),
+            ],
+            is_error: Some(uploaded.is_empty() && failed),
+        })
+    }
+}
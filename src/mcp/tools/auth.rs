@@ -1,5 +1,8 @@
-use crate::config::JiraConfig;
+use crate::config::{ConfigManager, JiraConfig};
 use crate::error::Result;
+use crate::jira::client::JiraClient;
+use crate::jira::doctor::run_config_checks;
+use crate::jira::warmup::last_warmup;
 use crate::types::mcp::{MCPContent, MCPToolResult};
 use serde_json::json;
 use tracing::info;
@@ -32,10 +35,109 @@ impl crate::mcp::server::MCPToolHandler for TestAuthTool {
             "api_base_url": self.config.api_base_url
         });
 
+        let mut content = vec![MCPContent::text(
+            "Authentication test successful".to_string(),
+        )];
+        if let Some(warmup) = last_warmup(&self.config) {
+            content.push(MCPContent::text(warmup.summary()));
+        }
+
+        Ok(MCPToolResult {
+            content,
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Version and deployment of the Jira instance, with its attachment limits
+pub struct GetServerInfoTool {
+    client: JiraClient,
+}
+
+impl GetServerInfoTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetServerInfoTool {
+    async fn handle(&self, _args: serde_json::Value) -> Result<MCPToolResult> {
+        info!("Getting Jira server info");
+
+        let mut server_info = self.client.get_server_info().await?;
+        let text = |key: &str| {
+            server_info
+                .get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown")
+                .to_string()
+        };
+        let mut response_text = format!(
+            "Jira {} (build {}, {})\nBase URL: {}\nServer time: {}",
+            text("version"),
+            server_info
+                .get("buildNumber")
+                .map_or_else(|| "unknown".to_string(), ToString::to_string),
+            text("deploymentType"),
+            text("baseUrl"),
+            text("serverTime")
+        );
+        match self.client.get_attachment_meta().await {
+            Ok(meta) => {
+                response_text.push_str(&match (meta.enabled, meta.upload_limit) {
+                    (false, _) => "\nAttachments: disabled".to_string(),
+                    (true, Some(limit)) => format!("\nAttachments: up to {limit} bytes per file"),
+                    (true, None) => "\nAttachments: enabled, size limit not reported".to_string(),
+                });
+                server_info["attachments"] = json!(meta);
+            }
+            Err(e) => {
+                response_text.push_str(&format!("\nAttachments: limit could not be read ({e})"));
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![
+                MCPContent::text(response_text),
+                MCPContent::json(&server_info),
+            ],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Diagnose the configuration: URL shape, reachability, authentication, API
+/// version, Zephyr and the permissions on the default project
+pub struct ValidateConfigurationTool {
+    config: JiraConfig,
+}
+
+impl ValidateConfigurationTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ValidateConfigurationTool {
+    async fn handle(&self, _args: serde_json::Value) -> Result<MCPToolResult> {
+        info!("Validating the configuration");
+
+        let sources = ConfigManager::new().get_config_sources();
+        let report = run_config_checks(&self.config, &sources).await;
+
         Ok(MCPToolResult {
-            content: vec![MCPContent::text(
-                "Authentication test successful".to_string(),
-            )],
+            content: vec![MCPContent::text(report.render()), MCPContent::json(&report)],
             is_error: Some(false),
         })
     }
@@ -1,9 +1,387 @@
+use crate::config::bulk::{resolve_bulk_config, EffectiveBulkConfig};
 use crate::config::JiraConfig;
-use crate::error::Result;
-use crate::jira::client::JiraClient;
+use crate::error::{JiraError, Result};
+use crate::jira::bulk_csv::{
+    check_against_editmeta, coerce_to_create_meta, create_fields, parse_bulk_csv, read_mapped_rows,
+    CsvOperation, CsvRowError,
+};
+use crate::jira::bulk_history::{
+    bulk_history, bulk_operation, operation_type_name, record_bulk_operation, still_applied,
+    BulkHistoryEntry, BulkHistoryFilter, MAX_BULK_HISTORY,
+};
+use crate::jira::bulk_validation::validate_bulk_operations;
+use crate::jira::client::{FetchedIssues, JiraClient};
+use crate::jira::issue_update::{item_operations, parse_update};
+use crate::mcp::tools::events::string_list;
+use crate::mcp::tools::formatting::ResponseFormatter;
+use crate::mcp::tools::issues::{issue_details, read_issue, return_fields};
+use crate::mcp::tools::projects::ProjectArg;
+use crate::types::jira::{
+    BulkOperationConfig, BulkOperationItem, BulkOperationResult, BulkOperationSummary,
+    BulkOperationType, JiraCommentVisibility, JiraCreateMeta,
+};
 use crate::types::mcp::{MCPContent, MCPToolResult};
+use crate::utils::datetime::DateFormatter;
+use crate::utils::paths::resolve_allowed_file;
+use crate::utils::time::parse_date_or_timestamp;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
 use tracing::info;
 
+/// Most operations `execute_bulk_operations` accepts in one call
+const BULK_CHUNK_SIZE: usize = 100;
+
+/// Rows checked against the edit metadata when `validate_rows` isn't given
+const DEFAULT_CSV_VALIDATE_ROWS: usize = 5;
+
+/// Rows whose fields a dry run of `bulk_create_issues_from_csv` shows
+const CSV_DRY_RUN_PREVIEW_ROWS: usize = 5;
+
+/// Most issues `return_issues` reads back after a bulk update
+const MAX_RETURNED_ISSUES: usize = 20;
+
+/// Most keys of a selection listed by name
+const SELECTION_SAMPLE: usize = 20;
+
+/// The issues a bulk operation runs on: its `issue_keys` argument, or the
+/// issues its `jql` argument matches
+struct IssueSelection {
+    keys: Vec<String>,
+    /// The query the keys were read from, with how its pages were read
+    jql: Option<(String, FetchedIssues)>,
+}
+
+impl IssueSelection {
+    /// Read `issue_keys`, or search every page of `jql`; a query must come
+    /// with `max_issues`, and one matching more issues selects nothing
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if both or neither are given, `max_issues`
+    /// is missing or the query matches too many issues, or an error if the
+    /// search fails
+    async fn from_args(client: &JiraClient, args: &serde_json::Value) -> Result<Self> {
+        let jql = args.get("jql").and_then(|v| v.as_str());
+        match (args.get("issue_keys"), jql) {
+            (Some(_), Some(_)) => Err(JiraError::validation_error(
+                "jql",
+                "Give either jql or issue_keys, not both",
+            )),
+            (Some(issue_keys), None) => {
+                let issue_keys = issue_keys.as_array().ok_or_else(|| {
+                    JiraError::validation_error("issue_keys", "must be an array of issue keys")
+                })?;
+                Ok(Self {
+                    keys: issue_keys
+                        .iter()
+                        .filter_map(|v| v.as_str().map(ToString::to_string))
+                        .collect(),
+                    jql: None,
+                })
+            }
+            (None, Some(jql)) => {
+                let max_issues = args
+                    .get("max_issues")
+                    .and_then(serde_json::Value::as_u64)
+                    .and_then(|max| usize::try_from(max).ok())
+                    .filter(|max| *max > 0)
+                    .ok_or_else(|| {
+                        JiraError::validation_error(
+                            "max_issues",
+                            "is required with jql: the most issues the query may select, at least 1",
+                        )
+                    })?;
+                let fetched = client
+                    .search_issues_bounded(jql, &["key".to_string()], max_issues)
+                    .await?;
+                Ok(Self {
+                    keys: fetched
+                        .issues
+                        .iter()
+                        .filter_map(|issue| issue.get("key").and_then(|v| v.as_str()))
+                        .map(ToString::to_string)
+                        .collect(),
+                    jql: Some((jql.to_string(), fetched)),
+                })
+            }
+            (None, None) => Err(JiraError::api_error(
+                "Missing required parameter: issue_keys or jql",
+            )),
+        }
+    }
+
+    /// The selected issues, naming at most [`SELECTION_SAMPLE`] of them
+    fn describe(&self) -> String {
+        let mut text = format!("{} issues", self.keys.len());
+        if let Some((jql, _)) = &self.jql {
+            text.push_str(&format!(" matching `{jql}`"));
+        }
+        if !self.keys.is_empty() {
+            let sample: Vec<&str> = self
+                .keys
+                .iter()
+                .take(SELECTION_SAMPLE)
+                .map(String::as_str)
+                .collect();
+            text.push_str(&format!(": {}", sample.join(", ")));
+        }
+        if self.keys.len() > SELECTION_SAMPLE {
+            text.push_str(&format!(" and {} more", self.keys.len() - SELECTION_SAMPLE));
+        }
+        text
+    }
+
+    /// How the pages of the query were read, each line after a newline
+    fn note(&self) -> String {
+        self.jql
+            .as_ref()
+            .map(|(_, fetched)| {
+                fetched
+                    .note()
+                    .lines()
+                    .map(|line| format!("\n{line}"))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// What to return instead of running `operation`: the selection on a
+    /// `dry_run`, or a note when the query selected nothing
+    fn preview(&self, operation: &str, args: &serde_json::Value) -> Option<MCPToolResult> {
+        let dry_run = args
+            .get("dry_run")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let text = if dry_run {
+            format!(
+                "Dry run: {operation} would change {}; nothing was changed{}",
+                self.describe(),
+                self.note()
+            )
+        } else if self.keys.is_empty() && self.jql.is_some() {
+            format!(
+                "{operation}: {}; nothing was changed{}",
+                self.describe(),
+                self.note()
+            )
+        } else {
+            return None;
+        };
+        Some(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&self.keys)],
+            is_error: Some(false),
+        })
+    }
+
+    /// Echo of the issues a query selected, for the result of the operation
+    fn echo(&self) -> String {
+        if self.jql.is_some() {
+            format!("\nSelected {}{}", self.describe(), self.note())
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Report of a finished bulk operation: a summary line counting the failures
+/// by kind and the skipped operations, then one line per failed or skipped
+/// issue and per issue found under a new key
+#[must_use]
+pub fn bulk_summary_text(operation: &str, summary: &BulkOperationSummary) -> String {
+    let mut text = bulk_summary_headline(operation, summary);
+    for result in summary.results.iter().filter(|r| !r.success) {
+        text.push_str(&format!(
+            "\n• {} {}: {}",
+            result.issue_key,
+            if result.skipped { "skipped" } else { "failed" },
+            result.error_message.as_deref().unwrap_or("unknown error")
+        ));
+    }
+    for result in &summary.results {
+        if let Some(moved_from) = &result.moved_from {
+            text.push_str(&format!(
+                "\n• {moved_from} was moved to {}",
+                result.issue_key
+            ));
+        }
+    }
+    text
+}
+
+/// Line naming the signature the comments written by `summary` end with,
+/// when any were written and `comment_signature` is set
+fn signature_note(client: &JiraClient, summary: &BulkOperationSummary) -> Option<String> {
+    let commented = summary
+        .results
+        .iter()
+        .any(|r| r.success && r.operation_type == BulkOperationType::AddComment);
+    client.signature_note().filter(|_| commented)
+}
+
+/// First line of [`bulk_summary_text`]
+fn bulk_summary_headline(operation: &str, summary: &BulkOperationSummary) -> String {
+    let mut text = format!(
+        "{operation}: {} of {} operations succeeded",
+        summary.successful_operations, summary.total_operations
+    );
+    if let Some(errors) = summary.describe_errors() {
+        text.push_str(&format!(" ({errors})"));
+    }
+    if summary.skipped_operations > 0 {
+        text.push_str(&format!(", {} skipped", summary.skipped_operations));
+    }
+    text
+}
+
+/// The settings a bulk operation on `issue_keys` runs with, from the
+/// `config` argument and the `[bulk_defaults]` of the configuration, logging
+/// where each came from
+///
+/// # Errors
+///
+/// Returns a validation error if the `config` argument is invalid
+pub fn bulk_config_for<S: AsRef<str>>(
+    config: &JiraConfig,
+    issue_keys: &[S],
+    args: &serde_json::Value,
+) -> Result<EffectiveBulkConfig> {
+    let effective = resolve_bulk_config(
+        config.bulk_defaults.as_ref(),
+        issue_keys,
+        args.get("config"),
+    )?;
+    info!("{}", effective.describe());
+    Ok(effective)
+}
+
+/// Execute `items` at most [`BULK_CHUNK_SIZE`] per call, not starting
+/// another chunk after a failure unless `config` continues on errors
+async fn execute_in_chunks(
+    client: &JiraClient,
+    items: &[BulkOperationItem],
+    config: &BulkOperationConfig,
+) -> Result<BulkOperationSummary> {
+    let mut summary = BulkOperationSummary::new();
+    for (index, chunk) in items.chunks(BULK_CHUNK_SIZE).enumerate() {
+        let chunk_summary = client
+            .execute_bulk_operations(chunk.to_vec(), config.clone())
+            .await?;
+        summary.duration_ms += chunk_summary.duration_ms;
+        let failed = chunk_summary.failed_operations > 0;
+        for result in chunk_summary.results {
+            summary.add_result(result);
+        }
+        if failed && !config.continue_on_error {
+            for item in items.iter().skip((index + 1) * BULK_CHUNK_SIZE) {
+                summary.add_result(BulkOperationResult::not_run(item));
+            }
+            break;
+        }
+    }
+    Ok(summary)
+}
+
+/// Tool result for a finished bulk operation: `text`, then the summary as
+/// JSON for clients that parse it, with the `settings` it ran with and the id
+/// of its `entry` in the bulk history. Minimal output keeps only the first
+/// line of `text`; otherwise the settings are listed when `[bulk_defaults]`
+/// set any, and detailed output also lists the issues that succeeded with
+/// their links.
+fn bulk_summary_result(
+    client: &JiraClient,
+    mut text: String,
+    summary: &BulkOperationSummary,
+    settings: &EffectiveBulkConfig,
+    entry: &BulkHistoryEntry,
+    output: &ResponseFormatter,
+) -> MCPToolResult {
+    let is_error = Some(summary.successful_operations == 0 && summary.failed_operations > 0);
+    if output.is_minimal() {
+        text.truncate(text.find('\n').unwrap_or(text.len()));
+        return MCPToolResult {
+            content: vec![MCPContent::text(text)],
+            is_error,
+        };
+    }
+    if settings.from_bulk_defaults() {
+        text.push_str(&format!("\n{}", settings.describe()));
+    }
+    if output.is_detailed() {
+        for result in summary.results.iter().filter(|r| r.success) {
+            text.push_str(&format!(
+                "\n• {} succeeded: {}",
+                result.issue_key,
+                client.browse_url(&result.issue_key)
+            ));
+        }
+    }
+    let mut report = serde_json::to_value(summary).unwrap_or_default();
+    report["settings"] = json!(settings);
+    report["history_id"] = json!(entry.id);
+    MCPToolResult {
+        content: vec![MCPContent::text(text), MCPContent::json(&report)],
+        is_error,
+    }
+}
+
+/// Arguments of `bulk_update_issues` that add or remove items of an array
+/// field, with the field they change
+const BULK_ITEM_ARGS: [(&str, &str, &str); 4] = [
+    ("add_labels", "labels", "add"),
+    ("remove_labels", "labels", "remove"),
+    ("add_fix_versions", "fixVersions", "add"),
+    ("remove_fix_versions", "fixVersions", "remove"),
+];
+
+/// The data each issue of `bulk_update_issues` is updated with: `update_data`
+/// with the label and fix version arguments added to its `update` operations
+///
+/// # Errors
+///
+/// Returns a validation error if the `update` operations are malformed or
+/// nothing would be changed
+fn bulk_update_data(args: &serde_json::Value) -> Result<serde_json::Value> {
+    let mut data = args
+        .get("update_data")
+        .cloned()
+        .unwrap_or_else(|| json!({}));
+    if !data.is_object() {
+        return Err(JiraError::validation_error(
+            "update_data",
+            "must be an object with fields to set and update operations",
+        ));
+    }
+    let update = match data.get("update") {
+        Some(update) => parse_update(update)?,
+        None => serde_json::Map::new(),
+    };
+    let mut update = serde_json::Value::Object(update);
+    for (arg, field_id, verb) in BULK_ITEM_ARGS {
+        let items = string_list(args, arg)?;
+        if items.is_empty() {
+            continue;
+        }
+        let operations = update[field_id].as_array().cloned().unwrap_or_default();
+        update[field_id] = json!([operations, item_operations(field_id, verb, &items)].concat());
+    }
+    if update.as_object().is_some_and(|update| !update.is_empty()) {
+        data["update"] = update;
+    }
+
+    let changes = ["fields", "update"].iter().any(|part| {
+        data.get(part)
+            .and_then(|v| v.as_object())
+            .is_some_and(|fields| !fields.is_empty())
+    });
+    if !changes {
+        return Err(JiraError::api_error(
+            "Missing required parameter: update_data, or labels or fix versions to add or remove",
+        ));
+    }
+    Ok(data)
+}
+
 /// Bulk update issues
 pub struct BulkUpdateIssuesTool {
     client: JiraClient,
@@ -23,42 +401,86 @@ impl BulkUpdateIssuesTool {
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for BulkUpdateIssuesTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
-        let issue_keys = args
-            .get("issue_keys")
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: issue_keys")
-            })?;
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
 
-        let update_data = args.get("update_data").ok_or_else(|| {
-            crate::error::JiraError::api_error("Missing required parameter: update_data")
-        })?;
+        // Which issues to read back afterwards: none, the failed ones or all
+        let return_issues = args
+            .get("return_issues")
+            .and_then(|v| v.as_str())
+            .unwrap_or("none");
+        if !["none", "failed", "all"].contains(&return_issues) {
+            return Err(JiraError::validation_error(
+                "return_issues",
+                &format!("Unknown value '{return_issues}'; use none, failed or all"),
+            ));
+        }
 
-        let config = args
-            .get("config")
-            .map(|c| serde_json::from_value(c.clone()).unwrap_or_default())
-            .unwrap_or_default();
+        let update_data = bulk_update_data(&args)?;
 
-        info!("Bulk updating {} issues", issue_keys.len());
+        let selection = IssueSelection::from_args(&self.client, &args).await?;
+        if let Some(preview) = selection.preview("Bulk update", &args) {
+            return Ok(preview);
+        }
+        info!("Bulk updating {}", selection.describe());
 
-        let issue_keys_vec: Vec<String> = issue_keys
-            .iter()
-            .filter_map(|v| v.as_str().map(ToString::to_string))
-            .collect();
+        let issue_keys_vec = selection.keys.clone();
+        let settings = bulk_config_for(self.client.config(), &issue_keys_vec, &args)?;
+        let mut config = settings.config.clone();
+        // Kept with the history entry for undo_bulk_operation
+        config.capture_previous_fields = true;
 
-        self.client
-            .bulk_update_issues(issue_keys_vec, update_data.clone(), Some(config))
+        let summary = self
+            .client
+            .bulk_update_issues(issue_keys_vec, update_data, Some(config))
             .await?;
+        let entry = record_bulk_operation(
+            self.client.config(),
+            "bulk_update_issues",
+            BulkOperationType::Update,
+            &args,
+            &summary,
+        )
+        .await;
 
-        let response_text = format!(
-            "Bulk update completed successfully for {} issues",
-            issue_keys.len()
+        let mut result = bulk_summary_result(
+            &self.client,
+            bulk_summary_text("Bulk update", &summary) + &selection.echo(),
+            &summary,
+            &settings,
+            &entry,
+            &output,
         );
+        if return_issues == "none" {
+            return Ok(result);
+        }
 
-        Ok(MCPToolResult {
-            content: vec![MCPContent::text(response_text)],
-            is_error: Some(false),
-        })
+        let keys: Vec<&str> = summary
+            .results
+            .iter()
+            .filter(|r| return_issues == "all" || !r.success)
+            .map(|r| r.issue_key.as_str())
+            .collect();
+        let fields = return_fields(&args);
+        let mut issues = Vec::new();
+        for key in keys.iter().take(MAX_RETURNED_ISSUES) {
+            let text = match read_issue(&self.client, key, &fields).await {
+                Ok(issue) => {
+                    let details = issue_details(&self.client, &issue).await;
+                    issues.push(issue);
+                    details
+                }
+                Err(e) => format!("Issue {key} could not be read back: {e}"),
+            };
+            result.content[0].text.push_str(&format!("\n\n{text}"));
+        }
+        if keys.len() > MAX_RETURNED_ISSUES {
+            result.content[0].text.push_str(&format!(
+                "\n\n{} more issues not shown; return_issues reads at most {MAX_RETURNED_ISSUES}",
+                keys.len() - MAX_RETURNED_ISSUES
+            ));
+        }
+        result.content.push(MCPContent::json(&issues));
+        Ok(result)
     }
 }
 
@@ -81,56 +503,99 @@ impl BulkTransitionIssuesTool {
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for BulkTransitionIssuesTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
-        let issue_keys = args
-            .get("issue_keys")
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: issue_keys")
-            })?;
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
 
-        let transition_id = args
-            .get("transition_id")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: transition_id")
-            })?;
+        let transition_id = args.get("transition_id").and_then(|v| v.as_str());
+        let transition_name = args.get("transition_name").and_then(|v| v.as_str());
+        let transition = transition_id.or(transition_name).ok_or_else(|| {
+            crate::error::JiraError::api_error(
+                "Missing required parameter: transition_id or transition_name",
+            )
+        })?;
 
         let comment = args.get("comment").and_then(|v| v.as_str());
-        let config = args
-            .get("config")
-            .map(|c| serde_json::from_value(c.clone()).unwrap_or_default())
-            .unwrap_or_default();
 
+        let operation = format!("Bulk transition '{transition}'");
+        let selection = IssueSelection::from_args(&self.client, &args).await?;
+        if let Some(preview) = selection.preview(&operation, &args) {
+            return Ok(preview);
+        }
         info!(
-            "Bulk transitioning {} issues to {}",
-            issue_keys.len(),
-            transition_id
+            "Bulk transitioning {} to {}",
+            selection.describe(),
+            transition
         );
 
-        let issue_keys_vec: Vec<String> = issue_keys
-            .iter()
-            .filter_map(|v| v.as_str().map(ToString::to_string))
-            .collect();
+        let mut issue_keys_vec = selection.keys.clone();
+        let settings = bulk_config_for(self.client.config(), &issue_keys_vec, &args)?;
 
-        self.client
-            .bulk_transition_issues(
-                issue_keys_vec,
-                transition_id.to_string(),
-                comment.map(ToString::to_string),
-                Some(config),
-            )
-            .await?;
+        // Issues with unresolved blockers are left alone and reported as
+        // skipped
+        let mut skipped = Vec::new();
+        if args
+            .get("check_blockers")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+        {
+            let link_type = args.get("blocker_link_type").and_then(|v| v.as_str());
+            let blocked = self
+                .client
+                .find_unresolved_blockers(&issue_keys_vec, link_type)
+                .await?;
+            issue_keys_vec.retain(|key| match blocked.get(key) {
+                Some(blockers) => {
+                    skipped.push(BulkOperationResult::skipped(
+                        key,
+                        BulkOperationType::Transition,
+                        format!("Blocked by unresolved {}", blockers.join(", ")),
+                    ));
+                    false
+                }
+                None => true,
+            });
+        }
 
-        let response_text = format!(
-            "Bulk transition completed successfully for {} issues to transition {}",
-            issue_keys.len(),
-            transition_id
-        );
+        let mut summary = if issue_keys_vec.is_empty() {
+            BulkOperationSummary::new()
+        } else if let Some(transition_id) = transition_id {
+            self.client
+                .bulk_transition_issues(
+                    issue_keys_vec,
+                    transition_id.to_string(),
+                    comment.map(ToString::to_string),
+                    Some(settings.config.clone()),
+                )
+                .await?
+        } else {
+            self.client
+                .bulk_transition_issues_by_name(
+                    issue_keys_vec,
+                    transition,
+                    comment.map(ToString::to_string),
+                    Some(settings.config.clone()),
+                )
+                .await?
+        };
+        for result in skipped {
+            summary.add_result(result);
+        }
+        let entry = record_bulk_operation(
+            self.client.config(),
+            "bulk_transition_issues",
+            BulkOperationType::Transition,
+            &args,
+            &summary,
+        )
+        .await;
 
-        Ok(MCPToolResult {
-            content: vec![MCPContent::text(response_text)],
-            is_error: Some(false),
-        })
+        Ok(bulk_summary_result(
+            &self.client,
+            bulk_summary_text(&operation, &summary) + &selection.echo(),
+            &summary,
+            &settings,
+            &entry,
+            &output,
+        ))
     }
 }
 
@@ -153,12 +618,7 @@ impl BulkAddCommentsTool {
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for BulkAddCommentsTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
-        let issue_keys = args
-            .get("issue_keys")
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: issue_keys")
-            })?;
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
 
         let comment = args
             .get("comment")
@@ -167,31 +627,66 @@ impl crate::mcp::server::MCPToolHandler for BulkAddCommentsTool {
                 crate::error::JiraError::api_error("Missing required parameter: comment")
             })?;
 
-        let config = args
-            .get("config")
-            .map(|c| serde_json::from_value(c.clone()).unwrap_or_default())
-            .unwrap_or_default();
+        let visibility = JiraCommentVisibility::from_arg(args.get("visibility"))?;
+        let resolve_mentions = args
+            .get("resolve_mentions")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
 
-        info!("Bulk adding comments to {} issues", issue_keys.len());
+        let selection = IssueSelection::from_args(&self.client, &args).await?;
+        if let Some(preview) = selection.preview("Bulk comment", &args) {
+            return Ok(preview);
+        }
+        info!("Bulk adding comments to {}", selection.describe());
 
-        let issue_keys_vec: Vec<String> = issue_keys
-            .iter()
-            .filter_map(|v| v.as_str().map(ToString::to_string))
-            .collect();
+        // Resolve once; every issue gets the same body
+        let mentions = if resolve_mentions {
+            Some(self.client.resolve_mentions(comment).await?)
+        } else {
+            None
+        };
+        let body = mentions
+            .as_ref()
+            .map_or_else(|| comment.into(), |m| m.body.clone());
+
+        let issue_keys_vec = selection.keys.clone();
+        let settings = bulk_config_for(self.client.config(), &issue_keys_vec, &args)?;
 
-        self.client
-            .bulk_add_comments(issue_keys_vec, comment.to_string(), Some(config))
+        let summary = self
+            .client
+            .bulk_add_comments(
+                issue_keys_vec,
+                body,
+                visibility,
+                Some(settings.config.clone()),
+            )
             .await?;
+        let entry = record_bulk_operation(
+            self.client.config(),
+            "bulk_add_comments",
+            BulkOperationType::AddComment,
+            &args,
+            &summary,
+        )
+        .await;
 
-        let response_text = format!(
-            "Bulk comment addition completed successfully for {} issues",
-            issue_keys.len()
-        );
+        let mut response_text = bulk_summary_text("Bulk comment", &summary);
+        if let Some(note) = signature_note(&self.client, &summary) {
+            response_text.push_str(&format!("\n{note}"));
+        }
+        response_text.push_str(&selection.echo());
+        if let Some(report) = mentions.and_then(|m| m.report()) {
+            response_text.push_str(&format!("\n{report}"));
+        }
 
-        Ok(MCPToolResult {
-            content: vec![MCPContent::text(response_text)],
-            is_error: Some(false),
-        })
+        Ok(bulk_summary_result(
+            &self.client,
+            response_text,
+            &summary,
+            &settings,
+            &entry,
+            &output,
+        ))
     }
 }
 
@@ -214,6 +709,7 @@ impl MixedBulkOperationsTool {
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for MixedBulkOperationsTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
         let operations = args
             .get("operations")
             .and_then(|v| v.as_array())
@@ -221,33 +717,795 @@ impl crate::mcp::server::MCPToolHandler for MixedBulkOperationsTool {
                 crate::error::JiraError::api_error("Missing required parameter: operations")
             })?;
 
-        let config = args
-            .get("config")
-            .map(|c| serde_json::from_value(c.clone()).unwrap_or_default())
-            .unwrap_or_default();
-
         info!("Executing {} mixed bulk operations", operations.len());
 
-        let operations_vec: Vec<crate::types::jira::BulkOperationItem> = operations
+        let operations_vec = validate_bulk_operations(operations)?;
+        let keys: Vec<&str> = operations_vec
             .iter()
-            .filter_map(|v| serde_json::from_value(v.clone()).ok())
+            .map(|operation| operation.issue_key.as_str())
             .collect();
+        let settings = bulk_config_for(self.client.config(), &keys, &args)?;
 
-        let results = self
+        let summary = self
             .client
-            .execute_bulk_operations(operations_vec, config)
+            .execute_bulk_operations(operations_vec, settings.config.clone())
             .await?;
+        let entry = record_bulk_operation(
+            self.client.config(),
+            "mixed_bulk_operations",
+            BulkOperationType::Mixed,
+            &args,
+            &summary,
+        )
+        .await;
+
+        let mut response_text = bulk_summary_text("Mixed bulk operations", &summary);
+        if let Some(note) = signature_note(&self.client, &summary) {
+            response_text.push_str(&format!("\n{note}"));
+        }
+
+        Ok(bulk_summary_result(
+            &self.client,
+            response_text,
+            &summary,
+            &settings,
+            &entry,
+            &output,
+        ))
+    }
+}
+
+/// Result of one CSV row, with the line it was read from
+#[derive(Debug, Serialize)]
+struct CsvRowResult<'a> {
+    line: u64,
+    #[serde(flatten)]
+    result: &'a BulkOperationResult,
+}
+
+/// Bulk operations read from a CSV file
+pub struct BulkOperationsFromCsvTool {
+    client: JiraClient,
+}
 
-        let response_text = format!(
-            "Mixed bulk operations completed successfully\nTotal operations: {}\nSuccessful: {}\nFailed: {}",
-            results.total_operations,
-            results.successful_operations,
-            results.failed_operations
+impl BulkOperationsFromCsvTool {
+    #[must_use]
+    /// # Panics
+    /// This function panics if `JiraClient::new` fails.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+
+    /// Problems found checking the field values of the first `rows` updates
+    /// against each issue's edit metadata, as `line N: KEY: problem`
+    async fn validate_updates(&self, operations: &[CsvOperation], rows: usize) -> Vec<String> {
+        let mut problems = Vec::new();
+        for operation in operations.iter().take(rows) {
+            let key = &operation.item.issue_key;
+            let Some(fields) = operation
+                .item
+                .data
+                .get("fields")
+                .and_then(|f| f.as_object())
+            else {
+                continue;
+            };
+            match self.client.get_edit_metadata(key).await {
+                Ok(editmeta) => problems.extend(
+                    check_against_editmeta(fields, &editmeta)
+                        .into_iter()
+                        .map(|problem| format!("line {}: {key}: {problem}", operation.line)),
+                ),
+                Err(e) => problems.push(format!(
+                    "line {}: {key}: could not load the edit metadata: {e}",
+                    operation.line
+                )),
+            }
+        }
+        problems
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for BulkOperationsFromCsvTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let file_path = args
+            .get("file_path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: file_path"))?;
+        let operation_name = args
+            .get("operation_type")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: operation_type"))?;
+        let operation_type: BulkOperationType = serde_json::from_value(serde_json::Value::String(
+            operation_name.to_string(),
+        ))
+        .map_err(|_| {
+            JiraError::validation_error(
+                "operation_type",
+                &format!("Unknown operation type '{operation_name}'"),
+            )
+        })?;
+        let column_mapping: BTreeMap<String, String> = args
+            .get("column_mapping")
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: column_mapping"))
+            .and_then(|v| {
+                serde_json::from_value(v.clone()).map_err(|_| {
+                    JiraError::validation_error(
+                        "column_mapping",
+                        "must map CSV column names to field ids or operation keys",
+                    )
+                })
+            })?;
+        let validate = args
+            .get("validate")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let validate_rows = args
+            .get("validate_rows")
+            .and_then(serde_json::Value::as_u64)
+            .map_or(DEFAULT_CSV_VALIDATE_ROWS, |rows| {
+                usize::try_from(rows).unwrap_or(usize::MAX)
+            });
+
+        let allowed_dirs = self.client.config().allowed_directories();
+        let path = resolve_allowed_file("file_path", file_path, &allowed_dirs)?;
+        let content = tokio::fs::read(&path).await?;
+
+        let operations = match parse_bulk_csv(&content, &operation_type, &column_mapping)? {
+            Ok(operations) => operations,
+            Err(errors) => {
+                let mut text = format!(
+                    "{} rows of {} could not be read; nothing was executed",
+                    errors.len(),
+                    path.display()
+                );
+                for error in &errors {
+                    text.push_str(&format!("\n• {error}"));
+                }
+                return Ok(MCPToolResult {
+                    content: vec![MCPContent::text(text), MCPContent::json(&errors)],
+                    is_error: Some(true),
+                });
+            }
+        };
+
+        // Only updates carry field values the edit metadata describes
+        if validate && matches!(operation_type, BulkOperationType::Update) {
+            let problems = self.validate_updates(&operations, validate_rows).await;
+            if !problems.is_empty() {
+                let mut text = format!(
+                    "Validation of {} found {} problems; nothing was executed",
+                    path.display(),
+                    problems.len()
+                );
+                for problem in &problems {
+                    text.push_str(&format!("\n• {problem}"));
+                }
+                return Ok(MCPToolResult {
+                    content: vec![MCPContent::text(text)],
+                    is_error: Some(true),
+                });
+            }
+        }
+
+        info!(
+            "Executing {} {operation_name} operations from {}",
+            operations.len(),
+            path.display()
+        );
+
+        let items: Vec<BulkOperationItem> = operations
+            .iter()
+            .map(|operation| operation.item.clone())
+            .collect();
+        let keys: Vec<&str> = items.iter().map(|item| item.issue_key.as_str()).collect();
+        let settings = bulk_config_for(self.client.config(), &keys, &args)?;
+        let summary = execute_in_chunks(&self.client, &items, &settings.config).await?;
+
+        // Operations run in order, so results line up with the rows they
+        // came from
+        let rows: Vec<CsvRowResult> = operations
+            .iter()
+            .zip(&summary.results)
+            .map(|(operation, result)| CsvRowResult {
+                line: operation.line,
+                result,
+            })
+            .collect();
+        let mut text = bulk_summary_headline(
+            &format!("Bulk {operation_name} from {}", path.display()),
+            &summary,
+        );
+        if settings.from_bulk_defaults() {
+            text.push_str(&format!("\n{}", settings.describe()));
+        }
+        for row in rows.iter().filter(|row| !row.result.success) {
+            text.push_str(&format!(
+                "\n• line {} {} failed: {}",
+                row.line,
+                row.result.issue_key,
+                row.result
+                    .error_message
+                    .as_deref()
+                    .unwrap_or("unknown error")
+            ));
+        }
+
+        let entry = record_bulk_operation(
+            self.client.config(),
+            "bulk_operations_from_csv",
+            operation_type,
+            &args,
+            &summary,
+        )
+        .await;
+
+        let mut report = serde_json::to_value(&summary)?;
+        report["results"] = serde_json::to_value(&rows)?;
+        report["settings"] = json!(settings);
+        report["history_id"] = json!(entry.id);
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&report)],
+            is_error: Some(summary.successful_operations == 0 && summary.failed_operations > 0),
+        })
+    }
+}
+
+/// Outcome of one row of `bulk_create_issues_from_csv`
+#[derive(Debug, Serialize)]
+pub struct CsvCreateOutcome {
+    pub line: u64,
+    pub issue_key: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Create one issue per row of a CSV file
+pub struct BulkCreateIssuesFromCsvTool {
+    client: JiraClient,
+}
+
+impl BulkCreateIssuesFromCsvTool {
+    #[must_use]
+    /// # Panics
+    /// This function panics if `JiraClient::new` fails.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+
+    /// Id of the field given by id or display name
+    async fn field_id(&self, field: &str) -> Result<String> {
+        let definition = self.client.resolve_field(field).await?;
+        Ok(definition
+            .get("id")
+            .and_then(|id| id.as_str())
+            .unwrap_or(field)
+            .to_string())
+    }
+
+    /// Fields of every row, ready to create, or the problems of the rows that
+    /// are not. Each project and issue type's create screen is read once.
+    async fn validate_rows(
+        &self,
+        rows: Vec<(u64, serde_json::Map<String, serde_json::Value>)>,
+    ) -> (
+        Vec<(u64, serde_json::Map<String, serde_json::Value>)>,
+        Vec<CsvRowError>,
+    ) {
+        let mut screens: HashMap<(String, String), std::result::Result<JiraCreateMeta, String>> =
+            HashMap::new();
+        let mut valid = Vec::new();
+        let mut errors = Vec::new();
+        for (line, mut fields) in rows {
+            let reference = |field: &str, keys: [&str; 2]| {
+                let value = fields.get(field)?;
+                keys.iter()
+                    .find_map(|key| value.get(key).and_then(|v| v.as_str()))
+                    .map(ToString::to_string)
+            };
+            let project = reference("project", ["key", "id"]);
+            let issue_type = reference("issuetype", ["id", "name"]);
+            let (Some(project), Some(issue_type)) = (project, issue_type) else {
+                errors.push(CsvRowError {
+                    line,
+                    message: "no project or issue type; map a column to them or pass \
+                              project_key and issue_type"
+                        .to_string(),
+                });
+                continue;
+            };
+
+            let screen = match screens.entry((project.clone(), issue_type.clone())) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => entry.insert(
+                    self.client
+                        .get_create_meta(&project, &issue_type, false)
+                        .await
+                        .map_err(|e| e.to_string()),
+                ),
+            };
+            let meta = match screen {
+                Ok(meta) => meta,
+                Err(message) => {
+                    errors.push(CsvRowError {
+                        line,
+                        message: message.clone(),
+                    });
+                    continue;
+                }
+            };
+
+            fields.insert("issuetype".to_string(), json!({"id": meta.issue_type_id}));
+            let mut problems = coerce_to_create_meta(&mut fields, meta);
+            problems.extend(JiraClient::create_screen_problems(meta, &fields));
+            if problems.is_empty() {
+                valid.push((line, fields));
+            } else {
+                errors.push(CsvRowError {
+                    line,
+                    message: problems.join("; "),
+                });
+            }
+        }
+        (valid, errors)
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for BulkCreateIssuesFromCsvTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let file_path = args
+            .get("file_path")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: file_path"))?;
+        let column_mapping: BTreeMap<String, String> = args
+            .get("column_mapping")
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: column_mapping"))
+            .and_then(|v| {
+                serde_json::from_value(v.clone()).map_err(|_| {
+                    JiraError::validation_error(
+                        "column_mapping",
+                        "must map CSV column names to field ids or names",
+                    )
+                })
+            })?;
+        let dry_run = args
+            .get("dry_run")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        // Targets and defaults may name fields by display name
+        let mut field_mapping = BTreeMap::new();
+        for (column, target) in column_mapping {
+            field_mapping.insert(column, self.field_id(&target).await?);
+        }
+        let mut defaults = serde_json::Map::new();
+        if let Some(given) = args.get("defaults").and_then(|v| v.as_object()) {
+            for (field, value) in given {
+                defaults.insert(self.field_id(field).await?, value.clone());
+            }
+        }
+        if let Ok(project) = ProjectArg::from_args(&args, "project_key", self.client.config()) {
+            defaults
+                .entry("project")
+                .or_insert_with(|| json!(project.key));
+        }
+        if let Some(issue_type) = args.get("issue_type").and_then(|v| v.as_str()) {
+            defaults
+                .entry("issuetype")
+                .or_insert_with(|| json!(issue_type));
+        }
+
+        let allowed_dirs = self.client.config().allowed_directories();
+        let path = resolve_allowed_file("file_path", file_path, &allowed_dirs)?;
+        let content = tokio::fs::read(&path).await?;
+        let (records, mut errors) = read_mapped_rows(&content, &field_mapping)?;
+
+        let rows = records
+            .into_iter()
+            .map(|record| (record.line, create_fields(&defaults, record.values)))
+            .collect();
+        let (rows, invalid) = self.validate_rows(rows).await;
+        errors.extend(invalid);
+
+        // Every problem is reported at once, before anything is created
+        if !errors.is_empty() {
+            errors.sort_by_key(|error| error.line);
+            let mut text = format!(
+                "{} rows of {} failed validation; nothing was created",
+                errors.len(),
+                path.display()
+            );
+            for error in &errors {
+                text.push_str(&format!("\n• {error}"));
+            }
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(text), MCPContent::json(&errors)],
+                is_error: Some(true),
+            });
+        }
+
+        if dry_run {
+            let preview: Vec<serde_json::Value> = rows
+                .iter()
+                .take(CSV_DRY_RUN_PREVIEW_ROWS)
+                .map(|(line, fields)| json!({"line": line, "fields": fields}))
+                .collect();
+            let mut text = format!(
+                "Dry run: {} rows of {} would create issues; nothing was created",
+                rows.len(),
+                path.display()
+            );
+            if !preview.is_empty() {
+                text.push_str(&format!("\nFields of the first {} rows:", preview.len()));
+            }
+            for (line, fields) in rows.iter().take(CSV_DRY_RUN_PREVIEW_ROWS) {
+                text.push_str(&format!(
+                    "\n• line {line}: {}",
+                    serde_json::Value::Object(fields.clone())
+                ));
+            }
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(text), MCPContent::json(&preview)],
+                is_error: Some(false),
+            });
+        }
+
+        info!("Creating {} issues from {}", rows.len(), path.display());
+
+        let mut outcomes = Vec::new();
+        for (line, fields) in rows {
+            let outcome = match self.client.create_issue(&json!({ "fields": fields })).await {
+                Ok(issue) => CsvCreateOutcome {
+                    line,
+                    issue_key: Some(issue.key),
+                    error: None,
+                },
+                Err(e) => CsvCreateOutcome {
+                    line,
+                    issue_key: None,
+                    error: Some(e.to_string()),
+                },
+            };
+            outcomes.push(outcome);
+        }
+
+        let created = outcomes.iter().filter(|o| o.issue_key.is_some()).count();
+        let mut text = format!(
+            "Created {created} of {} issues from {}",
+            outcomes.len(),
+            path.display()
+        );
+        if let Some(note) = self.client.label_note().filter(|_| created > 0) {
+            text.push_str(&format!("\n{note}"));
+        }
+        for outcome in &outcomes {
+            match (&outcome.issue_key, &outcome.error) {
+                (Some(key), _) => text.push_str(&format!(
+                    "\n• line {}: {key} - {}",
+                    outcome.line,
+                    self.client.browse_url(key)
+                )),
+                (None, error) => text.push_str(&format!(
+                    "\n• line {} failed: {}",
+                    outcome.line,
+                    error.as_deref().unwrap_or("unknown error")
+                )),
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![
+                MCPContent::text(text),
+                MCPContent::json(&json!({"created": created, "issues": outcomes})),
+            ],
+            is_error: Some(created == 0 && !outcomes.is_empty()),
+        })
+    }
+}
+
+/// List the bulk operations recorded in this session, newest first
+pub struct GetBulkOperationHistoryTool {
+    config: JiraConfig,
+}
+
+impl GetBulkOperationHistoryTool {
+    #[must_use]
+    pub fn new(config: JiraConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetBulkOperationHistoryTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let bound = |field: &str| {
+            args.get(field)
+                .and_then(|v| v.as_str())
+                .map(|value| parse_date_or_timestamp(field, value))
+                .transpose()
+        };
+        let operation_type = args
+            .get("operation_type")
+            .and_then(|v| v.as_str())
+            .map(|name| {
+                serde_json::from_value::<BulkOperationType>(json!(name)).map_err(|_| {
+                    JiraError::validation_error(
+                        "operation_type",
+                        &format!("Unknown operation type '{name}'"),
+                    )
+                })
+            })
+            .transpose()?;
+        let filter = BulkHistoryFilter {
+            since: bound("since")?,
+            until: bound("until")?,
+            operation_type,
+        };
+        let limit = args
+            .get("limit")
+            .and_then(serde_json::Value::as_u64)
+            .map_or(usize::MAX, |limit| {
+                usize::try_from(limit).unwrap_or(usize::MAX)
+            });
+
+        info!("Getting bulk operation history");
+
+        let mut entries = bulk_history(&self.config, &filter);
+        let matched = entries.len();
+        entries.truncate(limit);
+
+        let formatter = DateFormatter::from_config(&self.config);
+        let mut text = if entries.is_empty() {
+            "No bulk operations recorded".to_string()
+        } else if entries.len() < matched {
+            format!("Latest {} of {matched} bulk operations:", entries.len())
+        } else {
+            format!("{matched} bulk operations, newest first:")
+        };
+        for entry in &entries {
+            text.push_str(&format!(
+                "\n• {} at {}",
+                entry.headline(),
+                formatter.format(&entry.finished_at.fixed_offset())
+            ));
+        }
+
+        // The per-issue results are left to get_bulk_operation_detail
+        let listed: Vec<serde_json::Value> = entries
+            .iter()
+            .map(|entry| {
+                let mut value = serde_json::to_value(entry).unwrap_or_default();
+                if let Some(object) = value.as_object_mut() {
+                    object.remove("results");
+                }
+                value
+            })
+            .collect();
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&listed)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Show one recorded bulk operation with the result of every issue
+pub struct GetBulkOperationDetailTool {
+    config: JiraConfig,
+}
+
+impl GetBulkOperationDetailTool {
+    #[must_use]
+    pub fn new(config: JiraConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetBulkOperationDetailTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let id = args
+            .get("id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: id"))?;
+
+        info!("Getting bulk operation {}", id);
+
+        let Some(entry) = bulk_operation(&self.config, id) else {
+            return Err(JiraError::validation_error(
+                "id",
+                &format!(
+                    "No bulk operation '{id}' in the history, which keeps the last \
+                     {MAX_BULK_HISTORY}"
+                ),
+            ));
+        };
+
+        let formatter = DateFormatter::from_config(&self.config);
+        let mut text = format!(
+            "{}\nStarted {}, finished {} ({} ms)\nIssues: {}\nArguments: {}",
+            entry.headline(),
+            formatter.format(&entry.started_at.fixed_offset()),
+            formatter.format(&entry.finished_at.fixed_offset()),
+            entry.duration_ms,
+            entry.issue_keys.join(", "),
+            entry.arguments
         );
+        for result in entry.results.iter().filter(|r| !r.success) {
+            text.push_str(&format!(
+                "\n• {} {} {}: {}",
+                result.issue_key,
+                operation_type_name(&result.operation_type),
+                if result.skipped { "skipped" } else { "failed" },
+                result.error_message.as_deref().unwrap_or("unknown error")
+            ));
+        }
 
         Ok(MCPToolResult {
-            content: vec![MCPContent::text(response_text)],
+            content: vec![MCPContent::text(text), MCPContent::json(&entry)],
             is_error: Some(false),
         })
     }
 }
+
+/// Put back the values a `bulk_update_issues` operation replaced
+pub struct UndoBulkOperationTool {
+    client: JiraClient,
+}
+
+impl UndoBulkOperationTool {
+    #[must_use]
+    /// # Panics
+    /// This function panics if `JiraClient::new` fails.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+
+    /// Fields of `issue_key` that no longer hold the value in `applied`, or
+    /// why the issue could not be checked
+    async fn changed_fields(
+        &self,
+        issue_key: &str,
+        applied: &serde_json::Map<String, serde_json::Value>,
+        names: Vec<String>,
+    ) -> std::result::Result<Vec<String>, String> {
+        let issue = self
+            .client
+            .get_issue_fields(issue_key, &names)
+            .await
+            .map_err(|e| format!("Could not read the current values: {e}"))?;
+        Ok(names
+            .into_iter()
+            .filter(|name| {
+                let applied = applied.get(name).unwrap_or(&serde_json::Value::Null);
+                let current = issue.fields.get(name).unwrap_or(&serde_json::Value::Null);
+                !still_applied(applied, current)
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for UndoBulkOperationTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
+        let id = args
+            .get("id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: id"))?;
+        let force = args
+            .get("force")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        let entry = bulk_operation(self.client.config(), id).ok_or_else(|| {
+            JiraError::validation_error(
+                "id",
+                &format!(
+                    "No bulk operation '{id}' in the history, which keeps the last \
+                     {MAX_BULK_HISTORY}"
+                ),
+            )
+        })?;
+        if entry.tool != "bulk_update_issues" {
+            return Err(JiraError::validation_error(
+                "id",
+                &format!(
+                    "Only bulk_update_issues can be undone; {id} was run by {}",
+                    entry.tool
+                ),
+            ));
+        }
+        let applied = entry
+            .arguments
+            .get("update_data")
+            .and_then(|data| data.get("fields"))
+            .and_then(|fields| fields.as_object())
+            .cloned()
+            .unwrap_or_default();
+
+        info!("Undoing bulk operation {}", id);
+
+        // Issues the operation failed on were left as they were
+        let mut items = Vec::new();
+        let mut skipped = Vec::new();
+        for result in entry.results.iter().filter(|r| r.success) {
+            let Some(previous) = &result.previous_fields else {
+                skipped.push(BulkOperationResult::skipped(
+                    &result.issue_key,
+                    BulkOperationType::Update,
+                    "No previous values were recorded".to_string(),
+                ));
+                continue;
+            };
+            if !force {
+                // Fields changed through `update` verbs have no single value
+                // the operation left, so only those set through `fields` are
+                // compared
+                let names = previous
+                    .keys()
+                    .filter(|name| applied.contains_key(*name))
+                    .cloned()
+                    .collect();
+                let reason = match self
+                    .changed_fields(&result.issue_key, &applied, names)
+                    .await
+                {
+                    Ok(changed) if changed.is_empty() => None,
+                    Ok(changed) => Some(format!(
+                        "{} changed since {id}; pass force to overwrite",
+                        changed.join(", ")
+                    )),
+                    Err(reason) => Some(reason),
+                };
+                if let Some(reason) = reason {
+                    skipped.push(BulkOperationResult::skipped(
+                        &result.issue_key,
+                        BulkOperationType::Update,
+                        reason,
+                    ));
+                    continue;
+                }
+            }
+            items.push(BulkOperationItem {
+                issue_key: result.issue_key.clone(),
+                operation_type: BulkOperationType::Update,
+                data: json!({ "fields": previous }),
+            });
+        }
+
+        let keys: Vec<&str> = entry
+            .results
+            .iter()
+            .map(|result| result.issue_key.as_str())
+            .collect();
+        let settings = bulk_config_for(self.client.config(), &keys, &args)?;
+        let mut summary = execute_in_chunks(&self.client, &items, &settings.config).await?;
+        for result in skipped {
+            summary.add_result(result);
+        }
+        let undo = record_bulk_operation(
+            self.client.config(),
+            "undo_bulk_operation",
+            BulkOperationType::Update,
+            &args,
+            &summary,
+        )
+        .await;
+
+        Ok(bulk_summary_result(
+            &self.client,
+            bulk_summary_text(&format!("Undo of {id}"), &summary),
+            &summary,
+            &settings,
+            &undo,
+            &output,
+        ))
+    }
+}
@@ -0,0 +1,223 @@
+use crate::config::JiraConfig;
+use crate::error::Result;
+use crate::jira::bundle::{
+    BundleImportOptions, BundleItemKind, BundleItemOutcome, IssueBundle, BUNDLE_FILE_NAME,
+};
+use crate::jira::client::JiraClient;
+use crate::mcp::tools::attachments::DEFAULT_MAX_ATTACHMENT_DOWNLOAD_BYTES;
+use crate::mcp::tools::required_str;
+use crate::types::jira::JiraFieldMapping;
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use crate::utils::paths::resolve_allowed_dir;
+use tracing::info;
+
+/// The string array argument `name`, empty when absent
+fn string_list(args: &serde_json::Value, name: &str) -> Vec<String> {
+    args.get(name)
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str())
+                .map(ToString::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The field mapping of an import: the `clone_jira_issue` style
+/// `field_mapping` argument, or the portable default fields without one
+fn parse_field_mapping(args: &serde_json::Value) -> JiraFieldMapping {
+    let Some(mapping) = args.get("field_mapping") else {
+        return JiraFieldMapping::default();
+    };
+    JiraFieldMapping {
+        copy_fields: string_list(mapping, "copy_fields"),
+        exclude_fields: string_list(mapping, "exclude_fields"),
+        custom_field_mapping: mapping
+            .get("custom_field_mapping")
+            .and_then(|v| v.as_object())
+            .map(|obj| {
+                obj.iter()
+                    .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
+                    .collect()
+            }),
+    }
+}
+
+/// Export an issue with its comments, worklogs, attachments and links as a
+/// portable bundle
+pub struct ExportIssueBundleTool {
+    client: JiraClient,
+}
+
+impl ExportIssueBundleTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ExportIssueBundleTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = required_str(&args, "issue_key")?;
+        let target_dir = required_str(&args, "target_dir")?;
+        let force = args
+            .get("force")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        // Check the destination before fetching anything
+        let dir = resolve_allowed_dir(
+            "target_dir",
+            target_dir,
+            &self.client.config().allowed_directories(),
+        )?;
+        let limit = (!force).then(|| {
+            self.client
+                .config()
+                .max_attachment_download_bytes
+                .unwrap_or(DEFAULT_MAX_ATTACHMENT_DOWNLOAD_BYTES)
+        });
+
+        info!(
+            "Exporting issue bundle of {} to {}",
+            issue_key,
+            dir.display()
+        );
+
+        let bundle = self
+            .client
+            .export_issue_bundle(issue_key, &dir, limit)
+            .await?;
+        let saved = bundle
+            .attachments
+            .iter()
+            .filter(|attachment| attachment.file.is_some())
+            .count();
+        let mut response_text = format!(
+            "Exported {} to {}\nComments: {}\nWorklogs: {}\nAttachments: {saved} of {}\nLinks: {}",
+            bundle.source.issue_key,
+            dir.join(BUNDLE_FILE_NAME).display(),
+            bundle.comments.len(),
+            bundle.worklogs.len(),
+            bundle.attachments.len(),
+            bundle.links.len()
+        );
+        for attachment in &bundle.attachments {
+            if let Some(error) = &attachment.error {
+                response_text.push_str(&format!(
+                    "\n• {} not exported: {error}",
+                    attachment.filename
+                ));
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text), MCPContent::json(&bundle)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Create an issue from a bundle written by `export_issue_bundle`
+pub struct ImportIssueBundleTool {
+    client: JiraClient,
+}
+
+impl ImportIssueBundleTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ImportIssueBundleTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let bundle_dir = required_str(&args, "bundle_dir")?;
+        let dir = resolve_allowed_dir(
+            "bundle_dir",
+            bundle_dir,
+            &self.client.config().allowed_directories(),
+        )?;
+        let options = BundleImportOptions {
+            project_key: args
+                .get("project_key")
+                .and_then(|v| v.as_str())
+                .map(ToString::to_string),
+            issue_type: args
+                .get("issue_type")
+                .and_then(|v| v.as_str())
+                .map(ToString::to_string),
+            field_mapping: parse_field_mapping(&args),
+            link_issues: args
+                .get("link_issues")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+        };
+
+        let bundle = IssueBundle::read(&dir).await?;
+        info!(
+            "Importing issue bundle of {} from {}",
+            bundle.source.issue_key,
+            dir.display()
+        );
+
+        let report = self
+            .client
+            .import_issue_bundle(&bundle, &dir, &options)
+            .await?;
+        let mut response_text = format!(
+            "Imported {} as {}\nURL: {}",
+            report.source_key,
+            report.issue_key,
+            self.client.browse_url(&report.issue_key)
+        );
+        for (kind, label) in [
+            (BundleItemKind::Comment, "Comments"),
+            (BundleItemKind::Worklog, "Worklogs"),
+            (BundleItemKind::Attachment, "Attachments"),
+            (BundleItemKind::Link, "Links"),
+        ] {
+            let total = report.items.iter().filter(|item| item.kind == kind).count();
+            if total > 0 {
+                response_text.push_str(&format!(
+                    "\n{label}: {} of {total} imported",
+                    report.count(kind, BundleItemOutcome::Imported)
+                ));
+            }
+        }
+        for item in &report.items {
+            if let Some(message) = &item.message {
+                let outcome = match item.outcome {
+                    BundleItemOutcome::Failed => "failed",
+                    _ => "skipped",
+                };
+                response_text.push_str(&format!(
+                    "\n• {} {} {outcome}: {message}",
+                    item.kind.as_str(),
+                    item.source
+                ));
+            }
+        }
+        if let Some(note) = self.client.label_note() {
+            response_text.push_str(&format!("\n{note}"));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text), MCPContent::json(&report)],
+            is_error: Some(false),
+        })
+    }
+}
@@ -159,14 +159,18 @@ impl crate::mcp::server::MCPToolHandler for CloneIssueTool {
             .await?;
 
         // Format response
-        let response_text = format!(
-            "Issue cloned successfully\nOriginal: {}\nCloned: {}\nProject: {}\nType: {}\nSummary: {}",
+        let mut response_text = format!(
+            "Issue cloned successfully\nOriginal: {}\nCloned: {}\nProject: {}\nType: {}\nSummary: {}\nURL: {}",
             clone_response.original_issue_key,
             clone_response.cloned_issue_key,
             project_key,
             issue_type,
-            summary
+            summary,
+            self.client.browse_url(&clone_response.cloned_issue_key)
         );
+        if let Some(note) = self.client.label_note() {
+            response_text.push_str(&format!("\n{note}"));
+        }
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
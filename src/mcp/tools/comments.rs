@@ -1,8 +1,91 @@
 use crate::config::JiraConfig;
-use crate::error::Result;
+use crate::error::{JiraError, Result};
 use crate::jira::client::JiraClient;
+use crate::jira::jsm::comment_is_public;
+use crate::mcp::tools::formatting::ResponseFormatter;
+use crate::types::jira::{JiraComment, JiraCommentVisibility};
 use crate::types::mcp::{MCPContent, MCPToolResult};
-use tracing::info;
+use crate::utils::datetime::{parse_timestamp, DateFormatter};
+use crate::utils::markdown::{markdown_to_adf, InputFormat};
+use crate::utils::time::{parse_date_or_timestamp, relative_time};
+use chrono::{DateTime, Utc};
+use tracing::{debug, info, warn};
+
+/// Which comments `get_jira_comments` returns
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommentFilter {
+    /// Keep comments created at or after this instant
+    pub since: Option<DateTime<Utc>>,
+    /// Keep comments whose author has this account id, username, key, email
+    /// address or display name, ignoring case
+    pub author: Option<String>,
+}
+
+impl CommentFilter {
+    /// Read the `since` (`YYYY-MM-DD` or an ISO timestamp) and `author`
+    /// arguments of a tool call
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `since` is neither a date nor a
+    /// timestamp.
+    pub fn from_args(args: &serde_json::Value) -> Result<Self> {
+        let since = args
+            .get("since")
+            .and_then(|v| v.as_str())
+            .map(|value| parse_date_or_timestamp("since", value))
+            .transpose()?;
+        let author = args
+            .get("author")
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string);
+        Ok(Self { since, author })
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.since.is_none() && self.author.is_none()
+    }
+
+    /// Whether the raw comment `comment` passes the filter
+    #[must_use]
+    pub fn matches(&self, comment: &serde_json::Value) -> bool {
+        let since_ok = self.since.is_none_or(|since| {
+            comment
+                .get("created")
+                .and_then(|v| v.as_str())
+                .and_then(parse_timestamp)
+                .is_some_and(|created| created >= since)
+        });
+        let author_ok = self
+            .author
+            .as_deref()
+            .is_none_or(|wanted| author_matches(comment.get("author"), wanted));
+        since_ok && author_ok
+    }
+
+    /// The filter in words, e.g. "by alice since 2024-03-04T00:00:00+00:00"
+    fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(author) = &self.author {
+            parts.push(format!("by {author}"));
+        }
+        if let Some(since) = self.since {
+            parts.push(format!("since {}", since.to_rfc3339()));
+        }
+        parts.join(" ")
+    }
+}
+
+/// Whether the raw user `author` has `wanted` as account id, username, key,
+/// email address or display name, ignoring case
+#[must_use]
+pub fn author_matches(author: Option<&serde_json::Value>, wanted: &str) -> bool {
+    ["accountId", "name", "key", "emailAddress", "displayName"]
+        .iter()
+        .filter_map(|field| author?.get(field)?.as_str())
+        .any(|value| value.eq_ignore_ascii_case(wanted))
+}
 
 /// Get comments for a Jira issue
 pub struct GetCommentsTool {
@@ -29,25 +112,105 @@ impl crate::mcp::server::MCPToolHandler for GetCommentsTool {
             .ok_or_else(|| {
                 crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
+        let filter = CommentFilter::from_args(&args)?;
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
+        let json_output = match args.get("format").and_then(|v| v.as_str()) {
+            None | Some("text") => false,
+            Some("json") => true,
+            Some(other) => {
+                return Err(JiraError::validation_error(
+                    "format",
+                    &format!("Unknown format '{other}'; expected 'text' or 'json'"),
+                ))
+            }
+        };
 
         info!("Getting comments for issue: {}", issue_key);
 
-        let comments = self.client.get_comments(issue_key).await?;
+        // The comment endpoint has no date parameter (unlike `startedAfter`
+        // on worklogs), so every page is fetched and filtered here. Service
+        // desk comments are marked public or internal, which Data Center
+        // keeps in a comment property
+        let service_desk = self
+            .client
+            .is_service_desk_issue(issue_key)
+            .await
+            .unwrap_or_else(|e| {
+                debug!("Could not read the project type of {}: {}", issue_key, e);
+                false
+            });
+        let all = if service_desk {
+            self.client
+                .get_all_comments_with_properties(issue_key)
+                .await?
+        } else {
+            self.client.get_all_comments(issue_key).await?
+        };
+        let total = all.len();
+        let comments: Vec<serde_json::Value> =
+            all.into_iter().filter(|c| filter.matches(c)).collect();
 
-        let mut content = vec![MCPContent::text(format!(
-            "Found {} comments for issue {}\n\n",
-            comments.len(),
-            issue_key
-        ))];
+        let header = if filter.is_empty() {
+            format!("Found {total} comments for issue {issue_key}\n\n")
+        } else {
+            format!(
+                "Found {} of {total} comments for issue {issue_key} {}\n\n",
+                comments.len(),
+                filter.describe()
+            )
+        };
+        let mut content = vec![MCPContent::text(header)];
+
+        if json_output {
+            content.push(MCPContent::json(&comments));
+            return Ok(MCPToolResult {
+                content,
+                is_error: Some(false),
+            });
+        }
 
-        for comment in comments {
-            let author = comment.author.display_name;
-            let created = comment.created;
-            let body = comment.body;
+        let dates = DateFormatter::from_config(self.client.config());
+        let now = Utc::now();
 
-            let comment_text = format!("• {} by {} on {}\n{}\n", comment.id, author, created, body);
+        for raw in &comments {
+            let comment: JiraComment =
+                serde_json::from_value(raw.clone()).map_err(JiraError::SerializationError)?;
+            let author = &comment.author;
+            let audience = match (service_desk, comment_is_public(raw)) {
+                (false, _) => "",
+                (true, true) => " [public]",
+                (true, false) => " [internal]",
+            };
+            if output.is_minimal() {
+                content[0].text.push_str(&output.item_line(
+                    &comment.id,
+                    &format!("{}{audience}: {}", author.label(), comment.body),
+                ));
+                continue;
+            }
+            let account = author.account_id.as_deref().unwrap_or(&author.name);
+            let age = parse_timestamp(&comment.created)
+                .map(|created| format!(" ({})", relative_time(&created, now)))
+                .unwrap_or_default();
+            let body = output.fields().format_field(
+                &comment.body,
+                issue_key,
+                &format!("comment:{}", comment.id),
+            );
+            let restriction = comment
+                .visibility
+                .map(|v| format!(" [restricted to {v}]"))
+                .unwrap_or_default();
+
+            let comment_text = format!(
+                "• {} by {} ({account}) on {}{age}{audience}{restriction}\n{body}\n",
+                comment.id,
+                author.label(),
+                dates.format_timestamp(&comment.created)
+            );
             content.push(MCPContent::text(comment_text));
         }
+        output.push_raw(&mut content, &comments);
 
         Ok(MCPToolResult {
             content,
@@ -56,6 +219,47 @@ impl crate::mcp::server::MCPToolHandler for GetCommentsTool {
     }
 }
 
+/// Apply the `on_behalf_of` argument of a comment or work log tool to `text`.
+///
+/// Returns the text to write and the display name of the user it is written
+/// for; `text` is returned unchanged when the argument is absent.
+pub(crate) async fn attribute_text(
+    client: &JiraClient,
+    args: &serde_json::Value,
+    text: &str,
+) -> Result<(String, Option<String>)> {
+    let Some(on_behalf_of) = args
+        .get("on_behalf_of")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    else {
+        return Ok((text.to_string(), None));
+    };
+    let (text, user) = client.attribute_on_behalf(text, on_behalf_of).await?;
+    Ok((text, Some(user.display_name)))
+}
+
+/// The body a comment or work log tool writes for `text`: converted as its
+/// `input_format` argument says, then attributed as [`attribute_text`] does.
+///
+/// Returns the body and the display name of the user it is written for. The
+/// body is a string, or an ADF document for Markdown on REST API v3.
+pub(crate) async fn rich_text_body(
+    client: &JiraClient,
+    args: &serde_json::Value,
+    text: &str,
+) -> Result<(serde_json::Value, Option<String>)> {
+    let format = InputFormat::from_args(args, client.config())?;
+    if format == InputFormat::Markdown && client.config().is_api_v3() {
+        // The attribution line is italic in Markdown as well
+        let (text, on_behalf_of) = attribute_text(client, args, text).await?;
+        return Ok((markdown_to_adf(&text), on_behalf_of));
+    }
+    let (text, on_behalf_of) = attribute_text(client, args, &format.wiki(text)).await?;
+    Ok((text.into(), on_behalf_of))
+}
+
 /// Add a comment to a Jira issue
 pub struct AddCommentTool {
     client: JiraClient,
@@ -89,17 +293,167 @@ impl crate::mcp::server::MCPToolHandler for AddCommentTool {
                 crate::error::JiraError::api_error("Missing required parameter: comment")
             })?;
 
+        let visibility = JiraCommentVisibility::from_arg(args.get("visibility"))?;
+        let resolve_mentions = args
+            .get("resolve_mentions")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let public = args.get("public").and_then(serde_json::Value::as_bool);
+
         info!("Adding comment to issue: {}", issue_key);
 
-        let created_comment = self.client.add_comment(issue_key, comment).await?;
+        let (body, on_behalf_of) = rich_text_body(&self.client, &args, comment).await?;
+        let mentions = match (resolve_mentions, body.as_str()) {
+            (false, _) => None,
+            (true, Some(text)) => Some(self.client.resolve_mentions(text).await?),
+            (true, None) => {
+                return Err(JiraError::validation_error(
+                    "resolve_mentions",
+                    "cannot be combined with Markdown input on REST API v3",
+                ))
+            }
+        };
+        let body = mentions.as_ref().map_or(body, |m| m.body.clone());
+
+        // Comments on service desk issues stay internal unless asked
+        // otherwise, so notes meant for agents do not reach the customer.
+        // When the project type cannot be read the comment still carries
+        // the internal property, which other projects ignore
+        let service_desk = match self.client.is_service_desk_issue(issue_key).await {
+            Ok(service_desk) => Some(service_desk),
+            Err(e) => {
+                warn!(
+                    "Could not read the project type of {}, marking the comment internal: {}",
+                    issue_key, e
+                );
+                None
+            }
+        };
+        let public = public
+            .filter(|_| service_desk != Some(false))
+            .unwrap_or(false);
+        let created_comment = match service_desk {
+            Some(true) => {
+                self.client
+                    .add_service_desk_comment(issue_key, body, visibility.as_ref(), public)
+                    .await?
+            }
+            Some(false) => {
+                self.client
+                    .add_comment_with_visibility(issue_key, body, visibility.as_ref())
+                    .await?
+            }
+            None => {
+                self.client
+                    .add_comment_with_public_flag(issue_key, body, visibility.as_ref(), public)
+                    .await?
+            }
+        };
 
-        let response_text = format!(
+        let mut response_text = format!(
             "Comment added successfully to issue {}\nComment ID: {}\nAuthor: {}\nCreated: {}",
             issue_key,
             created_comment.id,
-            created_comment.author.display_name,
-            created_comment.created
+            created_comment.author.label(),
+            DateFormatter::from_config(self.client.config())
+                .format_timestamp(&created_comment.created)
+        );
+        if let Some(name) = on_behalf_of {
+            response_text.push_str(&format!("\nOn behalf of: {name}"));
+        }
+        if let Some(note) = self.client.signature_note() {
+            response_text.push_str(&format!("\n{note}"));
+        }
+        match (service_desk, public) {
+            (Some(false), _) => {}
+            (Some(true), true) => response_text.push_str("\nShared with the customer: yes (public)"),
+            (Some(true), false) => {
+                response_text.push_str("\nShared with the customer: no (internal)");
+            }
+            (None, true) => response_text.push_str(
+                "\nShared with the customer: yes (public, if this is a service desk project)",
+            ),
+            (None, false) => response_text.push_str(
+                "\nShared with the customer: no (marked internal; the project type could not be read)",
+            ),
+        }
+        if let Some(visibility) = visibility {
+            response_text.push_str(&format!("\nVisibility: restricted to {visibility}"));
+        }
+        if let Some(report) = mentions.and_then(|m| m.report()) {
+            response_text.push_str(&format!("\n{report}"));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Update an existing comment on a Jira issue
+pub struct UpdateCommentTool {
+    client: JiraClient,
+}
+
+impl UpdateCommentTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for UpdateCommentTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = args
+            .get("issue_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: issue_key")
+            })?;
+
+        let comment_id = args
+            .get("comment_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: comment_id")
+            })?;
+
+        let comment = args
+            .get("comment")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: comment")
+            })?;
+
+        let visibility = JiraCommentVisibility::from_arg(args.get("visibility"))?;
+
+        info!("Updating comment {} on issue: {}", comment_id, issue_key);
+
+        let (comment, on_behalf_of) = attribute_text(&self.client, &args, comment).await?;
+        let updated_comment = self
+            .client
+            .update_comment(issue_key, comment_id, &comment, visibility.as_ref())
+            .await?;
+
+        let mut response_text = format!(
+            "Comment {} updated successfully on issue {}",
+            updated_comment.id, issue_key
         );
+        if let Some(name) = on_behalf_of {
+            response_text.push_str(&format!("\nOn behalf of: {name}"));
+        }
+        if let Some(note) = self.client.signature_note() {
+            response_text.push_str(&format!("\n{note}"));
+        }
+        if let Some(visibility) = updated_comment.visibility.or(visibility) {
+            response_text.push_str(&format!("\nVisibility: restricted to {visibility}"));
+        }
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
@@ -1,6 +1,7 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
+use crate::mcp::tools::projects::ProjectArg;
 use crate::types::mcp::{MCPContent, MCPToolResult};
 use tracing::info;
 
@@ -27,12 +28,8 @@ impl crate::mcp::server::MCPToolHandler for CreateComponentTool {
             crate::error::JiraError::api_error("Missing required parameter: name")
         })?;
 
-        let project = args
-            .get("project")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: project")
-            })?;
+        let project_arg = ProjectArg::from_args(&args, "project", self.client.config())?;
+        let project = project_arg.key.as_str();
 
         let description = args.get("description").and_then(|v| v.as_str());
         let lead_account_id = args.get("lead_account_id").and_then(|v| v.as_str());
@@ -50,8 +47,10 @@ impl crate::mcp::server::MCPToolHandler for CreateComponentTool {
         let created_component = self.client.create_component(&component_request).await?;
 
         let response_text = format!(
-            "Component created successfully: {} for project {}",
-            created_component.name, project
+            "{}Component created successfully: {} for project {}",
+            project_arg.note(),
+            created_component.name,
+            project
         );
 
         Ok(MCPToolResult {
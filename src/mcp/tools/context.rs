@@ -0,0 +1,335 @@
+//! Session context: the project, board, sprint and issue a conversation is
+//! about, set once with `set_jira_context` and used by later tool calls that
+//! leave the matching argument out.
+//!
+//! The context belongs to one [`crate::mcp::server::MCPServer`], so it lasts
+//! as long as the connection and is never shared between clients. Arguments
+//! a tool requires are filled in by the server before the call (see
+//! [`SessionContext::fill_arguments`]); project arguments that fall back to
+//! `JIRA_DEFAULT_PROJECT` read the session project first (see
+//! [`crate::mcp::tools::projects::ProjectArg`]). Either way an explicit
+//! argument wins over the session context, which wins over the configuration.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+use crate::jira::client::JiraClient;
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::{Arc, RwLock};
+use tracing::info;
+
+/// Argument the server passes the session project in, for tools whose
+/// project argument is optional
+pub const SESSION_PROJECT_ARG: &str = "_session_project";
+
+/// The context entries and the tool arguments each one fills
+const CONTEXT_ARGUMENTS: [(&str, &[&str]); 4] = [
+    ("project", &["project_key", "project"]),
+    ("board", &["board_id"]),
+    ("sprint", &["sprint_id"]),
+    ("issue", &["issue_key", "issue_id_or_key"]),
+];
+
+/// What the conversation is about; every entry is optional
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionContext {
+    pub project: Option<String>,
+    pub board: Option<i64>,
+    pub sprint: Option<i64>,
+    pub issue: Option<String>,
+}
+
+/// Session context shared by the server and the context tools
+pub type SharedSessionContext = Arc<RwLock<SessionContext>>;
+
+impl SessionContext {
+    /// Value of the entry `name` as a tool argument
+    fn argument(&self, name: &str) -> Option<Value> {
+        match name {
+            "project" => self.project.clone().map(Value::from),
+            "board" => self.board.map(Value::from),
+            "sprint" => self.sprint.map(Value::from),
+            "issue" => self.issue.clone().map(Value::from),
+            _ => None,
+        }
+    }
+
+    /// Whether no entry is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Fill the arguments listed in the `required` of `input_schema` that
+    /// `args` leaves out from the context, and pass the session project to
+    /// tools with an optional project argument. Returns a note for each
+    /// argument filled, e.g. "Using session issue PROJ-1 (set_jira_context)".
+    pub fn fill_arguments(&self, args: &mut Value, input_schema: &Value) -> Vec<String> {
+        let mut notes = Vec::new();
+        let Some(args) = args.as_object_mut() else {
+            return notes;
+        };
+        let required: Vec<&str> = input_schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let declared = |name: &str| {
+            input_schema
+                .pointer(&format!("/properties/{name}"))
+                .is_some()
+        };
+        let omitted = |args: &serde_json::Map<String, Value>, name: &str| {
+            args.get(name)
+                .is_none_or(|v| v.is_null() || v.as_str().is_some_and(str::is_empty))
+        };
+
+        for (entry, names) in CONTEXT_ARGUMENTS {
+            let Some(value) = self.argument(entry) else {
+                continue;
+            };
+            for name in names {
+                if !declared(name) || !omitted(args, name) {
+                    continue;
+                }
+                if required.contains(name) {
+                    notes.push(format!(
+                        "Using session {entry} {} (set_jira_context)",
+                        display(&value)
+                    ));
+                    args.insert((*name).to_string(), value.clone());
+                } else if entry == "project" {
+                    args.insert(SESSION_PROJECT_ARG.to_string(), value.clone());
+                }
+            }
+        }
+        notes
+    }
+
+    /// Remove the arguments the context can fill from the `required` list of
+    /// a tool's `input_schema`, so clients may leave them out
+    pub fn relax_required(&self, input_schema: &mut Value) {
+        let fillable: Vec<&str> = CONTEXT_ARGUMENTS
+            .iter()
+            .filter(|(entry, _)| self.argument(entry).is_some())
+            .flat_map(|(_, names)| names.iter().copied())
+            .collect();
+        if let Some(required) = input_schema
+            .get_mut("required")
+            .and_then(Value::as_array_mut)
+        {
+            required.retain(|name| name.as_str().is_none_or(|name| !fillable.contains(&name)));
+        }
+    }
+
+    /// One line per entry, e.g. "project: PROJ", or a note that none is set
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let lines: Vec<String> = CONTEXT_ARGUMENTS
+            .iter()
+            .filter_map(|(entry, _)| {
+                self.argument(entry)
+                    .map(|value| format!("{entry}: {}", display(&value)))
+            })
+            .collect();
+        if lines.is_empty() {
+            "No session context is set".to_string()
+        } else {
+            lines.join("\n")
+        }
+    }
+}
+
+fn display(value: &Value) -> String {
+    value
+        .as_str()
+        .map_or_else(|| value.to_string(), ToString::to_string)
+}
+
+/// Read the shared context, recovering it if a writer panicked
+#[must_use]
+pub fn read_context(context: &SharedSessionContext) -> SessionContext {
+    context
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// What an argument of `set_jira_context` asks for
+enum Change<T> {
+    Keep,
+    Clear,
+    Set(T),
+}
+
+fn text_change(args: &Value, name: &str) -> Result<Change<String>> {
+    match args.get(name) {
+        None => Ok(Change::Keep),
+        Some(Value::Null) => Ok(Change::Clear),
+        Some(Value::String(text)) if text.trim().is_empty() => Ok(Change::Clear),
+        Some(Value::String(text)) => Ok(Change::Set(text.trim().to_string())),
+        Some(_) => Err(JiraError::validation_error(
+            name,
+            "must be a string, or null or \"\" to clear it",
+        )),
+    }
+}
+
+fn id_change(args: &Value, name: &str) -> Result<Change<i64>> {
+    match args.get(name) {
+        None => Ok(Change::Keep),
+        Some(Value::Null) => Ok(Change::Clear),
+        Some(Value::String(text)) if text.trim().is_empty() => Ok(Change::Clear),
+        Some(value) => value
+            .as_i64()
+            .or_else(|| value.as_str().and_then(|text| text.trim().parse().ok()))
+            .filter(|id| *id > 0)
+            .map(Change::Set)
+            .ok_or_else(|| {
+                JiraError::validation_error(
+                    name,
+                    "must be a positive id, or null or \"\" to clear it",
+                )
+            }),
+    }
+}
+
+/// Set the project, board, sprint and issue later tool calls default to
+pub struct SetJiraContextTool {
+    client: JiraClient,
+    context: SharedSessionContext,
+}
+
+impl SetJiraContextTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig, context: SharedSessionContext) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+            context,
+        }
+    }
+
+    /// The key of `project` as Jira spells it
+    async fn check_project(&self, project: &str) -> Result<String> {
+        let found: Value = self
+            .client
+            .get(&format!("project/{}", urlencoding::encode(project)))
+            .await
+            .map_err(|e| {
+                JiraError::validation_error("project", &format!("Project {project}: {e}"))
+            })?;
+        Ok(found
+            .get("key")
+            .and_then(Value::as_str)
+            .unwrap_or(project)
+            .to_string())
+    }
+
+    /// The current key of `issue`, following moves
+    async fn check_issue(&self, issue: &str) -> Result<String> {
+        let found =
+            self.client.get_issue(issue).await.map_err(|e| {
+                JiraError::validation_error("issue", &format!("Issue {issue}: {e}"))
+            })?;
+        Ok(found.key)
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for SetJiraContextTool {
+    async fn handle(&self, args: Value) -> Result<MCPToolResult> {
+        let clear_all = args.get("clear").and_then(Value::as_bool).unwrap_or(false);
+        let project = text_change(&args, "project")?;
+        let issue = text_change(&args, "issue")?;
+        let board = id_change(&args, "board")?;
+        let sprint = id_change(&args, "sprint")?;
+
+        // Everything is checked before anything is changed
+        let project = match project {
+            Change::Set(key) => Change::Set(self.check_project(&key).await?),
+            other => other,
+        };
+        let issue = match issue {
+            Change::Set(key) => Change::Set(self.check_issue(&key).await?),
+            other => other,
+        };
+        if let Change::Set(id) = board {
+            self.client
+                .get_board(id)
+                .await
+                .map_err(|e| JiraError::validation_error("board", &format!("Board {id}: {e}")))?;
+        }
+        if let Change::Set(id) = sprint {
+            let sprint_id = i32::try_from(id).map_err(|_| {
+                JiraError::validation_error("sprint", &format!("Sprint {id} does not exist"))
+            })?;
+            self.client
+                .get_sprint(sprint_id)
+                .await
+                .map_err(|e| JiraError::validation_error("sprint", &format!("Sprint {id}: {e}")))?;
+        }
+
+        let updated = {
+            let mut context = self
+                .context
+                .write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if clear_all {
+                *context = SessionContext::default();
+            }
+            apply(&mut context.project, project);
+            apply(&mut context.issue, issue);
+            apply(&mut context.board, board);
+            apply(&mut context.sprint, sprint);
+            context.clone()
+        };
+        info!("Session context is now {:?}", updated);
+
+        Ok(MCPToolResult {
+            content: vec![
+                MCPContent::text(format!("Session context updated\n{}", updated.describe())),
+                MCPContent::json(&updated),
+            ],
+            is_error: Some(false),
+        })
+    }
+}
+
+fn apply<T>(entry: &mut Option<T>, change: Change<T>) {
+    match change {
+        Change::Keep => {}
+        Change::Clear => *entry = None,
+        Change::Set(value) => *entry = Some(value),
+    }
+}
+
+/// Show the session context
+pub struct GetJiraContextTool {
+    context: SharedSessionContext,
+}
+
+impl GetJiraContextTool {
+    #[must_use]
+    pub fn new(context: SharedSessionContext) -> Self {
+        Self { context }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetJiraContextTool {
+    async fn handle(&self, _args: Value) -> Result<MCPToolResult> {
+        let context = read_context(&self.context);
+        Ok(MCPToolResult {
+            content: vec![
+                MCPContent::text(context.describe()),
+                MCPContent::json(&context),
+            ],
+            is_error: Some(false),
+        })
+    }
+}
@@ -0,0 +1,74 @@
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+use crate::jira::error_explain::{explain, JiraErrorReport};
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use tracing::info;
+
+/// Explain a Jira error: what it means, its likely causes and what to change
+pub struct ExplainJiraErrorTool;
+
+impl ExplainJiraErrorTool {
+    #[must_use]
+    pub fn new(_config: JiraConfig) -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ExplainJiraErrorTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let status = args
+            .get("status")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|status| u16::try_from(status).ok());
+        let report = match (args.get("error"), args.get("body")) {
+            (Some(error), _) => {
+                let mut report = JiraErrorReport::from_tool_error(error);
+                if status.is_some() {
+                    report.status = status;
+                }
+                report
+            }
+            (None, Some(body)) => JiraErrorReport::from_body(status, body),
+            (None, None) => {
+                return Err(JiraError::api_error(
+                    "Missing required parameter: error, or body with status",
+                ))
+            }
+        };
+
+        info!("Explaining Jira error with status {:?}", report.status);
+
+        let explanation = explain(&report);
+        let mut text = match explanation.status {
+            Some(status) => format!("Jira error (HTTP {status})"),
+            None => "Jira error".to_string(),
+        };
+        for finding in &explanation.findings {
+            text.push_str("\n\n• ");
+            if let Some(field) = &finding.field {
+                text.push_str(&format!("{field}: "));
+            }
+            text.push_str(finding.message.as_deref().unwrap_or("(no message)"));
+            text.push_str(&format!(
+                "\n  What it means: {} [{}]",
+                finding.explanation, finding.rule
+            ));
+            if !finding.likely_causes.is_empty() {
+                text.push_str("\n  Likely causes:");
+                for cause in &finding.likely_causes {
+                    text.push_str(&format!("\n  - {cause}"));
+                }
+            }
+            if let Some(permission) = finding.permission {
+                text.push_str(&format!("\n  Permission: {permission}"));
+            }
+            text.push_str(&format!("\n  Fix: {}", finding.remediation));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&explanation)],
+            is_error: Some(false),
+        })
+    }
+}
@@ -0,0 +1,250 @@
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+use crate::jira::client::JiraClient;
+use crate::jira::events::{jira_events, JiraEventFilter, JiraEventType};
+use crate::jira::webhooks::{CLOUD_WEBHOOK_LIFETIME_DAYS, DEFAULT_WEBHOOK_EVENTS};
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use tracing::info;
+
+/// Events returned when no `limit` is given
+const DEFAULT_EVENT_LIMIT: usize = 20;
+
+/// List the Jira webhook events received most recently, newest first
+pub struct GetRecentJiraEventsTool {
+    config: JiraConfig,
+}
+
+impl GetRecentJiraEventsTool {
+    #[must_use]
+    pub fn new(config: JiraConfig) -> Self {
+        Self { config }
+    }
+}
+
+/// The strings of `field`, given as one string or an array of them
+///
+/// # Errors
+///
+/// Returns a validation error if `field` holds anything else
+pub fn string_list(args: &serde_json::Value, field: &str) -> Result<Vec<String>> {
+    match args.get(field) {
+        None | Some(serde_json::Value::Null) => Ok(Vec::new()),
+        Some(serde_json::Value::String(value)) => Ok(vec![value.clone()]),
+        Some(serde_json::Value::Array(values)) => values
+            .iter()
+            .map(|value| {
+                value.as_str().map(ToString::to_string).ok_or_else(|| {
+                    JiraError::validation_error(field, &format!("{field} must hold strings"))
+                })
+            })
+            .collect(),
+        Some(_) => Err(JiraError::validation_error(
+            field,
+            &format!("{field} must be a string or an array of strings"),
+        )),
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetRecentJiraEventsTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let event_types = string_list(&args, "event_type")?
+            .iter()
+            .map(|name| {
+                JiraEventType::from_name(name).ok_or_else(|| {
+                    let known: Vec<&str> =
+                        JiraEventType::ALL.iter().map(|kind| kind.name()).collect();
+                    JiraError::validation_error(
+                        "event_type",
+                        &format!(
+                            "Unknown event type '{name}'; use one of {}",
+                            known.join(", ")
+                        ),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let filter = JiraEventFilter {
+            project_keys: string_list(&args, "project_key")?,
+            event_types,
+            issue_key: args
+                .get("issue_key")
+                .and_then(|v| v.as_str())
+                .map(ToString::to_string),
+            after_sequence: args
+                .get("after_sequence")
+                .and_then(serde_json::Value::as_u64),
+        };
+        let limit = args
+            .get("limit")
+            .and_then(serde_json::Value::as_u64)
+            .map_or(DEFAULT_EVENT_LIMIT, |limit| {
+                usize::try_from(limit).unwrap_or(usize::MAX)
+            });
+
+        info!("Getting recent Jira events");
+
+        if self.config.webhook_listen_addr.is_none() {
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(
+                    "No webhook events are received: set JIRA_WEBHOOK_LISTEN_ADDR and JIRA_WEBHOOK_SECRET and register the receiver's /webhooks/jira URL as a webhook in Jira".to_string(),
+                )],
+                is_error: Some(true),
+            });
+        }
+
+        let buffer = jira_events();
+        let events = buffer.recent(&filter, limit);
+        let mut text = if events.is_empty() {
+            "No matching webhook events received".to_string()
+        } else {
+            format!("{} webhook events, newest first:", events.len())
+        };
+        for event in &events {
+            text.push_str(&format!(
+                "\n• {} at {}",
+                event.headline(),
+                event.timestamp.format("%Y-%m-%d %H:%M:%S UTC")
+            ));
+        }
+        if buffer.evicted() > 0 {
+            text.push_str(&format!(
+                "\n\nOnly the last {} events are kept; {} older ones were dropped.",
+                buffer.capacity(),
+                buffer.evicted()
+            ));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&events)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// List the webhooks registered with Jira
+pub struct ListWebhooksTool {
+    client: JiraClient,
+}
+
+impl ListWebhooksTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ListWebhooksTool {
+    async fn handle(&self, _args: serde_json::Value) -> Result<MCPToolResult> {
+        info!("Listing webhooks");
+
+        let webhooks = self.client.list_webhooks().await?;
+        let mut text = if webhooks.is_empty() {
+            "No webhooks registered".to_string()
+        } else {
+            format!("{} webhooks registered:", webhooks.len())
+        };
+        for webhook in &webhooks {
+            text.push_str(&format!("\n• {}", webhook.headline()));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&webhooks)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Register a webhook that posts to this server's webhook receiver
+pub struct RegisterWebhookTool {
+    config: JiraConfig,
+    client: JiraClient,
+}
+
+impl RegisterWebhookTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config.clone()).expect("Failed to create JiraClient"),
+            config,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for RegisterWebhookTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let url = self.config.webhook_registration_url()?;
+        let mut events = string_list(&args, "events")?;
+        if events.is_empty() {
+            events = DEFAULT_WEBHOOK_EVENTS.map(String::from).to_vec();
+        }
+        let jql_filter = args.get("jql_filter").and_then(|v| v.as_str());
+
+        info!("Registering a webhook for {:?}", events);
+
+        let id = self
+            .client
+            .register_webhook(&url, &events, jql_filter)
+            .await?;
+
+        let mut text = format!("Registered webhook {id} for {}", events.join(", "));
+        if let Some(jql) = jql_filter {
+            text.push_str(&format!(" on issues matching {jql}"));
+        }
+        if self.config.is_cloud() {
+            text.push_str(&format!(
+                ". It expires in {CLOUD_WEBHOOK_LIFETIME_DAYS} days unless refreshed, which the server does while it runs"
+            ));
+        }
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Delete a registered webhook
+pub struct DeleteWebhookTool {
+    client: JiraClient,
+}
+
+impl DeleteWebhookTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for DeleteWebhookTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let id = args
+            .get("webhook_id")
+            .and_then(serde_json::Value::as_i64)
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: webhook_id"))?;
+
+        info!("Deleting webhook {}", id);
+
+        self.client.delete_webhook(id).await?;
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(format!("Deleted webhook {id}"))],
+            is_error: Some(false),
+        })
+    }
+}
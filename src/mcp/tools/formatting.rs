@@ -0,0 +1,339 @@
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+use crate::types::mcp::MCPContent;
+
+/// Default number of characters of a text field shown in tool output
+pub const DEFAULT_MAX_FIELD_OUTPUT_CHARS: usize = 4000;
+
+/// Shortens long text fields (descriptions, comment bodies, ...) before they are
+/// inlined into tool output, so one pasted log can't flood the client's context.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldFormatter {
+    max_chars: Option<usize>,
+}
+
+impl FieldFormatter {
+    /// Create a formatter keeping at most `max_chars` characters per field.
+    /// A limit of 0 disables truncation.
+    #[must_use]
+    pub fn new(max_chars: usize) -> Self {
+        Self {
+            max_chars: (max_chars > 0).then_some(max_chars),
+        }
+    }
+
+    /// Create a formatter using `max_field_output_chars` from the configuration
+    #[must_use]
+    pub fn from_config(config: &JiraConfig) -> Self {
+        Self::new(
+            config
+                .max_field_output_chars
+                .unwrap_or(DEFAULT_MAX_FIELD_OUTPUT_CHARS),
+        )
+    }
+
+    /// Format the `field` text of `issue_key`, truncating it with a marker that
+    /// says how much was left out and how to fetch the whole value.
+    #[must_use]
+    pub fn format_field(&self, text: &str, issue_key: &str, field: &str) -> String {
+        let Some((kept, omitted)) = self.max_chars.and_then(|max| truncate_chars(text, max)) else {
+            return text.to_string();
+        };
+
+        format!(
+            "{kept}\n[… truncated: {omitted} more characters. Use get_jira_issue_field with \
+             issue_key \"{issue_key}\" and field \"{field}\" to read it in full]"
+        )
+    }
+}
+
+impl Default for FieldFormatter {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_FIELD_OUTPUT_CHARS)
+    }
+}
+
+/// How much tool output says about each item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Verbosity {
+    /// One `KEY — summary` line per item, without descriptions or bodies
+    Minimal,
+    /// The usual text output
+    #[default]
+    Normal,
+    /// The usual text output followed by the raw JSON it was made from
+    Detailed,
+}
+
+impl Verbosity {
+    /// Parse `minimal`, `normal` or `detailed`, ignoring case
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "minimal" => Some(Self::Minimal),
+            "normal" => Some(Self::Normal),
+            "detailed" => Some(Self::Detailed),
+            _ => None,
+        }
+    }
+}
+
+/// Characters of the text after the key in a minimal line
+pub const MINIMAL_LINE_MAX_CHARS: usize = 80;
+
+/// Shapes tool output at the verbosity the call asks for with `verbosity`,
+/// or else the configured `output_verbosity`, and shortens long fields with
+/// a [`FieldFormatter`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResponseFormatter {
+    verbosity: Verbosity,
+    fields: FieldFormatter,
+}
+
+impl ResponseFormatter {
+    #[must_use]
+    pub fn new(verbosity: Verbosity, fields: FieldFormatter) -> Self {
+        Self { verbosity, fields }
+    }
+
+    /// Create a formatter using `output_verbosity` and
+    /// `max_field_output_chars` from the configuration. `JiraConfig::validate`
+    /// reports an unknown verbosity; here it falls back to normal.
+    #[must_use]
+    pub fn from_config(config: &JiraConfig) -> Self {
+        Self::new(
+            config
+                .output_verbosity
+                .as_deref()
+                .and_then(Verbosity::parse)
+                .unwrap_or_default(),
+            FieldFormatter::from_config(config),
+        )
+    }
+
+    /// Create a formatter for a tool call, its `verbosity` argument taking
+    /// precedence over the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `verbosity` is not `minimal`, `normal` or
+    /// `detailed`.
+    pub fn from_args(args: &serde_json::Value, config: &JiraConfig) -> Result<Self> {
+        let formatter = Self::from_config(config);
+        match args.get("verbosity").and_then(|v| v.as_str()) {
+            None => Ok(formatter),
+            Some(value) => Verbosity::parse(value)
+                .map(|verbosity| Self {
+                    verbosity,
+                    ..formatter
+                })
+                .ok_or_else(|| {
+                    JiraError::validation_error(
+                        "verbosity",
+                        &format!(
+                            "Unknown verbosity '{value}'; expected minimal, normal or detailed"
+                        ),
+                    )
+                }),
+        }
+    }
+
+    #[must_use]
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    #[must_use]
+    pub fn is_minimal(&self) -> bool {
+        self.verbosity == Verbosity::Minimal
+    }
+
+    #[must_use]
+    pub fn is_detailed(&self) -> bool {
+        self.verbosity == Verbosity::Detailed
+    }
+
+    /// The formatter of long text fields
+    #[must_use]
+    pub fn fields(&self) -> &FieldFormatter {
+        &self.fields
+    }
+
+    /// Minimal line of one item: `KEY — text`, the text flattened onto one
+    /// line and cut to [`MINIMAL_LINE_MAX_CHARS`]
+    #[must_use]
+    pub fn item_line(&self, key: &str, text: &str) -> String {
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let text = match truncate_chars(&text, MINIMAL_LINE_MAX_CHARS) {
+            Some((kept, _)) => format!("{kept}…"),
+            None => text,
+        };
+        format!("{key} — {text}\n")
+    }
+
+    /// Append `value` as a JSON block when the output is detailed
+    pub fn push_raw<T: serde::Serialize>(&self, content: &mut Vec<MCPContent>, value: &T) {
+        if self.is_detailed() {
+            content.push(MCPContent::json(value));
+        }
+    }
+}
+
+/// Cut `text` down to at most `max_chars` characters.
+///
+/// Returns `None` if the text already fits, otherwise the kept prefix and the
+/// number of characters dropped. The cut never splits a UTF-8 character, and
+/// backs up to a line break or space when one is close to the limit so words
+/// and log lines stay whole.
+#[must_use]
+pub fn truncate_chars(text: &str, max_chars: usize) -> Option<(&str, usize)> {
+    let (cut, _) = text.char_indices().nth(max_chars)?;
+    let window = &text[..cut];
+
+    // Only back up within the last fifth of the window
+    let min_cut = window
+        .char_indices()
+        .nth(max_chars - max_chars / 5)
+        .map_or(0, |(i, _)| i);
+    let smart_cut = window
+        .rfind('\n')
+        .filter(|&i| i >= min_cut)
+        .or_else(|| window.rfind(char::is_whitespace).filter(|&i| i >= min_cut))
+        .unwrap_or(cut);
+
+    let kept = window[..smart_cut].trim_end();
+    let omitted = text.chars().count() - kept.chars().count();
+    Some((kept, omitted))
+}
+
+/// Plain text of a rich text value: wiki markup strings are returned as-is and
+/// Atlassian Document Format documents (REST API v3) are flattened.
+///
+/// Returns `None` if the value is neither.
+#[must_use]
+pub fn rich_text(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(text) => Some(text.clone()),
+        serde_json::Value::Object(node)
+            if node.get("type").and_then(|t| t.as_str()) == Some("doc") =>
+        {
+            let mut text = String::new();
+            push_adf_text(value, &mut text);
+            Some(text.trim_end().to_string())
+        }
+        _ => None,
+    }
+}
+
+fn push_adf_text(node: &serde_json::Value, out: &mut String) {
+    match node
+        .get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+    {
+        "text" => out.push_str(
+            node.get("text")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default(),
+        ),
+        "hardBreak" => out.push('\n'),
+        "mention" | "emoji" => out.push_str(
+            node.pointer("/attrs/text")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default(),
+        ),
+        kind => {
+            if kind == "listItem" {
+                out.push_str("- ");
+            }
+            for child in node
+                .get("content")
+                .and_then(|c| c.as_array())
+                .into_iter()
+                .flatten()
+            {
+                push_adf_text(child, out);
+            }
+            let is_block = matches!(
+                kind,
+                "paragraph" | "heading" | "codeBlock" | "blockquote" | "rule" | "tableRow"
+            );
+            if is_block && !out.ends_with('\n') {
+                out.push('\n');
+            }
+        }
+    }
+}
+
+/// Widest a table column gets before its values are cut with `…`
+pub const TABLE_COLUMN_MAX_CHARS: usize = 40;
+
+/// One-line text of a field value for a table cell: the name of users,
+/// statuses and options, the items of lists joined by commas, and rich text
+/// flattened onto a single line
+#[must_use]
+pub fn cell_text(value: &serde_json::Value) -> String {
+    let text = match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Array(items) => {
+            items.iter().map(cell_text).collect::<Vec<_>>().join(", ")
+        }
+        serde_json::Value::Object(object) => rich_text(value)
+            .or_else(|| {
+                ["displayName", "name", "value", "key"]
+                    .iter()
+                    .find_map(|key| object.get(*key).and_then(|v| v.as_str()))
+                    .map(ToString::to_string)
+            })
+            .unwrap_or_else(|| value.to_string()),
+        other => other.to_string(),
+    };
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Render rows as a table with left-aligned columns two spaces apart and a
+/// rule under the header. Columns are as wide as their widest value up to
+/// `max_chars`; longer values are cut to fit and end with `…`.
+#[must_use]
+pub fn render_table(headers: &[String], rows: &[Vec<String>], max_chars: usize) -> String {
+    let max_chars = max_chars.max(2);
+    let fit = |cell: &str| -> String {
+        if cell.chars().count() <= max_chars {
+            cell.to_string()
+        } else {
+            let kept: String = cell.chars().take(max_chars - 1).collect();
+            format!("{}…", kept.trim_end())
+        }
+    };
+    let header: Vec<String> = headers.iter().map(|h| fit(h)).collect();
+    let body: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(|cell| fit(cell)).collect())
+        .collect();
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&body)
+                .filter_map(|row| row.get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let line = |cells: &[String]| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        padded.join("  ").trim_end().to_string()
+    };
+    let rule: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    std::iter::once(line(&header))
+        .chain(std::iter::once(rule.join("  ")))
+        .chain(body.iter().map(|row| line(row)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
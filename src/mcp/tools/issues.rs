@@ -1,7 +1,24 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
+use crate::jira::create_plan::CreationPlan;
+use crate::jira::hierarchy::{IssueParent, IssueTypeLevel};
+use crate::jira::issue_content;
+use crate::jira::issue_update::parse_update;
+use crate::jira::jql::{Jql, SortOrder};
+use crate::jira::moved::ResolvedIssueKey;
+use crate::mcp::tools::formatting::{
+    cell_text, render_table, rich_text, FieldFormatter, ResponseFormatter, TABLE_COLUMN_MAX_CHARS,
+};
+use crate::mcp::tools::pagination::PageArgs;
+use crate::mcp::tools::projects::ProjectArg;
+use crate::types::jira::{IssueActions, JiraIssue, JiraUser};
 use crate::types::mcp::{MCPContent, MCPToolResult};
+use crate::utils::datetime::DateFormatter;
+use crate::utils::markdown::InputFormat;
+use crate::utils::paths::resolve_output_path;
+use crate::utils::snippets::{find_snippets, SNIPPET_CONTEXT_CHARS};
+use futures::{StreamExt, TryStreamExt};
 use serde_json::json;
 use tracing::info;
 
@@ -29,52 +46,183 @@ impl crate::mcp::server::MCPToolHandler for SearchIssuesTool {
             .and_then(|v| v.as_str())
             .ok_or_else(|| crate::error::JiraError::api_error("Missing required parameter: jql"))?;
 
-        let start_at = args
-            .get("start_at")
-            .or_else(|| args.get("startAt")) // Support both snake_case and camelCase
-            .and_then(serde_json::Value::as_i64)
-            .map(|v| i32::try_from(v).unwrap_or(0));
+        let page = PageArgs::from_args(&args, self.client.config())?;
 
-        let max_results = args
-            .get("max_results")
-            .or_else(|| args.get("maxResults")) // Support both snake_case and camelCase
-            .and_then(serde_json::Value::as_i64)
-            .map(|v| i32::try_from(v).unwrap_or(0));
+        let fields: Vec<String> = args
+            .get("fields")
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(ToString::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let bypass_cache = args
+            .get("bypass_cache")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let json_output = match args.get("format").and_then(|v| v.as_str()) {
+            None | Some("text") => false,
+            Some("json") => true,
+            Some(other) => {
+                return Err(crate::error::JiraError::validation_error(
+                    "format",
+                    &format!("Unknown format '{other}'; expected 'text' or 'json'"),
+                ))
+            }
+        };
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
+
+        // Without `fields`, the configured default fields become table columns
+        let mut columns = Vec::new();
+        if fields.is_empty() {
+            for field in self.client.config().default_search_fields() {
+                let definition = self.client.resolve_field(&field).await?;
+                let text = |key: &str| {
+                    definition
+                        .get(key)
+                        .and_then(|v| v.as_str())
+                        .unwrap_or(&field)
+                        .to_string()
+                };
+                columns.push((text("id"), text("name")));
+            }
+        }
+        let fields = if columns.is_empty() {
+            fields
+        } else {
+            std::iter::once("summary".to_string())
+                .chain(columns.iter().map(|(id, _)| id.clone()))
+                .collect()
+        };
 
         info!("Searching Jira issues with JQL: {}", jql);
 
-        let search_result = self
+        let (search_result, cached) = self
             .client
-            .search_issues(jql, start_at, max_results)
+            .search_issues_cached(
+                jql,
+                page.start_at_i32(),
+                page.max_results_i32(),
+                &fields,
+                bypass_cache,
+            )
             .await?;
 
+        if page.is_count_only() {
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(format!(
+                    "{} issues match (count only, since max_results is 0){}\n",
+                    search_result.total,
+                    if cached { " (cached)" } else { "" }
+                ))],
+                is_error: Some(false),
+            });
+        }
+
         let response_text = format!(
-            "Found {} issues (showing {} of {} total)\n\n",
+            "Found {} issues (showing {} of {} total){}\n\n",
             search_result.issues.len(),
             search_result.issues.len(),
-            search_result.total
+            search_result.total,
+            if cached { " (cached)" } else { "" }
         );
 
         let mut content = vec![MCPContent::text(response_text)];
+        if json_output {
+            content.push(MCPContent::json(&search_result.issues));
+            return Ok(MCPToolResult {
+                content,
+                is_error: Some(false),
+            });
+        }
+        if output.is_minimal() {
+            for issue in &search_result.issues {
+                content[0].text.push_str(
+                    &output.item_line(
+                        &issue.key,
+                        issue
+                            .fields
+                            .get("summary")
+                            .and_then(|s| s.as_str())
+                            .unwrap_or("No summary"),
+                    ),
+                );
+            }
+            return Ok(MCPToolResult {
+                content,
+                is_error: Some(false),
+            });
+        }
+        if !columns.is_empty() {
+            let headers: Vec<String> = ["Key", "Summary"]
+                .into_iter()
+                .map(ToString::to_string)
+                .chain(columns.iter().map(|(_, name)| name.clone()))
+                .collect();
+            let rows: Vec<Vec<String>> = search_result
+                .issues
+                .iter()
+                .map(|issue| {
+                    let cell = |id: &str| issue.fields.get(id).map(cell_text).unwrap_or_default();
+                    std::iter::once(issue.key.clone())
+                        .chain(std::iter::once(cell("summary")))
+                        .chain(columns.iter().map(|(id, _)| cell(id)))
+                        .collect()
+                })
+                .collect();
+            content.push(MCPContent::text(render_table(
+                &headers,
+                &rows,
+                TABLE_COLUMN_MAX_CHARS,
+            )));
+            output.push_raw(&mut content, &search_result.issues);
+            return Ok(MCPToolResult {
+                content,
+                is_error: Some(false),
+            });
+        }
+        let formatter = output.fields();
 
-        for issue in search_result.issues {
-            let issue_text = format!(
-                "• {} - {} ({})\n",
+        for issue in &search_result.issues {
+            let mut issue_text = format!(
+                "• {} - {} ({})\n  URL: {}\n",
                 issue.key,
-                issue
-                    .fields
-                    .get("summary")
-                    .and_then(|s| s.as_str())
-                    .unwrap_or("No summary"),
+                formatter.format_field(
+                    issue
+                        .fields
+                        .get("summary")
+                        .and_then(|s| s.as_str())
+                        .unwrap_or("No summary"),
+                    &issue.key,
+                    "summary"
+                ),
                 issue
                     .fields
                     .get("status")
                     .and_then(|s| s.get("name"))
                     .and_then(|n| n.as_str())
-                    .unwrap_or("Unknown status")
+                    .unwrap_or("Unknown status"),
+                self.client.browse_url(&issue.key)
             );
+            // Only present when requested through `fields`
+            if let Some(description) = issue
+                .fields
+                .get("description")
+                .and_then(|d| d.as_str())
+                .filter(|d| !d.is_empty())
+            {
+                issue_text.push_str(&formatter.format_field(
+                    description,
+                    &issue.key,
+                    "description",
+                ));
+                issue_text.push('\n');
+            }
             content.push(MCPContent::text(issue_text));
         }
+        output.push_raw(&mut content, &search_result.issues);
 
         Ok(MCPToolResult {
             content,
@@ -83,12 +231,17 @@ impl crate::mcp::server::MCPToolHandler for SearchIssuesTool {
     }
 }
 
-/// Create a new Jira issue
-pub struct CreateIssueTool {
+/// Most JQL queries `count_jira_issues` takes in one call
+pub const MAX_COUNT_QUERIES: usize = 50;
+/// Count queries `count_jira_issues` keeps in flight at once
+const COUNT_CONCURRENCY: usize = 8;
+
+/// Count the issues matching one or more JQL queries without fetching them
+pub struct CountIssuesTool {
     client: JiraClient,
 }
 
-impl CreateIssueTool {
+impl CountIssuesTool {
     #[must_use]
     /// # Panics
     /// This function does not panic.
@@ -100,54 +253,617 @@ impl CreateIssueTool {
 }
 
 #[async_trait::async_trait]
-impl crate::mcp::server::MCPToolHandler for CreateIssueTool {
+impl crate::mcp::server::MCPToolHandler for CountIssuesTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let queries: Vec<String> = match args.get("jql") {
+            Some(serde_json::Value::String(jql)) => vec![jql.clone()],
+            Some(serde_json::Value::Array(items)) => items
+                .iter()
+                .map(|item| {
+                    item.as_str().map(ToString::to_string).ok_or_else(|| {
+                        crate::error::JiraError::validation_error(
+                            "jql",
+                            "Every entry of jql must be a JQL string",
+                        )
+                    })
+                })
+                .collect::<Result<_>>()?,
+            _ => {
+                return Err(crate::error::JiraError::api_error(
+                    "Missing required parameter: jql",
+                ))
+            }
+        };
+        if queries.is_empty() || queries.iter().any(|jql| jql.trim().is_empty()) {
+            return Err(crate::error::JiraError::validation_error(
+                "jql",
+                "Give at least one query, and no empty ones",
+            ));
+        }
+        if queries.len() > MAX_COUNT_QUERIES {
+            return Err(crate::error::JiraError::validation_error(
+                "jql",
+                &format!(
+                    "{} queries given; count at most {MAX_COUNT_QUERIES} per call",
+                    queries.len()
+                ),
+            ));
+        }
+
+        info!("Counting issues for {} queries", queries.len());
+
+        // One failing query should not lose the counts of the others
+        let client = &self.client;
+        let counts: Vec<Result<u64>> = futures::stream::iter(queries.clone())
+            .map(|jql| async move { client.count_issues(&jql).await })
+            .buffered(COUNT_CONCURRENCY)
+            .collect()
+            .await;
+
+        let text = if let [count] = counts.as_slice() {
+            match count {
+                Ok(count) => format!("{count} issues match: {}", queries[0]),
+                Err(e) => return Err(e.duplicate()),
+            }
+        } else {
+            let mut text = format!("Issue counts for {} queries:\n", queries.len());
+            for (jql, count) in queries.iter().zip(&counts) {
+                match count {
+                    Ok(count) => text.push_str(&format!("• {count} — {jql}\n")),
+                    Err(e) => text.push_str(&format!("• failed ({e}) — {jql}\n")),
+                }
+            }
+            text
+        };
+        let failed = counts.iter().filter(|count| count.is_err()).count();
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text)],
+            is_error: Some(failed == counts.len()),
+        })
+    }
+}
+
+/// Issues scanned by `search_jira_text` unless `max_issues` says otherwise
+pub const DEFAULT_TEXT_SEARCH_ISSUES: usize = 10;
+/// Upper bound on `max_issues` for `search_jira_text`
+pub const TEXT_SEARCH_ISSUE_LIMIT: usize = 50;
+/// Most recent comments scanned per issue by `search_jira_text`
+pub const TEXT_SEARCH_COMMENTS_PER_ISSUE: usize = 50;
+/// Excerpts shown per field by `search_jira_text`
+const TEXT_SEARCH_SNIPPETS_PER_FIELD: usize = 3;
+/// Comment requests `search_jira_text` keeps in flight at once
+const TEXT_SEARCH_CONCURRENCY: usize = 5;
+
+/// Find issues mentioning a string anywhere in their text and show where
+pub struct SearchTextTool {
+    client: JiraClient,
+}
+
+impl SearchTextTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for SearchTextTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
-        let project_key = args
-            .get("project_key")
+        let query = args
+            .get("query")
             .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
             .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: project_key")
+                crate::error::JiraError::api_error("Missing required parameter: query")
             })?;
+        let project_key = args.get("project_key").and_then(|v| v.as_str());
+        let max_issues = args
+            .get("max_issues")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|n| usize::try_from(n).ok())
+            .unwrap_or(DEFAULT_TEXT_SEARCH_ISSUES)
+            .clamp(1, TEXT_SEARCH_ISSUE_LIMIT);
 
-        let issue_type = args
-            .get("issue_type")
+        let mut jql = Jql::new();
+        if let Some(project_key) = project_key {
+            jql = jql.project(project_key);
+        }
+        let jql = jql
+            .text_contains(query)
+            .order_by("updated", SortOrder::Desc)
+            .build();
+
+        info!("Searching Jira text with JQL: {}", jql);
+
+        let search_result = self
+            .client
+            .search_issues_with_fields(
+                &jql,
+                i32::try_from(max_issues).unwrap_or(i32::MAX),
+                &["summary", "description"].map(ToString::to_string),
+            )
+            .await?;
+
+        let client = &self.client;
+        let keys: Vec<String> = search_result
+            .issues
+            .iter()
+            .map(|issue| issue.key.clone())
+            .collect();
+        let comments: Vec<Vec<serde_json::Value>> = futures::stream::iter(keys)
+            .map(|key| async move {
+                client
+                    .get_recent_comments(&key, TEXT_SEARCH_COMMENTS_PER_ISSUE)
+                    .await
+            })
+            .buffered(TEXT_SEARCH_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut content = vec![MCPContent::text(format!(
+            "Found {} issues mentioning \"{query}\" (scanned {}, up to {} recent comments each)\n\n",
+            search_result.total,
+            search_result.issues.len(),
+            TEXT_SEARCH_COMMENTS_PER_ISSUE
+        ))];
+
+        let dates = DateFormatter::from_config(client.config());
+        let snippets = |text: &str| {
+            find_snippets(
+                text,
+                query,
+                SNIPPET_CONTEXT_CHARS,
+                TEXT_SEARCH_SNIPPETS_PER_FIELD,
+            )
+        };
+        for (issue, comments) in search_result.issues.iter().zip(&comments) {
+            let summary = issue
+                .fields
+                .get("summary")
+                .and_then(|s| s.as_str())
+                .unwrap_or("No summary");
+            let mut issue_text = format!("• {} - {}\n", issue.key, summary);
+            let mut matched = false;
+
+            let mut fields = vec![("summary".to_string(), summary.to_string())];
+            if let Some(description) = issue.fields.get("description").and_then(rich_text) {
+                fields.push(("description".to_string(), description));
+            }
+            for comment in comments {
+                let Some(body) = comment.get("body").and_then(rich_text) else {
+                    continue;
+                };
+                let author = comment
+                    .pointer("/author/displayName")
+                    .and_then(|a| a.as_str())
+                    .unwrap_or("Unknown");
+                let created = dates.format_timestamp(
+                    comment
+                        .get("created")
+                        .and_then(|c| c.as_str())
+                        .unwrap_or_default(),
+                );
+                fields.push((format!("comment by {author} on {created}"), body));
+            }
+
+            for (field, text) in &fields {
+                for snippet in snippets(text) {
+                    matched = true;
+                    issue_text.push_str(&format!("  [{field}] {}\n", snippet.excerpt));
+                }
+            }
+            if !matched {
+                issue_text.push_str(
+                    "  Matched by Jira's text search, but not literally in the summary, description or scanned comments\n",
+                );
+            }
+            content.push(MCPContent::text(issue_text));
+        }
+
+        Ok(MCPToolResult {
+            content,
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Most hits listed by `search_issue_content`
+pub const ISSUE_CONTENT_HIT_LIMIT: usize = 100;
+
+/// Find where a string was mentioned in one issue: its description, every
+/// comment and the changelog
+pub struct SearchIssueContentTool {
+    client: JiraClient,
+}
+
+impl SearchIssueContentTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for SearchIssueContentTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = args
+            .get("issue_key")
             .and_then(|v| v.as_str())
             .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: issue_type")
+                crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
-
-        let summary = args
-            .get("summary")
+        let query = args
+            .get("query")
             .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
             .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: summary")
+                crate::error::JiraError::api_error("Missing required parameter: query")
             })?;
 
-        let description = args.get("description").and_then(|v| v.as_str());
+        info!("Searching the content of {} for {:?}", issue_key, query);
 
-        info!("Creating Jira issue: {} - {}", project_key, summary);
+        let issue = self
+            .client
+            .get_issue_fields(
+                issue_key,
+                &["description", "created", "reporter"].map(ToString::to_string),
+            )
+            .await?;
+        let mut hits = issue_content::description_hits(&issue.fields, query);
+        let comments = self
+            .client
+            .visit_comment_pages(issue_key, |page| {
+                hits.extend(
+                    page.iter()
+                        .flat_map(|comment| issue_content::comment_hits(comment, query)),
+                );
+            })
+            .await?;
+        let histories = self
+            .client
+            .visit_changelog_pages(issue_key, |page| {
+                hits.extend(
+                    page.iter()
+                        .flat_map(|history| issue_content::changelog_hits(history, query)),
+                );
+            })
+            .await?;
+        issue_content::sort_hits(&mut hits);
+
+        let searched =
+            format!("description, {comments} comments and {histories} changelog entries searched");
+        if hits.is_empty() {
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(format!(
+                    "No mentions of \"{query}\" in {issue_key} ({searched})"
+                ))],
+                is_error: Some(false),
+            });
+        }
 
-        let issue_data = json!({
-            "fields": {
-                "project": {"key": project_key},
+        let mut text = format!(
+            "Found {} mentions of \"{query}\" in {issue_key} ({searched}), oldest first",
+            hits.len()
+        );
+        let dates = DateFormatter::from_config(self.client.config());
+        for hit in hits.iter().take(ISSUE_CONTENT_HIT_LIMIT) {
+            text.push_str(&format!(
+                "\n• {} by {} in {}: {}",
+                dates.format_timestamp(&hit.timestamp),
+                hit.author,
+                hit.location,
+                hit.snippet
+            ));
+        }
+        if hits.len() > ISSUE_CONTENT_HIT_LIMIT {
+            text.push_str(&format!(
+                "\n… and {} more",
+                hits.len() - ISSUE_CONTENT_HIT_LIMIT
+            ));
+            hits.truncate(ISSUE_CONTENT_HIT_LIMIT);
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&hits)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Create a new Jira issue
+pub struct CreateIssueTool {
+    client: JiraClient,
+}
+
+impl CreateIssueTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+
+    /// Check that the issue type of the new issue may be a child of the type
+    /// of `parent`, so a wrong combination is refused before anything is
+    /// created
+    async fn check_hierarchy(
+        &self,
+        project_key: &str,
+        fields: &serde_json::Map<String, serde_json::Value>,
+        parent: &str,
+    ) -> Result<()> {
+        let Some(issue_type) = fields
+            .get("issuetype")
+            .and_then(|t| t.get("id").or_else(|| t.get("name")))
+            .and_then(|t| t.as_str())
+        else {
+            return Ok(());
+        };
+        let parent_issue = self
+            .client
+            .get_issue_fields(parent, &["issuetype".to_string()])
+            .await?;
+        let Some(parent_type) = parent_issue.fields.get("issuetype") else {
+            return Ok(());
+        };
+        let (parent_type, _) = IssueTypeLevel::from_json(parent_type);
+        let hierarchy = self.client.get_issue_type_hierarchy(project_key).await?;
+        hierarchy.check_parent(issue_type, &parent_type)
+    }
+
+    /// Add create-screen problems to a rejected create, when create-meta can
+    /// be read and shows any; otherwise return `error` unchanged.
+    async fn explain_create_error(
+        &self,
+        error: crate::error::JiraError,
+        project_key: &str,
+        fields: &serde_json::Map<String, serde_json::Value>,
+    ) -> crate::error::JiraError {
+        let crate::error::JiraError::ApiError { message, .. } = &error else {
+            return error;
+        };
+        let Some(issue_type) = fields
+            .get("issuetype")
+            .and_then(|t| t.get("id").or_else(|| t.get("name")))
+            .and_then(|t| t.as_str())
+        else {
+            return error;
+        };
+        let Ok(meta) = self
+            .client
+            .get_create_meta(project_key, issue_type, false)
+            .await
+        else {
+            return error;
+        };
+
+        let problems = JiraClient::create_screen_problems(&meta, fields);
+        if problems.is_empty() {
+            return error;
+        }
+        let rejection = if message.is_empty() {
+            "Jira rejected the issue".to_string()
+        } else {
+            message.clone()
+        };
+        crate::error::JiraError::validation_error(
+            "fields",
+            &format!(
+                "{rejection}. {}. Use list_creatable_fields to see the create screen",
+                problems.join(". ")
+            ),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for CreateIssueTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        // Either a full `fields` object or the individual parameters
+        let mut fields = if let Some(fields) = args.get("fields").and_then(|v| v.as_object()) {
+            fields.clone()
+        } else {
+            let issue_type = args
+                .get("issue_type")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| {
+                    crate::error::JiraError::api_error("Missing required parameter: issue_type")
+                })?;
+
+            let summary = args
+                .get("summary")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| {
+                    crate::error::JiraError::api_error("Missing required parameter: summary")
+                })?;
+
+            let description = args.get("description").and_then(|v| v.as_str());
+
+            json!({
                 "summary": summary,
                 "issuetype": {"name": issue_type},
                 "description": description.unwrap_or("")
-            }
-        });
+            })
+            .as_object()
+            .cloned()
+            .unwrap_or_default()
+        };
 
-        let created_issue = self.client.create_issue(&issue_data).await?;
+        InputFormat::from_args(&args, self.client.config())?
+            .convert_description(&mut fields, self.client.config().is_api_v3());
 
-        let response_text = format!(
-            "Issue created successfully: {} - {}\nURL: {}/browse/{}",
+        let mut project_note = String::new();
+        if !fields.contains_key("project") {
+            let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+            project_note = project.note();
+            fields.insert("project".to_string(), json!({"key": project.key}));
+        }
+
+        let summary = fields
+            .get("summary")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let project_key = fields
+            .get("project")
+            .and_then(|p| p.get("key").or_else(|| p.get("id")))
+            .and_then(|k| k.as_str())
+            .unwrap_or("unknown project")
+            .to_string();
+
+        // A security level given by id or name is checked against the project's levels
+        let security = args
+            .get("security")
+            .or_else(|| fields.get("security"))
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string);
+        if let Some(level) = security {
+            let level = self
+                .client
+                .resolve_security_level(&project_key, &level)
+                .await?;
+            fields.insert("security".to_string(), json!({"id": level.id}));
+        }
+
+        if let Some(parent) = args.get("parent_key").and_then(|v| v.as_str()) {
+            fields.insert("parent".to_string(), json!({"key": parent}));
+        }
+        let parent = fields
+            .get("parent")
+            .and_then(|p| p.get("key").or_else(|| p.get("id")))
+            .and_then(|k| k.as_str())
+            .map(ToString::to_string);
+        if let Some(parent) = parent {
+            self.check_hierarchy(&project_key, &fields, &parent).await?;
+        }
+
+        info!("Creating Jira issue: {} - {}", project_key, summary);
+
+        let issue_data = json!({ "fields": fields });
+
+        let created_issue = match self.client.create_issue(&issue_data).await {
+            Ok(issue) => issue,
+            Err(e) => return Err(self.explain_create_error(e, &project_key, &fields).await),
+        };
+
+        let mut response_text = format!(
+            "{}Issue created successfully: {} - {}\nURL: {}",
+            project_note,
             created_issue.key,
             summary,
-            self.client.api_base_url().replace("/rest/api/2", ""),
-            created_issue.key
+            self.client.browse_url(&created_issue.key)
         );
+        if let Some(note) = self.client.label_note() {
+            response_text.push_str(&format!("\n{note}"));
+        }
+
+        let mut content = vec![MCPContent::text(response_text)];
+        append_returned_issue(&self.client, &args, &created_issue.key, &mut content).await;
 
         Ok(MCPToolResult {
-            content: vec![MCPContent::text(response_text)],
+            content,
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Gather the fields of a new issue over several calls, creating it once the
+/// create screen's required fields are set
+pub struct PlanIssueCreationTool {
+    client: JiraClient,
+}
+
+impl PlanIssueCreationTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for PlanIssueCreationTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let issue_type = args
+            .get("issue_type")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: issue_type")
+            })?;
+        let given = args
+            .get("fields")
+            .and_then(|v| v.as_object())
+            .cloned()
+            .unwrap_or_default();
+        let refresh = args
+            .get("refresh")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let create = args
+            .get("create_when_complete")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        info!(
+            "Planning creation of {} in project {}",
+            issue_type, project.key
+        );
+        let meta = self
+            .client
+            .get_create_meta(&project.key, issue_type, refresh)
+            .await?;
+        let plan = CreationPlan::new(&meta, &given);
+        let mut report = serde_json::to_value(&plan)?;
+
+        if !(create && plan.complete) {
+            let not_created = if create { "Not created yet. " } else { "" };
+            let text = format!("{}{not_created}{}", project.note(), plan.render());
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(text), MCPContent::json(&report)],
+                is_error: Some(false),
+            });
+        }
+
+        info!("Creating planned {} in project {}", issue_type, project.key);
+        let created = self
+            .client
+            .create_issue(&json!({ "fields": plan.fields }))
+            .await?;
+        report["created"] = json!({"id": created.id, "key": created.key});
+        let summary = plan
+            .fields
+            .get("summary")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        let mut text = format!(
+            "{}Issue created successfully: {} - {}\nURL: {}",
+            project.note(),
+            created.key,
+            summary,
+            self.client.browse_url(&created.key)
+        );
+        if let Some(note) = self.client.label_note() {
+            text.push_str(&format!("\n{note}"));
+        }
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&report)],
             is_error: Some(false),
         })
     }
@@ -171,6 +887,102 @@ impl UpdateIssueTool {
 
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for UpdateIssueTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = update_issue_key(&args)?;
+        let fields = update_fields(&self.client, issue_key, &args).await?;
+        let update = match args.get("update") {
+            Some(update) => parse_update(update)?,
+            None => serde_json::Map::new(),
+        };
+        if fields.is_empty() && update.is_empty() {
+            return Err(crate::error::JiraError::api_error("No fields to update"));
+        }
+        let expected_updated = args.get("expected_updated").and_then(|v| v.as_str());
+
+        if args.get("validate").and_then(serde_json::Value::as_bool) == Some(true) {
+            let problems = self
+                .client
+                .check_issue_edit(issue_key, &fields, &update)
+                .await?;
+            if !problems.is_empty() {
+                return Err(crate::error::JiraError::validation_error(
+                    "update",
+                    &format!(
+                        "Issue {issue_key} was not updated:\n• {}",
+                        problems.join("\n• ")
+                    ),
+                ));
+            }
+        }
+
+        if args.get("preview").and_then(serde_json::Value::as_bool) == Some(true) {
+            let preview = self
+                .client
+                .preview_issue_update(issue_key, &fields, expected_updated)
+                .await?;
+            let mut text = preview.render();
+            if !update.is_empty() {
+                let names: Vec<&str> = update.keys().map(String::as_str).collect();
+                text.push_str(&format!(
+                    "\n\nNot shown: the update operations on {}, which apply to the values \
+                     the issue has when it is updated",
+                    names.join(", ")
+                ));
+            }
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(text), MCPContent::json(&preview)],
+                is_error: Some(false),
+            });
+        }
+
+        info!("Updating Jira issue: {}", issue_key);
+
+        if let Some(expected) = expected_updated {
+            self.client
+                .ensure_unchanged_since(issue_key, expected)
+                .await?;
+        }
+
+        let mut update_data = json!({});
+        if !fields.is_empty() {
+            update_data["fields"] = json!(fields);
+        }
+        if !update.is_empty() {
+            update_data["update"] = json!(update);
+        }
+
+        self.client.update_issue(issue_key, &update_data).await?;
+
+        let response_text = format!("Issue {issue_key} updated successfully");
+
+        let mut content = vec![MCPContent::text(response_text)];
+        append_returned_issue(&self.client, &args, issue_key, &mut content).await;
+
+        Ok(MCPToolResult {
+            content,
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Attach an issue to a parent, or detach it from its parent
+pub struct SetIssueParentTool {
+    client: JiraClient,
+}
+
+impl SetIssueParentTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for SetIssueParentTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
         let issue_key = args
             .get("issue_key")
@@ -178,42 +990,300 @@ impl crate::mcp::server::MCPToolHandler for UpdateIssueTool {
             .ok_or_else(|| {
                 crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
+        let parent_key = match args.get("parent_key") {
+            Some(serde_json::Value::String(key)) => Some(key.as_str()),
+            Some(serde_json::Value::Null) => None,
+            _ => {
+                return Err(crate::error::JiraError::api_error(
+                    "Missing required parameter: parent_key (null to detach the issue)",
+                ))
+            }
+        };
 
-        info!("Updating Jira issue: {}", issue_key);
+        info!("Setting parent of {} to {:?}", issue_key, parent_key);
+        let change = self.client.set_issue_parent(issue_key, parent_key).await?;
+
+        let text = match (
+            &change.parent_key,
+            &change.previous_parent_key,
+            &change.mechanism,
+        ) {
+            (_, _, None) => format!("{issue_key} has no parent; nothing was changed"),
+            (Some(parent), _, Some(mechanism)) => {
+                format!("{issue_key} is now under {parent} (set through the {mechanism})")
+            }
+            (None, Some(previous), Some(mechanism)) => {
+                format!("{issue_key} was detached from {previous} (cleared the {mechanism})")
+            }
+            (None, None, Some(_)) => format!("{issue_key} was detached from its parent"),
+        };
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&change)],
+            is_error: Some(false),
+        })
+    }
+}
 
-        let mut fields = serde_json::Map::new();
+/// The issue an update names, as `issue_key` or `issue_id_or_key`
+fn update_issue_key(args: &serde_json::Value) -> Result<&str> {
+    args.get("issue_key")
+        .or_else(|| args.get("issue_id_or_key"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| crate::error::JiraError::api_error("Missing required parameter: issue_key"))
+}
 
-        if let Some(summary) = args.get("summary").and_then(|v| v.as_str()) {
-            fields.insert("summary".to_string(), json!(summary));
-        }
+/// The fields an update of `issue_key` sets: those of the `fields` argument,
+/// then `summary`, `description`, `assignee` and `security`, with a text
+/// description converted as `input_format` says
+///
+/// # Errors
+///
+/// Returns an error if `input_format` is unknown or the security level
+/// cannot be resolved
+async fn update_fields(
+    client: &JiraClient,
+    issue_key: &str,
+    args: &serde_json::Value,
+) -> Result<serde_json::Map<String, serde_json::Value>> {
+    let mut fields = args
+        .get("fields")
+        .and_then(|v| v.as_object())
+        .cloned()
+        .unwrap_or_default();
 
-        if let Some(description) = args.get("description").and_then(|v| v.as_str()) {
-            fields.insert("description".to_string(), json!(description));
-        }
+    if let Some(summary) = args.get("summary").and_then(|v| v.as_str()) {
+        fields.insert("summary".to_string(), json!(summary));
+    }
 
-        if let Some(assignee) = args.get("assignee").and_then(|v| v.as_str()) {
-            fields.insert("assignee".to_string(), json!({"name": assignee}));
+    if let Some(description) = args.get("description").and_then(|v| v.as_str()) {
+        fields.insert("description".to_string(), json!(description));
+    }
+    InputFormat::from_args(args, client.config())?
+        .convert_description(&mut fields, client.config().is_api_v3());
+
+    if let Some(assignee) = args.get("assignee").and_then(|v| v.as_str()) {
+        fields.insert("assignee".to_string(), json!({"name": assignee}));
+    }
+
+    if let Some(level) = args.get("security").and_then(|v| v.as_str()) {
+        let issue = client
+            .get_issue_fields(issue_key, &["project".to_string()])
+            .await?;
+        let project_key = issue
+            .fields
+            .get("project")
+            .and_then(|p| p.get("key"))
+            .and_then(|k| k.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error(&format!(
+                    "Could not determine the project of issue {issue_key}"
+                ))
+            })?;
+        let level = client.resolve_security_level(project_key, level).await?;
+        fields.insert("security".to_string(), json!({"id": level.id}));
+    }
+
+    Ok(fields)
+}
+
+/// Preview an update of a Jira issue without making it
+pub struct PreviewIssueUpdateTool {
+    client: JiraClient,
+}
+
+impl PreviewIssueUpdateTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
         }
+    }
+}
 
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for PreviewIssueUpdateTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = update_issue_key(&args)?;
+        let fields = update_fields(&self.client, issue_key, &args).await?;
         if fields.is_empty() {
             return Err(crate::error::JiraError::api_error("No fields to update"));
         }
+        let expected_updated = args.get("expected_updated").and_then(|v| v.as_str());
 
-        let update_data = json!({
-            "fields": fields
-        });
-
-        self.client.update_issue(issue_key, &update_data).await?;
-
-        let response_text = format!("Issue {issue_key} updated successfully");
+        info!("Previewing update of Jira issue: {}", issue_key);
+        let preview = self
+            .client
+            .preview_issue_update(issue_key, &fields, expected_updated)
+            .await?;
 
         Ok(MCPToolResult {
-            content: vec![MCPContent::text(response_text)],
+            content: vec![
+                MCPContent::text(preview.render()),
+                MCPContent::json(&preview),
+            ],
             is_error: Some(false),
         })
     }
 }
 
+/// Fields to read back for `return_issue`: the `return_fields` argument, or
+/// every field when it is not given
+#[must_use]
+pub fn return_fields(args: &serde_json::Value) -> Vec<String> {
+    args.get("return_fields")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(ToString::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Read an issue, limited to `fields` when any are given
+///
+/// # Errors
+///
+/// Returns an error if the issue cannot be read.
+pub async fn read_issue(
+    client: &JiraClient,
+    issue_key: &str,
+    fields: &[String],
+) -> Result<JiraIssue> {
+    if fields.is_empty() {
+        client.get_issue(issue_key).await
+    } else {
+        client.get_issue_fields(issue_key, fields).await
+    }
+}
+
+/// An issue as `get_jira_issue` shows it: key, summary, status, assignee,
+/// parent, security level, Assets objects, URL and description
+#[must_use]
+pub async fn issue_details(client: &JiraClient, issue: &JiraIssue) -> String {
+    let summary = issue
+        .fields
+        .get("summary")
+        .and_then(|s| s.as_str())
+        .unwrap_or("No summary");
+    let status = issue
+        .fields
+        .get("status")
+        .and_then(|s| s.get("name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or("Unknown status");
+    let assignee = issue
+        .fields
+        .get("assignee")
+        .filter(|a| !a.is_null())
+        .map_or_else(|| "Unassigned".to_string(), JiraUser::label_of);
+    let security = issue
+        .fields
+        .get("security")
+        .and_then(|s| s.get("name"))
+        .and_then(|n| n.as_str())
+        .map(|name| format!("\nSecurity level: {name}"))
+        .unwrap_or_default();
+    let parent = match client.get_issue_parent(issue).await {
+        Some(IssueParent {
+            key,
+            summary: Some(summary),
+            ..
+        }) => format!("\nParent: {key} - {summary}"),
+        Some(parent) => format!("\nParent: {}", parent.key),
+        None => String::new(),
+    };
+    let assets: String = client
+        .issue_asset_fields(issue)
+        .await
+        .into_iter()
+        .map(|(field, objects)| format!("\n{field}: {}", objects.join(", ")))
+        .collect();
+
+    let mut details = format!(
+        "Issue: {}\nSummary: {}\nStatus: {}\nAssignee: {}{}{}{}\nURL: {}",
+        issue.key,
+        summary,
+        status,
+        assignee,
+        parent,
+        security,
+        assets,
+        client.browse_url(&issue.key)
+    );
+
+    if let Some(description) = issue
+        .fields
+        .get("description")
+        .and_then(|d| d.as_str())
+        .filter(|d| !d.is_empty())
+    {
+        let formatter = FieldFormatter::from_config(client.config());
+        details.push_str(&format!(
+            "\n\nDescription:\n{}",
+            formatter.format_field(description, &issue.key, "description")
+        ));
+    }
+    details
+}
+
+/// Summary, status and assignee of an issue on one line, for minimal output
+#[must_use]
+pub fn issue_line(issue: &JiraIssue) -> String {
+    let name = |field: &str| {
+        issue
+            .fields
+            .get(field)
+            .and_then(|f| f.get("name").or_else(|| f.get("displayName")))
+            .and_then(|n| n.as_str())
+    };
+    format!(
+        "{} ({}, {})",
+        issue
+            .fields
+            .get("summary")
+            .and_then(|s| s.as_str())
+            .unwrap_or("No summary"),
+        name("status").unwrap_or("Unknown status"),
+        issue
+            .fields
+            .get("assignee")
+            .filter(|a| !a.is_null())
+            .map_or_else(|| "Unassigned".to_string(), JiraUser::label_of)
+    )
+}
+
+/// Append the issue a create or update changed to `content` when the caller
+/// passed `return_issue`: its details to the text and its JSON as another
+/// item. A failed read is noted rather than failing the change that worked.
+async fn append_returned_issue(
+    client: &JiraClient,
+    args: &serde_json::Value,
+    issue_key: &str,
+    content: &mut Vec<MCPContent>,
+) {
+    if !args
+        .get("return_issue")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false)
+    {
+        return;
+    }
+    match read_issue(client, issue_key, &return_fields(args)).await {
+        Ok(issue) => {
+            content[0]
+                .text
+                .push_str(&format!("\n\n{}", issue_details(client, &issue).await));
+            content.push(MCPContent::json(&issue));
+        }
+        Err(e) => content[0].text.push_str(&format!(
+            "\n\nIssue {issue_key} could not be read back: {e}"
+        )),
+    }
+}
+
 /// Get a specific Jira issue
 pub struct GetIssueTool {
     client: JiraClient,
@@ -240,37 +1310,241 @@ impl crate::mcp::server::MCPToolHandler for GetIssueTool {
                 crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
 
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
+
         info!("Getting Jira issue: {}", issue_key);
 
         let issue = self.client.get_issue(issue_key).await?;
+        // Jira follows moved issues to their new key
+        let moved = ResolvedIssueKey::new(issue_key, &issue.key).note();
 
-        let summary = issue
-            .fields
-            .get("summary")
-            .and_then(|s| s.as_str())
-            .unwrap_or("No summary");
-        let status = issue
-            .fields
-            .get("status")
-            .and_then(|s| s.get("name"))
-            .and_then(|n| n.as_str())
-            .unwrap_or("Unknown status");
-        let assignee = issue
+        let mut content = vec![MCPContent::text(if output.is_minimal() {
+            format!(
+                "{moved}{}",
+                output.item_line(&issue.key, &issue_line(&issue))
+            )
+        } else {
+            format!("{moved}{}", issue_details(&self.client, &issue).await)
+        })];
+        output.push_raw(&mut content, &issue);
+
+        Ok(MCPToolResult {
+            content,
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Read the complete value of a single issue field
+pub struct GetIssueFieldTool {
+    client: JiraClient,
+}
+
+impl GetIssueFieldTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+/// Render a field value: rich text fields, comments and worklogs as text,
+/// anything else as pretty JSON
+fn render_field_value(value: &serde_json::Value) -> Result<String> {
+    if let Some(text) = rich_text(value) {
+        return Ok(text);
+    }
+
+    let entries = |list: &str| value.get(list).and_then(|v| v.as_array());
+
+    if let Some(comments) = entries("comments") {
+        return Ok(comments
+            .iter()
+            .map(render_comment)
+            .collect::<Vec<_>>()
+            .join("\n"));
+    }
+    if let Some(worklogs) = entries("worklogs") {
+        return Ok(worklogs
+            .iter()
+            .map(render_work_log)
+            .collect::<Vec<_>>()
+            .join("\n"));
+    }
+
+    Ok(serde_json::to_string_pretty(value)?)
+}
+
+/// The string at `pointer` of `entry`, empty when missing
+fn entry_text(entry: &serde_json::Value, pointer: &str) -> String {
+    entry
+        .pointer(pointer)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// A raw comment as its id, author, creation time and full body
+fn render_comment(comment: &serde_json::Value) -> String {
+    format!(
+        "• {} by {} on {}\n{}\n",
+        entry_text(comment, "/id"),
+        entry_text(comment, "/author/displayName"),
+        entry_text(comment, "/created"),
+        comment.get("body").and_then(rich_text).unwrap_or_default()
+    )
+}
+
+/// A raw work log as its id, time spent, author, start and full comment
+fn render_work_log(work_log: &serde_json::Value) -> String {
+    format!(
+        "• {} - {} by {} on {}\n{}\n",
+        entry_text(work_log, "/id"),
+        entry_text(work_log, "/timeSpent"),
+        entry_text(work_log, "/author/displayName"),
+        entry_text(work_log, "/started"),
+        work_log
+            .get("comment")
+            .and_then(rich_text)
+            .unwrap_or_default()
+    )
+}
+
+/// The kind and id of a single comment or work log asked for as the field
+/// `comment:<id>` or `worklog:<id>`, as truncated entries name themselves
+fn single_entry(field: &str) -> Option<(&'static str, &str)> {
+    let (kind, id) = field.split_once(':')?;
+    let id = id.trim();
+    if id.is_empty() {
+        return None;
+    }
+    match kind.trim().to_ascii_lowercase().as_str() {
+        "comment" => Some(("comment", id)),
+        "worklog" => Some(("worklog", id)),
+        _ => None,
+    }
+}
+
+fn is_empty_field_value(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => true,
+        serde_json::Value::String(s) => s.trim().is_empty(),
+        serde_json::Value::Array(items) => items.is_empty(),
+        serde_json::Value::Object(map) => {
+            map.is_empty()
+                || ["comments", "worklogs"].iter().any(|list| {
+                    map.get(*list)
+                        .and_then(|v| v.as_array())
+                        .is_some_and(Vec::is_empty)
+                })
+        }
+        _ => false,
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetIssueFieldTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = args
+            .get("issue_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: issue_key")
+            })?;
+        let field = args.get("field").and_then(|v| v.as_str()).ok_or_else(|| {
+            crate::error::JiraError::api_error("Missing required parameter: field")
+        })?;
+
+        // Check the destination before fetching anything
+        let output_path = args
+            .get("output_path")
+            .and_then(|v| v.as_str())
+            .map(|path| {
+                resolve_output_path(
+                    "output_path",
+                    path,
+                    &self.client.config().allowed_directories(),
+                )
+            })
+            .transpose()?;
+
+        // A truncated comment or work log names itself, so only that entry
+        // is read rather than every one of the issue
+        if let Some((kind, id)) = single_entry(field) {
+            info!("Getting {} {} of Jira issue: {}", kind, id, issue_key);
+            let (rendered, what, title) = if kind == "comment" {
+                let comment = self.client.get_comment(issue_key, id).await?;
+                (render_comment(&comment), "comment", "Comment")
+            } else {
+                let work_log = self.client.get_work_log(issue_key, id).await?;
+                (render_work_log(&work_log), "work log", "Work log")
+            };
+            let response_text = if let Some(path) = output_path {
+                tokio::fs::write(&path, &rendered).await?;
+                format!(
+                    "Wrote {what} {id} of issue {issue_key} to {} ({} characters)",
+                    path.display(),
+                    rendered.chars().count()
+                )
+            } else {
+                format!("{title} {id} of issue {issue_key}:\n\n{rendered}")
+            };
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(response_text)],
+                is_error: Some(false),
+            });
+        }
+
+        info!("Getting field {} of Jira issue: {}", field, issue_key);
+
+        let definition = self.client.resolve_field(field).await?;
+        let field_id = definition
+            .get("id")
+            .and_then(|v| v.as_str())
+            .unwrap_or(field)
+            .to_string();
+        let field_name = definition
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&field_id)
+            .to_string();
+
+        let issue = self
+            .client
+            .get_issue_fields(issue_key, std::slice::from_ref(&field_id))
+            .await?;
+        let Some(value) = issue
             .fields
-            .get("assignee")
-            .and_then(|a| a.get("displayName"))
-            .and_then(|n| n.as_str())
-            .unwrap_or("Unassigned");
+            .get(&field_id)
+            .filter(|v| !is_empty_field_value(v))
+        else {
+            return Err(crate::error::JiraError::validation_error(
+                "field",
+                &format!(
+                    "Field '{field_name}' ({field_id}) is empty on issue {}",
+                    issue.key
+                ),
+            ));
+        };
+        let rendered = render_field_value(value)?;
 
-        let response_text = format!(
-            "Issue: {}\nSummary: {}\nStatus: {}\nAssignee: {}\nURL: {}/browse/{}",
-            issue.key,
-            summary,
-            status,
-            assignee,
-            self.client.api_base_url().replace("/rest/api/2", ""),
-            issue.key
-        );
+        let response_text = if let Some(path) = output_path {
+            tokio::fs::write(&path, &rendered).await?;
+            format!(
+                "Wrote field '{field_name}' ({field_id}) of issue {} to {} ({} characters)",
+                issue.key,
+                path.display(),
+                rendered.chars().count()
+            )
+        } else {
+            format!(
+                "Field '{field_name}' ({field_id}) of issue {}:\n\n{rendered}",
+                issue.key
+            )
+        };
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
@@ -348,3 +1622,94 @@ impl crate::mcp::server::MCPToolHandler for LinkIssuesTool {
         })
     }
 }
+
+/// Assign, relabel, comment on and transition an issue in one call
+pub struct IssueActionTool {
+    client: JiraClient,
+}
+
+impl IssueActionTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for IssueActionTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = args
+            .get("issue_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: issue_key")
+            })?;
+
+        let text = |name: &str| {
+            args.get(name)
+                .and_then(|v| v.as_str())
+                .map(ToString::to_string)
+        };
+        let labels = |name: &str| -> Vec<String> {
+            args.get(name)
+                .and_then(|v| v.as_array())
+                .map(|labels| {
+                    labels
+                        .iter()
+                        .filter_map(|v| v.as_str())
+                        .map(ToString::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let actions = IssueActions {
+            assign: text("assign"),
+            add_labels: labels("add_labels"),
+            remove_labels: labels("remove_labels"),
+            comment: text("comment"),
+            transition: text("transition"),
+        };
+        if actions.steps().is_empty() {
+            return Err(crate::error::JiraError::validation_error(
+                "issue_key",
+                "Nothing to do; give at least one of assign, add_labels, remove_labels, comment \
+                 or transition",
+            ));
+        }
+
+        info!("Applying {:?} to issue {}", actions.steps(), issue_key);
+
+        let report = self.client.apply_issue_actions(issue_key, &actions).await;
+
+        let total = report.completed.len()
+            + usize::from(report.failed.is_some())
+            + report.not_attempted.len();
+        let mut response_text = format!(
+            "Applied {} of {} actions to {}",
+            report.completed.len(),
+            total,
+            issue_key
+        );
+        for step in &report.completed {
+            response_text.push_str(&format!("\n• {step}: done"));
+        }
+        if let Some(failure) = &report.failed {
+            response_text.push_str(&format!(
+                "\n• {}: failed ({})",
+                failure.step, failure.error_message
+            ));
+        }
+        for step in &report.not_attempted {
+            response_text.push_str(&format!("\n• {step}: not attempted"));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text), MCPContent::json(&report)],
+            is_error: Some(!report.success()),
+        })
+    }
+}
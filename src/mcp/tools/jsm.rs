@@ -0,0 +1,239 @@
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+use crate::jira::client::JiraClient;
+use crate::jira::jsm::render_slas;
+use crate::mcp::tools::required_str;
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use tracing::info;
+
+/// List the Jira Service Management service desks
+pub struct GetServiceDesksTool {
+    client: JiraClient,
+}
+
+impl GetServiceDesksTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetServiceDesksTool {
+    async fn handle(&self, _args: serde_json::Value) -> Result<MCPToolResult> {
+        info!("Getting service desks");
+
+        let desks = self.client.get_service_desks().await?;
+        let mut text = format!("Found {} service desks:", desks.len());
+        for desk in &desks {
+            text.push_str(&format!(
+                "\n• {} — {} ({})",
+                desk.id, desk.project_name, desk.project_key
+            ));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&desks)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// List the request types of a service desk
+pub struct GetRequestTypesTool {
+    client: JiraClient,
+}
+
+impl GetRequestTypesTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetRequestTypesTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let service_desk_id = required_str(&args, "service_desk_id")?;
+
+        info!("Getting request types of service desk {}", service_desk_id);
+
+        let types = self.client.get_request_types(service_desk_id).await?;
+        let mut text = format!(
+            "Found {} request types on service desk {service_desk_id}:",
+            types.len()
+        );
+        for request_type in &types {
+            text.push_str(&format!("\n• {} — {}", request_type.id, request_type.name));
+            if let Some(description) = request_type
+                .description
+                .as_deref()
+                .filter(|d| !d.is_empty())
+            {
+                text.push_str(&format!(": {description}"));
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&types)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Raise a customer request on a service desk
+pub struct CreateCustomerRequestTool {
+    client: JiraClient,
+}
+
+impl CreateCustomerRequestTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for CreateCustomerRequestTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let service_desk_id = required_str(&args, "service_desk_id")?;
+        let request_type_id = required_str(&args, "request_type_id")?;
+        let fields = args
+            .get("fields")
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: fields"))?;
+
+        info!(
+            "Creating a request of type {} on service desk {}",
+            request_type_id, service_desk_id
+        );
+
+        let request = self
+            .client
+            .create_customer_request(service_desk_id, request_type_id, fields)
+            .await?;
+        let mut text = format!("Created customer request {}", request.issue_key);
+        if let Some(status) = &request.current_status {
+            text.push_str(&format!(" ({})", status.status));
+        }
+        if let Some(web) = request
+            .links
+            .as_ref()
+            .and_then(|links| links.get("web"))
+            .and_then(|web| web.as_str())
+        {
+            text.push_str(&format!("\nCustomer portal: {web}"));
+        }
+        if let Some(note) = self.client.label_note() {
+            text.push_str(&format!("\n{note}"));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Show the SLAs of a customer request
+pub struct GetRequestSlaTool {
+    client: JiraClient,
+}
+
+impl GetRequestSlaTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetRequestSlaTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = required_str(&args, "issue_key")?;
+
+        info!("Getting the SLAs of {}", issue_key);
+
+        let slas = self.client.get_request_sla(issue_key).await?;
+        let text = format!(
+            "SLAs of {issue_key}:\n{}",
+            render_slas(&slas, chrono::Utc::now())
+        );
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&slas)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Comment on a customer request, for the customer or for agents only
+pub struct AddCustomerCommentTool {
+    client: JiraClient,
+}
+
+impl AddCustomerCommentTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for AddCustomerCommentTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = required_str(&args, "issue_key")?;
+        let body = required_str(&args, "body")?;
+        // Internal unless asked otherwise: a public comment emails the customer
+        let public = args
+            .get("public")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        info!("Adding a customer comment to {}", issue_key);
+
+        let comment = self
+            .client
+            .add_customer_comment(issue_key, body, public)
+            .await?;
+        let visibility = if comment.public {
+            "visible to the customer"
+        } else {
+            "internal, visible to agents only"
+        };
+
+        let mut text = format!("Added comment {} to {issue_key} ({visibility})", comment.id);
+        if let Some(note) = self.client.signature_note() {
+            text.push_str(&format!("\n{note}"));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text)],
+            is_error: Some(false),
+        })
+    }
+}
@@ -1,6 +1,10 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
+use crate::jira::link_graph::{
+    LinkDirection, LinkGraphOptions, DEFAULT_LINK_GRAPH_DEPTH, MAX_LINK_GRAPH_DEPTH,
+    MAX_LINK_GRAPH_NODES,
+};
 use crate::types::mcp::{MCPContent, MCPToolResult};
 use tracing::info;
 
@@ -32,12 +36,14 @@ impl crate::mcp::server::MCPToolHandler for GetIssueLinksTool {
 
         info!("Getting issue links for: {}", issue_key);
 
-        let links = self.client.get_issue_links(issue_key).await?;
+        let resolved = self.client.resolve_issue_key(issue_key).await;
+        let links = self.client.get_issue_links(&resolved.key).await?;
 
         let mut content = vec![MCPContent::text(format!(
-            "Found {} links for issue {}\n\n",
+            "{}Found {} links for issue {}\n\n",
+            resolved.note(),
             links.len(),
-            issue_key
+            resolved.key
         ))];
 
         for link in links {
@@ -52,6 +58,93 @@ impl crate::mcp::server::MCPToolHandler for GetIssueLinksTool {
     }
 }
 
+/// Walk the links of an issue transitively, e.g. everything blocking it
+pub struct GetIssueLinkGraphTool {
+    client: JiraClient,
+}
+
+impl GetIssueLinkGraphTool {
+    #[must_use]
+    /// # Panics
+    /// This function panics if `JiraClient::new` fails.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetIssueLinkGraphTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = args
+            .get("issue_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: issue_key")
+            })?;
+
+        let direction = match args.get("direction").and_then(|v| v.as_str()) {
+            None => LinkDirection::Both,
+            Some(value) => LinkDirection::parse(value).ok_or_else(|| {
+                crate::error::JiraError::validation_error(
+                    "direction",
+                    &format!("Unknown direction '{value}', expected inward, outward or both"),
+                )
+            })?,
+        };
+        let link_types = args
+            .get("link_types")
+            .and_then(|v| v.as_array())
+            .map(|types| {
+                types
+                    .iter()
+                    .filter_map(|v| v.as_str().map(ToString::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let max_depth = args
+            .get("max_depth")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|n| usize::try_from(n).ok())
+            .unwrap_or(DEFAULT_LINK_GRAPH_DEPTH)
+            .clamp(1, MAX_LINK_GRAPH_DEPTH);
+
+        info!(
+            "Walking {:?} links of {} to depth {}",
+            direction, issue_key, max_depth
+        );
+
+        let options = LinkGraphOptions {
+            link_types,
+            direction,
+            max_depth,
+        };
+        let graph = self
+            .client
+            .get_issue_link_graph(issue_key, &options)
+            .await?;
+
+        let mut response_text = format!(
+            "Link graph of {issue_key}: {} issues, {} links, depth {max_depth}\n",
+            graph.nodes.len(),
+            graph.edges.len()
+        );
+        if graph.truncated {
+            response_text.push_str(&format!(
+                "Stopped after {MAX_LINK_GRAPH_NODES} issues; further links were not followed\n"
+            ));
+        }
+        response_text.push('\n');
+        response_text.push_str(&graph.tree());
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text), MCPContent::json(&graph)],
+            is_error: Some(false),
+        })
+    }
+}
+
 /// Create a link between two issues
 pub struct CreateIssueLinkTool {
     client: JiraClient,
@@ -99,6 +192,10 @@ impl crate::mcp::server::MCPToolHandler for CreateIssueLinkTool {
             inward_issue, outward_issue, link_type
         );
 
+        let inward = self.client.resolve_issue_key(inward_issue).await;
+        let outward = self.client.resolve_issue_key(outward_issue).await;
+        let (inward_issue, outward_issue) = (inward.key.as_str(), outward.key.as_str());
+
         let link_request = crate::types::jira::JiraIssueLinkCreateRequest {
             link_type: crate::types::jira::JiraIssueLinkType {
                 name: link_type.to_string(),
@@ -118,7 +215,9 @@ impl crate::mcp::server::MCPToolHandler for CreateIssueLinkTool {
         self.client.create_issue_link(&link_request).await?;
 
         let response_text = format!(
-            "Link created successfully between {inward_issue} and {outward_issue} with type {link_type}"
+            "{}{}Link created successfully between {inward_issue} and {outward_issue} with type {link_type}",
+            inward.note(),
+            outward.note()
         );
 
         Ok(MCPToolResult {
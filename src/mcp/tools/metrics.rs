@@ -0,0 +1,154 @@
+use crate::config::JiraConfig;
+use crate::error::Result;
+use crate::jira::maintenance;
+use crate::jira::prefetch;
+use crate::jira::throttle::{shared_schedulers, RequestClass};
+use crate::jira::warmup::last_warmup;
+use crate::performance::{get_global_metrics, profiler};
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use serde_json::json;
+use tracing::info;
+
+/// Report server performance metrics and the current request throttles
+pub struct GetServerMetricsTool {
+    config: JiraConfig,
+}
+
+impl GetServerMetricsTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetServerMetricsTool {
+    async fn handle(&self, _args: serde_json::Value) -> Result<MCPToolResult> {
+        info!("Getting server metrics");
+
+        let stats = get_global_metrics().get_stats();
+        let schedulers = shared_schedulers(&self.config);
+        let throttle = schedulers.get(RequestClass::Core).throttle().snapshot();
+
+        let metrics = json!({
+            "requests": {
+                "total": stats.total_requests,
+                "successful": stats.successful_requests,
+                "failed": stats.failed_requests,
+                "success_rate": stats.success_rate,
+                "requests_per_second": stats.requests_per_second,
+                "average_response_time_ms": stats.average_response_time_ms,
+                "min_response_time_ms": stats.min_response_time_ms,
+                "max_response_time_ms": stats.max_response_time_ms,
+                "rate_limited": stats.rate_limited_requests,
+                "coalesced": stats.coalesced_requests,
+            },
+            // Estimated by the client; reqwest does not expose its pool
+            "connections": {
+                "estimated": true,
+                "new": stats.new_connections,
+                "reused": stats.reused_connections,
+                "reuse_rate": stats.connection_reuse_rate,
+            },
+            "cache": {
+                "hits": stats.cache_hits,
+                "misses": stats.cache_misses,
+                "hit_rate": stats.cache_hit_rate,
+            },
+            "throttle": throttle,
+            // Queue depth, waits and throttle of each request class
+            "request_classes": schedulers.snapshot(),
+            "warmup": last_warmup(&self.config),
+            // Issues kept warm by the background prefetcher
+            "prefetch": prefetch::status(&self.config),
+            // Set while Jira is in a maintenance window
+            "maintenance": maintenance::current(&self.config),
+            // Time taken by each section of get_project_metadata
+            "metadata_sections": stats.metadata_sections,
+            // Sizes of the responses written to the client, before any were
+            // replaced for exceeding max_response_bytes
+            "response_sizes": stats.response_sizes,
+            "uptime_seconds": stats.uptime_seconds,
+        });
+
+        let response_text = format!(
+            "Server metrics:\n{}",
+            serde_json::to_string_pretty(&metrics)
+                .unwrap_or_else(|_| "Failed to format metrics".to_string())
+        );
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Tool calls `get_performance_profile` reports when `limit` isn't given
+const DEFAULT_PROFILE_LIMIT: usize = 10;
+
+/// Report the slowest recent tool calls and where their time went
+pub struct GetPerformanceProfileTool;
+
+impl GetPerformanceProfileTool {
+    #[must_use]
+    pub fn new(_config: JiraConfig) -> Self {
+        Self
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetPerformanceProfileTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let limit = args
+            .get("limit")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(DEFAULT_PROFILE_LIMIT);
+        let reset = args
+            .get("reset")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        info!("Getting performance profile");
+
+        let mut calls = profiler::recent_calls();
+        let profiled = calls.len();
+        calls.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+        calls.truncate(limit);
+
+        let mut text = if calls.is_empty() {
+            "No tool calls have been profiled yet".to_string()
+        } else {
+            format!("Slowest {} of the last {profiled} tool calls:", calls.len())
+        };
+        for call in &calls {
+            text.push_str(&format!(
+                "\n• {} took {:.1} ms at {}",
+                call.tool,
+                call.total_ms,
+                call.started_at.to_rfc3339()
+            ));
+            for segment in &call.segments {
+                text.push_str(&format!(
+                    "\n  - {}: {:.1} ms",
+                    segment.name, segment.duration_ms
+                ));
+            }
+        }
+        if reset {
+            profiler::reset_recent_calls();
+            text.push_str("\nThe kept profiles were cleared");
+        }
+
+        Ok(MCPToolResult {
+            content: vec![
+                MCPContent::text(text),
+                MCPContent::json(&json!({ "profiled_calls": profiled, "calls": calls })),
+            ],
+            is_error: Some(false),
+        })
+    }
+}
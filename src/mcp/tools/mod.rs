@@ -1,33 +1,65 @@
 // MCP Tools Module
 // This module contains all the MCP tool implementations organized by functionality
 
+pub mod assets;
 pub mod attachments;
 pub mod auth;
 pub mod bulk;
+pub mod bundle;
 pub mod cloning;
 pub mod comments;
 pub mod components;
+pub mod context;
+pub mod errors;
+pub mod events;
+pub mod formatting;
 pub mod issues;
+pub mod jsm;
 pub mod labels;
 pub mod linking;
+pub mod metrics;
+pub mod pagination;
+pub mod policies;
 pub mod projects;
+pub mod reports;
 pub mod sprints;
 pub mod transitions;
 pub mod watchers;
 pub mod worklogs;
 
 // Re-export all tools for easy access
+pub use assets::*;
 pub use attachments::*;
 pub use auth::*;
 pub use bulk::*;
+pub use bundle::*;
 pub use cloning::*;
 pub use comments::*;
 pub use components::*;
+pub use context::*;
+pub use errors::*;
+pub use events::*;
+pub use formatting::*;
 pub use issues::*;
+pub use jsm::*;
 pub use labels::*;
 pub use linking::*;
+pub use metrics::*;
+pub use pagination::*;
+pub use policies::*;
 pub use projects::*;
+pub use reports::*;
 pub use sprints::*;
 pub use transitions::*;
 pub use watchers::*;
 pub use worklogs::*;
+
+/// The string argument `name`, or a "Missing required parameter" error
+pub(crate) fn required_str<'a>(
+    args: &'a serde_json::Value,
+    name: &str,
+) -> crate::error::Result<&'a str> {
+    args.get(name).and_then(|v| v.as_str()).ok_or_else(|| {
+        crate::error::JiraError::api_error(&format!("Missing required parameter: {name}"))
+    })
+}
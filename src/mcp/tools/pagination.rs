@@ -0,0 +1,80 @@
+//! Reading the `start_at` and `max_results` arguments of paginated tools.
+//!
+//! Both are also accepted in camelCase. Values are checked rather than
+//! coerced: an out-of-range value used to become 0, which Jira reads as "no
+//! items", so a typo looked like an empty result. A `max_results` of 0 is
+//! still allowed and asks for the total only.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+
+/// Largest `max_results` accepted when `max_results_limit` is not configured
+pub const DEFAULT_MAX_RESULTS_LIMIT: u32 = 1000;
+
+/// Where a page starts and how long it is
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PageArgs {
+    pub start_at: Option<u32>,
+    pub max_results: Option<u32>,
+}
+
+impl PageArgs {
+    /// Read `start_at` (or `startAt`) and `max_results` (or `maxResults`)
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the argument and the allowed range
+    /// if either is not a whole number, is negative, or `max_results` is
+    /// above `max_results_limit`.
+    pub fn from_args(args: &serde_json::Value, config: &JiraConfig) -> Result<Self> {
+        let limit = config
+            .max_results_limit
+            .unwrap_or(DEFAULT_MAX_RESULTS_LIMIT);
+        Ok(Self {
+            start_at: page_arg(args, "start_at", "startAt", i32::MAX.unsigned_abs())?,
+            max_results: page_arg(args, "max_results", "maxResults", limit)?,
+        })
+    }
+
+    /// Whether only the total was asked for (`max_results` of 0)
+    #[must_use]
+    pub fn is_count_only(&self) -> bool {
+        self.max_results == Some(0)
+    }
+
+    /// `start_at` for the client methods that take an `i32`
+    #[must_use]
+    pub fn start_at_i32(&self) -> Option<i32> {
+        self.start_at.map(|v| i32::try_from(v).unwrap_or(i32::MAX))
+    }
+
+    /// `max_results` for the client methods that take an `i32`
+    #[must_use]
+    pub fn max_results_i32(&self) -> Option<i32> {
+        self.max_results
+            .map(|v| i32::try_from(v).unwrap_or(i32::MAX))
+    }
+}
+
+/// The whole number between 0 and `max` given as `name` or `alias`
+fn page_arg(args: &serde_json::Value, name: &str, alias: &str, max: u32) -> Result<Option<u32>> {
+    let (field, value) = match (args.get(name), args.get(alias)) {
+        (Some(value), _) => (name, value),
+        (None, Some(value)) => (alias, value),
+        (None, None) => return Ok(None),
+    };
+    if value.is_null() {
+        return Ok(None);
+    }
+    value
+        .as_i64()
+        .and_then(|v| u32::try_from(v).ok())
+        .filter(|v| *v <= max)
+        .map(Some)
+        .ok_or_else(|| {
+            JiraError::validation_error(
+                field,
+                &format!("{field} must be a whole number from 0 to {max}; got {value}"),
+            )
+        })
+}
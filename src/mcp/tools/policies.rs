@@ -0,0 +1,58 @@
+use crate::config::JiraConfig;
+use crate::error::Result;
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use tracing::info;
+
+/// List the write policies tool calls are checked against, in the order they
+/// are checked
+pub struct ListWritePoliciesTool {
+    config: JiraConfig,
+}
+
+impl ListWritePoliciesTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ListWritePoliciesTool {
+    async fn handle(&self, _args: serde_json::Value) -> Result<MCPToolResult> {
+        info!("Listing write policies");
+
+        let policies = self.config.policies.clone().unwrap_or_default();
+        if policies.is_empty() {
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(
+                    "No write policies are configured; writes are only limited by Jira's permissions"
+                        .to_string(),
+                )],
+                is_error: Some(false),
+            });
+        }
+
+        let mut text = format!(
+            "{} write policies, checked in order; the first allow or deny that matches a call decides:",
+            policies.len()
+        );
+        for (index, policy) in policies.iter().enumerate() {
+            text.push_str(&format!(
+                "\n{}. {}: {}",
+                index + 1,
+                policy.name,
+                policy.describe()
+            ));
+            if let Some(message) = &policy.message {
+                text.push_str(&format!(" — \"{message}\""));
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&policies)],
+            is_error: Some(false),
+        })
+    }
+}
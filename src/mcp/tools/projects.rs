@@ -1,9 +1,223 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
+use crate::jira::create_plan::{schema_type, value_label, ALLOWED_VALUES_SAMPLE};
+use crate::mcp::tools::context::SESSION_PROJECT_ARG;
+use crate::types::jira::{ProjectFilter, ProjectStatus};
 use crate::types::mcp::{MCPContent, MCPToolResult};
 use tracing::info;
 
+/// Project key for a project-scoped tool, taken from the arguments or, when
+/// omitted, from the session context or `JIRA_DEFAULT_PROJECT`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectArg {
+    pub key: String,
+    /// Whether the key came from the configured default project
+    pub defaulted: bool,
+    /// Whether the key came from the session project set with
+    /// `set_jira_context`
+    pub from_session: bool,
+}
+
+impl ProjectArg {
+    /// Read the project key from argument `name`, falling back to the session
+    /// project the server passes in [`SESSION_PROJECT_ARG`], then to
+    /// `config.default_project`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the argument is missing and no default project is
+    /// configured.
+    pub fn from_args(args: &serde_json::Value, name: &str, config: &JiraConfig) -> Result<Self> {
+        if let Some(key) = args
+            .get(name)
+            .and_then(|v| v.as_str())
+            .filter(|key| !key.is_empty())
+        {
+            return Ok(Self {
+                key: key.to_string(),
+                defaulted: false,
+                from_session: false,
+            });
+        }
+        if let Some(key) = args
+            .get(SESSION_PROJECT_ARG)
+            .and_then(|v| v.as_str())
+            .filter(|key| !key.is_empty())
+        {
+            return Ok(Self {
+                key: key.to_string(),
+                defaulted: false,
+                from_session: true,
+            });
+        }
+        config
+            .default_project
+            .as_deref()
+            .filter(|key| !key.is_empty())
+            .map(|key| Self {
+                key: key.to_string(),
+                defaulted: true,
+                from_session: false,
+            })
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error(&format!(
+                    "Missing required parameter: {name} (or set JIRA_DEFAULT_PROJECT to use a default project)"
+                ))
+            })
+    }
+
+    /// Line stating that the session or default project was applied, empty
+    /// otherwise
+    #[must_use]
+    pub fn note(&self) -> String {
+        if self.from_session {
+            format!("Using session project {} (set_jira_context)\n", self.key)
+        } else if self.defaulted {
+            format!(
+                "Using default project {} (JIRA_DEFAULT_PROJECT)\n",
+                self.key
+            )
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// List projects, optionally by status, category and key prefix
+pub struct ListProjectsTool {
+    client: JiraClient,
+}
+
+impl ListProjectsTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ListProjectsTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let text = |name: &str| {
+            args.get(name)
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(ToString::to_string)
+        };
+        let statuses = match text("status").as_deref() {
+            None => vec![ProjectStatus::Live],
+            Some(all) if all.eq_ignore_ascii_case("all") => {
+                vec![
+                    ProjectStatus::Live,
+                    ProjectStatus::Archived,
+                    ProjectStatus::Deleted,
+                ]
+            }
+            Some(status) => vec![ProjectStatus::parse(status).ok_or_else(|| {
+                crate::error::JiraError::validation_error(
+                    "status",
+                    &format!("Unknown status '{status}'; use live, archived, deleted or all"),
+                )
+            })?],
+        };
+        let filter = ProjectFilter {
+            statuses,
+            category: text("category"),
+            key_prefix: text("key_prefix"),
+        };
+
+        info!("Listing projects: {:?}", filter);
+
+        let projects = self.client.list_projects(&filter).await?;
+
+        let mut criteria = vec![if filter.statuses.len() == 3 {
+            "any status".to_string()
+        } else {
+            filter
+                .statuses
+                .iter()
+                .map(|status| status.as_str())
+                .collect::<Vec<_>>()
+                .join(" or ")
+        }];
+        if let Some(category) = &filter.category {
+            criteria.push(format!("in category {category}"));
+        }
+        if let Some(prefix) = &filter.key_prefix {
+            criteria.push(format!("with keys starting {prefix}"));
+        }
+        let mut response_text = format!(
+            "Found {} projects ({})",
+            projects.len(),
+            criteria.join(", ")
+        );
+        for project in &projects {
+            response_text.push_str(&format!("\n• {} - {}", project.key, project.name));
+            if let Some(category) = &project.project_category {
+                response_text.push_str(&format!(" [{}]", category.name));
+            }
+            if project.status() != ProjectStatus::Live {
+                response_text.push_str(&format!(" ({})", project.status().as_str()));
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text), MCPContent::json(&projects)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Get the project categories
+pub struct GetProjectCategoriesTool {
+    client: JiraClient,
+}
+
+impl GetProjectCategoriesTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetProjectCategoriesTool {
+    async fn handle(&self, _args: serde_json::Value) -> Result<MCPToolResult> {
+        info!("Getting project categories");
+
+        let categories = self.client.get_project_categories().await?;
+
+        let mut response_text = format!("Found {} project categories", categories.len());
+        for category in &categories {
+            response_text.push_str(&format!(
+                "\n• {} (ID: {}) - {}",
+                category.name,
+                category.id,
+                category
+                    .description
+                    .as_deref()
+                    .filter(|d| !d.is_empty())
+                    .unwrap_or("No description")
+            ));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text)],
+            is_error: Some(false),
+        })
+    }
+}
+
 /// Get project configuration details
 pub struct GetProjectConfigTool {
     client: JiraClient,
@@ -23,19 +237,16 @@ impl GetProjectConfigTool {
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for GetProjectConfigTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
-        let project_key = args
-            .get("project_key")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: project_key")
-            })?;
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let project_key = project.key.as_str();
 
         info!("Getting project configuration for: {}", project_key);
 
         let config = self.client.get_project_configuration(project_key).await?;
 
         let response_text = format!(
-            "Project configuration for {}:\n{}",
+            "{}Project configuration for {}:\n{}",
+            project.note(),
             project_key,
             serde_json::to_string_pretty(&config)
                 .unwrap_or_else(|_| "Failed to format configuration".to_string())
@@ -67,19 +278,41 @@ impl GetProjectMetadataTool {
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for GetProjectMetadataTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
-        let project_key = args
-            .get("project_key")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: project_key")
-            })?;
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let project_key = project.key.as_str();
+
+        let include: Vec<String> = match args.get("include") {
+            None | Some(serde_json::Value::Null) => Vec::new(),
+            Some(include) => serde_json::from_value(include.clone()).map_err(|_| {
+                crate::error::JiraError::validation_error(
+                    "include",
+                    "Expected an array of section names",
+                )
+            })?,
+        };
 
         info!("Getting project metadata for: {}", project_key);
 
-        let metadata = self.client.get_project_metadata(project_key).await?;
-
+        let metadata = self
+            .client
+            .get_project_metadata(project_key, &include)
+            .await?;
+
+        let mut warnings = String::new();
+        if let Some(errors) = metadata["errors"].as_object().filter(|e| !e.is_empty()) {
+            warnings.push_str("Some sections could not be read:\n");
+            for (section, error) in errors {
+                warnings.push_str(&format!(
+                    "• {section}: {}\n",
+                    error.as_str().unwrap_or_default()
+                ));
+            }
+            warnings.push('\n');
+        }
         let response_text = format!(
-            "Project metadata for {}:\n{}",
+            "{}{}Project metadata for {}:\n{}",
+            project.note(),
+            warnings,
             project_key,
             serde_json::to_string_pretty(&metadata)
                 .unwrap_or_else(|_| "Failed to format metadata".to_string())
@@ -262,19 +495,16 @@ impl GetProjectComponentsTool {
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for GetProjectComponentsTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
-        let project_key = args
-            .get("project_key")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: project_key")
-            })?;
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let project_key = project.key.as_str();
 
         info!("Getting project components for: {}", project_key);
 
         let components = self.client.get_project_components(project_key).await?;
 
         let mut content = vec![MCPContent::text(format!(
-            "Found {} components for project {}:\n\n",
+            "{}Found {} components for project {}:\n\n",
+            project.note(),
             components.len(),
             project_key
         ))];
@@ -314,19 +544,16 @@ impl GetIssueTypesTool {
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for GetIssueTypesTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
-        let project_key = args
-            .get("project_key")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: project_key")
-            })?;
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let project_key = project.key.as_str();
 
         info!("Getting issue types for project: {}", project_key);
 
         let issue_types = self.client.get_project_issue_types(project_key).await?;
 
         let mut content = vec![MCPContent::text(format!(
-            "Found {} issue types for project {}:\n\n",
+            "{}Found {} issue types for project {}:\n\n",
+            project.note(),
             issue_types.len(),
             project_key
         ))];
@@ -347,6 +574,56 @@ impl crate::mcp::server::MCPToolHandler for GetIssueTypesTool {
     }
 }
 
+/// Get the issue type hierarchy of a project
+pub struct GetIssueTypeHierarchyTool {
+    client: JiraClient,
+}
+
+impl GetIssueTypeHierarchyTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetIssueTypeHierarchyTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+
+        info!("Getting issue type hierarchy for project: {}", project.key);
+
+        let hierarchy = self.client.get_issue_type_hierarchy(&project.key).await?;
+
+        let mut response_text = format!(
+            "{}Issue type hierarchy of {}, highest level first{}:",
+            project.note(),
+            project.key,
+            if hierarchy.inferred {
+                " (levels inferred, as Jira reports none)"
+            } else {
+                ""
+            }
+        );
+        for (level, issue_types) in hierarchy.levels() {
+            let names: Vec<&str> = issue_types.iter().map(|t| t.name.as_str()).collect();
+            response_text.push_str(&format!("\n• level {level}: {}", names.join(", ")));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![
+                MCPContent::text(response_text),
+                MCPContent::json(&hierarchy),
+            ],
+            is_error: Some(false),
+        })
+    }
+}
+
 /// Get link types
 pub struct GetLinkTypesTool {
     client: JiraClient,
@@ -386,3 +663,263 @@ impl crate::mcp::server::MCPToolHandler for GetLinkTypesTool {
         })
     }
 }
+
+/// Get the workflow (statuses and transitions) for each issue type in a project
+pub struct GetProjectWorkflowTool {
+    client: JiraClient,
+}
+
+impl GetProjectWorkflowTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetProjectWorkflowTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let project_key = project.key.as_str();
+
+        let issue_type_filter = args.get("issue_type").and_then(|v| v.as_str());
+        let example_issue_key = args.get("example_issue_key").and_then(|v| v.as_str());
+
+        info!("Getting workflow for project: {}", project_key);
+
+        let issue_types: Vec<_> = self
+            .client
+            .get_project_statuses(project_key)
+            .await?
+            .into_iter()
+            .filter(|it| issue_type_filter.is_none_or(|name| it.name.eq_ignore_ascii_case(name)))
+            .collect();
+
+        let mut content = vec![MCPContent::text(format!(
+            "{}Workflow for project {} ({} issue types):\n\n",
+            project.note(),
+            project_key,
+            issue_types.len()
+        ))];
+
+        for issue_type in &issue_types {
+            let mut text = format!(
+                "{}{}:\n",
+                issue_type.name,
+                if issue_type.subtask {
+                    " (sub-task)"
+                } else {
+                    ""
+                }
+            );
+            for status in &issue_type.statuses {
+                text.push_str(&format!(
+                    "  • {} [{}]\n",
+                    status.name, status.status_category.name
+                ));
+            }
+            content.push(MCPContent::text(text));
+        }
+
+        // Workflow schemes need admin permissions on most instances, so they are optional
+        match self.client.get_workflow_scheme(project_key).await {
+            Ok(scheme) => {
+                let mut text = format!("\nWorkflow scheme: {}\n", scheme.name);
+                if let Some(default_workflow) = &scheme.default_workflow {
+                    text.push_str(&format!("  Default workflow: {default_workflow}\n"));
+                }
+                for issue_type in &issue_types {
+                    if let Some(workflow) = scheme.issue_type_mappings.get(&issue_type.id) {
+                        text.push_str(&format!("  {}: {}\n", issue_type.name, workflow));
+                    }
+                }
+                content.push(MCPContent::text(text));
+            }
+            Err(e) => {
+                info!("Workflow scheme not available for {}: {}", project_key, e);
+            }
+        }
+
+        if let Some(issue_key) = example_issue_key {
+            let issue = self.client.get_issue(issue_key).await?;
+            let transitions = self.client.get_transitions(issue_key).await?;
+
+            let current_status = issue
+                .fields
+                .get("status")
+                .and_then(|s| s.get("name"))
+                .and_then(|n| n.as_str())
+                .unwrap_or("Unknown status");
+            let issue_type = issue
+                .fields
+                .get("issuetype")
+                .and_then(|t| t.get("name"))
+                .and_then(|n| n.as_str())
+                .unwrap_or("Unknown type");
+
+            let mut text = format!(
+                "\nTransitions from {issue_key} ({issue_type}, currently {current_status}):\n"
+            );
+            if transitions.is_empty() {
+                text.push_str("  No transitions available\n");
+            }
+            for transition in transitions {
+                text.push_str(&format!(
+                    "  • {} (id {}) → {}\n",
+                    transition.name, transition.id, transition.to.name
+                ));
+            }
+            content.push(MCPContent::text(text));
+        }
+
+        Ok(MCPToolResult {
+            content,
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Get the issue security levels of a project
+pub struct GetIssueSecurityLevelsTool {
+    client: JiraClient,
+}
+
+impl GetIssueSecurityLevelsTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetIssueSecurityLevelsTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let project_key = project.key.as_str();
+
+        info!("Getting issue security levels for project: {}", project_key);
+
+        let levels = self.client.get_issue_security_levels(project_key).await?;
+
+        let mut response_text = format!(
+            "{}Found {} issue security levels for project {}:\n\n",
+            project.note(),
+            levels.len(),
+            project_key
+        );
+        for level in levels {
+            response_text.push_str(&format!("• {} (id {})", level.name, level.id));
+            if let Some(description) = level.description.filter(|d| !d.is_empty()) {
+                response_text.push_str(&format!(" - {description}"));
+            }
+            response_text.push('\n');
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// List the fields on the create screen of an issue type in a project
+pub struct ListCreatableFieldsTool {
+    client: JiraClient,
+}
+
+impl ListCreatableFieldsTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ListCreatableFieldsTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let issue_type = args
+            .get("issue_type")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: issue_type")
+            })?;
+        let refresh = args
+            .get("refresh")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        info!(
+            "Listing creatable fields for {} in project {}",
+            issue_type, project.key
+        );
+
+        let meta = self
+            .client
+            .get_create_meta(&project.key, issue_type, refresh)
+            .await?;
+
+        let mut response_text = format!(
+            "{}Create screen of {} (id {}) in project {} has {} fields:\n\n",
+            project.note(),
+            meta.issue_type_name,
+            meta.issue_type_id,
+            meta.project_key,
+            meta.fields.len()
+        );
+        let mut fields: Vec<_> = meta.fields.iter().collect();
+        fields.sort_by_key(|f| !f.required);
+        for field in fields {
+            response_text.push_str(&format!(
+                "• {} ({}) - {}, {}",
+                field.name,
+                field.field_id,
+                if field.required {
+                    "required"
+                } else {
+                    "optional"
+                },
+                schema_type(field.schema.as_ref())
+            ));
+            if let Some(default) = field
+                .default_value
+                .as_ref()
+                .filter(|_| field.has_default_value)
+            {
+                response_text.push_str(&format!("; default: {}", value_label(default)));
+            }
+            if let Some(allowed) = field.allowed_values.as_ref().filter(|v| !v.is_empty()) {
+                let shown: Vec<String> = allowed
+                    .iter()
+                    .take(ALLOWED_VALUES_SAMPLE)
+                    .map(value_label)
+                    .collect();
+                response_text.push_str(&format!("; allowed: {}", shown.join(", ")));
+                if allowed.len() > ALLOWED_VALUES_SAMPLE {
+                    response_text.push_str(&format!(
+                        ", … and {} more",
+                        allowed.len() - ALLOWED_VALUES_SAMPLE
+                    ));
+                }
+            }
+            response_text.push('\n');
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text)],
+            is_error: Some(false),
+        })
+    }
+}
@@ -0,0 +1,520 @@
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+use crate::jira::client::JiraClient;
+use crate::jira::epic_rollup::{
+    epic_children_jql, render_rollup, EpicChild, EpicRollup, EPIC_CHILD_FIELDS,
+};
+use crate::jira::field_history::{field_changes, is_status_like, time_in_values, FieldMatcher};
+use crate::jira::jql::{Jql, SortOrder};
+use crate::jira::project_stats::{
+    median_resolution_hours, top_assignees, CountEntry, ProjectStats, StatsWindow, WeekCounts,
+    RESOLUTION_SAMPLE_SIZE, STATUS_CATEGORIES, TOP_ASSIGNEES,
+};
+use crate::jira::timeline::{build_timeline, timeline_series, timeline_table, IssueHistory};
+use crate::mcp::tools::formatting::cell_text;
+use crate::mcp::tools::projects::ProjectArg;
+use crate::types::mcp::{MCPContent, MCPToolResult};
+use crate::utils::datetime::{parse_timestamp, DateFormatter};
+use crate::utils::time::format_time_spent;
+use chrono::{Duration, NaiveDate};
+use futures::{StreamExt, TryStreamExt};
+use tracing::info;
+
+/// Issues fetched by `status_timeline` unless `max_issues` says otherwise
+pub const DEFAULT_TIMELINE_MAX_ISSUES: usize = 500;
+/// Upper bound on `max_issues`, since every issue is fetched with its changelog
+pub const TIMELINE_ISSUE_LIMIT: usize = 2000;
+/// Longest date range `status_timeline` accepts
+pub const TIMELINE_MAX_DAYS: i64 = 366;
+/// Children `summarize_epic` reads unless `max_children` says otherwise
+pub const DEFAULT_EPIC_MAX_CHILDREN: usize = 500;
+/// Upper bound on `max_children`
+pub const EPIC_CHILD_LIMIT: usize = 2000;
+/// Count queries `get_project_stats` keeps in flight at once
+const STATS_CONCURRENCY: usize = 8;
+
+fn parse_date(args: &serde_json::Value, name: &str) -> Result<Option<NaiveDate>> {
+    args.get(name)
+        .and_then(|v| v.as_str())
+        .map(|value| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+                JiraError::validation_error(
+                    name,
+                    &format!("'{value}' is not a date in YYYY-MM-DD format"),
+                )
+            })
+        })
+        .transpose()
+}
+
+/// Daily issue counts per status category for a JQL query
+pub struct StatusTimelineTool {
+    client: JiraClient,
+}
+
+impl StatusTimelineTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for StatusTimelineTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let jql = args
+            .get("jql")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: jql"))?;
+        let start = parse_date(&args, "start_date")?
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: start_date"))?;
+        let end = parse_date(&args, "end_date")?.unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+        let days = (end - start).num_days() + 1;
+        if days < 1 {
+            return Err(JiraError::validation_error(
+                "end_date",
+                "end_date must not be before start_date",
+            ));
+        }
+        if days > TIMELINE_MAX_DAYS {
+            return Err(JiraError::validation_error(
+                "end_date",
+                &format!("The date range spans {days} days, the limit is {TIMELINE_MAX_DAYS}"),
+            ));
+        }
+
+        let max_issues = args
+            .get("max_issues")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|n| usize::try_from(n).ok())
+            .unwrap_or(DEFAULT_TIMELINE_MAX_ISSUES)
+            .min(TIMELINE_ISSUE_LIMIT);
+
+        info!(
+            "Building status timeline for '{}' from {} to {}",
+            jql, start, end
+        );
+
+        let categories = self.client.get_status_categories().await?;
+        let fields = ["created", "status"].map(ToString::to_string);
+        let fetched = self
+            .client
+            .search_issues_with_changelog(jql, &fields, max_issues)
+            .await?;
+        let issues = &fetched.issues;
+
+        let histories: Vec<IssueHistory> = issues
+            .iter()
+            .filter_map(|issue| IssueHistory::from_json(issue, &categories))
+            .collect();
+        let timeline = build_timeline(&histories, start, end);
+
+        let mut response_text = format!(
+            "Status timeline for {} issues matching `{jql}` from {start} to {end} (end of day, UTC)\n",
+            histories.len()
+        );
+        response_text.push_str(&fetched.note());
+        let skipped = issues.len() - histories.len();
+        if skipped > 0 {
+            response_text.push_str(&format!(
+                "{skipped} issues were skipped because their creation date or status category could not be read\n"
+            ));
+        }
+        response_text.push('\n');
+        response_text.push_str(&timeline_table(&timeline));
+
+        Ok(MCPToolResult {
+            content: vec![
+                MCPContent::text(response_text),
+                MCPContent::text(serde_json::to_string_pretty(&timeline_series(&timeline))?),
+            ],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// The changes made to one field of an issue, oldest first, with the time
+/// spent in each value for status-like fields
+pub struct GetFieldHistoryTool {
+    client: JiraClient,
+}
+
+impl GetFieldHistoryTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetFieldHistoryTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let issue_key = args
+            .get("issue_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: issue_key"))?;
+        let field = args
+            .get("field")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: field"))?;
+
+        let definition = self.client.resolve_field(field).await?;
+        let matcher = FieldMatcher::from_definition(&definition);
+        let field_id = definition
+            .get("id")
+            .and_then(|v| v.as_str())
+            .unwrap_or(field)
+            .to_string();
+        let field_name = definition
+            .get("name")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&field_id)
+            .to_string();
+
+        info!("Reading the history of {} on {}", field_name, issue_key);
+
+        let issue = self
+            .client
+            .get_issue_fields(issue_key, &["created".to_string(), field_id.clone()])
+            .await?;
+        let mut histories = Vec::new();
+        self.client
+            .visit_changelog_pages(issue_key, |page| histories.extend_from_slice(page))
+            .await?;
+        let changes = field_changes(&histories, &matcher);
+
+        let dates = DateFormatter::from_config(self.client.config());
+        let shown = |value: Option<&str>| value.unwrap_or("(none)").to_string();
+        let mut text = match changes.len() {
+            0 => format!("{field_name} ({field_id}) of {issue_key} has never changed"),
+            1 => format!("{field_name} ({field_id}) of {issue_key} changed once"),
+            n => {
+                format!("{field_name} ({field_id}) of {issue_key} changed {n} times, oldest first")
+            }
+        };
+        for change in &changes {
+            text.push_str(&format!(
+                "\n• {} by {}: {} → {}",
+                dates.format(&change.at.fixed_offset()),
+                change.author.as_deref().unwrap_or("unknown"),
+                shown(change.from_display()),
+                shown(change.to_display())
+            ));
+        }
+
+        let current = issue
+            .fields
+            .get(&field_id)
+            .map(cell_text)
+            .filter(|value| !value.is_empty());
+        let created = issue
+            .fields
+            .get("created")
+            .and_then(|v| v.as_str())
+            .and_then(parse_timestamp);
+        let time_in_values = match created {
+            Some(created) if is_status_like(&definition) => {
+                let totals = time_in_values(
+                    created.to_utc(),
+                    current.as_deref(),
+                    &changes,
+                    chrono::Utc::now(),
+                );
+                text.push_str("\n\nTime in each value:");
+                for total in &totals {
+                    text.push_str(&format!(
+                        "\n• {}: {} ({} {})",
+                        shown(total.value.as_deref()),
+                        format_time_spent(total.seconds),
+                        total.times_set,
+                        if total.times_set == 1 {
+                            "time"
+                        } else {
+                            "times"
+                        }
+                    ));
+                }
+                Some(totals)
+            }
+            _ => None,
+        };
+
+        let mut structured = serde_json::json!({
+            "issue_key": issue_key,
+            "field": {"id": field_id, "name": field_name},
+            "changes": changes,
+        });
+        if let Some(totals) = time_in_values {
+            structured["time_in_values"] = serde_json::to_value(totals)?;
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&structured)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Roll-up of an epic's children: status categories, story points done and
+/// remaining, blocked children, recent activity and assignees
+pub struct SummarizeEpicTool {
+    client: JiraClient,
+}
+
+impl SummarizeEpicTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for SummarizeEpicTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let epic_key = args
+            .get("epic_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JiraError::api_error("Missing required parameter: epic_key"))?;
+        let list_children = args
+            .get("list_children")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let max_children = match args.get("max_children").and_then(serde_json::Value::as_u64) {
+            Some(max) => usize::try_from(max)
+                .ok()
+                .filter(|max| (1..=EPIC_CHILD_LIMIT).contains(max))
+                .ok_or_else(|| {
+                    JiraError::validation_error(
+                        "max_children",
+                        &format!("max_children must be between 1 and {EPIC_CHILD_LIMIT}"),
+                    )
+                })?,
+            None => DEFAULT_EPIC_MAX_CHILDREN,
+        };
+        let link_type = args
+            .get("blocker_link_type")
+            .and_then(|v| v.as_str())
+            .or(self.client.config().blocker_link_type.as_deref())
+            .unwrap_or("Blocks")
+            .to_string();
+
+        let epic = self
+            .client
+            .get_issue_fields(epic_key, &["summary".to_string()])
+            .await?;
+        let points_field = match args.get("story_points_field").and_then(|v| v.as_str()) {
+            Some(field) => Some(field.to_string()),
+            None => self.client.story_points_field().await?,
+        };
+        let epic_link_field = self.client.epic_link_field().await;
+        let jql = epic_children_jql(&epic.key, epic_link_field.as_deref());
+
+        info!("Summarizing epic {} with {}", epic.key, jql);
+
+        let mut fields: Vec<String> = EPIC_CHILD_FIELDS.iter().map(ToString::to_string).collect();
+        fields.extend(points_field.clone());
+        let fetched = self
+            .client
+            .search_issues_bounded(&jql, &fields, max_children)
+            .await?;
+        let children: Vec<EpicChild> = fetched
+            .issues
+            .iter()
+            .map(|issue| EpicChild::from_json(issue, points_field.as_deref(), &link_type))
+            .collect();
+        let rollup = EpicRollup::from_children(&children);
+
+        let summary = epic
+            .fields
+            .get("summary")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        let mut text = render_rollup(
+            &epic.key,
+            summary,
+            &children,
+            &rollup,
+            points_field.is_some(),
+            list_children,
+            &DateFormatter::from_config(self.client.config()),
+        );
+        let note = fetched.note();
+        if !note.is_empty() {
+            text.push_str(&format!("\n\n{}", note.trim_end()));
+        }
+        let mut structured = serde_json::json!({
+            "epic_key": epic.key,
+            "points_field": points_field,
+            "rollup": rollup,
+        });
+        if list_children {
+            structured["children"] = serde_json::to_value(&children)?;
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(text), MCPContent::json(&structured)],
+            is_error: Some(false),
+        })
+    }
+}
+
+/// Issue statistics for a project: totals by status category, type and
+/// priority, weekly created/resolved counts, resolution time and top assignees
+pub struct GetProjectStatsTool {
+    client: JiraClient,
+}
+
+impl GetProjectStatsTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetProjectStatsTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let project_key = project.key.as_str();
+        let window = StatsWindow {
+            since: parse_date(&args, "since")?,
+            until: parse_date(&args, "until")?,
+        };
+        if let (Some(since), Some(until)) = (window.since, window.until) {
+            if until < since {
+                return Err(JiraError::validation_error(
+                    "until",
+                    "until must not be before since",
+                ));
+            }
+        }
+
+        info!("Computing statistics for project: {}", project_key);
+
+        let (issue_types, priorities) = tokio::try_join!(
+            self.client.get_project_issue_types(project_key),
+            self.client.get_priorities()
+        )?;
+        let mut type_names: Vec<String> = issue_types.into_iter().map(|t| t.name).collect();
+        type_names.dedup();
+        let priority_names: Vec<String> = priorities.into_iter().map(|p| p.name).collect();
+
+        let created_in_window = window.clauses("created");
+        let count_jql = |field: &str, value: &str| {
+            created_in_window
+                .iter()
+                .fold(Jql::new().project(project_key), |jql, clause| {
+                    jql.raw(clause.clone())
+                })
+                .field_eq(field, value)
+                .build()
+        };
+        let weeks = window.weeks(chrono::Utc::now().date_naive());
+
+        let mut queries: Vec<String> = Vec::new();
+        queries.extend(
+            STATUS_CATEGORIES
+                .iter()
+                .map(|c| count_jql("statusCategory", c)),
+        );
+        queries.extend(type_names.iter().map(|t| count_jql("issuetype", t)));
+        queries.extend(priority_names.iter().map(|p| count_jql("priority", p)));
+        for week in &weeks {
+            for field in ["created", "resolved"] {
+                queries.push(
+                    Jql::new()
+                        .project(project_key)
+                        .field_gte(field, &week.to_string())
+                        .field_lt(field, &(*week + Duration::days(7)).to_string())
+                        .build(),
+                );
+            }
+        }
+
+        let client = &self.client;
+        let counts: Vec<u64> = futures::stream::iter(queries.clone())
+            .map(|jql| async move { client.count_issues(&jql).await })
+            .buffered(STATS_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let resolved_jql = window
+            .clauses("resolved")
+            .into_iter()
+            .fold(Jql::new().project(project_key), Jql::raw)
+            .raw("resolution is not EMPTY")
+            .order_by("resolved", SortOrder::Desc);
+        let sample = self
+            .client
+            .search_issues_with_fields(
+                &resolved_jql.build(),
+                RESOLUTION_SAMPLE_SIZE,
+                &["created", "resolutiondate", "assignee"].map(ToString::to_string),
+            )
+            .await?
+            .issues;
+
+        let mut counts = counts.into_iter();
+        let mut entries = |names: &[String]| -> Vec<CountEntry> {
+            names
+                .iter()
+                .map(|name| CountEntry {
+                    name: name.clone(),
+                    count: counts.next().unwrap_or(0),
+                })
+                .collect()
+        };
+        let by_status_category = entries(&STATUS_CATEGORIES.map(ToString::to_string));
+        let by_issue_type = entries(&type_names);
+        let by_priority = entries(&priority_names);
+        let weekly = weeks
+            .iter()
+            .map(|week| WeekCounts {
+                week_start: *week,
+                created: counts.next().unwrap_or(0),
+                resolved: counts.next().unwrap_or(0),
+            })
+            .collect();
+
+        let stats = ProjectStats {
+            project: project_key.to_string(),
+            since: window.since,
+            until: window.until,
+            total: by_status_category.iter().map(|e| e.count).sum(),
+            by_status_category,
+            by_issue_type,
+            by_priority,
+            weekly,
+            median_resolution_hours: median_resolution_hours(&sample),
+            resolution_sample: sample.len(),
+            top_assignees: top_assignees(&sample, TOP_ASSIGNEES),
+            // Issue types, priorities, the counts and the resolution sample
+            upstream_requests: 2 + queries.len() + 1,
+        };
+
+        Ok(MCPToolResult {
+            content: vec![
+                MCPContent::text(format!("{}{}", project.note(), stats.to_text())),
+                MCPContent::text(serde_json::to_string_pretty(&stats)?),
+            ],
+            is_error: Some(false),
+        })
+    }
+}
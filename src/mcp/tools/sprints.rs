@@ -1,8 +1,11 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
-use crate::types::jira::JiraSprintCreateRequest;
+use crate::jira::sprint_report::{SprintIssue, SprintReport, SPRINT_REPORT_FIELDS};
+use crate::mcp::tools::pagination::PageArgs;
+use crate::types::jira::{JiraSprintCreateRequest, JiraUser};
 use crate::types::mcp::{MCPContent, MCPToolResult};
+use crate::utils::datetime::DateFormatter;
 use std::fmt::Write;
 use tracing::info;
 
@@ -41,15 +44,19 @@ impl crate::mcp::server::MCPToolHandler for GetSprintTool {
                 })?)
                 .await?;
 
+        let dates = DateFormatter::from_config(self.client.config());
+        let not_set = |date: Option<&str>| {
+            date.map_or_else(|| "Not set".to_string(), |d| dates.format_timestamp(d))
+        };
         let response_text = format!(
             "Sprint: {}\nName: {}\nState: {:?}\nStart Date: {}\nEnd Date: {}\nGoal: {}\nURL: {}/secure/RapidBoard.jspa?rapidView={}&view=planning.nodetail&selectedIssue={}",
             sprint.id,
             sprint.name,
             sprint.state,
-            sprint.start_date.as_deref().unwrap_or("Not set"),
-            sprint.end_date.as_deref().unwrap_or("Not set"),
+            not_set(sprint.start_date.as_deref()),
+            not_set(sprint.end_date.as_deref()),
             sprint.goal.as_deref().unwrap_or("No goal set"),
-            self.client.api_base_url().replace("/rest/api/2", ""),
+            self.client.config().site_url(),
             sprint.rapid_view_id.unwrap_or(0),
             sprint.id
         );
@@ -114,7 +121,7 @@ impl crate::mcp::server::MCPToolHandler for CreateSprintTool {
             sprint.id,
             sprint.name,
             sprint.rapid_view_id,
-            self.client.api_base_url().replace("/rest/api/2", ""),
+            self.client.config().site_url(),
             sprint.rapid_view_id,
             sprint.id
         );
@@ -236,17 +243,7 @@ impl crate::mcp::server::MCPToolHandler for GetSprintIssuesTool {
                 crate::error::JiraError::api_error("Missing required parameter: sprint_id")
             })?;
 
-        let start_at = args
-            .get("start_at")
-            .or_else(|| args.get("startAt"))
-            .and_then(serde_json::Value::as_i64)
-            .map(|v| i32::try_from(v).unwrap_or(0));
-
-        let max_results = args
-            .get("max_results")
-            .or_else(|| args.get("maxResults"))
-            .and_then(serde_json::Value::as_i64)
-            .map(|v| i32::try_from(v).unwrap_or(0));
+        let page = PageArgs::from_args(&args, self.client.config())?;
 
         info!("Getting issues for sprint: {}", sprint_id);
 
@@ -256,11 +253,21 @@ impl crate::mcp::server::MCPToolHandler for GetSprintIssuesTool {
                 i32::try_from(sprint_id).map_err(|_| {
                     crate::error::JiraError::api_error("Sprint ID too large for i32")
                 })?,
-                start_at,
-                max_results,
+                page.start_at_i32(),
+                page.max_results_i32(),
             )
             .await?;
 
+        if page.is_count_only() {
+            return Ok(MCPToolResult {
+                content: vec![MCPContent::text(format!(
+                    "Sprint {sprint_id} has {} issues (count only, since max_results is 0)\n",
+                    sprint_issues.total
+                ))],
+                is_error: Some(false),
+            });
+        }
+
         let response_text = format!(
             "Found {} issues in sprint {} (showing {} of {} total)\n\n",
             sprint_issues.issues.len(),
@@ -286,18 +293,16 @@ impl crate::mcp::server::MCPToolHandler for GetSprintIssuesTool {
             let assignee = issue
                 .fields
                 .get("assignee")
-                .and_then(|a| a.get("displayName"))
-                .and_then(|n| n.as_str())
-                .unwrap_or("Unassigned");
+                .filter(|a| !a.is_null())
+                .map_or_else(|| "Unassigned".to_string(), JiraUser::label_of);
 
             let issue_text = format!(
-                "• {} - {}\n  Status: {}\n  Assignee: {}\n  URL: {}/browse/{}\n",
+                "• {} - {}\n  Status: {}\n  Assignee: {}\n  URL: {}\n",
                 issue.key,
                 summary,
                 status,
                 assignee,
-                self.client.api_base_url().replace("/rest/api/2", ""),
-                issue.key
+                self.client.browse_url(&issue.key)
             );
             content.push(MCPContent::text(issue_text));
         }
@@ -349,7 +354,7 @@ impl crate::mcp::server::MCPToolHandler for StartSprintTool {
             sprint.id,
             sprint.name,
             sprint.state,
-            self.client.api_base_url().replace("/rest/api/2", ""),
+            self.client.config().site_url(),
             sprint.rapid_view_id.unwrap_or(0),
             sprint.id
         );
@@ -401,7 +406,7 @@ impl crate::mcp::server::MCPToolHandler for CloseSprintTool {
             sprint.id,
             sprint.name,
             sprint.state,
-            self.client.api_base_url().replace("/rest/api/2", ""),
+            self.client.config().site_url(),
             sprint.rapid_view_id.unwrap_or(0),
             sprint.id
         );
@@ -455,6 +460,10 @@ impl crate::mcp::server::MCPToolHandler for GetBoardSprintsTool {
         );
 
         let mut content = vec![MCPContent::text(response_text)];
+        let dates = DateFormatter::from_config(self.client.config());
+        let not_set = |date: Option<&str>| {
+            date.map_or_else(|| "Not set".to_string(), |d| dates.format_timestamp(d))
+        };
 
         for sprint in sprints {
             let sprint_text = format!(
@@ -462,10 +471,10 @@ impl crate::mcp::server::MCPToolHandler for GetBoardSprintsTool {
                 sprint.id,
                 sprint.name,
                 sprint.state,
-                sprint.start_date.as_deref().unwrap_or("Not set"),
-                sprint.end_date.as_deref().unwrap_or("Not set"),
+                not_set(sprint.start_date.as_deref()),
+                not_set(sprint.end_date.as_deref()),
                 sprint.goal.as_deref().unwrap_or("No goal set"),
-                self.client.api_base_url().replace("/rest/api/2", ""),
+                self.client.config().site_url(),
                 rapid_view_id,
                 sprint.id
             );
@@ -478,3 +487,88 @@ impl crate::mcp::server::MCPToolHandler for GetBoardSprintsTool {
         })
     }
 }
+
+/// Summarize a sprint: committed vs completed, carry-over and points per assignee
+pub struct SprintReportTool {
+    client: JiraClient,
+}
+
+impl SprintReportTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for SprintReportTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let board_id = args
+            .get("board_id")
+            .and_then(serde_json::Value::as_i64)
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: board_id")
+            })?;
+        let board_id = i32::try_from(board_id)
+            .map_err(|_| crate::error::JiraError::api_error("Board ID too large for i32"))?;
+        let sprint_arg = args.get("sprint_id").ok_or_else(|| {
+            crate::error::JiraError::api_error("Missing required parameter: sprint_id")
+        })?;
+
+        let sprint = match (sprint_arg.as_i64(), sprint_arg.as_str()) {
+            (Some(id), _) => {
+                self.client
+                    .get_sprint(i32::try_from(id).map_err(|_| {
+                        crate::error::JiraError::api_error("Sprint ID too large for i32")
+                    })?)
+                    .await?
+            }
+            (None, Some(s)) if s.eq_ignore_ascii_case("active") => {
+                self.client.get_active_sprint(board_id).await?
+            }
+            _ => {
+                return Err(crate::error::JiraError::validation_error(
+                    "sprint_id",
+                    "sprint_id must be a sprint id or \"active\"",
+                ))
+            }
+        };
+
+        info!(
+            "Building report for sprint {} on board {}",
+            sprint.id, board_id
+        );
+
+        let points_field = match args.get("story_points_field").and_then(|v| v.as_str()) {
+            Some(field) => Some(field.to_string()),
+            None => self.client.story_points_field().await?,
+        };
+
+        let mut fields: Vec<String> = SPRINT_REPORT_FIELDS
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        fields.extend(points_field.clone());
+
+        let issues = self
+            .client
+            .get_sprint_issues_with_changelog(sprint.id, &fields)
+            .await?
+            .iter()
+            .map(|issue| SprintIssue::from_json(issue, &sprint, points_field.as_deref()))
+            .collect();
+
+        let report = SprintReport::new(sprint, issues, points_field);
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(
+                report.to_markdown(&DateFormatter::from_config(self.client.config())),
+            )],
+            is_error: Some(false),
+        })
+    }
+}
@@ -1,6 +1,8 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
+use crate::mcp::tools::formatting::ResponseFormatter;
+use crate::types::jira::TransitionOptions;
 use crate::types::mcp::{MCPContent, MCPToolResult};
 use tracing::info;
 
@@ -30,6 +32,8 @@ impl crate::mcp::server::MCPToolHandler for GetTransitionsTool {
                 crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
 
+        let output = ResponseFormatter::from_args(&args, self.client.config())?;
+
         info!("Getting transitions for issue: {}", issue_key);
 
         let transitions = self.client.get_transitions(issue_key).await?;
@@ -40,13 +44,21 @@ impl crate::mcp::server::MCPToolHandler for GetTransitionsTool {
             issue_key
         ))];
 
-        for transition in transitions {
+        for transition in &transitions {
+            if output.is_minimal() {
+                content[0].text.push_str(&output.item_line(
+                    &transition.id,
+                    &format!("{} → {}", transition.name, transition.to.name),
+                ));
+                continue;
+            }
             let transition_text = format!(
                 "• {} - {} (ID: {})\n",
                 transition.name, transition.to.name, transition.id
             );
             content.push(MCPContent::text(transition_text));
         }
+        output.push_raw(&mut content, &transitions);
 
         Ok(MCPToolResult {
             content,
@@ -81,14 +93,27 @@ impl crate::mcp::server::MCPToolHandler for TransitionIssueTool {
                 crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
 
-        let transition_id = args
-            .get("transition_id")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: transition_id")
-            })?;
-
-        let comment = args.get("comment").and_then(|v| v.as_str());
+        let (transition_id, transition_label) =
+            if let Some(id) = args.get("transition_id").and_then(|v| v.as_str()) {
+                (id.to_string(), format!("transition {id}"))
+            } else if let Some(name) = args.get("transition_name").and_then(|v| v.as_str()) {
+                let transition = self.client.resolve_transition(issue_key, name).await?;
+                let label = format!("'{}' (transition {})", transition.name, transition.id);
+                (transition.id, label)
+            } else {
+                return Err(crate::error::JiraError::api_error(
+                    "Missing required parameter: transition_id or transition_name",
+                ));
+            };
+
+        let options = TransitionOptions {
+            comment: args
+                .get("comment")
+                .and_then(|v| v.as_str())
+                .map(ToString::to_string),
+            fields: args.get("fields").and_then(|v| v.as_object()).cloned(),
+            resolution: args.get("resolution").cloned(),
+        };
 
         info!(
             "Transitioning issue {} to transition {}",
@@ -96,11 +121,18 @@ impl crate::mcp::server::MCPToolHandler for TransitionIssueTool {
         );
 
         self.client
-            .transition_issue(issue_key, transition_id, comment)
+            .transition_issue(issue_key, &transition_id, &options)
             .await?;
 
-        let response_text =
-            format!("Issue {issue_key} transitioned successfully to transition {transition_id}");
+        let mut response_text =
+            format!("Issue {issue_key} transitioned successfully to {transition_label}");
+        if let Some(note) = self
+            .client
+            .signature_note()
+            .filter(|_| options.comment.is_some())
+        {
+            response_text.push_str(&format!("\n{note}"));
+        }
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
@@ -1,9 +1,56 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
+use crate::mcp::tools::bulk::bulk_config_for;
+use crate::mcp::tools::pagination::PageArgs;
+use crate::types::jira::{JiraWatcher, JiraWatchersResponse};
 use crate::types::mcp::{MCPContent, MCPToolResult};
 use tracing::info;
 
+/// The lines listing `watchers` and where the next page starts. Servers that
+/// page the list echo `startAt`; for the others the page is cut from the
+/// whole list here.
+fn watcher_page_text(
+    watchers: &[JiraWatcher],
+    response: &JiraWatchersResponse,
+    start_at: u32,
+    max_results: Option<u32>,
+) -> String {
+    let server_paged = response.start_at.is_some();
+    let (offset, page, available) = if server_paged {
+        let count = usize::try_from(response.watch_count).unwrap_or(0);
+        (response.start_at.unwrap_or(0) as usize, watchers, count)
+    } else {
+        let start = (start_at as usize).min(watchers.len());
+        let end = max_results.map_or(watchers.len(), |max| {
+            start.saturating_add(max as usize).min(watchers.len())
+        });
+        (start, &watchers[start..end], watchers.len())
+    };
+
+    let mut text = String::new();
+    for watcher in page {
+        if watcher.account_id.is_empty() || watcher.display_name.is_none() {
+            text.push_str(&format!("• {}\n", watcher.label()));
+        } else {
+            text.push_str(&format!("• {} ({})\n", watcher.label(), watcher.account_id));
+        }
+    }
+    let listed = i32::try_from(watchers.len()).unwrap_or(i32::MAX);
+    if !server_paged && listed < response.watch_count {
+        text.push_str(&format!(
+            "Only {listed} of {} watchers are listed; the others watch through a group \
+             or are hidden from you\n",
+            response.watch_count
+        ));
+    }
+    let next = offset + page.len();
+    if !page.is_empty() && next < available {
+        text.push_str(&format!("More watchers: call again with start_at={next}\n"));
+    }
+    text
+}
+
 /// Get watchers for a Jira issue
 pub struct GetIssueWatchersTool {
     client: JiraClient,
@@ -31,14 +78,40 @@ impl crate::mcp::server::MCPToolHandler for GetIssueWatchersTool {
                 crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
 
+        let PageArgs {
+            start_at,
+            max_results,
+        } = PageArgs::from_args(&args, self.client.config())?;
+
         info!("Getting watchers for issue: {}", issue_key);
 
-        let watchers = self.client.get_issue_watchers(issue_key).await?;
+        let resolved = self.client.resolve_issue_key(issue_key).await;
+        let response = self
+            .client
+            .get_issue_watchers_page(&resolved.key, start_at, max_results)
+            .await?;
 
-        let response_text = format!(
-            "Issue {} has {} watchers\n",
-            issue_key, watchers.watch_count
+        let mut response_text = format!(
+            "{}Issue {} has {} watchers\n",
+            resolved.note(),
+            resolved.key,
+            response.watch_count
         );
+        match &response.watchers {
+            None => response_text.push_str(&format!(
+                "Watcher identities hidden by permissions (count: {})\n",
+                response.watch_count
+            )),
+            Some(_) if max_results == Some(0) => {}
+            Some(watchers) => {
+                response_text.push_str(&watcher_page_text(
+                    watchers,
+                    &response,
+                    start_at.unwrap_or(0),
+                    max_results,
+                ));
+            }
+        }
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
@@ -83,9 +156,16 @@ impl crate::mcp::server::MCPToolHandler for AddIssueWatcherTool {
 
         info!("Adding watcher {} to issue: {}", account_id, issue_key);
 
-        self.client.add_issue_watcher(issue_key, account_id).await?;
+        let resolved = self.client.resolve_issue_key(issue_key).await;
+        self.client
+            .add_issue_watcher(&resolved.key, account_id)
+            .await?;
 
-        let response_text = format!("Watcher {account_id} added successfully to issue {issue_key}");
+        let response_text = format!(
+            "{}Watcher {account_id} added successfully to issue {}",
+            resolved.note(),
+            resolved.key
+        );
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
@@ -130,12 +210,16 @@ impl crate::mcp::server::MCPToolHandler for RemoveIssueWatcherTool {
 
         info!("Removing watcher {} from issue: {}", account_id, issue_key);
 
+        let resolved = self.client.resolve_issue_key(issue_key).await;
         self.client
-            .remove_issue_watcher(issue_key, account_id)
+            .remove_issue_watcher(&resolved.key, account_id)
             .await?;
 
-        let response_text =
-            format!("Watcher {account_id} removed successfully from issue {issue_key}");
+        let response_text = format!(
+            "{}Watcher {account_id} removed successfully from issue {}",
+            resolved.note(),
+            resolved.key
+        );
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
@@ -143,3 +227,163 @@ impl crate::mcp::server::MCPToolHandler for RemoveIssueWatcherTool {
         })
     }
 }
+
+/// Issue keys from `issue_key` or, for the bulk form, `issue_keys`
+fn issue_keys_arg(args: &serde_json::Value) -> Result<Vec<String>> {
+    if let Some(issue_keys) = args.get("issue_keys").and_then(|v| v.as_array()) {
+        return Ok(issue_keys
+            .iter()
+            .filter_map(|v| v.as_str().map(ToString::to_string))
+            .collect());
+    }
+    args.get("issue_key")
+        .and_then(|v| v.as_str())
+        .map(|key| vec![key.to_string()])
+        .ok_or_else(|| {
+            crate::error::JiraError::api_error(
+                "Missing required parameter: issue_key or issue_keys",
+            )
+        })
+}
+
+/// Shared body of `watch_jira_issue` and `unwatch_jira_issue`: add or remove
+/// `account_id`, or the current user when it is omitted, and report the
+/// resulting watcher counts
+async fn update_watch(
+    client: &JiraClient,
+    args: &serde_json::Value,
+    watch: bool,
+) -> Result<MCPToolResult> {
+    let issue_keys = issue_keys_arg(args)?;
+    let account_id = args.get("account_id").and_then(|v| v.as_str());
+    let watcher = match account_id {
+        Some(account_id) => account_id.to_string(),
+        None => client.current_user_watcher_id().await?,
+    };
+    let who = account_id.map_or_else(|| "You".to_string(), ToString::to_string);
+    let verb = if watch {
+        if account_id.is_some() {
+            "is now watching"
+        } else {
+            "are now watching"
+        }
+    } else if account_id.is_some() {
+        "is no longer watching"
+    } else {
+        "are no longer watching"
+    };
+
+    info!(
+        "{} watcher {} on {} issues",
+        if watch { "Adding" } else { "Removing" },
+        watcher,
+        issue_keys.len()
+    );
+
+    if !args
+        .get("issue_keys")
+        .is_some_and(serde_json::Value::is_array)
+    {
+        let resolved = client.resolve_issue_key(&issue_keys[0]).await;
+        let issue_key = &resolved.key;
+        if watch {
+            client.add_issue_watcher(issue_key, &watcher).await?;
+        } else {
+            client.remove_issue_watcher(issue_key, &watcher).await?;
+        }
+        let watchers = client.get_issue_watchers(issue_key).await?;
+
+        return Ok(MCPToolResult {
+            content: vec![MCPContent::text(format!(
+                "{}{who} {verb} {issue_key} ({} watchers)",
+                resolved.note(),
+                watchers.watch_count
+            ))],
+            is_error: Some(false),
+        });
+    }
+
+    let settings = bulk_config_for(client.config(), &issue_keys, args)?;
+    let summary = client
+        .bulk_update_watchers(issue_keys, &watcher, watch, Some(settings.config.clone()))
+        .await?;
+
+    let mut response_text = format!(
+        "{who} {verb} {} of {} issues:\n",
+        summary.successful_operations, summary.total_operations
+    );
+    for result in &summary.results {
+        if result.success {
+            let watchers = client.get_issue_watchers(&result.issue_key).await?;
+            response_text.push_str(&format!(
+                "• {} ({} watchers)\n",
+                result.issue_key, watchers.watch_count
+            ));
+        } else {
+            response_text.push_str(&format!(
+                "• {} failed: {}\n",
+                result.issue_key,
+                result.error_message.as_deref().unwrap_or("unknown error")
+            ));
+        }
+    }
+
+    if settings.from_bulk_defaults() {
+        response_text.push_str(&settings.describe());
+    }
+
+    let mut report = serde_json::to_value(&summary)?;
+    report["settings"] = serde_json::json!(settings);
+    Ok(MCPToolResult {
+        content: vec![MCPContent::text(response_text), MCPContent::json(&report)],
+        is_error: Some(summary.successful_operations == 0),
+    })
+}
+
+/// Watch Jira issues as the current user, or on behalf of `account_id`
+pub struct WatchIssueTool {
+    client: JiraClient,
+}
+
+impl WatchIssueTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for WatchIssueTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        update_watch(&self.client, &args, true).await
+    }
+}
+
+/// Stop watching Jira issues as the current user, or on behalf of `account_id`
+pub struct UnwatchIssueTool {
+    client: JiraClient,
+}
+
+impl UnwatchIssueTool {
+    #[must_use]
+    /// # Panics
+    ///
+    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for UnwatchIssueTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        update_watch(&self.client, &args, false).await
+    }
+}
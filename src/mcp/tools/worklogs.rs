@@ -1,21 +1,212 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
+use crate::jira::worklogs::{worklog_provider, WorklogProvider};
+use crate::mcp::tools::comments::{author_matches, rich_text_body};
+use crate::mcp::tools::formatting::{rich_text, FieldFormatter};
+use crate::types::jira::JiraWorkLogEstimateAdjustment;
 use crate::types::mcp::{MCPContent, MCPToolResult};
+use crate::utils::datetime::{parse_timestamp, DateFormatter};
+use crate::utils::duration::{normalize_duration, WorkCalendar};
+use crate::utils::time::{format_time_spent, parse_date_or_timestamp};
+use chrono::{DateTime, Utc};
 use tracing::info;
 
+/// Which worklogs `get_jira_issue_work_logs` returns
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkLogFilter {
+    /// Keep worklogs started at or after this instant
+    pub started_after: Option<DateTime<Utc>>,
+    /// Keep worklogs started before this instant
+    pub started_before: Option<DateTime<Utc>>,
+    /// Keep worklogs whose author has this account id, username, key, email
+    /// address or display name, ignoring case
+    pub author: Option<String>,
+}
+
+impl WorkLogFilter {
+    /// Read the `started_after` and `started_before` (`YYYY-MM-DD` or an ISO
+    /// timestamp) and `author` arguments of a tool call
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if a bound is neither a date nor a
+    /// timestamp.
+    pub fn from_args(args: &serde_json::Value) -> Result<Self> {
+        let bound = |field: &str| {
+            args.get(field)
+                .and_then(|v| v.as_str())
+                .map(|value| parse_date_or_timestamp(field, value))
+                .transpose()
+        };
+        Ok(Self {
+            started_after: bound("started_after")?,
+            started_before: bound("started_before")?,
+            author: args
+                .get("author")
+                .and_then(|v| v.as_str())
+                .map(ToString::to_string),
+        })
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.started_after.is_none() && self.started_before.is_none() && self.author.is_none()
+    }
+
+    /// Whether the raw worklog `work_log` passes the filter
+    #[must_use]
+    pub fn matches(&self, work_log: &serde_json::Value) -> bool {
+        let started = work_log
+            .get("started")
+            .and_then(|v| v.as_str())
+            .and_then(parse_timestamp);
+        let after_ok = self
+            .started_after
+            .is_none_or(|after| started.is_some_and(|started| started >= after));
+        let before_ok = self
+            .started_before
+            .is_none_or(|before| started.is_some_and(|started| started < before));
+        let author_ok = self
+            .author
+            .as_deref()
+            .is_none_or(|wanted| author_matches(work_log.get("author"), wanted));
+        after_ok && before_ok && author_ok
+    }
+
+    /// The filter in words, e.g. "by alice started after 2024-03-04T00:00:00+00:00"
+    fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(author) = &self.author {
+            parts.push(format!("by {author}"));
+        }
+        if let Some(after) = self.started_after {
+            parts.push(format!("started after {}", after.to_rfc3339()));
+        }
+        if let Some(before) = self.started_before {
+            parts.push(format!("started before {}", before.to_rfc3339()));
+        }
+        parts.join(" ")
+    }
+}
+
+/// Read the `adjust_estimate` argument (`auto`, `leave`, `new` or, when
+/// adding, `manual`) with the `new_estimate` and `reduce_by` durations it
+/// needs. Either duration on its own implies its mode.
+///
+/// # Errors
+///
+/// Returns a validation error for an unknown mode, a mode without its
+/// duration, a duration the mode does not use, or a duration that does not
+/// parse.
+pub fn estimate_adjustment_from_args(
+    args: &serde_json::Value,
+    calendar: &WorkCalendar,
+    allow_manual: bool,
+) -> Result<JiraWorkLogEstimateAdjustment> {
+    let duration = |field: &str| {
+        args.get(field)
+            .and_then(|v| v.as_str())
+            .map(|value| normalize_duration(field, value, calendar))
+            .transpose()
+    };
+    let new_estimate = duration("new_estimate")?;
+    let reduce_by = duration("reduce_by")?;
+    let mode = match args.get("adjust_estimate").and_then(|v| v.as_str()) {
+        Some(mode) => mode.trim().to_lowercase(),
+        None if new_estimate.is_some() => "new".to_string(),
+        None if reduce_by.is_some() => "manual".to_string(),
+        None => "auto".to_string(),
+    };
+    let modes = if allow_manual {
+        "auto, leave, new or manual"
+    } else {
+        "auto, leave or new"
+    };
+
+    let unused = |field: &str, needs: &str| {
+        crate::error::JiraError::validation_error(
+            field,
+            &format!("{field} is only used with adjust_estimate '{needs}'"),
+        )
+    };
+    if new_estimate.is_some() && mode != "new" {
+        return Err(unused("new_estimate", "new"));
+    }
+    if reduce_by.is_some() && mode != "manual" {
+        return Err(unused("reduce_by", "manual"));
+    }
+
+    match mode.as_str() {
+        "auto" => Ok(JiraWorkLogEstimateAdjustment::Auto),
+        "leave" => Ok(JiraWorkLogEstimateAdjustment::Leave),
+        "new" => new_estimate
+            .map(JiraWorkLogEstimateAdjustment::New)
+            .ok_or_else(|| {
+                crate::error::JiraError::validation_error(
+                    "new_estimate",
+                    "adjust_estimate 'new' needs new_estimate, e.g. 2d 4h",
+                )
+            }),
+        "manual" if allow_manual => reduce_by
+            .map(JiraWorkLogEstimateAdjustment::Manual)
+            .ok_or_else(|| {
+                crate::error::JiraError::validation_error(
+                    "reduce_by",
+                    "adjust_estimate 'manual' needs reduce_by, e.g. 1h",
+                )
+            }),
+        _ => Err(crate::error::JiraError::validation_error(
+            "adjust_estimate",
+            &format!("Unknown estimate adjustment '{mode}'; expected {modes}"),
+        )),
+    }
+}
+
+/// The response lines for the time spent and the estimate adjustment
+fn time_spent_lines(
+    normalized: &str,
+    given: &str,
+    estimate: &JiraWorkLogEstimateAdjustment,
+) -> String {
+    let mut lines = if normalized == given.trim() {
+        format!("\nTime spent: {normalized}")
+    } else {
+        format!(
+            "\nTime spent: {normalized} (normalized from '{}')",
+            given.trim()
+        )
+    };
+    match estimate {
+        JiraWorkLogEstimateAdjustment::Auto => {}
+        JiraWorkLogEstimateAdjustment::Leave => {
+            lines.push_str("\nRemaining estimate: left unchanged");
+        }
+        JiraWorkLogEstimateAdjustment::New(estimate) => {
+            lines.push_str(&format!("\nRemaining estimate: set to {estimate}"));
+        }
+        JiraWorkLogEstimateAdjustment::Manual(reduce_by) => {
+            lines.push_str(&format!("\nRemaining estimate: reduced by {reduce_by}"));
+        }
+    }
+    lines
+}
+
 /// Get work logs for a Jira issue
 pub struct GetIssueWorkLogsTool {
     client: JiraClient,
+    worklogs: Box<dyn WorklogProvider>,
 }
 
 impl GetIssueWorkLogsTool {
     #[must_use]
     /// # Panics
     ///
-    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    /// Panics if the `JiraClient` or the worklog provider cannot be created
+    /// from the provided configuration.
     pub fn new(config: JiraConfig) -> Self {
         Self {
+            worklogs: worklog_provider(&config).expect("Failed to create worklog provider"),
             client: JiraClient::new(config).expect("Failed to create JiraClient"),
         }
     }
@@ -30,22 +221,84 @@ impl crate::mcp::server::MCPToolHandler for GetIssueWorkLogsTool {
             .ok_or_else(|| {
                 crate::error::JiraError::api_error("Missing required parameter: issue_key")
             })?;
+        let filter = WorkLogFilter::from_args(&args)?;
 
         info!("Getting work logs for issue: {}", issue_key);
 
-        let work_logs = self.client.get_issue_work_logs(issue_key).await?;
-
-        let mut content = vec![MCPContent::text(format!(
-            "Found {} work logs for issue {}\n\n",
-            work_logs.len(),
-            issue_key
-        ))];
+        let resolved = self.client.resolve_issue_key(issue_key).await;
+        let issue_key = resolved.key.as_str();
+        // `startedAfter` narrows what Jira sends; the filter is still applied
+        // here for the other bounds and for versions that ignore it
+        let limit = self.client.config().max_worklogs();
+        let (all, total) = self
+            .worklogs
+            .get_work_logs(issue_key, filter.started_after, limit)
+            .await?;
+        let considered = all.len();
+        let work_logs: Vec<serde_json::Value> =
+            all.into_iter().filter(|w| filter.matches(w)).collect();
+        let seconds: u64 = work_logs
+            .iter()
+            .filter_map(|w| {
+                w.get("timeSpentSeconds")
+                    .and_then(serde_json::Value::as_u64)
+            })
+            .sum();
+
+        let mut header = resolved.note();
+        header.push_str(&if filter.is_empty() {
+            format!(
+                "Found {} work logs for issue {issue_key}: {} logged",
+                work_logs.len(),
+                format_time_spent(seconds)
+            )
+        } else {
+            format!(
+                "Found {} of {considered} work logs for issue {issue_key} {}: {} logged",
+                work_logs.len(),
+                filter.describe(),
+                format_time_spent(seconds)
+            )
+        });
+        if considered < total {
+            header.push_str(&format!(
+                "\nOnly the first {considered} of {total} work logs were read \
+                 (JIRA_MAX_WORKLOGS is {limit})"
+            ));
+        }
+        header.push_str("\n\n");
+        let mut content = vec![MCPContent::text(header)];
+
+        let formatter = FieldFormatter::from_config(self.client.config());
+        let dates = DateFormatter::from_config(self.client.config());
+        let text = |work_log: &serde_json::Value, pointer: &str| {
+            work_log
+                .pointer(pointer)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
 
-        for work_log in work_logs {
-            let work_log_text = format!(
+        for work_log in &work_logs {
+            let mut work_log_text = format!(
                 "• {} - {} by {} on {}\n",
-                work_log.id, work_log.time_spent, work_log.author.display_name, work_log.created
+                text(work_log, "/id"),
+                text(work_log, "/timeSpent"),
+                text(work_log, "/author/displayName"),
+                dates.format_timestamp(&text(work_log, "/started"))
             );
+            if let Some(comment) = work_log
+                .get("comment")
+                .and_then(rich_text)
+                .filter(|c| !c.is_empty())
+            {
+                work_log_text.push_str(&formatter.format_field(
+                    &comment,
+                    issue_key,
+                    &format!("worklog:{}", text(work_log, "/id")),
+                ));
+                work_log_text.push('\n');
+            }
             content.push(MCPContent::text(work_log_text));
         }
 
@@ -59,15 +312,18 @@ impl crate::mcp::server::MCPToolHandler for GetIssueWorkLogsTool {
 /// Add a work log to a Jira issue
 pub struct AddWorkLogTool {
     client: JiraClient,
+    worklogs: Box<dyn WorklogProvider>,
 }
 
 impl AddWorkLogTool {
     #[must_use]
     /// # Panics
     ///
-    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    /// Panics if the `JiraClient` or the worklog provider cannot be created
+    /// from the provided configuration.
     pub fn new(config: JiraConfig) -> Self {
         Self {
+            worklogs: worklog_provider(&config).expect("Failed to create worklog provider"),
             client: JiraClient::new(config).expect("Failed to create JiraClient"),
         }
     }
@@ -90,30 +346,45 @@ impl crate::mcp::server::MCPToolHandler for AddWorkLogTool {
                 crate::error::JiraError::api_error("Missing required parameter: time_spent")
             })?;
 
+        let calendar = WorkCalendar::from_config(self.client.config());
+        let normalized = normalize_duration("time_spent", time_spent, &calendar)?;
+        let estimate = estimate_adjustment_from_args(&args, &calendar, true)?;
         let comment = args.get("comment").and_then(|v| v.as_str());
         let started = args.get("started").and_then(|v| v.as_str());
 
         info!("Adding work log to issue: {}", issue_key);
 
+        let resolved = self.client.resolve_issue_key(issue_key).await;
+        let issue_key = resolved.key.as_str();
+
+        let (text, on_behalf_of) =
+            rich_text_body(&self.client, &args, comment.unwrap_or_default()).await?;
         let work_log_request = crate::types::jira::JiraWorkLogCreateRequest {
-            time_spent: time_spent.to_string(),
-            comment: comment.map(ToString::to_string),
+            time_spent: normalized.clone(),
+            comment: (comment.is_some() || on_behalf_of.is_some()).then_some(text),
             started: started.map(ToString::to_string),
             visibility: None,
         };
 
         let created_work_log = self
-            .client
-            .add_work_log(issue_key, &work_log_request)
+            .worklogs
+            .add_work_log(issue_key, &work_log_request, &estimate)
             .await?;
 
-        let response_text = format!(
-            "Work log added successfully to issue {}\nWork log ID: {}\nTime spent: {}\nAuthor: {}",
+        let mut response_text = format!(
+            "{}Work log added successfully to issue {}\nWork log ID: {}{}\nAuthor: {}",
+            resolved.note(),
             issue_key,
             created_work_log.id,
-            created_work_log.time_spent,
-            created_work_log.author.display_name
+            time_spent_lines(&normalized, time_spent, &estimate),
+            created_work_log.author.label()
         );
+        if let Some(name) = on_behalf_of {
+            response_text.push_str(&format!("\nOn behalf of: {name}"));
+        }
+        if let Some(note) = self.client.signature_note() {
+            response_text.push_str(&format!("\n{note}"));
+        }
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
@@ -125,15 +396,18 @@ impl crate::mcp::server::MCPToolHandler for AddWorkLogTool {
 /// Update a work log
 pub struct UpdateWorkLogTool {
     client: JiraClient,
+    worklogs: Box<dyn WorklogProvider>,
 }
 
 impl UpdateWorkLogTool {
     #[must_use]
     /// # Panics
     ///
-    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    /// Panics if the `JiraClient` or the worklog provider cannot be created
+    /// from the provided configuration.
     pub fn new(config: JiraConfig) -> Self {
         Self {
+            worklogs: worklog_provider(&config).expect("Failed to create worklog provider"),
             client: JiraClient::new(config).expect("Failed to create JiraClient"),
         }
     }
@@ -163,24 +437,45 @@ impl crate::mcp::server::MCPToolHandler for UpdateWorkLogTool {
                 crate::error::JiraError::api_error("Missing required parameter: time_spent")
             })?;
 
+        let calendar = WorkCalendar::from_config(self.client.config());
+        let normalized = normalize_duration("time_spent", time_spent, &calendar)?;
+        let estimate = estimate_adjustment_from_args(&args, &calendar, false)?;
         let comment = args.get("comment").and_then(|v| v.as_str());
         let started = args.get("started").and_then(|v| v.as_str());
 
         info!("Updating work log {} for issue: {}", work_log_id, issue_key);
 
+        let resolved = self.client.resolve_issue_key(issue_key).await;
+        let issue_key = resolved.key.as_str();
+
+        let (text, on_behalf_of) =
+            rich_text_body(&self.client, &args, comment.unwrap_or_default()).await?;
         let update_request = crate::types::jira::JiraWorkLogUpdateRequest {
-            time_spent: Some(time_spent.to_string()),
-            comment: comment.map(ToString::to_string),
+            time_spent: Some(normalized.clone()),
+            comment: (comment.is_some() || on_behalf_of.is_some()).then_some(text),
             started: started.map(ToString::to_string),
             visibility: None,
         };
 
-        self.client
-            .update_work_log(issue_key, work_log_id, &update_request)
+        self.worklogs
+            .update_work_log(issue_key, work_log_id, &update_request, &estimate)
             .await?;
 
-        let response_text =
-            format!("Work log {work_log_id} updated successfully for issue {issue_key}");
+        let mut response_text = format!(
+            "{}Work log {work_log_id} updated successfully for issue {issue_key}{}",
+            resolved.note(),
+            time_spent_lines(&normalized, time_spent, &estimate)
+        );
+        if let Some(name) = on_behalf_of {
+            response_text.push_str(&format!("\nOn behalf of: {name}"));
+        }
+        if let Some(note) = self
+            .client
+            .signature_note()
+            .filter(|_| update_request.comment.is_some())
+        {
+            response_text.push_str(&format!("\n{note}"));
+        }
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
@@ -192,15 +487,18 @@ impl crate::mcp::server::MCPToolHandler for UpdateWorkLogTool {
 /// Delete a work log
 pub struct DeleteWorkLogTool {
     client: JiraClient,
+    worklogs: Box<dyn WorklogProvider>,
 }
 
 impl DeleteWorkLogTool {
     #[must_use]
     /// # Panics
     ///
-    /// Panics if the `JiraClient` cannot be created from the provided configuration.
+    /// Panics if the `JiraClient` or the worklog provider cannot be created
+    /// from the provided configuration.
     pub fn new(config: JiraConfig) -> Self {
         Self {
+            worklogs: worklog_provider(&config).expect("Failed to create worklog provider"),
             client: JiraClient::new(config).expect("Failed to create JiraClient"),
         }
     }
@@ -225,10 +523,16 @@ impl crate::mcp::server::MCPToolHandler for DeleteWorkLogTool {
 
         info!("Deleting work log {} for issue: {}", work_log_id, issue_key);
 
-        self.client.delete_work_log(issue_key, work_log_id).await?;
+        let resolved = self.client.resolve_issue_key(issue_key).await;
+        self.worklogs
+            .delete_work_log(&resolved.key, work_log_id)
+            .await?;
 
-        let response_text =
-            format!("Work log {work_log_id} deleted successfully from issue {issue_key}");
+        let response_text = format!(
+            "{}Work log {work_log_id} deleted successfully from issue {}",
+            resolved.note(),
+            resolved.key
+        );
 
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
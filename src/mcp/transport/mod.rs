@@ -0,0 +1,3 @@
+pub mod response_limit;
+pub mod stdio;
+pub mod webhook;
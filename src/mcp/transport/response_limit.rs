@@ -0,0 +1,144 @@
+//! Cap on the size of the messages the server writes.
+//!
+//! Some MCP clients stall or drop the pipe on a message of several megabytes,
+//! as a search export returned inline can be. A response larger than
+//! `max_response_bytes` is replaced before it is written: a tool result by an
+//! error result, anything else by a JSON-RPC error, both giving the size, the
+//! start of the text and how to get the data another way.
+
+use serde_json::{json, Value};
+
+/// `max_response_bytes` when not configured: 4 MiB
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Smallest `max_response_bytes` accepted, so that the replacement itself fits
+pub const MIN_MAX_RESPONSE_BYTES: usize = 4096;
+
+/// Longest start of the text a replacement shows
+pub const PREVIEW_CHARS: usize = 2000;
+
+/// A reply serialized for writing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedReply {
+    pub text: String,
+    /// Size of the reply before it was replaced
+    pub original_bytes: usize,
+    /// Whether the reply, or an entry of a batch, was replaced
+    pub replaced: bool,
+}
+
+/// Serialize `reply`, replacing it (or for a batch, its largest entries)
+/// when it comes to more than `max_bytes`; `None` or 0 leaves it whole
+///
+/// # Errors
+///
+/// Returns an error if the reply cannot be serialized.
+pub fn encode_reply(reply: &Value, max_bytes: Option<usize>) -> serde_json::Result<EncodedReply> {
+    let text = serde_json::to_string(reply)?;
+    let original_bytes = text.len();
+    let Some(max_bytes) = max_bytes.filter(|max| *max > 0 && original_bytes > *max) else {
+        return Ok(EncodedReply {
+            text,
+            original_bytes,
+            replaced: false,
+        });
+    };
+
+    let replacement = match reply {
+        Value::Array(entries) => {
+            let mut sized: Vec<(usize, Value)> = entries
+                .iter()
+                .map(|entry| Ok((serde_json::to_string(entry)?.len(), entry.clone())))
+                .collect::<serde_json::Result<_>>()?;
+            let mut order: Vec<usize> = (0..sized.len()).collect();
+            order.sort_by_key(|&i| std::cmp::Reverse(sized[i].0));
+            let mut total = original_bytes;
+            for i in order {
+                if total <= max_bytes {
+                    break;
+                }
+                let (bytes, entry) = &sized[i];
+                let substitute = substitute(entry, *bytes, max_bytes);
+                let substitute_bytes = serde_json::to_string(&substitute)?.len();
+                total = total - bytes + substitute_bytes;
+                sized[i] = (substitute_bytes, substitute);
+            }
+            Value::Array(sized.into_iter().map(|(_, entry)| entry).collect())
+        }
+        entry => substitute(entry, original_bytes, max_bytes),
+    };
+    Ok(EncodedReply {
+        text: serde_json::to_string(&replacement)?,
+        original_bytes,
+        replaced: true,
+    })
+}
+
+/// The start of `text`: at most [`PREVIEW_CHARS`] characters and an eighth
+/// of `max_bytes`, leaving room for JSON escapes
+fn preview(text: &str, max_bytes: usize) -> &str {
+    let budget = max_bytes / 8;
+    let end = text
+        .char_indices()
+        .take(PREVIEW_CHARS)
+        .map(|(i, c)| i + c.len_utf8())
+        .take_while(|end| *end <= budget)
+        .last()
+        .unwrap_or(0);
+    &text[..end]
+}
+
+const GUIDANCE: &str = "Ask for less (a smaller max_results, fewer fields, a narrower JQL) \
+     or have the tool write the data to a file where it can (e.g. output_path)";
+
+/// What replaces the response `entry` of `bytes` bytes
+fn substitute(entry: &Value, bytes: usize, max_bytes: usize) -> Value {
+    let id = entry.get("id").cloned().unwrap_or(Value::Null);
+    let summary = format!(
+        "The response was {bytes} bytes, more than the {max_bytes} max_response_bytes allows, \
+         and was not sent. {GUIDANCE}."
+    );
+
+    if let Some(content) = entry
+        .get("result")
+        .and_then(|result| result.get("content"))
+        .and_then(Value::as_array)
+    {
+        let text: String = content
+            .iter()
+            .filter_map(|item| item.get("text").and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let preview = preview(&text, max_bytes);
+        return json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "result": {
+                "content": [{
+                    "type": "text",
+                    "text": format!(
+                        "{summary}\nFirst {} characters:\n{preview}",
+                        preview.chars().count()
+                    ),
+                }],
+                "isError": true,
+            },
+        });
+    }
+
+    let original = serde_json::to_string(entry).unwrap_or_default();
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {
+            "code": -32603,
+            "message": "Response too large",
+            "data": {
+                "details": summary,
+                "bytes": bytes,
+                "max_response_bytes": max_bytes,
+                "preview": preview(&original, max_bytes),
+            },
+        },
+    })
+}
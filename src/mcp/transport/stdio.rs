@@ -0,0 +1,173 @@
+//! JSON-RPC messages over a byte stream such as stdin/stdout.
+//!
+//! Most MCP hosts send one JSON message per line, but some frame messages
+//! LSP-style with a `Content-Length` header block. The framing is detected
+//! from the first bytes received and used for reading and writing for the
+//! rest of the session.
+
+use crate::error::Result;
+use std::io::{Error, ErrorKind};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tracing::debug;
+
+/// How messages are delimited on the stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Framing {
+    /// One message per line
+    NewlineDelimited,
+    /// A `Content-Length: N` header block, a blank line, then N bytes
+    ContentLength,
+}
+
+/// Reads and writes whole JSON-RPC messages in the framing the peer uses
+pub struct MessageStream<R, W> {
+    reader: BufReader<R>,
+    writer: W,
+    framing: Option<Framing>,
+}
+
+impl<R, W> MessageStream<R, W>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    #[must_use]
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            writer,
+            framing: None,
+        }
+    }
+
+    /// The framing detected from the first message, if one has arrived
+    #[must_use]
+    pub fn framing(&self) -> Option<Framing> {
+        self.framing
+    }
+
+    /// Read the next message, or `None` once the stream ends
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails, a `Content-Length` header block is
+    /// malformed, or a message is not UTF-8.
+    pub async fn read_message(&mut self) -> Result<Option<String>> {
+        let framing = match self.framing {
+            Some(framing) => framing,
+            None => match self.detect_framing().await? {
+                Some(framing) => {
+                    debug!("Detected {:?} message framing", framing);
+                    self.framing = Some(framing);
+                    framing
+                }
+                None => return Ok(None),
+            },
+        };
+        match framing {
+            Framing::NewlineDelimited => self.read_line_message().await,
+            Framing::ContentLength => self.read_framed_message().await,
+        }
+    }
+
+    /// Write `message` in the detected framing, newline-delimited until a
+    /// message has been read. The writer is flushed after every frame, so a
+    /// client waiting on one response never waits on a buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub async fn write_message(&mut self, message: &str) -> Result<()> {
+        if self.framing == Some(Framing::ContentLength) {
+            let header = format!("Content-Length: {}\r\n\r\n", message.len());
+            self.writer.write_all(header.as_bytes()).await?;
+            self.writer.write_all(message.as_bytes()).await?;
+        } else {
+            self.writer.write_all(message.as_bytes()).await?;
+            self.writer.write_all(b"\n").await?;
+        }
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Skip leading whitespace and look at the first byte: JSON starts a
+    /// newline-delimited session, anything else a header block. `None` if
+    /// the stream ends first.
+    async fn detect_framing(&mut self) -> Result<Option<Framing>> {
+        loop {
+            let buffer = self.reader.fill_buf().await?;
+            let Some(&first) = buffer.first() else {
+                return Ok(None);
+            };
+            if first.is_ascii_whitespace() {
+                self.reader.consume(1);
+                continue;
+            }
+            return Ok(Some(if matches!(first, b'{' | b'[') {
+                Framing::NewlineDelimited
+            } else {
+                Framing::ContentLength
+            }));
+        }
+    }
+
+    async fn read_line_message(&mut self) -> Result<Option<String>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line).await? == 0 {
+                return Ok(None);
+            }
+            let trimmed = line.trim();
+            if !trimmed.is_empty() {
+                return Ok(Some(trimmed.to_string()));
+            }
+        }
+    }
+
+    async fn read_framed_message(&mut self) -> Result<Option<String>> {
+        let mut content_length = None;
+        let mut line = String::new();
+        let mut read_any = false;
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line).await? == 0 {
+                if read_any {
+                    return Err(invalid("stream ended inside a header block").into());
+                }
+                return Ok(None);
+            }
+            let header = line.trim_end_matches(['\r', '\n']);
+            if header.is_empty() {
+                // Blank lines between messages are not a header block's end
+                if read_any {
+                    break;
+                }
+                continue;
+            }
+            read_any = true;
+            let Some((name, value)) = header.split_once(':') else {
+                return Err(invalid(&format!("malformed header '{header}'")).into());
+            };
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                let length = value
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| invalid(&format!("invalid Content-Length '{}'", value.trim())))?;
+                content_length = Some(length);
+            }
+        }
+
+        let length =
+            content_length.ok_or_else(|| invalid("header block without Content-Length"))?;
+        let mut body = vec![0; length];
+        self.reader.read_exact(&mut body).await?;
+        String::from_utf8(body)
+            .map(Some)
+            .map_err(|_| invalid("message is not UTF-8").into())
+    }
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message.to_string())
+}
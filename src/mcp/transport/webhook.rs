@@ -0,0 +1,313 @@
+//! Receiver for Jira webhooks, listening next to the stdio transport.
+//!
+//! With `webhook_listen_addr` set the server also accepts HTTP POSTs to
+//! [`WEBHOOK_PATH`] on that address. Each request must prove it knows
+//! `webhook_secret`, either with an `X-Hub-Signature: sha256=<hex>` header
+//! holding the HMAC-SHA256 of the body, which Jira Cloud and Data Center 10+
+//! send for webhooks registered with a secret, or with a `token` query
+//! parameter equal to the secret, for older Data Center versions that cannot
+//! sign. Accepted payloads are normalized into
+//! [`JiraEvent`](crate::jira::events::JiraEvent)s and pushed to
+//! [`jira_events`].
+//!
+//! This is a deliberately small HTTP/1.1 server: one request per connection,
+//! bodies sent with `Content-Length` only, no TLS. Put it behind a reverse
+//! proxy to expose it beyond the local network.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+use crate::jira::events::{jira_events, JiraEvent, DEFAULT_EVENT_BUFFER_SIZE};
+use sha2::{Digest, Sha256};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info, warn};
+
+/// Path Jira posts webhooks to
+pub const WEBHOOK_PATH: &str = "/webhooks/jira";
+
+/// Largest request body accepted
+pub const MAX_WEBHOOK_BODY: usize = 1024 * 1024;
+
+/// Largest request line and header block accepted
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+
+/// Time a client gets to send its whole request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// HMAC-SHA256 of `message` under `key` (RFC 2104)
+#[must_use]
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let pad = |byte: u8| block.iter().map(|b| b ^ byte).collect::<Vec<u8>>();
+    let inner = Sha256::new()
+        .chain_update(pad(0x36))
+        .chain_update(message)
+        .finalize();
+    Sha256::new()
+        .chain_update(pad(0x5c))
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+/// The `X-Hub-Signature` value Jira sends for `body` signed with `secret`
+#[must_use]
+pub fn signature_header(secret: &str, body: &[u8]) -> String {
+    let digest = hmac_sha256(secret.as_bytes(), body);
+    let hex: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+    format!("sha256={hex}")
+}
+
+/// Whether `header`, an `X-Hub-Signature` value, signs `body` with `secret`.
+/// The hex digest may be in either case; other algorithms are refused.
+#[must_use]
+pub fn verify_signature(secret: &str, body: &[u8], header: &str) -> bool {
+    let Some(hex) = header.trim().strip_prefix("sha256=") else {
+        return false;
+    };
+    let expected = signature_header(secret, body);
+    constant_time_eq(
+        &expected.as_bytes()["sha256=".len()..],
+        hex.to_ascii_lowercase().as_bytes(),
+    )
+}
+
+/// Compare without returning early, so timing does not leak the secret
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// A request as far as the receiver reads it
+struct Request {
+    method: String,
+    path: String,
+    query: Option<String>,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn query_param(&self, name: &str) -> Option<String> {
+        url::form_urlencoded::parse(self.query.as_deref()?.as_bytes())
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    }
+
+    /// Whether the request proves it knows `secret`
+    fn is_authentic(&self, secret: &str) -> bool {
+        match self.header("X-Hub-Signature") {
+            Some(signature) => verify_signature(secret, &self.body, signature),
+            None => self
+                .query_param("token")
+                .is_some_and(|token| constant_time_eq(token.as_bytes(), secret.as_bytes())),
+        }
+    }
+}
+
+/// A request the receiver refuses, with the status to answer
+struct Rejection(u16, &'static str);
+
+/// Listens for Jira webhooks; see the module documentation
+pub struct WebhookReceiver {
+    listener: TcpListener,
+    secret: String,
+}
+
+impl WebhookReceiver {
+    /// Listen on `addr`, e.g. `127.0.0.1:8787`, accepting webhooks signed
+    /// with `secret`
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if `secret` is empty or `addr` cannot be
+    /// bound.
+    pub async fn bind(addr: &str, secret: &str) -> Result<Self> {
+        if secret.trim().is_empty() {
+            return Err(JiraError::config_error(
+                "webhook_secret must be set to receive webhooks",
+            ));
+        }
+        let listener = TcpListener::bind(addr).await.map_err(|e| {
+            JiraError::config_error(&format!("Cannot listen for webhooks on '{addr}': {e}"))
+        })?;
+        Ok(Self {
+            listener,
+            secret: secret.to_string(),
+        })
+    }
+
+    /// The address listened on, with the port chosen if `addr` gave 0
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket has no local address.
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Accept connections until the task is dropped, handling each on a task
+    /// of its own
+    pub async fn run(self) {
+        loop {
+            match self.listener.accept().await {
+                Ok((stream, peer)) => {
+                    let secret = self.secret.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_connection(stream, &secret).await {
+                            debug!("Webhook connection from {} failed: {}", peer, e);
+                        }
+                    });
+                }
+                Err(e) => warn!("Cannot accept webhook connection: {}", e),
+            }
+        }
+    }
+}
+
+/// Start receiving webhooks in the background if `webhook_listen_addr` is
+/// set, sizing the event buffer from `webhook_buffer_size`. Returns the
+/// address listened on.
+///
+/// # Errors
+///
+/// Returns a configuration error if `webhook_secret` is missing or the
+/// address cannot be bound.
+pub async fn start(config: &JiraConfig) -> Result<Option<SocketAddr>> {
+    let Some(addr) = config.webhook_listen_addr.as_deref() else {
+        return Ok(None);
+    };
+    let receiver =
+        WebhookReceiver::bind(addr, config.webhook_secret.as_deref().unwrap_or("")).await?;
+    let local_addr = receiver.local_addr()?;
+    jira_events().set_capacity(
+        config
+            .webhook_buffer_size
+            .unwrap_or(DEFAULT_EVENT_BUFFER_SIZE),
+    );
+    tokio::spawn(receiver.run());
+    info!(
+        "Receiving Jira webhooks on http://{}{}",
+        local_addr, WEBHOOK_PATH
+    );
+    Ok(Some(local_addr))
+}
+
+async fn handle_connection(mut stream: TcpStream, secret: &str) -> Result<()> {
+    let (status, reason) =
+        match tokio::time::timeout(REQUEST_TIMEOUT, read_request(&mut stream)).await {
+            Ok(Ok(request)) => match accept(&request, secret) {
+                Ok(sequence) => {
+                    debug!("Received webhook event #{}", sequence);
+                    (202, "Accepted")
+                }
+                Err(Rejection(status, reason)) => (status, reason),
+            },
+            Ok(Err(Rejection(status, reason))) => (status, reason),
+            Err(_) => (408, "Request Timeout"),
+        };
+    if status >= 400 {
+        debug!("Refused webhook request: {} {}", status, reason);
+    }
+    let response =
+        format!("HTTP/1.1 {status} {reason}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Check `request` and push its event, returning the event's sequence number
+fn accept(request: &Request, secret: &str) -> std::result::Result<u64, Rejection> {
+    if request.path != WEBHOOK_PATH {
+        return Err(Rejection(404, "Not Found"));
+    }
+    if request.method != "POST" {
+        return Err(Rejection(405, "Method Not Allowed"));
+    }
+    if !request.is_authentic(secret) {
+        warn!("Refused a webhook with a missing or wrong signature");
+        return Err(Rejection(401, "Unauthorized"));
+    }
+    let payload: serde_json::Value =
+        serde_json::from_slice(&request.body).map_err(|_| Rejection(400, "Bad Request"))?;
+    let event = JiraEvent::from_webhook(&payload).map_err(|_| Rejection(400, "Bad Request"))?;
+    Ok(jira_events().push(event))
+}
+
+async fn read_request(stream: &mut TcpStream) -> std::result::Result<Request, Rejection> {
+    let bad_request = || Rejection(400, "Bad Request");
+    let mut buffer = Vec::new();
+    let header_end = loop {
+        if let Some(end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end;
+        }
+        if buffer.len() > MAX_HEADER_BYTES {
+            return Err(Rejection(431, "Request Header Fields Too Large"));
+        }
+        let mut chunk = [0u8; 4096];
+        let read = stream.read(&mut chunk).await.map_err(|_| bad_request())?;
+        if read == 0 {
+            return Err(bad_request());
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = std::str::from_utf8(&buffer[..header_end]).map_err(|_| bad_request())?;
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(target)) = (request_line.next(), request_line.next()) else {
+        return Err(bad_request());
+    };
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), Some(query.to_string())),
+        None => (target.to_string(), None),
+    };
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect::<Vec<_>>();
+    let mut request = Request {
+        method: method.to_string(),
+        path,
+        query,
+        headers,
+        body: Vec::new(),
+    };
+
+    if request.method != "POST" {
+        return Ok(request);
+    }
+    let length = match request.header("Content-Length") {
+        Some(length) => length.parse::<usize>().map_err(|_| bad_request())?,
+        None => return Err(Rejection(411, "Length Required")),
+    };
+    if length > MAX_WEBHOOK_BODY {
+        return Err(Rejection(413, "Payload Too Large"));
+    }
+    let mut body = buffer.split_off(header_end + 4);
+    body.truncate(length);
+    if body.len() < length {
+        let mut rest = vec![0; length - body.len()];
+        stream
+            .read_exact(&mut rest)
+            .await
+            .map_err(|_| bad_request())?;
+        body.extend_from_slice(&rest);
+    }
+    request.body = body;
+    Ok(request)
+}
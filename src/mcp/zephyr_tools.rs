@@ -3,13 +3,43 @@
 use crate::config::JiraConfig;
 use crate::error::Result;
 use crate::jira::client::JiraClient;
+use crate::jira::jql::Jql;
+use crate::jira::traceability::{build_matrix, TRACEABILITY_ISSUE_LIMIT};
+use crate::jira::zephyr_report::CycleReport;
+use crate::mcp::tools::attachments::read_upload_file;
+use crate::mcp::tools::projects::ProjectArg;
+use crate::mcp::tools::required_str;
 use crate::types::jira::{
-    ZephyrTestCaseCreateRequest, ZephyrTestExecutionCreateRequest, ZephyrTestStepCreateRequest,
-    ZephyrTestStepUpdateRequest,
+    ZephyrFolderCreateRequest, ZephyrInlineTestStep, ZephyrTestCaseCreateRequest,
+    ZephyrTestExecutionCreateRequest, ZephyrTestStepCreateRequest, ZephyrTestStepUpdateRequest,
 };
 use crate::types::mcp::{MCPContent, MCPToolResult};
+use crate::utils::datetime::DateFormatter;
 use tracing::info;
 
+/// Version of unscheduled cycles, used when no `version_id` is given
+pub const UNSCHEDULED_VERSION_ID: &str = "-1";
+
+fn version_id_arg(args: &serde_json::Value) -> &str {
+    args.get("version_id")
+        .and_then(|v| v.as_str())
+        .unwrap_or(UNSCHEDULED_VERSION_ID)
+}
+
+/// The optional `folder_id`, given as a number or as a numeric string
+fn folder_id_arg(args: &serde_json::Value) -> Result<Option<i64>> {
+    let Some(value) = args.get("folder_id").filter(|v| !v.is_null()) else {
+        return Ok(None);
+    };
+    value
+        .as_i64()
+        .or_else(|| value.as_str()?.trim().parse().ok())
+        .map(Some)
+        .ok_or_else(|| {
+            crate::error::JiraError::validation_error("folder_id", "folder_id must be a number")
+        })
+}
+
 // Get Zephyr Test Steps Tool
 pub struct GetZephyrTestStepsTool {
     client: JiraClient,
@@ -296,12 +326,8 @@ impl GetZephyrTestCasesTool {
 #[async_trait::async_trait]
 impl crate::mcp::server::MCPToolHandler for GetZephyrTestCasesTool {
     async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
-        let project_key = args
-            .get("project_key")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| {
-                crate::error::JiraError::api_error("Missing required parameter: project_key")
-            })?;
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let project_key = project.key.as_str();
 
         let start_at = args
             .get("start_at")
@@ -323,7 +349,8 @@ impl crate::mcp::server::MCPToolHandler for GetZephyrTestCasesTool {
             .await?;
 
         let mut response_text = format!(
-            "Found {} test cases (showing {} of {} total)\n\n",
+            "{}Found {} test cases (showing {} of {} total)\n\n",
+            project.note(),
             search_result.test_cases.len(),
             search_result.test_cases.len(),
             search_result.total
@@ -402,6 +429,7 @@ impl crate::mcp::server::MCPToolHandler for CreateZephyrTestCaseTool {
             .get("description")
             .and_then(|v| v.as_str())
             .map(ToString::to_string);
+        let steps = parse_inline_steps(&args)?;
 
         let test_case_request = ZephyrTestCaseCreateRequest {
             name: name.to_string(),
@@ -414,16 +442,18 @@ impl crate::mcp::server::MCPToolHandler for CreateZephyrTestCaseTool {
             components: None,
             fix_versions: None,
             custom_fields: None,
+            steps,
         };
 
         info!("Creating Zephyr test case: {}", name);
 
-        let created_test_case = self
+        let creation = self
             .client
-            .create_zephyr_test_case(&test_case_request)
+            .create_zephyr_test_case_with_steps(&test_case_request)
             .await?;
+        let created_test_case = &creation.test_case;
 
-        let response_text = format!(
+        let mut response_text = format!(
             "Test case created successfully!\n\nName: {}\nKey: {}\nProject: {}\nType: {}\nPriority: {}\nAssignee: {}",
             created_test_case.name,
             created_test_case.key.as_deref().unwrap_or("No Key"),
@@ -433,13 +463,71 @@ impl crate::mcp::server::MCPToolHandler for CreateZephyrTestCaseTool {
             created_test_case.assignee.as_deref().unwrap_or("Unassigned")
         );
 
+        if test_case_request.steps.is_some() {
+            response_text.push_str(&format!("\n\nSteps created: {}", creation.steps.len()));
+            for step in &creation.steps {
+                response_text.push_str(&format!(
+                    "\n{}. {} (step ID: {})",
+                    step.order,
+                    step.step,
+                    step.id.as_deref().unwrap_or("unknown")
+                ));
+            }
+        }
+        if let Some(failure) = &creation.failed_step {
+            response_text.push_str(&format!(
+                "\n\nStep {} (\"{}\") could not be created: {}\n{} later steps were not tried. \
+                 Resume with create_zephyr_test_step for test case ID {} starting at order {}.",
+                failure.order,
+                failure.step,
+                failure.error,
+                failure.not_tried,
+                created_test_case
+                    .id
+                    .as_deref()
+                    .or(created_test_case.key.as_deref())
+                    .unwrap_or("unknown"),
+                failure.order
+            ));
+        }
+
         Ok(MCPToolResult {
             content: vec![MCPContent::text(response_text)],
-            is_error: Some(false),
+            is_error: Some(creation.failed_step.is_some()),
         })
     }
 }
 
+/// The `steps` of `create_zephyr_test_case`, each with a `step` and optional
+/// `data` and `result`
+fn parse_inline_steps(args: &serde_json::Value) -> Result<Option<Vec<ZephyrInlineTestStep>>> {
+    let Some(steps) = args.get("steps").filter(|v| !v.is_null()) else {
+        return Ok(None);
+    };
+    let steps = steps.as_array().ok_or_else(|| {
+        crate::error::JiraError::validation_error("steps", "steps must be an array of steps")
+    })?;
+    steps
+        .iter()
+        .enumerate()
+        .map(|(index, step)| {
+            serde_json::from_value::<ZephyrInlineTestStep>(step.clone())
+                .ok()
+                .filter(|step| !step.step.trim().is_empty())
+                .ok_or_else(|| {
+                    crate::error::JiraError::validation_error(
+                        "steps",
+                        &format!(
+                            "step {} needs a non-empty 'step' text, with optional 'data' and 'result'",
+                            index + 1
+                        ),
+                    )
+                })
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Some)
+}
+
 // Get Zephyr Test Executions Tool
 pub struct GetZephyrTestExecutionsTool {
     client: JiraClient,
@@ -466,18 +554,26 @@ impl crate::mcp::server::MCPToolHandler for GetZephyrTestExecutionsTool {
                 crate::error::JiraError::api_error("Missing required parameter: test_case_id")
             })?;
 
+        let folder_id = folder_id_arg(&args)?;
+
         info!(
             "Getting Zephyr test executions for test case: {}",
             test_case_id
         );
 
-        let test_executions = self.client.get_zephyr_test_executions(test_case_id).await?;
+        let mut test_executions = self.client.get_zephyr_test_executions(test_case_id).await?;
 
-        let mut response_text = format!("Test Executions for Test Case {test_case_id}:\n\n");
+        let mut response_text = format!("Test Executions for Test Case {test_case_id}");
+        if let Some(folder_id) = folder_id {
+            test_executions.retain(|execution| execution.folder_id == Some(folder_id));
+            response_text.push_str(&format!(" in folder {folder_id}"));
+        }
+        response_text.push_str(":\n\n");
 
         if test_executions.is_empty() {
             response_text.push_str("No test executions found.");
         } else {
+            let dates = DateFormatter::from_config(self.client.config());
             for (i, execution) in test_executions.iter().enumerate() {
                 response_text.push_str(&format!(
                     "{}. Execution ID: {}\n   Status: {}\n   Assignee: {}\n   Executed By: {}\n   Executed On: {}\n   Comment: {}\n\n",
@@ -486,7 +582,10 @@ impl crate::mcp::server::MCPToolHandler for GetZephyrTestExecutionsTool {
                     execution.status,
                     execution.assignee.as_deref().unwrap_or("Unassigned"),
                     execution.executed_by.as_deref().unwrap_or("Unknown"),
-                    execution.executed_on.as_deref().unwrap_or("Unknown"),
+                    execution
+                        .executed_on
+                        .as_deref()
+                        .map_or_else(|| "Unknown".to_string(), |d| dates.format_timestamp(d)),
                     execution.comment.as_deref().unwrap_or("None")
                 ));
             }
@@ -552,6 +651,13 @@ impl crate::mcp::server::MCPToolHandler for CreateZephyrTestExecutionTool {
             .get("comment")
             .and_then(|v| v.as_str())
             .map(ToString::to_string);
+        let folder_id = folder_id_arg(&args)?;
+        if folder_id.is_some() && cycle_id.is_none() {
+            return Err(crate::error::JiraError::validation_error(
+                "folder_id",
+                "folder_id needs the cycle_id of the cycle holding the folder",
+            ));
+        }
 
         let execution_request = ZephyrTestExecutionCreateRequest {
             test_case_id: test_case_id.to_string(),
@@ -562,6 +668,7 @@ impl crate::mcp::server::MCPToolHandler for CreateZephyrTestExecutionTool {
             assignee,
             comment,
             step_results: None,
+            folder_id,
         };
 
         info!(
@@ -591,6 +698,119 @@ impl crate::mcp::server::MCPToolHandler for CreateZephyrTestExecutionTool {
     }
 }
 
+// Upload Zephyr Execution Attachment Tool
+pub struct UploadZephyrExecutionAttachmentTool {
+    client: JiraClient,
+}
+
+impl UploadZephyrExecutionAttachmentTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for UploadZephyrExecutionAttachmentTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let execution_id = args
+            .get("execution_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: execution_id")
+            })?;
+
+        let (filename, content) = read_upload_file(&self.client, &args).await?;
+        let mime_type = args.get("mime_type").and_then(|v| v.as_str());
+
+        info!(
+            "Uploading attachment {} to Zephyr execution: {}",
+            filename, execution_id
+        );
+
+        self.client
+            .add_zephyr_execution_attachment(execution_id, &filename, &content, mime_type)
+            .await?;
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(format!(
+                "Successfully attached {} ({} bytes) to test execution {}",
+                filename,
+                content.len(),
+                execution_id
+            ))],
+            is_error: Some(false),
+        })
+    }
+}
+
+// Get Zephyr Execution Attachments Tool
+pub struct GetZephyrExecutionAttachmentsTool {
+    client: JiraClient,
+}
+
+impl GetZephyrExecutionAttachmentsTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetZephyrExecutionAttachmentsTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let execution_id = args
+            .get("execution_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: execution_id")
+            })?;
+
+        info!("Getting attachments for Zephyr execution: {}", execution_id);
+
+        let attachments = self
+            .client
+            .get_zephyr_execution_attachments(execution_id)
+            .await?;
+
+        let mut response_text = format!("Attachments for Test Execution {execution_id}:\n\n");
+
+        if attachments.is_empty() {
+            response_text.push_str("No attachments found.");
+        } else {
+            let dates = DateFormatter::from_config(self.client.config());
+            for (i, attachment) in attachments.iter().enumerate() {
+                response_text.push_str(&format!(
+                    "{}. {}\n   ID: {}\n   Type: {}\n   Size: {}\n   Added By: {}\n   Added On: {}\n\n",
+                    i + 1,
+                    attachment.name,
+                    attachment.id,
+                    attachment.content_type.as_deref().unwrap_or("Unknown"),
+                    attachment.file_size.as_deref().unwrap_or("Unknown"),
+                    attachment.author.as_deref().unwrap_or("Unknown"),
+                    attachment
+                        .date_created
+                        .as_deref()
+                        .map_or_else(|| "Unknown".to_string(), |d| dates.format_timestamp(d))
+                ));
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text)],
+            is_error: Some(false),
+        })
+    }
+}
+
 // Get Zephyr Test Cycles Tool
 pub struct GetZephyrTestCyclesTool {
     client: JiraClient,
@@ -701,3 +921,302 @@ impl crate::mcp::server::MCPToolHandler for GetZephyrTestPlansTool {
         })
     }
 }
+
+// Zephyr Traceability Report Tool
+pub struct ZephyrTraceabilityReportTool {
+    client: JiraClient,
+}
+
+impl ZephyrTraceabilityReportTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ZephyrTraceabilityReportTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let project = ProjectArg::from_args(&args, "project_key", self.client.config())?;
+        let project_key = project.key.as_str();
+
+        let requirements_jql = args
+            .get("requirements_jql")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                crate::error::JiraError::api_error("Missing required parameter: requirements_jql")
+            })?;
+        let tests_jql = args.get("tests_jql").and_then(|v| v.as_str()).map_or_else(
+            || {
+                Jql::new()
+                    .project(project_key)
+                    .field_eq("issuetype", "Test")
+                    .build()
+            },
+            ToString::to_string,
+        );
+        let link_type = args
+            .get("link_type")
+            .and_then(|v| v.as_str())
+            .or(self.client.config().test_link_type.as_deref())
+            .unwrap_or("Tests");
+
+        info!(
+            "Building traceability report for project {} from '{}' and '{}'",
+            project_key, requirements_jql, tests_jql
+        );
+
+        let summary = ["summary".to_string()];
+        let requirements = self
+            .client
+            .search_issues_bounded(requirements_jql, &summary, TRACEABILITY_ISSUE_LIMIT)
+            .await?;
+        let test_fields = ["summary", "issuelinks"].map(ToString::to_string);
+        let tests = self
+            .client
+            .search_issues_bounded(&tests_jql, &test_fields, TRACEABILITY_ISSUE_LIMIT)
+            .await?;
+        let matrix = build_matrix(&requirements.issues, &tests.issues, link_type);
+
+        let mut response_text = format!(
+            "Traceability for project {}: {} of {} requirements covered ({:.1}%) by {} test cases linked with '{}'\n",
+            project_key,
+            matrix.requirements.len() - matrix.uncovered.len(),
+            matrix.requirements.len(),
+            matrix.coverage_percent(),
+            matrix.tests.len(),
+            link_type
+        );
+        for (label, fetched) in [("Requirements", &requirements), ("Test cases", &tests)] {
+            for line in fetched.note().lines() {
+                response_text.push_str(&format!("{label}: {line}\n"));
+            }
+        }
+        response_text.push_str("\nRequirements:\n");
+        if matrix.requirements.is_empty() {
+            response_text.push_str("No requirements found.\n");
+        }
+        for requirement in &matrix.requirements {
+            let tests = if requirement.tests.is_empty() {
+                "no tests".to_string()
+            } else {
+                requirement.tests.join(", ")
+            };
+            response_text.push_str(&format!(
+                "• {} - {}: {}\n",
+                requirement.key, requirement.summary, tests
+            ));
+        }
+
+        response_text.push_str(&format!(
+            "\nUncovered requirements ({}): {}\n\nTest cases:\n",
+            matrix.uncovered.len(),
+            if matrix.uncovered.is_empty() {
+                "none".to_string()
+            } else {
+                matrix.uncovered.join(", ")
+            }
+        ));
+        if matrix.tests.is_empty() {
+            response_text.push_str("No test cases found.\n");
+        }
+        for test in &matrix.tests {
+            let requirements = if test.requirements.is_empty() {
+                "no requirements".to_string()
+            } else {
+                test.requirements.join(", ")
+            };
+            response_text.push_str(&format!(
+                "• {} - {}: {}\n",
+                test.key, test.summary, requirements
+            ));
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text), MCPContent::json(&matrix)],
+            is_error: Some(false),
+        })
+    }
+}
+
+// Get Zephyr Folders Tool
+pub struct GetZephyrFoldersTool {
+    client: JiraClient,
+}
+
+impl GetZephyrFoldersTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for GetZephyrFoldersTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let cycle_id = required_str(&args, "cycle_id")?;
+        let project_id = required_str(&args, "project_id")?;
+        let version_id = version_id_arg(&args);
+
+        info!("Getting Zephyr folders for test cycle: {}", cycle_id);
+
+        let folders = self
+            .client
+            .get_cycle_folders(cycle_id, project_id, version_id)
+            .await?;
+
+        let mut response_text = format!("Folders of Test Cycle {cycle_id}:\n\n");
+
+        if folders.is_empty() {
+            response_text.push_str("No folders found.");
+        } else {
+            for (i, folder) in folders.iter().enumerate() {
+                response_text.push_str(&format!(
+                    "{}. {}\n   ID: {}\n   Description: {}\n",
+                    i + 1,
+                    folder.name,
+                    folder.id,
+                    folder.description.as_deref().unwrap_or("None")
+                ));
+                if let (Some(executed), Some(total)) =
+                    (folder.total_executed, folder.total_executions)
+                {
+                    response_text.push_str(&format!("   Executed: {executed} of {total}\n"));
+                }
+                response_text.push('\n');
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text)],
+            is_error: Some(false),
+        })
+    }
+}
+
+// Create Zephyr Folder Tool
+pub struct CreateZephyrFolderTool {
+    client: JiraClient,
+}
+
+impl CreateZephyrFolderTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for CreateZephyrFolderTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let name = required_str(&args, "name")?;
+        let cycle_id = required_str(&args, "cycle_id")?;
+        let project_id = required_str(&args, "project_id")?;
+
+        let folder_request = ZephyrFolderCreateRequest {
+            name: name.to_string(),
+            description: args
+                .get("description")
+                .and_then(|v| v.as_str())
+                .map(ToString::to_string),
+            cycle_id: cycle_id.to_string(),
+            project_id: project_id.to_string(),
+            version_id: version_id_arg(&args).to_string(),
+        };
+
+        info!("Creating Zephyr folder {} in test cycle {}", name, cycle_id);
+
+        let folder = self.client.create_folder(&folder_request).await?;
+
+        let response_text = format!(
+            "Folder created successfully!\n\nName: {}\nID: {}\nCycle ID: {}",
+            folder.name, folder.id, cycle_id
+        );
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text)],
+            is_error: Some(false),
+        })
+    }
+}
+
+// Zephyr Cycle Report Tool
+pub struct ZephyrCycleReportTool {
+    client: JiraClient,
+}
+
+impl ZephyrCycleReportTool {
+    #[must_use]
+    /// # Panics
+    /// This function does not panic.
+    pub fn new(config: JiraConfig) -> Self {
+        Self {
+            client: JiraClient::new(config).expect("Failed to create JiraClient"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::mcp::server::MCPToolHandler for ZephyrCycleReportTool {
+    async fn handle(&self, args: serde_json::Value) -> Result<MCPToolResult> {
+        let cycle_id = required_str(&args, "cycle_id")?;
+        let project_id = required_str(&args, "project_id")?;
+        let version_id = version_id_arg(&args);
+        let folder_id = folder_id_arg(&args)?;
+
+        info!("Building pass rates of Zephyr test cycle: {}", cycle_id);
+
+        let executions = self
+            .client
+            .get_cycle_executions(cycle_id, project_id, version_id, folder_id)
+            .await?;
+        // A single folder needs no breakdown
+        let folders = match folder_id {
+            Some(_) => Vec::new(),
+            None => {
+                self.client
+                    .get_cycle_folders(cycle_id, project_id, version_id)
+                    .await?
+            }
+        };
+        let report = CycleReport::from_executions(&executions, &folders);
+
+        let mut response_text = match folder_id {
+            Some(folder_id) => format!("Test Cycle {cycle_id}, folder {folder_id}: "),
+            None => format!("Test Cycle {cycle_id}: "),
+        };
+        response_text.push_str(&format!(
+            "{} executions, {}",
+            report.overall.total,
+            report.overall.describe()
+        ));
+        if folder_id.is_none() && !report.folders.is_empty() {
+            response_text.push_str("\n\nBy folder:");
+            for folder in &report.folders {
+                response_text.push_str(&format!(
+                    "\n• {}: {} executions, {}",
+                    folder.name,
+                    folder.rate.total,
+                    folder.rate.describe()
+                ));
+            }
+        }
+
+        Ok(MCPToolResult {
+            content: vec![MCPContent::text(response_text), MCPContent::json(&report)],
+            is_error: Some(false),
+        })
+    }
+}
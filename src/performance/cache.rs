@@ -1,6 +1,8 @@
+use dashmap::DashMap;
 use moka::future::Cache;
+use std::collections::HashSet;
 use std::hash::Hash;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, info};
 
 /// Generic cache trait for different cache implementations
@@ -86,6 +88,8 @@ pub struct CacheManager {
     pub parsed_objects: Box<dyn CacheStore<String, serde_json::Value>>,
     // Cache for configuration data
     pub config_cache: Box<dyn CacheStore<String, serde_json::Value>>,
+    // Cache for JQL search results
+    pub search_results: SearchResultCache,
 }
 
 impl CacheManager {
@@ -96,6 +100,7 @@ impl CacheManager {
             api_responses: Box::new(MokaCache::new(500, Duration::from_secs(300))), // 5 minutes
             parsed_objects: Box::new(MokaCache::new(1000, Duration::from_secs(600))), // 10 minutes
             config_cache: Box::new(MokaCache::new(100, Duration::from_secs(3600))), // 1 hour
+            search_results: SearchResultCache::new(),
         }
     }
 
@@ -113,6 +118,7 @@ impl CacheManager {
             api_responses: Box::new(MokaCache::new(api_cache_capacity, api_cache_ttl)),
             parsed_objects: Box::new(MokaCache::new(parsed_cache_capacity, parsed_cache_ttl)),
             config_cache: Box::new(MokaCache::new(config_cache_capacity, config_cache_ttl)),
+            search_results: SearchResultCache::new(),
         }
     }
 
@@ -122,6 +128,7 @@ impl CacheManager {
             api_responses_count: self.api_responses.len().await,
             parsed_objects_count: self.parsed_objects.len().await,
             config_cache_count: self.config_cache.len().await,
+            search_results_count: self.search_results.len(),
         }
     }
 
@@ -130,6 +137,7 @@ impl CacheManager {
         self.api_responses.clear().await;
         self.parsed_objects.clear().await;
         self.config_cache.clear().await;
+        self.search_results.clear();
         info!("All caches cleared");
     }
 
@@ -140,6 +148,7 @@ impl CacheManager {
         info!("  API Responses: {} entries", stats.api_responses_count);
         info!("  Parsed Objects: {} entries", stats.parsed_objects_count);
         info!("  Config Cache: {} entries", stats.config_cache_count);
+        info!("  Search Results: {} entries", stats.search_results_count);
     }
 }
 
@@ -155,6 +164,7 @@ pub struct CacheStats {
     pub api_responses_count: usize,
     pub parsed_objects_count: usize,
     pub config_cache_count: usize,
+    pub search_results_count: usize,
 }
 
 /// Cache key generator for consistent key formatting
@@ -184,6 +194,153 @@ impl CacheKeyGenerator {
     pub fn search(jql: &str, start_at: i32, max_results: i32) -> String {
         format!("search:{jql}:{start_at}:{max_results}")
     }
+
+    /// Generate a cache key for search results from a specific Jira instance
+    #[must_use]
+    pub fn search_results(
+        api_base_url: &str,
+        jql: &str,
+        start_at: Option<i32>,
+        max_results: Option<i32>,
+        fields: &[String],
+    ) -> String {
+        let start_at = start_at.map_or_else(String::new, |v| v.to_string());
+        let max_results = max_results.map_or_else(String::new, |v| v.to_string());
+        format!(
+            "search:{api_base_url}:{jql}:{start_at}:{max_results}:{}",
+            fields.join(",")
+        )
+    }
+}
+
+struct CachedSearch {
+    result: serde_json::Value,
+    issue_keys: HashSet<String>,
+    cached_at: Instant,
+}
+
+/// Searches kept by [`SearchResultCache::new`]
+pub const DEFAULT_SEARCH_CACHE_CAPACITY: usize = 500;
+
+/// Short-lived cache of JQL search results.
+///
+/// Entries remember which issues they contain so that a change to any of those
+/// issues evicts every cached search it appears in. The TTL is given by each
+/// caller, which lets it apply its own configured lifetime; expired entries
+/// are dropped on read and swept on every insert, and once `capacity` searches
+/// are kept the oldest makes room for a new one.
+pub struct SearchResultCache {
+    entries: DashMap<String, CachedSearch>,
+    capacity: usize,
+}
+
+impl SearchResultCache {
+    /// Create an empty search result cache keeping up to
+    /// [`DEFAULT_SEARCH_CACHE_CAPACITY`] searches
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_SEARCH_CACHE_CAPACITY)
+    }
+
+    /// Create an empty search result cache keeping up to `capacity` searches
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: DashMap::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Get a cached search result if it is younger than `ttl`
+    #[must_use]
+    pub fn get(&self, key: &str, ttl: Duration) -> Option<serde_json::Value> {
+        let expired = {
+            let entry = self.entries.get(key)?;
+            if entry.cached_at.elapsed() < ttl {
+                debug!("Search cache hit for key: {}", key);
+                return Some(entry.result.clone());
+            }
+            true
+        };
+
+        if expired {
+            self.entries.remove(key);
+        }
+        None
+    }
+
+    /// Cache a search result along with the keys and ids of the issues it
+    /// contains, first dropping entries older than `ttl` and, if the cache is
+    /// still full, the oldest entry
+    pub fn insert(
+        &self,
+        key: String,
+        result: serde_json::Value,
+        issue_keys: HashSet<String>,
+        ttl: Duration,
+    ) {
+        self.entries
+            .retain(|_, entry| entry.cached_at.elapsed() < ttl);
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|entry| entry.cached_at)
+                .map(|entry| entry.key().clone());
+            if let Some(oldest) = oldest {
+                debug!("Search cache full, evicting {}", oldest);
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(
+            key,
+            CachedSearch {
+                result,
+                issue_keys,
+                cached_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Evict every cached search containing the given issue key or id.
+    ///
+    /// Returns the number of entries removed.
+    pub fn invalidate_issue(&self, issue_key: &str) -> usize {
+        let before = self.entries.len();
+        self.entries
+            .retain(|_, entry| !entry.issue_keys.contains(issue_key));
+        let removed = before.saturating_sub(self.entries.len());
+        if removed > 0 {
+            debug!(
+                "Invalidated {} cached searches containing {}",
+                removed, issue_key
+            );
+        }
+        removed
+    }
+
+    /// Remove all cached search results
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+
+    /// Number of cached search results
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Default for SearchResultCache {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 /// Cache wrapper with performance metrics
@@ -131,7 +131,11 @@ impl JiraClientOptimized {
         Ok(())
     }
 
-    /// Add a comment with optimized serialization
+    /// Add a comment with optimized serialization.
+    ///
+    /// This client does not read project types, so every comment carries the
+    /// internal `sd.public.comment` property: on Jira Service Management
+    /// projects it stays hidden from customers, and other projects ignore it.
     ///
     /// # Errors
     ///
@@ -139,7 +143,11 @@ impl JiraClientOptimized {
     pub async fn add_comment(&self, issue_key: &str, comment_body: &str) -> Result<JiraComment> {
         let endpoint = format!("issue/{issue_key}/comment");
         let comment_data = json!({
-            "body": comment_body
+            "body": comment_body,
+            "properties": [{
+                "key": crate::jira::jsm::PUBLIC_COMMENT_PROPERTY,
+                "value": {"internal": true}
+            }]
         });
         self.base_client
             .post_optimized(&endpoint, &comment_data)
@@ -318,6 +326,10 @@ impl JiraClientOptimized {
                         success: false,
                         error_message: Some(format!("Task failed: {e}")),
                         operation_type: crate::types::jira::BulkOperationType::Update,
+                        error_kind: Some(crate::error::JiraErrorKind::Other),
+                        skipped: false,
+                        previous_fields: None,
+                        moved_from: None,
                     });
                 }
             }
@@ -343,14 +355,17 @@ impl JiraClientOptimized {
                         success: true,
                         error_message: None,
                         operation_type: operation.operation_type.clone(),
+                        error_kind: None,
+                        skipped: false,
+                        previous_fields: None,
+                        moved_from: None,
                     };
                 }
                 Err(e) => {
                     if retry_count < max_retries && Self::should_retry_operation(&e) {
                         retry_count += 1;
-                        let delay = Duration::from_millis(
-                            1000 * u64::try_from(retry_count.max(0)).unwrap_or(0),
-                        );
+                        let delay =
+                            Duration::from_millis(1000 * u64::try_from(retry_count).unwrap_or(0));
                         warn!(
                             "Retrying operation for {} in {:?} (attempt {}/{})",
                             operation.issue_key, delay, retry_count, max_retries
@@ -364,6 +379,10 @@ impl JiraClientOptimized {
                         success: false,
                         error_message: Some(e.to_string()),
                         operation_type: operation.operation_type.clone(),
+                        error_kind: Some(e.kind()),
+                        skipped: false,
+                        previous_fields: None,
+                        moved_from: None,
                     };
                 }
             }
@@ -432,6 +451,10 @@ impl JiraClientOptimized {
                     ))
                 }
             }
+            crate::types::jira::BulkOperationType::AddWatcher
+            | crate::types::jira::BulkOperationType::RemoveWatcher => Err(JiraError::api_error(
+                "Watcher operations are not supported by the optimized client",
+            )),
         }
     }
 
@@ -1,5 +1,7 @@
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tracing::info;
 
@@ -27,6 +29,23 @@ pub struct PerformanceMetrics {
     // Rate limiting metrics
     pub rate_limited_requests: Arc<AtomicU64>,
 
+    // Connection pool metrics, estimated by the client since reqwest does
+    // not expose its pool
+    pub new_connections: Arc<AtomicU64>,
+    pub reused_connections: Arc<AtomicU64>,
+
+    // Request coalescing metrics
+    pub coalesced_requests: Arc<AtomicU64>,
+
+    // Time taken by each section of `get_project_metadata`
+    pub metadata_sections: Arc<Mutex<BTreeMap<String, SectionTiming>>>,
+
+    // Sizes of the responses written to the client
+    pub response_sizes: Arc<Mutex<ResponseSizes>>,
+
+    // Fetches of each issue by key, counted while prefetching is enabled
+    pub issue_accesses: Arc<Mutex<HashMap<String, u64>>>,
+
     // Start time for calculating averages
     pub start_time: Instant,
 }
@@ -53,6 +72,12 @@ impl PerformanceMetrics {
             cache_hits: Arc::new(AtomicU64::new(0)),
             cache_misses: Arc::new(AtomicU64::new(0)),
             rate_limited_requests: Arc::new(AtomicU64::new(0)),
+            new_connections: Arc::new(AtomicU64::new(0)),
+            reused_connections: Arc::new(AtomicU64::new(0)),
+            coalesced_requests: Arc::new(AtomicU64::new(0)),
+            metadata_sections: Arc::new(Mutex::new(BTreeMap::new())),
+            response_sizes: Arc::new(Mutex::new(ResponseSizes::default())),
+            issue_accesses: Arc::new(Mutex::new(HashMap::new())),
             start_time: Instant::now(),
         }
     }
@@ -127,6 +152,69 @@ impl PerformanceMetrics {
         self.rate_limited_requests.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Record whether a request was, by the client's estimate, served by a
+    /// pooled connection or a new one
+    pub fn record_connection(&self, reused: bool) {
+        if reused {
+            self.reused_connections.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.new_connections.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Record a request that was served by sharing an identical in-flight request
+    pub fn record_coalesced_request(&self) {
+        self.coalesced_requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record how long a section of `get_project_metadata` took, and whether
+    /// it was cut off by its timeout
+    pub fn record_metadata_section(&self, section: &str, duration: Duration, timed_out: bool) {
+        let duration_ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+        let mut sections = self
+            .metadata_sections
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let timing = sections.entry(section.to_string()).or_default();
+        timing.calls += 1;
+        timing.timeouts += u64::from(timed_out);
+        timing.total_ms = timing.total_ms.saturating_add(duration_ms);
+        timing.max_ms = timing.max_ms.max(duration_ms);
+        timing.last_ms = duration_ms;
+    }
+
+    /// Record a response of `bytes` written to the client, and whether it was
+    /// replaced for exceeding `max_response_bytes`
+    pub fn record_response_size(&self, bytes: usize, replaced: bool) {
+        self.response_sizes
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .record(bytes, replaced);
+    }
+
+    /// Record a fetch of the issue `issue_key` and return how many there
+    /// have been
+    pub fn record_issue_access(&self, issue_key: &str) -> u64 {
+        let mut accesses = self
+            .issue_accesses
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let count = accesses.entry(issue_key.to_string()).or_default();
+        *count += 1;
+        *count
+    }
+
+    /// Fetches of the issue `issue_key` recorded so far
+    #[must_use]
+    pub fn issue_access_count(&self, issue_key: &str) -> u64 {
+        self.issue_accesses
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(issue_key)
+            .copied()
+            .unwrap_or(0)
+    }
+
     /// Update memory usage
     pub fn update_memory_usage(&self, current_bytes: usize) {
         self.current_memory_usage_bytes
@@ -166,6 +254,8 @@ impl PerformanceMetrics {
         let cache_hits = self.cache_hits.load(Ordering::Relaxed);
         let cache_misses = self.cache_misses.load(Ordering::Relaxed);
         let rate_limited = self.rate_limited_requests.load(Ordering::Relaxed);
+        let new_connections = self.new_connections.load(Ordering::Relaxed);
+        let reused_connections = self.reused_connections.load(Ordering::Relaxed);
 
         let uptime = self.start_time.elapsed();
         let requests_per_second = if uptime.as_secs() > 0 {
@@ -192,6 +282,12 @@ impl PerformanceMetrics {
             0.0
         };
 
+        let connection_reuse_rate = if new_connections + reused_connections > 0 {
+            (reused_connections as f64 / (new_connections + reused_connections) as f64) * 100.0
+        } else {
+            0.0
+        };
+
         PerformanceStats {
             total_requests,
             successful_requests,
@@ -209,6 +305,20 @@ impl PerformanceMetrics {
             cache_misses,
             cache_hit_rate,
             rate_limited_requests: rate_limited,
+            new_connections,
+            reused_connections,
+            connection_reuse_rate,
+            coalesced_requests: self.coalesced_requests.load(Ordering::Relaxed),
+            metadata_sections: self
+                .metadata_sections
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .clone(),
+            response_sizes: self
+                .response_sizes
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .clone(),
             current_memory_usage_bytes: self.current_memory_usage_bytes.load(Ordering::Relaxed),
             peak_memory_usage_bytes: self.peak_memory_usage_bytes.load(Ordering::Relaxed),
             uptime_seconds: uptime.as_secs(),
@@ -242,6 +352,11 @@ impl PerformanceMetrics {
             stats.cache_hit_rate, stats.cache_hits, stats.cache_misses
         );
         info!("  Rate Limited: {}", stats.rate_limited_requests);
+        info!(
+            "  Connection Reuse (estimated): {:.1}% ({} reused, {} new)",
+            stats.connection_reuse_rate, stats.reused_connections, stats.new_connections
+        );
+        info!("  Coalesced Requests: {}", stats.coalesced_requests);
         info!(
             "  Memory Usage: {} bytes (peak: {} bytes)",
             stats.current_memory_usage_bytes, stats.peak_memory_usage_bytes
@@ -260,6 +375,94 @@ impl PerformanceMetrics {
         self.cache_hits.store(0, Ordering::Relaxed);
         self.cache_misses.store(0, Ordering::Relaxed);
         self.rate_limited_requests.store(0, Ordering::Relaxed);
+        self.new_connections.store(0, Ordering::Relaxed);
+        self.reused_connections.store(0, Ordering::Relaxed);
+        self.coalesced_requests.store(0, Ordering::Relaxed);
+        self.metadata_sections
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clear();
+        *self
+            .response_sizes
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = ResponseSizes::default();
+        self.issue_accesses
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clear();
+    }
+}
+
+/// Timing of one section of `get_project_metadata` across calls
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SectionTiming {
+    pub calls: u64,
+    /// Calls where the section did not finish within its timeout
+    pub timeouts: u64,
+    pub total_ms: u64,
+    pub max_ms: u64,
+    pub last_ms: u64,
+}
+
+/// Upper bounds of the response size buckets, in bytes
+pub const RESPONSE_SIZE_BUCKETS: [u64; 5] = [1 << 10, 10 << 10, 100 << 10, 1 << 20, 10 << 20];
+
+/// Sizes of the responses written to the client
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResponseSizes {
+    pub responses: u64,
+    pub total_bytes: u64,
+    pub max_bytes: u64,
+    /// Responses replaced for exceeding `max_response_bytes`
+    pub replaced: u64,
+    /// Responses by size, one bucket per [`RESPONSE_SIZE_BUCKETS`] bound and
+    /// a last one for anything larger
+    pub buckets: Vec<SizeBucket>,
+}
+
+/// Responses of at most `le_bytes`, and above the bound of the bucket before
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SizeBucket {
+    /// `None` for the last bucket
+    pub le_bytes: Option<u64>,
+    pub responses: u64,
+}
+
+impl Default for ResponseSizes {
+    fn default() -> Self {
+        Self {
+            responses: 0,
+            total_bytes: 0,
+            max_bytes: 0,
+            replaced: 0,
+            buckets: RESPONSE_SIZE_BUCKETS
+                .iter()
+                .copied()
+                .map(Some)
+                .chain([None])
+                .map(|le_bytes| SizeBucket {
+                    le_bytes,
+                    responses: 0,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ResponseSizes {
+    fn record(&mut self, bytes: usize, replaced: bool) {
+        let bytes = u64::try_from(bytes).unwrap_or(u64::MAX);
+        self.responses += 1;
+        self.total_bytes = self.total_bytes.saturating_add(bytes);
+        self.max_bytes = self.max_bytes.max(bytes);
+        self.replaced += u64::from(replaced);
+        if let Some(bucket) = self
+            .buckets
+            .iter_mut()
+            .find(|bucket| bucket.le_bytes.is_none_or(|le| bytes <= le))
+        {
+            bucket.responses += 1;
+        }
     }
 }
 
@@ -278,6 +481,14 @@ pub struct PerformanceStats {
     pub cache_misses: u64,
     pub cache_hit_rate: f64,
     pub rate_limited_requests: u64,
+    pub new_connections: u64,
+    pub reused_connections: u64,
+    pub connection_reuse_rate: f64,
+    pub coalesced_requests: u64,
+    /// Timing of each `get_project_metadata` section, by section name
+    pub metadata_sections: BTreeMap<String, SectionTiming>,
+    /// Sizes of the responses written to the client
+    pub response_sizes: ResponseSizes,
     pub current_memory_usage_bytes: usize,
     pub peak_memory_usage_bytes: usize,
     pub uptime_seconds: u64,
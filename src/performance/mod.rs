@@ -12,10 +12,13 @@ pub use async_optimizer::{
 };
 pub use cache::{
     get_global_cache_manager, CacheKeyGenerator, CacheManager, CacheStats, CacheStore,
-    CachedOperation, MokaCache,
+    CachedOperation, MokaCache, SearchResultCache,
 };
 pub use jira_client::JiraClientOptimized;
-pub use metrics::{get_global_metrics, PerformanceMetrics, PerformanceStats};
+pub use metrics::{
+    get_global_metrics, PerformanceMetrics, PerformanceStats, ResponseSizes, SectionTiming,
+    SizeBucket,
+};
 pub use monitoring::{
     get_global_performance_monitor, Alert, AlertStats, AlertThresholds, PerformanceMonitor,
 };
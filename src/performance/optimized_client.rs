@@ -162,12 +162,7 @@ impl OptimizedJiraClient {
 
     /// Build a complete URL from the endpoint
     fn build_url(&self, endpoint: &str) -> Result<url::Url> {
-        let base_url = url::Url::parse(&self.config.api_base_url)
-            .map_err(|e| JiraError::config_error(&format!("Invalid API base URL: {e}")))?;
-
-        base_url
-            .join(endpoint)
-            .map_err(|e| JiraError::config_error(&format!("Invalid endpoint URL: {e}")))
+        crate::jira::urls::join(&self.config.api_base_url, endpoint, "API base URL")
     }
 
     /// Build a request with optimized headers
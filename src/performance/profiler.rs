@@ -1,6 +1,33 @@
+//! Timing of operations by named segments, and of every tool call the
+//! server handles.
+//!
+//! [`profile_call`] runs a tool call with a [`Profiler`] for the current task;
+//! the server and `JiraClient` mark [`checkpoint`]s as the call moves from
+//! reading its arguments through HTTP requests to formatting its result.
+//! Checkpoints from other tasks (e.g. `tokio::spawn`) are not seen. The last
+//! [`MAX_PROFILED_CALLS`] calls are kept for `get_performance_profile`.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Arc, LazyLock, Mutex};
 use std::time::{Duration, Instant};
 use tracing::{debug, info};
 
+/// Tool calls whose profiles are kept
+pub const MAX_PROFILED_CALLS: usize = 100;
+
+/// Segment of a profiled call spent between HTTP requests
+pub const PROCESSING_SEGMENT: &str = "processing";
+
+tokio::task_local! {
+    static CURRENT: Arc<Mutex<Profiler>>;
+}
+
+static RECENT_CALLS: LazyLock<Mutex<VecDeque<ProfiledCall>>> =
+    LazyLock::new(|| Mutex::new(VecDeque::with_capacity(MAX_PROFILED_CALLS)));
+
 /// Simple profiler for measuring operation performance
 #[derive(Debug, Clone)]
 pub struct Profiler {
@@ -43,6 +70,40 @@ impl Profiler {
         self.checkpoints.last().map(|(_, time)| time.elapsed())
     }
 
+    /// Timing report of a finished profile: the segments up to the last
+    /// checkpoint, without the time since then
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn finish(&self) -> TimingReport {
+        let Some((_, end)) = self.checkpoints.last() else {
+            return self.get_timing_report();
+        };
+        let total_duration = end.duration_since(self.start_time);
+        let mut last_time = self.start_time;
+        let segments = self
+            .checkpoints
+            .iter()
+            .map(|(name, time)| {
+                let duration = time.duration_since(last_time);
+                last_time = *time;
+                TimingSegment {
+                    name: name.clone(),
+                    duration,
+                    percentage: if total_duration.is_zero() {
+                        0.0
+                    } else {
+                        duration.as_nanos() as f64 / total_duration.as_nanos() as f64 * 100.0
+                    },
+                }
+            })
+            .collect();
+        TimingReport {
+            profiler_name: self.name.clone(),
+            total_duration,
+            segments,
+        }
+    }
+
     /// Get detailed timing information
     #[must_use]
     #[allow(clippy::cast_precision_loss)]
@@ -127,6 +188,119 @@ pub struct TimingReport {
     pub segments: Vec<TimingSegment>,
 }
 
+/// A profiled tool call, with its segments in the order they ran
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfiledCall {
+    pub tool: String,
+    pub started_at: DateTime<Utc>,
+    pub total_ms: f64,
+    pub segments: Vec<ProfiledSegment>,
+}
+
+/// Segment of a profiled call, starting `start_ms` into the call
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfiledSegment {
+    pub name: String,
+    pub start_ms: f64,
+    pub duration_ms: f64,
+}
+
+impl ProfiledCall {
+    fn from_report(started_at: DateTime<Utc>, report: &TimingReport) -> Self {
+        let mut start = Duration::ZERO;
+        let segments = report
+            .segments
+            .iter()
+            .map(|segment| {
+                let profiled = ProfiledSegment {
+                    name: segment.name.clone(),
+                    start_ms: start.as_secs_f64() * 1000.0,
+                    duration_ms: segment.duration.as_secs_f64() * 1000.0,
+                };
+                start += segment.duration;
+                profiled
+            })
+            .collect();
+        Self {
+            tool: report.profiler_name.clone(),
+            started_at,
+            total_ms: report.total_duration.as_secs_f64() * 1000.0,
+            segments,
+        }
+    }
+}
+
+/// Run `future` as a call of `tool` with profiling switched on for the
+/// current task, and keep its profile among the recent calls
+pub async fn profile_call<F>(tool: &str, future: F) -> F::Output
+where
+    F: Future,
+{
+    let started_at = Utc::now();
+    let profiler = Arc::new(Mutex::new(Profiler::new(tool)));
+    let output = CURRENT.scope(profiler.clone(), future).await;
+    if let Ok(profiler) = profiler.lock() {
+        let call = ProfiledCall::from_report(started_at, &profiler.finish());
+        if let Ok(mut calls) = RECENT_CALLS.lock() {
+            if calls.len() == MAX_PROFILED_CALLS {
+                calls.pop_front();
+            }
+            calls.push_back(call);
+        }
+    }
+    output
+}
+
+/// End the segment `name` of the current task's profile; a no-op when the
+/// task is not being profiled
+pub fn checkpoint(name: impl Into<String>) {
+    let _ = CURRENT.try_with(|profiler| {
+        if let Ok(mut profiler) = profiler.lock() {
+            profiler.checkpoint(name);
+        }
+    });
+}
+
+/// Segment of the current profile covering an HTTP request, opened by
+/// [`http_segment`] and ended when dropped
+pub struct HttpSegment {
+    label: String,
+}
+
+/// Open a segment for a request to `endpoint`, labelled with the method and
+/// the path of the endpoint. The time since the previous checkpoint is ended
+/// as [`PROCESSING_SEGMENT`].
+#[must_use]
+pub fn http_segment(method: &reqwest::Method, endpoint: &str) -> HttpSegment {
+    checkpoint(PROCESSING_SEGMENT);
+    let path = endpoint.split('?').next().unwrap_or_default();
+    HttpSegment {
+        label: format!("{method} {path}"),
+    }
+}
+
+impl Drop for HttpSegment {
+    fn drop(&mut self) {
+        checkpoint(std::mem::take(&mut self.label));
+    }
+}
+
+/// The kept tool call profiles, oldest first
+#[must_use]
+pub fn recent_calls() -> Vec<ProfiledCall> {
+    RECENT_CALLS
+        .lock()
+        .map(|calls| calls.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Forget the kept tool call profiles
+pub fn reset_recent_calls() {
+    if let Ok(mut calls) = RECENT_CALLS.lock() {
+        calls.clear();
+    }
+}
+
 /// Macro for easy profiling of code blocks
 #[macro_export]
 macro_rules! profile {
@@ -1,13 +1,15 @@
 // This module is used to test that our code compiles and uses the functions
 // to avoid dead code warnings during development
 
-use crate::config::jira::JiraConfig;
-use crate::error::JiraError;
-use crate::jira::client::JiraClient;
-use crate::mcp::server::MCPServer;
-use crate::types::jira::TransitionProperties;
-use crate::types::mcp::{MCPContent, MCPToolCall};
-use crate::utils::{format_error_response, format_success_response, format_validation_error};
+use rust_jira_mcp::config::jira::JiraConfig;
+use rust_jira_mcp::error::JiraError;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPServer;
+use rust_jira_mcp::types::jira::TransitionProperties;
+use rust_jira_mcp::types::mcp::{MCPContent, MCPToolCall};
+use rust_jira_mcp::utils::{
+    format_error_response, format_success_response, format_validation_error,
+};
 
 pub fn test_usage() {
     // Test configuration
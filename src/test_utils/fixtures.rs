@@ -17,6 +17,7 @@ pub fn test_jira_config() -> JiraConfig {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     }
 }
 
@@ -32,6 +33,7 @@ pub fn mock_jira_config(base_url: &str) -> JiraConfig {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false), // Disable SSL verification for tests
+        ..Default::default()
     }
 }
 
@@ -69,12 +71,13 @@ pub fn sample_jira_comment() -> JiraComment {
             account_id: Some("test-user-123".to_string()),
             email_address: Some("test.user@example.com".to_string()),
             avatar_urls: None,
-            display_name: "Test User".to_string(),
+            display_name: Some("Test User".to_string()),
             active: true,
             time_zone: Some("UTC".to_string()),
         },
         created: "2024-01-01T10:00:00.000+0000".to_string(),
         updated: Some("2024-01-01T10:00:00.000+0000".to_string()),
+        visibility: None,
     }
 }
 
@@ -95,7 +98,7 @@ pub fn sample_jira_work_log() -> JiraWorkLog {
             account_id: Some("test-user-123".to_string()),
             email_address: Some("test.user@example.com".to_string()),
             avatar_urls: None,
-            display_name: "Test User".to_string(),
+            display_name: Some("Test User".to_string()),
             active: true,
             time_zone: Some("UTC".to_string()),
         },
@@ -25,6 +25,7 @@ pub fn create_test_config(
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     }
 }
 
@@ -42,7 +43,7 @@ pub fn create_test_user(
         account_id: account_id.map(ToString::to_string),
         email_address: email.map(ToString::to_string),
         avatar_urls: None,
-        display_name: display_name.unwrap_or("Test User").to_string(),
+        display_name: Some(display_name.unwrap_or("Test User").to_string()),
         active: true,
         time_zone: Some("UTC".to_string()),
     }
@@ -58,6 +59,9 @@ pub fn create_test_project(id: Option<&str>, key: Option<&str>, name: Option<&st
         project_type_key: "software".to_string(),
         self_url: "https://test-jira.example.com/rest/api/2/project/10000".to_string(),
         avatar_urls: None,
+        project_category: None,
+        archived: false,
+        deleted: false,
     }
 }
 
@@ -162,6 +166,7 @@ pub fn create_test_comment(
         author: author.unwrap_or_else(|| create_test_user(None, None, None)),
         created: current_timestamp(),
         updated: Some(current_timestamp()),
+        visibility: None,
     }
 }
 
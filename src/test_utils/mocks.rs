@@ -49,6 +49,7 @@ impl JiraMockBuilder {
             timeout_seconds: Some(30),
             log_file: None,
             strict_ssl: Some(false), // Disable SSL verification for tests
+            ..Default::default()
         };
         JiraClient::new(config).expect("Failed to create JiraClient")
     }
@@ -1,5 +1,6 @@
+use crate::error::JiraErrorKind;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Jira issue representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,27 +24,176 @@ pub struct JiraProject {
     pub self_url: String,
     #[serde(rename = "avatarUrls")]
     pub avatar_urls: Option<JiraAvatarUrls>,
+    #[serde(rename = "projectCategory", default)]
+    pub project_category: Option<JiraProjectCategory>,
+    /// Archived projects are still returned by issue fetches and listings
+    #[serde(default)]
+    pub archived: bool,
+    /// Set on Cloud for projects in the trash
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+impl JiraProject {
+    #[must_use]
+    pub fn status(&self) -> ProjectStatus {
+        if self.deleted {
+            ProjectStatus::Deleted
+        } else if self.archived {
+            ProjectStatus::Archived
+        } else {
+            ProjectStatus::Live
+        }
+    }
+}
+
+/// Jira project category representation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraProjectCategory {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(rename = "self", default)]
+    pub self_url: String,
+}
+
+/// Lifecycle status of a project
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectStatus {
+    Live,
+    Archived,
+    Deleted,
+}
+
+impl ProjectStatus {
+    /// Read a status name, ignoring case
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "live" => Some(Self::Live),
+            "archived" => Some(Self::Archived),
+            "deleted" => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Live => "live",
+            Self::Archived => "archived",
+            Self::Deleted => "deleted",
+        }
+    }
+}
+
+/// Which projects `JiraClient::list_projects` returns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFilter {
+    pub statuses: Vec<ProjectStatus>,
+    /// Category id or name, ignoring case
+    pub category: Option<String>,
+    /// Start of the project key, ignoring case
+    pub key_prefix: Option<String>,
+}
+
+impl Default for ProjectFilter {
+    fn default() -> Self {
+        Self {
+            statuses: vec![ProjectStatus::Live],
+            category: None,
+            key_prefix: None,
+        }
+    }
+}
+
+impl ProjectFilter {
+    #[must_use]
+    pub fn matches(&self, project: &JiraProject) -> bool {
+        let category_matches = self.category.as_deref().is_none_or(|wanted| {
+            project.project_category.as_ref().is_some_and(|category| {
+                category.id == wanted.trim() || category.name.eq_ignore_ascii_case(wanted.trim())
+            })
+        });
+        let key_matches = self.key_prefix.as_deref().is_none_or(|prefix| {
+            project
+                .key
+                .to_uppercase()
+                .starts_with(&prefix.trim().to_uppercase())
+        });
+        self.statuses.contains(&project.status()) && category_matches && key_matches
+    }
 }
 
 /// Jira user representation
+///
+/// GDPR-strict Cloud sites leave out `name`, `key` and `emailAddress`, and
+/// `displayName` of users who hide their profile; use [`JiraUser::label`]
+/// to show a user.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraUser {
-    #[serde(rename = "self")]
+    #[serde(rename = "self", default)]
     pub self_url: String,
+    #[serde(default)]
     pub name: String,
+    #[serde(default)]
     pub key: String,
+    #[serde(alias = "accountId")]
     pub account_id: Option<String>,
-    #[serde(rename = "emailAddress")]
+    #[serde(rename = "emailAddress", default)]
     pub email_address: Option<String>,
-    #[serde(rename = "avatarUrls")]
+    #[serde(rename = "avatarUrls", default)]
     pub avatar_urls: Option<JiraAvatarUrls>,
-    #[serde(rename = "displayName")]
-    pub display_name: String,
+    #[serde(rename = "displayName", default)]
+    pub display_name: Option<String>,
+    #[serde(default = "default_active")]
     pub active: bool,
     #[serde(rename = "timeZone")]
     pub time_zone: Option<String>,
 }
 
+fn default_active() -> bool {
+    true
+}
+
+impl JiraUser {
+    /// The display name, or for users hiding it "user <accountId>", falling
+    /// back to the Server/Data Center username
+    #[must_use]
+    pub fn label(&self) -> String {
+        user_label(
+            self.display_name.as_deref(),
+            self.account_id.as_deref().unwrap_or_default(),
+            &self.name,
+        )
+    }
+
+    /// [`JiraUser::label`] of a user object in a raw response, such as the
+    /// `assignee` field of an issue
+    #[must_use]
+    pub fn label_of(user: &serde_json::Value) -> String {
+        let text = |field: &str| user.get(field).and_then(|v| v.as_str()).unwrap_or_default();
+        user_label(
+            user.get("displayName").and_then(|v| v.as_str()),
+            text("accountId"),
+            text("name"),
+        )
+    }
+}
+
+/// The label of a user with `display_name`, `account_id` and `name`, any of
+/// which GDPR-strict sites may leave empty
+fn user_label(display_name: Option<&str>, account_id: &str, name: &str) -> String {
+    match display_name.map(str::trim).filter(|n| !n.is_empty()) {
+        Some(display_name) => display_name.to_string(),
+        None if !account_id.is_empty() => format!("user {account_id}"),
+        None if !name.is_empty() => name.to_string(),
+        None => "unknown user".to_string(),
+    }
+}
+
 /// Jira avatar URLs representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(clippy::struct_field_names)]
@@ -111,6 +261,68 @@ pub struct JiraStatusCategory {
     pub name: String,
 }
 
+/// Statuses available to one issue type in a project, as returned by
+/// `GET /project/{key}/statuses`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraIssueTypeStatuses {
+    #[serde(rename = "self")]
+    pub self_url: Option<String>,
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub subtask: bool,
+    pub statuses: Vec<JiraStatus>,
+}
+
+/// Workflow scheme associated with a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraWorkflowScheme {
+    pub id: Option<i64>,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(rename = "defaultWorkflow")]
+    pub default_workflow: Option<String>,
+    /// Issue type id to workflow name
+    #[serde(rename = "issueTypeMappings", default)]
+    pub issue_type_mappings: HashMap<String, String>,
+}
+
+/// Issue security level, restricting who can see an issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraSecurityLevel {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Field on the create screen of an issue type, from create-meta
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraCreateField {
+    #[serde(rename = "fieldId")]
+    pub field_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub schema: Option<serde_json::Value>,
+    #[serde(rename = "allowedValues", default)]
+    pub allowed_values: Option<Vec<serde_json::Value>>,
+    #[serde(rename = "defaultValue", default)]
+    pub default_value: Option<serde_json::Value>,
+    #[serde(rename = "hasDefaultValue", default)]
+    pub has_default_value: bool,
+}
+
+/// Fields on the create screen of one issue type in a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraCreateMeta {
+    pub project_key: String,
+    pub issue_type_id: String,
+    pub issue_type_name: String,
+    pub fields: Vec<JiraCreateField>,
+}
+
 /// Jira component representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraComponent {
@@ -129,6 +341,8 @@ pub struct JiraComment {
     pub author: JiraUser,
     pub created: String,
     pub updated: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<JiraCommentVisibility>,
 }
 
 /// Jira transition representation
@@ -137,6 +351,7 @@ pub struct JiraTransition {
     pub id: String,
     pub name: String,
     pub to: JiraStatus,
+    #[serde(default)]
     pub properties: TransitionProperties,
 }
 
@@ -207,6 +422,100 @@ impl Default for TransitionProperties {
     }
 }
 
+/// Optional data sent along with a transition
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransitionOptions {
+    /// Comment added as part of the transition
+    pub comment: Option<String>,
+    /// Field values required by the transition screen
+    pub fields: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Resolution to set, either a name such as `"Fixed"` or a resolution object
+    pub resolution: Option<serde_json::Value>,
+}
+
+/// Changes made to one issue by `JiraClient::apply_issue_actions`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssueActions {
+    /// Account id (Cloud) or username (Server/Data Center) to assign the
+    /// issue to; an empty string unassigns it
+    pub assign: Option<String>,
+    pub add_labels: Vec<String>,
+    pub remove_labels: Vec<String>,
+    pub comment: Option<String>,
+    /// Transition id, or the name of a transition or its target status
+    pub transition: Option<String>,
+}
+
+impl IssueActions {
+    /// Steps these actions take, in the order they are applied: the
+    /// transition comes last since it may close the issue to further edits
+    #[must_use]
+    pub fn steps(&self) -> Vec<IssueActionStep> {
+        let mut steps = Vec::new();
+        if self.assign.is_some() {
+            steps.push(IssueActionStep::Assign);
+        }
+        if !self.add_labels.is_empty() || !self.remove_labels.is_empty() {
+            steps.push(IssueActionStep::Labels);
+        }
+        if self.comment.is_some() {
+            steps.push(IssueActionStep::Comment);
+        }
+        if self.transition.is_some() {
+            steps.push(IssueActionStep::Transition);
+        }
+        steps
+    }
+}
+
+/// One step of the actions applied to an issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueActionStep {
+    Assign,
+    Labels,
+    Comment,
+    Transition,
+}
+
+impl std::fmt::Display for IssueActionStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Assign => "assign",
+            Self::Labels => "labels",
+            Self::Comment => "comment",
+            Self::Transition => "transition",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The step that stopped `JiraClient::apply_issue_actions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueActionFailure {
+    pub step: IssueActionStep,
+    pub error_message: String,
+    pub error_kind: JiraErrorKind,
+}
+
+/// What `JiraClient::apply_issue_actions` did to an issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueActionReport {
+    pub issue_key: String,
+    /// Steps that were applied, in order
+    pub completed: Vec<IssueActionStep>,
+    pub failed: Option<IssueActionFailure>,
+    /// Steps after the failed one, which were left undone
+    pub not_attempted: Vec<IssueActionStep>,
+}
+
+impl IssueActionReport {
+    #[must_use]
+    pub fn success(&self) -> bool {
+        self.failed.is_none()
+    }
+}
+
 /// Jira search result representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraSearchResult {
@@ -278,6 +587,9 @@ pub struct ZephyrTestExecution {
     pub execution_time: Option<i64>,
     pub defects: Option<Vec<String>>,
     pub step_results: Option<Vec<ZephyrStepResult>>,
+    /// Folder of the cycle holding the execution, `None` at the cycle's top level
+    #[serde(default, alias = "folderId")]
+    pub folder_id: Option<i64>,
 }
 
 /// Zephyr test step result representation
@@ -293,14 +605,24 @@ pub struct ZephyrStepResult {
     pub attachments: Option<Vec<ZephyrAttachment>>,
 }
 
-/// Zephyr attachment representation
+/// Zephyr attachment representation. ZAPI lists attachments with `fileId`,
+/// `fileName` and `mimeType`, and reports their size as text such as `21 kB`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZephyrAttachment {
+    #[serde(alias = "fileId")]
     pub id: String,
+    #[serde(alias = "fileName")]
     pub name: String,
-    pub url: String,
+    pub url: Option<String>,
+    #[serde(alias = "mimeType")]
     pub content_type: Option<String>,
     pub size: Option<i64>,
+    #[serde(alias = "fileSize")]
+    pub file_size: Option<String>,
+    pub author: Option<String>,
+    #[serde(alias = "dateCreated")]
+    pub date_created: Option<String>,
+    pub comment: Option<String>,
 }
 
 /// Zephyr test cycle representation
@@ -320,6 +642,24 @@ pub struct ZephyrTestCycle {
     pub test_executions: Option<Vec<ZephyrTestExecution>>,
 }
 
+/// Zephyr folder within a test cycle. ZAPI lists folders with `folderId`,
+/// `folderName` and `folderDescription`, and counts their executions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZephyrFolder {
+    #[serde(alias = "folderId")]
+    pub id: i64,
+    #[serde(alias = "folderName")]
+    pub name: String,
+    #[serde(default, alias = "folderDescription")]
+    pub description: Option<String>,
+    #[serde(default, alias = "cycleId")]
+    pub cycle_id: Option<i64>,
+    #[serde(default, alias = "totalExecutions")]
+    pub total_executions: Option<i64>,
+    #[serde(default, alias = "totalExecuted")]
+    pub total_executed: Option<i64>,
+}
+
 /// Zephyr test plan representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZephyrTestPlan {
@@ -366,6 +706,34 @@ pub struct ZephyrTestExecutionCreateRequest {
     pub assignee: Option<String>,
     pub comment: Option<String>,
     pub step_results: Option<Vec<ZephyrStepResultCreateRequest>>,
+    /// Folder of the cycle to create the execution in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<i64>,
+}
+
+/// Zephyr folder creation request. Folders belong to a cycle of a version;
+/// ZAPI uses version `-1` for unscheduled cycles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZephyrFolderCreateRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub cycle_id: String,
+    pub project_id: String,
+    pub version_id: String,
+}
+
+/// Request adding test issues to a cycle, or to one of its folders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZephyrAddTestsToCycleRequest {
+    /// Keys of the test issues
+    pub issues: Vec<String>,
+    pub cycle_id: String,
+    pub project_id: String,
+    pub version_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<i64>,
 }
 
 /// Zephyr test step result creation request
@@ -410,6 +778,41 @@ pub struct ZephyrTestCaseCreateRequest {
     pub components: Option<Vec<String>>,
     pub fix_versions: Option<Vec<String>>,
     pub custom_fields: Option<std::collections::HashMap<String, serde_json::Value>>,
+    /// Steps to create after the test case, in order. They are not part of
+    /// the test case request; each is created with a request of its own.
+    #[serde(default, skip_serializing)]
+    pub steps: Option<Vec<ZephyrInlineTestStep>>,
+}
+
+/// A step given along with a new test case
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZephyrInlineTestStep {
+    pub step: String,
+    #[serde(default)]
+    pub data: Option<String>,
+    #[serde(default)]
+    pub result: Option<String>,
+}
+
+/// A test case created with its steps
+#[derive(Debug, Clone, Serialize)]
+pub struct ZephyrTestCaseCreation {
+    pub test_case: ZephyrTestCase,
+    /// The steps created, in order
+    pub steps: Vec<ZephyrTestStep>,
+    /// The step that could not be created, after which no more were tried
+    pub failed_step: Option<ZephyrStepFailure>,
+}
+
+/// A step of a new test case that could not be created
+#[derive(Debug, Clone, Serialize)]
+pub struct ZephyrStepFailure {
+    /// Order the step was to have, counting from 1
+    pub order: i32,
+    pub step: String,
+    pub error: String,
+    /// Steps after this one, which were not tried
+    pub not_tried: usize,
 }
 
 /// Zephyr test case update request
@@ -528,6 +931,86 @@ pub struct JiraCommentVisibility {
     pub value: String,
 }
 
+impl JiraCommentVisibility {
+    /// Visibility types accepted by Jira
+    pub const TYPES: [&'static str; 2] = ["role", "group"];
+
+    /// Create a visibility restriction, rejecting unknown types
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `visibility_type` is not `role` or `group`,
+    /// or if `value` is empty.
+    pub fn new(visibility_type: &str, value: &str) -> crate::error::Result<Self> {
+        let visibility = Self {
+            r#type: visibility_type.to_lowercase(),
+            value: value.to_string(),
+        };
+        visibility.validate()?;
+        Ok(visibility)
+    }
+
+    /// Parse an optional `{"type": ..., "value": ...}` tool argument
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the argument is malformed or uses an unknown type.
+    pub fn from_arg(arg: Option<&serde_json::Value>) -> crate::error::Result<Option<Self>> {
+        let Some(arg) = arg.filter(|v| !v.is_null()) else {
+            return Ok(None);
+        };
+        let field = |name: &str| {
+            arg.get(name).and_then(|v| v.as_str()).ok_or_else(|| {
+                crate::error::JiraError::validation_error(
+                    "visibility",
+                    &format!("visibility must be an object with string '{name}'"),
+                )
+            })
+        };
+        Self::new(field("type")?, field("value")?).map(Some)
+    }
+
+    /// Check that the visibility type is one Jira understands
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the type or value is invalid.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        if !Self::TYPES.contains(&self.r#type.as_str()) {
+            return Err(crate::error::JiraError::validation_error(
+                "visibility",
+                &format!(
+                    "Unknown visibility type '{}', expected one of: {}",
+                    self.r#type,
+                    Self::TYPES.join(", ")
+                ),
+            ));
+        }
+        if self.value.trim().is_empty() {
+            return Err(crate::error::JiraError::validation_error(
+                "visibility",
+                "visibility value must name a role or group",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for JiraCommentVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.r#type, self.value)
+    }
+}
+
+/// Attachment settings of a Jira instance, from `attachment/meta`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraAttachmentMeta {
+    pub enabled: bool,
+    /// Largest file Jira accepts, in bytes
+    pub upload_limit: Option<u64>,
+}
+
 /// Jira attachment representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraAttachment {
@@ -538,8 +1021,12 @@ pub struct JiraAttachment {
     pub author: JiraUser,
     pub created: String,
     pub size: i64,
+    #[serde(alias = "mimeType")]
     pub mime_type: String,
     pub content: Option<String>,
+    /// Thumbnail URL, present for images Jira could render a thumbnail for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
 }
 
 /// Jira attachment creation request
@@ -553,7 +1040,8 @@ pub struct JiraAttachmentCreateRequest {
 /// Jira work log creation request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraWorkLogCreateRequest {
-    pub comment: Option<String>,
+    /// Wiki markup, or an ADF document for REST API v3
+    pub comment: Option<serde_json::Value>,
     pub time_spent: String,      // e.g., "1h 30m", "2d", "3w"
     pub started: Option<String>, // ISO 8601 datetime
     pub visibility: Option<JiraCommentVisibility>,
@@ -562,19 +1050,65 @@ pub struct JiraWorkLogCreateRequest {
 /// Jira work log update request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraWorkLogUpdateRequest {
-    pub comment: Option<String>,
+    /// Wiki markup, or an ADF document for REST API v3
+    pub comment: Option<serde_json::Value>,
     pub time_spent: Option<String>,
     pub started: Option<String>,
     pub visibility: Option<JiraCommentVisibility>,
 }
 
-/// Bulk operation types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// What adding or updating a work log does to the issue's remaining estimate
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum JiraWorkLogEstimateAdjustment {
+    /// Reduce the remaining estimate by the time spent (Jira's default)
+    #[default]
+    Auto,
+    /// Keep the remaining estimate as it is
+    Leave,
+    /// Set the remaining estimate to this duration, e.g. "2d 4h"
+    New(String),
+    /// Reduce the remaining estimate by this duration instead of the time
+    /// spent; only when adding a work log
+    Manual(String),
+}
+
+impl JiraWorkLogEstimateAdjustment {
+    /// The query string selecting the adjustment, e.g.
+    /// `?adjustEstimate=new&newEstimate=2d`; empty for Jira's default
+    #[must_use]
+    pub fn query(&self) -> String {
+        match self {
+            Self::Auto => String::new(),
+            Self::Leave => "?adjustEstimate=leave".to_string(),
+            Self::New(estimate) => format!(
+                "?adjustEstimate=new&newEstimate={}",
+                urlencoding::encode(estimate)
+            ),
+            Self::Manual(reduce_by) => format!(
+                "?adjustEstimate=manual&reduceBy={}",
+                urlencoding::encode(reduce_by)
+            ),
+        }
+    }
+}
+
+/// Bulk operation types, written in snake case (`add_comment`); the older
+/// `AddComment` spelling is still accepted
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum BulkOperationType {
+    #[serde(alias = "Update")]
     Update,
+    #[serde(alias = "Transition")]
     Transition,
+    #[serde(alias = "AddComment")]
     AddComment,
+    #[serde(alias = "Mixed")]
     Mixed,
+    #[serde(alias = "AddWatcher")]
+    AddWatcher,
+    #[serde(alias = "RemoveWatcher")]
+    RemoveWatcher,
 }
 
 /// Individual operation within a bulk operation
@@ -592,6 +1126,13 @@ pub struct BulkOperationConfig {
     pub continue_on_error: bool,
     pub rate_limit_ms: Option<u64>,
     pub max_retries: Option<usize>,
+    /// Operations of a batch run at the same time
+    #[serde(default)]
+    pub max_concurrency: Option<usize>,
+    /// Read the fields an update changes before changing them, one request
+    /// per issue, into each result's `previous_fields`
+    #[serde(skip)]
+    pub capture_previous_fields: bool,
 }
 
 impl Default for BulkOperationConfig {
@@ -601,6 +1142,8 @@ impl Default for BulkOperationConfig {
             continue_on_error: true,
             rate_limit_ms: Some(100),
             max_retries: Some(3),
+            max_concurrency: Some(1),
+            capture_previous_fields: false,
         }
     }
 }
@@ -612,6 +1155,48 @@ pub struct BulkOperationResult {
     pub success: bool,
     pub error_message: Option<String>,
     pub operation_type: BulkOperationType,
+    /// Category of the failure; `None` for successful operations
+    #[serde(default)]
+    pub error_kind: Option<JiraErrorKind>,
+    /// Set when the operation was not attempted; `error_message` says why
+    #[serde(default)]
+    pub skipped: bool,
+    /// Values the updated fields had just before an update run with
+    /// `capture_previous_fields`, which `undo_bulk_operation` puts back
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_fields: Option<serde_json::Map<String, serde_json::Value>>,
+    /// The key the operation was requested for, when the issue has since
+    /// been moved and `issue_key` is its new key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moved_from: Option<String>,
+}
+
+impl BulkOperationResult {
+    /// Result for an operation that was not attempted because of `reason`
+    #[must_use]
+    pub fn skipped(issue_key: &str, operation_type: BulkOperationType, reason: String) -> Self {
+        Self {
+            issue_key: issue_key.to_string(),
+            success: false,
+            error_message: Some(reason),
+            operation_type,
+            error_kind: None,
+            skipped: true,
+            previous_fields: None,
+            moved_from: None,
+        }
+    }
+
+    /// Result for `operation` when an earlier one failed with
+    /// `continue_on_error` off
+    #[must_use]
+    pub fn not_run(operation: &BulkOperationItem) -> Self {
+        Self::skipped(
+            &operation.issue_key,
+            operation.operation_type.clone(),
+            "Not run: an earlier operation failed and continue_on_error is off".to_string(),
+        )
+    }
 }
 
 /// Result of a complete bulk operation
@@ -620,6 +1205,9 @@ pub struct BulkOperationSummary {
     pub total_operations: u32,
     pub successful_operations: u32,
     pub failed_operations: u32,
+    /// Operations not attempted, counted in neither of the above
+    #[serde(default)]
+    pub skipped_operations: u32,
     pub results: Vec<BulkOperationResult>,
     pub duration_ms: u64,
 }
@@ -632,6 +1220,7 @@ impl BulkOperationSummary {
             total_operations: 0,
             successful_operations: 0,
             failed_operations: 0,
+            skipped_operations: 0,
             results: Vec::new(),
             duration_ms: 0,
         }
@@ -640,7 +1229,9 @@ impl BulkOperationSummary {
     /// Add a result to the summary
     pub fn add_result(&mut self, result: BulkOperationResult) {
         self.total_operations += 1;
-        if result.success {
+        if result.skipped {
+            self.skipped_operations += 1;
+        } else if result.success {
             self.successful_operations += 1;
         } else {
             self.failed_operations += 1;
@@ -661,6 +1252,35 @@ impl BulkOperationSummary {
             (successful_f64 / total_f64) * 100.0
         }
     }
+
+    /// Number of failed operations of each error kind
+    #[must_use]
+    pub fn errors_by_kind(&self) -> BTreeMap<JiraErrorKind, usize> {
+        let mut counts = BTreeMap::new();
+        for result in self.results.iter().filter(|r| !r.success && !r.skipped) {
+            *counts
+                .entry(result.error_kind.unwrap_or(JiraErrorKind::Other))
+                .or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Failures by kind in words, e.g. "3 permission errors, 2 not-found";
+    /// `None` when nothing failed
+    #[must_use]
+    pub fn describe_errors(&self) -> Option<String> {
+        let counts = self.errors_by_kind();
+        if counts.is_empty() {
+            return None;
+        }
+        Some(
+            counts
+                .into_iter()
+                .map(|(kind, count)| kind.describe(count))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
 }
 
 impl Default for BulkOperationSummary {
@@ -674,21 +1294,46 @@ impl Default for BulkOperationSummary {
 /// Jira issue watcher representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraWatcher {
+    // Server/Data Center identifies watchers by `name` and has no account id
+    #[serde(alias = "accountId", alias = "name", default)]
     pub account_id: String,
-    pub display_name: String,
+    /// Absent for users hiding their profile on GDPR-strict sites
+    #[serde(alias = "displayName", default)]
+    pub display_name: Option<String>,
+    #[serde(alias = "emailAddress", default)]
     pub email_address: Option<String>,
+    #[serde(default)]
     pub active: bool,
+    #[serde(alias = "timeZone", default)]
     pub time_zone: Option<String>,
 }
 
+impl JiraWatcher {
+    /// The display name, falling back as [`JiraUser::label`] does
+    #[must_use]
+    pub fn label(&self) -> String {
+        user_label(self.display_name.as_deref(), &self.account_id, "")
+    }
+}
+
 /// Jira watchers response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraWatchersResponse {
-    #[serde(rename = "self")]
+    #[serde(rename = "self", default)]
     pub self_url: String,
+    #[serde(alias = "isWatching", default)]
     pub is_watching: bool,
+    #[serde(alias = "watchCount", default)]
     pub watch_count: i32,
-    pub watchers: Vec<JiraWatcher>,
+    /// Absent when the caller may not view voters and watchers; may hold
+    /// fewer than `watch_count` entries when watchers come from groups or
+    /// the server caps the list
+    #[serde(default)]
+    pub watchers: Option<Vec<JiraWatcher>>,
+    #[serde(alias = "startAt", default, skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<u32>,
+    #[serde(alias = "maxResults", default, skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<u32>,
 }
 
 /// Jira add watcher request
@@ -911,3 +1556,100 @@ pub struct JiraSprintCreateResponse {
     #[serde(rename = "rapidViewId")]
     pub rapid_view_id: i32,
 }
+
+/// Jira Service Management service desk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsmServiceDesk {
+    pub id: String,
+    pub project_id: String,
+    pub project_key: String,
+    pub project_name: String,
+}
+
+/// Jira Service Management request type of a service desk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsmRequestType {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub help_text: Option<String>,
+    pub service_desk_id: Option<String>,
+    #[serde(default)]
+    pub group_ids: Vec<String>,
+}
+
+/// Current status of a customer request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsmRequestStatus {
+    pub status: String,
+}
+
+/// Customer request as Jira Service Management returns it on creation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsmCustomerRequest {
+    pub issue_id: String,
+    pub issue_key: String,
+    pub request_type_id: Option<String>,
+    pub service_desk_id: Option<String>,
+    pub current_status: Option<JsmRequestStatus>,
+    #[serde(rename = "_links")]
+    pub links: Option<serde_json::Value>,
+}
+
+/// Instant in a Jira Service Management response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsmDate {
+    pub iso8601: Option<String>,
+    pub friendly: Option<String>,
+    pub epoch_millis: Option<i64>,
+}
+
+/// Duration in a Jira Service Management response, e.g. an SLA goal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsmDuration {
+    pub millis: i64,
+    pub friendly: Option<String>,
+}
+
+/// One cycle of an SLA: running while ongoing, closed once completed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsmSlaCycle {
+    pub start_time: Option<JsmDate>,
+    pub stop_time: Option<JsmDate>,
+    pub breach_time: Option<JsmDate>,
+    #[serde(default)]
+    pub breached: bool,
+    pub paused: Option<bool>,
+    pub within_calendar_hours: Option<bool>,
+    pub goal_duration: Option<JsmDuration>,
+    pub elapsed_time: Option<JsmDuration>,
+    pub remaining_time: Option<JsmDuration>,
+}
+
+/// An SLA metric of a customer request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsmSla {
+    pub id: Option<String>,
+    pub name: String,
+    pub ongoing_cycle: Option<JsmSlaCycle>,
+    #[serde(default)]
+    pub completed_cycles: Vec<JsmSlaCycle>,
+}
+
+/// Comment on a customer request; only public ones are shown to customers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsmComment {
+    pub id: String,
+    pub body: String,
+    pub public: bool,
+    #[serde(default)]
+    pub author: Option<JiraUser>,
+    #[serde(default)]
+    pub created: Option<JsmDate>,
+}
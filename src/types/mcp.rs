@@ -32,11 +32,36 @@ pub struct JsonRpcError {
 /// MCP Initialize request parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InitializeParams {
+    #[serde(alias = "protocolVersion")]
     pub protocol_version: String,
+    #[serde(default)]
     pub capabilities: serde_json::Value,
+    #[serde(alias = "clientInfo")]
     pub client_info: ClientInfo,
 }
 
+/// What the server and the MCP client agreed on during initialization
+#[derive(Debug, Clone)]
+pub struct ClientSession {
+    /// Protocol version the server answered with
+    pub protocol_version: String,
+    /// Capabilities the client declared
+    pub capabilities: serde_json::Value,
+    pub client_info: ClientInfo,
+}
+
+impl ClientSession {
+    /// Whether the client declared `capability` (e.g. `roots`, `sampling`).
+    /// Nested capabilities are named with dots, e.g. `experimental.jiraEvents`.
+    #[must_use]
+    pub fn supports(&self, capability: &str) -> bool {
+        capability
+            .split('.')
+            .try_fold(&self.capabilities, |declared, key| declared.get(key))
+            .is_some_and(|declared| !declared.is_null() && *declared != false)
+    }
+}
+
 /// MCP Client information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientInfo {
@@ -56,6 +81,12 @@ pub struct InitializeResult {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerCapabilities {
     pub tools: Option<ToolsCapability>,
+    /// Declared to clients that take `notifications/message` log messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logging: Option<serde_json::Value>,
+    /// Declares `jiraEvents` to clients that subscribed to webhook events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experimental: Option<serde_json::Value>,
 }
 
 /// MCP Tools capability
@@ -73,11 +104,58 @@ pub struct ServerInfo {
 }
 
 /// MCP tool definition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MCPTool {
     pub name: String,
     pub description: String,
     pub input_schema: serde_json::Value,
+    /// Behaviour hints from the MCP tool annotation spec
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<ToolAnnotations>,
+    /// Extra metadata clients may use to group tools
+    #[serde(rename = "_meta", default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<ToolMeta>,
+}
+
+/// Hints about what a tool does, for clients deciding whether to ask the
+/// user before calling it. `destructive_hint` only means something when
+/// `read_only_hint` is false.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolAnnotations {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only_hint: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destructive_hint: Option<bool>,
+}
+
+/// Tool metadata sent under `_meta`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolMeta {
+    pub category: ToolCategory,
+}
+
+/// The group a tool is registered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolCategory {
+    Basic,
+    Project,
+    Bulk,
+    Sprints,
+    Reports,
+    Zephyr,
+    Linking,
+    Attachments,
+    Worklogs,
+    Watchers,
+    Labels,
+    Components,
+    Cloning,
+    Metrics,
+    Events,
+    ServiceManagement,
+    Assets,
 }
 
 /// MCP tool call request
@@ -110,6 +188,13 @@ impl MCPContent {
             text,
         }
     }
+
+    /// Text content holding `value` as pretty-printed JSON, for clients that
+    /// parse results rather than read them
+    #[must_use]
+    pub fn json<T: Serialize>(value: &T) -> Self {
+        Self::text(serde_json::to_string_pretty(value).unwrap_or_else(|_| "null".to_string()))
+    }
 }
 
 /// MCP List Tools request parameters
@@ -0,0 +1,190 @@
+//! Masking of people in tool output, for sessions whose output ends up in
+//! screenshots or recordings.
+//!
+//! With `anonymize_output`, the client remembers every user object of the
+//! responses it reads, and [`anonymize_text`] replaces their display names and
+//! usernames with stable aliases ("User 1", "User 2", …) and any email address
+//! with [`EMAIL_MASK`]. Account ids are kept: they identify no one outside the
+//! site, and tools still need them to address users.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+
+/// Replacement for email addresses
+pub const EMAIL_MASK: &str = "[email]";
+
+/// Names too short to mask without hitting ordinary words
+const MIN_MASKED_NAME_CHARS: usize = 3;
+
+/// Aliases of the names seen so far
+#[derive(Debug, Default)]
+struct Aliases {
+    /// Alias of each user, by account id, username or display name
+    users: HashMap<String, String>,
+    /// Names to replace, longest first so that "Ann Lee" wins over "Ann"
+    names: Vec<(String, String)>,
+}
+
+static ALIASES: LazyLock<RwLock<Aliases>> = LazyLock::new(|| RwLock::new(Aliases::default()));
+
+/// Whether `value` is a user object: it has a display name and an account
+/// id, an email address or a user URL
+fn is_user(object: &serde_json::Map<String, Value>) -> bool {
+    object.contains_key("displayName")
+        && (object.contains_key("accountId")
+            || object.contains_key("emailAddress")
+            || object
+                .get("self")
+                .and_then(Value::as_str)
+                .is_some_and(|url| url.contains("/user")))
+}
+
+/// Give the users of `value`, and of every object nested in it, an alias
+pub fn remember_users(value: &Value) {
+    match value {
+        Value::Object(object) => {
+            if is_user(object) {
+                remember_user(object);
+            }
+            object.values().for_each(remember_users);
+        }
+        Value::Array(items) => items.iter().for_each(remember_users),
+        _ => {}
+    }
+}
+
+fn remember_user(user: &serde_json::Map<String, Value>) {
+    let text = |field: &str| {
+        user.get(field)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    };
+    let names: Vec<&str> = [text("displayName"), text("name")]
+        .into_iter()
+        .flatten()
+        .filter(|name| name.chars().count() >= MIN_MASKED_NAME_CHARS && !name.contains('@'))
+        .collect();
+    let Some(id) = text("accountId").or(text("key")).or(names.first().copied()) else {
+        return;
+    };
+
+    let mut aliases = ALIASES
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let count = aliases.users.len();
+    let alias = aliases
+        .users
+        .entry(id.to_string())
+        .or_insert_with(|| format!("User {}", count + 1))
+        .clone();
+    let mut added = false;
+    for name in names {
+        if !aliases.names.iter().any(|(known, _)| known == name) {
+            aliases.names.push((name.to_string(), alias.clone()));
+            added = true;
+        }
+    }
+    if added {
+        aliases
+            .names
+            .sort_by_key(|(name, _)| std::cmp::Reverse(name.chars().count()));
+    }
+}
+
+/// The alias of the user with `name` (a display name or username), if seen
+#[must_use]
+pub fn alias_of(name: &str) -> Option<String> {
+    ALIASES.read().ok().and_then(|aliases| {
+        aliases
+            .names
+            .iter()
+            .find(|(known, _)| known == name)
+            .map(|(_, alias)| alias.clone())
+    })
+}
+
+/// `text` with email addresses masked and the names of the users seen so far
+/// replaced by their aliases
+#[must_use]
+pub fn anonymize_text(text: &str) -> String {
+    let mut text = mask_emails(text);
+    let Ok(aliases) = ALIASES.read() else {
+        return text;
+    };
+    for (name, alias) in &aliases.names {
+        text = replace_words(&text, name, alias);
+    }
+    text
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// `text` with each occurrence of `name` that is not part of a longer word
+/// replaced by `alias`
+fn replace_words(text: &str, name: &str, alias: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = rest.find(name) {
+        let end = at + name.len();
+        let before = rest[..at].chars().next_back();
+        let after = rest[end..].chars().next();
+        result.push_str(&rest[..at]);
+        if before.is_some_and(is_word_char) || after.is_some_and(is_word_char) {
+            result.push_str(name);
+        } else {
+            result.push_str(alias);
+        }
+        rest = &rest[end..];
+    }
+    result.push_str(rest);
+    result
+}
+
+fn is_local_part_char(c: char) -> bool {
+    c.is_alphanumeric() || "._%+-".contains(c)
+}
+
+fn is_domain_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '.'
+}
+
+/// `text` with every `local@domain.tld` replaced by [`EMAIL_MASK`]
+#[must_use]
+pub fn mask_emails(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = rest.find('@') {
+        let local_start = rest[..at]
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| is_local_part_char(*c))
+            .last()
+            .map_or(at, |(i, _)| i);
+        let domain = &rest[at + 1..];
+        let domain_len = domain
+            .char_indices()
+            .take_while(|(_, c)| is_domain_char(*c))
+            .last()
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        // A trailing dot ends the sentence, not the domain
+        let domain = domain[..domain_len].trim_end_matches('.');
+        let is_email = local_start < at
+            && domain.contains('.')
+            && !domain.starts_with('.')
+            && !domain.contains("..");
+        if is_email {
+            result.push_str(&rest[..local_start]);
+            result.push_str(EMAIL_MASK);
+            rest = &rest[at + 1 + domain.len()..];
+        } else {
+            result.push_str(&rest[..=at]);
+            rest = &rest[at + 1..];
+        }
+    }
+    result.push_str(rest);
+    result
+}
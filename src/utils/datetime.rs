@@ -0,0 +1,162 @@
+//! Reading the timestamps Jira sends and writing them for people.
+//!
+//! Jira sends timestamps in ISO 8601 whatever the locale of the server or
+//! the `Accept-Language` of the request: `2024-03-01T09:00:00.000+0000` from
+//! the REST API and RFC 3339 (`…Z`, `…+00:00`) from the Agile API, with or
+//! without milliseconds. Anything reading one goes through
+//! [`parse_timestamp`]; tool output shows them through a [`DateFormatter`],
+//! in the configured `display_timezone` and `display_date_format`.
+
+use crate::config::JiraConfig;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime};
+use tracing::warn;
+
+/// `display_date_format` used when none is configured, e.g.
+/// `2024-03-01 10:00 +01:00`
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d %H:%M %:z";
+
+/// Parse a Jira timestamp: ISO 8601 with an offset written `Z`, `+00:00` or
+/// `+0000`, with or without fractional seconds. A timestamp without any
+/// offset is taken to be UTC.
+#[must_use]
+pub fn parse_timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+                .ok()
+                .map(|time| time.and_utc().fixed_offset())
+        })
+}
+
+/// Time zone dates are shown in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayTimezone {
+    /// The time zone of the machine running the server
+    Local,
+    Fixed(FixedOffset),
+}
+
+impl DisplayTimezone {
+    /// Parse `local`, `UTC` or an offset from UTC such as `+02:00`, `-0530`
+    /// or `UTC+1`, ignoring case
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("local") {
+            return Some(Self::Local);
+        }
+        let offset = value
+            .strip_prefix("UTC")
+            .or_else(|| value.strip_prefix("utc"))
+            .or_else(|| value.strip_prefix("GMT"))
+            .unwrap_or(value);
+        if offset.is_empty() || offset.eq_ignore_ascii_case("z") {
+            return FixedOffset::east_opt(0).map(Self::Fixed);
+        }
+
+        let (sign, digits) = if let Some(rest) = offset.strip_prefix('+') {
+            (1, rest)
+        } else if let Some(rest) = offset.strip_prefix('-') {
+            (-1, rest)
+        } else {
+            return None;
+        };
+        // Only ASCII digits from here on, so byte offsets fall on characters
+        if !digits.chars().all(|c| c.is_ascii_digit() || c == ':') {
+            return None;
+        }
+        let (hours, minutes) = match digits.split_once(':') {
+            Some((hours, minutes)) => (hours, minutes),
+            None if digits.len() > 2 => digits.split_at(digits.len() - 2),
+            None => (digits, "0"),
+        };
+        let hours: i32 = hours.parse().ok().filter(|h| (0..=14).contains(h))?;
+        let minutes: i32 = minutes.parse().ok().filter(|m| (0..60).contains(m))?;
+        FixedOffset::east_opt(sign * (hours * 3_600 + minutes * 60)).map(Self::Fixed)
+    }
+}
+
+/// Whether `format` is a strftime format chrono can write
+#[must_use]
+pub fn is_valid_date_format(format: &str) -> bool {
+    !StrftimeItems::new(format).any(|item| matches!(item, Item::Error))
+}
+
+/// Writes timestamps in one time zone and format for tool output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateFormatter {
+    timezone: DisplayTimezone,
+    format: String,
+}
+
+impl Default for DateFormatter {
+    fn default() -> Self {
+        Self::new(DisplayTimezone::Local, DEFAULT_DATE_FORMAT)
+    }
+}
+
+impl DateFormatter {
+    /// Create a formatter; a `format` chrono cannot write falls back to
+    /// [`DEFAULT_DATE_FORMAT`]
+    #[must_use]
+    pub fn new(timezone: DisplayTimezone, format: &str) -> Self {
+        let format = if is_valid_date_format(format) {
+            format
+        } else {
+            warn!(
+                "Invalid date format '{}', using '{}'",
+                format, DEFAULT_DATE_FORMAT
+            );
+            DEFAULT_DATE_FORMAT
+        };
+        Self {
+            timezone,
+            format: format.to_string(),
+        }
+    }
+
+    /// Create a formatter using `display_timezone` and `display_date_format`
+    /// from the configuration; a time zone that cannot be read falls back to
+    /// the local one
+    #[must_use]
+    pub fn from_config(config: &JiraConfig) -> Self {
+        let timezone = config
+            .display_timezone
+            .as_deref()
+            .map_or(DisplayTimezone::Local, |value| {
+                DisplayTimezone::parse(value).unwrap_or_else(|| {
+                    warn!("Invalid display time zone '{}', using local time", value);
+                    DisplayTimezone::Local
+                })
+            });
+        Self::new(
+            timezone,
+            config
+                .display_date_format
+                .as_deref()
+                .unwrap_or(DEFAULT_DATE_FORMAT),
+        )
+    }
+
+    /// Write `time` in the display time zone and format
+    #[must_use]
+    pub fn format(&self, time: &DateTime<FixedOffset>) -> String {
+        match self.timezone {
+            DisplayTimezone::Local => time.with_timezone(&Local).format(&self.format).to_string(),
+            DisplayTimezone::Fixed(offset) => {
+                time.with_timezone(&offset).format(&self.format).to_string()
+            }
+        }
+    }
+
+    /// Write the Jira timestamp `value` for display; text that is not a
+    /// timestamp, such as ZAPI's "Today 2:10 PM", is returned unchanged
+    #[must_use]
+    pub fn format_timestamp(&self, value: &str) -> String {
+        parse_timestamp(value).map_or_else(|| value.to_string(), |time| self.format(&time))
+    }
+}
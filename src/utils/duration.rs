@@ -0,0 +1,276 @@
+//! Reading the durations people type for logged work and estimates, e.g.
+//! "1.5h", "90m", "1h30m", "2d 4h" or "1:30", into Jira's canonical
+//! `Nw Nd Nh Nm` form.
+//!
+//! Instances accept different subsets of these spellings, so tools normalize
+//! them before sending. Days and weeks are worth as many hours as the
+//! instance's time tracking says (`work_hours_per_day` and
+//! `work_days_per_week`, 8 and 5 by default), which is also how a total is
+//! split back into weeks, days, hours and minutes.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+
+/// Hours in a working day when `work_hours_per_day` is not configured
+pub const DEFAULT_WORK_HOURS_PER_DAY: f64 = 8.0;
+/// Days in a working week when `work_days_per_week` is not configured
+pub const DEFAULT_WORK_DAYS_PER_WEEK: f64 = 5.0;
+
+/// Examples quoted by every rejection message
+const EXAMPLES: &str = "e.g. 1h 30m, 90m, 1.5h or 2d 4h";
+
+/// How long a working day and week are, in minutes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkCalendar {
+    minutes_per_day: u64,
+    minutes_per_week: u64,
+}
+
+impl WorkCalendar {
+    /// Create a calendar of `hours_per_day` hours and `days_per_week` days,
+    /// both of which may be fractional (7.5 hours, 4.5 days). Values that are
+    /// not positive fall back to the defaults.
+    #[must_use]
+    pub fn new(hours_per_day: f64, days_per_week: f64) -> Self {
+        let positive = |value: f64, default: f64| {
+            if value.is_finite() && value > 0.0 {
+                value
+            } else {
+                default
+            }
+        };
+        let hours_per_day = positive(hours_per_day, DEFAULT_WORK_HOURS_PER_DAY);
+        let days_per_week = positive(days_per_week, DEFAULT_WORK_DAYS_PER_WEEK);
+        let minutes_per_day = whole_minutes(hours_per_day * 60.0).max(1);
+        Self {
+            minutes_per_day,
+            minutes_per_week: whole_minutes(minutes_per_day as f64 * days_per_week).max(1),
+        }
+    }
+
+    /// Create a calendar using `work_hours_per_day` and `work_days_per_week`
+    /// from the configuration
+    #[must_use]
+    pub fn from_config(config: &JiraConfig) -> Self {
+        Self::new(
+            config
+                .work_hours_per_day
+                .unwrap_or(DEFAULT_WORK_HOURS_PER_DAY),
+            config
+                .work_days_per_week
+                .unwrap_or(DEFAULT_WORK_DAYS_PER_WEEK),
+        )
+    }
+
+    #[must_use]
+    pub fn minutes_per_day(&self) -> u64 {
+        self.minutes_per_day
+    }
+
+    #[must_use]
+    pub fn minutes_per_week(&self) -> u64 {
+        self.minutes_per_week
+    }
+}
+
+impl Default for WorkCalendar {
+    fn default() -> Self {
+        Self::new(DEFAULT_WORK_HOURS_PER_DAY, DEFAULT_WORK_DAYS_PER_WEEK)
+    }
+}
+
+/// A duration of logged or estimated work, in whole minutes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WorkDuration {
+    minutes: u64,
+}
+
+impl WorkDuration {
+    #[must_use]
+    pub fn from_minutes(minutes: u64) -> Self {
+        Self { minutes }
+    }
+
+    #[must_use]
+    pub fn minutes(&self) -> u64 {
+        self.minutes
+    }
+
+    #[must_use]
+    pub fn seconds(&self) -> u64 {
+        self.minutes * 60
+    }
+
+    /// Read the duration given as argument `field`.
+    ///
+    /// Accepted are amounts with a unit (`w`, `d`, `h` or `m`, or the words
+    /// `week`, `day`, `hour`, `hr`, `minute`, `min` in singular or plural),
+    /// each unit at most once and in any order, with or without spaces
+    /// between them: `1h30m`, `2d 4h`, `3 hours`. Amounts may have a decimal
+    /// point or comma (`1.5h`, `1,5h`). `H:MM` reads as hours and minutes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error saying what is wrong if `input` is empty,
+    /// an amount has no unit or an unknown one, a unit is repeated, the total
+    /// is not a whole number of minutes, or it is zero.
+    pub fn parse(field: &str, input: &str, calendar: &WorkCalendar) -> Result<Self> {
+        let text = input.trim();
+        if text.is_empty() {
+            return Err(JiraError::validation_error(
+                field,
+                &format!("Enter a duration, {EXAMPLES}"),
+            ));
+        }
+
+        let minutes = if let Some((hours, minutes)) = text.split_once(':') {
+            Self::parse_clock(field, text, hours, minutes)?
+        } else {
+            Self::parse_amounts(field, text, calendar)?
+        };
+        if minutes < 0.5 {
+            return Err(JiraError::validation_error(
+                field,
+                &format!("'{text}' is no time; a duration needs at least 1m"),
+            ));
+        }
+        if (minutes - minutes.round()).abs() > 1e-6 {
+            return Err(JiraError::validation_error(
+                field,
+                &format!("'{text}' is not a whole number of minutes, which is what Jira records"),
+            ));
+        }
+        Ok(Self {
+            minutes: whole_minutes(minutes),
+        })
+    }
+
+    /// Minutes of an `H:MM` duration
+    fn parse_clock(field: &str, text: &str, hours: &str, minutes: &str) -> Result<f64> {
+        let invalid = || {
+            JiraError::validation_error(
+                field,
+                &format!("'{text}' is not a duration; write hours and minutes as H:MM, e.g. 1:30"),
+            )
+        };
+        let digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        if !digits(hours) || !digits(minutes) || minutes.len() != 2 {
+            return Err(invalid());
+        }
+        let hours: u64 = hours.parse().map_err(|_| invalid())?;
+        let minutes: u64 = minutes.parse().map_err(|_| invalid())?;
+        if minutes >= 60 {
+            return Err(invalid());
+        }
+        Ok((hours * 60 + minutes) as f64)
+    }
+
+    /// Minutes of a list of amounts with units
+    fn parse_amounts(field: &str, text: &str, calendar: &WorkCalendar) -> Result<f64> {
+        let mut rest = text;
+        let mut seen: Vec<&str> = Vec::new();
+        let mut total = 0.0;
+
+        while !rest.is_empty() {
+            let amount_len = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+                .unwrap_or(rest.len());
+            let amount_text = &rest[..amount_len];
+            if amount_text.is_empty() {
+                return Err(JiraError::validation_error(
+                    field,
+                    &format!("'{text}' is not a duration; use amounts with units, {EXAMPLES}"),
+                ));
+            }
+            let amount: f64 = amount_text
+                .replace(',', ".")
+                .parse()
+                .ok()
+                .filter(|_| amount_text.matches(['.', ',']).count() <= 1)
+                .ok_or_else(|| {
+                    JiraError::validation_error(
+                        field,
+                        &format!("'{amount_text}' in '{text}' is not a number"),
+                    )
+                })?;
+
+            rest = rest[amount_len..].trim_start();
+            let unit_len = rest
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let unit_text = &rest[..unit_len];
+            if unit_text.is_empty() {
+                return Err(JiraError::validation_error(
+                    field,
+                    &format!(
+                        "'{amount_text}' in '{text}' has no unit; add w, d, h or m, e.g. {amount_text}m"
+                    ),
+                ));
+            }
+            let (unit, minutes) = match unit_text.to_ascii_lowercase().as_str() {
+                "w" | "wk" | "wks" | "week" | "weeks" => ("weeks", calendar.minutes_per_week),
+                "d" | "day" | "days" => ("days", calendar.minutes_per_day),
+                "h" | "hr" | "hrs" | "hour" | "hours" => ("hours", 60),
+                "m" | "min" | "mins" | "minute" | "minutes" => ("minutes", 1),
+                _ => {
+                    return Err(JiraError::validation_error(
+                        field,
+                        &format!(
+                            "Unknown unit '{unit_text}' in '{text}'; use w (weeks), d (days), h (hours) or m (minutes)"
+                        ),
+                    ))
+                }
+            };
+            if seen.contains(&unit) {
+                return Err(JiraError::validation_error(
+                    field,
+                    &format!("'{text}' gives {unit} more than once"),
+                ));
+            }
+            seen.push(unit);
+            total += amount * minutes as f64;
+            rest = rest[unit_len..].trim_start();
+        }
+        Ok(total)
+    }
+
+    /// Jira's canonical form, e.g. `1w 2d 3h 30m`: the largest units first,
+    /// days and weeks as long as `calendar` says, parts that are zero left out
+    #[must_use]
+    pub fn to_jira(&self, calendar: &WorkCalendar) -> String {
+        let mut rest = self.minutes;
+        let mut parts = Vec::new();
+        for (unit, size) in [
+            ("w", calendar.minutes_per_week),
+            ("d", calendar.minutes_per_day),
+            ("h", 60),
+            ("m", 1),
+        ] {
+            let count = rest / size;
+            rest %= size;
+            if count > 0 {
+                parts.push(format!("{count}{unit}"));
+            }
+        }
+        if parts.is_empty() {
+            "0m".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+}
+
+/// Read the duration argument `field` and give it back in Jira's canonical
+/// form, see [`WorkDuration::parse`] and [`WorkDuration::to_jira`]
+///
+/// # Errors
+///
+/// Returns a validation error if `input` is not a duration.
+pub fn normalize_duration(field: &str, input: &str, calendar: &WorkCalendar) -> Result<String> {
+    Ok(WorkDuration::parse(field, input, calendar)?.to_jira(calendar))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn whole_minutes(minutes: f64) -> u64 {
+    minutes.round().max(0.0) as u64
+}
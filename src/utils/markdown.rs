@@ -0,0 +1,766 @@
+//! Markdown written by assistants, converted to what Jira renders: wiki
+//! markup for REST API v2 and Atlassian Document Format for v3.
+//!
+//! The supported subset is the one assistants use: bold, italic,
+//! strikethrough, inline code and links; headings, fenced code blocks with a
+//! language, bullet and numbered lists (nested by indentation), tables, block
+//! quotes and rules. Anything else is kept as text, with the characters wiki
+//! markup would read as formatting escaped.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraError, Result};
+use serde_json::{json, Value};
+
+/// How the rich text arguments of a tool call are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputFormat {
+    /// Jira wiki markup, sent as written
+    #[default]
+    Wiki,
+    /// Markdown, converted before it is sent
+    Markdown,
+}
+
+impl InputFormat {
+    /// Parse `wiki` or `markdown`, ignoring case
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "wiki" => Some(Self::Wiki),
+            "markdown" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+
+    /// The format of a tool call: its `input_format` argument, or else the
+    /// configured `default_input_format`. `JiraConfig::validate` reports an
+    /// unknown configured format; here it falls back to wiki markup.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if `input_format` is not `wiki` or
+    /// `markdown`.
+    pub fn from_args(args: &Value, config: &JiraConfig) -> Result<Self> {
+        match args.get("input_format").and_then(|v| v.as_str()) {
+            None => Ok(config
+                .default_input_format
+                .as_deref()
+                .and_then(Self::parse)
+                .unwrap_or_default()),
+            Some(value) => Self::parse(value).ok_or_else(|| {
+                JiraError::validation_error(
+                    "input_format",
+                    &format!("Unknown input format '{value}'; expected wiki or markdown"),
+                )
+            }),
+        }
+    }
+
+    /// `text` as wiki markup: unchanged, or converted from Markdown
+    #[must_use]
+    pub fn wiki(self, text: &str) -> String {
+        match self {
+            Self::Wiki => text.to_string(),
+            Self::Markdown => markdown_to_jira(text),
+        }
+    }
+
+    /// The rich text value to send for `text`: a string of wiki markup, or
+    /// an ADF document when `adf` is set and the text is Markdown
+    #[must_use]
+    pub fn body(self, text: &str, adf: bool) -> Value {
+        match self {
+            Self::Markdown if adf => markdown_to_adf(text),
+            _ => Value::String(self.wiki(text)),
+        }
+    }
+
+    /// Convert the `description` of issue `fields` when it is given as text
+    pub fn convert_description(self, fields: &mut serde_json::Map<String, Value>, adf: bool) {
+        if let Some(description) = fields.get("description").and_then(|v| v.as_str()) {
+            let body = self.body(description, adf);
+            fields.insert("description".to_string(), body);
+        }
+    }
+}
+
+/// Jira wiki markup for `markdown`
+#[must_use]
+pub fn markdown_to_jira(markdown: &str) -> String {
+    let blocks = parse_blocks(&markdown.lines().collect::<Vec<_>>());
+    wiki_blocks(&blocks)
+}
+
+/// Atlassian Document Format document for `markdown`
+#[must_use]
+pub fn markdown_to_adf(markdown: &str) -> Value {
+    let blocks = parse_blocks(&markdown.lines().collect::<Vec<_>>());
+    json!({
+        "type": "doc",
+        "version": 1,
+        "content": adf_blocks(&blocks)
+    })
+}
+
+/// Formatting of a run of inline text
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Style {
+    strong: bool,
+    em: bool,
+    strike: bool,
+    code: bool,
+    link: Option<String>,
+}
+
+/// Inline text in one style
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Span {
+    text: String,
+    style: Style,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ListItem {
+    ordered: bool,
+    depth: usize,
+    spans: Vec<Span>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Block {
+    Heading(usize, Vec<Span>),
+    /// Lines of a paragraph, each kept on its own line
+    Paragraph(Vec<Vec<Span>>),
+    Code {
+        language: Option<String>,
+        text: String,
+    },
+    List(Vec<ListItem>),
+    Table {
+        header: Vec<Vec<Span>>,
+        rows: Vec<Vec<Vec<Span>>>,
+    },
+    Quote(Vec<Block>),
+    Rule,
+}
+
+fn parse_blocks(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<Vec<Span>> = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+        let block_start = trimmed.is_empty()
+            || fence(line).is_some()
+            || heading(line).is_some()
+            || is_rule(line)
+            || list_marker(line).is_some()
+            || trimmed.starts_with('>')
+            || is_table_start(lines, i);
+        if !block_start {
+            paragraph.push(parse_inline(trimmed));
+            i += 1;
+            continue;
+        }
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(std::mem::take(&mut paragraph)));
+        }
+
+        if trimmed.is_empty() {
+            i += 1;
+        } else if let Some((marker, language)) = fence(line) {
+            let mut text = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with(marker) {
+                text.push(lines[i]);
+                i += 1;
+            }
+            i += 1;
+            blocks.push(Block::Code {
+                language,
+                text: text.join("\n"),
+            });
+        } else if let Some((level, text)) = heading(line) {
+            blocks.push(Block::Heading(level, parse_inline(text)));
+            i += 1;
+        } else if is_rule(line) {
+            blocks.push(Block::Rule);
+            i += 1;
+        } else if list_marker(line).is_some() {
+            let (items, next) = parse_list(lines, i);
+            blocks.push(Block::List(items));
+            i = next;
+        } else if trimmed.starts_with('>') {
+            let mut quoted = Vec::new();
+            while i < lines.len() && lines[i].trim_start().starts_with('>') {
+                let inner = &lines[i].trim_start()[1..];
+                quoted.push(inner.strip_prefix(' ').unwrap_or(inner));
+                i += 1;
+            }
+            blocks.push(Block::Quote(parse_blocks(&quoted)));
+        } else {
+            let header = table_cells(lines[i]);
+            let mut rows = Vec::new();
+            i += 2;
+            while i < lines.len() && lines[i].contains('|') && !lines[i].trim().is_empty() {
+                rows.push(table_cells(lines[i]));
+                i += 1;
+            }
+            blocks.push(Block::Table { header, rows });
+        }
+    }
+    if !paragraph.is_empty() {
+        blocks.push(Block::Paragraph(paragraph));
+    }
+    blocks
+}
+
+/// The marker and language of a line opening a fenced code block
+fn fence(line: &str) -> Option<(&'static str, Option<String>)> {
+    let trimmed = line.trim_start();
+    let marker = ["```", "~~~"]
+        .into_iter()
+        .find(|marker| trimmed.starts_with(marker))?;
+    let language = trimmed.trim_start_matches(marker.chars().next()?).trim();
+    let language = language.split_whitespace().next().map(code_language);
+    Some((marker, language))
+}
+
+/// Name of a fenced code block's language as the `{code}` macro knows it
+fn code_language(language: &str) -> String {
+    let language = language.to_ascii_lowercase();
+    match language.as_str() {
+        "js" => "javascript",
+        "ts" => "typescript",
+        "py" => "python",
+        "rb" => "ruby",
+        "sh" | "shell" | "zsh" => "bash",
+        "yml" => "yaml",
+        "rs" => "rust",
+        "kt" => "kotlin",
+        other => other,
+    }
+    .to_string()
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    let rest = &trimmed[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+    Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|marker| compact.chars().all(|c| c == *marker))
+}
+
+/// Indentation, whether the list is numbered, and the text of a list item
+fn list_marker(line: &str) -> Option<(usize, bool, &str)> {
+    let indent: usize = line
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum();
+    let trimmed = line.trim_start();
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(text) = trimmed.strip_prefix(bullet) {
+            return Some((indent, false, text.trim()));
+        }
+    }
+    let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+    let rest = &trimmed[digits..];
+    if digits > 0 && (rest.starts_with(". ") || rest.starts_with(") ")) {
+        return Some((indent, true, rest[2..].trim()));
+    }
+    None
+}
+
+/// The items of the list starting at line `start`, nested by indentation,
+/// and the line after it. Blank lines between items and indented lines
+/// continuing an item belong to the list.
+fn parse_list(lines: &[&str], start: usize) -> (Vec<ListItem>, usize) {
+    let mut items: Vec<ListItem> = Vec::new();
+    let mut raw: Vec<String> = Vec::new();
+    let mut indents: Vec<usize> = Vec::new();
+    let mut i = start;
+    while i < lines.len() {
+        let line = lines[i];
+        if let Some((indent, ordered, text)) = list_marker(line) {
+            while indents.last().is_some_and(|last| indent < *last) {
+                indents.pop();
+            }
+            if indents.last().is_none_or(|last| indent > *last) {
+                indents.push(indent);
+            }
+            items.push(ListItem {
+                ordered,
+                depth: indents.len() - 1,
+                spans: Vec::new(),
+            });
+            raw.push(text.to_string());
+        } else if line.trim().is_empty() {
+            let next = lines[i..].iter().position(|line| !line.trim().is_empty());
+            if next.is_none_or(|next| list_marker(lines[i + next]).is_none()) {
+                break;
+            }
+        } else if line.starts_with([' ', '\t']) && !raw.is_empty() {
+            if let Some(last) = raw.last_mut() {
+                last.push(' ');
+                last.push_str(line.trim());
+            }
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    for (item, text) in items.iter_mut().zip(raw) {
+        item.spans = parse_inline(&text);
+    }
+    (items, i)
+}
+
+fn is_separator_row(line: &str) -> bool {
+    let trimmed = line.trim().trim_matches('|');
+    !trimmed.is_empty()
+        && trimmed.split('|').all(|cell| {
+            let cell = cell.trim().trim_start_matches(':').trim_end_matches(':');
+            !cell.is_empty() && cell.chars().all(|c| c == '-')
+        })
+}
+
+fn is_table_start(lines: &[&str], i: usize) -> bool {
+    lines[i].contains('|') && lines.get(i + 1).is_some_and(|next| is_separator_row(next))
+}
+
+/// Cells of a table row, split on the pipes that are not escaped
+fn table_cells(line: &str) -> Vec<Vec<Span>> {
+    let row = line.trim();
+    let row = row.strip_prefix('|').unwrap_or(row);
+    let row = if row.ends_with('|') && !row.ends_with("\\|") {
+        &row[..row.len() - 1]
+    } else {
+        row
+    };
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = row.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                cell.push(c);
+                if let Some(next) = chars.next() {
+                    cell.push(next);
+                }
+            }
+            '|' => cells.push(parse_inline(std::mem::take(&mut cell).trim())),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(parse_inline(cell.trim()));
+    cells
+}
+
+fn parse_inline(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    push_inline(text, &Style::default(), &mut spans);
+    spans
+}
+
+/// Add the spans of `text` in `style` to `spans`, merging runs of one style
+fn push_inline(text: &str, style: &Style, spans: &mut Vec<Span>) {
+    let mut plain = String::new();
+    let mut previous: Option<char> = None;
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if c == '\\' {
+            if let Some(next) = rest[1..].chars().next().filter(char::is_ascii_punctuation) {
+                plain.push(next);
+                previous = Some(next);
+                rest = &rest[1 + next.len_utf8()..];
+                continue;
+            }
+        }
+        if let Some((inner, inner_style, after)) = inline_token(rest, previous, style) {
+            push_span(spans, std::mem::take(&mut plain), style);
+            if inner_style.code {
+                push_span(spans, inner.to_string(), &inner_style);
+            } else {
+                push_inline(inner, &inner_style, spans);
+            }
+            previous = rest[..rest.len() - after.len()].chars().last();
+            rest = after;
+            continue;
+        }
+        plain.push(c);
+        previous = Some(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    push_span(spans, plain, style);
+}
+
+fn push_span(spans: &mut Vec<Span>, text: String, style: &Style) {
+    if text.is_empty() {
+        return;
+    }
+    match spans.last_mut() {
+        Some(last) if last.style == *style => last.text.push_str(&text),
+        _ => spans.push(Span {
+            text,
+            style: style.clone(),
+        }),
+    }
+}
+
+/// The formatted text starting `rest`: its content, its style and the text
+/// after it
+fn inline_token<'a>(
+    rest: &'a str,
+    previous: Option<char>,
+    style: &Style,
+) -> Option<(&'a str, Style, &'a str)> {
+    let mut inner_style = style.clone();
+    if rest.starts_with('`') {
+        let ticks = rest.chars().take_while(|c| *c == '`').count();
+        let delimiter = &rest[..ticks];
+        let close = rest[ticks..].find(delimiter)?;
+        let inner = &rest[ticks..ticks + close];
+        let inner = if inner.len() > 2 && inner.starts_with(' ') && inner.ends_with(' ') {
+            &inner[1..inner.len() - 1]
+        } else {
+            inner
+        };
+        inner_style.code = true;
+        return Some((inner, inner_style, &rest[2 * ticks + close..]));
+    }
+    if rest.starts_with('[') && style.link.is_none() {
+        let label_end = rest.find("](")?;
+        let url_end = rest[label_end + 2..].find(')')? + label_end + 2;
+        let url = rest[label_end + 2..url_end].trim();
+        if url.is_empty() || url.contains(char::is_whitespace) {
+            return None;
+        }
+        inner_style.link = Some(url.to_string());
+        return Some((&rest[1..label_end], inner_style, &rest[url_end + 1..]));
+    }
+    if rest.starts_with("<http://") || rest.starts_with("<https://") {
+        let close = rest.find('>')?;
+        let url = &rest[1..close];
+        if url.contains(char::is_whitespace) {
+            return None;
+        }
+        inner_style.link = Some(url.to_string());
+        return Some((url, inner_style, &rest[close + 1..]));
+    }
+
+    let after_word = previous.is_some_and(char::is_alphanumeric);
+    for (delimiter, set) in [("**", 0), ("__", 0), ("~~", 2), ("*", 1), ("_", 1)] {
+        if !rest.starts_with(delimiter) || (delimiter.starts_with('_') && after_word) {
+            continue;
+        }
+        let body = &rest[delimiter.len()..];
+        if body.starts_with(char::is_whitespace) || body.starts_with(delimiter) {
+            continue;
+        }
+        let Some(close) = closing_delimiter(body, delimiter) else {
+            continue;
+        };
+        let after = &body[close + delimiter.len()..];
+        if delimiter.starts_with('_') && after.starts_with(char::is_alphanumeric) {
+            continue;
+        }
+        match set {
+            0 => inner_style.strong = true,
+            1 => inner_style.em = true,
+            _ => inner_style.strike = true,
+        }
+        return Some((&body[..close], inner_style, after));
+    }
+    None
+}
+
+/// Where `delimiter` closes in `body`: not after whitespace, and for a
+/// single `*` or `_` not as part of a double one
+fn closing_delimiter(body: &str, delimiter: &str) -> Option<usize> {
+    let single = delimiter.len() == 1;
+    let marker = delimiter.chars().next()?;
+    let mut from = 0;
+    while let Some(found) = body[from..].find(delimiter) {
+        let at = from + found;
+        let before = body[..at].chars().last();
+        let doubled = single && (body[at + 1..].starts_with(marker) || before == Some(marker));
+        if at > 0 && !before.is_some_and(char::is_whitespace) && !doubled {
+            return Some(at);
+        }
+        from = at + delimiter.len();
+        if single && body[from..].starts_with(marker) {
+            from += 1;
+        }
+    }
+    None
+}
+
+fn wiki_blocks(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(wiki_block)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn wiki_block(block: &Block) -> String {
+    match block {
+        Block::Heading(level, spans) => format!("h{level}. {}", wiki_inline(spans)),
+        Block::Paragraph(lines) => lines
+            .iter()
+            .map(|spans| wiki_inline(spans))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Code { language, text } => match language {
+            Some(language) => format!("{{code:{language}}}\n{text}\n{{code}}"),
+            None => format!("{{code}}\n{text}\n{{code}}"),
+        },
+        Block::List(items) => {
+            let mut markers = String::new();
+            items
+                .iter()
+                .map(|item| {
+                    markers.truncate(item.depth);
+                    markers.push(if item.ordered { '#' } else { '*' });
+                    format!("{markers} {}", wiki_inline(&item.spans))
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        Block::Table { header, rows } => {
+            let mut lines = vec![format!(
+                "||{}||",
+                header
+                    .iter()
+                    .map(|cell| wiki_cell(cell))
+                    .collect::<Vec<_>>()
+                    .join("||")
+            )];
+            for row in rows {
+                lines.push(format!(
+                    "|{}|",
+                    row.iter()
+                        .map(|cell| wiki_cell(cell))
+                        .collect::<Vec<_>>()
+                        .join("|")
+                ));
+            }
+            lines.join("\n")
+        }
+        Block::Quote(blocks) => format!("{{quote}}\n{}\n{{quote}}", wiki_blocks(blocks)),
+        Block::Rule => "----".to_string(),
+    }
+}
+
+/// A table cell; an empty one needs a space to keep its column
+fn wiki_cell(spans: &[Span]) -> String {
+    let text = wiki_inline(spans);
+    if text.is_empty() {
+        " ".to_string()
+    } else {
+        text
+    }
+}
+
+fn wiki_inline(spans: &[Span]) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < spans.len() {
+        let Some(url) = &spans[i].style.link else {
+            out.push_str(&wiki_span(&spans[i]));
+            i += 1;
+            continue;
+        };
+        let mut label = String::new();
+        while i < spans.len() && spans[i].style.link.as_ref() == Some(url) {
+            label.push_str(&wiki_span(&spans[i]));
+            i += 1;
+        }
+        if label == escape_wiki(url) {
+            out.push_str(&format!("[{url}]"));
+        } else {
+            out.push_str(&format!("[{label}|{url}]"));
+        }
+    }
+    out
+}
+
+fn wiki_span(span: &Span) -> String {
+    if span.style.code {
+        return format!("{{{{{}}}}}", span.text);
+    }
+    let text = escape_wiki(&span.text);
+    let core = text.trim();
+    if core.is_empty() {
+        return text;
+    }
+    let start = text.len() - text.trim_start().len();
+    let mut formatted = core.to_string();
+    for (on, marker) in [
+        (span.style.strike, '-'),
+        (span.style.em, '_'),
+        (span.style.strong, '*'),
+    ] {
+        if on {
+            formatted = format!("{marker}{formatted}{marker}");
+        }
+    }
+    format!(
+        "{}{formatted}{}",
+        &text[..start],
+        &text[start + core.len()..]
+    )
+}
+
+/// Escape the characters wiki markup would read as formatting or macros,
+/// except in bare URLs, which Jira links as written
+fn escape_wiki(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for word in text.split_inclusive(char::is_whitespace) {
+        if word.starts_with("http://") || word.starts_with("https://") {
+            escaped.push_str(word);
+            continue;
+        }
+        for c in word.chars() {
+            if matches!(c, '*' | '_' | '{' | '}' | '[' | ']' | '|') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+fn adf_blocks(blocks: &[Block]) -> Vec<Value> {
+    blocks.iter().flat_map(adf_block).collect()
+}
+
+/// The nodes of a block; one, except for a list switching between bullets
+/// and numbers
+fn adf_block(block: &Block) -> Vec<Value> {
+    let node = match block {
+        Block::Heading(level, spans) => json!({
+            "type": "heading",
+            "attrs": {"level": level},
+            "content": adf_inline(spans)
+        }),
+        Block::Paragraph(lines) => {
+            let mut content = Vec::new();
+            for (i, spans) in lines.iter().enumerate() {
+                if i > 0 {
+                    content.push(json!({"type": "hardBreak"}));
+                }
+                content.extend(adf_inline(spans));
+            }
+            json!({"type": "paragraph", "content": content})
+        }
+        Block::Code { language, text } => {
+            let mut node = json!({"type": "codeBlock", "content": []});
+            if let Some(language) = language {
+                node["attrs"] = json!({"language": language});
+            }
+            if !text.is_empty() {
+                node["content"] = json!([{"type": "text", "text": text}]);
+            }
+            node
+        }
+        Block::List(items) => return adf_lists(items, 0),
+        Block::Table { header, rows } => {
+            let row = |cells: &[Vec<Span>], kind: &str| {
+                json!({
+                    "type": "tableRow",
+                    "content": (0..header.len())
+                        .map(|i| json!({
+                            "type": kind,
+                            "content": [adf_paragraph(cells.get(i).map_or(&[][..], Vec::as_slice))]
+                        }))
+                        .collect::<Vec<_>>()
+                })
+            };
+            let mut content = vec![row(header, "tableHeader")];
+            content.extend(rows.iter().map(|cells| row(cells, "tableCell")));
+            json!({"type": "table", "content": content})
+        }
+        Block::Quote(blocks) => json!({"type": "blockquote", "content": adf_blocks(blocks)}),
+        Block::Rule => json!({"type": "rule"}),
+    };
+    vec![node]
+}
+
+fn adf_paragraph(spans: &[Span]) -> Value {
+    json!({"type": "paragraph", "content": adf_inline(spans)})
+}
+
+/// The lists `items` at `depth` make, the deeper items nested in the item
+/// before them; a change between bullets and numbers starts a new list
+fn adf_lists(items: &[ListItem], depth: usize) -> Vec<Value> {
+    let mut lists: Vec<Value> = Vec::new();
+    let mut i = 0;
+    while i < items.len() {
+        let item = &items[i];
+        let end = items[i + 1..]
+            .iter()
+            .position(|child| child.depth <= depth)
+            .map_or(items.len(), |offset| i + 1 + offset);
+        let mut content = vec![adf_paragraph(&item.spans)];
+        content.extend(adf_lists(&items[i + 1..end], depth + 1));
+        let list_item = json!({"type": "listItem", "content": content});
+        let kind = if item.ordered {
+            "orderedList"
+        } else {
+            "bulletList"
+        };
+        match lists.last_mut() {
+            Some(list) if list["type"] == kind => {
+                if let Some(items) = list["content"].as_array_mut() {
+                    items.push(list_item);
+                }
+            }
+            _ => lists.push(json!({"type": kind, "content": [list_item]})),
+        }
+        i = end;
+    }
+    lists
+}
+
+fn adf_inline(spans: &[Span]) -> Vec<Value> {
+    spans
+        .iter()
+        .map(|span| {
+            let mut marks = Vec::new();
+            if span.style.code {
+                marks.push(json!({"type": "code"}));
+            } else {
+                for (on, mark) in [
+                    (span.style.strong, "strong"),
+                    (span.style.em, "em"),
+                    (span.style.strike, "strike"),
+                ] {
+                    if on {
+                        marks.push(json!({"type": mark}));
+                    }
+                }
+            }
+            if let Some(url) = &span.style.link {
+                marks.push(json!({"type": "link", "attrs": {"href": url}}));
+            }
+            let mut node = json!({"type": "text", "text": span.text});
+            if !marks.is_empty() {
+                node["marks"] = Value::Array(marks);
+            }
+            node
+        })
+        .collect()
+}
@@ -0,0 +1,291 @@
+//! Finding `@name` mentions in comment text and rewriting them as Jira mentions.
+//!
+//! Mentions are written as `@alice`, `@alice@example.com` or, for names with
+//! spaces, `@"Alice Smith"`. An `@` inside a word (as in a plain email address)
+//! is not a mention.
+
+use serde_json::json;
+use std::collections::HashMap;
+
+/// A `@name` token found in comment text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MentionToken {
+    /// The name, email or username to look up, without `@` or quotes
+    pub query: String,
+    /// Byte offset of the `@`
+    pub start: usize,
+    /// Byte offset just past the token
+    pub end: usize,
+}
+
+/// Find all mention tokens in `text`, in order of appearance
+#[must_use]
+pub fn find_mentions(text: &str) -> Vec<MentionToken> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    let mut previous: Option<char> = None;
+
+    while let Some((start, c)) = chars.next() {
+        let at_boundary = previous.is_none_or(|p| p.is_whitespace() || "([{,;:".contains(p));
+        previous = Some(c);
+        if c != '@' || !at_boundary {
+            continue;
+        }
+
+        let rest = &text[start + 1..];
+        let token = if let Some(quoted) = rest.strip_prefix('"') {
+            quoted.find('"').map(|close| {
+                let query = quoted[..close].trim().to_string();
+                (query, start + 1 + close + 2)
+            })
+        } else {
+            let len = rest
+                .char_indices()
+                .find(|(_, ch)| !(ch.is_alphanumeric() || "._-@+".contains(*ch)))
+                .map_or(rest.len(), |(i, _)| i);
+            // Trailing punctuation belongs to the sentence, not the name
+            let query = rest[..len].trim_end_matches(['.', '-', '@']);
+            Some((query.to_string(), start + 1 + query.len()))
+        };
+
+        let Some((query, end)) = token else {
+            continue;
+        };
+        if query.is_empty() {
+            continue;
+        }
+
+        while chars.peek().is_some_and(|(i, _)| *i < end) {
+            previous = chars.next().map(|(_, ch)| ch);
+        }
+        tokens.push(MentionToken { query, start, end });
+    }
+
+    tokens
+}
+
+/// A user returned by the user search API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MentionCandidate {
+    pub account_id: Option<String>,
+    pub username: Option<String>,
+    pub display_name: String,
+    pub email: Option<String>,
+}
+
+impl MentionCandidate {
+    /// Read a user from a user search result, covering both Cloud (`accountId`)
+    /// and Server/Data Center (`name`) responses
+    #[must_use]
+    pub fn from_user_json(user: &serde_json::Value) -> Option<Self> {
+        let text = |field: &str| user.get(field).and_then(|v| v.as_str()).map(String::from);
+        let account_id = text("accountId");
+        let username = text("name");
+        if account_id.is_none() && username.is_none() {
+            return None;
+        }
+        Some(Self {
+            display_name: text("displayName")
+                .or_else(|| username.clone())
+                .or_else(|| account_id.as_ref().map(|id| format!("user {id}")))
+                .unwrap_or_default(),
+            account_id,
+            username,
+            email: text("emailAddress"),
+        })
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        let eq = |value: &Option<String>| {
+            value
+                .as_deref()
+                .is_some_and(|v| v.eq_ignore_ascii_case(query))
+        };
+        self.display_name.eq_ignore_ascii_case(query)
+            || eq(&self.email)
+            || eq(&self.username)
+            || eq(&self.account_id)
+    }
+
+    /// Wiki markup mention, as understood by REST API v2
+    #[must_use]
+    pub fn wiki_markup(&self) -> String {
+        match (&self.account_id, &self.username) {
+            (Some(account_id), _) => format!("[~accountid:{account_id}]"),
+            (None, Some(username)) => format!("[~{username}]"),
+            (None, None) => format!("@{}", self.display_name),
+        }
+    }
+
+    /// ADF mention node, as understood by REST API v3
+    #[must_use]
+    pub fn adf_node(&self) -> serde_json::Value {
+        json!({
+            "type": "mention",
+            "attrs": {
+                "id": self.account_id.as_deref().or(self.username.as_deref()).unwrap_or_default(),
+                "text": format!("@{}", self.display_name),
+            }
+        })
+    }
+}
+
+/// Outcome of matching a mention against user search results
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MentionMatch {
+    Resolved(MentionCandidate),
+    Ambiguous(Vec<String>),
+    NotFound,
+}
+
+/// Pick the user a mention refers to.
+///
+/// Only exact (case-insensitive) matches on display name, email, username or
+/// account id count; fuzzy search hits are never guessed at.
+#[must_use]
+pub fn match_candidate(query: &str, candidates: &[MentionCandidate]) -> MentionMatch {
+    let mut matches: Vec<&MentionCandidate> =
+        candidates.iter().filter(|c| c.matches(query)).collect();
+    matches.dedup_by(|a, b| a == b);
+
+    match matches.as_slice() {
+        [] => MentionMatch::NotFound,
+        [candidate] => MentionMatch::Resolved((*candidate).clone()),
+        many => MentionMatch::Ambiguous(
+            many.iter()
+                .map(|c| match &c.email {
+                    Some(email) => format!("{} <{email}>", c.display_name),
+                    None => c.display_name.clone(),
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Comment text with its mentions resolved
+#[derive(Debug, Clone, Default)]
+pub struct MentionResolution {
+    /// Comment body to send: a string for v2, an ADF document for v3
+    pub body: serde_json::Value,
+    /// Mentions that were rewritten, as `(query, display name)`
+    pub resolved: Vec<(String, String)>,
+    /// Mentions no user matched
+    pub unresolved: Vec<String>,
+    /// Mentions that matched several users, with the candidates
+    pub ambiguous: Vec<(String, Vec<String>)>,
+}
+
+impl MentionResolution {
+    /// Resolve the mentions in `text` using the matches looked up for each query.
+    ///
+    /// Unresolved and ambiguous mentions are left as literal text.
+    #[must_use]
+    pub fn new(text: &str, matches: &HashMap<String, MentionMatch>, adf: bool) -> Self {
+        let mut resolution = Self::default();
+        let mut segments: Vec<Segment> = Vec::new();
+        let mut cursor = 0;
+
+        for token in find_mentions(text) {
+            let literal = &text[token.start..token.end];
+            match matches.get(&token.query) {
+                Some(MentionMatch::Resolved(candidate)) => {
+                    segments.push(Segment::Text(&text[cursor..token.start]));
+                    segments.push(Segment::Mention(candidate));
+                    cursor = token.end;
+                    if !resolution.resolved.iter().any(|(q, _)| q == &token.query) {
+                        resolution
+                            .resolved
+                            .push((token.query.clone(), candidate.display_name.clone()));
+                    }
+                }
+                Some(MentionMatch::Ambiguous(names)) => {
+                    if !resolution.ambiguous.iter().any(|(q, _)| q == literal) {
+                        resolution
+                            .ambiguous
+                            .push((literal.to_string(), names.clone()));
+                    }
+                }
+                Some(MentionMatch::NotFound) | None => {
+                    if !resolution.unresolved.iter().any(|q| q == literal) {
+                        resolution.unresolved.push(literal.to_string());
+                    }
+                }
+            }
+        }
+        segments.push(Segment::Text(&text[cursor..]));
+
+        resolution.body = if adf {
+            adf_document(&segments)
+        } else {
+            serde_json::Value::String(
+                segments
+                    .iter()
+                    .map(|segment| match segment {
+                        Segment::Text(t) => (*t).to_string(),
+                        Segment::Mention(c) => c.wiki_markup(),
+                    })
+                    .collect(),
+            )
+        };
+        resolution
+    }
+
+    /// Summary of unresolved and ambiguous mentions for a tool response
+    #[must_use]
+    pub fn report(&self) -> Option<String> {
+        let mut lines = Vec::new();
+        if !self.resolved.is_empty() {
+            lines.push(format!(
+                "Mentioned: {}",
+                self.resolved
+                    .iter()
+                    .map(|(_, name)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        if !self.unresolved.is_empty() {
+            lines.push(format!(
+                "Unresolved mentions (left as text): {}",
+                self.unresolved.join(", ")
+            ));
+        }
+        for (mention, candidates) in &self.ambiguous {
+            lines.push(format!(
+                "Ambiguous mention {mention} (left as text), matches: {}",
+                candidates.join("; ")
+            ));
+        }
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+}
+
+enum Segment<'a> {
+    Text(&'a str),
+    Mention(&'a MentionCandidate),
+}
+
+fn adf_document(segments: &[Segment]) -> serde_json::Value {
+    let mut content = Vec::new();
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => {
+                for (i, line) in text.split('\n').enumerate() {
+                    if i > 0 {
+                        content.push(json!({"type": "hardBreak"}));
+                    }
+                    if !line.is_empty() {
+                        content.push(json!({"type": "text", "text": line}));
+                    }
+                }
+            }
+            Segment::Mention(candidate) => content.push(candidate.adf_node()),
+        }
+    }
+
+    json!({
+        "type": "doc",
+        "version": 1,
+        "content": [{"type": "paragraph", "content": content}]
+    })
+}
@@ -1,3 +1,13 @@
+pub mod anonymize;
+pub mod datetime;
+pub mod duration;
+pub mod markdown;
+pub mod mentions;
+pub mod paths;
+pub mod pattern;
 pub mod response;
+pub mod snippets;
+pub mod time;
 
+pub use markdown::{markdown_to_adf, markdown_to_jira};
 pub use response::*;
@@ -0,0 +1,279 @@
+//! Keeping files tools write, upload or read inside the directories the
+//! operator allowed.
+//!
+//! The allowlist comes from `JIRA_FILE_ALLOWLIST`. Paths are checked after
+//! resolving symlinks and `..` components, so a path can't escape an allowed
+//! directory by pointing through a link.
+
+use crate::error::{JiraError, Result};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Resolve `path` as a file to write, requiring it to sit inside one of
+/// `allowed_dirs`.
+///
+/// The parent directory must already exist. An existing symlink at `path` is
+/// rejected rather than followed.
+///
+/// # Errors
+///
+/// Returns a validation error for `field` if writing files is disabled, the
+/// path is relative or has no file name, its directory doesn't exist, or it
+/// falls outside every allowed directory.
+pub fn resolve_output_path(field: &str, path: &str, allowed_dirs: &[PathBuf]) -> Result<PathBuf> {
+    let invalid = |message: String| JiraError::validation_error(field, &message);
+    let requested = absolute_path(field, path, allowed_dirs)?;
+    let file_name = requested
+        .file_name()
+        .ok_or_else(|| invalid(format!("'{path}' does not name a file")))?;
+    let parent = requested
+        .parent()
+        .and_then(|dir| dir.canonicalize().ok())
+        .ok_or_else(|| invalid(format!("The directory of '{path}' does not exist")))?;
+
+    check_allowed(field, path, &parent, allowed_dirs)?;
+
+    let target = parent.join(file_name);
+    if target
+        .symlink_metadata()
+        .is_ok_and(|meta| meta.file_type().is_symlink())
+    {
+        return Err(invalid(format!("'{path}' is a symlink")));
+    }
+
+    Ok(target)
+}
+
+/// Resolve `path` as an existing directory to write files into or read files
+/// from, requiring it to sit inside one of `allowed_dirs`.
+///
+/// # Errors
+///
+/// Returns a validation error for `field` if file access is disabled, the
+/// path is relative, is not an existing directory, or falls outside every
+/// allowed directory.
+pub fn resolve_allowed_dir(field: &str, path: &str, allowed_dirs: &[PathBuf]) -> Result<PathBuf> {
+    let requested = absolute_path(field, path, allowed_dirs)?;
+    let dir = requested
+        .canonicalize()
+        .ok()
+        .filter(|dir| dir.is_dir())
+        .ok_or_else(|| {
+            JiraError::validation_error(field, &format!("'{path}' is not an existing directory"))
+        })?;
+    check_allowed(field, path, &dir, allowed_dirs)?;
+    Ok(dir)
+}
+
+/// Resolve `path` as an existing file to read, requiring it to sit inside one
+/// of `allowed_dirs`. Symlinks are resolved before the check.
+///
+/// # Errors
+///
+/// Returns a validation error for `field` if file access is disabled, the
+/// path is relative, is not an existing file, or falls outside every allowed
+/// directory.
+pub fn resolve_allowed_file(field: &str, path: &str, allowed_dirs: &[PathBuf]) -> Result<PathBuf> {
+    let requested = absolute_path(field, path, allowed_dirs)?;
+    let file = requested
+        .canonicalize()
+        .ok()
+        .filter(|file| file.is_file())
+        .ok_or_else(|| {
+            JiraError::validation_error(field, &format!("'{path}' is not an existing file"))
+        })?;
+    check_allowed(field, path, &file, allowed_dirs)?;
+    Ok(file)
+}
+
+/// A file name safe to create inside a directory, made from a name chosen by
+/// someone else (e.g. an attachment's): path separators, control characters
+/// and leading dots are dropped, and an empty result becomes `file`.
+#[must_use]
+pub fn sanitize_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '/' | '\\' | ':') && !c.is_control())
+        .collect();
+    let cleaned = cleaned.trim().trim_start_matches('.').trim();
+    if cleaned.is_empty() {
+        "file".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+/// `name`, or `name` with a numeric suffix before its extension
+/// (`report-1.pdf`, `report-2.pdf`, ...), whichever is neither in `taken`
+/// nor already present in `dir`. The chosen name is added to `taken`.
+pub fn unique_file_name(dir: &Path, name: &str, taken: &mut HashSet<String>) -> String {
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, Some(extension)),
+        _ => (name, None),
+    };
+    let mut candidate = name.to_string();
+    let mut suffix = 0;
+    while taken.contains(&candidate) || dir.join(&candidate).symlink_metadata().is_ok() {
+        suffix += 1;
+        candidate = match extension {
+            Some(extension) => format!("{stem}-{suffix}.{extension}"),
+            None => format!("{stem}-{suffix}"),
+        };
+    }
+    taken.insert(candidate.clone());
+    candidate
+}
+
+/// Files of a directory, as listed by [`list_files`]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DirectoryListing {
+    /// Regular files, in path order
+    pub files: Vec<PathBuf>,
+    /// Entries left out, with the reason
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+/// List the files of `dir` whose names match the glob `pattern` (every file
+/// when `None`), descending into subdirectories when `recursive` is set.
+///
+/// Symlinks are never followed out of `allowed_dirs`: a link to a file is
+/// listed only when its target is inside an allowed directory, and links to
+/// directories are not descended into.
+///
+/// # Errors
+///
+/// Returns an error if `dir` or one of its subdirectories cannot be read.
+pub fn list_files(
+    dir: &Path,
+    pattern: Option<&str>,
+    recursive: bool,
+    allowed_dirs: &[PathBuf],
+) -> Result<DirectoryListing> {
+    let allowed: Vec<PathBuf> = allowed_dirs
+        .iter()
+        .filter_map(|dir| dir.canonicalize().ok())
+        .collect();
+    let mut listing = DirectoryListing::default();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut entries = std::fs::read_dir(&dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        entries.sort();
+        for path in entries {
+            let file_type = path.symlink_metadata()?.file_type();
+            if file_type.is_dir() {
+                if recursive {
+                    pending.push(path);
+                }
+                continue;
+            }
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            if pattern.is_some_and(|pattern| !glob_matches(pattern, &name)) {
+                continue;
+            }
+            if file_type.is_symlink() {
+                match path.canonicalize() {
+                    Ok(target) if !allowed.iter().any(|dir| target.starts_with(dir)) => {
+                        listing.skipped.push((
+                            path,
+                            "symlink to a path outside the allowed directories".to_string(),
+                        ));
+                        continue;
+                    }
+                    Ok(target) if target.is_dir() => {
+                        listing
+                            .skipped
+                            .push((path, "symlink to a directory".to_string()));
+                        continue;
+                    }
+                    Ok(_) => {}
+                    Err(_) => {
+                        listing.skipped.push((path, "broken symlink".to_string()));
+                        continue;
+                    }
+                }
+            } else if !file_type.is_file() {
+                continue;
+            }
+            listing.files.push(path);
+        }
+    }
+    listing.files.sort();
+    Ok(listing)
+}
+
+/// Whether `name` matches the glob `pattern`, where `*` matches any run of
+/// characters and `?` any single character
+#[must_use]
+pub fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // Position of the last `*` and of the name when it was reached, to
+    // backtrack to when the rest fails to match
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// `path` as an absolute path, once file access is known to be enabled
+fn absolute_path<'a>(field: &str, path: &'a str, allowed_dirs: &[PathBuf]) -> Result<&'a Path> {
+    if allowed_dirs.is_empty() {
+        return Err(JiraError::validation_error(
+            field,
+            "File access is disabled. Set JIRA_FILE_ALLOWLIST to a comma-separated list \
+             of directories to allow it",
+        ));
+    }
+    let requested = Path::new(path);
+    if !requested.is_absolute() {
+        return Err(JiraError::validation_error(
+            field,
+            &format!("'{path}' must be an absolute path"),
+        ));
+    }
+    Ok(requested)
+}
+
+/// Reject the canonical directory `dir` (given by the caller as `path`)
+/// unless it is inside one of `allowed_dirs`
+fn check_allowed(field: &str, path: &str, dir: &Path, allowed_dirs: &[PathBuf]) -> Result<()> {
+    let allowed: Vec<PathBuf> = allowed_dirs
+        .iter()
+        .filter_map(|dir| dir.canonicalize().ok())
+        .collect();
+    if allowed.iter().any(|allowed| dir.starts_with(allowed)) {
+        return Ok(());
+    }
+    Err(JiraError::validation_error(
+        field,
+        &format!(
+            "'{path}' is outside the allowed directories: {}",
+            allowed_dirs
+                .iter()
+                .map(|dir| dir.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    ))
+}
@@ -0,0 +1,346 @@
+//! A small regular expression matcher for patterns in the configuration,
+//! such as the text a write policy requires.
+//!
+//! It supports literals, `.`, character classes (`[a-z]`, `[^0-9]`), the
+//! escapes `\d`, `\w` and `\s` and their negations, groups with `|`, the
+//! anchors `^` and `$`, and the quantifiers `*`, `+`, `?` and `{n,m}`. A
+//! pattern matches text that contains a match anywhere, unless anchored.
+
+/// A range of characters, both ends included
+type CharRange = (char, char);
+
+const DIGIT: [CharRange; 1] = [('0', '9')];
+const WORD: [CharRange; 4] = [('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_')];
+const SPACE: [CharRange; 4] = [
+    (' ', ' '),
+    ('\t', '\r'),
+    ('\u{a0}', '\u{a0}'),
+    ('\u{2028}', '\u{2029}'),
+];
+
+#[derive(Debug, Clone)]
+enum Atom {
+    Char(char),
+    Any,
+    Class {
+        ranges: Vec<CharRange>,
+        negated: bool,
+    },
+    Group(Vec<Vec<Piece>>),
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone)]
+struct Piece {
+    atom: Atom,
+    min: usize,
+    max: Option<usize>,
+}
+
+/// A compiled pattern
+#[derive(Debug, Clone)]
+pub struct TextPattern {
+    branches: Vec<Vec<Piece>>,
+}
+
+impl TextPattern {
+    /// Compile `pattern`
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem if `pattern` is not a valid
+    /// pattern of the supported syntax
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            pos: 0,
+        };
+        let branches = parser.alternation()?;
+        match parser.peek() {
+            None => Ok(Self { branches }),
+            Some(c) => Err(format!("unexpected '{c}' at {}", parser.pos)),
+        }
+    }
+
+    /// Whether `text` contains a match
+    #[must_use]
+    pub fn is_match(&self, text: &str) -> bool {
+        let text: Vec<char> = text.chars().collect();
+        (0..=text.len()).any(|start| match_alternation(&self.branches, &text, start, &mut |_| true))
+    }
+
+    /// Replace every match in `text` with `replacement`, in which `$0` stands
+    /// for the matched text. Matches are taken left to right without
+    /// overlapping, each as long as the greedy quantifiers make it.
+    #[must_use]
+    pub fn replace_all(&self, text: &str, replacement: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut result = String::with_capacity(text.len());
+        // Start of the text not copied to `result` yet
+        let mut copied = 0;
+        let mut pos = 0;
+        while pos <= chars.len() {
+            let mut end = None;
+            if !match_alternation(&self.branches, &chars, pos, &mut |at| {
+                end = Some(at);
+                true
+            }) {
+                pos += 1;
+                continue;
+            }
+            let end = end.unwrap_or(pos);
+            let matched: String = chars[pos..end].iter().collect();
+            result.extend(&chars[copied..pos]);
+            result.push_str(&replacement.replace("$0", &matched));
+            // Step over an empty match so the next search moves on
+            if end == pos {
+                result.extend(chars.get(pos));
+                pos += 1;
+            } else {
+                pos = end;
+            }
+            copied = pos.min(chars.len());
+        }
+        result.extend(&chars[copied..]);
+        result
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += usize::from(c.is_some());
+        c
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        self.pos += usize::from(found);
+        found
+    }
+
+    fn alternation(&mut self) -> Result<Vec<Vec<Piece>>, String> {
+        let mut branches = vec![self.sequence()?];
+        while self.eat('|') {
+            branches.push(self.sequence()?);
+        }
+        Ok(branches)
+    }
+
+    fn sequence(&mut self) -> Result<Vec<Piece>, String> {
+        let mut pieces = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            let (min, max) = self.quantifier()?;
+            pieces.push(Piece { atom, min, max });
+        }
+        Ok(pieces)
+    }
+
+    fn atom(&mut self) -> Result<Atom, String> {
+        let at = self.pos;
+        match self.next() {
+            Some('(') => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err(format!("unsupported group syntax at {at}"));
+                }
+                let branches = self.alternation()?;
+                if !self.eat(')') {
+                    return Err(format!("unclosed group opened at {at}"));
+                }
+                Ok(Atom::Group(branches))
+            }
+            Some('[') => self.class(at),
+            Some('.') => Ok(Atom::Any),
+            Some('^') => Ok(Atom::Start),
+            Some('$') => Ok(Atom::End),
+            Some('\\') => self.escape(),
+            Some(c @ ('*' | '+' | '?' | '{')) => {
+                Err(format!("nothing to repeat before '{c}' at {at}"))
+            }
+            Some(c) => Ok(Atom::Char(c)),
+            None => Err("pattern ends unexpectedly".to_string()),
+        }
+    }
+
+    fn escape(&mut self) -> Result<Atom, String> {
+        let class = |ranges: &[CharRange], negated| Atom::Class {
+            ranges: ranges.to_vec(),
+            negated,
+        };
+        match self.next() {
+            Some('d') => Ok(class(&DIGIT, false)),
+            Some('D') => Ok(class(&DIGIT, true)),
+            Some('w') => Ok(class(&WORD, false)),
+            Some('W') => Ok(class(&WORD, true)),
+            Some('s') => Ok(class(&SPACE, false)),
+            Some('S') => Ok(class(&SPACE, true)),
+            Some('n') => Ok(Atom::Char('\n')),
+            Some('t') => Ok(Atom::Char('\t')),
+            Some(c) if !c.is_alphanumeric() => Ok(Atom::Char(c)),
+            Some(c) => Err(format!("unsupported escape '\\{c}'")),
+            None => Err("pattern ends with '\\'".to_string()),
+        }
+    }
+
+    fn class(&mut self, at: usize) -> Result<Atom, String> {
+        let negated = self.eat('^');
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let c = self
+                .next()
+                .ok_or_else(|| format!("unclosed class opened at {at}"))?;
+            if c == ']' && !first {
+                break;
+            }
+            first = false;
+            let low = if c == '\\' {
+                match self.next() {
+                    Some('d') => {
+                        ranges.extend(DIGIT);
+                        continue;
+                    }
+                    Some('w') => {
+                        ranges.extend(WORD);
+                        continue;
+                    }
+                    Some('s') => {
+                        ranges.extend(SPACE);
+                        continue;
+                    }
+                    Some(c) if !c.is_alphanumeric() => c,
+                    Some(c) => return Err(format!("unsupported escape '\\{c}' in class")),
+                    None => return Err(format!("unclosed class opened at {at}")),
+                }
+            } else {
+                c
+            };
+            if self.peek() == Some('-') && self.chars.get(self.pos + 1).is_some_and(|&c| c != ']') {
+                self.pos += 1;
+                let high = self.next().unwrap_or(low);
+                if high < low {
+                    return Err(format!("invalid range {low}-{high}"));
+                }
+                ranges.push((low, high));
+            } else {
+                ranges.push((low, low));
+            }
+        }
+        Ok(Atom::Class { ranges, negated })
+    }
+
+    fn quantifier(&mut self) -> Result<(usize, Option<usize>), String> {
+        let bounds = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                let at = self.pos;
+                self.pos += 1;
+                let min = self
+                    .number()
+                    .ok_or_else(|| format!("invalid repeat at {at}"))?;
+                let max = if self.eat(',') {
+                    self.number()
+                } else {
+                    Some(min)
+                };
+                if !self.eat('}') || max.is_some_and(|max| max < min) {
+                    return Err(format!("invalid repeat at {at}"));
+                }
+                // Lazy repeats match the same texts
+                self.eat('?');
+                return Ok((min, max));
+            }
+            _ => return Ok((1, Some(1))),
+        };
+        self.pos += 1;
+        self.eat('?');
+        Ok(bounds)
+    }
+
+    fn number(&mut self) -> Option<usize> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+}
+
+fn match_alternation(
+    branches: &[Vec<Piece>],
+    text: &[char],
+    pos: usize,
+    then: &mut dyn FnMut(usize) -> bool,
+) -> bool {
+    branches
+        .iter()
+        .any(|branch| match_sequence(branch, text, pos, then))
+}
+
+fn match_sequence(
+    pieces: &[Piece],
+    text: &[char],
+    pos: usize,
+    then: &mut dyn FnMut(usize) -> bool,
+) -> bool {
+    match pieces.split_first() {
+        None => then(pos),
+        Some((piece, rest)) => match_repeat(piece, 0, text, pos, &mut |next| {
+            match_sequence(rest, text, next, then)
+        }),
+    }
+}
+
+/// Match `piece` having matched it `count` times, as often as possible
+fn match_repeat(
+    piece: &Piece,
+    count: usize,
+    text: &[char],
+    pos: usize,
+    then: &mut dyn FnMut(usize) -> bool,
+) -> bool {
+    if piece.max.is_none_or(|max| count < max)
+        && match_atom(&piece.atom, text, pos, &mut |next| {
+            // A repeat that matched nothing would repeat forever
+            (next != pos || count < piece.min) && match_repeat(piece, count + 1, text, next, then)
+        })
+    {
+        return true;
+    }
+    count >= piece.min && then(pos)
+}
+
+fn match_atom(atom: &Atom, text: &[char], pos: usize, then: &mut dyn FnMut(usize) -> bool) -> bool {
+    match atom {
+        Atom::Char(c) => text.get(pos) == Some(c) && then(pos + 1),
+        Atom::Any => text.get(pos).is_some_and(|&c| c != '\n') && then(pos + 1),
+        Atom::Class { ranges, negated } => {
+            text.get(pos).is_some_and(|&c| {
+                ranges.iter().any(|&(low, high)| (low..=high).contains(&c)) != *negated
+            }) && then(pos + 1)
+        }
+        Atom::Group(branches) => match_alternation(branches, text, pos, then),
+        Atom::Start => pos == 0 && then(pos),
+        Atom::End => pos == text.len() && then(pos),
+    }
+}
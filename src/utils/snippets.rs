@@ -0,0 +1,122 @@
+//! Locating a search string in free text and cutting excerpts around it.
+//!
+//! Matching is case-insensitive and works on characters rather than bytes, so
+//! excerpts never split a UTF-8 character. Whitespace inside an excerpt is
+//! collapsed so multi-line descriptions read as one line.
+
+/// Characters of context kept on each side of a match
+pub const SNIPPET_CONTEXT_CHARS: usize = 120;
+
+/// Marker placed around the matched text in an excerpt
+pub const HIGHLIGHT_MARKER: &str = "**";
+
+/// A match of the query in a text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    /// Character offset of the match in the original text
+    pub offset: usize,
+    /// Excerpt around the match, with the match highlighted
+    pub excerpt: String,
+}
+
+/// Character offsets of the non-overlapping, case-insensitive occurrences of
+/// `query` in `text`
+#[must_use]
+pub fn find_matches(text: &str, query: &str) -> Vec<usize> {
+    let fold = |s: &str| -> Vec<char> {
+        s.chars()
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect()
+    };
+    let haystack = fold(text);
+    let needle = fold(query.trim());
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return Vec::new();
+    }
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + needle.len() <= haystack.len() {
+        if haystack[i..i + needle.len()] == needle[..] {
+            matches.push(i);
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    matches
+}
+
+/// Excerpts of `text` around up to `max_snippets` occurrences of `query`,
+/// each with `context` characters on either side.
+///
+/// Occurrences that fall inside an earlier excerpt are skipped. If the whole
+/// query does not occur (Jira's text search matches words and stems, not
+/// exact phrases), the individual words of the query are tried instead.
+#[must_use]
+pub fn find_snippets(text: &str, query: &str, context: usize, max_snippets: usize) -> Vec<Snippet> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut terms: Vec<(usize, usize)> = find_matches(text, query)
+        .into_iter()
+        .map(|offset| (offset, query.trim().chars().count()))
+        .collect();
+    if terms.is_empty() {
+        for word in query.split_whitespace().filter(|w| w.chars().count() > 1) {
+            let len = word.chars().count();
+            terms.extend(find_matches(text, word).into_iter().map(|o| (o, len)));
+        }
+        terms.sort_unstable();
+    }
+
+    let mut snippets = Vec::new();
+    let mut covered_until = 0;
+    for (offset, len) in terms {
+        if snippets.len() >= max_snippets {
+            break;
+        }
+        if !snippets.is_empty() && offset < covered_until {
+            continue;
+        }
+        let start = offset.saturating_sub(context);
+        let end = (offset + len + context).min(chars.len());
+        covered_until = end;
+        snippets.push(Snippet {
+            offset,
+            excerpt: excerpt(&chars, start, offset, offset + len, end),
+        });
+    }
+    snippets
+}
+
+fn excerpt(
+    chars: &[char],
+    start: usize,
+    match_start: usize,
+    match_end: usize,
+    end: usize,
+) -> String {
+    let collapse = |range: &[char]| -> String {
+        let text: String = range.iter().collect();
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let mut collapsed = words.join(" ");
+        // Keep a boundary space so the match doesn't fuse with its context
+        if range.first().is_some_and(|c| c.is_whitespace()) && !collapsed.is_empty() {
+            collapsed.insert(0, ' ');
+        }
+        if range.last().is_some_and(|c| c.is_whitespace()) && !collapsed.is_empty() {
+            collapsed.push(' ');
+        }
+        collapsed
+    };
+
+    let before = collapse(&chars[start..match_start]);
+    let matched: String = chars[match_start..match_end].iter().collect();
+    let after = collapse(&chars[match_end..end]);
+    format!(
+        "{}{}{HIGHLIGHT_MARKER}{matched}{HIGHLIGHT_MARKER}{}{}",
+        if start > 0 { "…" } else { "" },
+        before.trim_start(),
+        after.trim_end(),
+        if end < chars.len() { "…" } else { "" },
+    )
+}
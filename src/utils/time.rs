@@ -0,0 +1,62 @@
+//! Reading the dates and timestamps tools take as arguments, and describing
+//! times for output read by people: instants relative to now, e.g. "2 days
+//! ago", and time spent, e.g. "3h 30m".
+
+use crate::error::{JiraError, Result};
+use crate::utils::datetime::parse_timestamp;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+
+/// Read the argument `field` given as a `YYYY-MM-DD` date (midnight UTC) or
+/// an ISO timestamp
+///
+/// # Errors
+///
+/// Returns a validation error if `value` is neither.
+pub fn parse_date_or_timestamp(field: &str, value: &str) -> Result<DateTime<Utc>> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|start| start.and_utc())
+        .or_else(|| parse_timestamp(value).map(|time| time.with_timezone(&Utc)))
+        .ok_or_else(|| {
+            JiraError::validation_error(
+                field,
+                &format!("'{value}' is not a YYYY-MM-DD date or an ISO timestamp"),
+            )
+        })
+}
+
+/// Seconds of logged work in hours and minutes, e.g. "26h 5m", "45m", "0m"
+#[must_use]
+pub fn format_time_spent(seconds: u64) -> String {
+    let hours = seconds / 3_600;
+    let minutes = seconds % 3_600 / 60;
+    match (hours, minutes) {
+        (0, minutes) => format!("{minutes}m"),
+        (hours, 0) => format!("{hours}h"),
+        (hours, minutes) => format!("{hours}h {minutes}m"),
+    }
+}
+
+/// How long before `now` the instant `then` was, in the largest whole unit:
+/// "just now", "5 minutes ago", "1 day ago", "3 months ago". Instants after
+/// `now` read "in 2 hours".
+#[must_use]
+pub fn relative_time<Tz: TimeZone>(then: &DateTime<Tz>, now: DateTime<Utc>) -> String {
+    let seconds = now.signed_duration_since(then).num_seconds();
+    let elapsed = seconds.unsigned_abs();
+    let (count, unit) = match elapsed {
+        0..=59 => return "just now".to_string(),
+        60..=3_599 => (elapsed / 60, "minute"),
+        3_600..=86_399 => (elapsed / 3_600, "hour"),
+        86_400..=2_591_999 => (elapsed / 86_400, "day"),
+        2_592_000..=31_535_999 => (elapsed / 2_592_000, "month"),
+        _ => (elapsed / 31_536_000, "year"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    if seconds < 0 {
+        format!("in {count} {unit}{plural}")
+    } else {
+        format!("{count} {unit}{plural} ago")
+    }
+}
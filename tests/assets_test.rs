@@ -0,0 +1,288 @@
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::assets::{is_assets_field, AssetObject};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{GetAssetObjectTool, GetIssueTool, SearchAssetObjectsTool};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        assets_enabled: Some(true),
+        ..Default::default()
+    }
+}
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!("tests/fixtures/{name}")).unwrap()
+}
+
+async fn iql_page_mock(server: &mut mockito::Server, page: &str, hits: usize) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/insight/1.0/iql/objects")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("iql".into(), "objectType = Laptop".into()),
+            Matcher::UrlEncoded("objectSchemaId".into(), "3".into()),
+            Matcher::UrlEncoded("page".into(), page.into()),
+            Matcher::UrlEncoded("resultPerPage".into(), "50".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture(&format!("insight_objects_page{page}.json")))
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_iql_search_follows_pages() {
+    let mut server = mockito::Server::new_async().await;
+    let first = iql_page_mock(&mut server, "1", 1).await;
+    let second = iql_page_mock(&mut server, "2", 1).await;
+
+    let result = SearchAssetObjectsTool::new(create_test_config(&server.url()))
+        .handle(json!({"iql": "objectType = Laptop", "schema_id": 3}))
+        .await
+        .unwrap();
+    first.assert_async().await;
+    second.assert_async().await;
+
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with("Found 5 Assets objects for objectType = Laptop, showing 5:\n"),
+        "{text}"
+    );
+    assert!(
+        text.contains("\n• HW-101 — MacBook Pro 14 #101 (Laptop)\n"),
+        "{text}"
+    );
+    assert!(
+        text.ends_with("\n• HW-105 — Dell Latitude #105 (Laptop)"),
+        "{text}"
+    );
+    let search: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(search["objects"].as_array().unwrap().len(), 5);
+    assert_eq!(search["truncated"], false);
+}
+
+#[tokio::test]
+async fn test_iql_search_stops_at_max_results() {
+    let mut server = mockito::Server::new_async().await;
+    let first = iql_page_mock(&mut server, "1", 1).await;
+    let second = iql_page_mock(&mut server, "2", 0).await;
+
+    let result = SearchAssetObjectsTool::new(create_test_config(&server.url()))
+        .handle(json!({"iql": "objectType = Laptop", "schema_id": "3", "max_results": 2}))
+        .await
+        .unwrap();
+    first.assert_async().await;
+    second.assert_async().await;
+
+    let text = &result.content[0].text;
+    assert!(text.contains("showing 2:"), "{text}");
+    assert!(!text.contains("HW-103"), "{text}");
+    assert!(
+        text.ends_with("\nMore objects match; raise max_results (now 2) or narrow the query"),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_cloud_aql_search_follows_start_at() {
+    let mut server = mockito::Server::new_async().await;
+    let config = JiraConfig {
+        assets_api_url: Some(format!("{}/jsm/assets/workspace/ws-1/v1", server.url())),
+        ..create_test_config(&server.url())
+    };
+    let mut pages = Vec::new();
+    for (start_at, page) in [("0", "1"), ("2", "2")] {
+        pages.push(
+            server
+                .mock("POST", "/jsm/assets/workspace/ws-1/v1/object/aql")
+                .match_query(Matcher::AllOf(vec![
+                    Matcher::UrlEncoded("startAt".into(), start_at.into()),
+                    Matcher::UrlEncoded("maxResults".into(), "50".into()),
+                ]))
+                .match_body(Matcher::Json(
+                    json!({"qlQuery": "objectSchemaId = 7 AND (objectType = Server)"}),
+                ))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(fixture(&format!("assets_aql_page{page}.json")))
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let result = SearchAssetObjectsTool::new(config)
+        .handle(json!({"iql": "objectType = Server", "schema_id": "7"}))
+        .await
+        .unwrap();
+    for page in pages {
+        page.assert_async().await;
+    }
+    assert_eq!(
+        result.content[0].text,
+        "Found 3 Assets objects for objectType = Server, showing 3:\n\
+         • CMDB-88 — db-primary-01 (Server)\n\
+         • CMDB-89 — db-replica-01 (Server)\n\
+         • CMDB-90 — db-replica-02 (Server)"
+    );
+}
+
+#[tokio::test]
+async fn test_get_asset_object_shows_attributes() {
+    let mut server = mockito::Server::new_async().await;
+    let page: Value = serde_json::from_str(&fixture("insight_objects_page1.json")).unwrap();
+    let object = server
+        .mock("GET", "/rest/insight/1.0/object/HW-101")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(page["objectEntries"][0].to_string())
+        .create_async()
+        .await;
+
+    let result = GetAssetObjectTool::new(create_test_config(&server.url()))
+        .handle(json!({"object_key": "HW-101"}))
+        .await
+        .unwrap();
+    object.assert_async().await;
+    assert_eq!(
+        result.content[0].text,
+        "Assets object HW-101: MacBook Pro 14 #101\nType: Laptop\nId: 101\n\
+         Attributes:\n• Name: MacBook Pro 14 #101\n• Serial number: C02XK1"
+    );
+}
+
+#[test]
+fn test_asset_objects_and_fields_are_recognized() {
+    let page: Value = serde_json::from_str(&fixture("insight_objects_page2.json")).unwrap();
+    let object = AssetObject::from_json(&page["objectEntries"][1]).unwrap();
+    assert_eq!(object.display(), "Dell Latitude #105 (HW-105)");
+    // The serial number has no value
+    assert_eq!(object.attributes.len(), 1);
+
+    let fields: Vec<Value> = serde_json::from_str(&fixture("fields_assets.json")).unwrap();
+    let assets: Vec<&str> = fields
+        .iter()
+        .filter(|field| is_assets_field(field))
+        .map(|field| field["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(assets, ["customfield_10300", "customfield_10301"]);
+}
+
+/// An issue with a Data Center Insight field holding a key, a Cloud Assets
+/// field holding an object reference and a plain text field
+fn hardware_issue(key: &str) -> String {
+    json!({
+        "id": "20001",
+        "key": key,
+        "self": "x",
+        "fields": {
+            "summary": "Replace laptop battery",
+            "status": {"name": "Open"},
+            "customfield_10300": ["HW-200"],
+            "customfield_10301": [{
+                "workspaceId": "a1b2c3d4-0000-4e5f-9a8b-000000000001",
+                "id": "a1b2c3d4-0000-4e5f-9a8b-000000000001:95",
+                "objectId": "95"
+            }],
+            "customfield_10302": "TAG-77"
+        }
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_issue_details_name_assets_objects_with_cached_lookups() {
+    let mut server = mockito::Server::new_async().await;
+    let issue = server
+        .mock("GET", "/rest/api/2/issue/IT-7")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(hardware_issue("IT-7"))
+        .expect(2)
+        .create_async()
+        .await;
+    let fields = server
+        .mock("GET", "/rest/api/2/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("fields_assets.json"))
+        .expect(1)
+        .create_async()
+        .await;
+    let mut objects = Vec::new();
+    for (path, id, key, label) in [
+        ("HW-200", 200, "HW-200", "MacBook Pro 16 #200"),
+        ("95", 95, "CMDB-95", "db-archive-01"),
+    ] {
+        objects.push(
+            server
+                .mock("GET", format!("/rest/insight/1.0/object/{path}").as_str())
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    json!({"id": id, "objectKey": key, "label": label, "objectType": {"name": "Laptop"}})
+                        .to_string(),
+                )
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let tool = GetIssueTool::new(create_test_config(&server.url()));
+    for _ in 0..2 {
+        let result = tool.handle(json!({"issue_key": "IT-7"})).await.unwrap();
+        let text = &result.content[0].text;
+        assert!(
+            text.contains(
+                "\nHardware: MacBook Pro 16 #200 (HW-200)\nAffected servers: db-archive-01 (CMDB-95)\nURL: "
+            ),
+            "{text}"
+        );
+        assert!(!text.contains("TAG-77"), "{text}");
+    }
+
+    issue.assert_async().await;
+    fields.assert_async().await;
+    for object in objects {
+        object.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_issue_details_leave_assets_alone_when_disabled() {
+    let mut server = mockito::Server::new_async().await;
+    let _issue = server
+        .mock("GET", "/rest/api/2/issue/IT-8")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(hardware_issue("IT-8"))
+        .create_async()
+        .await;
+    let insight = server
+        .mock("GET", Matcher::Regex("^/rest/insight/".to_string()))
+        .expect(0)
+        .create_async()
+        .await;
+    let config = JiraConfig {
+        assets_enabled: Some(false),
+        ..create_test_config(&server.url())
+    };
+
+    let result = GetIssueTool::new(config)
+        .handle(json!({"issue_key": "IT-8"}))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(!text.contains("Hardware"), "{text}");
+    insight.assert_async().await;
+}
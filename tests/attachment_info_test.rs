@@ -0,0 +1,150 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{DownloadAttachmentTool, GetAttachmentInfoTool};
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        max_attachment_download_bytes: Some(1000),
+        ..Default::default()
+    }
+}
+
+fn attachment(base_url: &str, size: u64, thumbnail: bool) -> serde_json::Value {
+    let mut attachment = json!({
+        "self": format!("{base_url}/rest/api/2/attachment/10100"),
+        "id": "10100",
+        "filename": "screenshot.png",
+        "author": {
+            "self": "https://jira.example.com/rest/api/2/user?username=alice",
+            "name": "alice",
+            "key": "alice",
+            "displayName": "Alice Smith",
+            "active": true
+        },
+        "created": "2024-05-01T10:00:00.000+0000",
+        "size": size,
+        "mimeType": "image/png",
+        "content": format!("{base_url}/secure/attachment/10100/screenshot.png")
+    });
+    if thumbnail {
+        attachment["thumbnail"] = json!(format!(
+            "{base_url}/secure/thumbnail/10100/_thumb_10100.png"
+        ));
+    }
+    attachment
+}
+
+async fn mock_metadata(
+    server: &mut mockito::ServerGuard,
+    body: &serde_json::Value,
+) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/2/attachment/10100")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_get_attachment_metadata() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _metadata = mock_metadata(&mut server, &attachment(&base_url, 2048, true)).await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    let metadata = client.get_attachment_metadata("10100").await.unwrap();
+
+    assert_eq!(metadata.filename, "screenshot.png");
+    assert_eq!(metadata.size, 2048);
+    assert_eq!(metadata.mime_type, "image/png");
+    assert!(metadata.thumbnail.is_some());
+}
+
+#[tokio::test]
+async fn test_attachment_info_tool_with_thumbnail() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _metadata = mock_metadata(&mut server, &attachment(&base_url, 2048, true)).await;
+    let thumbnail = server
+        .mock("GET", "/secure/thumbnail/10100/_thumb_10100.png")
+        .with_status(200)
+        .with_header("content-type", "image/png")
+        .with_body(b"PNGDATA")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = GetAttachmentInfoTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"attachment_id": "10100", "include_thumbnail": true}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Attachment 10100: screenshot.png\nSize: 2048 bytes"));
+    assert!(text.contains("MIME type: image/png"));
+    assert!(text.contains("Author: Alice Smith"));
+    assert!(text.contains("Thumbnail: available"));
+    assert!(text.contains("Thumbnail base64 (7 bytes): UE5HREFUQQ=="));
+    thumbnail.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_attachment_info_tool_without_thumbnail() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _metadata = mock_metadata(&mut server, &attachment(&base_url, 10, false)).await;
+
+    let tool = GetAttachmentInfoTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"attachment_id": "10100", "include_thumbnail": true}))
+        .await
+        .unwrap();
+
+    assert!(result.content[0].text.contains("Thumbnail: none"));
+    assert!(!result.content[0].text.contains("base64"));
+}
+
+#[tokio::test]
+async fn test_download_refuses_large_attachment_without_force() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _metadata = mock_metadata(&mut server, &attachment(&base_url, 5000, false)).await;
+    let content = server
+        .mock("GET", "/secure/attachment/10100/screenshot.png")
+        .with_status(200)
+        .with_body(b"content")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = DownloadAttachmentTool::new(create_test_config(&base_url));
+    let error = tool
+        .handle(json!({"attachment_id": "10100"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("5000 bytes, more than the download limit of 1000 bytes"));
+    assert!(error.contains("force: true"));
+
+    let result = tool
+        .handle(json!({"attachment_id": "10100", "force": true}))
+        .await
+        .unwrap();
+    assert!(result.content[0]
+        .text
+        .starts_with("Attachment 10100 downloaded successfully (7 bytes)"));
+    content.assert_async().await;
+}
@@ -0,0 +1,210 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{
+    GetServerInfoTool, UploadAttachmentTool, UploadDirectoryAttachmentsTool,
+};
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// Each test uses its own `api` path, since the attachment settings are
+/// cached by base URL and mock servers are reused
+fn create_test_config(base_url: &str, api: &str, allowed: &Path) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}{api}"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        file_allowlist: Some(allowed.display().to_string()),
+        ..Default::default()
+    }
+}
+
+async fn mock_attachment_meta(
+    server: &mut mockito::ServerGuard,
+    api: &str,
+    meta: Value,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("{api}/attachment/meta").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(meta.to_string())
+        // Read once, then cached
+        .expect(1)
+        .create_async()
+        .await
+}
+
+fn attachment_json(id: &str, filename: &str) -> Value {
+    json!({
+        "self": format!("https://jira.example.com/rest/api/2/attachment/{id}"),
+        "id": id,
+        "filename": filename,
+        "author": {"self": "x", "name": "me", "key": "me", "displayName": "Me", "active": true},
+        "created": "2024-05-01T10:00:00.000+0000",
+        "size": 1,
+        "mimeType": "text/plain",
+        "content": null
+    })
+}
+
+#[tokio::test]
+async fn test_oversized_upload_fails_before_it_is_sent() {
+    let mut server = mockito::Server::new_async().await;
+    let api = "/single/rest/api/2";
+    let meta = mock_attachment_meta(
+        &mut server,
+        api,
+        json!({"enabled": true, "uploadLimit": 100}),
+    )
+    .await;
+    let upload = server
+        .mock("POST", format!("{api}/issue/LIM-1/attachments").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([attachment_json("300", "small.txt")]).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let allowed = tempfile::tempdir().unwrap();
+    std::fs::write(allowed.path().join("big.bin"), "x".repeat(101)).unwrap();
+    let tool = UploadAttachmentTool::new(create_test_config(&server.url(), api, allowed.path()));
+
+    let error = tool
+        .handle(json!({
+            "issue_key": "LIM-1",
+            "file_path": allowed.path().join("big.bin").to_str().unwrap()
+        }))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains(
+            "big.bin is 101 bytes, more than the attachment size limit of this Jira instance \
+             (100 bytes)"
+        ),
+        "{error}"
+    );
+
+    let result = tool
+        .handle(json!({"issue_key": "LIM-1", "filename": "small.txt", "content": "c21hbGw="}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Successfully uploaded 1 attachment(s) to issue LIM-1"
+    );
+    meta.assert_async().await;
+    upload.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_directory_upload_skips_files_over_the_instance_limit() {
+    let mut server = mockito::Server::new_async().await;
+    let api = "/directory/rest/api/2";
+    let _meta = mock_attachment_meta(
+        &mut server,
+        api,
+        json!({"enabled": true, "uploadLimit": 10}),
+    )
+    .await;
+    let upload = server
+        .mock("POST", format!("{api}/issue/LIM-2/attachments").as_str())
+        .match_body(mockito::Matcher::Regex("filename=\"a.txt\"".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([attachment_json("301", "a.txt")]).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let allowed = tempfile::tempdir().unwrap();
+    std::fs::write(allowed.path().join("a.txt"), "small").unwrap();
+    std::fs::write(allowed.path().join("b.txt"), "x".repeat(50)).unwrap();
+
+    let result =
+        UploadDirectoryAttachmentsTool::new(create_test_config(&server.url(), api, allowed.path()))
+            .handle(json!({"issue_key": "LIM-2", "directory": allowed.path().to_str().unwrap()}))
+            .await
+            .unwrap();
+    let text = &result.content[0].text;
+    assert!(
+        text.contains("• a.txt: uploaded (5 bytes, attachment 301)"),
+        "{text}"
+    );
+    assert!(
+        text.contains(
+            "• b.txt: skipped (50 bytes is more than the attachment size limit of this Jira \
+             instance (10 bytes))"
+        ),
+        "{text}"
+    );
+    upload.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_disabled_attachments_are_reported_up_front() {
+    let mut server = mockito::Server::new_async().await;
+    let api = "/disabled/rest/api/2";
+    let _meta = mock_attachment_meta(&mut server, api, json!({"enabled": false})).await;
+    let upload = server
+        .mock("POST", format!("{api}/issue/LIM-3/attachments").as_str())
+        .expect(0)
+        .create_async()
+        .await;
+    let allowed = tempfile::tempdir().unwrap();
+
+    let error = UploadAttachmentTool::new(create_test_config(&server.url(), api, allowed.path()))
+        .handle(json!({"issue_key": "LIM-3", "filename": "a.txt", "content": "YQ=="}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("Attachments are disabled on this Jira instance"),
+        "{error}"
+    );
+    upload.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_server_info_includes_the_attachment_limit() {
+    let mut server = mockito::Server::new_async().await;
+    let api = "/info/rest/api/2";
+    let _meta = mock_attachment_meta(
+        &mut server,
+        api,
+        json!({"enabled": true, "uploadLimit": 10_485_760}),
+    )
+    .await;
+    let _info = server
+        .mock("GET", format!("{api}/serverInfo").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "baseUrl": "https://jira.example.com",
+                "version": "9.12.2",
+                "buildNumber": 9_120_002,
+                "deploymentType": "Server",
+                "serverTime": "2024-05-01T10:00:00.000+0000"
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let allowed = tempfile::tempdir().unwrap();
+
+    let result = GetServerInfoTool::new(create_test_config(&server.url(), api, allowed.path()))
+        .handle(json!({}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Jira 9.12.2 (build 9120002, Server)\n\
+         Base URL: https://jira.example.com\n\
+         Server time: 2024-05-01T10:00:00.000+0000\n\
+         Attachments: up to 10485760 bytes per file"
+    );
+    let info: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(info["attachments"]["uploadLimit"], 10_485_760);
+    assert_eq!(info["version"], "9.12.2");
+}
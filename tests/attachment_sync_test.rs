@@ -0,0 +1,248 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::DownloadAllIssueAttachmentsTool;
+use rust_jira_mcp::utils::paths::{sanitize_file_name, unique_file_name};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+
+fn create_test_config(base_url: &str, dir: &std::path::Path) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        file_allowlist: Some(dir.display().to_string()),
+        max_attachment_download_bytes: Some(100),
+        ..Default::default()
+    }
+}
+
+fn attachment(base_url: &str, id: &str, filename: &str, size: u64) -> Value {
+    json!({
+        "self": format!("{base_url}/rest/api/2/attachment/{id}"),
+        "id": id,
+        "filename": filename,
+        "author": {"self": "x", "name": "alice", "key": "alice", "displayName": "Alice", "active": true},
+        "created": "2024-05-01T10:00:00.000+0000",
+        "size": size,
+        "mimeType": "text/plain",
+        "content": format!("{base_url}/secure/attachment/{id}/download")
+    })
+}
+
+async fn mock_issue(
+    server: &mut mockito::ServerGuard,
+    key: &str,
+    attachments: Vec<Value>,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("/rest/api/2/issue/{key}").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10001",
+                "key": key,
+                "self": "x",
+                "fields": {"summary": "Outage", "attachment": attachments}
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await
+}
+
+async fn mock_content(
+    server: &mut mockito::ServerGuard,
+    id: &str,
+    status: usize,
+    body: &str,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("/secure/attachment/{id}/download").as_str())
+        .with_status(status)
+        .with_body(body)
+        .create_async()
+        .await
+}
+
+fn sha256(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+#[test]
+fn test_sanitize_file_name() {
+    assert_eq!(sanitize_file_name("report.pdf"), "report.pdf");
+    assert_eq!(sanitize_file_name("../../etc/passwd"), "etcpasswd");
+    assert_eq!(sanitize_file_name("C:\\logs\\app.log"), "Clogsapp.log");
+    assert_eq!(sanitize_file_name(".bashrc"), "bashrc");
+    assert_eq!(sanitize_file_name("a\nb.txt"), "ab.txt");
+    assert_eq!(sanitize_file_name(".."), "file");
+    assert_eq!(sanitize_file_name(""), "file");
+}
+
+#[test]
+fn test_unique_file_name_adds_numeric_suffixes() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("log.txt"), "already here").unwrap();
+    let mut taken = HashSet::new();
+
+    assert_eq!(
+        unique_file_name(dir.path(), "log.txt", &mut taken),
+        "log-1.txt"
+    );
+    assert_eq!(
+        unique_file_name(dir.path(), "log.txt", &mut taken),
+        "log-2.txt"
+    );
+    assert_eq!(unique_file_name(dir.path(), "README", &mut taken), "README");
+    assert_eq!(
+        unique_file_name(dir.path(), "README", &mut taken),
+        "README-1"
+    );
+    assert_eq!(
+        unique_file_name(dir.path(), "a.tar.gz", &mut taken),
+        "a.tar.gz"
+    );
+    assert_eq!(
+        unique_file_name(dir.path(), "a.tar.gz", &mut taken),
+        "a.tar-1.gz"
+    );
+}
+
+#[tokio::test]
+async fn test_downloads_all_attachments_and_reports_failures() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let dir = tempfile::tempdir().unwrap();
+    let target = dir.path().canonicalize().unwrap();
+
+    let _issue = mock_issue(
+        &mut server,
+        "INC-1",
+        vec![
+            attachment(&base_url, "1", "app.log", 11),
+            attachment(&base_url, "2", "../app.log", 13),
+            attachment(&base_url, "3", "gone.txt", 5),
+            attachment(&base_url, "4", "core.dump", 5000),
+        ],
+    )
+    .await;
+    let _first = mock_content(&mut server, "1", 200, "first log\r\n").await;
+    let _second = mock_content(&mut server, "2", 200, "second log\r\n\r").await;
+    let _gone = mock_content(&mut server, "3", 404, r#"{"errorMessages": ["Gone"]}"#).await;
+    let dump = server
+        .mock("GET", "/secure/attachment/4/download")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let tool = DownloadAllIssueAttachmentsTool::new(create_test_config(&base_url, dir.path()));
+    let result = tool
+        .handle(json!({"issue_key": "INC-1", "target_dir": dir.path().to_str().unwrap()}))
+        .await
+        .unwrap();
+
+    let first_path = target.join("app.log");
+    let second_path = target.join("app-1.log");
+    assert_eq!(
+        std::fs::read_to_string(&first_path).unwrap(),
+        "first log\r\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&second_path).unwrap(),
+        "second log\r\n\r"
+    );
+    assert!(!target.join("gone.txt").exists());
+    assert!(!target.join("core.dump").exists());
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with(&format!(
+        "Downloaded 2 of 4 attachments of INC-1 to {}\n",
+        target.display()
+    )));
+    assert!(text.contains(&format!(
+        "• app.log (11 bytes, sha256 {}) -> {}",
+        sha256("first log\r\n"),
+        first_path.display()
+    )));
+    assert!(text.contains("• gone.txt failed: "));
+    assert!(text.contains("• core.dump failed: 5000 bytes is more than the download limit"));
+    assert_eq!(result.is_error, Some(false));
+
+    let manifest: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(manifest.as_array().unwrap().len(), 4);
+    assert_eq!(
+        manifest[1],
+        json!({
+            "attachment_id": "2",
+            "filename": "../app.log",
+            "local_path": second_path.display().to_string(),
+            "size": 13,
+            "sha256": sha256("second log\r\n\r"),
+            "error": null
+        })
+    );
+    assert_eq!(manifest[2]["local_path"], Value::Null);
+    assert!(manifest[2]["error"].as_str().unwrap().contains("Gone"));
+    dump.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_oversized_download_is_removed() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let dir = tempfile::tempdir().unwrap();
+
+    // The metadata understates the size, so the limit only trips while
+    // streaming
+    let _issue = mock_issue(
+        &mut server,
+        "INC-2",
+        vec![attachment(&base_url, "5", "big.bin", 10)],
+    )
+    .await;
+    let _content = mock_content(&mut server, "5", 200, &"x".repeat(500)).await;
+
+    let tool = DownloadAllIssueAttachmentsTool::new(create_test_config(&base_url, dir.path()));
+    let result = tool
+        .handle(json!({"issue_key": "INC-2", "target_dir": dir.path().to_str().unwrap()}))
+        .await
+        .unwrap();
+
+    assert!(result.content[0]
+        .text
+        .contains("• big.bin failed: Validation error"));
+    assert_eq!(result.is_error, Some(true));
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[tokio::test]
+async fn test_target_dir_must_be_allowed() {
+    let allowed = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    let tool = DownloadAllIssueAttachmentsTool::new(create_test_config(
+        "http://localhost:1",
+        allowed.path(),
+    ));
+
+    let error = tool
+        .handle(json!({"issue_key": "INC-3", "target_dir": outside.path().to_str().unwrap()}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("outside the allowed directories"));
+
+    let missing = allowed.path().join("missing");
+    let error = tool
+        .handle(json!({"issue_key": "INC-3", "target_dir": missing.to_str().unwrap()}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("is not an existing directory"));
+}
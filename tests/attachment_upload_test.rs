@@ -0,0 +1,203 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::UploadDirectoryAttachmentsTool;
+use rust_jira_mcp::utils::paths::{glob_matches, list_files};
+use serde_json::{json, Value};
+use std::path::Path;
+
+fn create_test_config(base_url: &str, allowed: &Path) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        file_allowlist: Some(allowed.display().to_string()),
+        ..Default::default()
+    }
+}
+
+fn attachment_json(id: &str, filename: &str) -> Value {
+    json!({
+        "self": format!("https://jira.example.com/rest/api/2/attachment/{id}"),
+        "id": id,
+        "filename": filename,
+        "author": {"self": "x", "name": "me", "key": "me", "displayName": "Me", "active": true},
+        "created": "2024-05-01T10:00:00.000+0000",
+        "size": 1,
+        "mimeType": "text/plain",
+        "content": null
+    })
+}
+
+#[test]
+fn test_glob_matches() {
+    assert!(glob_matches("*.log", "app.log"));
+    assert!(glob_matches("*.log", ".log"));
+    assert!(!glob_matches("*.log", "app.log.gz"));
+    assert!(glob_matches("app-?.txt", "app-1.txt"));
+    assert!(!glob_matches("app-?.txt", "app-10.txt"));
+    assert!(glob_matches("*error*", "kernel-error-dump"));
+    assert!(glob_matches("._*", "._photo.jpg"));
+    assert!(glob_matches("*", ""));
+    assert!(!glob_matches(".DS_Store", "DS_Store"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_listing_does_not_follow_symlinks_out_of_the_allowlist() {
+    let allowed = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    std::fs::write(outside.path().join("secret.txt"), "secret").unwrap();
+    std::fs::create_dir(outside.path().join("tree")).unwrap();
+
+    let dir = allowed.path().join("upload");
+    std::fs::create_dir_all(dir.join("nested")).unwrap();
+    std::fs::write(dir.join("a.txt"), "a").unwrap();
+    std::fs::write(dir.join("nested").join("b.txt"), "b").unwrap();
+    std::fs::write(allowed.path().join("shared.txt"), "shared").unwrap();
+    std::os::unix::fs::symlink(outside.path().join("secret.txt"), dir.join("leak.txt")).unwrap();
+    std::os::unix::fs::symlink(outside.path().join("tree"), dir.join("escape")).unwrap();
+    std::os::unix::fs::symlink(allowed.path().join("shared.txt"), dir.join("shared.txt")).unwrap();
+
+    let allowed_dirs = [allowed.path().to_path_buf()];
+    let listing = list_files(&dir, None, true, &allowed_dirs).unwrap();
+
+    assert_eq!(
+        listing.files,
+        [
+            dir.join("a.txt"),
+            dir.join("nested").join("b.txt"),
+            dir.join("shared.txt")
+        ]
+    );
+    assert_eq!(listing.skipped.len(), 2);
+    assert_eq!(listing.skipped[0].0, dir.join("escape"));
+    assert_eq!(listing.skipped[1].0, dir.join("leak.txt"));
+    assert!(listing
+        .skipped
+        .iter()
+        .all(|(_, reason)| reason.contains("outside the allowed directories")));
+
+    let flat = list_files(&dir, Some("*.txt"), false, &allowed_dirs).unwrap();
+    assert_eq!(flat.files, [dir.join("a.txt"), dir.join("shared.txt")]);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_upload_directory_reports_each_file() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let allowed = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    let dir = allowed.path().canonicalize().unwrap();
+
+    std::fs::write(dir.join("app.log"), "app log").unwrap();
+    std::fs::write(dir.join("db.log"), "database log").unwrap();
+    std::fs::write(dir.join("core.log"), "x".repeat(500)).unwrap();
+    std::fs::write(dir.join(".DS_Store"), "finder").unwrap();
+    std::fs::write(dir.join("notes.md"), "not a log").unwrap();
+    std::fs::write(outside.path().join("secret.log"), "secret").unwrap();
+    std::os::unix::fs::symlink(outside.path().join("secret.log"), dir.join("secret.log")).unwrap();
+
+    let upload = server
+        .mock("POST", "/rest/api/2/issue/UP-1/attachments")
+        .match_header("X-Atlassian-Token", "no-check")
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex("filename=\"app.log\"".to_string()),
+            mockito::Matcher::Regex("filename=\"db.log\"".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                attachment_json("200", "app.log"),
+                attachment_json("201", "db.log")
+            ])
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = UploadDirectoryAttachmentsTool::new(create_test_config(&base_url, allowed.path()));
+    let result = tool
+        .handle(json!({
+            "issue_key": "UP-1",
+            "directory": allowed.path().to_str().unwrap(),
+            "pattern": "*.log",
+            "max_file_size": 100,
+            "exclude": ["*.md"]
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        format!(
+            "Uploaded 2 of 4 files (19 bytes) from {} to UP-1\n\
+             • app.log: uploaded (7 bytes, attachment 200)\n\
+             • core.log: skipped (500 bytes is more than max_file_size (100 bytes))\n\
+             • db.log: uploaded (12 bytes, attachment 201)\n\
+             • secret.log: skipped (symlink to a path outside the allowed directories)",
+            dir.display()
+        )
+    );
+    let report: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(report["uploaded"], 2);
+    assert_eq!(report["total_bytes"], 19);
+    assert_eq!(report["files"][1]["outcome"], "skipped");
+    assert_eq!(report["files"][2]["attachment_id"], "201");
+    assert_eq!(result.is_error, Some(false));
+    upload.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_excluded_files_and_failed_uploads_do_not_abort() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let allowed = tempfile::tempdir().unwrap();
+    std::fs::write(allowed.path().join(".DS_Store"), "finder").unwrap();
+    std::fs::write(allowed.path().join("trace.txt"), "trace").unwrap();
+
+    let _upload = server
+        .mock("POST", "/rest/api/2/issue/UP-2/attachments")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["No attach permission"]}).to_string())
+        .create_async()
+        .await;
+
+    let tool = UploadDirectoryAttachmentsTool::new(create_test_config(&base_url, allowed.path()));
+    let result = tool
+        .handle(json!({"issue_key": "UP-2", "directory": allowed.path().to_str().unwrap()}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Uploaded 0 of 2 files (0 bytes)"));
+    assert!(text.contains("• .DS_Store: skipped (excluded by '.DS_Store')"));
+    assert!(text.contains("• trace.txt: failed (Permission denied: No attach permission)"));
+    assert_eq!(result.is_error, Some(true));
+}
+
+#[tokio::test]
+async fn test_directory_must_be_allowed() {
+    let allowed = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    let tool = UploadDirectoryAttachmentsTool::new(create_test_config(
+        "http://localhost:1",
+        allowed.path(),
+    ));
+
+    let error = tool
+        .handle(json!({"issue_key": "UP-3", "directory": outside.path().to_str().unwrap()}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("outside the allowed directories"));
+}
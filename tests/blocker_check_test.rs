@@ -0,0 +1,209 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::link_graph::unresolved_blockers;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{bulk_summary_text, BulkTransitionIssuesTool};
+use rust_jira_mcp::types::jira::{BulkOperationResult, BulkOperationSummary, BulkOperationType};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+/// A link from the issue it is read on to `other`, whose status is in
+/// `category`
+fn link(id: &str, type_name: &str, inward: bool, other: &str, category: &str) -> Value {
+    let link_type = match type_name {
+        "Blocks" => json!({"name": "Blocks", "inward": "is blocked by", "outward": "blocks"}),
+        "Depends" => json!({"name": "Depends", "inward": "depends on", "outward": "is needed by"}),
+        _ => json!({"name": type_name, "inward": "relates to", "outward": "relates to"}),
+    };
+    let other = json!({
+        "key": other,
+        "fields": {"status": {"name": category, "statusCategory": {"key": category}}}
+    });
+    if inward {
+        json!({"id": id, "type": link_type, "inwardIssue": other})
+    } else {
+        json!({"id": id, "type": link_type, "outwardIssue": other})
+    }
+}
+
+fn issue_json(key: &str, links: Vec<Value>) -> Value {
+    json!({
+        "id": key,
+        "key": key,
+        "self": "x",
+        "fields": {"summary": key, "status": {"name": "Open"}, "issuelinks": links}
+    })
+}
+
+/// BLK-1 is blocked by an open issue, BLK-2 only by a done one, BLK-3 blocks
+/// an open issue and BLK-4 depends on an open issue
+fn fixture_issues() -> Vec<Value> {
+    vec![
+        issue_json(
+            "BLK-1",
+            vec![
+                link("1", "Blocks", true, "DEP-1", "indeterminate"),
+                link("2", "Blocks", true, "DEP-2", "new"),
+                link("3", "Relates", true, "DEP-3", "new"),
+            ],
+        ),
+        issue_json("BLK-2", vec![link("4", "Blocks", true, "DEP-4", "done")]),
+        issue_json("BLK-3", vec![link("5", "Blocks", false, "DEP-5", "new")]),
+        issue_json("BLK-4", vec![link("6", "Depends", true, "DEP-6", "new")]),
+    ]
+}
+
+async fn mock_search(server: &mut mockito::ServerGuard) -> mockito::Mock {
+    let issues = fixture_issues();
+    server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"startAt": 0, "maxResults": 50, "total": issues.len(), "issues": issues})
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+async fn mock_transition(
+    server: &mut mockito::ServerGuard,
+    key: &str,
+    calls: usize,
+) -> mockito::Mock {
+    server
+        .mock(
+            "POST",
+            format!("/rest/api/2/issue/{key}/transitions").as_str(),
+        )
+        .with_status(204)
+        .with_header("content-type", "application/json")
+        .expect(calls)
+        .create_async()
+        .await
+}
+
+#[test]
+fn test_unresolved_blockers_classification() {
+    let issues = fixture_issues();
+    assert_eq!(
+        unresolved_blockers(&issues[0], "Blocks"),
+        ["DEP-1", "DEP-2"]
+    );
+    assert_eq!(
+        unresolved_blockers(&issues[0], "is blocked by"),
+        ["DEP-1", "DEP-2"]
+    );
+    assert!(unresolved_blockers(&issues[1], "Blocks").is_empty());
+    assert!(unresolved_blockers(&issues[2], "Blocks").is_empty());
+    assert!(unresolved_blockers(&issues[3], "Blocks").is_empty());
+    assert_eq!(unresolved_blockers(&issues[3], "depends"), ["DEP-6"]);
+}
+
+#[tokio::test]
+async fn test_blocked_issues_are_skipped_and_the_rest_transitioned() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let search = mock_search(&mut server).await;
+    let blocked = mock_transition(&mut server, "BLK-1", 0).await;
+    let ready: Vec<_> = [
+        mock_transition(&mut server, "BLK-2", 1).await,
+        mock_transition(&mut server, "BLK-3", 1).await,
+        mock_transition(&mut server, "BLK-4", 1).await,
+    ]
+    .into();
+
+    let tool = BulkTransitionIssuesTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_keys": ["BLK-1", "BLK-2", "BLK-3", "BLK-4"],
+            "transition_id": "31",
+            "check_blockers": true,
+            "config": {"rate_limit_ms": 0, "max_retries": 0}
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        "Bulk transition '31': 3 of 4 operations succeeded, 1 skipped\n\
+         • BLK-1 skipped: Blocked by unresolved DEP-1, DEP-2"
+    );
+    let summary: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(summary["skipped_operations"], 1);
+    assert_eq!(summary["failed_operations"], 0);
+    assert_eq!(summary["results"][3]["issue_key"], "BLK-1");
+    assert_eq!(summary["results"][3]["skipped"], true);
+    assert_eq!(summary["results"][3]["operation_type"], "transition");
+    assert_eq!(result.is_error, Some(false));
+
+    search.assert_async().await;
+    blocked.assert_async().await;
+    for mock in ready {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_blocker_link_type_is_configurable() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _search = mock_search(&mut server).await;
+    let ready = mock_transition(&mut server, "BLK-1", 1).await;
+    let blocked = mock_transition(&mut server, "BLK-4", 0).await;
+
+    let tool = BulkTransitionIssuesTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_keys": ["BLK-1", "BLK-4"],
+            "transition_id": "31",
+            "check_blockers": true,
+            "blocker_link_type": "Depends",
+            "config": {"rate_limit_ms": 0, "max_retries": 0}
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        "Bulk transition '31': 1 of 2 operations succeeded, 1 skipped\n\
+         • BLK-4 skipped: Blocked by unresolved DEP-6"
+    );
+    ready.assert_async().await;
+    blocked.assert_async().await;
+}
+
+#[test]
+fn test_skipped_results_are_not_failures() {
+    let mut summary = BulkOperationSummary::new();
+    summary.add_result(BulkOperationResult::skipped(
+        "SKIP-1",
+        BulkOperationType::Transition,
+        "Blocked by unresolved DEP-1".to_string(),
+    ));
+
+    assert_eq!(summary.total_operations, 1);
+    assert_eq!(summary.failed_operations, 0);
+    assert_eq!(summary.skipped_operations, 1);
+    assert_eq!(summary.describe_errors(), None);
+    assert_eq!(
+        bulk_summary_text("Bulk transition 'Done'", &summary),
+        "Bulk transition 'Done': 0 of 1 operations succeeded, 1 skipped\n\
+         • SKIP-1 skipped: Blocked by unresolved DEP-1"
+    );
+}
@@ -0,0 +1,342 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::bulk_csv::{coerce_to_create_meta, create_fields};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::BulkCreateIssuesFromCsvTool;
+use rust_jira_mcp::types::jira::{JiraCreateField, JiraCreateMeta};
+use serde_json::{json, Value};
+use std::path::Path;
+
+// Each test uses its own project key and email, as create-meta and the field
+// catalog live in a global cache
+fn create_test_config(base_url: &str, allowed: &Path, email: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: email.to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        file_allowlist: Some(allowed.display().to_string()),
+        ..Default::default()
+    }
+}
+
+fn field(id: &str, name: &str, schema: Value, allowed: Option<Value>) -> JiraCreateField {
+    serde_json::from_value(json!({
+        "fieldId": id,
+        "name": name,
+        "required": id == "summary",
+        "schema": schema,
+        "allowedValues": allowed
+    }))
+    .unwrap()
+}
+
+fn task_meta(project: &str) -> JiraCreateMeta {
+    JiraCreateMeta {
+        project_key: project.to_string(),
+        issue_type_id: "3".to_string(),
+        issue_type_name: "Task".to_string(),
+        fields: vec![
+            field("summary", "Summary", json!({"type": "string"}), None),
+            field(
+                "priority",
+                "Priority",
+                json!({"type": "priority"}),
+                Some(json!([{"id": "1", "name": "High"}, {"id": "2", "name": "Low"}])),
+            ),
+            field(
+                "customfield_10016",
+                "Story Points",
+                json!({"type": "number"}),
+                None,
+            ),
+            field(
+                "labels",
+                "Labels",
+                json!({"type": "array", "items": "string"}),
+                None,
+            ),
+            field(
+                "components",
+                "Component/s",
+                json!({"type": "array", "items": "component"}),
+                Some(json!([{"id": "10", "name": "API"}, {"id": "11", "name": "Web"}])),
+            ),
+        ],
+    }
+}
+
+async fn mock_metadata(server: &mut mockito::ServerGuard, project: &str) {
+    server
+        .mock("GET", "/rest/api/2/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {"id": "summary", "name": "Summary"},
+                {"id": "project", "name": "Project"},
+                {"id": "issuetype", "name": "Issue Type"},
+                {"id": "priority", "name": "Priority"},
+                {"id": "customfield_10016", "name": "Story Points"}
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    server
+        .mock(
+            "GET",
+            format!("/rest/api/2/issue/createmeta/{project}/issuetypes").as_str(),
+        )
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"values": [{"id": "3", "name": "Task"}]}).to_string())
+        .create_async()
+        .await;
+    let fields: Vec<Value> = task_meta(project)
+        .fields
+        .iter()
+        .map(|f| serde_json::to_value(f).unwrap())
+        .collect();
+    server
+        .mock(
+            "GET",
+            format!("/rest/api/2/issue/createmeta/{project}/issuetypes/3").as_str(),
+        )
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "values": fields }).to_string())
+        .create_async()
+        .await;
+}
+
+#[test]
+fn test_create_fields_and_coercion() {
+    let defaults = json!({"project": "CSVA", "labels": "imported"});
+    let values = json!({
+        "issuetype": "Task",
+        "summary": "Login fails",
+        "priority": "high",
+        "customfield_10016": "3",
+        "labels": "imported, backend",
+        "components": "Web,API"
+    });
+    let mut fields = create_fields(
+        defaults.as_object().unwrap(),
+        values.as_object().unwrap().clone(),
+    );
+    assert_eq!(fields["project"], json!({"key": "CSVA"}));
+    assert_eq!(fields["issuetype"], json!({"name": "Task"}));
+
+    assert!(coerce_to_create_meta(&mut fields, &task_meta("CSVA")).is_empty());
+    assert_eq!(fields["priority"], json!({"id": "1"}));
+    assert_eq!(fields["customfield_10016"], json!(3.0));
+    assert_eq!(fields["labels"], json!(["imported", "backend"]));
+    assert_eq!(fields["components"], json!([{"id": "11"}, {"id": "10"}]));
+
+    let mut fields = json!({"priority": "Urgent", "customfield_10016": "lots"})
+        .as_object()
+        .unwrap()
+        .clone();
+    assert_eq!(
+        coerce_to_create_meta(&mut fields, &task_meta("CSVA")),
+        [
+            "'lots' is not a number for Story Points (customfield_10016)",
+            "'Urgent' is not an allowed value of Priority (priority); allowed: High, Low"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_shows_the_first_rows() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    mock_metadata(&mut server, "CSVB").await;
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .expect(0)
+        .create_async()
+        .await;
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("new.csv");
+    let rows: String = (1..=6).map(|n| format!("Issue {n},Low\n")).collect();
+    std::fs::write(&file, format!("\u{feff}Title,Priority\n{rows}")).unwrap();
+
+    let tool = BulkCreateIssuesFromCsvTool::new(create_test_config(
+        &base_url,
+        dir.path(),
+        "dry-run@example.com",
+    ));
+    let result = tool
+        .handle(json!({
+            "file_path": file.to_str().unwrap(),
+            "column_mapping": {"Title": "Summary", "Priority": "priority"},
+            "project_key": "CSVB",
+            "issue_type": "Task",
+            "dry_run": true
+        }))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with(&format!(
+        "Dry run: 6 rows of {} would create issues; nothing was created\n\
+         Fields of the first 5 rows:\n",
+        file.canonicalize().unwrap().display()
+    )));
+    assert!(text.contains("\n• line 2: "));
+    assert!(!text.contains("Issue 6"));
+    let preview: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(preview.as_array().unwrap().len(), 5);
+    assert_eq!(
+        preview[0],
+        json!({
+            "line": 2,
+            "fields": {
+                "summary": "Issue 1",
+                "priority": {"id": "2"},
+                "project": {"key": "CSVB"},
+                "issuetype": {"id": "3"}
+            }
+        })
+    );
+    assert_eq!(result.is_error, Some(false));
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_invalid_rows_are_reported_together() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    mock_metadata(&mut server, "CSVC").await;
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .expect(0)
+        .create_async()
+        .await;
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("new.csv");
+    std::fs::write(
+        &file,
+        "Project,Type,Title,Points\n\
+         CSVC,Task,Fine,2\n\
+         CSVC,Task,,2\n\
+         CSVC,Task,Fine too,\n\
+         CSVC,Task,Points,many\n\
+         ,Task,No project,1\n",
+    )
+    .unwrap();
+
+    let tool = BulkCreateIssuesFromCsvTool::new(create_test_config(
+        &base_url,
+        dir.path(),
+        "invalid@example.com",
+    ));
+    let result = tool
+        .handle(json!({
+            "file_path": file.to_str().unwrap(),
+            "column_mapping": {
+                "Project": "project",
+                "Type": "Issue Type",
+                "Title": "summary",
+                "Points": "Story Points"
+            }
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        format!(
+            "3 rows of {} failed validation; nothing was created\n\
+             • line 3: Required on the create screen of Task in CSVC but not set: Summary (summary)\n\
+             • line 5: 'many' is not a number for Story Points (customfield_10016)\n\
+             • line 6: no project or issue type; map a column to them or pass project_key and issue_type",
+            file.canonicalize().unwrap().display()
+        )
+    );
+    assert_eq!(result.is_error, Some(true));
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_created_keys_are_listed_in_row_order() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    mock_metadata(&mut server, "CSVD").await;
+    let mut creates = Vec::new();
+    for (summary, status, body) in [
+        (
+            "First",
+            201,
+            json!({"id": "1", "key": "CSVD-7", "self": "x", "fields": {}}),
+        ),
+        (
+            "Second",
+            400,
+            json!({"errorMessages": ["Summary is too long"]}),
+        ),
+        (
+            "Third",
+            201,
+            json!({"id": "2", "key": "CSVD-8", "self": "x", "fields": {}}),
+        ),
+    ] {
+        creates.push(
+            server
+                .mock("POST", "/rest/api/2/issue")
+                .match_body(mockito::Matcher::PartialJson(
+                    json!({"fields": {"summary": summary, "project": {"key": "CSVD"}}}),
+                ))
+                .with_status(status)
+                .with_header("content-type", "application/json")
+                .with_body(body.to_string())
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("new.csv");
+    std::fs::write(&file, "Title\nFirst\nSecond\nThird\n").unwrap();
+
+    let tool = BulkCreateIssuesFromCsvTool::new(create_test_config(
+        &base_url,
+        dir.path(),
+        "create@example.com",
+    ));
+    let result = tool
+        .handle(json!({
+            "file_path": file.to_str().unwrap(),
+            "column_mapping": {"Title": "summary"},
+            "defaults": {"project": "CSVD", "Issue Type": "Task"}
+        }))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with(&format!(
+        "Created 2 of 3 issues from {}\n\
+         • line 2: CSVD-7 - {base_url}/browse/CSVD-7\n\
+         • line 3 failed: ",
+        file.canonicalize().unwrap().display()
+    )));
+    assert!(text.ends_with(&format!("\n• line 4: CSVD-8 - {base_url}/browse/CSVD-8")));
+    let report: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(report["created"], 2);
+    assert_eq!(
+        report["issues"][0],
+        json!({"line": 2, "issue_key": "CSVD-7", "error": null})
+    );
+    assert_eq!(report["issues"][1]["issue_key"], Value::Null);
+    assert_eq!(report["issues"][2]["issue_key"], "CSVD-8");
+    assert_eq!(result.is_error, Some(false));
+    for create in creates {
+        create.assert_async().await;
+    }
+}
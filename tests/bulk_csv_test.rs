@@ -0,0 +1,367 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::bulk_csv::{check_against_editmeta, parse_bulk_csv, CsvRowError};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::BulkOperationsFromCsvTool;
+use rust_jira_mcp::types::jira::BulkOperationType;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+fn create_test_config(base_url: &str, allowed: &Path) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        file_allowlist: Some(allowed.display().to_string()),
+        ..Default::default()
+    }
+}
+
+fn mapping(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(column, target)| ((*column).to_string(), (*target).to_string()))
+        .collect()
+}
+
+#[test]
+fn test_quoted_cells_and_line_numbers() {
+    let content = "Key,Summary,Priority,Notes\n\
+                   CSV-1,\"Login fails, sometimes\",\"{\"\"name\"\": \"\"High\"\"}\",ignored\n\
+                   CSV-2,\"Says \"\"hi\"\"\nover two lines\",,\n\
+                   CSV-3,,,\n";
+    let operations = parse_bulk_csv(
+        content.as_bytes(),
+        &BulkOperationType::Update,
+        &mapping(&[
+            ("Key", "issue_key"),
+            ("Summary", "summary"),
+            ("Priority", "priority"),
+        ]),
+    )
+    .unwrap();
+
+    let errors = operations.unwrap_err();
+    assert_eq!(
+        errors,
+        [CsvRowError {
+            line: 5,
+            message: "CSV-3 has no field values to update".to_string()
+        }]
+    );
+
+    let operations = parse_bulk_csv(
+        content.as_bytes(),
+        &BulkOperationType::Update,
+        &mapping(&[("Key", "issue_key"), ("Summary", "summary")]),
+    )
+    .unwrap();
+    let errors = operations.unwrap_err();
+    assert_eq!(
+        errors[0].to_string(),
+        "line 5: CSV-3 has no field values to update"
+    );
+
+    let content = content.replace("CSV-3,,,\n", "");
+    let operations = parse_bulk_csv(
+        content.as_bytes(),
+        &BulkOperationType::Update,
+        &mapping(&[
+            ("Key", "issue_key"),
+            ("Summary", "summary"),
+            ("Priority", "priority"),
+        ]),
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(operations.len(), 2);
+    assert_eq!(operations[0].line, 2);
+    assert_eq!(
+        operations[0].item.data,
+        json!({"fields": {"summary": "Login fails, sometimes", "priority": {"name": "High"}}})
+    );
+    assert_eq!(operations[1].line, 3);
+    assert_eq!(operations[1].item.issue_key, "CSV-2");
+    assert_eq!(
+        operations[1].item.data,
+        json!({"fields": {"summary": "Says \"hi\"\nover two lines"}})
+    );
+}
+
+#[test]
+fn test_bom_and_padded_headers_are_accepted() {
+    let content = "\u{feff} Key , Transition \nCSV-1,31\n";
+    let operations = parse_bulk_csv(
+        content.as_bytes(),
+        &BulkOperationType::Transition,
+        &mapping(&[("Key", "issue_key"), ("Transition", "transition_id")]),
+    )
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(operations[0].item.issue_key, "CSV-1");
+    assert_eq!(operations[0].item.data, json!({"transition_id": "31"}));
+}
+
+#[test]
+fn test_bad_header_is_rejected() {
+    let content = b"Issue,Summary\nCSV-1,Broken\n";
+    let error = parse_bulk_csv(
+        content,
+        &BulkOperationType::Update,
+        &mapping(&[("Key", "issue_key"), ("Summary", "summary")]),
+    )
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Column 'Key' is not in the CSV header (Issue, Summary)"));
+
+    let error = parse_bulk_csv(
+        content,
+        &BulkOperationType::Update,
+        &mapping(&[("Summary", "summary")]),
+    )
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("No column is mapped to 'issue_key'"));
+
+    let error = parse_bulk_csv(
+        content,
+        &BulkOperationType::Mixed,
+        &mapping(&[("Issue", "issue_key")]),
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("mixed operations"));
+}
+
+#[test]
+fn test_rows_missing_required_values_are_reported() {
+    let content = b"Key,Account\nCSV-1,acc-1\n,acc-2\nCSV-3,\nCSV-4,acc-4,extra\n";
+    let errors = parse_bulk_csv(
+        content,
+        &BulkOperationType::AddWatcher,
+        &mapping(&[("Key", "issue_key"), ("Account", "account_id")]),
+    )
+    .unwrap()
+    .unwrap_err();
+
+    let lines: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(lines[0], "line 3: the issue key is empty");
+    assert_eq!(lines[1], "line 4: CSV-3 has no value for account_id");
+    assert!(lines[2].starts_with("line 5: "));
+    assert!(lines[2].contains("found record with 3 fields"));
+}
+
+#[test]
+fn test_check_against_editmeta() {
+    let editmeta = json!({"fields": {
+        "summary": {"name": "Summary"},
+        "priority": {"allowedValues": [{"id": "1", "name": "High"}, {"id": "2", "name": "Low"}]}
+    }});
+    let fields = json!({
+        "summary": "New title",
+        "priority": {"name": "high"},
+        "resolution": "Done"
+    });
+    assert_eq!(
+        check_against_editmeta(fields.as_object().unwrap(), &editmeta),
+        ["field 'resolution' cannot be edited on this issue"]
+    );
+
+    let fields = json!({"priority": {"name": "Urgent"}});
+    assert_eq!(
+        check_against_editmeta(fields.as_object().unwrap(), &editmeta),
+        ["'Urgent' is not an allowed name of field 'priority'"]
+    );
+
+    let fields = json!({"priority": "High"});
+    assert_eq!(
+        check_against_editmeta(fields.as_object().unwrap(), &editmeta),
+        ["field 'priority' takes one of its allowed values as an object, e.g. {\"name\": \"High\"}"]
+    );
+}
+
+#[tokio::test]
+async fn test_row_errors_stop_before_anything_runs() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("comments.csv");
+    std::fs::write(&file, "Key,Comment\nCSV-1,Looks good\nCSV-2,\n").unwrap();
+
+    let comment = server
+        .mock("POST", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let tool = BulkOperationsFromCsvTool::new(create_test_config(&base_url, dir.path()));
+    let result = tool
+        .handle(json!({
+            "file_path": file.to_str().unwrap(),
+            "operation_type": "add_comment",
+            "column_mapping": {"Key": "issue_key", "Comment": "comment_body"}
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        format!(
+            "1 rows of {} could not be read; nothing was executed\n\
+             • line 3: CSV-2 has no value for comment_body",
+            file.canonicalize().unwrap().display()
+        )
+    );
+    assert_eq!(result.is_error, Some(true));
+    comment.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_results_map_back_to_csv_lines() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("updates.csv");
+    std::fs::write(&file, "Key,Summary\nCSV-1,\"First, fixed\"\nCSV-2,Second\n").unwrap();
+
+    let first = server
+        .mock("PUT", "/rest/api/2/issue/CSV-1")
+        .match_body(mockito::Matcher::Json(
+            json!({"fields": {"summary": "First, fixed"}}),
+        ))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("PUT", "/rest/api/2/issue/CSV-2")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["Issue does not exist"]}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = BulkOperationsFromCsvTool::new(create_test_config(&base_url, dir.path()));
+    let result = tool
+        .handle(json!({
+            "file_path": file.to_str().unwrap(),
+            "operation_type": "update",
+            "column_mapping": {"Key": "issue_key", "Summary": "summary"},
+            "config": {"rate_limit_ms": 0, "max_retries": 0}
+        }))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with(&format!(
+        "Bulk update from {}: 1 of 2 operations succeeded",
+        file.canonicalize().unwrap().display()
+    )));
+    assert!(text.contains("\n• line 3 CSV-2 failed: "));
+    let report: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(report["successful_operations"], 1);
+    assert_eq!(report["results"][0]["line"], 2);
+    assert_eq!(report["results"][0]["success"], true);
+    assert_eq!(report["results"][1]["line"], 3);
+    assert_eq!(report["results"][1]["issue_key"], "CSV-2");
+    assert_eq!(report["results"][1]["error_kind"], "not_found");
+    assert_eq!(result.is_error, Some(false));
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_validation_against_editmeta_blocks_execution() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("priorities.csv");
+    std::fs::write(
+        &file,
+        "Key,Priority\nCSV-1,\"{\"\"name\"\": \"\"High\"\"}\"\nCSV-2,\"{\"\"name\"\": \"\"Urgent\"\"}\"\nCSV-3,\"{\"\"name\"\": \"\"Nope\"\"}\"\n",
+    )
+    .unwrap();
+
+    let editmeta = json!({"fields": {
+        "priority": {"allowedValues": [{"id": "1", "name": "High"}]}
+    }})
+    .to_string();
+    let _first = server
+        .mock("GET", "/rest/api/2/issue/CSV-1/editmeta")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(&editmeta)
+        .create_async()
+        .await;
+    let _second = server
+        .mock("GET", "/rest/api/2/issue/CSV-2/editmeta")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(&editmeta)
+        .create_async()
+        .await;
+    let third = server
+        .mock("GET", "/rest/api/2/issue/CSV-3/editmeta")
+        .expect(0)
+        .create_async()
+        .await;
+    let update = server
+        .mock("PUT", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let tool = BulkOperationsFromCsvTool::new(create_test_config(&base_url, dir.path()));
+    let result = tool
+        .handle(json!({
+            "file_path": file.to_str().unwrap(),
+            "operation_type": "update",
+            "column_mapping": {"Key": "issue_key", "Priority": "priority"},
+            "validate": true,
+            "validate_rows": 2
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        format!(
+            "Validation of {} found 1 problems; nothing was executed\n\
+             • line 3: CSV-2: 'Urgent' is not an allowed name of field 'priority'",
+            file.canonicalize().unwrap().display()
+        )
+    );
+    assert_eq!(result.is_error, Some(true));
+    third.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_file_must_be_allowed() {
+    let allowed = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    let file = outside.path().join("ops.csv");
+    std::fs::write(&file, "Key\nCSV-1\n").unwrap();
+    let tool =
+        BulkOperationsFromCsvTool::new(create_test_config("http://localhost:1", allowed.path()));
+
+    let error = tool
+        .handle(json!({
+            "file_path": file.to_str().unwrap(),
+            "operation_type": "update",
+            "column_mapping": {"Key": "issue_key"}
+        }))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("outside the allowed directories"));
+}
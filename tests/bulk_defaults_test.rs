@@ -0,0 +1,190 @@
+use rust_jira_mcp::config::bulk::{
+    majority_project, resolve_bulk_config, BulkConfigSource, BulkDefaults,
+};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::BulkAddCommentsTool;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const DEFAULTS: &str = r#"
+[bulk_defaults]
+batch_size = 20
+rate_limit_ms = 250
+continue_on_error = true
+
+[bulk_defaults.legacy]
+batch_size = 5
+max_concurrency = 1
+continue_on_error = false
+"#;
+
+#[derive(Deserialize)]
+struct ConfigFile {
+    bulk_defaults: BulkDefaults,
+}
+
+/// `DEFAULTS` read the way configuration files are
+fn bulk_defaults() -> BulkDefaults {
+    config::Config::builder()
+        .add_source(config::File::from_str(DEFAULTS, config::FileFormat::Toml))
+        .build()
+        .unwrap()
+        .try_deserialize::<ConfigFile>()
+        .unwrap()
+        .bulk_defaults
+}
+
+fn source_of(
+    effective: &rust_jira_mcp::config::bulk::EffectiveBulkConfig,
+    name: &str,
+) -> (Value, BulkConfigSource) {
+    effective
+        .settings
+        .iter()
+        .find(|(setting, _, _)| *setting == name)
+        .map(|(_, value, source)| (value.clone(), source.clone()))
+        .unwrap()
+}
+
+#[test]
+fn test_call_then_project_then_global_then_built_in() {
+    let defaults = bulk_defaults();
+    assert_eq!(defaults.global.batch_size, Some(20));
+    assert_eq!(defaults.projects["LEGACY"].batch_size, Some(5));
+
+    let effective = resolve_bulk_config(
+        Some(&defaults),
+        &["LEGACY-1", "LEGACY-2"],
+        Some(&json!({"max_concurrency": 4})),
+    )
+    .unwrap();
+    assert_eq!(effective.project.as_deref(), Some("LEGACY"));
+    assert_eq!(effective.config.max_concurrency, Some(4));
+    assert_eq!(effective.config.batch_size, Some(5));
+    assert_eq!(effective.config.rate_limit_ms, Some(250));
+    assert_eq!(effective.config.max_retries, Some(3));
+    assert!(!effective.config.continue_on_error);
+    assert_eq!(
+        source_of(&effective, "max_concurrency"),
+        (json!(4), BulkConfigSource::Call)
+    );
+    assert_eq!(
+        source_of(&effective, "batch_size"),
+        (json!(5), BulkConfigSource::Project("LEGACY".to_string()))
+    );
+    assert_eq!(
+        source_of(&effective, "rate_limit_ms"),
+        (json!(250), BulkConfigSource::Global)
+    );
+    assert_eq!(
+        source_of(&effective, "max_retries"),
+        (json!(3), BulkConfigSource::BuiltIn)
+    );
+    assert_eq!(
+        effective.describe(),
+        "Bulk settings: batch_size 5 (project LEGACY), rate_limit_ms 250 (bulk_defaults), \
+         max_retries 3 (built-in), max_concurrency 4 (call), continue_on_error false (project LEGACY)"
+    );
+
+    // A project without a section falls through to the global settings
+    let effective = resolve_bulk_config(Some(&defaults), &["NEW-1"], None).unwrap();
+    assert_eq!(effective.config.batch_size, Some(20));
+    assert!(effective.config.continue_on_error);
+
+    // No defaults at all: built-in values
+    let effective = resolve_bulk_config(None, &["NEW-1"], None).unwrap();
+    assert!(effective
+        .settings
+        .iter()
+        .all(|(_, _, source)| *source == BulkConfigSource::BuiltIn));
+
+    let error = resolve_bulk_config(
+        Some(&defaults),
+        &["NEW-1"],
+        Some(&json!({"batch_size": "many"})),
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(error.contains("config"), "{error}");
+}
+
+#[test]
+fn test_project_is_the_majority_of_mixed_keys() {
+    assert_eq!(
+        majority_project(&["NEW-1", "LEGACY-2", "LEGACY-3", "new-4", "LEGACY-5"]).as_deref(),
+        Some("LEGACY")
+    );
+    // A tie goes to the project named first
+    assert_eq!(
+        majority_project(&["NEW-1", "LEGACY-2", "LEGACY-3", "NEW-4"]).as_deref(),
+        Some("NEW")
+    );
+    assert_eq!(
+        majority_project(&["LEGACY-2", "NEW-1"]).as_deref(),
+        Some("LEGACY")
+    );
+    // Ids and malformed keys are not counted
+    assert_eq!(
+        majority_project(&["10001", "NEW-x", "-5", "OPS-1"]).as_deref(),
+        Some("OPS")
+    );
+    assert_eq!(majority_project::<&str>(&[]), None);
+
+    let defaults = bulk_defaults();
+    let effective =
+        resolve_bulk_config(Some(&defaults), &["NEW-1", "LEGACY-2", "NEW-3"], None).unwrap();
+    assert_eq!(effective.project.as_deref(), Some("NEW"));
+    assert_eq!(effective.config.batch_size, Some(20));
+}
+
+#[tokio::test]
+async fn test_project_defaults_stop_a_bulk_tool_after_a_failure() {
+    let mut server = mockito::Server::new_async().await;
+    let failed = server
+        .mock("POST", "/rest/api/2/issue/LEGACY-1/comment")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["Comments are locked"]}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let not_run = server
+        .mock("POST", "/rest/api/2/issue/LEGACY-2/comment")
+        .expect(0)
+        .create_async()
+        .await;
+    let config = JiraConfig {
+        api_base_url: format!("{}/rest/api/2", server.url()),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        bulk_defaults: Some(bulk_defaults()),
+        ..Default::default()
+    };
+
+    let result = BulkAddCommentsTool::new(config)
+        .handle(json!({
+            "issue_keys": ["LEGACY-1", "LEGACY-2"],
+            "comment": "Migrated",
+            "config": {"max_retries": 0, "rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with("Bulk comment: 0 of 2 operations succeeded"),
+        "{text}"
+    );
+    assert!(text.contains(", 1 skipped"), "{text}");
+    assert!(
+        text.contains("Bulk settings: batch_size 5 (project LEGACY), rate_limit_ms 0 (call)"),
+        "{text}"
+    );
+    let report: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(report["settings"]["project"], "LEGACY");
+    assert_eq!(report["results"][1]["skipped"], true);
+
+    failed.assert_async().await;
+    not_run.assert_async().await;
+}
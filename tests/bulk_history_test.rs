@@ -0,0 +1,180 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{
+    BulkAddCommentsTool, GetBulkOperationDetailTool, GetBulkOperationHistoryTool,
+};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        ..Default::default()
+    }
+}
+
+async fn mock_comments(server: &mut mockito::ServerGuard) -> Vec<mockito::Mock> {
+    let ok = server
+        .mock("POST", "/rest/api/2/issue/HIS-1/comment")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "1",
+                "body": "Hi",
+                "author": {"self": "x", "name": "me", "key": "me", "displayName": "Me", "active": true},
+                "created": "2024-03-02T14:30:00.000+0000"
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let forbidden = server
+        .mock("POST", "/rest/api/2/issue/HIS-2/comment")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["No comment permission"]}).to_string())
+        .create_async()
+        .await;
+    vec![ok, forbidden]
+}
+
+async fn add_comments(config: &JiraConfig) -> String {
+    let result = BulkAddCommentsTool::new(config.clone())
+        .handle(json!({
+            "issue_keys": ["HIS-1", "HIS-2"],
+            "comment": "Hi",
+            "api_token": "secret-value",
+            "config": {"continue_on_error": true, "rate_limit_ms": 0, "max_retries": 0}
+        }))
+        .await
+        .unwrap();
+    let summary: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    summary["history_id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_bulk_operations_are_recorded_and_looked_up_by_id() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let _mocks = mock_comments(&mut server).await;
+
+    let id = add_comments(&config).await;
+    assert!(id.starts_with("bulk-"));
+
+    let history = GetBulkOperationHistoryTool::new(config.clone());
+    let result = history.handle(json!({"limit": 1})).await.unwrap();
+    assert!(result.content[0].text.contains(&format!(
+        "\n• {id} bulk_add_comments (add_comment) on 2 issues: 1 succeeded, 1 failed, 0 skipped at "
+    )));
+    let listed: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(listed[0]["issue_keys"], json!(["HIS-1", "HIS-2"]));
+    assert_eq!(listed[0]["arguments"]["api_token"], "[REDACTED]");
+    assert_eq!(listed[0]["arguments"]["comment"], "Hi");
+    assert!(listed[0].get("results").is_none());
+
+    let result = GetBulkOperationDetailTool::new(config.clone())
+        .handle(json!({"id": id}))
+        .await
+        .unwrap();
+    assert!(result.content[0]
+        .text
+        .ends_with("\n• HIS-2 add_comment failed: Permission denied: No comment permission"));
+    let detail: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(detail["results"][1]["error_kind"], "permission_denied");
+
+    let error = GetBulkOperationDetailTool::new(config)
+        .handle(json!({"id": "bulk-000000000000"}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("No bulk operation 'bulk-000000000000'"));
+}
+
+#[tokio::test]
+async fn test_history_filters_by_type_and_time() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let _mocks = mock_comments(&mut server).await;
+    // Servers are reused between tests, so only this test's operations count
+    let since = chrono::Utc::now().to_rfc3339();
+    add_comments(&config).await;
+    add_comments(&config).await;
+
+    let history = GetBulkOperationHistoryTool::new(config);
+    let count = |result: rust_jira_mcp::types::mcp::MCPToolResult| {
+        serde_json::from_str::<Value>(&result.content[1].text)
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .len()
+    };
+
+    let all = history.handle(json!({"since": since})).await.unwrap();
+    assert_eq!(count(all), 2);
+    let limited = history
+        .handle(json!({"since": since, "limit": 1}))
+        .await
+        .unwrap();
+    assert!(limited.content[0]
+        .text
+        .starts_with("Latest 1 of 2 bulk operations:"));
+    assert_eq!(count(limited), 1);
+    let comments = history
+        .handle(json!({"operation_type": "add_comment", "since": since}))
+        .await
+        .unwrap();
+    assert_eq!(count(comments), 2);
+    let transitions = history
+        .handle(json!({"operation_type": "transition", "since": since}))
+        .await
+        .unwrap();
+    assert_eq!(transitions.content[0].text, "No bulk operations recorded");
+    let old = history
+        .handle(json!({"until": "2000-01-01"}))
+        .await
+        .unwrap();
+    assert_eq!(count(old), 0);
+
+    let error = history
+        .handle(json!({"operation_type": "delete"}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Unknown operation type 'delete'"));
+}
+
+#[tokio::test]
+async fn test_history_file_keeps_operations_across_restarts() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("bulk-history.jsonl");
+    let mut server = mockito::Server::new_async().await;
+    let config = JiraConfig {
+        bulk_history_file: Some(file.clone()),
+        ..create_test_config(&server.url())
+    };
+    let _mocks = mock_comments(&mut server).await;
+
+    let id = add_comments(&config).await;
+    let content = std::fs::read_to_string(&file).unwrap();
+    assert_eq!(content.lines().count(), 1);
+    assert!(!content.contains("secret-value"));
+
+    // A server for another site starts without history and reads the file
+    let restarted = JiraConfig {
+        api_base_url: format!("{}/restarted", config.api_base_url),
+        ..config
+    };
+    let result = GetBulkOperationDetailTool::new(restarted)
+        .handle(json!({"id": id}))
+        .await
+        .unwrap();
+    let detail: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(detail["tool"], "bulk_add_comments");
+    assert_eq!(detail["successful_operations"], 1);
+}
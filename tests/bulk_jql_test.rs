@@ -0,0 +1,239 @@
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{
+    BulkAddCommentsTool, BulkTransitionIssuesTool, BulkUpdateIssuesTool,
+};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn search_mock(
+    server: &mut mockito::Server,
+    jql: &str,
+    total: usize,
+    keys: &[&str],
+) -> mockito::Mock {
+    let issues: Vec<Value> = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| json!({"id": format!("{}", 100 + index), "key": key}))
+        .collect();
+    server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("jql".to_string(), jql.to_string()),
+            Matcher::UrlEncoded("fields".to_string(), "key".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"total": total, "issues": issues}).to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_bulk_comment_on_the_issues_a_query_selects() {
+    let mut server = mockito::Server::new_async().await;
+    let search = search_mock(
+        &mut server,
+        "project = SEL AND labels = stale ORDER BY key ASC",
+        2,
+        &["SEL-1", "SEL-2"],
+    )
+    .await;
+    let mut comments = Vec::new();
+    for key in ["SEL-1", "SEL-2"] {
+        comments.push(
+            server
+                .mock("POST", format!("/rest/api/2/issue/{key}/comment").as_str())
+                .with_status(201)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    json!({
+                        "id": "1",
+                        "body": "Closing stale issues",
+                        "author": {
+                            "self": "https://jira.example.com/rest/api/2/user?username=test",
+                            "name": "test",
+                            "key": "test",
+                            "displayName": "Test User",
+                            "active": true
+                        },
+                        "created": "2024-03-02T14:30:00.000+0000"
+                    })
+                    .to_string(),
+                )
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let result = BulkAddCommentsTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "jql": "project = SEL AND labels = stale",
+            "max_issues": 10,
+            "comment": "Closing stale issues",
+            "config": {"rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+
+    search.assert_async().await;
+    for comment in comments {
+        comment.assert_async().await;
+    }
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with("Bulk comment: 2 of 2 operations succeeded"),
+        "{text}"
+    );
+    assert!(
+        text.contains(
+            "\nSelected 2 issues matching `project = SEL AND labels = stale`: SEL-1, SEL-2\n\
+             Sorted by key (ORDER BY key ASC added) so pages could not shift"
+        ),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_dry_run_only_lists_the_selection() {
+    let mut server = mockito::Server::new_async().await;
+    let keys: Vec<String> = (1..=25).map(|n| format!("DRY-{n}")).collect();
+    let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+    let search = search_mock(&mut server, "project = DRY ORDER BY rank", 25, &keys).await;
+    let transitions = server
+        .mock("POST", Matcher::Regex("/transitions$".to_string()))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = BulkTransitionIssuesTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "jql": "project = DRY ORDER BY rank",
+            "max_issues": 50,
+            "transition_id": "31",
+            "dry_run": true
+        }))
+        .await
+        .unwrap();
+
+    search.assert_async().await;
+    transitions.assert_async().await;
+    assert_eq!(result.is_error, Some(false));
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with(
+            "Dry run: Bulk transition '31' would change 25 issues matching `project = DRY ORDER BY rank`: DRY-1, DRY-2,"
+        ),
+        "{text}"
+    );
+    assert!(
+        text.ends_with("DRY-20 and 5 more; nothing was changed"),
+        "{text}"
+    );
+    let selected: Vec<String> = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(selected.len(), 25);
+}
+
+#[tokio::test]
+async fn test_selection_over_the_cap_changes_nothing() {
+    let mut server = mockito::Server::new_async().await;
+    let search = search_mock(
+        &mut server,
+        "project = CAP ORDER BY key ASC",
+        120,
+        &["CAP-1", "CAP-2"],
+    )
+    .await;
+    let updates = server
+        .mock("PUT", Matcher::Regex("/rest/api/2/issue/".to_string()))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let error = BulkUpdateIssuesTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "jql": "project = CAP",
+            "max_issues": 100,
+            "add_labels": ["triaged"]
+        }))
+        .await
+        .unwrap_err()
+        .to_string();
+
+    search.assert_async().await;
+    updates.assert_async().await;
+    assert!(
+        error.contains("The query matches 120 issues, more than the limit of 100"),
+        "{error}"
+    );
+}
+
+#[tokio::test]
+async fn test_selection_arguments_are_validated() {
+    let server = mockito::Server::new_async().await;
+    let tool = BulkAddCommentsTool::new(create_test_config(&server.url()));
+
+    let both = tool
+        .handle(json!({
+            "jql": "project = SEL",
+            "max_issues": 10,
+            "issue_keys": ["SEL-1"],
+            "comment": "Hi"
+        }))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(both.contains("Give either jql or issue_keys"), "{both}");
+
+    let uncapped = tool
+        .handle(json!({"jql": "project = SEL", "comment": "Hi"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(uncapped.contains("max_issues"), "{uncapped}");
+
+    let neither = tool
+        .handle(json!({"comment": "Hi"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        neither.contains("Missing required parameter: issue_keys or jql"),
+        "{neither}"
+    );
+}
+
+#[tokio::test]
+async fn test_query_selecting_nothing_changes_nothing() {
+    let mut server = mockito::Server::new_async().await;
+    let search = search_mock(&mut server, "project = NONE ORDER BY key ASC", 0, &[]).await;
+
+    let result = BulkUpdateIssuesTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "jql": "project = NONE",
+            "max_issues": 5,
+            "add_labels": ["triaged"]
+        }))
+        .await
+        .unwrap();
+
+    search.assert_async().await;
+    assert_eq!(
+        result.content[0].text,
+        "Bulk update: 0 issues matching `project = NONE`; nothing was changed\n\
+         Sorted by key (ORDER BY key ASC added) so pages could not shift"
+    );
+}
@@ -19,6 +19,10 @@ async fn test_bulk_operation_summary() {
         success: true,
         error_message: None,
         operation_type: BulkOperationType::Update,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     });
 
     summary.add_result(rust_jira_mcp::types::jira::BulkOperationResult {
@@ -26,6 +30,10 @@ async fn test_bulk_operation_summary() {
         success: false,
         error_message: Some("Test error".to_string()),
         operation_type: BulkOperationType::Transition,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     });
 
     summary.add_result(rust_jira_mcp::types::jira::BulkOperationResult {
@@ -33,6 +41,10 @@ async fn test_bulk_operation_summary() {
         success: true,
         error_message: None,
         operation_type: BulkOperationType::AddComment,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     });
 
     // Test final state
@@ -106,6 +118,8 @@ fn test_bulk_operation_config_serialization() {
         continue_on_error: false,
         rate_limit_ms: Some(200),
         max_retries: Some(5),
+        max_concurrency: Some(2),
+        capture_previous_fields: false,
     };
 
     // Test serialization
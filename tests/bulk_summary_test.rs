@@ -0,0 +1,226 @@
+use reqwest::StatusCode;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::error::{JiraError, JiraErrorKind};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{bulk_summary_text, BulkAddCommentsTool};
+use rust_jira_mcp::types::jira::{
+    BulkOperationItem, BulkOperationResult, BulkOperationSummary, BulkOperationType,
+};
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn result(
+    issue_key: &str,
+    operation_type: BulkOperationType,
+    error: Option<JiraError>,
+) -> BulkOperationResult {
+    BulkOperationResult {
+        issue_key: issue_key.to_string(),
+        success: error.is_none(),
+        error_message: error.as_ref().map(ToString::to_string),
+        operation_type,
+        error_kind: error.as_ref().map(JiraError::kind),
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
+    }
+}
+
+fn sample_summary() -> BulkOperationSummary {
+    let mut summary = BulkOperationSummary::new();
+    summary.add_result(result("TEST-1", BulkOperationType::Update, None));
+    for key in ["TEST-2", "TEST-3", "TEST-4"] {
+        summary.add_result(result(
+            key,
+            BulkOperationType::Transition,
+            Some(JiraError::permission_denied(
+                "You cannot transition this issue",
+            )),
+        ));
+    }
+    for key in ["TEST-5", "TEST-6"] {
+        summary.add_result(result(
+            key,
+            BulkOperationType::AddComment,
+            Some(JiraError::from_jira_response(
+                StatusCode::NOT_FOUND,
+                &json!({"errorMessages": ["Issue does not exist or you do not have permission to see it."]}),
+            )),
+        ));
+    }
+    summary.duration_ms = 1234;
+    summary
+}
+
+#[test]
+fn test_errors_by_kind() {
+    let counts = sample_summary().errors_by_kind();
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[&JiraErrorKind::PermissionDenied], 3);
+    assert_eq!(counts[&JiraErrorKind::NotFound], 2);
+    assert_eq!(
+        sample_summary().describe_errors().as_deref(),
+        Some("3 permission errors, 2 not-found")
+    );
+    assert_eq!(BulkOperationSummary::new().describe_errors(), None);
+}
+
+#[test]
+fn test_error_kinds() {
+    assert_eq!(JiraError::auth_error("x").kind(), JiraErrorKind::Auth);
+    assert_eq!(
+        JiraError::validation_error("summary", "x").kind(),
+        JiraErrorKind::Validation
+    );
+    assert_eq!(
+        JiraError::from_jira_response(
+            StatusCode::BAD_REQUEST,
+            &json!({"errors": {"summary": "Summary is required."}})
+        )
+        .kind(),
+        JiraErrorKind::Validation
+    );
+    assert_eq!(
+        JiraError::from_jira_response(StatusCode::NOT_FOUND, &json!({})).kind(),
+        JiraErrorKind::NotFound
+    );
+    assert_eq!(
+        JiraError::HttpError {
+            status: StatusCode::TOO_MANY_REQUESTS,
+            message: "slow down".to_string()
+        }
+        .kind(),
+        JiraErrorKind::RateLimited
+    );
+    assert_eq!(JiraError::api_error("boom").kind(), JiraErrorKind::Other);
+}
+
+#[test]
+fn test_summary_text_snapshot() {
+    assert_eq!(
+        bulk_summary_text("Bulk update", &sample_summary()),
+        "Bulk update: 1 of 6 operations succeeded (3 permission errors, 2 not-found)\n\
+         • TEST-2 failed: Permission denied: You cannot transition this issue\n\
+         • TEST-3 failed: Permission denied: You cannot transition this issue\n\
+         • TEST-4 failed: Permission denied: You cannot transition this issue\n\
+         • TEST-5 failed: Jira API error: Issue does not exist or you do not have permission to see it.\n\
+         • TEST-6 failed: Jira API error: Issue does not exist or you do not have permission to see it."
+    );
+}
+
+#[test]
+fn test_summary_json_snapshot() {
+    let mut summary = BulkOperationSummary::new();
+    summary.add_result(result("TEST-1", BulkOperationType::AddWatcher, None));
+    summary.add_result(result(
+        "TEST-2",
+        BulkOperationType::AddComment,
+        Some(JiraError::permission_denied("Nope")),
+    ));
+    summary.duration_ms = 42;
+
+    assert_eq!(
+        serde_json::to_string_pretty(&summary).unwrap(),
+        r#"{
+  "total_operations": 2,
+  "successful_operations": 1,
+  "failed_operations": 1,
+  "skipped_operations": 0,
+  "results": [
+    {
+      "issue_key": "TEST-1",
+      "success": true,
+      "error_message": null,
+      "operation_type": "add_watcher",
+      "error_kind": null,
+      "skipped": false
+    },
+    {
+      "issue_key": "TEST-2",
+      "success": false,
+      "error_message": "Permission denied: Nope",
+      "operation_type": "add_comment",
+      "error_kind": "permission_denied",
+      "skipped": false
+    }
+  ],
+  "duration_ms": 42
+}"#
+    );
+}
+
+#[test]
+fn test_operation_type_accepts_old_spelling() {
+    for operation_type in ["add_comment", "AddComment"] {
+        let item: BulkOperationItem = serde_json::from_value(json!({
+            "issue_key": "TEST-1",
+            "operation_type": operation_type,
+            "data": {}
+        }))
+        .unwrap();
+        assert!(matches!(item.operation_type, BulkOperationType::AddComment));
+    }
+}
+
+#[tokio::test]
+async fn test_bulk_tool_attaches_json_summary() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _ok = server
+        .mock("POST", "/rest/api/2/issue/SUM-1/comment")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "1",
+                "body": "Hi",
+                "author": {"self": "x", "name": "me", "key": "me", "displayName": "Me", "active": true},
+                "created": "2024-03-02T14:30:00.000+0000"
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let _forbidden = server
+        .mock("POST", "/rest/api/2/issue/SUM-2/comment")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["No comment permission"]}).to_string())
+        .create_async()
+        .await;
+
+    let tool = BulkAddCommentsTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_keys": ["SUM-1", "SUM-2"],
+            "comment": "Hi",
+            "config": {"continue_on_error": true, "rate_limit_ms": 0, "max_retries": 0}
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(result.content.len(), 2);
+    assert_eq!(
+        result.content[0].text,
+        "Bulk comment: 1 of 2 operations succeeded (1 permission error)\n\
+         • SUM-2 failed: Permission denied: No comment permission"
+    );
+    let summary: serde_json::Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(summary["successful_operations"], 1);
+    assert_eq!(summary["results"][1]["operation_type"], "add_comment");
+    assert_eq!(summary["results"][1]["error_kind"], "permission_denied");
+    assert_eq!(result.is_error, Some(false));
+}
@@ -0,0 +1,228 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::bulk_history::still_applied;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{
+    BulkUpdateIssuesTool, GetBulkOperationDetailTool, UndoBulkOperationTool,
+};
+use serde_json::{json, Value};
+
+const KEYS: [&str; 3] = ["UND-1", "UND-2", "UND-3"];
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn mock_fix_versions(
+    server: &mut mockito::ServerGuard,
+    key: &str,
+    versions: Value,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("/rest/api/2/issue/{key}").as_str())
+        .match_query(mockito::Matcher::UrlEncoded(
+            "fields".into(),
+            "fixVersions".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "1",
+                "key": key,
+                "self": "x",
+                "fields": {"fixVersions": versions}
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await
+}
+
+async fn mock_update(
+    server: &mut mockito::ServerGuard,
+    key: &str,
+    body: mockito::Matcher,
+    hits: usize,
+) -> mockito::Mock {
+    server
+        .mock("PUT", format!("/rest/api/2/issue/{key}").as_str())
+        .match_body(body)
+        .with_status(204)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+fn old_versions() -> Value {
+    json!([{"self": "x", "id": "10", "name": "1.0"}])
+}
+
+fn undo_body() -> mockito::Matcher {
+    mockito::Matcher::Json(json!({"fields": {"fixVersions": old_versions()}}))
+}
+
+fn history_id(result: &rust_jira_mcp::types::mcp::MCPToolResult) -> String {
+    let summary: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    summary["history_id"].as_str().unwrap().to_string()
+}
+
+/// Set fix version 2.0 on every issue, which all had 1.0, and return the
+/// history id; afterwards UND-3 has been moved on to 3.0 by someone else
+async fn update_then_change_one(server: &mut mockito::ServerGuard, config: &JiraConfig) -> String {
+    let mut mocks = Vec::new();
+    for key in KEYS {
+        mocks.push(mock_fix_versions(server, key, old_versions()).await);
+        mocks.push(mock_update(server, key, mockito::Matcher::Any, 1).await);
+    }
+    let result = BulkUpdateIssuesTool::new(config.clone())
+        .handle(json!({
+            "issue_keys": KEYS,
+            "update_data": {"fields": {"fixVersions": [{"name": "2.0"}]}},
+            "config": {"max_retries": 0, "rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Bulk update: 3 of 3 operations succeeded"
+    );
+    for mock in mocks {
+        mock.assert_async().await;
+        mock.remove_async().await;
+    }
+
+    let now = json!([{"self": "x", "id": "20", "name": "2.0"}]);
+    mock_fix_versions(server, "UND-1", now.clone()).await;
+    mock_fix_versions(server, "UND-2", now).await;
+    mock_fix_versions(
+        server,
+        "UND-3",
+        json!([{"self": "x", "id": "30", "name": "3.0"}]),
+    )
+    .await;
+    history_id(&result)
+}
+
+#[tokio::test]
+async fn test_undo_puts_back_previous_values_and_refuses_changed_issues() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let id = update_then_change_one(&mut server, &config).await;
+
+    let detail = GetBulkOperationDetailTool::new(config.clone())
+        .handle(json!({"id": id}))
+        .await
+        .unwrap();
+    let detail: Value = serde_json::from_str(&detail.content[1].text).unwrap();
+    assert_eq!(
+        detail["results"][0]["previous_fields"],
+        json!({"fixVersions": old_versions()})
+    );
+
+    let restored = [
+        mock_update(&mut server, "UND-1", undo_body(), 1).await,
+        mock_update(&mut server, "UND-2", undo_body(), 1).await,
+    ];
+    let untouched = mock_update(&mut server, "UND-3", mockito::Matcher::Any, 0).await;
+
+    let undo = UndoBulkOperationTool::new(config.clone());
+    let result = undo
+        .handle(json!({"id": id, "config": {"max_retries": 0, "rate_limit_ms": 0}}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        format!(
+            "Undo of {id}: 2 of 3 operations succeeded, 1 skipped\n\
+             • UND-3 skipped: fixVersions changed since {id}; pass force to overwrite"
+        )
+    );
+    for mock in restored {
+        mock.assert_async().await;
+    }
+    untouched.assert_async().await;
+    untouched.remove_async().await;
+
+    // The undo is an operation of its own, which cannot itself be undone
+    let error = undo
+        .handle(json!({"id": history_id(&result)}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Only bulk_update_issues can be undone"));
+}
+
+#[tokio::test]
+async fn test_forced_undo_overwrites_changed_issues() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let id = update_then_change_one(&mut server, &config).await;
+
+    let mut restored = Vec::new();
+    for key in KEYS {
+        restored.push(mock_update(&mut server, key, undo_body(), 1).await);
+    }
+    let result = UndoBulkOperationTool::new(config)
+        .handle(json!({
+            "id": id,
+            "force": true,
+            "config": {"max_retries": 0, "rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        format!("Undo of {id}: 3 of 3 operations succeeded")
+    );
+    for mock in restored {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_issue_whose_values_cannot_be_read_is_not_updated() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let _missing = server
+        .mock("GET", "/rest/api/2/issue/UND-9")
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["Issue does not exist"]}).to_string())
+        .create_async()
+        .await;
+    let not_updated = mock_update(&mut server, "UND-9", mockito::Matcher::Any, 0).await;
+
+    let result = BulkUpdateIssuesTool::new(config)
+        .handle(json!({
+            "issue_keys": ["UND-9"],
+            "update_data": {"fields": {"fixVersions": [{"name": "2.0"}]}},
+            "config": {"max_retries": 0, "rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+    assert!(result.content[0]
+        .text
+        .contains("• UND-9 failed: Not updated; the current values could not be read"));
+    not_updated.assert_async().await;
+}
+
+#[test]
+fn test_still_applied_matches_the_values_jira_returns() {
+    let set = json!([{"name": "2.0"}, {"name": "2.1"}]);
+    assert!(still_applied(
+        &set,
+        &json!([{"id": "21", "name": "2.1"}, {"id": "20", "name": "2.0"}])
+    ));
+    assert!(!still_applied(&set, &json!([{"id": "20", "name": "2.0"}])));
+    assert!(still_applied(&json!("Title"), &json!("Title")));
+    assert!(!still_applied(&json!({"name": "ada"}), &Value::Null));
+    assert!(still_applied(&Value::Null, &Value::Null));
+}
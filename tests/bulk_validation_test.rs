@@ -0,0 +1,114 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::error::{JiraError, JiraErrorKind};
+use rust_jira_mcp::jira::bulk_validation::validate_bulk_operations;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::MixedBulkOperationsTool;
+use rust_jira_mcp::types::jira::BulkOperationType;
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn problems(error: &JiraError) -> Vec<&str> {
+    match error {
+        JiraError::ValidationError { field, message } => {
+            assert_eq!(field, "operations");
+            message.lines().skip(1).collect()
+        }
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_validate_bulk_operations_reads_valid_operations() {
+    let operations = validate_bulk_operations(&[
+        json!({"issue_key": "DNA-1", "operation_type": "update", "data": {"fields": {"summary": "New"}}}),
+        json!({"issue_key": "DNA-2", "operation_type": "Transition", "data": {"transition_id": "31"}}),
+        json!({"issue_key": "DNA-3", "operation_type": "add_comment", "data": {"comment_body": {"type": "doc", "version": 1, "content": []}}}),
+        json!({"issue_key": "DNA-4", "operation_type": "add_watcher", "data": {"account_id": "abc"}}),
+        json!({"issue_key": "DNA-5", "operation_type": "mixed", "data": {"comment_body": "Done"}}),
+    ])
+    .unwrap();
+    assert_eq!(operations.len(), 5);
+    assert!(matches!(
+        operations[1].operation_type,
+        BulkOperationType::Transition
+    ));
+    assert_eq!(operations[4].issue_key, "DNA-5");
+}
+
+#[test]
+fn test_validate_bulk_operations_reports_every_problem() {
+    let error = validate_bulk_operations(&[
+        json!({"issue_key": "DNA-1", "operation_type": "update", "data": {"fields": {"summary": "New"}}}),
+        json!({"operation_type": "add_comment", "data": {"comment_body": "Hi"}}),
+        json!({"issue_key": "DNA-3", "operation_type": "delete", "data": {}}),
+        json!({"issue_key": "DNA-4", "operation_type": "transition", "data": {"comment": "Moving"}}),
+        json!({"issue_key": "", "operation_type": "add_comment", "data": {"comment_body": 7}}),
+        json!({"issue_key": "DNA-6", "operation_type": "mixed", "data": {"labels": ["x"]}}),
+        json!("DNA-7"),
+    ])
+    .unwrap_err();
+    assert_eq!(error.kind(), JiraErrorKind::Validation);
+    assert!(error.to_string().contains("7 problems found"));
+    assert_eq!(
+        problems(&error),
+        [
+            "operation 1: issue_key is required",
+            "operation 2: operation_type \"delete\" is not one of update, transition, add_comment, add_watcher, remove_watcher or mixed",
+            "operation 3: data.transition_id is required",
+            "operation 4: issue_key must be a non-empty string",
+            "operation 4: data.comment_body must be a non-empty string or an ADF document",
+            "operation 5: data needs one of fields, transition_id, comment_body to tell what a mixed operation does",
+            "operation 6: operation must be an object",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_mixed_bulk_operations_reports_all_defects_before_executing() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let any_request = server
+        .mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let any_write = server
+        .mock("POST", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let tool = MixedBulkOperationsTool::new(create_test_config(&base_url));
+    let error = tool
+        .handle(json!({"operations": [
+            {"issue_key": "DNA-1", "operation_type": "add_comment", "data": {"comment_body": "Fine"}},
+            {"operation_type": "update", "data": {"fields": {"summary": "No key"}}},
+            {"issue_key": "DNA-3", "operation_type": "close", "data": {}},
+            {"issue_key": "DNA-4", "operation_type": "transition", "data": {}}
+        ]}))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        problems(&error),
+        [
+            "operation 1: issue_key is required",
+            "operation 2: operation_type \"close\" is not one of update, transition, add_comment, add_watcher, remove_watcher or mixed",
+            "operation 3: data.transition_id is required",
+        ]
+    );
+    any_request.assert_async().await;
+    any_write.assert_async().await;
+}
@@ -0,0 +1,109 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::identity::{CLIENT_NAME, CLIENT_VERSION};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn identified(mock: mockito::Mock, user_agent: &str) -> mockito::Mock {
+    mock.match_header("User-Agent", user_agent)
+        .match_header("X-Client-Name", "rust-jira-mcp")
+        .match_header("X-Client-Version", CLIENT_VERSION)
+}
+
+fn default_user_agent() -> String {
+    format!("rust-jira-mcp/{}", env!("CARGO_PKG_VERSION"))
+}
+
+#[test]
+fn test_user_agent_defaults_to_the_package() {
+    assert_eq!(CLIENT_NAME, "rust-jira-mcp");
+    assert_eq!(JiraConfig::default().user_agent(), default_user_agent());
+    let blank = JiraConfig {
+        user_agent: Some("  ".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(blank.user_agent(), default_user_agent());
+}
+
+#[tokio::test]
+async fn test_json_and_raw_requests_identify_the_tool() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let issue = identified(
+        server.mock("GET", "/rest/api/2/issue/ID-1"),
+        &default_user_agent(),
+    )
+    .with_status(200)
+    .with_header("content-type", "application/json")
+    .with_body(json!({"id": "1", "key": "ID-1", "self": "x", "fields": {}}).to_string())
+    .expect(1)
+    .create_async()
+    .await;
+    let content = identified(
+        server.mock("GET", "/rest/api/2/attachment/7"),
+        &default_user_agent(),
+    )
+    .with_status(200)
+    .with_body("bytes")
+    .expect(1)
+    .create_async()
+    .await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    client.get_issue("ID-1").await.unwrap();
+    assert_eq!(client.download_attachment("7").await.unwrap(), b"bytes");
+    issue.assert_async().await;
+    content.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_multipart_and_zephyr_requests_identify_the_tool() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let upload = identified(
+        server.mock("POST", "/rest/api/2/issue/ID-2/attachments"),
+        "ops-audit/2.0",
+    )
+    .with_status(200)
+    .with_header("content-type", "application/json")
+    .with_body("[]")
+    .expect(1)
+    .create_async()
+    .await;
+    let steps = identified(
+        server.mock("GET", "/rest/zapi/latest/teststep/5"),
+        "ops-audit/2.0",
+    )
+    .with_status(200)
+    .with_header("content-type", "application/json")
+    .with_body("{}")
+    .expect(1)
+    .create_async()
+    .await;
+
+    let config = JiraConfig {
+        user_agent: Some("ops-audit/2.0".to_string()),
+        ..create_test_config(&base_url)
+    };
+    let client = JiraClient::new(config).unwrap();
+    client
+        .upload_attachment("ID-2", "notes.txt", b"notes", Some("text/plain"))
+        .await
+        .unwrap();
+    let _: Value = client.zephyr_get("teststep/5").await.unwrap();
+    upload.assert_async().await;
+    steps.assert_async().await;
+}
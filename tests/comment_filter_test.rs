@@ -0,0 +1,197 @@
+use chrono::{Duration, TimeZone, Utc};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{CommentFilter, GetCommentsTool};
+use rust_jira_mcp::utils::time::relative_time;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        ..Default::default()
+    }
+}
+
+fn comment(id: &str, author: &str, created: &str) -> Value {
+    json!({
+        "id": id,
+        "body": format!("Comment {id}"),
+        "author": {
+            "self": "x",
+            "name": author,
+            "key": author,
+            "accountId": format!("acc-{author}"),
+            "displayName": format!("{} Example", author.to_uppercase()),
+            "emailAddress": format!("{author}@example.com"),
+            "active": true
+        },
+        "created": created
+    })
+}
+
+async fn mock_page(
+    server: &mut mockito::ServerGuard,
+    start_at: &str,
+    comments: Vec<Value>,
+) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/2/issue/THREAD-1/comment")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("startAt".to_string(), start_at.to_string()),
+            mockito::Matcher::UrlEncoded("orderBy".to_string(), "created".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"startAt": start_at.parse::<u32>().unwrap(), "maxResults": 2, "total": 3, "comments": comments})
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[test]
+fn test_relative_time() {
+    let now = Utc.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+    let ago = |duration: Duration| relative_time(&(now - duration), now);
+
+    assert_eq!(ago(Duration::seconds(30)), "just now");
+    assert_eq!(ago(Duration::minutes(1)), "1 minute ago");
+    assert_eq!(ago(Duration::minutes(59)), "59 minutes ago");
+    assert_eq!(ago(Duration::hours(5)), "5 hours ago");
+    assert_eq!(ago(Duration::days(2)), "2 days ago");
+    assert_eq!(ago(Duration::days(65)), "2 months ago");
+    assert_eq!(ago(Duration::days(800)), "2 years ago");
+    assert_eq!(ago(Duration::hours(-3)), "in 3 hours");
+}
+
+#[test]
+fn test_comment_filter() {
+    let filter =
+        CommentFilter::from_args(&json!({"since": "2024-03-04", "author": "ALICE"})).unwrap();
+    assert_eq!(
+        filter.since,
+        Some(Utc.with_ymd_and_hms(2024, 3, 4, 0, 0, 0).unwrap())
+    );
+
+    assert!(filter.matches(&comment("1", "alice", "2024-03-04T00:00:00.000+0000")));
+    assert!(!filter.matches(&comment("2", "alice", "2024-03-03T23:59:59.000+0000")));
+    assert!(!filter.matches(&comment("3", "bob", "2024-03-05T10:00:00.000+0000")));
+    // Times in other zones are compared as instants
+    assert!(!filter.matches(&comment("4", "alice", "2024-03-04T01:00:00.000+0200")));
+
+    for author in ["acc-alice", "alice@example.com", "Alice Example"] {
+        let filter = CommentFilter::from_args(&json!({ "author": author })).unwrap();
+        assert!(filter.matches(&comment("1", "alice", "2024-03-04T00:00:00.000+0000")));
+    }
+
+    let timestamp =
+        CommentFilter::from_args(&json!({"since": "2024-03-04T09:30:00+01:00"})).unwrap();
+    assert_eq!(
+        timestamp.since,
+        Some(Utc.with_ymd_and_hms(2024, 3, 4, 8, 30, 0).unwrap())
+    );
+
+    let error = CommentFilter::from_args(&json!({"since": "last monday"})).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("'last monday' is not a YYYY-MM-DD date"));
+}
+
+#[tokio::test]
+async fn test_get_comments_fetches_every_page_and_filters() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let recent = Utc::now() - Duration::days(2);
+    let first = mock_page(
+        &mut server,
+        "0",
+        vec![
+            comment("10", "alice", "2024-03-01T09:00:00.000+0000"),
+            comment("11", "bob", &recent.to_rfc3339()),
+        ],
+    )
+    .await;
+    let second = mock_page(
+        &mut server,
+        "2",
+        vec![comment("12", "alice", &recent.to_rfc3339())],
+    )
+    .await;
+
+    let tool = GetCommentsTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "THREAD-1", "author": "alice", "since": "2024-03-02"}))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        "Found 1 of 3 comments for issue THREAD-1 by alice since 2024-03-02T00:00:00+00:00\n\n"
+    );
+    assert_eq!(result.content.len(), 2);
+    assert_eq!(
+        result.content[1].text,
+        format!(
+            "• 12 by ALICE Example (acc-alice) on {} (2 days ago)\nComment 12\n",
+            recent.format("%Y-%m-%d %H:%M +00:00")
+        )
+    );
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_comments_json_mode_returns_raw_comments() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _first = mock_page(
+        &mut server,
+        "0",
+        vec![
+            comment("10", "alice", "2024-03-01T09:00:00.000+0000"),
+            comment("11", "bob", "2024-03-02T09:00:00.000+0000"),
+        ],
+    )
+    .await;
+    let _second = mock_page(
+        &mut server,
+        "2",
+        vec![comment("12", "bob", "2024-03-03T09:00:00.000+0000")],
+    )
+    .await;
+
+    let tool = GetCommentsTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "THREAD-1", "author": "bob", "format": "json"}))
+        .await
+        .unwrap();
+
+    let comments: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(
+        comments,
+        json!([
+            comment("11", "bob", "2024-03-02T09:00:00.000+0000"),
+            comment("12", "bob", "2024-03-03T09:00:00.000+0000")
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_get_comments_rejects_unknown_format() {
+    let tool = GetCommentsTool::new(create_test_config("http://localhost:1"));
+    let error = tool
+        .handle(json!({"issue_key": "THREAD-1", "format": "xml"}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Unknown format 'xml'"));
+}
@@ -0,0 +1,195 @@
+mod common;
+
+use common::mock_project_type;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{AddCommentTool, GetCommentsTool, UpdateCommentTool};
+use rust_jira_mcp::types::jira::JiraCommentVisibility;
+use serde_json::json;
+
+const RESTRICTED_COMMENTS: &str = include_str!("fixtures/restricted_comments.json");
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        ..Default::default()
+    }
+}
+
+fn comment_response(id: &str) -> String {
+    json!({
+        "id": id,
+        "body": "Restricted",
+        "author": {
+            "self": "https://jira.example.com/rest/api/2/user?username=legal",
+            "name": "legal",
+            "key": "legal",
+            "displayName": "Legal Team",
+            "active": true
+        },
+        "created": "2024-03-02T14:30:00.000+0000",
+        "visibility": {"type": "role", "value": "Legal"}
+    })
+    .to_string()
+}
+
+#[test]
+fn test_comment_payload_with_visibility() {
+    let visibility = JiraCommentVisibility::new("Role", "Legal").unwrap();
+    let payload = JiraClient::comment_payload("Terms attached", Some(&visibility)).unwrap();
+
+    assert_eq!(
+        payload,
+        json!({
+            "body": "Terms attached",
+            "visibility": {"type": "role", "value": "Legal"}
+        })
+    );
+    assert_eq!(
+        JiraClient::comment_payload("Public", None).unwrap(),
+        json!({"body": "Public"})
+    );
+}
+
+#[test]
+fn test_visibility_rejects_unknown_type() {
+    let error = JiraCommentVisibility::new("user", "alice")
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Unknown visibility type 'user'"));
+
+    assert!(JiraCommentVisibility::new("group", " ").is_err());
+    assert!(JiraCommentVisibility::from_arg(Some(&json!({"type": "role"}))).is_err());
+    assert!(JiraCommentVisibility::from_arg(None).unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_add_comment_tool_sends_visibility() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _project = mock_project_type(&mut server, "/rest/api/2", "LEGAL", "software").await;
+
+    let mock = server
+        .mock("POST", "/rest/api/2/issue/LEGAL-1/comment")
+        .match_body(mockito::Matcher::Json(json!({
+            "body": "Terms attached",
+            "visibility": {"type": "role", "value": "Legal"}
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(comment_response("10101"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = AddCommentTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_key": "LEGAL-1",
+            "comment": "Terms attached",
+            "visibility": {"type": "role", "value": "Legal"}
+        }))
+        .await
+        .unwrap();
+
+    assert!(result.content[0]
+        .text
+        .contains("Visibility: restricted to role Legal"));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_invalid_visibility_rejected_before_request() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let mock = server
+        .mock("PUT", "/rest/api/2/issue/LEGAL-1/comment/10101")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let tool = UpdateCommentTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_key": "LEGAL-1",
+            "comment_id": "10101",
+            "comment": "Updated",
+            "visibility": {"type": "project", "value": "Legal"}
+        }))
+        .await;
+
+    assert!(result.is_err());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_update_comment_tool_sends_visibility() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let mock = server
+        .mock("PUT", "/rest/api/2/issue/LEGAL-1/comment/10101")
+        .match_body(mockito::Matcher::Json(json!({
+            "body": "Updated terms",
+            "visibility": {"type": "group", "value": "legal-team"}
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(comment_response("10101"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = UpdateCommentTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_key": "LEGAL-1",
+            "comment_id": "10101",
+            "comment": "Updated terms",
+            "visibility": {"type": "group", "value": "legal-team"}
+        }))
+        .await
+        .unwrap();
+
+    assert!(result.content[0].text.contains("Comment 10101 updated"));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_comments_renders_restricted_marker() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _mock = server
+        .mock("GET", "/rest/api/2/issue/LEGAL-1/comment")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(RESTRICTED_COMMENTS)
+        .create_async()
+        .await;
+
+    let tool = GetCommentsTool::new(create_test_config(&base_url));
+    let result = tool.handle(json!({"issue_key": "LEGAL-1"})).await.unwrap();
+
+    assert!(result.content[1]
+        .text
+        .starts_with("• 10100 by Alice Example (alice) on 2024-03-01 09:00 +00:00 ("));
+    assert!(result.content[1].text.contains(" ago)\n"));
+    assert!(result.content[2]
+        .text
+        .starts_with("• 10101 by Legal Team (legal) on 2024-03-02 14:30 +00:00 ("));
+    assert!(result.content[2]
+        .text
+        .contains(" ago) [restricted to role Legal]\n"));
+}
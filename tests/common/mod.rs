@@ -0,0 +1,21 @@
+//! Helpers shared by the integration tests
+
+/// Mock `GET {api_path}/project/{project_key}` on `server` as a project of
+/// `project_type` (`software`, `service_desk`), which decides whether
+/// comments written to its issues are marked internal
+pub async fn mock_project_type(
+    server: &mut mockito::Server,
+    api_path: &str,
+    project_key: &str,
+    project_type: &str,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("{api_path}/project/{project_key}").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({"key": project_key, "projectTypeKey": project_type}).to_string(),
+        )
+        .create_async()
+        .await
+}
@@ -0,0 +1,208 @@
+use rust_jira_mcp::config::manager::ConfigSource;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::doctor::{
+    check_authentication, check_base_url, check_project_permission, check_sources,
+    run_config_checks, CheckStatus,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::ValidateConfigurationTool;
+use serde_json::{json, Value};
+
+fn create_test_config(api_base_url: &str, token: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: api_base_url.to_string(),
+        email: "doctor@example.com".to_string(),
+        personal_access_token: token.to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_base_url_shape() {
+    let status = |url: &str| {
+        let check = check_base_url(&create_test_config(url, "test-token-123"));
+        (check.status, check.detail, check.hint.unwrap_or_default())
+    };
+
+    let (result, _, hint) = status("https://jira.example.com/jira");
+    assert_eq!(result, CheckStatus::Fail);
+    assert_eq!(
+        hint,
+        "Use https://jira.example.com/jira/rest/api/2, keeping any context path such as /jira"
+    );
+
+    let (result, detail, _) = status("https://jira.example.com/rest/api/latest");
+    assert_eq!(result, CheckStatus::Fail);
+    assert!(detail.contains("version 'latest'"), "{detail}");
+
+    let (result, detail, _) = status("jira.example.com/rest/api/2");
+    assert_eq!(result, CheckStatus::Fail);
+    assert!(detail.contains("not an absolute http(s) URL"), "{detail}");
+
+    assert_eq!(
+        status("http://localhost:8080/rest/api/2").0,
+        CheckStatus::Warn
+    );
+    let (result, detail, _) = status("https://site.atlassian.net/rest/api/3/");
+    assert_eq!(result, CheckStatus::Pass);
+    assert_eq!(detail, "REST API v3 at site.atlassian.net");
+}
+
+#[test]
+fn test_sources_are_listed_in_precedence_order() {
+    let check = check_sources(&[
+        ConfigSource::Environment,
+        ConfigSource::Toml("config/local.toml".into()),
+        ConfigSource::Default,
+    ]);
+    assert_eq!(check.status, CheckStatus::Pass);
+    assert_eq!(
+        check.detail,
+        "environment variables, TOML file config/local.toml, defaults"
+    );
+}
+
+#[tokio::test]
+async fn test_refused_credentials_hint_at_the_auth_scheme() {
+    let mut server = mockito::Server::new_async().await;
+    let _myself = server
+        .mock("GET", "/refused/rest/api/2/myself")
+        .with_status(401)
+        .with_body("Unauthorized")
+        .create_async()
+        .await;
+    let api = format!("{}/refused/rest/api/2", server.url());
+
+    // A long token without a colon is sent as a Bearer token
+    let bearer = JiraClient::new(create_test_config(&api, "a-long-personal-access-token")).unwrap();
+    let check = check_authentication(&bearer).await;
+    assert_eq!(check.status, CheckStatus::Fail);
+    assert!(
+        check.detail.starts_with("Bearer token auth was refused"),
+        "{}",
+        check.detail
+    );
+    assert!(
+        check
+            .hint
+            .as_deref()
+            .unwrap()
+            .contains("Bearer personal access token"),
+        "{check:?}"
+    );
+
+    let basic = JiraClient::new(create_test_config(&api, "short-tok")).unwrap();
+    let check = check_authentication(&basic).await;
+    assert_eq!(check.status, CheckStatus::Fail);
+    assert!(check
+        .hint
+        .unwrap()
+        .contains("with JIRA_EMAIL as Basic credentials"));
+}
+
+#[tokio::test]
+async fn test_default_project_needs_browse_permission() {
+    let mut server = mockito::Server::new_async().await;
+    let _permissions = server
+        .mock("GET", "/perm/rest/api/2/mypermissions")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("projectKey".into(), "SECRET".into()),
+            mockito::Matcher::UrlEncoded("permissions".into(), "BROWSE_PROJECTS".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"permissions": {"BROWSE_PROJECTS": {"havePermission": false}}}).to_string(),
+        )
+        .create_async()
+        .await;
+    let api = format!("{}/perm/rest/api/2", server.url());
+    let client = JiraClient::new(create_test_config(&api, "test-token-123")).unwrap();
+
+    let check = check_project_permission(&client, "SECRET").await;
+    assert_eq!(check.status, CheckStatus::Fail);
+    assert_eq!(check.detail, "you cannot browse project SECRET");
+}
+
+#[tokio::test]
+async fn test_every_check_runs_against_a_healthy_instance() {
+    let mut server = mockito::Server::new_async().await;
+    let mut mocks = Vec::new();
+    for (path, body) in [
+        ("/healthy", json!({})),
+        (
+            "/healthy/rest/api/2/myself",
+            json!({"displayName": "Doc Tor"}),
+        ),
+        (
+            "/healthy/rest/api/2/serverInfo",
+            json!({"version": "9.12.0", "deploymentType": "Server"}),
+        ),
+        ("/healthy/rest/zapi/latest/moduleInfo", json!({})),
+        (
+            "/healthy/rest/api/2/mypermissions",
+            json!({"permissions": {"BROWSE_PROJECTS": {"havePermission": true}}}),
+        ),
+    ] {
+        mocks.push(
+            server
+                .mock("GET", path)
+                .match_query(mockito::Matcher::Any)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(body.to_string())
+                .create_async()
+                .await,
+        );
+    }
+    let api = format!("{}/healthy/rest/api/2", server.url());
+    let config = JiraConfig {
+        default_project: Some("DOC".to_string()),
+        ..create_test_config(&api, "test-token-123")
+    };
+
+    let report = run_config_checks(&config, &[ConfigSource::Default]).await;
+    let names: Vec<&str> = report.checks.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "sources",
+            "settings",
+            "base URL",
+            "reachability",
+            "authentication",
+            "API version",
+            "Zephyr",
+            "permissions"
+        ]
+    );
+    // Plain http to the mock server is the only warning
+    assert_eq!(report.count(CheckStatus::Warn), 1, "{}", report.render());
+    assert!(!report.has_failures());
+    let text = report.render();
+    assert!(text.starts_with("Configuration check: 7 passed, 1 warnings, 0 failed\n"));
+    assert!(text.contains("[PASS] authentication: authenticated as Doc Tor (Basic auth)\n"));
+    assert!(text.contains("[PASS] API version: Jira 9.12.0 (Server) answers REST API v2\n"));
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_unreachable_site_stops_after_the_reachability_check() {
+    let config = create_test_config("http://127.0.0.1:1/rest/api/2", "test-token-123");
+    let result = ValidateConfigurationTool::new(config)
+        .handle(json!({}))
+        .await
+        .unwrap();
+
+    let report: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    let checks = report["checks"].as_array().unwrap();
+    let last = checks.last().unwrap();
+    assert_eq!(last["name"], "reachability");
+    assert_eq!(last["status"], "fail");
+    assert!(last["hint"].as_str().is_some());
+    assert!(result.content[0].text.contains("[FAIL] reachability: "));
+}
@@ -29,6 +29,7 @@ fn test_jira_config_serialization() {
         timeout_seconds: Some(60),
         log_file: Some(PathBuf::from("/tmp/test.log")),
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     let serialized = serde_json::to_string(&config).unwrap();
@@ -58,6 +59,7 @@ fn test_jira_config_clone() {
         timeout_seconds: Some(60),
         log_file: Some(PathBuf::from("/tmp/test.log")),
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     let cloned = config.clone();
@@ -95,6 +97,7 @@ fn test_auth_header_bearer_token() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let auth_header = config.auth_header();
@@ -113,6 +116,7 @@ fn test_auth_header_basic_token() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let auth_header = config.auth_header();
@@ -134,6 +138,7 @@ fn test_auth_header_with_colon_token() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let auth_header = config.auth_header();
@@ -154,6 +159,7 @@ fn test_auth_header_with_empty_token() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let auth_header = config.auth_header();
@@ -172,6 +178,7 @@ fn test_auth_header_short_token() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let auth_header = config.auth_header();
@@ -189,6 +196,7 @@ fn test_timeout_duration_default() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let duration = config.timeout_duration();
@@ -206,6 +214,7 @@ fn test_timeout_duration_custom() {
         timeout_seconds: Some(120),
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let duration = config.timeout_duration();
@@ -223,6 +232,7 @@ fn test_validate_success() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -240,6 +250,7 @@ fn test_validate_invalid_email() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -258,6 +269,7 @@ fn test_validate_empty_email() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -276,6 +288,7 @@ fn test_validate_short_token() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -297,6 +310,7 @@ fn test_validate_invalid_url() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -315,6 +329,7 @@ fn test_validate_http_url() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -332,6 +347,7 @@ fn test_validate_https_url() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -349,6 +365,7 @@ fn test_validate_email_with_at_start() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -366,6 +383,7 @@ fn test_validate_email_with_at_end() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -383,6 +401,7 @@ fn test_validate_email_without_dot() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -400,6 +419,7 @@ fn test_validate_email_without_at() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let result = config.validate();
@@ -15,6 +15,7 @@ fn _test_config() -> JiraConfig {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     }
 }
 
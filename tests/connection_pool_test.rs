@@ -0,0 +1,103 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::performance::get_global_metrics;
+use serde_json::json;
+use std::time::Duration;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: base_url.to_string(),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_pool_config_defaults() {
+    let config = JiraConfig::default();
+
+    assert_eq!(config.pool_max_idle_per_host, None);
+    assert_eq!(config.pool_idle_timeout(), Duration::from_secs(90));
+    assert_eq!(config.tcp_keepalive(), Some(Duration::from_secs(60)));
+    assert_eq!(config.http2_prior_knowledge, Some(false));
+    assert!(config.connection_pooling_enabled());
+}
+
+#[test]
+fn test_pool_config_overrides() {
+    let config = JiraConfig {
+        pool_max_idle_per_host: Some(0),
+        pool_idle_timeout_seconds: Some(15),
+        tcp_keepalive_seconds: Some(0),
+        http2_prior_knowledge: Some(true),
+        ..create_test_config("https://test.example.com/rest/api/2")
+    };
+
+    assert!(!config.connection_pooling_enabled());
+    assert_eq!(config.pool_idle_timeout(), Duration::from_secs(15));
+    assert_eq!(config.tcp_keepalive(), None);
+    assert!(JiraClient::new(config).is_ok());
+}
+
+#[tokio::test]
+async fn test_connection_reuse_metrics() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let mock_response = json!({
+        "id": "1",
+        "key": "TEST-1",
+        "self": format!("{base_url}/rest/api/2/issue/1"),
+        "fields": {}
+    });
+
+    let _mock = server
+        .mock("GET", "/rest/api/2/issue/TEST-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response.to_string())
+        .expect(7)
+        .create_async()
+        .await;
+
+    let metrics = get_global_metrics();
+
+    // Pooled client: only the first request should open a connection
+    let client = JiraClient::new(create_test_config(&format!("{base_url}/rest/api/2"))).unwrap();
+    let before = metrics.get_stats();
+    for _ in 0..3 {
+        client.get_issue("TEST-1").await.unwrap();
+    }
+    let after = metrics.get_stats();
+    assert_eq!(after.new_connections - before.new_connections, 1);
+    assert_eq!(after.reused_connections - before.reused_connections, 2);
+
+    // Another client with the same settings shares the pool
+    let other = JiraClient::new(create_test_config(&format!("{base_url}/rest/api/2"))).unwrap();
+    let before = metrics.get_stats();
+    other.get_issue("TEST-1").await.unwrap();
+    let after = metrics.get_stats();
+    assert_eq!(after.new_connections, before.new_connections);
+    assert_eq!(after.reused_connections - before.reused_connections, 1);
+
+    // Pooling disabled: every request opens a new connection
+    let config = JiraConfig {
+        pool_max_idle_per_host: Some(0),
+        ..create_test_config(&format!("{base_url}/rest/api/2"))
+    };
+    let client = JiraClient::new(config).unwrap();
+    let before = metrics.get_stats();
+    for _ in 0..3 {
+        client.get_issue("TEST-1").await.unwrap();
+    }
+    let after = metrics.get_stats();
+    assert_eq!(after.new_connections - before.new_connections, 3);
+    assert_eq!(after.reused_connections, before.reused_connections);
+    assert!(after.connection_reuse_rate > 0.0);
+}
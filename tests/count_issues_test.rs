@@ -0,0 +1,120 @@
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::CountIssuesTool;
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+/// A search for `jql` that asks for no issue bodies and answers `total`
+async fn mock_count(server: &mut mockito::ServerGuard, jql: &str, total: u64) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("jql".to_string(), jql.to_string()),
+            Matcher::UrlEncoded("maxResults".to_string(), "0".to_string()),
+            Matcher::UrlEncoded("fields".to_string(), "id".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"startAt": 0, "maxResults": 0, "total": total, "issues": []}).to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_count_issues_requests_no_issues() {
+    let mut server = mockito::Server::new_async().await;
+    let jql = "project = CNT AND type = Bug AND resolution is EMPTY";
+    let mock = mock_count(&mut server, jql, 42).await;
+
+    let client = JiraClient::new(create_test_config(&server.url())).unwrap();
+    assert_eq!(client.count_issues(jql).await.unwrap(), 42);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_count_tool_with_one_query() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = mock_count(&mut server, "project = CNT", 7).await;
+
+    let result = CountIssuesTool::new(create_test_config(&server.url()))
+        .handle(json!({"jql": "project = CNT"}))
+        .await
+        .unwrap();
+
+    assert_eq!(result.content[0].text, "7 issues match: project = CNT");
+    assert_eq!(result.is_error, Some(false));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_count_tool_with_several_queries() {
+    let mut server = mockito::Server::new_async().await;
+    let mocks = vec![
+        mock_count(&mut server, "project = CNT AND type = Bug", 12).await,
+        mock_count(&mut server, "project = CNT AND type = Story", 30).await,
+    ];
+    let _broken = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(Matcher::UrlEncoded(
+            "jql".to_string(),
+            "project = CNT AND nonsense".to_string(),
+        ))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["Error in the JQL Query"]}).to_string())
+        .create_async()
+        .await;
+
+    let result = CountIssuesTool::new(create_test_config(&server.url()))
+        .handle(json!({"jql": [
+            "project = CNT AND type = Bug",
+            "project = CNT AND nonsense",
+            "project = CNT AND type = Story"
+        ]}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Issue counts for 3 queries:\n"), "{text}");
+    // Counts are listed in the order the queries were given
+    let bug = text.find("• 12 — project = CNT AND type = Bug").unwrap();
+    let failed = text
+        .find("• failed (Jira API error: Error in the JQL Query) — project = CNT AND nonsense")
+        .unwrap();
+    let story = text.find("• 30 — project = CNT AND type = Story").unwrap();
+    assert!(bug < failed && failed < story, "{text}");
+    assert_eq!(result.is_error, Some(false));
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_count_tool_rejects_bad_input() {
+    let tool = CountIssuesTool::new(create_test_config("http://localhost:1"));
+    for (args, message) in [
+        (json!({}), "Missing required parameter: jql"),
+        (json!({"jql": []}), "Give at least one query"),
+        (json!({"jql": ["project = A", " "]}), "no empty ones"),
+        (json!({"jql": ["project = A", 5]}), "must be a JQL string"),
+        (
+            json!({"jql": vec!["project = A"; 51]}),
+            "51 queries given; count at most 50 per call",
+        ),
+    ] {
+        let error = tool.handle(args).await.unwrap_err().to_string();
+        assert!(error.contains(message), "{error}");
+    }
+}
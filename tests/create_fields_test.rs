@@ -0,0 +1,252 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::error::JiraError;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{CreateIssueTool, ListCreatableFieldsTool};
+use rust_jira_mcp::types::jira::JiraCreateMeta;
+use serde_json::json;
+
+// Each test uses its own project key, as create-meta lives in a global cache
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn issue_types_json() -> serde_json::Value {
+    json!({
+        "maxResults": 200,
+        "startAt": 0,
+        "total": 2,
+        "isLast": true,
+        "values": [
+            {"self": "x", "id": "1", "name": "Bug", "subtask": false},
+            {"self": "x", "id": "3", "name": "Task", "subtask": false}
+        ]
+    })
+}
+
+fn bug_fields_json() -> serde_json::Value {
+    let versions: Vec<serde_json::Value> = (1..=12)
+        .map(|n| json!({"id": format!("{}", 100 + n), "name": format!("1.{n}")}))
+        .collect();
+    json!({
+        "isLast": true,
+        "values": [
+            {
+                "fieldId": "summary",
+                "name": "Summary",
+                "required": true,
+                "schema": {"type": "string", "system": "summary"},
+                "hasDefaultValue": false
+            },
+            {
+                "fieldId": "priority",
+                "name": "Priority",
+                "required": false,
+                "schema": {"type": "priority", "system": "priority"},
+                "hasDefaultValue": true,
+                "defaultValue": {"id": "3", "name": "Major"},
+                "allowedValues": [
+                    {"id": "1", "name": "Blocker"},
+                    {"id": "3", "name": "Major"}
+                ]
+            },
+            {
+                "fieldId": "versions",
+                "name": "Affects Version/s",
+                "required": false,
+                "schema": {"type": "array", "items": "version"},
+                "hasDefaultValue": false,
+                "allowedValues": versions
+            },
+            {
+                "fieldId": "customfield_10100",
+                "name": "Severity",
+                "required": true,
+                "schema": {"type": "option", "custom": "select"},
+                "hasDefaultValue": false,
+                "allowedValues": [{"id": "1", "value": "S1"}, {"id": "2", "value": "S2"}]
+            }
+        ]
+    })
+}
+
+async fn mock_create_meta(server: &mut mockito::ServerGuard, project: &str) {
+    server
+        .mock(
+            "GET",
+            format!("/rest/api/2/issue/createmeta/{project}/issuetypes").as_str(),
+        )
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_types_json().to_string())
+        .create_async()
+        .await;
+    server
+        .mock(
+            "GET",
+            format!("/rest/api/2/issue/createmeta/{project}/issuetypes/1").as_str(),
+        )
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(bug_fields_json().to_string())
+        .create_async()
+        .await;
+}
+
+fn bug_meta() -> JiraCreateMeta {
+    JiraCreateMeta {
+        project_key: "CF".to_string(),
+        issue_type_id: "1".to_string(),
+        issue_type_name: "Bug".to_string(),
+        fields: serde_json::from_value(bug_fields_json()["values"].clone()).unwrap(),
+    }
+}
+
+#[test]
+fn test_create_screen_problems() {
+    let meta = bug_meta();
+    let fields = json!({
+        "project": {"key": "CF"},
+        "issuetype": {"name": "Bug"},
+        "summary": "Crash",
+        "customfield_20000": "x"
+    });
+    let problems = JiraClient::create_screen_problems(&meta, fields.as_object().unwrap());
+    assert_eq!(
+        problems,
+        vec![
+            "Not on the create screen of Bug in CF: customfield_20000".to_string(),
+            "Required on the create screen of Bug in CF but not set: Severity (customfield_10100)"
+                .to_string(),
+        ]
+    );
+
+    let fields = json!({"summary": "Crash", "customfield_10100": {"value": "S1"}});
+    assert!(JiraClient::create_screen_problems(&meta, fields.as_object().unwrap()).is_empty());
+}
+
+#[tokio::test]
+async fn test_list_creatable_fields() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    mock_create_meta(&mut server, "CFLIST").await;
+
+    let tool = ListCreatableFieldsTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"project_key": "CFLIST", "issue_type": "bug"}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Create screen of Bug (id 1) in project CFLIST has 4 fields:\n\n"));
+    assert!(text.contains("• Summary (summary) - required, string\n"));
+    assert!(text.contains("• Severity (customfield_10100) - required, option; allowed: S1, S2\n"));
+    assert!(text.contains(
+        "• Priority (priority) - optional, priority; default: Major; allowed: Blocker, Major\n"
+    ));
+    assert!(text.contains("- optional, array of version; allowed: 1.1, 1.2,"));
+    assert!(text.contains("1.10, … and 2 more\n"));
+    // Required fields come first
+    assert!(text.find("Severity").unwrap() < text.find("Priority").unwrap());
+}
+
+#[tokio::test]
+async fn test_create_meta_is_cached_until_refresh() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let issue_types = server
+        .mock("GET", "/rest/api/2/issue/createmeta/CFCACHE/issuetypes")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_types_json().to_string())
+        .expect(2)
+        .create_async()
+        .await;
+    let fields = server
+        .mock("GET", "/rest/api/2/issue/createmeta/CFCACHE/issuetypes/3")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"values": []}).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    client.get_create_meta("CFCACHE", "3", false).await.unwrap();
+    client
+        .get_create_meta("CFCACHE", "Task", false)
+        .await
+        .unwrap();
+    let meta = client
+        .get_create_meta("CFCACHE", "Task", true)
+        .await
+        .unwrap();
+    assert_eq!(meta.issue_type_name, "Task");
+
+    issue_types.assert_async().await;
+    fields.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_unknown_issue_type_lists_creatable_types() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    mock_create_meta(&mut server, "CFTYPE").await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    let error = client
+        .get_create_meta("CFTYPE", "Epic", false)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, JiraError::ValidationError { .. }));
+    assert!(error.to_string().contains(
+        "Issue type 'Epic' cannot be created in project CFTYPE. Creatable issue types: Bug, Task"
+    ));
+}
+
+#[tokio::test]
+async fn test_rejected_create_explains_create_screen() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    mock_create_meta(&mut server, "CFCREATE").await;
+    let _create = server
+        .mock("POST", "/rest/api/2/issue")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errors": {"customfield_10100": "Severity is required."}}).to_string())
+        .create_async()
+        .await;
+
+    let tool = CreateIssueTool::new(create_test_config(&base_url));
+    let error = tool
+        .handle(json!({
+            "project_key": "CFCREATE",
+            "issue_type": "Bug",
+            "summary": "Crash"
+        }))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, JiraError::ValidationError { .. }));
+    let message = error.to_string();
+    assert!(message.contains("customfield_10100: \"Severity is required.\""));
+    assert!(message.contains("Not on the create screen of Bug in CFCREATE: description"));
+    assert!(message.contains(
+        "Required on the create screen of Bug in CFCREATE but not set: Severity (customfield_10100)"
+    ));
+    assert!(message.contains("list_creatable_fields"));
+}
@@ -0,0 +1,171 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::utils::datetime::{
+    is_valid_date_format, parse_timestamp, DateFormatter, DisplayTimezone, DEFAULT_DATE_FORMAT,
+};
+use serde_json::json;
+
+fn utc() -> DisplayTimezone {
+    DisplayTimezone::parse("UTC").unwrap()
+}
+
+#[test]
+fn test_parse_timestamp_variants() {
+    let instant = parse_timestamp("2024-03-04T09:00:00.000Z").unwrap();
+    for value in [
+        "2024-03-04T09:00:00.000+0000",
+        "2024-03-04T09:00:00.000+00:00",
+        "2024-03-04T09:00:00Z",
+        "2024-03-04T09:00:00+0000",
+        "2024-03-04T10:00:00.000+0100",
+        "2024-03-04T04:30:00.000-04:30",
+        "2024-03-04T09:00:00.000",
+        " 2024-03-04T09:00:00.000+0000 ",
+    ] {
+        assert_eq!(parse_timestamp(value), Some(instant), "{value}");
+    }
+
+    // The offset Jira sent is kept
+    let offset = parse_timestamp("2024-03-04T10:00:00.000+0100").unwrap();
+    assert_eq!(offset.offset().local_minus_utc(), 3_600);
+
+    // Milliseconds survive
+    let millis = parse_timestamp("2024-03-04T09:00:00.123+0000").unwrap();
+    assert_eq!(millis.timestamp_subsec_millis(), 123);
+
+    assert!(parse_timestamp("04/03/2024 09:00").is_none());
+    assert!(parse_timestamp("Today 2:10 PM").is_none());
+    assert!(parse_timestamp("").is_none());
+}
+
+#[test]
+fn test_display_timezone_parse() {
+    let offset = |seconds: i32| {
+        Some(DisplayTimezone::Fixed(
+            chrono::FixedOffset::east_opt(seconds).unwrap(),
+        ))
+    };
+    assert_eq!(
+        DisplayTimezone::parse("local"),
+        Some(DisplayTimezone::Local)
+    );
+    assert_eq!(
+        DisplayTimezone::parse("LOCAL"),
+        Some(DisplayTimezone::Local)
+    );
+    assert_eq!(DisplayTimezone::parse("UTC"), offset(0));
+    assert_eq!(DisplayTimezone::parse("Z"), offset(0));
+    assert_eq!(DisplayTimezone::parse("+02:00"), offset(7_200));
+    assert_eq!(DisplayTimezone::parse("-0530"), offset(-19_800));
+    assert_eq!(DisplayTimezone::parse("UTC+1"), offset(3_600));
+    assert_eq!(DisplayTimezone::parse("+14"), offset(50_400));
+    assert_eq!(DisplayTimezone::parse("+15:00"), None);
+    assert_eq!(DisplayTimezone::parse("Europe/Berlin"), None);
+    // Not ASCII: the Unicode minus sign, a full-width plus, non-ASCII digits
+    assert_eq!(DisplayTimezone::parse("\u{2212}05:00"), None);
+    assert_eq!(DisplayTimezone::parse("UTC\u{2212}5"), None);
+    assert_eq!(DisplayTimezone::parse("\u{FF0B}02:00"), None);
+    assert_eq!(DisplayTimezone::parse("+0\u{0665}30"), None);
+    assert_eq!(DisplayTimezone::parse("+\u{00e9}1"), None);
+    assert_eq!(DisplayTimezone::parse("français"), None);
+}
+
+#[test]
+fn test_date_formatter_normalizes_timestamps() {
+    let formatter = DateFormatter::new(utc(), DEFAULT_DATE_FORMAT);
+    assert_eq!(
+        formatter.format_timestamp("2024-03-04T10:00:00.000+0100"),
+        "2024-03-04 09:00 +00:00"
+    );
+    assert_eq!(
+        formatter.format_timestamp("2024-03-04T09:00:00Z"),
+        "2024-03-04 09:00 +00:00"
+    );
+
+    let formatter = DateFormatter::new(
+        DisplayTimezone::parse("+05:30").unwrap(),
+        "%d.%m.%Y %H:%M:%S%.3f",
+    );
+    assert_eq!(
+        formatter.format_timestamp("2024-03-04T22:00:00.250+0000"),
+        "05.03.2024 03:30:00.250"
+    );
+
+    // Text that is not a timestamp is passed through
+    assert_eq!(formatter.format_timestamp("Today 2:10 PM"), "Today 2:10 PM");
+
+    // A format chrono cannot write falls back to the default
+    assert!(!is_valid_date_format("%Y-%Q"));
+    let formatter = DateFormatter::new(utc(), "%Y-%Q");
+    assert_eq!(formatter, DateFormatter::new(utc(), DEFAULT_DATE_FORMAT));
+}
+
+#[test]
+fn test_date_formatter_from_config() {
+    let config = JiraConfig {
+        display_timezone: Some("-03:00".to_string()),
+        display_date_format: Some("%Y-%m-%d %H:%M".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        DateFormatter::from_config(&config).format_timestamp("2024-03-04T01:00:00.000+0000"),
+        "2024-03-03 22:00"
+    );
+
+    // Unreadable time zones fall back to local time
+    let config = JiraConfig {
+        display_timezone: Some("Mars/Olympus".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        DateFormatter::from_config(&config),
+        DateFormatter::default()
+    );
+}
+
+#[tokio::test]
+async fn test_locale_is_sent_as_accept_language() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let myself = server
+        .mock("GET", "/rest/api/2/myself")
+        .match_header("accept-language", "de-DE")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"name": "alice", "displayName": "Alice"}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        locale: Some("de-DE".to_string()),
+        ..Default::default()
+    };
+    let client = JiraClient::new(config).unwrap();
+    let _: serde_json::Value = client.get("myself").await.unwrap();
+    myself.assert_async().await;
+
+    // A locale that cannot be a header is a configuration error, but costs
+    // a client only the header
+    for locale in ["de\nDE", "français"] {
+        let config = JiraConfig {
+            email: "test@example.com".to_string(),
+            personal_access_token: "test-token-123".to_string(),
+            locale: Some(locale.to_string()),
+            ..Default::default()
+        };
+        let error = config.validate().unwrap_err();
+        assert!(error.to_string().contains("Invalid locale"), "{error}");
+        assert!(JiraClient::new(config).is_ok());
+    }
+    let config = JiraConfig {
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token-123".to_string(),
+        locale: Some("de-DE".to_string()),
+        ..Default::default()
+    };
+    assert!(config.validate().is_ok());
+}
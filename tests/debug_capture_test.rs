@@ -0,0 +1,190 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::logging::sanitize::{
+    sanitize_header, sanitize_json, sanitize_text, sanitize_url, REDACTED,
+};
+use rust_jira_mcp::mcp::server::MCPServer;
+use rust_jira_mcp::types::mcp::MCPToolCall;
+use serde_json::json;
+use std::path::Path;
+
+fn create_test_config(base_url: &str, capture_dir: &Path) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        debug_capture_dir: Some(capture_dir.to_path_buf()),
+        ..Default::default()
+    }
+}
+
+fn assert_no_credentials(text: &str, config: &JiraConfig) {
+    let auth_header = config.auth_header();
+    let credentials = auth_header.split(' ').nth(1).unwrap();
+    assert!(!text.contains(&auth_header));
+    assert!(!text.contains(credentials));
+    assert!(!text.contains("test-token"));
+}
+
+#[test]
+fn test_sanitizer_rules() {
+    assert_eq!(sanitize_header("Authorization", "Basic abc"), REDACTED);
+    assert_eq!(sanitize_header("set-cookie", "JSESSIONID=1"), REDACTED);
+    assert_eq!(
+        sanitize_header("Accept", "application/json"),
+        "application/json"
+    );
+
+    let json = sanitize_json(&json!({
+        "fields": {"summary": "Basic setup", "password": "hunter2"},
+        "nested": [{"apiToken": "abc"}]
+    }));
+    assert_eq!(json["fields"]["summary"], "Basic setup");
+    assert_eq!(json["fields"]["password"], REDACTED);
+    assert_eq!(json["nested"][0]["apiToken"], REDACTED);
+
+    assert_eq!(
+        sanitize_text("sent Bearer abcdefghijklmnopqrstuvwxyz123 upstream"),
+        format!("sent Bearer {REDACTED} upstream")
+    );
+    assert_eq!(
+        sanitize_url("https://jira.example.com/rest/api/2/search?jql=x&token=abc"),
+        "https://jira.example.com/rest/api/2/search?jql=x&token=%5BREDACTED%5D"
+    );
+}
+
+#[tokio::test]
+async fn test_debug_capture_appends_redacted_exchanges() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _issue = server
+        .mock("GET", "/rest/api/2/issue/CAP-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("set-cookie", "JSESSIONID=secret-session")
+        .with_body(
+            json!({
+                "id": "1",
+                "key": "CAP-1",
+                "self": "x",
+                "fields": {"summary": "Captured", "status": {"name": "Open"}}
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let capture_dir = tempfile::tempdir().unwrap();
+    let config = create_test_config(&base_url, capture_dir.path());
+    let server = MCPServer::new(config.clone());
+    let result = server
+        .call_tool(MCPToolCall {
+            name: "get_jira_issue".to_string(),
+            arguments: json!({"issue_key": "CAP-1", "debug_capture": true}),
+        })
+        .await
+        .unwrap();
+
+    assert!(result.content[0].text.contains("Summary: Captured"));
+    let capture_text = &result.content.last().unwrap().text;
+    assert!(capture_text.starts_with("Debug capture of 1 HTTP exchanges (written to "));
+    assert!(capture_text.contains("```json\n"));
+    assert!(capture_text.contains("\"method\": \"GET\""));
+    assert!(capture_text.contains("/rest/api/2/issue/CAP-1"));
+    assert!(capture_text.contains("\"status\": 200"));
+    assert!(capture_text.contains(&format!("\"authorization\": \"{REDACTED}\"")));
+    assert_no_credentials(capture_text, &config);
+
+    let files: Vec<_> = std::fs::read_dir(capture_dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(files.len(), 1);
+    assert!(files[0]
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .ends_with("-get_jira_issue.json"));
+    let file_text = std::fs::read_to_string(&files[0]).unwrap();
+    assert!(file_text.contains("CAP-1"));
+    assert_no_credentials(&file_text, &config);
+}
+
+#[tokio::test]
+async fn test_debug_capture_of_failed_call() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _create = server
+        .mock("POST", "/rest/api/2/issue")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errors": {"summary": "Summary is required."}}).to_string())
+        .create_async()
+        .await;
+
+    let capture_dir = tempfile::tempdir().unwrap();
+    let config = create_test_config(&base_url, capture_dir.path());
+    let server = MCPServer::new(config.clone());
+    let result = server
+        .call_tool(MCPToolCall {
+            name: "create_jira_issue".to_string(),
+            arguments: json!({
+                "fields": {"project": {"key": "CAP"}, "password": "hunter2"},
+                "debug_capture": true
+            }),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.is_error, Some(true));
+    assert!(result.content[0].text.contains("Summary is required."));
+    let capture_text = &result.content.last().unwrap().text;
+    assert!(capture_text.contains("\"status\": 400"));
+    assert!(capture_text.contains(&format!("\"password\": \"{REDACTED}\"")));
+    assert!(!capture_text.contains("hunter2"));
+    assert_no_credentials(capture_text, &config);
+}
+
+#[tokio::test]
+async fn test_calls_without_debug_capture_are_unchanged() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _issue = server
+        .mock("GET", "/rest/api/2/issue/CAP-2")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "2", "key": "CAP-2", "self": "x", "fields": {}}).to_string())
+        .create_async()
+        .await;
+
+    let capture_dir = tempfile::tempdir().unwrap();
+    let server = MCPServer::new(create_test_config(&base_url, capture_dir.path()));
+    let result = server
+        .call_tool(MCPToolCall {
+            name: "get_jira_issue".to_string(),
+            arguments: json!({"issue_key": "CAP-2"}),
+        })
+        .await
+        .unwrap();
+
+    assert!(!result
+        .content
+        .iter()
+        .any(|c| c.text.contains("Debug capture")));
+    assert_eq!(std::fs::read_dir(capture_dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn test_every_tool_accepts_debug_capture() {
+    for tool in MCPServer::list_tools() {
+        assert!(
+            tool.input_schema["properties"]["debug_capture"].is_object(),
+            "{} does not list debug_capture",
+            tool.name
+        );
+    }
+}
@@ -12,6 +12,7 @@ fn test_jira_client_creation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     let _client = JiraClient::new(config);
@@ -39,6 +40,7 @@ fn test_mcp_server_creation_minimal() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     let _server = MCPServer::new(config);
@@ -0,0 +1,207 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{
+    CreateComponentTool, CreateIssueTool, GetProjectComponentsTool, GetProjectConfigTool,
+    ProjectArg,
+};
+use serde_json::json;
+
+fn create_test_config(base_url: &str, default_project: Option<&str>) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: default_project.map(ToString::to_string),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_project_arg_prefers_explicit_key() {
+    let config = create_test_config("http://localhost:1", Some("DEF"));
+
+    let explicit =
+        ProjectArg::from_args(&json!({"project_key": "ABC"}), "project_key", &config).unwrap();
+    assert_eq!(explicit.key, "ABC");
+    assert!(!explicit.defaulted);
+    assert_eq!(explicit.note(), "");
+
+    let defaulted =
+        ProjectArg::from_args(&json!({"project_key": ""}), "project_key", &config).unwrap();
+    assert_eq!(defaulted.key, "DEF");
+    assert!(defaulted.defaulted);
+    assert_eq!(
+        defaulted.note(),
+        "Using default project DEF (JIRA_DEFAULT_PROJECT)\n"
+    );
+}
+
+#[test]
+fn test_project_arg_without_default() {
+    let config = create_test_config("http://localhost:1", None);
+    let error = ProjectArg::from_args(&json!({}), "project_key", &config)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Missing required parameter: project_key"));
+    assert!(error.contains("JIRA_DEFAULT_PROJECT"));
+}
+
+#[tokio::test]
+async fn test_project_components_use_default_project() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let components = server
+        .mock("GET", "/rest/api/2/project/DEF/components")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([{
+                "id": "1",
+                "name": "Backend",
+                "description": "Server side",
+                "self": "https://jira.example.com/rest/api/2/component/1"
+            }])
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = GetProjectComponentsTool::new(create_test_config(&base_url, Some("DEF")));
+    let result = tool.handle(json!({})).await.unwrap();
+
+    assert!(result.content[0]
+        .text
+        .starts_with("Using default project DEF (JIRA_DEFAULT_PROJECT)\nFound 1 components"));
+    components.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_explicit_project_skips_default_note() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let configuration = server
+        .mock("GET", "/rest/api/2/project/ABC/configuration")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"key": "ABC", "name": "Alphabet"}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = GetProjectConfigTool::new(create_test_config(&base_url, Some("DEF")));
+    let result = tool.handle(json!({"project_key": "ABC"})).await.unwrap();
+
+    assert!(result.content[0]
+        .text
+        .starts_with("Project configuration for ABC"));
+    configuration.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_issue_fills_missing_project() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "fields": {
+                "project": {"key": "DEF"},
+                "summary": "Defaulted",
+                "issuetype": {"name": "Task"}
+            }
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10001",
+                "key": "DEF-1",
+                "self": "https://jira.example.com/rest/api/2/issue/10001",
+                "fields": {}
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = CreateIssueTool::new(create_test_config(&base_url, Some("DEF")));
+    let result = tool
+        .handle(json!({
+            "fields": {
+                "summary": "Defaulted",
+                "issuetype": {"name": "Task"}
+            }
+        }))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Using default project DEF (JIRA_DEFAULT_PROJECT)\n"));
+    assert!(text.contains("Issue created successfully: DEF-1 - Defaulted"));
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_issue_keeps_project_from_fields() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "fields": {"project": {"key": "OWN"}}
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10002",
+                "key": "OWN-7",
+                "self": "https://jira.example.com/rest/api/2/issue/10002",
+                "fields": {}
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = CreateIssueTool::new(create_test_config(&base_url, Some("DEF")));
+    let result = tool
+        .handle(json!({
+            "fields": {
+                "project": {"key": "OWN"},
+                "summary": "Explicit",
+                "issuetype": {"name": "Bug"}
+            }
+        }))
+        .await
+        .unwrap();
+
+    assert!(result.content[0]
+        .text
+        .starts_with("Issue created successfully: OWN-7"));
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_component_requires_project_without_default() {
+    let tool = CreateComponentTool::new(create_test_config("http://localhost:1", None));
+    let error = tool
+        .handle(json!({"name": "Backend"}))
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("Missing required parameter: project"));
+    assert!(error.contains("JIRA_DEFAULT_PROJECT"));
+}
@@ -0,0 +1,338 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{AddWorkLogTool, UpdateWorkLogTool};
+use rust_jira_mcp::utils::duration::{normalize_duration, WorkCalendar, WorkDuration};
+use serde_json::json;
+
+fn normalize(input: &str) -> String {
+    normalize_duration("time_spent", input, &WorkCalendar::default()).unwrap()
+}
+
+fn rejection(input: &str) -> String {
+    normalize_duration("time_spent", input, &WorkCalendar::default())
+        .unwrap_err()
+        .to_string()
+}
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_durations_are_normalized() {
+    for (input, expected) in [
+        ("1.5h", "1h 30m"),
+        ("1,5h", "1h 30m"),
+        ("90m", "1h 30m"),
+        ("1h30m", "1h 30m"),
+        ("1h 30m", "1h 30m"),
+        ("30m 1h", "1h 30m"),
+        ("2d 4h", "2d 4h"),
+        ("2d4h", "2d 4h"),
+        ("20h", "2d 4h"),
+        ("0.5d", "4h"),
+        ("1w", "1w"),
+        ("40h", "1w"),
+        ("1w 2d 3h 4m", "1w 2d 3h 4m"),
+        ("1.5w", "1w 2d 4h"),
+        ("  2h  ", "2h"),
+        ("2H", "2h"),
+        ("1:30", "1h 30m"),
+        ("0:45", "45m"),
+        ("10:00", "1d 2h"),
+        (".5h", "30m"),
+    ] {
+        assert_eq!(normalize(input), expected, "{input}");
+    }
+}
+
+#[test]
+fn test_unit_words() {
+    for input in [
+        "3 hours",
+        "3 hour",
+        "3 hrs",
+        "3hr",
+        "180 minutes",
+        "180 mins",
+        "180min",
+    ] {
+        assert_eq!(normalize(input), "3h", "{input}");
+    }
+    assert_eq!(normalize("2 days 1 hour"), "2d 1h");
+    assert_eq!(normalize("1 week"), "1w");
+    assert_eq!(normalize("2 wks"), "2w");
+    assert_eq!(normalize("1 Day"), "1d");
+}
+
+#[test]
+fn test_work_calendar() {
+    let calendar = WorkCalendar::new(7.5, 4.0);
+    assert_eq!(calendar.minutes_per_day(), 450);
+    assert_eq!(calendar.minutes_per_week(), 1800);
+    assert_eq!(
+        normalize_duration("time_spent", "1d", &calendar).unwrap(),
+        "1d"
+    );
+    assert_eq!(
+        normalize_duration("time_spent", "8h", &calendar).unwrap(),
+        "1d 30m"
+    );
+    assert_eq!(
+        normalize_duration("time_spent", "30h", &calendar).unwrap(),
+        "1w"
+    );
+    assert_eq!(
+        WorkDuration::parse("time_spent", "1w 1d", &calendar)
+            .unwrap()
+            .minutes(),
+        2250
+    );
+
+    // Nonsense falls back to the defaults
+    assert_eq!(WorkCalendar::new(0.0, -1.0), WorkCalendar::default());
+    assert_eq!(WorkCalendar::new(f64::NAN, 5.0), WorkCalendar::default());
+
+    let config = JiraConfig {
+        work_hours_per_day: Some(6.0),
+        work_days_per_week: Some(4.0),
+        ..Default::default()
+    };
+    let calendar = WorkCalendar::from_config(&config);
+    assert_eq!(calendar.minutes_per_day(), 360);
+    assert_eq!(calendar.minutes_per_week(), 1440);
+}
+
+#[test]
+fn test_work_duration_values() {
+    let duration = WorkDuration::parse("time_spent", "1h 30m", &WorkCalendar::default()).unwrap();
+    assert_eq!(duration.minutes(), 90);
+    assert_eq!(duration.seconds(), 5400);
+    assert_eq!(duration, WorkDuration::from_minutes(90));
+    assert_eq!(
+        WorkDuration::from_minutes(0).to_jira(&WorkCalendar::default()),
+        "0m"
+    );
+}
+
+#[test]
+fn test_rejections() {
+    let error = rejection("");
+    assert!(error.contains("time_spent"), "{error}");
+    assert!(error.contains("Enter a duration"), "{error}");
+    assert!(rejection("   ").contains("Enter a duration"));
+
+    let error = rejection("90");
+    assert!(
+        error.contains("'90' in '90' has no unit; add w, d, h or m, e.g. 90m"),
+        "{error}"
+    );
+    assert!(rejection("1h 30").contains("'30' in '1h 30' has no unit"));
+
+    let error = rejection("2y");
+    assert!(
+        error.contains(
+            "Unknown unit 'y' in '2y'; use w (weeks), d (days), h (hours) or m (minutes)"
+        ),
+        "{error}"
+    );
+    assert!(rejection("1 fortnight").contains("Unknown unit 'fortnight'"));
+
+    assert!(rejection("1h 2h").contains("'1h 2h' gives hours more than once"));
+    assert!(rejection("1h 30 minutes 5m").contains("gives minutes more than once"));
+
+    assert!(rejection("h").contains("'h' is not a duration; use amounts with units"));
+    assert!(rejection("-1h").contains("'-1h' is not a duration"));
+    assert!(rejection("1h-30m").contains("is not a duration"));
+    assert!(rejection("1.2.3h").contains("'1.2.3' in '1.2.3h' is not a number"));
+    assert!(rejection("1.h.m").contains("is not"));
+
+    assert!(rejection("0h").contains("'0h' is no time; a duration needs at least 1m"));
+    assert!(rejection("0m 0h").contains("is no time"));
+
+    assert!(rejection("0.5m").contains("'0.5m' is not a whole number of minutes"));
+    assert!(rejection("1.01h").contains("is not a whole number of minutes"));
+
+    assert!(rejection("1:3").contains("write hours and minutes as H:MM"));
+    assert!(rejection("1:60").contains("H:MM"));
+    assert!(rejection("1:30:00").contains("H:MM"));
+    assert!(rejection("h:mm").contains("H:MM"));
+    assert!(rejection("0:00").contains("is no time"));
+}
+
+#[test]
+fn test_rejections_name_the_argument() {
+    let error = normalize_duration("new_estimate", "soon", &WorkCalendar::default())
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("new_estimate"), "{error}");
+}
+
+#[test]
+fn test_config_validation_of_the_work_calendar() {
+    let valid = JiraConfig {
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token-123".to_string(),
+        api_base_url: "https://jira.example.com/rest/api/2".to_string(),
+        work_hours_per_day: Some(7.5),
+        work_days_per_week: Some(4.5),
+        ..Default::default()
+    };
+    assert!(valid.validate().is_ok());
+
+    for (hours, days) in [(0.0, 5.0), (25.0, 5.0), (8.0, 0.0), (8.0, 8.0), (-1.0, 5.0)] {
+        let config = JiraConfig {
+            work_hours_per_day: Some(hours),
+            work_days_per_week: Some(days),
+            ..valid.clone()
+        };
+        assert!(config.validate().is_err(), "{hours}h {days}d");
+    }
+}
+
+fn work_log(id: &str, time_spent: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "time_spent": time_spent,
+        "time_spent_seconds": 5400,
+        "author": {
+            "self": "https://jira.example.com/rest/api/2/user?username=test",
+            "name": "test",
+            "key": "test",
+            "displayName": "Test User",
+            "emailAddress": "test@example.com",
+            "active": true
+        },
+        "created": "2024-03-02T14:30:00.000+0000"
+    })
+}
+
+#[tokio::test]
+async fn test_add_work_log_sends_the_normalized_duration() {
+    let mut server = mockito::Server::new_async().await;
+    let post = server
+        .mock("POST", "/rest/api/2/issue/DUR-1/worklog")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("adjustEstimate".to_string(), "manual".to_string()),
+            mockito::Matcher::UrlEncoded("reduceBy".to_string(), "1h".to_string()),
+        ]))
+        .match_body(mockito::Matcher::PartialJson(
+            json!({"time_spent": "1h 30m"}),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(work_log("600", "1h 30m").to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = AddWorkLogTool::new(create_test_config(&server.url()));
+    let result = tool
+        .handle(json!({"issue_key": "DUR-1", "time_spent": "1.5h", "reduce_by": "60m"}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(
+        text.contains("Time spent: 1h 30m (normalized from '1.5h')"),
+        "{text}"
+    );
+    assert!(text.contains("Remaining estimate: reduced by 1h"), "{text}");
+    post.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_invalid_durations_are_not_sent() {
+    let mut server = mockito::Server::new_async().await;
+    let post = server
+        .mock("POST", "/rest/api/2/issue/DUR-2/worklog")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let tool = AddWorkLogTool::new(create_test_config(&server.url()));
+    for (args, message) in [
+        (
+            json!({"issue_key": "DUR-2", "time_spent": "90"}),
+            "has no unit",
+        ),
+        (
+            json!({"issue_key": "DUR-2", "time_spent": "1h", "adjust_estimate": "new"}),
+            "adjust_estimate 'new' needs new_estimate",
+        ),
+        (
+            json!({"issue_key": "DUR-2", "time_spent": "1h", "adjust_estimate": "manual"}),
+            "adjust_estimate 'manual' needs reduce_by",
+        ),
+        (
+            json!({"issue_key": "DUR-2", "time_spent": "1h", "adjust_estimate": "sometimes"}),
+            "Unknown estimate adjustment 'sometimes'; expected auto, leave, new or manual",
+        ),
+        (
+            json!({"issue_key": "DUR-2", "time_spent": "1h", "adjust_estimate": "leave", "new_estimate": "2d"}),
+            "new_estimate is only used with adjust_estimate 'new'",
+        ),
+        (
+            json!({"issue_key": "DUR-2", "time_spent": "1h", "new_estimate": "2 sprints"}),
+            "Unknown unit 'sprints'",
+        ),
+    ] {
+        let error = tool.handle(args).await.unwrap_err().to_string();
+        assert!(error.contains(message), "{error}");
+    }
+    post.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_update_work_log_sets_a_new_estimate() {
+    let mut server = mockito::Server::new_async().await;
+    let put = server
+        .mock("PUT", "/rest/api/2/issue/DUR-3/worklog/601")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("adjustEstimate".to_string(), "new".to_string()),
+            mockito::Matcher::UrlEncoded("newEstimate".to_string(), "2d 4h".to_string()),
+        ]))
+        .match_body(mockito::Matcher::PartialJson(json!({"time_spent": "2h"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(work_log("601", "2h").to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = UpdateWorkLogTool::new(create_test_config(&server.url()));
+    let result = tool
+        .handle(json!({
+            "issue_key": "DUR-3",
+            "work_log_id": "601",
+            "time_spent": "2h",
+            "new_estimate": "20h"
+        }))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.contains("\nTime spent: 2h\n"), "{text}");
+    assert!(text.ends_with("Remaining estimate: set to 2d 4h"), "{text}");
+    put.assert_async().await;
+
+    // Updates cannot reduce the estimate by a separate amount
+    let error = tool
+        .handle(json!({
+            "issue_key": "DUR-3",
+            "work_log_id": "601",
+            "time_spent": "2h",
+            "adjust_estimate": "manual"
+        }))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("expected auto, leave or new"), "{error}");
+}
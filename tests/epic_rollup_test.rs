@@ -0,0 +1,213 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::epic_rollup::{epic_children_jql, AssigneeShare, EpicChild, EpicRollup};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::SummarizeEpicTool;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        story_points_field: Some("customfield_10016".to_string()),
+        ..Default::default()
+    }
+}
+
+fn child(key: &str, category: &str, points: Option<f64>, assignee: Option<&str>) -> Value {
+    json!({
+        "key": key,
+        "fields": {
+            "summary": format!("Child {key}"),
+            "status": {"name": category, "statusCategory": {"key": category}},
+            "assignee": assignee.map(|name| json!({"displayName": name})),
+            "customfield_10016": points,
+            "updated": "2024-03-01T10:00:00.000+0000",
+            "issuelinks": []
+        }
+    })
+}
+
+fn blocked_by(mut issue: Value, blocker: &str, blocker_category: &str) -> Value {
+    issue["fields"]["issuelinks"] = json!([{
+        "id": "1",
+        "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+        "inwardIssue": {
+            "key": blocker,
+            "fields": {"status": {"name": "x", "statusCategory": {"key": blocker_category}}}
+        }
+    }]);
+    issue
+}
+
+fn children(issues: &[Value]) -> Vec<EpicChild> {
+    issues
+        .iter()
+        .map(|issue| EpicChild::from_json(issue, Some("customfield_10016"), "Blocks"))
+        .collect()
+}
+
+#[test]
+fn test_rollup_counts_points_and_unestimated_children() {
+    let children = children(&[
+        child("E-1", "done", Some(5.0), Some("Al")),
+        child("E-2", "done", None, Some("Al")),
+        child("E-3", "indeterminate", Some(3.0), Some("Bo")),
+        child("E-4", "new", Some(2.5), None),
+        child("E-5", "new", None, Some("Bo")),
+    ]);
+
+    let rollup = EpicRollup::from_children(&children);
+    assert_eq!(rollup.total, 5);
+    assert_eq!(rollup.in_category("done"), 2);
+    assert_eq!(rollup.in_category("indeterminate"), 1);
+    assert_eq!(rollup.in_category("new"), 2);
+    assert!((rollup.points_done - 5.0).abs() < f64::EPSILON);
+    assert!((rollup.points_remaining - 5.5).abs() < f64::EPSILON);
+    assert_eq!(rollup.unestimated, 2);
+    assert_eq!(rollup.points_done_percent().map(f64::round), Some(48.0));
+    assert_eq!(
+        rollup.assignees,
+        [
+            AssigneeShare {
+                assignee: Some("Al".to_string()),
+                issues: 2,
+                open: 0
+            },
+            AssigneeShare {
+                assignee: Some("Bo".to_string()),
+                issues: 2,
+                open: 2
+            },
+            AssigneeShare {
+                assignee: None,
+                issues: 1,
+                open: 1
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_rollup_without_any_points() {
+    let children = children(&[
+        child("E-1", "new", None, None),
+        child("E-2", "done", None, None),
+    ]);
+    let rollup = EpicRollup::from_children(&children);
+    assert_eq!(rollup.unestimated, 2);
+    assert_eq!(rollup.points_done_percent(), None);
+
+    let empty = EpicRollup::from_children(&[]);
+    assert_eq!(empty.total, 0);
+    assert_eq!(empty.last_activity, None);
+}
+
+#[test]
+fn test_rollup_blocked_children_and_last_activity() {
+    let mut recent = child("E-2", "new", Some(1.0), None);
+    recent["fields"]["updated"] = json!("2024-03-05T08:30:00.000+0000");
+    let children = children(&[
+        blocked_by(child("E-1", "new", Some(1.0), None), "X-1", "indeterminate"),
+        blocked_by(recent, "X-2", "done"),
+        // Done children are no longer held up by their blockers
+        blocked_by(child("E-3", "done", Some(1.0), None), "X-3", "new"),
+    ]);
+
+    let rollup = EpicRollup::from_children(&children);
+    assert_eq!(rollup.blocked, ["E-1"]);
+    let (key, at) = rollup.last_activity.unwrap();
+    assert_eq!(key, "E-2");
+    assert_eq!(at.to_rfc3339(), "2024-03-05T08:30:00+00:00");
+}
+
+#[test]
+fn test_epic_children_jql() {
+    assert_eq!(
+        epic_children_jql("EP-1", None),
+        "parent = \"EP-1\" ORDER BY key ASC"
+    );
+    assert_eq!(
+        epic_children_jql("EP-1", Some("customfield_10014")),
+        "(parent = \"EP-1\" OR cf[10014] = \"EP-1\") ORDER BY key ASC"
+    );
+}
+
+#[tokio::test]
+async fn test_summarize_epic_tool() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let _epic = server
+        .mock("GET", "/rest/api/2/issue/ROLL-1")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"id": "1", "key": "ROLL-1", "self": "x", "fields": {"summary": "Checkout"}})
+                .to_string(),
+        )
+        .create_async()
+        .await;
+    let _fields = server
+        .mock("GET", "/rest/api/2/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([{"id": "customfield_10014", "name": "Epic Link", "custom": true}]).to_string(),
+        )
+        .create_async()
+        .await;
+    let _search = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "jql".to_string(),
+            "(parent = \"ROLL-1\" OR cf[10014] = \"ROLL-1\") ORDER BY key ASC".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "startAt": 0,
+                "maxResults": 50,
+                "total": 3,
+                "issues": [
+                    child("ROLL-2", "done", Some(3.0), Some("Al")),
+                    blocked_by(child("ROLL-3", "new", Some(2.0), Some("Bo")), "DEP-9", "new"),
+                    child("ROLL-4", "indeterminate", None, Some("Al"))
+                ]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = SummarizeEpicTool::new(config)
+        .handle(json!({"epic_key": "ROLL-1", "list_children": true}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with(
+            "Epic ROLL-1: Checkout\n3 children: 1 To Do, 1 In Progress, 1 Done\n\
+             Points: 3 done, 2 remaining (60% done); 1 unestimated\n\
+             Blocked: ROLL-3 (by DEP-9)\n"
+        ),
+        "{text}"
+    );
+    assert!(
+        text.contains("Assignees: Al 2 (1 open), Bo 1 (1 open)"),
+        "{text}"
+    );
+    assert!(
+        text.contains("• ROLL-4 [indeterminate] Child ROLL-4 (unestimated) — Al"),
+        "{text}"
+    );
+
+    let structured: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(structured["rollup"]["unestimated"], 1);
+    assert_eq!(structured["children"].as_array().unwrap().len(), 3);
+}
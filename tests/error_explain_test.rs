@@ -0,0 +1,536 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::error_explain::{
+    explain, match_rule, status_rule, JiraErrorReport, RULES, STATUS_RULES,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::ExplainJiraErrorTool;
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+
+/// Jira error bodies in English, German, French and Spanish, each with the
+/// rule that must explain it
+const LOCALIZED_ERRORS: &str = include_str!("fixtures/jira_errors_localized.json");
+
+/// The rule explaining `message` returned with `status`
+fn rule_for(status: u16, message: &str) -> &'static str {
+    match_rule(Some(status), message).map_or("none", |rule| rule.id)
+}
+
+/// The only finding of a field error of `field`
+fn explain_field_error(field: &str, message: &str) -> rust_jira_mcp::jira::error_explain::Finding {
+    let report = JiraErrorReport::from_body(
+        Some(400),
+        &json!({"errorMessages": [], "errors": {field: message}}),
+    );
+    let mut findings = explain(&report).findings;
+    assert_eq!(findings.len(), 1);
+    findings.remove(0)
+}
+
+#[test]
+fn test_rule_patterns_are_lower_case() {
+    for rule in RULES {
+        for fragment in rule.patterns.iter().flat_map(|pattern| pattern.iter()) {
+            assert_eq!(
+                *fragment,
+                fragment.to_lowercase(),
+                "pattern of {} is not lower case",
+                rule.id
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rule_ids_are_unique() {
+    let mut ids = BTreeSet::new();
+    for rule in RULES.iter().chain(STATUS_RULES) {
+        assert!(ids.insert(rule.id), "duplicate rule id {}", rule.id);
+    }
+}
+
+#[test]
+fn test_localized_fixtures_match_their_rule() {
+    let cases: Vec<Value> = serde_json::from_str(LOCALIZED_ERRORS).unwrap();
+    for case in &cases {
+        let status = u16::try_from(case["status"].as_u64().unwrap()).unwrap();
+        let report = JiraErrorReport::from_body(Some(status), &case["body"]);
+        let explanation = explain(&report);
+        assert_eq!(explanation.findings.len(), 1, "{case}");
+        let finding = &explanation.findings[0];
+        assert_eq!(finding.rule, case["rule"].as_str().unwrap(), "{case}");
+        assert_eq!(finding.field.as_deref(), case["field"].as_str(), "{case}");
+    }
+}
+
+#[test]
+fn test_every_message_rule_has_localized_fixtures() {
+    let cases: Vec<Value> = serde_json::from_str(LOCALIZED_ERRORS).unwrap();
+    let covered: BTreeSet<&str> = cases
+        .iter()
+        .filter_map(|case| case["rule"].as_str())
+        .collect();
+    for rule in RULES {
+        if rule.id != "write_policy" {
+            assert!(covered.contains(rule.id), "no fixture for {}", rule.id);
+        }
+    }
+}
+
+#[test]
+fn test_write_policy_rule() {
+    assert_eq!(
+        rule_for(
+            400,
+            "Policy violation: no-prod-deletes - Deleting in PROD is not allowed"
+        ),
+        "write_policy"
+    );
+    let finding = &explain(&JiraErrorReport::from_details(
+        "Policy violation: no-prod-deletes - Deleting in PROD is not allowed",
+    ))
+    .findings[0];
+    assert!(finding.remediation.contains("list_write_policies"));
+}
+
+#[test]
+fn test_xsrf_rule_only_for_forbidden() {
+    assert_eq!(rule_for(403, "XSRF check failed"), "xsrf_check_failed");
+    assert_eq!(rule_for(400, "XSRF check failed"), "none");
+}
+
+#[test]
+fn test_captcha_rule() {
+    assert_eq!(rule_for(403, "CAPTCHA_CHALLENGE"), "captcha_required");
+    assert_eq!(rule_for(401, "captcha required"), "captcha_required");
+}
+
+#[test]
+fn test_not_authenticated_rule() {
+    assert_eq!(
+        rule_for(401, "Client must be authenticated to access this resource."),
+        "not_authenticated"
+    );
+    assert_eq!(
+        rule_for(401, "Sie sind nicht authentifiziert."),
+        "not_authenticated"
+    );
+}
+
+#[test]
+fn test_rate_limited_rule() {
+    assert_eq!(rule_for(429, "Rate limit exceeded."), "rate_limited");
+    assert_eq!(rule_for(503, "Too many requests"), "rate_limited");
+}
+
+#[test]
+fn test_jql_invalid_rule_names_the_value() {
+    assert_eq!(
+        rule_for(400, "Error in the JQL Query: Expecting ')' but got 'AND'."),
+        "jql_invalid"
+    );
+    let report = JiraErrorReport::from_body(
+        Some(400),
+        &json!({"errorMessages": ["The value 'FOO' does not exist for the field 'project'."]}),
+    );
+    let finding = &explain(&report).findings[0];
+    assert_eq!(finding.rule, "jql_invalid");
+    assert!(finding.remediation.contains("the value 'FOO'"));
+}
+
+#[test]
+fn test_transition_rule_names_the_transition() {
+    let report = JiraErrorReport::from_body(
+        Some(400),
+        &json!({"errorMessages": [
+            "It seems that you have tried to perform a workflow operation (Close Issue) that is not valid for the current state of this issue (OPS-12)."
+        ]}),
+    );
+    let finding = &explain(&report).findings[0];
+    assert_eq!(finding.rule, "transition_not_available");
+    assert_eq!(finding.permission, Some("Transition Issues"));
+    assert_eq!(
+        finding.explanation,
+        "Jira cannot make the transition 'Close Issue' from the issue's current status."
+    );
+    assert!(finding.remediation.contains("get_jira_transitions"));
+    assert!(finding
+        .likely_causes
+        .iter()
+        .any(|cause| cause.contains("workflow condition")));
+}
+
+#[test]
+fn test_transition_rule_without_a_name() {
+    let finding = explain_field_error(
+        "transition",
+        "Transition id '31' is not valid for this issue.",
+    );
+    assert_eq!(finding.rule, "transition_not_available");
+    assert_eq!(
+        finding.explanation,
+        "Jira cannot make the transition from the issue's current status."
+    );
+}
+
+#[test]
+fn test_field_not_on_screen_rule_names_the_field() {
+    let finding = explain_field_error(
+        "customfield_10010",
+        "Field 'customfield_10010' cannot be set. It is not on the appropriate screen, or unknown.",
+    );
+    assert_eq!(finding.rule, "field_not_on_screen");
+    assert_eq!(finding.field.as_deref(), Some("customfield_10010"));
+    assert!(finding
+        .remediation
+        .starts_with("Leave the field 'customfield_10010' out"));
+}
+
+#[test]
+fn test_field_not_on_screen_rule_from_message_alone() {
+    assert_eq!(
+        rule_for(
+            400,
+            "Field 'labels' cannot be set. It is not on the appropriate screen, or unknown."
+        ),
+        "field_not_on_screen"
+    );
+    let report = JiraErrorReport::from_details(
+        "Field 'labels' cannot be set. It is not on the appropriate screen, or unknown.",
+    );
+    assert!(explain(&report).findings[0]
+        .explanation
+        .contains("the field 'labels'"));
+}
+
+#[test]
+fn test_create_permission_rule() {
+    let finding = explain_field_error(
+        "project",
+        "You do not have permission to create issues in this project.",
+    );
+    assert_eq!(finding.rule, "create_permission");
+    assert_eq!(finding.permission, Some("Create Issues"));
+}
+
+#[test]
+fn test_comment_permission_rule() {
+    assert_eq!(
+        rule_for(
+            403,
+            "You do not have the permission to comment on this issue."
+        ),
+        "comment_permission"
+    );
+    assert_eq!(
+        match_rule(
+            Some(403),
+            "You do not have the permission to comment on this issue."
+        )
+        .unwrap()
+        .permission,
+        Some("Add Comments")
+    );
+}
+
+#[test]
+fn test_edit_permission_rule() {
+    let rule = match_rule(
+        Some(403),
+        "You do not have permission to edit issues in this project.",
+    )
+    .unwrap();
+    assert_eq!(rule.id, "edit_permission");
+    assert_eq!(rule.permission, Some("Edit Issues"));
+}
+
+#[test]
+fn test_assignee_rule_names_the_user() {
+    let finding = explain_field_error("assignee", "User 'jdoe' cannot be assigned issues.");
+    assert_eq!(finding.rule, "assignee_not_assignable");
+    assert_eq!(finding.permission, Some("Assignable User"));
+    assert!(finding
+        .remediation
+        .ends_with("grant it to the user 'jdoe'."));
+}
+
+#[test]
+fn test_worklog_permission_rule() {
+    let rule = match_rule(
+        Some(403),
+        "You do not have the permission to associate a worklog to this issue.",
+    )
+    .unwrap();
+    assert_eq!(rule.id, "worklog_permission");
+    assert_eq!(rule.permission, Some("Work On Issues"));
+}
+
+#[test]
+fn test_attachment_permission_rule() {
+    let rule = match_rule(
+        Some(403),
+        "You do not have permission to create attachments for this issue.",
+    )
+    .unwrap();
+    assert_eq!(rule.id, "attachment_permission");
+    assert_eq!(rule.permission, Some("Create Attachments"));
+}
+
+#[test]
+fn test_link_permission_rule() {
+    let rule = match_rule(Some(403), "You do not have the permission to link issues.").unwrap();
+    assert_eq!(rule.id, "link_permission");
+    assert_eq!(rule.permission, Some("Link Issues"));
+}
+
+#[test]
+fn test_issue_not_found_rule() {
+    let rule = match_rule(
+        Some(404),
+        "Issue does not exist or you do not have permission to see it.",
+    )
+    .unwrap();
+    assert_eq!(rule.id, "issue_not_found");
+    assert_eq!(rule.permission, Some("Browse Projects"));
+}
+
+#[test]
+fn test_user_not_found_rule() {
+    let finding = explain_field_error("reporter", "The user 'jdoe' does not exist.");
+    assert_eq!(finding.rule, "user_not_found");
+    assert!(finding.explanation.contains("the user 'jdoe'"));
+}
+
+#[test]
+fn test_project_invalid_rule() {
+    assert_eq!(
+        explain_field_error("project", "valid project is required").rule,
+        "project_invalid"
+    );
+}
+
+#[test]
+fn test_issue_type_invalid_rule() {
+    let finding = explain_field_error("issuetype", "valid issue type is required");
+    assert_eq!(finding.rule, "issue_type_invalid");
+    assert!(finding.remediation.contains("get_project_issue_types"));
+}
+
+#[test]
+fn test_security_level_rule() {
+    let finding = explain_field_error("security", "Security Level: The security level is invalid.");
+    assert_eq!(finding.rule, "security_level_invalid");
+    assert_eq!(finding.permission, Some("Set Issue Security"));
+}
+
+#[test]
+fn test_value_too_long_rule() {
+    let finding = explain_field_error("summary", "Summary must be less than 255 characters.");
+    assert_eq!(finding.rule, "value_too_long");
+    assert_eq!(
+        finding.explanation,
+        "The value of the field 'summary' is longer than Jira allows."
+    );
+}
+
+#[test]
+fn test_invalid_value_rule() {
+    let finding = explain_field_error("priority", "Specify a valid value for priority");
+    assert_eq!(finding.rule, "invalid_value");
+    assert!(finding.remediation.contains("get_issue_type_metadata"));
+    assert!(finding.remediation.contains("the field 'priority'"));
+}
+
+#[test]
+fn test_field_required_rule() {
+    let finding = explain_field_error("summary", "You must specify a summary of the issue.");
+    assert_eq!(finding.rule, "field_required");
+    assert_eq!(
+        finding.explanation,
+        "Jira needs a value for the field 'summary' on this screen or transition."
+    );
+}
+
+#[test]
+fn test_status_rules() {
+    for (status, id) in [
+        (400, "bad_request"),
+        (401, "unauthorized"),
+        (403, "forbidden"),
+        (404, "not_found"),
+        (409, "conflict"),
+        (413, "payload_too_large"),
+        (429, "too_many_requests"),
+        (500, "server_error"),
+        (502, "server_error"),
+        (503, "server_error"),
+        (504, "server_error"),
+    ] {
+        assert_eq!(status_rule(status).map(|rule| rule.id), Some(id));
+    }
+    assert!(status_rule(418).is_none());
+}
+
+#[test]
+fn test_unknown_message_falls_back_to_status() {
+    let report = JiraErrorReport::from_body(
+        Some(403),
+        &json!({"errorMessages": ["Something only this add-on says"]}),
+    );
+    let finding = &explain(&report).findings[0];
+    assert_eq!(finding.rule, "forbidden");
+    assert_eq!(
+        finding.message.as_deref(),
+        Some("Something only this add-on says")
+    );
+}
+
+#[test]
+fn test_unknown_message_without_status_is_unrecognized() {
+    let report = JiraErrorReport::from_details("Something went sideways");
+    assert_eq!(explain(&report).findings[0].rule, "unrecognized");
+}
+
+#[test]
+fn test_status_alone_is_explained() {
+    let report = JiraErrorReport::from_body(Some(503), &json!("<html><body>Down</body></html>"));
+    assert!(report.messages.is_empty());
+    let explanation = explain(&report);
+    assert_eq!(explanation.findings.len(), 1);
+    assert_eq!(explanation.findings[0].rule, "server_error");
+    assert_eq!(explanation.findings[0].message, None);
+}
+
+#[test]
+fn test_every_field_error_is_explained() {
+    let report = JiraErrorReport::from_body(
+        Some(400),
+        &json!({
+            "errorMessages": ["Error in the JQL Query: bad"],
+            "errors": {
+                "summary": "You must specify a summary of the issue.",
+                "priority": "Specify a valid value for priority"
+            }
+        }),
+    );
+    let rules: Vec<&str> = explain(&report).findings.iter().map(|f| f.rule).collect();
+    assert_eq!(
+        rules,
+        vec!["invalid_value", "field_required", "jql_invalid"]
+    );
+}
+
+#[test]
+fn test_body_as_json_text() {
+    let report = JiraErrorReport::from_body(
+        Some(400),
+        &json!(r#"{"errorMessages":[],"errors":{"summary":"Summary is required."}}"#),
+    );
+    assert_eq!(report.field_errors["summary"], "Summary is required.");
+}
+
+#[test]
+fn test_report_from_http_error_details() {
+    let report = JiraErrorReport::from_details(
+        "HTTP error: 404 Not Found - Issue does not exist or you do not have permission to see it.\nGave up after 2 retries",
+    );
+    assert_eq!(report.status, Some(404));
+    assert_eq!(
+        report.messages,
+        vec!["Issue does not exist or you do not have permission to see it."]
+    );
+}
+
+#[test]
+fn test_report_from_field_error_details() {
+    let report = JiraErrorReport::from_details(
+        r#"Jira API error: customfield_10010: "Field 'customfield_10010' cannot be set. It is not on the appropriate screen, or unknown.", summary: "Summary is required.""#,
+    );
+    assert_eq!(report.field_errors.len(), 2);
+    assert_eq!(report.field_errors["summary"], "Summary is required.");
+    let rules: Vec<&str> = explain(&report).findings.iter().map(|f| f.rule).collect();
+    assert_eq!(rules, vec!["field_not_on_screen", "field_required"]);
+}
+
+#[test]
+fn test_report_from_permission_denied_details() {
+    let report = JiraErrorReport::from_details(
+        "Permission denied: You do not have permission to edit issues in this project.",
+    );
+    assert_eq!(report.status, Some(403));
+    assert_eq!(explain(&report).findings[0].rule, "edit_permission");
+}
+
+#[test]
+fn test_report_from_json_rpc_error() {
+    let error = json!({
+        "jsonrpc": "2.0",
+        "id": 7,
+        "error": {
+            "code": -32603,
+            "message": "Tool execution failed",
+            "data": {"details": "Authentication error: token rejected"}
+        }
+    });
+    let report = JiraErrorReport::from_tool_error(&error);
+    assert_eq!(report.status, Some(401));
+    assert_eq!(explain(&report).findings[0].rule, "unauthorized");
+}
+
+#[test]
+fn test_report_from_bulk_result() {
+    let error = json!({
+        "issue_key": "OPS-1",
+        "success": false,
+        "error_message": "Jira API error: You do not have the permission to comment on this issue.",
+        "error_kind": "permission_denied"
+    });
+    let report = JiraErrorReport::from_tool_error(&error);
+    assert_eq!(report.status, Some(403));
+    assert_eq!(explain(&report).findings[0].rule, "comment_permission");
+}
+
+#[tokio::test]
+async fn test_tool_explains_tool_error() {
+    let tool = ExplainJiraErrorTool::new(JiraConfig::default());
+    let result = tool
+        .handle(json!({
+            "error": {
+                "code": -32603,
+                "message": "Tool execution failed",
+                "data": {"details": "HTTP error: 400 Bad Request - It seems that you have tried to perform a workflow operation (Close Issue) that is not valid for the current state of this issue (OPS-12)."}
+            }
+        }))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Jira error (HTTP 400)"));
+    assert!(text.contains("Permission: Transition Issues"));
+    assert!(text.contains("Fix: Run get_jira_transitions"));
+    let json: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(json["findings"][0]["rule"], "transition_not_available");
+}
+
+#[tokio::test]
+async fn test_tool_explains_raw_body() {
+    let tool = ExplainJiraErrorTool::new(JiraConfig::default());
+    let result = tool
+        .handle(json!({
+            "status": 400,
+            "body": {"errorMessages": [], "errors": {"customfield_10010": "Das Feld 'customfield_10010' kann nicht festgelegt werden. Es befindet sich nicht auf dem entsprechenden Bildschirm oder ist unbekannt."}}
+        }))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.contains("customfield_10010: Das Feld"));
+    assert!(text.contains("[field_not_on_screen]"));
+}
+
+#[tokio::test]
+async fn test_tool_requires_error_or_body() {
+    let tool = ExplainJiraErrorTool::new(JiraConfig::default());
+    let error = tool.handle(json!({"status": 400})).await.unwrap_err();
+    assert!(error.to_string().contains("Missing required parameter"));
+}
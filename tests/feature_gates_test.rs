@@ -0,0 +1,166 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::features::FeatureGates;
+use rust_jira_mcp::mcp::server::MCPServer;
+use serde_json::json;
+
+/// Tools registered by each gated group
+const ZEPHYR_TOOLS: usize = 16;
+const AGILE_TOOLS: usize = 8;
+const JSM_TOOLS: usize = 5;
+const ASSETS_TOOLS: usize = 2;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn probe_mock(server: &mut mockito::Server, status: usize, hits: usize) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/zapi/latest/moduleInfo")
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"moduleName": "zephyr"}).to_string())
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+/// Names of the tools `tools/list` returns for a server with `features`
+async fn listed_tools(config: JiraConfig, features: FeatureGates) -> Vec<String> {
+    let mut server = MCPServer::with_feature_gates(config, features);
+    let response = server
+        .handle_request(
+            &json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list", "params": {}}).to_string(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    response.result.unwrap()["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+fn count(tools: &[String], pattern: &str) -> usize {
+    tools.iter().filter(|name| name.contains(pattern)).count()
+}
+
+#[tokio::test]
+async fn test_zephyr_tools_are_listed_when_zapi_answers() {
+    let mut server = mockito::Server::new_async().await;
+    let probe = probe_mock(&mut server, 200, 1).await;
+    let config = create_test_config(&server.url());
+
+    let features = FeatureGates::detect(&config).await;
+    probe.assert_async().await;
+    assert!(features.zephyr);
+    assert_eq!(features.zephyr_available, Some(true));
+
+    let tools = listed_tools(config, features).await;
+    assert_eq!(count(&tools, "zephyr"), ZEPHYR_TOOLS);
+    assert_eq!(
+        tools.len(),
+        MCPServer::list_tools().len() - JSM_TOOLS - ASSETS_TOOLS,
+        "JSM and Assets are off by default"
+    );
+}
+
+#[tokio::test]
+async fn test_zephyr_tools_are_left_out_when_zapi_is_missing() {
+    let mut server = mockito::Server::new_async().await;
+    let probe = probe_mock(&mut server, 404, 1).await;
+    let config = create_test_config(&server.url());
+
+    let features = FeatureGates::detect(&config).await;
+    probe.assert_async().await;
+    assert!(!features.zephyr);
+
+    let tools = listed_tools(config.clone(), features).await;
+    assert_eq!(count(&tools, "zephyr"), 0);
+    assert_eq!(
+        tools.len(),
+        MCPServer::list_tools().len() - JSM_TOOLS - ASSETS_TOOLS - ZEPHYR_TOOLS
+    );
+
+    // A reload keeps what the probe found, unless Zephyr is set on
+    assert!(!features.reconfigure(&config).zephyr);
+    let forced = JiraConfig {
+        zephyr_enabled: Some("true".to_string()),
+        ..config
+    };
+    assert!(features.reconfigure(&forced).zephyr);
+}
+
+#[tokio::test]
+async fn test_explicit_zephyr_setting_skips_the_probe() {
+    let mut server = mockito::Server::new_async().await;
+    let probe = probe_mock(&mut server, 404, 0).await;
+    let config = create_test_config(&server.url());
+
+    let on = JiraConfig {
+        zephyr_enabled: Some("TRUE".to_string()),
+        ..config.clone()
+    };
+    let features = FeatureGates::detect(&on).await;
+    assert!(features.zephyr);
+    assert_eq!(features.zephyr_available, None);
+    assert_eq!(
+        count(&listed_tools(on, features).await, "zephyr"),
+        ZEPHYR_TOOLS
+    );
+
+    let off = JiraConfig {
+        zephyr_enabled: Some("false".to_string()),
+        ..config
+    };
+    let features = FeatureGates::detect(&off).await;
+    assert!(!features.zephyr);
+    assert_eq!(count(&listed_tools(off, features).await, "zephyr"), 0);
+
+    probe.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_agile_and_jsm_gates() {
+    let config = create_test_config("http://localhost:1");
+    let all = MCPServer::list_tools().len() - ASSETS_TOOLS;
+    let cases = [
+        (true, false, all - JSM_TOOLS),
+        (false, false, all - JSM_TOOLS - AGILE_TOOLS),
+        (true, true, all),
+        (false, true, all - AGILE_TOOLS),
+    ];
+    for (agile, jsm, expected) in cases {
+        let config = JiraConfig {
+            agile_enabled: Some(agile),
+            jsm_enabled: Some(jsm),
+            zephyr_enabled: Some("true".to_string()),
+            ..config.clone()
+        };
+        let tools = listed_tools(config.clone(), FeatureGates::from_config(&config)).await;
+        assert_eq!(tools.len(), expected, "agile {agile}, jsm {jsm}");
+        assert_eq!(
+            tools.contains(&"get_board_sprints".to_string()),
+            agile,
+            "agile {agile}"
+        );
+        assert_eq!(count(&tools, "jsm") > 0, jsm, "jsm {jsm}");
+    }
+}
+
+#[test]
+fn test_invalid_zephyr_setting_is_rejected() {
+    let config = JiraConfig {
+        zephyr_enabled: Some("sometimes".to_string()),
+        ..create_test_config("https://jira.example.com")
+    };
+    let error = config.validate().unwrap_err().to_string();
+    assert!(error.contains("use true, false or auto"), "{error}");
+}
@@ -0,0 +1,194 @@
+use chrono::{DateTime, Utc};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::field_history::{field_changes, time_in_values, FieldMatcher, ValueTime};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::GetFieldHistoryTool;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        ..Default::default()
+    }
+}
+
+fn at(timestamp: &str) -> DateTime<Utc> {
+    timestamp.parse().unwrap()
+}
+
+fn history(created: &str, author: &str, items: Value) -> Value {
+    json!({"created": created, "author": {"displayName": author}, "items": items})
+}
+
+#[test]
+fn test_items_match_by_field_id_before_name() {
+    let histories = [
+        // Renamed from "Target" since; the id still matches
+        history(
+            "2024-01-03T10:00:00.000+0000",
+            "Bo",
+            json!([{"field": "Target", "fieldId": "customfield_10100", "fromString": "Q1", "toString": "Q2"}]),
+        ),
+        // Another field that happens to carry the current name
+        history(
+            "2024-01-02T10:00:00.000+0000",
+            "Al",
+            json!([{"field": "Target release", "fieldId": "customfield_10200", "toString": "x"}]),
+        ),
+        // Older Server versions only name the field
+        history(
+            "2024-01-01T10:00:00.000+0000",
+            "Al",
+            json!([{"field": "target release", "toString": "Q1"}]),
+        ),
+    ];
+    let matcher = FieldMatcher::new("customfield_10100", "Target release");
+
+    let changes = field_changes(&histories, &matcher);
+    let values: Vec<_> = changes
+        .iter()
+        .map(|c| (c.author.as_deref(), c.from_display(), c.to_display()))
+        .collect();
+    assert_eq!(
+        values,
+        [
+            (Some("Al"), None, Some("Q1")),
+            (Some("Bo"), Some("Q1"), Some("Q2"))
+        ]
+    );
+}
+
+#[test]
+fn test_time_in_values_adds_up_repeated_values() {
+    let histories = [
+        history(
+            "2024-01-01T02:00:00.000+0000",
+            "Al",
+            json!([{"field": "status", "fromString": "Open", "toString": "In Progress"}]),
+        ),
+        history(
+            "2024-01-01T03:00:00.000+0000",
+            "Al",
+            json!([{"field": "status", "fromString": "In Progress", "toString": "Open"}]),
+        ),
+        history(
+            "2024-01-01T05:30:00.000+0000",
+            "Al",
+            json!([{"field": "status", "fromString": "Open", "toString": "In Progress"}]),
+        ),
+    ];
+    let changes = field_changes(&histories, &FieldMatcher::new("status", "Status"));
+
+    let totals = time_in_values(
+        at("2024-01-01T00:00:00Z"),
+        Some("In Progress"),
+        &changes,
+        at("2024-01-01T06:00:00Z"),
+    );
+    assert_eq!(
+        totals,
+        [
+            ValueTime {
+                value: Some("Open".to_string()),
+                seconds: 4 * 3600 + 1800,
+                times_set: 2
+            },
+            ValueTime {
+                value: Some("In Progress".to_string()),
+                seconds: 3600 + 1800,
+                times_set: 2
+            },
+        ]
+    );
+
+    // A field that never changed held its current value all along
+    let unchanged = time_in_values(
+        at("2024-01-01T00:00:00Z"),
+        Some("High"),
+        &[],
+        at("2024-01-01T01:00:00Z"),
+    );
+    assert_eq!(unchanged[0].value.as_deref(), Some("High"));
+    assert_eq!(unchanged[0].seconds, 3600);
+}
+
+#[tokio::test]
+async fn test_field_history_tool_lists_due_date_changes() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let _fields = server
+        .mock("GET", "/rest/api/2/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {"id": "duedate", "name": "Due date", "schema": {"type": "date", "system": "duedate"}},
+                {"id": "status", "name": "Status", "schema": {"type": "status", "system": "status"}}
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let _issue = server
+        .mock("GET", "/rest/api/2/issue/HIST-1")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "1",
+                "key": "HIST-1",
+                "self": "x",
+                "fields": {"created": "2024-01-01T00:00:00.000+0000", "duedate": "2024-03-01"},
+                "changelog": {"histories": [
+                    history(
+                        "2024-01-05T09:00:00.000+0000",
+                        "Bo",
+                        json!([
+                            {"field": "duedate", "fieldId": "duedate", "from": "2024-02-01", "fromString": "2024-02-01 00:00:00.0", "to": "2024-03-01", "toString": "2024-03-01 00:00:00.0"},
+                            {"field": "summary", "fieldId": "summary", "toString": "Later"}
+                        ]),
+                    ),
+                    history(
+                        "2024-01-02T09:00:00.000+0000",
+                        "Al",
+                        json!([{"field": "duedate", "fieldId": "duedate", "to": "2024-02-01", "toString": "2024-02-01 00:00:00.0"}]),
+                    )
+                ]}
+            })
+            .to_string(),
+        )
+        .expect(2)
+        .create_async()
+        .await;
+
+    let result = GetFieldHistoryTool::new(config)
+        .handle(json!({"issue_key": "HIST-1", "field": "Due Date"}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with("Due date (duedate) of HIST-1 changed 2 times, oldest first\n• "),
+        "{text}"
+    );
+    assert!(
+        text.contains("by Al: (none) → 2024-02-01 00:00:00.0"),
+        "{text}"
+    );
+    assert!(
+        text.contains("by Bo: 2024-02-01 00:00:00.0 → 2024-03-01 00:00:00.0"),
+        "{text}"
+    );
+    // Dates are not status-like
+    assert!(!text.contains("Time in each value"), "{text}");
+
+    let structured: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(structured["changes"].as_array().unwrap().len(), 2);
+    assert_eq!(structured["changes"][1]["to"], "2024-03-01");
+    assert!(structured.get("time_in_values").is_none());
+}
@@ -0,0 +1,162 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{
+    truncate_chars, FieldFormatter, GetCommentsTool, GetIssueTool, DEFAULT_MAX_FIELD_OUTPUT_CHARS,
+};
+use serde_json::json;
+
+fn create_test_config(base_url: &str, max_chars: Option<usize>) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        max_field_output_chars: max_chars,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_default_limit() {
+    assert_eq!(
+        JiraConfig::default().max_field_output_chars,
+        Some(DEFAULT_MAX_FIELD_OUTPUT_CHARS)
+    );
+}
+
+#[test]
+fn test_short_text_is_untouched() {
+    assert!(truncate_chars("short", 10).is_none());
+    assert!(truncate_chars("exactly10!", 10).is_none());
+    assert_eq!(
+        FieldFormatter::new(10).format_field("short", "T-1", "description"),
+        "short"
+    );
+}
+
+#[test]
+fn test_truncation_does_not_split_multibyte_characters() {
+    // Each character is 4 bytes, so any byte-based cut would land mid-character
+    let text = "🦀".repeat(50);
+    let (kept, omitted) = truncate_chars(&text, 7).unwrap();
+    assert_eq!(kept, "🦀".repeat(7));
+    assert_eq!(omitted, 43);
+
+    let mixed = "aé中🦀".repeat(10);
+    for max in 1..mixed.chars().count() {
+        let (kept, omitted) = truncate_chars(&mixed, max).unwrap();
+        assert!(kept.chars().count() <= max);
+        assert_eq!(kept.chars().count() + omitted, mixed.chars().count());
+    }
+}
+
+#[test]
+fn test_truncation_prefers_line_and_word_boundaries() {
+    let log = "line one\nline two\nline three is longer";
+    let (kept, omitted) = truncate_chars(log, 20).unwrap();
+    assert_eq!(kept, "line one\nline two");
+    assert_eq!(omitted, log.chars().count() - kept.chars().count());
+
+    let (kept, _) = truncate_chars("alpha beta gamma delta epsilon", 20).unwrap();
+    assert_eq!(kept, "alpha beta gamma");
+
+    // No boundary near the limit: cut exactly at the limit
+    let (kept, _) = truncate_chars("abcdefghij klmnop", 8).unwrap();
+    assert_eq!(kept, "abcdefgh");
+}
+
+#[test]
+fn test_marker_and_disabled_limit() {
+    let text = "x".repeat(100);
+
+    let formatted = FieldFormatter::new(40).format_field(&text, "LOG-1", "description");
+    assert!(formatted.starts_with(&"x".repeat(40)));
+    assert!(formatted.contains("truncated: 60 more characters"));
+    assert!(formatted.contains("get_jira_issue_field"));
+    assert!(formatted.contains("issue_key \"LOG-1\" and field \"description\""));
+
+    assert_eq!(
+        FieldFormatter::new(0).format_field(&text, "LOG-1", "description"),
+        text
+    );
+}
+
+#[tokio::test]
+async fn test_get_issue_truncates_description() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let description = format!("Stack trace:\n{}", "at frame()\n".repeat(1000));
+
+    let _mock = server
+        .mock("GET", "/rest/api/2/issue/LOG-1")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10001",
+                "key": "LOG-1",
+                "self": format!("{base_url}/rest/api/2/issue/10001"),
+                "fields": {
+                    "summary": "Crash with pasted log",
+                    "status": {"name": "Open"},
+                    "description": description
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let tool = GetIssueTool::new(create_test_config(&base_url, Some(200)));
+    let result = tool.handle(json!({"issue_key": "LOG-1"})).await.unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.contains("Description:\nStack trace:\nat frame()"));
+    assert!(text.contains("more characters. Use get_jira_issue_field"));
+    assert!(text.len() < 1000);
+}
+
+#[tokio::test]
+async fn test_get_comments_truncates_bodies() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _mock = server
+        .mock("GET", "/rest/api/2/issue/LOG-2/comment")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "comments": [{
+                    "id": "10100",
+                    "body": "ü".repeat(500),
+                    "author": {
+                        "self": format!("{base_url}/rest/api/2/user?username=alice"),
+                        "name": "alice",
+                        "key": "alice",
+                        "displayName": "Alice Example",
+                        "active": true
+                    },
+                    "created": "2024-03-01T09:00:00.000+0000"
+                }]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let tool = GetCommentsTool::new(create_test_config(&base_url, Some(100)));
+    let result = tool.handle(json!({"issue_key": "LOG-2"})).await.unwrap();
+
+    let text = &result.content[1].text;
+    assert!(text.contains(&"ü".repeat(100)));
+    assert!(!text.contains(&"ü".repeat(101)));
+    assert!(text.contains("truncated: 400 more characters"));
+    assert!(text.contains("field \"comment:10100\""));
+}
@@ -0,0 +1,236 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::{MCPServer, MCPToolHandler};
+use rust_jira_mcp::mcp::tools::{GetCommentsTool, GetIssueWatchersTool};
+use rust_jira_mcp::types::jira::{JiraAttachment, JiraComment, JiraUser, JiraWatchersResponse};
+use rust_jira_mcp::types::mcp::JsonRpcResponse;
+use rust_jira_mcp::utils::anonymize;
+use serde_json::{json, Value};
+
+// Written by `scripts/anonymize_fixtures.py --gdpr`: the first user keeps its
+// display name, the second hides it
+const GDPR_COMMENTS: &str = include_str!("fixtures/gdpr_comments.json");
+const GDPR_WORKLOGS: &str = include_str!("fixtures/gdpr_worklogs.json");
+const GDPR_ATTACHMENT: &str = include_str!("fixtures/gdpr_attachment.json");
+const GDPR_WATCHERS: &str = include_str!("fixtures/gdpr_watchers.json");
+
+const VISIBLE_ACCOUNT: &str = "5b10ac8d82e05b22cc7d0001";
+const HIDDEN_ACCOUNT: &str = "5b10ac8d82e05b22cc7d0002";
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        ..Default::default()
+    }
+}
+
+fn fixture(text: &str) -> Value {
+    serde_json::from_str(text).unwrap()
+}
+
+#[test]
+fn test_gdpr_user_payloads_deserialize() {
+    let comments: Vec<JiraComment> =
+        serde_json::from_value(fixture(GDPR_COMMENTS)["comments"].clone()).unwrap();
+    let work_log_author: JiraUser =
+        serde_json::from_value(fixture(GDPR_WORKLOGS)["worklogs"][1]["author"].clone()).unwrap();
+    let attachment: JiraAttachment = serde_json::from_str(GDPR_ATTACHMENT).unwrap();
+    let watchers: JiraWatchersResponse = serde_json::from_str(GDPR_WATCHERS).unwrap();
+
+    let visible = &comments[0].author;
+    assert_eq!(visible.display_name.as_deref(), Some("Test User 1"));
+    assert_eq!(visible.label(), "Test User 1");
+    assert!(visible.name.is_empty());
+    assert!(visible.email_address.is_none());
+
+    let hidden = &comments[1].author;
+    assert!(hidden.display_name.is_none());
+    assert_eq!(hidden.label(), format!("user {HIDDEN_ACCOUNT}"));
+    assert_eq!(work_log_author.label(), hidden.label());
+    assert_eq!(attachment.author.label(), hidden.label());
+
+    let watchers = watchers.watchers.unwrap();
+    assert_eq!(watchers[0].label(), "Test User 1");
+    assert_eq!(watchers[0].account_id, VISIBLE_ACCOUNT);
+    assert_eq!(watchers[1].label(), format!("user {HIDDEN_ACCOUNT}"));
+}
+
+#[test]
+fn test_user_label_fallbacks() {
+    let label = |user: Value| JiraUser::label_of(&user);
+    assert_eq!(
+        label(json!({"displayName": " ", "accountId": "abc"})),
+        "user abc"
+    );
+    assert_eq!(label(json!({"name": "jdoe"})), "jdoe");
+    assert_eq!(label(json!({})), "unknown user");
+    assert_eq!(
+        label(json!({"displayName": "Jane Doe", "accountId": "abc"})),
+        "Jane Doe"
+    );
+}
+
+#[tokio::test]
+async fn test_comments_and_watchers_render_hidden_users_by_account_id() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let comments = server
+        .mock("GET", "/rest/api/2/issue/GDPR-1/comment")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(GDPR_COMMENTS)
+        .create_async()
+        .await;
+    let watchers = server
+        .mock("GET", "/rest/api/2/issue/GDPR-1/watchers")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(GDPR_WATCHERS)
+        .create_async()
+        .await;
+
+    let result = GetCommentsTool::new(config.clone())
+        .handle(json!({"issue_key": "GDPR-1"}))
+        .await
+        .unwrap();
+    let text: String = result.content.iter().map(|c| c.text.as_str()).collect();
+    assert!(
+        text.contains("by Test User 1 (5b10ac8d82e05b22cc7d0001)"),
+        "{text}"
+    );
+    assert!(
+        text.contains("by user 5b10ac8d82e05b22cc7d0002 ("),
+        "{text}"
+    );
+
+    let result = GetIssueWatchersTool::new(config)
+        .handle(json!({"issue_key": "GDPR-1"}))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(
+        text.contains("• Test User 1 (5b10ac8d82e05b22cc7d0001)\n"),
+        "{text}"
+    );
+    assert!(text.contains("• user 5b10ac8d82e05b22cc7d0002\n"), "{text}");
+
+    comments.assert_async().await;
+    watchers.assert_async().await;
+}
+
+#[test]
+fn test_anonymize_text_masks_emails_and_whole_names() {
+    assert_eq!(
+        anonymize::mask_emails("Mail a.b+jira@corp.example.org. Or @here, a@b"),
+        "Mail [email]. Or @here, a@b"
+    );
+
+    anonymize::remember_users(&json!({
+        "comments": [{"author": {
+            "accountId": "acc-wren",
+            "displayName": "Wren Okafor",
+            "emailAddress": "wren@example.com"
+        }}]
+    }));
+    let alias = anonymize::alias_of("Wren Okafor").unwrap();
+    assert!(alias.starts_with("User "), "{alias}");
+    assert_eq!(
+        anonymize::anonymize_text("Wren Okafor asked; Wren Okaforson did not"),
+        format!("{alias} asked; Wren Okaforson did not")
+    );
+}
+
+async fn initialized_server(config: JiraConfig) -> MCPServer {
+    let mut server = MCPServer::new(config);
+    for message in [
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {"protocolVersion": "2025-03-26", "capabilities": {}, "clientInfo": {"name": "gdpr-test", "version": "1.0"}}
+        }),
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+    ] {
+        server.handle_request(&message.to_string()).await.unwrap();
+    }
+    server
+}
+
+async fn call_tool(server: &mut MCPServer, name: &str, arguments: Value) -> JsonRpcResponse {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {"name": name, "arguments": arguments}
+    });
+    server
+        .handle_request(&request.to_string())
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_anonymize_output_masks_users_in_tool_output() {
+    let mut server = mockito::Server::new_async().await;
+    let config = JiraConfig {
+        anonymize_output: Some(true),
+        ..create_test_config(&server.url())
+    };
+    let comments = server
+        .mock("GET", "/rest/api/2/issue/ANON-1/comment")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "startAt": 0,
+                "maxResults": 50,
+                "total": 1,
+                "comments": [{
+                    "id": "10200",
+                    "body": "Priya Raman will follow up, write to priya.raman@example.com",
+                    "author": {
+                        "self": "https://jira.example.com/rest/api/2/user?username=praman",
+                        "name": "praman",
+                        "key": "praman",
+                        "emailAddress": "priya.raman@example.com",
+                        "displayName": "Priya Raman",
+                        "active": true
+                    },
+                    "created": "2026-03-04T08:00:00.000+0000"
+                }]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let mut mcp = initialized_server(config).await;
+    let response = call_tool(
+        &mut mcp,
+        "get_jira_comments",
+        json!({"issue_key": "ANON-1"}),
+    )
+    .await;
+    let text: String = response.result.unwrap()["content"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["text"].as_str().unwrap().to_string())
+        .collect();
+    comments.assert_async().await;
+
+    let alias = anonymize::alias_of("Priya Raman").unwrap();
+    assert_eq!(anonymize::alias_of("praman"), Some(alias.clone()));
+    assert!(!text.contains("Priya"), "{text}");
+    assert!(!text.contains("praman"), "{text}");
+    assert!(
+        text.contains(&format!("{alias} will follow up, write to [email]")),
+        "{text}"
+    );
+}
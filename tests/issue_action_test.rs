@@ -0,0 +1,281 @@
+mod common;
+
+use common::mock_project_type;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::error::JiraErrorKind;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::IssueActionTool;
+use rust_jira_mcp::types::jira::{IssueActionStep, IssueActions};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn comment_response(id: &str) -> String {
+    json!({
+        "id": id,
+        "body": "Picking this up",
+        "author": {"self": "x", "name": "me", "key": "me", "displayName": "Me", "active": true},
+        "created": "2024-05-01T10:00:00.000+0000",
+        "updated": null
+    })
+    .to_string()
+}
+
+fn transitions_body() -> String {
+    let status = |id: &str, name: &str| {
+        json!({
+            "self": format!("https://jira.example.com/rest/api/2/status/{id}"),
+            "id": id,
+            "name": name,
+            "statusCategory": {
+                "self": "x",
+                "id": 4,
+                "key": "indeterminate",
+                "colorName": "yellow",
+                "name": "In Progress"
+            }
+        })
+    };
+    json!({
+        "transitions": [
+            {"id": "11", "name": "Start Progress", "to": status("3", "In Progress")},
+            {"id": "31", "name": "Done", "to": status("10001", "Done")}
+        ]
+    })
+    .to_string()
+}
+
+async fn mock_put(
+    server: &mut mockito::ServerGuard,
+    path: &str,
+    body: Value,
+    status: usize,
+) -> mockito::Mock {
+    server
+        .mock("PUT", path)
+        .match_body(mockito::Matcher::Json(body))
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(if status < 300 {
+            String::new()
+        } else {
+            json!({"errorMessages": ["Rejected"]}).to_string()
+        })
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[test]
+fn test_steps_run_in_a_fixed_order() {
+    let actions = IssueActions {
+        transition: Some("Done".to_string()),
+        comment: Some("Done and dusted".to_string()),
+        remove_labels: vec!["triage".to_string()],
+        assign: Some("bob".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        actions.steps(),
+        [
+            IssueActionStep::Assign,
+            IssueActionStep::Labels,
+            IssueActionStep::Comment,
+            IssueActionStep::Transition
+        ]
+    );
+
+    let actions = IssueActions {
+        transition: Some("31".to_string()),
+        add_labels: vec!["backend".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        actions.steps(),
+        [IssueActionStep::Labels, IssueActionStep::Transition]
+    );
+    assert!(IssueActions::default().steps().is_empty());
+}
+
+#[tokio::test]
+async fn test_apply_issue_actions_runs_every_step() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _project = mock_project_type(&mut server, "/rest/api/2", "ACT", "software").await;
+
+    let assign = mock_put(
+        &mut server,
+        "/rest/api/2/issue/ACT-1/assignee",
+        json!({"name": "bob"}),
+        204,
+    )
+    .await;
+    let labels = mock_put(
+        &mut server,
+        "/rest/api/2/issue/ACT-1",
+        json!({"update": {"labels": [{"add": "backend"}, {"remove": "triage"}]}}),
+        204,
+    )
+    .await;
+    let comment = server
+        .mock("POST", "/rest/api/2/issue/ACT-1/comment")
+        .match_body(mockito::Matcher::Json(json!({"body": "Picking this up"})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(comment_response("500"))
+        .expect(1)
+        .create_async()
+        .await;
+    let _transitions = server
+        .mock("GET", "/rest/api/2/issue/ACT-1/transitions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transitions_body())
+        .create_async()
+        .await;
+    let transition = server
+        .mock("POST", "/rest/api/2/issue/ACT-1/transitions")
+        .match_body(mockito::Matcher::Json(json!({"transition": {"id": "11"}})))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    let actions = IssueActions {
+        assign: Some("bob".to_string()),
+        add_labels: vec!["backend".to_string()],
+        remove_labels: vec!["triage".to_string()],
+        comment: Some("Picking this up".to_string()),
+        transition: Some("in progress".to_string()),
+    };
+    let report = client.apply_issue_actions("ACT-1", &actions).await;
+
+    assert!(report.success());
+    assert_eq!(report.completed, actions.steps());
+    assert!(report.not_attempted.is_empty());
+    for mock in [assign, labels, comment, transition] {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_apply_issue_actions_stops_at_the_first_failure() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let unassign = mock_put(
+        &mut server,
+        "/rest/api/2/issue/ACT-2/assignee",
+        json!({"name": null}),
+        204,
+    )
+    .await;
+    let labels = mock_put(
+        &mut server,
+        "/rest/api/2/issue/ACT-2",
+        json!({"update": {"labels": [{"add": "blocked"}]}}),
+        403,
+    )
+    .await;
+    let comment = server
+        .mock("POST", "/rest/api/2/issue/ACT-2/comment")
+        .expect(0)
+        .create_async()
+        .await;
+    let transition = server
+        .mock("POST", "/rest/api/2/issue/ACT-2/transitions")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    let actions = IssueActions {
+        assign: Some(String::new()),
+        add_labels: vec!["blocked".to_string()],
+        comment: Some("Waiting on the vendor".to_string()),
+        transition: Some("31".to_string()),
+        ..Default::default()
+    };
+    let report = client.apply_issue_actions("ACT-2", &actions).await;
+
+    assert!(!report.success());
+    assert_eq!(report.completed, [IssueActionStep::Assign]);
+    let failure = report.failed.as_ref().unwrap();
+    assert_eq!(failure.step, IssueActionStep::Labels);
+    assert_eq!(failure.error_kind, JiraErrorKind::PermissionDenied);
+    assert_eq!(
+        report.not_attempted,
+        [IssueActionStep::Comment, IssueActionStep::Transition]
+    );
+    for mock in [unassign, labels, comment, transition] {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_issue_action_tool_reports_each_step() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _comment = server
+        .mock("POST", "/rest/api/2/issue/ACT-3/comment")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(comment_response("501"))
+        .create_async()
+        .await;
+    let _transitions = server
+        .mock("GET", "/rest/api/2/issue/ACT-3/transitions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transitions_body())
+        .create_async()
+        .await;
+
+    let tool = IssueActionTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_key": "ACT-3",
+            "comment": "Picking this up",
+            "transition": "Review"
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        "Applied 1 of 2 actions to ACT-3\n\
+         • comment: done\n\
+         • transition: failed (Validation error: transition_name - No transition named 'Review' \
+         is available. Valid transitions: 'Start Progress', 'Done')"
+    );
+    let report: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(report["completed"], json!(["comment"]));
+    assert_eq!(report["failed"]["step"], "transition");
+    assert_eq!(report["not_attempted"], json!([]));
+    assert_eq!(result.is_error, Some(true));
+}
+
+#[tokio::test]
+async fn test_issue_action_tool_needs_an_action() {
+    let tool = IssueActionTool::new(create_test_config("http://localhost:1"));
+    let error = tool
+        .handle(json!({"issue_key": "ACT-4", "add_labels": []}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Nothing to do"));
+}
@@ -0,0 +1,494 @@
+mod common;
+
+use common::mock_project_type;
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::bundle::{
+    replay_line, with_replay_line, BundleLink, IssueBundle, LinkDirection, BUNDLE_FILE_NAME,
+    BUNDLE_SCHEMA_VERSION,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{ExportIssueBundleTool, ImportIssueBundleTool};
+use serde_json::{json, Value};
+use std::path::Path;
+
+const SPEC: &[u8] = b"Rollout plan: canary first, then 10%, 50%, 100%.";
+
+fn create_test_config(base_url: &str, allowed: &Path) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        file_allowlist: Some(allowed.display().to_string()),
+        ..Default::default()
+    }
+}
+
+fn user(name: &str, display_name: &str) -> Value {
+    json!({"self": "x", "name": name, "key": name, "displayName": display_name, "active": true})
+}
+
+fn source_issue(base_url: &str) -> Value {
+    json!({
+        "id": "10001",
+        "key": "SRC-1",
+        "self": format!("{base_url}/rest/api/2/issue/10001"),
+        "fields": {
+            "summary": "Roll out the new billing service",
+            "description": "Staged rollout, see the attached plan",
+            "priority": {"name": "High"},
+            "labels": ["billing"],
+            "project": {"key": "SRC", "name": "Source"},
+            "issuetype": {"name": "Story"},
+            "status": {"name": "In Progress"},
+            "customfield_10100": "Q3",
+            "attachment": [{
+                "self": format!("{base_url}/rest/api/2/attachment/501"),
+                "id": "501",
+                "filename": "plan.txt",
+                "author": user("ana", "Ana Ruiz"),
+                "created": "2025-09-01T10:00:00.000+0000",
+                "size": SPEC.len(),
+                "mimeType": "text/plain",
+                "content": format!("{base_url}/secure/attachment/501/plan.txt")
+            }],
+            "issuelinks": [
+                {
+                    "id": "900",
+                    "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+                    "outwardIssue": {"key": "SRC-2"}
+                },
+                {
+                    "id": "901",
+                    "type": {"name": "Relates", "inward": "relates to", "outward": "relates to"},
+                    "inwardIssue": {"key": "OPS-9"}
+                }
+            ]
+        }
+    })
+}
+
+/// Mocks of the source instance for exporting SRC-1
+async fn mock_source(server: &mut mockito::Server) -> Vec<mockito::Mock> {
+    let base_url = server.url();
+    vec![
+        server
+            .mock("GET", "/rest/api/2/issue/SRC-1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(source_issue(&base_url).to_string())
+            .expect(1)
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/rest/api/2/issue/SRC-1/comment")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "startAt": 0, "maxResults": 50, "total": 2,
+                    "comments": [
+                        {"id": "300", "body": "Canary is green", "author": user("ana", "Ana Ruiz"),
+                         "created": "2025-09-02T09:00:00.000+0000"},
+                        {"id": "301", "body": "Moving to 50%", "author": user("li", "Li Wei"),
+                         "created": "2025-09-03T09:00:00.000+0000"}
+                    ]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/rest/api/2/issue/SRC-1/worklog")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({
+                    "startAt": 0, "maxResults": 100, "total": 1,
+                    "worklogs": [{
+                        "id": "700", "author": user("li", "Li Wei"), "comment": "Load testing",
+                        "started": "2025-09-02T13:00:00.000+0000", "timeSpent": "2h",
+                        "timeSpentSeconds": 7200
+                    }]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/secure/attachment/501/plan.txt")
+            .with_status(200)
+            .with_body(SPEC)
+            .expect(1)
+            .create_async()
+            .await,
+    ]
+}
+
+async fn export(source: &mockito::Server, dir: &Path) -> Value {
+    let result = ExportIssueBundleTool::new(create_test_config(&source.url(), dir))
+        .handle(json!({"issue_key": "SRC-1", "target_dir": dir.to_str().unwrap()}))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Exported SRC-1 to "), "{text}");
+    assert!(
+        text.contains("\nComments: 2\nWorklogs: 1\nAttachments: 1 of 1\nLinks: 2"),
+        "{text}"
+    );
+    serde_json::from_str(&result.content[1].text).unwrap()
+}
+
+#[test]
+fn test_bundle_links_and_replay_lines() {
+    let outward = BundleLink::from_issue_link(&json!({
+        "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+        "outwardIssue": {"key": "SRC-2"}
+    }))
+    .unwrap();
+    assert_eq!(outward.direction, LinkDirection::Outward);
+    assert_eq!(outward.description, "blocks");
+    assert_eq!(outward.issue_key, "SRC-2");
+    let inward = BundleLink::from_issue_link(&json!({
+        "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+        "inwardIssue": {"key": "SRC-3"}
+    }))
+    .unwrap();
+    assert_eq!(inward.direction, LinkDirection::Inward);
+    assert_eq!(inward.description, "is blocked by");
+    assert!(BundleLink::from_issue_link(&json!({"type": {"name": "Blocks"}})).is_none());
+
+    let line = replay_line(Some("Ana Ruiz"), Some("2025-09-02"), "SRC-1");
+    assert_eq!(line, "_Originally by Ana Ruiz on SRC-1, 2025-09-02_");
+    assert_eq!(
+        replay_line(None, None, "SRC-1"),
+        "_Originally by an unknown user on SRC-1_"
+    );
+    assert_eq!(
+        with_replay_line(&json!("Canary is green"), &line),
+        json!(format!("{line}\n\nCanary is green"))
+    );
+    assert_eq!(with_replay_line(&Value::Null, &line), json!(line));
+    let document = json!({"type": "doc", "version": 1, "content": [
+        {"type": "paragraph", "content": [{"type": "text", "text": "Canary is green"}]}
+    ]});
+    let replayed = with_replay_line(&document, &line);
+    assert_eq!(
+        replayed["content"][0],
+        json!({"type": "paragraph", "content": [{"type": "text", "text": line}]})
+    );
+    assert_eq!(replayed["content"][1], document["content"][0]);
+}
+
+#[test]
+fn test_bundle_schema_version_is_checked() {
+    let bundle = json!({
+        "schema_version": BUNDLE_SCHEMA_VERSION,
+        "exported_by": "rust-jira-mcp",
+        "exported_at": "2025-09-04T00:00:00Z",
+        "source": {"site_url": "https://a.example.com", "issue_key": "SRC-1", "issue_id": "1"},
+        "fields": {"summary": "x", "project": {"key": "SRC"}, "issuetype": {"name": "Task"}}
+    });
+    let parsed = IssueBundle::parse(&bundle.to_string()).unwrap();
+    assert_eq!(parsed.project_key(), Some("SRC"));
+    assert_eq!(parsed.issue_type(), Some("Task"));
+    assert!(parsed.comments.is_empty());
+
+    let mut newer = bundle.clone();
+    newer["schema_version"] = json!(BUNDLE_SCHEMA_VERSION + 1);
+    let error = IssueBundle::parse(&newer.to_string())
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("newer than version 1"), "{error}");
+
+    let error = IssueBundle::parse(r#"{"fields": {}}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("no schema_version"), "{error}");
+}
+
+#[tokio::test]
+async fn test_export_writes_a_bundle_with_files() {
+    let mut source = mockito::Server::new_async().await;
+    let mocks = mock_source(&mut source).await;
+    let dir = tempfile::tempdir().unwrap();
+
+    let bundle = export(&source, dir.path()).await;
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+
+    assert_eq!(bundle["schema_version"], BUNDLE_SCHEMA_VERSION);
+    assert_eq!(bundle["source"]["issue_key"], "SRC-1");
+    assert_eq!(bundle["comments"][1]["author"], "Li Wei");
+    assert_eq!(bundle["worklogs"][0]["time_spent"], "2h");
+    assert_eq!(bundle["attachments"][0]["file"], "attachments/plan.txt");
+    assert_eq!(
+        bundle["links"],
+        json!([
+            {"link_type": "Blocks", "direction": "outward", "description": "blocks", "issue_key": "SRC-2"},
+            {"link_type": "Relates", "direction": "inward", "description": "relates to", "issue_key": "OPS-9"}
+        ])
+    );
+    assert_eq!(
+        std::fs::read(dir.path().join("attachments/plan.txt")).unwrap(),
+        SPEC
+    );
+    let written = IssueBundle::read(dir.path()).await.unwrap();
+    assert_eq!(serde_json::to_value(&written).unwrap(), bundle);
+
+    // A second export into the same directory would overwrite the bundle
+    let error = ExportIssueBundleTool::new(create_test_config(&source.url(), dir.path()))
+        .handle(json!({"issue_key": "SRC-1", "target_dir": dir.path().to_str().unwrap()}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("already exists"), "{error}");
+}
+
+#[tokio::test]
+async fn test_bundle_round_trip_to_another_instance() {
+    let mut source = mockito::Server::new_async().await;
+    let _source_mocks = mock_source(&mut source).await;
+    let mut target = mockito::Server::new_async().await;
+    let _project = mock_project_type(&mut target, "/rest/api/2", "DST", "software").await;
+    let dir = tempfile::tempdir().unwrap();
+    export(&source, dir.path()).await;
+
+    let create = target
+        .mock("POST", "/rest/api/2/issue")
+        .match_body(Matcher::Json(json!({
+            "fields": {
+                "project": {"key": "DST"},
+                "issuetype": {"name": "Story"},
+                "summary": "Roll out the new billing service",
+                "description": "Staged rollout, see the attached plan",
+                "priority": {"name": "High"},
+                "labels": ["billing"],
+                "customfield_20200": "Q3"
+            }
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "20001", "key": "DST-7", "self": "x", "fields": {}}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let first_comment = target
+        .mock("POST", "/rest/api/2/issue/DST-7/comment")
+        .match_body(Matcher::Json(json!({
+            "body": "_Originally by Ana Ruiz on SRC-1, 2025-09-02T09:00:00.000+0000_\n\nCanary is green"
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"id": "800", "body": "x", "author": user("svc", "Service"), "created": "now"})
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    // The second comment is refused and reported, without stopping the rest
+    let second_comment = target
+        .mock("POST", "/rest/api/2/issue/DST-7/comment")
+        .match_body(Matcher::PartialJsonString(
+            json!({"body": "_Originally by Li Wei on SRC-1, 2025-09-03T09:00:00.000+0000_\n\nMoving to 50%"})
+                .to_string(),
+        ))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["Comment body too long"]}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let worklog = target
+        .mock("POST", "/rest/api/2/issue/DST-7/worklog")
+        .match_query(Matcher::UrlEncoded("adjustEstimate".into(), "leave".into()))
+        .match_body(Matcher::PartialJson(json!({
+            "comment": "_Originally by Li Wei on SRC-1, 2025-09-02T13:00:00.000+0000_\n\nLoad testing",
+            "time_spent": "2h",
+            "started": "2025-09-02T13:00:00.000+0000"
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "900", "comment": "x", "time_spent": "2h", "time_spent_seconds": 7200,
+                "author": user("svc", "Service"), "created": "now"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let meta = target
+        .mock("GET", "/rest/api/2/attachment/meta")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"enabled": true, "uploadLimit": 1_048_576}).to_string())
+        .create_async()
+        .await;
+    let upload = target
+        .mock("POST", "/rest/api/2/issue/DST-7/attachments")
+        .match_body(Matcher::Regex("Rollout plan: canary first".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([{
+                "self": "x", "id": "1200", "filename": "plan.txt", "author": user("svc", "Service"),
+                "created": "now", "size": SPEC.len(), "mimeType": "text/plain", "content": null
+            }])
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let link = target
+        .mock("POST", "/rest/api/2/issueLink")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = ImportIssueBundleTool::new(create_test_config(&target.url(), dir.path()))
+        .handle(json!({
+            "bundle_dir": dir.path().to_str().unwrap(),
+            "project_key": "DST",
+            "field_mapping": {
+                "copy_fields": ["description", "priority", "labels", "customfield_10100", "status"],
+                "exclude_fields": ["status"],
+                "custom_field_mapping": {"customfield_10100": "customfield_20200"}
+            }
+        }))
+        .await
+        .unwrap();
+    for mock in [
+        create,
+        first_comment,
+        second_comment,
+        worklog,
+        meta,
+        upload,
+        link,
+    ] {
+        mock.assert_async().await;
+    }
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Imported SRC-1 as DST-7\n"), "{text}");
+    assert!(text.contains("\nComments: 1 of 2 imported"), "{text}");
+    assert!(text.contains("\nWorklogs: 1 of 1 imported"), "{text}");
+    assert!(text.contains("\nAttachments: 1 of 1 imported"), "{text}");
+    assert!(text.contains("\nLinks: 0 of 2 imported"), "{text}");
+    assert!(text.contains("\n• comment 301 failed: "), "{text}");
+    assert!(
+        text.contains("\n• link SRC-2 skipped: blocks SRC-2; pass link_issues: true"),
+        "{text}"
+    );
+
+    let report: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(report["issue_key"], "DST-7");
+    let items = report["items"].as_array().unwrap();
+    assert_eq!(items.len(), 6);
+    assert_eq!(
+        items[0],
+        json!({"kind": "comment", "source": "300", "outcome": "imported", "target_id": "800", "message": null})
+    );
+    assert_eq!(items[1]["outcome"], "failed");
+    assert_eq!(items[3]["target_id"], "1200");
+    assert_eq!(items[4]["outcome"], "skipped");
+}
+
+#[tokio::test]
+async fn test_import_links_and_checks_files() {
+    let mut source = mockito::Server::new_async().await;
+    let _source_mocks = mock_source(&mut source).await;
+    let mut target = mockito::Server::new_async().await;
+    let dir = tempfile::tempdir().unwrap();
+    export(&source, dir.path()).await;
+    // The attachment no longer matches its checksum
+    std::fs::write(dir.path().join("attachments/plan.txt"), b"edited").unwrap();
+    let mut bundle: Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.path().join(BUNDLE_FILE_NAME)).unwrap())
+            .unwrap();
+    bundle["comments"] = json!([]);
+    bundle["worklogs"] = json!([]);
+    std::fs::write(dir.path().join(BUNDLE_FILE_NAME), bundle.to_string()).unwrap();
+
+    // Without a field mapping the portable default fields are copied
+    let create = target
+        .mock("POST", "/rest/api/2/issue")
+        .match_body(Matcher::Json(json!({
+            "fields": {
+                "project": {"key": "SRC"},
+                "issuetype": {"name": "Task"},
+                "summary": "Roll out the new billing service",
+                "description": "Staged rollout, see the attached plan",
+                "priority": {"name": "High"},
+                "labels": ["billing"]
+            }
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "20002", "key": "SRC-40", "self": "x", "fields": {}}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let upload = target
+        .mock("POST", "/rest/api/2/issue/SRC-40/attachments")
+        .expect(0)
+        .create_async()
+        .await;
+    let blocks = target
+        .mock("POST", "/rest/api/2/issueLink")
+        .match_body(Matcher::PartialJson(json!({
+            "link_type": {"name": "Blocks"},
+            "inward_issue": {"key": "SRC-40"},
+            "outward_issue": {"key": "SRC-2"}
+        })))
+        .with_status(201)
+        .with_body("{}")
+        .expect(1)
+        .create_async()
+        .await;
+    let relates = target
+        .mock("POST", "/rest/api/2/issueLink")
+        .match_body(Matcher::PartialJson(json!({
+            "link_type": {"name": "Relates"},
+            "inward_issue": {"key": "OPS-9"},
+            "outward_issue": {"key": "SRC-40"}
+        })))
+        .with_status(404)
+        .with_body(json!({"errorMessages": ["Issue Does Not Exist"]}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = ImportIssueBundleTool::new(create_test_config(&target.url(), dir.path()))
+        .handle(json!({
+            "bundle_dir": dir.path().to_str().unwrap(),
+            "issue_type": "Task",
+            "link_issues": true
+        }))
+        .await
+        .unwrap();
+    for mock in [create, upload, blocks, relates] {
+        mock.assert_async().await;
+    }
+
+    let text = &result.content[0].text;
+    assert!(text.contains("\nAttachments: 0 of 1 imported"), "{text}");
+    assert!(
+        text.contains("\n• attachment plan.txt failed: ")
+            && text.contains("changed since the export"),
+        "{text}"
+    );
+    assert!(text.contains("\nLinks: 1 of 2 imported"), "{text}");
+    assert!(text.contains("\n• link OPS-9 failed: "), "{text}");
+    let report: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(report["items"][1]["target_id"], "blocks SRC-2");
+}
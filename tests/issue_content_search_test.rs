@@ -0,0 +1,239 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::issue_content::{
+    changelog_hits, comment_hits, sort_hits, ContentHit, HitLocation,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::SearchIssueContentTool;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        ..Default::default()
+    }
+}
+
+fn comment(id: &str, author: &str, created: &str, body: &str) -> Value {
+    json!({
+        "id": id,
+        "author": {"displayName": author},
+        "body": body,
+        "created": created
+    })
+}
+
+fn history(id: &str, author: &str, created: &str, field: &str, from: &str, to: &str) -> Value {
+    json!({
+        "id": id,
+        "author": {"displayName": author},
+        "created": created,
+        "items": [{"field": field, "fromString": from, "toString": to}]
+    })
+}
+
+#[test]
+fn test_hits_match_whole_query_ignoring_case() {
+    let hits = comment_hits(
+        &comment(
+            "10",
+            "Ana",
+            "2024-03-01T10:00:00.000+0000",
+            "We should plan a ROLLBACK first",
+        ),
+        "rollback",
+    );
+    assert_eq!(
+        hits,
+        [ContentHit {
+            timestamp: "2024-03-01T10:00:00.000+0000".to_string(),
+            author: "Ana".to_string(),
+            location: HitLocation::Comment {
+                id: "10".to_string()
+            },
+            snippet: "We should plan a **ROLLBACK** first".to_string(),
+        }]
+    );
+    // Words of the query on their own are not a hit
+    assert!(comment_hits(
+        &comment("11", "Ana", "2024-03-01T10:00:00.000+0000", "plan the roll"),
+        "rollback plan"
+    )
+    .is_empty());
+
+    // Only the value a change set is searched, not the one it replaced
+    let change = history(
+        "200",
+        "Bo",
+        "2024-03-02T09:00:00.000+0000",
+        "summary",
+        "Rollback of payments",
+        "Payments outage",
+    );
+    assert!(changelog_hits(&change, "rollback").is_empty());
+    let hits = changelog_hits(&change, "outage");
+    assert_eq!(hits[0].location.to_string(), "changelog #200 (summary)");
+}
+
+#[test]
+fn test_sort_hits_orders_by_time_across_formats() {
+    let hit = |timestamp: &str| ContentHit {
+        timestamp: timestamp.to_string(),
+        author: String::new(),
+        location: HitLocation::Description,
+        snippet: String::new(),
+    };
+    let mut hits = vec![
+        hit("not a time"),
+        hit("2024-03-01T12:00:00.000+0200"),
+        hit("2024-03-01T09:30:00Z"),
+    ];
+    sort_hits(&mut hits);
+    let order: Vec<&str> = hits.iter().map(|h| h.timestamp.as_str()).collect();
+    assert_eq!(
+        order,
+        [
+            "2024-03-01T09:30:00Z",
+            "2024-03-01T12:00:00.000+0200",
+            "not a time"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_search_issue_content_pages_and_orders_hits() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _description = server
+        .mock("GET", "/rest/api/2/issue/PAY-7")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "fields".to_string(),
+            "description,created,reporter".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "1",
+                "key": "PAY-7",
+                "self": "x",
+                "fields": {
+                    "description": "Deploy the new ledger. No rollback plan yet.",
+                    "created": "2024-03-01T08:00:00.000+0000",
+                    "reporter": {"displayName": "Cleo"}
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let first_page: Vec<Value> = (0..100)
+        .map(|n| {
+            comment(
+                &format!("{}", 100 + n),
+                "Dev",
+                "2024-03-01T09:00:00.000+0000",
+                "Looks good",
+            )
+        })
+        .collect();
+    let comment_pages = [
+        (
+            "0",
+            json!({"startAt": 0, "total": 101, "comments": first_page}),
+        ),
+        (
+            "100",
+            json!({"startAt": 100, "total": 101, "comments": [comment(
+                "300",
+                "Ana",
+                "2024-03-03T10:00:00.000+0000",
+                "Ran the Rollback in staging"
+            )]}),
+        ),
+    ];
+    for (start_at, body) in comment_pages {
+        server
+            .mock("GET", "/rest/api/2/issue/PAY-7/comment")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "startAt".to_string(),
+                start_at.to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+    }
+    let _changelog = server
+        .mock("GET", "/rest/api/2/issue/PAY-7")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "expand".to_string(),
+            "changelog".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "1",
+                "key": "PAY-7",
+                "fields": {"created": "2024-03-01T08:00:00.000+0000"},
+                "changelog": {"histories": [
+                    history(
+                        "501",
+                        "Bo",
+                        "2024-03-02T15:00:00.000+0000",
+                        "labels",
+                        "",
+                        "needs-rollback"
+                    ),
+                    history(
+                        "502",
+                        "Bo",
+                        "2024-03-02T16:00:00.000+0000",
+                        "status",
+                        "Open",
+                        "In Progress"
+                    )
+                ]}
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let tool = SearchIssueContentTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "PAY-7", "query": "rollback"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Found 3 mentions of \"rollback\" in PAY-7 (description, 101 comments and 2 changelog entries searched), oldest first\n\
+         • 2024-03-01 08:00 +00:00 by Cleo in description: Deploy the new ledger. No **rollback** plan yet.\n\
+         • 2024-03-02 15:00 +00:00 by Bo in changelog #501 (labels): needs-**rollback**\n\
+         • 2024-03-03 10:00 +00:00 by Ana in comment #300: Ran the **Rollback** in staging"
+    );
+    let hits: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(
+        hits[1]["location"],
+        json!({"type": "changelog", "id": "501", "field": "labels"})
+    );
+
+    let result = tool
+        .handle(json!({"issue_key": "PAY-7", "query": "revert"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "No mentions of \"revert\" in PAY-7 (description, 101 comments and 2 changelog entries searched)"
+    );
+}
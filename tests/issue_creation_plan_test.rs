@@ -0,0 +1,204 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::PlanIssueCreationTool;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+/// The create screen of Bug in `project`: summary, priority and a team
+/// select are required, reporter has a default, labels are optional
+async fn mock_create_screen(server: &mut mockito::ServerGuard, project: &str) {
+    server
+        .mock(
+            "GET",
+            format!("/rest/api/2/issue/createmeta/{project}/issuetypes").as_str(),
+        )
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"values": [{"id": "1", "name": "Bug"}]}).to_string())
+        .create_async()
+        .await;
+    server
+        .mock(
+            "GET",
+            format!("/rest/api/2/issue/createmeta/{project}/issuetypes/1").as_str(),
+        )
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"values": [
+                {"fieldId": "project", "name": "Project", "required": true},
+                {"fieldId": "issuetype", "name": "Issue Type", "required": true},
+                {"fieldId": "summary", "name": "Summary", "required": true,
+                 "schema": {"type": "string"}},
+                {"fieldId": "priority", "name": "Priority", "required": true,
+                 "schema": {"type": "priority"},
+                 "allowedValues": [{"id": "1", "name": "High"}, {"id": "3", "name": "Low"}]},
+                {"fieldId": "customfield_10200", "name": "Team", "required": true,
+                 "schema": {"type": "option"},
+                 "allowedValues": (1..=12)
+                    .map(|n| json!({"id": format!("{}", 100 + n), "value": format!("Team {n}")}))
+                    .collect::<Vec<_>>()},
+                {"fieldId": "reporter", "name": "Reporter", "required": true,
+                 "hasDefaultValue": true, "schema": {"type": "user"}},
+                {"fieldId": "labels", "name": "Labels", "required": false,
+                 "schema": {"type": "array", "items": "string"}}
+            ]})
+            .to_string(),
+        )
+        .create_async()
+        .await;
+}
+
+#[tokio::test]
+async fn test_plan_completes_over_two_rounds_and_creates_the_issue() {
+    let mut server = mockito::Server::new_async().await;
+    mock_create_screen(&mut server, "WIZ").await;
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .match_body(mockito::Matcher::Json(json!({"fields": {
+            "project": {"key": "WIZ"},
+            "issuetype": {"id": "1"},
+            "summary": "Login fails",
+            "priority": {"id": "1"},
+            "customfield_10200": {"id": "103"},
+            "labels": ["auth", "web"]
+        }})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10050",
+                "key": "WIZ-7",
+                "self": format!("{}/rest/api/2/issue/10050", server.url()),
+                "fields": {}
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let tool = PlanIssueCreationTool::new(create_test_config(&server.url()));
+
+    // Round one: only the summary is known, so nothing is created
+    let result = tool
+        .handle(json!({
+            "project_key": "WIZ",
+            "issue_type": "Bug",
+            "fields": {"summary": "Login fails"},
+            "create_when_complete": true
+        }))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with(
+            "Not created yet. Creating a Bug in WIZ: 2 of 4 required fields set (by default: Reporter)"
+        ),
+        "{text}"
+    );
+    assert!(
+        text.contains("• Priority (priority) - priority; allowed: High, Low"),
+        "{text}"
+    );
+    assert!(
+        text.contains("• Team (customfield_10200) - option; allowed: Team 1,"),
+        "{text}"
+    );
+    assert!(text.contains("Team 10, … and 2 more"), "{text}");
+    let plan: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(plan["complete"], false);
+    assert_eq!(plan["missing"][0]["field_id"], "priority");
+    assert_eq!(plan["missing"][0]["type"], "priority");
+    assert_eq!(
+        plan["missing"][1]["allowed_values"]
+            .as_array()
+            .unwrap()
+            .len(),
+        10
+    );
+    assert_eq!(plan["missing"][1]["allowed_values_total"], 12);
+    assert_eq!(plan["satisfied"][0]["field_id"], "summary");
+    assert_eq!(plan["satisfied"][1]["from_default"], true);
+    assert!(plan.get("created").is_none());
+
+    // Round two: the rest, by field name and as text
+    let result = tool
+        .handle(json!({
+            "project_key": "WIZ",
+            "issue_type": "Bug",
+            "fields": {
+                "summary": "Login fails",
+                "Priority": "high",
+                "Team": "Team 3",
+                "labels": "auth, web"
+            },
+            "create_when_complete": true
+        }))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with("Issue created successfully: WIZ-7 - Login fails"),
+        "{text}"
+    );
+    let plan: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(plan["complete"], true);
+    assert_eq!(plan["missing"], json!([]));
+    assert_eq!(plan["created"]["key"], "WIZ-7");
+
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_plan_reports_values_it_cannot_use() {
+    let mut server = mockito::Server::new_async().await;
+    mock_create_screen(&mut server, "WIZBAD").await;
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = PlanIssueCreationTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "project_key": "WIZBAD",
+            "issue_type": "Bug",
+            "fields": {
+                "summary": "Login fails",
+                "priority": "Urgent",
+                "customfield_10200": "Team 1",
+                "Story Points": 3
+            }
+        }))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(text.contains("\n\nProblems:\n"), "{text}");
+    assert!(
+        text.contains("• Story Points is not on the create screen of Bug in WIZBAD"),
+        "{text}"
+    );
+    assert!(
+        text.contains(
+            "• 'Urgent' is not an allowed value of Priority (priority); allowed: High, Low"
+        ),
+        "{text}"
+    );
+    let plan: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    // Every required field has a value, but not one the screen accepts
+    assert_eq!(plan["complete"], false);
+    assert_eq!(plan["problems"].as_array().unwrap().len(), 2);
+
+    create.assert_async().await;
+}
@@ -0,0 +1,332 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{rich_text, GetIssueFieldTool};
+use rust_jira_mcp::utils::paths::resolve_output_path;
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn field_catalog() -> serde_json::Value {
+    json!([
+        {"id": "description", "name": "Description", "custom": false},
+        {"id": "customfield_10010", "name": "Acceptance Criteria", "custom": true},
+        {"id": "customfield_10020", "name": "Team", "custom": true},
+        {"id": "customfield_10021", "name": "Team", "custom": true},
+        {"id": "customfield_10030", "name": "Release Notes", "custom": true}
+    ])
+}
+
+async fn mock_catalog(server: &mut mockito::ServerGuard) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/2/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(field_catalog().to_string())
+        .create_async()
+        .await
+}
+
+async fn mock_issue_field(
+    server: &mut mockito::ServerGuard,
+    issue_key: &str,
+    field_id: &str,
+    value: serde_json::Value,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("/rest/api/2/issue/{issue_key}").as_str())
+        .match_query(mockito::Matcher::UrlEncoded(
+            "fields".to_string(),
+            field_id.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10001",
+                "key": issue_key,
+                "self": format!("{}/rest/api/2/issue/10001", server.url()),
+                "fields": {field_id: value}
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[test]
+fn test_match_field_by_id_and_name() {
+    let catalog: Vec<serde_json::Value> = serde_json::from_value(field_catalog()).unwrap();
+
+    let by_id = JiraClient::match_field(&catalog, "customfield_10010").unwrap();
+    assert_eq!(by_id["name"], "Acceptance Criteria");
+
+    let by_name = JiraClient::match_field(&catalog, " acceptance criteria ").unwrap();
+    assert_eq!(by_name["id"], "customfield_10010");
+
+    let ambiguous = JiraClient::match_field(&catalog, "Team")
+        .unwrap_err()
+        .to_string();
+    assert!(ambiguous.contains("customfield_10020, customfield_10021"));
+
+    let missing = JiraClient::match_field(&catalog, "Notes")
+        .unwrap_err()
+        .to_string();
+    assert!(missing.contains("Field 'Notes' does not exist"));
+    assert!(missing.contains("'Release Notes' (customfield_10030)"));
+}
+
+#[test]
+fn test_rich_text_flattens_adf() {
+    let doc = json!({
+        "type": "doc",
+        "version": 1,
+        "content": [
+            {"type": "paragraph", "content": [
+                {"type": "text", "text": "Steps:"},
+                {"type": "hardBreak"},
+                {"type": "text", "text": "ask "},
+                {"type": "mention", "attrs": {"id": "a1", "text": "@Alice"}}
+            ]},
+            {"type": "bulletList", "content": [
+                {"type": "listItem", "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "one"}]}
+                ]}
+            ]}
+        ]
+    });
+
+    assert_eq!(rich_text(&doc).unwrap(), "Steps:\nask @Alice\n- one");
+    assert_eq!(rich_text(&json!("plain")).unwrap(), "plain");
+    assert!(rich_text(&json!({"value": "Red"})).is_none());
+}
+
+#[test]
+fn test_output_path_must_be_allowed() {
+    let allowed = tempfile::tempdir().unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    let dirs = vec![allowed.path().to_path_buf()];
+
+    let inside = allowed.path().join("field.txt");
+    let resolved = resolve_output_path("output_path", inside.to_str().unwrap(), &dirs).unwrap();
+    assert_eq!(
+        resolved,
+        allowed.path().canonicalize().unwrap().join("field.txt")
+    );
+
+    let escape = allowed.path().join("..").join("escape.txt");
+    assert!(resolve_output_path("output_path", escape.to_str().unwrap(), &dirs).is_err());
+
+    let elsewhere = outside.path().join("field.txt");
+    let error = resolve_output_path("output_path", elsewhere.to_str().unwrap(), &dirs)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("outside the allowed directories"));
+
+    assert!(resolve_output_path("output_path", "field.txt", &dirs).is_err());
+
+    let disabled = resolve_output_path("output_path", inside.to_str().unwrap(), &[])
+        .unwrap_err()
+        .to_string();
+    assert!(disabled.contains("JIRA_FILE_ALLOWLIST"));
+}
+
+#[tokio::test]
+async fn test_get_issue_field_by_display_name() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let criteria = "Given a user\n".repeat(2000);
+
+    let _catalog = mock_catalog(&mut server).await;
+    let issue = mock_issue_field(
+        &mut server,
+        "FIELD-1",
+        "customfield_10010",
+        json!(criteria.clone()),
+    )
+    .await;
+
+    let tool = GetIssueFieldTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "FIELD-1", "field": "Acceptance Criteria"}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Field 'Acceptance Criteria' (customfield_10010) of issue FIELD-1:"));
+    assert!(text.ends_with(&criteria));
+    issue.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_issue_field_renders_json_for_structured_values() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _catalog = mock_catalog(&mut server).await;
+    let _issue = mock_issue_field(
+        &mut server,
+        "FIELD-2",
+        "customfield_10030",
+        json!({"value": "Customer facing", "id": "10100"}),
+    )
+    .await;
+
+    let tool = GetIssueFieldTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "FIELD-2", "field": "customfield_10030"}))
+        .await
+        .unwrap();
+
+    assert!(result.content[0]
+        .text
+        .contains("\"value\": \"Customer facing\""));
+}
+
+#[tokio::test]
+async fn test_get_issue_field_distinguishes_missing_and_empty() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _catalog = mock_catalog(&mut server).await;
+    let _issue = mock_issue_field(&mut server, "FIELD-3", "description", json!(null)).await;
+
+    let tool = GetIssueFieldTool::new(create_test_config(&base_url));
+
+    let empty = tool
+        .handle(json!({"issue_key": "FIELD-3", "field": "description"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(empty.contains("Field 'Description' (description) is empty on issue FIELD-3"));
+
+    let missing = tool
+        .handle(json!({"issue_key": "FIELD-3", "field": "Story Points"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(missing.contains("Field 'Story Points' does not exist"));
+}
+
+#[tokio::test]
+async fn test_get_issue_field_writes_output_file() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let dir = tempfile::tempdir().unwrap();
+    let log = "at frame()\n".repeat(5000);
+
+    let _catalog = mock_catalog(&mut server).await;
+    let _issue = mock_issue_field(&mut server, "FIELD-4", "description", json!(log.clone())).await;
+
+    let config = JiraConfig {
+        file_allowlist: Some(dir.path().display().to_string()),
+        ..create_test_config(&base_url)
+    };
+    let path = dir.path().join("FIELD-4-description.txt");
+
+    let tool = GetIssueFieldTool::new(config);
+    let result = tool
+        .handle(json!({
+            "issue_key": "FIELD-4",
+            "field": "description",
+            "output_path": path.to_str().unwrap()
+        }))
+        .await
+        .unwrap();
+
+    assert!(result.content[0].text.contains("Wrote field 'Description'"));
+    assert!(!result.content[0].text.contains("at frame()"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), log);
+}
+
+#[tokio::test]
+async fn test_get_issue_field_fetches_single_comment() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let body = "ü".repeat(500);
+
+    let all_comments = server
+        .mock("GET", "/rest/api/2/issue/FIELD-5/comment")
+        .match_query(mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let comment = server
+        .mock("GET", "/rest/api/2/issue/FIELD-5/comment/10100")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10100",
+                "body": body,
+                "author": {"displayName": "Alice Example"},
+                "created": "2024-03-01T09:00:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = GetIssueFieldTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "FIELD-5", "field": "comment:10100"}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Comment 10100 of issue FIELD-5:"));
+    assert!(text.contains("by Alice Example"));
+    assert!(text.contains(&body));
+    comment.assert_async().await;
+    all_comments.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_issue_field_fetches_single_work_log() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let work_log = server
+        .mock("GET", "/rest/api/2/issue/FIELD-6/worklog/20200")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "20200",
+                "timeSpent": "2h",
+                "comment": "Profiled the import",
+                "author": {"displayName": "Bob Example"},
+                "started": "2024-03-01T09:00:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = GetIssueFieldTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "FIELD-6", "field": "worklog:20200"}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Work log 20200 of issue FIELD-6:"));
+    assert!(text.contains("2h by Bob Example"));
+    assert!(text.contains("Profiled the import"));
+    work_log.assert_async().await;
+}
@@ -0,0 +1,309 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{GetIssueTool, SetIssueParentTool};
+use serde_json::{json, Value};
+
+const PROJECT_TEAM_MANAGED: &str = include_str!("fixtures/project_team_managed.json");
+const PROJECT_DC_EPICS: &str = include_str!("fixtures/project_dc_epics.json");
+const FIELDS_DC_EPIC_LINK: &str = include_str!("fixtures/fields_dc_epic_link.json");
+
+fn create_test_config(api_base_url: String) -> JiraConfig {
+    JiraConfig {
+        api_base_url,
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn mock_get(server: &mut mockito::ServerGuard, path: &str, body: String) {
+    server
+        .mock("GET", path)
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+        .create_async()
+        .await;
+}
+
+async fn mock_issue(server: &mut mockito::ServerGuard, api: &str, key: &str, fields: Value) {
+    let body = json!({
+        "id": "1",
+        "key": key,
+        "self": format!("{}{api}/issue/1", server.url()),
+        "fields": fields
+    });
+    mock_get(server, &format!("{api}/issue/{key}"), body.to_string()).await;
+}
+
+async fn mock_update(
+    server: &mut mockito::ServerGuard,
+    api: &str,
+    key: &str,
+    body: Value,
+) -> mockito::Mock {
+    server
+        .mock("PUT", format!("{api}/issue/{key}").as_str())
+        .match_body(mockito::Matcher::Json(body))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await
+}
+
+fn issue_type(id: &str, name: &str, level: Option<i64>) -> Value {
+    let mut issue_type = json!({"id": id, "name": name, "subtask": level == Some(-1)});
+    if let Some(level) = level {
+        issue_type["hierarchyLevel"] = json!(level);
+    }
+    issue_type
+}
+
+#[tokio::test]
+async fn test_team_managed_issue_moves_through_the_parent_field() {
+    let mut server = mockito::Server::new_async().await;
+    let api = "/rest/api/2";
+    mock_get(&mut server, &format!("{api}/field"), "[]".to_string()).await;
+    mock_get(
+        &mut server,
+        &format!("{api}/project/TMP"),
+        PROJECT_TEAM_MANAGED.to_string(),
+    )
+    .await;
+    let story = issue_type("10311", "Story", Some(0));
+    let epic = issue_type("10310", "Epic", Some(1));
+    mock_issue(
+        &mut server,
+        api,
+        "TMP-2",
+        json!({
+            "summary": "Pay by card",
+            "issuetype": story,
+            "project": {"key": "TMP"},
+            "parent": {"key": "TMP-1", "fields": {"summary": "Checkout"}}
+        }),
+    )
+    .await;
+    mock_issue(
+        &mut server,
+        api,
+        "TMP-5",
+        json!({"issuetype": epic, "project": {"key": "TMP"}}),
+    )
+    .await;
+    mock_issue(
+        &mut server,
+        api,
+        "OTH-1",
+        json!({"issuetype": epic, "project": {"key": "OTH"}}),
+    )
+    .await;
+    mock_issue(
+        &mut server,
+        api,
+        "TMP-3",
+        json!({"issuetype": story, "project": {"key": "TMP"}}),
+    )
+    .await;
+    let update = mock_update(
+        &mut server,
+        api,
+        "TMP-2",
+        json!({"fields": {"parent": {"key": "TMP-5"}}}),
+    )
+    .await;
+    let config = create_test_config(format!("{}{api}", server.url()));
+
+    let result = GetIssueTool::new(config.clone())
+        .handle(json!({"issue_key": "TMP-2"}))
+        .await
+        .unwrap();
+    assert!(
+        result.content[0]
+            .text
+            .contains("\nParent: TMP-1 - Checkout\n"),
+        "{}",
+        result.content[0].text
+    );
+
+    let tool = SetIssueParentTool::new(config);
+    let result = tool
+        .handle(json!({"issue_key": "TMP-2", "parent_key": "TMP-5"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "TMP-2 is now under TMP-5 (set through the parent field)"
+    );
+    let change: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(change["previous_parent_key"], "TMP-1");
+    assert_eq!(change["mechanism"]["mechanism"], "parent_field");
+
+    // Team-managed parents must be in the same project
+    let error = tool
+        .handle(json!({"issue_key": "TMP-2", "parent_key": "OTH-1"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains(
+            "OTH-1 is in project OTH, but the parent of issues of team-managed projects \
+             must be in the same project (TMP)"
+        ),
+        "{error}"
+    );
+    // ...and one level up
+    let error = tool
+        .handle(json!({"issue_key": "TMP-2", "parent_key": "TMP-3"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains(
+            "Story cannot be a child of Story on this instance; Story issues go under Epic"
+        ),
+        "{error}"
+    );
+
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_data_center_story_moves_through_the_epic_link_field() {
+    let mut server = mockito::Server::new_async().await;
+    // A context-path install, which also keeps this catalog apart from the
+    // other test's in the metadata cache
+    let api = "/jira/rest/api/2";
+    mock_get(
+        &mut server,
+        &format!("{api}/field"),
+        FIELDS_DC_EPIC_LINK.to_string(),
+    )
+    .await;
+    mock_get(
+        &mut server,
+        &format!("{api}/project/DCP"),
+        PROJECT_DC_EPICS.to_string(),
+    )
+    .await;
+    let story = issue_type("10001", "Story", None);
+    let epic = issue_type("10000", "Epic", None);
+    let subtask = json!({"id": "10003", "name": "Sub-task", "subtask": true});
+    mock_issue(
+        &mut server,
+        api,
+        "DCP-3",
+        json!({
+            "summary": "Export to CSV",
+            "issuetype": story,
+            "project": {"key": "DCP"},
+            "customfield_10008": "DCP-1"
+        }),
+    )
+    .await;
+    mock_issue(
+        &mut server,
+        api,
+        "OPS-9",
+        json!({"issuetype": epic, "project": {"key": "OPS"}}),
+    )
+    .await;
+    mock_issue(
+        &mut server,
+        api,
+        "DCP-4",
+        json!({"issuetype": subtask, "project": {"key": "DCP"}, "parent": {"key": "DCP-3"}}),
+    )
+    .await;
+    mock_issue(
+        &mut server,
+        api,
+        "DCP-6",
+        json!({"issuetype": story, "project": {"key": "DCP"}}),
+    )
+    .await;
+    let to_epic = mock_update(
+        &mut server,
+        api,
+        "DCP-3",
+        json!({"fields": {"customfield_10008": "OPS-9"}}),
+    )
+    .await;
+    let detach = mock_update(
+        &mut server,
+        api,
+        "DCP-3",
+        json!({"fields": {"customfield_10008": null}}),
+    )
+    .await;
+    let subtask_move = mock_update(
+        &mut server,
+        api,
+        "DCP-4",
+        json!({"fields": {"parent": {"key": "DCP-6"}}}),
+    )
+    .await;
+    let config = create_test_config(format!("{}{api}", server.url()));
+
+    let result = GetIssueTool::new(config.clone())
+        .handle(json!({"issue_key": "DCP-3"}))
+        .await
+        .unwrap();
+    assert!(
+        result.content[0].text.contains("\nParent: DCP-1\n"),
+        "{}",
+        result.content[0].text
+    );
+
+    let tool = SetIssueParentTool::new(config);
+    // Company-managed epics may be in another project
+    let result = tool
+        .handle(json!({"issue_key": "DCP-3", "parent_key": "OPS-9"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "DCP-3 is now under OPS-9 (set through the Epic Link field customfield_10008)"
+    );
+
+    let result = tool
+        .handle(json!({"issue_key": "DCP-3", "parent_key": null}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "DCP-3 was detached from DCP-1 (cleared the Epic Link field customfield_10008)"
+    );
+
+    // Subtasks always use the parent field and cannot be detached
+    let result = tool
+        .handle(json!({"issue_key": "DCP-4", "parent_key": "DCP-6"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "DCP-4 is now under DCP-6 (set through the parent field)"
+    );
+    let error = tool
+        .handle(json!({"issue_key": "DCP-4", "parent_key": null}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("DCP-4 is a subtask and cannot be detached from its parent"),
+        "{error}"
+    );
+
+    let error = tool
+        .handle(json!({"issue_key": "DCP-3"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("parent_key"), "{error}");
+
+    to_epic.assert_async().await;
+    detach.assert_async().await;
+    subtask_move.assert_async().await;
+}
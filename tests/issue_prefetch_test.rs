@@ -0,0 +1,500 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::prefetch::{
+    invalidate_endpoint, shared_prefetcher, Prefetcher, MAX_WARM_ISSUES,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::GetServerMetricsTool;
+use rust_jira_mcp::performance::PerformanceMetrics;
+use serde_json::{json, Value};
+use std::cell::Cell;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const INTERVAL: Duration = Duration::from_secs(60);
+
+/// A clock that only moves when told to
+struct FakeClock {
+    start: Instant,
+    elapsed: Cell<Duration>,
+}
+
+impl FakeClock {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: Cell::new(Duration::ZERO),
+        }
+    }
+
+    fn now(&self) -> Instant {
+        self.start + self.elapsed.get()
+    }
+
+    fn advance(&self, by: Duration) {
+        self.elapsed.set(self.elapsed.get() + by);
+    }
+}
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        prefetch_enabled: Some(true),
+        prefetch_threshold: Some(2),
+        prefetch_interval_seconds: Some(INTERVAL.as_secs()),
+        ..Default::default()
+    }
+}
+
+fn issue(key: &str, id: &str, summary: &str) -> Value {
+    json!({
+        "id": id,
+        "key": key,
+        "self": format!("https://jira.example.com/rest/api/2/issue/{id}"),
+        "fields": {"summary": summary}
+    })
+}
+
+/// A prefetcher keeping `key` warm, with a copy fetched at the clock's now
+fn warm_prefetcher(clock: &FakeClock, key: &str) -> Prefetcher {
+    let prefetcher = Prefetcher::new(2, INTERVAL);
+    assert!(prefetcher.record_access(key, 3, clock.now()));
+    prefetcher.store(
+        key,
+        &issue(key, "10001", "Warm"),
+        prefetcher.generation(),
+        clock.now(),
+    );
+    prefetcher
+}
+
+#[test]
+fn test_issue_is_kept_warm_after_threshold() {
+    let clock = FakeClock::new();
+    let prefetcher = Prefetcher::new(2, INTERVAL);
+
+    assert!(!prefetcher.record_access("OPS-1", 1, clock.now()));
+    assert!(!prefetcher.record_access("OPS-1", 2, clock.now()));
+    prefetcher.store(
+        "OPS-1",
+        &issue("OPS-1", "10001", "Cold"),
+        prefetcher.generation(),
+        clock.now(),
+    );
+    assert!(prefetcher.cached("OPS-1", clock.now()).is_none());
+
+    assert!(prefetcher.record_access("OPS-1", 3, clock.now()));
+    assert!(prefetcher.cached("OPS-1", clock.now()).is_none());
+    prefetcher.store(
+        "OPS-1",
+        &issue("OPS-1", "10001", "Warm"),
+        prefetcher.generation(),
+        clock.now(),
+    );
+    assert_eq!(
+        prefetcher.cached("OPS-1", clock.now()).unwrap()["fields"]["summary"],
+        "Warm"
+    );
+}
+
+#[test]
+fn test_refresh_cadence() {
+    let clock = FakeClock::new();
+    let prefetcher = warm_prefetcher(&clock, "OPS-1");
+
+    assert!(prefetcher.due(clock.now()).is_empty());
+    assert_eq!(prefetcher.next_wait(clock.now()), Some(INTERVAL));
+
+    clock.advance(INTERVAL - Duration::from_secs(1));
+    assert!(prefetcher.due(clock.now()).is_empty());
+    assert_eq!(
+        prefetcher.next_wait(clock.now()),
+        Some(Duration::from_secs(1))
+    );
+
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(prefetcher.due(clock.now()), vec!["OPS-1"]);
+    prefetcher.refreshed(
+        "OPS-1",
+        &issue("OPS-1", "10001", "Refreshed"),
+        prefetcher.generation(),
+        clock.now(),
+    );
+    assert!(prefetcher.due(clock.now()).is_empty());
+
+    // The next refresh is an interval after the last one
+    clock.advance(INTERVAL - Duration::from_secs(1));
+    assert!(prefetcher.due(clock.now()).is_empty());
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(prefetcher.due(clock.now()), vec!["OPS-1"]);
+
+    let status = prefetcher.snapshot(true, clock.now());
+    assert_eq!(status.refreshes, 1);
+    assert_eq!(status.warm_issues[0].refreshes, 1);
+    assert_eq!(status.warm_issues[0].age_seconds, Some(INTERVAL.as_secs()));
+    assert_eq!(status.warm_issues[0].next_refresh_in_seconds, 0);
+}
+
+#[test]
+fn test_due_issues_longest_overdue_first() {
+    let clock = FakeClock::new();
+    let prefetcher = warm_prefetcher(&clock, "OPS-2");
+    clock.advance(Duration::from_secs(10));
+    assert!(prefetcher.record_access("OPS-1", 3, clock.now()));
+    prefetcher.store(
+        "OPS-1",
+        &issue("OPS-1", "10002", "Later"),
+        prefetcher.generation(),
+        clock.now(),
+    );
+
+    clock.advance(INTERVAL);
+    assert_eq!(prefetcher.due(clock.now()), vec!["OPS-2", "OPS-1"]);
+}
+
+#[test]
+fn test_failed_refresh_keeps_copy_and_retries_after_interval() {
+    let clock = FakeClock::new();
+    let prefetcher = warm_prefetcher(&clock, "OPS-1");
+
+    clock.advance(INTERVAL);
+    prefetcher.refresh_failed("OPS-1", "HTTP error: 503".to_string(), clock.now());
+    assert!(prefetcher.due(clock.now()).is_empty());
+    assert!(prefetcher.cached("OPS-1", clock.now()).is_some());
+
+    clock.advance(INTERVAL);
+    assert_eq!(prefetcher.due(clock.now()), vec!["OPS-1"]);
+    // A copy older than two intervals is not served
+    assert!(prefetcher.cached("OPS-1", clock.now()).is_some());
+    clock.advance(Duration::from_secs(1));
+    assert!(prefetcher.cached("OPS-1", clock.now()).is_none());
+
+    let status = prefetcher.snapshot(true, clock.now());
+    assert_eq!(status.failed_refreshes, 1);
+    assert_eq!(
+        status.warm_issues[0].last_error.as_deref(),
+        Some("HTTP error: 503")
+    );
+}
+
+#[test]
+fn test_invalidate_by_key_or_id() {
+    let clock = FakeClock::new();
+    let prefetcher = warm_prefetcher(&clock, "OPS-1");
+
+    assert!(!prefetcher.invalidate("OPS-2"));
+    assert!(prefetcher.invalidate("ops-1"));
+    assert!(prefetcher.cached("OPS-1", clock.now()).is_none());
+    assert!(!prefetcher.invalidate("OPS-1"));
+
+    prefetcher.store(
+        "OPS-1",
+        &issue("OPS-1", "10001", "Again"),
+        prefetcher.generation(),
+        clock.now(),
+    );
+    assert!(prefetcher.invalidate("10001"));
+    assert!(prefetcher.cached("OPS-1", clock.now()).is_none());
+
+    // Still warm: the next refresh fetches it again
+    clock.advance(INTERVAL);
+    assert_eq!(prefetcher.due(clock.now()), vec!["OPS-1"]);
+    assert_eq!(prefetcher.snapshot(true, clock.now()).invalidations, 2);
+}
+
+#[test]
+fn test_fetch_overtaken_by_a_change_is_not_kept() {
+    let clock = FakeClock::new();
+    let prefetcher = warm_prefetcher(&clock, "OPS-1");
+
+    // Fetched before the change, stored after it
+    let generation = prefetcher.generation();
+    assert!(prefetcher.invalidate("OPS-1"));
+    assert!(!prefetcher.store(
+        "OPS-1",
+        &issue("OPS-1", "10001", "Stale"),
+        generation,
+        clock.now()
+    ));
+    assert!(prefetcher.cached("OPS-1", clock.now()).is_none());
+
+    // A background refresh overtaken by a change leaves the issue due
+    clock.advance(INTERVAL);
+    let generation = prefetcher.generation();
+    prefetcher.invalidate_all();
+    assert!(!prefetcher.refreshed(
+        "OPS-1",
+        &issue("OPS-1", "10001", "Stale"),
+        generation,
+        clock.now()
+    ));
+    assert_eq!(prefetcher.due(clock.now()), vec!["OPS-1"]);
+    assert_eq!(prefetcher.snapshot(true, clock.now()).refreshes, 0);
+
+    // A fetch started after the change is kept
+    let generation = prefetcher.generation();
+    assert!(prefetcher.refreshed(
+        "OPS-1",
+        &issue("OPS-1", "10001", "Fresh"),
+        generation,
+        clock.now()
+    ));
+    assert_eq!(
+        prefetcher.cached("OPS-1", clock.now()).unwrap()["fields"]["summary"],
+        "Fresh"
+    );
+}
+
+#[test]
+fn test_invalidate_all() {
+    let clock = FakeClock::new();
+    let prefetcher = warm_prefetcher(&clock, "OPS-1");
+    assert!(prefetcher.record_access("OPS-2", 3, clock.now()));
+    prefetcher.store(
+        "OPS-2",
+        &issue("OPS-2", "10002", "Warm"),
+        prefetcher.generation(),
+        clock.now(),
+    );
+
+    assert_eq!(prefetcher.invalidate_all(), 2);
+    assert!(prefetcher.cached("OPS-1", clock.now()).is_none());
+    assert!(prefetcher.cached("OPS-2", clock.now()).is_none());
+    assert_eq!(prefetcher.invalidate_all(), 0);
+}
+
+#[test]
+fn test_warm_issues_are_capped() {
+    let clock = FakeClock::new();
+    let prefetcher = Prefetcher::new(0, INTERVAL);
+    for n in 0..MAX_WARM_ISSUES {
+        assert!(prefetcher.record_access(&format!("OPS-{n}"), 1, clock.now()));
+    }
+    assert!(!prefetcher.record_access("OPS-LAST", 100, clock.now()));
+    assert_eq!(
+        prefetcher.snapshot(true, clock.now()).warm_issues.len(),
+        MAX_WARM_ISSUES
+    );
+}
+
+#[test]
+fn test_issue_access_counts() {
+    let metrics = PerformanceMetrics::new();
+    assert_eq!(metrics.record_issue_access("OPS-1"), 1);
+    assert_eq!(metrics.record_issue_access("OPS-1"), 2);
+    assert_eq!(metrics.record_issue_access("OPS-2"), 1);
+    assert_eq!(metrics.issue_access_count("OPS-1"), 2);
+
+    metrics.reset();
+    assert_eq!(metrics.issue_access_count("OPS-1"), 0);
+}
+
+#[test]
+fn test_invalidate_endpoint() {
+    let config = create_test_config("http://prefetch-invalidate.invalid");
+    let prefetcher = shared_prefetcher(&config);
+    let clock = FakeClock::new();
+    for key in ["INV-1", "INV-2"] {
+        assert!(prefetcher.record_access(key, 3, clock.now()));
+        prefetcher.store(
+            key,
+            &issue(key, key, "Warm"),
+            prefetcher.generation(),
+            clock.now(),
+        );
+    }
+
+    invalidate_endpoint(&config, "issue/INV-1/comment");
+    assert!(prefetcher.cached("INV-1", clock.now()).is_none());
+    assert!(prefetcher.cached("INV-2", clock.now()).is_some());
+
+    // Links name no issue in their endpoint
+    invalidate_endpoint(&config, "issueLink");
+    assert!(prefetcher.cached("INV-2", clock.now()).is_none());
+
+    // Nothing is dropped once prefetching is switched off
+    prefetcher.store(
+        "INV-2",
+        &issue("INV-2", "INV-2", "Warm"),
+        prefetcher.generation(),
+        clock.now(),
+    );
+    let disabled = JiraConfig {
+        prefetch_enabled: Some(false),
+        ..config
+    };
+    invalidate_endpoint(&disabled, "issueLink");
+    assert!(prefetcher.cached("INV-2", clock.now()).is_some());
+}
+
+#[tokio::test]
+async fn test_client_serves_warm_issue_and_refetches_after_update() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let client = JiraClient::new(config.clone()).unwrap();
+
+    let fetches = server
+        .mock("GET", "/rest/api/2/issue/PRE-1")
+        .with_status(200)
+        .with_body(issue("PRE-1", "20001", "First").to_string())
+        .expect(3)
+        .create_async()
+        .await;
+
+    // Fetched twice without being warm, the third fetch keeps a copy
+    for _ in 0..3 {
+        client.get_issue("PRE-1").await.unwrap();
+    }
+    // Served from the copy
+    let served = client.get_issue("pre-1").await.unwrap();
+    assert_eq!(served.fields["summary"], "First");
+    fetches.assert_async().await;
+
+    // Refreshed in the background once an interval has passed
+    let prefetcher = shared_prefetcher(&config);
+    let clock = FakeClock::new();
+    fetches.remove_async().await;
+    let refresh = server
+        .mock("GET", "/rest/api/2/issue/PRE-1")
+        .with_status(200)
+        .with_body(issue("PRE-1", "20001", "Refreshed").to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    clock.advance(INTERVAL - Duration::from_secs(1));
+    assert_eq!(
+        client.refresh_warm_issues(&prefetcher, clock.now()).await,
+        0
+    );
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(
+        client.refresh_warm_issues(&prefetcher, clock.now()).await,
+        1
+    );
+    refresh.assert_async().await;
+    assert_eq!(
+        client.get_issue("PRE-1").await.unwrap().fields["summary"],
+        "Refreshed"
+    );
+
+    // An update drops the copy, so the next read goes to Jira
+    let update = server
+        .mock("PUT", "/rest/api/2/issue/PRE-1")
+        .with_status(204)
+        .create_async()
+        .await;
+    refresh.remove_async().await;
+    let after_update = server
+        .mock("GET", "/rest/api/2/issue/PRE-1")
+        .with_status(200)
+        .with_body(issue("PRE-1", "20001", "Updated").to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    client
+        .update_issue("PRE-1", &json!({"fields": {"summary": "Updated"}}))
+        .await
+        .unwrap();
+    update.assert_async().await;
+    assert_eq!(
+        client.get_issue("PRE-1").await.unwrap().fields["summary"],
+        "Updated"
+    );
+    assert_eq!(
+        client.get_issue("PRE-1").await.unwrap().fields["summary"],
+        "Updated"
+    );
+    after_update.assert_async().await;
+
+    // get_server_metrics reports the warm issue
+    let metrics = GetServerMetricsTool::new(config)
+        .handle(json!({}))
+        .await
+        .unwrap();
+    let text = &metrics.content[0].text;
+    let metrics: Value =
+        serde_json::from_str(text.trim_start_matches("Server metrics:\n")).unwrap();
+    let prefetch = &metrics["prefetch"];
+    assert_eq!(prefetch["enabled"], true);
+    assert_eq!(prefetch["threshold"], 2);
+    assert_eq!(prefetch["refreshes"], 1);
+    assert_eq!(prefetch["invalidations"], 1);
+    assert_eq!(prefetch["served_from_cache"], 3);
+    assert_eq!(prefetch["warm_issues"][0]["issue_key"], "PRE-1");
+    assert_eq!(prefetch["warm_issues"][0]["accesses"], 7);
+    assert_eq!(prefetch["warm_issues"][0]["cached"], true);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_change_during_a_fetch_drops_its_response() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let client = Arc::new(JiraClient::new(config.clone()).unwrap());
+    let prefetcher = shared_prefetcher(&config);
+
+    let quick = server
+        .mock("GET", "/rest/api/2/issue/RACE-1")
+        .with_status(200)
+        .with_body(issue("RACE-1", "40001", "Before").to_string())
+        .expect(2)
+        .create_async()
+        .await;
+    for _ in 0..2 {
+        client.get_issue("RACE-1").await.unwrap();
+    }
+    quick.assert_async().await;
+    quick.remove_async().await;
+
+    // The third fetch makes the issue warm, and is answered after an update
+    let slow = server
+        .mock("GET", "/rest/api/2/issue/RACE-1")
+        .with_status(200)
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_millis(300));
+            w.write_all(issue("RACE-1", "40001", "Before").to_string().as_bytes())
+        })
+        .expect(1)
+        .create_async()
+        .await;
+    let fetch = tokio::spawn({
+        let client = Arc::clone(&client);
+        async move { client.get_issue("RACE-1").await }
+    });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    invalidate_endpoint(&config, "issue/RACE-1");
+    assert_eq!(fetch.await.unwrap().unwrap().fields["summary"], "Before");
+    slow.assert_async().await;
+
+    // The response predates the update, so the next read goes to Jira
+    assert!(prefetcher.cached("RACE-1", Instant::now()).is_none());
+}
+
+#[tokio::test]
+async fn test_client_without_prefetch_always_fetches() {
+    let mut server = mockito::Server::new_async().await;
+    let config = JiraConfig {
+        prefetch_enabled: Some(false),
+        ..create_test_config(&server.url())
+    };
+    let client = JiraClient::new(config.clone()).unwrap();
+    let fetches = server
+        .mock("GET", "/rest/api/2/issue/OFF-1")
+        .with_status(200)
+        .with_body(issue("OFF-1", "30001", "Off").to_string())
+        .expect(5)
+        .create_async()
+        .await;
+
+    for _ in 0..5 {
+        client.get_issue("OFF-1").await.unwrap();
+    }
+    fetches.assert_async().await;
+    assert!(shared_prefetcher(&config)
+        .snapshot(false, Instant::now())
+        .warm_issues
+        .iter()
+        .all(|warm| warm.issue_key != "OFF-1"));
+}
@@ -0,0 +1,226 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::hierarchy::{IssueTypeHierarchy, IssueTypeLevel};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{CreateIssueTool, GetIssueTypeHierarchyTool};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn issue_type(id: &str, name: &str, subtask: bool, level: Option<i64>) -> Value {
+    let mut issue_type = json!({
+        "self": format!("https://jira.example.com/rest/api/2/issuetype/{id}"),
+        "id": id,
+        "name": name,
+        "description": "",
+        "subtask": subtask
+    });
+    if let Some(level) = level {
+        issue_type["hierarchyLevel"] = json!(level);
+    }
+    issue_type
+}
+
+/// `GET project/{key}` on Cloud, with an Advanced Roadmaps level above epics
+fn cloud_project() -> Value {
+    json!({
+        "id": "10000",
+        "key": "CLOUD",
+        "issueTypes": [
+            issue_type("10000", "Epic", false, Some(1)),
+            issue_type("10001", "Story", false, Some(0)),
+            issue_type("10002", "Task", false, Some(0)),
+            issue_type("10003", "Subtask", true, Some(-1)),
+            issue_type("10004", "Initiative", false, Some(2))
+        ]
+    })
+}
+
+/// `GET project/{key}` on Data Center, which reports no levels
+fn data_center_project() -> Value {
+    json!({
+        "id": "10100",
+        "key": "DC",
+        "issueTypes": [
+            issue_type("1", "Bug", false, None),
+            issue_type("3", "Task", false, None),
+            issue_type("5", "Sub-task", true, None),
+            issue_type("6", "Epic", false, None),
+            issue_type("7", "Story", false, None)
+        ]
+    })
+}
+
+fn level(hierarchy: &IssueTypeHierarchy, name: &str) -> IssueTypeLevel {
+    hierarchy.find(name).unwrap().clone()
+}
+
+#[test]
+fn test_cloud_hierarchy_uses_reported_levels() {
+    let hierarchy = IssueTypeHierarchy::from_project("CLOUD", &cloud_project()).unwrap();
+    assert!(!hierarchy.inferred);
+
+    let levels: Vec<(i64, Vec<&str>)> = hierarchy
+        .levels()
+        .into_iter()
+        .map(|(level, types)| (level, types.iter().map(|t| t.name.as_str()).collect()))
+        .collect();
+    assert_eq!(
+        levels,
+        [
+            (2, vec!["Initiative"]),
+            (1, vec!["Epic"]),
+            (0, vec!["Story", "Task"]),
+            (-1, vec!["Subtask"])
+        ]
+    );
+
+    assert!(hierarchy
+        .check_parent("story", &level(&hierarchy, "Epic"))
+        .is_ok());
+    assert!(hierarchy
+        .check_parent("10000", &level(&hierarchy, "Initiative"))
+        .is_ok());
+    let error = hierarchy
+        .check_parent("Story", &level(&hierarchy, "Task"))
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Validation error: parent - Story cannot be a child of Task on this instance; \
+         Story issues go under Epic"
+    );
+    let error = hierarchy
+        .check_parent("Bug", &level(&hierarchy, "Epic"))
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Issue type 'Bug' is not one of the issue types of CLOUD"));
+}
+
+#[test]
+fn test_data_center_hierarchy_is_inferred() {
+    let hierarchy = IssueTypeHierarchy::from_project("DC", &data_center_project()).unwrap();
+    assert!(hierarchy.inferred);
+    assert_eq!(level(&hierarchy, "Epic").level, 1);
+    assert_eq!(level(&hierarchy, "Bug").level, 0);
+    assert_eq!(level(&hierarchy, "Sub-task").level, -1);
+
+    assert!(hierarchy
+        .check_parent("Sub-task", &level(&hierarchy, "Bug"))
+        .is_ok());
+    let error = hierarchy
+        .check_parent("Sub-task", &level(&hierarchy, "Epic"))
+        .unwrap_err();
+    assert!(error.to_string().contains(
+        "Sub-task cannot be a child of Epic on this instance; Sub-task issues go under \
+         Bug or Task or Story"
+    ));
+    let error = hierarchy
+        .check_parent("Epic", &level(&hierarchy, "Story"))
+        .unwrap_err();
+    assert!(error.to_string().ends_with("Epic issues have no parent"));
+}
+
+#[tokio::test]
+async fn test_hierarchy_tool_lists_levels() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let project = server
+        .mock("GET", "/rest/api/2/project/DC")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(data_center_project().to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = GetIssueTypeHierarchyTool::new(create_test_config(&base_url));
+    let result = tool.handle(json!({"project_key": "DC"})).await.unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Issue type hierarchy of DC, highest level first (levels inferred, as Jira reports none):\n\
+         • level 1: Epic\n\
+         • level 0: Bug, Task, Story\n\
+         • level -1: Sub-task"
+    );
+    // The hierarchy is cached
+    tool.handle(json!({"project_key": "DC"})).await.unwrap();
+    project.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_refuses_a_parent_at_the_wrong_level() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _project = server
+        .mock("GET", "/rest/api/2/project/CLOUD")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(cloud_project().to_string())
+        .create_async()
+        .await;
+    for (key, parent_type) in [
+        ("CLOUD-1", issue_type("10002", "Task", false, Some(0))),
+        ("CLOUD-2", issue_type("10000", "Epic", false, Some(1))),
+    ] {
+        server
+            .mock("GET", format!("/rest/api/2/issue/{key}").as_str())
+            .match_query(mockito::Matcher::UrlEncoded(
+                "fields".to_string(),
+                "issuetype".to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({"id": "1", "key": key, "self": "x", "fields": {"issuetype": parent_type}})
+                    .to_string(),
+            )
+            .create_async()
+            .await;
+    }
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({"fields": {"parent": {"key": "CLOUD-2"}}}),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "2", "key": "CLOUD-3", "self": "x", "fields": {}}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = CreateIssueTool::new(create_test_config(&base_url));
+    let fields = json!({
+        "project": {"key": "CLOUD"},
+        "issuetype": {"name": "Story"},
+        "summary": "Checkout flow"
+    });
+    let error = tool
+        .handle(json!({"fields": fields, "parent_key": "CLOUD-1"}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Story cannot be a child of Task on this instance"));
+
+    let result = tool
+        .handle(json!({"fields": fields, "parent_key": "CLOUD-2"}))
+        .await
+        .unwrap();
+    assert!(result.content[0]
+        .text
+        .starts_with("Issue created successfully: CLOUD-3 - Checkout flow"));
+    create.assert_async().await;
+}
@@ -0,0 +1,223 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::issue_update::{
+    check_update_against_editmeta, item_operations, parse_update,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{BulkUpdateIssuesTool, UpdateIssueTool};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn mock_update(
+    server: &mut mockito::ServerGuard,
+    key: &str,
+    body: Value,
+    hits: usize,
+) -> mockito::Mock {
+    server
+        .mock("PUT", format!("/rest/api/2/issue/{key}").as_str())
+        .match_body(mockito::Matcher::Json(body))
+        .with_status(204)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+fn editmeta() -> Value {
+    json!({
+        "fields": {
+            "labels": {"operations": ["add", "set", "remove"]},
+            "components": {"operations": ["add", "set", "remove"]},
+            "fixVersions": {"operations": ["set", "add", "remove"]},
+            "summary": {"operations": ["set"]}
+        }
+    })
+}
+
+#[test]
+fn test_item_operations_name_components_and_versions() {
+    let items = ["a".to_string(), "b".to_string()];
+    assert_eq!(
+        item_operations("labels", "add", &items),
+        vec![json!({"add": "a"}), json!({"add": "b"})]
+    );
+    assert_eq!(
+        item_operations("components", "remove", &items[..1]),
+        vec![json!({"remove": {"name": "a"}})]
+    );
+    assert_eq!(
+        item_operations("fixVersions", "add", &items[1..]),
+        vec![json!({"add": {"name": "b"}})]
+    );
+}
+
+#[test]
+fn test_parse_update_rejects_malformed_operations() {
+    let valid = json!({"labels": [{"add": "x"}, {"remove": "y"}]});
+    assert_eq!(parse_update(&valid).unwrap().len(), 1);
+
+    for invalid in [
+        json!(["labels"]),
+        json!({"labels": {"add": "x"}}),
+        json!({"labels": [{"add": "x", "remove": "y"}]}),
+        json!({"labels": [{"append": "x"}]}),
+    ] {
+        let err = parse_update(&invalid).unwrap_err().to_string();
+        assert!(err.contains("update"), "{invalid}: {err}");
+    }
+    let err = parse_update(&json!({"labels": [{"append": "x"}]}))
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("unknown verb 'append' for field 'labels'"),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_update_checked_against_editmeta_operations() {
+    let update = parse_update(&json!({
+        "labels": [{"add": "x"}],
+        "summary": [{"add": "more"}],
+        "priority": [{"set": {"name": "High"}}]
+    }))
+    .unwrap();
+    assert_eq!(
+        check_update_against_editmeta(&update, &editmeta()),
+        vec![
+            "field 'priority' cannot be edited on this issue".to_string(),
+            "field 'summary' does not support 'add'; it supports set".to_string(),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_update_operations_are_sent_alongside_fields() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let update = json!({
+        "labels": [{"add": "urgent"}, {"remove": "triage"}],
+        "components": [{"add": {"name": "API"}}, {"remove": {"name": "UI"}}],
+        "fixVersions": [{"remove": {"name": "2.0"}}, {"add": {"name": "2.1"}}]
+    });
+    let mock = mock_update(
+        &mut server,
+        "UPD-1",
+        json!({"fields": {"summary": "New"}, "update": update}),
+        1,
+    )
+    .await;
+
+    let result = UpdateIssueTool::new(config)
+        .handle(json!({
+            "issue_id_or_key": "UPD-1",
+            "summary": "New",
+            "update": update
+        }))
+        .await
+        .unwrap();
+    assert_eq!(result.content[0].text, "Issue UPD-1 updated successfully");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_validation_refuses_verbs_the_field_does_not_support() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let meta = server
+        .mock("GET", "/rest/api/2/issue/UPD-2/editmeta")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(editmeta().to_string())
+        .create_async()
+        .await;
+    let put = server
+        .mock("PUT", "/rest/api/2/issue/UPD-2")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let err = UpdateIssueTool::new(config)
+        .handle(json!({
+            "issue_id_or_key": "UPD-2",
+            "update": {
+                "labels": [{"add": "x"}],
+                "summary": [{"edit": "y"}]
+            },
+            "validate": true
+        }))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("field 'summary' does not support 'edit'; it supports set"),
+        "{err}"
+    );
+    meta.assert_async().await;
+    put.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bulk_label_and_version_arguments_become_update_operations() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let mut current = Vec::new();
+    for key in ["UPD-3", "UPD-4"] {
+        current.push(
+            server
+                .mock("GET", format!("/rest/api/2/issue/{key}").as_str())
+                .match_query(mockito::Matcher::Any)
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    json!({"id": "1", "key": key, "self": "x", "fields": {"labels": []}})
+                        .to_string(),
+                )
+                .create_async()
+                .await,
+        );
+    }
+    let body = json!({
+        "fields": {"priority": {"name": "High"}},
+        "update": {
+            "components": [{"add": {"name": "API"}}],
+            "labels": [{"add": "release"}, {"remove": "draft"}],
+            "fixVersions": [{"add": {"name": "2.1"}}, {"remove": {"name": "2.0"}}]
+        }
+    });
+    let updates = [
+        mock_update(&mut server, "UPD-3", body.clone(), 1).await,
+        mock_update(&mut server, "UPD-4", body, 1).await,
+    ];
+
+    let result = BulkUpdateIssuesTool::new(config)
+        .handle(json!({
+            "issue_keys": ["UPD-3", "UPD-4"],
+            "update_data": {
+                "fields": {"priority": {"name": "High"}},
+                "update": {"components": [{"add": {"name": "API"}}]}
+            },
+            "add_labels": ["release"],
+            "remove_labels": ["draft"],
+            "add_fix_versions": ["2.1"],
+            "remove_fix_versions": "2.0",
+            "config": {"max_retries": 0, "rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Bulk update: 2 of 2 operations succeeded"
+    );
+    for mock in updates {
+        mock.assert_async().await;
+    }
+}
@@ -11,6 +11,7 @@ fn test_config() -> JiraConfig {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     }
 }
 
@@ -41,6 +42,7 @@ async fn test_jira_client_creation_bearer_token() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     let client = JiraClient::new(config).unwrap();
@@ -292,6 +294,7 @@ async fn test_jira_client_configuration_validation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     // This should still work as URL validation happens during actual requests
@@ -310,6 +313,7 @@ async fn test_jira_client_timeout_configuration() {
         timeout_seconds: Some(60), // Different timeout
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     let client = JiraClient::new(config).unwrap();
@@ -331,6 +335,7 @@ async fn test_jira_client_ssl_configuration() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true), // Strict SSL
+        ..Default::default()
     };
 
     let client = JiraClient::new(config).unwrap();
@@ -352,6 +357,7 @@ async fn test_jira_client_auth_header_generation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     let client = JiraClient::new(config).unwrap();
@@ -0,0 +1,146 @@
+use rust_jira_mcp::jira::jql::{
+    has_order_by, jql_escape_string, jql_field, jql_unescape_string, with_key_order, Jql, SortOrder,
+};
+
+/// Awkward fragments combined pairwise to build the round-trip inputs
+const FRAGMENTS: &[&str] = &[
+    "",
+    "plain",
+    "\"",
+    "\\",
+    "\\\"",
+    "\"\\",
+    "'",
+    " OR project = SECRET",
+    "\" OR reporter = admin OR summary ~ \"",
+    "\\\" AND 1=1",
+    "ORDER BY",
+    "empty",
+    "line\nbreak\ttab\r",
+    "naïve Ünïcode",
+    "日本語テキスト",
+    "emoji 🚀🔥",
+    "\u{200b}zero width",
+];
+
+fn values() -> Vec<String> {
+    FRAGMENTS
+        .iter()
+        .flat_map(|a| FRAGMENTS.iter().map(move |b| format!("{a}{b}")))
+        .collect()
+}
+
+/// Index just past the closing quote of the literal starting at `start`
+fn literal_end(jql: &str, start: usize) -> usize {
+    let bytes = jql.as_bytes();
+    assert_eq!(bytes[start], b'"');
+    let mut i = start + 1;
+    while bytes[i] != b'"' {
+        i += if bytes[i] == b'\\' { 2 } else { 1 };
+    }
+    i + 1
+}
+
+#[test]
+fn test_escape_round_trips() {
+    for value in values() {
+        let literal = jql_escape_string(&value);
+        assert_eq!(
+            jql_unescape_string(&literal).as_deref(),
+            Some(value.as_str()),
+            "round trip of {value:?} via {literal}"
+        );
+        // The literal ends exactly at its last character
+        assert_eq!(literal_end(&literal, 0), literal.len(), "{literal}");
+    }
+}
+
+#[test]
+fn test_values_cannot_escape_their_literal() {
+    for value in values() {
+        let jql = Jql::new()
+            .project(&value)
+            .field_eq("summary", &value)
+            .build();
+        let first = "project = ".len();
+        let end = literal_end(&jql, first);
+        assert_eq!(
+            jql_unescape_string(&jql[first..end]).as_deref(),
+            Some(value.as_str())
+        );
+        let rest = &jql[end..];
+        assert!(rest.starts_with(" AND summary = \""), "{jql}");
+        assert_eq!(
+            literal_end(&jql, end + " AND summary = ".len()),
+            jql.len(),
+            "{jql}"
+        );
+    }
+}
+
+#[test]
+fn test_unescape_rejects_malformed_literals() {
+    assert_eq!(jql_unescape_string("unquoted"), None);
+    assert_eq!(jql_unescape_string("\"a\" OR \"b\""), None);
+    assert_eq!(jql_unescape_string("\"dangling\\\""), None);
+    assert_eq!(jql_unescape_string("\"\\q\""), None);
+    assert_eq!(jql_unescape_string("\"it\\'s\"").as_deref(), Some("it's"));
+}
+
+#[test]
+fn test_field_names() {
+    assert_eq!(jql_field("status"), "status");
+    assert_eq!(jql_field("customfield_10016"), "customfield_10016");
+    assert_eq!(jql_field("cf[10016]"), "cf[10016]");
+    assert_eq!(jql_field("Story Points"), "\"Story Points\"");
+    assert_eq!(jql_field("order"), "\"order\"");
+    assert_eq!(jql_field("Empty"), "\"Empty\"");
+}
+
+#[test]
+fn test_builder() {
+    let jql = Jql::new()
+        .project("PROJ")
+        .status_in(&["To Do", "In Progress"])
+        .text_contains("say \"hi\"")
+        .order_by("updated", SortOrder::Desc)
+        .order_by("key", SortOrder::Asc);
+    assert_eq!(
+        jql.build(),
+        r#"project = "PROJ" AND status in ("To Do", "In Progress") AND text ~ "say \"hi\"" ORDER BY updated DESC, key ASC"#
+    );
+
+    assert_eq!(
+        Jql::new()
+            .key_in(&["A-1", "B-2"])
+            .raw("resolution is not EMPTY")
+            .to_string(),
+        r#"key in ("A-1", "B-2") AND resolution is not EMPTY"#
+    );
+    assert_eq!(
+        Jql::new()
+            .status_in::<&str>(&[])
+            .order_by("created", SortOrder::Asc)
+            .build(),
+        "ORDER BY created ASC"
+    );
+    assert_eq!(Jql::new().build(), "");
+}
+
+#[test]
+fn test_order_by_detection() {
+    assert!(has_order_by("project = X ORDER BY created DESC"));
+    assert!(has_order_by("project = X order  by key"));
+    assert!(has_order_by("ORDER BY rank"));
+    assert!(!has_order_by("project = X"));
+    assert!(!has_order_by(r#"summary ~ "order by""#));
+    assert!(!has_order_by("summary ~ 'sort order by date'"));
+    assert!(!has_order_by("labels = border AND labels = bypass"));
+
+    assert_eq!(
+        with_key_order("project = X").as_deref(),
+        Some("project = X ORDER BY key ASC")
+    );
+    assert_eq!(with_key_order("  ").as_deref(), Some("ORDER BY key ASC"));
+    assert_eq!(with_key_order("project = X ORDER BY updated"), None);
+}
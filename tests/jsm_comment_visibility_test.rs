@@ -0,0 +1,454 @@
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::jsm::comment_is_public;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{AddCommentTool, BulkAddCommentsTool, GetCommentsTool};
+use serde_json::{json, Value};
+
+// Project metadata is cached per site, and mockito reuses servers between
+// tests, so every test uses project keys of its own
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        ..Default::default()
+    }
+}
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!("tests/fixtures/{name}")).unwrap()
+}
+
+/// The service desk project fixture under `key`, or with `project_type`
+fn project(key: &str, project_type: &str) -> String {
+    let mut project: Value = serde_json::from_str(&fixture("project_service_desk.json")).unwrap();
+    project["key"] = json!(key);
+    project["projectTypeKey"] = json!(project_type);
+    project.to_string()
+}
+
+async fn project_mock(
+    server: &mut mockito::Server,
+    key: &str,
+    project_type: &str,
+    hits: usize,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("/rest/api/2/project/{key}").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(project(key, project_type))
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+fn text(result: &rust_jira_mcp::types::mcp::MCPToolResult) -> String {
+    result.content.iter().map(|c| c.text.as_str()).collect()
+}
+
+#[tokio::test]
+async fn test_service_desk_issues_are_detected_by_project_type() {
+    let mut server = mockito::Server::new_async().await;
+    let desk = project_mock(&mut server, "DETECT", "service_desk", 1).await;
+    let software = project_mock(&mut server, "SOFTDET", "software", 1).await;
+    let _missing = server
+        .mock("GET", "/rest/api/2/project/GONE")
+        .with_status(404)
+        .create_async()
+        .await;
+    let client = JiraClient::new(create_test_config(&server.url())).unwrap();
+
+    assert!(client.is_service_desk_issue("DETECT-1").await.unwrap());
+    // The project type comes from the metadata cache the second time
+    assert!(client.is_service_desk_issue("DETECT-2").await.unwrap());
+    assert!(!client.is_service_desk_issue("SOFTDET-1").await.unwrap());
+    // An unreadable project is unknown, not "no service desk"
+    assert!(client.is_service_desk_issue("GONE-1").await.is_err());
+    assert!(client.is_service_desk_issue("10001").await.is_err());
+
+    desk.assert_async().await;
+    software.assert_async().await;
+}
+
+#[test]
+fn test_public_flag_is_read_from_jsd_public_or_the_comment_property() {
+    assert!(comment_is_public(&json!({"jsdPublic": true})));
+    assert!(!comment_is_public(&json!({"jsdPublic": false})));
+    let internal = |value: Value| {
+        !comment_is_public(&json!({
+            "properties": [{"key": "sd.public.comment", "value": {"internal": value}}]
+        }))
+    };
+    assert!(internal(json!(true)));
+    assert!(internal(json!("true")));
+    assert!(!internal(json!(false)));
+    // JSM only marks internal comments
+    assert!(comment_is_public(&json!({"properties": []})));
+}
+
+#[tokio::test]
+async fn test_comments_on_cloud_service_desk_issues_show_their_audience() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = project_mock(&mut server, "CLOUDC", "service_desk", 1).await;
+    let comments = server
+        .mock("GET", "/rest/api/2/issue/CLOUDC-1/comment")
+        .match_query(Matcher::UrlEncoded("expand".into(), "properties".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("jsm_issue_comments_cloud.json"))
+        .create_async()
+        .await;
+
+    let result = GetCommentsTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "CLOUDC-1"}))
+        .await
+        .unwrap();
+    comments.assert_async().await;
+    let text = text(&result);
+    assert!(
+        text.contains("• 20100 by Mia Krystof (5b10a2844c20165700ede21g) on 2025-10-17 09:00")
+            && text.contains(" [public]\nWe are replacing the VPN certificate"),
+        "{text}"
+    );
+    assert!(
+        text.contains(" [internal]\nCustomer is on the legacy client"),
+        "{text}"
+    );
+
+    let minimal = GetCommentsTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "CLOUDC-1", "verbosity": "minimal"}))
+        .await
+        .unwrap();
+    let minimal = &minimal.content[0].text;
+    assert!(
+        minimal.contains("Emma Richards [internal]: Customer is on the legacy client"),
+        "{minimal}"
+    );
+}
+
+#[tokio::test]
+async fn test_comments_on_data_center_service_desk_issues_use_the_property() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = project_mock(&mut server, "DCC", "service_desk", 1).await;
+    let _comments = server
+        .mock("GET", "/rest/api/2/issue/DCC-4/comment")
+        .match_query(Matcher::UrlEncoded("expand".into(), "properties".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("jsm_issue_comments_dc.json"))
+        .create_async()
+        .await;
+
+    let result = GetCommentsTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "DCC-4"}))
+        .await
+        .unwrap();
+    let markers: Vec<&str> = result.content[1..]
+        .iter()
+        .map(|c| {
+            if c.text.contains("[internal]") {
+                "internal"
+            } else if c.text.contains("[public]") {
+                "public"
+            } else {
+                "none"
+            }
+        })
+        .collect();
+    assert_eq!(markers, ["public", "internal", "public"]);
+}
+
+#[tokio::test]
+async fn test_comments_on_other_issues_have_no_audience() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = project_mock(&mut server, "SOFTC", "software", 1).await;
+    let comments = server
+        .mock("GET", "/rest/api/2/issue/SOFTC-1/comment")
+        .match_query(Matcher::Regex(
+            "^startAt=0&maxResults=\\d+&orderBy=created$".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("jsm_issue_comments_cloud.json"))
+        .create_async()
+        .await;
+
+    let result = GetCommentsTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "SOFTC-1"}))
+        .await
+        .unwrap();
+    comments.assert_async().await;
+    let text = text(&result);
+    assert!(
+        !text.contains("[public]") && !text.contains("[internal]"),
+        "{text}"
+    );
+}
+
+async fn customer_comment_mock(
+    server: &mut mockito::Server,
+    issue_key: &str,
+    public: bool,
+) -> mockito::Mock {
+    let mut comment: Value = serde_json::from_str(&fixture("jsm_comment.json")).unwrap();
+    comment["public"] = json!(public);
+    server
+        .mock(
+            "POST",
+            format!("/rest/servicedeskapi/request/{issue_key}/comment").as_str(),
+        )
+        .match_body(Matcher::Json(
+            json!({"body": "We are looking into it", "public": public}),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(comment.to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_service_desk_comments_default_to_internal() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = project_mock(&mut server, "ADDI", "service_desk", 1).await;
+    let internal = customer_comment_mock(&mut server, "ADDI-1", false).await;
+    let core = server
+        .mock("POST", "/rest/api/2/issue/ADDI-1/comment")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = AddCommentTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "ADDI-1", "comment": "We are looking into it"}))
+        .await
+        .unwrap();
+    internal.assert_async().await;
+    core.assert_async().await;
+    let text = &result.content[0].text;
+    assert!(
+        text.contains("Comment ID: 1000\nAuthor: Mia Krystof\n"),
+        "{text}"
+    );
+    assert!(
+        text.contains("\nShared with the customer: no (internal)"),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_public_service_desk_comments_are_shared_with_the_customer() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = project_mock(&mut server, "ADDP", "service_desk", 1).await;
+    let public = customer_comment_mock(&mut server, "ADDP-1", true).await;
+
+    let result = AddCommentTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "issue_key": "ADDP-1",
+            "comment": "We are looking into it",
+            "public": true
+        }))
+        .await
+        .unwrap();
+    public.assert_async().await;
+    assert!(result.content[0]
+        .text
+        .contains("\nShared with the customer: yes (public)"));
+}
+
+#[tokio::test]
+async fn test_rich_text_service_desk_comments_carry_the_internal_property() {
+    let mut server = mockito::Server::new_async().await;
+    let config = JiraConfig {
+        api_base_url: format!("{}/rest/api/3", server.url()),
+        ..create_test_config(&server.url())
+    };
+    let _project = server
+        .mock("GET", "/rest/api/3/project/ADF")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(project("ADF", "service_desk"))
+        .create_async()
+        .await;
+    let core = server
+        .mock("POST", "/rest/api/3/issue/ADF-1/comment")
+        .match_body(Matcher::PartialJson(json!({
+            "properties": [{"key": "sd.public.comment", "value": {"internal": true}}]
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "40100",
+                "body": "ignored",
+                "author": {"accountId": "abc", "displayName": "Mia Krystof"},
+                "created": "2025-10-17T09:00:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = AddCommentTool::new(config);
+    let result = tool
+        .handle(json!({
+            "issue_key": "ADF-1",
+            "comment": "**Disk** is failing",
+            "input_format": "markdown"
+        }))
+        .await
+        .unwrap();
+    core.assert_async().await;
+    assert!(result.content[0].text.contains("(internal)"));
+
+    let error = tool
+        .handle(json!({
+            "issue_key": "ADF-1",
+            "comment": "Legal only",
+            "public": true,
+            "visibility": {"type": "role", "value": "Legal"}
+        }))
+        .await
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("cannot be shared with the customer"),
+        "{error}"
+    );
+}
+
+#[tokio::test]
+async fn test_public_flag_is_ignored_outside_service_desks() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = project_mock(&mut server, "ADDS", "software", 1).await;
+    let core = server
+        .mock("POST", "/rest/api/2/issue/ADDS-1/comment")
+        .match_body(Matcher::Json(json!({"body": "Looks good"})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "50100",
+                "body": "Looks good",
+                "author": {"accountId": "abc", "displayName": "Mia Krystof"},
+                "created": "2025-10-17T09:00:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = AddCommentTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "ADDS-1", "comment": "Looks good", "public": true}))
+        .await
+        .unwrap();
+    core.assert_async().await;
+    assert!(!result.content[0].text.contains("Shared with the customer"));
+}
+
+#[tokio::test]
+async fn test_comments_stay_internal_when_the_project_type_cannot_be_read() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = server
+        .mock("GET", "/rest/api/2/project/HIDDEN")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["Forbidden"]}).to_string())
+        .create_async()
+        .await;
+    let core = server
+        .mock("POST", "/rest/api/2/issue/HIDDEN-1/comment")
+        .match_body(Matcher::Json(json!({
+            "body": "We are looking into it",
+            "properties": [{"key": "sd.public.comment", "value": {"internal": true}}]
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "60100",
+                "body": "We are looking into it",
+                "author": {"accountId": "abc", "displayName": "Mia Krystof"},
+                "created": "2025-10-17T09:00:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = AddCommentTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "HIDDEN-1", "comment": "We are looking into it"}))
+        .await
+        .unwrap();
+    core.assert_async().await;
+    let text = &result.content[0].text;
+    assert!(
+        text.contains(
+            "\nShared with the customer: no (marked internal; the project type could not be read)"
+        ),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_bulk_comments_on_service_desk_issues_are_internal() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = project_mock(&mut server, "BULKJ", "service_desk", 1).await;
+    let mut posts = Vec::new();
+    for key in ["BULKJ-1", "BULKJ-2"] {
+        let _resolve = server
+            .mock("GET", format!("/rest/api/2/issue/{key}").as_str())
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({"id": "1", "key": key}).to_string())
+            .create_async()
+            .await;
+        posts.push(
+            server
+                .mock("POST", format!("/rest/api/2/issue/{key}/comment").as_str())
+                .match_body(Matcher::Json(json!({
+                    "body": "Release 4.2 is out",
+                    "properties": [{"key": "sd.public.comment", "value": {"internal": true}}]
+                })))
+                .with_status(201)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    json!({
+                        "id": "70100",
+                        "body": "Release 4.2 is out",
+                        "author": {"accountId": "abc", "displayName": "Mia Krystof"},
+                        "created": "2025-10-17T09:00:00.000+0000"
+                    })
+                    .to_string(),
+                )
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let result = BulkAddCommentsTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "issue_keys": ["BULKJ-1", "BULKJ-2"],
+            "comment": "Release 4.2 is out",
+            "config": {"rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+    for post in posts {
+        post.assert_async().await;
+    }
+    let text = &result.content[0].text;
+    assert!(text.contains("2 of 2 operations succeeded"), "{text}");
+}
@@ -0,0 +1,275 @@
+use chrono::{TimeZone, Utc};
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::jsm::render_slas;
+use rust_jira_mcp::mcp::server::{MCPServer, MCPToolHandler};
+use rust_jira_mcp::mcp::tools::{
+    AddCustomerCommentTool, CreateCustomerRequestTool, GetRequestTypesTool, GetServiceDesksTool,
+};
+use rust_jira_mcp::types::jira::JsmSla;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        jsm_enabled: Some(true),
+        ..Default::default()
+    }
+}
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!("tests/fixtures/{name}")).unwrap()
+}
+
+#[tokio::test]
+async fn test_service_desk_api_base_path_and_header() {
+    let mut server = mockito::Server::new_async().await;
+    let desks = server
+        .mock("GET", "/rest/servicedeskapi/servicedesk")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("start".into(), "0".into()),
+            Matcher::UrlEncoded("limit".into(), "50".into()),
+        ]))
+        .match_header("X-ExperimentalApi", "opt-in")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("jsm_service_desks.json"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = GetServiceDesksTool::new(create_test_config(&server.url()))
+        .handle(json!({}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Found 2 service desks:\n• 1 — IT Help (HELP)\n• 2 — HR Requests (HR)"
+    );
+    desks.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_request_types_follow_pages() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/rest/servicedeskapi/servicedesk/1/requesttype")
+        .match_query(Matcher::UrlEncoded("start".into(), "0".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "isLastPage": false,
+                "values": [{"id": "10", "name": "Request new hardware"}]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/rest/servicedeskapi/servicedesk/1/requesttype")
+        .match_query(Matcher::UrlEncoded("start".into(), "1".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("jsm_request_types.json"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = GetRequestTypesTool::new(create_test_config(&server.url()))
+        .handle(json!({"service_desk_id": "1"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Found 3 request types on service desk 1:\n• 10 — Request new hardware\n• 11 — Get IT help: Get assistance for general IT problems and questions.\n• 12 — Fix an account problem"
+    );
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_customer_request() {
+    let mut server = mockito::Server::new_async().await;
+    let create = server
+        .mock("POST", "/rest/servicedeskapi/request")
+        .match_header("X-ExperimentalApi", "opt-in")
+        .match_body(Matcher::Json(json!({
+            "serviceDeskId": "1",
+            "requestTypeId": "11",
+            "requestFieldValues": {"summary": "VPN is down", "description": "Since 9am"}
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("jsm_created_request.json"))
+        .expect(1)
+        .create_async()
+        .await;
+    let tool = CreateCustomerRequestTool::new(create_test_config(&server.url()));
+
+    let result = tool
+        .handle(json!({
+            "service_desk_id": "1",
+            "request_type_id": "11",
+            "fields": {"summary": "VPN is down", "description": "Since 9am"}
+        }))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Created customer request HELP-12 (Waiting for support)\nCustomer portal: https://example.atlassian.net/servicedesk/customer/portal/1/HELP-12"
+    );
+
+    // Field values must be an object; nothing is sent otherwise
+    let error = tool
+        .handle(json!({
+            "service_desk_id": "1",
+            "request_type_id": "11",
+            "fields": "VPN is down"
+        }))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("fields"), "{error}");
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_request_sla() {
+    let mut server = mockito::Server::new_async().await;
+    let sla = server
+        .mock("GET", "/rest/servicedeskapi/request/HELP-12/sla")
+        .match_query(Matcher::Any)
+        .match_header("X-ExperimentalApi", "opt-in")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("jsm_request_sla.json"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let slas = JiraClient::new(create_test_config(&server.url()))
+        .unwrap()
+        .get_request_sla("HELP-12")
+        .await
+        .unwrap();
+    assert_eq!(slas.len(), 3);
+    assert!(slas[1].ongoing_cycle.is_some());
+    sla.assert_async().await;
+}
+
+#[test]
+fn test_render_slas_shows_remaining_time_of_ongoing_cycles() {
+    let page: Value = serde_json::from_str(&fixture("jsm_request_sla.json")).unwrap();
+    let slas: Vec<JsmSla> = serde_json::from_value(page["values"].clone()).unwrap();
+    let now = Utc.with_ymd_and_hms(2025, 10, 17, 12, 0, 0).unwrap();
+
+    assert_eq!(
+        render_slas(&slas, now),
+        "• Time to first response: completed, met in 45m (goal 4h)\n\
+         • Time to resolution: ongoing, 5h remaining (goal 8h), due Today 5:00 PM\n\
+         • Time to approve: ongoing, BREACHED 2h ago (goal 2h), due Today 10:00 AM"
+    );
+
+    // A paused cycle keeps the remaining time Jira reported
+    let mut paused = slas[1].clone();
+    let cycle = paused.ongoing_cycle.as_mut().unwrap();
+    cycle.paused = Some(true);
+    cycle.remaining_time.as_mut().unwrap().friendly = None;
+    cycle.remaining_time.as_mut().unwrap().millis = 5_400_000;
+    assert_eq!(
+        render_slas(&[paused], now),
+        "• Time to resolution: ongoing, 1h 30m remaining, paused (goal 8h), due Today 5:00 PM"
+    );
+
+    assert_eq!(render_slas(&[], now), "No SLAs apply to this request");
+}
+
+#[tokio::test]
+async fn test_add_customer_comment_is_internal_by_default() {
+    let mut server = mockito::Server::new_async().await;
+    let internal = server
+        .mock("POST", "/rest/servicedeskapi/request/HELP-12/comment")
+        .match_body(Matcher::Json(
+            json!({"body": "Checking the VPN logs", "public": false}),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"id": "999", "body": "Checking the VPN logs", "public": false}).to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let public = server
+        .mock("POST", "/rest/servicedeskapi/request/HELP-12/comment")
+        .match_body(Matcher::PartialJson(json!({"public": true})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("jsm_comment.json"))
+        .expect(1)
+        .create_async()
+        .await;
+    let tool = AddCustomerCommentTool::new(create_test_config(&server.url()));
+
+    let result = tool
+        .handle(json!({"issue_key": "HELP-12", "body": "Checking the VPN logs"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Added comment 999 to HELP-12 (internal, visible to agents only)"
+    );
+
+    let result = tool
+        .handle(json!({"issue_key": "HELP-12", "body": "We are looking into it", "public": true}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Added comment 1000 to HELP-12 (visible to the customer)"
+    );
+    internal.assert_async().await;
+    public.assert_async().await;
+}
+
+/// Names of the tools a server built from `config` lists
+async fn listed_tools(config: JiraConfig) -> Vec<String> {
+    let mut server = MCPServer::new(config);
+    let response = server
+        .handle_request(
+            &json!({"jsonrpc": "2.0", "id": 1, "method": "tools/list", "params": {}}).to_string(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    response.result.unwrap()["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_jsm_tools_are_listed_only_when_enabled() {
+    let enabled = create_test_config("http://localhost:1");
+    let disabled = JiraConfig {
+        jsm_enabled: Some(false),
+        ..enabled.clone()
+    };
+
+    let all = listed_tools(enabled).await;
+    assert!(all.contains(&"get_jsm_request_sla".to_string()));
+    // The two Assets tools are off
+    assert_eq!(all.len(), MCPServer::list_tools().len() - 2);
+
+    let without = listed_tools(disabled).await;
+    assert!(!without.iter().any(|name| name.contains("jsm")));
+    assert_eq!(without.len(), all.len() - 5);
+}
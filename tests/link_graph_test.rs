@@ -0,0 +1,222 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::link_graph::{
+    IssueLinkRef, LinkDirection, LinkGraph, LinkGraphOptions, MAX_LINK_GRAPH_NODES,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::GetIssueLinkGraphTool;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+/// (key, summary, status)
+const ISSUES: &[(&str, &str, &str)] = &[
+    ("GRAPH-1", "Release", "Open"),
+    ("GRAPH-2", "Migrate database", "In Progress"),
+    ("GRAPH-3", "Sign off", "Done"),
+    ("GRAPH-4", "Order hardware", "Open"),
+    ("GRAPH-5", "Release notes", "Open"),
+];
+
+/// (link id, type, outward issue, inward issue): GRAPH-2 blocks GRAPH-1 and
+/// so on. GRAPH-1 -> GRAPH-4 -> GRAPH-2 -> GRAPH-1 is a cycle.
+const LINKS: &[(&str, &str, &str, &str)] = &[
+    ("1", "Blocks", "GRAPH-2", "GRAPH-1"),
+    ("2", "Blocks", "GRAPH-3", "GRAPH-1"),
+    ("3", "Blocks", "GRAPH-4", "GRAPH-2"),
+    ("4", "Blocks", "GRAPH-1", "GRAPH-4"),
+    ("5", "Relates", "GRAPH-1", "GRAPH-5"),
+];
+
+fn issue_ref(key: &str) -> Value {
+    let (_, summary, status) = ISSUES.iter().find(|(k, _, _)| *k == key).unwrap();
+    json!({"key": key, "fields": {"summary": summary, "status": {"name": status}}})
+}
+
+fn issue_json(key: &str) -> Value {
+    let links: Vec<Value> = LINKS
+        .iter()
+        .filter_map(|(id, name, outward, inward)| {
+            let link_type = if *name == "Blocks" {
+                json!({"name": "Blocks", "inward": "is blocked by", "outward": "blocks"})
+            } else {
+                json!({"name": "Relates", "inward": "relates to", "outward": "relates to"})
+            };
+            if *inward == key {
+                Some(json!({"id": id, "type": link_type, "inwardIssue": issue_ref(outward)}))
+            } else if *outward == key {
+                Some(json!({"id": id, "type": link_type, "outwardIssue": issue_ref(inward)}))
+            } else {
+                None
+            }
+        })
+        .collect();
+    let mut issue = issue_ref(key);
+    issue["id"] = json!(key);
+    issue["self"] = json!("x");
+    issue["fields"]["issuelinks"] = json!(links);
+    issue
+}
+
+/// Answer `key in (...)` searches from the fixtures
+async fn mock_search(server: &mut mockito::ServerGuard, expected_calls: usize) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body_from_request(|request| {
+            let query = request.path_and_query().split_once('?').unwrap().1;
+            let jql = url::form_urlencoded::parse(query.as_bytes())
+                .find(|(name, _)| name == "jql")
+                .unwrap()
+                .1
+                .into_owned();
+            let issues: Vec<Value> = ISSUES
+                .iter()
+                .filter(|(key, _, _)| jql.contains(&format!("\"{key}\"")))
+                .map(|(key, _, _)| issue_json(key))
+                .collect();
+            json!({"startAt": 0, "maxResults": 50, "total": issues.len(), "issues": issues})
+                .to_string()
+                .into()
+        })
+        .expect(expected_calls)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_blockers_are_followed_transitively_through_a_cycle() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    // Levels GRAPH-1, then GRAPH-2 and GRAPH-3, then GRAPH-4, whose only
+    // blocker is GRAPH-1 again
+    let search = mock_search(&mut server, 3).await;
+
+    let tool = GetIssueLinkGraphTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_key": "GRAPH-1",
+            "link_types": ["blocks"],
+            "direction": "inward",
+            "max_depth": 5
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        "Link graph of GRAPH-1: 4 issues, 4 links, depth 5\n\
+         \n\
+         GRAPH-1 [Open] Release\n\
+         \x20 is blocked by GRAPH-2 [In Progress] Migrate database\n\
+         \x20   is blocked by GRAPH-4 [Open] Order hardware\n\
+         \x20 is blocked by GRAPH-3 [Done] Sign off\n"
+    );
+
+    let graph: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(graph["truncated"], false);
+    assert_eq!(graph["nodes"][3]["key"], "GRAPH-4");
+    assert_eq!(graph["nodes"][3]["depth"], 2);
+    assert_eq!(graph["nodes"][3]["status"], "Open");
+    assert_eq!(
+        graph["edges"],
+        json!([
+            {"id": "1", "link_type": "Blocks", "from": "GRAPH-2", "to": "GRAPH-1"},
+            {"id": "2", "link_type": "Blocks", "from": "GRAPH-3", "to": "GRAPH-1"},
+            {"id": "3", "link_type": "Blocks", "from": "GRAPH-4", "to": "GRAPH-2"},
+            {"id": "4", "link_type": "Blocks", "from": "GRAPH-1", "to": "GRAPH-4"}
+        ])
+    );
+    search.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_both_directions_record_each_link_once() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    // GRAPH-1 reaches every other issue directly
+    let search = mock_search(&mut server, 2).await;
+
+    let tool = GetIssueLinkGraphTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "GRAPH-1", "max_depth": 50}))
+        .await
+        .unwrap();
+
+    assert!(result.content[0]
+        .text
+        .starts_with("Link graph of GRAPH-1: 5 issues, 5 links, depth 5\n"));
+    let graph: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(graph["max_depth"], 5);
+    assert_eq!(graph["edges"].as_array().unwrap().len(), 5);
+    search.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_depth_limits_the_walk() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let search = mock_search(&mut server, 1).await;
+
+    let tool = GetIssueLinkGraphTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "GRAPH-1", "direction": "inward", "max_depth": 1}))
+        .await
+        .unwrap();
+
+    assert!(result.content[0]
+        .text
+        .starts_with("Link graph of GRAPH-1: 3 issues, 2 links, depth 1\n"));
+    search.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_unknown_direction_is_rejected() {
+    let tool = GetIssueLinkGraphTool::new(create_test_config("http://localhost:1"));
+    let error = tool
+        .handle(json!({"issue_key": "GRAPH-1", "direction": "sideways"}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Unknown direction 'sideways'"));
+}
+
+#[test]
+fn test_visited_cap_truncates_graph() {
+    let mut graph = LinkGraph::new("HUB-1", 1);
+    let options = LinkGraphOptions {
+        direction: LinkDirection::Outward,
+        ..Default::default()
+    };
+    let mut expanded = 0;
+    for n in 2..=MAX_LINK_GRAPH_NODES + 50 {
+        let hub = json!({
+            "key": "HUB-1",
+            "fields": {"issuelinks": [{
+                "id": n.to_string(),
+                "type": {"name": "Relates", "inward": "relates to", "outward": "relates to"},
+                "outwardIssue": {"key": format!("HUB-{n}")}
+            }]}
+        });
+        for link in IssueLinkRef::from_issue(&hub) {
+            assert!(link.matches(&options));
+            expanded += usize::from(graph.add_link("HUB-1", 0, &link).is_some());
+        }
+    }
+
+    assert!(graph.truncated);
+    assert_eq!(graph.nodes.len(), MAX_LINK_GRAPH_NODES);
+    assert_eq!(expanded, MAX_LINK_GRAPH_NODES - 1);
+}
@@ -55,6 +55,7 @@ async fn test_main_application_startup_flow() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     // Test configuration validation
@@ -225,6 +226,7 @@ async fn test_main_application_configuration_validation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     // Test configuration validation
@@ -251,6 +253,7 @@ async fn test_main_application_server_initialization() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     // Test MCP server creation
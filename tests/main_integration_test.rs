@@ -45,6 +45,7 @@ async fn test_main_application_flow() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
     let _server = MCPServer::new(test_config);
     // Test that server can be created - if we get here, it worked
@@ -129,6 +130,7 @@ async fn test_mcp_server_initialization() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     };
 
     let _server = MCPServer::new(config);
@@ -210,6 +212,7 @@ async fn test_jira_config_validation() {
         timeout_seconds: Some(60),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
 
     let validation_result = valid_config.validate();
@@ -225,6 +228,7 @@ async fn test_jira_config_validation() {
         timeout_seconds: Some(0),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
 
     let invalid_validation = invalid_config.validate();
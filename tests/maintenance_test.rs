@@ -0,0 +1,221 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::maintenance;
+use rust_jira_mcp::jira::throttle::ThrottleState;
+use rust_jira_mcp::mcp::server::MCPServer;
+use rust_jira_mcp::types::mcp::JsonRpcResponse;
+use serde_json::json;
+use std::time::Duration;
+
+const MAINTENANCE_PAGE: &str =
+    "<html><body><h1>Jira is down for maintenance</h1><p>Back soon.</p></body></html>";
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn initialized_server(config: JiraConfig) -> MCPServer {
+    let mut server = MCPServer::new(config);
+    for message in [
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {"protocolVersion": "2025-03-26", "capabilities": {}, "clientInfo": {"name": "maintenance-test", "version": "1.0"}}
+        }),
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+    ] {
+        server.handle_request(&message.to_string()).await.unwrap();
+    }
+    server
+}
+
+async fn call_tool(
+    server: &mut MCPServer,
+    name: &str,
+    arguments: serde_json::Value,
+) -> JsonRpcResponse {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {"name": name, "arguments": arguments}
+    });
+    server
+        .handle_request(&request.to_string())
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+fn error_details(response: JsonRpcResponse) -> String {
+    response.error.unwrap().data.unwrap()["details"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+fn result_text(response: &JsonRpcResponse) -> String {
+    response.result.as_ref().unwrap()["content"][0]["text"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+async fn issue_mock(server: &mut mockito::Server, issue_key: &str) -> mockito::Mock {
+    server
+        .mock("GET", format!("/rest/api/2/issue/{issue_key}").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10001",
+                "key": issue_key,
+                "self": format!("https://jira.example.com/rest/api/2/issue/{issue_key}"),
+                "fields": {"summary": "Before the upgrade"}
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+async fn maintenance_mock(
+    server: &mut mockito::Server,
+    issue_key: &str,
+    retry_after: Option<&str>,
+    body: &str,
+) -> mockito::Mock {
+    let mut mock = server
+        .mock("GET", format!("/rest/api/2/issue/{issue_key}").as_str())
+        .with_status(503)
+        .with_body(body);
+    if let Some(retry_after) = retry_after {
+        mock = mock.with_header("retry-after", retry_after);
+    }
+    mock.expect(1).create_async().await
+}
+
+#[tokio::test]
+async fn test_maintenance_page_is_entered_and_left() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let page = maintenance_mock(&mut server, "MNT-1", Some("1"), MAINTENANCE_PAGE).await;
+    let issue = issue_mock(&mut server, "MNT-1").await;
+    let mut mcp = initialized_server(config.clone()).await;
+
+    // Not retried, and reported as maintenance rather than a raw 503
+    let details =
+        error_details(call_tool(&mut mcp, "get_jira_issue", json!({"issue_key": "MNT-1"})).await);
+    assert!(
+        details.contains("Jira is in maintenance until ~"),
+        "{details}"
+    );
+    assert!(!details.contains("attempts"), "{details}");
+    let window = maintenance::current(&config).unwrap();
+    assert!(window.until.is_some());
+
+    // Requests fail without reaching Jira until the advertised end
+    let details =
+        error_details(call_tool(&mut mcp, "get_jira_issue", json!({"issue_key": "MNT-1"})).await);
+    assert!(
+        details.contains("Jira is in maintenance until ~"),
+        "{details}"
+    );
+    page.assert_async().await;
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    let response = call_tool(&mut mcp, "get_jira_issue", json!({"issue_key": "MNT-1"})).await;
+    assert!(result_text(&response).contains("MNT-1"));
+    issue.assert_async().await;
+    assert!(maintenance::current(&config).is_none());
+}
+
+#[tokio::test]
+async fn test_long_retry_after_is_maintenance_and_does_not_pause_the_throttle() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let page = maintenance_mock(&mut server, "MNT-LONG", Some("3600"), "Service Unavailable").await;
+    let client = JiraClient::new(config.clone()).unwrap();
+
+    let error = client.get_issue("MNT-LONG").await.unwrap_err().to_string();
+    assert!(error.contains("Jira is in maintenance until ~"), "{error}");
+    page.assert_async().await;
+
+    let window = maintenance::current(&config).unwrap();
+    let remaining = window.until.unwrap() - window.since;
+    assert!(remaining > chrono::Duration::minutes(59), "{remaining}");
+    assert_ne!(client.throttle().state(), ThrottleState::BackingOff);
+
+    // Writes fail at once as well
+    let error = client
+        .update_issue("MNT-LONG", &json!({"fields": {"summary": "x"}}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Jira is in maintenance"), "{error}");
+
+    // Mock servers are reused by later tests
+    maintenance::clear(&config);
+}
+
+#[tokio::test]
+async fn test_reads_are_served_stale_during_maintenance() {
+    let mut server = mockito::Server::new_async().await;
+    let config = JiraConfig {
+        serve_stale_during_maintenance: Some(true),
+        ..create_test_config(&server.url())
+    };
+    let issue = issue_mock(&mut server, "STALE-1").await;
+    let page = maintenance_mock(&mut server, "STALE-2", None, MAINTENANCE_PAGE).await;
+    let mut mcp = initialized_server(config.clone()).await;
+
+    call_tool(&mut mcp, "get_jira_issue", json!({"issue_key": "STALE-1"})).await;
+    let details =
+        error_details(call_tool(&mut mcp, "get_jira_issue", json!({"issue_key": "STALE-2"})).await);
+    assert!(details.contains("(no end time advertised)"), "{details}");
+
+    let response = call_tool(&mut mcp, "get_jira_issue", json!({"issue_key": "STALE-1"})).await;
+    let text = result_text(&response);
+    assert!(text.contains("Before the upgrade"), "{text}");
+    assert!(
+        text.ends_with(
+            "(no end time advertised); 1 response was served from the last copy read before it"
+        ),
+        "{text}"
+    );
+    issue.assert_async().await;
+    page.assert_async().await;
+
+    // Nothing kept for this one
+    let details =
+        error_details(call_tool(&mut mcp, "get_jira_issue", json!({"issue_key": "STALE-3"})).await);
+    assert!(
+        details.contains("Jira is in maintenance since"),
+        "{details}"
+    );
+    maintenance::clear(&config);
+}
+
+#[tokio::test]
+async fn test_plain_service_unavailable_is_not_maintenance() {
+    let mut server = mockito::Server::new_async().await;
+    let config = create_test_config(&server.url());
+    let overloaded =
+        maintenance_mock(&mut server, "BUSY-1", Some("1"), "Service Unavailable").await;
+    let issue = issue_mock(&mut server, "BUSY-1").await;
+    let client = JiraClient::new(config.clone()).unwrap();
+
+    let issue_result = client.get_issue("BUSY-1").await.unwrap();
+    assert_eq!(issue_result.key, "BUSY-1");
+    overloaded.assert_async().await;
+    issue.assert_async().await;
+    assert!(maintenance::current(&config).is_none());
+}
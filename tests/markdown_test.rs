@@ -0,0 +1,343 @@
+mod common;
+
+use common::mock_project_type;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{AddCommentTool, UpdateIssueTool};
+use rust_jira_mcp::utils::markdown::InputFormat;
+use rust_jira_mcp::utils::{markdown_to_adf, markdown_to_jira};
+use serde_json::{json, Value};
+
+fn create_test_config(api_base_url: String) -> JiraConfig {
+    JiraConfig {
+        api_base_url,
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+/// Representative Markdown and the wiki markup it becomes
+const WIKI_CORPUS: &[(&str, &str)] = &[
+    (
+        "Some **bold**, *italic*, _also italic_ and ~~gone~~ text",
+        "Some *bold*, _italic_, _also italic_ and -gone- text",
+    ),
+    (
+        "**bold with _italic_ inside**",
+        "*bold with* *_italic_* *inside*",
+    ),
+    ("Run `cargo test` first", "Run {{cargo test}} first"),
+    (
+        "See [the docs](https://example.com/docs) or <https://example.com>",
+        "See [the docs|https://example.com/docs] or [https://example.com]",
+    ),
+    (
+        "[**Bold** link](https://example.com)",
+        "[*Bold* link|https://example.com]",
+    ),
+    ("# Title\n\n### Details ###", "h1. Title\n\nh3. Details"),
+    (
+        "```rust\nfn main() {\n    println!(\"**hi**\");\n}\n```",
+        "{code:rust}\nfn main() {\n    println!(\"**hi**\");\n}\n{code}",
+    ),
+    ("```\nplain\n```", "{code}\nplain\n{code}"),
+    ("~~~sh\nls -la\n~~~", "{code:bash}\nls -la\n{code}"),
+    (
+        "- one\n- two\n  - nested\n    1. deep\n- three",
+        "* one\n* two\n** nested\n**# deep\n* three",
+    ),
+    (
+        "1. first\n\n2. second\n3) third",
+        "# first\n# second\n# third",
+    ),
+    (
+        "- item that\n  continues\n\nAfter the list",
+        "* item that continues\n\nAfter the list",
+    ),
+    (
+        "| Name | Status |\n|------|:------:|\n| `api` | **Done** |\n| ui | |",
+        "||Name||Status||\n|{{api}}|*Done*|\n|ui| |",
+    ),
+    (
+        "> Quoted **text**\n> - and a list",
+        "{quote}\nQuoted *text*\n\n* and a list\n{quote}",
+    ),
+    ("Above\n\n---\n\nBelow", "Above\n\n----\n\nBelow"),
+    (
+        "first line\nsecond line\n\nnext paragraph",
+        "first line\nsecond line\n\nnext paragraph",
+    ),
+    (
+        "snake_case_name and 2 * 3 * 4 and {braces} [brackets]",
+        "snake\\_case\\_name and 2 \\* 3 \\* 4 and \\{braces\\} \\[brackets\\]",
+    ),
+    (
+        "Escaped \\*stars\\* and https://example.com/a_b_c",
+        "Escaped \\*stars\\* and https://example.com/a_b_c",
+    ),
+    ("#hashtag is not a heading", "#hashtag is not a heading"),
+];
+
+#[test]
+fn test_markdown_to_wiki_corpus() {
+    for (markdown, wiki) in WIKI_CORPUS {
+        assert_eq!(markdown_to_jira(markdown), *wiki, "{markdown}");
+    }
+}
+
+#[test]
+fn test_markdown_to_adf_inline_marks() {
+    let doc = markdown_to_adf("**Bold** `code` [link](https://example.com) ~~old~~ _em_");
+    assert_eq!(doc["type"], "doc");
+    assert_eq!(doc["version"], 1);
+    assert_eq!(
+        doc["content"],
+        json!([{
+            "type": "paragraph",
+            "content": [
+                {"type": "text", "text": "Bold", "marks": [{"type": "strong"}]},
+                {"type": "text", "text": " "},
+                {"type": "text", "text": "code", "marks": [{"type": "code"}]},
+                {"type": "text", "text": " "},
+                {"type": "text", "text": "link", "marks": [{"type": "link", "attrs": {"href": "https://example.com"}}]},
+                {"type": "text", "text": " "},
+                {"type": "text", "text": "old", "marks": [{"type": "strike"}]},
+                {"type": "text", "text": " "},
+                {"type": "text", "text": "em", "marks": [{"type": "em"}]}
+            ]
+        }])
+    );
+}
+
+#[test]
+fn test_markdown_to_adf_blocks() {
+    let doc = markdown_to_adf(
+        "## Plan\n\n```python\nprint(1)\n```\n\n- a\n  1. a.1\n- b\n\n| H1 | H2 |\n|---|---|\n| x |\n\n> quoted\n\n***\n\nline one\nline two",
+    );
+    let content = doc["content"].as_array().unwrap();
+    let kinds: Vec<&str> = content
+        .iter()
+        .map(|node| node["type"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            "heading",
+            "codeBlock",
+            "bulletList",
+            "table",
+            "blockquote",
+            "rule",
+            "paragraph"
+        ]
+    );
+    assert_eq!(content[0]["attrs"]["level"], 2);
+    assert_eq!(content[0]["content"][0]["text"], "Plan");
+    assert_eq!(
+        content[1],
+        json!({"type": "codeBlock", "attrs": {"language": "python"}, "content": [{"type": "text", "text": "print(1)"}]})
+    );
+
+    // The numbered item is nested in the first bullet
+    let list = &content[2];
+    assert_eq!(list["content"].as_array().unwrap().len(), 2);
+    let first = &list["content"][0];
+    assert_eq!(first["content"][0]["content"][0]["text"], "a");
+    assert_eq!(first["content"][1]["type"], "orderedList");
+    assert_eq!(
+        first["content"][1]["content"][0]["content"][0]["content"][0]["text"],
+        "a.1"
+    );
+
+    // Short rows are padded to the header's width
+    let table = &content[3];
+    assert_eq!(table["content"][0]["content"][1]["type"], "tableHeader");
+    assert_eq!(
+        table["content"][0]["content"][1]["content"][0]["content"][0]["text"],
+        "H2"
+    );
+    assert_eq!(table["content"][1]["content"][1]["type"], "tableCell");
+    assert_eq!(
+        table["content"][1]["content"][1]["content"][0]["content"],
+        json!([])
+    );
+
+    assert_eq!(content[4]["content"][0]["type"], "paragraph");
+    assert_eq!(
+        content[6]["content"],
+        json!([
+            {"type": "text", "text": "line one"},
+            {"type": "hardBreak"},
+            {"type": "text", "text": "line two"}
+        ])
+    );
+}
+
+#[test]
+fn test_adf_corpus_keeps_the_text() {
+    // Every wiki corpus entry converts to ADF without losing its words
+    for (markdown, _) in WIKI_CORPUS {
+        let doc = markdown_to_adf(markdown);
+        let text = rust_jira_mcp::mcp::tools::formatting::rich_text(&doc).unwrap();
+        for word in ["bold", "docs", "Title", "deep", "Status", "Quoted", "Below"] {
+            if markdown.contains(word) {
+                assert!(text.contains(word), "{markdown} lost {word}: {text}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_input_format_from_args_and_config() {
+    let config = create_test_config("https://jira.example.com/rest/api/2".to_string());
+    assert_eq!(
+        InputFormat::from_args(&json!({}), &config).unwrap(),
+        InputFormat::Wiki
+    );
+    assert_eq!(
+        InputFormat::from_args(&json!({"input_format": "Markdown"}), &config).unwrap(),
+        InputFormat::Markdown
+    );
+    let error = InputFormat::from_args(&json!({"input_format": "html"}), &config)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("expected wiki or markdown"), "{error}");
+
+    let markdown_default = JiraConfig {
+        default_input_format: Some("markdown".to_string()),
+        ..config.clone()
+    };
+    assert_eq!(
+        InputFormat::from_args(&json!({}), &markdown_default).unwrap(),
+        InputFormat::Markdown
+    );
+    assert_eq!(
+        InputFormat::from_args(&json!({"input_format": "wiki"}), &markdown_default).unwrap(),
+        InputFormat::Wiki
+    );
+
+    let invalid = JiraConfig {
+        default_input_format: Some("rtf".to_string()),
+        ..config
+    };
+    assert!(invalid.validate().is_err());
+
+    assert_eq!(
+        InputFormat::Wiki.body("**as is**", true),
+        json!("**as is**")
+    );
+    assert_eq!(InputFormat::Markdown.body("**b**", false), json!("*b*"));
+}
+
+fn comment_response() -> String {
+    json!({
+        "id": "10100",
+        "body": "",
+        "author": {
+            "self": "https://jira.example.com/rest/api/2/user?username=test",
+            "name": "test",
+            "key": "test",
+            "displayName": "Test User",
+            "active": true
+        },
+        "created": "2024-03-02T14:30:00.000+0000"
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_markdown_comment_is_sent_as_wiki_markup() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = mock_project_type(&mut server, "/rest/api/2", "MD", "software").await;
+    let comment = server
+        .mock("POST", "/rest/api/2/issue/MD-1/comment")
+        .match_body(mockito::Matcher::Json(
+            json!({"body": "h2. Result\n\n* *all* tests pass\n* see {{ci.log}}"}),
+        ))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(comment_response())
+        .expect(1)
+        .create_async()
+        .await;
+
+    AddCommentTool::new(create_test_config(format!("{}/rest/api/2", server.url())))
+        .handle(json!({
+            "issue_key": "MD-1",
+            "comment": "## Result\n\n- **all** tests pass\n- see `ci.log`",
+            "input_format": "markdown"
+        }))
+        .await
+        .unwrap();
+    comment.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_markdown_comment_is_sent_as_adf_on_api_v3() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = mock_project_type(&mut server, "/rest/api/3", "MD", "software").await;
+    let comment = server
+        .mock("POST", "/rest/api/3/issue/MD-2/comment")
+        .match_body(mockito::Matcher::Json(json!({"body": {
+            "type": "doc",
+            "version": 1,
+            "content": [{"type": "paragraph", "content": [
+                {"type": "text", "text": "Looks "},
+                {"type": "text", "text": "good", "marks": [{"type": "strong"}]}
+            ]}]
+        }})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(comment_response())
+        .expect(1)
+        .create_async()
+        .await;
+    let tool = AddCommentTool::new(JiraConfig {
+        default_input_format: Some("markdown".to_string()),
+        ..create_test_config(format!("{}/rest/api/3", server.url()))
+    });
+
+    tool.handle(json!({"issue_key": "MD-2", "comment": "Looks **good**"}))
+        .await
+        .unwrap();
+    comment.assert_async().await;
+
+    let error = tool
+        .handle(json!({"issue_key": "MD-2", "comment": "Hi @alice", "resolve_mentions": true}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("resolve_mentions"), "{error}");
+}
+
+#[tokio::test]
+async fn test_markdown_description_on_update() {
+    let mut server = mockito::Server::new_async().await;
+    let update = server
+        .mock("PUT", "/rest/api/2/issue/MD-3")
+        .match_body(mockito::Matcher::Json(json!({"fields": {
+            "description": "h3. Steps\n\n# Open [the page|https://example.com]\n# Click *Save*"
+        }})))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    UpdateIssueTool::new(create_test_config(format!("{}/rest/api/2", server.url())))
+        .handle(json!({
+            "issue_id_or_key": "MD-3",
+            "description": "### Steps\n\n1. Open [the page](https://example.com)\n2. Click **Save**",
+            "input_format": "markdown"
+        }))
+        .await
+        .unwrap();
+    update.assert_async().await;
+}
+
+#[test]
+fn test_plain_wiki_text_is_untouched() {
+    let text: Value = InputFormat::Wiki.body("h1. Already *wiki*", false);
+    assert_eq!(text, json!("h1. Already *wiki*"));
+}
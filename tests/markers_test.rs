@@ -0,0 +1,293 @@
+mod common;
+
+use common::mock_project_type;
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::markers::{has_signature, with_label, with_signature};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{
+    AddCommentTool, AddWorkLogTool, BulkAddCommentsTool, CloneIssueTool, CreateIssueTool,
+};
+use serde_json::json;
+
+const LABEL: &str = "created-by-mcp";
+const SIGNATURE: &str = "— posted via rust-jira-mcp";
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        auto_label: Some(LABEL.to_string()),
+        comment_signature: Some(SIGNATURE.to_string()),
+        ..Default::default()
+    }
+}
+
+fn comment_response(id: &str, body: &str) -> String {
+    json!({
+        "id": id,
+        "body": body,
+        "author": {"name": "svc-bot", "displayName": "Service Bot"},
+        "created": "2025-10-17T09:00:00.000+0000"
+    })
+    .to_string()
+}
+
+#[test]
+fn test_markers_are_added_once() {
+    let issue = json!({"fields": {"summary": "Rotate keys", "labels": ["security"]}});
+    let labelled = with_label(&issue, LABEL);
+    assert_eq!(labelled["fields"]["labels"], json!(["security", LABEL]));
+    assert_eq!(with_label(&labelled, LABEL), labelled);
+    assert_eq!(
+        with_label(&json!({"fields": {"summary": "x"}}), LABEL)["fields"]["labels"],
+        json!([LABEL])
+    );
+    // Labels set with verbs get an `add`, since Jira refuses both forms at once
+    let verbs = with_label(
+        &json!({"fields": {}, "update": {"labels": [{"add": "ops"}]}}),
+        LABEL,
+    );
+    assert_eq!(
+        verbs["update"]["labels"],
+        json!([{"add": "ops"}, {"add": LABEL}])
+    );
+    assert!(verbs["fields"].get("labels").is_none());
+
+    let signed = with_signature(&json!("Deployed  \n"), SIGNATURE);
+    assert_eq!(signed, json!(format!("Deployed\n\n{SIGNATURE}")));
+    assert_eq!(with_signature(&signed, SIGNATURE), signed);
+    assert_eq!(with_signature(&json!(""), SIGNATURE), json!(SIGNATURE));
+
+    let document = json!({"type": "doc", "version": 1, "content": [
+        {"type": "paragraph", "content": [{"type": "text", "text": "Deployed"}]}
+    ]});
+    let signed = with_signature(&document, SIGNATURE);
+    assert_eq!(
+        signed["content"][1],
+        json!({"type": "paragraph", "content": [{"type": "text", "text": SIGNATURE}]})
+    );
+    assert!(has_signature(&signed, SIGNATURE));
+    assert_eq!(with_signature(&signed, SIGNATURE), signed);
+}
+
+#[tokio::test]
+async fn test_created_issues_get_the_label() {
+    let mut server = mockito::Server::new_async().await;
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .match_body(Matcher::PartialJson(json!({
+            "fields": {"summary": "Rotate keys", "labels": [LABEL]}
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "10001", "key": "MARK-1", "self": "x", "fields": {}}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = CreateIssueTool::new(create_test_config(&server.url()))
+        .handle(json!({"project_key": "MARK", "issue_type": "Task", "summary": "Rotate keys"}))
+        .await
+        .unwrap();
+    create.assert_async().await;
+    let text = &result.content[0].text;
+    assert!(
+        text.contains("Issue created successfully: MARK-1"),
+        "{text}"
+    );
+    assert!(text.contains(&format!("\nLabel added: {LABEL}")), "{text}");
+}
+
+#[tokio::test]
+async fn test_comments_and_work_logs_are_signed() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = mock_project_type(&mut server, "/rest/api/2", "MARK", "software").await;
+    let signed = format!("Deployed to staging\n\n{SIGNATURE}");
+    let comment = server
+        .mock("POST", "/rest/api/2/issue/MARK-2/comment")
+        .match_body(Matcher::Json(json!({ "body": signed })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(comment_response("10400", &signed))
+        .expect(1)
+        .create_async()
+        .await;
+    let work_log = server
+        .mock("POST", "/rest/api/2/issue/MARK-2/worklog")
+        .match_body(Matcher::PartialJson(json!({"comment": SIGNATURE})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "600",
+                "comment": SIGNATURE,
+                "time_spent": "1h",
+                "time_spent_seconds": 3600,
+                "author": {"name": "svc-bot", "displayName": "Service Bot"},
+                "created": "2025-10-17T09:00:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = AddCommentTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "MARK-2", "comment": "Deployed to staging"}))
+        .await
+        .unwrap();
+    comment.assert_async().await;
+    let text = &result.content[0].text;
+    assert!(
+        text.contains(&format!("\nSignature added: {SIGNATURE}")),
+        "{text}"
+    );
+
+    // A work log without a comment gets the signature as its comment
+    let result = AddWorkLogTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "MARK-2", "time_spent": "1h"}))
+        .await
+        .unwrap();
+    work_log.assert_async().await;
+    assert!(result.content[0].text.contains("Signature added"));
+}
+
+#[tokio::test]
+async fn test_bulk_comments_are_signed() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = mock_project_type(&mut server, "/rest/api/2", "MARK", "software").await;
+    let signed = format!("Release 4.2 is out\n\n{SIGNATURE}");
+    let mut posts = Vec::new();
+    for key in ["MARK-3", "MARK-4"] {
+        let _resolve = server
+            .mock("GET", format!("/rest/api/2/issue/{key}").as_str())
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({"id": "1", "key": key}).to_string())
+            .create_async()
+            .await;
+        posts.push(
+            server
+                .mock("POST", format!("/rest/api/2/issue/{key}/comment").as_str())
+                .match_body(Matcher::Json(json!({ "body": signed })))
+                .with_status(201)
+                .with_header("content-type", "application/json")
+                .with_body(comment_response("10500", &signed))
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+
+    let result = BulkAddCommentsTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "issue_keys": ["MARK-3", "MARK-4"],
+            "comment": "Release 4.2 is out",
+            "config": {"rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+    for post in posts {
+        post.assert_async().await;
+    }
+    let text = &result.content[0].text;
+    assert!(text.contains("2 of 2 operations succeeded"), "{text}");
+    assert!(
+        text.contains(&format!("\nSignature added: {SIGNATURE}")),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_clones_get_the_label() {
+    let mut server = mockito::Server::new_async().await;
+    let _original = server
+        .mock("GET", "/rest/api/2/issue/MARK-5")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10005",
+                "key": "MARK-5",
+                "self": "x",
+                "fields": {"summary": "Original", "labels": ["security"]}
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .match_body(Matcher::PartialJson(json!({
+            "fields": {"project": {"key": "COPY"}, "summary": "Copy", "labels": [LABEL]}
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "10006", "key": "COPY-1", "self": "x", "fields": {}}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = CloneIssueTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "original_issue_key": "MARK-5",
+            "project_key": "COPY",
+            "issue_type": "Task",
+            "summary": "Copy"
+        }))
+        .await
+        .unwrap();
+    create.assert_async().await;
+    let text = &result.content[0].text;
+    assert!(text.contains("Cloned: COPY-1"), "{text}");
+    assert!(text.contains(&format!("\nLabel added: {LABEL}")), "{text}");
+}
+
+#[tokio::test]
+async fn test_nothing_is_marked_by_default() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = mock_project_type(&mut server, "/rest/api/2", "MARK", "software").await;
+    let comment = server
+        .mock("POST", "/rest/api/2/issue/MARK-6/comment")
+        .match_body(Matcher::Json(json!({"body": "Plain"})))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(comment_response("10600", "Plain"))
+        .expect(1)
+        .create_async()
+        .await;
+    let config = JiraConfig {
+        auto_label: None,
+        comment_signature: None,
+        ..create_test_config(&server.url())
+    };
+
+    let result = AddCommentTool::new(config)
+        .handle(json!({"issue_key": "MARK-6", "comment": "Plain"}))
+        .await
+        .unwrap();
+    comment.assert_async().await;
+    assert!(!result.content[0].text.contains("Signature"));
+}
+
+#[test]
+fn test_invalid_markers_fail_validation() {
+    let config = |label: Option<&str>, signature: Option<&str>| JiraConfig {
+        auto_label: label.map(ToString::to_string),
+        comment_signature: signature.map(ToString::to_string),
+        ..create_test_config("https://jira.example.com")
+    };
+    assert!(config(Some(LABEL), Some(SIGNATURE)).validate().is_ok());
+    let error = config(Some("created by mcp"), None).validate().unwrap_err();
+    assert!(
+        error.to_string().contains("cannot contain spaces"),
+        "{error}"
+    );
+    let error = config(None, Some("one\ntwo")).validate().unwrap_err();
+    assert!(error.to_string().contains("single line"), "{error}");
+}
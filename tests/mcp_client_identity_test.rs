@@ -0,0 +1,60 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::identity::{mcp_client, set_mcp_client, user_agent, CLIENT_VERSION};
+use rust_jira_mcp::types::mcp::ClientInfo;
+use serde_json::json;
+
+// Kept apart from the other identity tests, as the MCP client is process-wide
+#[tokio::test]
+async fn test_mcp_client_is_named_in_the_user_agent() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let config = JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    };
+    assert_eq!(mcp_client().map(|c| c.name), None);
+    assert_eq!(
+        user_agent(&config),
+        format!("rust-jira-mcp/{CLIENT_VERSION}")
+    );
+
+    set_mcp_client(&ClientInfo {
+        name: "Claude Desktop".to_string(),
+        version: "0.9.3 (beta)".to_string(),
+    });
+    let expected = format!("rust-jira-mcp/{CLIENT_VERSION} (Claude-Desktop/0.9.3-beta)");
+    assert_eq!(user_agent(&config), expected);
+
+    let issue = server
+        .mock("GET", "/rest/api/2/issue/ID-3")
+        .match_header("User-Agent", expected.as_str())
+        .match_header("X-Client-Name", "rust-jira-mcp")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "3", "key": "ID-3", "self": "x", "fields": {}}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let upload = server
+        .mock("POST", "/rest/api/2/issue/ID-3/attachments")
+        .match_header("User-Agent", expected.as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(config).unwrap();
+    client.get_issue("ID-3").await.unwrap();
+    client
+        .upload_attachment("ID-3", "a.txt", b"a", None)
+        .await
+        .unwrap();
+    issue.assert_async().await;
+    upload.assert_async().await;
+}
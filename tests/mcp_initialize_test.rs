@@ -0,0 +1,181 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::{MCPServer, SUPPORTED_PROTOCOL_VERSIONS};
+use rust_jira_mcp::types::mcp::JsonRpcResponse;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn send(server: &mut MCPServer, message: Value) -> Option<JsonRpcResponse> {
+    server.handle_request(&message.to_string()).await.unwrap()
+}
+
+fn initialize(id: u64, protocol_version: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": protocol_version,
+            "capabilities": {"roots": {"listChanged": true}, "sampling": null},
+            "clientInfo": {"name": "test-client", "version": "1.0.0"}
+        }
+    })
+}
+
+fn call_get_issue(id: u64) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "tools/call",
+        "params": {"name": "get_jira_issue", "arguments": {"issue_key": "INIT-1"}}
+    })
+}
+
+#[tokio::test]
+async fn test_tools_are_not_called_before_initialization() {
+    let mut mock_server = mockito::Server::new_async().await;
+    let base_url = mock_server.url();
+    let issue = mock_server
+        .mock("GET", "/rest/api/2/issue/INIT-1")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"id": "1", "key": "INIT-1", "self": "x", "fields": {"summary": "Ready"}})
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let mut server = MCPServer::new(create_test_config(&base_url));
+
+    let response = send(&mut server, call_get_issue(1)).await.unwrap();
+    let error = response.error.unwrap();
+    assert_eq!(error.code, -32002);
+    assert_eq!(error.message, "Server not initialized");
+    assert_eq!(response.id, Some(json!(1)));
+
+    // Initialization is only complete once the client confirms it
+    send(&mut server, initialize(2, "2024-11-05"))
+        .await
+        .unwrap();
+    let response = send(&mut server, call_get_issue(3)).await.unwrap();
+    assert_eq!(response.error.unwrap().code, -32002);
+
+    let notification = json!({"jsonrpc": "2.0", "method": "notifications/initialized"});
+    assert!(send(&mut server, notification).await.is_none());
+    let response = send(&mut server, call_get_issue(4)).await.unwrap();
+    assert!(response.error.is_none());
+    assert!(response.result.is_some());
+    issue.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_protocol_version_negotiation() {
+    for (requested, expected) in [
+        ("2024-11-05", "2024-11-05"),
+        ("2025-03-26", "2025-03-26"),
+        ("2099-01-01", SUPPORTED_PROTOCOL_VERSIONS[0]),
+        ("2023-01-01", SUPPORTED_PROTOCOL_VERSIONS[0]),
+    ] {
+        let mut server = MCPServer::new(create_test_config("http://localhost:1"));
+        assert!(server.client_session().is_none());
+        assert!(!server.client_supports("roots"));
+        let response = send(&mut server, initialize(1, requested)).await.unwrap();
+        assert_eq!(
+            response.result.unwrap()["protocol_version"],
+            expected,
+            "requested {requested}"
+        );
+        assert_eq!(server.client_session().unwrap().protocol_version, expected);
+
+        let session = server.client_session().unwrap();
+        assert_eq!(session.client_info.name, "test-client");
+        assert!(server.client_supports("roots"));
+        assert!(!server.client_supports("sampling"));
+        assert!(!server.client_supports("elicitation"));
+    }
+}
+
+#[tokio::test]
+async fn test_second_initialize_is_rejected() {
+    let mut server = MCPServer::new(create_test_config("http://localhost:1"));
+    send(&mut server, initialize(1, "2024-11-05"))
+        .await
+        .unwrap();
+    let notification = json!({"jsonrpc": "2.0", "method": "notifications/initialized"});
+    assert!(send(&mut server, notification).await.is_none());
+
+    let response = send(&mut server, initialize(2, "2025-03-26"))
+        .await
+        .unwrap();
+    let error = response.error.unwrap();
+    assert_eq!(error.code, -32600);
+    assert_eq!(response.id, Some(json!(2)));
+    // The session stays as the first initialize left it
+    assert_eq!(
+        server.client_session().unwrap().protocol_version,
+        "2024-11-05"
+    );
+    let response = send(&mut server, call_get_issue(3)).await.unwrap();
+    assert_ne!(response.error.map(|error| error.code), Some(-32002));
+}
+
+#[tokio::test]
+async fn test_warmup_warning_only_goes_to_clients_taking_log_messages() {
+    // Nothing listens here, so every warm-up step fails
+    let config = create_test_config("http://127.0.0.1:9");
+    rust_jira_mcp::jira::warmup::warm_up(&config).await;
+    let notification = json!({"jsonrpc": "2.0", "method": "notifications/initialized"});
+
+    let mut server = MCPServer::new(config.clone());
+    let response = send(&mut server, initialize(1, "2025-03-26"))
+        .await
+        .unwrap();
+    assert!(response.result.unwrap()["capabilities"]
+        .get("logging")
+        .is_none());
+    send(&mut server, notification.clone()).await;
+    assert!(server.take_notifications().is_empty());
+
+    let mut server = MCPServer::new(config);
+    let mut request = initialize(1, "2025-03-26");
+    request["params"]["capabilities"] = json!({"logging": {}});
+    let response = send(&mut server, request).await.unwrap();
+    assert_eq!(
+        response.result.unwrap()["capabilities"]["logging"],
+        json!({})
+    );
+    send(&mut server, notification).await;
+    let notifications = server.take_notifications();
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0]["method"], "notifications/message");
+    assert_eq!(notifications[0]["params"]["level"], "warning");
+    assert!(notifications[0]["params"]["data"]
+        .as_str()
+        .unwrap()
+        .contains("failed: server info"));
+    assert!(server.take_notifications().is_empty());
+}
+
+#[test]
+fn test_negotiate_protocol_version() {
+    assert_eq!(SUPPORTED_PROTOCOL_VERSIONS[0], "2025-03-26");
+    assert_eq!(
+        MCPServer::negotiate_protocol_version("2024-11-05"),
+        "2024-11-05"
+    );
+    assert_eq!(MCPServer::negotiate_protocol_version(""), "2025-03-26");
+}
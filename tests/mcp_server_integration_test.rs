@@ -14,6 +14,7 @@ fn create_test_config(base_url: &str) -> JiraConfig {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false), // Disable SSL verification for tests
+        ..Default::default()
     }
 }
 
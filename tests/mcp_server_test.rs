@@ -14,6 +14,7 @@ fn test_config() -> JiraConfig {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     }
 }
 
@@ -171,13 +172,49 @@ fn test_tool_schemas() {
     }
 }
 
+#[test]
+fn test_list_tools_annotations_and_categories() {
+    let tools = MCPServer::list_tools();
+    let tool = |name: &str| tools.iter().find(|t| t.name == name).unwrap();
+    let hints = |name: &str| {
+        let annotations = tool(name).annotations.clone().unwrap();
+        (annotations.read_only_hint, annotations.destructive_hint)
+    };
+
+    assert_eq!(hints("get_jira_issue"), (Some(true), None));
+    assert_eq!(hints("search_jira_issues"), (Some(true), None));
+    assert_eq!(hints("sprint_report"), (Some(true), None));
+    assert_eq!(hints("create_jira_issue"), (Some(false), Some(false)));
+    assert_eq!(hints("add_jira_comment"), (Some(false), Some(false)));
+    assert_eq!(hints("update_jira_issue"), (Some(false), Some(true)));
+    assert_eq!(hints("delete_jira_attachment"), (Some(false), Some(true)));
+    assert_eq!(hints("mixed_bulk_operations"), (Some(false), Some(true)));
+
+    let category = |name: &str| tool(name).meta.as_ref().unwrap().category;
+    assert_eq!(category("get_jira_issue"), ToolCategory::Basic);
+    assert_eq!(category("bulk_add_comments"), ToolCategory::Bulk);
+    assert_eq!(category("get_zephyr_test_cases"), ToolCategory::Zephyr);
+    assert_eq!(category("delete_jira_label"), ToolCategory::Labels);
+    assert_eq!(category("start_sprint"), ToolCategory::Sprints);
+    assert!(tools
+        .iter()
+        .all(|t| t.annotations.is_some() && t.meta.is_some()));
+
+    let listed = serde_json::to_value(tool("delete_jira_work_log")).unwrap();
+    assert_eq!(
+        listed["annotations"],
+        json!({"readOnlyHint": false, "destructiveHint": true})
+    );
+    assert_eq!(listed["_meta"], json!({"category": "worklogs"}));
+}
+
 #[test]
 fn test_tool_count() {
     let tools = MCPServer::list_tools();
 
     // Should have a reasonable number of tools
     assert!(tools.len() >= 50);
-    assert!(tools.len() <= 100); // Reasonable upper bound
+    assert!(tools.len() <= 150); // Reasonable upper bound
 }
 
 #[tokio::test]
@@ -0,0 +1,265 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPServer;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+fn create_test_config() -> JiraConfig {
+    create_test_config_for("http://localhost:1")
+}
+
+fn create_test_config_for(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+/// Feed `lines` to a fresh server over the stdio loop and return the lines it
+/// wrote back, parsed
+async fn run(lines: &[String]) -> Vec<Value> {
+    run_with(create_test_config(), lines).await
+}
+
+async fn run_with(config: JiraConfig, lines: &[String]) -> Vec<Value> {
+    let input = lines.join("\n") + "\n";
+    let mut output = Vec::new();
+    let mut server = MCPServer::new(config);
+    server.serve(input.as_bytes(), &mut output).await.unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+fn handshake() -> Vec<String> {
+    vec![
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2024-11-05",
+                "capabilities": {},
+                "clientInfo": {"name": "stdio-test", "version": "1.0.0"}
+            }
+        })
+        .to_string(),
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}).to_string(),
+    ]
+}
+
+#[tokio::test]
+async fn test_mixed_batch_is_answered_in_order() {
+    let mut lines = handshake();
+    lines.push(
+        json!([
+            {
+                "jsonrpc": "2.0",
+                "id": "call-1",
+                "method": "tools/call",
+                "params": {"name": "test_jira_auth", "arguments": {}}
+            },
+            {"jsonrpc": "2.0", "method": "notifications/initialized"},
+            {"jsonrpc": "2.0", "id": 7},
+            42,
+            {"jsonrpc": "2.0", "id": "list", "method": "tools/list", "params": {}}
+        ])
+        .to_string(),
+    );
+
+    let replies = run(&lines).await;
+    assert_eq!(replies.len(), 2);
+    assert_eq!(replies[0]["id"], 1);
+
+    let batch = replies[1].as_array().unwrap();
+    assert_eq!(batch.len(), 4);
+    assert_eq!(batch[0]["id"], "call-1");
+    assert_eq!(
+        batch[0]["result"]["content"][0]["text"],
+        "Authentication test successful"
+    );
+    assert_eq!(
+        batch[1],
+        json!({"jsonrpc": "2.0", "id": 7, "error": {"code": -32600, "message": "Invalid Request"}})
+    );
+    assert_eq!(
+        batch[2],
+        json!({"jsonrpc": "2.0", "id": null, "error": {"code": -32600, "message": "Invalid Request"}})
+    );
+    assert_eq!(batch[3]["id"], "list");
+    assert!(batch[3]["result"]["tools"].as_array().unwrap().len() > 50);
+}
+
+#[tokio::test]
+async fn test_empty_unparseable_and_notification_only_batches() {
+    let mut lines = handshake();
+    lines.push("[]".to_string());
+    lines.push("[{\"jsonrpc\": \"2.0\",".to_string());
+    lines.push(json!([{"jsonrpc": "2.0", "method": "notifications/initialized"}]).to_string());
+    lines
+        .push(json!({"jsonrpc": "2.0", "id": 9, "method": "tools/list", "params": {}}).to_string());
+
+    let replies = run(&lines).await;
+    assert_eq!(replies.len(), 4);
+    assert_eq!(
+        replies[1],
+        json!({"jsonrpc": "2.0", "id": null, "error": {"code": -32600, "message": "Invalid Request"}})
+    );
+    assert_eq!(
+        replies[2],
+        json!({"jsonrpc": "2.0", "id": null, "error": {"code": -32700, "message": "Parse error"}})
+    );
+    // The notification-only batch got no reply
+    assert_eq!(replies[3]["id"], 9);
+}
+
+#[tokio::test]
+async fn test_content_length_framing_is_answered_in_kind() {
+    let input: String = handshake()
+        .iter()
+        .chain(
+            [
+                json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list", "params": {}})
+                    .to_string(),
+            ]
+            .iter(),
+        )
+        .map(|message| format!("Content-Length: {}\r\n\r\n{message}", message.len()))
+        .collect();
+    let mut output = Vec::new();
+    let mut server = MCPServer::new(create_test_config());
+    server.serve(input.as_bytes(), &mut output).await.unwrap();
+
+    let mut output = String::from_utf8(output).unwrap();
+    let mut replies = Vec::new();
+    while !output.is_empty() {
+        let (header, rest) = output.split_once("\r\n\r\n").unwrap();
+        let length: usize = header
+            .strip_prefix("Content-Length: ")
+            .unwrap()
+            .parse()
+            .unwrap();
+        replies.push(serde_json::from_str::<Value>(&rest[..length]).unwrap());
+        output = rest[length..].to_string();
+    }
+    assert_eq!(replies.len(), 2);
+    assert_eq!(replies[0]["id"], 1);
+    // The tool list is well past the 8 KiB read buffer
+    assert_eq!(replies[1]["id"], 2);
+    assert!(replies[1]["result"]["tools"].as_array().unwrap().len() > 50);
+}
+
+#[tokio::test]
+async fn test_notifications_are_never_answered() {
+    // Before initialization, a tool call sent as a notification is dropped
+    // rather than answered with "Server not initialized"
+    let mut lines = vec![json!([
+        {"jsonrpc": "2.0", "method": "tools/call", "params": {"name": "test_jira_auth", "arguments": {}}},
+        {"jsonrpc": "2.0", "id": "early", "method": "tools/list", "params": {}}
+    ])
+    .to_string()];
+    lines.extend(handshake());
+    lines.push(
+        json!({"jsonrpc": "2.0", "method": "notifications/cancelled", "params": {"requestId": 3}})
+            .to_string(),
+    );
+    lines.push(
+        json!([
+            {"jsonrpc": "2.0", "method": "notifications/cancelled", "params": {"requestId": 4}},
+            {"jsonrpc": "2.0", "method": "notifications/progress"}
+        ])
+        .to_string(),
+    );
+    lines.push(
+        json!([
+            {"jsonrpc": "2.0", "method": "notifications/roots/list_changed"},
+            {"jsonrpc": "2.0", "id": 5, "method": "resources/list"}
+        ])
+        .to_string(),
+    );
+
+    let replies = run(&lines).await;
+    assert_eq!(replies.len(), 3);
+    assert_eq!(replies[0].as_array().unwrap().len(), 1);
+    assert_eq!(replies[0][0]["id"], "early");
+    assert_eq!(replies[1]["id"], 1);
+    assert_eq!(
+        replies[2],
+        json!([{"jsonrpc": "2.0", "id": 5, "error": {"code": -32601, "message": "Method not found"}}])
+    );
+}
+
+/// Serve every HTTP request after a pause, recording the most requests that
+/// were in flight at once
+async fn slow_jira() -> (String, Arc<AtomicUsize>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let most_in_flight = Arc::new(AtomicUsize::new(0));
+    let most = most_in_flight.clone();
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let in_flight = in_flight.clone();
+            let most = most.clone();
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buffer = [0; 1024];
+                while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                    match socket.read(&mut buffer).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(read) => request.extend_from_slice(&buffer[..read]),
+                    }
+                }
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                most.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(300)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                let body = r#"{"key": "DNA-1", "fields": {"summary": "Slow"}}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+    (base_url, most_in_flight)
+}
+
+#[tokio::test]
+async fn test_batch_tool_calls_run_concurrently() {
+    let (base_url, most_in_flight) = slow_jira().await;
+    let calls: Vec<Value> = (1..=6)
+        .map(|n| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": n,
+                "method": "tools/call",
+                "params": {"name": "get_jira_issue", "arguments": {"issue_key": format!("DNA-{n}")}}
+            })
+        })
+        .collect();
+    let mut lines = handshake();
+    lines.push(Value::Array(calls).to_string());
+
+    let replies = run_with(create_test_config_for(&base_url), &lines).await;
+    let batch = replies[1].as_array().unwrap();
+    // Answered in order even though the calls overlapped
+    let ids: Vec<_> = batch.iter().map(|reply| reply["id"].clone()).collect();
+    assert_eq!(ids, (1..=6).map(Value::from).collect::<Vec<_>>());
+    let most = most_in_flight.load(Ordering::SeqCst);
+    assert!(most > 1, "calls ran one at a time");
+    assert!(most <= rust_jira_mcp::mcp::server::BATCH_CONCURRENCY);
+}
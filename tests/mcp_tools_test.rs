@@ -9,9 +9,9 @@ use rust_jira_mcp::mcp::tools::{
     GetIssueLinksTool, GetIssueTool, GetIssueTypeMetadataTool, GetIssueTypesTool,
     GetIssueWatchersTool, GetIssueWorkLogsTool, GetLabelsTool, GetLinkTypesTool,
     GetPrioritiesAndStatusesTool, GetProjectComponentsTool, GetProjectConfigTool,
-    GetProjectMetadataTool, GetTransitionsTool, MixedBulkOperationsTool, RemoveIssueWatcherTool,
-    SearchIssuesTool, TestAuthTool, TransitionIssueTool, UpdateComponentTool, UpdateIssueTool,
-    UpdateLabelTool, UpdateWorkLogTool, UploadAttachmentTool,
+    GetProjectMetadataTool, GetServerMetricsTool, GetTransitionsTool, MixedBulkOperationsTool,
+    RemoveIssueWatcherTool, SearchIssuesTool, TestAuthTool, TransitionIssueTool,
+    UpdateComponentTool, UpdateIssueTool, UpdateLabelTool, UpdateWorkLogTool, UploadAttachmentTool,
 };
 use serde_json::json;
 
@@ -25,6 +25,7 @@ fn test_config() -> JiraConfig {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(false),
+        ..Default::default()
     }
 }
 
@@ -37,6 +38,17 @@ async fn test_test_auth_tool() {
     let _ = tool;
 }
 
+#[tokio::test]
+async fn test_get_server_metrics_tool_handle() {
+    let config = test_config();
+    let tool = GetServerMetricsTool::new(config);
+
+    let result = tool.handle(json!({})).await.unwrap();
+    assert!(!result.is_error.unwrap_or(true));
+    assert!(result.content[0].text.contains("\"throttle\""));
+    assert!(result.content[0].text.contains("effective_rate_per_second"));
+}
+
 #[tokio::test]
 async fn test_test_auth_tool_handle() {
     let config = test_config();
@@ -1160,6 +1172,7 @@ async fn test_parameter_extraction_logic() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: None,
+        ..Default::default()
     };
 
     let tool = SearchIssuesTool::new(config);
@@ -0,0 +1,235 @@
+mod common;
+
+use common::mock_project_type;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::AddCommentTool;
+use rust_jira_mcp::utils::mentions::{
+    find_mentions, match_candidate, MentionCandidate, MentionMatch, MentionResolution,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn queries(text: &str) -> Vec<String> {
+    find_mentions(text).into_iter().map(|t| t.query).collect()
+}
+
+fn candidate(account_id: &str, display_name: &str, email: &str) -> MentionCandidate {
+    MentionCandidate {
+        account_id: Some(account_id.to_string()),
+        username: None,
+        display_name: display_name.to_string(),
+        email: Some(email.to_string()),
+    }
+}
+
+#[test]
+fn test_find_simple_mentions() {
+    assert_eq!(
+        queries("@alice please review, cc @bob."),
+        vec!["alice", "bob"]
+    );
+    assert_eq!(queries("(@carol) and @dave-ops"), vec!["carol", "dave-ops"]);
+}
+
+#[test]
+fn test_find_email_mentions() {
+    assert_eq!(
+        queries("@alice@example.com can you check?"),
+        vec!["alice@example.com"]
+    );
+    // A plain email address is not a mention
+    assert!(queries("mail bob@example.com for access").is_empty());
+}
+
+#[test]
+fn test_find_quoted_display_names() {
+    let tokens = find_mentions("Thanks @\"Alice Smith\", and @\" Bob Jones \"!");
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].query, "Alice Smith");
+    assert_eq!(tokens[0].start, 7);
+    assert_eq!(tokens[0].end, 21);
+    assert_eq!(tokens[1].query, "Bob Jones");
+}
+
+#[test]
+fn test_find_no_mentions() {
+    assert!(queries("no mentions here").is_empty());
+    assert!(queries("lonely @ sign and @\"unterminated").is_empty());
+    assert!(queries("@. @-").is_empty());
+}
+
+#[test]
+fn test_match_candidate_requires_exact_match() {
+    let candidates = vec![
+        candidate("a1", "Alice Smith", "alice@example.com"),
+        candidate("a2", "Alice Smithers", "asmithers@example.com"),
+    ];
+
+    assert_eq!(
+        match_candidate("alice smith", &candidates),
+        MentionMatch::Resolved(candidates[0].clone())
+    );
+    assert_eq!(
+        match_candidate("ASMITHERS@example.com", &candidates),
+        MentionMatch::Resolved(candidates[1].clone())
+    );
+    assert_eq!(
+        match_candidate("Alice", &candidates),
+        MentionMatch::NotFound
+    );
+
+    let twins = vec![
+        candidate("a1", "Sam Lee", "sam.lee@example.com"),
+        candidate("a3", "Sam Lee", "slee@example.com"),
+    ];
+    assert!(matches!(
+        match_candidate("Sam Lee", &twins),
+        MentionMatch::Ambiguous(names) if names.len() == 2
+    ));
+}
+
+#[test]
+fn test_resolution_rewrites_wiki_markup_and_reports() {
+    let mut matches = HashMap::new();
+    matches.insert(
+        "alice".to_string(),
+        MentionMatch::Resolved(candidate("a1", "Alice Smith", "alice@example.com")),
+    );
+    matches.insert("nobody".to_string(), MentionMatch::NotFound);
+    matches.insert(
+        "Sam Lee".to_string(),
+        MentionMatch::Ambiguous(vec!["Sam Lee <a>".to_string(), "Sam Lee <b>".to_string()]),
+    );
+
+    let resolution =
+        MentionResolution::new("@alice and @nobody, ask @\"Sam Lee\"", &matches, false);
+
+    assert_eq!(
+        resolution.body,
+        json!("[~accountid:a1] and @nobody, ask @\"Sam Lee\"")
+    );
+    let report = resolution.report().unwrap();
+    assert!(report.contains("Mentioned: Alice Smith"));
+    assert!(report.contains("Unresolved mentions (left as text): @nobody"));
+    assert!(report.contains("Ambiguous mention @\"Sam Lee\""));
+}
+
+#[test]
+fn test_resolution_builds_adf_for_v3() {
+    let mut matches = HashMap::new();
+    matches.insert(
+        "alice".to_string(),
+        MentionMatch::Resolved(candidate("a1", "Alice Smith", "alice@example.com")),
+    );
+
+    let resolution = MentionResolution::new("Hi @alice\nthanks", &matches, true);
+
+    assert_eq!(resolution.body["type"], "doc");
+    assert_eq!(
+        resolution.body["content"][0]["content"],
+        json!([
+            {"type": "text", "text": "Hi "},
+            {"type": "mention", "attrs": {"id": "a1", "text": "@Alice Smith"}},
+            {"type": "hardBreak"},
+            {"type": "text", "text": "thanks"}
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_add_comment_resolves_mentions() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = mock_project_type(&mut server, "/rest/api/2", "MENTION", "software").await;
+    let base_url = server.url();
+
+    let search = server
+        .mock("GET", "/rest/api/2/user/search")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "username".to_string(),
+            "mentionee".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([{
+                "self": format!("{base_url}/rest/api/2/user?username=mentionee"),
+                "name": "mentionee",
+                "key": "mentionee",
+                "displayName": "Mention Ee",
+                "active": true
+            }])
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _empty_search = server
+        .mock("GET", "/rest/api/2/user/search")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "username".to_string(),
+            "ghost".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let post = server
+        .mock("POST", "/rest/api/2/issue/MENTION-1/comment")
+        .match_body(mockito::Matcher::Json(json!({
+            "body": "[~mentionee] and [~mentionee], not @ghost"
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10200",
+                "body": "[~mentionee] and [~mentionee], not @ghost",
+                "author": {
+                    "self": format!("{base_url}/rest/api/2/user?username=test"),
+                    "name": "test",
+                    "key": "test",
+                    "displayName": "Test User",
+                    "active": true
+                },
+                "created": "2024-03-02T14:30:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = AddCommentTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_key": "MENTION-1",
+            "comment": "@mentionee and @mentionee, not @ghost",
+            "resolve_mentions": true
+        }))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.contains("Mentioned: Mention Ee"));
+    assert!(text.contains("Unresolved mentions (left as text): @ghost"));
+    search.assert_async().await;
+    post.assert_async().await;
+}
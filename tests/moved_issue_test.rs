@@ -0,0 +1,149 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{AddIssueWatcherTool, BulkAddCommentsTool, GetIssueTool};
+use serde_json::{json, Value};
+
+/// `OLD-12` as Jira returns it after the issue moved to project NEW
+const ISSUE_MOVED: &str = include_str!("fixtures/issue_moved.json");
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn mock_moved_issue(server: &mut mockito::ServerGuard, requested: &str) {
+    server
+        .mock("GET", format!("/rest/api/2/issue/{requested}").as_str())
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(ISSUE_MOVED)
+        .create_async()
+        .await;
+}
+
+#[tokio::test]
+async fn test_resolve_issue_key() {
+    let mut server = mockito::Server::new_async().await;
+    mock_moved_issue(&mut server, "OLD-12").await;
+    mock_moved_issue(&mut server, "new-34").await;
+    let _missing = server
+        .mock("GET", "/rest/api/2/issue/GONE-1")
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["Issue does not exist"]}).to_string())
+        .create_async()
+        .await;
+    let client = JiraClient::new(create_test_config(&server.url())).unwrap();
+
+    let moved = client.resolve_issue_key("OLD-12").await;
+    assert_eq!(moved.key, "NEW-34");
+    assert!(moved.was_moved());
+    assert_eq!(moved.note(), "Issue OLD-12 was moved to NEW-34\n");
+
+    // A key in another case is the same issue
+    let same = client.resolve_issue_key("new-34").await;
+    assert_eq!(same.key, "NEW-34");
+    assert!(!same.was_moved());
+    assert_eq!(same.note(), "");
+
+    // Unreadable issues keep their key for the request that follows
+    let missing = client.resolve_issue_key("GONE-1").await;
+    assert_eq!(missing.key, "GONE-1");
+    assert!(!missing.was_moved());
+}
+
+#[tokio::test]
+async fn test_get_issue_reports_the_move() {
+    let mut server = mockito::Server::new_async().await;
+    mock_moved_issue(&mut server, "OLD-12").await;
+
+    let result = GetIssueTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "OLD-12"}))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with("Issue OLD-12 was moved to NEW-34\nIssue: NEW-34\n"),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_watcher_is_added_under_the_new_key() {
+    let mut server = mockito::Server::new_async().await;
+    mock_moved_issue(&mut server, "OLD-12").await;
+    let add = server
+        .mock("POST", "/rest/api/2/issue/NEW-34/watchers")
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = AddIssueWatcherTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "OLD-12", "account_id": "dana"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Issue OLD-12 was moved to NEW-34\nWatcher dana added successfully to issue NEW-34"
+    );
+    add.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bulk_summary_uses_the_new_key() {
+    let mut server = mockito::Server::new_async().await;
+    mock_moved_issue(&mut server, "OLD-12").await;
+    let comments = server
+        .mock(
+            "POST",
+            mockito::Matcher::Regex(r"^/rest/api/2/issue/(NEW-34|KEPT-1)/comment$".to_string()),
+        )
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "1",
+                "body": "Released",
+                "author": {
+                    "self": "x",
+                    "name": "dana",
+                    "key": "dana",
+                    "displayName": "Dana Reyes",
+                    "active": true
+                },
+                "created": "2024-03-02T14:30:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(2)
+        .create_async()
+        .await;
+
+    let result = BulkAddCommentsTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "issue_keys": ["OLD-12", "KEPT-1"],
+            "comment": "Released",
+            "config": {"max_retries": 0, "rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Bulk comment: 2 of 2 operations succeeded\n• OLD-12 was moved to NEW-34"
+    );
+    let report: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(report["results"][0]["issue_key"], "NEW-34");
+    assert_eq!(report["results"][0]["moved_from"], "OLD-12");
+    assert_eq!(report["results"][1]["issue_key"], "KEPT-1");
+    assert!(report["results"][1].get("moved_from").is_none());
+    comments.assert_async().await;
+}
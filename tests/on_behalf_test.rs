@@ -0,0 +1,218 @@
+mod common;
+
+use common::mock_project_type;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::attribution::{
+    attribution_line, parse_attribution, strip_attribution, with_attribution,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{AddCommentTool, AddWorkLogTool};
+use serde_json::json;
+
+fn create_test_config(base_url: &str, annotate: bool) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        annotate_on_behalf: Some(annotate),
+        ..Default::default()
+    }
+}
+
+fn user(name: &str, display_name: &str) -> serde_json::Value {
+    json!({
+        "self": format!("https://jira.example.com/rest/api/2/user?username={name}"),
+        "name": name,
+        "key": name,
+        "displayName": display_name,
+        "emailAddress": format!("{name}@example.com"),
+        "active": true
+    })
+}
+
+async fn mock_user_search(server: &mut mockito::Server, query: &str, users: serde_json::Value) {
+    server
+        .mock("GET", "/rest/api/2/user/search")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "username".to_string(),
+            query.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(users.to_string())
+        .create_async()
+        .await;
+}
+
+#[test]
+fn test_attribution_format_round_trips() {
+    let line = attribution_line("Dana Scully");
+    assert_eq!(line, "_on behalf of Dana Scully via rust-jira-mcp_");
+    assert_eq!(parse_attribution(&line), Some("Dana Scully"));
+
+    let body = with_attribution("Deployed to staging", "Dana Scully");
+    assert_eq!(
+        body,
+        "_on behalf of Dana Scully via rust-jira-mcp_\n\nDeployed to staging"
+    );
+    assert_eq!(parse_attribution(&body), Some("Dana Scully"));
+    assert_eq!(strip_attribution(&body), "Deployed to staging");
+
+    // Attributing again replaces the line instead of stacking another one
+    let edited = with_attribution(&body, "Fox Mulder");
+    assert_eq!(
+        edited,
+        "_on behalf of Fox Mulder via rust-jira-mcp_\n\nDeployed to staging"
+    );
+    assert_eq!(
+        with_attribution("", "Fox Mulder"),
+        attribution_line("Fox Mulder")
+    );
+
+    assert_eq!(parse_attribution("on behalf of Dana Scully"), None);
+    assert_eq!(parse_attribution("_on behalf of  via rust-jira-mcp_"), None);
+    assert_eq!(strip_attribution("Plain comment"), "Plain comment");
+}
+
+#[tokio::test]
+async fn test_comment_on_behalf_of_a_resolved_user() {
+    let mut server = mockito::Server::new_async().await;
+    let _project = mock_project_type(&mut server, "/rest/api/2", "XF", "software").await;
+    let base_url = server.url();
+    mock_user_search(
+        &mut server,
+        "dscully",
+        json!([user("dscully", "Dana Scully")]),
+    )
+    .await;
+    let post = server
+        .mock("POST", "/rest/api/2/issue/XF-1/comment")
+        .match_body(mockito::Matcher::Json(json!({
+            "body": "_on behalf of Dana Scully via rust-jira-mcp_\n\nSamples sent to the lab"
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10300",
+                "body": "_on behalf of Dana Scully via rust-jira-mcp_\n\nSamples sent to the lab",
+                "author": user("svc-bot", "Service Bot"),
+                "created": "2024-03-02T14:30:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = AddCommentTool::new(create_test_config(&base_url, true));
+    let result = tool
+        .handle(json!({
+            "issue_key": "XF-1",
+            "comment": "Samples sent to the lab",
+            "on_behalf_of": "dscully"
+        }))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(text.contains("Author: Service Bot"));
+    assert!(text.contains("On behalf of: Dana Scully"));
+    post.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_unresolved_user_blocks_the_write() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    mock_user_search(&mut server, "nobody", json!([])).await;
+    mock_user_search(
+        &mut server,
+        "Smith",
+        json!([user("asmith", "Smith"), user("bsmith", "Smith")]),
+    )
+    .await;
+    let comment = server
+        .mock("POST", "/rest/api/2/issue/XF-2/comment")
+        .expect(0)
+        .create_async()
+        .await;
+    let worklog = server
+        .mock("POST", "/rest/api/2/issue/XF-2/worklog")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let tool = AddCommentTool::new(create_test_config(&base_url, true));
+    let error = tool
+        .handle(json!({"issue_key": "XF-2", "comment": "Hi", "on_behalf_of": "nobody"}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("No user matches 'nobody'"));
+
+    let tool = AddWorkLogTool::new(create_test_config(&base_url, true));
+    let error = tool
+        .handle(json!({"issue_key": "XF-2", "time_spent": "1h", "on_behalf_of": "Smith"}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains(
+        "'Smith' matches several users: Smith <asmith@example.com>, Smith <bsmith@example.com>"
+    ));
+
+    // Disabled unless configured, rather than writing without attribution
+    let tool = AddCommentTool::new(create_test_config(&base_url, false));
+    let error = tool
+        .handle(json!({"issue_key": "XF-2", "comment": "Hi", "on_behalf_of": "dscully"}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("JIRA_ANNOTATE_ON_BEHALF=true"));
+
+    comment.assert_async().await;
+    worklog.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_work_log_without_comment_gets_the_attribution_line() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    mock_user_search(
+        &mut server,
+        "fmulder",
+        json!([user("fmulder", "Fox Mulder")]),
+    )
+    .await;
+    let post = server
+        .mock("POST", "/rest/api/2/issue/XF-3/worklog")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "comment": "_on behalf of Fox Mulder via rust-jira-mcp_",
+            "time_spent": "2h"
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "500",
+                "comment": "_on behalf of Fox Mulder via rust-jira-mcp_",
+                "time_spent": "2h",
+                "time_spent_seconds": 7200,
+                "author": user("svc-bot", "Service Bot"),
+                "created": "2024-03-02T14:30:00.000+0000"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = AddWorkLogTool::new(create_test_config(&base_url, true));
+    let result = tool
+        .handle(json!({"issue_key": "XF-3", "time_spent": "2h", "on_behalf_of": "fmulder"}))
+        .await
+        .unwrap();
+    assert!(result.content[0].text.ends_with("On behalf of: Fox Mulder"));
+    post.assert_async().await;
+}
@@ -0,0 +1,363 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::{MCPServer, MCPToolHandler, VERBOSITY_TOOLS};
+use rust_jira_mcp::mcp::tools::{
+    BulkAddCommentsTool, FieldFormatter, GetCommentsTool, GetIssueAttachmentsTool, GetIssueTool,
+    GetTransitionsTool, ResponseFormatter, SearchIssuesTool, Verbosity,
+};
+use rust_jira_mcp::types::mcp::MCPToolResult;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str, verbosity: Option<&str>) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        output_verbosity: verbosity.map(ToString::to_string),
+        ..Default::default()
+    }
+}
+
+fn user(name: &str) -> Value {
+    json!({
+        "self": "x",
+        "name": name,
+        "key": name,
+        "accountId": format!("acc-{name}"),
+        "displayName": format!("{name} Example"),
+        "active": true
+    })
+}
+
+fn issue(key: &str, summary: &str) -> Value {
+    json!({
+        "id": "10001",
+        "key": key,
+        "self": format!("https://jira.example.com/rest/api/2/issue/{key}"),
+        "fields": {
+            "summary": summary,
+            "status": {"name": "In Progress"},
+            "assignee": {"displayName": "Ana Lima"},
+            "description": "Steps to reproduce:\n1. Open the app",
+            "attachment": [{
+                "id": "900",
+                "self": "https://jira.example.com/rest/api/2/attachment/900",
+                "filename": "crash.log",
+                "author": user("ana"),
+                "created": "2024-03-01T09:00:00.000+0000",
+                "size": 2048,
+                "mimeType": "text/plain",
+                "content": "https://jira.example.com/secure/attachment/900/crash.log"
+            }]
+        }
+    })
+}
+
+async fn mock_json(server: &mut mockito::ServerGuard, path: &str, body: Value) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .create_async()
+        .await
+}
+
+/// Text of every content item
+fn texts(result: &MCPToolResult) -> Vec<String> {
+    result.content.iter().map(|c| c.text.clone()).collect()
+}
+
+#[test]
+fn test_verbosity_from_config_and_arguments() {
+    assert_eq!(Verbosity::parse(" Minimal "), Some(Verbosity::Minimal));
+    assert_eq!(Verbosity::parse("loud"), None);
+
+    let config = create_test_config("http://localhost", Some("minimal"));
+    assert!(ResponseFormatter::from_config(&config).is_minimal());
+    let formatter = ResponseFormatter::from_args(&json!({"verbosity": "detailed"}), &config);
+    assert!(formatter.unwrap().is_detailed());
+    let error = ResponseFormatter::from_args(&json!({"verbosity": "loud"}), &config).unwrap_err();
+    assert!(error.to_string().contains("Unknown verbosity 'loud'"));
+
+    let default = ResponseFormatter::from_config(&JiraConfig::default());
+    assert_eq!(default.verbosity(), Verbosity::Normal);
+
+    let invalid = JiraConfig {
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token-123".to_string(),
+        output_verbosity: Some("loud".to_string()),
+        ..Default::default()
+    };
+    assert!(invalid.validate().is_err());
+}
+
+#[test]
+fn test_minimal_item_line() {
+    let formatter = ResponseFormatter::new(Verbosity::Minimal, FieldFormatter::default());
+    assert_eq!(
+        formatter.item_line("TEST-1", "Crash on\n  start"),
+        "TEST-1 — Crash on start\n"
+    );
+    let long = "word ".repeat(30);
+    let line = formatter.item_line("TEST-2", &long);
+    assert!(line.ends_with("…\n"));
+    assert!(line.chars().count() < 100);
+}
+
+#[test]
+fn test_verbosity_is_offered_by_the_retrofitted_tools() {
+    for tool in MCPServer::list_tools() {
+        let offered = tool.input_schema["properties"].get("verbosity").is_some();
+        assert_eq!(
+            offered,
+            VERBOSITY_TOOLS.contains(&tool.name.as_str()),
+            "{}",
+            tool.name
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_search_at_each_verbosity() {
+    let mut server = mockito::Server::new_async().await;
+    let _search = mock_json(
+        &mut server,
+        "/rest/api/2/search",
+        json!({
+            "startAt": 0,
+            "maxResults": 50,
+            "total": 2,
+            "issues": [issue("TEST-1", "Crash on start"), issue("TEST-2", "Typo in footer")]
+        }),
+    )
+    .await;
+    let search = |verbosity: &str| {
+        let tool = SearchIssuesTool::new(create_test_config(&server.url(), Some(verbosity)));
+        async move {
+            tool.handle(json!({"jql": "project = TEST", "bypass_cache": true}))
+                .await
+                .unwrap()
+        }
+    };
+
+    assert_eq!(
+        texts(&search("minimal").await),
+        ["Found 2 issues (showing 2 of 2 total)\n\n\
+          TEST-1 — Crash on start\n\
+          TEST-2 — Typo in footer\n"]
+    );
+
+    let normal = texts(&search("normal").await);
+    let url = server.url();
+    assert_eq!(
+        normal,
+        [
+            "Found 2 issues (showing 2 of 2 total)\n\n".to_string(),
+            format!(
+                "• TEST-1 - Crash on start (In Progress)\n  URL: {url}/browse/TEST-1\n\
+                 Steps to reproduce:\n1. Open the app\n"
+            ),
+            format!(
+                "• TEST-2 - Typo in footer (In Progress)\n  URL: {url}/browse/TEST-2\n\
+                 Steps to reproduce:\n1. Open the app\n"
+            ),
+        ]
+    );
+
+    let detailed = texts(&search("detailed").await);
+    assert_eq!(detailed[..3], normal[..]);
+    let raw: Value = serde_json::from_str(&detailed[3]).unwrap();
+    assert_eq!(raw[1]["key"], "TEST-2");
+}
+
+#[tokio::test]
+async fn test_get_issue_at_each_verbosity() {
+    let mut server = mockito::Server::new_async().await;
+    let _issue = mock_json(
+        &mut server,
+        "/rest/api/2/issue/TEST-1",
+        issue("TEST-1", "Crash on start"),
+    )
+    .await;
+    let tool = GetIssueTool::new(create_test_config(&server.url(), None));
+    let get = |verbosity: &str| tool.handle(json!({"issue_key": "TEST-1", "verbosity": verbosity}));
+
+    assert_eq!(
+        texts(&get("minimal").await.unwrap()),
+        ["TEST-1 — Crash on start (In Progress, Ana Lima)\n"]
+    );
+    let normal = texts(&get("normal").await.unwrap());
+    assert_eq!(
+        normal,
+        [format!(
+            "Issue: TEST-1\nSummary: Crash on start\nStatus: In Progress\nAssignee: Ana Lima\n\
+             URL: {}/browse/TEST-1\n\nDescription:\nSteps to reproduce:\n1. Open the app",
+            server.url()
+        )]
+    );
+    let detailed = texts(&get("detailed").await.unwrap());
+    assert_eq!(detailed[0], normal[0]);
+    let raw: Value = serde_json::from_str(&detailed[1]).unwrap();
+    assert_eq!(raw["fields"]["summary"], "Crash on start");
+}
+
+#[tokio::test]
+async fn test_transitions_and_attachments_at_each_verbosity() {
+    let mut server = mockito::Server::new_async().await;
+    let _transitions = mock_json(
+        &mut server,
+        "/rest/api/2/issue/TEST-1/transitions",
+        json!({"transitions": [{
+            "id": "31",
+            "name": "Finish",
+            "to": {
+                "self": "x",
+                "id": "10002",
+                "name": "Done",
+                "statusCategory": {"self": "x", "id": 3, "key": "done", "colorName": "green", "name": "Done"}
+            }
+        }]}),
+    )
+    .await;
+    let _issue = mock_json(
+        &mut server,
+        "/rest/api/2/issue/TEST-1",
+        issue("TEST-1", "Crash on start"),
+    )
+    .await;
+    let config = create_test_config(&server.url(), None);
+    let transitions = GetTransitionsTool::new(config.clone());
+    let attachments = GetIssueAttachmentsTool::new(config);
+    let args = |verbosity: &str| json!({"issue_key": "TEST-1", "verbosity": verbosity});
+
+    assert_eq!(
+        texts(&transitions.handle(args("minimal")).await.unwrap()),
+        ["Found 1 transitions for issue TEST-1\n\n31 — Finish → Done\n"]
+    );
+    assert_eq!(
+        texts(&transitions.handle(args("normal")).await.unwrap()),
+        [
+            "Found 1 transitions for issue TEST-1\n\n",
+            "• Finish - Done (ID: 31)\n"
+        ]
+    );
+    let detailed = texts(&transitions.handle(args("detailed")).await.unwrap());
+    assert_eq!(detailed.len(), 3);
+    let raw: Value = serde_json::from_str(&detailed[2]).unwrap();
+    assert_eq!(raw[0]["to"]["name"], "Done");
+
+    assert_eq!(
+        texts(&attachments.handle(args("minimal")).await.unwrap()),
+        ["Found 1 attachments for issue TEST-1\n\n900 — crash.log\n"]
+    );
+    assert_eq!(
+        texts(&attachments.handle(args("normal")).await.unwrap()),
+        [
+            "Found 1 attachments for issue TEST-1\n\n",
+            "• crash.log (text/plain) - 2048 bytes\n"
+        ]
+    );
+    let detailed = texts(&attachments.handle(args("detailed")).await.unwrap());
+    let raw: Value = serde_json::from_str(&detailed[2]).unwrap();
+    assert_eq!(raw[0]["filename"], "crash.log");
+}
+
+#[tokio::test]
+async fn test_comments_at_minimal_and_detailed_verbosity() {
+    let mut server = mockito::Server::new_async().await;
+    let _comments = mock_json(
+        &mut server,
+        "/rest/api/2/issue/TEST-1/comment",
+        json!({"startAt": 0, "maxResults": 100, "total": 1, "comments": [{
+            "id": "501",
+            "body": "Reproduced on\nversion 2.1",
+            "author": user("bob"),
+            "created": "2024-03-01T09:00:00.000+0000"
+        }]}),
+    )
+    .await;
+    let tool = GetCommentsTool::new(create_test_config(&server.url(), None));
+
+    let minimal = tool
+        .handle(json!({"issue_key": "TEST-1", "verbosity": "minimal"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        texts(&minimal),
+        ["Found 1 comments for issue TEST-1\n\n501 — bob Example: Reproduced on version 2.1\n"]
+    );
+
+    let detailed = tool
+        .handle(json!({"issue_key": "TEST-1", "verbosity": "detailed"}))
+        .await
+        .unwrap();
+    let detailed = texts(&detailed);
+    assert_eq!(detailed.len(), 3);
+    assert!(detailed[1].starts_with("• 501 by bob Example (acc-bob) on "));
+    let raw: Value = serde_json::from_str(&detailed[2]).unwrap();
+    assert_eq!(raw[0]["id"], "501");
+}
+
+#[tokio::test]
+async fn test_bulk_summary_at_each_verbosity() {
+    let mut server = mockito::Server::new_async().await;
+    let _ok = server
+        .mock("POST", "/rest/api/2/issue/TEST-1/comment")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "1",
+                "body": "Released",
+                "author": user("me"),
+                "created": "2024-03-02T14:30:00.000+0000"
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let _missing = server
+        .mock("POST", "/rest/api/2/issue/TEST-2/comment")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["Issue does not exist"]}).to_string())
+        .create_async()
+        .await;
+    let tool = BulkAddCommentsTool::new(create_test_config(&server.url(), None));
+    let comment = |verbosity: &str| {
+        tool.handle(json!({
+            "issue_keys": ["TEST-1", "TEST-2"],
+            "comment": "Released",
+            "verbosity": verbosity
+        }))
+    };
+
+    let minimal = comment("minimal").await.unwrap();
+    assert_eq!(
+        texts(&minimal),
+        ["Bulk comment: 1 of 2 operations succeeded (1 not-found)"]
+    );
+
+    let normal = comment("normal").await.unwrap();
+    assert_eq!(
+        normal.content[0].text,
+        "Bulk comment: 1 of 2 operations succeeded (1 not-found)\n\
+         • TEST-2 failed: Jira API error: Issue does not exist"
+    );
+    assert_eq!(normal.content.len(), 2);
+
+    let detailed = comment("detailed").await.unwrap();
+    assert_eq!(
+        detailed.content[0].text,
+        format!(
+            "Bulk comment: 1 of 2 operations succeeded (1 not-found)\n\
+         • TEST-2 failed: Jira API error: Issue does not exist\n\
+         • TEST-1 succeeded: {}/browse/TEST-1",
+            server.url()
+        )
+    );
+    let report: Value = serde_json::from_str(&detailed.content[1].text).unwrap();
+    assert_eq!(report["successful_operations"], 1);
+}
@@ -0,0 +1,167 @@
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{
+    GetIssueWatchersTool, GetSprintIssuesTool, PageArgs, SearchIssuesTool,
+};
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn page(args: serde_json::Value) -> rust_jira_mcp::error::Result<PageArgs> {
+    PageArgs::from_args(&args, &JiraConfig::default())
+}
+
+#[test]
+fn test_page_args_boundaries() {
+    assert_eq!(page(json!({})).unwrap(), PageArgs::default());
+    assert_eq!(
+        page(json!({"start_at": 0, "max_results": 0})).unwrap(),
+        PageArgs {
+            start_at: Some(0),
+            max_results: Some(0)
+        }
+    );
+    assert!(page(json!({"max_results": 0})).unwrap().is_count_only());
+    assert!(!page(json!({"max_results": 1})).unwrap().is_count_only());
+    assert!(!page(json!({})).unwrap().is_count_only());
+    assert_eq!(
+        page(json!({"max_results": 1000})).unwrap().max_results,
+        Some(1000)
+    );
+    assert_eq!(
+        page(json!({"startAt": 2_147_483_647_i64}))
+            .unwrap()
+            .start_at_i32(),
+        Some(i32::MAX)
+    );
+    assert_eq!(
+        page(json!({"maxResults": 25, "max_results": null})).unwrap(),
+        PageArgs {
+            start_at: None,
+            max_results: None
+        },
+        "an explicit null counts as not given"
+    );
+
+    for (args, message) in [
+        (
+            json!({"max_results": -1}),
+            "max_results must be a whole number from 0 to 1000; got -1",
+        ),
+        (
+            json!({"max_results": 1001}),
+            "max_results must be a whole number from 0 to 1000; got 1001",
+        ),
+        (
+            json!({"maxResults": -5}),
+            "maxResults must be a whole number from 0 to 1000; got -5",
+        ),
+        (
+            json!({"max_results": 2.5}),
+            "max_results must be a whole number",
+        ),
+        (
+            json!({"max_results": "50"}),
+            "max_results must be a whole number",
+        ),
+        (
+            json!({"start_at": -1}),
+            "start_at must be a whole number from 0 to 2147483647; got -1",
+        ),
+        (
+            json!({"startAt": 2_147_483_648_i64}),
+            "startAt must be a whole number from 0 to 2147483647",
+        ),
+    ] {
+        let error = page(args).unwrap_err().to_string();
+        assert!(error.contains(message), "{error}");
+    }
+}
+
+#[test]
+fn test_configured_limit() {
+    let config = JiraConfig {
+        max_results_limit: Some(100),
+        ..Default::default()
+    };
+    assert!(PageArgs::from_args(&json!({"max_results": 100}), &config).is_ok());
+    let error = PageArgs::from_args(&json!({"max_results": 101}), &config)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("from 0 to 100; got 101"), "{error}");
+}
+
+#[tokio::test]
+async fn test_invalid_values_are_not_sent() {
+    let mut server = mockito::Server::new_async().await;
+    let search = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let sprint = server
+        .mock("GET", "/rest/agile/1.0/sprint/5/issue")
+        .match_query(Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let config = create_test_config(&server.url());
+
+    let error = SearchIssuesTool::new(config.clone())
+        .handle(json!({"jql": "project = PAG", "max_results": -10}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("max_results"), "{error}");
+    let error = GetSprintIssuesTool::new(config.clone())
+        .handle(json!({"sprint_id": 5, "start_at": -1}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("start_at"), "{error}");
+    let error = GetIssueWatchersTool::new(config)
+        .handle(json!({"issue_key": "PAG-1", "max_results": 5000}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("max_results"), "{error}");
+
+    search.assert_async().await;
+    sprint.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_zero_max_results_counts_only() {
+    let mut server = mockito::Server::new_async().await;
+    let search = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("jql".to_string(), "project = PAGZ".to_string()),
+            Matcher::UrlEncoded("maxResults".to_string(), "0".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"startAt": 0, "maxResults": 0, "total": 17, "issues": []}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = SearchIssuesTool::new(create_test_config(&server.url()))
+        .handle(json!({"jql": "project = PAGZ", "max_results": 0, "bypass_cache": true}))
+        .await
+        .unwrap();
+
+    assert_eq!(result.content.len(), 1);
+    assert_eq!(
+        result.content[0].text,
+        "17 issues match (count only, since max_results is 0)\n"
+    );
+    search.assert_async().await;
+}
@@ -0,0 +1,159 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPServer;
+use rust_jira_mcp::performance::profiler;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn call_tool(server: &mut MCPServer, id: u64, name: &str, arguments: Value) -> Value {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "tools/call",
+        "params": {"name": name, "arguments": arguments}
+    });
+    let response = server
+        .handle_request(&request.to_string())
+        .await
+        .unwrap()
+        .unwrap();
+    response.result.unwrap()
+}
+
+async fn mock_comment_page(
+    server: &mut mockito::ServerGuard,
+    start_at: u32,
+    ids: &[&str],
+) -> mockito::Mock {
+    let comments: Vec<Value> = ids
+        .iter()
+        .map(|id| {
+            json!({
+                "id": id,
+                "body": "Looks good",
+                "author": {"self": "x", "name": "alice", "key": "alice", "displayName": "Alice", "active": true},
+                "created": "2024-03-01T09:00:00.000+0000"
+            })
+        })
+        .collect();
+    server
+        .mock("GET", "/rest/api/2/issue/PROF-1/comment")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "startAt".to_string(),
+            start_at.to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"startAt": start_at, "maxResults": 2, "total": 3, "comments": comments})
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+// The kept profiles are process-wide, so everything runs in one test
+#[tokio::test]
+async fn test_tool_calls_are_profiled_by_segment() {
+    let mut mock_server = mockito::Server::new_async().await;
+    let base_url = mock_server.url();
+    let first = mock_comment_page(&mut mock_server, 0, &["1", "2"]).await;
+    let second = mock_comment_page(&mut mock_server, 2, &["3"]).await;
+
+    let mut server = MCPServer::new(create_test_config(&base_url));
+    for message in [
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {"protocolVersion": "2025-03-26", "capabilities": {}, "clientInfo": {"name": "profile-test", "version": "1.0"}}
+        }),
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+    ] {
+        server.handle_request(&message.to_string()).await.unwrap();
+    }
+
+    // Forget calls profiled by other tests in this process
+    profiler::reset_recent_calls();
+    call_tool(
+        &mut server,
+        3,
+        "get_jira_comments",
+        json!({"issue_key": "PROF-1"}),
+    )
+    .await;
+    first.assert_async().await;
+    second.assert_async().await;
+
+    let result = call_tool(
+        &mut server,
+        4,
+        "get_performance_profile",
+        json!({"limit": 5, "reset": true}),
+    )
+    .await;
+    let text = result["content"][0]["text"].as_str().unwrap();
+    assert!(text.starts_with("Slowest 1 of the last 1 tool calls:\n• get_jira_comments took "));
+    assert!(text.ends_with("\nThe kept profiles were cleared"));
+
+    let profile: Value =
+        serde_json::from_str(result["content"][1]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(profile["profiled_calls"], 1);
+    let call = &profile["calls"][0];
+    assert_eq!(call["tool"], "get_jira_comments");
+    let segments = call["segments"].as_array().unwrap();
+    let names: Vec<&str> = segments
+        .iter()
+        .map(|s| s["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "deserialize arguments",
+            "processing",
+            // Whether PROF is a service desk project
+            "GET project/PROF",
+            "processing",
+            "GET issue/PROF-1/comment",
+            "processing",
+            "GET issue/PROF-1/comment",
+            "format response",
+            "serialize result"
+        ]
+    );
+
+    // Segments follow each other and add up to the whole call
+    let mut end = 0.0;
+    for segment in segments {
+        let start = segment["start_ms"].as_f64().unwrap();
+        let duration = segment["duration_ms"].as_f64().unwrap();
+        assert!(
+            (start - end).abs() < 1e-6,
+            "{segment} does not start where the last ended"
+        );
+        assert!(duration >= 0.0);
+        end = start + duration;
+    }
+    assert!((end - call["total_ms"].as_f64().unwrap()).abs() < 1e-6);
+    assert!(segments[2]["duration_ms"].as_f64().unwrap() > 0.0);
+
+    // Only this call, made after the reset, is kept
+    let result = call_tool(&mut server, 5, "get_performance_profile", json!({})).await;
+    let profile: Value =
+        serde_json::from_str(result["content"][1]["text"].as_str().unwrap()).unwrap();
+    assert_eq!(profile["profiled_calls"], 1);
+    assert_eq!(profile["calls"][0]["tool"], "get_performance_profile");
+}
@@ -0,0 +1,220 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::GetProjectConfigTool;
+use serde_json::{json, Value};
+
+const PROJECT_CONFIGURATION_DC: &str = include_str!("fixtures/project_configuration_dc.json");
+const PROJECT_DETAILS_CLOUD: &str = include_str!("fixtures/project_details_cloud.json");
+const PROJECT_STATUSES: &str = include_str!("fixtures/project_statuses.json");
+
+/// Servers are reused between tests and the deployment type is cached by
+/// base URL, so each test runs under its own context path
+fn create_test_config(base_url: &str, context: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/{context}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn mock_server_info(
+    server: &mut mockito::ServerGuard,
+    context: &str,
+    deployment_type: &str,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("/{context}/rest/api/2/serverInfo").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"version": "1001.0.0", "deploymentType": deployment_type}).to_string())
+        .create_async()
+        .await
+}
+
+/// The internal configuration endpoint, which only Server/Data Center has
+async fn mock_configuration(
+    server: &mut mockito::ServerGuard,
+    context: &str,
+    status: usize,
+    hits: usize,
+) -> mockito::Mock {
+    server
+        .mock(
+            "GET",
+            format!("/{context}/rest/api/2/project/CONF/configuration").as_str(),
+        )
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(if status == 200 {
+            PROJECT_CONFIGURATION_DC.to_string()
+        } else {
+            json!({"errorMessages": ["Not Found"]}).to_string()
+        })
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+/// Mocks of the endpoints the Cloud view is put together from
+async fn mock_cloud_endpoints(
+    server: &mut mockito::ServerGuard,
+    context: &str,
+    scheme_status: usize,
+) -> Vec<mockito::Mock> {
+    vec![
+        server
+            .mock(
+                "GET",
+                format!("/{context}/rest/api/2/project/CONF").as_str(),
+            )
+            .match_query(mockito::Matcher::UrlEncoded(
+                "expand".to_string(),
+                "description,lead,issueTypes,url,projectKeys".to_string(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(PROJECT_DETAILS_CLOUD)
+            .expect(1)
+            .create_async()
+            .await,
+        server
+            .mock(
+                "GET",
+                format!("/{context}/rest/api/2/project/CONF").as_str(),
+            )
+            .match_query(mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({"id": "10200", "key": "CONF"}).to_string())
+            .create_async()
+            .await,
+        server
+            .mock(
+                "GET",
+                format!("/{context}/rest/api/2/workflowscheme/project").as_str(),
+            )
+            .match_query(mockito::Matcher::UrlEncoded(
+                "projectId".to_string(),
+                "10200".to_string(),
+            ))
+            .with_status(scheme_status)
+            .with_header("content-type", "application/json")
+            .with_body(
+                json!({"values": [{
+                    "projectIds": ["10200"],
+                    "workflowScheme": {
+                        "id": 10100,
+                        "name": "CONF Workflow Scheme",
+                        "defaultWorkflow": "jira",
+                        "issueTypeMappings": {"1": "CONF Bug Workflow"}
+                    }
+                }]})
+                .to_string(),
+            )
+            .create_async()
+            .await,
+        server
+            .mock(
+                "GET",
+                format!("/{context}/rest/api/2/project/CONF/statuses").as_str(),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(PROJECT_STATUSES)
+            .expect(1)
+            .create_async()
+            .await,
+    ]
+}
+
+fn configuration(text: &str) -> Value {
+    serde_json::from_str(&text[text.find('{').unwrap()..]).unwrap()
+}
+
+#[tokio::test]
+async fn test_data_center_reads_the_configuration_endpoint() {
+    let mut server = mockito::Server::new_async().await;
+    let _info = mock_server_info(&mut server, "dc", "Server").await;
+    let configuration_mock = mock_configuration(&mut server, "dc", 200, 1).await;
+
+    let result = GetProjectConfigTool::new(create_test_config(&server.url(), "dc"))
+        .handle(json!({"project_key": "CONF"}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Project configuration for CONF:\n"));
+    assert_eq!(
+        configuration(text),
+        serde_json::from_str::<Value>(PROJECT_CONFIGURATION_DC).unwrap()
+    );
+    configuration_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_cloud_does_not_call_the_configuration_endpoint() {
+    // Regression: the endpoint 404s on Cloud, failing the tool for everyone
+    let mut server = mockito::Server::new_async().await;
+    let _info = mock_server_info(&mut server, "cloud", "Cloud").await;
+    let configuration_mock = mock_configuration(&mut server, "cloud", 404, 0).await;
+    let mocks = mock_cloud_endpoints(&mut server, "cloud", 200).await;
+
+    let result = GetProjectConfigTool::new(create_test_config(&server.url(), "cloud"))
+        .handle(json!({"project_key": "CONF"}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Project configuration for CONF:\n"));
+    let configuration = configuration(text);
+    assert_eq!(configuration["key"], "CONF");
+    assert_eq!(configuration["lead"]["displayName"], "Mock Lead");
+    assert_eq!(configuration["projectKeys"], json!(["CONF", "OLDCONF"]));
+    assert_eq!(configuration["issueTypes"][1]["name"], "Sub-task");
+    assert_eq!(
+        configuration["workflowScheme"]["name"],
+        "CONF Workflow Scheme"
+    );
+    assert_eq!(configuration["issueTypeStatuses"][0]["name"], "Bug");
+    assert_eq!(
+        configuration["issueTypeStatuses"][0]["statuses"][0]["name"],
+        "Open"
+    );
+
+    configuration_mock.assert_async().await;
+    mocks[0].assert_async().await;
+    mocks[3].assert_async().await;
+}
+
+#[tokio::test]
+async fn test_cloud_configuration_without_workflow_scheme_permission() {
+    let mut server = mockito::Server::new_async().await;
+    let _info = mock_server_info(&mut server, "restricted", "Cloud").await;
+    let _mocks = mock_cloud_endpoints(&mut server, "restricted", 403).await;
+
+    let configuration = JiraClient::new(create_test_config(&server.url(), "restricted"))
+        .unwrap()
+        .get_project_configuration("CONF")
+        .await
+        .unwrap();
+
+    assert_eq!(configuration["key"], "CONF");
+    assert!(configuration["workflowScheme"].is_null());
+    assert_eq!(configuration["issueTypeStatuses"][1]["name"], "Sub-task");
+}
+
+#[tokio::test]
+async fn test_unreadable_server_info_keeps_the_configuration_endpoint() {
+    let mut server = mockito::Server::new_async().await;
+    let configuration_mock = mock_configuration(&mut server, "unknown", 200, 1).await;
+
+    let client = JiraClient::new(create_test_config(&server.url(), "unknown")).unwrap();
+    assert!(!client.is_cloud_deployment().await);
+    let configuration = client.get_project_configuration("CONF").await.unwrap();
+
+    assert_eq!(configuration["workflowScheme"]["id"], 10100);
+    configuration_mock.assert_async().await;
+}
@@ -0,0 +1,183 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{GetProjectCategoriesTool, ListProjectsTool};
+use rust_jira_mcp::types::jira::{JiraProject, ProjectFilter, ProjectStatus};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn project(key: &str, name: &str, category: Option<(&str, &str)>, archived: bool) -> Value {
+    let mut project = json!({
+        "self": format!("https://jira.example.com/rest/api/2/project/{key}"),
+        "id": format!("1{}", key.len()),
+        "key": key,
+        "name": name,
+        "projectTypeKey": "software",
+        "archived": archived
+    });
+    if let Some((id, name)) = category {
+        project["projectCategory"] = json!({
+            "self": format!("https://jira.example.com/rest/api/2/projectCategory/{id}"),
+            "id": id,
+            "name": name,
+            "description": format!("{name} projects")
+        });
+    }
+    project
+}
+
+fn projects_body() -> String {
+    json!([
+        project("PAY", "Payments", Some(("10000", "Finance")), false),
+        project(
+            "PAYOLD",
+            "Payments (2019)",
+            Some(("10000", "Finance")),
+            true
+        ),
+        project("WEB", "Website", Some(("10001", "Marketing")), false),
+        project("PAYTMP", "Payments spike", None, true)
+    ])
+    .to_string()
+}
+
+#[test]
+fn test_project_filter() {
+    let projects: Vec<JiraProject> = serde_json::from_str(&projects_body()).unwrap();
+
+    let keys = |filter: &ProjectFilter| {
+        projects
+            .iter()
+            .filter(|p| filter.matches(p))
+            .map(|p| p.key.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(keys(&ProjectFilter::default()), ["PAY", "WEB"]);
+    assert_eq!(
+        keys(&ProjectFilter {
+            statuses: vec![ProjectStatus::Live, ProjectStatus::Archived],
+            key_prefix: Some("pay".to_string()),
+            ..Default::default()
+        }),
+        ["PAY", "PAYOLD", "PAYTMP"]
+    );
+    for category in ["finance", "10000"] {
+        assert_eq!(
+            keys(&ProjectFilter {
+                statuses: vec![ProjectStatus::Archived],
+                category: Some(category.to_string()),
+                ..Default::default()
+            }),
+            ["PAYOLD"]
+        );
+    }
+}
+
+#[test]
+fn test_project_without_status_fields_deserializes_as_live() {
+    let project: JiraProject = serde_json::from_value(json!({
+        "self": "https://jira.example.com/rest/api/2/project/10000",
+        "id": "10000",
+        "key": "OLD",
+        "name": "Old",
+        "projectTypeKey": "business"
+    }))
+    .unwrap();
+    assert_eq!(project.status(), ProjectStatus::Live);
+    assert!(project.project_category.is_none());
+
+    let trashed: JiraProject = serde_json::from_value(json!({
+        "self": "https://example.atlassian.net/rest/api/2/project/10001",
+        "id": "10001",
+        "key": "GONE",
+        "name": "Gone",
+        "projectTypeKey": "software",
+        "archived": false,
+        "deleted": true
+    }))
+    .unwrap();
+    assert_eq!(trashed.status(), ProjectStatus::Deleted);
+}
+
+#[tokio::test]
+async fn test_list_projects_filters_and_shows_categories() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let listing = server
+        .mock("GET", "/rest/api/2/project")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "includeArchived".to_string(),
+            "true".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(projects_body())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let tool = ListProjectsTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"status": "all", "key_prefix": "pay"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Found 3 projects (any status, with keys starting pay)\n\
+         • PAY - Payments [Finance]\n\
+         • PAYOLD - Payments (2019) [Finance] (archived)\n\
+         • PAYTMP - Payments spike (archived)"
+    );
+    let projects: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(projects[1]["archived"], true);
+
+    let result = tool.handle(json!({"category": "Marketing"})).await.unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Found 1 projects (live, in category Marketing)\n• WEB - Website [Marketing]"
+    );
+    listing.assert_async().await;
+
+    let error = tool.handle(json!({"status": "closed"})).await.unwrap_err();
+    assert!(error.to_string().contains("Unknown status 'closed'"));
+}
+
+#[tokio::test]
+async fn test_get_project_categories() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _categories = server
+        .mock("GET", "/rest/api/2/projectCategory")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {"self": "x", "id": "10000", "name": "Finance", "description": "Money"},
+                {"self": "x", "id": "10001", "name": "Marketing", "description": ""}
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let tool = GetProjectCategoriesTool::new(create_test_config(&base_url));
+    let result = tool.handle(json!({})).await.unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Found 2 project categories\n\
+         • Finance (ID: 10000) - Money\n\
+         • Marketing (ID: 10001) - No description"
+    );
+}
@@ -0,0 +1,219 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::GetProjectMetadataTool;
+use rust_jira_mcp::performance::get_global_metrics;
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn mock_get(
+    server: &mut mockito::ServerGuard,
+    path: &str,
+    status: usize,
+    body: Value,
+    hits: usize,
+) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+/// Mocks of every section, the configuration one failing for lack of
+/// permission; `hits` is how often each is expected to be read
+async fn mock_sections(server: &mut mockito::ServerGuard, hits: [usize; 6]) -> Vec<mockito::Mock> {
+    vec![
+        mock_get(
+            server,
+            "/rest/api/2/project/MET/configuration",
+            403,
+            json!({"errorMessages": ["You cannot administer this project"]}),
+            hits[0],
+        )
+        .await,
+        mock_get(
+            server,
+            "/rest/api/2/project/MET",
+            200,
+            json!({"key": "MET", "issueTypes": [
+                {"self": "x", "id": "1", "name": "Bug", "subtask": false}
+            ]}),
+            hits[1],
+        )
+        .await,
+        mock_get(
+            server,
+            "/rest/api/2/project/MET/components",
+            200,
+            json!([{"self": "x", "id": "7", "name": "Backend"}]),
+            hits[2],
+        )
+        .await,
+        mock_get(
+            server,
+            "/rest/api/2/priority",
+            200,
+            json!([{"self": "x", "id": "2", "name": "High"}]),
+            hits[3],
+        )
+        .await,
+        mock_get(
+            server,
+            "/rest/api/2/status",
+            200,
+            json!([{"self": "x", "id": "3", "name": "Open", "statusCategory": {
+                "self": "x", "id": 2, "key": "new", "colorName": "blue-gray", "name": "To Do"
+            }}]),
+            hits[4],
+        )
+        .await,
+        mock_get(
+            server,
+            "/rest/api/2/field",
+            200,
+            json!([{"id": "customfield_10016", "name": "Story Points", "custom": true}]),
+            hits[5],
+        )
+        .await,
+    ]
+}
+
+fn metadata(result: &rust_jira_mcp::types::mcp::MCPToolResult) -> Value {
+    let text = &result.content[0].text;
+    serde_json::from_str(&text[text.find('{').unwrap()..]).unwrap()
+}
+
+#[tokio::test]
+async fn test_failing_section_is_reported_and_the_rest_returned() {
+    let mut server = mockito::Server::new_async().await;
+    let mocks = mock_sections(&mut server, [1; 6]).await;
+
+    let result = GetProjectMetadataTool::new(create_test_config(&server.url()))
+        .handle(json!({"project_key": "MET"}))
+        .await
+        .unwrap();
+
+    assert!(result.content[0].text.starts_with(
+        "Some sections could not be read:\n\
+         • configuration: Permission denied: You cannot administer this project\n\n\
+         Project metadata for MET:\n"
+    ));
+    let metadata = metadata(&result);
+    assert!(metadata.get("configuration").is_none());
+    assert_eq!(metadata["issue_types"][0]["name"], "Bug");
+    assert_eq!(metadata["components"][0]["name"], "Backend");
+    assert_eq!(metadata["priorities"][0]["name"], "High");
+    assert_eq!(metadata["statuses"][0]["name"], "Open");
+    assert_eq!(metadata["custom_fields"][0]["id"], "customfield_10016");
+    assert_eq!(
+        metadata["errors"],
+        json!({"configuration": "Permission denied: You cannot administer this project"})
+    );
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_include_reads_only_the_sections_asked_for() {
+    let mut server = mockito::Server::new_async().await;
+    let mocks = mock_sections(&mut server, [0, 0, 1, 0, 0, 1]).await;
+
+    let result = GetProjectMetadataTool::new(create_test_config(&server.url()))
+        .handle(json!({"project_key": "MET", "include": ["components", "custom_fields"]}))
+        .await
+        .unwrap();
+
+    assert!(result.content[0]
+        .text
+        .starts_with("Project metadata for MET:\n"));
+    let metadata = metadata(&result);
+    let mut sections: Vec<&String> = metadata.as_object().unwrap().keys().collect();
+    sections.sort();
+    assert_eq!(
+        sections,
+        ["components", "custom_fields", "errors", "project_key"]
+    );
+    assert_eq!(metadata["errors"], json!({}));
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_metadata_fails_when_nothing_can_be_read() {
+    let mut server = mockito::Server::new_async().await;
+    let _mocks = mock_sections(&mut server, [1, 0, 0, 0, 0, 0]).await;
+    let tool = GetProjectMetadataTool::new(create_test_config(&server.url()));
+
+    let error = tool
+        .handle(json!({"project_key": "MET", "include": ["configuration"]}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("You cannot administer this project"));
+
+    let error = tool
+        .handle(json!({"project_key": "MET", "include": ["workflows"]}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Unknown section 'workflows'"));
+}
+
+#[tokio::test]
+async fn test_slow_section_times_out_without_holding_up_the_others() {
+    let mut server = mockito::Server::new_async().await;
+    let mut mocks = mock_sections(&mut server, [1, 1, 1, 1, 1, 0]).await;
+    // The fast field mock expects no hits, so this one answers
+    let _slow_fields = server
+        .mock("GET", "/rest/api/2/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_secs(3));
+            w.write_all(b"[]")
+        })
+        .create_async()
+        .await;
+    let config = JiraConfig {
+        metadata_subcall_timeout_seconds: Some(1),
+        ..create_test_config(&server.url())
+    };
+
+    let started = Instant::now();
+    let result = GetProjectMetadataTool::new(config)
+        .handle(json!({"project_key": "MET"}))
+        .await
+        .unwrap();
+    assert!(started.elapsed() < Duration::from_millis(2500));
+
+    assert!(result.content[0].text.contains(
+        "• custom_fields: Jira API error: timed out after 1s (JIRA_METADATA_SUBCALL_TIMEOUT_SECONDS)\n"
+    ));
+    let metadata = metadata(&result);
+    assert!(metadata.get("custom_fields").is_none());
+    assert_eq!(metadata["components"][0]["name"], "Backend");
+    assert_eq!(metadata["statuses"][0]["name"], "Open");
+    for mock in mocks.drain(..5) {
+        mock.assert_async().await;
+    }
+
+    let sections = get_global_metrics().get_stats().metadata_sections;
+    assert!(sections["custom_fields"].timeouts >= 1);
+    assert!(sections["custom_fields"].max_ms >= 1000);
+    assert!(sections["components"].calls >= 1);
+}
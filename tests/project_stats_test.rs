@@ -0,0 +1,243 @@
+use chrono::NaiveDate;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::jql::jql_escape_string;
+use rust_jira_mcp::jira::project_stats::{
+    median_resolution_hours, project_jql, top_assignees, StatsWindow, MAX_WEEKS,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::GetProjectStatsTool;
+use rust_jira_mcp::types::jira::JiraIssue;
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+}
+
+fn resolved_issue(
+    key: &str,
+    created: &str,
+    resolved: &str,
+    assignee: Option<&str>,
+) -> serde_json::Value {
+    json!({
+        "id": key,
+        "key": key,
+        "self": format!("https://jira.example.com/rest/api/2/issue/{key}"),
+        "fields": {
+            "created": created,
+            "resolutiondate": resolved,
+            "assignee": assignee.map(|name| json!({"displayName": name}))
+        }
+    })
+}
+
+fn sample() -> Vec<serde_json::Value> {
+    vec![
+        // 1 day
+        resolved_issue(
+            "ST-1",
+            "2024-05-01T00:00:00.000+0000",
+            "2024-05-02T00:00:00.000+0000",
+            Some("Alice"),
+        ),
+        // 3 days
+        resolved_issue(
+            "ST-2",
+            "2024-05-01T00:00:00.000+0000",
+            "2024-05-04T00:00:00.000+0000",
+            Some("Bob"),
+        ),
+        // 10 days
+        resolved_issue(
+            "ST-3",
+            "2024-05-01T00:00:00.000+0000",
+            "2024-05-11T00:00:00.000+0000",
+            Some("Alice"),
+        ),
+        // 2 days
+        resolved_issue(
+            "ST-4",
+            "2024-05-01T00:00:00.000+0000",
+            "2024-05-03T00:00:00.000+0000",
+            None,
+        ),
+    ]
+}
+
+fn sample_issues() -> Vec<JiraIssue> {
+    serde_json::from_value(json!(sample())).unwrap()
+}
+
+#[test]
+fn test_jql_building() {
+    assert_eq!(jql_escape_string("Say \"hi\""), "\"Say \\\"hi\\\"\"");
+    let window = StatsWindow {
+        since: Some(date(5, 6)),
+        until: Some(date(5, 19)),
+    };
+    assert_eq!(
+        project_jql("TEST", &window.clauses("created")),
+        "project = \"TEST\" AND created >= \"2024-05-06\" AND created < \"2024-05-20\""
+    );
+    assert_eq!(
+        project_jql("TEST", &StatsWindow::default().clauses("resolved")),
+        "project = \"TEST\""
+    );
+}
+
+#[test]
+fn test_weeks() {
+    let window = StatsWindow {
+        since: Some(date(5, 8)),
+        until: Some(date(5, 19)),
+    };
+    assert_eq!(window.weeks(date(6, 1)), vec![date(5, 6), date(5, 13)]);
+
+    // No window: the last eight weeks up to the current one
+    let weeks = StatsWindow::default().weeks(date(5, 22));
+    assert_eq!(weeks.len(), 8);
+    assert_eq!(weeks.last(), Some(&date(5, 20)));
+
+    // Long windows keep only the most recent weeks
+    let long = StatsWindow {
+        since: Some(date(1, 1)),
+        until: Some(date(6, 30)),
+    };
+    let weeks = long.weeks(date(7, 1));
+    assert_eq!(weeks.len(), MAX_WEEKS);
+    assert_eq!(weeks.last(), Some(&date(6, 24)));
+}
+
+#[test]
+fn test_median_resolution() {
+    let issues = sample_issues();
+    // 1, 2, 3 and 10 days: the median is 2.5 days
+    assert_eq!(median_resolution_hours(&issues), Some(60.0));
+    // 1, 3 and 10 days
+    assert_eq!(median_resolution_hours(&issues[..3]), Some(72.0));
+    assert_eq!(median_resolution_hours(&[]), None);
+}
+
+#[test]
+fn test_top_assignees() {
+    let top = top_assignees(&sample_issues(), 2);
+    let names: Vec<(&str, u64)> = top.iter().map(|e| (e.name.as_str(), e.count)).collect();
+    assert_eq!(names, vec![("Alice", 2), ("Bob", 1)]);
+}
+
+#[tokio::test]
+async fn test_get_project_stats_tool() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _project = server
+        .mock("GET", "/rest/api/2/project/TEST")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "key": "TEST",
+                "issueTypes": [
+                    {"self": "x", "id": "1", "name": "Bug", "subtask": false},
+                    {"self": "x", "id": "2", "name": "Story", "subtask": false}
+                ]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let _priorities = server
+        .mock("GET", "/rest/api/2/priority")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {"self": "x", "id": "1", "name": "High"},
+                {"self": "x", "id": "2", "name": "Low"}
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    // 3 status categories + 2 issue types + 2 priorities + 2 weeks x (created, resolved)
+    let counts = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "maxResults".to_string(),
+            "0".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"startAt": 0, "maxResults": 0, "total": 3, "issues": []}).to_string())
+        .expect(11)
+        .create_async()
+        .await;
+    let resolved = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("maxResults".to_string(), "200".to_string()),
+            mockito::Matcher::Regex("ORDER(\\+|%20)BY(\\+|%20)resolved".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"startAt": 0, "maxResults": 200, "total": 4, "issues": sample()}).to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = GetProjectStatsTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "project_key": "TEST",
+            "since": "2024-05-06",
+            "until": "2024-05-19"
+        }))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Project statistics for TEST (created 2024-05-06 to 2024-05-19)"));
+    assert!(text.contains("Total issues: 9"));
+    assert!(text.contains("  Bug: 3"));
+    assert!(text.contains("Week of 2024-05-13: created 3, resolved 3"));
+    assert!(text.contains("Median time to resolution: 2.5 days"));
+    assert!(text.contains("  Alice: 2"));
+    assert!(text.contains("Upstream requests: 14"));
+
+    let stats: serde_json::Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(stats["by_priority"][1], json!({"name": "Low", "count": 3}));
+    assert_eq!(stats["weekly"].as_array().unwrap().len(), 2);
+    assert_eq!(stats["median_resolution_hours"], json!(60.0));
+
+    counts.assert_async().await;
+    resolved.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_project_stats_rejects_reversed_window() {
+    let tool = GetProjectStatsTool::new(create_test_config("http://localhost:1"));
+    let result = tool
+        .handle(json!({
+            "project_key": "TEST",
+            "since": "2024-05-19",
+            "until": "2024-05-06"
+        }))
+        .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,203 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::GetProjectWorkflowTool;
+use rust_jira_mcp::types::jira::JiraIssueTypeStatuses;
+use serde_json::json;
+
+const PROJECT_STATUSES: &str = include_str!("fixtures/project_statuses.json");
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn status(id: &str, name: &str) -> serde_json::Value {
+    json!({
+        "self": format!("https://jira.example.com/rest/api/2/status/{id}"),
+        "id": id,
+        "name": name,
+        "statusCategory": {
+            "self": "https://jira.example.com/rest/api/2/statuscategory/4",
+            "id": 4,
+            "key": "indeterminate",
+            "colorName": "yellow",
+            "name": "In Progress"
+        }
+    })
+}
+
+#[test]
+fn test_deserialize_project_statuses_fixture() {
+    let issue_types: Vec<JiraIssueTypeStatuses> = serde_json::from_str(PROJECT_STATUSES).unwrap();
+
+    assert_eq!(issue_types.len(), 2);
+    assert_eq!(issue_types[0].name, "Bug");
+    assert!(!issue_types[0].subtask);
+    assert_eq!(issue_types[0].statuses.len(), 3);
+    assert_eq!(issue_types[0].statuses[2].status_category.key, "done");
+    assert!(issue_types[1].subtask);
+}
+
+#[tokio::test]
+async fn test_project_statuses_are_cached() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let mock = server
+        .mock("GET", "/rest/api/2/project/CACHED/statuses")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(PROJECT_STATUSES)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    let first = client.get_project_statuses("CACHED").await.unwrap();
+    let second = client.get_project_statuses("CACHED").await.unwrap();
+
+    assert_eq!(first.len(), second.len());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_project_workflow_tool() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _statuses = server
+        .mock("GET", "/rest/api/2/project/PROJ/statuses")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(PROJECT_STATUSES)
+        .create_async()
+        .await;
+
+    let _project = server
+        .mock("GET", "/rest/api/2/project/PROJ")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "10000", "key": "PROJ"}).to_string())
+        .create_async()
+        .await;
+
+    let _scheme = server
+        .mock("GET", "/rest/api/2/workflowscheme/project")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "projectId".to_string(),
+            "10000".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "values": [{
+                    "projectIds": ["10000"],
+                    "workflowScheme": {
+                        "id": 10100,
+                        "name": "PROJ Workflow Scheme",
+                        "defaultWorkflow": "jira",
+                        "issueTypeMappings": {"1": "PROJ Bug Workflow"}
+                    }
+                }]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let _issue = server
+        .mock("GET", "/rest/api/2/issue/PROJ-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "20001",
+                "key": "PROJ-1",
+                "self": format!("{base_url}/rest/api/2/issue/20001"),
+                "fields": {
+                    "status": {"name": "Open"},
+                    "issuetype": {"name": "Bug"}
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let _transitions = server
+        .mock("GET", "/rest/api/2/issue/PROJ-1/transitions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "transitions": [
+                    {"id": "11", "name": "Start Progress", "to": status("3", "In Progress")},
+                    {"id": "21", "name": "Close Issue", "to": status("6", "Closed")}
+                ]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let tool = GetProjectWorkflowTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "project_key": "PROJ",
+            "issue_type": "bug",
+            "example_issue_key": "PROJ-1"
+        }))
+        .await
+        .unwrap();
+
+    let text: String = result.content.iter().map(|c| c.text.as_str()).collect();
+    assert!(text.contains("1 issue types"));
+    assert!(text.contains("  • In Progress [In Progress]"));
+    assert!(text.contains("  • Closed [Done]"));
+    assert!(!text.contains("Sub-task"));
+    assert!(text.contains("Workflow scheme: PROJ Workflow Scheme"));
+    assert!(text.contains("Bug: PROJ Bug Workflow"));
+    assert!(text.contains("Transitions from PROJ-1 (Bug, currently Open)"));
+    assert!(text.contains("Start Progress (id 11) → In Progress"));
+}
+
+#[tokio::test]
+async fn test_get_project_workflow_without_scheme_permission() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _statuses = server
+        .mock("GET", "/rest/api/2/project/NOPERM/statuses")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(PROJECT_STATUSES)
+        .create_async()
+        .await;
+
+    let _project = server
+        .mock("GET", "/rest/api/2/project/NOPERM")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errorMessages": ["Forbidden"]}).to_string())
+        .create_async()
+        .await;
+
+    let tool = GetProjectWorkflowTool::new(create_test_config(&base_url));
+    let result = tool.handle(json!({"project_key": "NOPERM"})).await.unwrap();
+
+    let text: String = result.content.iter().map(|c| c.text.as_str()).collect();
+    assert!(text.contains("2 issue types"));
+    assert!(text.contains("Sub-task (sub-task):"));
+    assert!(!text.contains("Workflow scheme"));
+}
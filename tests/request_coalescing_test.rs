@@ -0,0 +1,133 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::error::JiraError;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::coalesce::InFlightRequests;
+use rust_jira_mcp::performance::get_global_metrics;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn create_test_config(base_url: &str, coalesce_endpoints: Option<&str>) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        coalesce_endpoints: coalesce_endpoints.map(ToString::to_string),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_coalesces_endpoint_categories() {
+    let config = create_test_config("https://test.example.com", Some("project, field"));
+
+    assert!(config.coalesces_endpoint("project/TEST"));
+    assert!(config.coalesces_endpoint("field"));
+    assert!(config.coalesces_endpoint("field?expand=names"));
+    assert!(!config.coalesces_endpoint("issue/TEST-1"));
+    assert!(!create_test_config("https://test.example.com", None).coalesces_endpoint("project"));
+    assert!(create_test_config("https://test.example.com", Some("*")).coalesces_endpoint("issue"));
+}
+
+#[tokio::test]
+async fn test_in_flight_requests_share_result() {
+    let in_flight = Arc::new(InFlightRequests::new());
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let handles: Vec<_> = (0..5)
+        .map(|_| {
+            let in_flight = in_flight.clone();
+            let calls = calls.clone();
+            tokio::spawn(async move {
+                in_flight
+                    .run("GET /project/TEST".to_string(), || async {
+                        calls.fetch_add(1, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(200)).await;
+                        Ok(json!({"key": "TEST"}))
+                    })
+                    .await
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.await.unwrap().unwrap(), json!({"key": "TEST"}));
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert!(in_flight.is_empty());
+}
+
+#[tokio::test]
+async fn test_in_flight_requests_share_errors() {
+    let in_flight = Arc::new(InFlightRequests::new());
+
+    let leader = {
+        let in_flight = in_flight.clone();
+        tokio::spawn(async move {
+            in_flight
+                .run("GET /project/MISSING".to_string(), || async {
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    Err(JiraError::api_error("Project not found"))
+                })
+                .await
+        })
+    };
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let follower = in_flight
+        .run("GET /project/MISSING".to_string(), || async {
+            Ok(json!("should not run"))
+        })
+        .await;
+
+    assert!(leader.await.unwrap().is_err());
+    let error = follower.unwrap_err();
+    assert!(error.to_string().contains("Project not found"));
+}
+
+#[tokio::test]
+async fn test_concurrent_gets_hit_upstream_once() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let mock = server
+        .mock("GET", "/rest/api/2/project/SLOW")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(Duration::from_millis(500));
+            w.write_all(br#"{"id": "10000", "key": "SLOW", "name": "Slow project"}"#)
+        })
+        .expect(1)
+        .create_async()
+        .await;
+
+    let before = get_global_metrics().get_stats().coalesced_requests;
+
+    // Separate clients, as each tool owns its own client
+    let clients: Vec<_> = (0..5)
+        .map(|_| Arc::new(JiraClient::new(create_test_config(&base_url, Some("project"))).unwrap()))
+        .collect();
+
+    let handles: Vec<_> = clients
+        .into_iter()
+        .map(|client| {
+            tokio::spawn(async move { client.get::<serde_json::Value>("project/SLOW").await })
+        })
+        .collect();
+
+    for handle in handles {
+        let project = handle.await.unwrap().unwrap();
+        assert_eq!(project["key"], "SLOW");
+    }
+
+    mock.assert_async().await;
+    let after = get_global_metrics().get_stats().coalesced_requests;
+    assert_eq!(after - before, 4);
+}
@@ -0,0 +1,187 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::throttle::{
+    current_priority, in_background, shared_schedulers, RequestClass, RequestPriority,
+    RequestSchedulers,
+};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Schedulers sending core requests at `core_rate` per second
+fn schedulers(core_rate: f64) -> Arc<RequestSchedulers> {
+    Arc::new(RequestSchedulers::from_config(&JiraConfig {
+        core_requests_per_second: Some(core_rate),
+        ..Default::default()
+    }))
+}
+
+/// Start `count` background requests of `class` and give them time to queue
+async fn saturate(
+    schedulers: &Arc<RequestSchedulers>,
+    class: RequestClass,
+    count: usize,
+) -> Vec<tokio::task::JoinHandle<()>> {
+    let stream = (0..count)
+        .map(|_| {
+            let schedulers = schedulers.clone();
+            tokio::spawn(async move {
+                schedulers
+                    .get(class)
+                    .acquire(RequestPriority::Background)
+                    .await;
+            })
+        })
+        .collect();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    stream
+}
+
+#[test]
+fn test_endpoints_are_classified() {
+    assert_eq!(
+        RequestClass::for_endpoint("issue/PROJ-1"),
+        RequestClass::Core
+    );
+    assert_eq!(
+        RequestClass::for_endpoint("search?jql=x"),
+        RequestClass::Core
+    );
+    assert_eq!(
+        RequestClass::for_endpoint("agile/1.0/sprint/5/issue"),
+        RequestClass::Agile
+    );
+    assert_eq!(
+        RequestClass::for_endpoint("issue/PROJ-1/attachments"),
+        RequestClass::Attachments
+    );
+    assert_eq!(
+        RequestClass::for_endpoint("https://jira.example.com/secure/attachment/10/a.png"),
+        RequestClass::Attachments
+    );
+}
+
+#[tokio::test]
+async fn test_priority_follows_the_task() {
+    assert_eq!(current_priority(), RequestPriority::Interactive);
+    assert_eq!(
+        in_background(async { current_priority() }).await,
+        RequestPriority::Background
+    );
+}
+
+#[tokio::test]
+async fn test_interactive_request_preempts_queued_background_requests() {
+    // 200 ms between requests: the ten background requests need two seconds
+    let schedulers = schedulers(5.0);
+    let scheduler = schedulers.get(RequestClass::Core);
+    let stream = saturate(&schedulers, RequestClass::Core, 10).await;
+
+    let start = Instant::now();
+    scheduler.acquire(RequestPriority::Interactive).await;
+    assert!(start.elapsed() < Duration::from_millis(500));
+
+    let snapshot = scheduler.snapshot();
+    assert_eq!(snapshot.queued_interactive, 0);
+    assert!(snapshot.queued_background >= 7);
+
+    for request in stream {
+        request.await.unwrap();
+    }
+    let snapshot = scheduler.snapshot();
+    assert_eq!(snapshot.requests, 11);
+    assert_eq!(snapshot.queued_background, 0);
+    assert!(snapshot.max_wait_ms >= 1500.0);
+    assert!(snapshot.average_wait_ms > 0.0);
+}
+
+#[tokio::test]
+async fn test_cancelled_request_gives_up_its_place() {
+    let schedulers = schedulers(5.0);
+    let scheduler = schedulers.get(RequestClass::Core);
+    scheduler.acquire(RequestPriority::Background).await;
+    let queued = saturate(&schedulers, RequestClass::Core, 2).await;
+    queued[1].abort();
+
+    let start = Instant::now();
+    scheduler.acquire(RequestPriority::Background).await;
+    assert!(start.elapsed() < Duration::from_millis(700));
+    assert_eq!(scheduler.snapshot().queued_background, 0);
+}
+
+#[tokio::test]
+async fn test_classes_are_paced_independently() {
+    let config = JiraConfig {
+        api_base_url: "https://scheduling.example.com/rest/api/2".to_string(),
+        zephyr_requests_per_second: Some(2.0),
+        ..Default::default()
+    };
+    let schedulers = shared_schedulers(&config);
+    assert!(Arc::ptr_eq(&schedulers, &shared_schedulers(&config)));
+    let _sync = saturate(&schedulers, RequestClass::Zephyr, 6).await;
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        schedulers
+            .get(RequestClass::Core)
+            .acquire(RequestPriority::Interactive)
+            .await;
+    }
+    assert!(start.elapsed() < Duration::from_millis(400));
+
+    let rates: Vec<f64> = schedulers
+        .snapshot()
+        .iter()
+        .map(|class| class.requests_per_second)
+        .collect();
+    assert_eq!(rates, [10.0, 10.0, 2.0, 10.0]);
+}
+
+#[tokio::test]
+async fn test_issue_lookup_is_prompt_during_a_background_stream() {
+    let mut server = mockito::Server::new_async().await;
+    let _issue = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex("^/rest/api/2/issue/".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"id": "1", "key": "SCH-1", "self": "x", "fields": {"summary": "S"}}).to_string(),
+        )
+        .create_async()
+        .await;
+    let config = JiraConfig {
+        api_base_url: format!("{}/rest/api/2", server.url()),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        core_requests_per_second: Some(5.0),
+        ..Default::default()
+    };
+    let client = Arc::new(JiraClient::new(config.clone()).unwrap());
+
+    let stream: Vec<_> = (0..10)
+        .map(|i| {
+            let client = client.clone();
+            tokio::spawn(in_background(async move {
+                client.get_issue(&format!("BG-{i}")).await.unwrap();
+            }))
+        })
+        .collect();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let start = Instant::now();
+    client.get_issue("SCH-1").await.unwrap();
+    assert!(start.elapsed() < Duration::from_millis(500));
+
+    let core = shared_schedulers(&config)
+        .get(RequestClass::Core)
+        .snapshot();
+    assert!(core.queued_background >= 7);
+
+    for request in stream {
+        request.await.unwrap();
+    }
+}
@@ -0,0 +1,243 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::middleware::{ResponseMiddleware, ToolContext};
+use rust_jira_mcp::mcp::server::MCPServer;
+use rust_jira_mcp::mcp::transport::response_limit::{encode_reply, PREVIEW_CHARS};
+use rust_jira_mcp::performance::get_global_metrics;
+use rust_jira_mcp::types::mcp::MCPToolResult;
+use serde_json::{json, Value};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::io::AsyncWrite;
+
+const MAX_BYTES: usize = 8192;
+
+fn create_test_config() -> JiraConfig {
+    JiraConfig {
+        api_base_url: "http://localhost:1/rest/api/2".to_string(),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        max_response_bytes: Some(MAX_BYTES),
+        ..Default::default()
+    }
+}
+
+/// A tool result of `chars` characters, starting with a recognizable line
+fn tool_result(id: Value, chars: usize) -> Value {
+    let text = format!("ISSUE-1 first line\n{}", "x".repeat(chars));
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "result": {"content": [{"type": "text", "text": text}], "isError": false}
+    })
+}
+
+fn parse(text: &str) -> Value {
+    serde_json::from_str(text).unwrap()
+}
+
+#[test]
+fn test_oversized_tool_result_is_replaced() {
+    let reply = tool_result(json!(5), 3 * MAX_BYTES);
+    let encoded = encode_reply(&reply, Some(MAX_BYTES)).unwrap();
+
+    assert!(encoded.replaced);
+    assert!(encoded.original_bytes > 3 * MAX_BYTES);
+    assert!(encoded.text.len() <= MAX_BYTES, "{}", encoded.text.len());
+    let replaced = parse(&encoded.text);
+    assert_eq!(replaced["id"], 5);
+    assert_eq!(replaced["result"]["isError"], true);
+    let text = replaced["result"]["content"][0]["text"].as_str().unwrap();
+    assert!(
+        text.starts_with(&format!(
+            "The response was {} bytes, more than the 8192 max_response_bytes allows",
+            encoded.original_bytes
+        )),
+        "{text}"
+    );
+    assert!(text.contains("output_path"), "{text}");
+    assert!(
+        text.contains("First 1024 characters:\nISSUE-1 first line\nxxx"),
+        "{text}"
+    );
+}
+
+#[test]
+fn test_preview_is_capped_in_characters() {
+    let large = 1024 * 1024;
+    let encoded = encode_reply(&tool_result(json!(1), 2 * large), Some(large)).unwrap();
+    let replaced = parse(&encoded.text);
+    let text = replaced["result"]["content"][0]["text"].as_str().unwrap();
+    assert!(
+        text.contains(&format!("First {PREVIEW_CHARS} characters:")),
+        "{text}"
+    );
+}
+
+#[test]
+fn test_other_oversized_responses_become_errors() {
+    let tools: Vec<Value> = (0..400)
+        .map(|n| json!({"name": format!("tool_{n}"), "description": "d".repeat(40)}))
+        .collect();
+    let reply = json!({"jsonrpc": "2.0", "id": "list", "result": {"tools": tools}});
+    let encoded = encode_reply(&reply, Some(MAX_BYTES)).unwrap();
+
+    let replaced = parse(&encoded.text);
+    assert_eq!(replaced["id"], "list");
+    assert_eq!(replaced["error"]["code"], -32603);
+    assert_eq!(replaced["error"]["message"], "Response too large");
+    assert_eq!(replaced["error"]["data"]["max_response_bytes"], MAX_BYTES);
+    assert!(replaced["error"]["data"]["preview"]
+        .as_str()
+        .unwrap()
+        .starts_with("{\"id\":\"list\""));
+}
+
+#[test]
+fn test_only_the_oversized_batch_entries_are_replaced() {
+    let small = tool_result(json!("small"), 10);
+    let batch = json!([small.clone(), tool_result(json!("big"), 2 * MAX_BYTES)]);
+    let encoded = encode_reply(&batch, Some(MAX_BYTES)).unwrap();
+
+    assert!(encoded.replaced);
+    let replies = parse(&encoded.text);
+    assert_eq!(replies[0], small);
+    assert_eq!(replies[1]["id"], "big");
+    assert_eq!(replies[1]["result"]["isError"], true);
+}
+
+#[test]
+fn test_responses_within_the_cap_are_untouched() {
+    let reply = tool_result(json!(1), 3 * MAX_BYTES);
+    for max in [None, Some(0), Some(10 * MAX_BYTES)] {
+        let encoded = encode_reply(&reply, max).unwrap();
+        assert!(!encoded.replaced);
+        assert_eq!(parse(&encoded.text), reply);
+    }
+}
+
+#[test]
+fn test_max_response_bytes_is_validated() {
+    let config = JiraConfig {
+        max_response_bytes: Some(100),
+        ..create_test_config()
+    };
+    let error = config.validate().unwrap_err().to_string();
+    assert!(
+        error.contains("use 0 to disable the cap or at least 4096"),
+        "{error}"
+    );
+
+    for bytes in [0, 4096] {
+        let config = JiraConfig {
+            max_response_bytes: Some(bytes),
+            ..create_test_config()
+        };
+        config.validate().unwrap();
+    }
+}
+
+/// Pads every tool result well past the cap
+struct Inflate;
+
+impl ResponseMiddleware for Inflate {
+    fn name(&self) -> &str {
+        "inflate"
+    }
+
+    fn process(&self, result: &mut MCPToolResult, _context: &ToolContext) {
+        result.content[0].text.push_str(&"y".repeat(4 * MAX_BYTES));
+    }
+}
+
+/// Collects what is written and counts writes since the last flush
+#[derive(Default)]
+struct FlushCountingWriter {
+    output: Vec<u8>,
+    unflushed_writes: usize,
+    /// Newlines in the output at each flush
+    lines_at_flush: Vec<usize>,
+}
+
+impl AsyncWrite for FlushCountingWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        this.output.extend_from_slice(buf);
+        this.unflushed_writes += 1;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        this.unflushed_writes = 0;
+        this.lines_at_flush
+            .push(this.output.iter().filter(|b| **b == b'\n').count());
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[tokio::test]
+async fn test_writer_replaces_oversized_results_and_flushes_every_frame() {
+    let replaced_before = get_global_metrics().get_stats().response_sizes.replaced;
+    let lines = [
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {"protocolVersion": "2025-03-26", "capabilities": {}, "clientInfo": {"name": "limit-test", "version": "1.0"}}
+        }),
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+        json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": {"name": "test_jira_auth", "arguments": {}}
+        }),
+        json!([
+            {"jsonrpc": "2.0", "id": 3, "method": "tools/call", "params": {"name": "test_jira_auth", "arguments": {}}},
+            {"jsonrpc": "2.0", "id": 4, "method": "ping"}
+        ]),
+    ];
+    let input: String = lines.iter().map(|line| format!("{line}\n")).collect();
+
+    let mut server = MCPServer::new(create_test_config());
+    server.add_response_middleware(Arc::new(Inflate));
+    let mut writer = FlushCountingWriter::default();
+    server.serve(input.as_bytes(), &mut writer).await.unwrap();
+
+    let output = String::from_utf8(writer.output).unwrap();
+    let replies: Vec<Value> = output.lines().map(parse).collect();
+    assert_eq!(replies.len(), 3);
+    assert!(output.lines().all(|line| line.len() <= MAX_BYTES));
+
+    let text = replies[1]["result"]["content"][0]["text"].as_str().unwrap();
+    assert!(text.starts_with("The response was "), "{text}");
+    assert!(
+        text.contains("characters:\nAuthentication test successfulyyy"),
+        "{text}"
+    );
+    let batch = replies[2].as_array().unwrap();
+    assert_eq!(batch[0]["result"]["isError"], true);
+    assert_eq!(batch[1]["id"], 4);
+
+    // Every frame is flushed as soon as it is written
+    assert_eq!(writer.unflushed_writes, 0);
+    assert_eq!(writer.lines_at_flush, vec![1, 2, 3]);
+
+    let sizes = get_global_metrics().get_stats().response_sizes;
+    assert!(sizes.replaced >= replaced_before + 2);
+    assert!(sizes.max_bytes > 4 * MAX_BYTES as u64);
+    assert_eq!(
+        sizes.buckets.iter().map(|b| b.responses).sum::<u64>(),
+        sizes.responses
+    );
+}
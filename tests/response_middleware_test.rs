@@ -0,0 +1,249 @@
+use rust_jira_mcp::config::response::{
+    FooterSettings, LinkRewriteSettings, RedactSettings, ResponseMiddlewareConfig, TruncateSettings,
+};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::middleware::{
+    FooterMiddleware, LinkRewriteMiddleware, RedactMiddleware, ResponseMiddleware,
+    ResponsePipeline, ToolContext, TruncateMiddleware,
+};
+use rust_jira_mcp::mcp::server::MCPServer;
+use rust_jira_mcp::types::mcp::{MCPContent, MCPToolResult};
+use serde_json::{json, Value};
+use std::sync::Arc;
+
+fn context() -> ToolContext {
+    ToolContext {
+        tool_name: "get_jira_issue".to_string(),
+        correlation_id: "c0ffee".to_string(),
+        client_name: Some("test-client".to_string()),
+    }
+}
+
+fn result(text: &str) -> MCPToolResult {
+    MCPToolResult {
+        content: vec![MCPContent::text(text.to_string())],
+        is_error: Some(false),
+    }
+}
+
+fn texts(result: &MCPToolResult) -> Vec<&str> {
+    result.content.iter().map(|c| c.text.as_str()).collect()
+}
+
+fn links() -> LinkRewriteSettings {
+    LinkRewriteSettings {
+        pattern: r"https://jira\.example\.com/browse/".to_string(),
+        replacement: "https://go.example.com/jira/".to_string(),
+    }
+}
+
+fn footer() -> FooterSettings {
+    FooterSettings {
+        text: "{tool} call {correlation_id}".to_string(),
+    }
+}
+
+#[test]
+fn test_redact_replaces_every_pattern() {
+    let middleware = RedactMiddleware::new(&RedactSettings {
+        patterns: vec![r"\d{3}-\d{2}-\d{4}".to_string(), "hunter2".to_string()],
+        replacement: None,
+    })
+    .unwrap();
+    let mut output = result("SSN 123-45-6789, password hunter2");
+    middleware.process(&mut output, &context());
+    assert_eq!(texts(&output), ["SSN [REDACTED], password [REDACTED]"]);
+}
+
+#[test]
+fn test_links_are_rewritten_in_every_item() {
+    let middleware = LinkRewriteMiddleware::new(&links()).unwrap();
+    let mut output = result("Created https://jira.example.com/browse/ABC-1");
+    output.content.push(MCPContent::json(
+        &json!({"url": "https://jira.example.com/browse/ABC-1"}),
+    ));
+    middleware.process(&mut output, &context());
+
+    assert_eq!(
+        output.content[0].text,
+        "Created https://go.example.com/jira/ABC-1"
+    );
+    let item: Value = serde_json::from_str(&output.content[1].text).unwrap();
+    assert_eq!(item["url"], "https://go.example.com/jira/ABC-1");
+}
+
+#[test]
+fn test_truncate_notes_what_was_left_out() {
+    let middleware = TruncateMiddleware::new(TruncateSettings { max_chars: 10 });
+    let mut output = result("0123456789abcdef");
+    output.content.push(MCPContent::text("short".to_string()));
+    middleware.process(&mut output, &context());
+    assert_eq!(
+        texts(&output),
+        ["0123456789\n[… truncated: 6 more characters]", "short"]
+    );
+}
+
+#[test]
+fn test_footer_is_a_last_item_with_the_call_details() {
+    let mut output = result("Issue");
+    FooterMiddleware::new(&footer()).process(&mut output, &context());
+    assert_eq!(texts(&output), ["Issue", "get_jira_issue call c0ffee"]);
+}
+
+#[test]
+fn test_middleware_run_in_configured_order() {
+    let text = "See https://jira.example.com/browse/ABC-1";
+    let config = |order: &[&str]| ResponseMiddlewareConfig {
+        order: Some(order.iter().map(ToString::to_string).collect()),
+        links: Some(links()),
+        truncate: Some(TruncateSettings { max_chars: 20 }),
+        footer: Some(footer()),
+        ..Default::default()
+    };
+
+    // Truncating first cuts the link before it can be rewritten
+    let pipeline = ResponsePipeline::from_config(&config(&["truncate", "links"])).unwrap();
+    assert_eq!(pipeline.names(), ["truncate", "links"]);
+    let mut output = result(text);
+    pipeline.process(&mut output, &context());
+    assert_eq!(
+        texts(&output),
+        ["See https://jira.exa\n[… truncated: 21 more characters]"]
+    );
+
+    // The footer is truncated too when it is added before
+    let pipeline =
+        ResponsePipeline::from_config(&config(&["links", "footer", "truncate"])).unwrap();
+    let mut output = result(text);
+    pipeline.process(&mut output, &context());
+    assert_eq!(
+        texts(&output),
+        [
+            "See https://go.examp\n[… truncated: 17 more characters]",
+            "get_jira_issue call\n[… truncated: 7 more characters]"
+        ]
+    );
+
+    // Without an order every configured middleware runs in the default order
+    let pipeline = ResponsePipeline::from_config(&ResponseMiddlewareConfig {
+        order: None,
+        ..config(&[])
+    })
+    .unwrap();
+    assert_eq!(pipeline.names(), ["links", "truncate", "footer"]);
+}
+
+#[test]
+fn test_invalid_sections_are_refused() {
+    for (config, expected) in [
+        (
+            ResponseMiddlewareConfig {
+                order: Some(vec!["emoji".to_string()]),
+                ..Default::default()
+            },
+            "unknown middleware 'emoji'",
+        ),
+        (
+            ResponseMiddlewareConfig {
+                order: Some(vec!["footer".to_string()]),
+                ..Default::default()
+            },
+            "without a [response_middleware.footer] section",
+        ),
+        (
+            ResponseMiddlewareConfig {
+                links: Some(LinkRewriteSettings {
+                    pattern: "(".to_string(),
+                    replacement: String::new(),
+                }),
+                ..Default::default()
+            },
+            "Invalid pattern in response_middleware.links",
+        ),
+    ] {
+        let err = ResponsePipeline::from_config(&config)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(expected), "{err}");
+    }
+}
+
+struct Shout;
+
+impl ResponseMiddleware for Shout {
+    fn name(&self) -> &str {
+        "shout"
+    }
+
+    fn process(&self, result: &mut MCPToolResult, context: &ToolContext) {
+        if context.client_name.as_deref() == Some("test-client") {
+            for item in &mut result.content {
+                item.text = item.text.to_uppercase();
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_server_runs_configured_and_custom_middleware() {
+    let mut mock_server = mockito::Server::new_async().await;
+    let _issue = mock_server
+        .mock("GET", "/middleware/rest/api/2/issue/MID-1")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"id": "1", "key": "MID-1", "self": "x", "fields": {"summary": "Quiet"}})
+                .to_string(),
+        )
+        .create_async()
+        .await;
+    let config = JiraConfig {
+        api_base_url: format!("{}/middleware/rest/api/2", mock_server.url()),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        response_middleware: Some(ResponseMiddlewareConfig {
+            footer: Some(FooterSettings {
+                text: "ref {correlation_id}".to_string(),
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let mut server = MCPServer::new(config);
+    server.add_response_middleware(Arc::new(Shout));
+
+    for message in [
+        json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {
+            "protocolVersion": "2024-11-05",
+            "capabilities": {},
+            "clientInfo": {"name": "test-client", "version": "1.0.0"}
+        }}),
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+    ] {
+        server.handle_request(&message.to_string()).await.unwrap();
+    }
+    let response = server
+        .handle_request(
+            &json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": "tools/call",
+                "params": {"name": "get_jira_issue", "arguments": {"issue_key": "MID-1"}}
+            })
+            .to_string(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+    let content = response.result.unwrap()["content"].clone();
+    let content = content.as_array().unwrap();
+    assert!(content[0]["text"].as_str().unwrap().contains("QUIET"));
+    // The custom middleware ran after the footer was added
+    let footer = content.last().unwrap()["text"].as_str().unwrap();
+    assert!(footer.starts_with("REF "), "{footer}");
+    assert_eq!(footer.len(), "REF ".len() + 36);
+}
@@ -0,0 +1,145 @@
+use reqwest::StatusCode;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::error::JiraError;
+use rust_jira_mcp::jira::client::JiraClient;
+use serde_json::json;
+
+// The current user is cached per base URL and email, and mock servers reuse
+// ports, so each test authenticates as someone else
+fn create_test_config(base_url: &str, email: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: email.to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn comment_json(id: &str, body: &str, author: &str, created: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "body": body,
+        "author": {
+            "self": "x",
+            "name": author,
+            "key": author,
+            "displayName": author,
+            "active": true
+        },
+        "created": created
+    })
+}
+
+fn jira_timestamp(time: chrono::DateTime<chrono::Utc>) -> String {
+    time.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string()
+}
+
+async fn mock_myself(server: &mut mockito::ServerGuard) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/2/myself")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"name": "me", "displayName": "Me"}).to_string())
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_comment_lost_after_success_is_not_posted_twice() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _myself = mock_myself(&mut server).await;
+    let post = server
+        .mock("POST", "/rest/api/2/issue/RETRY-1/comment")
+        .with_status(502)
+        .with_body("<html>Bad Gateway</html>")
+        .expect(1)
+        .create_async()
+        .await;
+    let now = chrono::Utc::now();
+    let _comments = server
+        .mock("GET", "/rest/api/2/issue/RETRY-1/comment")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"comments": [
+                comment_json("3", "Deploy finished", "me", &jira_timestamp(now)),
+                comment_json("2", "Deploy finished", "someone-else", &jira_timestamp(now)),
+                comment_json(
+                    "1",
+                    "Deploy finished",
+                    "me",
+                    &jira_timestamp(now - chrono::Duration::minutes(10))
+                )
+            ]})
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url, "dedup@example.com")).unwrap();
+    let comment = client
+        .add_comment("RETRY-1", "Deploy finished")
+        .await
+        .unwrap();
+
+    assert_eq!(comment.id, "3");
+    post.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_issue_is_not_retried_after_server_error() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let post = server
+        .mock("POST", "/rest/api/2/issue")
+        .with_status(502)
+        .with_body("Bad Gateway")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url, "create@example.com")).unwrap();
+    let error = client
+        .create_issue(&json!({"fields": {"summary": "Once"}}))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        JiraError::HttpError {
+            status: StatusCode::BAD_GATEWAY,
+            ..
+        }
+    ));
+    assert!(JiraClient::may_have_been_applied(&error));
+    post.assert_async().await;
+}
+
+#[test]
+fn test_may_have_been_applied() {
+    let server_error = |status| JiraError::HttpError {
+        status,
+        message: String::new(),
+    };
+    assert!(JiraClient::may_have_been_applied(&server_error(
+        StatusCode::INTERNAL_SERVER_ERROR
+    )));
+    assert!(!JiraClient::may_have_been_applied(&server_error(
+        StatusCode::TOO_MANY_REQUESTS
+    )));
+    assert!(!JiraClient::may_have_been_applied(&JiraError::api_error(
+        "Issue does not exist"
+    )));
+}
+
+#[test]
+fn test_non_idempotent_retries_are_off_by_default() {
+    assert_eq!(JiraConfig::default().retry_non_idempotent, Some(false));
+}
@@ -0,0 +1,141 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPServer;
+use rust_jira_mcp::types::mcp::JsonRpcResponse;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn initialized_server(base_url: &str) -> MCPServer {
+    let mut server = MCPServer::new(create_test_config(base_url));
+    for message in [
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {"protocolVersion": "2025-03-26", "capabilities": {}, "clientInfo": {"name": "retry-test", "version": "1.0"}}
+        }),
+        json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+    ] {
+        server.handle_request(&message.to_string()).await.unwrap();
+    }
+    server
+}
+
+async fn get_issue(server: &mut MCPServer, issue_key: &str) -> JsonRpcResponse {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 2,
+        "method": "tools/call",
+        "params": {"name": "get_jira_issue", "arguments": {"issue_key": issue_key}}
+    });
+    server
+        .handle_request(&request.to_string())
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+async fn issue_mock(server: &mut mockito::Server, issue_key: &str) -> mockito::Mock {
+    server
+        .mock("GET", format!("/rest/api/2/issue/{issue_key}").as_str())
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10001",
+                "key": issue_key,
+                "self": format!("https://jira.example.com/rest/api/2/issue/{issue_key}"),
+                "fields": {"summary": "Flaky"}
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+async fn failure_mock(
+    server: &mut mockito::Server,
+    issue_key: &str,
+    status: usize,
+    times: usize,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("/rest/api/2/issue/{issue_key}").as_str())
+        .with_status(status)
+        .with_body("Bad Gateway")
+        .expect(times)
+        .create_async()
+        .await
+}
+
+fn text(response: &JsonRpcResponse) -> String {
+    let result: &Value = response.result.as_ref().unwrap();
+    result["content"][0]["text"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_retried_call_notes_its_attempts() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let failure = failure_mock(&mut server, "FLAKY-1", 502, 1).await;
+    let success = issue_mock(&mut server, "FLAKY-1").await;
+
+    let mut mcp = initialized_server(&base_url).await;
+    let response = get_issue(&mut mcp, "FLAKY-1").await;
+
+    failure.assert_async().await;
+    success.assert_async().await;
+    let text = text(&response);
+    assert!(
+        text.ends_with(
+            "\nRequest succeeded after 2 attempts, 1.0s in backoff (last failure: HTTP 502)"
+        ),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_call_without_retries_has_no_note() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let success = issue_mock(&mut server, "STEADY-1").await;
+
+    let mut mcp = initialized_server(&base_url).await;
+    let response = get_issue(&mut mcp, "STEADY-1").await;
+
+    success.assert_async().await;
+    let text = text(&response);
+    assert!(text.contains("STEADY-1"), "{text}");
+    assert!(!text.contains("attempts"), "{text}");
+}
+
+#[tokio::test]
+async fn test_call_that_gives_up_summarizes_its_retries() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let failure = failure_mock(&mut server, "DOWN-1", 503, 4).await;
+
+    let mut mcp = initialized_server(&base_url).await;
+    let response = get_issue(&mut mcp, "DOWN-1").await;
+
+    failure.assert_async().await;
+    let details = response.error.unwrap().data.unwrap()["details"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert!(
+        details.ends_with(
+            "\nRequest gave up after 4 attempts, 6.0s in backoff (last failure: HTTP 503)"
+        ),
+        "{details}"
+    );
+}
@@ -0,0 +1,195 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{BulkUpdateIssuesTool, CreateIssueTool, UpdateIssueTool};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn issue_json(key: &str, summary: &str) -> Value {
+    json!({
+        "id": "10001",
+        "key": key,
+        "self": format!("https://jira.example.com/rest/api/2/issue/{key}"),
+        "fields": {
+            "summary": summary,
+            "status": {"name": "To Do"},
+            "assignee": {"displayName": "Ada Lovelace"}
+        }
+    })
+}
+
+fn fields_query(fields: &str) -> mockito::Matcher {
+    mockito::Matcher::UrlEncoded("fields".into(), fields.into())
+}
+
+async fn mock_read_back(
+    server: &mut mockito::ServerGuard,
+    key: &str,
+    query: mockito::Matcher,
+    summary: &str,
+) -> mockito::Mock {
+    server
+        .mock("GET", format!("/rest/api/2/issue/{key}").as_str())
+        .match_query(query)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_json(key, summary).to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_create_returns_the_issue_with_the_selected_fields() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "10001", "key": "RET-1", "self": "x", "fields": {}}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let read = mock_read_back(
+        &mut server,
+        "RET-1",
+        mockito::Matcher::UrlEncoded("fields".to_string(), "summary,status,assignee".to_string()),
+        "Login fails",
+    )
+    .await;
+
+    let tool = CreateIssueTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "fields": {
+                "project": {"key": "RET"},
+                "summary": "Login fails",
+                "issuetype": {"name": "Bug"}
+            },
+            "return_issue": true,
+            "return_fields": ["summary", "status", "assignee"]
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        format!(
+            "Issue created successfully: RET-1 - Login fails\nURL: {base_url}/browse/RET-1\n\n\
+             Issue: RET-1\nSummary: Login fails\nStatus: To Do\nAssignee: Ada Lovelace\n\
+             URL: {base_url}/browse/RET-1"
+        )
+    );
+    let issue: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(issue["fields"]["status"]["name"], "To Do");
+    create.assert_async().await;
+    read.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_update_returns_the_issue_only_when_asked() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _update = server
+        .mock("PUT", "/rest/api/2/issue/RET-2")
+        .with_status(204)
+        .expect(2)
+        .create_async()
+        .await;
+    let read = mock_read_back(&mut server, "RET-2", mockito::Matcher::Missing, "Renamed").await;
+
+    let tool = UpdateIssueTool::new(create_test_config(&base_url));
+    let plain = tool
+        .handle(json!({"issue_key": "RET-2", "summary": "Renamed"}))
+        .await
+        .unwrap();
+    assert_eq!(plain.content.len(), 1);
+
+    let result = tool
+        .handle(json!({"issue_key": "RET-2", "summary": "Renamed", "return_issue": true}))
+        .await
+        .unwrap();
+    assert!(result.content[0]
+        .text
+        .starts_with("Issue RET-2 updated successfully\n\nIssue: RET-2\nSummary: Renamed\n"));
+    assert_eq!(result.content.len(), 2);
+    read.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bulk_update_returns_failed_issues() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _updated = server
+        .mock("PUT", "/rest/api/2/issue/RET-3")
+        .with_status(204)
+        .create_async()
+        .await;
+    let _rejected = server
+        .mock("PUT", "/rest/api/2/issue/RET-4")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"errors": {"summary": "Summary is too long"}}).to_string())
+        .create_async()
+        .await;
+    // The summary each issue had is read before the update
+    let previous = [
+        mock_read_back(&mut server, "RET-3", fields_query("summary"), "Old").await,
+        mock_read_back(&mut server, "RET-4", fields_query("summary"), "Old").await,
+    ];
+    let not_read = server
+        .mock("GET", "/rest/api/2/issue/RET-3")
+        .match_query(mockito::Matcher::Missing)
+        .expect(0)
+        .create_async()
+        .await;
+    let read = mock_read_back(&mut server, "RET-4", mockito::Matcher::Missing, "Old").await;
+
+    let tool = BulkUpdateIssuesTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_keys": ["RET-3", "RET-4"],
+            "update_data": {"fields": {"summary": "x".repeat(300)}},
+            "return_issues": "failed",
+            "config": {"max_retries": 0, "rate_limit_ms": 0}
+        }))
+        .await
+        .unwrap();
+
+    assert!(result.content[0]
+        .text
+        .contains("\n\nIssue: RET-4\nSummary: Old\n"));
+    let issues: Value = serde_json::from_str(&result.content[2].text).unwrap();
+    assert_eq!(issues.as_array().unwrap().len(), 1);
+    assert_eq!(issues[0]["key"], "RET-4");
+    not_read.assert_async().await;
+    read.assert_async().await;
+    for mock in previous {
+        mock.assert_async().await;
+    }
+
+    let error = tool
+        .handle(json!({
+            "issue_keys": ["RET-3"],
+            "update_data": {"fields": {}},
+            "return_issues": "some"
+        }))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Unknown value 'some'; use none, failed or all"));
+}
@@ -0,0 +1,217 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::SearchIssuesTool;
+use rust_jira_mcp::performance::SearchResultCache;
+use serde_json::json;
+use std::collections::HashSet;
+use std::time::Duration;
+
+const TTL: Duration = Duration::from_secs(30);
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        search_cache_enabled: Some(true),
+        ..Default::default()
+    }
+}
+
+fn search_response(base_url: &str) -> serde_json::Value {
+    json!({
+        "startAt": 0,
+        "maxResults": 50,
+        "total": 1,
+        "issues": [{
+            "id": "10001",
+            "key": "TEST-1",
+            "self": format!("{base_url}/rest/api/2/issue/10001"),
+            "fields": {
+                "summary": "Cached issue",
+                "status": {"name": "To Do"}
+            }
+        }]
+    })
+}
+
+#[test]
+fn test_search_result_cache_ttl_expiry() {
+    let cache = SearchResultCache::new();
+    let keys: HashSet<String> = ["TEST-1".to_string()].into_iter().collect();
+    cache.insert("search:a".to_string(), json!({"total": 1}), keys, TTL);
+
+    assert!(cache.get("search:a", Duration::from_secs(30)).is_some());
+    assert!(cache.get("search:a", Duration::ZERO).is_none());
+    // Expired entries are evicted on read
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_search_result_cache_sweeps_expired_entries_on_insert() {
+    let cache = SearchResultCache::new();
+    let ttl = Duration::from_millis(20);
+    for key in ["search:a", "search:b"] {
+        cache.insert(key.to_string(), json!({}), HashSet::new(), ttl);
+    }
+    std::thread::sleep(ttl * 2);
+
+    // Neither expired entry is read again, the next insert drops both
+    cache.insert("search:c".to_string(), json!({}), HashSet::new(), ttl);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get("search:c", ttl).is_some());
+}
+
+#[test]
+fn test_search_result_cache_evicts_oldest_when_full() {
+    let cache = SearchResultCache::with_capacity(2);
+    for key in ["search:a", "search:b", "search:c"] {
+        cache.insert(key.to_string(), json!({}), HashSet::new(), TTL);
+        std::thread::sleep(Duration::from_millis(2));
+    }
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("search:a", TTL).is_none());
+    assert!(cache.get("search:b", TTL).is_some());
+    assert!(cache.get("search:c", TTL).is_some());
+}
+
+#[test]
+fn test_search_result_cache_invalidate_issue() {
+    let cache = SearchResultCache::new();
+    let keys = |k: &[&str]| k.iter().map(ToString::to_string).collect::<HashSet<_>>();
+    cache.insert(
+        "search:a".to_string(),
+        json!({}),
+        keys(&["TEST-1", "TEST-2"]),
+        TTL,
+    );
+    cache.insert("search:b".to_string(), json!({}), keys(&["TEST-3"]), TTL);
+
+    assert_eq!(cache.invalidate_issue("TEST-2"), 1);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get("search:b", Duration::from_secs(30)).is_some());
+}
+
+#[tokio::test]
+async fn test_search_tool_serves_cached_results() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let mock = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_response(&base_url).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let tool = SearchIssuesTool::new(create_test_config(&base_url));
+    let args = json!({"jql": "project = CACHEHIT"});
+
+    let first = tool.handle(args.clone()).await.unwrap();
+    assert!(!first.content[0].text.contains("(cached)"));
+
+    let second = tool.handle(args.clone()).await.unwrap();
+    assert!(second.content[0].text.contains("(cached)"));
+
+    let bypassed = tool
+        .handle(json!({"jql": "project = CACHEHIT", "bypass_cache": true}))
+        .await
+        .unwrap();
+    assert!(!bypassed.content[0].text.contains("(cached)"));
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_search_cache_disabled_by_default() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let mock = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_response(&base_url).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = JiraConfig {
+        search_cache_enabled: None,
+        ..create_test_config(&base_url)
+    };
+    let client = JiraClient::new(config).unwrap();
+
+    for _ in 0..2 {
+        let (_, cached) = client
+            .search_issues_cached("project = NOCACHE", None, None, &[], false)
+            .await
+            .unwrap();
+        assert!(!cached);
+    }
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_search_cache_invalidated_on_update() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let search_mock = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_response(&base_url).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let _update_mock = server
+        .mock("PUT", "/rest/api/2/issue/TEST-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    let jql = "project = INVALIDATE";
+
+    let (_, cached) = client
+        .search_issues_cached(jql, None, None, &[], false)
+        .await
+        .unwrap();
+    assert!(!cached);
+
+    let (_, cached) = client
+        .search_issues_cached(jql, None, None, &[], false)
+        .await
+        .unwrap();
+    assert!(cached);
+
+    client
+        .update_issue("TEST-1", &json!({"fields": {"summary": "Changed"}}))
+        .await
+        .unwrap();
+
+    let (_, cached) = client
+        .search_issues_cached(jql, None, None, &[], false)
+        .await
+        .unwrap();
+    assert!(!cached);
+
+    search_mock.assert_async().await;
+}
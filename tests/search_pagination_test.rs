@@ -0,0 +1,130 @@
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        max_results: Some(2),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn issue(id: &str) -> Value {
+    json!({"id": id, "key": format!("PAGE-{id}"), "fields": {"summary": format!("Issue {id}")}})
+}
+
+async fn page_mock(
+    server: &mut mockito::Server,
+    jql: &str,
+    start_at: &str,
+    body: Value,
+) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("jql".to_string(), jql.to_string()),
+            Matcher::UrlEncoded("startAt".to_string(), start_at.to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_overlapping_pages_are_sorted_and_deduplicated() {
+    let mut server = mockito::Server::new_async().await;
+    let jql = "project = PAGE ORDER BY key ASC";
+    // An issue created mid-read pushes issue 2 onto the second page as well
+    let pages = [
+        page_mock(
+            &mut server,
+            jql,
+            "0",
+            json!({"total": 4, "issues": [issue("1"), issue("2")]}),
+        )
+        .await,
+        page_mock(
+            &mut server,
+            jql,
+            "2",
+            json!({"total": 5, "issues": [issue("2"), issue("3")]}),
+        )
+        .await,
+        page_mock(
+            &mut server,
+            jql,
+            "4",
+            json!({"total": 5, "issues": [issue("4")]}),
+        )
+        .await,
+    ];
+
+    let fetched = JiraClient::new(create_test_config(&server.url()))
+        .unwrap()
+        .search_issues_bounded("project = PAGE", &["summary".to_string()], 100)
+        .await
+        .unwrap();
+
+    for page in pages {
+        page.assert_async().await;
+    }
+    let ids: Vec<&str> = fetched
+        .issues
+        .iter()
+        .map(|issue| issue["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, ["1", "2", "3", "4"]);
+    assert!(fetched.order_added);
+    assert_eq!(fetched.duplicates, 1);
+    assert_eq!((fetched.first_total, fetched.last_total), (4, 5));
+    assert!(fetched.total_changed());
+    assert_eq!(
+        fetched.note(),
+        "Sorted by key (ORDER BY key ASC added) so pages could not shift\n\
+         1 issues returned on more than one page were kept once\n\
+         The query matched 4 issues at the first page and 5 at the last; \
+         issues changed while they were read, so some may be missing\n"
+    );
+}
+
+#[tokio::test]
+async fn test_ordered_query_is_kept_as_is() {
+    let mut server = mockito::Server::new_async().await;
+    let jql = "project = PAGO ORDER BY created DESC";
+    let first = page_mock(
+        &mut server,
+        jql,
+        "0",
+        json!({"total": 3, "issues": [issue("30"), issue("20")]}),
+    )
+    .await;
+    let second = page_mock(
+        &mut server,
+        jql,
+        "2",
+        json!({"total": 3, "issues": [issue("10")]}),
+    )
+    .await;
+
+    let fetched = JiraClient::new(create_test_config(&server.url()))
+        .unwrap()
+        .search_issues_bounded(jql, &["summary".to_string()], 100)
+        .await
+        .unwrap();
+
+    first.assert_async().await;
+    second.assert_async().await;
+    assert_eq!(fetched.issues.len(), 3);
+    assert!(!fetched.order_added);
+    assert_eq!(fetched.duplicates, 0);
+    assert!(!fetched.total_changed());
+    assert_eq!(fetched.note(), "");
+}
@@ -0,0 +1,229 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{cell_text, render_table, SearchIssuesTool};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str, default_fields: Option<&str>) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        search_default_fields: default_fields.map(ToString::to_string),
+        ..Default::default()
+    }
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_default_search_fields_config() {
+    assert!(JiraConfig::default().default_search_fields().is_empty());
+    let config = JiraConfig {
+        search_default_fields: Some(" status, Story Points ,,updated".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        config.default_search_fields(),
+        ["status", "Story Points", "updated"]
+    );
+}
+
+#[test]
+fn test_cell_text() {
+    assert_eq!(cell_text(&Value::Null), "");
+    assert_eq!(cell_text(&json!(5.0)), "5.0");
+    assert_eq!(
+        cell_text(&json!({"name": "In Progress", "id": "3"})),
+        "In Progress"
+    );
+    assert_eq!(
+        cell_text(&json!({"displayName": "Ana Lima", "name": "alima"})),
+        "Ana Lima"
+    );
+    assert_eq!(
+        cell_text(&json!([{"value": "iOS"}, {"value": "Android"}])),
+        "iOS, Android"
+    );
+    assert_eq!(
+        cell_text(&json!("first line\n\nsecond  line")),
+        "first line second line"
+    );
+    let adf = json!({
+        "type": "doc",
+        "version": 1,
+        "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Rich"}]}]
+    });
+    assert_eq!(cell_text(&adf), "Rich");
+}
+
+#[test]
+fn test_render_table_snapshot() {
+    let table = render_table(
+        &strings(&["Key", "Summary", "Status", "Assignee"]),
+        &[
+            strings(&["PAY-1", "Fix rounding", "Done", "Ana Lima"]),
+            strings(&[
+                "PAY-12",
+                "Reconcile settlement files from every acquirer nightly",
+                "In Progress",
+                "",
+            ]),
+        ],
+        24,
+    );
+    assert_eq!(
+        table,
+        "\
+Key     Summary                   Status       Assignee
+------  ------------------------  -----------  --------
+PAY-1   Fix rounding              Done         Ana Lima
+PAY-12  Reconcile settlement fi…  In Progress"
+    );
+}
+
+#[test]
+fn test_render_table_counts_characters_not_bytes() {
+    let table = render_table(
+        &strings(&["Key", "Summary"]),
+        &[
+            strings(&["UX-1", "Café menu ✓"]),
+            strings(&["UX-2", "Über"]),
+        ],
+        40,
+    );
+    assert_eq!(
+        table,
+        "\
+Key   Summary
+----  -----------
+UX-1  Café menu ✓
+UX-2  Über"
+    );
+    assert_eq!(render_table(&strings(&["Key"]), &[], 40), "Key\n---");
+}
+
+fn search_body() -> String {
+    json!({
+        "startAt": 0,
+        "maxResults": 50,
+        "total": 2,
+        "issues": [
+            {
+                "id": "1",
+                "key": "PAY-1",
+                "self": "x",
+                "fields": {
+                    "summary": "Fix rounding",
+                    "status": {"name": "Done"},
+                    "customfield_10016": 3.0
+                }
+            },
+            {
+                "id": "2",
+                "key": "PAY-12",
+                "self": "x",
+                "fields": {
+                    "summary": "Reconcile settlement files from every acquirer nightly, then alert",
+                    "status": {"name": "In Progress"},
+                    "customfield_10016": null
+                }
+            }
+        ]
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_search_uses_default_fields_as_columns() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _catalog = server
+        .mock("GET", "/rest/api/2/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {"id": "summary", "name": "Summary", "custom": false},
+                {"id": "status", "name": "Status", "custom": false},
+                {"id": "customfield_10016", "name": "Story Points", "custom": true}
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let search = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "fields".to_string(),
+            "summary,status,customfield_10016".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_body())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let tool = SearchIssuesTool::new(create_test_config(&base_url, Some("status,Story Points")));
+    let result = tool.handle(json!({"jql": "project = PAY"})).await.unwrap();
+    assert_eq!(
+        result.content[1].text,
+        "\
+Key     Summary                                   Status       Story Points
+------  ----------------------------------------  -----------  ------------
+PAY-1   Fix rounding                              Done         3.0
+PAY-12  Reconcile settlement files from every a…  In Progress"
+    );
+
+    // JSON output keeps full values
+    let result = tool
+        .handle(json!({"jql": "project = PAY", "format": "json"}))
+        .await
+        .unwrap();
+    let issues: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(
+        issues[1]["fields"]["summary"],
+        "Reconcile settlement files from every acquirer nightly, then alert"
+    );
+    search.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_explicit_fields_keep_the_list_output() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _search = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "fields".to_string(),
+            "summary,status".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_body())
+        .create_async()
+        .await;
+
+    let tool = SearchIssuesTool::new(create_test_config(&base_url, Some("Story Points")));
+    let result = tool
+        .handle(json!({"jql": "project = PAY", "fields": ["summary", "status"]}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[1].text,
+        format!("• PAY-1 - Fix rounding (Done)\n  URL: {base_url}/browse/PAY-1\n")
+    );
+
+    let error = tool
+        .handle(json!({"jql": "project = PAY", "format": "csv"}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Unknown format 'csv'"));
+}
@@ -0,0 +1,217 @@
+use reqwest::StatusCode;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::error::JiraError;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{CreateIssueTool, GetIssueSecurityLevelsTool, GetIssueTool};
+use rust_jira_mcp::types::jira::JiraSecurityLevel;
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("SEC".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn levels_json() -> serde_json::Value {
+    json!([
+        {"self": "x", "id": "10000", "name": "Internal", "description": "Staff only"},
+        {"self": "x", "id": "10001", "name": "Security Team", "description": ""}
+    ])
+}
+
+fn levels() -> Vec<JiraSecurityLevel> {
+    serde_json::from_value(levels_json()).unwrap()
+}
+
+fn forbidden() -> String {
+    json!({"errorMessages": ["You do not have the permission to see the specified project."]})
+        .to_string()
+}
+
+#[test]
+fn test_match_security_level() {
+    let levels = levels();
+    assert_eq!(
+        JiraClient::match_security_level(&levels, "10001", "SEC")
+            .unwrap()
+            .name,
+        "Security Team"
+    );
+    assert_eq!(
+        JiraClient::match_security_level(&levels, "internal", "SEC")
+            .unwrap()
+            .id,
+        "10000"
+    );
+
+    let error = JiraClient::match_security_level(&levels, "Public", "SEC")
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("Security level 'Public' does not exist in project SEC"));
+    assert!(error.contains("'Internal' (10000), 'Security Team' (10001)"));
+}
+
+#[test]
+fn test_forbidden_response_maps_to_permission_denied() {
+    let error = JiraError::from_jira_response(
+        StatusCode::FORBIDDEN,
+        &json!({"errorMessages": ["No permission"]}),
+    );
+    assert!(matches!(error, JiraError::PermissionDenied { .. }));
+    assert_eq!(error.to_string(), "Permission denied: No permission");
+}
+
+#[tokio::test]
+async fn test_security_levels_from_scheme() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _scheme = server
+        .mock("GET", "/rest/api/2/project/SEC/issuesecuritylevelscheme")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": 1, "name": "Scheme", "levels": levels_json()}).to_string())
+        .create_async()
+        .await;
+
+    let tool = GetIssueSecurityLevelsTool::new(create_test_config(&base_url));
+    let result = tool.handle(json!({"project_key": "SEC"})).await.unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("Found 2 issue security levels for project SEC"));
+    assert!(text.contains("• Internal (id 10000) - Staff only\n"));
+    assert!(text.contains("• Security Team (id 10001)\n"));
+}
+
+#[tokio::test]
+async fn test_security_levels_fall_back_when_scheme_is_forbidden() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _scheme = server
+        .mock("GET", "/rest/api/2/project/SEC/issuesecuritylevelscheme")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(forbidden())
+        .create_async()
+        .await;
+    let fallback = server
+        .mock("GET", "/rest/api/2/project/SEC/securitylevel")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"levels": levels_json()}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    let levels = client.get_issue_security_levels("SEC").await.unwrap();
+    assert_eq!(levels.len(), 2);
+    fallback.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_security_levels_permission_denied_guidance() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    for path in ["issuesecuritylevelscheme", "securitylevel"] {
+        server
+            .mock("GET", format!("/rest/api/2/project/SEC/{path}").as_str())
+            .with_status(403)
+            .with_header("content-type", "application/json")
+            .with_body(forbidden())
+            .create_async()
+            .await;
+    }
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    let error = client.get_issue_security_levels("SEC").await.unwrap_err();
+    assert!(matches!(error, JiraError::PermissionDenied { .. }));
+    let message = error.to_string();
+    assert!(message.contains("Cannot read the issue security levels of project SEC"));
+    assert!(message.contains("Set Issue Security permission"));
+}
+
+#[tokio::test]
+async fn test_create_issue_with_security_level_name() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _scheme = server
+        .mock("GET", "/rest/api/2/project/SEC/issuesecuritylevelscheme")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"levels": levels_json()}).to_string())
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/rest/api/2/issue")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "fields": {"project": {"key": "SEC"}, "security": {"id": "10001"}}
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "1", "key": "SEC-1", "self": "x", "fields": {}}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = CreateIssueTool::new(create_test_config(&base_url));
+    tool.handle(json!({
+        "project_key": "SEC",
+        "issue_type": "Bug",
+        "summary": "Leak",
+        "security": "security team"
+    }))
+    .await
+    .unwrap();
+    create.assert_async().await;
+
+    let error = tool
+        .handle(json!({
+            "project_key": "SEC",
+            "issue_type": "Bug",
+            "summary": "Leak",
+            "security": "Public"
+        }))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("does not exist in project SEC"));
+}
+
+#[tokio::test]
+async fn test_get_issue_shows_security_level() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _issue = server
+        .mock("GET", "/rest/api/2/issue/SEC-1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "1",
+                "key": "SEC-1",
+                "self": "x",
+                "fields": {
+                    "summary": "Leak",
+                    "status": {"name": "Open"},
+                    "security": {"id": "10000", "name": "Internal"}
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let tool = GetIssueTool::new(create_test_config(&base_url));
+    let result = tool.handle(json!({"issue_key": "SEC-1"})).await.unwrap();
+    assert!(result.content[0]
+        .text
+        .contains("Assignee: Unassigned\nSecurity level: Internal\n"));
+}
@@ -0,0 +1,195 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPServer;
+use rust_jira_mcp::mcp::tools::{ProjectArg, SESSION_PROJECT_ARG};
+use rust_jira_mcp::types::mcp::{MCPToolCall, MCPToolResult};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str, default_project: Option<&str>) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: default_project.map(ToString::to_string),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn call(server: &MCPServer, name: &str, arguments: Value) -> MCPToolResult {
+    server
+        .call_tool(MCPToolCall {
+            name: name.to_string(),
+            arguments,
+        })
+        .await
+        .unwrap()
+}
+
+fn components_mock(server: &mut mockito::Server, project: &str) -> mockito::Mock {
+    server
+        .mock(
+            "GET",
+            format!("/rest/api/2/project/{project}/components").as_str(),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([]).to_string())
+        .expect(1)
+        .create()
+}
+
+#[test]
+fn test_project_arg_precedence() {
+    let config = create_test_config("http://localhost:1", Some("DEF"));
+    let args = json!({"project_key": "ABC", SESSION_PROJECT_ARG: "SES"});
+
+    let explicit = ProjectArg::from_args(&args, "project_key", &config).unwrap();
+    assert_eq!(explicit.key, "ABC");
+    assert_eq!(explicit.note(), "");
+
+    let session =
+        ProjectArg::from_args(&json!({SESSION_PROJECT_ARG: "SES"}), "project_key", &config)
+            .unwrap();
+    assert_eq!(session.key, "SES");
+    assert!(session.from_session && !session.defaulted);
+    assert_eq!(
+        session.note(),
+        "Using session project SES (set_jira_context)\n"
+    );
+
+    let defaulted = ProjectArg::from_args(&json!({}), "project_key", &config).unwrap();
+    assert_eq!(defaulted.key, "DEF");
+    assert!(defaulted.defaulted);
+}
+
+#[tokio::test]
+async fn test_session_context_fills_omitted_arguments() {
+    let mut jira = mockito::Server::new_async().await;
+    let config = create_test_config(&jira.url(), Some("DEF"));
+    let _project = jira
+        .mock("GET", "/rest/api/2/project/ses")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "10", "key": "SES", "name": "Session"}).to_string())
+        .create_async()
+        .await;
+    let _board = jira
+        .mock("GET", "/rest/api/2/agile/1.0/board/7")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": 7, "name": "SES board", "type": "scrum"}).to_string())
+        .create_async()
+        .await;
+    let issue = jira
+        .mock("GET", "/rest/api/2/issue/SES-1")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"id": "1", "key": "SES-1", "self": "x", "fields": {"summary": "Context"}})
+                .to_string(),
+        )
+        .expect(2)
+        .create_async()
+        .await;
+    let server = MCPServer::new(config);
+
+    // Before anything is set the configured default applies
+    let default_components = components_mock(&mut jira, "DEF");
+    let result = call(&server, "get_project_components", json!({})).await;
+    assert!(result.content[0]
+        .text
+        .starts_with("Using default project DEF (JIRA_DEFAULT_PROJECT)\n"));
+    default_components.assert_async().await;
+
+    let result = call(
+        &server,
+        "set_jira_context",
+        json!({"project": "ses", "board": 7, "issue": "SES-1"}),
+    )
+    .await;
+    let context: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(
+        context,
+        json!({"project": "SES", "board": 7, "sprint": null, "issue": "SES-1"})
+    );
+
+    // The session project wins over the configured default
+    let session_components = components_mock(&mut jira, "SES");
+    let result = call(&server, "get_project_components", json!({})).await;
+    assert!(result.content[0]
+        .text
+        .starts_with("Using session project SES (set_jira_context)\n"));
+    session_components.assert_async().await;
+
+    // An explicit argument wins over the session project
+    let explicit_components = components_mock(&mut jira, "EXP");
+    let result = call(
+        &server,
+        "get_project_components",
+        json!({"project_key": "EXP"}),
+    )
+    .await;
+    assert!(!result.content[0].text.starts_with("Using"));
+    explicit_components.assert_async().await;
+
+    // Required arguments are filled too
+    let result = call(&server, "get_jira_issue", json!({})).await;
+    assert!(
+        result.content[0]
+            .text
+            .starts_with("Using session issue SES-1 (set_jira_context)\n"),
+        "{}",
+        result.content[0].text
+    );
+    issue.assert_async().await;
+
+    let result = call(&server, "get_jira_context", json!({})).await;
+    assert_eq!(
+        result.content[0].text,
+        "project: SES\nboard: 7\nissue: SES-1"
+    );
+}
+
+#[tokio::test]
+async fn test_set_jira_context_rejects_unknown_entities() {
+    let mut jira = mockito::Server::new_async().await;
+    let config = create_test_config(&jira.url(), None);
+    let _project = jira
+        .mock("GET", "/rest/api/2/project/KEEP")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": "10", "key": "KEEP"}).to_string())
+        .create_async()
+        .await;
+    let _missing = jira
+        .mock("GET", "/rest/api/2/project/NOPE")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"errorMessages": ["No project could be found with key 'NOPE'."]}).to_string(),
+        )
+        .create_async()
+        .await;
+    let server = MCPServer::new(config);
+
+    call(&server, "set_jira_context", json!({"project": "KEEP"})).await;
+    let error = server
+        .call_tool(MCPToolCall {
+            name: "set_jira_context".to_string(),
+            arguments: json!({"project": "NOPE", "sprint": null}),
+        })
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("NOPE"), "{error}");
+
+    // Nothing changed, not even the entries that were valid
+    let result = call(&server, "get_jira_context", json!({})).await;
+    assert_eq!(result.content[0].text, "project: KEEP");
+
+    let result = call(&server, "set_jira_context", json!({"project": ""})).await;
+    assert!(result.content[0]
+        .text
+        .ends_with("No session context is set"));
+}
@@ -0,0 +1,245 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{GetIssueTool, SearchIssuesTool};
+use serde_json::{json, Value};
+
+fn config(api_base_url: &str, site_base_url: Option<&str>) -> JiraConfig {
+    JiraConfig {
+        api_base_url: api_base_url.to_string(),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token-123".to_string(),
+        site_base_url: site_base_url.map(ToString::to_string),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_site_url_is_derived_from_the_api_base_url() {
+    for (api_base_url, site) in [
+        (
+            "https://x.atlassian.net/rest/api/2",
+            "https://x.atlassian.net",
+        ),
+        (
+            "https://x.atlassian.net/rest/api/2/",
+            "https://x.atlassian.net",
+        ),
+        (
+            "https://x.atlassian.net/rest/api/3",
+            "https://x.atlassian.net",
+        ),
+        (
+            "https://x.atlassian.net/rest/api/latest",
+            "https://x.atlassian.net",
+        ),
+        (
+            "https://jira.example.com/jira/rest/api/2",
+            "https://jira.example.com/jira",
+        ),
+        (
+            "https://jira.example.com/jira/rest/api/2/",
+            "https://jira.example.com/jira",
+        ),
+        ("http://localhost:8080/rest/api/2", "http://localhost:8080"),
+        // Without the REST API suffix the URL is taken as the site root
+        ("https://jira.example.com", "https://jira.example.com"),
+        (
+            "https://jira.example.com/jira/",
+            "https://jira.example.com/jira",
+        ),
+        (
+            "https://jira.example.com/rest/api/2/issue",
+            "https://jira.example.com/rest/api/2/issue",
+        ),
+    ] {
+        assert_eq!(
+            config(api_base_url, None).site_url(),
+            site,
+            "{api_base_url}"
+        );
+    }
+}
+
+#[test]
+fn test_explicit_site_base_url_wins() {
+    for (site_base_url, site) in [
+        (
+            "https://jira.example.com/tools",
+            "https://jira.example.com/tools",
+        ),
+        (
+            "https://jira.example.com/tools/",
+            "https://jira.example.com/tools",
+        ),
+        (" https://jira.example.com ", "https://jira.example.com"),
+    ] {
+        let config = config("https://api.example.com/rest/api/2", Some(site_base_url));
+        assert_eq!(config.site_url(), site, "{site_base_url}");
+    }
+    // Blank is the same as unset
+    assert_eq!(
+        config("https://jira.example.com/jira/rest/api/2", Some(" ")).site_url(),
+        "https://jira.example.com/jira"
+    );
+}
+
+#[test]
+fn test_site_base_url_is_validated() {
+    let valid = config(
+        "https://jira.example.com/rest/api/2",
+        Some("https://jira.example.com"),
+    );
+    assert!(valid.validate().is_ok());
+    let invalid = config(
+        "https://jira.example.com/rest/api/2",
+        Some("jira.example.com"),
+    );
+    assert!(invalid.validate().is_err());
+}
+
+#[test]
+fn test_zephyr_api_base_url() {
+    for (api_base_url, site_base_url, zephyr) in [
+        (
+            "https://jira.example.com/rest/api/2",
+            None,
+            "https://jira.example.com/rest/zapi/latest",
+        ),
+        (
+            "https://jira.example.com/rest/api/2/",
+            None,
+            "https://jira.example.com/rest/zapi/latest",
+        ),
+        (
+            "https://jira.example.com/jira/rest/api/2",
+            None,
+            "https://jira.example.com/jira/rest/zapi/latest",
+        ),
+        (
+            "https://jira.example.com/rest/api/2",
+            Some("https://zephyr.example.com/"),
+            "https://zephyr.example.com/rest/zapi/latest",
+        ),
+    ] {
+        let client = JiraClient::new(config(api_base_url, site_base_url)).unwrap();
+        assert_eq!(client.zephyr_api_base_url(), zephyr, "{api_base_url}");
+    }
+}
+
+#[tokio::test]
+async fn test_zephyr_requests_go_to_the_site_root() {
+    // Regression: Zephyr was called under /rest/api/2/rest/zapi/latest
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/jira/rest/zapi/latest/teststep/5")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"id": 5}).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client =
+        JiraClient::new(config(&format!("{}/jira/rest/api/2/", server.url()), None)).unwrap();
+    for endpoint in ["teststep/5", "/teststep/5"] {
+        let step: serde_json::Value = client.zephyr_get(endpoint).await.unwrap();
+        assert_eq!(step["id"], 5, "{endpoint}");
+    }
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_browse_url_shapes() {
+    for (api_base_url, site_base_url, browse) in [
+        // Cloud
+        (
+            "https://x.atlassian.net/rest/api/3",
+            None,
+            "https://x.atlassian.net/browse/PROJ-1",
+        ),
+        // Data Center at the root
+        (
+            "https://jira.example.com/rest/api/2/",
+            None,
+            "https://jira.example.com/browse/PROJ-1",
+        ),
+        // Data Center under a context path
+        (
+            "https://jira.example.com/jira/rest/api/2",
+            None,
+            "https://jira.example.com/jira/browse/PROJ-1",
+        ),
+        (
+            "https://api.example.com/rest/api/2",
+            Some("https://jira.example.com/"),
+            "https://jira.example.com/browse/PROJ-1",
+        ),
+    ] {
+        let config = config(api_base_url, site_base_url);
+        assert_eq!(config.browse_url("PROJ-1"), browse, "{api_base_url}");
+        let client = JiraClient::new(config).unwrap();
+        assert_eq!(client.browse_url("PROJ-1"), browse, "{api_base_url}");
+    }
+}
+
+#[tokio::test]
+async fn test_issue_output_links_to_the_browse_page_on_context_path_installs() {
+    let mut server = mockito::Server::new_async().await;
+    let issue = json!({
+        "id": "10001",
+        "key": "CTX-1",
+        "self": format!("{}/jira/rest/api/2/issue/10001", server.url()),
+        "fields": {"summary": "Broken link", "status": {"name": "Open"}}
+    });
+    let _issue = server
+        .mock("GET", "/jira/rest/api/2/issue/CTX-1")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue.to_string())
+        .create_async()
+        .await;
+    let _search = server
+        .mock("GET", "/jira/rest/api/2/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"startAt": 0, "maxResults": 50, "total": 1, "issues": [issue]}).to_string(),
+        )
+        .create_async()
+        .await;
+    let config = config(&format!("{}/jira/rest/api/2", server.url()), None);
+    let browse = format!("{}/jira/browse/CTX-1", server.url());
+
+    let result = GetIssueTool::new(config.clone())
+        .handle(json!({"issue_key": "CTX-1"}))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(text.contains(&format!("\nURL: {browse}")), "{text}");
+    assert!(!text.contains("/rest/api/"), "{text}");
+
+    let tool = SearchIssuesTool::new(config);
+    let result = tool
+        .handle(json!({"jql": "project = CTX", "bypass_cache": true}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[1].text,
+        format!("• CTX-1 - Broken link (Open)\n  URL: {browse}\n")
+    );
+
+    // JSON output keeps the REST URL
+    let result = tool
+        .handle(json!({"jql": "project = CTX", "bypass_cache": true, "format": "json"}))
+        .await
+        .unwrap();
+    let issues: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(
+        issues[0]["self"],
+        format!("{}/jira/rest/api/2/issue/10001", server.url())
+    );
+}
@@ -0,0 +1,231 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::sprint_report::{detect_story_points_field, SprintIssue, SprintReport};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::SprintReportTool;
+use rust_jira_mcp::types::jira::JiraSprint;
+use rust_jira_mcp::utils::datetime::{parse_timestamp, DateFormatter};
+use serde_json::json;
+
+const SPRINT_FIXTURE: &str = include_str!("fixtures/sprint_report_issues.json");
+const POINTS_FIELD: &str = "customfield_10016";
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn fixture_report(points_field: Option<&str>) -> SprintReport {
+    let fixture: serde_json::Value = serde_json::from_str(SPRINT_FIXTURE).unwrap();
+    let sprint: JiraSprint = serde_json::from_value(fixture["sprint"].clone()).unwrap();
+    let issues = fixture["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|issue| SprintIssue::from_json(issue, &sprint, points_field))
+        .collect();
+    SprintReport::new(sprint, issues, points_field.map(String::from))
+}
+
+fn keys<'a>(issues: impl Iterator<Item = &'a SprintIssue>) -> Vec<&'a str> {
+    issues.map(|i| i.key.as_str()).collect()
+}
+
+#[test]
+fn test_parse_both_timestamp_formats() {
+    let rest = parse_timestamp("2024-03-04T10:00:00.000+0100").unwrap();
+    let agile = parse_timestamp("2024-03-04T09:00:00.000Z").unwrap();
+    assert_eq!(rest, agile);
+    assert!(parse_timestamp("last tuesday").is_none());
+}
+
+#[test]
+fn test_classifies_fixture_issues() {
+    let report = fixture_report(Some(POINTS_FIELD));
+
+    // SPR-1 joined before the sprint started (carried in from sprint 41)
+    assert_eq!(
+        keys(report.committed()),
+        vec!["SPR-1", "SPR-2", "SPR-4", "SPR-5"]
+    );
+    // SPR-3 was added via the changelog, SPR-6 was created into the sprint
+    assert_eq!(keys(report.added()), vec!["SPR-3", "SPR-6"]);
+    assert_eq!(keys(report.completed()), vec!["SPR-1", "SPR-2", "SPR-3"]);
+    // SPR-5 is done now, but was resolved after the sprint was completed
+    assert_eq!(
+        keys(report.not_completed()),
+        vec!["SPR-4", "SPR-5", "SPR-6"]
+    );
+}
+
+#[test]
+fn test_point_totals() {
+    let report = fixture_report(Some(POINTS_FIELD));
+
+    assert!((SprintReport::points(report.committed()) - 16.0).abs() < f64::EPSILON);
+    assert!((SprintReport::points(report.added()) - 3.0).abs() < f64::EPSILON);
+    assert!((SprintReport::points(report.not_completed()) - 9.0).abs() < f64::EPSILON);
+    assert!((report.velocity() - 10.0).abs() < f64::EPSILON);
+    // 8 of the 16 committed points were completed
+    assert!((report.commitment_completion_percent().unwrap() - 50.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_totals_per_assignee() {
+    let report = fixture_report(Some(POINTS_FIELD));
+    let totals = report.by_assignee();
+
+    let summary: Vec<(&str, usize, usize, f64, f64)> = totals
+        .iter()
+        .map(|t| {
+            (
+                t.assignee.as_str(),
+                t.issues,
+                t.completed,
+                t.points,
+                t.completed_points,
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("Alice Example", 2, 2, 7.0, 7.0),
+            ("Bob Example", 2, 1, 11.0, 3.0),
+            ("Carol Example", 1, 0, 1.0, 0.0),
+            ("Unassigned", 1, 0, 0.0, 0.0),
+        ]
+    );
+}
+
+#[test]
+fn test_markdown_report() {
+    let markdown = fixture_report(Some(POINTS_FIELD)).to_markdown(&DateFormatter::default());
+
+    assert!(markdown.starts_with("# Sprint report: Sprint 42\n"));
+    assert!(markdown.contains("Goal: Ship self-service signup"));
+    assert!(markdown.contains("| Committed | 4 | 16 |"));
+    assert!(markdown.contains("| Added mid-sprint | 2 | 3 |"));
+    assert!(markdown.contains("| Completed | 3 | 10 |"));
+    assert!(markdown.contains("| Not completed | 3 | 9 |"));
+    assert!(markdown.contains("Velocity: 10 points completed (50% of committed points)"));
+    assert!(markdown.contains("| Bob Example | 2 | 1 | 11 | 3 |"));
+    assert!(markdown.contains("## Not completed (carried over) (3)"));
+    assert!(markdown.contains("- SPR-4 Reporting dashboard (8 pts) — Bob Example"));
+}
+
+#[test]
+fn test_markdown_without_points_field() {
+    let markdown = fixture_report(None).to_markdown(&DateFormatter::default());
+
+    assert!(markdown.contains("| Committed | 4 |\n"));
+    assert!(markdown.contains("counting issues only"));
+    assert!(!markdown.contains("pts)"));
+}
+
+#[test]
+fn test_detect_story_points_field() {
+    let catalog = vec![
+        json!({"id": "summary", "name": "Summary"}),
+        json!({"id": "customfield_10026", "name": "Story point estimate"}),
+        json!({"id": "customfield_10016", "name": "Story Points"}),
+    ];
+    assert_eq!(
+        detect_story_points_field(&catalog).as_deref(),
+        Some("customfield_10016")
+    );
+    assert_eq!(
+        detect_story_points_field(&catalog[..2]).as_deref(),
+        Some("customfield_10026")
+    );
+    assert!(detect_story_points_field(&catalog[..1]).is_none());
+}
+
+#[tokio::test]
+async fn test_sprint_report_tool_for_active_sprint() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let fixture: serde_json::Value = serde_json::from_str(SPRINT_FIXTURE).unwrap();
+
+    let mut active = fixture["sprint"].clone();
+    active["state"] = json!("active");
+    active["completeDate"] = json!(null);
+    let _sprints = server
+        .mock("GET", "/rest/api/2/agile/1.0/board/7/sprint")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"values": [
+                {"id": 41, "self": "x", "state": "closed", "name": "Sprint 41"},
+                active
+            ]})
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let _catalog = server
+        .mock("GET", "/rest/api/2/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([{"id": POINTS_FIELD, "name": "Story Points"}]).to_string())
+        .create_async()
+        .await;
+
+    let issues = fixture["issues"].as_array().unwrap();
+    let first_page = server
+        .mock("GET", "/rest/api/2/agile/1.0/sprint/42/issue")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("startAt".to_string(), "0".to_string()),
+            mockito::Matcher::UrlEncoded("expand".to_string(), "changelog".to_string()),
+            mockito::Matcher::Regex(format!("fields=[^&]*{POINTS_FIELD}")),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"total": 6, "issues": issues[..3]}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let second_page = server
+        .mock("GET", "/rest/api/2/agile/1.0/sprint/42/issue")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "startAt".to_string(),
+            "3".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"total": 6, "issues": issues[3..]}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = SprintReportTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"board_id": 7, "sprint_id": "active"}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("# Sprint report: Sprint 42"));
+    // The sprint is still open, so SPR-5 counts as completed
+    assert!(text.contains("| Completed | 4 | 10 |"));
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_sprint_report_tool_rejects_bad_sprint_id() {
+    let tool = SprintReportTool::new(create_test_config("http://localhost:1"));
+    let result = tool
+        .handle(json!({"board_id": 7, "sprint_id": "latest"}))
+        .await;
+    assert!(result.is_err());
+}
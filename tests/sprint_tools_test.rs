@@ -15,6 +15,7 @@ async fn test_sprint_tools_creation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
 
     // Test that all sprint tools can be created without panicking
@@ -40,6 +41,7 @@ async fn test_sprint_tool_parameter_validation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
 
     let get_sprint_tool = GetSprintTool::new(config);
@@ -67,6 +69,7 @@ async fn test_create_sprint_tool_parameter_validation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
 
     let create_sprint_tool = CreateSprintTool::new(config);
@@ -107,6 +110,7 @@ async fn test_add_issues_to_sprint_tool_parameter_validation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
 
     let add_issues_tool = AddIssuesToSprintTool::new(config);
@@ -0,0 +1,340 @@
+use chrono::NaiveDate;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::timeline::{
+    build_timeline, timeline_series, IssueHistory, StatusCategories, StatusCategory, TimelineDay,
+};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::StatusTimelineTool;
+use rust_jira_mcp::types::jira::JiraStatus;
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn status(id: &str, name: &str, category: &str) -> serde_json::Value {
+    json!({
+        "self": format!("https://jira.example.com/rest/api/2/status/{id}"),
+        "id": id,
+        "name": name,
+        "statusCategory": {
+            "self": "https://jira.example.com/rest/api/2/statuscategory/1",
+            "id": 1,
+            "key": category,
+            "colorName": "blue",
+            "name": category
+        }
+    })
+}
+
+/// The status catalog as it is today: "Review" has since been renamed "Code Review"
+fn status_catalog() -> serde_json::Value {
+    json!([
+        status("1", "Open", "new"),
+        status("3", "In Progress", "indeterminate"),
+        status("10001", "Code Review", "indeterminate"),
+        status("6", "Closed", "done"),
+    ])
+}
+
+fn categories() -> StatusCategories {
+    let statuses: Vec<JiraStatus> = serde_json::from_value(status_catalog()).unwrap();
+    StatusCategories::from_statuses(&statuses)
+}
+
+fn change(created: &str, from: (&str, &str), to: (&str, &str)) -> serde_json::Value {
+    json!({
+        "created": created,
+        "items": [{
+            "field": "status",
+            "from": from.0,
+            "fromString": from.1,
+            "to": to.0,
+            "toString": to.1
+        }]
+    })
+}
+
+fn issue(
+    key: &str,
+    created: &str,
+    current: &str,
+    histories: Vec<serde_json::Value>,
+) -> serde_json::Value {
+    json!({
+        "key": key,
+        "fields": {
+            "created": created,
+            "status": {"id": "0", "name": current, "statusCategory": {"key": match current {
+                "Closed" => "done",
+                "Open" => "new",
+                _ => "indeterminate",
+            }}}
+        },
+        "changelog": {"histories": histories}
+    })
+}
+
+fn synthetic_issues() -> Vec<serde_json::Value> {
+    vec![
+        // Walks through every category, passing a status that was renamed since
+        issue(
+            "TL-1",
+            "2024-05-01T10:00:00.000+0000",
+            "Closed",
+            vec![
+                change(
+                    "2024-05-04T12:00:00.000+0000",
+                    ("10001", "Review"),
+                    ("6", "Closed"),
+                ),
+                change(
+                    "2024-05-02T09:00:00.000+0000",
+                    ("1", "Open"),
+                    ("3", "In Progress"),
+                ),
+                change(
+                    "2024-05-03T09:00:00.000+0000",
+                    ("3", "In Progress"),
+                    ("10001", "Review"),
+                ),
+            ],
+        ),
+        // Created inside the window, never moved
+        issue("TL-2", "2024-05-02T15:00:00.000+0000", "Open", vec![]),
+        // Resolved late on the 3rd in UTC-2, which is already the 4th in UTC
+        issue(
+            "TL-3",
+            "2024-04-20T08:00:00.000+0000",
+            "Closed",
+            vec![change(
+                "2024-05-03T23:30:00.000-0200",
+                ("1", "Open"),
+                ("6", "Closed"),
+            )],
+        ),
+        // Created after the window
+        issue("TL-4", "2024-05-06T08:00:00.000+0000", "Open", vec![]),
+    ]
+}
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+}
+
+fn counts(days: &[TimelineDay]) -> Vec<(usize, usize, usize)> {
+    days.iter()
+        .map(|d| (d.to_do, d.in_progress, d.done))
+        .collect()
+}
+
+#[test]
+fn test_status_categories_prefer_ids_over_names() {
+    let categories = categories();
+
+    // The old name no longer exists, but the id still maps to its category
+    assert_eq!(
+        categories.category(Some("10001"), Some("Review")),
+        Some(StatusCategory::InProgress)
+    );
+    assert_eq!(
+        categories.category(None, Some("closed")),
+        Some(StatusCategory::Done)
+    );
+    assert_eq!(categories.category(Some("999"), Some("Gone")), None);
+}
+
+#[test]
+fn test_issue_history_orders_transitions() {
+    let history = IssueHistory::from_json(&synthetic_issues()[0], &categories()).unwrap();
+
+    assert_eq!(history.initial, StatusCategory::ToDo);
+    let categories: Vec<StatusCategory> = history.transitions.iter().map(|(_, c)| *c).collect();
+    assert_eq!(
+        categories,
+        vec![
+            StatusCategory::InProgress,
+            StatusCategory::InProgress,
+            StatusCategory::Done
+        ]
+    );
+    assert_eq!(
+        history.category_at(
+            "2024-04-30T00:00:00Z"
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .unwrap()
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_daily_buckets() {
+    let categories = categories();
+    let histories: Vec<IssueHistory> = synthetic_issues()
+        .iter()
+        .filter_map(|issue| IssueHistory::from_json(issue, &categories))
+        .collect();
+
+    let days = build_timeline(&histories, date(1), date(5));
+
+    assert_eq!(days.len(), 5);
+    assert_eq!(days[0].date, date(1));
+    assert_eq!(
+        counts(&days),
+        vec![(2, 0, 0), (2, 1, 0), (2, 1, 0), (1, 0, 2), (1, 0, 2)]
+    );
+}
+
+#[test]
+fn test_issue_without_changelog_uses_current_status() {
+    let history = IssueHistory::from_json(
+        &issue("TL-9", "2024-05-01T00:00:00.000+0000", "Closed", vec![]),
+        &categories(),
+    )
+    .unwrap();
+    let days = build_timeline(std::slice::from_ref(&history), date(1), date(2));
+    assert_eq!(counts(&days), vec![(0, 0, 1), (0, 0, 1)]);
+}
+
+#[test]
+fn test_series_json() {
+    let days = vec![
+        TimelineDay {
+            date: date(1),
+            to_do: 3,
+            in_progress: 1,
+            done: 0,
+        },
+        TimelineDay {
+            date: date(2),
+            to_do: 2,
+            in_progress: 1,
+            done: 1,
+        },
+    ];
+
+    assert_eq!(
+        timeline_series(&days),
+        json!({
+            "dates": ["2024-05-01", "2024-05-02"],
+            "series": {
+                "To Do": [3, 2],
+                "In Progress": [1, 1],
+                "Done": [0, 1]
+            }
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_status_timeline_tool() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _statuses = server
+        .mock("GET", "/rest/api/2/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(status_catalog().to_string())
+        .create_async()
+        .await;
+    let search = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded(
+                "jql".to_string(),
+                "project = TL ORDER BY key ASC".to_string(),
+            ),
+            mockito::Matcher::UrlEncoded("expand".to_string(), "changelog".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"total": 4, "issues": synthetic_issues()}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = StatusTimelineTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "jql": "project = TL",
+            "start_date": "2024-05-01",
+            "end_date": "2024-05-05"
+        }))
+        .await
+        .unwrap();
+
+    let table = &result.content[0].text;
+    assert!(table.starts_with("Status timeline for 4 issues matching `project = TL`"));
+    assert!(table.contains("\nSorted by key (ORDER BY key ASC added) so pages could not shift\n"));
+    assert!(table.contains("| 2024-05-04 | 1 | 0 | 2 | 3 |"));
+
+    let series: serde_json::Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(series["series"]["Done"], json!([0, 0, 0, 2, 2]));
+    search.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_status_timeline_caps_issue_count() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _statuses = server
+        .mock("GET", "/rest/api/2/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(status_catalog().to_string())
+        .create_async()
+        .await;
+    let search = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"total": 5000, "issues": []}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = StatusTimelineTool::new(create_test_config(&base_url));
+    let error = tool
+        .handle(json!({
+            "jql": "project = BIG",
+            "start_date": "2024-05-01",
+            "end_date": "2024-05-05"
+        }))
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("matches 5000 issues, more than the limit of 500"));
+    search.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_status_timeline_validates_dates() {
+    let tool = StatusTimelineTool::new(create_test_config("http://localhost:1"));
+
+    let reversed = tool
+        .handle(json!({"jql": "x", "start_date": "2024-05-05", "end_date": "2024-05-01"}))
+        .await;
+    assert!(reversed.is_err());
+
+    let malformed = tool
+        .handle(json!({"jql": "x", "start_date": "05/01/2024"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(malformed.contains("YYYY-MM-DD"));
+}
@@ -0,0 +1,98 @@
+use rust_jira_mcp::mcp::transport::stdio::{Framing, MessageStream};
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+fn framed(message: &str) -> String {
+    format!("Content-Length: {}\r\n\r\n{message}", message.len())
+}
+
+#[tokio::test]
+async fn test_newline_delimited_messages() {
+    let input = "\n  {\"id\": 1}\n\n[{\"id\": 2}]\r\n";
+    let mut output = Vec::new();
+    let mut stream = MessageStream::new(input.as_bytes(), &mut output);
+
+    assert_eq!(stream.framing(), None);
+    assert_eq!(stream.read_message().await.unwrap().unwrap(), "{\"id\": 1}");
+    assert_eq!(stream.framing(), Some(Framing::NewlineDelimited));
+    assert_eq!(
+        stream.read_message().await.unwrap().unwrap(),
+        "[{\"id\": 2}]"
+    );
+    assert_eq!(stream.read_message().await.unwrap(), None);
+
+    stream.write_message("{\"ok\":true}").await.unwrap();
+    assert_eq!(output, b"{\"ok\":true}\n");
+}
+
+#[tokio::test]
+async fn test_content_length_framed_messages() {
+    // Headers are matched without regard to case, other headers are ignored
+    // and a body may hold newlines and multi-byte characters
+    let second = "{\n  \"id\": 2,\n  \"name\": \"Zoë\"\n}";
+    let input = format!(
+        "{}content-length: {}\r\nContent-Type: application/vscode-jsonrpc; charset=utf-8\r\n\r\n{second}",
+        framed("{\"id\":1}"),
+        second.len()
+    );
+    let mut output = Vec::new();
+    let mut stream = MessageStream::new(input.as_bytes(), &mut output);
+
+    assert_eq!(stream.read_message().await.unwrap().unwrap(), "{\"id\":1}");
+    assert_eq!(stream.framing(), Some(Framing::ContentLength));
+    assert_eq!(stream.read_message().await.unwrap().unwrap(), second);
+    assert_eq!(stream.read_message().await.unwrap(), None);
+
+    stream.write_message("{\"name\":\"Zoë\"}").await.unwrap();
+    assert_eq!(output, framed("{\"name\":\"Zoë\"}").as_bytes());
+}
+
+#[tokio::test]
+async fn test_malformed_header_blocks_are_errors() {
+    for input in [
+        "Content-Length: ten\r\n\r\n{}",
+        "Content-Type: application/json\r\n\r\n{}",
+        "Content-Length 2\r\n\r\n{}",
+        "Content-Length: 2\r\n",
+    ] {
+        let mut stream = MessageStream::new(input.as_bytes(), Vec::new());
+        assert!(stream.read_message().await.is_err(), "{input:?}");
+    }
+}
+
+#[tokio::test]
+async fn test_messages_larger_than_the_read_buffer() {
+    // Far beyond the 8 KiB buffer of BufReader and the pipe, so every message
+    // arrives in many chunks
+    let big = json!({"id": 1, "padding": "x".repeat(100_000)}).to_string();
+    for framing in [Framing::NewlineDelimited, Framing::ContentLength] {
+        let (client, server) = tokio::io::duplex(1024);
+        let (server_read, server_write) = tokio::io::split(server);
+        let (mut client_read, mut client_write) = tokio::io::split(client);
+
+        let input = match framing {
+            Framing::NewlineDelimited => format!("{big}\n{big}\n"),
+            Framing::ContentLength => framed(&big).repeat(2),
+        };
+        let sent = input.clone();
+        let writer = tokio::spawn(async move {
+            client_write.write_all(sent.as_bytes()).await.unwrap();
+            client_write.shutdown().await.unwrap();
+        });
+
+        let echo = tokio::spawn(async move {
+            let mut stream = MessageStream::new(server_read, server_write);
+            while let Some(message) = stream.read_message().await.unwrap() {
+                stream.write_message(&message).await.unwrap();
+            }
+            stream.framing()
+        });
+
+        let mut echoed = String::new();
+        client_read.read_to_string(&mut echoed).await.unwrap();
+        writer.await.unwrap();
+        assert_eq!(echo.await.unwrap(), Some(framing));
+        // Echoed in the framing it was read in
+        assert!(echoed == input, "{framing:?} echo differs");
+    }
+}
@@ -0,0 +1,272 @@
+use chrono::{TimeZone, Utc};
+use mockito::{Matcher, Mock, ServerGuard};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::worklogs::worklog_provider;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{AddWorkLogTool, DeleteWorkLogTool, GetIssueWorkLogsTool};
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "tempo-test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        worklog_provider: Some("tempo".to_string()),
+        tempo_api_url: Some(format!("{base_url}/tempo/4")),
+        tempo_api_token: Some("tempo-token".to_string()),
+        ..Default::default()
+    }
+}
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(format!("tests/fixtures/{name}")).unwrap()
+}
+
+async fn issue_mock(server: &mut ServerGuard) -> Mock {
+    server
+        .mock("GET", "/rest/api/2/issue/TT-1")
+        .match_query(Matcher::UrlEncoded("fields".into(), "timetracking".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10100",
+                "key": "TT-1",
+                "fields": {"timetracking": {"remainingEstimateSeconds": 14400}}
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await
+}
+
+async fn user_mock(server: &mut ServerGuard, account_id: &str, name: &str) -> Mock {
+    server
+        .mock("GET", "/rest/api/2/user")
+        .match_query(Matcher::UrlEncoded("accountId".into(), account_id.into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"accountId": account_id, "displayName": name, "active": true}).to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+async fn worklog_page_mocks(server: &mut ServerGuard) -> (Mock, Mock) {
+    let first = server
+        .mock("GET", "/tempo/4/worklogs/issue/10100")
+        .match_query(Matcher::UrlEncoded("offset".into(), "0".into()))
+        .match_header("authorization", "Bearer tempo-token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("tempo_worklogs_page1.json"))
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/tempo/4/worklogs/issue/10100")
+        .match_query(Matcher::UrlEncoded("offset".into(), "2".into()))
+        .match_header("authorization", "Bearer tempo-token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("tempo_worklogs_page2.json"))
+        .create_async()
+        .await;
+    (first, second)
+}
+
+#[tokio::test]
+async fn test_tempo_worklogs_read_like_jira_worklogs() {
+    let mut server = mockito::Server::new_async().await;
+    let _issue = issue_mock(&mut server).await;
+    let alice = user_mock(&mut server, "acc-alice", "Alice Smith").await;
+    let bob = user_mock(&mut server, "acc-bob", "Bob Jones").await;
+    let (first, second) = worklog_page_mocks(&mut server).await;
+    let jira_worklogs = server
+        .mock("GET", "/rest/api/2/issue/TT-1/worklog")
+        .match_query(Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = GetIssueWorkLogsTool::new(create_test_config(&server.url()))
+        .handle(json!({"issue_key": "TT-1"}))
+        .await
+        .unwrap();
+
+    let text: String = result.content.iter().map(|c| c.text.as_str()).collect();
+    assert!(
+        text.starts_with("Found 3 work logs for issue TT-1: 2h 30m logged"),
+        "{text}"
+    );
+    assert!(text.contains("• 126 - 1h by Alice Smith"), "{text}");
+    assert!(text.contains("• 127 - 30m by Bob Jones"), "{text}");
+    assert!(text.contains("• 128 - 1h by Alice Smith"), "{text}");
+    assert!(text.contains("Code review"), "{text}");
+    // Alice's two worklogs are resolved to her Jira account once
+    alice.assert_async().await;
+    bob.assert_async().await;
+    first.assert_async().await;
+    second.assert_async().await;
+    jira_worklogs.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_tempo_report_aggregates_by_jira_author() {
+    let mut server = mockito::Server::new_async().await;
+    let _issue = issue_mock(&mut server).await;
+    let _alice = user_mock(&mut server, "acc-alice", "Alice Smith").await;
+    let _bob = user_mock(&mut server, "acc-bob", "Bob Jones").await;
+    let (_first, _second) = worklog_page_mocks(&mut server).await;
+
+    let provider = worklog_provider(&create_test_config(&server.url())).unwrap();
+    let report = provider
+        .report(
+            &["TT-1".to_string()],
+            Some(Utc.with_ymd_and_hms(2025, 10, 16, 0, 0, 0).unwrap()),
+            None,
+            1000,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(report.worklogs, 2, "worklog 126 started before the range");
+    assert_eq!(report.total_seconds, 5400);
+    assert_eq!(
+        report.by_author,
+        vec![
+            ("Alice Smith".to_string(), 3600),
+            ("Bob Jones".to_string(), 1800)
+        ]
+    );
+    assert_eq!(report.by_issue, vec![("TT-1".to_string(), 5400)]);
+}
+
+#[tokio::test]
+async fn test_tempo_data_center_workers_map_to_jira_users() {
+    let mut server = mockito::Server::new_async().await;
+    let _issue = issue_mock(&mut server).await;
+    let _worklogs = server
+        .mock("GET", "/tempo/4/worklogs/issue/10100")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(format!("[{}]", fixture("tempo_worklog_dc.json")))
+        .create_async()
+        .await;
+    let worker = server
+        .mock("GET", "/rest/api/2/user")
+        .match_query(Matcher::UrlEncoded("key".into(), "JIRAUSER10000".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"key": "JIRAUSER10000", "name": "csmith", "displayName": "Carol Smith"})
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let provider = worklog_provider(&create_test_config(&server.url())).unwrap();
+    let (work_logs, total) = provider.get_work_logs("TT-1", None, 1000).await.unwrap();
+
+    assert_eq!(total, 1);
+    assert_eq!(work_logs[0]["id"], "5001");
+    assert_eq!(work_logs[0]["author"]["displayName"], "Carol Smith");
+    assert_eq!(work_logs[0]["started"], "2025-10-17T08:00:00.000+0000");
+    assert_eq!(work_logs[0]["timeSpent"], "2h");
+    assert_eq!(work_logs[0]["comment"], "Release checklist");
+    worker.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_add_and_delete_tempo_worklog() {
+    let mut server = mockito::Server::new_async().await;
+    let _issue = issue_mock(&mut server).await;
+    let _alice = user_mock(&mut server, "acc-alice", "Alice Smith").await;
+    let _myself = server
+        .mock("GET", "/rest/api/2/myself")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"accountId": "acc-alice", "displayName": "Alice Smith"}).to_string())
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/tempo/4/worklogs")
+        .match_header("authorization", "Bearer tempo-token")
+        .match_body(Matcher::Json(json!({
+            "issueId": 10100,
+            "authorAccountId": "acc-alice",
+            "timeSpentSeconds": 5400,
+            "startDate": "2025-10-17",
+            "startTime": "09:30:00",
+            "description": "Pairing on the importer",
+            "remainingEstimateSeconds": 14400
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fixture("tempo_worklog_created.json"))
+        .expect(1)
+        .create_async()
+        .await;
+    let delete = server
+        .mock("DELETE", "/tempo/4/worklogs/129")
+        .match_header("authorization", "Bearer tempo-token")
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+    let config = create_test_config(&server.url());
+
+    // The schema is Jira's: durations and estimate adjustments are mapped
+    let result = AddWorkLogTool::new(config.clone())
+        .handle(json!({
+            "issue_key": "TT-1",
+            "time_spent": "1.5h",
+            "started": "2025-10-17T09:30:00.000+0200",
+            "comment": "Pairing on the importer",
+            "adjust_estimate": "leave"
+        }))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(text.contains("Work log ID: 129"), "{text}");
+    assert!(text.contains("Author: Alice Smith"), "{text}");
+
+    let result = DeleteWorkLogTool::new(config)
+        .handle(json!({"issue_key": "TT-1", "work_log_id": "129"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Work log 129 deleted successfully from issue TT-1"
+    );
+    create.assert_async().await;
+    delete.assert_async().await;
+}
+
+#[test]
+fn test_tempo_needs_a_token() {
+    let config = JiraConfig {
+        tempo_api_token: None,
+        ..create_test_config("https://jira.example.com")
+    };
+    assert!(config.validate().is_err());
+    let error = worklog_provider(&config).err().unwrap().to_string();
+    assert!(error.contains("JIRA_TEMPO_API_TOKEN"), "{error}");
+
+    let jira = JiraConfig {
+        worklog_provider: Some("jira".to_string()),
+        ..config.clone()
+    };
+    assert!(worklog_provider(&jira).is_ok());
+
+    let unknown = JiraConfig {
+        worklog_provider: Some("harvest".to_string()),
+        ..config
+    };
+    let error = unknown.validate().unwrap_err().to_string();
+    assert!(error.contains("harvest"), "{error}");
+}
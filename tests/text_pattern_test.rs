@@ -0,0 +1,70 @@
+use rust_jira_mcp::utils::pattern::TextPattern;
+
+fn matches(pattern: &str, text: &str) -> bool {
+    TextPattern::new(pattern).unwrap().is_match(text)
+}
+
+#[test]
+fn test_ticket_references() {
+    let pattern = "[A-Z][A-Z0-9]+-[0-9]+";
+    assert!(matches(pattern, "Fixed by OPS-12"));
+    assert!(matches(pattern, "SEC2-1 then"));
+    assert!(!matches(pattern, "Fixed by ops-12"));
+    assert!(!matches(pattern, "Fixed by O-12"));
+    assert!(!matches(pattern, "Fixed"));
+
+    assert!(matches(r"\w+-\d{1,5}$", "see ABC-12345"));
+    assert!(!matches(r"\w+-\d{1,5}$", "see ABC-12345."));
+}
+
+#[test]
+fn test_syntax() {
+    assert!(matches("^(fix|close)(es|ed)? #?\\d+", "fixes #42"));
+    assert!(matches("^(fix|close)(es|ed)? #?\\d+", "fix 7"));
+    assert!(!matches("^(fix|close)(es|ed)? #?\\d+", "we fix 7"));
+    assert!(matches("a.c", "abc"));
+    assert!(!matches("a.c", "a\nc"));
+    assert!(matches("[^a-z ]", "lower UPPER"));
+    assert!(!matches("[^a-z ]", "lower case"));
+    assert!(matches(r"\S+\s\S+", "two words"));
+    assert!(matches(r"\$\d+\.\d\d", "costs $10.50"));
+    assert!(matches("(a*)*b", "aaab"));
+    assert!(!matches("(a*)*b", "aaaa"));
+    assert!(matches("x{2,}", "axxb"));
+    assert!(!matches("x{2,}", "axb"));
+    assert!(matches("", "anything"));
+    assert!(matches("^$", ""));
+}
+
+#[test]
+fn test_invalid_patterns() {
+    for pattern in [
+        "(", "(a", "[a-", "[z-a]", "*a", "a{2", "a{3,1}", r"\q", "(?i)a", "a)",
+    ] {
+        assert!(TextPattern::new(pattern).is_err(), "{pattern}");
+    }
+}
+
+#[test]
+fn test_replace_all() {
+    let replace = |pattern: &str, text: &str, replacement: &str| {
+        TextPattern::new(pattern)
+            .unwrap()
+            .replace_all(text, replacement)
+    };
+    assert_eq!(
+        replace(r"\d{3}-\d{4}", "call 555-1234 or 555-9876.", "###"),
+        "call ### or ###."
+    );
+    assert_eq!(
+        replace(
+            "https://[a-z.]+/browse/",
+            "see https://jira.example.com/browse/AB-1",
+            "https://go/?to=$0"
+        ),
+        "see https://go/?to=https://jira.example.com/browse/AB-1"
+    );
+    assert_eq!(replace("x*", "ab", "-"), "-a-b-");
+    assert_eq!(replace("^a", "aaa", "b"), "baa");
+    assert_eq!(replace("é+", "café éé", "e"), "cafe e");
+}
@@ -0,0 +1,175 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::SearchTextTool;
+use rust_jira_mcp::utils::snippets::{find_matches, find_snippets};
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        ..Default::default()
+    }
+}
+
+fn excerpts(text: &str, query: &str, context: usize) -> Vec<String> {
+    find_snippets(text, query, context, 3)
+        .into_iter()
+        .map(|s| s.excerpt)
+        .collect()
+}
+
+#[test]
+fn test_find_matches_is_case_insensitive() {
+    assert_eq!(
+        find_matches("Timeout, then TIMEOUT again", "timeout"),
+        vec![0, 14]
+    );
+    assert_eq!(find_matches("aaaa", "aa"), vec![0, 2]);
+    assert!(find_matches("short", "").is_empty());
+    assert!(find_matches("ab", "abc").is_empty());
+}
+
+#[test]
+fn test_snippet_context_and_ellipses() {
+    let text = "The login page throws a timeout error after the upgrade";
+    assert_eq!(
+        excerpts(text, "timeout", 10),
+        vec!["…throws a **timeout** error aft…"]
+    );
+    assert_eq!(excerpts(text, "the login", 6), vec!["**The login** page…"]);
+}
+
+#[test]
+fn test_snippets_are_unicode_safe() {
+    let text = "Café crème brûlée 🍮 naïve Ünïcode test";
+    assert_eq!(excerpts(text, "ÜNÏCODE", 3), vec!["…ve **Ünïcode** te…"]);
+    assert_eq!(excerpts(text, "🍮", 2), vec!["…e **🍮** n…"]);
+}
+
+#[test]
+fn test_snippets_collapse_whitespace_and_skip_overlaps() {
+    let text = "first line\n\n  error here\nerror there";
+    assert_eq!(
+        excerpts(text, "error", 40),
+        vec!["first line **error** here error there"]
+    );
+}
+
+#[test]
+fn test_snippets_fall_back_to_words() {
+    let text = "The cache was flushed before the deploy";
+    assert_eq!(
+        excerpts(text, "deploy cache", 4),
+        vec!["The **cache** was…", "…the **deploy**"]
+    );
+}
+
+#[tokio::test]
+async fn test_search_jira_text_tool() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let search = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded(
+                "jql".to_string(),
+                "project = \"TEST\" AND text ~ \"say \\\"hi\\\"\" ORDER BY updated DESC"
+                    .to_string(),
+            ),
+            mockito::Matcher::UrlEncoded("maxResults".to_string(), "2".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "startAt": 0,
+                "maxResults": 2,
+                "total": 7,
+                "issues": [
+                    {
+                        "id": "1",
+                        "key": "TEST-1",
+                        "self": "https://jira.example.com/rest/api/2/issue/1",
+                        "fields": {
+                            "summary": "Greeting bug",
+                            "description": "The bot should say \"hi\" when a user joins"
+                        }
+                    },
+                    {
+                        "id": "2",
+                        "key": "TEST-2",
+                        "self": "https://jira.example.com/rest/api/2/issue/2",
+                        "fields": {"summary": "Unrelated", "description": null}
+                    }
+                ]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let comments_1 = server
+        .mock("GET", "/rest/api/2/issue/TEST-1/comment")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "maxResults".to_string(),
+            "50".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"comments": [{
+                "id": "100",
+                "body": "Confirmed, it does not say \"hi\" anymore",
+                "author": {"displayName": "Alice"},
+                "created": "2024-05-01T10:00:00.000+0000"
+            }]})
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let comments_2 = server
+        .mock("GET", "/rest/api/2/issue/TEST-2/comment")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"comments": []}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = SearchTextTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"query": "say \"hi\"", "project_key": "TEST", "max_issues": 2}))
+        .await
+        .unwrap();
+
+    assert!(result.content[0]
+        .text
+        .starts_with("Found 7 issues mentioning \"say \"hi\"\" (scanned 2"));
+    let first = &result.content[1].text;
+    assert!(first.contains("[description] The bot should **say \"hi\"** when a user joins"));
+    assert!(first.contains(
+        "[comment by Alice on 2024-05-01 10:00 +00:00] Confirmed, it does not **say \"hi\"** anymore"
+    ));
+    assert!(result.content[2].text.contains("not literally"));
+
+    search.assert_async().await;
+    comments_1.assert_async().await;
+    comments_2.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_search_jira_text_requires_query() {
+    let tool = SearchTextTool::new(create_test_config("http://localhost:1"));
+    assert!(tool.handle(json!({"query": "  "})).await.is_err());
+}
@@ -0,0 +1,145 @@
+use reqwest::header::{HeaderMap, HeaderValue};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::throttle::{
+    shared_throttle, AdaptiveThrottle, RateLimitHeaders, ThrottleState,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+const MIN: Duration = Duration::from_millis(100);
+const MAX: Duration = Duration::from_millis(2100);
+
+fn budget(remaining: u64, limit: u64) -> RateLimitHeaders {
+    RateLimitHeaders {
+        limit: Some(limit),
+        remaining: Some(remaining),
+        retry_after: None,
+    }
+}
+
+#[test]
+fn test_parse_rate_limit_headers() {
+    let mut headers = HeaderMap::new();
+    headers.insert("X-RateLimit-Limit", HeaderValue::from_static("1000"));
+    headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("42"));
+    headers.insert("Retry-After", HeaderValue::from_static("5"));
+
+    let parsed = RateLimitHeaders::from_headers(&headers);
+    assert_eq!(parsed.limit, Some(1000));
+    assert_eq!(parsed.remaining, Some(42));
+    assert_eq!(parsed.retry_after, Some(Duration::from_secs(5)));
+}
+
+#[test]
+fn test_parse_missing_and_malformed_headers() {
+    let mut headers = HeaderMap::new();
+    headers.insert("X-RateLimit-Remaining", HeaderValue::from_static("many"));
+
+    let parsed = RateLimitHeaders::from_headers(&headers);
+    assert_eq!(parsed, RateLimitHeaders::default());
+}
+
+#[test]
+fn test_plenty_of_budget_keeps_base_rate() {
+    let throttle = AdaptiveThrottle::new(MIN, MAX, 20);
+
+    for remaining in [1000, 800, 500, 250] {
+        throttle.observe(&budget(remaining, 1000));
+        assert_eq!(throttle.state(), ThrottleState::Normal);
+        assert_eq!(throttle.current_interval(), MIN);
+    }
+    assert!((throttle.effective_rate() - 10.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_slows_down_as_budget_drops() {
+    let throttle = AdaptiveThrottle::new(MIN, MAX, 20);
+
+    // Halfway through the threshold band the interval is halfway to the maximum
+    throttle.observe(&budget(100, 1000));
+    assert_eq!(throttle.state(), ThrottleState::Throttled);
+    assert_eq!(throttle.current_interval(), Duration::from_millis(1100));
+
+    throttle.observe(&budget(0, 1000));
+    assert_eq!(throttle.current_interval(), MAX);
+    assert!(throttle.effective_rate() < 1.0);
+}
+
+#[test]
+fn test_speeds_back_up_after_budget_recovers() {
+    let throttle = AdaptiveThrottle::new(MIN, MAX, 20);
+    throttle.observe(&budget(0, 1000));
+    assert_eq!(throttle.current_interval(), MAX);
+
+    let mut intervals = Vec::new();
+    for _ in 0..6 {
+        throttle.observe(&budget(900, 1000));
+        intervals.push(throttle.current_interval());
+    }
+
+    assert_eq!(intervals[0], Duration::from_millis(1050));
+    assert!(intervals.windows(2).all(|w| w[1] <= w[0]));
+    assert_eq!(*intervals.last().unwrap(), MIN);
+    assert_eq!(throttle.state(), ThrottleState::Normal);
+}
+
+#[test]
+fn test_retry_after_backs_off() {
+    let throttle = AdaptiveThrottle::new(MIN, MAX, 20);
+    throttle.observe(&RateLimitHeaders {
+        retry_after: Some(Duration::from_secs(30)),
+        ..budget(0, 1000)
+    });
+
+    assert_eq!(throttle.state(), ThrottleState::BackingOff);
+    assert_eq!(throttle.current_interval(), MAX);
+
+    // Budget headers arriving during the pause do not end the back-off early
+    throttle.observe(&budget(900, 1000));
+    assert_eq!(throttle.state(), ThrottleState::BackingOff);
+}
+
+#[test]
+fn test_snapshot_reports_effective_rate() {
+    let throttle = AdaptiveThrottle::new(MIN, MAX, 20);
+    throttle.observe(&budget(0, 500));
+
+    let snapshot = throttle.snapshot();
+    assert_eq!(snapshot.state, ThrottleState::Throttled);
+    assert_eq!(snapshot.interval_ms, 2100);
+    assert_eq!(snapshot.remaining, Some(0));
+    assert_eq!(snapshot.limit, Some(500));
+    assert!(snapshot.effective_rate_per_second > 0.0);
+}
+
+#[tokio::test]
+async fn test_wait_if_needed_spaces_requests() {
+    let throttle = AdaptiveThrottle::new(Duration::from_millis(50), MAX, 20);
+
+    let start = std::time::Instant::now();
+    for _ in 0..3 {
+        throttle.wait_if_needed().await;
+    }
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
+
+#[test]
+fn test_shared_throttle_per_instance() {
+    let config = JiraConfig {
+        api_base_url: "https://throttle-a.example.com/rest/api/2".to_string(),
+        ..Default::default()
+    };
+    let other = JiraConfig {
+        api_base_url: "https://throttle-b.example.com/rest/api/2".to_string(),
+        ..Default::default()
+    };
+
+    assert!(Arc::ptr_eq(
+        &shared_throttle(&config),
+        &shared_throttle(&config)
+    ));
+    assert!(!Arc::ptr_eq(
+        &shared_throttle(&config),
+        &shared_throttle(&other)
+    ));
+}
@@ -0,0 +1,104 @@
+//! The tool surface is compared against `tests/fixtures/tool_schemas.json`,
+//! so changes to it show up in review. After an intended change, refresh the
+//! file with `UPDATE_TOOL_SCHEMAS=1 cargo test --test tool_schemas_test`.
+
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPServer;
+use serde_json::Value;
+use std::path::Path;
+
+const GOLDEN: &str = "tests/fixtures/tool_schemas.json";
+
+fn create_test_config() -> JiraConfig {
+    JiraConfig {
+        api_base_url: "https://jira.example.com/rest/api/2".to_string(),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        ..Default::default()
+    }
+}
+
+fn tool_names(dump: &Value) -> Vec<&str> {
+    dump["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|tool| tool["name"].as_str())
+        .collect()
+}
+
+#[test]
+fn test_tool_schemas_match_golden_file() {
+    let server = MCPServer::new(create_test_config());
+    if std::env::var_os("UPDATE_TOOL_SCHEMAS").is_some() {
+        server.dump_tools(Path::new(GOLDEN)).unwrap();
+    }
+
+    let dumped_path = std::env::temp_dir().join(format!(
+        "tool_schemas_{}.json",
+        uuid::Uuid::new_v4().simple()
+    ));
+    let count = server.dump_tools(&dumped_path).unwrap();
+    let dumped = std::fs::read_to_string(&dumped_path).unwrap();
+    std::fs::remove_file(&dumped_path).unwrap();
+    let golden = std::fs::read_to_string(GOLDEN).unwrap();
+    if dumped == golden {
+        return;
+    }
+
+    let dumped: Value = serde_json::from_str(&dumped).unwrap();
+    let golden: Value = serde_json::from_str(&golden).unwrap();
+    assert_eq!(tool_names(&dumped).len(), count);
+    let changed: Vec<&str> = tool_names(&dumped)
+        .into_iter()
+        .filter(|name| {
+            let find = |dump: &Value| {
+                dump["tools"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .find(|tool| tool["name"] == *name)
+                    .cloned()
+            };
+            find(&dumped) != find(&golden)
+        })
+        .collect();
+    let removed: Vec<&str> = tool_names(&golden)
+        .into_iter()
+        .filter(|name| !tool_names(&dumped).contains(name))
+        .collect();
+    panic!(
+        "The tool surface differs from {GOLDEN}: added or changed {changed:?}, removed {removed:?}. \
+         If that is intended, run UPDATE_TOOL_SCHEMAS=1 cargo test --test tool_schemas_test"
+    );
+}
+
+#[test]
+fn test_dump_follows_config_flags() {
+    let path = std::env::temp_dir().join(format!(
+        "tool_schemas_{}.json",
+        uuid::Uuid::new_v4().simple()
+    ));
+    let config = JiraConfig {
+        jsm_enabled: Some(true),
+        ..create_test_config()
+    };
+    let server = MCPServer::new(config);
+    let count = server.dump_tools(&path).unwrap();
+    let dump: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(count, server.available_tools().len());
+    let names = tool_names(&dump);
+    assert!(names.contains(&"get_jsm_service_desks"));
+    // Annotations and categories are part of the dump
+    let first = &dump["tools"][0];
+    assert!(first.get("annotations").is_some(), "{first}");
+    assert!(first.get("_meta").is_some(), "{first}");
+
+    let without_jsm = MCPServer::new(create_test_config()).available_tools();
+    assert!(!without_jsm
+        .iter()
+        .any(|tool| tool.name == "get_jsm_service_desks"));
+    assert!(without_jsm.len() < count);
+}
@@ -0,0 +1,197 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::traceability::build_matrix;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::zephyr_tools::ZephyrTraceabilityReportTool;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(2),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn requirement(key: &str, summary: &str) -> Value {
+    json!({"key": key, "fields": {"summary": summary}})
+}
+
+fn link(type_name: &str, outward_key: Option<&str>, inward_key: Option<&str>) -> Value {
+    let (inward, outward) = match type_name {
+        "Tests" => ("is tested by", "tests"),
+        _ => ("relates to", "relates to"),
+    };
+    let mut link = json!({
+        "id": "1",
+        "type": {"name": type_name, "inward": inward, "outward": outward}
+    });
+    if let Some(key) = outward_key {
+        link["outwardIssue"] = json!({"key": key});
+    }
+    if let Some(key) = inward_key {
+        link["inwardIssue"] = json!({"key": key});
+    }
+    link
+}
+
+fn test_case(key: &str, links: Vec<Value>) -> Value {
+    json!({"key": key, "fields": {"summary": format!("Test {key}"), "issuelinks": links}})
+}
+
+#[test]
+fn test_build_matrix_joins_tests_and_requirements() {
+    let requirements = [
+        requirement("DNA-1", "Login"),
+        requirement("DNA-2", "Logout"),
+        requirement("DNA-3", "Export"),
+    ];
+    let tests = [
+        test_case(
+            "DNA-T2",
+            vec![
+                link("Tests", Some("DNA-1"), None),
+                // Outside the requirement query
+                link("Tests", Some("OPS-9"), None),
+            ],
+        ),
+        test_case(
+            "DNA-T1",
+            vec![
+                link("Tests", Some("DNA-1"), None),
+                link("Tests", Some("DNA-2"), None),
+            ],
+        ),
+        // The requirement tests the test, not the other way around
+        test_case("DNA-T3", vec![link("Tests", None, Some("DNA-3"))]),
+        // Another link type
+        test_case("DNA-T4", vec![link("Relates", Some("DNA-3"), None)]),
+    ];
+
+    let matrix = build_matrix(&requirements, &tests, "Tests");
+    let covering: Vec<(&str, Vec<String>)> = matrix
+        .requirements
+        .iter()
+        .map(|r| (r.key.as_str(), r.tests.clone()))
+        .collect();
+    assert_eq!(
+        covering,
+        [
+            ("DNA-1", vec!["DNA-T1".to_string(), "DNA-T2".to_string()]),
+            ("DNA-2", vec!["DNA-T1".to_string()]),
+            ("DNA-3", vec![]),
+        ]
+    );
+    assert_eq!(matrix.uncovered, ["DNA-3"]);
+    assert_eq!(matrix.tests[0].key, "DNA-T2");
+    assert_eq!(matrix.tests[0].requirements, ["DNA-1"]);
+    assert_eq!(matrix.tests[1].requirements, ["DNA-1", "DNA-2"]);
+    assert!(matrix.tests[2].requirements.is_empty());
+    assert!((matrix.coverage_percent() - 200.0 / 3.0).abs() < 1e-9);
+
+    // The test's side of the link type works as well
+    let by_relation = build_matrix(&requirements, &tests, "TESTS");
+    assert_eq!(by_relation.uncovered, ["DNA-3"]);
+    // Read from the requirement side instead, only the reversed link counts
+    let reversed = build_matrix(&requirements, &tests, "is tested by");
+    assert_eq!(reversed.uncovered, ["DNA-1", "DNA-2"]);
+
+    assert!((build_matrix(&[], &tests, "Tests").coverage_percent() - 100.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_traceability_report_pages_through_both_queries() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let pages = [
+        (
+            "project = DNA AND issuetype = Story ORDER BY key ASC",
+            "0",
+            json!({"total": 3, "issues": [requirement("DNA-1", "Login"), requirement("DNA-2", "Logout")]}),
+        ),
+        (
+            "project = DNA AND issuetype = Story ORDER BY key ASC",
+            "2",
+            json!({"total": 3, "issues": [requirement("DNA-3", "Export")]}),
+        ),
+        (
+            "project = \"DNA\" AND issuetype = \"Test\" ORDER BY key ASC",
+            "0",
+            json!({"total": 1, "issues": [test_case("DNA-T1", vec![link("Tests", Some("DNA-2"), None)])]}),
+        ),
+    ];
+    for (jql, start_at, body) in pages {
+        server
+            .mock("GET", "/rest/api/2/search")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("jql".to_string(), jql.to_string()),
+                mockito::Matcher::UrlEncoded("startAt".to_string(), start_at.to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .expect(1)
+            .create_async()
+            .await;
+    }
+
+    let tool = ZephyrTraceabilityReportTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "project_key": "DNA",
+            "requirements_jql": "project = DNA AND issuetype = Story"
+        }))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Traceability for project DNA: 1 of 3 requirements covered (33.3%) by 1 test cases linked with 'Tests'\n\
+         Requirements: Sorted by key (ORDER BY key ASC added) so pages could not shift\n\
+         Test cases: Sorted by key (ORDER BY key ASC added) so pages could not shift\n\n\
+         Requirements:\n\
+         • DNA-1 - Login: no tests\n\
+         • DNA-2 - Logout: DNA-T1\n\
+         • DNA-3 - Export: no tests\n\n\
+         Uncovered requirements (2): DNA-1, DNA-3\n\n\
+         Test cases:\n\
+         • DNA-T1 - Test DNA-T1: DNA-2\n"
+    );
+    let matrix: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(matrix["uncovered"], json!(["DNA-1", "DNA-3"]));
+
+    let error = tool
+        .handle(json!({"project_key": "DNA"}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Missing required parameter: requirements_jql"));
+}
+
+#[tokio::test]
+async fn test_traceability_report_rejects_oversized_queries() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let first_page = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"total": 5000, "issues": [requirement("DNA-1", "Login")]}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = ZephyrTraceabilityReportTool::new(create_test_config(&base_url));
+    let error = tool
+        .handle(json!({"project_key": "DNA", "requirements_jql": "project = DNA"}))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("matches 5000 issues"));
+    first_page.assert_async().await;
+}
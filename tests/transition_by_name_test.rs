@@ -0,0 +1,294 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{BulkTransitionIssuesTool, TransitionIssueTool};
+use rust_jira_mcp::types::jira::{JiraTransition, TransitionOptions};
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: Some("TEST".to_string()),
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn transition(id: &str, name: &str, to: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "name": name,
+        "to": {
+            "self": format!("https://jira.example.com/rest/api/2/status/{id}"),
+            "id": id,
+            "name": to,
+            "statusCategory": {
+                "self": "https://jira.example.com/rest/api/2/statuscategory/4",
+                "id": 4,
+                "key": "indeterminate",
+                "colorName": "yellow",
+                "name": "In Progress"
+            }
+        }
+    })
+}
+
+fn transitions_body() -> serde_json::Value {
+    json!({
+        "transitions": [
+            transition("11", "Start Progress", "In Progress"),
+            transition("31", "Done", "Done"),
+            transition("41", "Close", "Closed"),
+            transition("42", "close", "Closed")
+        ]
+    })
+}
+
+fn parsed_transitions() -> Vec<JiraTransition> {
+    serde_json::from_value(transitions_body()["transitions"].clone()).unwrap()
+}
+
+#[test]
+fn test_match_transition_by_name() {
+    let transitions = parsed_transitions();
+
+    assert_eq!(
+        JiraClient::match_transition(&transitions, "done")
+            .unwrap()
+            .id,
+        "31"
+    );
+    assert_eq!(
+        JiraClient::match_transition(&transitions, " START PROGRESS ")
+            .unwrap()
+            .id,
+        "11"
+    );
+    // Falls back to the target status name
+    assert_eq!(
+        JiraClient::match_transition(&transitions, "in progress")
+            .unwrap()
+            .id,
+        "11"
+    );
+}
+
+#[test]
+fn test_match_transition_missing_lists_valid_names() {
+    let transitions = parsed_transitions();
+
+    let error = JiraClient::match_transition(&transitions, "Reopen")
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("No transition named 'Reopen'"));
+    assert!(error.contains("'Start Progress', 'Done', 'Close', 'close'"));
+}
+
+#[test]
+fn test_match_transition_ambiguous() {
+    let transitions = parsed_transitions();
+
+    let error = JiraClient::match_transition(&transitions, "CLOSE")
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("ambiguous"));
+    assert!(error.contains("'Close' (id 41)"));
+    assert!(error.contains("'close' (id 42)"));
+}
+
+#[test]
+fn test_transition_payload_injects_resolution() {
+    let mut fields = serde_json::Map::new();
+    fields.insert("customfield_10010".to_string(), json!("Released"));
+    let options = TransitionOptions {
+        comment: Some("Shipped".to_string()),
+        fields: Some(fields),
+        resolution: Some(json!("Fixed")),
+    };
+
+    let payload = JiraClient::transition_payload("31", &options);
+
+    assert_eq!(payload["transition"]["id"], "31");
+    assert_eq!(payload["fields"]["resolution"], json!({"name": "Fixed"}));
+    assert_eq!(payload["fields"]["customfield_10010"], "Released");
+    assert_eq!(payload["update"]["comment"][0]["add"]["body"], "Shipped");
+
+    let options = TransitionOptions {
+        resolution: Some(json!({"id": "1"})),
+        ..Default::default()
+    };
+    let payload = JiraClient::transition_payload("31", &options);
+    assert_eq!(payload["fields"]["resolution"], json!({"id": "1"}));
+    assert!(payload.get("update").is_none());
+}
+
+#[tokio::test]
+async fn test_transition_tool_resolves_name_and_sends_resolution() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _transitions = server
+        .mock("GET", "/rest/api/2/issue/NAME-1/transitions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transitions_body().to_string())
+        .create_async()
+        .await;
+
+    let post = server
+        .mock("POST", "/rest/api/2/issue/NAME-1/transitions")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "transition": {"id": "31"},
+            "fields": {"resolution": {"name": "Fixed"}}
+        })))
+        .with_status(204)
+        .with_header("content-type", "application/json")
+        .with_body("{}")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = TransitionIssueTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_key": "NAME-1",
+            "transition_name": "done",
+            "resolution": "Fixed"
+        }))
+        .await
+        .unwrap();
+
+    assert!(result.content[0].text.contains("'Done' (transition 31)"));
+    post.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_transition_tool_rejects_ambiguous_name() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let _transitions = server
+        .mock("GET", "/rest/api/2/issue/NAME-2/transitions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transitions_body().to_string())
+        .create_async()
+        .await;
+
+    let post = server
+        .mock("POST", "/rest/api/2/issue/NAME-2/transitions")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let tool = TransitionIssueTool::new(create_test_config(&base_url));
+    let error = tool
+        .handle(json!({"issue_key": "NAME-2", "transition_name": "close"}))
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("ambiguous"));
+    post.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bulk_transition_resolves_once_per_workflow() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+
+    let issue = |key: &str, status_id: &str| {
+        json!({
+            "id": key,
+            "key": key,
+            "self": format!("{base_url}/rest/api/2/issue/{key}"),
+            "fields": {
+                "project": {"id": "10000", "key": "BULK"},
+                "issuetype": {"id": "1", "name": "Bug"},
+                "status": {"id": status_id, "name": "Open"}
+            }
+        })
+    };
+
+    let _search = server
+        .mock("GET", "/rest/api/2/search")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "startAt": 0,
+                "maxResults": 3,
+                "total": 3,
+                "issues": [issue("BULK-1", "1"), issue("BULK-2", "1"), issue("BULK-3", "3")]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    // BULK-1 and BULK-2 share a workflow and status, so only one of them is asked
+    let shared_group = server
+        .mock(
+            "GET",
+            mockito::Matcher::Regex(r"^/rest/api/2/issue/BULK-[12]/transitions$".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(transitions_body().to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let other_group = server
+        .mock("GET", "/rest/api/2/issue/BULK-3/transitions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"transitions": [transition("51", "Done", "Done")]}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let shared_post = server
+        .mock(
+            "POST",
+            mockito::Matcher::Regex(r"^/rest/api/2/issue/BULK-[12]/transitions$".to_string()),
+        )
+        .match_body(mockito::Matcher::PartialJson(
+            json!({"transition": {"id": "31"}}),
+        ))
+        .with_status(204)
+        .with_body("{}")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let other_post = server
+        .mock("POST", "/rest/api/2/issue/BULK-3/transitions")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({"transition": {"id": "51"}}),
+        ))
+        .with_status(204)
+        .with_body("{}")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = BulkTransitionIssuesTool::new(create_test_config(&base_url));
+    tool.handle(json!({
+        "issue_keys": ["BULK-1", "BULK-2", "BULK-3"],
+        "transition_name": "Done",
+        "config": {"continue_on_error": false, "rate_limit_ms": 0}
+    }))
+    .await
+    .unwrap();
+
+    shared_group.assert_async().await;
+    other_group.assert_async().await;
+    shared_post.assert_async().await;
+    other_post.assert_async().await;
+}
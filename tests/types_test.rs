@@ -36,6 +36,9 @@ fn test_jira_project_serialization() {
         project_type_key: "software".to_string(),
         self_url: "https://example.com/rest/api/2/project/10000".to_string(),
         avatar_urls: None,
+        project_category: None,
+        archived: false,
+        deleted: false,
     };
 
     let serialized = serde_json::to_string(&project).unwrap();
@@ -57,7 +60,7 @@ fn test_jira_user_serialization() {
         account_id: Some("5d5f8b8b8b8b8b8b8b8b8b8b".to_string()),
         email_address: Some("john.doe@example.com".to_string()),
         avatar_urls: None,
-        display_name: "John Doe".to_string(),
+        display_name: Some("John Doe".to_string()),
         active: true,
         time_zone: Some("America/New_York".to_string()),
     };
@@ -162,7 +165,7 @@ fn test_jira_comment_serialization() {
         account_id: Some("5d5f8b8b8b8b8b8b8b8b8b8b".to_string()),
         email_address: Some("john.doe@example.com".to_string()),
         avatar_urls: None,
-        display_name: "John Doe".to_string(),
+        display_name: Some("John Doe".to_string()),
         active: true,
         time_zone: Some("America/New_York".to_string()),
     };
@@ -173,6 +176,7 @@ fn test_jira_comment_serialization() {
         author,
         created: "2023-01-01T00:00:00.000+0000".to_string(),
         updated: Some("2023-01-01T00:00:00.000+0000".to_string()),
+        visibility: None,
     };
 
     let serialized = serde_json::to_string(&comment).unwrap();
@@ -272,7 +276,7 @@ fn test_jira_work_log_serialization() {
         account_id: Some("5d5f8b8b8b8b8b8b8b8b8b8b".to_string()),
         email_address: Some("john.doe@example.com".to_string()),
         avatar_urls: None,
-        display_name: "John Doe".to_string(),
+        display_name: Some("John Doe".to_string()),
         active: true,
         time_zone: Some("America/New_York".to_string()),
     };
@@ -386,6 +390,10 @@ fn test_bulk_operation_summary_add_result() {
         success: true,
         error_message: None,
         operation_type: BulkOperationType::Update,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     };
 
     let failure_result = BulkOperationResult {
@@ -393,6 +401,10 @@ fn test_bulk_operation_summary_add_result() {
         success: false,
         error_message: Some("Error occurred".to_string()),
         operation_type: BulkOperationType::Update,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     };
 
     summary.add_result(success_result);
@@ -417,12 +429,20 @@ fn test_bulk_operation_summary_success_rate() {
         success: true,
         error_message: None,
         operation_type: BulkOperationType::Update,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     });
     summary.add_result(BulkOperationResult {
         issue_key: "TEST-2".to_string(),
         success: true,
         error_message: None,
         operation_type: BulkOperationType::Update,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     });
 
     assert!((summary.success_rate() - 100.0).abs() < f64::EPSILON);
@@ -433,6 +453,10 @@ fn test_bulk_operation_summary_success_rate() {
         success: false,
         error_message: Some("Error".to_string()),
         operation_type: BulkOperationType::Update,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     });
 
     // Use approximate equality for floating point comparison
@@ -553,6 +577,8 @@ fn test_initialize_result_serialization() {
         tools: Some(ToolsCapability {
             list_changed: Some(true),
         }),
+        logging: None,
+        experimental: None,
     };
 
     let server_info = ServerInfo {
@@ -593,6 +619,7 @@ fn test_mcp_tool_serialization() {
             },
             "required": ["issue_key"]
         }),
+        ..Default::default()
     };
 
     let serialized = serde_json::to_string(&tool).unwrap();
@@ -601,6 +628,45 @@ fn test_mcp_tool_serialization() {
     assert_eq!(tool.name, deserialized.name);
     assert_eq!(tool.description, deserialized.description);
     assert_eq!(tool.input_schema, deserialized.input_schema);
+    // Without hints, nothing new is sent to clients
+    assert!(!serialized.contains("annotations"));
+    assert!(!serialized.contains("_meta"));
+}
+
+#[test]
+fn test_mcp_tool_annotations_serialization() {
+    let tool = MCPTool {
+        name: "delete_jira_label".to_string(),
+        description: "Delete a label".to_string(),
+        input_schema: json!({}),
+        annotations: Some(ToolAnnotations {
+            read_only_hint: Some(false),
+            destructive_hint: Some(true),
+        }),
+        meta: Some(ToolMeta {
+            category: ToolCategory::Labels,
+        }),
+    };
+
+    let serialized = serde_json::to_value(&tool).unwrap();
+    assert_eq!(
+        serialized["annotations"],
+        json!({"readOnlyHint": false, "destructiveHint": true})
+    );
+    assert_eq!(serialized["_meta"], json!({"category": "labels"}));
+
+    let deserialized: MCPTool = serde_json::from_value(serialized).unwrap();
+    assert_eq!(deserialized.annotations, tool.annotations);
+    assert_eq!(deserialized.meta, tool.meta);
+
+    // Definitions from servers without hints still parse
+    let plain: MCPTool = serde_json::from_value(json!({
+        "name": "get_issue",
+        "description": "Get an issue",
+        "input_schema": {}
+    }))
+    .unwrap();
+    assert!(plain.annotations.is_none() && plain.meta.is_none());
 }
 
 #[test]
@@ -673,6 +739,7 @@ fn test_list_tools_result_serialization() {
         name: "get_issue".to_string(),
         description: "Get a Jira issue by key".to_string(),
         input_schema: json!({}),
+        ..Default::default()
     };
 
     let result = ListToolsResult { tools: vec![tool] };
@@ -768,6 +835,10 @@ fn test_bulk_operation_result_serialization() {
         success: true,
         error_message: None,
         operation_type: BulkOperationType::Update,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     };
 
     let serialized = serde_json::to_string(&result).unwrap();
@@ -793,6 +864,10 @@ fn test_bulk_operation_summary_serialization() {
         success: true,
         error_message: None,
         operation_type: BulkOperationType::Update,
+        error_kind: None,
+        skipped: false,
+        previous_fields: None,
+        moved_from: None,
     });
 
     let serialized = serde_json::to_string(&summary).unwrap();
@@ -15,6 +15,7 @@ fn test_config() -> JiraConfig {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     }
 }
 
@@ -101,13 +102,13 @@ async fn test_jira_project_creation() {
 async fn test_jira_user_creation() {
     let user = JiraUser {
         account_id: "12345:abcdef".to_string(),
-        display_name: "Test User".to_string(),
+        display_name: Some("Test User".to_string()),
         email_address: Some("test@example.com".to_string()),
         active: true,
         time_zone: Some("UTC".to_string()),
     };
     
-    assert_eq!(user.display_name, "Test User");
+    assert_eq!(user.display_name.as_deref(), Some("Test User"));
     assert_eq!(user.email_address, Some("test@example.com".to_string()));
 }
 
@@ -115,7 +116,7 @@ async fn test_jira_user_creation() {
 async fn test_jira_comment_creation() {
     let user = JiraUser {
         account_id: "12345:abcdef".to_string(),
-        display_name: "Test User".to_string(),
+        display_name: Some("Test User".to_string()),
         email_address: Some("test@example.com".to_string()),
         active: true,
         time_zone: Some("UTC".to_string()),
@@ -127,10 +128,11 @@ async fn test_jira_comment_creation() {
         author: user,
         created: "2023-01-01T00:00:00.000Z".to_string(),
         updated: None,
+        visibility: None,
     };
     
     assert_eq!(comment.body, "This is a test comment");
-    assert_eq!(comment.author.display_name, "Test User");
+    assert_eq!(comment.author.display_name.as_deref(), Some("Test User"));
 }
 
 #[tokio::test]
@@ -208,7 +210,7 @@ async fn test_jira_status_creation() {
 async fn test_jira_work_log_creation() {
     let user = JiraUser {
         account_id: "12345:abcdef".to_string(),
-        display_name: "Test User".to_string(),
+        display_name: Some("Test User".to_string()),
         email_address: Some("test@example.com".to_string()),
         active: true,
         time_zone: Some("UTC".to_string()),
@@ -226,7 +228,7 @@ async fn test_jira_work_log_creation() {
     
     assert_eq!(work_log.time_spent, "1h 30m");
     assert_eq!(work_log.time_spent_seconds, 5400);
-    assert_eq!(work_log.author.display_name, "Test User");
+    assert_eq!(work_log.author.display_name.as_deref(), Some("Test User"));
 }
 
 #[tokio::test]
@@ -323,6 +325,7 @@ async fn test_config_validation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
     
     let client = JiraClient::new(valid_config);
@@ -338,6 +341,7 @@ async fn test_config_validation() {
         timeout_seconds: Some(30),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
     
     let client = JiraClient::new(config_with_empty_email);
@@ -355,6 +359,7 @@ async fn test_timeout_duration() {
         timeout_seconds: Some(60),
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
     
     assert_eq!(config.timeout_duration().as_secs(), 60);
@@ -369,6 +374,7 @@ async fn test_timeout_duration() {
         timeout_seconds: None,
         log_file: None,
         strict_ssl: Some(true),
+        ..Default::default()
     };
     
     assert_eq!(config_default.timeout_duration().as_secs(), 30);
@@ -0,0 +1,241 @@
+use chrono::{TimeZone, Utc};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::error::JiraErrorKind;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::conflicts::{changes_since, ExpectedUpdated};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::UpdateIssueTool;
+use rust_jira_mcp::types::jira::{BulkOperationConfig, BulkOperationItem, BulkOperationType};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn history(created: &str, author: &str, items: Value) -> Value {
+    json!({
+        "id": created,
+        "author": {"displayName": author},
+        "created": created,
+        "items": items
+    })
+}
+
+/// An issue last updated at 11:30 after three rounds of edits
+fn edited_issue() -> Value {
+    json!({
+        "key": "EDIT-1",
+        "fields": {"updated": "2024-05-01T11:30:00.000+0000"},
+        "changelog": {
+            "histories": [
+                history(
+                    "2024-05-01T09:00:00.000+0000",
+                    "Alice",
+                    json!([{"field": "summary", "fromString": "Old", "toString": "Seen"}])
+                ),
+                history(
+                    "2024-05-01T11:30:00.000+0000",
+                    "Carol",
+                    json!([{"field": "status", "fromString": "Review", "toString": "Done"}])
+                ),
+                history(
+                    "2024-05-01T10:15:00.000+0000",
+                    "Bob",
+                    json!([
+                        {"field": "status", "fromString": "To Do", "toString": "Review"},
+                        {"field": "assignee", "fromString": null, "toString": "Bob"}
+                    ])
+                )
+            ]
+        }
+    })
+}
+
+async fn mock_issue(server: &mut mockito::ServerGuard, key: &str, issue: &Value) -> mockito::Mock {
+    server
+        .mock("GET", format!("/rest/api/2/issue/{key}").as_str())
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("fields".to_string(), "updated".to_string()),
+            mockito::Matcher::UrlEncoded("expand".to_string(), "changelog".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue.to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[test]
+fn test_expected_updated_tolerates_other_timestamp_formats() {
+    let jira =
+        Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap() + chrono::Duration::milliseconds(123);
+
+    for same in [
+        "2024-05-01T09:00:00.123+0000",
+        "2024-05-01T09:00:00.123Z",
+        "2024-05-01T11:00:00.123+02:00",
+        "2024-05-01T09:00:00.123456Z",
+        // Without milliseconds the whole second matches
+        "2024-05-01T09:00:00Z",
+        "2024-05-01T09:00:00+0000",
+    ] {
+        let expected = ExpectedUpdated::parse(same).unwrap();
+        assert!(expected.matches(jira), "{same}");
+        assert!(!expected.is_before(jira), "{same}");
+    }
+    for different in ["2024-05-01T09:00:00.124Z", "2024-05-01T09:00:01Z"] {
+        assert!(!ExpectedUpdated::parse(different).unwrap().matches(jira));
+    }
+
+    let error = ExpectedUpdated::parse("yesterday").unwrap_err();
+    assert_eq!(error.kind(), JiraErrorKind::Validation);
+}
+
+#[test]
+fn test_changes_since_merges_edits_per_field() {
+    let expected = ExpectedUpdated::parse("2024-05-01T09:00:00.000Z").unwrap();
+    let changes = changes_since(&edited_issue(), &expected);
+
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].field, "status");
+    assert_eq!(changes[0].from.as_deref(), Some("To Do"));
+    assert_eq!(changes[0].to.as_deref(), Some("Done"));
+    assert_eq!(changes[0].authors, ["Bob", "Carol"]);
+    assert_eq!(changes[0].changed_at, "2024-05-01T11:30:00.000+0000");
+    assert_eq!(changes[1].field, "assignee");
+    assert_eq!(changes[1].from, None);
+}
+
+#[tokio::test]
+async fn test_update_goes_ahead_when_the_issue_is_unchanged() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let read = mock_issue(&mut server, "EDIT-1", &edited_issue()).await;
+    let update = server
+        .mock("PUT", "/rest/api/2/issue/EDIT-1")
+        .match_body(mockito::Matcher::Json(
+            json!({"fields": {"summary": "New"}}),
+        ))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = UpdateIssueTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({
+            "issue_key": "EDIT-1",
+            "summary": "New",
+            "expected_updated": "2024-05-01T13:30:00+02:00"
+        }))
+        .await
+        .unwrap();
+
+    assert_eq!(result.content[0].text, "Issue EDIT-1 updated successfully");
+    read.assert_async().await;
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_update_is_refused_when_the_issue_changed() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _read = mock_issue(&mut server, "EDIT-1", &edited_issue()).await;
+    let update = server
+        .mock("PUT", "/rest/api/2/issue/EDIT-1")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let tool = UpdateIssueTool::new(create_test_config(&base_url));
+    let error = tool
+        .handle(json!({
+            "issue_key": "EDIT-1",
+            "summary": "New",
+            "expected_updated": "2024-05-01T09:00:00.000+0000"
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.kind(), JiraErrorKind::Conflict);
+    assert_eq!(
+        error.to_string(),
+        "Conflict: EDIT-1 was last updated at 2024-05-01T11:30:00.000+0000, not \
+         2024-05-01T09:00:00.000+0000 as expected; nothing was changed. Read the issue \
+         again and reapply the update\n\
+         Changes since then:\n\
+         • status: 'To Do' → 'Done' by Bob, Carol (last at 2024-05-01T11:30:00.000+0000)\n\
+         • assignee: empty → 'Bob' by Bob (last at 2024-05-01T10:15:00.000+0000)"
+    );
+    update.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bulk_update_checks_each_item() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _changed = mock_issue(&mut server, "EDIT-1", &edited_issue()).await;
+    let _unchanged = mock_issue(
+        &mut server,
+        "EDIT-2",
+        &json!({"key": "EDIT-2", "fields": {"updated": "2024-05-01T08:00:00.000+0000"}}),
+    )
+    .await;
+    let skipped = server
+        .mock("PUT", "/rest/api/2/issue/EDIT-1")
+        .expect(0)
+        .create_async()
+        .await;
+    let update = server
+        .mock("PUT", "/rest/api/2/issue/EDIT-2")
+        .match_body(mockito::Matcher::Json(
+            json!({"fields": {"priority": {"name": "High"}}}),
+        ))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url)).unwrap();
+    let operations = ["EDIT-1", "EDIT-2"]
+        .into_iter()
+        .map(|key| BulkOperationItem {
+            issue_key: key.to_string(),
+            operation_type: BulkOperationType::Update,
+            data: json!({
+                "fields": {"priority": {"name": "High"}},
+                "expected_updated": "2024-05-01T08:00:00Z"
+            }),
+        })
+        .collect();
+    let summary = client
+        .execute_bulk_operations(operations, BulkOperationConfig::default())
+        .await
+        .unwrap();
+
+    assert_eq!(summary.successful_operations, 1);
+    let failed = summary
+        .results
+        .iter()
+        .find(|result| result.issue_key == "EDIT-1")
+        .unwrap();
+    assert_eq!(failed.error_kind, Some(JiraErrorKind::Conflict));
+    assert!(failed
+        .error_message
+        .as_deref()
+        .unwrap()
+        .contains("• summary: 'Old' → 'Seen' by Alice"));
+    skipped.assert_async().await;
+    update.assert_async().await;
+}
@@ -0,0 +1,237 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::update_preview::{diff_values, FieldDiff};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{PreviewIssueUpdateTool, UpdateIssueTool};
+use serde_json::{json, Value};
+
+const UPDATED: &str = "2024-05-01T11:30:00.000+0000";
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+/// Mock the field catalog and `key` with `fields`, and a PUT that must not
+/// be sent
+async fn mock_issue(
+    server: &mut mockito::ServerGuard,
+    key: &str,
+    fields: Value,
+) -> (mockito::Mock, mockito::Mock) {
+    server
+        .mock("GET", "/rest/api/2/field")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {"id": "summary", "name": "Summary"},
+                {"id": "description", "name": "Description"},
+                {"id": "labels", "name": "Labels"},
+                {"id": "assignee", "name": "Assignee"},
+                {"id": "priority", "name": "Priority"}
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let read = server
+        .mock("GET", format!("/rest/api/2/issue/{key}").as_str())
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "10001",
+                "key": key,
+                "self": format!("{}/rest/api/2/issue/10001", server.url()),
+                "fields": fields
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let write = server
+        .mock("PUT", format!("/rest/api/2/issue/{key}").as_str())
+        .expect(0)
+        .create_async()
+        .await;
+    (read, write)
+}
+
+#[tokio::test]
+async fn test_preview_of_scalar_and_rich_text_fields_changes_nothing() {
+    let mut server = mockito::Server::new_async().await;
+    let (read, write) = mock_issue(
+        &mut server,
+        "PREV-1",
+        json!({
+            "summary": "Old title",
+            "description": "First line\nSecond line",
+            "updated": UPDATED
+        }),
+    )
+    .await;
+
+    let result = UpdateIssueTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "issue_key": "PREV-1",
+            "summary": "New title",
+            "description": "First line\nSecond line, reworded",
+            "preview": true
+        }))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with("Preview of the update to PREV-1; nothing was changed:"),
+        "{text}"
+    );
+    let summary_row = text
+        .lines()
+        .find(|line| line.starts_with("Summary"))
+        .unwrap();
+    assert!(summary_row.contains("Old title"), "{text}");
+    assert!(summary_row.contains("New title"), "{text}");
+    assert!(summary_row.ends_with("changed"), "{text}");
+    // Multi-line rich text is shown in full below the table
+    assert!(
+        text.contains("Description (proposed):\nFirst line\nSecond line, reworded"),
+        "{text}"
+    );
+    assert!(
+        text.ends_with(&format!(
+            "Pass expected_updated \"{UPDATED}\" to apply the update only if the issue is still as shown"
+        )),
+        "{text}"
+    );
+
+    let preview: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(preview["updated"], UPDATED);
+    assert_eq!(preview["fields"][1]["id"], "summary");
+    assert_eq!(preview["fields"][1]["current"], "Old title");
+
+    read.assert_async().await;
+    write.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_preview_of_array_field_lists_added_and_removed_items() {
+    assert_eq!(
+        diff_values(&json!(["backend", "urgent"]), &json!(["urgent", "api"])),
+        FieldDiff::Items {
+            added: vec!["api".to_string()],
+            removed: vec!["backend".to_string()],
+        }
+    );
+    assert_eq!(
+        diff_values(&Value::Null, &json!(["api"])).to_string(),
+        "+api"
+    );
+    assert_eq!(
+        diff_values(&json!(["api"]), &json!(["api"])).to_string(),
+        "unchanged"
+    );
+
+    let mut server = mockito::Server::new_async().await;
+    let (read, write) = mock_issue(
+        &mut server,
+        "PREV-2",
+        json!({"labels": ["backend", "urgent"], "updated": UPDATED}),
+    )
+    .await;
+    let result = PreviewIssueUpdateTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "issue_id_or_key": "PREV-2",
+            "fields": {"labels": ["urgent", "api"]},
+            "expected_updated": "2024-05-01T09:00:00.000+0000"
+        }))
+        .await
+        .unwrap();
+    let text = &result.content[0].text;
+    let labels_row = text
+        .lines()
+        .find(|line| line.starts_with("Labels"))
+        .unwrap();
+    assert!(labels_row.contains("backend, urgent"), "{text}");
+    assert!(labels_row.ends_with("+api -backend"), "{text}");
+    // The issue changed after the timestamp the preview was asked with
+    assert!(
+        text.contains(&format!(
+            "The issue was updated since expected_updated, at {UPDATED}"
+        )),
+        "{text}"
+    );
+    let preview: Value = serde_json::from_str(&result.content[1].text).unwrap();
+    assert_eq!(preview["changed_since_expected"], true);
+    assert_eq!(preview["fields"][0]["diff"]["added"], json!(["api"]));
+
+    read.assert_async().await;
+    write.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_preview_of_object_fields_compares_the_keys_given() {
+    let mut server = mockito::Server::new_async().await;
+    let (read, write) = mock_issue(
+        &mut server,
+        "PREV-3",
+        json!({
+            "assignee": {"name": "alice", "displayName": "Alice Archer"},
+            "priority": {"id": "4", "name": "Low"},
+            "customfield_10100": null,
+            "updated": UPDATED
+        }),
+    )
+    .await;
+
+    let client = JiraClient::new(create_test_config(&server.url())).unwrap();
+    let fields = json!({
+        "assignee": {"name": "alice"},
+        "priority": {"name": "High"},
+        "customfield_10100": {"value": "Blue"}
+    });
+    let preview = client
+        .preview_issue_update("PREV-3", fields.as_object().unwrap(), Some(UPDATED))
+        .await
+        .unwrap();
+    assert_eq!(preview.changed_since_expected, Some(false));
+
+    let diffs: Vec<(&str, &FieldDiff)> = preview
+        .fields
+        .iter()
+        .map(|field| (field.name.as_str(), &field.diff))
+        .collect();
+    assert_eq!(
+        diffs,
+        vec![
+            ("Assignee", &FieldDiff::Unchanged),
+            // Not in the catalog: shown by id
+            ("customfield_10100", &FieldDiff::Set),
+            ("Priority", &FieldDiff::Changed),
+        ]
+    );
+
+    let text = preview.render();
+    let priority_row = text
+        .lines()
+        .find(|line| line.starts_with("Priority"))
+        .unwrap();
+    assert!(priority_row.contains("Low"), "{text}");
+    assert!(priority_row.contains("High"), "{text}");
+    let custom_row = text
+        .lines()
+        .find(|line| line.starts_with("customfield_10100"))
+        .unwrap();
+    assert!(custom_row.contains("(empty)"), "{text}");
+    assert!(custom_row.contains("Blue"), "{text}");
+
+    read.assert_async().await;
+    write.assert_async().await;
+}
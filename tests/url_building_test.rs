@@ -0,0 +1,138 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::urls::{base_url, join, path_segment};
+use serde_json::json;
+
+#[test]
+fn test_join_over_base_url_variants() {
+    for base in [
+        "https://x.atlassian.net/rest/api/2",
+        "https://x.atlassian.net/rest/api/2/",
+        " https://x.atlassian.net/rest/api/2 ",
+    ] {
+        for (endpoint, expected) in [
+            (
+                "issue/KEY-1",
+                "https://x.atlassian.net/rest/api/2/issue/KEY-1",
+            ),
+            (
+                "/issue/KEY-1",
+                "https://x.atlassian.net/rest/api/2/issue/KEY-1",
+            ),
+            (
+                "//issue/KEY-1",
+                "https://x.atlassian.net/rest/api/2/issue/KEY-1",
+            ),
+            (
+                "search?jql=project%3DX&maxResults=5",
+                "https://x.atlassian.net/rest/api/2/search?jql=project%3DX&maxResults=5",
+            ),
+            ("", "https://x.atlassian.net/rest/api/2/"),
+        ] {
+            assert_eq!(
+                join(base, endpoint, "API base URL").unwrap().as_str(),
+                expected,
+                "{base:?} + {endpoint:?}"
+            );
+        }
+    }
+
+    assert_eq!(
+        join(
+            "https://jira.example.com/jira/rest/api/2",
+            "label/x",
+            "API base URL"
+        )
+        .unwrap()
+        .as_str(),
+        "https://jira.example.com/jira/rest/api/2/label/x"
+    );
+    assert_eq!(
+        join("http://localhost:8080", "serverInfo", "API base URL")
+            .unwrap()
+            .as_str(),
+        "http://localhost:8080/serverInfo"
+    );
+}
+
+#[test]
+fn test_invalid_base_urls() {
+    for base in ["", "jira.example.com/rest/api/2", "not a url"] {
+        let error = base_url(base, "API base URL").unwrap_err().to_string();
+        assert!(error.contains("Invalid API base URL"), "{base:?}: {error}");
+    }
+}
+
+#[test]
+fn test_path_segments_are_percent_encoded() {
+    for (value, encoded) in [
+        ("KEY-1", "KEY-1"),
+        ("needs review", "needs%20review"),
+        ("a/b", "a%2Fb"),
+        ("what?", "what%3F"),
+        ("#1", "%231"),
+        ("100%", "100%25"),
+        ("ünïcode", "%C3%BCn%C3%AFcode"),
+        ("dot.tilde~under_score", "dot.tilde~under_score"),
+    ] {
+        assert_eq!(path_segment(value), encoded, "{value}");
+    }
+
+    for (label, expected) in [
+        (
+            "needs review",
+            "https://jira.example.com/rest/api/2/label/needs%20review",
+        ),
+        ("a/b", "https://jira.example.com/rest/api/2/label/a%2Fb"),
+        (
+            "what?#",
+            "https://jira.example.com/rest/api/2/label/what%3F%23",
+        ),
+    ] {
+        let url = join(
+            "https://jira.example.com/rest/api/2",
+            &format!("label/{}", path_segment(label)),
+            "API base URL",
+        )
+        .unwrap();
+        assert_eq!(url.as_str(), expected, "{label}");
+        assert!(url.query().is_none() && url.fragment().is_none(), "{label}");
+    }
+}
+
+#[tokio::test]
+async fn test_delete_label_with_special_characters() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("DELETE", "/rest/api/2/label/needs%20review%2Fqa")
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+    let issue = server
+        .mock("GET", "/rest/api/2/issue/KEY%231")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"id": "1", "key": "KEY#1", "self": "https://jira.example.com", "fields": {}})
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    // No trailing slash on the base URL
+    let client = JiraClient::new(JiraConfig {
+        api_base_url: format!("{}/rest/api/2", server.url()),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    })
+    .unwrap();
+    client.delete_label("needs review/qa").await.unwrap();
+    client.get_issue("KEY#1").await.unwrap();
+
+    mock.assert_async().await;
+    issue.assert_async().await;
+}
@@ -0,0 +1,131 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::warmup::{last_warmup, warm_up};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{GetServerMetricsTool, TestAuthTool};
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "warmup@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        warmup: Some(true),
+        ..Default::default()
+    }
+}
+
+async fn mock_once(server: &mut mockito::ServerGuard, path: &str, body: Value) -> mockito::Mock {
+    server
+        .mock("GET", path)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+// The caches and the last report are process-wide, so everything runs in
+// one test
+#[tokio::test]
+async fn test_warm_up_fills_the_caches_and_reports_failures() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let config = create_test_config(&base_url);
+    let mocks = vec![
+        mock_once(
+            &mut server,
+            "/rest/api/2/serverInfo",
+            json!({"version": "9.12.0", "deploymentType": "Server"}),
+        )
+        .await,
+        mock_once(
+            &mut server,
+            "/rest/api/2/priority",
+            json!([{"self": "x", "id": "1", "name": "High", "description": null, "iconUrl": null, "statusColor": null}]),
+        )
+        .await,
+        mock_once(
+            &mut server,
+            "/rest/api/2/field",
+            json!([{"id": "customfield_10016", "name": "Story Points"}]),
+        )
+        .await,
+        mock_once(
+            &mut server,
+            "/rest/api/2/myself",
+            json!({"name": "warmup", "displayName": "Warm Up"}),
+        )
+        .await,
+    ];
+    let statuses = server
+        .mock("GET", "/rest/api/2/status")
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+
+    assert!(last_warmup(&config).is_none());
+    let report = warm_up(&config).await;
+    assert_eq!(
+        report.primed(),
+        ["server info", "priorities", "fields", "current user"]
+    );
+    let failed = &report.steps[2];
+    assert_eq!(failed.name, "statuses");
+    assert!(failed.error.is_some());
+
+    // The metadata cache holds what was fetched, and later lookups are
+    // answered from the caches
+    let client = JiraClient::new(config.clone()).unwrap();
+    assert!(client.is_metadata_cached("priority").await);
+    assert!(client.is_metadata_cached("field").await);
+    assert!(!client.is_metadata_cached("status").await);
+    assert_eq!(
+        client.resolve_field("Story Points").await.unwrap()["id"],
+        "customfield_10016"
+    );
+    assert_eq!(client.get_current_user().await.unwrap()["name"], "warmup");
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+    statuses.assert_async().await;
+
+    // The health check reports it
+    let health = TestAuthTool::new(config.clone())
+        .handle(json!({"random_string": "ping"}))
+        .await
+        .unwrap();
+    let summary = &health.content[1].text;
+    assert!(
+        summary.starts_with("Start-up warm-up finished in "),
+        "{summary}"
+    );
+    assert!(summary
+        .contains("primed: server info, priorities, fields, current user; failed: statuses ("));
+
+    let metrics = GetServerMetricsTool::new(config)
+        .handle(json!({}))
+        .await
+        .unwrap();
+    let text = &metrics.content[0].text;
+    let metrics: Value =
+        serde_json::from_str(text.trim_start_matches("Server metrics:\n")).unwrap();
+    assert_eq!(metrics["warmup"]["steps"].as_array().unwrap().len(), 5);
+    assert_eq!(metrics["warmup"]["steps"][0]["error"], Value::Null);
+
+    // Another instance has not warmed up
+    let other = create_test_config("http://127.0.0.1:9");
+    assert!(last_warmup(&other).is_none());
+    let health = TestAuthTool::new(other)
+        .handle(json!({"random_string": "ping"}))
+        .await
+        .unwrap();
+    assert_eq!(health.content.len(), 1);
+}
@@ -0,0 +1,160 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{UnwatchIssueTool, WatchIssueTool};
+use serde_json::json;
+
+// The current user is cached per base URL and email, and mock servers reuse
+// ports, so each test authenticates as someone else
+fn create_test_config(base_url: &str, email: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: email.to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn watchers_json(count: i32) -> String {
+    json!({
+        "self": "x",
+        "isWatching": true,
+        "watchCount": count,
+        "watchers": [{"accountId": "me-123", "displayName": "Me", "active": true}]
+    })
+    .to_string()
+}
+
+async fn mock_myself(server: &mut mockito::ServerGuard) -> mockito::Mock {
+    server
+        .mock("GET", "/rest/api/2/myself")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"accountId": "me-123", "displayName": "Me"}).to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_watch_issue_as_current_user() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let myself = mock_myself(&mut server).await;
+    let add = server
+        .mock("POST", "/rest/api/2/issue/TEST-1/watchers")
+        .match_body(mockito::Matcher::Json(json!({"accountId": "me-123"})))
+        .with_status(204)
+        .expect(2)
+        .create_async()
+        .await;
+    let _watchers = server
+        .mock("GET", "/rest/api/2/issue/TEST-1/watchers")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(watchers_json(3))
+        .create_async()
+        .await;
+
+    let tool = WatchIssueTool::new(create_test_config(&base_url, "watch@example.com"));
+    for _ in 0..2 {
+        let result = tool.handle(json!({"issue_key": "TEST-1"})).await.unwrap();
+        assert_eq!(
+            result.content[0].text,
+            "You are now watching TEST-1 (3 watchers)"
+        );
+    }
+
+    // The current user is looked up once
+    myself.assert_async().await;
+    add.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_unwatch_issue_on_behalf_of_account() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let myself = server
+        .mock("GET", "/rest/api/2/myself")
+        .expect(0)
+        .create_async()
+        .await;
+    let remove = server
+        .mock("DELETE", "/rest/api/2/issue/TEST-2/watchers")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "accountId".into(),
+            "other-456".into(),
+        ))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+    let _watchers = server
+        .mock("GET", "/rest/api/2/issue/TEST-2/watchers")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(watchers_json(1))
+        .create_async()
+        .await;
+
+    let tool = UnwatchIssueTool::new(create_test_config(&base_url, "unwatch@example.com"));
+    let result = tool
+        .handle(json!({"issue_key": "TEST-2", "account_id": "other-456"}))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        "other-456 is no longer watching TEST-2 (1 watchers)"
+    );
+    remove.assert_async().await;
+    myself.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bulk_watch_issues() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _myself = mock_myself(&mut server).await;
+    for (key, status) in [("TEST-1", 204), ("TEST-2", 404)] {
+        server
+            .mock("POST", format!("/rest/api/2/issue/{key}/watchers").as_str())
+            .with_status(status)
+            .with_header("content-type", "application/json")
+            .with_body(json!({"errorMessages": ["Issue does not exist"]}).to_string())
+            .create_async()
+            .await;
+    }
+    let _watchers = server
+        .mock("GET", "/rest/api/2/issue/TEST-1/watchers")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(watchers_json(2))
+        .create_async()
+        .await;
+
+    let tool = WatchIssueTool::new(create_test_config(&base_url, "bulk@example.com"));
+    let result = tool
+        .handle(json!({"issue_keys": ["TEST-1", "TEST-2"]}))
+        .await
+        .unwrap();
+
+    let text = &result.content[0].text;
+    assert!(text.starts_with("You are now watching 1 of 2 issues:\n"));
+    assert!(text.contains("• TEST-1 (2 watchers)\n"));
+    assert!(text.contains("• TEST-2 failed: Jira API error: Issue does not exist\n"));
+    assert_eq!(result.is_error, Some(false));
+}
+
+#[tokio::test]
+async fn test_watch_issue_requires_issue_key() {
+    let tool = WatchIssueTool::new(create_test_config("http://localhost:1", "test@example.com"));
+    let error = tool.handle(json!({})).await.unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Missing required parameter: issue_key or issue_keys"));
+}
@@ -0,0 +1,173 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::GetIssueWatchersTool;
+use rust_jira_mcp::types::jira::JiraWatchersResponse;
+use serde_json::json;
+
+const WATCHERS_RESTRICTED: &str = include_str!("fixtures/watchers_restricted.json");
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn watcher(name: &str, display_name: &str) -> serde_json::Value {
+    json!({"name": name, "displayName": display_name, "active": true})
+}
+
+async fn watchers_text(server: &mockito::ServerGuard, args: serde_json::Value) -> String {
+    GetIssueWatchersTool::new(create_test_config(&server.url()))
+        .handle(args)
+        .await
+        .unwrap()
+        .content[0]
+        .text
+        .clone()
+}
+
+#[test]
+fn test_restricted_payload_deserializes() {
+    // Regression: a missing `watchers` array failed deserialization
+    let response: JiraWatchersResponse = serde_json::from_str(WATCHERS_RESTRICTED).unwrap();
+    assert_eq!(response.watch_count, 7);
+    assert!(response.watchers.is_none());
+}
+
+#[tokio::test]
+async fn test_hidden_watchers_show_the_count() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/rest/api/2/issue/WAT-1/watchers")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(WATCHERS_RESTRICTED)
+        .create_async()
+        .await;
+
+    let text = watchers_text(&server, json!({"issue_key": "WAT-1"})).await;
+    assert_eq!(
+        text,
+        "Issue WAT-1 has 7 watchers\nWatcher identities hidden by permissions (count: 7)\n"
+    );
+}
+
+#[tokio::test]
+async fn test_watchers_are_listed() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/rest/api/2/issue/WAT-2/watchers")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "watchCount": 2,
+                "watchers": [
+                    {"accountId": "5b10a2844c20165700ede21g", "displayName": "Dana Scully", "active": true},
+                    watcher("fmulder", "Fox Mulder")
+                ]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let text = watchers_text(&server, json!({"issue_key": "WAT-2"})).await;
+    assert_eq!(
+        text,
+        "Issue WAT-2 has 2 watchers\n\
+         • Dana Scully (5b10a2844c20165700ede21g)\n\
+         • Fox Mulder (fmulder)\n"
+    );
+}
+
+#[tokio::test]
+async fn test_partial_watcher_list_is_called_out() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/rest/api/2/issue/WAT-3/watchers")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"watchCount": 5, "watchers": [watcher("a", "A")]}).to_string())
+        .create_async()
+        .await;
+
+    let text = watchers_text(&server, json!({"issue_key": "WAT-3"})).await;
+    assert!(text.contains("• A (a)\n"), "{text}");
+    assert!(
+        text.contains("Only 1 of 5 watchers are listed; the others watch through a group"),
+        "{text}"
+    );
+    assert!(!text.contains("More watchers"), "{text}");
+}
+
+#[tokio::test]
+async fn test_unpaged_server_is_paged_locally() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/rest/api/2/issue/WAT-4/watchers")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("startAt".to_string(), "1".to_string()),
+            mockito::Matcher::UrlEncoded("maxResults".to_string(), "2".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "watchCount": 4,
+                "watchers": [watcher("a", "A"), watcher("b", "B"), watcher("c", "C"), watcher("d", "D")]
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let text = watchers_text(
+        &server,
+        json!({"issue_key": "WAT-4", "start_at": 1, "max_results": 2}),
+    )
+    .await;
+    assert_eq!(
+        text,
+        "Issue WAT-4 has 4 watchers\n• B (b)\n• C (c)\nMore watchers: call again with start_at=3\n"
+    );
+}
+
+#[tokio::test]
+async fn test_paged_server_gives_the_next_start() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/rest/api/2/issue/WAT-5/watchers")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("startAt".to_string(), "50".to_string()),
+            mockito::Matcher::UrlEncoded("maxResults".to_string(), "2".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "watchCount": 120,
+                "startAt": 50,
+                "maxResults": 2,
+                "watchers": [watcher("w50", "W50"), watcher("w51", "W51")]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let text = watchers_text(
+        &server,
+        json!({"issue_key": "WAT-5", "start_at": 50, "max_results": 2}),
+    )
+    .await;
+    assert_eq!(
+        text,
+        "Issue WAT-5 has 120 watchers\n• W50 (w50)\n• W51 (w51)\nMore watchers: call again with start_at=52\n"
+    );
+}
@@ -0,0 +1,236 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use mockito::Matcher;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::webhooks::{parse_expiration, plan_refresh, JiraWebhook};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{ListWebhooksTool, RegisterWebhookTool};
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        webhook_secret: Some("s3cret&more".to_string()),
+        webhook_public_url: Some("https://hooks.example.com/webhooks/jira".to_string()),
+        ..Default::default()
+    }
+}
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 10, 17, 12, 0, 0).unwrap()
+}
+
+fn expiring(id: i64, expiration: Option<DateTime<Utc>>) -> JiraWebhook {
+    JiraWebhook {
+        id,
+        name: None,
+        url: None,
+        events: vec!["jira:issue_updated".to_string()],
+        jql_filter: Some("project = HOOK".to_string()),
+        expiration_date: expiration,
+        enabled: None,
+    }
+}
+
+#[test]
+fn test_refresh_plan() {
+    let now = now();
+
+    // Nothing registered: look again in a day
+    let plan = plan_refresh(&[], now);
+    assert!(plan.due.is_empty());
+    assert_eq!(plan.next_check, now + Duration::days(1));
+
+    let webhooks = [
+        expiring(1, Some(now + Duration::days(30))),
+        expiring(2, Some(now + Duration::days(7))),
+        expiring(3, Some(now - Duration::hours(1))),
+        expiring(4, Some(now + Duration::days(6) + Duration::hours(23))),
+        expiring(5, None),
+    ];
+    let plan = plan_refresh(&webhooks, now);
+    assert_eq!(plan.due, vec![2, 3, 4], "expiring within 7 days or expired");
+    // Webhook 1 is due in 23 days, past the longest wait
+    assert_eq!(plan.next_check, now + Duration::days(1));
+
+    // Due in 5 hours: check then
+    let plan = plan_refresh(
+        &[expiring(
+            1,
+            Some(now + Duration::days(7) + Duration::hours(5)),
+        )],
+        now,
+    );
+    assert!(plan.due.is_empty());
+    assert_eq!(plan.next_check, now + Duration::hours(5));
+
+    // Due in 10 minutes: wait at least an hour between checks
+    let plan = plan_refresh(
+        &[expiring(
+            1,
+            Some(now + Duration::days(7) + Duration::minutes(10)),
+        )],
+        now,
+    );
+    assert_eq!(plan.next_check, now + Duration::hours(1));
+}
+
+#[test]
+fn test_parse_webhooks() {
+    let cloud = JiraWebhook::from_cloud(&json!({
+        "id": 10000,
+        "jqlFilter": "project = HOOK",
+        "events": ["jira:issue_created", "jira:issue_updated"],
+        "expirationDate": "2025-11-16T12:00:00.000+0000"
+    }))
+    .unwrap();
+    assert_eq!(cloud.id, 10000);
+    assert_eq!(
+        cloud.expiration_date,
+        Some(Utc.with_ymd_and_hms(2025, 11, 16, 12, 0, 0).unwrap())
+    );
+    assert_eq!(
+        cloud.headline(),
+        "10000: jira:issue_created, jira:issue_updated for project = HOOK, expires 2025-11-16 12:00 UTC"
+    );
+    assert_eq!(
+        parse_expiration(&json!(1_763_294_400_000_i64)),
+        Some(Utc.with_ymd_and_hms(2025, 11, 16, 12, 0, 0).unwrap())
+    );
+    assert_eq!(parse_expiration(&json!("soon")), None);
+
+    let dc = JiraWebhook::from_dc(&json!({
+        "name": "rust-jira-mcp",
+        "url": "https://hooks.example.com/webhooks/jira",
+        "events": ["jira:issue_created"],
+        "filters": {"issue-related-events-section": ""},
+        "self": "https://jira.example.com/rest/webhooks/1.0/webhook/7",
+        "enabled": false
+    }))
+    .unwrap();
+    assert_eq!(dc.id, 7);
+    assert_eq!(dc.jql_filter, None);
+    assert_eq!(dc.expiration_date, None);
+    assert!(dc.headline().ends_with(", disabled"));
+}
+
+#[test]
+fn test_registration_needs_the_receiver_url() {
+    let config = create_test_config("https://jira.example.com");
+    assert_eq!(
+        config.webhook_registration_url().unwrap(),
+        "https://hooks.example.com/webhooks/jira?token=s3cret%26more"
+    );
+
+    let unset = JiraConfig {
+        webhook_public_url: None,
+        ..config.clone()
+    };
+    let error = unset.webhook_registration_url().unwrap_err().to_string();
+    assert!(error.contains("JIRA_WEBHOOK_PUBLIC_URL"), "{error}");
+
+    let invalid = JiraConfig {
+        webhook_public_url: Some("hooks.example.com".to_string()),
+        ..config
+    };
+    assert!(invalid.webhook_registration_url().is_err());
+    assert!(invalid.validate().is_err());
+}
+
+#[tokio::test]
+async fn test_data_center_webhooks_use_the_admin_api() {
+    let mut server = mockito::Server::new_async().await;
+    let list = server
+        .mock("GET", "/rest/webhooks/1.0/webhook")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([{
+                "name": "rust-jira-mcp",
+                "url": "https://hooks.example.com/webhooks/jira",
+                "events": ["jira:issue_created", "comment_created"],
+                "filters": {"issue-related-events-section": "project = OPS"},
+                "self": format!("{}/rest/webhooks/1.0/webhook/12", server.url()),
+                "enabled": true
+            }])
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let register = server
+        .mock("POST", "/rest/webhooks/1.0/webhook")
+        .match_body(Matcher::PartialJson(json!({
+            "name": "rust-jira-mcp",
+            "url": "https://hooks.example.com/webhooks/jira?token=s3cret%26more",
+            "events": ["jira:issue_created", "jira:issue_updated", "comment_created"],
+            "filters": {"issue-related-events-section": "project = OPS"}
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"self": format!("{}/rest/webhooks/1.0/webhook/13", server.url())}).to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let delete = server
+        .mock("DELETE", "/rest/webhooks/1.0/webhook/13")
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+    let config = create_test_config(&server.url());
+
+    let result = ListWebhooksTool::new(config.clone())
+        .handle(json!({}))
+        .await
+        .unwrap();
+    assert!(result.content[0]
+        .text
+        .contains("12 (rust-jira-mcp): jira:issue_created, comment_created for project = OPS"));
+
+    let result = RegisterWebhookTool::new(config.clone())
+        .handle(json!({"jql_filter": "project = OPS"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Registered webhook 13 for jira:issue_created, jira:issue_updated, comment_created on issues matching project = OPS"
+    );
+
+    JiraClient::new(config)
+        .unwrap()
+        .delete_webhook(13)
+        .await
+        .unwrap();
+
+    list.assert_async().await;
+    register.assert_async().await;
+    delete.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_register_tool_refuses_without_receiver_url() {
+    let mut server = mockito::Server::new_async().await;
+    let register = server
+        .mock("POST", "/rest/webhooks/1.0/webhook")
+        .expect(0)
+        .create_async()
+        .await;
+    let config = JiraConfig {
+        webhook_public_url: None,
+        ..create_test_config(&server.url())
+    };
+
+    let error = RegisterWebhookTool::new(config)
+        .handle(json!({"jql_filter": "project = OPS"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("JIRA_WEBHOOK_PUBLIC_URL"), "{error}");
+    register.assert_async().await;
+}
@@ -0,0 +1,367 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::events::{
+    jira_events, EventBuffer, JiraEvent, JiraEventFilter, JiraEventType,
+};
+use rust_jira_mcp::mcp::server::{MCPServer, MCPToolHandler};
+use rust_jira_mcp::mcp::tools::GetRecentJiraEventsTool;
+use rust_jira_mcp::mcp::transport::webhook::{
+    hmac_sha256, signature_header, verify_signature, WebhookReceiver, WEBHOOK_PATH,
+};
+use serde_json::{json, Value};
+
+const CLOUD_ISSUE_UPDATED: &str = include_str!("fixtures/webhook_cloud_issue_updated.json");
+const CLOUD_COMMENT_CREATED: &str = include_str!("fixtures/webhook_cloud_comment_created.json");
+const DC_ISSUE_COMMENTED: &str = include_str!("fixtures/webhook_dc_issue_commented.json");
+
+const SECRET: &str = "It's a Secret to Everybody";
+
+fn event(payload: &str) -> JiraEvent {
+    JiraEvent::from_webhook(&serde_json::from_str::<Value>(payload).unwrap()).unwrap()
+}
+
+/// A minimal payload for `issue_key` of `webhook_event`
+fn payload(webhook_event: &str, issue_key: &str) -> Value {
+    json!({
+        "timestamp": 1_760_695_200_000_i64,
+        "webhookEvent": webhook_event,
+        "issue": {"id": "1", "key": issue_key, "fields": {"summary": "Test"}}
+    })
+}
+
+fn test_config() -> JiraConfig {
+    JiraConfig {
+        api_base_url: "http://127.0.0.1:9/rest/api/2".to_string(),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        webhook_listen_addr: Some("127.0.0.1:0".to_string()),
+        webhook_secret: Some(SECRET.to_string()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_hmac_and_signature_verification() {
+    // RFC 4231 test case 2
+    let digest = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+    let hex: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+    assert_eq!(
+        hex,
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+    // RFC 4231 test case 6: a key longer than the block size
+    let digest = hmac_sha256(
+        &[0xaa; 131],
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+    );
+    assert_eq!(digest[..4], [0x60, 0xe4, 0x31, 0x59]);
+
+    let body = CLOUD_ISSUE_UPDATED.as_bytes();
+    let signature = signature_header(SECRET, body);
+    assert!(signature.starts_with("sha256="));
+    assert!(verify_signature(SECRET, body, &signature));
+    assert!(verify_signature(
+        SECRET,
+        body,
+        &signature.to_uppercase().replace("SHA256=", "sha256=")
+    ));
+    assert!(!verify_signature("another secret", body, &signature));
+    assert!(!verify_signature(
+        SECRET,
+        b"{\"tampered\": true}",
+        &signature
+    ));
+    assert!(!verify_signature(
+        SECRET,
+        body,
+        &signature.replace("sha256=", "sha1=")
+    ));
+    assert!(!verify_signature(SECRET, body, "sha256="));
+    assert!(!verify_signature(SECRET, body, ""));
+}
+
+#[test]
+fn test_normalize_cloud_and_dc_payloads() {
+    let updated = event(CLOUD_ISSUE_UPDATED);
+    assert_eq!(updated.event_type, JiraEventType::IssueUpdated);
+    assert_eq!(updated.webhook_event, "jira:issue_updated");
+    assert_eq!(updated.issue_key.as_deref(), Some("HOOK-7"));
+    assert_eq!(updated.issue_id.as_deref(), Some("10042"));
+    assert_eq!(updated.project_key.as_deref(), Some("HOOK"));
+    assert_eq!(updated.summary.as_deref(), Some("Login fails with SSO"));
+    assert_eq!(updated.actor.as_deref(), Some("Alice Smith"));
+    assert_eq!(updated.timestamp.timestamp_millis(), 1_760_695_200_000);
+    assert_eq!(updated.changes.len(), 2);
+    assert_eq!(updated.changes[0].field, "status");
+    assert_eq!(updated.changes[0].from.as_deref(), Some("To Do"));
+    assert_eq!(updated.changes[0].to.as_deref(), Some("In Progress"));
+    assert_eq!(updated.changes[1].from, None);
+    assert_eq!(updated.comment, None);
+    assert_eq!(
+        updated.headline(),
+        "#0 issue_updated HOOK-7 \"Login fails with SSO\" by Alice Smith: status To Do → In Progress; assignee (none) → Alice Smith"
+    );
+
+    // Cloud names comment events itself and leaves out the user
+    let cloud_comment = event(CLOUD_COMMENT_CREATED);
+    assert_eq!(cloud_comment.event_type, JiraEventType::CommentCreated);
+    assert_eq!(cloud_comment.actor.as_deref(), Some("Carol White"));
+    assert_eq!(
+        cloud_comment.comment.as_deref(),
+        Some("Reproduced on Firefox too.")
+    );
+
+    // Data Center sends comments as issue updates
+    let dc_comment = event(DC_ISSUE_COMMENTED);
+    assert_eq!(dc_comment.event_type, JiraEventType::CommentCreated);
+    assert_eq!(dc_comment.webhook_event, "jira:issue_updated");
+    assert_eq!(dc_comment.issue_key.as_deref(), Some("OPS-128"));
+    assert_eq!(dc_comment.project_key.as_deref(), Some("OPS"));
+    assert_eq!(dc_comment.actor.as_deref(), Some("Bob Jones"));
+    assert_eq!(
+        dc_comment.comment.as_deref(),
+        Some("Scheduled for the Saturday maintenance window.")
+    );
+
+    // The project falls back to the issue key
+    let created = JiraEvent::from_webhook(&payload("jira:issue_created", "NEW-1")).unwrap();
+    assert_eq!(created.event_type, JiraEventType::IssueCreated);
+    assert_eq!(created.project_key.as_deref(), Some("NEW"));
+    let other = JiraEvent::from_webhook(&payload("worklog_created", "NEW-1")).unwrap();
+    assert_eq!(other.event_type, JiraEventType::Other);
+    assert!(other.headline().contains("other (worklog_created)"));
+
+    let error = JiraEvent::from_webhook(&json!({"issue": {}})).unwrap_err();
+    assert!(error.to_string().contains("webhookEvent is missing"));
+}
+
+#[test]
+fn test_buffer_eviction_and_filters() {
+    let buffer = EventBuffer::new(3);
+    for (n, kind) in [
+        "jira:issue_created",
+        "jira:issue_updated",
+        "comment_created",
+        "jira:issue_updated",
+        "jira:issue_deleted",
+    ]
+    .iter()
+    .enumerate()
+    {
+        let project = if n % 2 == 0 { "AAA" } else { "BBB" };
+        let sequence = buffer
+            .push(JiraEvent::from_webhook(&payload(kind, &format!("{project}-{n}"))).unwrap());
+        assert_eq!(sequence, n as u64 + 1);
+    }
+
+    // The two oldest were dropped to keep three
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.evicted(), 2);
+    assert_eq!(buffer.last_sequence(), 5);
+    let all = buffer.recent(&JiraEventFilter::default(), 10);
+    let sequences: Vec<u64> = all.iter().map(|event| event.sequence).collect();
+    assert_eq!(sequences, vec![5, 4, 3], "newest first");
+    assert_eq!(buffer.recent(&JiraEventFilter::default(), 1).len(), 1);
+
+    let aaa = JiraEventFilter {
+        project_keys: vec!["aaa".to_string()],
+        ..Default::default()
+    };
+    let keys: Vec<String> = buffer
+        .recent(&aaa, 10)
+        .into_iter()
+        .filter_map(|event| event.issue_key)
+        .collect();
+    assert_eq!(keys, vec!["AAA-4", "AAA-2"]);
+
+    let updates = JiraEventFilter {
+        event_types: vec![JiraEventType::IssueUpdated, JiraEventType::IssueDeleted],
+        ..Default::default()
+    };
+    assert_eq!(buffer.recent(&updates, 10).len(), 2);
+    let by_issue = JiraEventFilter {
+        issue_key: Some("bbb-3".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(buffer.recent(&by_issue, 10)[0].sequence, 4);
+
+    let since: Vec<u64> = buffer.since(3).iter().map(|event| event.sequence).collect();
+    assert_eq!(since, vec![4, 5], "oldest first");
+    assert_eq!(buffer.since(0).len(), 3, "only what is still kept");
+    assert!(buffer.since(5).is_empty());
+
+    buffer.set_capacity(1);
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer.evicted(), 4);
+    assert_eq!(buffer.since(0)[0].sequence, 5);
+}
+
+/// Send `body` to the receiver at `addr` and return the status
+async fn post(addr: std::net::SocketAddr, path: &str, body: &str, signature: Option<&str>) -> u16 {
+    let mut request = reqwest::Client::new()
+        .post(format!("http://{addr}{path}"))
+        .header("content-type", "application/json")
+        .body(body.to_string());
+    if let Some(signature) = signature {
+        request = request.header("X-Hub-Signature", signature);
+    }
+    request.send().await.unwrap().status().as_u16()
+}
+
+#[tokio::test]
+async fn test_receiver_accepts_only_signed_webhooks() {
+    let receiver = WebhookReceiver::bind("127.0.0.1:0", SECRET).await.unwrap();
+    let addr = receiver.local_addr().unwrap();
+    tokio::spawn(receiver.run());
+
+    let body = payload("jira:issue_created", "RECV-1").to_string();
+    let signature = signature_header(SECRET, body.as_bytes());
+    assert_eq!(post(addr, WEBHOOK_PATH, &body, Some(&signature)).await, 202);
+    assert_eq!(
+        post(
+            addr,
+            WEBHOOK_PATH,
+            &body,
+            Some(&signature_header("wrong", body.as_bytes()))
+        )
+        .await,
+        401
+    );
+    assert_eq!(post(addr, WEBHOOK_PATH, &body, None).await, 401);
+    let token_path = format!("{WEBHOOK_PATH}?token={}", urlencoding::encode(SECRET));
+    let token_body = payload("jira:issue_created", "RECV-2").to_string();
+    assert_eq!(post(addr, &token_path, &token_body, None).await, 202);
+    assert_eq!(
+        post(addr, "/webhooks/other", &body, Some(&signature)).await,
+        404
+    );
+    let not_json = "not json";
+    assert_eq!(
+        post(
+            addr,
+            WEBHOOK_PATH,
+            not_json,
+            Some(&signature_header(SECRET, not_json.as_bytes()))
+        )
+        .await,
+        400
+    );
+    let status = reqwest::get(format!("http://{addr}{WEBHOOK_PATH}"))
+        .await
+        .unwrap()
+        .status();
+    assert_eq!(status.as_u16(), 405);
+
+    let received = jira_events().recent(
+        &JiraEventFilter {
+            project_keys: vec!["RECV".to_string()],
+            ..Default::default()
+        },
+        10,
+    );
+    let keys: Vec<String> = received
+        .into_iter()
+        .filter_map(|event| event.issue_key)
+        .collect();
+    assert_eq!(keys, vec!["RECV-2", "RECV-1"]);
+}
+
+#[tokio::test]
+async fn test_events_reach_subscribed_clients_and_the_tool() {
+    let config = test_config();
+    let initialized = json!({"jsonrpc": "2.0", "method": "notifications/initialized"});
+    let initialize = |capabilities: Value| {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-03-26",
+                "capabilities": capabilities,
+                "clientInfo": {"name": "test-client", "version": "1.0.0"}
+            }
+        })
+    };
+
+    let mut subscribed = MCPServer::new(config.clone());
+    let response = subscribed
+        .handle_request(&initialize(json!({"experimental": {"jiraEvents": {}}})).to_string())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        response.result.unwrap()["capabilities"]["experimental"],
+        json!({"jiraEvents": {}})
+    );
+    subscribed
+        .handle_request(&initialized.to_string())
+        .await
+        .unwrap();
+    let mut other = MCPServer::new(config.clone());
+    other
+        .handle_request(&initialize(json!({})).to_string())
+        .await
+        .unwrap();
+
+    jira_events().push(event(DC_ISSUE_COMMENTED));
+
+    subscribed.queue_jira_events();
+    let notifications: Vec<Value> = subscribed
+        .take_notifications()
+        .into_iter()
+        .filter(|n| n["params"]["issue_key"] == "OPS-128")
+        .collect();
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(notifications[0]["method"], "notifications/jira/event");
+    assert_eq!(notifications[0]["params"]["event_type"], "comment_created");
+    // Each event is sent once
+    subscribed.queue_jira_events();
+    assert!(subscribed
+        .take_notifications()
+        .iter()
+        .all(|n| n["params"]["issue_key"] != "OPS-128"));
+    other.queue_jira_events();
+    assert!(other.take_notifications().is_empty());
+
+    let result = GetRecentJiraEventsTool::new(config.clone())
+        .handle(json!({"project_key": "OPS", "event_type": ["comment_created"]}))
+        .await
+        .unwrap();
+    assert_eq!(result.is_error, Some(false));
+    assert!(result.content[0].text.contains(
+        "comment_created OPS-128 \"Rotate the database credentials\" by Bob Jones: Scheduled for the Saturday maintenance window."
+    ));
+
+    let error = GetRecentJiraEventsTool::new(config.clone())
+        .handle(json!({"event_type": "issue_moved"}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Unknown event type 'issue_moved'"));
+
+    let off = GetRecentJiraEventsTool::new(JiraConfig {
+        webhook_listen_addr: None,
+        ..config
+    })
+    .handle(json!({}))
+    .await
+    .unwrap();
+    assert_eq!(off.is_error, Some(true));
+    assert!(off.content[0].text.contains("JIRA_WEBHOOK_LISTEN_ADDR"));
+}
+
+#[test]
+fn test_listen_address_needs_a_secret() {
+    let config = JiraConfig {
+        webhook_secret: None,
+        ..test_config()
+    };
+    let error = config.validate().unwrap_err().to_string();
+    assert!(error.contains("webhook_secret must be set"), "{error}");
+    assert!(test_config().validate().is_ok());
+    let bad_addr = JiraConfig {
+        webhook_listen_addr: Some("8787".to_string()),
+        ..test_config()
+    };
+    assert!(bad_addr.validate().is_err());
+}
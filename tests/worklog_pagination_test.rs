@@ -0,0 +1,201 @@
+use chrono::{TimeZone, Utc};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::tools::{GetIssueWorkLogsTool, WorkLogFilter};
+use rust_jira_mcp::utils::time::format_time_spent;
+use serde_json::{json, Value};
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        display_timezone: Some("UTC".to_string()),
+        ..Default::default()
+    }
+}
+
+/// Worklog `n` of 45: an hour each, started on consecutive days of March
+/// 2024, by alice when `n` is even and bob otherwise
+fn work_log(n: u32) -> Value {
+    let author = if n.is_multiple_of(2) { "alice" } else { "bob" };
+    let started =
+        Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap() + chrono::Duration::days(i64::from(n));
+    json!({
+        "id": format!("{}", 100 + n),
+        "author": {
+            "name": author,
+            "accountId": format!("acc-{author}"),
+            "displayName": format!("{} Example", author.to_uppercase())
+        },
+        "comment": format!("Entry {n}"),
+        "created": "2024-05-01T10:00:00.000+0000",
+        "started": started.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string(),
+        "timeSpent": "1h",
+        "timeSpentSeconds": 3600
+    })
+}
+
+/// Jira caps pages at 20 whatever `maxResults` asks for
+async fn mock_pages(server: &mut mockito::ServerGuard, expected: [usize; 3]) -> Vec<mockito::Mock> {
+    let mut mocks = Vec::new();
+    for (page, expect) in expected.into_iter().enumerate() {
+        let start = page * 20;
+        let end = (start + 20).min(45);
+        let work_logs: Vec<Value> = (start..end)
+            .map(|n| work_log(u32::try_from(n).unwrap()))
+            .collect();
+        mocks.push(
+            server
+                .mock("GET", "/rest/api/2/issue/LOG-1/worklog")
+                .match_query(mockito::Matcher::UrlEncoded(
+                    "startAt".to_string(),
+                    start.to_string(),
+                ))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    json!({"startAt": start, "maxResults": 20, "total": 45, "worklogs": work_logs})
+                        .to_string(),
+                )
+                .expect(expect)
+                .create_async()
+                .await,
+        );
+    }
+    mocks
+}
+
+#[test]
+fn test_format_time_spent() {
+    assert_eq!(format_time_spent(0), "0m");
+    assert_eq!(format_time_spent(45 * 60), "45m");
+    assert_eq!(format_time_spent(3 * 3600), "3h");
+    assert_eq!(format_time_spent(26 * 3600 + 5 * 60 + 59), "26h 5m");
+}
+
+#[test]
+fn test_work_log_filter() {
+    let filter = WorkLogFilter::from_args(&json!({
+        "started_after": "2024-03-05",
+        "started_before": "2024-03-07T09:00:00+00:00",
+        "author": "Alice Example"
+    }))
+    .unwrap();
+    // Entry n started on March n + 1
+    assert!(!filter.matches(&work_log(2)));
+    assert!(filter.matches(&work_log(4)));
+    assert!(!filter.matches(&work_log(5)));
+    assert!(!filter.matches(&work_log(6)));
+    assert!(WorkLogFilter::from_args(&json!({})).unwrap().is_empty());
+
+    let error = WorkLogFilter::from_args(&json!({"started_before": "soon"})).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("'soon' is not a YYYY-MM-DD date or an ISO timestamp"));
+}
+
+#[tokio::test]
+async fn test_every_page_is_read_and_totalled() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let pages = mock_pages(&mut server, [1, 1, 1]).await;
+
+    let tool = GetIssueWorkLogsTool::new(create_test_config(&base_url));
+    let result = tool.handle(json!({"issue_key": "LOG-1"})).await.unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        "Found 45 work logs for issue LOG-1: 45h logged\n\n"
+    );
+    assert_eq!(result.content.len(), 46);
+    assert_eq!(
+        result.content[45].text,
+        "• 144 - 1h by ALICE Example on 2024-04-14 09:00 +00:00\nEntry 44\n"
+    );
+    for page in pages {
+        page.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_filtered_total_counts_only_matching_entries() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _pages = mock_pages(&mut server, [1, 1, 1]).await;
+
+    let tool = GetIssueWorkLogsTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "LOG-1", "author": "acc-bob", "started_before": "2024-04-01"}))
+        .await
+        .unwrap();
+
+    // Bob logged the odd entries; entries 0 to 30 started in March
+    assert_eq!(
+        result.content[0].text,
+        "Found 15 of 45 work logs for issue LOG-1 by acc-bob started before \
+         2024-04-01T00:00:00+00:00: 15h logged\n\n"
+    );
+}
+
+#[tokio::test]
+async fn test_reading_stops_at_the_configured_cap() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let pages = mock_pages(&mut server, [1, 1, 0]).await;
+
+    let tool = GetIssueWorkLogsTool::new(JiraConfig {
+        max_worklogs: Some(30),
+        ..create_test_config(&base_url)
+    });
+    let result = tool.handle(json!({"issue_key": "LOG-1"})).await.unwrap();
+
+    assert_eq!(
+        result.content[0].text,
+        "Found 30 work logs for issue LOG-1: 30h logged\n\
+         Only the first 30 of 45 work logs were read (JIRA_MAX_WORKLOGS is 30)\n\n"
+    );
+    for page in pages {
+        page.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_started_after_is_sent_to_jira() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let page = server
+        .mock("GET", "/rest/api/2/issue/LOG-2/worklog")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "startedAfter".to_string(),
+            Utc.with_ymd_and_hms(2024, 4, 10, 0, 0, 0)
+                .unwrap()
+                .timestamp_millis()
+                .to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"startAt": 0, "maxResults": 20, "total": 1, "worklogs": [work_log(44)]})
+                .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = GetIssueWorkLogsTool::new(create_test_config(&base_url));
+    let result = tool
+        .handle(json!({"issue_key": "LOG-2", "started_after": "2024-04-10"}))
+        .await
+        .unwrap();
+
+    assert!(result.content[0].text.starts_with(
+        "Found 1 of 1 work logs for issue LOG-2 started after 2024-04-10T00:00:00+00:00: 1h logged"
+    ));
+    page.assert_async().await;
+}
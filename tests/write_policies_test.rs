@@ -0,0 +1,303 @@
+use rust_jira_mcp::config::policies::{check_write, validate_policies, PolicyEffect, WritePolicy};
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::error::{JiraError, Result};
+use rust_jira_mcp::mcp::server::{MCPServer, MCPToolHandler};
+use rust_jira_mcp::mcp::tools::ListWritePoliciesTool;
+use rust_jira_mcp::types::mcp::MCPToolCall;
+use serde_json::{json, Value};
+
+fn policy(name: &str, effect: PolicyEffect) -> WritePolicy {
+    WritePolicy {
+        name: name.to_string(),
+        effect,
+        tools: Vec::new(),
+        fields: Vec::new(),
+        projects: Vec::new(),
+        issues: Vec::new(),
+        pattern: None,
+        text_fields: Vec::new(),
+        message: None,
+    }
+}
+
+fn no_priority_on_sec() -> WritePolicy {
+    WritePolicy {
+        fields: vec!["Priority".to_string()],
+        issues: vec!["sec-*".to_string()],
+        ..policy("no-priority-on-sec", PolicyEffect::Deny)
+    }
+}
+
+fn ticket_reference() -> WritePolicy {
+    WritePolicy {
+        tools: vec!["*comment*".to_string()],
+        pattern: Some("[A-Z][A-Z0-9]+-[0-9]+".to_string()),
+        ..policy("ticket-reference", PolicyEffect::Require)
+    }
+}
+
+fn rule_of(result: Result<()>) -> Option<String> {
+    match result {
+        Ok(()) => None,
+        Err(JiraError::PolicyViolation { rule, .. }) => Some(rule),
+        Err(e) => panic!("unexpected error {e}"),
+    }
+}
+
+#[test]
+fn test_deny_needs_every_matcher() {
+    let policies = [no_priority_on_sec()];
+    let update = |key: &str, fields: Value| {
+        rule_of(check_write(
+            &policies,
+            "update_jira_issue",
+            &json!({"issue_id_or_key": key, "fields": fields}),
+        ))
+    };
+    assert_eq!(
+        update("SEC-1", json!({"priority": {"name": "High"}})),
+        Some("no-priority-on-sec".to_string())
+    );
+    assert_eq!(update("SEC-1", json!({"summary": "x"})), None);
+    assert_eq!(update("OPS-1", json!({"priority": {"name": "High"}})), None);
+
+    // Bulk calls are refused when any of their issues matches
+    let bulk = json!({
+        "issue_keys": ["OPS-1", "SEC-2"],
+        "update_data": {"update": {"priority": [{"set": {"name": "Low"}}]}}
+    });
+    assert!(rule_of(check_write(&policies, "bulk_update_issues", &bulk)).is_some());
+    let mixed = json!({"operations": [
+        {"issue_key": "SEC-3", "operation_type": "update", "data": {"fields": {"priority": {"id": "1"}}}}
+    ]});
+    assert!(rule_of(check_write(&policies, "mixed_bulk_operations", &mixed)).is_some());
+}
+
+#[test]
+fn test_first_allow_or_deny_wins() {
+    let allow_admin = WritePolicy {
+        tools: vec!["update_jira_issue".to_string()],
+        projects: vec!["SEC".to_string()],
+        ..policy("admin-updates", PolicyEffect::Allow)
+    };
+    let deny_all = policy("read-only", PolicyEffect::Deny);
+    let args = json!({"issue_id_or_key": "SEC-1", "fields": {"priority": {"name": "High"}}});
+
+    let allow_first = [allow_admin.clone(), no_priority_on_sec(), deny_all.clone()];
+    assert_eq!(
+        rule_of(check_write(&allow_first, "update_jira_issue", &args)),
+        None
+    );
+    // The allow rule does not cover other tools
+    assert_eq!(
+        rule_of(check_write(
+            &allow_first,
+            "bulk_update_issues",
+            &json!({"issue_keys": ["SEC-1"]})
+        )),
+        Some("read-only".to_string())
+    );
+
+    let deny_first = [no_priority_on_sec(), allow_admin, deny_all];
+    assert_eq!(
+        rule_of(check_write(&deny_first, "update_jira_issue", &args)),
+        Some("no-priority-on-sec".to_string())
+    );
+}
+
+#[test]
+fn test_require_checks_text_until_a_decision() {
+    let policies = [ticket_reference()];
+    let comment = |body: &str| {
+        rule_of(check_write(
+            &policies,
+            "add_jira_comment",
+            &json!({"issue_key": "OPS-1", "comment_body": body}),
+        ))
+    };
+    assert_eq!(comment("Fixed by OPS-2"), None);
+    assert_eq!(comment("Fixed"), Some("ticket-reference".to_string()));
+    // Document format bodies are checked by their text
+    assert_eq!(
+        rule_of(check_write(
+            &policies,
+            "add_jira_comment",
+            &json!({"issue_key": "OPS-1", "comment_body": {"type": "doc", "content": [
+                {"type": "paragraph", "content": [{"type": "text", "text": "See OPS-9"}]}
+            ]}})
+        )),
+        None
+    );
+    // Calls without the text, and other tools, are not checked
+    assert_eq!(
+        rule_of(check_write(
+            &policies,
+            "transition_jira_issue",
+            &json!({"issue_key": "OPS-1"})
+        )),
+        None
+    );
+
+    // An earlier allow exempts the call; a later one does not
+    let exempt = WritePolicy {
+        projects: vec!["OPS".to_string()],
+        ..policy("ops-free-text", PolicyEffect::Allow)
+    };
+    let args = json!({"issue_key": "OPS-1", "comment": "Fixed"});
+    assert_eq!(
+        rule_of(check_write(
+            &[exempt.clone(), ticket_reference()],
+            "bulk_add_comments",
+            &args
+        )),
+        None
+    );
+    assert_eq!(
+        rule_of(check_write(
+            &[ticket_reference(), exempt],
+            "bulk_add_comments",
+            &args
+        )),
+        Some("ticket-reference".to_string())
+    );
+}
+
+#[test]
+fn test_violation_names_the_rule() {
+    let error = check_write(
+        &[no_priority_on_sec()],
+        "update_jira_issue",
+        &json!({"issue_id_or_key": "SEC-1", "fields": {"priority": {"id": "2"}}}),
+    )
+    .unwrap_err()
+    .to_string();
+    assert_eq!(
+        error,
+        "Policy violation: no-priority-on-sec - update_jira_issue is not allowed \
+         (deny writes setting Priority on sec-*)"
+    );
+
+    let custom = WritePolicy {
+        message: Some("Comments must mention a ticket".to_string()),
+        ..ticket_reference()
+    };
+    let error = check_write(&[custom], "add_jira_comment", &json!({"comment_body": "x"}))
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        error,
+        "Policy violation: ticket-reference - Comments must mention a ticket"
+    );
+}
+
+#[test]
+fn test_validate_policies() {
+    assert!(validate_policies(&[no_priority_on_sec(), ticket_reference()]).is_ok());
+    assert!(validate_policies(&[policy(" ", PolicyEffect::Deny)]).is_err());
+    assert!(validate_policies(&[policy("x", PolicyEffect::Require)]).is_err());
+    let invalid = WritePolicy {
+        pattern: Some("([".to_string()),
+        ..ticket_reference()
+    };
+    assert!(validate_policies(&[invalid]).is_err());
+    let stray = WritePolicy {
+        pattern: Some("x".to_string()),
+        ..no_priority_on_sec()
+    };
+    assert!(validate_policies(&[stray]).is_err());
+}
+
+#[tokio::test]
+async fn test_server_refuses_writes_before_calling_jira() {
+    let mut server = mockito::Server::new_async().await;
+    let update = server
+        .mock("PUT", "/rest/api/2/issue/SEC-1")
+        .expect(0)
+        .create_async()
+        .await;
+    let config = JiraConfig {
+        api_base_url: format!("{}/rest/api/2", server.url()),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        policies: Some(vec![no_priority_on_sec(), ticket_reference()]),
+        ..Default::default()
+    };
+    let mcp = MCPServer::new(config.clone());
+
+    let error = mcp
+        .call_tool(MCPToolCall {
+            name: "update_jira_issue".to_string(),
+            arguments: json!({"issue_id_or_key": "SEC-1", "fields": {"priority": {"name": "Highest"}}}),
+        })
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&error, JiraError::PolicyViolation { rule, .. } if rule == "no-priority-on-sec"),
+        "{error}"
+    );
+    update.assert_async().await;
+
+    // Reads are never checked
+    let result = mcp
+        .call_tool(MCPToolCall {
+            name: "list_write_policies".to_string(),
+            arguments: json!({"comment": "no reference"}),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "2 write policies, checked in order; the first allow or deny that matches a call decides:\n\
+         1. no-priority-on-sec: deny writes setting Priority on sec-*\n\
+         2. ticket-reference: require comment, comment_body, body, description, text to match \
+         /[A-Z][A-Z0-9]+-[0-9]+/ in *comment*"
+    );
+
+    let none = ListWritePoliciesTool::new(JiraConfig::default())
+        .handle(json!({}))
+        .await
+        .unwrap();
+    assert!(none.content[0].text.starts_with("No write policies"));
+}
+
+#[test]
+fn test_policies_load_from_configuration_files() {
+    #[derive(serde::Deserialize)]
+    struct ConfigFile {
+        policies: Vec<WritePolicy>,
+    }
+    let file: ConfigFile = config::Config::builder()
+        .add_source(config::File::from_str(
+            r#"
+[[policies]]
+name = "no-priority-on-sec"
+effect = "deny"
+fields = ["priority"]
+issues = ["SEC-*"]
+
+[[policies]]
+name = "ticket-reference"
+effect = "require"
+tools = ["add_jira_comment"]
+pattern = "[A-Z]+-[0-9]+"
+"#,
+            config::FileFormat::Toml,
+        ))
+        .build()
+        .unwrap()
+        .try_deserialize()
+        .unwrap();
+    assert_eq!(file.policies.len(), 2);
+    assert_eq!(file.policies[0].effect, PolicyEffect::Deny);
+    assert_eq!(file.policies[1].pattern.as_deref(), Some("[A-Z]+-[0-9]+"));
+
+    let config = JiraConfig {
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token-123".to_string(),
+        api_base_url: "https://jira.example.com/rest/api/2".to_string(),
+        policies: Some(file.policies),
+        ..Default::default()
+    };
+    assert!(config.validate().is_ok());
+}
@@ -0,0 +1,192 @@
+use base64::Engine;
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::zephyr_tools::{
+    GetZephyrExecutionAttachmentsTool, UploadZephyrExecutionAttachmentTool,
+};
+use serde_json::json;
+
+fn create_test_config(base_url: &str, file_allowlist: Option<String>) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        default_project: None,
+        max_results: Some(50),
+        timeout_seconds: Some(30),
+        log_file: None,
+        strict_ssl: Some(false),
+        file_allowlist,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_execution_attachment_request_building() {
+    let client = JiraClient::new(create_test_config("https://jira.example.com", None)).unwrap();
+    let request = client
+        .zephyr_execution_attachment_request("7 01", "run.log", b"ok", Some("text/plain"))
+        .unwrap();
+
+    assert_eq!(request.method(), reqwest::Method::POST);
+    assert_eq!(
+        request.url().as_str(),
+        "https://jira.example.com/rest/zapi/latest/attachment?entityId=7+01&entityType=execution"
+    );
+    let headers = request.headers();
+    // ZAPI's spelling, not the `no-check` Jira's attachment endpoint takes
+    assert_eq!(headers["X-Atlassian-Token"], "nocheck");
+    assert!(headers["Authorization"]
+        .to_str()
+        .unwrap()
+        .starts_with("Basic "));
+    assert!(headers["Content-Type"]
+        .to_str()
+        .unwrap()
+        .starts_with("multipart/form-data; boundary="));
+    assert_eq!(headers.get_all("Content-Type").iter().count(), 1);
+
+    let error = client
+        .zephyr_execution_attachment_request("701", "run.log", b"ok", Some("not a mime"))
+        .unwrap_err();
+    assert!(error.to_string().contains("Invalid MIME type 'not a mime'"));
+}
+
+#[tokio::test]
+async fn test_upload_execution_attachment_from_base64() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let upload = server
+        .mock("POST", "/rest/zapi/latest/attachment")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("entityId".to_string(), "701".to_string()),
+            mockito::Matcher::UrlEncoded("entityType".to_string(), "execution".to_string()),
+        ]))
+        .match_header("X-Atlassian-Token", "nocheck")
+        .match_header(
+            "content-type",
+            mockito::Matcher::Regex("^multipart/form-data; boundary=".to_string()),
+        )
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex(
+                r#"name="file"; filename="screen.png"\r\nContent-Type: image/png"#.to_string(),
+            ),
+            mockito::Matcher::Regex("PNGDATA".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"success": "File uploaded successfully"}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let tool = UploadZephyrExecutionAttachmentTool::new(create_test_config(&base_url, None));
+    let result = tool
+        .handle(json!({
+            "execution_id": "701",
+            "filename": "screen.png",
+            "content": base64::engine::general_purpose::STANDARD.encode("PNGDATA"),
+            "mime_type": "image/png"
+        }))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Successfully attached screen.png (7 bytes) to test execution 701"
+    );
+    upload.assert_async().await;
+
+    let error = tool
+        .handle(json!({"execution_id": "701", "filename": "screen.png"}))
+        .await
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Missing required parameter: content"));
+}
+
+#[tokio::test]
+async fn test_upload_execution_attachment_from_allowed_file() {
+    let allowed = tempfile::tempdir().unwrap();
+    let log = allowed.path().join("run.log");
+    std::fs::write(&log, "step 3 failed").unwrap();
+    let outside = tempfile::tempdir().unwrap();
+    let secret = outside.path().join("secret.txt");
+    std::fs::write(&secret, "nope").unwrap();
+
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let upload = server
+        .mock("POST", "/rest/zapi/latest/attachment")
+        .match_query(mockito::Matcher::Any)
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex(r#"filename="run.log""#.to_string()),
+            mockito::Matcher::Regex("step 3 failed".to_string()),
+        ]))
+        .with_status(200)
+        .with_body("{}")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let config = create_test_config(
+        &base_url,
+        Some(allowed.path().to_string_lossy().into_owned()),
+    );
+    let tool = UploadZephyrExecutionAttachmentTool::new(config);
+    tool.handle(json!({"execution_id": "702", "file_path": log.to_string_lossy()}))
+        .await
+        .unwrap();
+    upload.assert_async().await;
+
+    assert!(tool
+        .handle(json!({"execution_id": "702", "file_path": secret.to_string_lossy()}))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_get_execution_attachments() {
+    let mut server = mockito::Server::new_async().await;
+    let base_url = server.url();
+    let _list = server
+        .mock("GET", "/rest/zapi/latest/attachment/attachmentsByEntity")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("entityId".to_string(), "701".to_string()),
+            mockito::Matcher::UrlEncoded("entityType".to_string(), "execution".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"data": [{
+                "fileId": "31",
+                "fileName": "screen.png",
+                "fileSize": "21 kB",
+                "mimeType": "image/png",
+                "author": "qa.lead",
+                "dateCreated": "2024-03-02 10:15",
+                "comment": ""
+            }]})
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = JiraClient::new(create_test_config(&base_url, None)).unwrap();
+    let attachments = client
+        .get_zephyr_execution_attachments("701")
+        .await
+        .unwrap();
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].id, "31");
+    assert_eq!(attachments[0].content_type.as_deref(), Some("image/png"));
+
+    let tool = GetZephyrExecutionAttachmentsTool::new(create_test_config(&base_url, None));
+    let result = tool.handle(json!({"execution_id": "701"})).await.unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Attachments for Test Execution 701:\n\n\
+         1. screen.png\n   ID: 31\n   Type: image/png\n   Size: 21 kB\n   Added By: qa.lead\n   Added On: 2024-03-02 10:15\n\n"
+    );
+}
@@ -0,0 +1,248 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::jira::client::JiraClient;
+use rust_jira_mcp::jira::zephyr_report::{CycleReport, ExecutionOutcome};
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::zephyr_tools::{CreateZephyrFolderTool, ZephyrCycleReportTool};
+use rust_jira_mcp::types::jira::{ZephyrAddTestsToCycleRequest, ZephyrFolder, ZephyrTestExecution};
+use serde_json::{json, Value};
+
+const CYCLE_FOLDERS: &str = include_str!("fixtures/zephyr_cycle_folders.json");
+const CYCLE_EXECUTIONS: &str = include_str!("fixtures/zephyr_cycle_executions.json");
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+fn fixture_executions() -> Vec<ZephyrTestExecution> {
+    let response: Value = serde_json::from_str(CYCLE_EXECUTIONS).unwrap();
+    serde_json::from_value(response["executions"].clone()).unwrap()
+}
+
+#[test]
+fn test_folder_fixture_parsing() {
+    let folders: Vec<ZephyrFolder> = serde_json::from_str(CYCLE_FOLDERS).unwrap();
+    assert_eq!(folders.len(), 3);
+    assert_eq!(folders[0].id, 31);
+    assert_eq!(folders[0].name, "Smoke");
+    assert_eq!(
+        folders[0].description.as_deref(),
+        Some("Runs on every build")
+    );
+    assert_eq!(folders[1].total_executed, Some(1));
+    assert_eq!(folders[2].description, None);
+
+    let executions = fixture_executions();
+    assert_eq!(executions[0].folder_id, Some(31));
+    assert_eq!(executions[5].folder_id, None);
+}
+
+#[test]
+fn test_execution_outcomes() {
+    assert_eq!(ExecutionOutcome::from_status("1"), ExecutionOutcome::Passed);
+    assert_eq!(
+        ExecutionOutcome::from_status("pass"),
+        ExecutionOutcome::Passed
+    );
+    assert_eq!(ExecutionOutcome::from_status("2"), ExecutionOutcome::Failed);
+    assert_eq!(
+        ExecutionOutcome::from_status("WIP"),
+        ExecutionOutcome::InProgress
+    );
+    assert_eq!(
+        ExecutionOutcome::from_status("In Progress"),
+        ExecutionOutcome::InProgress
+    );
+    assert_eq!(
+        ExecutionOutcome::from_status("4"),
+        ExecutionOutcome::Blocked
+    );
+    assert_eq!(
+        ExecutionOutcome::from_status("-1"),
+        ExecutionOutcome::Unexecuted
+    );
+    assert_eq!(
+        ExecutionOutcome::from_status("Flaky"),
+        ExecutionOutcome::Other
+    );
+}
+
+#[test]
+fn test_cycle_report_by_folder() {
+    let folders: Vec<ZephyrFolder> = serde_json::from_str(CYCLE_FOLDERS).unwrap();
+    let report = CycleReport::from_executions(&fixture_executions(), &folders);
+
+    assert_eq!(report.overall.total, 6);
+    assert_eq!(report.overall.passed, 3);
+    assert_eq!(report.overall.executed(), 5);
+    assert_eq!(
+        report.overall.describe(),
+        "3/5 passed (60.0%), 1 failed, 1 blocked, 1 unexecuted"
+    );
+
+    let names: Vec<&str> = report.folders.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, ["Smoke", "Regression", "Exploratory", "(no folder)"]);
+    assert_eq!(
+        report.folders[0].rate.describe(),
+        "2/3 passed (66.7%), 1 failed"
+    );
+    assert_eq!(
+        report.folders[1].rate.describe(),
+        "1/1 passed (100.0%), 1 unexecuted"
+    );
+    assert_eq!(report.folders[2].rate.pass_percent(), None);
+    assert_eq!(report.folders[2].rate.describe(), "nothing executed");
+    assert_eq!(report.folders[3].folder_id, None);
+}
+
+#[tokio::test]
+async fn test_zephyr_cycle_report_tool() {
+    let mut server = mockito::Server::new_async().await;
+    let folders = server
+        .mock("GET", "/rest/zapi/latest/cycle/12/folders")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("projectId".to_string(), "10000".to_string()),
+            mockito::Matcher::UrlEncoded("versionId".to_string(), "-1".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(CYCLE_FOLDERS)
+        .expect(1)
+        .create_async()
+        .await;
+    let executions = server
+        .mock("GET", "/rest/zapi/latest/execution")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "cycleId".to_string(),
+            "12".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(CYCLE_EXECUTIONS)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = ZephyrCycleReportTool::new(create_test_config(&server.url()))
+        .handle(json!({"cycle_id": "12", "project_id": "10000"}))
+        .await
+        .unwrap();
+
+    folders.assert_async().await;
+    executions.assert_async().await;
+    let text = &result.content[0].text;
+    assert!(
+        text.starts_with(
+            "Test Cycle 12: 6 executions, 3/5 passed (60.0%), 1 failed, 1 blocked, 1 unexecuted\n\nBy folder:\n\
+             • Smoke: 3 executions, 2/3 passed (66.7%), 1 failed\n"
+        ),
+        "{text}"
+    );
+    assert!(
+        text.ends_with("• (no folder): 1 executions, 0/1 passed (0.0%), 1 blocked"),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_zephyr_cycle_report_for_one_folder() {
+    let mut server = mockito::Server::new_async().await;
+    let executions = server
+        .mock("GET", "/rest/zapi/latest/execution")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "folderId".to_string(),
+            "32".to_string(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"executions": [
+                {"id": "704", "test_case_id": "504", "project_id": "10000", "status": "1", "folderId": 32}
+            ]})
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let result = ZephyrCycleReportTool::new(create_test_config(&server.url()))
+        .handle(
+            json!({"cycle_id": "12", "project_id": "10000", "version_id": "3", "folder_id": "32"}),
+        )
+        .await
+        .unwrap();
+
+    executions.assert_async().await;
+    assert_eq!(
+        result.content[0].text,
+        "Test Cycle 12, folder 32: 1 executions, 1/1 passed (100.0%)"
+    );
+}
+
+#[tokio::test]
+async fn test_create_folder_and_add_tests_to_it() {
+    let mut server = mockito::Server::new_async().await;
+    let create = server
+        .mock("POST", "/rest/zapi/latest/folder/create")
+        .match_body(mockito::Matcher::Json(json!({
+            "name": "Smoke",
+            "description": null,
+            "cycleId": "12",
+            "projectId": "10000",
+            "versionId": "-1"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"id": 31, "responseMessage": "Folder Smoke created successfully."}).to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let add = server
+        .mock("POST", "/rest/zapi/latest/execution/addTestsToCycle/")
+        .match_body(mockito::Matcher::Json(json!({
+            "issues": ["QA-1", "QA-2"],
+            "cycleId": "12",
+            "projectId": "10000",
+            "versionId": "-1",
+            "folderId": 31,
+            "method": "1"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"jobProgressToken": "0001"}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+    let config = create_test_config(&server.url());
+
+    let result = CreateZephyrFolderTool::new(config.clone())
+        .handle(json!({"name": "Smoke", "cycle_id": "12", "project_id": "10000"}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result.content[0].text,
+        "Folder created successfully!\n\nName: Smoke\nID: 31\nCycle ID: 12"
+    );
+
+    let job = JiraClient::new(config)
+        .unwrap()
+        .add_tests_to_cycle(&ZephyrAddTestsToCycleRequest {
+            issues: vec!["QA-1".to_string(), "QA-2".to_string()],
+            cycle_id: "12".to_string(),
+            project_id: "10000".to_string(),
+            version_id: "-1".to_string(),
+            folder_id: Some(31),
+        })
+        .await
+        .unwrap();
+    assert_eq!(job["jobProgressToken"], "0001");
+    create.assert_async().await;
+    add.assert_async().await;
+}
@@ -0,0 +1,156 @@
+use rust_jira_mcp::config::JiraConfig;
+use rust_jira_mcp::mcp::server::MCPToolHandler;
+use rust_jira_mcp::mcp::zephyr_tools::CreateZephyrTestCaseTool;
+use serde_json::json;
+
+fn create_test_config(base_url: &str) -> JiraConfig {
+    JiraConfig {
+        api_base_url: format!("{base_url}/rest/api/2"),
+        email: "test@example.com".to_string(),
+        personal_access_token: "test-token".to_string(),
+        strict_ssl: Some(false),
+        ..Default::default()
+    }
+}
+
+async fn test_case_mock(server: &mut mockito::Server) -> mockito::Mock {
+    server
+        .mock("POST", "/rest/zapi/latest/testcase")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": "501",
+                "key": "STEP-1",
+                "name": "Checkout",
+                "project_key": "STEP",
+                "issue_type": "Test"
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await
+}
+
+async fn step_mock(server: &mut mockito::Server, order: i32, status: usize) -> mockito::Mock {
+    let body = if status == 200 {
+        json!({
+            "id": format!("9{order}"),
+            "step": format!("Step {order}"),
+            "order": order,
+            "test_case_id": "501"
+        })
+    } else {
+        json!({"errorMessages": ["Step could not be saved"]})
+    };
+    server
+        .mock("POST", "/rest/zapi/latest/teststep/501")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({"order": order, "test_case_id": "501"}),
+        ))
+        .with_status(status)
+        .with_header("content-type", "application/json")
+        .with_body(body.to_string())
+        .expect(1)
+        .create_async()
+        .await
+}
+
+fn arguments(steps: usize) -> serde_json::Value {
+    json!({
+        "name": "Checkout",
+        "project_key": "STEP",
+        "issue_type": "Test",
+        "steps": (1..=steps)
+            .map(|order| json!({"step": format!("Step {order}"), "result": "Works"}))
+            .collect::<Vec<_>>()
+    })
+}
+
+#[tokio::test]
+async fn test_create_test_case_with_inline_steps_in_order() {
+    let mut server = mockito::Server::new_async().await;
+    let test_case = test_case_mock(&mut server).await;
+    let mut steps = Vec::new();
+    for order in 1..=3 {
+        steps.push(step_mock(&mut server, order, 200).await);
+    }
+
+    let result = CreateZephyrTestCaseTool::new(create_test_config(&server.url()))
+        .handle(arguments(3))
+        .await
+        .unwrap();
+
+    test_case.assert_async().await;
+    for step in steps {
+        step.assert_async().await;
+    }
+    assert_eq!(result.is_error, Some(false));
+    let text = &result.content[0].text;
+    assert!(text.contains("Key: STEP-1"), "{text}");
+    assert!(
+        text.ends_with(
+            "Steps created: 3\n1. Step 1 (step ID: 91)\n2. Step 2 (step ID: 92)\n3. Step 3 (step ID: 93)"
+        ),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_inline_step_failure_reports_created_steps() {
+    let mut server = mockito::Server::new_async().await;
+    let _test_case = test_case_mock(&mut server).await;
+    let first = step_mock(&mut server, 1, 200).await;
+    let second = step_mock(&mut server, 2, 400).await;
+    let third = server
+        .mock("POST", "/rest/zapi/latest/teststep/501")
+        .match_body(mockito::Matcher::PartialJson(json!({"order": 3})))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = CreateZephyrTestCaseTool::new(create_test_config(&server.url()))
+        .handle(arguments(4))
+        .await
+        .unwrap();
+
+    first.assert_async().await;
+    second.assert_async().await;
+    third.assert_async().await;
+    assert_eq!(result.is_error, Some(true));
+    let text = &result.content[0].text;
+    assert!(
+        text.contains("Steps created: 1\n1. Step 1 (step ID: 91)"),
+        "{text}"
+    );
+    assert!(
+        text.contains("Step 2 (\"Step 2\") could not be created"),
+        "{text}"
+    );
+    assert!(
+        text.ends_with(
+            "2 later steps were not tried. Resume with create_zephyr_test_step for test case ID 501 starting at order 2."
+        ),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn test_inline_steps_need_step_text() {
+    let server = mockito::Server::new_async().await;
+    let error = CreateZephyrTestCaseTool::new(create_test_config(&server.url()))
+        .handle(json!({
+            "name": "Checkout",
+            "project_key": "STEP",
+            "issue_type": "Test",
+            "steps": [{"step": "Open the cart"}, {"data": "no text"}]
+        }))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("step 2 needs a non-empty 'step' text"),
+        "{error}"
+    );
+}
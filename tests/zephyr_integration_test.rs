@@ -13,6 +13,7 @@ async fn test_zephyr_tools_registration() {
         strict_ssl: Some(true),
         default_project: None,
         log_file: None,
+        ..Default::default()
     };
 
     let _server = MCPServer::new(config);
@@ -81,11 +82,11 @@ async fn test_zephyr_tool_count() {
     let zephyr_tools: Vec<&rust_jira_mcp::types::mcp::MCPTool> =
         tools.iter().filter(|t| t.name.contains("zephyr")).collect();
 
-    // We should have 10 Zephyr tools
+    // We should have 16 Zephyr tools
     assert_eq!(
         zephyr_tools.len(),
-        10,
-        "Expected 10 Zephyr tools, found {}",
+        16,
+        "Expected 16 Zephyr tools, found {}",
         zephyr_tools.len()
     );
 
@@ -99,8 +100,14 @@ async fn test_zephyr_tool_count() {
         "create_zephyr_test_case",
         "get_zephyr_test_executions",
         "create_zephyr_test_execution",
+        "upload_zephyr_execution_attachment",
+        "get_zephyr_execution_attachments",
         "get_zephyr_test_cycles",
         "get_zephyr_test_plans",
+        "get_zephyr_folders",
+        "create_zephyr_folder",
+        "zephyr_cycle_report",
+        "zephyr_traceability_report",
     ];
 
     for expected_tool in expected_tools {
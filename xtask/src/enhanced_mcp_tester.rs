@@ -47,18 +47,6 @@ impl EnhancedMCPTester {
         Ok(key.to_string())
     }
 
-    /// Get real JQL query from collected data
-    fn get_real_jql_queries(&self) -> Vec<String> {
-        vec![
-            "project = DNA ORDER BY updated DESC".to_string(),
-            "project = DNA AND status in (Open, \"In Progress\", \"To Do\") ORDER BY updated DESC"
-                .to_string(),
-            "project = DNA AND assignee = garthdb ORDER BY updated DESC".to_string(),
-            "project = DNA AND labels is not EMPTY ORDER BY updated DESC".to_string(),
-            "project = DNA AND updated >= -7d ORDER BY updated DESC".to_string(),
-        ]
-    }
-
     /// Test operation with real data patterns
     pub async fn test_operation_with_real_data(
         &self,
@@ -194,7 +182,7 @@ impl EnhancedMCPTester {
         let issue_key = create_response
             .get("content")
             .and_then(|c| c.as_array())
-            .and_then(|arr| arr.get(0))
+            .and_then(|arr| arr.first())
             .and_then(|item| item.get("data"))
             .and_then(|data| data.get("key"))
             .and_then(|key| key.as_str())
@@ -343,7 +331,7 @@ impl EnhancedMCPTester {
         });
 
         info!("🔄 Bulk updating test issues...");
-        let bulk_update_response = self
+        let _bulk_update_response = self
             .send_mcp_request(
                 "bulk_update_issues",
                 bulk_update_data.as_object().unwrap().clone(),
@@ -357,7 +345,7 @@ impl EnhancedMCPTester {
         });
 
         info!("💬 Bulk adding comments...");
-        let bulk_comment_response = self
+        let _bulk_comment_response = self
             .send_mcp_request(
                 "bulk_add_comments",
                 bulk_comment_data.as_object().unwrap().clone(),
@@ -442,22 +430,4 @@ impl EnhancedMCPTester {
             anyhow::bail!("No JSON response found in MCP server output");
         }
     }
-
-    /// Extract issue key from search response (same as original implementation)
-    fn extract_issue_key(&self, response: &Value) -> Result<Option<String>> {
-        if let Some(result) = response.get("result") {
-            if let Some(issues) = result.get("issues") {
-                if let Some(issues_array) = issues.as_array() {
-                    if let Some(first_issue) = issues_array.first() {
-                        if let Some(key) = first_issue.get("key") {
-                            if let Some(key_str) = key.as_str() {
-                                return Ok(Some(key_str.to_string()));
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        Ok(None)
-    }
 }
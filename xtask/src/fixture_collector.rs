@@ -4,7 +4,7 @@ use std::fs;
 use std::io::Write;
 use std::path::Path;
 use std::process::{Command, Stdio};
-use tracing::{debug, error, info, warn};
+use tracing::info;
 
 pub struct FixtureCollector {
     request_id: u64,
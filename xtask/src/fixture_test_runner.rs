@@ -1,8 +1,8 @@
 use anyhow::{Context, Result};
-use serde_json::{json, Map, Value};
+use serde_json::{json, Value};
 use std::fs;
 use std::path::Path;
-use tracing::{debug, error, info, warn};
+use tracing::{info, warn};
 
 pub struct FixtureTestRunner {
     fixtures_dir: String,
@@ -80,7 +80,7 @@ impl FixtureTestRunner {
                 if !data.is_object() {
                     anyhow::bail!("Expected object for JiraUser");
                 }
-                if !data.get("name").is_some() || !data.get("displayName").is_some() {
+                if data.get("name").is_none() || data.get("displayName").is_none() {
                     anyhow::bail!("Missing required JiraUser fields");
                 }
             }
@@ -88,7 +88,7 @@ impl FixtureTestRunner {
                 if !data.is_object() {
                     anyhow::bail!("Expected object for JiraProject");
                 }
-                if !data.get("key").is_some() || !data.get("name").is_some() {
+                if data.get("key").is_none() || data.get("name").is_none() {
                     anyhow::bail!("Missing required JiraProject fields");
                 }
             }
@@ -96,7 +96,7 @@ impl FixtureTestRunner {
                 if !data.is_object() {
                     anyhow::bail!("Expected object for JiraIssue");
                 }
-                if !data.get("key").is_some() || !data.get("fields").is_some() {
+                if data.get("key").is_none() || data.get("fields").is_none() {
                     anyhow::bail!("Missing required JiraIssue fields");
                 }
             }
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
-use tracing::{error, info, warn};
+use tracing::{error, info};
 
 mod enhanced_mcp_tester;
 mod fixture_collector;
@@ -130,6 +130,26 @@ enum Commands {
         release: bool,
     },
 
+    /// Benchmark bulk updates against a local mock Jira at concurrency 1,
+    /// 4 and 8, writing the results as JSON
+    Bench {
+        /// Latency the mock Jira adds to every request, in milliseconds
+        #[arg(short, long, default_value = "20")]
+        latency_ms: u64,
+
+        /// File to write the results to
+        #[arg(short, long, default_value = "target/criterion/bulk_operations.json")]
+        output: PathBuf,
+    },
+
+    /// Write the tool schemas the configuration enables to a file, for
+    /// review or client configuration
+    DumpTools {
+        /// File to write the schemas to
+        #[arg(short, long, default_value = "tools.json")]
+        output: PathBuf,
+    },
+
     /// Run the MCP server
     Run {
         /// Configuration file path
@@ -266,6 +286,14 @@ async fn main() -> Result<()> {
             build_mcp_server(release).await?;
         }
 
+        Commands::Bench { latency_ms, output } => {
+            run_bulk_benchmark(latency_ms, &output).await?;
+        }
+
+        Commands::DumpTools { output } => {
+            dump_tool_schemas(&output).await?;
+        }
+
         Commands::Run { config } => {
             run_mcp_server(config).await?;
         }
@@ -299,6 +327,46 @@ async fn build_mcp_server(release: bool) -> Result<()> {
     Ok(())
 }
 
+async fn run_bulk_benchmark(latency_ms: u64, output: &std::path::Path) -> Result<()> {
+    info!(
+        "Benchmarking bulk updates with {}ms of mock latency...",
+        latency_ms
+    );
+
+    let status = std::process::Command::new("cargo")
+        .args(["bench", "--bench", "bulk_operations"])
+        .env("BULK_BENCH_LATENCY_MS", latency_ms.to_string())
+        .env("BULK_BENCH_OUTPUT", output)
+        .status()?;
+
+    if status.success() {
+        info!("✅ Benchmark results written to {:?}", output);
+    } else {
+        error!("❌ Benchmark failed");
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+async fn dump_tool_schemas(output: &std::path::Path) -> Result<()> {
+    info!("Writing tool schemas to {:?}...", output);
+
+    let status = std::process::Command::new("cargo")
+        .args(["run", "--bin", "rust-jira-mcp", "--", "--dump-tools"])
+        .arg(output)
+        .status()?;
+
+    if status.success() {
+        info!("✅ Tool schemas written to {:?}", output);
+    } else {
+        error!("❌ Could not write the tool schemas");
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
 async fn run_mcp_server(config: Option<PathBuf>) -> Result<()> {
     info!("Starting MCP server...");
 
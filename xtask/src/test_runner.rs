@@ -3,7 +3,7 @@ use serde_json::{json, Map, Value};
 use std::fs;
 use std::io::Write;
 use std::process::{Command, Stdio};
-use tracing::{debug, error, info, warn};
+use tracing::{info, warn};
 
 pub struct TestRunner {
     request_id: u64,